@@ -90,25 +90,30 @@ from daft.convert import (
     from_arrow,
     from_dask_dataframe,
     from_pandas,
+    from_polars,
     from_pydict,
     from_pylist,
     from_ray_dataset,
 )
 from daft.daft import ImageFormat, ImageMode, ResourceRequest
-from daft.dataframe import DataFrame
+from daft.dataframe import Constraint, DataFrame, StreamingWindowAggregation, ValidationError, VectorIndex
+from daft.dataframe.display import display_options_ctx, set_display_options
 from daft.logical.schema import Schema
 from daft.datatype import DataType, TimeUnit
-from daft.expressions import Expression, col, list_, lit, interval, struct, coalesce
+from daft.expressions import Expression, accumulator, col, element, list_, lit, interval, struct, coalesce
 from daft.io import (
     DataCatalogTable,
     DataCatalogType,
     from_glob_path,
     _range as range,
+    list_deltalake_versions,
+    list_iceberg_snapshots,
     read_csv,
     read_deltalake,
     read_hudi,
     read_iceberg,
     read_json,
+    read_kafka,
     read_parquet,
     read_sql,
     read_lance,
@@ -156,6 +161,7 @@ to_struct = Expression.to_struct
 
 __all__ = [
     "Catalog",
+    "Constraint",
     "DataCatalogTable",
     "DataCatalogType",
     "DataFrame",
@@ -168,9 +174,13 @@ __all__ = [
     "Schema",
     "Series",
     "Session",
+    "StreamingWindowAggregation",
     "Table",
     "TimeUnit",
+    "ValidationError",
+    "VectorIndex",
     "Window",
+    "accumulator",
     "attach",
     "attach_catalog",
     "attach_function",
@@ -188,13 +198,16 @@ __all__ = [
     "detach_catalog",
     "detach_function",
     "detach_table",
+    "display_options_ctx",
     "drop_namespace",
     "drop_table",
+    "element",
     "execution_config_ctx",
     "from_arrow",
     "from_dask_dataframe",
     "from_glob_path",
     "from_pandas",
+    "from_polars",
     "from_pydict",
     "from_pylist",
     "from_ray_dataset",
@@ -206,6 +219,8 @@ __all__ = [
     "interval",
     "list_",
     "list_catalogs",
+    "list_deltalake_versions",
+    "list_iceberg_snapshots",
     "list_tables",
     "lit",
     "planning_config_ctx",
@@ -215,6 +230,7 @@ __all__ = [
     "read_hudi",
     "read_iceberg",
     "read_json",
+    "read_kafka",
     "read_lance",
     "read_parquet",
     "read_sql",
@@ -224,6 +240,7 @@ __all__ = [
     "register_table",
     "register_viz_hook",
     "set_catalog",
+    "set_display_options",
     "set_execution_config",
     "set_namespace",
     "set_planning_config",
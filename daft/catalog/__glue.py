@@ -40,6 +40,7 @@ def load_glue(
     aws_access_key_id: str | None = None,
     aws_secret_access_key: str | None = None,
     aws_session_token: str | None = None,
+    io_config: IOConfig | None = None,
 ) -> GlueCatalog:
     """Creates a GlueCatalog with some give name and optional boto3 client configuration.
 
@@ -53,10 +54,20 @@ def load_glue(
         aws_access_key_id (str, optional): AWS access key ID for authentication.
         aws_secret_access_key (str, optional): AWS secret access key for authentication.
         aws_session_token (str, optional): AWS session token for temporary credentials.
+        io_config (IOConfig, optional): The IOConfig to read and write the data of the tables with. Its S3
+            credentials, region and endpoint are also used for Glue when not given explicitly, and if it isn't
+            provided, one is created from the region and credentials of the Glue client.
 
     Returns:
         GlueCatalog: Catalog instance backed by an AWS Glue Database.
     """
+    if io_config is not None:
+        s3 = io_config.s3
+        region_name = region_name if region_name is not None else s3.region_name
+        if aws_access_key_id is None and aws_secret_access_key is None:
+            aws_access_key_id = s3.key_id
+            aws_secret_access_key = s3.access_key
+            aws_session_token = aws_session_token if aws_session_token is not None else s3.session_token
     c = GlueCatalog.__new__(GlueCatalog)
     c._name = name
     options: dict[str, Any] = {}
@@ -77,6 +88,19 @@ def load_glue(
     if aws_session_token is not None:
         options["aws_session_token"] = aws_session_token
     c._client = boto3.client("glue", **options)
+    if io_config is None:
+        from daft.daft import S3Config
+        from daft.io import IOConfig
+
+        io_config = IOConfig(
+            s3=S3Config(
+                region_name=c._client.meta.region_name,
+                key_id=aws_access_key_id,
+                access_key=aws_secret_access_key,
+                session_token=aws_session_token,
+            )
+        )
+    c._io_config = io_config
     return c
 
 
@@ -85,6 +109,7 @@ class GlueCatalog(Catalog):
 
     _name: str
     _client: GlueClient
+    _io_config: IOConfig | None = None
 
     # !! PATCH HERE TO PROVIDE CUSTOM GLUE TABLE IMPLEMENTATIONS !!
     _table_impls: list[type[GlueTable]] = []
@@ -103,19 +128,21 @@ class GlueCatalog(Catalog):
         raise ValueError("GlueCatalog.__init__() not supported!")
 
     @staticmethod
-    def from_client(name: str, client: GlueClient) -> GlueCatalog:
-        """Creates a GlueCatalog using the given boto3 client."""
+    def from_client(name: str, client: GlueClient, io_config: IOConfig | None = None) -> GlueCatalog:
+        """Creates a GlueCatalog using the given boto3 client, reading tables in the client's region by default."""
         c = GlueCatalog.__new__(GlueCatalog)
         c._name = name
         c._client = client
+        c._io_config = io_config if io_config is not None else _io_config_from_region(client.meta.region_name)
         return c
 
     @staticmethod
-    def from_session(name: str, session: Session) -> GlueCatalog:
-        """Creates a GlueCatalog using the boto3 session to get a glue client."""
+    def from_session(name: str, session: Session, io_config: IOConfig | None = None) -> GlueCatalog:
+        """Creates a GlueCatalog using the boto3 session to get a glue client, and its credentials to read tables by default."""
         c = GlueCatalog.__new__(GlueCatalog)
         c._name = name
         c._client = session.client("glue")
+        c._io_config = io_config if io_config is not None else _io_config_from_session(session)
         return c
 
     @property
@@ -342,15 +369,15 @@ class GlueCsvTable(GlueTable):
         t = GlueCsvTable.__new__(GlueCsvTable)
         t._catalog = catalog
         t._table = table
-        t._io_config = None  # todo
+        t._io_config = catalog._io_config
 
         # parse csv format information
-        t._schema = _convert_glue_schema(table["StorageDescriptor"]["Columns"])
+        t._schema = _convert_glue_table_schema(table)
         t._path = table["StorageDescriptor"]["Location"]
         t._has_headers = parameters.get("skip.header.line.count", "0") == "1"
         t._delimiter = parameters.get("delimiter", ",")
-        t._hive_partitioning = False
-        t._hive_partitioning_cols = []
+        t._hive_partitioning_cols = [key["Name"] for key in table.get("PartitionKeys", [])]
+        t._hive_partitioning = len(t._hive_partitioning_cols) > 0
 
         return t
 
@@ -378,6 +405,7 @@ class GlueCsvTable(GlueTable):
             root_dir=self._path,
             write_mode=mode,
             partition_cols=(self._hive_partitioning_cols if self._hive_partitioning else None),  # type: ignore
+            io_config=self._io_config,
         )
 
 
@@ -408,11 +436,13 @@ class GlueParquetTable(GlueTable):
         t = GlueParquetTable.__new__(GlueParquetTable)
         t._catalog = catalog
         t._table = table
-        t._io_config = None  # todo
+        t._io_config = catalog._io_config
 
         # parse parquet format information
-        t._schema = _convert_glue_schema(table["StorageDescriptor"]["Columns"])
+        t._schema = _convert_glue_table_schema(table)
         t._path = table["StorageDescriptor"]["Location"]
+        t._hive_partitioning_cols = [key["Name"] for key in table.get("PartitionKeys", [])]
+        t._hive_partitioning = len(t._hive_partitioning_cols) > 0
 
         return t
 
@@ -465,7 +495,7 @@ class GlueIcebergTable(GlueTable):
         t = GlueIcebergTable.__new__(GlueIcebergTable)
         t._catalog = catalog
         t._table = table
-        t._io_config = None  # todo
+        t._io_config = catalog._io_config
         t._pyiceberg_table = cls._create_pyiceberg_table(catalog, table)
 
         return t
@@ -477,7 +507,7 @@ class GlueIcebergTable(GlueTable):
         # cannot create a pyiceberg GlueCatalog with custom client directly
         gc = PyIcebergGlueCatalog.__new__(PyIcebergGlueCatalog)
         gc.name = catalog.name
-        gc.properties = {}
+        gc.properties = _io_config_to_pyiceberg_properties(catalog._io_config)
         gc.glue = catalog._client
 
         # the pyiceberg table will hold a ref to gc
@@ -507,8 +537,8 @@ class GlueDeltaTable(GlueTable):
     def from_table_info(cls, catalog: GlueCatalog, table: GlueTableInfo) -> GlueTable:
         parameters: Parameters = table.get("Parameters", {})
 
-        # verify we have table_type = "delta"
-        table_type = parameters.get("table_type")
+        # verify we have table_type = "delta", which tables created by Spark record as their provider instead
+        table_type = parameters.get("table_type", parameters.get("spark.sql.sources.provider"))
         if table_type is None:
             raise ValueError("GlueDeltaTable is missing the required 'table_type' parameter.")
         if table_type.lower() != "delta":
@@ -517,8 +547,8 @@ class GlueDeltaTable(GlueTable):
         t = GlueDeltaTable.__new__(GlueDeltaTable)
         t._catalog = catalog
         t._table = table
-        t._io_config = None  # todo
-        t._unity_catalog_table = cls._create_unity_catalog_table(table, None)
+        t._io_config = catalog._io_config
+        t._unity_catalog_table = cls._create_unity_catalog_table(table, t._io_config)
 
         return t
 
@@ -554,6 +584,53 @@ class GlueDeltaTable(GlueTable):
         raise NotImplementedError
 
 
+def _io_config_from_region(region_name: str | None) -> IOConfig:
+    from daft.daft import S3Config
+    from daft.io import IOConfig
+
+    return IOConfig(s3=S3Config(region_name=region_name))
+
+
+def _io_config_from_session(session: Session) -> IOConfig:
+    from daft.daft import S3Config
+    from daft.io import IOConfig
+
+    # Only credentials that the session was created with are copied, others are resolved from its profile so that
+    # they are refreshed when they expire.
+    credentials = session.get_credentials()
+    if credentials is None or credentials.method != "explicit":
+        return IOConfig(s3=S3Config(region_name=session.region_name, profile_name=session.profile_name))
+    credentials = credentials.get_frozen_credentials()
+    return IOConfig(
+        s3=S3Config(
+            region_name=session.region_name,
+            key_id=credentials.access_key,
+            access_key=credentials.secret_key,
+            session_token=credentials.token,
+        )
+    )
+
+
+def _io_config_to_pyiceberg_properties(io_config: IOConfig | None) -> dict[str, str]:
+    """Converts the S3 credentials of an IOConfig to the FileIO properties that pyiceberg reads table metadata with."""
+    if io_config is None:
+        return {}
+    s3 = io_config.s3
+    properties = {
+        "s3.region": s3.region_name,
+        "s3.endpoint": s3.endpoint_url,
+        "s3.access-key-id": s3.key_id,
+        "s3.secret-access-key": s3.access_key,
+        "s3.session-token": s3.session_token,
+    }
+    return {key: value for key, value in properties.items() if value is not None}
+
+
+def _convert_glue_table_schema(table: GlueTableInfo) -> Schema:
+    """Converts the schema of a Glue table, which lists its partition keys separately from its columns."""
+    return _convert_glue_schema(table["StorageDescriptor"]["Columns"] + table.get("PartitionKeys", []))
+
+
 def _convert_glue_schema(columns: list[GlueColumnInfo]) -> Schema:
     return Schema._from_fields([_convert_glue_column(column) for column in columns])
 
@@ -562,17 +639,16 @@ def _convert_glue_column(column: GlueColumnInfo) -> Field:
     return Field.create(column["Name"], _convert_glue_type(column["Type"]))
 
 
-# TODO update to support more Hive types.
 # https://cwiki.apache.org/confluence/display/hive/languagemanual+types
 def _convert_glue_type(type: str) -> DataType:
-    type = type.lower()
+    type = type.strip().lower()
     if type == "boolean":
         return DataType.bool()
-    elif type == "byte":
+    elif type == "byte" or type == "tinyint":
         return DataType.int8()
-    elif type == "short":
+    elif type == "short" or type == "smallint":
         return DataType.int16()
-    elif type == "integer":
+    elif type == "integer" or type == "int":
         return DataType.int32()
     elif type == "long" or type == "bigint":
         return DataType.int64()
@@ -582,11 +658,44 @@ def _convert_glue_type(type: str) -> DataType:
         return DataType.float64()
     elif type == "decimal":
         return DataType.decimal128(precision=38, scale=18)
-    elif type == "string":
+    elif type.startswith("decimal(") and type.endswith(")"):
+        precision, scale = type[len("decimal(") : -1].split(",")
+        return DataType.decimal128(precision=int(precision), scale=int(scale))
+    elif type == "string" or type.startswith("varchar(") or type.startswith("char("):
         return DataType.string()
+    elif type == "binary":
+        return DataType.binary()
     elif type == "timestamp":
         return DataType.timestamp(timeunit="us", timezone="UTC")
     elif type == "date":
         return DataType.date()
+    elif type.startswith("array<") and type.endswith(">"):
+        return DataType.list(_convert_glue_type(type[len("array<") : -1]))
+    elif type.startswith("map<") and type.endswith(">"):
+        key, value = _split_glue_type_params(type[len("map<") : -1])
+        return DataType.map(_convert_glue_type(key), _convert_glue_type(value))
+    elif type.startswith("struct<") and type.endswith(">"):
+        fields = {}
+        for field in _split_glue_type_params(type[len("struct<") : -1]):
+            name, field_type = field.split(":", 1)
+            fields[name.strip()] = _convert_glue_type(field_type)
+        return DataType.struct(fields)
     else:
         raise ValueError(f"Unsupported Glue type: {type}")
+
+
+def _split_glue_type_params(params: str) -> list[str]:
+    """Splits the comma-separated parameters of a nested Glue type, ignoring the commas of nested types."""
+    parts = []
+    depth = 0
+    start = 0
+    for i, c in enumerate(params):
+        if c in "<(":
+            depth += 1
+        elif c in ">)":
+            depth -= 1
+        elif c == "," and depth == 0:
+            parts.append(params[start:i].strip())
+            start = i + 1
+    parts.append(params[start:].strip())
+    return parts
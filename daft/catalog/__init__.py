@@ -54,6 +54,7 @@ from daft.logical.schema import Schema
 if TYPE_CHECKING:
     from daft.dataframe.dataframe import ColumnInputType
     from daft.convert import InputListType
+    from daft.daft import IOConfig
 
 
 __all__ = [
@@ -321,6 +322,7 @@ class Catalog(ABC):
         name: str,
         client: object | None = None,
         session: object | None = None,
+        io_config: IOConfig | None = None,
     ) -> Catalog:
         """Creates a Daft Catalog backed by the AWS Glue service, with optional client or session.
 
@@ -334,6 +336,8 @@ class Catalog(ABC):
             type (Literal["iceberg"]): optional catalog type
             client: optional boto3 client
             session: optional boto3 session
+            io_config: optional IOConfig to read and write the data of tables with, which defaults to one with the
+                region (and, for sessions, the credentials) of the client or session
             **options: additional options for boto3 client creation
 
         Returns:
@@ -345,9 +349,9 @@ class Catalog(ABC):
             if client is not None and session is not None:
                 raise ValueError("Can provide either a client or session but not both.")
             elif client is not None:
-                return GlueCatalog.from_client(name, client)
+                return GlueCatalog.from_client(name, client, io_config)
             elif session is not None:
-                return GlueCatalog.from_session(name, session)
+                return GlueCatalog.from_session(name, session, io_config)
             else:
                 raise ValueError("Must provide either a client or session.")
         except ImportError:
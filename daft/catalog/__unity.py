@@ -143,7 +143,23 @@ class UnityTable(Table):
     def read(self, **options) -> DataFrame:
         Table._validate_options("Unity read", options, UnityTable._read_options)
 
-        return read_deltalake(self._inner, version=options.get("version"))
+        data_source_format = str(self._inner.table_info.data_source_format or "DELTA").upper()
+        if data_source_format == "DELTA":
+            return read_deltalake(self._inner, version=options.get("version"))
+        elif data_source_format == "PARQUET":
+            from daft.io._parquet import read_parquet
+
+            return read_parquet(self._inner.table_uri, io_config=self._inner.io_config)
+        elif data_source_format == "CSV":
+            from daft.io._csv import read_csv
+
+            return read_csv(self._inner.table_uri, io_config=self._inner.io_config)
+        elif data_source_format == "JSON":
+            from daft.io._json import read_json
+
+            return read_json(self._inner.table_uri, io_config=self._inner.io_config)
+        else:
+            raise NotImplementedError(f"Reading Unity tables of format {data_source_format} is not yet supported.")
 
     ###
     # write methods
@@ -1,10 +1,130 @@
+from __future__ import annotations
+
 import sys
+from typing import TYPE_CHECKING
 
 from daft.daft import cli
 
+if TYPE_CHECKING:
+    from daft.dataframe import DataFrame
+    from daft.session import Session
+
+REPL_HELP = """\
+Statements end with a semicolon, and may span several lines.
+
+  \\dt          list the tables of the session
+  \\d <table>   print the schema of a table
+  \\?           print this help
+  \\q           quit
+"""
+
+
+def _run(sess: Session, sql: str) -> DataFrame | None:
+    return sess.sql(sql.strip().rstrip(";"))
+
+
+def _write(df: DataFrame, output: str) -> None:
+    """Writes the results of a query to a single Parquet or CSV file, streaming them as they're computed."""
+    import pyarrow.csv as pacsv
+    import pyarrow.parquet as pq
+
+    reader = df.to_arrow_reader()
+    if output.endswith(".parquet"):
+        writer = pq.ParquetWriter(output, reader.schema)
+    elif output.endswith(".csv"):
+        writer = pacsv.CSVWriter(output, reader.schema)
+    else:
+        raise ValueError(f"Unsupported output format of {output}, expected a .parquet or .csv file")
+    with writer:
+        for batch in reader:
+            writer.write_batch(batch)
+
+
+def run_query(sql: str, output: str | None = None, limit: int = 20) -> None:
+    """Runs a SQL query in the current session, and prints up to `limit` rows of its results or writes them to `output`."""
+    from daft.session import current_session
+
+    df = _run(current_session(), sql)
+    if df is None:
+        return
+    if output is not None:
+        _write(df, output)
+    else:
+        df.show(limit)
+
+
+def print_schema(path: str) -> None:
+    """Prints the schema of a Parquet, CSV or JSON file, inferred from its extension."""
+    import daft
+
+    if path.endswith(".csv"):
+        df = daft.read_csv(path)
+    elif path.endswith((".json", ".jsonl")):
+        df = daft.read_json(path)
+    else:
+        df = daft.read_parquet(path)
+    print(df.schema())
+
+
+def _run_command(sess: Session, command: str) -> None:
+    name, _, arg = command.partition(" ")
+    if name == "\\dt":
+        for table in sess.list_tables():
+            print(table)
+    elif name == "\\d" and arg.strip():
+        print(sess.read_table(arg.strip()).schema())
+    else:
+        print(REPL_HELP)
+
+
+def run_repl() -> None:
+    """Runs an interactive SQL shell in the current session, until it's quit or its input ends."""
+    from daft.session import current_session
+
+    try:
+        import readline  # noqa: F401
+    except ImportError:
+        pass
+
+    sess = current_session()
+    print("Daft SQL shell. Type \\? for help, or \\q to quit.")
+    lines: list[str] = []
+    while True:
+        try:
+            line = input("daft> " if not lines else "   -> ")
+        except EOFError:
+            print()
+            return
+        except KeyboardInterrupt:
+            print()
+            lines.clear()
+            continue
+
+        if not lines and line.strip().startswith("\\"):
+            if line.strip() == "\\q":
+                return
+            _run_command(sess, line.strip())
+            continue
+
+        lines.append(line)
+        if not line.rstrip().endswith(";"):
+            continue
+        sql = "\n".join(lines)
+        lines.clear()
+        try:
+            df = _run(sess, sql)
+            if df is not None:
+                df.show()
+        except Exception as e:
+            print(f"Error: {e}", file=sys.stderr)
+
 
 def main():
-    cli(sys.argv)
+    try:
+        cli(sys.argv)
+    except Exception as e:
+        print(f"Error: {e}", file=sys.stderr)
+        sys.exit(1)
 
 
 if __name__ == "__main__":
@@ -146,6 +146,8 @@ def planning_config_ctx(**kwargs):
 def set_planning_config(
     config: PyDaftPlanningConfig | None = None,
     default_io_config: IOConfig | None = None,
+    enable_cost_based_planning: bool | None = None,
+    enable_plan_cache: bool | None = None,
 ) -> DaftContext:
     """Globally sets various configuration parameters which control Daft plan construction behavior.
 
@@ -156,6 +158,11 @@ def set_planning_config(
             that the old (current) config should be used.
         default_io_config: A default IOConfig to use in the absence of one being explicitly passed into any Expression (e.g. `.url.download()`)
             or Dataframe operation (e.g. `daft.read_parquet()`).
+        enable_cost_based_planning: Whether the planner should use statistics collected by `DataFrame.analyze()` to estimate
+            cardinalities for join strategies, build sides, and partition counts. Defaults to False.
+        enable_plan_cache: Whether to reuse the optimized plans and materialized results of previously executed queries
+            whose plans have the same fingerprint, within the current session. Sources are assumed to be unchanged
+            unless they report a new snapshot id, e.g. for Iceberg and Delta Lake tables. Defaults to False.
     """
     # Replace values in the DaftPlanningConfig with user-specified overrides
     ctx = get_context()
@@ -163,6 +170,8 @@ def set_planning_config(
         old_daft_planning_config = ctx._ctx._daft_planning_config if config is None else config
         new_daft_planning_config = old_daft_planning_config.with_config_values(
             default_io_config=default_io_config,
+            enable_cost_based_planning=enable_cost_based_planning,
+            enable_plan_cache=enable_plan_cache,
         )
 
         ctx._ctx._daft_planning_config = new_daft_planning_config
@@ -188,7 +197,9 @@ def set_execution_config(
     broadcast_join_size_bytes_threshold: int | None = None,
     parquet_split_row_groups_max_files: int | None = None,
     sort_merge_join_sort_with_aligned_boundaries: bool | None = None,
+    sort_merge_join_size_bytes_threshold: int | None = None,
     hash_join_partition_size_leniency: float | None = None,
+    dynamic_partition_pruning_max_keys: int | None = None,
     sample_size_for_sort: int | None = None,
     num_preview_rows: int | None = None,
     parquet_target_filesize: int | None = None,
@@ -201,6 +212,7 @@ def set_execution_config(
     high_cardinality_aggregation_threshold: float | None = None,
     read_sql_partition_size_bytes: int | None = None,
     enable_aqe: bool | None = None,
+    aqe_target_partition_size_bytes: int | None = None,
     enable_native_executor: bool | None = None,
     default_morsel_size: int | None = None,
     shuffle_algorithm: str | None = None,
@@ -208,6 +220,29 @@ def set_execution_config(
     flight_shuffle_dirs: list[str] | None = None,
     enable_ray_tracing: bool | None = None,
     scantask_splitting_level: int | None = None,
+    buffer_allocator: str | None = None,
+    buffer_pool_max_bytes: int | None = None,
+    persist_memory_limit_bytes: int | None = None,
+    operator_memory_limit_bytes: int | None = None,
+    query_memory_limit_bytes: int | None = None,
+    compute_threads: int | None = None,
+    io_threads: int | None = None,
+    thread_affinity: str | None = None,
+    shuffle_compression: str | None = None,
+    shuffle_compression_level: int | None = None,
+    external_shuffle_dir: str | None = None,
+    enable_speculative_execution: bool | None = None,
+    speculation_quantile: float | None = None,
+    speculation_multiplier: float | None = None,
+    speculation_min_runtime_ms: int | None = None,
+    checkpoint_dir: str | None = None,
+    enable_opentelemetry: bool | None = None,
+    upload_part_size: int | None = None,
+    max_inflight_upload_parts: int | None = None,
+    task_max_attempts: int | None = None,
+    task_retry_initial_backoff_ms: int | None = None,
+    task_retry_max_backoff_ms: int | None = None,
+    deterministic: bool | None = None,
 ) -> DaftContext:
     """Globally sets various configuration parameters which control various aspects of Daft execution.
 
@@ -230,9 +265,15 @@ def set_execution_config(
         sort_merge_join_sort_with_aligned_boundaries: Whether to use a specialized algorithm for sorting both sides of a
             sort-merge join such that they have aligned boundaries. This can lead to a faster merge-join at the cost of
             more skewed sorted join inputs, increasing the risk of OOMs.
+        sort_merge_join_size_bytes_threshold: If each partition of the smaller side of an inner join is estimated to be larger than
+            this threshold, a sort-merge join will be used instead of a hash join, since its hash table may not fit in memory.
+            Defaults to 1 GiB.
         hash_join_partition_size_leniency: If the left side of a hash join is already correctly partitioned and the right side isn't,
             and the ratio between the left and right size is at least this value, then the right side is repartitioned to have an equal
             number of partitions as the left. Defaults to 0.5.
+        dynamic_partition_pruning_max_keys: If the build side of a hash join on the Native Runner has at most this many rows, its join keys
+            are used to skip files of a partitioned scan on the probe side whose partition values cannot match. Set to 0 to disable
+            dynamic partition pruning. Defaults to 10000 rows.
         sample_size_for_sort: number of elements to sample from each partition when running sort,
             Default is 20.
         num_preview_rows: number of rows to when showing a dataframe preview,
@@ -247,13 +288,82 @@ def set_execution_config(
         high_cardinality_aggregation_threshold: Threshold selectivity for performing high cardinality aggregations on the Native Runner. Defaults to 0.8.
         read_sql_partition_size_bytes: Target size of partition when reading from SQL databases. Defaults to 512MB
         enable_aqe: Enables Adaptive Query Execution, Defaults to False
+        aqe_target_partition_size_bytes: Target size of partitions produced by a shuffle when Adaptive Query Execution is enabled.
+            Once a shuffle's input has been materialized, its number of output partitions is reduced so that partitions are at
+            least roughly this large. Defaults to 128MB
         enable_native_executor: Enables the native executor, Defaults to False
         default_morsel_size: Default size of morsels used for the new local executor. Defaults to 131072 rows.
-        shuffle_algorithm: The shuffle algorithm to use. Defaults to "auto", which will let Daft determine the algorithm. Options are "map_reduce" and "pre_shuffle_merge".
+        shuffle_algorithm: The shuffle algorithm to use. Defaults to "auto", which will let Daft determine the algorithm. Options are "map_reduce", "pre_shuffle_merge",
+            "flight_shuffle", and "external_shuffle".
         pre_shuffle_merge_threshold: Memory threshold in bytes for pre-shuffle merge. Defaults to 1GB
-        flight_shuffle_dirs: The directories to use for flight shuffle. Defaults to ["/tmp"].
+        flight_shuffle_dirs: The directories to use for flight shuffle. These can be local paths, including volumes shared by all
+            nodes such as an NVMe array, or object store URLs (e.g. "s3://bucket/prefix"), which are accessed with the default
+            IOConfig of the planning config. Defaults to ["/tmp"].
         enable_ray_tracing: Enable tracing for Ray. Accessible in `/tmp/ray/session_latest/logs/daft` after the run completes. Defaults to False.
         scantask_splitting_level: How aggressively to split scan tasks. Setting this to `2` will use a more aggressive ScanTask splitting algorithm which might be more expensive to run but results in more even splits of partitions. Defaults to 1.
+        buffer_allocator: Where the native executor allocates large buffers from. Defaults to "heap". Options are "pooled", which reuses
+            released buffers instead of returning them to the system allocator, and "huge_pages", which additionally backs them by transparent huge pages on Linux.
+        buffer_pool_max_bytes: Maximum number of bytes of released buffers kept for reuse by the "pooled" and "huge_pages" allocators. Defaults to 1GB
+        persist_memory_limit_bytes: Maximum number of bytes of persisted DataFrame results kept in memory on the local runners. Partitions
+            persisted beyond this limit are spilled to disk and read back when they are scanned. Defaults to 4GB
+        operator_memory_limit_bytes: Maximum number of bytes of state held in memory by a hash aggregation or the build side of an
+            inner hash join on the Native Runner. Beyond this limit, the state is partitioned by key and spilled to disk, and the
+            partitions are processed one at a time. Defaults to 4GB
+        query_memory_limit_bytes: Maximum number of bytes held in memory by all the operators of a query on the Native Runner.
+            Hash aggregations and inner hash join builds share what the other operators leave of this budget, and spill to disk
+            once they hold more than their share. Defaults to 0, which uses the memory limit of the process
+        compute_threads: Number of threads in the pool that the Native Runner runs operators on. Defaults to 0, which uses the
+            number of threads the runner was created with, or else the number of CPUs
+        io_threads: Number of threads in the pool that reads and writes of remote and local files run on. Defaults to 0, which
+            uses the number of CPUs, up to 8
+        thread_affinity: How the threads of the compute pool are placed on the cores of the machine. "none" leaves it to the
+            operating system, "cores" pins each thread to its own core, and "numa" also pins each thread to its own core, but
+            fills up the cores of one NUMA node before moving on to the next. Pinning is only supported on Linux. Defaults to "none"
+        shuffle_compression: Compression codec for the files written by flight shuffle and external shuffle. Options are "none", "lz4", and "zstd".
+            Defaults to "none"
+        shuffle_compression_level: Compression level for "zstd" shuffle compression. Defaults to 0, which uses the codec's default
+        external_shuffle_dir: The directory that external shuffle writes its files and manifests to on the Ray Runner, which must be
+            reachable from every node, e.g. an object store URL (e.g. "s3://bucket/prefix") or a volume shared by all nodes. Since reduce
+            tasks only read from this directory, they can be retried on any node when the node that ran a map task is lost.
+            Required when shuffle_algorithm is "external_shuffle". Defaults to ""
+        enable_speculative_execution: Whether the Ray Runner runs a second copy of straggling tasks on other workers, and uses the
+            result of whichever copy finishes first. Tasks that write files or run on actor pools are never speculated. Defaults to False
+        speculation_quantile: Fraction of the tasks of a stage that must be done before its stragglers are speculated. Defaults to 0.75
+        speculation_multiplier: How many times longer than the median runtime of the finished tasks of its stage a task must run
+            to be a straggler. Defaults to 1.5
+        speculation_min_runtime_ms: Minimum runtime in milliseconds of a task before it may be speculated. Defaults to 10000
+        checkpoint_dir: Directory, e.g. an object store URL, that the Ray Runner and PyRunner persist the output of every completed
+            stage of a query to, keyed by the fingerprint of the query's plan. When the same query is run again, e.g. after the job
+            failed or was preempted, the stages that completed before are read back from the directory instead of being run. Queries
+            run in stages as with `enable_aqe` while this is set. Queries that scan in-memory data aren't checkpointed, and checkpoints
+            aren't invalidated by changes that don't show in the plan, such as to the code of a UDF. Defaults to "", which disables
+            checkpointing
+        enable_opentelemetry: Whether to record the execution of queries as OpenTelemetry spans and metrics. Every query gets a span,
+            and on the Ray Runner and PyRunner, so does each of its stages, and on the Ray Runner, each task and each of its
+            instructions. Metrics cover the duration of tasks, the rows and bytes output and time taken by each kind of instruction,
+            and the number of speculative attempts and retries. Unless the process has already set up OpenTelemetry, spans and
+            metrics are exported over OTLP as configured by the standard `OTEL_*` environment variables, which needs
+            `pip install 'daft[otel]'`. Defaults to False
+        upload_part_size: Size in bytes of the parts that Parquet, CSV and Iceberg files written to S3 or GCS are uploaded in while they
+            are being written. Must be at least 5MiB. Defaults to 8MiB
+        max_inflight_upload_parts: Maximum number of parts of a file being written to S3 or GCS that are uploaded at once. Writing blocks
+            while that many are in flight, so that a slow object store throttles the query rather than encoded data piling up
+            in memory. Defaults to 4
+        task_max_attempts: Maximum number of times the Ray Runner runs a task whose failure is likely transient, such as a timeout
+            reading from an object store or the loss of the worker running it. Only the failed task is run again, from the same
+            inputs. Once a task has failed this many times, the query fails with a `daft.runners.retry.PartitionTaskFailedError`
+            that reports the inputs of the task and the error of each attempt. Tasks that run on actor pools aren't retried.
+            Defaults to 3
+        task_retry_initial_backoff_ms: How long in milliseconds the Ray Runner waits before retrying a task the first time. The wait
+            doubles with each further attempt. Defaults to 1000
+        task_retry_max_backoff_ms: Maximum time in milliseconds the Ray Runner waits before retrying a task. Defaults to 30000
+        deterministic: Whether the native runner guarantees that queries return the same rows in the same order on every run over
+            the same inputs, at a performance cost. Sorts break ties by the order of their input rows, the outputs of parallel
+            workers are combined in a fixed order, seeded samples always pick the same rows, and writes assign the same rows to each
+            file. Sorts and top-N's run on a single worker, and aggregations, joins and writes wait on their inputs in order.
+            Can also be set with the `DAFT_DETERMINISTIC` environment variable. Defaults to False
+
+        The thread pools are shared by all queries of the process, so changes to them apply to the queries started afterwards.
     """
     # Replace values in the DaftExecutionConfig with user-specified overrides
     ctx = get_context()
@@ -267,7 +377,9 @@ def set_execution_config(
             broadcast_join_size_bytes_threshold=broadcast_join_size_bytes_threshold,
             parquet_split_row_groups_max_files=parquet_split_row_groups_max_files,
             sort_merge_join_sort_with_aligned_boundaries=sort_merge_join_sort_with_aligned_boundaries,
+            sort_merge_join_size_bytes_threshold=sort_merge_join_size_bytes_threshold,
             hash_join_partition_size_leniency=hash_join_partition_size_leniency,
+            dynamic_partition_pruning_max_keys=dynamic_partition_pruning_max_keys,
             sample_size_for_sort=sample_size_for_sort,
             num_preview_rows=num_preview_rows,
             parquet_target_filesize=parquet_target_filesize,
@@ -280,6 +392,7 @@ def set_execution_config(
             high_cardinality_aggregation_threshold=high_cardinality_aggregation_threshold,
             read_sql_partition_size_bytes=read_sql_partition_size_bytes,
             enable_aqe=enable_aqe,
+            aqe_target_partition_size_bytes=aqe_target_partition_size_bytes,
             enable_native_executor=enable_native_executor,
             default_morsel_size=default_morsel_size,
             shuffle_algorithm=shuffle_algorithm,
@@ -287,6 +400,29 @@ def set_execution_config(
             pre_shuffle_merge_threshold=pre_shuffle_merge_threshold,
             enable_ray_tracing=enable_ray_tracing,
             scantask_splitting_level=scantask_splitting_level,
+            buffer_allocator=buffer_allocator,
+            buffer_pool_max_bytes=buffer_pool_max_bytes,
+            persist_memory_limit_bytes=persist_memory_limit_bytes,
+            operator_memory_limit_bytes=operator_memory_limit_bytes,
+            query_memory_limit_bytes=query_memory_limit_bytes,
+            compute_threads=compute_threads,
+            io_threads=io_threads,
+            thread_affinity=thread_affinity,
+            shuffle_compression=shuffle_compression,
+            shuffle_compression_level=shuffle_compression_level,
+            external_shuffle_dir=external_shuffle_dir,
+            enable_speculative_execution=enable_speculative_execution,
+            speculation_quantile=speculation_quantile,
+            speculation_multiplier=speculation_multiplier,
+            speculation_min_runtime_ms=speculation_min_runtime_ms,
+            checkpoint_dir=checkpoint_dir,
+            enable_opentelemetry=enable_opentelemetry,
+            upload_part_size=upload_part_size,
+            max_inflight_upload_parts=max_inflight_upload_parts,
+            task_max_attempts=task_max_attempts,
+            task_retry_initial_backoff_ms=task_retry_initial_backoff_ms,
+            task_retry_max_backoff_ms=task_retry_max_backoff_ms,
+            deterministic=deterministic,
         )
 
         ctx._ctx._daft_execution_config = new_daft_execution_config
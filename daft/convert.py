@@ -8,6 +8,7 @@ if TYPE_CHECKING:
     import dask
     import numpy as np
     import pandas as pd
+    import polars as pl
     import pyarrow as pa
     from ray.data.dataset import Dataset as RayDataset
 
@@ -84,6 +85,9 @@ def from_pydict(data: Dict[str, InputListType]) -> "DataFrame":
 def from_arrow(data: Union["pa.Table", List["pa.Table"], Iterable["pa.Table"]]) -> "DataFrame":
     """Creates a DataFrame from a pyarrow Table.
 
+    Tables of other Arrow libraries, such as polars DataFrames, are accepted if they implement the
+    Arrow PyCapsule interface (``__arrow_c_stream__``).
+
     Args:
         data: pyarrow Table(s) that we wish to convert into a Daft DataFrame.
 
@@ -150,6 +154,45 @@ def from_pandas(data: Union["pd.DataFrame", List["pd.DataFrame"]]) -> "DataFrame
     return DataFrame._from_pandas(data)
 
 
+@PublicAPI
+def from_polars(data: Union["pl.DataFrame", List["pl.DataFrame"]]) -> "DataFrame":
+    """Creates a Daft DataFrame from a polars DataFrame.
+
+    Buffers are handed over through the Arrow C data interface, so columns are not copied where Arrow and polars share
+    a representation. Columns produced by [DataFrame.to_polars](#daft.DataFrame.to_polars) from Daft extension types
+    can be cast back to them, e.g. `df.with_column("e", df["e"].cast(daft.DataType.embedding(daft.DataType.float32(), 4)))`.
+
+    Args:
+        data: polars DataFrame(s) that we wish to convert into a Daft DataFrame.
+
+    Returns:
+        DataFrame: Daft DataFrame created from the provided polars DataFrame.
+
+    Examples:
+        >>> import polars as pl
+        >>> import daft
+        >>> pl_df = pl.DataFrame({"a": [1, 2, 3], "b": ["foo", "bar", "baz"]})
+        >>> df = daft.from_polars(pl_df)
+        >>> df.show()
+        ╭───────┬──────╮
+        │ a     ┆ b    │
+        │ ---   ┆ ---  │
+        │ Int64 ┆ Utf8 │
+        ╞═══════╪══════╡
+        │ 1     ┆ foo  │
+        ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌┤
+        │ 2     ┆ bar  │
+        ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌┤
+        │ 3     ┆ baz  │
+        ╰───────┴──────╯
+        <BLANKLINE>
+        (Showing first 3 of 3 rows)
+    """
+    from daft import DataFrame
+
+    return DataFrame._from_polars(data)
+
+
 @PublicAPI
 def from_ray_dataset(ds: "RayDataset") -> "DataFrame":
     """Creates a DataFrame from a Ray Dataset.
@@ -13,10 +13,12 @@ from daft.sql.sql_connection import SQLConnection
 from daft.udf import UDF, BoundUDFArgs, InitArgsType, UninitializedUdf
 
 if TYPE_CHECKING:
+    import numpy as np
     import pyarrow as pa
     from pyiceberg.schema import Schema as IcebergSchema
     from pyiceberg.table import TableProperties as IcebergTableProperties
 
+    from daft.progress import QueryProgressTracker
     from daft.runners.runner import Runner
 
 class ImageMode(Enum):
@@ -113,6 +115,7 @@ class ImageFormat(Enum):
     TIFF: int
     GIF: int
     BMP: int
+    WEBP: int
 
     @staticmethod
     def from_format_string(mode: str) -> ImageFormat:
@@ -158,6 +161,22 @@ class JoinStrategy(Enum):
         """
         ...
 
+class AsofStrategy(Enum):
+    """Direction in which an as-of join looks for the nearest right key."""
+
+    Backward: int
+    Forward: int
+    Nearest: int
+
+    @staticmethod
+    def from_asof_strategy_str(asof_strategy: str) -> AsofStrategy:
+        """Create an AsofStrategy from its string representation.
+
+        Args:
+            asof_strategy: String representation of the as-of strategy, e.g. "backward", "forward", or "nearest".
+        """
+        ...
+
 class JoinSide(Enum):
     Left: int
     Right: int
@@ -426,9 +445,21 @@ class FileInfos:
     file_paths: list[str]
     file_sizes: list[int | None]
     num_rows: list[int | None]
-
-    @staticmethod
-    def from_infos(file_paths: list[str], file_sizes: list[int | None], num_rows: list[int | None]) -> FileInfos: ...
+    last_modified: list[int | None]
+    etags: list[str | None]
+    version_ids: list[str | None]
+    storage_classes: list[str | None]
+
+    @staticmethod
+    def from_infos(
+        file_paths: list[str],
+        file_sizes: list[int | None],
+        num_rows: list[int | None],
+        last_modified: list[int | None] | None = None,
+        etags: list[str | None] | None = None,
+        version_ids: list[str | None] | None = None,
+        storage_classes: list[str | None] | None = None,
+    ) -> FileInfos: ...
     def extend(self, new_infos: FileInfos) -> FileInfos:
         """Concatenate two FileInfos together."""
         ...
@@ -443,6 +474,137 @@ class HTTPConfig:
 
     def __init__(self, bearer_token: str | None = None): ...
 
+class HDFSConfig:
+    """I/O configuration for accessing HDFS through WebHDFS."""
+
+    webhdfs_address: str | None
+    webhdfs_port: int
+    use_https: bool
+    user: str | None
+    delegation_token: str | None
+
+    def __init__(
+        self,
+        webhdfs_address: str | None = None,
+        webhdfs_port: int | None = None,
+        use_https: bool | None = None,
+        user: str | None = None,
+        delegation_token: str | None = None,
+    ): ...
+    def replace(
+        self,
+        webhdfs_address: str | None = None,
+        webhdfs_port: int | None = None,
+        use_https: bool | None = None,
+        user: str | None = None,
+        delegation_token: str | None = None,
+    ) -> HDFSConfig:
+        """Replaces values if provided, returning a new HDFSConfig."""
+        ...
+
+class SFTPConfig:
+    """I/O configuration for accessing SFTP servers."""
+
+    user: str | None
+    password: str | None
+    private_key: str | None
+    private_key_path: str | None
+    private_key_passphrase: str | None
+    known_hosts_path: str | None
+    skip_host_key_check: bool
+
+    def __init__(
+        self,
+        user: str | None = None,
+        password: str | None = None,
+        private_key: str | None = None,
+        private_key_path: str | None = None,
+        private_key_passphrase: str | None = None,
+        known_hosts_path: str | None = None,
+        skip_host_key_check: bool | None = None,
+    ): ...
+    def replace(
+        self,
+        user: str | None = None,
+        password: str | None = None,
+        private_key: str | None = None,
+        private_key_path: str | None = None,
+        private_key_passphrase: str | None = None,
+        known_hosts_path: str | None = None,
+        skip_host_key_check: bool | None = None,
+    ) -> SFTPConfig:
+        """Replaces values if provided, returning a new SFTPConfig."""
+        ...
+
+class RetryConfig:
+    """Configuration of how requests to object stores that fail with throttling or transient errors are retried."""
+
+    max_attempts: int
+    initial_backoff_ms: int
+    max_backoff_ms: int
+    jitter: bool
+    retry_budget: int | None
+
+    def __init__(
+        self,
+        max_attempts: int | None = None,
+        initial_backoff_ms: int | None = None,
+        max_backoff_ms: int | None = None,
+        jitter: bool | None = None,
+        retry_budget: int | None = None,
+    ): ...
+    def replace(
+        self,
+        max_attempts: int | None = None,
+        initial_backoff_ms: int | None = None,
+        max_backoff_ms: int | None = None,
+        jitter: bool | None = None,
+        retry_budget: int | None = None,
+    ) -> RetryConfig:
+        """Replaces values if provided, returning a new RetryConfig."""
+        ...
+
+class DiskCacheConfig:
+    """Configuration of the cache of data read from object stores on local disk."""
+
+    enabled: bool
+    directory: str | None
+    max_size_bytes: int
+
+    def __init__(
+        self,
+        enabled: bool | None = None,
+        directory: str | None = None,
+        max_size_bytes: int | None = None,
+    ): ...
+    def replace(
+        self,
+        enabled: bool | None = None,
+        directory: str | None = None,
+        max_size_bytes: int | None = None,
+    ) -> DiskCacheConfig:
+        """Replaces values if provided, returning a new DiskCacheConfig."""
+        ...
+
+class RateLimitConfig:
+    """Configuration of the caps on the requests made to each endpoint, shared fairly by all queries of the process."""
+
+    max_concurrent_requests: int | None
+    max_requests_per_second: int | None
+
+    def __init__(
+        self,
+        max_concurrent_requests: int | None = None,
+        max_requests_per_second: int | None = None,
+    ): ...
+    def replace(
+        self,
+        max_concurrent_requests: int | None = None,
+        max_requests_per_second: int | None = None,
+    ) -> RateLimitConfig:
+        """Replaces values if provided, returning a new RateLimitConfig."""
+        ...
+
 class S3Config:
     """I/O configuration for accessing an S3-compatible system."""
 
@@ -537,6 +699,16 @@ class S3Credentials:
         expiry: datetime.datetime | None = None,
     ): ...
 
+class BearerToken:
+    token: str
+    expiry: datetime.datetime | None
+
+    def __init__(
+        self,
+        token: str,
+        expiry: datetime.datetime | None = None,
+    ): ...
+
 class AzureConfig:
     """I/O configuration for accessing Azure Blob Storage."""
 
@@ -544,9 +716,11 @@ class AzureConfig:
     access_key: str | None
     sas_token: str | None
     bearer_token: str | None
+    token_provider: Callable[[], BearerToken] | None
     tenant_id: str | None
     client_id: str | None
     client_secret: str | None
+    use_managed_identity: bool | None
     use_fabric_endpoint: bool | None
     anonymous: bool | None
     endpoint_url: str | None = None
@@ -558,9 +732,11 @@ class AzureConfig:
         access_key: str | None = None,
         sas_token: str | None = None,
         bearer_token: str | None = None,
+        token_provider: Callable[[], BearerToken] | None = None,
         tenant_id: str | None = None,
         client_id: str | None = None,
         client_secret: str | None = None,
+        use_managed_identity: bool | None = None,
         use_fabric_endpoint: bool | None = None,
         anonymous: bool | None = None,
         endpoint_url: str | None = None,
@@ -572,9 +748,11 @@ class AzureConfig:
         access_key: str | None = None,
         sas_token: str | None = None,
         bearer_token: str | None = None,
+        token_provider: Callable[[], BearerToken] | None = None,
         tenant_id: str | None = None,
         client_id: str | None = None,
         client_secret: str | None = None,
+        use_managed_identity: bool | None = None,
         use_fabric_endpoint: bool | None = None,
         anonymous: bool | None = None,
         endpoint_url: str | None = None,
@@ -589,6 +767,7 @@ class GCSConfig:
     project_id: str | None
     credentials: str | None
     token: str | None
+    token_provider: Callable[[], BearerToken] | None
     anonymous: bool
     max_connections: int
     retry_initial_backoff_ms: int
@@ -601,6 +780,7 @@ class GCSConfig:
         project_id: str | None = None,
         credentials: str | None = None,
         token: str | None = None,
+        token_provider: Callable[[], BearerToken] | None = None,
         anonymous: bool | None = None,
         max_connections: int | None = None,
         retry_initial_backoff_ms: int | None = None,
@@ -613,6 +793,7 @@ class GCSConfig:
         project_id: str | None = None,
         credentials: str | None = None,
         token: str | None = None,
+        token_provider: Callable[[], BearerToken] | None = None,
         anonymous: bool | None = None,
         max_connections: int | None = None,
         retry_initial_backoff_ms: int | None = None,
@@ -630,6 +811,11 @@ class IOConfig:
     azure: AzureConfig
     gcs: GCSConfig
     http: HTTPConfig
+    hdfs: HDFSConfig
+    sftp: SFTPConfig
+    retry: RetryConfig
+    disk_cache: DiskCacheConfig
+    rate_limit: RateLimitConfig
 
     def __init__(
         self,
@@ -637,6 +823,11 @@ class IOConfig:
         azure: AzureConfig | None = None,
         gcs: GCSConfig | None = None,
         http: HTTPConfig | None = None,
+        hdfs: HDFSConfig | None = None,
+        sftp: SFTPConfig | None = None,
+        retry: RetryConfig | None = None,
+        disk_cache: DiskCacheConfig | None = None,
+        rate_limit: RateLimitConfig | None = None,
     ): ...
     def replace(
         self,
@@ -644,6 +835,11 @@ class IOConfig:
         azure: AzureConfig | None = None,
         gcs: GCSConfig | None = None,
         http: HTTPConfig | None = None,
+        hdfs: HDFSConfig | None = None,
+        sftp: SFTPConfig | None = None,
+        retry: RetryConfig | None = None,
+        disk_cache: DiskCacheConfig | None = None,
+        rate_limit: RateLimitConfig | None = None,
     ) -> IOConfig:
         """Replaces values if provided, returning a new IOConfig."""
         ...
@@ -668,6 +864,10 @@ class ScanTask:
         """Estimate the In Memory Size of this ScanTask."""
         ...
 
+    def paths(self) -> list[str]:
+        """Get the paths of the files or other sources that this ScanTask reads."""
+        ...
+
     @staticmethod
     def catalog_scan_task(
         file: str,
@@ -680,6 +880,8 @@ class ScanTask:
         pushdowns: Pushdowns | None,
         partition_values: PyRecordBatch | None,
         stats: PyRecordBatch | None,
+        iceberg_equality_delete_files: list[tuple[str, list[str]]] | None = None,
+        delta_deletion_vector: tuple[str, str, str, int | None, int, int] | None = None,
     ) -> ScanTask | None:
         """Create a Catalog Scan Task."""
         ...
@@ -731,6 +933,8 @@ class ScanOperatorHandle:
         infer_schema: bool,
         schema: PySchema | None = None,
         file_path_column: str | None = None,
+        schema_resolution: Literal["first", "union"] | None = None,
+        column_mapping: Literal["name", "field_id"] | None = None,
     ) -> ScanOperatorHandle: ...
     @staticmethod
     def from_python_scan_operator(operator: ScanOperator) -> ScanOperatorHandle: ...
@@ -865,6 +1069,7 @@ def read_json_schema(
     io_config: IOConfig | None = None,
     multithreaded_io: bool | None = None,
 ): ...
+def decode_json_values(values: PySeries, schema: PySchema) -> PySeries: ...
 
 class PyTimeUnit:
     @staticmethod
@@ -932,6 +1137,10 @@ class PyDataType:
     @staticmethod
     def extension(name: str, storage_data_type: PyDataType, metadata: str | None = None) -> PyDataType: ...
     @staticmethod
+    def uuid() -> PyDataType: ...
+    @staticmethod
+    def json() -> PyDataType: ...
+    @staticmethod
     def embedding(data_type: PyDataType, size: int) -> PyDataType: ...
     @staticmethod
     def image(
@@ -946,6 +1155,8 @@ class PyDataType:
         dtype: PyDataType, shape: tuple[int, ...] | None = None, use_offset_indices: builtins.bool = False
     ) -> PyDataType: ...
     @staticmethod
+    def categorical(categories: builtins.list[str]) -> PyDataType: ...
+    @staticmethod
     def python() -> PyDataType: ...
     def to_arrow(self, cast_tensor_type_for_ray: builtins.bool | None = None) -> pa.DataType: ...
     def is_null(self) -> builtins.bool: ...
@@ -974,9 +1185,12 @@ class PyDataType:
     def is_struct(self) -> builtins.bool: ...
     def is_map(self) -> builtins.bool: ...
     def is_extension(self) -> builtins.bool: ...
+    def is_uuid(self) -> builtins.bool: ...
+    def is_json(self) -> builtins.bool: ...
     def is_image(self) -> builtins.bool: ...
     def is_fixed_shape_image(self) -> builtins.bool: ...
     def is_embedding(self) -> builtins.bool: ...
+    def is_categorical(self) -> builtins.bool: ...
     def is_tensor(self) -> builtins.bool: ...
     def is_fixed_shape_tensor(self) -> builtins.bool: ...
     def is_sparse_tensor(self) -> builtins.bool: ...
@@ -998,6 +1212,7 @@ class PyDataType:
     def use_offset_indices(self) -> builtins.bool: ...
     def key_type(self) -> PyDataType: ...
     def value_type(self) -> PyDataType: ...
+    def categories(self) -> builtins.list[str]: ...
     def is_equal(self, other: Any) -> builtins.bool: ...
     @staticmethod
     def from_json(serialized: str) -> PyDataType: ...
@@ -1034,10 +1249,12 @@ class PyExpr:
     def alias(self, name: str) -> PyExpr: ...
     def cast(self, dtype: PyDataType) -> PyExpr: ...
     def if_else(self, if_true: PyExpr, if_false: PyExpr) -> PyExpr: ...
+    def agg_filter(self, predicate: PyExpr) -> PyExpr: ...
     def count(self, mode: CountMode) -> PyExpr: ...
     def count_distinct(self) -> PyExpr: ...
     def sum(self) -> PyExpr: ...
     def approx_count_distinct(self) -> PyExpr: ...
+    def approx_top_k(self, k: int) -> PyExpr: ...
     def approx_percentiles(self, percentiles: float | list[float]) -> PyExpr: ...
     def mean(self) -> PyExpr: ...
     def stddev(self) -> PyExpr: ...
@@ -1049,6 +1266,10 @@ class PyExpr:
     def agg_list(self) -> PyExpr: ...
     def agg_set(self) -> PyExpr: ...
     def agg_concat(self) -> PyExpr: ...
+    def agg_collect(
+        self, distinct: bool, order_by: PyExpr | None = None, descending: bool = False, limit: int | None = None
+    ) -> PyExpr: ...
+    def offset(self, offset: int, default: PyExpr | None = None) -> PyExpr: ...
     def over(self, window_spec: WindowSpec) -> PyExpr: ...
     def __add__(self, other: PyExpr) -> PyExpr: ...
     def __sub__(self, other: PyExpr) -> PyExpr: ...
@@ -1081,6 +1302,9 @@ class PyExpr:
     def __hash__(self) -> int: ...
     def __reduce__(self) -> tuple: ...
     def struct_get(self, name: str) -> PyExpr: ...
+    def struct_with_field(self, name: str, value: PyExpr) -> PyExpr: ...
+    def struct_drop(self, names: list[str]) -> PyExpr: ...
+    def struct_rename(self, mapping: list[tuple[str, str]]) -> PyExpr: ...
     def map_get(self, key: PyExpr) -> PyExpr: ...
     def partitioning_days(self) -> PyExpr: ...
     def partitioning_hours(self) -> PyExpr: ...
@@ -1095,11 +1319,26 @@ class PyExpr:
     ###
     def _input_mapping(self) -> builtins.str | None: ...
 
+def register_extension_type(
+    name: str,
+    storage_data_type: PyDataType,
+    serialize: Callable[[str | None], str | None] | None = None,
+    deserialize: Callable[[str | None], str | None] | None = None,
+) -> None: ...
+def unregister_extension_type(name: str) -> bool: ...
+def registered_extension_types() -> list[str]: ...
+def deserialize_extension_metadata(name: str, storage_data_type: PyDataType, metadata: str | None = None) -> str | None: ...
+
 def eq(expr1: PyExpr, expr2: PyExpr) -> bool: ...
 def unresolved_col(name: str) -> PyExpr: ...
 def resolved_col(name: str) -> PyExpr: ...
 def lit(item: Any) -> PyExpr: ...
 def list_(items: list[PyExpr]) -> PyExpr: ...
+def element() -> PyExpr: ...
+def accumulator() -> PyExpr: ...
+def list_map(expr: PyExpr, body: PyExpr) -> PyExpr: ...
+def list_filter(expr: PyExpr, body: PyExpr) -> PyExpr: ...
+def list_reduce(expr: PyExpr, body: PyExpr) -> PyExpr: ...
 def date_lit(item: int) -> PyExpr: ...
 def time_lit(item: int, tu: PyTimeUnit) -> PyExpr: ...
 def timestamp_lit(item: int, tu: PyTimeUnit, tz: str | None) -> PyExpr: ...
@@ -1114,6 +1353,7 @@ def interval_lit(
     millis: int | None,
     nanos: int | None,
 ) -> PyExpr: ...
+def interval_lit_from_str(interval: str) -> PyExpr: ...
 def decimal_lit(sign: bool, digits: tuple[int, ...], exp: int) -> PyExpr: ...
 def series_lit(item: PySeries) -> PyExpr: ...
 def udf(
@@ -1131,6 +1371,8 @@ def initialize_udfs(expression: PyExpr) -> PyExpr: ...
 def get_udf_names(expression: PyExpr) -> list[str]: ...
 def resolve_expr(expr: PyExpr, schema: PySchema) -> tuple[PyExpr, PyField]: ...
 def hash(expr: PyExpr, seed: Any | None = None) -> PyExpr: ...
+def hash_columns(exprs: list[PyExpr], seed: int = 0) -> PyExpr: ...
+def uuid(version: str = "4") -> PyExpr: ...
 def cosine_distance(expr: PyExpr, other: PyExpr) -> PyExpr: ...
 def url_download(
     expr: PyExpr,
@@ -1148,6 +1390,11 @@ def url_upload(
     is_single_folder: bool,
     io_config: IOConfig | None,
 ) -> PyExpr: ...
+def url_scheme(expr: PyExpr) -> PyExpr: ...
+def url_host(expr: PyExpr) -> PyExpr: ...
+def url_path(expr: PyExpr) -> PyExpr: ...
+def url_query_param(expr: PyExpr, name: str) -> PyExpr: ...
+def url_decode(expr: PyExpr) -> PyExpr: ...
 def tokenize_encode(
     expr: PyExpr,
     tokens_path: str,
@@ -1163,6 +1410,13 @@ def tokenize_decode(
     pattern: str | None,
     special_tokens: str | None,
 ) -> PyExpr: ...
+def tokenize(
+    expr: PyExpr,
+    tokenizer: str,
+    use_special_tokens: bool,
+    io_config: IOConfig | None = None,
+) -> PyExpr: ...
+def detokenize(expr: PyExpr, tokenizer: str, io_config: IOConfig | None = None) -> PyExpr: ...
 def minhash(
     expr: PyExpr,
     num_hashes: int,
@@ -1171,6 +1425,13 @@ def minhash(
     hash_function: Literal["murmurhash3", "xxhash", "sha1"] = "murmurhash3",
 ) -> PyExpr: ...
 def coalesce(exprs: list[PyExpr]) -> PyExpr: ...
+def native_udf(name: str, inputs: list[PyExpr]) -> PyExpr: ...
+def load_native_udfs(path: str) -> list[str]: ...
+def registered_native_udfs() -> list[str]: ...
+def try_(expr: PyExpr) -> PyExpr: ...
+def try_cast(expr: PyExpr, dtype: PyDataType) -> PyExpr: ...
+def try_error_count() -> int: ...
+def reset_try_error_count() -> int: ...
 
 # -----
 # SQL functions
@@ -1183,13 +1444,30 @@ class SQLFunctionStub:
     @property
     def arg_names(self) -> list[str]: ...
 
-def sql_exec(source: str, session: PySession, config: PyDaftPlanningConfig) -> object | None: ...
+def sql_exec(
+    source: str,
+    session: PySession,
+    config: PyDaftPlanningConfig,
+    positional_params: list[PyExpr] | None = None,
+    named_params: dict[str, PyExpr] | None = None,
+) -> object | None: ...
 def sql(
-    sql: str, catalog: PyCatalog, session: PySession, daft_planning_config: PyDaftPlanningConfig
+    sql: str,
+    catalog: PyCatalog,
+    session: PySession,
+    daft_planning_config: PyDaftPlanningConfig,
+    positional_params: list[PyExpr] | None = None,
+    named_params: dict[str, PyExpr] | None = None,
 ) -> LogicalPlanBuilder: ...
 def sql_expr(sql: str) -> PyExpr: ...
 def sql_datatype(sql: str) -> PyDataType: ...
 def list_sql_functions() -> list[SQLFunctionStub]: ...
+def substrait_from_expressions(exprs: list[PyExpr], schema: PySchema) -> bytes: ...
+def substrait_to_expressions(data: bytes) -> tuple[list[PyExpr], PySchema]: ...
+def substrait_capability_report(exprs: list[PyExpr], schema: PySchema) -> list[str]: ...
+def substrait_from_plan(builder: LogicalPlanBuilder, tables: list[tuple[str, LogicalPlanBuilder]]) -> bytes: ...
+def substrait_plan_tables(data: bytes) -> list[str]: ...
+def substrait_to_plan(data: bytes, tables: dict[str, LogicalPlanBuilder]) -> LogicalPlanBuilder: ...
 def utf8_count_matches(expr: PyExpr, patterns: PyExpr, whole_words: bool, case_sensitive: bool) -> PyExpr: ...
 def struct(inputs: list[PyExpr]) -> PyExpr: ...
 def connect_start(addr: str = "sc://0.0.0.0:0") -> ConnectionHandle: ...
@@ -1198,6 +1476,18 @@ class ConnectionHandle:
     def shutdown(self) -> None: ...
     def port(self) -> int: ...
 
+def flight_sql_start(session: PySession, host: str = "0.0.0.0", port: int = 0) -> FlightSqlServerHandle: ...
+
+class FlightSqlServerHandle:
+    def shutdown(self) -> None: ...
+    def port(self) -> int: ...
+
+def pgwire_start(session: PySession, host: str = "0.0.0.0", port: int = 0) -> PgWireServerHandle: ...
+
+class PgWireServerHandle:
+    def shutdown(self) -> None: ...
+    def port(self) -> int: ...
+
 # expr numeric ops
 def abs(expr: PyExpr) -> PyExpr: ...
 def cbrt(expr: PyExpr) -> PyExpr: ...
@@ -1257,6 +1547,7 @@ def fill_nan(expr: PyExpr, fill_value: PyExpr) -> PyExpr: ...
 # expr.json namespace
 # ---
 def json_query(expr: PyExpr, query: str) -> PyExpr: ...
+def json_extract(expr: PyExpr, path: str, dtype: PyDataType | None = None) -> PyExpr: ...
 
 # ---
 # expr.dt namespace
@@ -1277,6 +1568,22 @@ def dt_day_of_year(expr: PyExpr) -> PyExpr: ...
 def dt_truncate(expr: PyExpr, interval: str, relative_to: PyExpr) -> PyExpr: ...
 def dt_to_unix_epoch(expr: PyExpr, timeunit: PyTimeUnit) -> PyExpr: ...
 def dt_strftime(expr: PyExpr, format: str | None = None) -> PyExpr: ...
+def dt_quarter(expr: PyExpr) -> PyExpr: ...
+def dt_week_of_year(expr: PyExpr) -> PyExpr: ...
+def dt_last_day_of_month(expr: PyExpr) -> PyExpr: ...
+def dt_date_add(expr: PyExpr, n: PyExpr, unit: str) -> PyExpr: ...
+def dt_date_sub(expr: PyExpr, n: PyExpr, unit: str) -> PyExpr: ...
+def dt_date_diff(expr: PyExpr, start: PyExpr, unit: str) -> PyExpr: ...
+def make_date(year: PyExpr, month: PyExpr, day: PyExpr) -> PyExpr: ...
+def make_timestamp(
+    year: PyExpr,
+    month: PyExpr,
+    day: PyExpr,
+    hour: PyExpr,
+    minute: PyExpr,
+    second: PyExpr,
+    timezone: str | None = None,
+) -> PyExpr: ...
 
 # ---
 # expr.list namespace
@@ -1307,7 +1614,9 @@ def utf8_match(expr: PyExpr, pattern: PyExpr) -> PyExpr: ...
 def utf8_split(expr: PyExpr, pattern: PyExpr, regex: bool) -> PyExpr: ...
 def utf8_extract(expr: PyExpr, pattern: PyExpr, index: int) -> PyExpr: ...
 def utf8_extract_all(expr: PyExpr, pattern: PyExpr, index: int) -> PyExpr: ...
-def utf8_replace(expr: PyExpr, pattern: PyExpr, replacement: PyExpr, regex: bool) -> PyExpr: ...
+def utf8_replace(
+    expr: PyExpr, pattern: PyExpr, replacement: PyExpr, regex: bool, max_replacements: int | None = None
+) -> PyExpr: ...
 def utf8_length(expr: PyExpr) -> PyExpr: ...
 def utf8_length_bytes(expr: PyExpr) -> PyExpr: ...
 def utf8_lower(expr: PyExpr) -> PyExpr: ...
@@ -1352,9 +1661,17 @@ class PySeries:
     @staticmethod
     def from_arrow(name: str, pyarrow_array: pa.Array) -> PySeries: ...
     @staticmethod
+    def from_numpy(name: str, array: np.ndarray) -> PySeries | None: ...
+    @staticmethod
+    def from_arrow_c_array(name: str, schema_capsule: object, array_capsule: object) -> PySeries: ...
+    @staticmethod
+    def from_arrow_c_stream(name: str, stream_capsule: object) -> PySeries: ...
+    @staticmethod
     def from_pylist(name: str, pylist: list[Any], pyobj: str) -> PySeries: ...
     def to_pylist(self) -> list[Any]: ...
     def to_arrow(self) -> pa.Array: ...
+    def __arrow_c_schema__(self) -> object: ...
+    def __arrow_c_array__(self, requested_schema: object | None = None) -> tuple[object, object]: ...
     def __abs__(self) -> PySeries: ...
     def __add__(self, other: PySeries) -> PySeries: ...
     def __sub__(self, other: PySeries) -> PySeries: ...
@@ -1378,6 +1695,8 @@ class PySeries:
     def filter(self, mask: PySeries) -> PySeries: ...
     def sort(self, descending: bool, nulls_first: bool) -> PySeries: ...
     def argsort(self, descending: bool, nulls_first: bool) -> PySeries: ...
+    def rank(self, method: str, descending: bool, nulls_first: bool | None = None) -> PySeries: ...
+    def value_counts(self) -> PySeries: ...
     def hash(self, seed: PySeries | None = None) -> PySeries: ...
     def minhash(
         self,
@@ -1393,10 +1712,12 @@ class PySeries:
     def mean(self) -> PySeries: ...
     def stddev(self) -> PySeries: ...
     def min(self) -> PySeries: ...
+    def approx_quantiles(self, quantiles: list[float]) -> PySeries: ...
     def max(self) -> PySeries: ...
     def agg_list(self) -> PySeries: ...
     def agg_set(self) -> PySeries: ...
     def cast(self, dtype: PyDataType) -> PySeries: ...
+    def cast_to_categorical(self, categories: list[str] | None) -> PySeries: ...
     def ceil(self) -> PySeries: ...
     def floor(self) -> PySeries: ...
     def sign(self) -> PySeries: ...
@@ -1455,6 +1776,7 @@ class PySeries:
     def utf8_rstrip(self) -> PySeries: ...
     def utf8_reverse(self) -> PySeries: ...
     def utf8_capitalize(self) -> PySeries: ...
+    def utf8_to_titlecase(self) -> PySeries: ...
     def utf8_left(self, nchars: PySeries) -> PySeries: ...
     def utf8_right(self, nchars: PySeries) -> PySeries: ...
     def utf8_find(self, substr: PySeries) -> PySeries: ...
@@ -1487,6 +1809,8 @@ class PySeries:
     def dt_day_of_year(self) -> PySeries: ...
     def dt_truncate(self, interval: str, relative_to: PySeries) -> PySeries: ...
     def dt_strftime(self, format: str | None = None) -> PySeries: ...
+    def dt_convert_time_zone(self, tz: str) -> PySeries: ...
+    def dt_replace_time_zone(self, tz: str | None = None) -> PySeries: ...
     def dt_to_unix_epoch(self, timeunit: PyTimeUnit) -> PySeries: ...
     def partitioning_days(self) -> PySeries: ...
     def partitioning_hours(self) -> PySeries: ...
@@ -1499,6 +1823,13 @@ class PySeries:
     def list_slice(self, start: PySeries, end: PySeries | None = None) -> PySeries: ...
     def list_sort(self, desc: PySeries, nulls_first: PySeries) -> PySeries: ...
     def map_get(self, key: PySeries) -> PySeries: ...
+    def map_keys(self) -> PySeries: ...
+    def map_values(self) -> PySeries: ...
+    def embedding_dot_product(self, other: PySeries) -> PySeries: ...
+    def embedding_cosine_similarity(self, other: PySeries, normalized: bool) -> PySeries: ...
+    def embedding_normalize(self) -> PySeries: ...
+    def tensor_sum(self, axes: list[int] | None = None) -> PySeries: ...
+    def tensor_mean(self, axes: list[int] | None = None) -> PySeries: ...
     def if_else(self, other: PySeries, predicate: PySeries) -> PySeries: ...
     def is_null(self) -> PySeries: ...
     def not_null(self) -> PySeries: ...
@@ -1509,6 +1840,38 @@ class PySeries:
     @staticmethod
     def _debug_bincode_deserialize(b: bytes) -> PySeries: ...
 
+class PyVectorIndex:
+    def __init__(
+        self,
+        vector_field: PyField,
+        id_field: PyField,
+        metric: str,
+        kind: str,
+        num_lists: int | None = None,
+        num_probes: int | None = None,
+    ) -> None: ...
+    def add_partition(self, vectors: PySeries, ids: PySeries) -> None: ...
+    def search(self, queries: PySeries, k: int, num_probes: int | None = None) -> PySeries: ...
+    def to_bytes(self) -> bytes: ...
+    @staticmethod
+    def from_bytes(data: bytes) -> PyVectorIndex: ...
+    @property
+    def column(self) -> str: ...
+    @property
+    def id_column(self) -> str: ...
+    @property
+    def id_dtype(self) -> PyDataType: ...
+    @property
+    def dimension(self) -> int: ...
+    @property
+    def metric(self) -> str: ...
+    @property
+    def kind(self) -> str: ...
+    @property
+    def num_rows(self) -> int: ...
+    @property
+    def num_partitions(self) -> int: ...
+
 class PyShowOptions:
     pass
 
@@ -1542,7 +1905,7 @@ class PyRecordBatch:
         right_on: list[PyExpr],
         is_sorted: bool,
     ) -> PyRecordBatch: ...
-    def explode(self, to_explode: list[PyExpr]) -> PyRecordBatch: ...
+    def explode(self, to_explode: list[PyExpr], unnest_structs: bool = False) -> PyRecordBatch: ...
     def head(self, num: int) -> PyRecordBatch: ...
     def sample_by_fraction(self, fraction: float, with_replacement: bool, seed: int | None) -> PyRecordBatch: ...
     def sample_by_size(self, size: int, with_replacement: bool, seed: int | None) -> PyRecordBatch: ...
@@ -1557,6 +1920,8 @@ class PyRecordBatch:
     def preview(self, format: str | None, options: str | None) -> str: ...
     def __repr__(self) -> str: ...
     def _repr_html_(self) -> str: ...
+    def repr_with_options(self, options: str) -> str: ...
+    def repr_html_with_options(self, options: str) -> str: ...
     def __len__(self) -> int: ...
     def size_bytes(self) -> int: ...
     def column_names(self) -> list[str]: ...
@@ -1568,6 +1933,10 @@ class PyRecordBatch:
     @staticmethod
     def from_arrow_record_batches(record_batches: list[pa.RecordBatch], schema: PySchema) -> PyRecordBatch: ...
     @staticmethod
+    def to_arrow_c_stream(record_batches: list[PyRecordBatch], schema: PySchema) -> object: ...
+    @staticmethod
+    def from_arrow_c_stream(stream_capsule: object) -> tuple[PySchema, list[PyRecordBatch]]: ...
+    @staticmethod
     def from_pylist_series(dict: dict[str, PySeries]) -> PyRecordBatch: ...
     def to_arrow_record_batch(self) -> pa.RecordBatch: ...
     @staticmethod
@@ -1628,7 +1997,7 @@ class PyMicroPartition:
         right: PyMicroPartition,
         outer_loop_side: JoinSide,
     ) -> PyMicroPartition: ...
-    def explode(self, to_explode: list[PyExpr]) -> PyMicroPartition: ...
+    def explode(self, to_explode: list[PyExpr], unnest_structs: bool = False) -> PyMicroPartition: ...
     def unpivot(
         self,
         ids: list[PyExpr],
@@ -1756,6 +2125,34 @@ class AdaptivePhysicalPlanScheduler:
     ) -> None: ...
     def explain_analyze(self, explain_analyze_dir: str) -> None: ...
 
+class IcebergWrite:
+    """A native write to an Iceberg table, planned from the metadata of the table."""
+
+    def __init__(self, metadata_json: str, schema: PySchema, mode: str, merge_schema: bool = False): ...
+    def commit(self, data_files: list[str], io_config: IOConfig | None = None) -> str:
+        """Writes the manifests of a snapshot with the JSON-serialized `data_files` of the write, returning the requirements and updates that commit it as JSON."""
+        ...
+
+class DeltaLakeWrite:
+    """A native write to a Delta Lake table, planned from a snapshot of the table."""
+
+    def __init__(
+        self,
+        table_uri: str,
+        schema_json: str,
+        mode: str,
+        partition_columns: list[str],
+        snapshot_json: str | None = None,
+        overwrite_schema: bool = False,
+        name: str | None = None,
+        description: str | None = None,
+        configuration: dict[str, str | None] | None = None,
+        custom_metadata: dict[str, str] | None = None,
+    ): ...
+    def commit(self, add_actions: list[str], io_config: IOConfig | None = None) -> str:
+        """Commits the JSON-serialized `add_actions` of the write to the log of the table, returning the version it committed and the files it adds and removes as JSON."""
+        ...
+
 class LogicalPlanBuilder:
     """A logical plan builder, which simplifies constructing logical plans via a fluent interface.
 
@@ -1773,6 +2170,7 @@ class LogicalPlanBuilder:
         num_partitions: int,
         size_bytes: int,
         num_rows: int,
+        persisted: bool = False,
     ) -> LogicalPlanBuilder: ...
     def with_planning_config(self, daft_planning_config: PyDaftPlanningConfig) -> LogicalPlanBuilder: ...
     def select(self, to_select: list[PyExpr]) -> LogicalPlanBuilder: ...
@@ -1780,8 +2178,9 @@ class LogicalPlanBuilder:
     def with_columns_renamed(self, cols_map: dict[str, str]) -> LogicalPlanBuilder: ...
     def exclude(self, to_exclude: list[str]) -> LogicalPlanBuilder: ...
     def filter(self, predicate: PyExpr) -> LogicalPlanBuilder: ...
+    def in_subquery(self, expr: PyExpr) -> PyExpr: ...
     def limit(self, limit: int, eager: bool) -> LogicalPlanBuilder: ...
-    def explode(self, to_explode: list[PyExpr]) -> LogicalPlanBuilder: ...
+    def explode(self, to_explode: list[PyExpr], unnest_structs: bool = False) -> LogicalPlanBuilder: ...
     def unpivot(
         self,
         ids: list[PyExpr],
@@ -1801,6 +2200,9 @@ class LogicalPlanBuilder:
     def distinct(self) -> LogicalPlanBuilder: ...
     def sample(self, fraction: float, with_replacement: bool, seed: int | None) -> LogicalPlanBuilder: ...
     def aggregate(self, agg_exprs: list[PyExpr], groupby_exprs: list[PyExpr]) -> LogicalPlanBuilder: ...
+    def aggregate_grouping_sets(
+        self, agg_exprs: list[PyExpr], groupby_exprs: list[PyExpr], grouping_sets: list[list[int]]
+    ) -> LogicalPlanBuilder: ...
     def pivot(
         self,
         groupby_exprs: list[PyExpr],
@@ -1819,6 +2221,18 @@ class LogicalPlanBuilder:
         prefix: str | None = None,
         suffix: str | None = None,
     ) -> LogicalPlanBuilder: ...
+    def asof_join(
+        self,
+        right: LogicalPlanBuilder,
+        left_on: PyExpr,
+        right_on: PyExpr,
+        left_by: list[PyExpr],
+        right_by: list[PyExpr],
+        strategy: AsofStrategy,
+        tolerance: PyExpr | None = None,
+        prefix: str | None = None,
+        suffix: str | None = None,
+    ) -> LogicalPlanBuilder: ...
     def concat(self, other: LogicalPlanBuilder) -> LogicalPlanBuilder: ...
     def union(self, other: LogicalPlanBuilder, is_all: bool, is_by_name: bool) -> LogicalPlanBuilder: ...
     def intersect(self, other: LogicalPlanBuilder, is_all: bool) -> LogicalPlanBuilder: ...
@@ -1832,6 +2246,7 @@ class LogicalPlanBuilder:
         partition_cols: list[PyExpr] | None = None,
         compression: str | None = None,
         io_config: IOConfig | None = None,
+        max_open_writers: int | None = None,
     ) -> LogicalPlanBuilder: ...
     def iceberg_write(
         self,
@@ -1843,6 +2258,7 @@ class LogicalPlanBuilder:
         iceberg_properties: IcebergTableProperties,
         catalog_columns: list[str],
         io_config: IOConfig | None = None,
+        native_write: IcebergWrite | None = None,
     ) -> LogicalPlanBuilder: ...
     def delta_write(
         self,
@@ -1853,6 +2269,7 @@ class LogicalPlanBuilder:
         large_dtypes: bool,
         partition_cols: list[str] | None = None,
         io_config: IOConfig | None = None,
+        native_write: DeltaLakeWrite | None = None,
     ) -> LogicalPlanBuilder: ...
     def lance_write(
         self,
@@ -1865,21 +2282,47 @@ class LogicalPlanBuilder:
     def schema(self) -> PySchema: ...
     def describe(self) -> LogicalPlanBuilder: ...
     def summarize(self) -> LogicalPlanBuilder: ...
+    def analyze(self) -> LogicalPlanBuilder: ...
+    def with_source_statistics(
+        self, num_rows: int, columns: dict[str, tuple[int, int, PyExpr | None, PyExpr | None]]
+    ) -> LogicalPlanBuilder: ...
     def optimize(self) -> LogicalPlanBuilder: ...
+    def fingerprint(self) -> int | None: ...
+    def durable_fingerprint(self) -> str | None: ...
+    def in_memory_cache_keys(self) -> list[str]: ...
     def to_physical_plan_scheduler(self, cfg: PyDaftExecutionConfig) -> PhysicalPlanScheduler: ...
     def to_adaptive_physical_plan_scheduler(self, cfg: PyDaftExecutionConfig) -> AdaptivePhysicalPlanScheduler: ...
     def repr_ascii(self, simple: bool) -> str: ...
     def repr_mermaid(self, options: MermaidOptions) -> str: ...
 
+class LocalPartitionIterator(Iterator[PyMicroPartition]):
+    def __next__(self) -> PyMicroPartition: ...
+    def cancel(self) -> None:
+        """Cancels the query, which stops its operators and ends iteration with a `QueryCancelledError`."""
+        ...
+    def io_stats(self) -> dict[str, Any]:
+        """The IO of the query so far, in total and by store.
+
+        Each of them counts requests by type, bytes downloaded and uploaded, retries, throttled requests and a
+        histogram of time to first byte.
+        """
+        ...
+
 class NativeExecutor:
-    def __init__(self) -> None: ...
+    def __init__(self, progress: QueryProgressTracker | None = None) -> None: ...
     def run(
         self,
         builder: LogicalPlanBuilder,
         psets: dict[str, list[PartitionT]],
         daft_execution_config: PyDaftExecutionConfig,
         results_buffer_size: int | None,
-    ) -> Iterator[PyMicroPartition]: ...
+    ) -> LocalPartitionIterator: ...
+    def explain_analyze(
+        self,
+        builder: LogicalPlanBuilder,
+        psets: dict[str, list[PartitionT]],
+        daft_execution_config: PyDaftExecutionConfig,
+    ) -> str: ...
     def repr_ascii(
         self, builder: LogicalPlanBuilder, daft_execution_config: PyDaftExecutionConfig, simple: bool
     ) -> str: ...
@@ -1898,7 +2341,9 @@ class PyDaftExecutionConfig:
         broadcast_join_size_bytes_threshold: int | None = None,
         parquet_split_row_groups_max_files: int | None = None,
         sort_merge_join_sort_with_aligned_boundaries: bool | None = None,
+        sort_merge_join_size_bytes_threshold: int | None = None,
         hash_join_partition_size_leniency: float | None = None,
+        dynamic_partition_pruning_max_keys: int | None = None,
         sample_size_for_sort: int | None = None,
         num_preview_rows: int | None = None,
         parquet_target_filesize: int | None = None,
@@ -1911,6 +2356,7 @@ class PyDaftExecutionConfig:
         high_cardinality_aggregation_threshold: float | None = None,
         read_sql_partition_size_bytes: int | None = None,
         enable_aqe: bool | None = None,
+        aqe_target_partition_size_bytes: int | None = None,
         enable_native_executor: bool | None = None,
         default_morsel_size: int | None = None,
         enable_ray_tracing: bool | None = None,
@@ -1918,6 +2364,29 @@ class PyDaftExecutionConfig:
         pre_shuffle_merge_threshold: int | None = None,
         flight_shuffle_dirs: list[str] | None = None,
         scantask_splitting_level: int | None = None,
+        buffer_allocator: str | None = None,
+        buffer_pool_max_bytes: int | None = None,
+        persist_memory_limit_bytes: int | None = None,
+        operator_memory_limit_bytes: int | None = None,
+        query_memory_limit_bytes: int | None = None,
+        compute_threads: int | None = None,
+        io_threads: int | None = None,
+        thread_affinity: str | None = None,
+        shuffle_compression: str | None = None,
+        shuffle_compression_level: int | None = None,
+        external_shuffle_dir: str | None = None,
+        enable_speculative_execution: bool | None = None,
+        speculation_quantile: float | None = None,
+        speculation_multiplier: float | None = None,
+        speculation_min_runtime_ms: int | None = None,
+        checkpoint_dir: str | None = None,
+        enable_opentelemetry: bool | None = None,
+        upload_part_size: int | None = None,
+        max_inflight_upload_parts: int | None = None,
+        task_max_attempts: int | None = None,
+        task_retry_initial_backoff_ms: int | None = None,
+        task_retry_max_backoff_ms: int | None = None,
+        deterministic: bool | None = None,
     ) -> PyDaftExecutionConfig: ...
     @property
     def scan_tasks_min_size_bytes(self) -> int: ...
@@ -1930,8 +2399,12 @@ class PyDaftExecutionConfig:
     @property
     def sort_merge_join_sort_with_aligned_boundaries(self) -> bool: ...
     @property
+    def sort_merge_join_size_bytes_threshold(self) -> int: ...
+    @property
     def hash_join_partition_size_leniency(self) -> float: ...
     @property
+    def dynamic_partition_pruning_max_keys(self) -> int: ...
+    @property
     def sample_size_for_sort(self) -> int: ...
     @property
     def num_preview_rows(self) -> int: ...
@@ -1956,6 +2429,8 @@ class PyDaftExecutionConfig:
     @property
     def enable_aqe(self) -> bool: ...
     @property
+    def aqe_target_partition_size_bytes(self) -> int: ...
+    @property
     def enable_native_executor(self) -> bool: ...
     @property
     def default_morsel_size(self) -> int: ...
@@ -1967,6 +2442,52 @@ class PyDaftExecutionConfig:
     def flight_shuffle_dirs(self) -> list[str]: ...
     @property
     def enable_ray_tracing(self) -> bool: ...
+    @property
+    def buffer_allocator(self) -> str: ...
+    @property
+    def buffer_pool_max_bytes(self) -> int: ...
+    @property
+    def persist_memory_limit_bytes(self) -> int: ...
+    @property
+    def operator_memory_limit_bytes(self) -> int: ...
+    @property
+    def query_memory_limit_bytes(self) -> int: ...
+    @property
+    def compute_threads(self) -> int: ...
+    @property
+    def io_threads(self) -> int: ...
+    @property
+    def thread_affinity(self) -> str: ...
+    @property
+    def shuffle_compression(self) -> str: ...
+    @property
+    def shuffle_compression_level(self) -> int: ...
+    @property
+    def external_shuffle_dir(self) -> str: ...
+    @property
+    def enable_speculative_execution(self) -> bool: ...
+    @property
+    def speculation_quantile(self) -> float: ...
+    @property
+    def speculation_multiplier(self) -> float: ...
+    @property
+    def speculation_min_runtime_ms(self) -> int: ...
+    @property
+    def checkpoint_dir(self) -> str: ...
+    @property
+    def enable_opentelemetry(self) -> bool: ...
+    @property
+    def upload_part_size(self) -> int: ...
+    @property
+    def max_inflight_upload_parts(self) -> int: ...
+    @property
+    def task_max_attempts(self) -> int: ...
+    @property
+    def task_retry_initial_backoff_ms(self) -> int: ...
+    @property
+    def task_retry_max_backoff_ms(self) -> int: ...
+    @property
+    def deterministic(self) -> bool: ...
 
 class PyDaftPlanningConfig:
     @staticmethod
@@ -1974,9 +2495,15 @@ class PyDaftPlanningConfig:
     def with_config_values(
         self,
         default_io_config: IOConfig | None = None,
+        enable_cost_based_planning: bool | None = None,
+        enable_plan_cache: bool | None = None,
     ) -> PyDaftPlanningConfig: ...
     @property
     def default_io_config(self) -> IOConfig: ...
+    @property
+    def enable_cost_based_planning(self) -> bool: ...
+    @property
+    def enable_plan_cache(self) -> bool: ...
 
 class PyDaftContext:
     def __init__(self) -> None: ...
@@ -2011,8 +2538,29 @@ def io_glob(
     fanout_limit: int | None = None,
     page_size: int | None = None,
     limit: int | None = None,
+    exclude: list[str] | None = None,
+    max_depth: int | None = None,
 ) -> list[dict]: ...
 
+class ObjectSink:
+    """A writable file-like object that streams what is written to it to an object store in parts."""
+
+    def __init__(
+        self, uri: str, part_size: int, max_inflight_parts: int, io_config: IOConfig | None = None
+    ) -> None: ...
+    def write(self, data: bytes | memoryview) -> int: ...
+    def tell(self) -> int: ...
+    def flush(self) -> None: ...
+    def close(self) -> None: ...
+    def abort(self) -> None:
+        """Abandons the file, so that nothing of it is uploaded."""
+        ...
+    @property
+    def closed(self) -> bool: ...
+    def writable(self) -> bool: ...
+    def readable(self) -> bool: ...
+    def seekable(self) -> bool: ...
+
 class SystemInfo:
     """Accessor for system information."""
 
@@ -2078,7 +2626,9 @@ class InProgressShuffleCache:
         dirs: list[str],
         target_filesize: int,
         compression: str | None = None,
+        compression_level: int | None = None,
         partition_by: list[PyExpr] | None = None,
+        io_config: IOConfig | None = None,
     ) -> InProgressShuffleCache: ...
     async def push_partitions(self, input_partitions: list[PyMicroPartition]) -> None: ...
     async def close(self) -> ShuffleCache: ...
@@ -2096,4 +2646,23 @@ def start_flight_server(
     shuffle_cache: ShuffleCache,
     ip: str,
 ) -> FlightServerConnectionHandle: ...
+def write_shuffle_map_output(
+    partitions: list[PyMicroPartition],
+    dir: str,
+    map_idx: int,
+    compression: str | None = None,
+    compression_level: int | None = None,
+    io_config: IOConfig | None = None,
+) -> PyMicroPartition: ...
+def write_shuffle_manifest(
+    manifests: list[PyMicroPartition],
+    dir: str,
+    io_config: IOConfig | None = None,
+) -> str: ...
+def read_shuffle_partition(
+    manifest_path: str,
+    partition_idx: int,
+    schema: PySchema,
+    io_config: IOConfig | None = None,
+) -> PyMicroPartition: ...
 def cli(args: list[str]) -> None: ...
@@ -1,5 +1,17 @@
 from __future__ import annotations
 
 from .dataframe import DataFrame, GroupedDataFrame
+from .event_time_windows import StreamingWindowAggregation
+from .validation import Constraint, ConstraintSummary, ValidationError, ValidationResult
+from .vector_search import VectorIndex
 
-__all__ = ["DataFrame", "GroupedDataFrame"]
+__all__ = [
+    "Constraint",
+    "ConstraintSummary",
+    "DataFrame",
+    "GroupedDataFrame",
+    "StreamingWindowAggregation",
+    "ValidationError",
+    "ValidationResult",
+    "VectorIndex",
+]
@@ -8,10 +8,11 @@ import io
 import multiprocessing
 import os
 import pathlib
+import random
 import typing
 import warnings
 from dataclasses import dataclass
-from datetime import datetime, timezone
+from datetime import datetime, timedelta, timezone
 from functools import partial, reduce
 from typing import (
     TYPE_CHECKING,
@@ -33,7 +34,8 @@ from typing import (
 from daft.api_annotations import DataframePublicAPI
 from daft.context import get_context
 from daft.convert import InputListType
-from daft.daft import FileFormat, IOConfig, JoinStrategy, JoinType, WriteMode
+from daft.daft import AsofStrategy, FileFormat, IOConfig, JoinStrategy, JoinType, WriteMode
+from daft.dataframe.display import get_display_options
 from daft.dataframe.preview import Preview, PreviewAlign, PreviewColumn, PreviewFormat, PreviewFormatter
 from daft.datatype import DataType
 from daft.errors import ExpressionTypeError
@@ -48,11 +50,14 @@ if TYPE_CHECKING:
     import dask
     import deltalake
     import pandas
+    import polars
     import pyarrow
     import pyiceberg
     import ray
     import torch
 
+    from daft.dataframe.validation import Constraint, ValidationResult
+    from daft.dataframe.vector_search import Metric, VectorIndex
     from daft.io import DataCatalogTable
     from daft.unity_catalog import UnityCatalogTable
 
@@ -93,6 +98,12 @@ def _utc_now() -> datetime:
     return datetime.now(timezone.utc)
 
 
+def _is_pyarrow_table(data: Any) -> bool:
+    from daft.dependencies import pa
+
+    return pa.module_available() and isinstance(data, pa.Table)
+
+
 class DataFrame:
     """A Daft DataFrame is a table of data.
 
@@ -120,8 +131,10 @@ class DataFrame:
 
         self.__builder = builder
         self._result_cache: Optional[PartitionCacheEntry] = None
+        self._persisted = False
         self._preview = Preview(partition=None, total_rows=None)
         self._num_preview_rows = get_context().daft_execution_config.num_preview_rows
+        self._broadcast_hint = False
 
     @property
     def _builder(self) -> LogicalPlanBuilder:
@@ -143,6 +156,7 @@ class DataFrame:
                 num_partitions=num_partitions,
                 size_bytes=size_bytes,
                 num_rows=num_rows,
+                persisted=self._persisted,
             )
 
     def _get_current_builder(self) -> LogicalPlanBuilder:
@@ -243,6 +257,27 @@ class DataFrame:
             )
         return None
 
+    @DataframePublicAPI
+    def explain_analyze(self, file: Optional[io.IOBase] = None) -> None:
+        """Executes the DataFrame and prints its physical plan, annotated with the runtime metrics of each operator.
+
+        For every operator, this shows the number of rows received and emitted, CPU and wall time, peak memory held,
+        and bytes spilled, along with the bytes scanned by each source. The results of the query are discarded.
+
+        Note:
+            This is only supported on the native runner.
+
+        Args:
+            file (Optional[io.IOBase]): Location to print the output to, or defaults to None which defaults to the default location for
+                print (in Python, that should be sys.stdout)
+        """
+        from daft.runners.native_runner import NativeRunner
+
+        runner = get_context().get_or_create_runner()
+        if not isinstance(runner, NativeRunner):
+            raise ValueError(f"explain_analyze is only supported on the native runner, but got: {runner.name}")
+        print(runner.explain_analyze(self._builder), file=file)
+
     def num_partitions(self) -> int:
         # We need to run the optimizer since that could change the number of partitions
         return (
@@ -365,17 +400,20 @@ class DataFrame:
                         f"Unsupported column_format: {column_format}, supported formats are 'python' and 'arrow'"
                     )
 
+    def _check_arrow_convertible(self) -> None:
+        for name in self.schema().column_names():
+            if self.schema()[name].dtype.is_python():
+                raise ValueError(
+                    f"Cannot convert column {name} to Arrow type, found Python type: {self.schema()[name].dtype}"
+                )
+
     @DataframePublicAPI
     def to_arrow_iter(
         self,
         results_buffer_size: Union[Optional[int], Literal["num_cpus"]] = "num_cpus",
     ) -> Iterator["pyarrow.RecordBatch"]:
         """Return an iterator of pyarrow recordbatches for this dataframe."""
-        for name in self.schema().column_names():
-            if self.schema()[name].dtype.is_python():
-                raise ValueError(
-                    f"Cannot convert column {name} to Arrow type, found Python type: {self.schema()[name].dtype}"
-                )
+        self._check_arrow_convertible()
 
         if results_buffer_size == "num_cpus":
             results_buffer_size = multiprocessing.cpu_count()
@@ -397,6 +435,54 @@ class DataFrame:
             for partition in partitions_iter:
                 yield from partition.to_arrow().to_batches()
 
+    @DataframePublicAPI
+    def iter_arrow_batches(
+        self,
+        batch_size: int,
+        results_buffer_size: Union[Optional[int], Literal["num_cpus"]] = "num_cpus",
+    ) -> Iterator["pyarrow.RecordBatch"]:
+        """Return an iterator of pyarrow recordbatches of `batch_size` rows each, except for the last, which may be smaller.
+
+        The dataframe is executed in a streaming fashion, and batches are yielded as soon as the partitions that make them
+        up are done, so results that are much larger than memory can be exported without collecting them.
+
+        Args:
+            batch_size: the number of rows in each batch.
+            results_buffer_size: how many partitions to allow in the results buffer (defaults to the total number of CPUs
+                available on the machine). See [`df.iter_partitions()`][daft.DataFrame.iter_partitions].
+
+        Examples:
+            >>> import daft
+            >>>
+            >>> df = daft.from_pydict({"foo": [1, 2, 3, 4, 5]}).into_partitions(2)
+            >>> for batch in df.iter_arrow_batches(batch_size=2):
+            ...     print(batch.num_rows)
+            2
+            2
+            1
+        """
+        if batch_size <= 0:
+            raise ValueError(f"Provided `batch_size` value must be > 0, received: {batch_size}")
+
+        import pyarrow as pa
+
+        pending: List[pa.RecordBatch] = []
+        num_pending_rows = 0
+        for batch in self.to_arrow_iter(results_buffer_size=results_buffer_size):
+            pending.append(batch)
+            num_pending_rows += batch.num_rows
+            if num_pending_rows < batch_size:
+                continue
+            table = pa.Table.from_batches(pending)
+            offset = 0
+            while num_pending_rows - offset >= batch_size:
+                yield table.slice(offset, batch_size).combine_chunks().to_batches()[0]
+                offset += batch_size
+            pending = table.slice(offset).to_batches()
+            num_pending_rows -= offset
+        if num_pending_rows > 0:
+            yield pa.Table.from_batches(pending).combine_chunks().to_batches()[0]
+
     @DataframePublicAPI
     def iter_partitions(
         self, results_buffer_size: Union[Optional[int], Literal["num_cpus"]] = "num_cpus"
@@ -482,20 +568,25 @@ class DataFrame:
         if self._result is None:
             return
 
+        num_preview_rows = get_display_options().max_rows or self._num_preview_rows
         preview_partition_invalid = (
-            self._preview.partition is None or len(self._preview.partition) < self._num_preview_rows
+            self._preview.partition is None or len(self._preview.partition) < num_preview_rows
         )
         if preview_partition_invalid:
-            preview_parts = self._result._get_preview_micropartitions(self._num_preview_rows)
-            preview_results = LocalPartitionSet()
-            for i, part in enumerate(preview_parts):
-                preview_results.set_partition_from_table(i, part)
-            preview_partition = preview_results._get_merged_micropartition()
             self._preview = Preview(
-                partition=preview_partition,
+                partition=self._preview_micropartition(num_preview_rows),
                 total_rows=len(self),
             )
 
+    def _preview_micropartition(self, n: int) -> MicroPartition:
+        """Merges the first `n` rows of the materialized results of the DataFrame, without recollecting them."""
+        assert self._result is not None
+        preview_parts = self._result._get_preview_micropartitions(n)
+        preview_results = LocalPartitionSet()
+        for i, part in enumerate(preview_parts):
+            preview_results.set_partition_from_table(i, part)
+        return preview_results._get_merged_micropartition()
+
     @DataframePublicAPI
     def __repr__(self) -> str:
         self._populate_preview()
@@ -538,13 +629,25 @@ class DataFrame:
     @classmethod
     def _from_arrow(cls, data: Union["pyarrow.Table", List["pyarrow.Table"], Iterable["pyarrow.Table"]]) -> "DataFrame":
         """Creates a DataFrame from a `pyarrow Table <https://arrow.apache.org/docs/python/generated/pyarrow.Table.html>`__."""
-        if isinstance(data, Iterable):
+        if hasattr(data, "__arrow_c_stream__"):
+            data = [data]
+        elif isinstance(data, Iterable):
             data = list(data)
         if not isinstance(data, list):
             data = [data]
-        parts = [MicroPartition.from_arrow(table) for table in data]
+        parts = [
+            MicroPartition.from_arrow(table) if _is_pyarrow_table(table) else MicroPartition.from_arrow_c_stream(table)
+            for table in data
+        ]
         return cls._from_micropartitions(*parts)
 
+    @classmethod
+    def _from_polars(cls, data: Union["polars.DataFrame", List["polars.DataFrame"]]) -> "DataFrame":
+        """Creates a Daft DataFrame from a `polars DataFrame <https://docs.pola.rs/api/python/stable/reference/dataframe/index.html>`__."""
+        if not isinstance(data, list):
+            data = [data]
+        return cls._from_arrow([pl_df.to_arrow() for pl_df in data])
+
     @classmethod
     def _from_pandas(cls, data: Union["pandas.DataFrame", List["pandas.DataFrame"]]) -> "DataFrame":
         """Creates a Daft DataFrame from a `pandas DataFrame <https://pandas.pydata.org/docs/reference/api/pandas.DataFrame.html>`__."""
@@ -616,10 +719,13 @@ class DataFrame:
         write_mode: Literal["append", "overwrite", "overwrite-partitions"] = "append",
         partition_cols: Optional[List[ColumnInputType]] = None,
         io_config: Optional[IOConfig] = None,
+        max_open_writers: Optional[int] = None,
     ) -> "DataFrame":
         """Writes the DataFrame as parquet files, returning a new DataFrame with paths to the files that were written.
 
-        Files will be written to `<root_dir>/*` with randomly generated UUIDs as the file names.
+        Files will be written to `<root_dir>/*` with randomly generated UUIDs as the file names. Partitioned writes
+        route rows to Hive-style `<root_dir>/<col>=<value>/` directories, escaping characters such as `/` and `=` in
+        partition values, and writing nulls as `__HIVE_DEFAULT_PARTITION__`.
 
         Args:
             root_dir (str): root file path to write parquet files to.
@@ -627,9 +733,10 @@ class DataFrame:
             write_mode (str, optional): Operation mode of the write. `append` will add new data, `overwrite` will replace the contents of the root directory with new data. `overwrite-partitions` will replace only the contents in the partitions that are being written to. Defaults to "append".
             partition_cols (Optional[List[ColumnInputType]], optional): How to subpartition each partition further. Defaults to None.
             io_config (Optional[IOConfig], optional): configurations to use when interacting with remote storage.
+            max_open_writers (Optional[int], optional): The maximum number of files that a partitioned write keeps open at once in each task. Once reached, the file of the partition that was least recently written to is closed, and a new file is started if the partition is written to again. Defaults to None, which keeps a file open for every partition.
 
         Returns:
-            DataFrame: The filenames that were written out as strings.
+            DataFrame: The files that were written, with a row per file of its path and, for partitioned writes, the partition values of its rows.
 
         Note:
             This call is **blocking** and will execute the DataFrame when called
@@ -640,6 +747,8 @@ class DataFrame:
             )
         if write_mode == "overwrite-partitions" and partition_cols is None:
             raise ValueError("Partition columns must be specified to use `overwrite-partitions` mode.")
+        if max_open_writers is not None and max_open_writers < 1:
+            raise ValueError(f"max_open_writers must be at least 1, but got: {max_open_writers}")
 
         io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
 
@@ -654,6 +763,7 @@ class DataFrame:
             file_format=FileFormat.Parquet,
             compression=compression,
             io_config=io_config,
+            max_open_writers=max_open_writers,
         )
         # Block and write, then retrieve data
         write_df = DataFrame(builder)
@@ -687,6 +797,7 @@ class DataFrame:
         write_mode: Literal["append", "overwrite", "overwrite-partitions"] = "append",
         partition_cols: Optional[List[ColumnInputType]] = None,
         io_config: Optional[IOConfig] = None,
+        max_open_writers: Optional[int] = None,
     ) -> "DataFrame":
         """Writes the DataFrame as CSV files, returning a new DataFrame with paths to the files that were written.
 
@@ -697,6 +808,7 @@ class DataFrame:
             write_mode (str, optional): Operation mode of the write. `append` will add new data, `overwrite` will replace the contents of the root directory with new data. `overwrite-partitions` will replace only the contents in the partitions that are being written to. Defaults to "append".
             partition_cols (Optional[List[ColumnInputType]], optional): How to subpartition each partition further. Defaults to None.
             io_config (Optional[IOConfig], optional): configurations to use when interacting with remote storage.
+            max_open_writers (Optional[int], optional): The maximum number of files that a partitioned write keeps open at once in each task, as in [`write_parquet`][daft.DataFrame.write_parquet]. Defaults to None.
 
         Returns:
             DataFrame: The filenames that were written out as strings.
@@ -711,6 +823,8 @@ class DataFrame:
             )
         if write_mode == "overwrite-partitions" and partition_cols is None:
             raise ValueError("Partition columns must be specified to use `overwrite-partitions` mode.")
+        if max_open_writers is not None and max_open_writers < 1:
+            raise ValueError(f"max_open_writers must be at least 1, but got: {max_open_writers}")
 
         io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
 
@@ -724,6 +838,7 @@ class DataFrame:
             write_mode=WriteMode.from_str(write_mode),
             file_format=FileFormat.Csv,
             io_config=io_config,
+            max_open_writers=max_open_writers,
         )
 
         # Block and write, then retrieve data
@@ -751,16 +866,25 @@ class DataFrame:
 
     @DataframePublicAPI
     def write_iceberg(
-        self, table: "pyiceberg.table.Table", mode: str = "append", io_config: Optional[IOConfig] = None
+        self,
+        table: "pyiceberg.table.Table",
+        mode: Literal["append", "overwrite", "overwrite-partitions"] = "append",
+        io_config: Optional[IOConfig] = None,
+        schema_mode: Optional[Literal["merge"]] = None,
     ) -> "DataFrame":
         """Writes the DataFrame to an [Iceberg](https://iceberg.apache.org/docs/nightly/) table, returning a new DataFrame with the operations that occurred.
 
-        Can be run in either `append` or `overwrite` mode which will either appends the rows in the DataFrame or will delete the existing rows and then append the DataFrame rows respectively.
+        Can be run in `append` mode, which appends the rows in the DataFrame to the table, `overwrite` mode, which deletes the existing rows and then appends the DataFrame rows,
+        or `overwrite-partitions` mode, which only replaces the partitions of the table that the DataFrame has rows in.
+
+        On the native runner, the data files, manifests and snapshot of the write are written by Daft, and the snapshot is committed through the catalog of `table`.
+        Other runners write the data files with PyArrow and commit them with PyIceberg, which only supports the `append` and `overwrite` modes.
 
         Args:
             table (pyiceberg.table.Table): Destination [PyIceberg Table](https://py.iceberg.apache.org/reference/pyiceberg/table/#pyiceberg.table.Table) to write dataframe to.
-            mode (str, optional): Operation mode of the write. `append` or `overwrite` Iceberg Table. Defaults to `append`.
+            mode (str, optional): Operation mode of the write. `append`, `overwrite` or `overwrite-partitions` Iceberg Table. Defaults to `append`.
             io_config (IOConfig, optional): A custom IOConfig to use when accessing Iceberg object storage data. If provided, configurations set in `table` are ignored.
+            schema_mode (str, optional): Schema mode of the write. If set to `merge`, columns of the DataFrame that the table doesn't have are added to the table as optional columns. Only supported on the native runner.
 
         Returns:
             DataFrame: The operations that occurred with this write.
@@ -786,14 +910,31 @@ class DataFrame:
                 f"Write Iceberg is only supported on pyarrow>=12.0.1, found {pa.__version__}. See this issue for more information: https://github.com/apache/arrow/issues/37054#issuecomment-1668644887"
             )
 
-        if mode not in ["append", "overwrite"]:
-            raise ValueError(f"Only support `append` or `overwrite` mode. {mode} is unsupported")
+        if mode not in ["append", "overwrite", "overwrite-partitions"]:
+            raise ValueError(
+                f"Only support `append`, `overwrite` or `overwrite-partitions` mode. {mode} is unsupported"
+            )
+
+        if schema_mode not in [None, "merge"]:
+            raise ValueError(f"Only support `merge` schema mode. {schema_mode} is unsupported")
 
         io_config = (
             _convert_iceberg_file_io_properties_to_io_config(table.io.properties) if io_config is None else io_config
         )
         io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
 
+        if get_context().get_or_create_runner().name == "native":
+            if parse(pyiceberg.__version__) < parse("0.7.0"):
+                raise ValueError(
+                    f"Native Iceberg writes require pyiceberg>=0.7.0, found {pyiceberg.__version__}"
+                )
+            return self._write_iceberg_native(table, mode, io_config, schema_mode == "merge")
+
+        if mode == "overwrite-partitions" or schema_mode is not None:
+            raise ValueError(
+                "Writing Iceberg tables in `overwrite-partitions` mode or with `merge` schema mode is only supported on the native runner"
+            )
+
         operations = []
         path = []
         rows = []
@@ -900,6 +1041,57 @@ class DataFrame:
         # This is due to the fact that the logical plan of the write_iceberg returns datafiles but we want to return the above data
         return from_pydict(with_operations)
 
+    def _write_iceberg_native(
+        self, table: "pyiceberg.table.Table", mode: str, io_config: IOConfig, merge_schema: bool
+    ) -> "DataFrame":
+        import json
+
+        import pyarrow as pa
+        from pydantic import TypeAdapter
+
+        try:
+            from pyiceberg.table.update import TableRequirement, TableUpdate
+        except ImportError:
+            from pyiceberg.table import TableRequirement, TableUpdate
+
+        from daft.daft import IcebergWrite
+
+        native_write = IcebergWrite(table.metadata.model_dump_json(), self.schema()._schema, mode, merge_schema)
+
+        builder = self._builder.write_iceberg(table, io_config, native_write)
+        write_df = DataFrame(builder)
+        write_df.collect()
+
+        write_result = write_df.to_pydict()
+        assert "data_file" in write_result
+        commit = json.loads(native_write.commit(write_result["data_file"], io_config))
+
+        update_adapter = TypeAdapter(TableUpdate)
+        requirement_adapter = TypeAdapter(TableRequirement)
+        table._do_commit(
+            updates=tuple(update_adapter.validate_python(update) for update in commit["updates"]),
+            requirements=tuple(requirement_adapter.validate_python(req) for req in commit["requirements"]),
+        )
+
+        operations = commit["operations"]
+        with_operations = {
+            "operation": pa.array([op["operation"] for op in operations], type=pa.string()),
+            "rows": pa.array([op["record_count"] for op in operations], type=pa.int64()),
+            "file_size": pa.array([op["file_size_in_bytes"] for op in operations], type=pa.int64()),
+            "file_name": pa.array([op["file_path"] for op in operations], type=pa.string()),
+        }
+
+        partition_names = [field.name for field in table.spec().fields]
+        if partition_names:
+            with_operations["partitioning"] = pa.StructArray.from_arrays(
+                [pa.array([op["partition"].get(name) for op in operations]) for name in partition_names],
+                names=partition_names,
+            )
+
+        from daft import from_pydict
+
+        return from_pydict(with_operations)
+
     @DataframePublicAPI
     def write_deltalake(
         self,
@@ -917,6 +1109,8 @@ class DataFrame:
     ) -> "DataFrame":
         """Writes the DataFrame to a [Delta Lake](https://docs.delta.io/latest/index.html) table, returning a new DataFrame with the operations that occurred.
 
+        On the native runner, tables on local disk or S3 are written and committed to by Daft when `dynamo_table_name` isn't set. Commits are conditional uploads of log files, so concurrent writers are detected without a locking provider: appends are retried on top of the commits of other writers, while overwrites fail if other writers changed the table.
+
         Args:
             table (Union[str, pathlib.Path, DataCatalogTable, deltalake.DeltaTable, UnityCatalogTable]): Destination [Delta Lake Table](https://delta-io.github.io/delta-rs/api/delta_table/) or table URI to write dataframe to.
             partition_cols (List[str], optional): How to subpartition each partition further. If table exists, expected to match table's existing partitioning scheme, otherwise creates the table with specified partition columns. Defaults to None.
@@ -1007,6 +1201,12 @@ class DataFrame:
 
         # see: https://delta-io.github.io/delta-rs/usage/writing/writing-to-s3-with-locking-provider/
         scheme = get_protocol_from_path(table_uri)
+        # The native runner commits with conditional uploads of log files, which need no locking provider
+        native = (
+            get_context().get_or_create_runner().name == "native"
+            and dynamo_table_name is None
+            and scheme in ("file", "s3", "s3a")
+        )
         if scheme == "s3" or scheme == "s3a":
             if dynamo_table_name is not None:
                 storage_options["AWS_S3_LOCKING_PROVIDER"] = "dynamodb"
@@ -1014,13 +1214,25 @@ class DataFrame:
             else:
                 storage_options["AWS_S3_ALLOW_UNSAFE_RENAME"] = "true"
 
-                if not allow_unsafe_rename:
+                if not allow_unsafe_rename and not native:
                     warnings.warn("No DynamoDB table specified for Delta Lake locking. Defaulting to unsafe writes.")
         elif scheme == "file":
             if allow_unsafe_rename:
                 storage_options["MOUNT_ALLOW_UNSAFE_RENAME"] = "true"
 
-        pyarrow_schema = pa.schema((f.name, f.dtype.to_arrow_dtype()) for f in self.schema())
+        if vector_index is not None and vector_index.get("column") not in self.column_names:
+            raise ValueError(
+                f"The vector index must be built on one of the columns {self.column_names}, got: {vector_index.get('column')}"
+            )
+
+        # Embeddings are written as their fixed size lists, since Lance can't index Daft's extension type of them.
+        embeddings = {
+            f.name: col(f.name).cast(DataType.fixed_size_list(f.dtype.dtype, f.dtype.size))
+            for f in self.schema()
+            if f.dtype.is_embedding()
+        }
+        df = self.with_columns(embeddings) if embeddings else self
+        pyarrow_schema = pa.schema((f.name, f.dtype.to_arrow_dtype()) for f in df.schema())
 
         large_dtypes = True
         delta_schema = _convert_pa_schema_to_delta(pyarrow_schema, **large_dtypes_kwargs(large_dtypes))
@@ -1061,6 +1273,23 @@ class DataFrame:
                 if self.schema()[c].dtype == DataType.binary():
                     raise NotImplementedError("Binary partition columns are not yet supported for Delta Lake writes")
 
+        if native:
+            return self._write_deltalake_native(
+                table_uri,
+                table,
+                mode,
+                schema_mode == "overwrite",
+                version,
+                large_dtypes,
+                pyarrow_schema=delta_schema,
+                partition_cols=partition_cols,
+                name=name,
+                description=description,
+                configuration=configuration,
+                custom_metadata=custom_metadata,
+                io_config=io_config,
+            )
+
         builder = self._builder.write_deltalake(
             table_uri,
             mode,
@@ -1128,12 +1357,112 @@ class DataFrame:
 
         return with_operations
 
+    def _write_deltalake_native(
+        self,
+        table_uri: str,
+        table: Optional["deltalake.DeltaTable"],
+        mode: str,
+        overwrite_schema: bool,
+        version: int,
+        large_dtypes: bool,
+        pyarrow_schema: "pa.Schema",
+        partition_cols: Optional[List[str]],
+        name: Optional[str],
+        description: Optional[str],
+        configuration: Optional[Mapping[str, Optional[str]]],
+        custom_metadata: Optional[Dict[str, str]],
+        io_config: IOConfig,
+    ) -> "DataFrame":
+        import json
+
+        import pyarrow as pa
+        from deltalake.schema import Schema as DeltaSchema
+
+        from daft import from_pydict
+        from daft.daft import DeltaLakeWrite
+
+        snapshot_json = None
+        if table is not None:
+            metadata = table.metadata()
+            protocol = table.protocol()
+            # Only overwrites remove the files of the table
+            files = table.get_add_actions().to_pydict() if mode == "overwrite" else {}
+            snapshot_json = json.dumps(
+                {
+                    "version": table.version(),
+                    "protocol": {
+                        "minReaderVersion": protocol.min_reader_version,
+                        "minWriterVersion": protocol.min_writer_version,
+                        "readerFeatures": getattr(protocol, "reader_features", None),
+                        "writerFeatures": getattr(protocol, "writer_features", None),
+                    },
+                    "metaData": {
+                        "id": str(metadata.id),
+                        "name": metadata.name,
+                        "description": metadata.description,
+                        "format": {"provider": "parquet", "options": {}},
+                        "schemaString": table.schema().to_json(),
+                        "partitionColumns": metadata.partition_columns,
+                        "configuration": metadata.configuration,
+                        "createdTime": metadata.created_time,
+                    },
+                    "files": [
+                        {"path": path, "size": size, "numRecords": num_records}
+                        for path, size, num_records in zip(
+                            files.get("path", []), files.get("size_bytes", []), files.get("num_records", [])
+                        )
+                    ],
+                }
+            )
+
+        native_write = DeltaLakeWrite(
+            table_uri,
+            DeltaSchema.from_pyarrow(pyarrow_schema).to_json(),
+            "overwrite" if mode == "overwrite" else "append",
+            partition_cols or [],
+            snapshot_json,
+            overwrite_schema=overwrite_schema,
+            name=name,
+            description=description,
+            configuration=dict(configuration) if configuration is not None else None,
+            custom_metadata=custom_metadata,
+        )
+
+        builder = self._builder.write_deltalake(
+            table_uri,
+            mode,
+            version,
+            large_dtypes,
+            io_config=io_config,
+            partition_cols=partition_cols,
+            native_write=native_write,
+        )
+        write_df = DataFrame(builder)
+        write_df.collect()
+
+        write_result = write_df.to_pydict()
+        assert "add_action" in write_result
+        commit = json.loads(native_write.commit(write_result["add_action"], io_config))
+        if table is not None:
+            table.update_incremental()
+
+        operations = commit["operations"]
+        return from_pydict(
+            {
+                "operation": pa.array([op["operation"] for op in operations], type=pa.string()),
+                "rows": pa.array([op["rows"] for op in operations], type=pa.int64()),
+                "file_size": pa.array([op["size"] for op in operations], type=pa.int64()),
+                "file_name": pa.array([os.path.basename(op["path"]) for op in operations], type=pa.string()),
+            }
+        )
+
     @DataframePublicAPI
     def write_lance(
         self,
         uri: Union[str, pathlib.Path],
         mode: Literal["create", "append", "overwrite"] = "create",
         io_config: Optional[IOConfig] = None,
+        vector_index: Optional[Dict[str, Any]] = None,
         **kwargs,
     ) -> "DataFrame":
         """Writes the DataFrame to a Lance table.
@@ -1142,11 +1471,16 @@ class DataFrame:
           uri: The URI of the Lance table to write to
           mode: The write mode. One of "create", "append", or "overwrite"
           io_config (IOConfig, optional): configurations to use when interacting with remote storage.
+          vector_index (Dict[str, Any], optional): If provided, a vector index is (re)built on the table after the
+            write, with these keyword arguments passed to `lance.LanceDataset.create_index`. They must include the
+            `column` to index, and `index_type` defaults to "IVF_PQ".
           **kwargs: Additional keyword arguments to pass to the Lance writer.
 
         Note:
             write_lance` requires python 3.9 or higher
 
+            Embedding columns are written as fixed size lists, which Lance can build vector indices on.
+
         Examples:
             >>> import daft
             >>> df = daft.from_pydict({"a": [1, 2, 3, 4]})
@@ -1188,6 +1522,13 @@ class DataFrame:
             ╰───────────────┴──────────────────┴─────────────────┴─────────╯
             <BLANKLINE>
             (Showing first 1 of 1 rows)
+            >>> # Build a vector index on an embedding column after writing it
+            >>> df = daft.from_pydict({"vector": [[float(i), float(i + 1)] for i in range(1024)]})
+            >>> df = df.with_column("vector", df["vector"].cast(daft.DataType.embedding(daft.DataType.float32(), 2)))
+            >>> df.write_lance(
+            ...     "/tmp/lance/vectors.lance",
+            ...     vector_index={"column": "vector", "num_partitions": 4, "num_sub_vectors": 1},
+            ... )  # doctest: +SKIP
         """
         from daft import from_pydict
         from daft.io.object_store_options import io_config_to_storage_options
@@ -1208,7 +1549,19 @@ class DataFrame:
                 table_uri = str(uri)
             else:
                 table_uri = uri
-        pyarrow_schema = pa.schema((f.name, f.dtype.to_arrow_dtype()) for f in self.schema())
+        if vector_index is not None and vector_index.get("column") not in self.column_names:
+            raise ValueError(
+                f"The vector index must be built on one of the columns {self.column_names}, got: {vector_index.get('column')}"
+            )
+
+        # Embeddings are written as their fixed size lists, since Lance can't index Daft's extension type of them.
+        embeddings = {
+            f.name: col(f.name).cast(DataType.fixed_size_list(f.dtype.dtype, f.dtype.size))
+            for f in self.schema()
+            if f.dtype.is_embedding()
+        }
+        df = self.with_columns(embeddings) if embeddings else self
+        pyarrow_schema = pa.schema((f.name, f.dtype.to_arrow_dtype()) for f in df.schema())
 
         storage_options = io_config_to_storage_options(io_config, table_uri)
 
@@ -1228,7 +1581,7 @@ class DataFrame:
                     f"Data schema:\n{pyarrow_schema}\nTable Schema:\n{table_schema}"
                 )
 
-        builder = self._builder.write_lance(
+        builder = df._builder.write_lance(
             table_uri,
             mode,
             io_config=io_config,
@@ -1247,6 +1600,9 @@ class DataFrame:
             operation = lance.LanceOperation.Append(fragments)
 
         dataset = lance.LanceDataset.commit(table_uri, operation, read_version=version, storage_options=storage_options)
+        if vector_index is not None:
+            dataset.create_index(**{"index_type": "IVF_PQ", "replace": True, **vector_index})
+            dataset = lance.dataset(table_uri, storage_options=storage_options)
         stats = dataset.stats.dataset_stats()
 
         tbl = from_pydict(
@@ -1420,6 +1776,288 @@ class DataFrame:
         builder = self._builder.summarize()
         return DataFrame(builder)
 
+    @DataframePublicAPI
+    def analyze(self) -> "DataFrame":
+        """Executes the DataFrame to collect table and column statistics, and attaches them to its source.
+
+        The collected statistics are the number of rows, and for each column, its number of nulls, its approximate
+        number of distinct values, and for numeric and temporal columns, its minimum and maximum values. They're kept with
+        the returned DataFrame and any DataFrame derived from it, and are used to estimate cardinalities when choosing
+        join strategies, join build sides, and partition counts if cost-based planning is enabled via
+        `daft.set_planning_config(enable_cost_based_planning=True)`.
+
+        Note: This can only be called on a DataFrame that directly reads from a table or files, before any other
+            operations are applied.
+
+        Returns:
+            DataFrame: DataFrame with the same data, whose source has the collected statistics attached.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.read_parquet("data/*.parquet")  # doctest: +SKIP
+            >>> df = df.analyze()  # doctest: +SKIP
+        """
+        stats = DataFrame(self._builder.analyze()).to_pydict()
+        columns = {}
+        for i, name in enumerate(self.column_names):
+            min_value = stats[f"min_{i}"][0] if f"min_{i}" in stats else None
+            max_value = stats[f"max_{i}"][0] if f"max_{i}" in stats else None
+            columns[name] = (
+                stats[f"null_count_{i}"][0],
+                stats[f"approx_distinct_count_{i}"][0],
+                lit(min_value) if min_value is not None else None,
+                lit(max_value) if max_value is not None else None,
+            )
+        builder = self._builder.with_source_statistics(stats["num_rows"][0], columns)
+        return DataFrame(builder)
+
+    @DataframePublicAPI
+    def intern_strings(self, *cols: str, max_categories: int = 1 << 16) -> "DataFrame":
+        """Interns low-cardinality string columns, so that their repeated values share storage across partitions.
+
+        Executes the DataFrame to collect the distinct values of each column, and casts the columns to categoricals of
+        those values. Each value is then stored as a ``uint32`` code into categories that are held once by the data
+        type rather than by every partition, which shrinks what scans, shuffles and joins hold and move for columns
+        such as country codes.
+
+        Note: This executes the DataFrame once to collect the distinct values, and again when the result is executed,
+            so it's best called on the output of a read or of `DataFrame.collect`. When the values are known up front,
+            reads can intern them directly by passing ``DataType.categorical`` in their ``schema``.
+
+        Args:
+            *cols (str): The names of the string columns to intern.
+            max_categories (int): The number of distinct values that a column may have at most, above which a
+                ValueError is raised rather than interning it. Defaults to 65536.
+
+        Returns:
+            DataFrame: DataFrame whose interned columns are categoricals of their distinct values, in sorted order.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"country": ["US", "FR", "US", None, "FR"]}).intern_strings("country")
+            >>> df.schema()["country"].dtype == daft.DataType.categorical(["FR", "US"])
+            True
+            >>> df.to_pydict()
+            {'country': ['US', 'FR', 'US', None, 'FR']}
+        """
+        schema = self.schema()
+        for name in cols:
+            if name not in schema.column_names():
+                raise ValueError(f"Column {name} not found in the DataFrame, which has columns: {schema.column_names()}")
+            if schema[name].dtype != DataType.string():
+                raise ValueError(f"Only string columns can be interned, but {name} is of type {schema[name].dtype}")
+        if not cols:
+            return self
+
+        distinct = self.agg(*[col(name).agg_set().alias(name) for name in cols]).to_pydict()
+        interned = {}
+        for name in cols:
+            categories = sorted(v for v in distinct[name][0] if v is not None)
+            if len(categories) > max_categories:
+                raise ValueError(
+                    f"Column {name} has {len(categories)} distinct values, more than the {max_categories} that "
+                    "can be interned"
+                )
+            interned[name] = col(name).cast_to_categorical(categories)
+        return self.with_columns(interned)
+
+    @DataframePublicAPI
+    def validate(self, constraints: Iterable[Union["Constraint", Expression]]) -> "ValidationResult":
+        """Checks that the rows of the DataFrame satisfy constraints, while executing it.
+
+        Each constraint is a boolean expression along with a severity, and a row violates it when the expression is
+        false or null. Violations of ``"fail"`` constraints raise a `ValidationError`, violations of ``"warn"``
+        constraints emit a warning, and rows that violate ``"quarantine"`` constraints are split off into a separate
+        DataFrame. Expressions that aren't wrapped in a `Constraint` have severity ``"fail"``.
+
+        Note: This executes the DataFrame, evaluating the constraints in the same pass, and the DataFrames of the
+            result read its materialized rows rather than executing it again.
+
+        Args:
+            constraints (Iterable[Constraint | Expression]): The constraints to check, with unique names.
+
+        Returns:
+            ValidationResult: The rows that weren't quarantined, the quarantined rows with a ``_violations`` column
+                listing the constraints that they violated, and the number of violations of each constraint.
+
+        Raises:
+            ValidationError: If any row violates a ``"fail"`` constraint. Its ``summary`` has the number of violations
+                of each constraint.
+
+        Examples:
+            >>> import daft
+            >>> from daft import Constraint, col
+            >>> df = daft.from_pydict({"id": [1, 2, 3], "age": [30, -1, None]})
+            >>> result = df.validate(
+            ...     [
+            ...         Constraint(col("id") > 0, name="positive_id"),
+            ...         Constraint(col("age") >= 0, severity="quarantine", name="valid_age"),
+            ...     ]
+            ... )
+            >>> [(s.name, s.violations) for s in result.summary]
+            [('positive_id', 0), ('valid_age', 2)]
+            >>> result.df.to_pydict()
+            {'id': [1], 'age': [30]}
+            >>> result.quarantine.to_pydict()
+            {'id': [2, 3], 'age': [-1, None], '_violations': [['valid_age'], ['valid_age']]}
+        """
+        from daft.dataframe.validation import Constraint, validate
+
+        return validate(self, [c if isinstance(c, Constraint) else Constraint(c) for c in constraints])
+
+    @DataframePublicAPI
+    def sessionize(
+        self,
+        by: ManyColumnsInputType,
+        timestamp: ColumnInputType,
+        gap: Union[int, float, timedelta],
+        session_column: str = "session_id",
+    ) -> "DataFrame":
+        """Groups the events of each key into sessions, which end after a gap of inactivity.
+
+        Events are ordered by their timestamp within each key, and an event starts a new session when more than ``gap``
+        has passed since the previous event of its key. The events of each key are brought together before they're
+        ordered, so sessions are assigned correctly regardless of how events are spread across partitions.
+
+        Note: Events with a null timestamp are each put in a session of their own.
+
+        Args:
+            by (ManyColumnsInputType): The columns whose values identify the key that events belong to, such as a user ID.
+            timestamp (ColumnInputType): The column that orders the events of each key, such as a timestamp or a number.
+            gap (int | float | timedelta): The inactivity gap, greater than which consecutive events of a key are in
+                different sessions. A ``timedelta`` for timestamp columns, and a number for numeric columns.
+            session_column (str): The name of the column to add. Defaults to ``"session_id"``.
+
+        Returns:
+            DataFrame: DataFrame with a column that numbers the sessions of each key from 0 in time order, which
+                identifies a session together with the key columns.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"user": ["a", "a", "a", "b", "b"], "ts": [1, 3, 20, 5, 6]})
+            >>> df.sessionize("user", "ts", gap=10).sort(["user", "ts"]).to_pydict()
+            {'user': ['a', 'a', 'a', 'b', 'b'], 'ts': [1, 3, 20, 5, 6], 'session_id': [0, 0, 1, 0, 0]}
+        """
+        from daft.window import Window
+
+        if gap < (timedelta(0) if isinstance(gap, timedelta) else 0):
+            raise ValueError(f"The gap of a session must be non-negative, but got: {gap}")
+        keys = self._wildcard_inputs_to_expressions((by,))
+        if not keys:
+            raise ValueError("sessionize requires at least one column to group events by")
+        ts = col(timestamp) if isinstance(timestamp, str) else timestamp
+        window = Window().partition_by(keys).order_by(ts)
+        is_start = "__daft_session_start"
+        df = self.with_column(is_start, ((ts - ts.lag().over(window)) > lit(gap)).fill_null(lit(True)))
+        session = col(is_start).cast(DataType.int64()).sum().over(
+            window.rows_between(Window.unbounded_preceding, Window.current_row)
+        )
+        return df.with_column(session_column, session - 1).exclude(is_start)
+
+    @DataframePublicAPI
+    def window_agg(
+        self,
+        timestamp: ColumnInputType,
+        size: Union[int, timedelta],
+        *to_agg: Union[Expression, Iterable[Expression]],
+        slide: Optional[Union[int, timedelta]] = None,
+        by: Optional[ManyColumnsInputType] = None,
+    ) -> "DataFrame":
+        """Aggregates the events of each key over tumbling or sliding windows of event time.
+
+        Windows cover ``[window_start, window_end)`` and start at multiples of ``slide`` since the epoch, or since zero
+        for integer event times. With the default ``slide`` windows tumble, so each event falls in exactly one of them,
+        while a smaller ``slide`` makes windows overlap, so each event is aggregated in every window that it falls in.
+        To aggregate the micro-batches of a stream over windows, use `StreamingWindowAggregation`.
+
+        Note: Events with a null timestamp aren't in any window.
+
+        Args:
+            timestamp (ColumnInputType): The event time of each event, which must be a timestamp or an integer.
+            size (int | timedelta): The length of each window. A ``timedelta`` for timestamp columns, and an integer for
+                integer columns.
+            *to_agg (Expression): The aggregations to compute over the events of each window and key.
+            slide (int | timedelta, optional): How far apart the starts of consecutive windows are, which must not be
+                greater than ``size``. Defaults to ``size``.
+            by (ManyColumnsInputType, optional): The keys to aggregate the events of each window by.
+
+        Returns:
+            DataFrame: One row per window and key that has events, with the ``window_start`` and ``window_end`` of the
+                window, the keys, and the aggregations.
+
+        Examples:
+            >>> import daft
+            >>> from daft import col
+            >>> df = daft.from_pydict({"ts": [1, 4, 12, 15], "x": [1, 2, 3, 4]})
+            >>> df.window_agg("ts", 10, col("x").sum()).sort("window_start").to_pydict()
+            {'window_start': [0, 10], 'window_end': [10, 20], 'x': [3, 7]}
+            >>> df.window_agg("ts", 10, col("x").sum(), slide=5).sort("window_start").to_pydict()
+            {'window_start': [-5, 0, 5, 10, 15], 'window_end': [5, 10, 15, 20, 25], 'x': [3, 3, 3, 7, 4]}
+        """
+        from daft.dataframe.event_time_windows import window_agg
+
+        to_agg_list = (
+            list(to_agg[0])
+            if (len(to_agg) == 1 and not isinstance(to_agg[0], Expression))
+            else list(typing.cast("Tuple[Expression]", to_agg))
+        )
+        if not to_agg_list:
+            raise ValueError("window_agg requires at least one aggregation")
+        keys = self._wildcard_inputs_to_expressions((by,)) if by is not None else []
+        ts = col(timestamp) if isinstance(timestamp, str) else timestamp
+        return window_agg(self, ts, size, slide, keys, to_agg_list)
+
+    @DataframePublicAPI
+    def nearest_neighbors(
+        self,
+        query_vectors: Union["DataFrame", List[Any]],
+        on: str,
+        k: int = 10,
+        *,
+        query_on: Optional[str] = None,
+        metric: Optional["Metric"] = None,
+        index: Optional["VectorIndex"] = None,
+        num_probes: Optional[int] = None,
+        distance_column: str = "distance",
+    ) -> "DataFrame":
+        """Finds the ``k`` rows of the DataFrame whose vectors are nearest to each query vector.
+
+        Without an index, the rows are executed and searched exhaustively, which is exact. A `VectorIndex` built from
+        this DataFrame with `VectorIndex.build`, or loaded with `VectorIndex.load`, is searched instead, which avoids
+        reindexing the rows for every search and, for an IVF index, is approximate but faster.
+
+        Args:
+            query_vectors (DataFrame | list): The queries, either as a DataFrame with a column of vectors or as a list of
+                vectors. Null query vectors have no neighbors.
+            on (str): The column of vectors to search, which must be fixed-size lists or embeddings of numbers. Null
+                vectors are never neighbors.
+            k (int): The number of neighbors to find for each query. Defaults to 10.
+            query_on (str, optional): The column of vectors of ``query_vectors``. Defaults to ``on``.
+            metric (str, optional): How distances are measured: ``"cosine"`` (one minus the cosine similarity),
+                ``"l2"`` (the Euclidean distance) or ``"dot"`` (the negated dot product). Defaults to ``"cosine"``, or
+                to the metric of the index.
+            index (VectorIndex, optional): An index built from this DataFrame over the ``on`` column.
+            num_probes (int, optional): Overrides the number of lists of each partition that an IVF index searches.
+            distance_column (str): The name of the distance column to add. Defaults to ``"distance"``.
+
+        Returns:
+            DataFrame: One row per query and neighbor, with the columns of the neighbor, the columns of the query, and
+                the distance between them. Query columns whose names are taken are prefixed with ``query_``.
+
+        Examples:
+            >>> import daft
+            >>> dtype = daft.DataType.fixed_size_list(daft.DataType.float32(), 2)
+            >>> df = daft.from_pydict({"doc": ["a", "b", "c"], "embedding": [[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]})
+            >>> df = df.with_column("embedding", df["embedding"].cast(dtype))
+            >>> queries = daft.from_pydict({"query": ["x"], "embedding": [[2.0, 0.1]]})
+            >>> neighbors = df.nearest_neighbors(queries, on="embedding", k=2, metric="l2")
+            >>> neighbors.sort("distance").select("doc", "query").to_pydict()
+            {'doc': ['a', 'c'], 'query': ['x', 'x']}
+        """
+        from daft.dataframe.vector_search import nearest_neighbors
+
+        return nearest_neighbors(self, query_vectors, on, k, query_on, metric, index, num_probes, distance_column)
+
     @DataframePublicAPI
     def distinct(self) -> "DataFrame":
         """Computes distinct rows, dropping duplicates.
@@ -1517,6 +2155,36 @@ class DataFrame:
         builder = self._builder.sample(fraction, with_replacement, seed)
         return DataFrame(builder)
 
+    @DataframePublicAPI
+    def sample_n(self, n: int, seed: Optional[int] = None) -> "DataFrame":
+        """Samples `n` rows uniformly at random from the DataFrame, without replacement.
+
+        Each row is given a random key and the `n` rows with the smallest keys are kept, so sampling only holds a
+        reservoir of `n` candidate rows per partition at a time.
+
+        Args:
+            n (int): number of rows to sample. If the DataFrame has fewer rows, all of them are returned.
+            seed (Optional[int], optional): random seed. Defaults to None.
+
+        Returns:
+            DataFrame: DataFrame with `n` rows, or all rows if it has fewer.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"x": [1, 2, 3, 4, 5]})
+            >>> df.sample_n(2).count_rows()
+            2
+        """
+        if n < 0:
+            raise ValueError(f"n should be non-negative, but got {n}")
+        if seed is None:
+            seed = random.getrandbits(63)
+
+        id_column, key_column = "__sample_n_id__", "__sample_n_key__"
+        df = self._add_monotonically_increasing_id(id_column)
+        df = df.with_column(key_column, col(id_column).hash(seed=seed))
+        return df.sort(key_column).limit(n).exclude(id_column, key_column)
+
     @DataframePublicAPI
     def exclude(self, *names: str) -> "DataFrame":
         """Drops columns from the current DataFrame by name.
@@ -2001,7 +2669,8 @@ class DataFrame:
             right_on (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): key or keys to join on right DataFrame. Defaults to None.
             how (str, optional): what type of join to perform; currently "inner", "left", "right", "outer", "anti", "semi", and "cross" are supported. Defaults to "inner".
             strategy (Optional[str]): The join strategy (algorithm) to use; currently "hash", "sort_merge", "broadcast", and None are supported, where None
-                chooses the join strategy automatically during query optimization. The default is None.
+                chooses the join strategy automatically during query optimization, or a broadcast join if either side is hinted with
+                [DataFrame.broadcast()][daft.DataFrame.broadcast]. The default is None.
             suffix (Optional[str], optional): Suffix to add to the column names in case of a name collision. Defaults to "".
             prefix (Optional[str], optional): Prefix to add to the column names in case of a name collision. Defaults to "right.".
 
@@ -2088,6 +2757,15 @@ class DataFrame:
 
         join_type = JoinType.from_join_type_str(how)
         join_strategy = JoinStrategy.from_join_strategy_str(strategy) if strategy is not None else None
+        if join_strategy is None and how != "cross":
+            for side, df, broadcastable_joins in [
+                ("left", self, (JoinType.Inner, JoinType.Right)),
+                ("right", other, (JoinType.Inner, JoinType.Left, JoinType.Anti, JoinType.Semi)),
+            ]:
+                if df._broadcast_hint:
+                    if join_type not in broadcastable_joins:
+                        raise ValueError(f"Cannot broadcast the {side} side of a {how} join")
+                    join_strategy = JoinStrategy.Broadcast
 
         if join_strategy == JoinStrategy.SortMerge and join_type != JoinType.Inner:
             raise ValueError("Sort merge join only supports inner joins")
@@ -2107,6 +2785,161 @@ class DataFrame:
         )
         return DataFrame(builder)
 
+    @DataframePublicAPI
+    def join_asof(
+        self,
+        other: "DataFrame",
+        on: Optional[ColumnInputType] = None,
+        left_on: Optional[ColumnInputType] = None,
+        right_on: Optional[ColumnInputType] = None,
+        by: Optional[Union[List[ColumnInputType], ColumnInputType]] = None,
+        left_by: Optional[Union[List[ColumnInputType], ColumnInputType]] = None,
+        right_by: Optional[Union[List[ColumnInputType], ColumnInputType]] = None,
+        strategy: Literal["backward", "forward", "nearest"] = "backward",
+        tolerance: Optional[Any] = None,
+        prefix: Optional[str] = None,
+        suffix: Optional[str] = None,
+    ) -> "DataFrame":
+        """As-of join of the current DataFrame with an ``other`` DataFrame, matching each row with the row of ``other`` whose key is nearest to it.
+
+        Every row of the current DataFrame is kept and matched with at most one row of ``other``, like a left join. With the
+        "backward" strategy, a row is matched with the last row of ``other`` whose key is less than or equal to its own; with
+        "forward", the first row whose key is greater than or equal to it; and with "nearest", whichever of the two is closer,
+        preferring the backward match on ties. Rows with a null key are never matched.
+
+        Keys that are columns with the same name on both sides are merged, and other conflicting right columns are renamed as
+        in [DataFrame.join()][daft.DataFrame.join].
+
+        Args:
+            other (DataFrame): the right DataFrame to join with.
+            on (Optional[ColumnInputType], optional): ordered key to match on, if it's the same on both sides. Usually a
+                timestamp. Defaults to None.
+            left_on (Optional[ColumnInputType], optional): ordered key of the left DataFrame. Defaults to None.
+            right_on (Optional[ColumnInputType], optional): ordered key of the right DataFrame. Defaults to None.
+            by (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): keys that must be equal between matched
+                rows, if they're the same on both sides. Defaults to None.
+            left_by (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): equality keys of the left DataFrame.
+                Defaults to None.
+            right_by (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): equality keys of the right
+                DataFrame. Defaults to None.
+            strategy (str, optional): which direction to look for a match in; "backward", "forward", or "nearest". Defaults
+                to "backward".
+            tolerance (Optional[Any], optional): the largest distance between matched keys, such as a number or a
+                ``datetime.timedelta``. Rows further than it from their nearest match aren't matched. Defaults to None.
+            prefix (Optional[str], optional): Prefix to add to the column names in case of a name collision. Defaults to "right.".
+            suffix (Optional[str], optional): Suffix to add to the column names in case of a name collision. Defaults to "".
+
+        Returns:
+            DataFrame: Joined DataFrame.
+
+        Raises:
+            ValueError: if `on` is passed in and `left_on` or `right_on` is not None, or the same for `by`.
+            ValueError: if `on` is None but both `left_on` and `right_on` are not defined.
+
+        Note:
+            As-of joins are currently only supported on the native runner.
+
+        Examples:
+            >>> import daft
+            >>> trades = daft.from_pydict({"ts": [1, 5, 10], "sym": ["a", "a", "b"]})
+            >>> quotes = daft.from_pydict({"ts": [0, 4, 8], "sym": ["a", "a", "b"], "price": [10, 11, 20]})
+            >>> trades.join_asof(quotes, on="ts", by="sym").sort("ts").show()
+            ╭───────┬──────┬───────╮
+            │ ts    ┆ sym  ┆ price │
+            │ ---   ┆ ---  ┆ ---   │
+            │ Int64 ┆ Utf8 ┆ Int64 │
+            ╞═══════╪══════╪═══════╡
+            │ 1     ┆ a    ┆ 10    │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+            │ 5     ┆ a    ┆ 11    │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+            │ 10    ┆ b    ┆ 20    │
+            ╰───────┴──────┴───────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+        """
+        if on is None:
+            if left_on is None or right_on is None:
+                raise ValueError("If `on` is None then both `left_on` and `right_on` must not be None")
+        else:
+            if left_on is not None or right_on is not None:
+                raise ValueError("If `on` is not None then both `left_on` and `right_on` must be None")
+            left_on = on
+            right_on = on
+
+        if by is None:
+            if (left_by is None) != (right_by is None):
+                raise ValueError("`left_by` and `right_by` must either both be set or both be None")
+            left_by = left_by if left_by is not None else []
+            right_by = right_by if right_by is not None else []
+        else:
+            if left_by is not None or right_by is not None:
+                raise ValueError("If `by` is not None then both `left_by` and `right_by` must be None")
+            left_by = by
+            right_by = by
+
+        (left_on_expr,) = self.__column_input_to_expression((left_on,))
+        (right_on_expr,) = self.__column_input_to_expression((right_on,))
+        left_by_exprs = self.__column_input_to_expression(tuple(left_by) if isinstance(left_by, list) else (left_by,))
+        right_by_exprs = self.__column_input_to_expression(
+            tuple(right_by) if isinstance(right_by, list) else (right_by,)
+        )
+        if tolerance is not None and not isinstance(tolerance, Expression):
+            tolerance = lit(tolerance)
+        builder = self._builder.asof_join(
+            other._builder,
+            left_on=left_on_expr,
+            right_on=right_on_expr,
+            left_by=left_by_exprs,
+            right_by=right_by_exprs,
+            strategy=AsofStrategy.from_asof_strategy_str(strategy),
+            tolerance=tolerance,
+            prefix=prefix,
+            suffix=suffix,
+        )
+        return DataFrame(builder)
+
+    @DataframePublicAPI
+    def broadcast(self) -> "DataFrame":
+        """Hints that this DataFrame is small enough to be sent in full to every partition of the DataFrame it's joined with.
+
+        A join with a hinted DataFrame uses a broadcast join, which avoids shuffling the other, larger DataFrame. Without a
+        hint, DataFrames are broadcast when their estimated size is below the `broadcast_join_size_bytes_threshold` of the
+        execution config. The hint only applies to the DataFrame returned here, so it should be passed straight to
+        [DataFrame.join()][daft.DataFrame.join], and is ignored if the join is given an explicit `strategy`.
+
+        Note:
+            For inner joins, where either side can be broadcast, the smaller side by estimated size is broadcast.
+            Broadcasting only changes how joins are executed on the Ray runner; the native runner doesn't shuffle joins.
+
+        Returns:
+            DataFrame: This DataFrame, hinted to be broadcast.
+
+        Examples:
+            >>> import daft
+            >>> large = daft.from_pydict({"id": [1, 2, 3, 4], "value": ["a", "b", "c", "d"]})
+            >>> small = daft.from_pydict({"id": [2, 4], "label": ["even", "even"]})
+            >>> large.join(small.broadcast(), on="id", how="left").sort("id").show()
+            ╭───────┬───────┬───────╮
+            │ id    ┆ value ┆ label │
+            │ ---   ┆ ---   ┆ ---   │
+            │ Int64 ┆ Utf8  ┆ Utf8  │
+            ╞═══════╪═══════╪═══════╡
+            │ 1     ┆ a     ┆ None  │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+            │ 2     ┆ b     ┆ even  │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+            │ 3     ┆ c     ┆ None  │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+            │ 4     ┆ d     ┆ even  │
+            ╰───────┴───────┴───────╯
+            <BLANKLINE>
+            (Showing first 4 of 4 rows)
+        """
+        df = DataFrame(self._builder)
+        df._broadcast_hint = True
+        return df
+
     @DataframePublicAPI
     def concat(self, other: "DataFrame") -> "DataFrame":
         """Concatenates two DataFrames together in a "vertical" concatenation.
@@ -2242,15 +3075,19 @@ class DataFrame:
         return self.where(~reduce(lambda x, y: x | y, (x.is_null() for x in columns)))
 
     @DataframePublicAPI
-    def explode(self, *columns: ColumnInputType) -> "DataFrame":
+    def explode(self, *columns: ColumnInputType, unnest_structs: bool = False) -> "DataFrame":
         """Explodes a List column, where every element in each row's List becomes its own row, and all other columns in the DataFrame are duplicated across rows.
 
         If multiple columns are specified, each row must contain the same number of items in each specified column.
 
         Exploding Null values or empty lists will create a single Null entry (see example below).
 
+        With ``unnest_structs``, exploded columns of structs are replaced by a column for each of their fields,
+        so a ``List[Struct]`` column can be flattened in a single pass.
+
         Args:
             *columns (ColumnInputType): columns to explode
+            unnest_structs (bool): whether to replace exploded struct columns by their fields. Defaults to False.
 
         Returns:
             DataFrame: DataFrame with exploded column
@@ -2284,7 +3121,7 @@ class DataFrame:
 
         """
         parsed_exprs = self.__column_input_to_expression(columns)
-        builder = self._builder.explode(parsed_exprs)
+        builder = self._builder.explode(parsed_exprs, unnest_structs)
         return DataFrame(builder)
 
     @DataframePublicAPI
@@ -2414,8 +3251,13 @@ class DataFrame:
         self,
         to_agg: Iterable[Expression],
         group_by: Optional[ExpressionsProjection] = None,
+        grouping_sets: Optional[List[List[int]]] = None,
     ) -> "DataFrame":
-        builder = self._builder.agg(list(to_agg), list(group_by) if group_by is not None else None)
+        if grouping_sets is not None:
+            assert group_by is not None, "grouping sets require group by expressions"
+            builder = self._builder.agg_grouping_sets(list(to_agg), list(group_by), grouping_sets)
+        else:
+            builder = self._builder.agg(list(to_agg), list(group_by) if group_by is not None else None)
         return DataFrame(builder)
 
     def _map_agg_string_to_expr(self, expr: Expression, op: str) -> Expression:
@@ -2445,6 +3287,7 @@ class DataFrame:
         fn: Callable[[Expression], Expression],
         cols: Tuple[ManyColumnsInputType, ...],
         group_by: Optional[ExpressionsProjection] = None,
+        grouping_sets: Optional[List[List[int]]] = None,
     ) -> "DataFrame":
         if len(cols) == 0:
             warnings.warn("No columns specified; performing aggregation on all columns.")
@@ -2452,7 +3295,7 @@ class DataFrame:
             groupby_name_set = set() if group_by is None else group_by.to_name_set()
             cols = tuple(c for c in self.column_names if c not in groupby_name_set)
         exprs = self._wildcard_inputs_to_expressions(cols)
-        return self._agg([fn(c) for c in exprs], group_by)
+        return self._agg([fn(c) for c in exprs], group_by, grouping_sets)
 
     def _map_groups(self, udf: Expression, group_by: Optional[ExpressionsProjection] = None) -> "DataFrame":
         builder = self._builder.map_groups(udf, list(group_by) if group_by is not None else None)
@@ -2529,6 +3372,18 @@ class DataFrame:
         """
         return self._apply_agg_fn(Expression.max, cols)
 
+    @DataframePublicAPI
+    def approx_quantiles(self, quantiles: List[float], *cols: ColumnInputType) -> "DataFrame":
+        """Performs a global approximate quantiles aggregation on the DataFrame.
+
+        Args:
+            quantiles (List[float]): quantiles, between 0 and 1, at which to find approximate values
+            *cols (Union[str, Expression]): columns to find approximate quantiles of
+        Returns:
+            DataFrame: Globally aggregated ``FixedSizeList[Float64; len(quantiles)]`` quantiles. Should be a single row.
+        """
+        return self._apply_agg_fn(lambda c: c.approx_quantiles(quantiles), cols)
+
     @DataframePublicAPI
     def any_value(self, *cols: ColumnInputType) -> "DataFrame":
         """Returns an arbitrary value on this DataFrame.
@@ -2735,6 +3590,134 @@ class DataFrame:
         """
         return GroupedDataFrame(self, ExpressionsProjection(self._wildcard_inputs_to_expressions(group_by)))
 
+    @DataframePublicAPI
+    def rollup(self, *cols: ManyColumnsInputType) -> "GroupedDataFrame":
+        """Groups the DataFrame by every prefix of the given columns, from all of them down to none of them.
+
+        Aggregating the result gives a row for each group of each prefix. Columns that aren't in a row's prefix
+        are null, and a ``grouping_id`` column identifies the prefix of each row: it has a bit for each column
+        that is set when the row isn't grouped by it, the first column being the most significant bit.
+
+        Args:
+            *cols (Union[str, Expression]): columns to roll up
+
+        Returns:
+            GroupedDataFrame: DataFrame to Aggregate
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"dept": ["IT", "IT", "HR"], "year": [2022, 2023, 2022], "cost": [100, 200, 300]})
+            >>> df.rollup("dept").sum("cost").sort(["grouping_id", "dept"]).to_pydict()
+            {'dept': ['HR', 'IT', None], 'cost': [300, 300, 600], 'grouping_id': [0, 0, 1]}
+
+        """
+        exprs = self._wildcard_inputs_to_expressions(cols)
+        return GroupedDataFrame(self, ExpressionsProjection(exprs), [list(range(n)) for n in range(len(exprs), -1, -1)])
+
+    @DataframePublicAPI
+    def cube(self, *cols: ManyColumnsInputType) -> "GroupedDataFrame":
+        """Groups the DataFrame by every subset of the given columns.
+
+        Aggregating the result gives a row for each group of each subset, with a ``grouping_id`` column
+        identifying the subset as in :meth:`DataFrame.rollup`.
+
+        Args:
+            *cols (Union[str, Expression]): columns to group by
+
+        Returns:
+            GroupedDataFrame: DataFrame to Aggregate
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"dept": ["IT", "IT", "HR"], "year": [2022, 2023, 2022], "cost": [100, 200, 300]})
+            >>> df.cube("dept", "year").sum("cost").where(daft.col("grouping_id") == 2).sort("year").to_pydict()
+            {'dept': [None, None], 'year': [2022, 2023], 'cost': [400, 200], 'grouping_id': [2, 2]}
+
+        """
+        exprs = self._wildcard_inputs_to_expressions(cols)
+        n = len(exprs)
+        sets = [[i for i in range(n) if not dropped & (1 << (n - 1 - i))] for dropped in range(1 << n)]
+        return GroupedDataFrame(self, ExpressionsProjection(exprs), sets)
+
+    @DataframePublicAPI
+    def grouping_sets(self, *sets: ManyColumnsInputType) -> "GroupedDataFrame":
+        """Groups the DataFrame by each of the given sets of columns.
+
+        Aggregating the result gives a row for each group of each set. Columns that aren't in a row's set are null,
+        and a ``grouping_id`` column identifies the set as in :meth:`DataFrame.rollup`, with the columns ordered
+        by their first appearance in the sets.
+
+        Args:
+            *sets (Union[str, Expression, List[Union[str, Expression]]]): sets of columns to group by, where an
+                empty list aggregates over the whole DataFrame
+
+        Returns:
+            GroupedDataFrame: DataFrame to Aggregate
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"dept": ["IT", "IT", "HR"], "year": [2022, 2023, 2022], "cost": [100, 200, 300]})
+            >>> df.grouping_sets("dept", []).sum("cost").sort(["grouping_id", "dept"]).to_pydict()
+            {'dept': ['HR', 'IT', None], 'cost': [300, 300, 600], 'grouping_id': [0, 0, 1]}
+
+        """
+        if len(sets) == 0:
+            raise ValueError("grouping_sets requires at least one set of columns")
+        keys: List[Expression] = []
+        names: List[str] = []
+        index_sets = []
+        for columns in sets:
+            exprs = column_inputs_to_expressions([columns] if isinstance(columns, (str, Expression)) else columns)
+            index_set = []
+            for e in exprs:
+                if e.name() not in names:
+                    names.append(e.name())
+                    keys.append(e)
+                index_set.append(names.index(e.name()))
+            index_sets.append(index_set)
+        return GroupedDataFrame(self, ExpressionsProjection(keys), index_sets)
+
+    @DataframePublicAPI
+    def value_counts(self, *cols: ManyColumnsInputType) -> "DataFrame":
+        """Counts the occurrences of each distinct combination of values of the given columns.
+
+        Rows with a null in any of the columns are not counted. This is a shorthand for grouping by the columns
+        and counting the rows of each group.
+
+        Args:
+            *cols (Union[str, Expression]): columns whose values to count
+
+        Returns:
+            DataFrame: DataFrame with the distinct values of the columns and their counts in a ``count`` column,
+                sorted by descending count.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"pet": ["cat", "dog", "dog", None, "dog", "cat", "bird"]})
+            >>> df.value_counts("pet").show()
+            ╭──────┬────────╮
+            │ pet  ┆ count  │
+            │ ---  ┆ ---    │
+            │ Utf8 ┆ UInt64 │
+            ╞══════╪════════╡
+            │ dog  ┆ 3      │
+            ├╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+            │ cat  ┆ 2      │
+            ├╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+            │ bird ┆ 1      │
+            ╰──────┴────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        """
+        exprs = self._wildcard_inputs_to_expressions(cols)
+        if not exprs:
+            raise ValueError("value_counts requires at least one column")
+        names = [e.name() for e in exprs]
+        not_null = reduce(lambda l, r: l & r, [col(name).not_null() for name in names])
+        counts = self.select(*exprs).where(not_null).groupby(*names).agg(col(names[0]).count().alias("count"))
+        return counts.sort("count", desc=True)
+
     @DataframePublicAPI
     def pivot(
         self,
@@ -2759,7 +3742,7 @@ class DataFrame:
         Note:
             You may wish to provide a list of distinct values to pivot on, which is more efficient as it avoids
             a distinct operation. Without this list, Daft will perform a distinct operation on the pivot column to
-            determine the unique values to pivot on.
+            determine the unique values to pivot on, and the pivoted columns will be in sorted order of those values.
 
         Examples:
             >>> import daft
@@ -2793,7 +3776,9 @@ class DataFrame:
         agg_expr = self._map_agg_string_to_expr(value_col_expr, agg_fn)
 
         if names is None:
-            names = self.select(pivot_col_expr).distinct().to_pydict()[pivot_col_expr.name()]
+            # sort the distinct values so that the pivoted columns come in a deterministic order
+            pivot_values = self.select(pivot_col_expr).distinct().sort(pivot_col_expr.name())
+            names = pivot_values.to_pydict()[pivot_col_expr.name()]
             names = [str(x) for x in names]
         builder = self._builder.pivot(group_by_expr, pivot_col_expr, value_col_expr, agg_expr, names)
         return DataFrame(builder)
@@ -3078,8 +4063,163 @@ class DataFrame:
             self._num_preview_rows = dataframe_len
         return self
 
-    def _construct_show_preview(self, n: int) -> "Preview":
+    @DataframePublicAPI
+    def persist(self) -> "DataFrame":
+        """Executes the DataFrame and retains its results in the session until [DataFrame.unpersist()](https://www.getdaft.io/projects/docs/en/stable/api/dataframe/#daft.DataFrame.unpersist) is called.
+
+        Queries on this DataFrame and on DataFrames derived from it scan the persisted partitions instead of re-executing
+        its lineage, which shows up in [DataFrame.explain()](https://www.getdaft.io/projects/docs/en/stable/api/dataframe/#daft.DataFrame.explain) as a cache hit. On the native and Python runners, partitions
+        that don't fit under the `persist_memory_limit_bytes` execution config are spilled to disk and read back when they
+        are scanned.
+
+        Returns:
+            DataFrame: DataFrame with persisted results.
+
+        Note:
+            This call is **blocking** and will execute the DataFrame when called. If several DataFrames persist the same
+            results, e.g. through the plan cache, the results are retained until each of them has unpersisted them.
+        """
+        if self._persisted:
+            return self
+        self._materialize_results()
+        assert self._result_cache is not None
+        get_context().get_or_create_runner().persist(self._result_cache)
+        self._persisted = True
+        return self
+
+    @DataframePublicAPI
+    def unpersist(self) -> "DataFrame":
+        """Releases the results retained by [DataFrame.persist()](https://www.getdaft.io/projects/docs/en/stable/api/dataframe/#daft.DataFrame.persist), so that this DataFrame is re-executed when it's next used.
+
+        DataFrames that were derived from this one while it was persisted keep scanning its results, which are freed once
+        none of them reference the results anymore.
+
+        Returns:
+            DataFrame: DataFrame without materialized results.
+        """
+        if not self._persisted:
+            return self
+        assert self._result_cache is not None
+        get_context().get_or_create_runner().unpersist(self._result_cache)
+        self._persisted = False
+        self._result_cache = None
+        self._preview = Preview(partition=None, total_rows=None)
+        return self
+
+    @DataframePublicAPI
+    def iterate(
+        self,
+        step: Callable[["DataFrame"], "DataFrame"],
+        until: Optional[Callable[["DataFrame", "DataFrame"], bool]] = None,
+        max_iterations: int = 100,
+        invariants: Optional[List["DataFrame"]] = None,
+    ) -> "DataFrame":
+        """Repeatedly applies a step function to this DataFrame until it converges, e.g. for connected components or label propagation.
+
+        The result of each iteration is persisted and becomes the input of the next one, so every iteration only plans and
+        executes a single step, rather than an ever-growing plan of all the steps so far. The result of the previous
+        iteration is released as soon as the next one is materialized.
+
+        Args:
+            step (Callable[[DataFrame], DataFrame]): Function that computes the next iteration from the current one. Its
+                result must have the same schema as this DataFrame.
+            until (Optional[Callable[[DataFrame, DataFrame], bool]]): Function of the previous and the next iteration that
+                returns whether the iteration has converged. Defaults to None, which stops once an iteration no longer
+                changes the DataFrame, i.e. it reached a fixed point.
+            max_iterations (int): Maximum number of iterations to run, regardless of convergence. Defaults to 100.
+            invariants (Optional[List[DataFrame]]): DataFrames used by `step` that don't change across iterations, such as
+                the edges of a graph. These are persisted once before the first iteration instead of being re-executed by
+                every iteration, and unpersisted afterwards.
+
+        Returns:
+            DataFrame: DataFrame with the materialized result of the last iteration.
+
+        Note:
+            This call is **blocking** and will execute every iteration when called.
+
+        Examples:
+            >>> import daft
+            >>> from daft import col
+            >>> edges = daft.from_pydict({"src": [1, 2, 2, 3], "dst": [2, 1, 3, 2]})
+            >>> labels = daft.from_pydict({"node": [1, 2, 3], "label": [1, 2, 3]})
+            >>> def propagate(labels):
+            ...     neighbors = edges.join(labels.with_column_renamed("node", "src"), on="src")
+            ...     neighbors = neighbors.select(col("dst").alias("node"), col("label"))
+            ...     return labels.concat(neighbors).groupby("node").agg(col("label").min())
+            >>> labels.iterate(propagate, invariants=[edges]).sort("node").show()
+            ╭───────┬───────╮
+            │ node  ┆ label │
+            │ ---   ┆ ---   │
+            │ Int64 ┆ Int64 │
+            ╞═══════╪═══════╡
+            │ 1     ┆ 1     │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+            │ 2     ┆ 1     │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+            │ 3     ┆ 1     │
+            ╰───────┴───────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        """
+        if max_iterations < 1:
+            raise ValueError(f"max_iterations must be at least 1, but got: {max_iterations}")
+
+        def is_fixed_point(prev: DataFrame, next_df: DataFrame) -> bool:
+            return prev.except_all(next_df).count_rows() == 0 and next_df.except_all(prev).count_rows() == 0
+
+        converged = until if until is not None else is_fixed_point
+        # Invariants that are already materialized don't need to be hoisted out of the loop.
+        hoisted = [df for df in invariants or [] if df._result_cache is None]
+        for df in hoisted:
+            df.persist()
+        current = DataFrame(self._builder).persist()
+        try:
+            for _ in range(max_iterations):
+                next_df = step(current)
+                if next_df is current:
+                    break
+                if next_df.schema() != current.schema():
+                    raise ValueError(
+                        f"The step function of iterate must preserve the schema of the DataFrame, expected:\n{current.schema()}\nbut got:\n{next_df.schema()}"
+                    )
+                next_df = DataFrame(next_df._builder).persist()
+                done = converged(current, next_df)
+                current.unpersist()
+                current = next_df
+                if done:
+                    break
+        except BaseException:
+            current.unpersist()
+            raise
+        finally:
+            for df in hoisted:
+                df.unpersist()
+        # Hand back the last iteration as a regular materialized DataFrame, which is freed once it's no longer used.
+        assert current._result_cache is not None
+        get_context().get_or_create_runner().unpersist(current._result_cache)
+        current._persisted = False
+        return current
+
+    def _construct_show_preview(self, n: int, offset: int = 0) -> "Preview":
         """Helper for .show() which will construct the underlying Preview object."""
+        if offset > 0:
+            # Pages of materialized DataFrames are sliced from their results, and pages of unmaterialized ones from a
+            # preview of the rows up to the end of the page.
+            if self._result is not None:
+                partition = self._preview_micropartition(offset + n)
+                total_rows: Optional[int] = len(self)
+            else:
+                preview = self._construct_show_preview(offset + n)
+                assert preview.partition is not None
+                partition, total_rows = preview.partition, preview.total_rows
+            start = min(offset, len(partition))
+            return Preview(
+                partition=partition.slice(start, min(offset + n, len(partition))),
+                total_rows=total_rows,
+                offset=start,
+            )
+
         preview_partition = self._preview.partition
         total_rows = self._preview.total_rows
 
@@ -3135,8 +4275,9 @@ class DataFrame:
         max_width: int = 30,
         align: PreviewAlign = "left",
         columns: Optional[List[PreviewColumn]] = None,
+        offset: int = 0,
     ) -> None:
-        """Executes enough of the DataFrame in order to display the first ``n`` rows.
+        """Executes enough of the DataFrame in order to display the first ``n`` rows, or ``n`` rows after ``offset``.
 
         If IPython is installed, this will use IPython's `display` utility to pretty-print in a
         notebook/REPL environment. Otherwise, this will fall back onto a naive Python `print`.
@@ -3150,6 +4291,8 @@ class DataFrame:
         Args:
             n: number of rows to show. Defaults to 8.
             format (PreviewFormat): the box-drawing format e.g. "fancy" or "markdown".
+            offset: number of rows to skip before the rows that are shown, which pages through the DataFrame. Pages of
+                collected DataFrames are sliced from their results without re-executing them. Defaults to 0.
             **options: keyword arguments to modify the formatting, please see the options section.
 
         Options:
@@ -3168,6 +4311,7 @@ class DataFrame:
             >>> df.show(format="markdown")  # doctest: +SKIP
             >>> df.show(max_width=50)  # doctest: +SKIP
             >>> df.show(align="left")  # doctest: +SKIP
+            >>> df.show(2, offset=2)  # doctest: +SKIP
 
         Tip: Usage
             - If columns are given, their length MUST match the schema.
@@ -3175,7 +4319,9 @@ class DataFrame:
 
         """
         schema = self.schema()
-        preview = self._construct_show_preview(n)
+        if offset < 0:
+            raise ValueError(f"'offset' must be >= 0, received: {offset}")
+        preview = self._construct_show_preview(n, offset)
         preview_formatter = PreviewFormatter(
             preview,
             schema,
@@ -3275,6 +4421,92 @@ class DataFrame:
         arrow_rb_iter = self.to_arrow_iter(results_buffer_size=None)
         return pa.Table.from_batches(arrow_rb_iter, schema=self.schema().to_pyarrow_schema())
 
+    @DataframePublicAPI
+    def to_arrow_reader(
+        self,
+        results_buffer_size: Union[Optional[int], Literal["num_cpus"]] = "num_cpus",
+    ) -> "pyarrow.RecordBatchReader":
+        """Converts the current DataFrame to a [pyarrow RecordBatchReader](https://arrow.apache.org/docs/python/generated/pyarrow.RecordBatchReader.html), which is an Arrow C stream.
+
+        The DataFrame is executed in a streaming fashion as the consumer reads batches from the reader, so consumers of Arrow
+        C streams such as DuckDB and ADBC can scan results that are larger than memory without a copy.
+
+        Args:
+            results_buffer_size: how many partitions to allow in the results buffer (defaults to the total number of CPUs
+                available on the machine). See [`df.iter_partitions()`][daft.DataFrame.iter_partitions].
+
+        Returns:
+            pyarrow.RecordBatchReader: a reader of the batches of the DataFrame, which can only be read once.
+
+        Examples:
+            >>> import daft
+            >>>
+            >>> df = daft.from_pydict({"foo": [1, 2, 3], "bar": ["a", "b", "c"]})
+            >>> reader = df.to_arrow_reader()
+            >>> reader.read_all().num_rows
+            3
+
+            DuckDB scans readers in the scope of a query by their variable name:
+
+            >>> import duckdb  # doctest: +SKIP
+            >>> duckdb.sql("SELECT sum(foo) FROM reader").fetchall()  # doctest: +SKIP
+            [(6,)]
+        """
+        import pyarrow as pa
+
+        self._check_arrow_convertible()
+        return pa.RecordBatchReader.from_batches(
+            self.schema().to_pyarrow_schema(), self.to_arrow_iter(results_buffer_size=results_buffer_size)
+        )
+
+    @DataframePublicAPI
+    def to_polars(self) -> "polars.DataFrame":
+        """Converts the current DataFrame to a [polars DataFrame](https://docs.pola.rs/api/python/stable/reference/dataframe/index.html).
+
+        Buffers are handed over through the Arrow C data interface, so columns of types that polars supports natively are
+        not copied. Daft's extension types have no polars equivalent and are converted to their Arrow storage instead:
+
+        - Embedding, FixedShapeTensor and FixedShapeImage columns become `polars.Array` columns of their flattened values.
+        - Image columns become `polars.Struct` columns with the fields `data` (flattened pixels), `channel`, `height`,
+          `width` and `mode`.
+        - Tensor columns become `polars.Struct` columns with the fields `data` (flattened values) and `shape`.
+
+        Such columns can be converted back with a cast after [daft.from_polars](#daft.from_polars).
+
+        If results have not computed yet, collect will be called.
+
+        Returns:
+            polars.DataFrame: polars DataFrame converted from a Daft DataFrame
+
+        Note:
+            This call is **blocking** and will execute the DataFrame when called
+        """
+        import polars as pl
+        import pyarrow as pa
+
+        table = self.to_arrow()
+        columns = [
+            pa.chunked_array([chunk.storage for chunk in column.chunks], type=column.type.storage_type)
+            if isinstance(column.type, pa.ExtensionType)
+            else column
+            for column in table.columns
+        ]
+        return pl.from_arrow(pa.table(columns, names=table.column_names), rechunk=False)
+
+    def __arrow_c_stream__(self, requested_schema: Optional[object] = None) -> object:
+        """Exports the DataFrame through the Arrow PyCapsule interface, so that e.g. ``pyarrow.table(df)`` or ``polars.DataFrame(df)`` work without a conversion to pyarrow first.
+
+        If results have not computed yet, the DataFrame is executed in a streaming fashion as the consumer pulls batches, as
+        with [`df.to_arrow_reader()`][daft.DataFrame.to_arrow_reader], and isn't collected. The ``requested_schema`` is
+        ignored, which the interface allows.
+        """
+        self._check_arrow_convertible()
+        result = self._result
+        if result is None:
+            return self.to_arrow_reader().__arrow_c_stream__()
+        parts = [partition.micropartition() for _, partition in result.items()]
+        return MicroPartition.to_arrow_c_stream(parts, self.schema())
+
     @DataframePublicAPI
     def to_pydict(self) -> Dict[str, List[Any]]:
         """Converts the current DataFrame to a python dictionary. The dictionary contains Python lists of Python objects for each column.
@@ -3335,17 +4567,24 @@ class DataFrame:
         return DaftTorchDataset(self.to_pydict(), len(self))
 
     @DataframePublicAPI
-    def to_torch_iter_dataset(self) -> "torch.utils.data.IterableDataset":
+    def to_torch_iter_dataset(
+        self, num_workers_aware: bool = True, prefetch: Optional[int] = None
+    ) -> "torch.utils.data.IterableDataset":
         """Convert the current DataFrame into a `Torch IterableDataset <https://pytorch.org/docs/stable/data.html#torch.utils.data.IterableDataset>`__ for use with PyTorch.
 
-        Begins execution of the DataFrame if it is not yet executed.
+        Begins execution of the DataFrame if it is not yet executed, and streams its rows as its partitions finish without
+        materializing the entire DataFrame.
 
         Items will be returned in pydict format: a dict of `{"column name": value}` for each row in the data.
 
-        Note:
-            The produced dataset is meant to be used with the single-process DataLoader,
-            and does not support data sharding hooks for multi-process data loading.
+        Args:
+            num_workers_aware: whether to split the partitions of the DataFrame round-robin across the workers of a
+                multi-process DataLoader and the ranks of a `torch.distributed` process group, so that each row is
+                yielded by exactly one of them. Each of them executes the DataFrame, and keeps only its own partitions.
+            prefetch: how many partitions to compute ahead of the training loop in a background thread, which overlaps
+                Daft's execution with training. Defaults to None, which computes partitions as they are consumed.
 
+        Note:
             Do keep in mind that Daft is already using multithreading or multiprocessing under the hood
             to compute the data stream that feeds this dataset.
 
@@ -3353,9 +4592,12 @@ class DataFrame:
             This method returns results locally.
             For distributed training, you may want to use [DataFrame.to_ray_dataset()][daft.DataFrame.to_ray_dataset].
         """
+        if prefetch is not None and prefetch <= 0:
+            raise ValueError(f"Provided `prefetch` value must be > 0, received: {prefetch}")
+
         from daft.dataframe.to_torch import DaftTorchIterableDataset
 
-        return DaftTorchIterableDataset(self)
+        return DaftTorchIterableDataset(self, num_workers_aware=num_workers_aware, prefetch=prefetch)
 
     @DataframePublicAPI
     def to_ray_dataset(self) -> "ray.data.dataset.DataSet":
@@ -3530,6 +4772,7 @@ class DataFrame:
 class GroupedDataFrame:
     df: DataFrame
     group_by: ExpressionsProjection
+    grouping_sets: Optional[List[List[int]]] = None
 
     def __post_init__(self):
         resolved_groupby_schema = self.group_by.resolve_schema(self.df._builder.schema())
@@ -3550,7 +4793,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped sums.
         """
-        return self.df._apply_agg_fn(Expression.sum, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.sum, cols, self.group_by, self.grouping_sets)
 
     def mean(self, *cols: ColumnInputType) -> "DataFrame":
         """Performs grouped mean on this GroupedDataFrame.
@@ -3561,7 +4804,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped mean.
         """
-        return self.df._apply_agg_fn(Expression.mean, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.mean, cols, self.group_by, self.grouping_sets)
 
     def stddev(self, *cols: ColumnInputType) -> "DataFrame":
         """Performs grouped standard deviation on this GroupedDataFrame.
@@ -3591,7 +4834,7 @@ class GroupedDataFrame:
             (Showing first 2 of 2 rows)
 
         """
-        return self.df._apply_agg_fn(Expression.stddev, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.stddev, cols, self.group_by, self.grouping_sets)
 
     def min(self, *cols: ColumnInputType) -> "DataFrame":
         """Perform grouped min on this GroupedDataFrame.
@@ -3602,7 +4845,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped min.
         """
-        return self.df._apply_agg_fn(Expression.min, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.min, cols, self.group_by, self.grouping_sets)
 
     def max(self, *cols: ColumnInputType) -> "DataFrame":
         """Performs grouped max on this GroupedDataFrame.
@@ -3613,7 +4856,19 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped max.
         """
-        return self.df._apply_agg_fn(Expression.max, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.max, cols, self.group_by, self.grouping_sets)
+
+    def approx_quantiles(self, quantiles: List[float], *cols: ColumnInputType) -> "DataFrame":
+        """Performs grouped approximate quantiles on this GroupedDataFrame.
+
+        Args:
+            quantiles (List[float]): quantiles, between 0 and 1, at which to find approximate values
+            *cols (Union[str, Expression]): columns to find approximate quantiles of
+
+        Returns:
+            DataFrame: DataFrame with grouped ``FixedSizeList[Float64; len(quantiles)]`` quantiles.
+        """
+        return self.df._apply_agg_fn(lambda c: c.approx_quantiles(quantiles), cols, self.group_by, self.grouping_sets)
 
     def any_value(self, *cols: ColumnInputType) -> "DataFrame":
         """Returns an arbitrary value on this GroupedDataFrame.
@@ -3626,7 +4881,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with any values.
         """
-        return self.df._apply_agg_fn(Expression.any_value, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.any_value, cols, self.group_by, self.grouping_sets)
 
     def count(self, *cols: ColumnInputType) -> "DataFrame":
         """Performs grouped count on this GroupedDataFrame.
@@ -3634,7 +4889,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped count per column.
         """
-        return self.df._apply_agg_fn(Expression.count, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.count, cols, self.group_by, self.grouping_sets)
 
     def agg_list(self, *cols: ColumnInputType) -> "DataFrame":
         """Performs grouped list on this GroupedDataFrame.
@@ -3642,7 +4897,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped list per column.
         """
-        return self.df._apply_agg_fn(Expression.agg_list, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.agg_list, cols, self.group_by, self.grouping_sets)
 
     def agg_set(self, *cols: ColumnInputType) -> "DataFrame":
         """Performs grouped set on this GroupedDataFrame (ignoring nulls).
@@ -3653,7 +4908,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped set per column.
         """
-        return self.df._apply_agg_fn(Expression.agg_set, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.agg_set, cols, self.group_by, self.grouping_sets)
 
     def agg_concat(self, *cols: ColumnInputType) -> "DataFrame":
         """Performs grouped concat on this GroupedDataFrame.
@@ -3661,7 +4916,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped concatenated list per column.
         """
-        return self.df._apply_agg_fn(Expression.agg_concat, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.agg_concat, cols, self.group_by, self.grouping_sets)
 
     def agg(self, *to_agg: Union[Expression, Iterable[Expression]]) -> "DataFrame":
         """Perform aggregations on this GroupedDataFrame. Allows for mixed aggregations.
@@ -3713,7 +4968,7 @@ class GroupedDataFrame:
             if not isinstance(expr, Expression):
                 raise ValueError(f"GroupedDataFrame.agg() only accepts expression type, received: {type(expr)}")
 
-        return self.df._agg(to_agg_list, group_by=self.group_by)
+        return self.df._agg(to_agg_list, group_by=self.group_by, grouping_sets=self.grouping_sets)
 
     def map_groups(self, udf: Expression) -> "DataFrame":
         """Apply a user-defined function to each group. The name of the resultant column will default to the name of the first input column.
@@ -3749,4 +5004,6 @@ class GroupedDataFrame:
             (Showing first 2 of 2 rows)
 
         """
+        if self.grouping_sets is not None:
+            raise ValueError("map_groups is not supported with grouping sets")
         return self.df._map_groups(udf, group_by=self.group_by)
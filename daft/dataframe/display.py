@@ -1,8 +1,98 @@
-from typing import Optional
+from __future__ import annotations
+
+import contextlib
+import dataclasses
+import json
+from typing import Any, Iterator, Optional
 
 from daft.context import get_context
 
 
+@dataclasses.dataclass(frozen=True)
+class DisplayOptions:
+    """Options of the previews of DataFrames in reprs, notebooks and [DataFrame.show()][daft.DataFrame.show].
+
+    Attributes:
+        max_rows: the number of rows that previews of materialized DataFrames keep, and that tables show, half from the
+            start and half from the end when there are more. Defaults to the execution config's `num_preview_rows` for
+            previews, and to 10 for tables.
+        max_columns: the number of columns that tables show, half from the start and half from the end when there are
+            more. Defaults to as many as fit the terminal in text, and to all of them in HTML.
+        max_width: the number of characters of the values that tables show. Defaults to 32 in text, and to no limit for
+            strings in HTML, which only truncates strings.
+        thumbnails: whether HTML tables render image and tensor values as inline thumbnails. Defaults to False.
+    """
+
+    max_rows: Optional[int] = None
+    max_columns: Optional[int] = None
+    max_width: Optional[int] = None
+    thumbnails: bool = False
+
+    def __post_init__(self) -> None:
+        for name in ("max_rows", "max_columns", "max_width"):
+            value = getattr(self, name)
+            if value is not None and value <= 0:
+                raise ValueError(f"Display option `{name}` must be > 0, received: {value}")
+
+    def serialize(self) -> str:
+        """Serializes the options for the Rust formatter, which fills in the defaults of unset options."""
+        options: dict[str, Any] = {
+            "max_columns": self.max_columns,
+            "max_width": self.max_width,
+            "thumbnails": self.thumbnails,
+        }
+        if self.max_rows is not None:
+            options["max_rows"] = self.max_rows
+        return json.dumps(options)
+
+
+_display_options = DisplayOptions()
+
+
+def get_display_options() -> DisplayOptions:
+    """Returns the display options of DataFrame previews."""
+    return _display_options
+
+
+def set_display_options(**options: Any) -> DisplayOptions:
+    """Sets the given display options of DataFrame previews, keeping the others.
+
+    Args:
+        **options: the options to set, which are the attributes of [DisplayOptions][daft.dataframe.display.DisplayOptions].
+
+    Returns:
+        DisplayOptions: the display options that were set.
+
+    Examples:
+        >>> import daft
+        >>> options = daft.set_display_options(max_columns=4, max_width=16)
+        >>> options.max_width
+        16
+        >>> _ = daft.set_display_options(max_columns=None, max_width=None)
+    """
+    global _display_options
+    _display_options = dataclasses.replace(_display_options, **options)
+    return _display_options
+
+
+@contextlib.contextmanager
+def display_options_ctx(**options: Any) -> Iterator[DisplayOptions]:
+    """Context manager that sets the given display options of DataFrame previews, and restores the previous ones on exit.
+
+    Examples:
+        >>> import daft
+        >>> df = daft.from_pydict({"text": ["a" * 100]})
+        >>> with daft.display_options_ctx(max_width=10):
+        ...     df.show()  # doctest: +SKIP
+    """
+    global _display_options
+    previous = _display_options
+    try:
+        yield set_display_options(**options)
+    finally:
+        _display_options = previous
+
+
 class AsciiOptions:
     simple: bool
 
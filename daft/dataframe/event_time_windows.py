@@ -0,0 +1,261 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from datetime import timedelta
+from typing import TYPE_CHECKING, Any, Union
+
+from daft.datatype import DataType
+from daft.expressions import Expression, col, list_, lit
+from daft.series import Series
+
+if TYPE_CHECKING:
+    from daft.dataframe import DataFrame
+
+WindowDuration = Union[int, timedelta]
+
+WINDOW_START = "window_start"
+WINDOW_END = "window_end"
+EVENT_TIME_COLUMN = "__daft_event_time"
+WINDOW_START_COLUMN = "__daft_window_start"
+WINDOW_END_COLUMN = "__daft_window_end"
+
+# The duration of a tick of each time unit that timedeltas can represent exactly.
+TICKS = {
+    "s": timedelta(seconds=1),
+    "ms": timedelta(milliseconds=1),
+    "us": timedelta(microseconds=1),
+}
+
+
+def _to_ticks(dtype: DataType, duration: WindowDuration) -> int:
+    """The number of ticks of the event times of a column in a duration."""
+    if not dtype.is_timestamp():
+        return int(duration)
+    unit = str(dtype.timeunit)
+    return duration // TICKS["us"] * 1000 if unit == "ns" else duration // TICKS[unit]
+
+
+@dataclass(frozen=True)
+class _EventTimeWindows:
+    """Assigns events to the tumbling or sliding windows of event time that they fall in.
+
+    Event times are handled as integers: the values of integer columns, or the ticks of the time unit of timestamp
+    columns since the epoch. Windows start at multiples of their slide, and cover ``[start, start + size)``.
+    """
+
+    timestamp: Expression
+    dtype: DataType
+    size: int
+    slide: int
+
+    @staticmethod
+    def create(
+        df: DataFrame, timestamp: str | Expression, size: WindowDuration, slide: WindowDuration | None
+    ) -> _EventTimeWindows:
+        timestamp = col(timestamp) if isinstance(timestamp, str) else timestamp
+        dtype = timestamp._to_field(df.schema()).dtype
+        if dtype.is_timestamp():
+            duration_type, expected = timedelta, "a timedelta"
+        elif dtype.is_integer():
+            duration_type, expected = int, "an integer"
+        else:
+            raise ValueError(f"Windows of event time require a timestamp or integer column, but got: {dtype}")
+
+        slide = size if slide is None else slide
+        for name, duration in (("size", size), ("slide", slide)):
+            if not isinstance(duration, duration_type) or isinstance(duration, bool):
+                raise TypeError(f"The window {name} of a {dtype} column must be {expected}, but got: {duration!r}")
+        size, slide = _to_ticks(dtype, size), _to_ticks(dtype, slide)
+        if size <= 0 or slide <= 0:
+            raise ValueError("The size and slide of windows must be positive")
+        if slide > size:
+            raise ValueError("The slide of windows must not be greater than their size, which would skip events")
+        return _EventTimeWindows(timestamp, dtype, size, slide)
+
+    def from_ticks(self, ticks: int) -> Any:
+        """The value of the timestamp column at a number of ticks."""
+        return Series.from_pylist([ticks]).cast(self.dtype).to_pylist()[0]
+
+    def with_event_time(self, df: DataFrame) -> DataFrame:
+        """Adds the event time of each event as ticks, dropping events without one."""
+        event_time = self.timestamp.cast(DataType.int64())
+        return df.where(self.timestamp.not_null()).with_column(EVENT_TIME_COLUMN, event_time)
+
+    def latest_start(self, event_time: Expression) -> Expression:
+        """The start of the last window that an event falls in."""
+        # Integer division and remainders round towards zero, so the remainder is made non-negative for event times
+        # before zero.
+        return event_time - (event_time % self.slide + self.slide) % self.slide
+
+    def assign(self, events: DataFrame, after: int | None) -> DataFrame:
+        """One row per event and window that it falls in, for the windows that end after ``after``."""
+        event_time = col(EVENT_TIME_COLUMN)
+        latest_start = self.latest_start(event_time)
+        num_windows = -(-self.size // self.slide)
+        if num_windows == 1:
+            rows = events.with_column(WINDOW_START_COLUMN, latest_start)
+        else:
+            starts = list_(*[latest_start - i * self.slide for i in range(num_windows)])
+            rows = (
+                events.with_column(WINDOW_START_COLUMN, starts)
+                .explode(WINDOW_START_COLUMN)
+                .where(col(WINDOW_START_COLUMN) + self.size > event_time)
+            )
+        rows = rows.with_column(WINDOW_END_COLUMN, col(WINDOW_START_COLUMN) + self.size)
+        return rows if after is None else rows.where(col(WINDOW_END_COLUMN) > after)
+
+    def aggregate(self, rows: DataFrame, by: list[Expression], to_agg: list[Expression]) -> DataFrame:
+        """Aggregates the rows of each window and key, with the bounds of windows in the type of the event times."""
+        aggregated = rows.groupby(WINDOW_START_COLUMN, WINDOW_END_COLUMN, *by).agg(*to_agg)
+        return aggregated.select(
+            col(WINDOW_START_COLUMN).cast(self.dtype).alias(WINDOW_START),
+            col(WINDOW_END_COLUMN).cast(self.dtype).alias(WINDOW_END),
+            *[c for c in aggregated.column_names if c not in (WINDOW_START_COLUMN, WINDOW_END_COLUMN)],
+        )
+
+
+def window_agg(
+    df: DataFrame,
+    timestamp: str | Expression,
+    size: WindowDuration,
+    slide: WindowDuration | None,
+    by: list[Expression],
+    to_agg: list[Expression],
+) -> DataFrame:
+    windows = _EventTimeWindows.create(df, timestamp, size, slide)
+    return windows.aggregate(windows.assign(windows.with_event_time(df), after=None), by, to_agg)
+
+
+class StreamingWindowAggregation:
+    """Aggregates the micro-batches of a stream over tumbling or sliding windows of event time.
+
+    Each batch, such as one of those that `KafkaStream.foreach_batch` processes, is added with `update`, which returns
+    the windows that are complete.
+
+    The aggregation tracks a watermark, which trails the latest event time that it has seen by ``watermark_delay``. Each
+    window is emitted once, by the batch that moves the watermark past its end, so events may arrive out of order by up
+    to the delay. Events that arrive later, when every window that they fall in has been emitted, are dropped.
+
+    The events of the windows that haven't been emitted yet are the state of the aggregation, which is persisted between
+    batches, so that it's spilled to disk when it doesn't fit under the `persist_memory_limit_bytes` execution config.
+    The state isn't checkpointed, so the windows that it holds are lost if the process stops before they're emitted.
+
+    Examples:
+        >>> import daft
+        >>> from daft import col
+        >>> agg = daft.StreamingWindowAggregation("ts", 10, col("x").sum(), by="key", watermark_delay=5)
+        >>> agg.update(daft.from_pydict({"ts": [1, 4, 12], "key": ["a", "a", "b"], "x": [1, 2, 3]})).to_pydict()
+        {'window_start': [], 'window_end': [], 'key': [], 'x': []}
+        >>> agg.update(daft.from_pydict({"ts": [7, 16], "key": ["a", "b"], "x": [4, 5]})).to_pydict()
+        {'window_start': [0], 'window_end': [10], 'key': ['a'], 'x': [7]}
+        >>> agg.update(daft.from_pydict({"ts": [3], "key": ["a"], "x": [6]})).to_pydict()
+        {'window_start': [], 'window_end': [], 'key': [], 'x': []}
+        >>> agg.num_late_events
+        1
+        >>> agg.flush().to_pydict()
+        {'window_start': [10], 'window_end': [20], 'key': ['b'], 'x': [8]}
+    """
+
+    def __init__(
+        self,
+        timestamp: str | Expression,
+        size: WindowDuration,
+        *to_agg: Expression,
+        slide: WindowDuration | None = None,
+        by: str | Expression | list[str | Expression] | None = None,
+        watermark_delay: WindowDuration | None = None,
+    ) -> None:
+        """Creates an aggregation of the micro-batches of a stream over windows of event time.
+
+        Args:
+            timestamp (str | Expression): The event time of each event, which must be a timestamp or an integer.
+            size (int | timedelta): The length of each window. A ``timedelta`` for timestamps, and an integer otherwise.
+            *to_agg (Expression): The aggregations to compute over the events of each window and key.
+            slide (int | timedelta, optional): How far apart the starts of consecutive windows are, which makes windows
+                overlap when it's less than ``size``. Defaults to ``size``, for tumbling windows.
+            by (str | Expression | list, optional): The keys to aggregate the events of each window by.
+            watermark_delay (int | timedelta, optional): How far the watermark trails the latest event time, which is
+                how late events can arrive before they're dropped. Defaults to no delay.
+        """
+        if not to_agg:
+            raise ValueError("A windowed aggregation requires at least one aggregation")
+        self._timestamp = timestamp
+        self._size = size
+        self._slide = slide
+        by = [] if by is None else by if isinstance(by, list) else [by]
+        self._by = [col(c) if isinstance(c, str) else c for c in by]
+        self._to_agg = list(to_agg)
+        self._watermark_delay = watermark_delay
+        self._delay = 0
+        self._windows: _EventTimeWindows | None = None
+        self._watermark: int | None = None
+        self._state: DataFrame | None = None
+        self._num_late_events = 0
+
+    @property
+    def watermark(self) -> Any:
+        """The event time that every window ending at or before it has been emitted by, or None before any events."""
+        if self._windows is None or self._watermark is None:
+            return None
+        return self._windows.from_ticks(self._watermark)
+
+    @property
+    def num_late_events(self) -> int:
+        """The number of events that were dropped because every window that they fall in had already been emitted."""
+        return self._num_late_events
+
+    def update(self, batch: DataFrame) -> DataFrame:
+        """Adds a micro-batch of events to the aggregation, executing it.
+
+        Args:
+            batch (DataFrame): The events of the batch.
+
+        Returns:
+            DataFrame: The aggregations of the windows that the batch moved the watermark past, with the
+                ``window_start`` and ``window_end`` of each window, the keys, and the aggregations.
+        """
+        if self._windows is None:
+            self._windows = _EventTimeWindows.create(batch, self._timestamp, self._size, self._slide)
+            if self._watermark_delay is not None:
+                self._delay = _to_ticks(self._windows.dtype, self._watermark_delay)
+            if self._delay < 0:
+                raise ValueError(f"The watermark delay must be non-negative, but got: {self._watermark_delay}")
+        windows = self._windows
+
+        events = windows.with_event_time(batch).collect()
+        if self._watermark is not None:
+            late = windows.latest_start(col(EVENT_TIME_COLUMN)) + windows.size <= self._watermark
+            self._num_late_events += events.where(late).count_rows()
+            events = events.where(~late)
+        rows = windows.assign(events, after=self._watermark)
+
+        latest = events.agg(col(EVENT_TIME_COLUMN).max()).to_pydict()[EVENT_TIME_COLUMN][0]
+        if latest is not None:
+            watermark = latest - self._delay
+            self._watermark = watermark if self._watermark is None else max(self._watermark, watermark)
+
+        state = (rows if self._state is None else self._state.concat(rows)).persist()
+        ready = col(WINDOW_END_COLUMN) <= self._watermark if self._watermark is not None else lit(False)
+        emitted = windows.aggregate(state.where(ready), self._by, self._to_agg).collect()
+        if self._state is not None:
+            self._state.unpersist()
+        self._state = state.where(~ready).persist()
+        state.unpersist()
+        return emitted
+
+    def flush(self) -> DataFrame:
+        """Emits the aggregations of every window that hasn't been emitted yet, such as when a stream ends.
+
+        Returns:
+            DataFrame: The aggregations of the windows, like those that `update` returns.
+        """
+        if self._windows is None or self._state is None:
+            raise ValueError("A windowed aggregation must be updated with a batch before it's flushed")
+        emitted = self._windows.aggregate(self._state, self._by, self._to_agg).collect()
+        state = self._state.where(lit(False)).persist()
+        self._state.unpersist()
+        self._state = state
+        return emitted
+
+    def __repr__(self) -> str:
+        return f"StreamingWindowAggregation(watermark={self.watermark!r}, num_late_events={self.num_late_events})"
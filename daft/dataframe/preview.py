@@ -4,6 +4,8 @@ import json
 from dataclasses import dataclass
 from typing import TYPE_CHECKING, Literal, TypedDict
 
+from daft.dataframe.display import get_display_options
+
 if TYPE_CHECKING:
     from daft.logical.schema import Schema
     from daft.recordbatch import MicroPartition
@@ -13,6 +15,7 @@ if TYPE_CHECKING:
 class Preview:
     partition: MicroPartition | None
     total_rows: int | None
+    offset: int = 0
 
 
 PreviewFormat = Literal[
@@ -100,6 +103,12 @@ class PreviewFormatter:
             return "(No data to display: Dataframe not materialized)"
         if self._preview.total_rows == 0:
             return "(No data to display: Materialized dataframe has no rows)"
+        if self._preview.offset > 0:
+            start = self._preview.offset + 1
+            end = self._preview.offset + len(self._preview.partition)
+            if self._preview.total_rows is None:
+                return f"(Showing rows {start} to {end})"
+            return f"(Showing rows {start} to {end} of {self._preview.total_rows} rows)"
         if self._preview.total_rows is None:
             first_rows = len(self._preview.partition)
             return f"(Showing first {first_rows} rows)"
@@ -125,7 +134,8 @@ class PreviewFormatter:
 
     def _to_html(self) -> str:
         if self._preview.partition is not None:
-            return self._preview.partition.to_record_batch()._repr_html_()
+            options = get_display_options().serialize()
+            return self._preview.partition.to_record_batch()._table.repr_html_with_options(options)
         else:
             return self._schema._truncated_table_html()
 
@@ -138,6 +148,7 @@ class PreviewFormatter:
             if self._format:
                 return self._preview.partition.to_record_batch()._table.preview(self._format, self._options.serialize())
             else:
-                return self._preview.partition.to_record_batch().__repr__()
+                options = get_display_options().serialize()
+                return self._preview.partition.to_record_batch()._table.repr_with_options(options)
         else:
             return self._schema._truncated_table_string()
@@ -1,7 +1,15 @@
 from __future__ import annotations
 
 import logging
-from typing import Any, Iterable, Iterator
+import queue
+import threading
+from typing import TYPE_CHECKING, Any, Iterable, Iterator
+
+from daft.context import get_context
+
+if TYPE_CHECKING:
+    from daft.dataframe import DataFrame
+    from daft.recordbatch import MicroPartition
 
 logger = logging.getLogger(__name__)
 
@@ -36,11 +44,95 @@ class DaftTorchDataset(MAP_DATASET_CLASS):  # type: ignore
         return {key: vallist[i] for (key, vallist) in self.data.items()}
 
 
+def _shard_info() -> tuple[int, int]:
+    """Returns the index of the shard of the current DDP rank and DataLoader worker, and the number of shards."""
+    import torch
+
+    shard, num_shards = 0, 1
+    if torch.distributed.is_available() and torch.distributed.is_initialized():
+        shard, num_shards = torch.distributed.get_rank(), torch.distributed.get_world_size()
+    worker_info = torch.utils.data.get_worker_info()
+    if worker_info is not None:
+        shard = shard * worker_info.num_workers + worker_info.id
+        num_shards *= worker_info.num_workers
+    return shard, num_shards
+
+
+class _PrefetchError:
+    def __init__(self, error: BaseException):
+        self.error = error
+
+
+def _prefetch(iterable: Iterable[Any], size: int) -> Iterator[Any]:
+    """Iterates over `iterable` in a background thread, which runs ahead of the consumer by up to `size` items.
+
+    Daft releases the GIL while it executes, so the next items are computed while the consumer is busy with the current one.
+    """
+    items: queue.Queue[Any] = queue.Queue(maxsize=size)
+    done = object()
+    stopped = threading.Event()
+
+    def put(item: Any) -> bool:
+        while not stopped.is_set():
+            try:
+                items.put(item, timeout=0.1)
+                return True
+            except queue.Full:
+                pass
+        return False
+
+    def produce() -> None:
+        try:
+            for item in iterable:
+                if not put(item):
+                    return
+        except BaseException as e:
+            put(_PrefetchError(e))
+            return
+        put(done)
+
+    thread = threading.Thread(target=produce, name="daft-torch-prefetch", daemon=True)
+    thread.start()
+    try:
+        while (item := items.get()) is not done:
+            if isinstance(item, _PrefetchError):
+                raise item.error
+            yield item
+    finally:
+        stopped.set()
+
+
 class DaftTorchIterableDataset(ITER_DATASET_CLASS):  # type: ignore
-    """A thin wrapper to create a torch IterableDataset from an iterable."""
+    """A torch IterableDataset that streams the rows of a Daft DataFrame.
+
+    When `num_workers_aware` is set, the partitions of the DataFrame are split round-robin across DDP ranks and
+    DataLoader workers, so that each row is yielded by exactly one of them.
+    """
+
+    def __init__(self, df: DataFrame, num_workers_aware: bool = True, prefetch: int | None = None):
+        self.df = df
+        self.num_workers_aware = num_workers_aware
+        self.prefetch = prefetch
+
+    def _partitions(self) -> Iterator[MicroPartition]:
+        if self.df._result is not None:
+            for result in self.df._result.values():
+                yield result.micropartition()
+        else:
+            yield from get_context().get_or_create_runner().run_iter_tables(
+                self.df._builder, results_buffer_size=self.prefetch
+            )
 
-    def __init__(self, iterable: Iterable[dict[str, Any]]):
-        self.iterable = iterable
+    def _shard_partitions(self) -> Iterator[tuple[dict[str, list[Any]], int]]:
+        shard, num_shards = _shard_info() if self.num_workers_aware else (0, 1)
+        for i, partition in enumerate(self._partitions()):
+            if i % num_shards == shard:
+                yield partition.to_pydict(), len(partition)
 
     def __iter__(self) -> Iterator[dict[str, Any]]:
-        return iter(self.iterable)
+        partitions = self._shard_partitions()
+        if self.prefetch:
+            partitions = _prefetch(partitions, self.prefetch)
+        for pydict, num_rows in partitions:
+            for i in range(num_rows):
+                yield {key: values[i] for (key, values) in pydict.items()}
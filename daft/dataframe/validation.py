@@ -0,0 +1,141 @@
+from __future__ import annotations
+
+import warnings
+from dataclasses import dataclass
+from typing import TYPE_CHECKING, Literal
+
+from daft.datatype import DataType
+from daft.expressions import Expression, col, element, list_, lit
+
+if TYPE_CHECKING:
+    from daft.dataframe import DataFrame
+
+Severity = Literal["fail", "warn", "quarantine"]
+
+_SEVERITIES = ("fail", "warn", "quarantine")
+
+VIOLATIONS_COLUMN = "_violations"
+
+
+@dataclass(frozen=True)
+class Constraint:
+    """A boolean expression that every row of a DataFrame is expected to satisfy, for `DataFrame.validate`.
+
+    A row violates a constraint when the expression is false or null for it.
+
+    Args:
+        expr (Expression): The boolean expression that rows are expected to satisfy.
+        severity (str, optional): What happens to violations. ``"fail"`` raises a `ValidationError`, ``"warn"``
+            emits a warning, and ``"quarantine"`` moves the violating rows to the quarantine DataFrame of the result.
+            Defaults to ``"fail"``.
+        name (str, optional): The name of the constraint in the summary. Defaults to the name of the expression.
+    """
+
+    expr: Expression
+    severity: Severity = "fail"
+    name: str | None = None
+
+    def __post_init__(self) -> None:
+        if not isinstance(self.expr, Expression):
+            raise TypeError(f"Expected the expression of a constraint to be an Expression, but got: {type(self.expr)}")
+        if self.severity not in _SEVERITIES:
+            raise ValueError(f"Unknown constraint severity: {self.severity}, expected one of {_SEVERITIES}")
+
+
+@dataclass(frozen=True)
+class ConstraintSummary:
+    """The number of rows that violated a constraint of `DataFrame.validate`."""
+
+    name: str
+    severity: Severity
+    violations: int
+
+
+@dataclass(frozen=True)
+class ValidationResult:
+    """The result of `DataFrame.validate`.
+
+    Attributes:
+        df (DataFrame): The rows that didn't violate any quarantine constraint.
+        quarantine (DataFrame): The rows that violated a quarantine constraint, with a ``_violations`` column that lists
+            the names of the quarantine constraints that each violated.
+        summary (list[ConstraintSummary]): The number of violations of each constraint, in the order they were given.
+        num_rows (int): The number of rows that were validated.
+    """
+
+    df: DataFrame
+    quarantine: DataFrame
+    summary: list[ConstraintSummary]
+    num_rows: int
+
+    @property
+    def passed(self) -> bool:
+        """Whether no constraint was violated."""
+        return all(s.violations == 0 for s in self.summary)
+
+
+class ValidationError(ValueError):
+    """Raised by `DataFrame.validate` when rows violate a constraint of severity ``"fail"``.
+
+    Attributes:
+        summary (list[ConstraintSummary]): The number of violations of each constraint.
+        num_rows (int): The number of rows that were validated.
+    """
+
+    def __init__(self, summary: list[ConstraintSummary], num_rows: int) -> None:
+        failed = ", ".join(
+            f"`{s.name}` ({s.violations} of {num_rows} rows)"
+            for s in summary
+            if s.severity == "fail" and s.violations > 0
+        )
+        super().__init__(f"DataFrame violates constraints: {failed}")
+        self.summary = summary
+        self.num_rows = num_rows
+
+
+def _constraint_names(constraints: list[Constraint]) -> list[str]:
+    names = [c.name if c.name is not None else c.expr.name() for c in constraints]
+    duplicates = sorted({name for name in names if names.count(name) > 1})
+    if duplicates:
+        raise ValueError(f"Constraints must have unique names, but these are repeated: {duplicates}")
+    return names
+
+
+def validate(df: DataFrame, constraints: list[Constraint]) -> ValidationResult:
+    names = _constraint_names(constraints)
+    flags = [f"__daft_violates_{i}" for i in range(len(constraints))]
+    flagged = df.with_columns({flag: c.expr for flag, c in zip(flags, constraints)})
+    schema = flagged.schema()
+    for name, flag in zip(names, flags):
+        if schema[flag].dtype != DataType.bool():
+            raise ValueError(f"Constraint `{name}` must be a boolean expression, but is of type {schema[flag].dtype}")
+    flagged = flagged.with_columns({flag: ~col(flag).fill_null(lit(False)) for flag in flags})
+
+    # The checks run in the same pass as the rest of the DataFrame, and what follows reads its materialized result.
+    flagged = flagged.collect()
+    num_rows = flagged.count_rows()
+    counts = {}
+    if flags:
+        counts = flagged.agg(*[col(flag).cast(DataType.int64()).sum().alias(flag) for flag in flags]).to_pydict()
+    summary = [
+        ConstraintSummary(name, c.severity, counts[flag][0] or 0) for name, flag, c in zip(names, flags, constraints)
+    ]
+
+    if any(s.severity == "fail" and s.violations > 0 for s in summary):
+        raise ValidationError(summary, num_rows)
+    for s in summary:
+        if s.severity == "warn" and s.violations > 0:
+            warnings.warn(f"Constraint `{s.name}` was violated by {s.violations} of {num_rows} rows")
+
+    quarantined = [(name, flag) for name, flag, c in zip(names, flags, constraints) if c.severity == "quarantine"]
+    if quarantined:
+        null = lit(None).cast(DataType.string())
+        violated = list_(*[col(flag).if_else(lit(name), null) for name, flag in quarantined])
+        flagged = flagged.with_column(VIOLATIONS_COLUMN, violated.list.filter(element().not_null()))
+        is_quarantined = col(VIOLATIONS_COLUMN).list.length() > 0
+        valid = flagged.where(~is_quarantined).exclude(VIOLATIONS_COLUMN, *flags)
+        quarantine = flagged.where(is_quarantined).exclude(*flags)
+    else:
+        valid = flagged.exclude(*flags)
+        quarantine = valid.limit(0).with_column(VIOLATIONS_COLUMN, lit(None).cast(DataType.list(DataType.string())))
+    return ValidationResult(valid, quarantine, summary, num_rows)
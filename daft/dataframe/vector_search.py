@@ -0,0 +1,204 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Literal
+
+from daft.context import get_context
+from daft.daft import PyVectorIndex
+from daft.datatype import DataType
+from daft.expressions import Expression, col
+from daft.series import Series
+
+if TYPE_CHECKING:
+    from daft.dataframe import DataFrame
+
+Metric = Literal["cosine", "l2", "dot"]
+IndexKind = Literal["flat", "ivf"]
+
+ROW_ID_COLUMN = "__daft_nn_row_id"
+NEIGHBOR_COLUMN = "__daft_nn_neighbor"
+NEIGHBOR_ID_COLUMN = "__daft_nn_neighbor_id"
+
+
+class VectorIndex:
+    """An index over the vectors of a DataFrame column, for `DataFrame.nearest_neighbors`.
+
+    The index has one shard per partition of the DataFrame it was built from, and identifies rows by the values of an id
+    column, so that it can be saved and used again with the same data without being rebuilt.
+    """
+
+    _index: PyVectorIndex
+
+    def __init__(self) -> None:
+        raise NotImplementedError("Build a VectorIndex with `VectorIndex.build` or load one with `VectorIndex.load`")
+
+    @staticmethod
+    def _from_pyindex(index: PyVectorIndex) -> VectorIndex:
+        vi = VectorIndex.__new__(VectorIndex)
+        vi._index = index
+        return vi
+
+    @staticmethod
+    def build(
+        df: DataFrame,
+        on: str,
+        id_column: str,
+        *,
+        metric: Metric = "cosine",
+        kind: IndexKind = "flat",
+        num_lists: int | None = None,
+        num_probes: int = 8,
+    ) -> VectorIndex:
+        """Builds an index over the vectors of a DataFrame column, executing the DataFrame.
+
+        Args:
+            df (DataFrame): The DataFrame whose rows to index.
+            on (str): The column of vectors, which must be fixed-size lists or embeddings of numbers. Null vectors
+                aren't indexed.
+            id_column (str): A column that uniquely identifies the rows of the DataFrame, whose values the index returns
+                for each neighbor.
+            metric (str, optional): How distances are measured: ``"cosine"`` (one minus the cosine similarity),
+                ``"l2"`` (the Euclidean distance) or ``"dot"`` (the negated dot product). Defaults to ``"cosine"``.
+            kind (str, optional): ``"flat"`` compares each query with every vector, which is exact. ``"ivf"`` clusters
+                the vectors of each partition into lists and only compares a query with the vectors of the lists
+                closest to it, which is approximate but faster on large partitions. Defaults to ``"flat"``.
+            num_lists (int, optional): The number of lists of each partition of an IVF index. Defaults to the square
+                root of the number of rows in the partition.
+            num_probes (int, optional): The number of lists of each partition that an IVF index searches, unless
+                overridden when searching. Defaults to 8.
+
+        Returns:
+            VectorIndex: The index, with one shard per partition of the DataFrame.
+        """
+        schema = df.schema()
+        index = PyVectorIndex(schema[on]._field, schema[id_column]._field, metric, kind, num_lists, num_probes)
+        builder = df.select(on, id_column)._builder
+        for partition in get_context().get_or_create_runner().run_iter_tables(builder, results_buffer_size=1):
+            index.add_partition(partition.get_column(on)._series, partition.get_column(id_column)._series)
+        return VectorIndex._from_pyindex(index)
+
+    def save(self, path: str) -> None:
+        """Writes the index to a local file, from which it can be loaded with `VectorIndex.load`."""
+        with open(path, "wb") as f:
+            f.write(self._index.to_bytes())
+
+    @staticmethod
+    def load(path: str) -> VectorIndex:
+        """Reads an index from a local file written by `VectorIndex.save`."""
+        with open(path, "rb") as f:
+            return VectorIndex._from_pyindex(PyVectorIndex.from_bytes(f.read()))
+
+    @property
+    def column(self) -> str:
+        """The name of the column of vectors that the index was built from."""
+        return self._index.column
+
+    @property
+    def id_column(self) -> str:
+        """The name of the column that identifies the indexed rows."""
+        return self._index.id_column
+
+    @property
+    def dimension(self) -> int:
+        return self._index.dimension
+
+    @property
+    def metric(self) -> Metric:
+        return self._index.metric  # type: ignore[return-value]
+
+    @property
+    def kind(self) -> IndexKind:
+        return self._index.kind  # type: ignore[return-value]
+
+    @property
+    def num_rows(self) -> int:
+        """The number of indexed vectors."""
+        return self._index.num_rows
+
+    @property
+    def num_partitions(self) -> int:
+        return self._index.num_partitions
+
+    def __repr__(self) -> str:
+        return (
+            f"VectorIndex(column={self.column!r}, id_column={self.id_column!r}, metric={self.metric!r}, "
+            f"kind={self.kind!r}, num_rows={self.num_rows}, num_partitions={self.num_partitions})"
+        )
+
+    def search(self, queries: Expression, k: int, *, num_probes: int | None = None) -> Expression:
+        """Finds the ``k`` nearest neighbors of each query vector.
+
+        Args:
+            queries (Expression): The query vectors, which must have the dimension of the index.
+            k (int): The number of neighbors to find for each query.
+            num_probes (int, optional): Overrides the number of lists of each partition that an IVF index searches.
+
+        Returns:
+            Expression: A list of structs with the ``id`` and ``distance`` of each neighbor, closest first.
+        """
+        from daft.udf import udf
+
+        if k <= 0:
+            raise ValueError(f"The number of neighbors to find must be positive, but got: {k}")
+        index = self._index
+        neighbor_dtype = DataType.struct({"id": self._id_dtype, "distance": DataType.float64()})
+
+        @udf(return_dtype=DataType.list(neighbor_dtype))
+        def nearest_neighbors(queries: Series) -> Series:
+            return Series._from_pyseries(index.search(queries._series, k, num_probes))
+
+        return nearest_neighbors(queries)
+
+    @property
+    def _id_dtype(self) -> DataType:
+        return DataType._from_pydatatype(self._index.id_dtype)
+
+
+def nearest_neighbors(
+    df: DataFrame,
+    query_vectors: DataFrame | list,
+    on: str,
+    k: int,
+    query_on: str | None,
+    metric: Metric | None,
+    index: VectorIndex | None,
+    num_probes: int | None,
+    distance_column: str,
+) -> DataFrame:
+    from daft.convert import from_pydict
+    from daft.dataframe import DataFrame
+    from daft.functions import monotonically_increasing_id
+
+    query_on = query_on or on
+    if not isinstance(query_vectors, DataFrame):
+        query_vectors = from_pydict({query_on: list(query_vectors)})
+
+    if index is None:
+        # The rows are materialized so that the row ids that the index returns refer to the same rows when joined.
+        rows = df.with_column(ROW_ID_COLUMN, monotonically_increasing_id()).collect()
+        index = VectorIndex.build(rows, on, ROW_ID_COLUMN, metric=metric or "cosine")
+    else:
+        if index.column != on:
+            raise ValueError(f"The vector index was built from column `{index.column}`, not `{on}`")
+        if metric is not None and metric != index.metric:
+            raise ValueError(f"The vector index measures distances with metric `{index.metric}`, not `{metric}`")
+        rows = df
+
+    query = col(query_on)
+    dtype = query_vectors.schema()[query_on].dtype
+    if not (dtype.is_fixed_size_list() or dtype.is_embedding()):
+        query = query.cast(DataType.fixed_size_list(DataType.float32(), index.dimension))
+    neighbor = col(NEIGHBOR_COLUMN)
+    matches = (
+        query_vectors.with_column(NEIGHBOR_COLUMN, index.search(query, k, num_probes=num_probes))
+        .explode(NEIGHBOR_COLUMN)
+        .where(neighbor.not_null())
+        .with_columns(
+            {
+                NEIGHBOR_ID_COLUMN: neighbor.struct.get("id"),
+                distance_column: neighbor.struct.get("distance"),
+            }
+        )
+        .exclude(NEIGHBOR_COLUMN)
+    )
+    result = rows.join(matches, left_on=index.id_column, right_on=NEIGHBOR_ID_COLUMN, prefix="query_")
+    return result.exclude(*[c for c in (ROW_ID_COLUMN, NEIGHBOR_ID_COLUMN) if c in result.column_names])
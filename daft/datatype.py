@@ -1,10 +1,13 @@
 from __future__ import annotations
 
 import threading
-from typing import TYPE_CHECKING, Union
+from typing import TYPE_CHECKING, Callable, Union
 
 from daft.context import get_context
-from daft.daft import ImageMode, PyDataType, PyTimeUnit
+from daft.daft import ImageMode, PyDataType, PyTimeUnit, deserialize_extension_metadata
+from daft.daft import register_extension_type as _register_extension_type
+from daft.daft import registered_extension_types as _registered_extension_types
+from daft.daft import unregister_extension_type as _unregister_extension_type
 from daft.dependencies import pa
 
 if TYPE_CHECKING:
@@ -298,6 +301,33 @@ class DataType:
     def extension(cls, name: str, storage_dtype: DataType, metadata: str | None = None) -> DataType:
         return cls._from_pydatatype(PyDataType.extension(name, storage_dtype._dtype, metadata))
 
+    @classmethod
+    def uuid(cls) -> DataType:
+        """Create the Arrow canonical UUID extension type (``arrow.uuid``), stored as 16 bytes per value.
+
+        Casting a UUID column to a string produces the hyphenated hex representation, and casting a
+        string column to UUID parses it (invalid UUIDs become null).
+
+        Examples:
+            >>> import daft
+            >>> dtype = daft.DataType.uuid()
+            >>> assert dtype.is_uuid()
+        """
+        return cls._from_pydatatype(PyDataType.uuid())
+
+    @classmethod
+    def json(cls) -> DataType:
+        """Create the Arrow canonical JSON extension type (``arrow.json``), stored as a UTF-8 string per value.
+
+        Casting a string column to JSON validates each value (invalid JSON documents become null).
+
+        Examples:
+            >>> import daft
+            >>> dtype = daft.DataType.json()
+            >>> assert dtype.is_json()
+        """
+        return cls._from_pydatatype(PyDataType.json())
+
     @classmethod
     def embedding(cls, dtype: DataType, size: int) -> DataType:
         """Create an Embedding DataType: embeddings are fixed size arrays, where each element in the array has a **numeric** ``dtype`` and each array has a fixed length of ``size``.
@@ -406,6 +436,21 @@ class DataType:
                 raise ValueError("SparseTensor shape must be a non-empty tuple of ints, but got: ", shape)
         return cls._from_pydatatype(PyDataType.sparse_tensor(dtype._dtype, shape, use_offset_indices))
 
+    @classmethod
+    def categorical(cls, categories: builtins.list[str]) -> DataType:
+        """Create a Categorical DataType: strings stored as ``uint32`` codes into a fixed list of ``categories``.
+
+        The categories act as a dictionary shared by every value of the type, so columns and partitions with the same
+        categorical type can be grouped, joined and concatenated on their codes. Values sort in the order of their
+        categories, and convert to a pyarrow ``DictionaryArray``.
+
+        Args:
+            categories: The distinct strings that values can take, in the order of their codes.
+        """
+        if not isinstance(categories, builtins.list) or any(not isinstance(c, str) for c in categories):
+            raise ValueError("The categories of a categorical type must be a list of strings, but got: ", categories)
+        return cls._from_pydatatype(PyDataType.categorical(categories))
+
     @classmethod
     def from_arrow_type(cls, arrow_type: pa.lib.DataType) -> DataType:
         """Maps a PyArrow DataType to a Daft DataType."""
@@ -486,6 +531,14 @@ class DataType:
         elif isinstance(arrow_type, pa.BaseExtensionType):
             name = arrow_type.extension_name
 
+            # Arrow canonical extension types are understood natively by Daft.
+            if name == "arrow.uuid" and arrow_type.storage_type == pa.binary(16):
+                return cls.uuid()
+            elif name == "arrow.json" and (
+                pa.types.is_string(arrow_type.storage_type) or pa.types.is_large_string(arrow_type.storage_type)
+            ):
+                return cls.json()
+
             if (get_context().get_or_create_runner().name == "ray") and (
                 type(arrow_type).__reduce__ == pa.BaseExtensionType.__reduce__
             ):
@@ -504,10 +557,11 @@ class DataType:
                 assert metadata is not None
                 return cls._from_pydatatype(PyDataType.from_json(metadata))
             else:
+                storage_dtype = cls.from_arrow_type(arrow_type.storage_type)
                 return cls.extension(
                     name,
-                    cls.from_arrow_type(arrow_type.storage_type),
-                    metadata,
+                    storage_dtype,
+                    deserialize_extension_metadata(name, storage_dtype._dtype, metadata),
                 )
         else:
             # Fall back to a Python object type.
@@ -789,6 +843,26 @@ class DataType:
         """
         return self._dtype.is_extension()
 
+    def is_uuid(self) -> builtins.bool:
+        """Check if this is the canonical UUID extension type.
+
+        Examples:
+            >>> import daft
+            >>> dtype = daft.DataType.uuid()
+            >>> assert dtype.is_uuid()
+        """
+        return self._dtype.is_uuid()
+
+    def is_json(self) -> builtins.bool:
+        """Check if this is the canonical JSON extension type.
+
+        Examples:
+            >>> import daft
+            >>> dtype = daft.DataType.json()
+            >>> assert dtype.is_json()
+        """
+        return self._dtype.is_json()
+
     def is_image(self) -> builtins.bool:
         """Check if this is an image type.
 
@@ -819,6 +893,16 @@ class DataType:
         """
         return self._dtype.is_embedding()
 
+    def is_categorical(self) -> builtins.bool:
+        """Check if this is a categorical type.
+
+        Examples:
+            >>> import daft
+            >>> dtype = daft.DataType.categorical(["a", "b"])
+            >>> assert dtype.is_categorical()
+        """
+        return self._dtype.is_categorical()
+
     def is_tensor(self) -> builtins.bool:
         """Check if this is a tensor type.
 
@@ -1104,6 +1188,17 @@ class DataType:
         """
         return DataType._from_pydatatype(self._dtype.value_type())
 
+    @property
+    def categories(self) -> builtins.list[str]:
+        """If this is a categorical type, return its categories in the order of their codes, otherwise an attribute error is raised.
+
+        Examples:
+            >>> import daft
+            >>> dtype = daft.DataType.categorical(["a", "b"])
+            >>> assert dtype.categories == ["a", "b"]
+        """
+        return self._dtype.categories()
+
     def _should_cast_to_python(self) -> builtins.bool:
         # NOTE: This is used to determine if we should cast a column to a Python object type when converting to PyList.
         # Map is a logical type, but we don't want to cast it to Python because the underlying physical type is a List,
@@ -1170,3 +1265,84 @@ def _ensure_registered_super_ext_type():
 def get_super_ext_type():
     _ensure_registered_super_ext_type()
     return _STATIC_DAFT_EXTENSION
+
+
+_REGISTERED_PYARROW_EXTENSION_TYPES: dict[str, type[pa.ExtensionType]] = {}
+
+
+def _pyarrow_extension_type(name: str) -> type[pa.ExtensionType]:
+    """Returns a pyarrow extension type that carries its storage type and metadata as is for the extension type `name`."""
+    if name not in _REGISTERED_PYARROW_EXTENSION_TYPES:
+
+        class RegisteredExtension(pa.ExtensionType):
+            def __init__(self, storage_type, metadata=b""):
+                # attributes need to be set first before calling
+                # super init (as that calls serialize)
+                self._metadata = metadata
+                super().__init__(storage_type, name)
+
+            def __reduce__(self):
+                return _make_pyarrow_extension_type, (name, self.storage_type, self._metadata)
+
+            def __arrow_ext_serialize__(self):
+                return self._metadata
+
+            @classmethod
+            def __arrow_ext_deserialize__(cls, storage_type, serialized):
+                return cls(storage_type, serialized)
+
+        _REGISTERED_PYARROW_EXTENSION_TYPES[name] = RegisteredExtension
+    return _REGISTERED_PYARROW_EXTENSION_TYPES[name]
+
+
+def _make_pyarrow_extension_type(name: str, storage_type: pa.DataType, metadata: bytes) -> pa.ExtensionType:
+    return _pyarrow_extension_type(name)(storage_type, metadata)
+
+
+def register_extension_type(
+    name: str,
+    storage_dtype: DataType,
+    serialize: Callable[[str | None], str | None] | None = None,
+    deserialize: Callable[[str | None], str | None] | None = None,
+) -> None:
+    """Registers a user-defined extension type, so that it survives IPC and Parquet round-trips instead of being read back as its storage type.
+
+    Registration is process-wide and replaces any earlier registration under the same name. If pyarrow does not already know
+    about an extension type with this name, one is registered with pyarrow as well.
+
+    Args:
+        name: The name of the extension type, as stored in the ``ARROW:extension:name`` field metadata.
+        storage_dtype: The storage type of the extension type.
+        serialize: Optional function that converts the metadata of the extension type to the form it is stored in.
+        deserialize: Optional function that converts stored metadata back, the inverse of ``serialize``.
+
+    Examples:
+        >>> import daft
+        >>> from daft.datatype import register_extension_type, unregister_extension_type
+        >>> register_extension_type("example.celsius", daft.DataType.float64())
+        >>> daft.DataType.extension("example.celsius", daft.DataType.float64())
+        Extension[example.celsius; Float64]
+        >>> unregister_extension_type("example.celsius")
+        True
+    """
+    _register_extension_type(name, storage_dtype._dtype, serialize, deserialize)
+    if name not in _REGISTERED_PYARROW_EXTENSION_TYPES:
+        ext_type = _pyarrow_extension_type(name)
+        try:
+            pa.register_extension_type(ext_type(storage_dtype.to_arrow_dtype()))
+        except pa.ArrowKeyError:
+            # pyarrow already has its own extension type registered under this name.
+            del _REGISTERED_PYARROW_EXTENSION_TYPES[name]
+
+
+def unregister_extension_type(name: str) -> bool:
+    """Removes the extension type registered under ``name``, returning whether there was one."""
+    if name in _REGISTERED_PYARROW_EXTENSION_TYPES:
+        pa.unregister_extension_type(name)
+        del _REGISTERED_PYARROW_EXTENSION_TYPES[name]
+    return _unregister_extension_type(name)
+
+
+def registered_extension_types() -> list[str]:
+    """Returns the names of all registered extension types."""
+    return _registered_extension_types()
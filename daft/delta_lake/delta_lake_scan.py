@@ -2,7 +2,7 @@ from __future__ import annotations
 
 import logging
 import os
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Any
 from urllib.parse import urlparse
 
 from deltalake.table import DeltaTable
@@ -17,96 +17,134 @@ from daft.daft import (
     ScanTask,
     StorageConfig,
 )
+from daft.datatype import DataType
 from daft.io.aws_config import boto3_client_from_s3_config
 from daft.io.object_store_options import io_config_to_storage_options
 from daft.io.scan import PartitionField, ScanOperator
-from daft.logical.schema import Schema
+from daft.logical.schema import Field, Schema
 
 if TYPE_CHECKING:
     from collections.abc import Iterator
     from datetime import datetime
 
+    import pyarrow as pa
+
 logger = logging.getLogger(__name__)
 
+# The column that incremental reads return the type of the change of each row in, which is either "insert" or "delete".
+CHANGE_TYPE_COLUMN_NAME = "_change_type"
+
+
+def delta_lake_storage_options(table_uri: str, storage_config: StorageConfig) -> dict[str, str]:
+    """The storage options that delta-rs needs to access a table with the IO config of a storage config."""
+    # Unfortunately delta-rs doesn't do very good inference of credentials for S3. Thus the current Daft behavior of passing
+    # in `None` for credentials will cause issues when instantiating the DeltaTable without credentials.
+    #
+    # Thus, if we don't detect any credentials being available, we attempt to detect it from the environment using our Daft credentials chain.
+    #
+    # See: https://github.com/delta-io/delta-rs/issues/2117
+    deltalake_sdk_io_config = storage_config.io_config
+    scheme = urlparse(table_uri).scheme
+    if scheme == "s3" or scheme == "s3a":
+        # Try to get region from boto3
+        if deltalake_sdk_io_config.s3.region_name is None:
+            from botocore.exceptions import BotoCoreError
 
-class DeltaLakeScanOperator(ScanOperator):
-    def __init__(
-        self, table_uri: str, storage_config: StorageConfig, version: int | str | datetime | None = None
-    ) -> None:
-        super().__init__()
+            try:
+                client = boto3_client_from_s3_config("s3", deltalake_sdk_io_config.s3)
+                response = client.get_bucket_location(Bucket=urlparse(table_uri).netloc)
+            except BotoCoreError as e:
+                logger.warning(
+                    "Failed to get the S3 bucket region using existing storage config, will attempt to get it from the environment instead. Error from boto3: %s",
+                    e,
+                )
+            else:
+                deltalake_sdk_io_config = deltalake_sdk_io_config.replace(
+                    s3=deltalake_sdk_io_config.s3.replace(region_name=response["LocationConstraint"])
+                )
 
-        # Unfortunately delta-rs doesn't do very good inference of credentials for S3. Thus the current Daft behavior of passing
-        # in `None` for credentials will cause issues when instantiating the DeltaTable without credentials.
-        #
-        # Thus, if we don't detect any credentials being available, we attempt to detect it from the environment using our Daft credentials chain.
-        #
-        # See: https://github.com/delta-io/delta-rs/issues/2117
-        deltalake_sdk_io_config = storage_config.io_config
-        scheme = urlparse(table_uri).scheme
-        if scheme == "s3" or scheme == "s3a":
-            # Try to get region from boto3
-            if deltalake_sdk_io_config.s3.region_name is None:
-                from botocore.exceptions import BotoCoreError
-
-                try:
-                    client = boto3_client_from_s3_config("s3", deltalake_sdk_io_config.s3)
-                    response = client.get_bucket_location(Bucket=urlparse(table_uri).netloc)
-                except BotoCoreError as e:
-                    logger.warning(
-                        "Failed to get the S3 bucket region using existing storage config, will attempt to get it from the environment instead. Error from boto3: %s",
-                        e,
+        # Try to get config from the environment
+        if any([deltalake_sdk_io_config.s3.key_id is None, deltalake_sdk_io_config.s3.region_name is None]):
+            try:
+                s3_config_from_env = S3Config.from_env()
+            # Sometimes S3Config.from_env throws an error, for example on CI machines with weird metadata servers.
+            except daft.exceptions.DaftCoreException:
+                pass
+            else:
+                if (
+                    deltalake_sdk_io_config.s3.key_id is None
+                    and deltalake_sdk_io_config.s3.access_key is None
+                    and deltalake_sdk_io_config.s3.session_token is None
+                ):
+                    deltalake_sdk_io_config = deltalake_sdk_io_config.replace(
+                        s3=deltalake_sdk_io_config.s3.replace(
+                            key_id=s3_config_from_env.key_id,
+                            access_key=s3_config_from_env.access_key,
+                            session_token=s3_config_from_env.session_token,
+                        )
                     )
-                else:
+                if deltalake_sdk_io_config.s3.region_name is None:
                     deltalake_sdk_io_config = deltalake_sdk_io_config.replace(
-                        s3=deltalake_sdk_io_config.s3.replace(region_name=response["LocationConstraint"])
+                        s3=deltalake_sdk_io_config.s3.replace(
+                            region_name=s3_config_from_env.region_name,
+                        )
                     )
+    elif scheme == "gcs" or scheme == "gs":
+        # TO-DO: Handle any key-value replacements in `io_config` if there are missing elements
+        pass
+    elif scheme == "az" or scheme == "abfs" or scheme == "abfss":
+        # TO-DO: Handle any key-value replacements in `io_config` if there are missing elements
+        pass
 
-            # Try to get config from the environment
-            if any([deltalake_sdk_io_config.s3.key_id is None, deltalake_sdk_io_config.s3.region_name is None]):
-                try:
-                    s3_config_from_env = S3Config.from_env()
-                # Sometimes S3Config.from_env throws an error, for example on CI machines with weird metadata servers.
-                except daft.exceptions.DaftCoreException:
-                    pass
-                else:
-                    if (
-                        deltalake_sdk_io_config.s3.key_id is None
-                        and deltalake_sdk_io_config.s3.access_key is None
-                        and deltalake_sdk_io_config.s3.session_token is None
-                    ):
-                        deltalake_sdk_io_config = deltalake_sdk_io_config.replace(
-                            s3=deltalake_sdk_io_config.s3.replace(
-                                key_id=s3_config_from_env.key_id,
-                                access_key=s3_config_from_env.access_key,
-                                session_token=s3_config_from_env.session_token,
-                            )
-                        )
-                    if deltalake_sdk_io_config.s3.region_name is None:
-                        deltalake_sdk_io_config = deltalake_sdk_io_config.replace(
-                            s3=deltalake_sdk_io_config.s3.replace(
-                                region_name=s3_config_from_env.region_name,
-                            )
-                        )
-        elif scheme == "gcs" or scheme == "gs":
-            # TO-DO: Handle any key-value replacements in `io_config` if there are missing elements
-            pass
-        elif scheme == "az" or scheme == "abfs" or scheme == "abfss":
-            # TO-DO: Handle any key-value replacements in `io_config` if there are missing elements
-            pass
+    return io_config_to_storage_options(deltalake_sdk_io_config, table_uri)
+
+
+class DeltaLakeScanOperator(ScanOperator):
+    def __init__(
+        self,
+        table_uri: str,
+        storage_config: StorageConfig,
+        version: int | str | datetime | None = None,
+        start_version: int | str | datetime | None = None,
+        timestamp: str | datetime | None = None,
+    ) -> None:
+        super().__init__()
+
+        if timestamp is not None:
+            if version is not None:
+                raise ValueError("Only one of version and timestamp can be given to read a Delta Lake table")
+            version = timestamp
 
-        self._table = DeltaTable(
-            table_uri, storage_options=io_config_to_storage_options(deltalake_sdk_io_config, table_uri)
-        )
+        storage_options = delta_lake_storage_options(table_uri, storage_config)
+        self._table = DeltaTable(table_uri, storage_options=storage_options)
 
         if version is not None:
             self._table.load_as_version(version)
 
+        # Incremental reads diff the files of the table at `start_version` against those at `version`.
+        self._start_table = None
+        if start_version is not None:
+            self._start_table = DeltaTable(table_uri, storage_options=storage_options)
+            self._start_table.load_as_version(start_version)
+            if self._start_table.version() > self._table.version():
+                raise ValueError(
+                    f"The start version {self._start_table.version()} of the changes to read is after their end version {self._table.version()}"
+                )
+
         self._storage_config = storage_config
         self._schema = Schema.from_pyarrow_schema(self._table.schema().to_pyarrow())
         partition_columns = set(self._table.metadata().partition_columns)
         self._partition_keys = [
             PartitionField(field._field) for field in self._schema if field.name in partition_columns
         ]
+        if self._start_table is not None:
+            if CHANGE_TYPE_COLUMN_NAME in self._schema.column_names():
+                raise ValueError(
+                    f"Cannot read the changes of a Delta Lake table with a column named {CHANGE_TYPE_COLUMN_NAME}"
+                )
+            change_type_field = Field.create(CHANGE_TYPE_COLUMN_NAME, DataType.string())
+            self._schema = Schema._from_fields([*self._schema, change_type_field])
+            self._partition_keys.append(PartitionField(change_type_field._field))
 
     def schema(self) -> Schema:
         return self._schema
@@ -117,25 +155,68 @@ class DeltaLakeScanOperator(ScanOperator):
     def display_name(self) -> str:
         return f"DeltaLakeScanOperator({self._table.metadata().name})"
 
+    def snapshot_id(self) -> str | None:
+        return str(self._table.version())
+
     def partitioning_keys(self) -> list[PartitionField]:
         return self._partition_keys
 
     def multiline_display(self) -> list[str]:
-        return [
+        lines = [
             self.display_name(),
             f"Schema = {self._schema}",
             f"Partitioning keys = {self.partitioning_keys()}",
             # TODO(Clark): Improve repr of storage config here.
             f"Storage config = {self._storage_config}",
         ]
+        if self._start_table is not None:
+            lines.append(f"Changes = versions {self._start_table.version()} to {self._table.version()}")
+        return lines
+
+    @staticmethod
+    def _file_keys(add_actions: pa.RecordBatch) -> list[tuple[Any, ...]]:
+        """Returns the keys that identify the rows of the data files of a snapshot, which are their paths along with
+        their deletion vectors, since a file whose deletion vector changed holds different rows.
+        """
+        paths = add_actions["path"].to_pylist()
+        if "deletionVector" not in add_actions.schema.names:
+            return [(path,) for path in paths]
+        dvs = add_actions["deletionVector"].to_pylist()
+        return [
+            (path, None if dv is None else (dv.get("storageType"), dv.get("pathOrInlineDv"), dv.get("offset")))
+            for path, dv in zip(paths, dvs)
+        ]
+
+    def _planned_files(self) -> Iterator[tuple[pa.RecordBatch, int, str | None]]:
+        """Yields the add actions of the files to scan, as a batch of add actions with the index of the file in it,
+        along with the type of the change of the rows of the file for incremental reads.
+
+        Changes are tracked at the granularity of files, so the rows of files that were rewritten between the two
+        versions, such as by updates, compactions or deletion vectors, are read as deleted and then inserted again.
+        """
+        add_actions: pa.RecordBatch = self._table.get_add_actions()
+        if self._start_table is None:
+            for task_idx in range(add_actions.num_rows):
+                yield add_actions, task_idx, None
+            return
+
+        start_add_actions: pa.RecordBatch = self._start_table.get_add_actions()
+        start_keys = self._file_keys(start_add_actions)
+        end_keys = self._file_keys(add_actions)
+        start_key_set = set(start_keys)
+        end_key_set = set(end_keys)
+        for task_idx, key in enumerate(start_keys):
+            if key not in end_key_set:
+                yield start_add_actions, task_idx, "delete"
+        for task_idx, key in enumerate(end_keys):
+            if key not in start_key_set:
+                yield add_actions, task_idx, "insert"
 
     def to_scan_tasks(self, pushdowns: Pushdowns) -> Iterator[ScanTask]:
         import pyarrow as pa
 
         # TODO(Clark): Push limit and filter expressions into deltalake action fetch, to prune the files returned.
         # Issue: https://github.com/Eventual-Inc/Daft/issues/1953
-        add_actions: pa.RecordBatch = self._table.get_add_actions()
-
         if len(self.partitioning_keys()) > 0 and pushdowns.partition_filters is None:
             logger.warning(
                 "%s has partitioning keys = %s, but no partition filter was specified. This will result in a full table scan.",
@@ -143,25 +224,13 @@ class DeltaLakeScanOperator(ScanOperator):
                 self.partitioning_keys(),
             )
 
-        # TODO(Clark): Add support for deletion vectors.
-        # Issue: https://github.com/Eventual-Inc/Daft/issues/1954
-        if "deletionVector" in add_actions.schema.names:
-            raise NotImplementedError(
-                "Delta Lake deletion vectors are not yet supported; please let the Daft team know if you'd like to see this feature!\n"
-                "Deletion records can be dropped from this table to allow it to be read with Daft: https://docs.delta.io/latest/delta-drop-feature.html"
-            )
-
         # TODO(Clark): Add support for column mappings.
         # Issue: https://github.com/Eventual-Inc/Daft/issues/1955
 
         limit_files = pushdowns.limit is not None and pushdowns.filters is None and pushdowns.partition_filters is None
         rows_left = pushdowns.limit if pushdowns.limit is not None else 0
         scan_tasks = []
-        is_partitioned = (
-            "partition_values" in add_actions.schema.names
-            and add_actions.schema.field("partition_values").type.num_fields > 0
-        )
-        for task_idx in range(add_actions.num_rows):
+        for add_actions, task_idx, change_type in self._planned_files():
             if limit_files and rows_left <= 0:
                 break
 
@@ -177,12 +246,32 @@ class DeltaLakeScanOperator(ScanOperator):
                 size_bytes = add_actions["size_bytes"][task_idx].as_py()
             except KeyError:
                 size_bytes = None
+
+            # Rows deleted by a deletion vector are dropped while reading the file, by their positions in it.
+            deletion_vector = None
+            if "deletionVector" in add_actions.schema.names:
+                dv = add_actions["deletionVector"][task_idx].as_py()
+                if dv is not None and dv.get("storageType") is not None:
+                    deletion_vector = (
+                        self._table.table_uri,
+                        dv["storageType"],
+                        dv["pathOrInlineDv"],
+                        dv.get("offset"),
+                        dv["sizeInBytes"],
+                        dv["cardinality"],
+                    )
+                    if record_count is not None:
+                        record_count -= dv["cardinality"]
             file_format_config = FileFormatConfig.from_parquet_config(ParquetSourceConfig())
 
+            is_partitioned = (
+                "partition_values" in add_actions.schema.names
+                and add_actions.schema.field("partition_values").type.num_fields > 0
+            )
+            arrays = {}
             if is_partitioned:
                 dtype = add_actions.schema.field("partition_values").type
                 part_values = add_actions["partition_values"][task_idx]
-                arrays = {}
                 for field_idx in range(dtype.num_fields):
                     field_name = dtype.field(field_idx).name
                     try:
@@ -191,9 +280,9 @@ class DeltaLakeScanOperator(ScanOperator):
                         # pyarrow < 13.0.0 doesn't accept pyarrow scalars in the array constructor.
                         arrow_arr = pa.array([part_values[field_name].as_py()], type=dtype.field(field_idx).type)
                     arrays[field_name] = daft.Series.from_arrow(arrow_arr, field_name)
-                partition_values = daft.recordbatch.RecordBatch.from_pydict(arrays)._table
-            else:
-                partition_values = None
+            if change_type is not None:
+                arrays[CHANGE_TYPE_COLUMN_NAME] = daft.Series.from_pylist([change_type], CHANGE_TYPE_COLUMN_NAME)
+            partition_values = daft.recordbatch.RecordBatch.from_pydict(arrays)._table if arrays else None
 
             # Populate scan task with column-wise stats.
             schema_names = add_actions.schema.names
@@ -231,6 +320,7 @@ class DeltaLakeScanOperator(ScanOperator):
                 pushdowns=pushdowns,
                 partition_values=partition_values,
                 stats=stats._table if stats is not None else None,
+                delta_deletion_vector=deletion_vector,
             )
             if st is None:
                 continue
@@ -41,7 +41,7 @@ def partitioned_table_to_deltalake_iter(
         assert partition_strings is not None
 
         for part_table, part_strs in zip(partitioned.partitions(), partition_strings.to_pylist()):
-            part_path = partition_strings_to_path("", part_strs)
+            part_path = partition_strings_to_path("", part_strs, escape=False)
             converted_arrow_table = sanitize_table_for_deltalake(part_table, large_dtypes, partition_keys)
             yield converted_arrow_table, part_path, part_strs
     else:
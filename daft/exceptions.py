@@ -75,3 +75,12 @@ class MiscTransientError(DaftTransientError):
     """
 
     pass
+
+
+class QueryCancelledError(DaftCoreException):
+    """Daft Query Cancelled Error.
+
+    The query was cancelled before it finished, e.g. because it was interrupted or killed from a server.
+    """
+
+    pass
@@ -2,6 +2,7 @@ from __future__ import annotations
 
 import logging
 import multiprocessing as mp
+import os
 from typing import TYPE_CHECKING
 
 from daft.expressions import Expression, ExpressionsProjection
@@ -15,11 +16,16 @@ if TYPE_CHECKING:
 logger = logging.getLogger(__name__)
 
 
-def actor_event_loop(uninitialized_projection: ExpressionsProjection, conn: Connection) -> None:
+def actor_event_loop(
+    uninitialized_projection: ExpressionsProjection, conn: Connection, cuda_visible_devices: str | None
+) -> None:
     """Event loop that runs in a actor process and receives MicroPartitions to evaluate with an initialized UDF projection.
 
-    Terminates once it receives None.
+    The UDFs only see the GPUs in `cuda_visible_devices`, if given. Terminates once it receives None.
     """
+    if cuda_visible_devices is not None:
+        os.environ["CUDA_VISIBLE_DEVICES"] = cuda_visible_devices
+
     initialized_projection = ExpressionsProjection([e._initialize_udfs() for e in uninitialized_projection])
 
     while True:
@@ -34,11 +40,13 @@ def actor_event_loop(uninitialized_projection: ExpressionsProjection, conn: Conn
 class ActorHandle:
     """Handle class for initializing, interacting with, and tearing down a single local actor process."""
 
-    def __init__(self, projection: list[PyExpr]) -> None:
+    def __init__(self, projection: list[PyExpr], cuda_visible_devices: str | None = None) -> None:
         self.handle_conn, actor_conn = mp.Pipe()
 
         expr_projection = ExpressionsProjection([Expression._from_pyexpr(expr) for expr in projection])
-        self.actor_process = mp.Process(target=actor_event_loop, args=(expr_projection, actor_conn))
+        self.actor_process = mp.Process(
+            target=actor_event_loop, args=(expr_projection, actor_conn, cuda_visible_devices)
+        )
         self.actor_process.start()
 
     def eval_input(self, input: PyMicroPartition) -> PyMicroPartition:
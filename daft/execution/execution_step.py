@@ -93,6 +93,10 @@ class PartitionTask(Generic[PartitionT]):
         """
         raise NotImplementedError
 
+    def replace_result(self, result: list[MaterializedResult[PartitionT]]) -> None:
+        """Replace the result of this Task before it is done, e.g. with the result of a speculative copy of it that finished first."""
+        raise NotImplementedError
+
     def is_empty(self) -> bool:
         """Whether this partition task is guaranteed to result in an empty partition."""
         return len(self.partial_metadatas) > 0 and all(meta.num_rows == 0 for meta in self.partial_metadatas)
@@ -224,6 +228,11 @@ class SingleOutputPartitionTask(PartitionTask[PartitionT]):
         [partition] = result
         self._result = partition
 
+    def replace_result(self, result: list[MaterializedResult[PartitionT]]) -> None:
+        assert not self.done(), "Cannot replace the result of a PartitionTask that is done"
+        self._result = None
+        self.set_result(result)
+
     def result(self) -> MaterializedResult[PartitionT]:
         assert self._result is not None, "Cannot call .result() on a PartitionTask that is not done"
         return self._result
@@ -281,11 +290,20 @@ class MultiOutputPartitionTask(PartitionTask[PartitionT]):
         assert self._results is None, f"Cannot set result twice. Result is already {self._results}"
         self._results = result
 
+    def replace_result(self, result: list[MaterializedResult[PartitionT]]) -> None:
+        assert not self.done(), "Cannot replace the result of a PartitionTask that is done"
+        self._results = None
+        self.set_result(result)
+
     def cancel(self) -> None:
         if self._results is not None:
             for result in self._results:
                 result.cancel()
 
+    def results(self) -> list[MaterializedResult[PartitionT]]:
+        assert self._results is not None, "Cannot call .results() on a PartitionTask that has no result"
+        return self._results
+
     def partitions(self) -> list[PartitionT]:
         """Get the PartitionTs resulting from running this PartitionTask."""
         assert self._results is not None
@@ -1206,3 +1224,76 @@ class FanoutEvenSlices(FanoutInstruction):
             )
             for _ in range(self._num_outputs)
         ]
+
+
+@dataclass(frozen=True)
+class WriteShuffleMapOutput(Instruction):
+    """Partitions the input and writes the partitions to the directory of an external shuffle.
+
+    Outputs the manifest of the written files, followed by an empty partition with the schema of the input.
+    """
+
+    num_partitions: int
+    partition_by: ExpressionsProjection | None
+    shuffle_dir: str
+    map_idx: int
+    compression: str | None
+    compression_level: int | None
+    io_config: IOConfig | None
+
+    def run(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        return self._write_shuffle_map_output(inputs)
+
+    def _write_shuffle_map_output(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        from daft.daft import write_shuffle_map_output
+
+        [input] = inputs
+        if self.partition_by is None:
+            partitions = input.partition_by_random(num_partitions=self.num_partitions, seed=self.map_idx)
+        else:
+            partitions = input.partition_by_hash(self.partition_by, num_partitions=self.num_partitions)
+        manifest = write_shuffle_map_output(
+            [partition._micropartition for partition in partitions],
+            self.shuffle_dir,
+            self.map_idx,
+            compression=self.compression,
+            compression_level=self.compression_level,
+            io_config=self.io_config,
+        )
+        return [MicroPartition._from_pymicropartition(manifest), input.slice(0, 0)]
+
+    def run_partial_metadata(self, input_metadatas: list[PartialPartitionMetadata]) -> list[PartialPartitionMetadata]:
+        return [
+            PartialPartitionMetadata(num_rows=None, size_bytes=None),
+            PartialPartitionMetadata(num_rows=0, size_bytes=0),
+        ]
+
+    def num_outputs(self) -> int:
+        return 2
+
+
+@dataclass(frozen=True)
+class ReadShufflePartition(SingleOutputInstruction):
+    """Reads one output partition of an external shuffle, using the manifest to find its files."""
+
+    manifest_path: str
+    partition_idx: int
+    schema: Schema
+    num_rows: int
+    io_config: IOConfig | None
+
+    def run(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        return self._read_shuffle_partition(inputs)
+
+    def _read_shuffle_partition(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        from daft.daft import read_shuffle_partition
+
+        assert len(inputs) == 0
+        partition = read_shuffle_partition(
+            self.manifest_path, self.partition_idx, self.schema._schema, io_config=self.io_config
+        )
+        return [MicroPartition._from_pymicropartition(partition)]
+
+    def run_partial_metadata(self, input_metadatas: list[PartialPartitionMetadata]) -> list[PartialPartitionMetadata]:
+        assert len(input_metadatas) == 0
+        return [PartialPartitionMetadata(num_rows=self.num_rows, size_bytes=None)]
@@ -11,6 +11,7 @@ from daft.recordbatch import MicroPartition
 if TYPE_CHECKING:
     from daft.daft import PyDaftExecutionConfig
     from daft.logical.builder import LogicalPlanBuilder
+    from daft.progress import QueryProgressTracker
     from daft.runners.partitioning import (
         LocalMaterializedResult,
         MaterializedResult,
@@ -19,8 +20,9 @@ if TYPE_CHECKING:
 
 
 class NativeExecutor:
-    def __init__(self):
-        self._executor = _NativeExecutor()
+    def __init__(self, progress: QueryProgressTracker | None = None):
+        # Reports the progress of the operators of the query to the tracker, if given
+        self._executor = _NativeExecutor(progress)
 
     def run(
         self,
@@ -34,10 +36,29 @@ class NativeExecutor:
         psets_mp = {
             part_id: [part.micropartition()._micropartition for part in parts] for part_id, parts in psets.items()
         }
-        return (
-            LocalMaterializedResult(MicroPartition._from_pymicropartition(part))
-            for part in self._executor.run(builder._builder, psets_mp, daft_execution_config, results_buffer_size)
-        )
+        results = self._executor.run(builder._builder, psets_mp, daft_execution_config, results_buffer_size)
+
+        def results_gen() -> Iterator[LocalMaterializedResult]:
+            try:
+                for part in results:
+                    yield LocalMaterializedResult(MicroPartition._from_pymicropartition(part))
+            finally:
+                # Stop the query if its results are abandoned before they run out, e.g. because of an interrupt
+                results.cancel()
+
+        return results_gen()
+
+    def explain_analyze(
+        self,
+        builder: LogicalPlanBuilder,
+        psets: dict[str, list[MaterializedResult[PartitionT]]],
+        daft_execution_config: PyDaftExecutionConfig,
+    ) -> str:
+        """Runs the plan to completion and returns the physical plan annotated with per-operator runtime metrics."""
+        psets_mp = {
+            part_id: [part.micropartition()._micropartition for part in parts] for part_id, parts in psets.items()
+        }
+        return self._executor.explain_analyze(builder._builder, psets_mp, daft_execution_config)
 
     def pretty_print(
         self,
@@ -102,24 +102,28 @@ def actor_pool_project(
 
 class ShimExplodeOp(MapPartitionOp):
     explode_columns: ExpressionsProjection
+    unnest_structs: bool
 
-    def __init__(self, explode_columns: ExpressionsProjection) -> None:
+    def __init__(self, explode_columns: ExpressionsProjection, unnest_structs: bool = False) -> None:
         self.explode_columns = explode_columns
+        self.unnest_structs = unnest_structs
 
     def get_output_schema(self) -> Schema:
         raise NotImplementedError("Output schema shouldn't be needed at execution time")
 
     def run(self, input_partition: MicroPartition) -> MicroPartition:
-        return input_partition.explode(self.explode_columns)
+        return input_partition.explode(self.explode_columns, unnest_structs=self.unnest_structs)
 
 
 def explode(
-    input: physical_plan.InProgressPhysicalPlan[PartitionT], explode_exprs: list[PyExpr]
+    input: physical_plan.InProgressPhysicalPlan[PartitionT],
+    explode_exprs: list[PyExpr],
+    unnest_structs: bool = False,
 ) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
     explode_expr_projection = ExpressionsProjection(
         [Expression._from_pyexpr(expr)._explode() for expr in explode_exprs]
     )
-    explode_op = ShimExplodeOp(explode_expr_projection)
+    explode_op = ShimExplodeOp(explode_expr_projection, unnest_structs)
     return physical_plan.pipeline_instruction(
         child_plan=input,
         pipeable_instruction=execution_step.MapPartition(explode_op),
@@ -0,0 +1,118 @@
+import logging
+import shutil
+import uuid
+from collections import defaultdict
+from typing import Optional
+
+from daft.context import get_context
+from daft.daft import PyExpr, write_shuffle_manifest
+from daft.execution import execution_step
+from daft.execution.execution_step import MultiOutputPartitionTask, PartitionTaskBuilder
+from daft.execution.physical_plan import InProgressPhysicalPlan, stage_id_counter
+from daft.expressions import Expression, ExpressionsProjection
+from daft.runners.partitioning import PartitionT
+
+logger = logging.getLogger(__name__)
+
+
+def _local_path(path: str) -> Optional[str]:
+    """The path on the local filesystem, or None if the path is on an object store."""
+    if path.startswith("file://"):
+        return path[len("file://") :]
+    return None if "://" in path else path
+
+
+def external_shuffle(
+    fanout_plan: InProgressPhysicalPlan[PartitionT],
+    num_output_partitions: int,
+    shuffle_dir: str,
+    partition_by: Optional[list[PyExpr]] = None,
+    compression: Optional[str] = None,
+    compression_level: Optional[int] = None,
+) -> InProgressPhysicalPlan[PartitionT]:
+    """Shuffles through files in a shared directory, such as an object store or a shared volume.
+
+    Map tasks write their output partitions to files in the directory, and once every map task is done, a manifest
+    of the files is written next to them. Reduce tasks read their files through the manifest, so they don't depend on
+    the executors that ran the map tasks, and can be retried elsewhere when an executor is lost or the cluster scales
+    down.
+    """
+    map_stage_id = next(stage_id_counter)
+    stage_dir = f"{shuffle_dir.rstrip('/')}/daft_shuffle/{uuid.uuid4().hex}/shuffle_stage_{map_stage_id}"
+    io_config = get_context().daft_planning_config.default_io_config
+    partition_by_projection = (
+        ExpressionsProjection([Expression._from_pyexpr(expr) for expr in partition_by])
+        if partition_by is not None
+        else None
+    )
+
+    # Run the map phase
+    map_tasks: list[MultiOutputPartitionTask[PartitionT]] = []
+    for step in fanout_plan:
+        if isinstance(step, PartitionTaskBuilder):
+            step = step.add_instruction(
+                execution_step.WriteShuffleMapOutput(
+                    num_partitions=num_output_partitions,
+                    partition_by=partition_by_projection,
+                    shuffle_dir=stage_dir,
+                    map_idx=len(map_tasks),
+                    compression=compression,
+                    compression_level=compression_level,
+                    io_config=io_config,
+                )
+            ).finalize_partition_task_multi_output(stage_id=map_stage_id)
+            map_tasks.append(step)
+        yield step
+
+    while any(not task.done() for task in map_tasks):
+        logger.debug("external shuffle blocked on completion of map tasks: %s", map_tasks)
+        yield None
+
+    # Write the manifest, after which the reduce tasks no longer depend on the outputs of the map tasks
+    assert map_tasks, "External shuffle needs at least one map task"
+    manifests = [task.micropartition(0) for task in map_tasks]
+    schema = map_tasks[0].micropartition(1).schema()
+    del map_tasks
+    manifest_path = write_shuffle_manifest(
+        [manifest._micropartition for manifest in manifests], stage_dir, io_config=io_config
+    )
+    num_rows: dict[int, int] = defaultdict(int)
+    for manifest in manifests:
+        columns = manifest.to_pydict()
+        for partition_idx, partition_num_rows in zip(columns["partition"], columns["num_rows"]):
+            num_rows[partition_idx] += partition_num_rows
+
+    # Run the reduce phase
+    reduce_stage_id = next(stage_id_counter)
+    reduce_tasks = []
+    for partition_idx in range(num_output_partitions):
+        reduce_task = (
+            PartitionTaskBuilder[PartitionT](inputs=[], partial_metadatas=None)
+            .add_instruction(
+                execution_step.ReadShufflePartition(
+                    manifest_path=manifest_path,
+                    partition_idx=partition_idx,
+                    schema=schema,
+                    num_rows=num_rows[partition_idx],
+                    io_config=io_config,
+                )
+            )
+            .finalize_partition_task_single_output(stage_id=reduce_stage_id)
+        )
+        reduce_tasks.append(reduce_task)
+        yield reduce_task
+
+    while any(not task.done() for task in reduce_tasks):
+        logger.debug("external shuffle blocked on completion of reduce tasks: %s", reduce_tasks)
+        yield None
+
+    # Files on an object store are left to expire with the lifecycle policy of the bucket
+    local_dir = _local_path(stage_dir)
+    if local_dir is not None:
+        shutil.rmtree(local_dir, ignore_errors=True)
+
+    for reduce_task in reduce_tasks:
+        yield PartitionTaskBuilder[PartitionT](
+            inputs=[reduce_task.partition()],
+            partial_metadatas=[reduce_task.partition_metadata()],
+        )
@@ -6,7 +6,8 @@ import shutil
 from collections import defaultdict, deque
 from typing import Optional
 
-from daft.daft import InProgressShuffleCache, PyExpr, start_flight_server
+from daft.context import get_context
+from daft.daft import InProgressShuffleCache, IOConfig, PyExpr, start_flight_server
 from daft.execution.execution_step import (
     PartitionTaskBuilder,
     SingleOutputPartitionTask,
@@ -58,11 +59,17 @@ class ShuffleActorManager:
         shuffle_dirs: list[str],
         num_output_partitions: int,
         partition_by: Optional[list[PyExpr]] = None,
+        compression: Optional[str] = None,
+        compression_level: Optional[int] = None,
+        io_config: Optional[IOConfig] = None,
     ):
         self.shuffle_stage_id = shuffle_stage_id
         self.shuffle_dirs = shuffle_dirs
         self.num_output_partitions = num_output_partitions
         self.partition_by = partition_by
+        self.compression = compression
+        self.compression_level = compression_level
+        self.io_config = io_config
 
         self.all_actors: dict[str, ShuffleActor] = {}
         self.active_actors: dict[str, ShuffleActor] = {}
@@ -86,6 +93,9 @@ class ShuffleActorManager:
                 self.shuffle_dirs,
                 self.num_output_partitions,
                 self.partition_by,
+                self.compression,
+                self.compression_level,
+                self.io_config,
             )
             self.all_actors[node_id] = actor
         return self.all_actors[node_id]
@@ -171,6 +181,9 @@ class ShuffleActor:
         shuffle_dirs: list[str],
         num_output_partitions: int,
         partition_by: Optional[list[PyExpr]] = None,
+        compression: Optional[str] = None,
+        compression_level: Optional[int] = None,
+        io_config: Optional[IOConfig] = None,
     ):
         self.node_id = ray.get_runtime_context().get_node_id()
         self.host = ray.util.get_node_ip_address()
@@ -184,8 +197,10 @@ class ShuffleActor:
             num_output_partitions,
             self.shuffle_dirs,
             target_filesize=1024 * 1024 * 10,
-            compression=None,
+            compression=compression,
+            compression_level=compression_level,
             partition_by=partition_by,
+            io_config=io_config,
         )
 
         self.server = None
@@ -205,7 +220,7 @@ class ShuffleActor:
         self.port = self.server.port()
         self.in_progress_shuffle_cache = None
 
-    # Clean up the shuffle files for the given partition
+    # Clean up the shuffle files for the given partition. Files on an object store are left to expire.
     def clear_partition(self, partition_idx: int):
         for shuffle_dir in self.shuffle_dirs:
             path = os.path.join(shuffle_dir, f"partition_{partition_idx}")
@@ -433,6 +448,8 @@ def flight_shuffle(
     num_output_partitions: int,
     shuffle_dirs: list[str],
     partition_by: Optional[list[PyExpr]] = None,
+    compression: Optional[str] = None,
+    compression_level: Optional[int] = None,
 ):
     map_stage_id = next(stage_id_counter)
     shuffle_stage_id = next(stage_id_counter)
@@ -448,6 +465,9 @@ def flight_shuffle(
         shuffle_dirs,
         num_output_partitions,
         partition_by,
+        compression,
+        compression_level,
+        get_context().daft_planning_config.default_io_config,
     )
 
     # Run the map phase
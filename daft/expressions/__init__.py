@@ -1,5 +1,27 @@
 from __future__ import annotations
 
-from .expressions import Expression, ExpressionsProjection, col, list_, lit, interval, struct, coalesce
+from .expressions import (
+    Expression,
+    ExpressionsProjection,
+    accumulator,
+    col,
+    element,
+    list_,
+    lit,
+    interval,
+    struct,
+    coalesce,
+)
 
-__all__ = ["Expression", "ExpressionsProjection", "coalesce", "col", "interval", "list_", "lit", "struct"]
+__all__ = [
+    "Expression",
+    "ExpressionsProjection",
+    "accumulator",
+    "coalesce",
+    "col",
+    "element",
+    "interval",
+    "list_",
+    "lit",
+    "struct",
+]
@@ -40,6 +40,8 @@ from daft.daft import series_lit as _series_lit
 from daft.daft import struct as _struct
 from daft.daft import time_lit as _time_lit
 from daft.daft import timestamp_lit as _timestamp_lit
+from daft.daft import detokenize as _detokenize
+from daft.daft import tokenize as _tokenize
 from daft.daft import tokenize_decode as _tokenize_decode
 from daft.daft import tokenize_encode as _tokenize_encode
 from daft.daft import udf as _udf
@@ -159,6 +161,26 @@ def _resolved_col(name: str) -> Expression:
     return Expression._from_pyexpr(resolved_col(name))
 
 
+def element() -> Expression:
+    """Creates an Expression referring to the current element of a list, in the body of a list lambda.
+
+    See `Expression.list.map`, `Expression.list.filter` and `Expression.list.reduce`.
+
+    Returns:
+        Expression: Expression representing the current element
+    """
+    return Expression._from_pyexpr(native.element())
+
+
+def accumulator() -> Expression:
+    """Creates an Expression referring to the value accumulated so far, in the body of `Expression.list.reduce`.
+
+    Returns:
+        Expression: Expression representing the accumulated value
+    """
+    return Expression._from_pyexpr(native.accumulator())
+
+
 def list_(*items: Expression | str):
     """Constructs a list from the item expressions.
 
@@ -239,7 +261,7 @@ def struct(*fields: Expression | str) -> Expression:
 
 
 def interval(
-    years: int | None = None,
+    years: int | str | None = None,
     months: int | None = None,
     days: int | None = None,
     hours: int | None = None,
@@ -248,7 +270,35 @@ def interval(
     millis: int | None = None,
     nanos: int | None = None,
 ) -> Expression:
-    """Creates an Expression representing an interval."""
+    """Creates an Expression representing an interval.
+
+    The interval can be given either as its parts, or as a single string of ``<count> <unit>`` pairs such as
+    ``"3 days 4 hours"``. Intervals can be added to and subtracted from dates and timestamps.
+
+    Examples:
+        >>> import daft
+        >>> from datetime import datetime
+        >>> df = daft.from_pydict({"ts": [datetime(2024, 1, 15, 12)]})
+        >>> df = df.select(
+        ...     (df["ts"] + daft.interval(months=1)).alias("next_month"),
+        ...     (df["ts"] - daft.interval("3 days 4 hours")).alias("earlier"),
+        ... )
+        >>> df.show()
+        ╭───────────────────────────────┬───────────────────────────────╮
+        │ next_month                    ┆ earlier                       │
+        │ ---                           ┆ ---                           │
+        │ Timestamp(Microseconds, None) ┆ Timestamp(Microseconds, None) │
+        ╞═══════════════════════════════╪═══════════════════════════════╡
+        │ 2024-02-15 12:00:00           ┆ 2024-01-12 08:00:00           │
+        ╰───────────────────────────────┴───────────────────────────────╯
+        <BLANKLINE>
+        (Showing first 1 of 1 rows)
+
+    """
+    if isinstance(years, str):
+        if any(part is not None for part in (months, days, hours, minutes, seconds, millis, nanos)):
+            raise ValueError("An interval string cannot be combined with other interval parts")
+        return Expression._from_pyexpr(native.interval_lit_from_str(years))
     lit_value = native.interval_lit(
         years=years, months=months, days=days, hours=hours, minutes=minutes, seconds=seconds, millis=millis, nanos=nanos
     )
@@ -493,6 +543,11 @@ class Expression:
         expr = Expression._to_expression(other)
         return Expression._from_pyexpr(expr._expr | self._expr)
 
+    def __rxor__(self, other: Expression) -> Expression:
+        """Takes the logical reverse XOR of two boolean expressions, or bitwise XOR of two integer expressions (``e1 ^ e2``)."""
+        expr = Expression._to_expression(other)
+        return Expression._from_pyexpr(expr._expr ^ self._expr)
+
     def __lt__(self, other: Expression) -> Expression:
         """Compares if an expression is less than another (``e1 < e2``)."""
         expr = Expression._to_expression(other)
@@ -563,8 +618,18 @@ class Expression:
         expr = Expression._to_expression(other)
         return Expression._from_pyexpr(self._expr >> expr._expr)
 
+    def __rlshift__(self, other: Expression) -> Expression:
+        """Shifts the bits of an integer to the left by an integer expression (``e1 << e2``)."""
+        expr = Expression._to_expression(other)
+        return Expression._from_pyexpr(expr._expr << self._expr)
+
+    def __rrshift__(self, other: Expression) -> Expression:
+        """Shifts the bits of an integer to the right by an integer expression (``e1 >> e2``)."""
+        expr = Expression._to_expression(other)
+        return Expression._from_pyexpr(expr._expr >> self._expr)
+
     def __invert__(self) -> Expression:
-        """Inverts a boolean expression (``~e``)."""
+        """Inverts a boolean expression, or the bits of an integer expression (``~e``)."""
         expr = self._expr.__invert__()
         return Expression._from_pyexpr(expr)
 
@@ -739,6 +804,80 @@ class Expression:
         expr = self._expr.cast(dtype._dtype)
         return Expression._from_pyexpr(expr)
 
+    def try_cast(self, dtype: DataTypeLike) -> Expression:
+        """Casts an expression to the given datatype, yielding null for the values that can't be cast.
+
+        This is shorthand for ``expr.cast(dtype).try_()``, see `Expression.try_`.
+
+        Args:
+            dtype (DataTypeLike): the datatype to cast to, as for `Expression.cast`
+
+        Returns:
+            Expression: Expression with the specified new datatype
+        """
+        if isinstance(dtype, str):
+            dtype = DataType._from_pydatatype(sql_datatype(dtype))
+        else:
+            assert isinstance(dtype, (DataType, type))
+            dtype = DataType._infer_type(dtype)
+        expr = native.try_cast(self._expr, dtype._dtype)
+        return Expression._from_pyexpr(expr)
+
+    def try_(self) -> Expression:
+        """Makes this expression yield null for the rows it fails on, instead of failing the query.
+
+        The expression must be a function call such as ``to_date``, a cast, or a binary operation such as ``a / b``.
+        Only that outermost call is guarded, so errors in its inputs still fail the query. Batches without bad rows
+        are evaluated as usual; batches with bad rows are re-evaluated in smaller pieces to find them.
+
+        The number of rows that were nulled out can be read with `daft.functions.try_error_count`.
+
+        Returns:
+            Expression: An expression with the same type that is null wherever the original failed.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"date": ["2024-01-01", "not a date", None]})
+            >>> df = df.select(daft.col("date").str.to_date("%Y-%m-%d").try_())
+            >>> df.show()
+            ╭────────────╮
+            │ date       │
+            │ ---        │
+            │ Date       │
+            ╞════════════╡
+            │ 2024-01-01 │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ None       │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ None       │
+            ╰────────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        """
+        expr = native.try_(self._expr)
+        return Expression._from_pyexpr(expr)
+
+    def cast_to_categorical(self, categories: builtins.list[str]) -> Expression:
+        """Casts a string expression to a ``DataType.categorical`` of ``categories``.
+
+        Values that are not one of the categories become null. The categories have to be given up front, so that every
+        partition of the column encodes its values with the same codes.
+
+        Args:
+            categories: The distinct strings that values can take, in the order of their codes.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"size": ["small", "large", "small", "huge"]})
+            >>> df = df.select(daft.col("size").cast_to_categorical(["small", "large"]))
+            >>> df.to_pydict()
+            {'size': ['small', 'large', 'small', None]}
+        """
+        if not isinstance(categories, builtins.list):
+            raise TypeError(f"Expected a list of categories, but got: {type(categories)}")
+        return self.cast(DataType.categorical(categories))
+
     def ceil(self) -> Expression:
         """The ceiling of a numeric expression."""
         expr = native.ceil(self._expr)
@@ -951,6 +1090,10 @@ class Expression:
         expr = Expression._to_expression(other)
         return Expression._from_pyexpr(self._expr ^ expr._expr)
 
+    def bitwise_not(self) -> Expression:
+        """Bitwise NOT of an integer expression, which is the same as ``~e``."""
+        return Expression._from_pyexpr(self._expr.__invert__())
+
     def shift_left(self, other: Expression) -> Expression:
         """Shifts the bits of an integer expression to the left (``expr << other``).
 
@@ -973,6 +1116,34 @@ class Expression:
         expr = Expression._to_expression(other)
         return Expression._from_pyexpr(self._expr >> expr._expr)
 
+    def where(self, predicate: Expression) -> Expression:
+        """Restricts an aggregation to the rows that a predicate is true for, like SQL's ``FILTER (WHERE ...)``.
+
+        The input of the aggregation is nulled out on the other rows instead of filtering the table, so each
+        aggregation of a groupby can have its own predicate and they are still computed in a single pass. Rows where
+        the predicate is null are left out. Aggregations that keep nulls, such as ``agg_list``, can't be filtered.
+
+        Args:
+            predicate (Expression): a boolean expression over the rows being aggregated
+
+        Returns:
+            Expression: the aggregation over the rows that the predicate is true for
+
+        Examples:
+            >>> import daft
+            >>> from daft import col
+            >>> df = daft.from_pydict({"k": ["a", "a", "b"], "x": [1, 2, 3]})
+            >>> df = df.groupby("k").agg(
+            ...     col("x").sum().where(col("x") > 1).alias("big_sum"),
+            ...     col("x").count().where(col("x") > 1).alias("big_count"),
+            ...     (col("x") > 2).bool_or().alias("any_above_2"),
+            ... )
+            >>> df.sort("k").to_pydict()
+            {'k': ['a', 'b'], 'big_sum': [2, 3], 'big_count': [1, 1], 'any_above_2': [False, True]}
+        """
+        predicate = Expression._to_expression(predicate)
+        return Expression._from_pyexpr(self._expr.agg_filter(predicate._expr))
+
     def count(self, mode: Literal["all", "valid", "null"] | CountMode = CountMode.Valid) -> Expression:
         """Counts the number of values in the expression.
 
@@ -1020,6 +1191,31 @@ class Expression:
         expr = self._expr.approx_count_distinct()
         return Expression._from_pyexpr(expr)
 
+    def approx_top_k(self, k: builtins.int) -> Expression:
+        """Calculates the approximate `k` most frequent non-`NULL` values in the expression, with their counts.
+
+        Approximation is performed using the [Space-Saving](https://www.cs.ucsb.edu/sites/default/files/documents/2005-23.pdf)
+        algorithm, so it works over high-cardinality columns with bounded memory and merges across partitions.
+        Counts may be overestimated when the column has many distinct values of similar frequency.
+
+        Args:
+            k: The number of most frequent values to return.
+
+        Returns:
+            Expression: a list of up to `k` structs of `value` and `count`, in descending order of count.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"values": [1, 2, 1, None, 3, 1, 2]})
+            >>> df = df.agg(df["values"].approx_top_k(2).alias("top"))
+            >>> df.to_pydict()
+            {'top': [[{'value': 1, 'count': 3}, {'value': 2, 'count': 2}]]}
+        """
+        if k <= 0:
+            raise ValueError(f"approx_top_k expects a positive k, got {k}")
+        expr = self._expr.approx_top_k(k)
+        return Expression._from_pyexpr(expr)
+
     def approx_percentiles(self, percentiles: builtins.float | builtins.list[builtins.float]) -> Expression:
         """Calculates the approximate percentile(s) for a column of numeric values.
 
@@ -1088,6 +1284,35 @@ class Expression:
         expr = self._expr.approx_percentiles(percentiles)
         return Expression._from_pyexpr(expr)
 
+    def approx_quantiles(self, quantiles: builtins.list[builtins.float]) -> Expression:
+        """Calculates approximate quantiles for a column of numeric values.
+
+        This is like [approx_percentiles][daft.expressions.Expression.approx_percentiles], but always returns a
+        ``FixedSizeList[Float64; N]`` column, where ``N`` is the number of ``quantiles``. The values of each group are
+        summarized with a mergeable sketch, so the aggregation is computed per partition and then merged.
+
+        Args:
+            quantiles: the quantiles, between 0 and 1, at which to find approximate values.
+
+        Returns:
+            Expression: A new expression of type ``FixedSizeList[Float64; len(quantiles)]``, which is null for a group
+                whose values are all null.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"class": ["a", "a", "a", "b"], "scores": [1, 2, 3, None]})
+            >>> df = df.groupby("class").agg(df["scores"].approx_quantiles([0.0, 1.0])).sort("class")
+            >>> df.schema()["scores"].dtype
+            FixedSizeList[Float64; 2]
+            >>> [None if qs is None else [round(q) for q in qs] for qs in df.to_pydict()["scores"]]
+            [[1, 3], None]
+
+        """
+        if isinstance(quantiles, (builtins.float, builtins.int)):
+            raise TypeError("expected `quantiles` to be a list of floats, use approx_percentiles for a single quantile")
+        expr = self._expr.approx_percentiles(builtins.list(quantiles))
+        return Expression._from_pyexpr(expr)
+
     def mean(self) -> Expression:
         """Calculates the mean of the values in the expression."""
         expr = self._expr.mean()
@@ -1227,6 +1452,82 @@ class Expression:
         expr = self._expr.agg_concat()
         return Expression._from_pyexpr(expr)
 
+    def collect_list(
+        self, order_by: Expression | None = None, desc: bool = False, limit: int | None = None
+    ) -> Expression:
+        """Aggregates the values in the expression into a list, optionally ordered and capped in size.
+
+        Unlike :meth:`agg_list`, which keeps every value in input order, this can order each group's values by
+        another expression and keep only the first ``limit`` of them, which bounds the size of the lists for
+        skewed groups. Null values are kept.
+
+        Args:
+            order_by: The expression to order each group's values by. Nulls are ordered last, or first if ``desc``.
+                Defaults to the input order.
+            desc: Whether to order by ``order_by`` in descending order. Defaults to False.
+            limit: The maximum number of values to keep for each group. Defaults to no limit.
+
+        Returns:
+            Expression: A List expression containing the collected values.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"group": [1, 1, 1, 2], "value": ["a", "b", "c", "d"], "ts": [3, 1, 2, 1]})
+            >>> df = df.groupby("group").agg(
+            ...     df["value"].collect_list(order_by=df["ts"], desc=True, limit=2).alias("latest")
+            ... )
+            >>> df.sort("group").show()
+            ╭───────┬────────────╮
+            │ group ┆ latest     │
+            │ ---   ┆ ---        │
+            │ Int64 ┆ List[Utf8] │
+            ╞═══════╪════════════╡
+            │ 1     ┆ [a, c]     │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ 2     ┆ [d]        │
+            ╰───────┴────────────╯
+            <BLANKLINE>
+            (Showing first 2 of 2 rows)
+        """
+        order_by_expr = Expression._to_expression(order_by)._expr if order_by is not None else None
+        expr = self._expr.agg_collect(False, order_by_expr, desc, limit)
+        return Expression._from_pyexpr(expr)
+
+    def collect_set(
+        self, order_by: Expression | None = None, desc: bool = False, limit: int | None = None
+    ) -> Expression:
+        """Aggregates the distinct values in the expression into a list, optionally ordered and capped in size.
+
+        Like :meth:`agg_set`, null values are ignored. Each value is kept at the position of its first occurrence in
+        the order.
+
+        Args:
+            order_by: The expression to order each group's values by. Nulls are ordered last, or first if ``desc``.
+                Defaults to the input order.
+            desc: Whether to order by ``order_by`` in descending order. Defaults to False.
+            limit: The maximum number of distinct values to keep for each group. Defaults to no limit.
+
+        Returns:
+            Expression: A List expression containing the collected distinct values.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"value": [3, 1, 3, None, 2], "ts": [1, 2, 3, 4, 5]})
+            >>> df.agg(df["value"].collect_set(order_by=df["ts"], limit=2).alias("first_two")).show()
+            ╭─────────────╮
+            │ first_two   │
+            │ ---         │
+            │ List[Int64] │
+            ╞═════════════╡
+            │ [3, 1]      │
+            ╰─────────────╯
+            <BLANKLINE>
+            (Showing first 1 of 1 rows)
+        """
+        order_by_expr = Expression._to_expression(order_by)._expr if order_by is not None else None
+        expr = self._expr.agg_collect(True, order_by_expr, desc, limit)
+        return Expression._from_pyexpr(expr)
+
     def _explode(self) -> Expression:
         expr = native.explode(self._expr)
         return Expression._from_pyexpr(expr)
@@ -1416,6 +1717,11 @@ class Expression:
     def is_in(self, other: Any) -> Expression:
         """Checks if values in the Expression are in the provided list.
 
+        Args:
+            other: The values to check membership in. This is either a collection of values or expressions, a Series,
+                or a DataFrame with a single column. Filtering on membership in a DataFrame's column is planned as a
+                semi join, so it scales to membership sources of any size.
+
         Returns:
             Expression: Boolean Expression indicating whether values are in the provided list
 
@@ -1439,8 +1745,17 @@ class Expression:
             (Showing first 3 of 3 rows)
 
         """
-        if isinstance(other, Collection):
-            other = [Expression._to_expression(item) for item in other]
+        from daft.dataframe import DataFrame
+
+        if isinstance(other, DataFrame):
+            return other._builder.in_subquery(self)
+        elif isinstance(other, Collection):
+            items = list(other)
+            if items and not any(isinstance(item, Expression) for item in items):
+                # plain values are gathered into a single Series literal, which is evaluated and hashed once per
+                # partition rather than as one literal expression per value
+                items = [item_to_series("items", items)]
+            other = [Expression._to_expression(item) for item in items]
         elif not isinstance(other, Expression):
             series = item_to_series("items", other)
             other = [Expression._to_expression(series)]
@@ -1632,6 +1947,47 @@ class Expression:
         expr = self._expr.over(window._spec)
         return Expression._from_pyexpr(expr)
 
+    def lag(self, offset: int = 1, default: Any | None = None) -> Expression:
+        """Get the value of this expression from ``offset`` rows before the current row (used for window functions).
+
+        Args:
+            offset (int): how many rows to look back. Defaults to 1.
+            default: value for rows without a row ``offset`` rows before them. Defaults to None (null).
+
+        Returns:
+            Expression: An expression that must be evaluated over a window with an order by.
+
+        Examples:
+            >>> from daft import Window, col
+            >>> window = Window().partition_by("category").order_by("ts")
+            >>> expr = col("value").lag(1, default=0).over(window)
+        """
+        return self._offset(-offset, default)
+
+    def lead(self, offset: int = 1, default: Any | None = None) -> Expression:
+        """Get the value of this expression from ``offset`` rows after the current row (used for window functions).
+
+        Args:
+            offset (int): how many rows to look ahead. Defaults to 1.
+            default: value for rows without a row ``offset`` rows after them. Defaults to None (null).
+
+        Returns:
+            Expression: An expression that must be evaluated over a window with an order by.
+
+        Examples:
+            >>> from daft import Window, col
+            >>> window = Window().partition_by("category").order_by("ts")
+            >>> expr = col("value").lead(1).over(window)
+        """
+        return self._offset(offset, default)
+
+    def _offset(self, offset: int, default: Any | None) -> Expression:
+        if not isinstance(offset, int):
+            raise TypeError(f"Expected an integer offset, but received: {type(offset)}")
+        default_expr = None if default is None else Expression._to_expression(default)._expr
+        expr = self._expr.offset(offset, default_expr)
+        return Expression._from_pyexpr(expr)
+
     def __repr__(self) -> builtins.str:
         return repr(self._expr)
 
@@ -1805,6 +2161,72 @@ class ExpressionUrlNamespace(ExpressionNamespace):
             )
         )
 
+    def scheme(self) -> Expression:
+        """Extracts the scheme of each URL, such as ``https``.
+
+        Strings that are not absolute URLs, such as relative paths, yield null.
+
+        Returns:
+            Expression: a String expression with the lowercase scheme of each URL
+        """
+        return Expression._from_pyexpr(native.url_scheme(self._expr))
+
+    def host(self) -> Expression:
+        """Extracts the host of each URL, without its port.
+
+        URLs without a host, such as ``mailto:`` URLs, and strings that are not absolute URLs yield null.
+
+        Returns:
+            Expression: a String expression with the lowercase host of each URL
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"url": ["https://www.Example.com:8080/a?b=c", "not a url"]})
+            >>> df.select(df["url"].url.host()).to_pydict()
+            {'url': ['www.example.com', None]}
+        """
+        return Expression._from_pyexpr(native.url_host(self._expr))
+
+    def path(self) -> Expression:
+        """Extracts the path of each URL, keeping its percent-encoding.
+
+        The path of URLs like ``https://example.com`` is ``/``. Strings that are not absolute URLs yield null.
+
+        Returns:
+            Expression: a String expression with the path of each URL
+        """
+        return Expression._from_pyexpr(native.url_path(self._expr))
+
+    def query_param(self, name: str) -> Expression:
+        """Extracts the value of the first query parameter of each URL called ``name``.
+
+        Values are decoded like form data, so ``+`` becomes a space. URLs without the parameter and strings that are
+        not absolute URLs yield null.
+
+        Args:
+            name: The name of the query parameter to extract.
+
+        Returns:
+            Expression: a String expression with the decoded value of the parameter
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"url": ["https://example.com/search?q=daft+rs&page=2", "https://example.com"]})
+            >>> df.select(df["url"].url.query_param("q")).to_pydict()
+            {'url': ['daft rs', None]}
+        """
+        return Expression._from_pyexpr(native.url_query_param(self._expr, name))
+
+    def decode(self) -> Expression:
+        """Decodes the percent-encoded characters of each string, such as ``%20``.
+
+        Unlike :meth:`query_param`, ``+`` is left as is. Strings that do not decode to valid UTF-8 raise an error.
+
+        Returns:
+            Expression: a String expression with the decoded strings
+        """
+        return Expression._from_pyexpr(native.url_decode(self._expr))
+
 
 class ExpressionFloatNamespace(ExpressionNamespace):
     """The following methods are available under the `expr.float` attribute."""
@@ -2394,6 +2816,33 @@ class ExpressionDatetimeNamespace(ExpressionNamespace):
         """
         return Expression._from_pyexpr(native.dt_day_of_year(self._expr))
 
+    def week_of_year(self) -> Expression:
+        """Retrieves the ISO 8601 week of the year for a datetime column, from 1 to 53.
+
+        Weeks start on Monday, and the first week of a year is the one that contains its first Thursday,
+        so the first days of January may belong to the last week of the previous year.
+
+        Returns:
+            Expression: a UInt32 expression with just the week of the year extracted from a datetime column
+        """
+        return Expression._from_pyexpr(native.dt_week_of_year(self._expr))
+
+    def quarter(self) -> Expression:
+        """Retrieves the quarter of the year for a datetime column, from 1 to 4.
+
+        Returns:
+            Expression: a UInt32 expression with just the quarter extracted from a datetime column
+        """
+        return Expression._from_pyexpr(native.dt_quarter(self._expr))
+
+    def last_day_of_month(self) -> Expression:
+        """Retrieves the last day of the month for a datetime column as a date.
+
+        Returns:
+            Expression: a Date expression with the last day of the month of each value
+        """
+        return Expression._from_pyexpr(native.dt_last_day_of_month(self._expr))
+
     def truncate(self, interval: str, relative_to: Expression | None = None) -> Expression:
         """Truncates the datetime column to the specified interval.
 
@@ -2524,6 +2973,69 @@ class ExpressionDatetimeNamespace(ExpressionNamespace):
         """
         return Expression._from_pyexpr(native.dt_strftime(self._expr, format))
 
+    def date_add(self, n: int | Expression, unit: str) -> Expression:
+        """Shifts a date or datetime column forward by ``n`` units.
+
+        Shifting by months, quarters or years keeps the day of the month, clamping it to the last day of shorter
+        months, so that 2024-01-31 plus one month is 2024-02-29. Shifts by days or larger units keep the local
+        time of datetimes with a timezone, while smaller units are exact durations.
+
+        Args:
+            n: The number of units to shift by, which may be negative.
+            unit: One of 'nanosecond', 'microsecond', 'millisecond', 'second', 'minute', 'hour', 'day', 'week',
+                'month', 'quarter' or 'year', optionally in plural. Dates can only be shifted by days or larger units.
+
+        Returns:
+            Expression: an expression of the same type as the column
+
+        Examples:
+            >>> import daft, datetime
+            >>> df = daft.from_pydict({"date": [datetime.date(2024, 1, 31), datetime.date(2024, 3, 31)]})
+            >>> df.select(df["date"].dt.date_add(1, "month")).to_pydict()
+            {'date': [datetime.date(2024, 2, 29), datetime.date(2024, 4, 30)]}
+        """
+        n = Expression._to_expression(n)
+        return Expression._from_pyexpr(native.dt_date_add(self._expr, n._expr, unit))
+
+    def date_sub(self, n: int | Expression, unit: str) -> Expression:
+        """Shifts a date or datetime column back by ``n`` units.
+
+        See :meth:`date_add <daft.expressions.expressions.ExpressionDatetimeNamespace.date_add>` for the units
+        and how months are shifted.
+
+        Args:
+            n: The number of units to shift by, which may be negative.
+            unit: The unit to shift by, such as 'day' or 'months'.
+
+        Returns:
+            Expression: an expression of the same type as the column
+        """
+        n = Expression._to_expression(n)
+        return Expression._from_pyexpr(native.dt_date_sub(self._expr, n._expr, unit))
+
+    def date_diff(self, start: Expression, unit: str) -> Expression:
+        """Returns the number of whole units from ``start`` to this column, truncated towards zero.
+
+        A month is only counted once the day and time of ``start`` is reached again, clamped to the end of shorter
+        months, so there is one month from 2024-01-31 to 2024-02-29. ``start`` is cast to the type of this column.
+
+        Args:
+            start: The dates or datetimes to count from.
+            unit: The unit to count in, as accepted by
+                :meth:`date_add <daft.expressions.expressions.ExpressionDatetimeNamespace.date_add>`.
+
+        Returns:
+            Expression: an Int64 expression
+
+        Examples:
+            >>> import daft, datetime
+            >>> df = daft.from_pydict({"start": [datetime.date(2024, 1, 15)], "end": [datetime.date(2024, 4, 14)]})
+            >>> df.select(df["end"].dt.date_diff(df["start"], "months").alias("months")).to_pydict()
+            {'months': [2]}
+        """
+        start = Expression._to_expression(start)
+        return Expression._from_pyexpr(native.dt_date_diff(self._expr, start._expr, unit))
+
 
 class ExpressionStringNamespace(ExpressionNamespace):
     """The following methods are available under the `expr.str` attribute."""
@@ -2859,13 +3371,19 @@ class ExpressionStringNamespace(ExpressionNamespace):
         pattern: str | Expression,
         replacement: str | Expression,
         regex: bool = False,
+        max_replacements: int | None = None,
     ) -> Expression:
-        """Replaces all occurrences of a pattern in a string column with a replacement string. The pattern can be a literal string or a regex pattern.
+        r"""Replaces all occurrences of a pattern in a string column with a replacement string. The pattern can be a literal string or a regex pattern.
+
+        With a regex pattern, the replacement can refer to capture groups as ``$1``, ``${1}`` or ``${name}``, and
+        ``$$`` stands for a literal ``$``. Use the braced form when a group is followed by letters, digits or
+        underscores, since ``$1a`` refers to a group named ``1a``.
 
         Args:
             pattern: The pattern to replace
             replacement: The replacement string
             regex: Whether the pattern is a regex pattern or an exact match. Defaults to False.
+            max_replacements: The maximum number of occurrences to replace in each string. Defaults to all of them.
 
         Returns:
             Expression: a String expression with patterns replaced by the replacement string
@@ -2907,11 +3425,30 @@ class ExpressionStringNamespace(ExpressionNamespace):
             <BLANKLINE>
             (Showing first 3 of 3 rows)
 
+            Reorder the parts of a date with capture groups
+
+            >>> import daft
+            >>> df = daft.from_pydict({"data": ["2024-01-02", "2025-03-04"]})
+            >>> df.with_column("replace", df["data"].str.replace(r"(\d+)-(\d+)-(\d+)", "$3/$2/$1", regex=True)).collect()
+            ╭────────────┬────────────╮
+            │ data       ┆ replace    │
+            │ ---        ┆ ---        │
+            │ Utf8       ┆ Utf8       │
+            ╞════════════╪════════════╡
+            │ 2024-01-02 ┆ 02/01/2024 │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ 2025-03-04 ┆ 04/03/2025 │
+            ╰────────────┴────────────╯
+            <BLANKLINE>
+            (Showing first 2 of 2 rows)
+
         """
+        if max_replacements is not None and max_replacements < 0:
+            raise ValueError(f"max_replacements must be non-negative, got {max_replacements}")
         pattern_expr = Expression._to_expression(pattern)
         replacement_expr = Expression._to_expression(replacement)
         return Expression._from_pyexpr(
-            native.utf8_replace(self._expr, pattern_expr._expr, replacement_expr._expr, regex)
+            native.utf8_replace(self._expr, pattern_expr._expr, replacement_expr._expr, regex, max_replacements)
         )
 
     def length(self) -> Expression:
@@ -3626,6 +4163,42 @@ class ExpressionStringNamespace(ExpressionNamespace):
         """
         return Expression._from_pyexpr(_tokenize_decode(self._expr, tokens_path, io_config, pattern, special_tokens))
 
+    def tokenize(
+        self,
+        tokenizer: str,
+        *,
+        io_config: IOConfig | None = None,
+        use_special_tokens: bool = True,
+    ) -> Expression:
+        """Encodes each string as a list of token IDs using a HuggingFace tokenizer.
+
+        The tokenizer is loaded from a HuggingFace ``tokenizer.json`` file, which can be a local path, a URL, a directory
+        containing a ``tokenizer.json``, or the name of a model on the HuggingFace Hub such as ``"gpt2"``. Currently only
+        byte-level BPE tokenizers are supported, such as those of GPT-2, Llama 3 and Qwen 2.
+
+        Args:
+            tokenizer: The path, URL or HuggingFace Hub model name of the tokenizer.
+            io_config (optional): IOConfig to use when accessing remote storage.
+            use_special_tokens (optional): Whether to encode the added special tokens of the tokenizer that appear in the
+                input as single tokens. Enabled by default.
+
+        Returns:
+            Expression: An expression with the encodings of the strings as lists of 32-bit integers.
+
+        Note:
+            The tokens that the post-processor of the tokenizer adds around each input, such as a beginning-of-sequence
+            token, are not added.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"text": ["hello world"]})
+            >>> df = df.with_column("ids", df["text"].str.tokenize("gpt2"))  # doctest: +SKIP
+
+        Tip: See Also
+            [Expression.list.detokenize](https://www.getdaft.io/projects/docs/en/stable/api/expressions/#daft.expressions.expressions.ExpressionListNamespace.detokenize)
+        """
+        return Expression._from_pyexpr(_tokenize(self._expr, tokenizer, use_special_tokens, io_config))
+
     def count_matches(
         self,
         patterns: Any,
@@ -3786,6 +4359,78 @@ class ExpressionListNamespace(ExpressionNamespace):
             raise ValueError(f"Invalid value for `size`: {size}")
         return Expression._from_pyexpr(native.list_chunk(self._expr, size))
 
+    def map(self, body: Expression) -> Expression:
+        """Evaluates an expression on each element of each list.
+
+        The body refers to the current element with `daft.element()`, and can't refer to other columns. It is
+        evaluated once over the elements of all the lists, rather than once per list.
+
+        Args:
+            body: an expression of `daft.element()`
+
+        Returns:
+            Expression: an expression with lists of the type of the body
+
+        Examples:
+            >>> import daft
+            >>> from daft import col, element
+            >>> df = daft.from_pydict({"xs": [[1, 2], [3], None]})
+            >>> df.select(col("xs").list.map(element() * 2)).show()
+            ╭─────────────╮
+            │ xs          │
+            │ ---         │
+            │ List[Int64] │
+            ╞═════════════╡
+            │ [2, 4]      │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ [6]         │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ None        │
+            ╰─────────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        """
+        body = Expression._to_expression(body)
+        return Expression._from_pyexpr(native.list_map(self._expr, body._expr))
+
+    def filter(self, predicate: Expression) -> Expression:
+        """Keeps the elements of each list that a predicate is true for.
+
+        The predicate refers to the current element with `daft.element()`, and can't refer to other columns.
+
+        Args:
+            predicate: a boolean expression of `daft.element()`
+
+        Returns:
+            Expression: an expression with lists of the type of the list values
+        """
+        predicate = Expression._to_expression(predicate)
+        return Expression._from_pyexpr(native.list_filter(self._expr, predicate._expr))
+
+    def reduce(self, body: Expression) -> Expression:
+        """Folds the elements of each list from left to right, starting with the first element.
+
+        The body combines the value accumulated so far, `daft.accumulator()`, with the next element,
+        `daft.element()`. Empty lists and nulls reduce to null. The body is evaluated once for each position in the
+        longest list, over all the lists that are that long.
+
+        Args:
+            body: an expression of `daft.accumulator()` and `daft.element()`
+
+        Returns:
+            Expression: an expression with the type of the body
+
+        Examples:
+            >>> import daft
+            >>> from daft import accumulator, col, element
+            >>> df = daft.from_pydict({"xs": [[1, 2, 3], [4], []]})
+            >>> df.select(col("xs").list.reduce(accumulator() * element())).to_pydict()
+            {'xs': [6, 4, None]}
+        """
+        body = Expression._to_expression(body)
+        return Expression._from_pyexpr(native.list_reduce(self._expr, body._expr))
+
     def sum(self) -> Expression:
         """Sums each list. Empty lists and lists with all nulls yield null.
 
@@ -4013,6 +4658,26 @@ class ExpressionListNamespace(ExpressionNamespace):
         """
         return self.distinct()
 
+    def detokenize(self, tokenizer: str, *, io_config: IOConfig | None = None) -> Expression:
+        """Decodes each list of token IDs into a string using a HuggingFace tokenizer.
+
+        The tokenizer is loaded the same way as in [Expression.str.tokenize](https://www.getdaft.io/projects/docs/en/stable/api/expressions/#daft.expressions.expressions.ExpressionStringNamespace.tokenize).
+        Byte sequences that aren't valid UTF-8 are decoded with replacement characters.
+
+        Args:
+            tokenizer: The path, URL or HuggingFace Hub model name of the tokenizer.
+            io_config (optional): IOConfig to use when accessing remote storage.
+
+        Returns:
+            Expression: An expression with the decoded strings.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"ids": [[31373, 995]]})
+            >>> df = df.with_column("text", df["ids"].list.detokenize("gpt2"))  # doctest: +SKIP
+        """
+        return Expression._from_pyexpr(_detokenize(self._expr, tokenizer, io_config))
+
 
 class ExpressionStructNamespace(ExpressionNamespace):
     """The following methods are available under the `expr.struct` attribute."""
@@ -4028,6 +4693,59 @@ class ExpressionStructNamespace(ExpressionNamespace):
         """
         return Expression._from_pyexpr(self._expr.struct_get(name))
 
+    def with_field(self, name: str, value: Expression | Any) -> Expression:
+        """Sets one field of a struct column, adding the field if it doesn't exist.
+
+        Args:
+            name: the name of the field to set
+            value: the value of the field, as an expression or a literal
+
+        Returns:
+            Expression: the struct expression with the field set
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"s": [{"a": 1, "b": "x"}, {"a": 2, "b": "y"}]})
+            >>> s = df["s"].struct.with_field("a", df["s"].struct.get("a") * 10)
+            >>> df = df.select(s.struct.with_field("c", True))
+            >>> df.select(df["s"].struct.get("*")).collect()
+            ╭───────┬──────┬─────────╮
+            │ a     ┆ b    ┆ c       │
+            │ ---   ┆ ---  ┆ ---     │
+            │ Int64 ┆ Utf8 ┆ Boolean │
+            ╞═══════╪══════╪═════════╡
+            │ 10    ┆ x    ┆ true    │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┤
+            │ 20    ┆ y    ┆ true    │
+            ╰───────┴──────┴─────────╯
+            <BLANKLINE>
+            (Showing first 2 of 2 rows)
+        """
+        value_expr = Expression._to_expression(value)
+        return Expression._from_pyexpr(self._expr.struct_with_field(name, value_expr._expr))
+
+    def drop(self, *names: str) -> Expression:
+        """Removes fields from a struct column.
+
+        Args:
+            *names: the names of the fields to remove
+
+        Returns:
+            Expression: the struct expression without the fields
+        """
+        return Expression._from_pyexpr(self._expr.struct_drop(list(names)))
+
+    def rename(self, mapping: dict[str, str]) -> Expression:
+        """Renames fields of a struct column.
+
+        Args:
+            mapping: a mapping from the current names of fields to their new names
+
+        Returns:
+            Expression: the struct expression with the fields renamed
+        """
+        return Expression._from_pyexpr(self._expr.struct_rename(list(mapping.items())))
+
 
 class ExpressionMapNamespace(ExpressionNamespace):
     """The following methods are available under the `expr.map` attribute."""
@@ -4187,7 +4905,7 @@ class ExpressionImageNamespace(ExpressionNamespace):
     ) -> Expression:
         """Decodes the binary data in this column into images.
 
-        This can only be applied to binary columns that contain encoded images (e.g. PNG, JPEG, etc.)
+        This can only be applied to binary columns that contain encoded images (e.g. PNG, JPEG, WebP, etc.)
 
         Args:
             on_error: Whether to raise when encountering an error, or log a warning and return a null
@@ -4370,6 +5088,47 @@ class ExpressionJsonNamespace(ExpressionNamespace):
         """
         return Expression._from_pyexpr(native.json_query(self._expr, jq_query))
 
+    def extract(self, path: str, dtype: DataTypeLike | None = None) -> Expression:
+        """Extracts the value at a JSONPath from JSON strings, such as ``$.a.b[0]``.
+
+        Supports ``$``, ``.name``, ``['name']``, ``[n]`` (negative indices count from the end), ``[*]``, ``.*``
+        and ``..name``. Paths that start with ``/`` are treated as JSON pointers instead, such as ``/a/b/0``.
+
+        Args:
+            path (str): JSONPath or JSON pointer to extract
+            dtype (DataTypeLike, optional): the datatype to cast the extracted values to, defaults to strings
+
+        Returns:
+            Expression: Expression with the extracted values. Strings are returned without their quotes and
+                other values as JSON. Missing paths yield null, and paths with wildcards or ``..`` yield a JSON
+                array of all the values they match.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"col": ['{"a": {"b": 1}}', '{"a": {"b": 2}}', '{"a": {}}']})
+            >>> df.with_column("res", df["col"].json.extract("$.a.b", daft.DataType.int64())).collect()
+            ╭─────────────────┬───────╮
+            │ col             ┆ res   │
+            │ ---             ┆ ---   │
+            │ Utf8            ┆ Int64 │
+            ╞═════════════════╪═══════╡
+            │ {"a": {"b": 1}} ┆ 1     │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+            │ {"a": {"b": 2}} ┆ 2     │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+            │ {"a": {}}       ┆ None  │
+            ╰─────────────────┴───────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        """
+        if isinstance(dtype, str):
+            dtype = DataType._from_pydatatype(sql_datatype(dtype))
+        elif dtype is not None:
+            assert isinstance(dtype, (DataType, type))
+            dtype = DataType._infer_type(dtype)
+        return Expression._from_pyexpr(native.json_extract(self._expr, path, dtype._dtype if dtype else None))
+
 
 class ExpressionEmbeddingNamespace(ExpressionNamespace):
     """The following methods are available under the `expr.embedding` attribute."""
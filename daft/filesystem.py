@@ -10,7 +10,7 @@ from datetime import datetime, timezone
 from typing import Any
 
 from daft.convert import from_pydict
-from daft.daft import FileFormat, FileInfos, IOConfig, io_glob
+from daft.daft import FileFormat, FileInfos, HDFSConfig, IOConfig, SFTPConfig, io_glob
 from daft.dependencies import fsspec, pafs
 from daft.expressions.expressions import col
 from daft.recordbatch import MicroPartition
@@ -304,6 +304,50 @@ def _infer_filesystem(
         resolved_path = resolved_filesystem.normalize_path(_unwrap_protocol(resolved_path))
         return resolved_path, resolved_filesystem, None
 
+    ###
+    # HDFS: Use FSSpec's WebHDFS filesystem as a fallback
+    ###
+    elif protocol in {"hdfs", "webhdfs", "swebhdfs"}:
+        fsspec_fs_cls = fsspec.get_filesystem_class("webhdfs")
+        hdfs_config = io_config.hdfs if io_config is not None else HDFSConfig()
+        parsed = urllib.parse.urlparse(path, allow_fragments=False)
+        if hdfs_config.webhdfs_address is not None:
+            address = urllib.parse.urlparse(hdfs_config.webhdfs_address)
+            host, port, use_https = address.hostname, address.port, address.scheme == "https"
+        else:
+            host = parsed.hostname
+            port = parsed.port if protocol != "hdfs" and parsed.port is not None else hdfs_config.webhdfs_port
+            use_https = protocol == "swebhdfs" or hdfs_config.use_https
+        fsspec_fs = fsspec_fs_cls(
+            host=host,
+            port=port,
+            use_https=use_https,
+            user=hdfs_config.user,
+            token=hdfs_config.delegation_token,
+        )
+        resolved_filesystem, resolved_path = pafs._resolve_filesystem_and_path(parsed.path, fsspec_fs)
+        resolved_path = resolved_filesystem.normalize_path(resolved_path)
+        return resolved_path, resolved_filesystem, None
+
+    ###
+    # SFTP: Use FSSpec's SFTP filesystem as a fallback
+    ###
+    elif protocol == "sftp":
+        fsspec_fs_cls = fsspec.get_filesystem_class("sftp")
+        sftp_config = io_config.sftp if io_config is not None else SFTPConfig()
+        parsed = urllib.parse.urlparse(path, allow_fragments=False)
+        fsspec_fs = fsspec_fs_cls(
+            host=parsed.hostname,
+            port=parsed.port or 22,
+            username=parsed.username or sftp_config.user,
+            password=sftp_config.password,
+            key_filename=sftp_config.private_key_path,
+            passphrase=sftp_config.private_key_passphrase,
+        )
+        resolved_filesystem, resolved_path = pafs._resolve_filesystem_and_path(parsed.path, fsspec_fs)
+        resolved_path = resolved_filesystem.normalize_path(resolved_path)
+        return resolved_path, resolved_filesystem, None
+
     else:
         raise NotImplementedError(f"Cannot infer PyArrow filesystem for protocol {protocol}: please file an issue!")
 
@@ -327,7 +371,7 @@ def glob_path_with_stats(
 ) -> FileInfos:
     """Glob a path, returning a FileInfos."""
     files = io_glob(path, io_config=io_config)
-    filepaths_to_infos = {f["path"]: {"size": f["size"], "type": f["type"]} for f in files}
+    filepaths_to_infos = {f["path"]: f for f in files}
 
     # Set number of rows if available.
     if file_format is not None and file_format == FileFormat.Parquet:
@@ -341,12 +385,28 @@ def glob_path_with_stats(
     file_paths = []
     file_sizes = []
     num_rows = []
+    last_modified = []
+    etags = []
+    version_ids = []
+    storage_classes = []
     for path, infos in filepaths_to_infos.items():
         file_paths.append(path)
         file_sizes.append(infos.get("size"))
         num_rows.append(infos.get("rows"))
-
-    return FileInfos.from_infos(file_paths=file_paths, file_sizes=file_sizes, num_rows=num_rows)
+        last_modified.append(infos.get("last_modified"))
+        etags.append(infos.get("etag"))
+        version_ids.append(infos.get("version_id"))
+        storage_classes.append(infos.get("storage_class"))
+
+    return FileInfos.from_infos(
+        file_paths=file_paths,
+        file_sizes=file_sizes,
+        num_rows=num_rows,
+        last_modified=last_modified,
+        etags=etags,
+        version_ids=version_ids,
+        storage_classes=storage_classes,
+    )
 
 
 ###
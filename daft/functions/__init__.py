@@ -7,7 +7,20 @@ from .functions import (
     columns_avg,
     columns_min,
     columns_max,
+    count_if,
+    hash,
+    make_date,
+    make_timestamp,
+    sum_if,
     row_number,
+    rank,
+    dense_rank,
+    native_udf,
+    load_native_udfs,
+    registered_native_udfs,
+    try_error_count,
+    reset_try_error_count,
+    uuid,
 )
 from .llm_generate import llm_generate
 
@@ -17,7 +30,20 @@ __all__ = [
     "columns_mean",
     "columns_min",
     "columns_sum",
+    "count_if",
+    "dense_rank",
+    "hash",
     "llm_generate",
+    "load_native_udfs",
+    "make_date",
+    "make_timestamp",
     "monotonically_increasing_id",
+    "native_udf",
+    "rank",
+    "registered_native_udfs",
+    "reset_try_error_count",
     "row_number",
+    "sum_if",
+    "try_error_count",
+    "uuid",
 ]
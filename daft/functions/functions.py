@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 import daft.daft as native
-from daft.expressions import Expression, list_
+from daft.expressions import Expression, col, list_
 
 
 def monotonically_increasing_id() -> Expression:
@@ -41,6 +41,55 @@ def monotonically_increasing_id() -> Expression:
     return Expression._from_pyexpr(native.monotonically_increasing_id())
 
 
+def hash(*exprs: Expression | str, seed: int = 0) -> Expression:
+    """Hashes the values of several columns into a single ``UInt64`` hash per row.
+
+    Uses the [XXH3_64bits](https://xxhash.com/) hash function over a platform-independent encoding of the values,
+    so the hashes are stable and can be stored, for example as surrogate keys or to assign rows to buckets.
+    Hashing a single column gives the same hashes as `Expression.hash` with the same seed.
+
+    Args:
+        exprs: The columns to hash.
+        seed: Seed used for generating the hashes. Defaults to 0.
+
+    Note:
+        Null values produce a hash value instead of being propagated as null.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import hash
+        >>> df = daft.from_pydict({"a": [1, 2, 1], "b": ["x", "y", "x"]})
+        >>> df = df.with_column("key", hash("a", "b"))
+        >>> df.show()  # doctest: +SKIP
+
+    """
+    if not exprs:
+        raise ValueError("hash requires at least one expression")
+    pyexprs = [col(e)._expr if isinstance(e, str) else e._expr for e in exprs]
+    return Expression._from_pyexpr(native.hash_columns(pyexprs, seed))
+
+
+def uuid(version: int = 4) -> Expression:
+    """Generates a new UUID string for every row.
+
+    Args:
+        version: 4 for random UUIDs, or 7 for UUIDs that start with a millisecond timestamp, so that they sort by
+            the time they were generated. Defaults to 4.
+
+    Returns:
+        Expression: A string expression of UUIDs, such as ``67e55044-10b1-426f-9247-bb680e5fe0c8``
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import uuid
+        >>> df = daft.from_pydict({"a": [1, 2, 3]})
+        >>> df = df.with_column("id", uuid())
+        >>> df.show()  # doctest: +SKIP
+
+    """
+    return Expression._from_pyexpr(native.uuid(str(version)))
+
+
 def columns_sum(*exprs: Expression | str) -> Expression:
     """Sum values across columns.
 
@@ -196,6 +245,86 @@ def columns_max(*exprs: Expression | str) -> Expression:
     return list_(*exprs).list.max().alias("columns_max")
 
 
+def sum_if(expr: Expression | str, predicate: Expression) -> Expression:
+    """Sums an expression over the rows that a predicate is true for, see `Expression.where`.
+
+    Args:
+        expr: the expression or column name to sum
+        predicate: a boolean expression over the rows being aggregated
+
+    Returns:
+        Expression: the sum, which is null when the predicate isn't true for any row
+    """
+    expr = col(expr) if isinstance(expr, str) else expr
+    return expr.sum().where(predicate)
+
+
+def count_if(predicate: Expression) -> Expression:
+    """Counts the rows that a predicate is true for, see `Expression.where`.
+
+    Args:
+        predicate: a boolean expression over the rows being aggregated
+
+    Returns:
+        Expression: the number of rows, named after the predicate
+    """
+    return predicate.count().where(predicate)
+
+
+def make_date(
+    year: Expression | int,
+    month: Expression | int,
+    day: Expression | int,
+) -> Expression:
+    """Builds a date from integer years, months and days, failing on dates that do not exist.
+
+    Args:
+        year: The year of each date.
+        month: The month of each date, from 1 to 12.
+        day: The day of the month of each date.
+
+    Returns:
+        Expression: a Date expression
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import make_date
+        >>> df = daft.from_pydict({"y": [2024, 2023], "m": [2, 12], "d": [29, 31]})
+        >>> df.select(make_date(df["y"], df["m"], df["d"]).alias("date")).to_pydict()
+        {'date': [datetime.date(2024, 2, 29), datetime.date(2023, 12, 31)]}
+    """
+    year, month, day = (Expression._to_expression(e) for e in (year, month, day))
+    return Expression._from_pyexpr(native.make_date(year._expr, month._expr, day._expr))
+
+
+def make_timestamp(
+    year: Expression | int,
+    month: Expression | int,
+    day: Expression | int,
+    hour: Expression | int,
+    minute: Expression | int,
+    second: Expression | float,
+    timezone: str | None = None,
+) -> Expression:
+    """Builds a microsecond datetime from the parts of its local date and time, failing on ones that do not exist.
+
+    Args:
+        year: The year of each datetime.
+        month: The month of each datetime, from 1 to 12.
+        day: The day of the month of each datetime.
+        hour: The hour of each datetime, from 0 to 23.
+        minute: The minute of each datetime, from 0 to 59.
+        second: The second of each datetime, which may be fractional.
+        timezone: The timezone that the parts are local to. Times that are ambiguous in it resolve to their earliest
+            instant, while times that it skips become null. Without a timezone, the datetimes are naive.
+
+    Returns:
+        Expression: a Timestamp expression
+    """
+    parts = [Expression._to_expression(e)._expr for e in (year, month, day, hour, minute, second)]
+    return Expression._from_pyexpr(native.make_timestamp(*parts, timezone=timezone))
+
+
 def row_number() -> Expression:
     """Return the row number of the current row (used for window functions).
 
@@ -237,3 +366,89 @@ def row_number() -> Expression:
         Expression: An expression that returns the row number of the current row.
     """
     return Expression._from_pyexpr(native.row_number())
+
+
+def rank() -> Expression:
+    """Return the rank of the current row (used for window functions).
+
+    Rows with equal values in the order by columns share a rank, and the ranks after them skip
+    ahead by the number of tied rows, like ``RANK`` in SQL.
+
+    Example:
+        >>> from daft.window import Window
+        >>> from daft.functions import rank
+        >>> window = Window().partition_by("category").order_by("value")
+        >>> expr = rank().over(window)
+
+    Returns:
+        Expression: An expression that returns the rank of the current row.
+    """
+    return Expression._from_pyexpr(native.rank())
+
+
+def dense_rank() -> Expression:
+    """Return the dense rank of the current row (used for window functions).
+
+    Rows with equal values in the order by columns share a rank, and the ranks after them continue
+    from the next integer, like ``DENSE_RANK`` in SQL.
+
+    Example:
+        >>> from daft.window import Window
+        >>> from daft.functions import dense_rank
+        >>> window = Window().partition_by("category").order_by("value")
+        >>> expr = dense_rank().over(window)
+
+    Returns:
+        Expression: An expression that returns the dense rank of the current row.
+    """
+    return Expression._from_pyexpr(native.dense_rank())
+
+
+def native_udf(name: str, *args: Expression) -> Expression:
+    """Call the native Rust UDF registered under ``name`` on the given expressions.
+
+    Native UDFs are kernels written in Rust against Daft's ``NativeScalarUdf`` trait, which skip the Python UDF
+    path entirely. They can also be called by name from SQL.
+
+    Args:
+        name: the name the UDF is registered under, see :func:`load_native_udfs`
+        *args: the expressions to pass to the UDF
+
+    Returns:
+        Expression: An expression that calls the UDF.
+    """
+    return Expression._from_pyexpr(native.native_udf(name, [Expression._to_expression(arg)._expr for arg in args]))
+
+
+def load_native_udfs(path: str) -> list[str]:
+    """Load the native Rust UDFs exported by a shared library with ``daft_functions::export_native_udfs!``.
+
+    The library runs arbitrary code when it is loaded, and must be built against the same version of Daft with
+    the same Rust compiler. UDFs are only registered on the current process, so libraries must be loaded on every
+    worker that evaluates them.
+
+    Args:
+        path: the path to the shared library
+
+    Returns:
+        list[str]: The names of the UDFs that were registered.
+    """
+    return native.load_native_udfs(path)
+
+
+def registered_native_udfs() -> list[str]:
+    """Return the names of all registered native Rust UDFs, in sorted order."""
+    return native.registered_native_udfs()
+
+
+def try_error_count() -> int:
+    """Returns the number of rows that ``try_`` expressions have nulled out in this process, see `Expression.try_`.
+
+    Each worker of a distributed job keeps its own count, so this only covers the rows evaluated in this process.
+    """
+    return native.try_error_count()
+
+
+def reset_try_error_count() -> int:
+    """Resets the count returned by :func:`try_error_count` to zero, returning the previous count."""
+    return native.reset_try_error_count()
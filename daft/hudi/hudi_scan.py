@@ -33,6 +33,12 @@ class HudiScanOperator(ScanOperator):
         self._partition_keys = [
             PartitionField(field._field) for field in self._schema if field.name in partition_fields
         ]
+        if self._table.props.is_merge_on_read:
+            logger.warning(
+                "%s is a Merge-on-Read table, which is read with a read-optimized query: only its base files are read, "
+                "so updates and deletes in log files that haven't been compacted yet are not visible.",
+                self.display_name(),
+            )
 
     def schema(self) -> Schema:
         return self._schema
@@ -51,6 +57,7 @@ class HudiScanOperator(ScanOperator):
             self.display_name(),
             f"Schema = {self._schema}",
             f"Partitioning keys = {self.partitioning_keys()}",
+            f"Table type = {self._table.props.table_type}",
             f"Storage config = {self._storage_config}",
         ]
 
@@ -39,13 +39,20 @@ class MetaClient:
         common_prefix_len = len(self.base_path) + 1 if relative else 0
         return [p[common_prefix_len:] for p in partition_paths]
 
-    def get_file_groups(self, partition_path: str) -> list[FileGroup]:
+    def get_file_groups(self, partition_path: str, replaced_file_group_ids: set[tuple[str, str]]) -> list[FileGroup]:
+        timeline = self.get_active_timeline()
         base_file_metadata = list_relative_file_paths(
             self.base_path, partition_path, self.fs, includes=BASE_FILE_EXTENSIONS
         )
         fg_id_to_base_files = defaultdict(list)
         for metadata in base_file_metadata:
             base_file = BaseFile(metadata)
+            # Base files of pending or failed writes, and file groups replaced by clustering or overwrites, aren't
+            # part of the latest snapshot of the table.
+            if not timeline.is_completed(base_file.commit_time):
+                continue
+            if (partition_path, base_file.file_group_id) in replaced_file_group_ids:
+                continue
             fg_id_to_base_files[base_file.file_group_id].append(base_file)
         file_groups = []
         for fg_id, base_files in fg_id_to_base_files.items():
@@ -64,15 +71,16 @@ class FileSystemView:
         self._load_partitions()
 
     def _load_partitions(self):
+        replaced_file_group_ids = self.meta_client.get_active_timeline().get_replaced_file_group_ids()
         partition_paths = self.meta_client.get_partition_paths()
         if not partition_paths:
-            self._load_partition("")
+            self._load_partition("", replaced_file_group_ids)
         else:
             for partition_path in partition_paths:
-                self._load_partition(partition_path)
+                self._load_partition(partition_path, replaced_file_group_ids)
 
-    def _load_partition(self, partition_path: str):
-        file_groups = self.meta_client.get_file_groups(partition_path)
+    def _load_partition(self, partition_path: str, replaced_file_group_ids: set[tuple[str, str]]):
+        file_groups = self.meta_client.get_file_groups(partition_path, replaced_file_group_ids)
         self.partition_to_file_groups[partition_path] = file_groups
 
     def get_latest_file_slices(self) -> list[FileSlice]:
@@ -113,6 +121,14 @@ class HudiTableProps:
 
         return self._props["hoodie.table.partition.fields"].split(",")
 
+    @property
+    def table_type(self) -> str:
+        return self._props["hoodie.table.type"]
+
+    @property
+    def is_merge_on_read(self) -> bool:
+        return self.table_type == "MERGE_ON_READ"
+
     @property
     def keygen_classname(self) -> str:
         return self._props["hoodie.table.keygenerator.class"]
@@ -131,8 +147,8 @@ class HudiTableProps:
         return self._props[key]
 
     def _validate(self):
-        if self.get_required_config("hoodie.table.type") != "COPY_ON_WRITE":
-            raise UnsupportedException("Only support COPY_ON_WRITE table")
+        if self.get_required_config("hoodie.table.type") not in ["COPY_ON_WRITE", "MERGE_ON_READ"]:
+            raise UnsupportedException("Only support COPY_ON_WRITE and MERGE_ON_READ tables")
         if self.get_required_config("hoodie.table.version") not in ["5", "6"]:
             raise UnsupportedException("Only support table version 5 and 6")
         if self.get_required_config("hoodie.timeline.layout.version") != "1":
@@ -30,11 +30,16 @@ class Instant:
         return [self.timestamp, self.state] < [other.timestamp, other.state]
 
 
+# Actions whose completed instants write base files, and thus the file slices that snapshot reads see.
+WRITE_ACTIONS = {"commit", "deltacommit", "replacecommit"}
+
+
 @dataclass(init=False)
 class Timeline:
     base_path: str
     fs: pafs.FileSystem
     completed_commit_instants: list[Instant]
+    earliest_timestamp: str | None
 
     def __init__(self, base_path: str, fs: pafs.FileSystem):
         self.base_path = base_path
@@ -47,31 +52,58 @@ class Timeline:
 
     def _load_completed_commit_instants(self):
         timeline_path = join_path(self.fs, self.base_path, ".hoodie")
-        write_action_exts = {".commit"}
         commit_instants = []
+        timestamps = []
         for file_info in self.fs.get_file_info(pafs.FileSelector(timeline_path)):
-            ext = os.path.splitext(file_info.base_name)[1]
-            if ext in write_action_exts:
-                timestamp = file_info.base_name[: -len(ext)]
-                instant = Instant(state=State.COMPLETED, action=ext[1:], timestamp=timestamp)
-                commit_instants.append(instant)
+            if file_info.type != pafs.FileType.File:
+                continue
+            # Instants are named `<timestamp>.<action>`, with a `.requested` or `.inflight` suffix until they complete.
+            timestamp, _, action = file_info.base_name.partition(".")
+            if not timestamp.isdigit():
+                continue
+            timestamps.append(timestamp)
+            if action in WRITE_ACTIONS:
+                commit_instants.append(Instant(state=State.COMPLETED, action=action, timestamp=timestamp))
         self.completed_commit_instants = sorted(commit_instants)
+        self.earliest_timestamp = min(timestamps) if timestamps else None
+
+    def is_completed(self, timestamp: str) -> bool:
+        """Whether the write at `timestamp` completed, which instants archived out of the active timeline did."""
+        if self.earliest_timestamp is not None and timestamp < self.earliest_timestamp:
+            return True
+        return any(instant.timestamp == timestamp for instant in self.completed_commit_instants)
+
+    def _read_commit_metadata(self, instant: Instant) -> dict:
+        instant_file_path = join_path(self.fs, self.base_path, ".hoodie", instant.file_name)
+        with self.fs.open_input_file(instant_file_path) as f:
+            return json.load(f)
 
     def get_latest_commit_metadata(self) -> dict:
         if not self.has_completed_commit:
             return {}
-        latest_instant_file_path = join_path(
-            self.fs, self.base_path, ".hoodie", self.completed_commit_instants[-1].file_name
-        )
-        with self.fs.open_input_file(latest_instant_file_path) as f:
-            return json.load(f)
+        return self._read_commit_metadata(self.completed_commit_instants[-1])
+
+    def get_replaced_file_group_ids(self) -> set[tuple[str, str]]:
+        """The partition paths and IDs of the file groups that completed replace commits replaced."""
+        replaced = set()
+        for instant in self.completed_commit_instants:
+            if instant.action != "replacecommit":
+                continue
+            metadata = self._read_commit_metadata(instant)
+            for partition_path, file_group_ids in (metadata.get("partitionToReplaceFileIds") or {}).items():
+                replaced.update((partition_path, file_group_id) for file_group_id in file_group_ids)
+        return replaced
 
     def get_latest_commit_schema(self) -> pa.Schema:
-        latest_commit_metadata = self.get_latest_commit_metadata()
-        if not latest_commit_metadata.get("partitionToWriteStats"):
-            return pa.schema([])
-
-        _, write_stats = next(iter(latest_commit_metadata["partitionToWriteStats"].items()))
-        base_file_path = join_path(self.fs, self.base_path, write_stats[0]["path"])
-        with self.fs.open_input_file(base_file_path) as f:
-            return pq.read_schema(f)
+        # Delta commits of Merge-on-Read tables may only have written log files, so the schema is read from the base
+        # file written by the latest commit that wrote one.
+        for instant in reversed(self.completed_commit_instants):
+            metadata = self._read_commit_metadata(instant)
+            for write_stats in (metadata.get("partitionToWriteStats") or {}).values():
+                for write_stat in write_stats:
+                    if os.path.splitext(write_stat["path"])[1] != ".parquet":
+                        continue
+                    base_file_path = join_path(self.fs, self.base_path, write_stat["path"])
+                    with self.fs.open_input_file(base_file_path) as f:
+                        return pq.read_schema(f)
+        return pa.schema([])
@@ -2,11 +2,16 @@ from __future__ import annotations
 
 import logging
 import warnings
-from typing import TYPE_CHECKING
+from collections import defaultdict
+from datetime import datetime, timedelta, timezone
+from itertools import chain
+from typing import TYPE_CHECKING, Any
 
 from pyiceberg.io.pyarrow import schema_to_pyarrow
+from pyiceberg.manifest import DataFileContent
 from pyiceberg.schema import Schema as IcebergSchema
 from pyiceberg.schema import visit
+from pyiceberg.utils.concurrent import ExecutorFactory
 
 import daft
 from daft.daft import (
@@ -26,6 +31,7 @@ from daft.logical.schema import Field, Schema
 if TYPE_CHECKING:
     from collections.abc import Iterator
 
+    from pyiceberg.manifest import DataFile, ManifestEntry
     from pyiceberg.partitioning import PartitionField as IcebergPartitionField
     from pyiceberg.partitioning import PartitionSpec as IcebergPartitionSpec
     from pyiceberg.table import Table
@@ -33,6 +39,12 @@ if TYPE_CHECKING:
 
 logger = logging.getLogger(__name__)
 
+# The id of the column of positional delete files with the paths of the data files they delete rows from.
+_POSITIONAL_DELETE_FILE_PATH_ID = 2147483546
+
+# The column that incremental reads return the type of the change of each row in, which is either "insert" or "delete".
+CHANGE_TYPE_COLUMN_NAME = "_change_type"
+
 
 def _iceberg_partition_field_to_daft_partition_field(
     iceberg_schema: IcebergSchema, pfield: IcebergPartitionField
@@ -90,15 +102,48 @@ def _iceberg_partition_field_to_daft_partition_field(
     return make_partition_field(result_field, daft_field, transform=tfm)
 
 
+def timestamp_ms(timestamp: int | str | datetime) -> int:
+    """Milliseconds since the epoch of a timestamp, given as milliseconds, an ISO 8601 string, or a datetime that's UTC unless it has a timezone."""
+    if isinstance(timestamp, int):
+        return timestamp
+    if isinstance(timestamp, str):
+        # Python < 3.11 doesn't parse the "Z" suffix for UTC.
+        timestamp = datetime.fromisoformat(timestamp.replace("Z", "+00:00"))
+    if timestamp.tzinfo is None:
+        timestamp = timestamp.replace(tzinfo=timezone.utc)
+    return (timestamp - datetime(1970, 1, 1, tzinfo=timezone.utc)) // timedelta(milliseconds=1)
+
+
+def snapshot_id_as_of(table: Table, as_of: int | str | datetime) -> int:
+    """The id of the snapshot that was the current snapshot of a table at a point in time, going by the table's history."""
+    as_of_ms = timestamp_ms(as_of)
+    snapshot_ids = [entry.snapshot_id for entry in table.history() if entry.timestamp_ms <= as_of_ms]
+    if not snapshot_ids:
+        raise ValueError(f"The Iceberg table has no snapshot as of {as_of}")
+    return snapshot_ids[-1]
+
+
 def iceberg_partition_spec_to_fields(iceberg_schema: IcebergSchema, spec: IcebergPartitionSpec) -> list[PartitionField]:
     return [_iceberg_partition_field_to_daft_partition_field(iceberg_schema, field) for field in spec.fields]
 
 
 class IcebergScanOperator(ScanOperator):
-    def __init__(self, iceberg_table: Table, snapshot_id: int | None, storage_config: StorageConfig) -> None:
+    def __init__(
+        self,
+        iceberg_table: Table,
+        snapshot_id: int | None,
+        storage_config: StorageConfig,
+        start_snapshot_id: int | None = None,
+        as_of: int | str | datetime | None = None,
+    ) -> None:
         super().__init__()
+        if as_of is not None:
+            if snapshot_id is not None:
+                raise ValueError("Only one of snapshot_id and as_of can be given to read an Iceberg table")
+            snapshot_id = snapshot_id_as_of(iceberg_table, as_of)
         self._table = iceberg_table
         self._snapshot_id = snapshot_id
+        self._start_snapshot_id = start_snapshot_id
         self._storage_config = storage_config
 
         iceberg_schema = (
@@ -106,11 +151,20 @@ class IcebergScanOperator(ScanOperator):
             if self._snapshot_id is None
             else self._table.scan(snapshot_id=self._snapshot_id).projection()
         )
+        self._iceberg_schema = iceberg_schema
         arrow_schema = schema_to_pyarrow(iceberg_schema)
         self._field_id_mapping = visit(iceberg_schema, SchemaFieldIdMappingVisitor())
         self._schema = Schema.from_pyarrow_schema(arrow_schema)
 
         self._partition_keys = iceberg_partition_spec_to_fields(iceberg_schema, self._table.spec())
+        if self._start_snapshot_id is not None:
+            if CHANGE_TYPE_COLUMN_NAME in self._schema.column_names():
+                raise ValueError(
+                    f"Cannot read the changes of an Iceberg table with a column named {CHANGE_TYPE_COLUMN_NAME}"
+                )
+            change_type_field = Field.create(CHANGE_TYPE_COLUMN_NAME, DataType.string())
+            self._schema = Schema._from_fields([*self._schema, change_type_field])
+            self._partition_keys.append(make_partition_field(change_type_field))
 
     def schema(self) -> Schema:
         return self._schema
@@ -118,6 +172,12 @@ class IcebergScanOperator(ScanOperator):
     def name(self) -> str:
         return "IcebergScanOperator"
 
+    def snapshot_id(self) -> str | None:
+        if self._snapshot_id is not None:
+            return str(self._snapshot_id)
+        snapshot = self._table.current_snapshot()
+        return str(snapshot.snapshot_id) if snapshot is not None else None
+
     def display_name(self) -> str:
         return f"IcebergScanOperator({'.'.join(self._table.name())})"
 
@@ -125,7 +185,7 @@ class IcebergScanOperator(ScanOperator):
         return self._partition_keys
 
     def _iceberg_record_to_partition_spec(
-        self, spec: IcebergPartitionSpec, record: Record
+        self, spec: IcebergPartitionSpec, record: Record, change_type: str | None = None
     ) -> daft.recordbatch.RecordBatch | None:
         partition_fields = iceberg_partition_spec_to_fields(self._table.schema(), spec)
         arrays = dict()
@@ -138,23 +198,161 @@ class IcebergScanOperator(ScanOperator):
             arrays[field_name] = daft.Series.from_arrow(pa.array([record[idx]], type=arrow_type), name=field_name).cast(
                 field_dtype
             )
+        if change_type is not None:
+            arrays[CHANGE_TYPE_COLUMN_NAME] = daft.Series.from_pylist([change_type], name=CHANGE_TYPE_COLUMN_NAME)
         if len(arrays) > 0:
             return daft.recordbatch.RecordBatch.from_pydict(arrays)
         else:
             return None
 
     def multiline_display(self) -> list[str]:
-        return [
+        lines = [
             self.display_name(),
             f"Schema = {self._schema}",
             f"Partitioning keys = {self.partitioning_keys}",
             # TODO(Clark): Improve repr of storage config here.
             f"Storage config = {self._storage_config}",
         ]
+        if self._start_snapshot_id is not None:
+            lines.append(f"Changes = snapshots {self._start_snapshot_id} to {self.snapshot_id()}")
+        return lines
+
+    def _partition_key(self, data_file: DataFile) -> tuple[int, tuple[Any, ...]]:
+        partition = data_file.partition
+        return data_file.spec_id, tuple(partition[idx] for idx in range(len(partition)))
+
+    def _equality_columns(self, delete_file: DataFile) -> list[str]:
+        top_level_fields = {field.field_id: field.name for field in self._iceberg_schema.fields}
+        columns = []
+        for field_id in delete_file.equality_ids or []:
+            if field_id not in top_level_fields:
+                raise NotImplementedError(
+                    f"Equality delete file {delete_file.file_path} matches rows on field {field_id}, which is not a "
+                    "top-level column of the table"
+                )
+            columns.append(top_level_fields[field_id])
+        return columns
+
+    def _plan_files(self) -> Iterator[tuple[DataFile, list[DataFile], list[DataFile]]]:
+        """Plans the data files of the scanned snapshot, along with the positional and equality delete files whose
+        deletes apply to each of them.
+
+        Unlike PyIceberg's `plan_files`, this supports equality deletes, which apply to the data files of their
+        partition (or to those of all partitions if they're unpartitioned) that were added before them.
+        """
+        snapshot = self._table.scan(snapshot_id=self._snapshot_id).snapshot()
+        if snapshot is None:
+            return
+        io = self._table.io
+        entries = chain.from_iterable(
+            ExecutorFactory.get_or_create().map(
+                lambda manifest: manifest.fetch_manifest_entry(io, discard_deleted=True), snapshot.manifests(io)
+            )
+        )
+
+        data_entries: list[ManifestEntry] = []
+        positional_deletes: defaultdict[tuple[int, tuple[Any, ...]], list[ManifestEntry]] = defaultdict(list)
+        equality_deletes: defaultdict[tuple[int, tuple[Any, ...]], list[ManifestEntry]] = defaultdict(list)
+        global_equality_deletes: list[ManifestEntry] = []
+        specs = self._table.specs()
+        for entry in entries:
+            data_file = entry.data_file
+            if data_file.content == DataFileContent.DATA:
+                data_entries.append(entry)
+            elif data_file.content == DataFileContent.POSITION_DELETES:
+                positional_deletes[self._partition_key(data_file)].append(entry)
+            elif specs[data_file.spec_id].is_unpartitioned():
+                global_equality_deletes.append(entry)
+            else:
+                equality_deletes[self._partition_key(data_file)].append(entry)
+
+        for entry in data_entries:
+            data_file = entry.data_file
+            partition_key = self._partition_key(data_file)
+            path = data_file.file_path.encode()
+
+            def deletes_rows_of_file(delete_file: DataFile) -> bool:
+                lower = (delete_file.lower_bounds or {}).get(_POSITIONAL_DELETE_FILE_PATH_ID)
+                upper = (delete_file.upper_bounds or {}).get(_POSITIONAL_DELETE_FILE_PATH_ID)
+                return (lower is None or lower <= path) and (upper is None or path <= upper)
+
+            # Positional deletes apply to data files of the same snapshot, while equality deletes only apply to
+            # those of earlier snapshots.
+            positional_delete_files = [
+                delete.data_file
+                for delete in positional_deletes[partition_key]
+                if delete.sequence_number >= entry.sequence_number and deletes_rows_of_file(delete.data_file)
+            ]
+            equality_delete_files = [
+                delete.data_file
+                for delete in chain(equality_deletes[partition_key], global_equality_deletes)
+                if delete.sequence_number > entry.sequence_number
+            ]
+            yield data_file, positional_delete_files, equality_delete_files
+
+    def _plan_changes(self) -> Iterator[tuple[DataFile, list[DataFile], list[DataFile], str | None]]:
+        """Plans the data files that the snapshots after the start snapshot up to the scanned snapshot added or
+        deleted, as the files of inserted and deleted rows.
+
+        Changes are tracked at the granularity of data files, so the rows of files that were rewritten by overwrites
+        are read as deleted and then inserted again, while the files that replace snapshots compact aren't read at
+        all, as they don't change the data of the table. The rows of deleted files are read without the row-level
+        deletes that applied to them.
+        """
+        from pyiceberg.manifest import ManifestEntryStatus
+        from pyiceberg.table.snapshots import Operation
+
+        end_snapshot = self._table.scan(snapshot_id=self._snapshot_id).snapshot()
+        snapshots = []
+        snapshot = end_snapshot
+        while snapshot is not None and snapshot.snapshot_id != self._start_snapshot_id:
+            snapshots.append(snapshot)
+            snapshot = (
+                self._table.snapshot_by_id(snapshot.parent_snapshot_id)
+                if snapshot.parent_snapshot_id is not None
+                else None
+            )
+        if snapshot is None:
+            raise ValueError(
+                f"The start snapshot {self._start_snapshot_id} of the changes to read is not an ancestor of snapshot {self.snapshot_id()}"
+            )
+
+        io = self._table.io
+        inserted: dict[str, DataFile] = {}
+        deleted: dict[str, DataFile] = {}
+        for snapshot in reversed(snapshots):
+            if snapshot.summary is not None and snapshot.summary.operation == Operation.REPLACE:
+                continue
+            manifests = [
+                manifest for manifest in snapshot.manifests(io) if manifest.added_snapshot_id == snapshot.snapshot_id
+            ]
+            entries = chain.from_iterable(
+                ExecutorFactory.get_or_create().map(
+                    lambda manifest: manifest.fetch_manifest_entry(io, discard_deleted=False), manifests
+                )
+            )
+            for entry in entries:
+                if entry.snapshot_id != snapshot.snapshot_id or entry.status == ManifestEntryStatus.EXISTING:
+                    continue
+                data_file = entry.data_file
+                if data_file.content != DataFileContent.DATA:
+                    raise NotImplementedError(
+                        f"Snapshot {snapshot.snapshot_id} deletes rows with delete file {data_file.file_path}, which "
+                        "reading the changes of Iceberg tables doesn't support yet"
+                    )
+                if entry.status == ManifestEntryStatus.ADDED:
+                    inserted[data_file.file_path] = data_file
+                # Files that are added and deleted again between the snapshots didn't change the table.
+                elif inserted.pop(data_file.file_path, None) is None:
+                    deleted[data_file.file_path] = data_file
+
+        for data_file in deleted.values():
+            yield data_file, [], [], "delete"
+        for data_file in inserted.values():
+            yield data_file, [], [], "insert"
 
     def to_scan_tasks(self, pushdowns: Pushdowns) -> Iterator[ScanTask]:
         limit = pushdowns.limit
-        iceberg_tasks = self._table.scan(limit=limit, snapshot_id=self._snapshot_id).plan_files()
 
         limit_files = limit is not None and pushdowns.filters is None and pushdowns.partition_filters is None
 
@@ -170,10 +368,14 @@ class IcebergScanOperator(ScanOperator):
             rows_left = limit
         else:
             rows_left = 0
-        for task in iceberg_tasks:
+        planned_files = (
+            ((*planned, None) for planned in self._plan_files())
+            if self._start_snapshot_id is None
+            else self._plan_changes()
+        )
+        for file, positional_delete_files, equality_delete_files, change_type in planned_files:
             if limit_files and (rows_left <= 0):
                 break
-            file = task.file
             path = file.file_path
             record_count = file.record_count
             file_format = file.file_format
@@ -185,25 +387,32 @@ class IcebergScanOperator(ScanOperator):
                 # TODO: Support ORC and AVRO when we can read it
                 raise NotImplementedError(f"{file_format} for iceberg not implemented!")
 
-            iceberg_delete_files = [f.file_path for f in task.delete_files]
+            iceberg_delete_files = [f.file_path for f in positional_delete_files]
+            iceberg_equality_delete_files = [(f.file_path, self._equality_columns(f)) for f in equality_delete_files]
+            # The record count of a data file doesn't account for the rows deleted from it
+            has_deletes = len(iceberg_delete_files) > 0 or len(iceberg_equality_delete_files) > 0
 
             # TODO: Thread in Statistics to each ScanTask: P2
-            pspec = self._iceberg_record_to_partition_spec(self._table.specs()[file.spec_id], file.partition)
+            pspec = self._iceberg_record_to_partition_spec(
+                self._table.specs()[file.spec_id], file.partition, change_type
+            )
             st = ScanTask.catalog_scan_task(
                 file=path,
                 file_format=file_format_config,
                 schema=self._schema._schema,
-                num_rows=record_count,
+                num_rows=None if has_deletes else record_count,
                 storage_config=self._storage_config,
                 size_bytes=file.file_size_in_bytes,
                 iceberg_delete_files=iceberg_delete_files,
                 pushdowns=pushdowns,
                 partition_values=pspec._table if pspec is not None else None,
                 stats=None,
+                iceberg_equality_delete_files=iceberg_equality_delete_files,
             )
             if st is None:
                 continue
-            rows_left -= record_count
+            if not has_deletes:
+                rows_left -= record_count
             scan_tasks.append(st)
         return iter(scan_tasks)
 
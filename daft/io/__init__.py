@@ -2,17 +2,24 @@ from __future__ import annotations
 
 from daft.daft import (
     AzureConfig,
+    BearerToken,
+    DiskCacheConfig,
     GCSConfig,
+    HDFSConfig,
     IOConfig,
     HTTPConfig,
+    RateLimitConfig,
+    RetryConfig,
     S3Config,
     S3Credentials,
+    SFTPConfig,
 )
 from daft.io._csv import read_csv
-from daft.io._deltalake import read_deltalake
+from daft.io._deltalake import list_deltalake_versions, read_deltalake
 from daft.io._hudi import read_hudi
-from daft.io._iceberg import read_iceberg
+from daft.io._iceberg import list_iceberg_snapshots, read_iceberg
 from daft.io._json import read_json
+from daft.io._kafka import KafkaStream, read_kafka
 from daft.io._lance import read_lance
 from daft.io._parquet import read_parquet
 from daft.io._sql import read_sql
@@ -20,23 +27,37 @@ from daft.io._warc import read_warc
 from daft.io._range import _range
 from daft.io.catalog import DataCatalogTable, DataCatalogType
 from daft.io.file_path import from_glob_path
+from daft.io.source import DataSource, DataSourcePushdowns, DataSourceTask
 
 __all__ = [
     "AzureConfig",
+    "BearerToken",
     "DataCatalogTable",
     "DataCatalogType",
+    "DataSource",
+    "DataSourcePushdowns",
+    "DataSourceTask",
+    "DiskCacheConfig",
     "GCSConfig",
+    "HDFSConfig",
     "HTTPConfig",
     "IOConfig",
+    "KafkaStream",
+    "RateLimitConfig",
+    "RetryConfig",
     "S3Config",
     "S3Credentials",
+    "SFTPConfig",
     "_range",
     "from_glob_path",
+    "list_deltalake_versions",
+    "list_iceberg_snapshots",
     "read_csv",
     "read_deltalake",
     "read_hudi",
     "read_iceberg",
     "read_json",
+    "read_kafka",
     "read_lance",
     "read_parquet",
     "read_sql",
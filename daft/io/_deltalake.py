@@ -1,6 +1,6 @@
 # isort: dont-add-import: from __future__ import annotations
 
-from typing import TYPE_CHECKING, Any, Dict, Optional, Union
+from typing import TYPE_CHECKING, Any, Dict, Optional, Tuple, Union
 
 from daft import context
 from daft.api_annotations import PublicAPI
@@ -16,11 +16,40 @@ if TYPE_CHECKING:
     from daft.unity_catalog import UnityCatalogTable
 
 
+def _resolve_table(
+    table: Union[str, DataCatalogTable, "UnityCatalogTable"],
+    io_config: Optional["IOConfig"],
+    multithreaded_io: bool,
+) -> Tuple[str, StorageConfig]:
+    """The URI of a Delta Lake table and the storage config to access it with."""
+    io_config = context.get_context().daft_planning_config.default_io_config if io_config is None else io_config
+    storage_config = StorageConfig(multithreaded_io, io_config)
+
+    if isinstance(table, str):
+        table_uri = table
+    elif isinstance(table, DataCatalogTable):
+        table_uri = table.table_uri(io_config)
+    elif unity_catalog.module_available() and isinstance(table, unity_catalog.UnityCatalogTable):
+        table_uri = table.table_uri
+
+        # Override the storage_config with the one provided by Unity catalog
+        recordbatch_io_config = table.io_config
+        if recordbatch_io_config is not None:
+            storage_config = StorageConfig(multithreaded_io, recordbatch_io_config)
+    else:
+        raise ValueError(
+            f"table argument must be a table URI string, DataCatalogTable or UnityCatalogTable instance, but got: {type(table)}, {table}"
+        )
+    return table_uri, storage_config
+
+
 @PublicAPI
 def read_deltalake(
     table: Union[str, DataCatalogTable, "UnityCatalogTable"],
     version: Optional[Union[int, str, "datetime"]] = None,
     io_config: Optional["IOConfig"] = None,
+    start_version: Optional[Union[int, str, "datetime"]] = None,
+    timestamp: Optional[Union[str, "datetime"]] = None,
     _multithreaded_io: Optional[bool] = None,
 ) -> DataFrame:
     """Create a DataFrame from a Delta Lake table.
@@ -32,6 +61,13 @@ def read_deltalake(
             read the timestamp version of the table. Strings must be RFC 3339 and ISO 8601 date and time format.
             Datetimes are assumed to be UTC timezone unless specified. By default, read the latest version of the table.
         io_config (optional): A custom :class:`~daft.daft.IOConfig` to use when accessing Delta Lake object storage data. Defaults to None.
+        start_version (optional): If passed, only read the rows that changed between this version of the table and `version`, which
+            is specified in the same way. The rows are returned with a `_change_type` column of "insert" for the rows added since
+            `start_version` and "delete" for those removed since then. Changes are tracked at the granularity of data files, so the
+            rows of files that were rewritten in between, such as by updates or compactions, are returned as deleted and inserted again.
+        timestamp (optional): Read the version of the table that was current at this point in time, instead of `version`. Strings must be
+            RFC 3339 and ISO 8601 date and time format, and datetimes are assumed to be UTC unless they have a timezone. Use
+            [`daft.list_deltalake_versions`][daft.list_deltalake_versions] to list the versions of the table.
         _multithreaded_io (optional): Whether to use multithreading for IO threads. Setting this to False can be helpful in reducing
             the amount of system resources (number of connections and thread contention) when running in the Ray runner.
             Defaults to None, which will let Daft decide based on the runner it is currently using.
@@ -49,6 +85,13 @@ def read_deltalake(
         >>> # the read operation from Delta Lake.
         >>> df = df.where(df["foo"] > 5)
         >>> df.show()
+        >>>
+        >>> # Read only the rows that changed since version 3
+        >>> changes = daft.read_deltalake("some-table-uri", start_version=3)
+        >>> inserts = changes.where(changes["_change_type"] == "insert")
+        >>>
+        >>> # Read the table as it was at a point in time
+        >>> df = daft.read_deltalake("some-table-uri", timestamp="2024-01-01T00:00:00Z")
     """
     from daft.delta_lake.delta_lake_scan import DeltaLakeScanOperator
 
@@ -58,31 +101,60 @@ def read_deltalake(
         (context.get_context().get_or_create_runner().name != "ray") if _multithreaded_io is None else _multithreaded_io
     )
 
-    io_config = context.get_context().daft_planning_config.default_io_config if io_config is None else io_config
-    storage_config = StorageConfig(multithreaded_io, io_config)
-
-    if isinstance(table, str):
-        table_uri = table
-    elif isinstance(table, DataCatalogTable):
-        table_uri = table.table_uri(io_config)
-    elif unity_catalog.module_available() and isinstance(table, unity_catalog.UnityCatalogTable):
-        table_uri = table.table_uri
-
-        # Override the storage_config with the one provided by Unity catalog
-        recordbatch_io_config = table.io_config
-        if recordbatch_io_config is not None:
-            storage_config = StorageConfig(multithreaded_io, recordbatch_io_config)
-    else:
-        raise ValueError(
-            f"table argument must be a table URI string, DataCatalogTable or UnityCatalogTable instance, but got: {type(table)}, {table}"
-        )
-    delta_lake_operator = DeltaLakeScanOperator(table_uri, storage_config=storage_config, version=version)
+    table_uri, storage_config = _resolve_table(table, io_config, multithreaded_io)
+    delta_lake_operator = DeltaLakeScanOperator(
+        table_uri, storage_config=storage_config, version=version, start_version=start_version, timestamp=timestamp
+    )
 
     handle = ScanOperatorHandle.from_python_scan_operator(delta_lake_operator)
     builder = LogicalPlanBuilder.from_tabular_scan(scan_operator=handle)
     return DataFrame(builder)
 
 
+@PublicAPI
+def list_deltalake_versions(
+    table: Union[str, DataCatalogTable, "UnityCatalogTable"],
+    io_config: Optional["IOConfig"] = None,
+) -> DataFrame:
+    """List the versions of a Delta Lake table, to pin the version that [`daft.read_deltalake`][daft.read_deltalake] reads.
+
+    Args:
+        table: Either a URI for the Delta Lake table or a :class:`~daft.io.catalog.DataCatalogTable` instance
+            referencing a table in a data catalog, such as AWS Glue Data Catalog or Databricks Unity Catalog.
+        io_config (optional): A custom :class:`~daft.daft.IOConfig` to use when accessing Delta Lake object storage data. Defaults to None.
+
+    Returns:
+        DataFrame: A DataFrame with a row for each version in the table's history, ordered by version, with the columns `version`,
+            `timestamp` (UTC), `operation` (e.g. "WRITE" or "MERGE") and `is_current`.
+
+    Examples:
+        >>> versions = daft.list_deltalake_versions("some-table-uri")
+        >>> df = daft.read_deltalake("some-table-uri", version=versions.to_pydict()["version"][0])
+    """
+    from deltalake import DeltaTable
+
+    from daft.convert import from_arrow
+    from daft.delta_lake.delta_lake_scan import delta_lake_storage_options
+    from daft.dependencies import pa
+
+    multithreaded_io = context.get_context().get_or_create_runner().name != "ray"
+    table_uri, storage_config = _resolve_table(table, io_config, multithreaded_io)
+    delta_table = DeltaTable(table_uri, storage_options=delta_lake_storage_options(table_uri, storage_config))
+
+    current_version = delta_table.version()
+    history = sorted(delta_table.history(), key=lambda commit: commit["version"])
+    return from_arrow(
+        pa.table(
+            {
+                "version": pa.array([commit["version"] for commit in history], pa.int64()),
+                "timestamp": pa.array([commit.get("timestamp") for commit in history], pa.timestamp("ms", tz="UTC")),
+                "operation": pa.array([commit.get("operation") for commit in history], pa.large_string()),
+                "is_current": pa.array([commit["version"] == current_version for commit in history], pa.bool_()),
+            }
+        )
+    )
+
+
 def large_dtypes_kwargs(large_dtypes: bool) -> Dict[str, Any]:
     import deltalake
     from packaging.version import parse
@@ -16,6 +16,9 @@ def read_hudi(
 ) -> DataFrame:
     """Create a DataFrame from a Hudi table.
 
+    Copy-on-Write tables are read with a snapshot query of their latest committed file slices, while Merge-on-Read tables
+    are read with a read-optimized query, which only reads their base files.
+
     Args:
         table_uri: URI to the Hudi table.
         io_config: A custom IOConfig to use when accessing Hudi table object storage data. Defaults to None.
@@ -9,6 +9,8 @@ from daft.dataframe import DataFrame
 from daft.logical.builder import LogicalPlanBuilder
 
 if TYPE_CHECKING:
+    from datetime import datetime
+
     import pyiceberg
 
 
@@ -56,6 +58,8 @@ def read_iceberg(
     table: Union[str, "pyiceberg.table.Table"],
     snapshot_id: Optional[int] = None,
     io_config: Optional["IOConfig"] = None,
+    start_snapshot_id: Optional[int] = None,
+    as_of: Optional[Union[int, str, "datetime"]] = None,
 ) -> DataFrame:
     """Create a DataFrame from an Iceberg table.
 
@@ -63,6 +67,13 @@ def read_iceberg(
         table (str or pyiceberg.table.Table): [PyIceberg Table](https://py.iceberg.apache.org/reference/pyiceberg/table/#pyiceberg.table.Table) created using the PyIceberg library
         snapshot_id (int, optional): Snapshot ID of the table to query
         io_config (IOConfig, optional): A custom IOConfig to use when accessing Iceberg object storage data. If provided, configurations set in `table` are ignored.
+        start_snapshot_id (int, optional): If provided, only read the rows that changed between this snapshot and `snapshot_id`, which
+            must be its descendant. The rows are returned with a `_change_type` column of "insert" for the rows of the data files added
+            since the start snapshot and "delete" for those of the data files deleted since then. Changes are tracked at the granularity
+            of data files, and snapshots that delete rows with delete files aren't supported yet.
+        as_of (int, str or datetime, optional): Read the snapshot that was the table's current snapshot at this point in time, instead of
+            `snapshot_id`. Given as milliseconds since the epoch, an ISO 8601 string, or a datetime, which is assumed to be UTC unless it
+            has a timezone. Use [`daft.list_iceberg_snapshots`][daft.list_iceberg_snapshots] to list the snapshots of the table.
 
     Returns:
         DataFrame: a DataFrame with the schema converted from the specified Iceberg table
@@ -81,6 +92,12 @@ def read_iceberg(
         >>> # the read operation from Iceberg
         >>> df = df.where(df["foo"] > 5)
         >>> df.show()
+        >>>
+        >>> # Read only the rows that changed since an earlier snapshot
+        >>> changes = daft.read_iceberg(table, start_snapshot_id=start_snapshot_id)
+        >>>
+        >>> # Read the table as it was at a point in time
+        >>> df = daft.read_iceberg(table, as_of="2024-01-01T00:00:00Z")
 
     """
     import pyiceberg
@@ -99,8 +116,65 @@ def read_iceberg(
     multithreaded_io = context.get_context().get_or_create_runner().name != "ray"
     storage_config = StorageConfig(multithreaded_io, io_config)
 
-    iceberg_operator = IcebergScanOperator(table, snapshot_id=snapshot_id, storage_config=storage_config)
+    iceberg_operator = IcebergScanOperator(
+        table,
+        snapshot_id=snapshot_id,
+        storage_config=storage_config,
+        start_snapshot_id=start_snapshot_id,
+        as_of=as_of,
+    )
 
     handle = ScanOperatorHandle.from_python_scan_operator(iceberg_operator)
     builder = LogicalPlanBuilder.from_tabular_scan(scan_operator=handle)
     return DataFrame(builder)
+
+
+@PublicAPI
+def list_iceberg_snapshots(table: Union[str, "pyiceberg.table.Table"]) -> DataFrame:
+    """List the snapshots of an Iceberg table, to pin the snapshot that [`daft.read_iceberg`][daft.read_iceberg] reads.
+
+    Args:
+        table (str or pyiceberg.table.Table): [PyIceberg Table](https://py.iceberg.apache.org/reference/pyiceberg/table/#pyiceberg.table.Table) created using the PyIceberg library, or the path to its metadata file
+
+    Returns:
+        DataFrame: a DataFrame with a row for each snapshot, ordered by time, with the columns `snapshot_id`, `parent_snapshot_id`,
+            `timestamp` (UTC), `operation` (e.g. "append" or "overwrite") and `is_current`
+
+    Examples:
+        >>> snapshots = daft.list_iceberg_snapshots(table)
+        >>> first_snapshot_id = snapshots.to_pydict()["snapshot_id"][0]
+        >>> df = daft.read_iceberg(table, snapshot_id=first_snapshot_id)
+
+    """
+    import pyiceberg
+
+    from daft.convert import from_arrow
+    from daft.dependencies import pa
+
+    if isinstance(table, str):
+        table = pyiceberg.table.StaticTable.from_metadata(metadata_location=table)
+
+    current_snapshot = table.current_snapshot()
+    current_snapshot_id = current_snapshot.snapshot_id if current_snapshot is not None else None
+    snapshots = sorted(table.metadata.snapshots, key=lambda snapshot: snapshot.timestamp_ms)
+    return from_arrow(
+        pa.table(
+            {
+                "snapshot_id": pa.array([snapshot.snapshot_id for snapshot in snapshots], pa.int64()),
+                "parent_snapshot_id": pa.array([snapshot.parent_snapshot_id for snapshot in snapshots], pa.int64()),
+                "timestamp": pa.array(
+                    [snapshot.timestamp_ms for snapshot in snapshots], pa.timestamp("ms", tz="UTC")
+                ),
+                "operation": pa.array(
+                    [
+                        snapshot.summary.operation.value if snapshot.summary is not None else None
+                        for snapshot in snapshots
+                    ],
+                    pa.large_string(),
+                ),
+                "is_current": pa.array(
+                    [snapshot.snapshot_id == current_snapshot_id for snapshot in snapshots], pa.bool_()
+                ),
+            }
+        )
+    )
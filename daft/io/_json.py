@@ -1,6 +1,6 @@
 # isort: dont-add-import: from __future__ import annotations
 
-from typing import Dict, List, Optional, Union
+from typing import Dict, List, Literal, Optional, Union
 
 from daft import context
 from daft.api_annotations import PublicAPI
@@ -24,6 +24,7 @@ def read_json(
     file_path_column: Optional[str] = None,
     hive_partitioning: bool = False,
     schema_hints: Optional[Dict[str, DataType]] = None,
+    schema_resolution: Literal["first", "union"] = "first",
     _buffer_size: Optional[int] = None,
     _chunk_size: Optional[int] = None,
 ) -> DataFrame:
@@ -36,6 +37,10 @@ def read_json(
         io_config (IOConfig): Config to be used with the native downloader
         file_path_column: Include the source path(s) as a column with this name. Defaults to None.
         hive_partitioning: Whether to infer hive_style partitions from file paths and include them as columns in the Dataframe. Defaults to False.
+        schema_resolution: How to resolve the schemas of files whose schemas have drifted apart. "first" uses the schema of the first file, reading
+            columns that are missing from other files as nulls. "union" uses the union of the schemas of all files, with columns in the order they're
+            first seen and their types safely widened to fit all files (e.g. int32 to int64, or float to double), which raises an error naming the
+            files if a column's types can't be widened to a common type. Defaults to "first".
 
     Returns:
         DataFrame: parsed DataFrame
@@ -74,5 +79,6 @@ def read_json(
         storage_config=storage_config,
         file_path_column=file_path_column,
         hive_partitioning=hive_partitioning,
+        schema_resolution=schema_resolution,
     )
     return DataFrame(builder)
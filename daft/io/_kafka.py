@@ -0,0 +1,524 @@
+"""Reads Kafka topics as a stream of micro-batches.
+
+Each micro-batch is a DataFrame that reads the messages of every partition of the topics from the offsets that the
+previous batch ended at to the end of the partition when the batch was planned. Offsets are tracked by the stream
+rather than committed to Kafka, and are written to a checkpoint file after each batch so that a restarted stream
+resumes where it left off.
+"""
+
+from __future__ import annotations
+
+import base64
+import datetime
+import decimal
+import io
+import json
+import os
+import time
+from dataclasses import dataclass
+from typing import TYPE_CHECKING, Any, Callable, Literal, Union
+
+from daft.api_annotations import PublicAPI
+from daft.daft import decode_json_values
+from daft.datatype import DataType, TimeUnit
+from daft.dependencies import pa
+from daft.io.source import DataSource, DataSourcePushdowns, DataSourceTask
+from daft.logical.schema import Schema
+from daft.recordbatch import RecordBatch
+from daft.series import Series
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+    from daft.dataframe import DataFrame
+
+ValueFormat = Literal["json", "avro", "avro-confluent"]
+Offsets = dict[str, dict[int, int]]
+StartingOffsets = Union[Literal["earliest", "latest"], Offsets]
+
+CHECKPOINT_VERSION = 1
+# The number of messages that a task decodes into each of the record batches that it reads.
+MESSAGES_PER_BATCH = 10_000
+# The bytes that the Confluent Schema Registry serializers put before Avro values: a magic byte and a schema id.
+CONFLUENT_HEADER_SIZE = 5
+
+
+@dataclass(frozen=True)
+class _Message:
+    offset: int
+    timestamp: int | None
+    key: bytes | None
+    value: bytes | None
+
+
+class _KafkaClient:
+    """The operations of a Kafka consumer that the stream and its tasks use."""
+
+    def __init__(self, config: dict[str, Any]) -> None:
+        try:
+            from confluent_kafka import Consumer
+        except ImportError as e:
+            raise ImportError(
+                "Unable to import the `confluent_kafka` package, please ensure that Daft is installed with the kafka "
+                "extra dependency: `pip install daft[kafka]`"
+            ) from e
+        self._consumer = Consumer({**config, "enable.auto.commit": False, "enable.partition.eof": True})
+
+    def partitions(self, topic: str) -> list[int]:
+        """The ids of the partitions of a topic."""
+        metadata = self._consumer.list_topics(topic, timeout=10).topics.get(topic)
+        if metadata is None:
+            raise ValueError(f"Kafka topic `{topic}` doesn't exist")
+        if metadata.error is not None:
+            raise ValueError(f"Unable to read the partitions of Kafka topic `{topic}`: {metadata.error}")
+        return sorted(metadata.partitions)
+
+    def watermarks(self, topic: str, partition: int) -> tuple[int, int]:
+        """The offset of the first message of a partition and the offset that its next message will have."""
+        from confluent_kafka import TopicPartition
+
+        return self._consumer.get_watermark_offsets(TopicPartition(topic, partition), timeout=10)
+
+    def read(self, topic: str, partition: int, start: int, end: int) -> Iterator[_Message]:
+        """Reads the messages of a partition with offsets from `start` up to, but not including, `end`."""
+        from confluent_kafka import KafkaError, TopicPartition
+
+        self._consumer.assign([TopicPartition(topic, partition, start)])
+        try:
+            while True:
+                for message in self._consumer.consume(num_messages=min(end - start, 1000), timeout=1.0):
+                    if message.error() is not None:
+                        if message.error().code() == KafkaError._PARTITION_EOF:
+                            return
+                        raise ValueError(
+                            f"Error reading Kafka topic `{topic}` partition {partition}: {message.error()}"
+                        )
+                    if message.offset() >= end:
+                        return
+                    _, timestamp = message.timestamp()
+                    timestamp = timestamp if timestamp >= 0 else None
+                    yield _Message(message.offset(), timestamp, message.key(), message.value())
+                    if message.offset() + 1 >= end:
+                        return
+        finally:
+            self._consumer.unassign()
+
+    def close(self) -> None:
+        self._consumer.close()
+
+
+def _connect(config: dict[str, Any]) -> _KafkaClient:
+    return _KafkaClient(config)
+
+
+def _avro_to_daft(schema: Any) -> DataType:
+    """The Daft type that the values of an Avro schema are decoded to."""
+    if isinstance(schema, list):
+        types = [t for t in schema if t != "null"]
+        if len(types) != 1:
+            raise ValueError(f"Only Avro unions of a type and null are supported, but got: {schema}")
+        return _avro_to_daft(types[0])
+    if isinstance(schema, dict):
+        logical_type = schema.get("logicalType")
+        if logical_type == "date":
+            return DataType.date()
+        if logical_type == "timestamp-millis":
+            return DataType.timestamp(TimeUnit.ms())
+        if logical_type == "timestamp-micros":
+            return DataType.timestamp(TimeUnit.us())
+        if logical_type == "decimal":
+            return DataType.float64()
+        avro_type = schema["type"]
+        if avro_type == "record":
+            return DataType.struct({field["name"]: _avro_to_daft(field["type"]) for field in schema["fields"]})
+        if avro_type == "array":
+            return DataType.list(_avro_to_daft(schema["items"]))
+        if avro_type == "enum":
+            return DataType.string()
+        if avro_type == "fixed":
+            return DataType.string()
+        return _avro_to_daft(avro_type)
+    primitives = {
+        "null": DataType.null(),
+        "boolean": DataType.bool(),
+        "int": DataType.int32(),
+        "long": DataType.int64(),
+        "float": DataType.float32(),
+        "double": DataType.float64(),
+        "bytes": DataType.string(),
+        "string": DataType.string(),
+    }
+    if schema not in primitives:
+        raise ValueError(f"Unsupported Avro type: {schema}")
+    return primitives[schema]
+
+
+def _to_json(value: Any) -> Any:
+    """Converts the values that fastavro decodes to that JSON can't represent."""
+    if isinstance(value, (datetime.datetime, datetime.date)):
+        return value.isoformat()
+    if isinstance(value, decimal.Decimal):
+        return float(value)
+    if isinstance(value, bytes):
+        return base64.b64encode(value).decode()
+    return str(value)
+
+
+@dataclass(frozen=True)
+class _ValueDecoder:
+    """Decodes the values of messages into structs, on the workers that read them."""
+
+    value_format: ValueFormat
+    schema: Schema
+    avro_schema: dict[str, Any] | None
+
+    def decode(self, values: list[bytes | None]) -> Series:
+        if self.value_format != "json":
+            values = [None if value is None else self._avro_to_json(value) for value in values]
+        series = Series.from_arrow(pa.array(values, type=pa.binary()), name="value")
+        return Series._from_pyseries(decode_json_values(series._series, self.schema._schema))
+
+    def _avro_to_json(self, value: bytes) -> bytes:
+        from fastavro import parse_schema, schemaless_reader
+
+        if self.value_format == "avro-confluent":
+            value = value[CONFLUENT_HEADER_SIZE:]
+        record = schemaless_reader(io.BytesIO(value), parse_schema(self.avro_schema))
+        return json.dumps(record, default=_to_json).encode()
+
+
+def _schema(decoder: _ValueDecoder | None) -> Schema:
+    value_dtype = DataType.binary() if decoder is None else DataType.struct({f.name: f.dtype for f in decoder.schema})
+    return Schema._from_pydict(
+        {
+            "topic": DataType.string(),
+            "partition": DataType.int32(),
+            "offset": DataType.int64(),
+            "timestamp": DataType.timestamp(TimeUnit.ms()),
+            "key": DataType.binary(),
+            "value": value_dtype,
+        }
+    )
+
+
+class _KafkaPartitionTask(DataSourceTask):
+    """Reads a range of the offsets of a partition of a topic."""
+
+    def __init__(
+        self, config: dict[str, Any], topic: str, partition: int, start: int, end: int, decoder: _ValueDecoder | None
+    ) -> None:
+        self._config = config
+        self._topic = topic
+        self._partition = partition
+        self._start = start
+        self._end = end
+        self._decoder = decoder
+
+    def get_batches(self) -> Iterator[RecordBatch]:
+        client = _connect(self._config)
+        try:
+            messages: list[_Message] = []
+            for message in client.read(self._topic, self._partition, self._start, self._end):
+                messages.append(message)
+                if len(messages) == MESSAGES_PER_BATCH:
+                    yield self._to_record_batch(messages)
+                    messages = []
+            if messages:
+                yield self._to_record_batch(messages)
+        finally:
+            client.close()
+
+    def _to_record_batch(self, messages: list[_Message]) -> RecordBatch:
+        values = [m.value for m in messages]
+        return RecordBatch.from_pydict(
+            {
+                "topic": pa.array([self._topic] * len(messages), type=pa.string()),
+                "partition": pa.array([self._partition] * len(messages), type=pa.int32()),
+                "offset": pa.array([m.offset for m in messages], type=pa.int64()),
+                "timestamp": pa.array([m.timestamp for m in messages], type=pa.timestamp("ms")),
+                "key": pa.array([m.key for m in messages], type=pa.binary()),
+                "value": pa.array(values, type=pa.binary()) if self._decoder is None else self._decoder.decode(values),
+            }
+        )
+
+    @property
+    def num_rows(self) -> int | None:
+        # Compacted topics may have fewer messages than offsets.
+        return None
+
+
+class _KafkaSource(DataSource):
+    """The messages of a micro-batch: one range of offsets per partition."""
+
+    def __init__(
+        self,
+        config: dict[str, Any],
+        ranges: dict[tuple[str, int], tuple[int, int]],
+        decoder: _ValueDecoder | None,
+    ) -> None:
+        self._config = config
+        self._ranges = ranges
+        self._decoder = decoder
+
+    @property
+    def name(self) -> str:
+        return "KafkaSource"
+
+    @property
+    def schema(self) -> Schema:
+        return _schema(self._decoder)
+
+    def display_lines(self) -> list[str]:
+        return [*super().display_lines(), f"Partitions = {len(self._ranges)}"]
+
+    def get_tasks(self, pushdowns: DataSourcePushdowns) -> Iterator[DataSourceTask]:
+        for (topic, partition), (start, end) in self._ranges.items():
+            yield _KafkaPartitionTask(self._config, topic, partition, start, end, self._decoder)
+
+
+class KafkaStream:
+    """A stream of the messages of Kafka topics, read as a sequence of micro-batches.
+
+    The stream tracks the offset to read next from each partition. A batch's offsets are only committed, advancing the
+    stream and writing its checkpoint, once the batch has been processed, so a batch that fails is read again.
+    """
+
+    def __init__(
+        self,
+        config: dict[str, Any],
+        topics: list[str],
+        starting_offsets: StartingOffsets,
+        max_records_per_partition: int | None,
+        checkpoint: str | None,
+        decoder: _ValueDecoder | None,
+    ) -> None:
+        self._config = config
+        self._topics = topics
+        self._max_records_per_partition = max_records_per_partition
+        self._checkpoint = checkpoint
+        self._decoder = decoder
+        self._client = _connect(config)
+        self._batch_id = 0
+        self._offsets: Offsets = {topic: {} for topic in topics}
+        self._pending: Offsets | None = None
+
+        if checkpoint is not None and os.path.exists(checkpoint):
+            self._load_checkpoint(checkpoint)
+        else:
+            for topic in topics:
+                for partition in self._client.partitions(topic):
+                    self._offsets[topic][partition] = self._starting_offset(topic, partition, starting_offsets)
+
+    def _starting_offset(self, topic: str, partition: int, starting_offsets: StartingOffsets) -> int:
+        low, high = self._client.watermarks(topic, partition)
+        if starting_offsets == "earliest":
+            return low
+        if starting_offsets == "latest":
+            return high
+        return max(starting_offsets.get(topic, {}).get(partition, low), low)
+
+    def _load_checkpoint(self, path: str) -> None:
+        with open(path) as f:
+            checkpoint = json.load(f)
+        if checkpoint.get("version") != CHECKPOINT_VERSION:
+            raise ValueError(f"Unsupported Kafka stream checkpoint version: {checkpoint.get('version')}")
+        self._batch_id = checkpoint["batch_id"]
+        for topic, offsets in checkpoint["offsets"].items():
+            if topic in self._offsets:
+                self._offsets[topic] = {int(partition): offset for partition, offset in offsets.items()}
+
+    def _write_checkpoint(self, path: str) -> None:
+        checkpoint = {"version": CHECKPOINT_VERSION, "batch_id": self._batch_id, "offsets": self._offsets}
+        # Replacing the checkpoint with a complete file means that a crash never leaves a partial one behind.
+        tmp = f"{path}.tmp"
+        with open(tmp, "w") as f:
+            json.dump(checkpoint, f)
+        os.replace(tmp, path)
+
+    @property
+    def schema(self) -> Schema:
+        """The schema of the batches of the stream."""
+        return _schema(self._decoder)
+
+    @property
+    def offsets(self) -> Offsets:
+        """The offset of the next message that the stream reads from each partition of each topic."""
+        return {topic: dict(offsets) for topic, offsets in self._offsets.items()}
+
+    @property
+    def batch_id(self) -> int:
+        """The id of the next batch of the stream, which counts the batches that have been committed."""
+        return self._batch_id
+
+    def next_batch(self) -> DataFrame | None:
+        """Plans the next micro-batch of the stream.
+
+        The batch reads the messages of each partition from the stream's offsets to the end of the partition when it's
+        planned, or at most ``max_records_per_partition`` of them. Partitions added to the topics since the previous
+        batch are read from their first message. Planning the next batch again before committing this one plans it
+        from the same offsets.
+
+        Returns:
+            DataFrame: The messages of the batch, or None if there are no new messages.
+        """
+        ranges = {}
+        pending: Offsets = {}
+        for topic in self._topics:
+            pending[topic] = dict(self._offsets[topic])
+            for partition in self._client.partitions(topic):
+                low, high = self._client.watermarks(topic, partition)
+                start = max(self._offsets[topic].get(partition, low), low)
+                end = high
+                if self._max_records_per_partition is not None:
+                    end = min(end, start + self._max_records_per_partition)
+                pending[topic][partition] = max(start, end)
+                if start < end:
+                    ranges[(topic, partition)] = (start, end)
+        if not ranges:
+            return None
+        self._pending = pending
+        return _KafkaSource(self._config, ranges, self._decoder).read()
+
+    def commit(self) -> None:
+        """Marks the batch last returned by `next_batch` as processed, advancing the stream past its messages and
+        writing the stream's checkpoint."""
+        if self._pending is None:
+            raise ValueError("There is no batch of the Kafka stream to commit")
+        self._offsets = self._pending
+        self._pending = None
+        self._batch_id += 1
+        if self._checkpoint is not None:
+            self._write_checkpoint(self._checkpoint)
+
+    def foreach_batch(
+        self,
+        func: Callable[[DataFrame, int], None],
+        *,
+        trigger_interval: float = 1.0,
+        max_batches: int | None = None,
+        stop_when_idle: bool = False,
+    ) -> int:
+        """Processes the stream one micro-batch at a time, committing each batch after ``func`` returns.
+
+        Since a batch is only committed once it has been processed, a stream that is restarted from its checkpoint
+        after ``func`` fails processes that batch again, so ``func`` should be idempotent for exactly-once results.
+
+        Args:
+            func (Callable[[DataFrame, int], None]): Processes a batch, given the batch and its id.
+            trigger_interval (float, optional): The number of seconds to wait for new messages when there are none.
+                Defaults to 1.0.
+            max_batches (int, optional): The number of batches to process before returning. Defaults to no limit.
+            stop_when_idle (bool, optional): Whether to return when there are no new messages instead of waiting for
+                them. Defaults to False.
+
+        Returns:
+            int: The number of batches that were processed.
+        """
+        processed = 0
+        while max_batches is None or processed < max_batches:
+            batch = self.next_batch()
+            if batch is None:
+                if stop_when_idle:
+                    break
+                time.sleep(trigger_interval)
+                continue
+            func(batch, self._batch_id)
+            self.commit()
+            processed += 1
+        return processed
+
+    def close(self) -> None:
+        """Closes the stream's connection to Kafka."""
+        self._client.close()
+
+    def __repr__(self) -> str:
+        return f"KafkaStream(topics={self._topics}, batch_id={self._batch_id}, offsets={self._offsets})"
+
+
+@PublicAPI
+def read_kafka(
+    brokers: str | list[str],
+    topics: str | list[str],
+    *,
+    starting_offsets: StartingOffsets = "earliest",
+    max_records_per_partition: int | None = None,
+    checkpoint: str | None = None,
+    value_format: ValueFormat | None = None,
+    value_schema: Schema | dict[str, DataType] | None = None,
+    avro_schema: dict[str, Any] | str | None = None,
+    group_id: str = "daft",
+    consumer_config: dict[str, Any] | None = None,
+) -> KafkaStream:
+    """Creates a stream of the messages of Kafka topics, which is consumed in micro-batches.
+
+    Each batch is a DataFrame with the ``topic``, ``partition``, ``offset``, ``timestamp``, ``key`` and ``value`` of
+    its messages. Values are bytes, unless a ``value_format`` decodes them into structs.
+
+    Args:
+        brokers (str | list[str]): The addresses of the Kafka brokers to bootstrap from.
+        topics (str | list[str]): The topics to read.
+        starting_offsets (str | dict, optional): Where the stream starts reading partitions when there's no
+            checkpoint: ``"earliest"`` from their first message, ``"latest"`` after their last message, or a dict of
+            the offset to start each partition of each topic from, which start from their first message otherwise.
+            Defaults to ``"earliest"``.
+        max_records_per_partition (int, optional): The number of messages that a batch reads from each partition at
+            most. Defaults to no limit.
+        checkpoint (str, optional): A local file that the offsets of the stream are written to after each batch,
+            which the stream resumes from if it exists. Defaults to None, which doesn't checkpoint the stream.
+        value_format (str, optional): How values are decoded: ``"json"`` for JSON objects, ``"avro"`` for Avro
+            records without a header, or ``"avro-confluent"`` for Avro records after the header of the Confluent Schema
+            Registry serializers. Defaults to None, which leaves values as bytes.
+        value_schema (Schema | dict, optional): The fields that values are decoded to, which JSON values require.
+            Defaults to the fields of ``avro_schema`` for Avro values.
+        avro_schema (dict | str, optional): The Avro schema of the values, which Avro values require.
+        group_id (str, optional): The consumer group that the stream's consumers join. The stream tracks its offsets
+            itself, so they aren't committed to the group. Defaults to ``"daft"``.
+        consumer_config (dict, optional): Further configuration for the consumers, as
+            [librdkafka properties](https://github.com/confluentinc/librdkafka/blob/master/CONFIGURATION.md).
+
+    Returns:
+        KafkaStream: The stream, whose batches are planned with `KafkaStream.next_batch` or processed with
+            `KafkaStream.foreach_batch`.
+
+    Note:
+        This function requires the `confluent-kafka` package, and the `fastavro` package to decode Avro values. To
+        ensure that these are installed with Daft, you may install: `pip install daft[kafka]`
+
+    Examples:
+        >>> schema = {"user": daft.DataType.string(), "amount": daft.DataType.float64()}
+        >>> stream = daft.read_kafka("localhost:9092", "events", value_format="json", value_schema=schema)
+        >>> stream.foreach_batch(lambda df, batch_id: df.write_parquet(f"events/{batch_id}"))
+    """
+    if isinstance(brokers, str):
+        brokers = [brokers]
+    if isinstance(topics, str):
+        topics = [topics]
+    if not topics:
+        raise ValueError("A Kafka stream must read at least one topic")
+    if max_records_per_partition is not None and max_records_per_partition <= 0:
+        raise ValueError(f"max_records_per_partition must be positive, but got: {max_records_per_partition}")
+    if isinstance(value_schema, dict):
+        value_schema = Schema._from_pydict(value_schema)
+
+    decoder = None
+    if value_format == "json":
+        if value_schema is None:
+            raise ValueError("Decoding JSON values of a Kafka stream requires a value_schema")
+        decoder = _ValueDecoder(value_format, value_schema, None)
+    elif value_format in ("avro", "avro-confluent"):
+        if avro_schema is None:
+            raise ValueError("Decoding Avro values of a Kafka stream requires an avro_schema")
+        if isinstance(avro_schema, str):
+            avro_schema = json.loads(avro_schema)
+        if value_schema is None:
+            if not isinstance(avro_schema, dict) or avro_schema.get("type") != "record":
+                raise ValueError(f"The Avro schema of Kafka values must be a record, but got: {avro_schema}")
+            value_schema = Schema._from_field_name_and_types(
+                [(field["name"], _avro_to_daft(field["type"])) for field in avro_schema["fields"]]
+            )
+        decoder = _ValueDecoder(value_format, value_schema, avro_schema)
+    elif value_format is not None:
+        raise ValueError(f"Unsupported Kafka value format: {value_format}, expected one of: json, avro, avro-confluent")
+
+    config = {"bootstrap.servers": ",".join(brokers), "group.id": group_id, **(consumer_config or {})}
+    return KafkaStream(config, topics, starting_offsets, max_records_per_partition, checkpoint, decoder)
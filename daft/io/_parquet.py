@@ -1,6 +1,6 @@
 # isort: dont-add-import: from __future__ import annotations
 
-from typing import Dict, List, Optional, Union
+from typing import Dict, List, Literal, Optional, Union
 
 from daft import context
 from daft.api_annotations import PublicAPI
@@ -26,6 +26,8 @@ def read_parquet(
     hive_partitioning: bool = False,
     coerce_int96_timestamp_unit: Optional[Union[str, TimeUnit]] = None,
     schema_hints: Optional[Dict[str, DataType]] = None,
+    schema_resolution: Literal["first", "union"] = "first",
+    column_mapping: Literal["name", "field_id"] = "name",
     _multithreaded_io: Optional[bool] = None,
     _chunk_size: Optional[int] = None,  # A hidden parameter for testing purposes.
 ) -> DataFrame:
@@ -40,6 +42,13 @@ def read_parquet(
         file_path_column: Include the source path(s) as a column with this name. Defaults to None.
         hive_partitioning: Whether to infer hive_style partitions from file paths and include them as columns in the Dataframe. Defaults to False.
         coerce_int96_timestamp_unit: TimeUnit to coerce Int96 TimeStamps to. e.g.: [ns, us, ms], Defaults to None.
+        schema_resolution: How to resolve the schemas of files whose schemas have drifted apart. "first" uses the schema of the first file, reading
+            columns that are missing from other files as nulls. "union" uses the union of the schemas of all files, with columns in the order they're
+            first seen and their types safely widened to fit all files (e.g. int32 to int64, or float to double), which raises an error naming the
+            files if a column's types can't be widened to a common type. Defaults to "first".
+        column_mapping: How to match the columns of each file to the columns of the schema. "name" matches them by name. "field_id" matches them by
+            their Parquet field IDs, Iceberg-style, under the names that they have in the first file, so that renamed columns are still read. Columns
+            without field IDs aren't read. Defaults to "name".
         _multithreaded_io: Whether to use multithreading for IO threads. Setting this to False can be helpful in reducing
             the amount of system resources (number of connections and thread contention) when running in the Ray runner.
             Defaults to None, which will let Daft decide based on the runner it is currently using.
@@ -94,5 +103,7 @@ def read_parquet(
         storage_config=storage_config,
         file_path_column=file_path_column,
         hive_partitioning=hive_partitioning,
+        schema_resolution=schema_resolution,
+        column_mapping=column_mapping,
     )
     return DataFrame(builder)
@@ -32,7 +32,7 @@ def read_sql(
 
     Args:
         sql (str): SQL query to execute
-        conn (Union[Callable[[], Connection], str]): SQLAlchemy connection factory or database URL
+        conn (Union[Callable[[], Connection], str]): SQLAlchemy or ADBC connection factory or database URL
         partition_col (Optional[str]): Column to partition the data by, defaults to None
         num_partitions (Optional[int]): Number of partitions to read the data into,
             defaults to None, which will lets Daft determine the number of partitions.
@@ -61,6 +61,8 @@ def read_sql(
         3. **Execution**:
             Daft executes SQL queries using using [ConnectorX](https://sfu-db.github.io/connector-x/intro.html) or [SQLAlchemy](https://docs.sqlalchemy.org/en/20/orm/quickstart.html#create-an-engine),
             preferring ConnectorX unless a SQLAlchemy connection factory is specified or the database dialect is unsupported by ConnectorX.
+            Queries are executed with [ADBC](https://arrow.apache.org/adbc/) when an ADBC connection factory is specified,
+            in which case the dialect is taken from the vendor name that the driver reports.
 
         4. **Pushdowns**:
             Daft pushes down operations such as filtering, projections, and limits into the SQL query when possible.
@@ -77,6 +79,12 @@ def read_sql(
         ...     return sqlalchemy.create_engine("sqlite:///my_database.db").connect()
         >>> df = daft.read_sql("SELECT * FROM my_table", create_conn)
 
+        Read data from a SQL query and an ADBC connection factory:
+
+        >>> def create_adbc_conn():
+        ...     return adbc_driver_postgresql.dbapi.connect("postgresql://localhost:5432/postgres")
+        >>> df = daft.read_sql("SELECT * FROM my_table", create_adbc_conn)
+
         Read data from a SQL query and partition the data by a column:
 
         >>> df = daft.read_sql("SELECT * FROM my_table", "sqlite:///my_database.db", partition_col="id")
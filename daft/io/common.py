@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Literal
 
 from daft.daft import FileFormatConfig, ScanOperatorHandle, StorageConfig
 from daft.logical.builder import LogicalPlanBuilder
@@ -25,6 +25,8 @@ def get_tabular_files_scan(
     storage_config: StorageConfig,
     file_path_column: str | None = None,
     hive_partitioning: bool = False,
+    schema_resolution: Literal["first", "union"] = "first",
+    column_mapping: Literal["name", "field_id"] = "name",
 ) -> LogicalPlanBuilder:
     """Returns a TabularFilesScan LogicalPlan for a given glob filepath."""
     # Glob the path using the Runner
@@ -44,6 +46,8 @@ def get_tabular_files_scan(
         schema=_get_schema_from_dict(schema)._schema if schema is not None else None,
         file_path_column=file_path_column,
         hive_partitioning=hive_partitioning,
+        schema_resolution=schema_resolution,
+        column_mapping=column_mapping,
     )
 
     builder = LogicalPlanBuilder.from_tabular_scan(
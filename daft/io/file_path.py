@@ -28,7 +28,15 @@ def from_glob_path(path: str, io_config: Optional[IOConfig] = None) -> DataFrame
 
     1. path: the path to the file/directory
     2. size: size of the object in bytes
-    3. type: either "file" or "directory"
+    3. num_rows: the number of rows in the file, which is always null
+    4. last_modified: when the object was last modified, as a UTC timestamp
+    5. etag: the entity tag of the object
+    6. version_id: the version of the object, which is its generation on GCS and its version ID on Azure
+    7. storage_class: the storage class of the object, which is its access tier on Azure
+
+    Metadata that the listings of a filesystem don't report is null, which is all of `etag`, `version_id` and
+    `storage_class` for local files, and all of it but `size` when the path matches a single file without wildcards.
+    Modification times are reported for local files, S3, GCS, Azure and HDFS.
 
     Args:
         path (str): Path to files on disk (allows wildcards).
@@ -41,6 +49,13 @@ def from_glob_path(path: str, io_config: Optional[IOConfig] = None) -> DataFrame
         >>> df = daft.from_glob_path("/path/to/files/*.jpeg")
         >>> df = daft.from_glob_path("/path/to/files/**/*.jpeg")
         >>> df = daft.from_glob_path("/path/to/files/**/image-?.jpeg")
+
+        Listing the files that haven't been modified in a year, for a retention job:
+
+        >>> import datetime
+        >>> cutoff = datetime.datetime.now(datetime.timezone.utc) - datetime.timedelta(days=365)
+        >>> df = daft.from_glob_path("s3://bucket/logs/**")
+        >>> df = df.where(df["last_modified"] < cutoff).select("path", "size", "storage_class")
     """
     context = get_context()
     io_config = context.daft_planning_config.default_io_config if io_config is None else io_config
@@ -53,3 +53,10 @@ class ScanOperator(abc.ABC):
     @abc.abstractmethod
     def to_scan_tasks(self, pushdowns: Pushdowns) -> Iterator[ScanTask]:
         raise NotImplementedError()
+
+    def snapshot_id(self) -> str | None:
+        """Identifies the version of the data that this operator reads, e.g. a table format's snapshot id.
+
+        Used to invalidate cached plans and results when the underlying data changes. Defaults to None.
+        """
+        return None
@@ -0,0 +1,220 @@
+"""Stable API to read custom formats and data services as first-class Daft sources.
+
+A `DataSource` describes the data that it reads and splits it into `DataSourceTask`s, each of which reads a part of
+it on a worker. Sources are given the projections, predicates and limits that the query optimizer pushes down into
+them, which they may apply in whole, in part, or not at all, since Daft applies them again to what tasks read.
+"""
+
+from __future__ import annotations
+
+from abc import ABC, abstractmethod
+from dataclasses import dataclass
+from typing import TYPE_CHECKING, Union
+
+from daft.daft import ScanOperatorHandle, ScanTask
+from daft.dependencies import pa
+from daft.expressions import Expression
+from daft.io.scan import PartitionField, ScanOperator
+from daft.recordbatch import MicroPartition, RecordBatch
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+    from daft.daft import Pushdowns as PyPushdowns
+    from daft.daft import PyRecordBatch
+    from daft.dataframe import DataFrame
+    from daft.logical.schema import Schema
+
+__all__ = [
+    "DataSource",
+    "DataSourcePushdowns",
+    "DataSourceTask",
+]
+
+DataSourceBatch = Union[RecordBatch, MicroPartition, "pa.RecordBatch", "pa.Table"]
+
+
+@dataclass(frozen=True)
+class DataSourcePushdowns:
+    """The operations of a query that Daft pushes down into the scan of a source.
+
+    Attributes:
+        columns: The names of the columns that the query reads, or None if it reads all of them.
+        filters: The predicate that the query filters rows with, or None.
+        partition_filters: The predicate on the partition fields of the source that the query filters rows with, or None.
+        limit: The number of rows that the query reads at most, or None.
+    """
+
+    columns: list[str] | None = None
+    filters: Expression | None = None
+    partition_filters: Expression | None = None
+    limit: int | None = None
+
+    @staticmethod
+    def _from_pypushdowns(pushdowns: PyPushdowns) -> DataSourcePushdowns:
+        return DataSourcePushdowns(
+            columns=pushdowns.columns,
+            filters=Expression._from_pyexpr(pushdowns.filters) if pushdowns.filters is not None else None,
+            partition_filters=(
+                Expression._from_pyexpr(pushdowns.partition_filters)
+                if pushdowns.partition_filters is not None
+                else None
+            ),
+            limit=pushdowns.limit,
+        )
+
+
+class DataSourceTask(ABC):
+    """A part of a `DataSource`, which is pickled and read on a worker.
+
+    Tasks may read all the columns of their source or only those that the pushdowns of the scan that created them
+    select, as the batches that they read are cast to the columns of the scan, which drops the others.
+    """
+
+    @abstractmethod
+    def get_batches(self) -> Iterator[DataSourceBatch]:
+        """Reads the data of the task as Daft record batches or micropartitions, or as Arrow record batches or tables."""
+        ...
+
+    @property
+    def num_rows(self) -> int | None:
+        """The number of rows that the task reads, if it's known before reading them, which planning uses."""
+        return None
+
+    @property
+    def size_bytes(self) -> int | None:
+        """An estimate of the in-memory size of the data that the task reads, which planning uses to size partitions."""
+        return None
+
+
+class DataSource(ABC):
+    """A custom source of data that Daft reads in parallel by splitting it into tasks.
+
+    Examples:
+        >>> import pyarrow as pa
+        >>> from daft import Schema
+        >>> from daft.io.source import DataSource, DataSourceTask
+        >>>
+        >>> SCHEMA = Schema.from_pyarrow_schema(pa.schema([("x", pa.int64())]))
+        >>>
+        >>> class RangeTask(DataSourceTask):
+        ...     def __init__(self, start, end):
+        ...         self._start, self._end = start, end
+        ...
+        ...     def get_batches(self):
+        ...         yield pa.table({"x": pa.array(range(self._start, self._end), type=pa.int64())})
+        >>>
+        >>> class RangeSource(DataSource):
+        ...     @property
+        ...     def name(self):
+        ...         return "RangeSource"
+        ...
+        ...     @property
+        ...     def schema(self):
+        ...         return SCHEMA
+        ...
+        ...     def get_tasks(self, pushdowns):
+        ...         yield RangeTask(0, 3)
+        ...         yield RangeTask(3, 5)
+        >>>
+        >>> RangeSource().read().sort("x").to_pydict()
+        {'x': [0, 1, 2, 3, 4]}
+    """
+
+    @property
+    @abstractmethod
+    def name(self) -> str:
+        """The name of the source, which plans are displayed with."""
+        ...
+
+    @property
+    @abstractmethod
+    def schema(self) -> Schema:
+        """The schema of the data of the source."""
+        ...
+
+    def get_partition_fields(self) -> list[PartitionField]:
+        """The fields that the data of the source is partitioned by, which predicates are split into `partition_filters` on."""
+        return []
+
+    def display_lines(self) -> list[str]:
+        """The lines that the source is displayed with in explained plans, after its name."""
+        return [f"Schema = {self.schema}"]
+
+    @abstractmethod
+    def get_tasks(self, pushdowns: DataSourcePushdowns) -> Iterator[DataSourceTask]:
+        """Splits the data of the source that a scan with `pushdowns` reads into tasks."""
+        ...
+
+    def read(self) -> DataFrame:
+        """Creates a DataFrame that reads the data of the source."""
+        from daft.dataframe import DataFrame
+        from daft.logical.builder import LogicalPlanBuilder
+
+        handle = ScanOperatorHandle.from_python_scan_operator(_DataSourceScanOperator(self))
+        return DataFrame(LogicalPlanBuilder.from_tabular_scan(scan_operator=handle))
+
+
+def _get_record_batches(task: DataSourceTask) -> Iterator[PyRecordBatch]:
+    """Reads the batches of a task on a worker, as the record batches that scan tasks of Python functions return."""
+    for batch in task.get_batches():
+        if isinstance(batch, RecordBatch):
+            yield batch._table
+        elif isinstance(batch, MicroPartition):
+            yield batch.to_record_batch()._table
+        elif isinstance(batch, pa.RecordBatch):
+            yield RecordBatch.from_arrow_record_batches([batch], batch.schema)._table
+        elif isinstance(batch, pa.Table):
+            yield RecordBatch.from_arrow(batch)._table
+        else:
+            raise TypeError(
+                f"DataSourceTask.get_batches must yield Daft or Arrow record batches or tables, got: {type(batch)}"
+            )
+
+
+class _DataSourceScanOperator(ScanOperator):
+    """Adapts a `DataSource` to the scan operators of the query planner.
+
+    Filters and limits aren't absorbed, so Daft applies them again to what tasks read, while projections are, as the
+    batches of tasks are cast to the columns that the scan reads.
+    """
+
+    def __init__(self, source: DataSource) -> None:
+        self._source = source
+
+    def name(self) -> str:
+        return self._source.name
+
+    def display_name(self) -> str:
+        return f"DataSource({self._source.name})"
+
+    def schema(self) -> Schema:
+        return self._source.schema
+
+    def partitioning_keys(self) -> list[PartitionField]:
+        return self._source.get_partition_fields()
+
+    def can_absorb_filter(self) -> bool:
+        return False
+
+    def can_absorb_limit(self) -> bool:
+        return False
+
+    def can_absorb_select(self) -> bool:
+        return True
+
+    def multiline_display(self) -> list[str]:
+        return [self.display_name(), *self._source.display_lines()]
+
+    def to_scan_tasks(self, pushdowns: PyPushdowns) -> Iterator[ScanTask]:
+        for task in self._source.get_tasks(DataSourcePushdowns._from_pypushdowns(pushdowns)):
+            yield ScanTask.python_factory_func_scan_task(
+                module=_get_record_batches.__module__,
+                func_name=_get_record_batches.__name__,
+                func_args=(task,),
+                schema=self._source.schema._schema,
+                num_rows=task.num_rows,
+                size_bytes=task.size_bytes,
+                pushdowns=pushdowns,
+                stats=None,
+            )
@@ -1,8 +1,9 @@
+import contextlib
 import uuid
 from abc import ABC, abstractmethod
 from typing import TYPE_CHECKING, List, Optional
 
-from daft.daft import IOConfig
+from daft.daft import IOConfig, ObjectSink
 from daft.delta_lake.delta_lake_write import make_deltalake_add_action, make_deltalake_fs, sanitize_table_for_deltalake
 from daft.dependencies import pa, pacsv, pq
 from daft.filesystem import (
@@ -39,11 +40,15 @@ class FileWriterBase(ABC):
         io_config: Optional[IOConfig] = None,
         version: Optional[int] = None,
         default_partition_fallback: Optional[str] = None,
+        escape_partition_values: bool = True,
     ):
         self.resolved_path, self.fs = self.resolve_path_and_fs(root_dir, io_config=io_config)
+        self.io_config = io_config
         self.protocol = get_protocol_from_path(root_dir)
         canonicalized_protocol = canonicalize_protocol(self.protocol)
         is_local_fs = canonicalized_protocol == "file"
+        # Files on object stores that support streaming uploads are written through an ObjectSink
+        self.sink_protocol = canonicalized_protocol if canonicalized_protocol in ("s3", "gs") else None
 
         self.file_name = (
             f"{uuid.uuid4()}-{file_idx}.{file_format}"
@@ -64,6 +69,7 @@ class FileWriterBase(ABC):
                     if default_partition_fallback is not None
                     else "__HIVE_DEFAULT_PARTITION__"
                 ),
+                escape=escape_partition_values,
             )
         else:
             self.partition_strings = {}
@@ -75,11 +81,31 @@ class FileWriterBase(ABC):
 
         self.compression = compression if compression is not None else "none"
         self.position = 0
+        self.file_handle = None
+        self.is_closed = False
 
     def resolve_path_and_fs(self, root_dir: str, io_config: Optional[IOConfig] = None):
         [resolved_path], fs = _resolve_paths_and_filesystem(root_dir, io_config=io_config)
         return resolved_path, fs
 
+    def open_output_stream(self):
+        """Opens the file for writing.
+
+        Files on S3 and GCS are uploaded in parts while they are being written, with a bounded number of parts in
+        flight, so that a slow store blocks the writer rather than the encoded file being buffered in memory.
+        """
+        if self.sink_protocol is not None:
+            from daft.context import get_context
+
+            execution_config = get_context().daft_execution_config
+            return ObjectSink(
+                f"{self.sink_protocol}://{self.full_path}",
+                part_size=execution_config.upload_part_size,
+                max_inflight_parts=execution_config.max_inflight_upload_parts,
+                io_config=self.io_config,
+            )
+        return self.fs.open_output_stream(self.full_path)
+
     @abstractmethod
     def write(self, table: MicroPartition) -> int:
         """Write data to the file using the appropriate writer.
@@ -101,6 +127,25 @@ class FileWriterBase(ABC):
         """
         pass
 
+    def abort(self) -> None:
+        """Abandon the file, e.g. because the query writing it was cancelled, removing whatever was written of it.
+
+        Neither write nor close should be called after abort.
+        """
+        if self.is_closed:
+            return
+        self.is_closed = True
+        if self.file_handle is None:
+            return
+        if isinstance(self.file_handle, ObjectSink):
+            # Nothing of the file shows up in the store until its upload completes
+            self.file_handle.abort()
+            return
+        with contextlib.suppress(Exception):
+            self.file_handle.close()
+        with contextlib.suppress(FileNotFoundError):
+            self.fs.delete_file(self.full_path)
+
 
 class ParquetFileWriter(FileWriterBase):
     def __init__(
@@ -113,6 +158,7 @@ class ParquetFileWriter(FileWriterBase):
         version: Optional[int] = None,
         default_partition_fallback: Optional[str] = None,
         metadata_collector: Optional[List[pq.FileMetaData]] = None,
+        escape_partition_values: bool = True,
     ):
         super().__init__(
             root_dir=root_dir,
@@ -123,8 +169,8 @@ class ParquetFileWriter(FileWriterBase):
             io_config=io_config,
             version=version,
             default_partition_fallback=default_partition_fallback,
+            escape_partition_values=escape_partition_values,
         )
-        self.is_closed = False
         self.current_writer: Optional[pq.ParquetWriter] = None
         self.metadata_collector: Optional[List[pq.FileMetaData]] = metadata_collector
 
@@ -132,12 +178,12 @@ class ParquetFileWriter(FileWriterBase):
         opts = {}
         if self.metadata_collector is not None:
             opts["metadata_collector"] = self.metadata_collector
+        self.file_handle = self.open_output_stream()
         return pq.ParquetWriter(
-            self.full_path,
+            self.file_handle,
             schema,
             compression=self.compression,
             use_compliant_nested_type=False,
-            filesystem=self.fs,
             **opts,
         )
 
@@ -147,7 +193,8 @@ class ParquetFileWriter(FileWriterBase):
             self.current_writer = self._create_writer(table.schema().to_pyarrow_schema())
         self.current_writer.write_table(table.to_arrow(), row_group_size=len(table))
 
-        current_position = self.current_writer.file_handle.tell()
+        assert self.file_handle is not None  # We should have created the file handle in _create_writer
+        current_position = self.file_handle.tell()
         bytes_written = current_position - self.position
         self.position = current_position
         return bytes_written
@@ -155,6 +202,8 @@ class ParquetFileWriter(FileWriterBase):
     def close(self) -> RecordBatch:
         if self.current_writer is not None:
             self.current_writer.close()
+        if self.file_handle is not None:
+            self.file_handle.close()
 
         self.is_closed = True
         metadata = {"path": Series.from_pylist([self.full_path])}
@@ -179,12 +228,10 @@ class CSVFileWriter(FileWriterBase):
             partition_values=partition_values,
             io_config=io_config,
         )
-        self.file_handle = None
         self.current_writer: Optional[pacsv.CSVWriter] = None
-        self.is_closed = False
 
     def _create_writer(self, schema: pa.Schema) -> pacsv.CSVWriter:
-        self.file_handle = self.fs.open_output_stream(self.full_path)
+        self.file_handle = self.open_output_stream()
         return pacsv.CSVWriter(
             self.file_handle,
             schema,
@@ -205,6 +252,8 @@ class CSVFileWriter(FileWriterBase):
     def close(self) -> RecordBatch:
         if self.current_writer is not None:
             self.current_writer.close()
+        if self.file_handle is not None:
+            self.file_handle.close()
 
         self.is_closed = True
         metadata = {"path": Series.from_pylist([self.full_path])}
@@ -254,7 +303,8 @@ class IcebergWriter(ParquetFileWriter):
         casted = coerce_pyarrow_table_to_schema(table.to_arrow(), self.file_schema)
         self.current_writer.write_table(casted)
 
-        current_position = self.current_writer.file_handle.tell()
+        assert self.file_handle is not None  # We should have created the file handle in _create_writer
+        current_position = self.file_handle.tell()
         bytes_written = current_position - self.position
         self.position = current_position
         return bytes_written
@@ -262,6 +312,8 @@ class IcebergWriter(ParquetFileWriter):
     def close(self) -> RecordBatch:
         if self.current_writer is not None:
             self.current_writer.close()
+        if self.file_handle is not None:
+            self.file_handle.close()
         self.is_closed = True
 
         assert self.metadata_collector is not None
@@ -299,6 +351,8 @@ class DeltalakeWriter(ParquetFileWriter):
             version=version,
             default_partition_fallback=None,
             metadata_collector=[],
+            # Paths in the log of the table are URIs, which readers would decode escaped partition values of.
+            escape_partition_values=False,
         )
 
         self.large_dtypes = large_dtypes
@@ -306,6 +360,10 @@ class DeltalakeWriter(ParquetFileWriter):
     def resolve_path_and_fs(self, root_dir: str, io_config: Optional[IOConfig] = None):
         return "", make_deltalake_fs(root_dir, io_config)
 
+    def open_output_stream(self):
+        # Paths are relative to the table, so files are written through the filesystem of the table
+        return self.fs.open_output_stream(self.full_path)
+
     def write(self, table: MicroPartition) -> int:
         assert not self.is_closed, "Cannot write to a closed DeltalakeFileWriter"
 
@@ -318,7 +376,8 @@ class DeltalakeWriter(ParquetFileWriter):
             self.current_writer = self._create_writer(converted_arrow_table.schema)
         self.current_writer.write_table(converted_arrow_table)
 
-        current_position = self.current_writer.file_handle.tell()
+        assert self.file_handle is not None  # We should have created the file handle in _create_writer
+        current_position = self.file_handle.tell()
         bytes_written = current_position - self.position
         self.position = current_position
         return bytes_written
@@ -326,6 +385,8 @@ class DeltalakeWriter(ParquetFileWriter):
     def close(self) -> RecordBatch:
         if self.current_writer is not None:
             self.current_writer.close()
+        if self.file_handle is not None:
+            self.file_handle.close()
         self.is_closed = True
 
         assert self.metadata_collector is not None
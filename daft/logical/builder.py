@@ -5,6 +5,7 @@ from typing import TYPE_CHECKING, Callable
 
 from daft.context import get_context
 from daft.daft import (
+    AsofStrategy,
     CountMode,
     FileFormat,
     IOConfig,
@@ -24,6 +25,7 @@ if TYPE_CHECKING:
 
     from pyiceberg.table import Table as IcebergTable
 
+    from daft.daft import DeltaLakeWrite, IcebergWrite
     from daft.plan_scheduler.physical_plan_scheduler import (
         AdaptivePhysicalPlanScheduler,
         PhysicalPlanScheduler,
@@ -98,6 +100,27 @@ class LogicalPlanBuilder:
         builder = self._builder.summarize()
         return LogicalPlanBuilder(builder)
 
+    def analyze(self) -> LogicalPlanBuilder:
+        builder = self._builder.analyze()
+        return LogicalPlanBuilder(builder)
+
+    def with_source_statistics(
+        self,
+        num_rows: int,
+        columns: dict[str, tuple[int, int, Expression | None, Expression | None]],
+    ) -> LogicalPlanBuilder:
+        columns_pyexprs = {
+            name: (
+                null_count,
+                approx_distinct_count,
+                min._expr if min is not None else None,
+                max._expr if max is not None else None,
+            )
+            for name, (null_count, approx_distinct_count, min, max) in columns.items()
+        }
+        builder = self._builder.with_source_statistics(num_rows, columns_pyexprs)
+        return LogicalPlanBuilder(builder)
+
     def pretty_print(self, simple: bool = False, format: str = "ascii") -> str:
         """Pretty prints the current underlying logical plan."""
         from daft.dataframe.display import MermaidOptions
@@ -117,6 +140,24 @@ class LogicalPlanBuilder:
         builder = self._builder.optimize()
         return LogicalPlanBuilder(builder)
 
+    def fingerprint(self) -> int | None:
+        """Stable hash of the underlying logical plan, used to reuse optimized plans and results within a session.
+
+        Returns None for plans that write data, which must not be reused.
+        """
+        return self._builder.fingerprint()
+
+    def durable_fingerprint(self) -> str | None:
+        """Hash of the underlying logical plan that is stable across processes, used to find checkpointed stage outputs.
+
+        Returns None for plans that scan in-memory data, which can't be checkpointed.
+        """
+        return self._builder.durable_fingerprint()
+
+    def in_memory_cache_keys(self) -> list[str]:
+        """Cache keys of the in-memory partition sets that this plan scans."""
+        return self._builder.in_memory_cache_keys()
+
     @classmethod
     @_apply_daft_planning_config_to_initializer
     def from_in_memory_scan(
@@ -126,6 +167,7 @@ class LogicalPlanBuilder:
         num_partitions: int,
         size_bytes: int,
         num_rows: int,
+        persisted: bool = False,
     ) -> LogicalPlanBuilder:
         builder = _LogicalPlanBuilder.in_memory_scan(
             cache_entry.key,
@@ -134,6 +176,7 @@ class LogicalPlanBuilder:
             num_partitions,
             size_bytes,
             num_rows,
+            persisted,
         )
         return cls(builder)
 
@@ -177,13 +220,16 @@ class LogicalPlanBuilder:
         builder = self._builder.filter(predicate._expr)
         return LogicalPlanBuilder(builder)
 
+    def in_subquery(self, expr: Expression) -> Expression:
+        return Expression._from_pyexpr(self._builder.in_subquery(expr._expr))
+
     def limit(self, num_rows: int, eager: bool) -> LogicalPlanBuilder:
         builder = self._builder.limit(num_rows, eager)
         return LogicalPlanBuilder(builder)
 
-    def explode(self, explode_expressions: list[Expression]) -> LogicalPlanBuilder:
+    def explode(self, explode_expressions: list[Expression], unnest_structs: bool = False) -> LogicalPlanBuilder:
         explode_pyexprs = [expr._expr for expr in explode_expressions]
-        builder = self._builder.explode(explode_pyexprs)
+        builder = self._builder.explode(explode_pyexprs, unnest_structs)
         return LogicalPlanBuilder(builder)
 
     def unpivot(
@@ -251,6 +297,17 @@ class LogicalPlanBuilder:
         builder = self._builder.aggregate([expr._expr for expr in to_agg], group_by_pyexprs)
         return LogicalPlanBuilder(builder)
 
+    def agg_grouping_sets(
+        self,
+        to_agg: list[Expression],
+        group_by: list[Expression],
+        grouping_sets: list[list[int]],
+    ) -> LogicalPlanBuilder:
+        builder = self._builder.aggregate_grouping_sets(
+            [expr._expr for expr in to_agg], [expr._expr for expr in group_by], grouping_sets
+        )
+        return LogicalPlanBuilder(builder)
+
     def map_groups(self, udf: Expression, group_by: list[Expression] | None) -> LogicalPlanBuilder:
         group_by_pyexprs = [expr._expr for expr in group_by] if group_by is not None else []
         builder = self._builder.aggregate([udf._expr], group_by_pyexprs)
@@ -289,6 +346,31 @@ class LogicalPlanBuilder:
         )
         return LogicalPlanBuilder(builder)
 
+    def asof_join(
+        self,
+        right: LogicalPlanBuilder,
+        left_on: Expression,
+        right_on: Expression,
+        left_by: list[Expression],
+        right_by: list[Expression],
+        strategy: AsofStrategy,
+        tolerance: Expression | None = None,
+        prefix: str | None = None,
+        suffix: str | None = None,
+    ) -> LogicalPlanBuilder:
+        builder = self._builder.asof_join(
+            right._builder,
+            left_on._expr,
+            right_on._expr,
+            [expr._expr for expr in left_by],
+            [expr._expr for expr in right_by],
+            strategy,
+            tolerance._expr if tolerance is not None else None,
+            prefix,
+            suffix,
+        )
+        return LogicalPlanBuilder(builder)
+
     def concat(self, other: LogicalPlanBuilder) -> LogicalPlanBuilder:  # type: ignore[override]
         builder = self._builder.concat(other._builder)
         return LogicalPlanBuilder(builder)
@@ -325,16 +407,19 @@ class LogicalPlanBuilder:
         io_config: IOConfig,
         partition_cols: list[Expression] | None = None,
         compression: str | None = None,
+        max_open_writers: int | None = None,
     ) -> LogicalPlanBuilder:
         if file_format != FileFormat.Csv and file_format != FileFormat.Parquet:
             raise ValueError(f"Writing is only supported for Parquet and CSV file formats, but got: {file_format}")
         part_cols_pyexprs = [expr._expr for expr in partition_cols] if partition_cols is not None else None
         builder = self._builder.table_write(
-            str(root_dir), write_mode, file_format, part_cols_pyexprs, compression, io_config
+            str(root_dir), write_mode, file_format, part_cols_pyexprs, compression, io_config, max_open_writers
         )
         return LogicalPlanBuilder(builder)
 
-    def write_iceberg(self, table: IcebergTable, io_config: IOConfig) -> LogicalPlanBuilder:
+    def write_iceberg(
+        self, table: IcebergTable, io_config: IOConfig, native_write: IcebergWrite | None = None
+    ) -> LogicalPlanBuilder:
         from daft.iceberg.iceberg_write import get_missing_columns, partition_field_to_expr
 
         name = ".".join(table.name())
@@ -351,7 +436,7 @@ class LogicalPlanBuilder:
         props = table.properties
         columns = [col.name for col in schema.columns]
         builder = builder.iceberg_write(
-            name, location, partition_spec.spec_id, partition_cols, schema, props, columns, io_config
+            name, location, partition_spec.spec_id, partition_cols, schema, props, columns, io_config, native_write
         )
         return LogicalPlanBuilder(builder)
 
@@ -363,6 +448,7 @@ class LogicalPlanBuilder:
         large_dtypes: bool,
         io_config: IOConfig,
         partition_cols: list[str] | None = None,
+        native_write: DeltaLakeWrite | None = None,
     ) -> LogicalPlanBuilder:
         columns_name = self.schema().column_names()
         builder = self._builder.delta_write(
@@ -373,6 +459,7 @@ class LogicalPlanBuilder:
             large_dtypes,
             partition_cols,
             io_config,
+            native_write,
         )
         return LogicalPlanBuilder(builder)
 
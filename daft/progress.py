@@ -0,0 +1,253 @@
+"""Structured progress of running queries, for showing it somewhere other than the terminal progress bar.
+
+Progress is reported to callbacks that are registered with [progress_callback][daft.progress.progress_callback], e.g.
+
+```python
+with daft.progress.progress_callback(lambda progress: print(progress.completed_tasks, progress.total_tasks)):
+    df.collect()
+```
+
+or can be iterated over from another thread with a [ProgressIterator][daft.progress.ProgressIterator].
+
+Progress is reported by the process that schedules the query, so queries that the Ray runner runs in Ray client mode
+don't report any.
+"""
+
+from __future__ import annotations
+
+import contextlib
+import logging
+import queue
+import threading
+import time
+import uuid
+from dataclasses import dataclass
+from typing import TYPE_CHECKING, Callable, Hashable, Iterator
+
+if TYPE_CHECKING:
+    from daft.execution.execution_step import PartitionTask
+
+logger = logging.getLogger(__name__)
+
+
+@dataclass(frozen=True)
+class StageProgress:
+    """The progress of a stage of a query.
+
+    The Ray runner reports the stages of the physical plan, which run as tasks. The native runner reports each operator
+    of its pipeline as a stage with a single task, which completes when the operator finishes.
+    """
+
+    stage_id: int
+    name: str
+    total_tasks: int
+    completed_tasks: int
+    # The rows and bytes output by the stage so far. Bytes are None if the size of some of the outputs isn't known.
+    rows_processed: int
+    bytes_processed: int | None
+
+
+@dataclass(frozen=True)
+class QueryProgress:
+    """The progress of a query, with the progress of each of its stages in the order they started."""
+
+    query_id: str
+    stages: tuple[StageProgress, ...]
+    elapsed_seconds: float
+    finished: bool
+
+    @property
+    def total_tasks(self) -> int:
+        return sum(stage.total_tasks for stage in self.stages)
+
+    @property
+    def completed_tasks(self) -> int:
+        return sum(stage.completed_tasks for stage in self.stages)
+
+    @property
+    def estimated_seconds_remaining(self) -> float | None:
+        """Estimated from the rate at which tasks completed so far, or None if no task has completed yet.
+
+        Stages are only known once they start, so the estimate doesn't account for stages that haven't started yet.
+        """
+        if self.finished:
+            return 0.0
+        completed = self.completed_tasks
+        if completed == 0:
+            return None
+        return self.elapsed_seconds * (self.total_tasks - completed) / completed
+
+
+ProgressCallback = Callable[[QueryProgress], None]
+
+_callbacks: list[ProgressCallback] = []
+_callbacks_lock = threading.Lock()
+
+
+@contextlib.contextmanager
+def progress_callback(callback: ProgressCallback) -> Iterator[None]:
+    """Calls `callback` with the progress of every query that runs while in the context, from any thread.
+
+    The callback is called from the thread that schedules the query, at most every half a second per query and once
+    more when the query finishes, so it should return quickly. Errors that it raises are logged and otherwise ignored.
+    """
+    with _callbacks_lock:
+        _callbacks.append(callback)
+    try:
+        yield
+    finally:
+        with _callbacks_lock:
+            _callbacks.remove(callback)
+
+
+class ProgressIterator:
+    """A progress callback that can be iterated over, e.g. from a different thread than the one running the query.
+
+    Iteration ends after the progress of the first query that finishes while the iterator is registered.
+
+    Example:
+        >>> progress = daft.progress.ProgressIterator()
+        >>> def run():
+        ...     with daft.progress.progress_callback(progress):
+        ...         df.collect()
+        >>> threading.Thread(target=run).start()
+        >>> for update in progress:
+        ...     print(f"{update.completed_tasks}/{update.total_tasks} tasks")
+    """
+
+    def __init__(self) -> None:
+        self._queue: queue.Queue[QueryProgress] = queue.Queue()
+        self._done = False
+
+    def __call__(self, progress: QueryProgress) -> None:
+        self._queue.put(progress)
+
+    def __iter__(self) -> ProgressIterator:
+        return self
+
+    def __next__(self) -> QueryProgress:
+        if self._done:
+            raise StopIteration
+        progress = self._queue.get()
+        self._done = progress.finished
+        return progress
+
+
+@dataclass
+class _StageState:
+    stage_id: int
+    name: str
+    total_tasks: int = 0
+    completed_tasks: int = 0
+    rows_processed: int = 0
+    bytes_processed: int | None = 0
+
+
+class QueryProgressTracker:
+    """Tracks the progress of a query as its runner starts and finishes tasks, and reports it to callbacks.
+
+    Stages are identified by keys chosen by the runner, and numbered in the order that they start.
+    """
+
+    REPORT_INTERVAL_SECONDS = 0.5
+
+    def __init__(self, callbacks: list[ProgressCallback], clock: Callable[[], float] = time.monotonic) -> None:
+        self._callbacks = callbacks
+        self._clock = clock
+        self._query_id = str(uuid.uuid4())
+        self._start = clock()
+        self._last_report: float | None = None
+        self._stages: dict[Hashable, _StageState] = {}
+        self._lock = threading.Lock()
+
+    def _stage(self, key: Hashable, name: str) -> _StageState:
+        if key not in self._stages:
+            self._stages[key] = _StageState(stage_id=len(self._stages), name=name)
+        return self._stages[key]
+
+    def task_started(self, stage_key: Hashable, name: str) -> None:
+        with self._lock:
+            self._stage(stage_key, name).total_tasks += 1
+        self._report(finished=False)
+
+    def task_finished(self, stage_key: Hashable, rows: int, size_bytes: int | None) -> None:
+        with self._lock:
+            stage = self._stages[stage_key]
+            stage.completed_tasks += 1
+            stage.rows_processed += rows
+            if stage.bytes_processed is not None:
+                stage.bytes_processed = None if size_bytes is None else stage.bytes_processed + size_bytes
+        self._report(finished=False)
+
+    def set_rows_processed(self, stage_key: Hashable, rows: int) -> None:
+        """Sets the rows output by a stage so far, for runners that count them while the stage runs."""
+        with self._lock:
+            stage = self._stages[stage_key]
+            stage.rows_processed = rows
+            stage.bytes_processed = None
+        self._report(finished=False)
+
+    def finish(self) -> None:
+        self._report(finished=True)
+
+    def progress(self, finished: bool = False) -> QueryProgress:
+        with self._lock:
+            return QueryProgress(
+                query_id=self._query_id,
+                stages=tuple(
+                    StageProgress(
+                        stage_id=stage.stage_id,
+                        name=stage.name,
+                        total_tasks=stage.total_tasks,
+                        completed_tasks=stage.completed_tasks,
+                        rows_processed=stage.rows_processed,
+                        bytes_processed=stage.bytes_processed,
+                    )
+                    for stage in self._stages.values()
+                ),
+                elapsed_seconds=self._clock() - self._start,
+                finished=finished,
+            )
+
+    def _report(self, finished: bool) -> None:
+        now = self._clock()
+        with self._lock:
+            if not finished and self._last_report is not None and now - self._last_report < self.REPORT_INTERVAL_SECONDS:
+                return
+            self._last_report = now
+        progress = self.progress(finished)
+        for callback in self._callbacks:
+            try:
+                callback(progress)
+            except Exception:
+                logger.exception("Progress callback %s failed", callback)
+
+
+def task_output_size(task: PartitionTask) -> tuple[int, int | None]:
+    """The rows and bytes output by a task that is done, or None bytes if the size of some output isn't known."""
+    from daft.execution.execution_step import SingleOutputPartitionTask
+
+    if isinstance(task, SingleOutputPartitionTask):
+        metadatas = [task.partition_metadata()]
+    else:
+        metadatas = task.partition_metadatas()
+    sizes = [metadata.size_bytes for metadata in metadatas]
+    return (
+        sum(metadata.num_rows for metadata in metadatas),
+        None if any(size is None for size in sizes) else sum(sizes),  # type: ignore[misc]
+    )
+
+
+@contextlib.contextmanager
+def query_progress() -> Iterator[QueryProgressTracker | None]:
+    """Tracks the progress of a query if any progress callbacks are registered, yielding its tracker, or None if not."""
+    with _callbacks_lock:
+        callbacks = list(_callbacks)
+    if not callbacks:
+        yield None
+        return
+    tracker = QueryProgressTracker(callbacks)
+    try:
+        yield tracker
+    finally:
+        tracker.finish()
@@ -98,6 +98,20 @@ class MicroPartition:
         pyt = _PyMicroPartition.from_arrow_record_batches(rbs, schema._schema)
         return MicroPartition._from_pymicropartition(pyt)
 
+    @staticmethod
+    def from_arrow_c_stream(data: Any) -> MicroPartition:
+        """Imports an object exposing `__arrow_c_stream__` (such as a polars DataFrame) through the Arrow PyCapsule interface."""
+        pyschema, pytables = _PyRecordBatch.from_arrow_c_stream(data.__arrow_c_stream__())
+        if not pytables:
+            return MicroPartition.empty(Schema._from_pyschema(pyschema))
+        return MicroPartition._from_pymicropartition(_PyMicroPartition.from_tables(pytables))
+
+    @staticmethod
+    def to_arrow_c_stream(parts: list[MicroPartition], schema: Schema) -> object:
+        """Exports the MicroPartitions as the `arrow_array_stream` PyCapsule of the Arrow PyCapsule interface."""
+        pytables = [part._micropartition.to_record_batch() for part in parts]
+        return _PyRecordBatch.to_arrow_c_stream(pytables, schema._schema)
+
     @staticmethod
     def from_pandas(pd_df: pd.DataFrame) -> MicroPartition:
         table = RecordBatch.from_pandas(pd_df)
@@ -254,10 +268,10 @@ class MicroPartition:
     def quantiles(self, num: int) -> MicroPartition:
         return MicroPartition._from_pymicropartition(self._micropartition.quantiles(num))
 
-    def explode(self, columns: ExpressionsProjection) -> MicroPartition:
+    def explode(self, columns: ExpressionsProjection, unnest_structs: bool = False) -> MicroPartition:
         """NOTE: Expressions here must be Explode expressions."""
         to_explode_pyexprs = [e._expr for e in columns]
-        return MicroPartition._from_pymicropartition(self._micropartition.explode(to_explode_pyexprs))
+        return MicroPartition._from_pymicropartition(self._micropartition.explode(to_explode_pyexprs, unnest_structs))
 
     def unpivot(
         self, ids: ExpressionsProjection, values: ExpressionsProjection, variable_name: str, value_name: str
@@ -7,13 +7,26 @@ from daft.recordbatch.recordbatch import RecordBatch
 from .micropartition import MicroPartition
 
 
+# Characters that Hive escapes in the names of partition directories, so that partition values can't add directory
+# levels or be mistaken for other keys and values. Readers of Hive-style partitions URL-decode them.
+HIVE_ESCAPED_CHARS = frozenset('"#%\'*/:=?\\{[]^\x7f') | frozenset(chr(c) for c in range(1, 0x20))
+
+
+def escape_partition_string(value: str) -> str:
+    return "".join(f"%{ord(c):02X}" if c in HIVE_ESCAPED_CHARS else c for c in value)
+
+
 def partition_strings_to_path(
     root_path: str,
     parts: Dict[str, str],
     partition_null_fallback: str = "__HIVE_DEFAULT_PARTITION__",
+    escape: bool = True,
 ) -> str:
     keys = parts.keys()
     values = [partition_null_fallback if value is None else value for value in parts.values()]
+    if escape:
+        keys = [escape_partition_string(k) for k in keys]
+        values = [v if v == partition_null_fallback else escape_partition_string(v) for v in values]
     postfix = "/".join(f"{k}={v}" for k, v in zip(keys, values))
     return f"{root_path}/{postfix}"
 
@@ -0,0 +1,89 @@
+from __future__ import annotations
+
+import logging
+import os
+import pickle
+from typing import TYPE_CHECKING
+
+from pyarrow import fs as pafs
+
+from daft.filesystem import _resolve_paths_and_filesystem
+
+if TYPE_CHECKING:
+    from daft.daft import IOConfig, PyDaftExecutionConfig
+    from daft.logical.builder import LogicalPlanBuilder
+    from daft.recordbatch import MicroPartition
+    from daft.runners.partitioning import PartitionMetadata
+
+logger = logging.getLogger(__name__)
+
+# Written to the directory of a stage once all of its partitions are, holding the number of partitions
+_COMPLETED_MARKER = "_COMPLETED"
+
+
+class StageCheckpoints:
+    """Persists the outputs of the completed stages of a query, so that running the query again resumes after them.
+
+    The outputs of each stage are written to `{checkpoint_dir}/{plan fingerprint}/stage_{stage_id}/`, one file per
+    partition, and once all of them are written, a marker that holds the number of partitions. A stage without the
+    marker, e.g. because the query failed while it was being checkpointed, is run again.
+    """
+
+    def __init__(self, query_dir: str, io_config: IOConfig | None) -> None:
+        self.query_dir = query_dir
+        self.io_config = io_config
+
+    @classmethod
+    def for_query(
+        cls,
+        builder: LogicalPlanBuilder,
+        execution_config: PyDaftExecutionConfig,
+        io_config: IOConfig | None,
+    ) -> StageCheckpoints | None:
+        """Returns the checkpoints of the query, or None if checkpointing is disabled or the query can't be checkpointed."""
+        if not execution_config.checkpoint_dir:
+            return None
+        fingerprint = builder.durable_fingerprint()
+        if fingerprint is None:
+            logger.warning("Not checkpointing a query that scans in-memory data, since it can't be resumed")
+            return None
+        return cls(f"{execution_config.checkpoint_dir.rstrip('/')}/{fingerprint}", io_config)
+
+    def partition_path(self, stage_id: int, partition_idx: int) -> str:
+        return f"{self._stage_dir(stage_id)}/partition_{partition_idx}"
+
+    def completed_num_partitions(self, stage_id: int) -> int | None:
+        """The number of partitions of the stage if it was checkpointed completely, or None otherwise."""
+        [path], fs = _resolve_paths_and_filesystem(f"{self._stage_dir(stage_id)}/{_COMPLETED_MARKER}", self.io_config)
+        if fs.get_file_info(path).type == pafs.FileType.NotFound:
+            return None
+        with fs.open_input_stream(path) as f:
+            return int(f.read().decode())
+
+    def mark_completed(self, stage_id: int, num_partitions: int) -> None:
+        _write_file(
+            f"{self._stage_dir(stage_id)}/{_COMPLETED_MARKER}", str(num_partitions).encode(), self.io_config
+        )
+
+    def _stage_dir(self, stage_id: int) -> str:
+        return f"{self.query_dir}/stage_{stage_id}"
+
+
+def write_checkpoint_partition(
+    path: str, partition: MicroPartition, metadata: PartitionMetadata, io_config: IOConfig | None
+) -> None:
+    _write_file(path, pickle.dumps((partition, metadata)), io_config)
+
+
+def read_checkpoint_partition(path: str, io_config: IOConfig | None) -> tuple[MicroPartition, PartitionMetadata]:
+    [resolved_path], fs = _resolve_paths_and_filesystem(path, io_config)
+    with fs.open_input_stream(resolved_path) as f:
+        return pickle.loads(f.read())
+
+
+def _write_file(path: str, data: bytes, io_config: IOConfig | None) -> None:
+    [resolved_path], fs = _resolve_paths_and_filesystem(path, io_config)
+    if isinstance(fs, pafs.LocalFileSystem):
+        fs.create_dir(os.path.dirname(resolved_path), recursive=True)
+    with fs.open_output_stream(resolved_path) as f:
+        f.write(data)
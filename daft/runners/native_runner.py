@@ -7,11 +7,14 @@ from daft.context import get_context
 from daft.daft import FileFormatConfig, FileInfos, IOConfig, set_compute_runtime_num_worker_threads
 from daft.execution.native_executor import NativeExecutor
 from daft.filesystem import glob_path_with_stats
+from daft.progress import query_progress
 from daft.recordbatch import MicroPartition
 from daft.runners import runner_io
+from daft.runners.otel import query_span
 from daft.runners.partitioning import (
     LocalMaterializedResult,
     LocalPartitionSet,
+    MaterializedResult,
     PartitionCacheEntry,
     PartitionSetCache,
 )
@@ -59,6 +62,10 @@ class NativeRunner(Runner[MicroPartition]):
         return NativeRunnerIO()
 
     def run(self, builder: LogicalPlanBuilder) -> PartitionCacheEntry:
+        cached_result = self.get_cached_result(builder)
+        if cached_result is not None:
+            return cached_result
+
         results = list(self.run_iter(builder))
 
         result_pset = LocalPartitionSet()
@@ -66,8 +73,17 @@ class NativeRunner(Runner[MicroPartition]):
             result_pset.set_partition(i, result)
 
         pset_entry = self.put_partition_set_into_cache(result_pset)
+        self.put_cached_result(builder, pset_entry)
         return pset_entry
 
+    def _get_scanned_partition_sets(self, builder: LogicalPlanBuilder) -> dict[str, list[MaterializedResult]]:
+        # The native executor takes every partition set it's given into memory up front, so only pass the ones that
+        # the plan scans, to avoid reading the spilled partitions of unrelated persisted results.
+        scanned_keys = set(builder.in_memory_cache_keys())
+        return {
+            k: v.values() for k, v in self._part_set_cache.get_all_partition_sets().items() if k in scanned_keys
+        }
+
     def run_iter(
         self,
         builder: LogicalPlanBuilder,
@@ -78,16 +94,27 @@ class NativeRunner(Runner[MicroPartition]):
         # NOTE: Freeze and use this same execution config for the entire execution
         daft_execution_config = get_context().daft_execution_config
 
-        # Optimize the logical plan.
-        builder = builder.optimize()
+        with query_span(self.name, daft_execution_config), query_progress() as progress:
+            # Optimize the logical plan.
+            builder = self.optimize(builder)
+            executor = NativeExecutor(progress)
+            results_gen = executor.run(
+                builder,
+                self._get_scanned_partition_sets(builder),
+                daft_execution_config,
+                results_buffer_size,
+            )
+            yield from results_gen
+
+    def explain_analyze(self, builder: LogicalPlanBuilder) -> str:
+        daft_execution_config = get_context().daft_execution_config
+        builder = self.optimize(builder)
         executor = NativeExecutor()
-        results_gen = executor.run(
+        return executor.explain_analyze(
             builder,
-            {k: v.values() for k, v in self._part_set_cache.get_all_partition_sets().items()},
+            self._get_scanned_partition_sets(builder),
             daft_execution_config,
-            results_buffer_size,
         )
-        yield from results_gen
 
     def run_iter_tables(
         self, builder: LogicalPlanBuilder, results_buffer_size: int | None = None
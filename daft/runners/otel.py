@@ -0,0 +1,234 @@
+from __future__ import annotations
+
+import contextlib
+import logging
+import os
+import threading
+import time
+from typing import TYPE_CHECKING, Any, Iterator
+
+if TYPE_CHECKING:
+    from opentelemetry.trace import Span
+
+    from daft.daft import PyDaftExecutionConfig
+    from daft.execution.execution_step import Instruction, PartitionTask
+    from daft.recordbatch import MicroPartition
+
+logger = logging.getLogger(__name__)
+
+_INSTRUMENTATION_NAME = "daft"
+
+_telemetry: ExecutionTelemetry | None = None
+_telemetry_lock = threading.Lock()
+
+
+def _configure_sdk() -> None:
+    """Exports spans and metrics over OTLP, unless the process has already set up OpenTelemetry itself.
+
+    The exporters are configured by the standard `OTEL_*` environment variables, e.g. `OTEL_EXPORTER_OTLP_ENDPOINT`.
+    """
+    from opentelemetry import metrics, trace
+
+    if not isinstance(trace.get_tracer_provider(), trace.ProxyTracerProvider):
+        return
+    try:
+        from opentelemetry.exporter.otlp.proto.grpc.metric_exporter import OTLPMetricExporter
+        from opentelemetry.exporter.otlp.proto.grpc.trace_exporter import OTLPSpanExporter
+        from opentelemetry.sdk.metrics import MeterProvider
+        from opentelemetry.sdk.metrics.export import PeriodicExportingMetricReader
+        from opentelemetry.sdk.resources import Resource
+        from opentelemetry.sdk.trace import TracerProvider
+        from opentelemetry.sdk.trace.export import BatchSpanProcessor
+    except ImportError:
+        logger.warning(
+            "OpenTelemetry is enabled, but its SDK or OTLP exporter isn't installed, so nothing will be exported. "
+            "Install them with `pip install 'daft[otel]'`, or set up OpenTelemetry before running queries."
+        )
+        return
+
+    resource = Resource.create({"service.name": os.environ.get("OTEL_SERVICE_NAME", "daft")})
+    tracer_provider = TracerProvider(resource=resource)
+    tracer_provider.add_span_processor(BatchSpanProcessor(OTLPSpanExporter()))
+    trace.set_tracer_provider(tracer_provider)
+    metrics.set_meter_provider(
+        MeterProvider(resource=resource, metric_readers=[PeriodicExportingMetricReader(OTLPMetricExporter())])
+    )
+
+
+def get_execution_telemetry() -> ExecutionTelemetry:
+    """Returns the telemetry of this process, setting up OpenTelemetry on first use."""
+    global _telemetry
+    with _telemetry_lock:
+        if _telemetry is None:
+            _configure_sdk()
+            _telemetry = ExecutionTelemetry()
+        return _telemetry
+
+
+class ExecutionTelemetry:
+    """Records the execution of queries as OpenTelemetry spans and metrics.
+
+    Each query gets a span, with a child span for each of its stages, and for each of the tasks that the scheduler runs.
+    Each instruction of a task gets a span on the worker that runs it. Spans are passed between threads and processes
+    as carriers, which are dicts of W3C trace context headers.
+    """
+
+    def __init__(self) -> None:
+        from opentelemetry import metrics, trace
+
+        self._tracer = trace.get_tracer(_INSTRUMENTATION_NAME)
+        meter = metrics.get_meter(_INSTRUMENTATION_NAME)
+        self._task_duration = meter.create_histogram(
+            "daft.task.duration", unit="s", description="Time from dispatching a task until its result is ready"
+        )
+        self._speculative_attempts = meter.create_counter(
+            "daft.task.speculative_attempts",
+            unit="{attempt}",
+            description="Copies of straggling tasks run by speculative execution",
+        )
+        self._task_retries = meter.create_counter(
+            "daft.task.retries", unit="{attempt}", description="Attempts of tasks run again after they failed"
+        )
+        self._operator_duration = meter.create_histogram(
+            "daft.operator.duration", unit="s", description="Time spent running an instruction of a task"
+        )
+        self._operator_rows = meter.create_counter(
+            "daft.operator.rows_out", unit="{row}", description="Rows output by the instructions of tasks"
+        )
+        self._operator_bytes = meter.create_counter(
+            "daft.operator.bytes_out", unit="By", description="Bytes output by the instructions of tasks"
+        )
+        # Spans and start times of the tasks that are running, by task ID
+        self._task_spans: dict[str, tuple[Span, float]] = {}
+
+    def _start_span(self, name: str, parent: dict[str, str] | None, attributes: dict[str, Any]) -> Span:
+        from opentelemetry import propagate
+
+        context = propagate.extract(parent) if parent is not None else None
+        return self._tracer.start_span(name, context=context, attributes=attributes)
+
+    @staticmethod
+    def _carrier(span: Span) -> dict[str, str]:
+        from opentelemetry import propagate, trace
+
+        carrier: dict[str, str] = {}
+        propagate.inject(carrier, context=trace.set_span_in_context(span))
+        return carrier
+
+    @contextlib.contextmanager
+    def span(self, name: str, parent: dict[str, str] | None, attributes: dict[str, Any]) -> Iterator[dict[str, str]]:
+        """Records a span for the duration of the context, and yields its carrier."""
+        from opentelemetry.trace import Status, StatusCode
+
+        span = self._start_span(name, parent, attributes)
+        try:
+            yield self._carrier(span)
+        except GeneratorExit:
+            raise
+        except BaseException as e:
+            span.record_exception(e)
+            span.set_status(Status(StatusCode.ERROR))
+            raise
+        finally:
+            span.end()
+
+    def task_started(self, task: PartitionTask, parent: dict[str, str] | None) -> dict[str, str]:
+        """Starts the span of a task that is being dispatched, and returns its carrier."""
+        span = self._start_span(
+            "daft.task",
+            parent,
+            {"daft.task.id": task.id(), "daft.task.name": task.name(), "daft.stage_id": task.stage_id},
+        )
+        self._task_spans[task.id()] = (span, time.monotonic())
+        return self._carrier(span)
+
+    def task_speculated(self, task: PartitionTask) -> dict[str, str] | None:
+        """Records that a copy of a task was started, and returns the carrier of the task's span."""
+        self._speculative_attempts.add(1)
+        if task.id() not in self._task_spans:
+            return None
+        span, _ = self._task_spans[task.id()]
+        span.add_event("speculative attempt started")
+        return self._carrier(span)
+
+    def task_retried(self, task: PartitionTask) -> dict[str, str] | None:
+        """Records that a failed task was run again, and returns the carrier of the task's span."""
+        self._task_retries.add(1)
+        if task.id() not in self._task_spans:
+            return None
+        span, _ = self._task_spans[task.id()]
+        span.add_event("retry started")
+        return self._carrier(span)
+
+    def task_finished(self, task: PartitionTask) -> None:
+        if task.id() not in self._task_spans:
+            return
+        span, start = self._task_spans.pop(task.id())
+        self._task_duration.record(
+            time.monotonic() - start,
+            {"daft.task.instructions": "-".join(i.__class__.__name__ for i in task.instructions)},
+        )
+        span.end()
+
+    def end_task_spans(self) -> None:
+        """Ends the spans of the tasks that didn't finish, e.g. because the query failed or was cancelled."""
+        for span, _ in self._task_spans.values():
+            span.end()
+        self._task_spans.clear()
+
+    @contextlib.contextmanager
+    def operator_span(
+        self, instruction: Instruction, parent: dict[str, str]
+    ) -> Iterator[list[list[MicroPartition]]]:
+        """Records a span and metrics for running an instruction.
+
+        Yields a list that the outputs of the instruction should be appended to, to record their size.
+        """
+        name = instruction.__class__.__name__
+        outputs: list[list[MicroPartition]] = []
+        start = time.monotonic()
+        with self.span(f"daft.operator.{name}", parent, {"daft.operator": name}):
+            yield outputs
+        attributes = {"daft.operator": name}
+        self._operator_duration.record(time.monotonic() - start, attributes)
+        for partitions in outputs:
+            self._operator_rows.add(sum(len(p) for p in partitions), attributes)
+            self._operator_bytes.add(sum(p.size_bytes() or 0 for p in partitions), attributes)
+
+
+@contextlib.contextmanager
+def query_span(runner: str, execution_config: PyDaftExecutionConfig) -> Iterator[dict[str, str] | None]:
+    """Records the span of a query if OpenTelemetry is enabled, yielding its carrier, or None if it isn't."""
+    if not execution_config.enable_opentelemetry:
+        yield None
+        return
+    with get_execution_telemetry().span("daft.query", None, {"daft.runner": runner}) as carrier:
+        yield carrier
+
+
+@contextlib.contextmanager
+def stage_span(parent: dict[str, str] | None, stage_id: int | None) -> Iterator[dict[str, str] | None]:
+    """Records the span of a stage of a query whose span is `parent`, or does nothing if `parent` is None."""
+    if parent is None:
+        yield None
+        return
+    attributes = {"daft.stage_id": stage_id} if stage_id is not None else {"daft.stage.final": True}
+    with get_execution_telemetry().span("daft.stage", parent, attributes) as carrier:
+        yield carrier
+
+
+def run_instructions(
+    instructions: list[Instruction], partitions: list[MicroPartition], parent: dict[str, str] | None
+) -> list[MicroPartition]:
+    """Runs the instructions of a task, recording a span for each if the task has the span `parent`."""
+    if parent is None:
+        for instruction in instructions:
+            partitions = instruction.run(partitions)
+        return partitions
+
+    telemetry = get_execution_telemetry()
+    for instruction in instructions:
+        with telemetry.operator_span(instruction, parent) as outputs:
+            partitions = instruction.run(partitions)
+            outputs.append(partitions)
+    return partitions
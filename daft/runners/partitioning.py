@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+import os
+import pickle
 import threading
 import weakref
 from abc import abstractmethod
@@ -323,10 +325,10 @@ class LocalPartitionSet(PartitionSet[MicroPartition]):
         return idx in self._partitions
 
     def __len__(self) -> int:
-        return sum(len(partition.partition()) for partition in self._partitions.values())
+        return sum(partition.metadata().num_rows for partition in self._partitions.values())
 
     def size_bytes(self) -> int | None:
-        size_bytes_ = [partition.partition().size_bytes() for partition in self._partitions.values()]
+        size_bytes_ = [partition.metadata().size_bytes for partition in self._partitions.values()]
         size_bytes: list[int] = [size for size in size_bytes_ if size is not None]
         if len(size_bytes) != len(size_bytes_):
             return None
@@ -339,6 +341,21 @@ class LocalPartitionSet(PartitionSet[MicroPartition]):
     def wait(self) -> None:
         pass
 
+    def spill_partition(self, idx: PartID, spill_dir: str) -> None:
+        """Writes a partition to a file in `spill_dir` and releases it from memory.
+
+        The file is removed once the partition is no longer referenced by any partition set.
+        """
+        part = self._partitions[idx]
+        if isinstance(part, SpilledMaterializedResult):
+            return
+        path = os.path.join(spill_dir, f"{uuid4().hex}.partition")
+        with open(path, "wb") as f:
+            pickle.dump(part.micropartition(), f)
+        spilled = SpilledMaterializedResult(path, part.metadata())
+        weakref.finalize(spilled, os.remove, path)
+        self._partitions[idx] = spilled
+
 
 @dataclass
 class LocalMaterializedResult(MaterializedResult[MicroPartition]):
@@ -363,6 +380,30 @@ class LocalMaterializedResult(MaterializedResult[MicroPartition]):
         return None
 
 
+@dataclass
+class SpilledMaterializedResult(MaterializedResult[MicroPartition]):
+    """A partition that was spilled to disk, which is read back into memory whenever it is accessed."""
+
+    _path: str
+    _metadata: PartitionMetadata
+
+    def partition(self) -> MicroPartition:
+        with open(self._path, "rb") as f:
+            return pickle.load(f)
+
+    def micropartition(self) -> MicroPartition:
+        return self.partition()
+
+    def metadata(self) -> PartitionMetadata:
+        return self._metadata
+
+    def cancel(self) -> None:
+        return None
+
+    def _noop(self, _: MicroPartition) -> None:
+        return None
+
+
 @dataclass(eq=False, repr=False)
 class PartitionCacheEntry:
     key: str
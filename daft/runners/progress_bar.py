@@ -6,6 +6,7 @@ from typing import TYPE_CHECKING, Any
 
 if TYPE_CHECKING:
     from daft.execution.execution_step import PartitionTask
+    from daft.progress import QueryProgressTracker
 
 
 def get_tqdm(use_ray_tqdm: bool) -> Any:
@@ -42,8 +43,16 @@ def get_tqdm(use_ray_tqdm: bool) -> Any:
 
 
 class ProgressBar:
-    def __init__(self, use_ray_tqdm: bool, show_tasks_bar: bool = False, disable: bool = False) -> None:
+    def __init__(
+        self,
+        use_ray_tqdm: bool,
+        show_tasks_bar: bool = False,
+        disable: bool = False,
+        progress: QueryProgressTracker | None = None,
+    ) -> None:
         self.show_tasks_bar = show_tasks_bar
+        # Tracks the progress of the query that the tasks belong to, regardless of whether the bars are shown
+        self.progress = progress
         self._maxinterval = 5.0
 
         self.use_ray_tqdm = use_ray_tqdm
@@ -69,7 +78,13 @@ class ProgressBar:
                 maxinterval=self._maxinterval,
             )
 
+    def _stage_key(self, step: PartitionTask[Any]) -> tuple[int, int]:
+        # A query may run several plans, whose stage IDs aren't unique across them
+        return (id(self), step.stage_id)
+
     def mark_task_start(self, step: PartitionTask[Any]) -> None:
+        if self.progress is not None:
+            self.progress.task_started(self._stage_key(step), step.name())
         if self.disable:
             return
         if self.show_tasks_bar:
@@ -93,6 +108,10 @@ class ProgressBar:
                     pb.refresh()
 
     def mark_task_done(self, step: PartitionTask[Any]) -> None:
+        if self.progress is not None:
+            from daft.progress import task_output_size
+
+            self.progress.task_finished(self._stage_key(step), *task_output_size(step))
         if self.disable:
             return
         stage_id = step.stage_id
@@ -17,8 +17,11 @@ from daft.execution.physical_plan import ActorPoolManager
 from daft.expressions import ExpressionsProjection
 from daft.filesystem import glob_path_with_stats
 from daft.internal.gpu import cuda_visible_devices
+from daft.progress import query_progress
 from daft.recordbatch import MicroPartition
 from daft.runners import runner_io
+from daft.runners.checkpoint import StageCheckpoints, read_checkpoint_partition, write_checkpoint_partition
+from daft.runners.otel import query_span, stage_span
 from daft.runners.partitioning import (
     LocalMaterializedResult,
     LocalPartitionSet,
@@ -37,6 +40,7 @@ if TYPE_CHECKING:
     from daft.execution import physical_plan
     from daft.execution.execution_step import Instruction, PartitionTask
     from daft.logical.builder import LogicalPlanBuilder
+    from daft.progress import QueryProgressTracker
 
 logger = logging.getLogger(__name__)
 
@@ -326,6 +330,10 @@ class PyRunner(Runner[MicroPartition], ActorPoolManager):
         return PyRunnerIO()
 
     def run(self, builder: LogicalPlanBuilder) -> PartitionCacheEntry:
+        cached_result = self.get_cached_result(builder)
+        if cached_result is not None:
+            return cached_result
+
         results = list(self.run_iter(builder))
 
         result_pset = LocalPartitionSet()
@@ -333,6 +341,7 @@ class PyRunner(Runner[MicroPartition], ActorPoolManager):
             result_pset.set_partition(i, result)
 
         pset_entry = self.put_partition_set_into_cache(result_pset)
+        self.put_cached_result(builder, pset_entry)
         return pset_entry
 
     def run_iter(
@@ -352,57 +361,88 @@ class PyRunner(Runner[MicroPartition], ActorPoolManager):
         daft_execution_config = get_context().daft_execution_config
         execution_id = str(uuid.uuid4())
 
-        # Optimize the logical plan.
-        builder = builder.optimize()
-
-        if daft_execution_config.enable_aqe:
-            adaptive_planner = builder.to_adaptive_physical_plan_scheduler(daft_execution_config)
-            while not adaptive_planner.is_done():
-                source_id, plan_scheduler = adaptive_planner.next()
-                # don't store partition sets in variable to avoid reference
-                tasks = plan_scheduler.to_partition_tasks(
-                    {k: v.values() for k, v in self._part_set_cache.get_all_partition_sets().items()},
-                    self,
-                    results_buffer_size,
-                )
-                del plan_scheduler
-                results_gen = self._physical_plan_to_partitions(execution_id, tasks)
-                # if source_id is none that means this is the final stage
-                if source_id is None:
-                    yield from results_gen
-                else:
-                    intermediate = LocalPartitionSet()
-                    for i, rg in enumerate(results_gen):
-                        intermediate.set_partition(i, rg)
-                    cache_entry = self._part_set_cache.put_partition_set(intermediate)
-                    del intermediate
-                    adaptive_planner.update(source_id, cache_entry)
-                    del cache_entry
-        else:
-            # Finalize the logical plan and get a physical plan scheduler for translating the
-            # physical plan to executable tasks.
-            if daft_execution_config.enable_native_executor:
-                logger.info("Using native executor")
-
-                executor = NativeExecutor()
-                results_gen = executor.run(
-                    builder,
-                    {k: v.values() for k, v in self._part_set_cache.get_all_partition_sets().items()},
-                    daft_execution_config,
-                    results_buffer_size,
-                )
-                yield from results_gen
+        with query_span(self.name, daft_execution_config) as query_carrier, query_progress() as progress:
+            # Optimize the logical plan.
+            builder = self.optimize(builder)
+            checkpoints = StageCheckpoints.for_query(
+                builder, daft_execution_config, get_context().daft_planning_config.default_io_config
+            )
+
+            if daft_execution_config.enable_aqe or checkpoints is not None:
+                adaptive_planner = builder.to_adaptive_physical_plan_scheduler(daft_execution_config)
+                while not adaptive_planner.is_done():
+                    source_id, plan_scheduler = adaptive_planner.next()
+                    with stage_span(query_carrier, source_id):
+                        if source_id is not None and checkpoints is not None:
+                            cache_entry = self._restore_checkpointed_stage(checkpoints, source_id)
+                            if cache_entry is not None:
+                                adaptive_planner.update(source_id, cache_entry)
+                                del cache_entry
+                                continue
+                        # don't store partition sets in variable to avoid reference
+                        tasks = plan_scheduler.to_partition_tasks(
+                            {k: v.values() for k, v in self._part_set_cache.get_all_partition_sets().items()},
+                            self,
+                            results_buffer_size,
+                        )
+                        del plan_scheduler
+                        results_gen = self._physical_plan_to_partitions(execution_id, tasks, progress)
+                        # if source_id is none that means this is the final stage
+                        if source_id is None:
+                            yield from results_gen
+                        else:
+                            intermediate = LocalPartitionSet()
+                            for i, rg in enumerate(results_gen):
+                                intermediate.set_partition(i, rg)
+                            if checkpoints is not None:
+                                self._checkpoint_stage(checkpoints, source_id, intermediate)
+                            cache_entry = self._part_set_cache.put_partition_set(intermediate)
+                            del intermediate
+                            adaptive_planner.update(source_id, cache_entry)
+                            del cache_entry
             else:
-                logger.info("Using python executor")
-
-                plan_scheduler = builder.to_physical_plan_scheduler(daft_execution_config)
-                psets = {k: v.values() for k, v in self._part_set_cache.get_all_partition_sets().items()}
-                # Get executable tasks from planner.
-                tasks = plan_scheduler.to_partition_tasks(psets, self, results_buffer_size)
-                del psets
-                with profiler("profile_PyRunner.run_{datetime.now().isoformat()}.json"):
-                    results_gen = self._physical_plan_to_partitions(execution_id, tasks)
+                # Finalize the logical plan and get a physical plan scheduler for translating the
+                # physical plan to executable tasks.
+                if daft_execution_config.enable_native_executor:
+                    logger.info("Using native executor")
+
+                    executor = NativeExecutor(progress)
+                    results_gen = executor.run(
+                        builder,
+                        {k: v.values() for k, v in self._part_set_cache.get_all_partition_sets().items()},
+                        daft_execution_config,
+                        results_buffer_size,
+                    )
                     yield from results_gen
+                else:
+                    logger.info("Using python executor")
+
+                    plan_scheduler = builder.to_physical_plan_scheduler(daft_execution_config)
+                    psets = {k: v.values() for k, v in self._part_set_cache.get_all_partition_sets().items()}
+                    # Get executable tasks from planner.
+                    tasks = plan_scheduler.to_partition_tasks(psets, self, results_buffer_size)
+                    del psets
+                    with profiler("profile_PyRunner.run_{datetime.now().isoformat()}.json"):
+                        results_gen = self._physical_plan_to_partitions(execution_id, tasks, progress)
+                        yield from results_gen
+
+    def _checkpoint_stage(self, checkpoints: StageCheckpoints, stage_id: int, pset: LocalPartitionSet) -> None:
+        for i, result in pset.items():
+            write_checkpoint_partition(
+                checkpoints.partition_path(stage_id, i), result.micropartition(), result.metadata(), checkpoints.io_config
+            )
+        checkpoints.mark_completed(stage_id, pset.num_partitions())
+
+    def _restore_checkpointed_stage(self, checkpoints: StageCheckpoints, stage_id: int) -> PartitionCacheEntry | None:
+        num_partitions = checkpoints.completed_num_partitions(stage_id)
+        if num_partitions is None:
+            return None
+        logger.info("Restoring the output of stage %s from its checkpoint", stage_id)
+        pset = LocalPartitionSet()
+        for i in range(num_partitions):
+            partition, metadata = read_checkpoint_partition(checkpoints.partition_path(stage_id, i), checkpoints.io_config)
+            pset.set_partition(i, LocalMaterializedResult(partition, metadata))
+        return self._part_set_cache.put_partition_set(pset)
 
     def run_iter_tables(
         self, builder: LogicalPlanBuilder, results_buffer_size: int | None = None
@@ -457,9 +497,10 @@ class PyRunner(Runner[MicroPartition], ActorPoolManager):
         self,
         execution_id: str,
         plan: physical_plan.MaterializedPhysicalPlan[MicroPartition],
+        progress: QueryProgressTracker | None = None,
     ) -> Iterator[LocalMaterializedResult]:
         local_futures_to_task: dict[futures.Future, PartitionTask] = {}
-        pbar = ProgressBar(use_ray_tqdm=False)
+        pbar = ProgressBar(use_ray_tqdm=False, progress=progress)
 
         try:
             next_step = next(plan)
@@ -600,9 +641,11 @@ class PyRunner(Runner[MicroPartition], ActorPoolManager):
             # Close the progress bar
             pbar.close()
 
-            # Cleanup any remaining inflight futures/results from this local execution
-            for (exec_id, task_id), _ in list(self._inflight_futures.items()):
+            # Cleanup any remaining inflight futures/results from this local execution, cancelling the tasks that haven't
+            # started yet so that an interrupted execution doesn't keep running in the background
+            for (exec_id, task_id), future in list(self._inflight_futures.items()):
                 if exec_id == execution_id:
+                    future.cancel()
                     del self._inflight_futures[(exec_id, task_id)]
 
     def build_partitions(
@@ -54,12 +54,20 @@ from daft.execution.execution_step import (
     ReduceInstruction,
     ScanWithTask,
     SingleOutputPartitionTask,
+    WriteDeltaLake,
+    WriteFile,
+    WriteIceberg,
+    WriteLance,
+    WriteShuffleMapOutput,
 )
 from daft.execution.physical_plan import ActorPoolManager
 from daft.expressions import ExpressionsProjection
 from daft.filesystem import glob_path_with_stats
+from daft.progress import query_progress
 from daft.recordbatch import MicroPartition
 from daft.runners import runner_io
+from daft.runners.checkpoint import StageCheckpoints, read_checkpoint_partition, write_checkpoint_partition
+from daft.runners.otel import get_execution_telemetry, query_span, run_instructions, stage_span
 from daft.runners.partitioning import (
     LocalPartitionSet,
     MaterializedResult,
@@ -70,7 +78,9 @@ from daft.runners.partitioning import (
     PartitionSetCache,
 )
 from daft.runners.profiler import profiler
+from daft.runners.retry import FailedTaskReport, PartitionTaskFailedError, TaskRetries, TaskRetryPolicy
 from daft.runners.runner import Runner
+from daft.runners.speculation import SpeculationTracker
 
 if TYPE_CHECKING:
     import dask
@@ -80,6 +90,8 @@ if TYPE_CHECKING:
 
     from daft.logical.builder import LogicalPlanBuilder
     from daft.plan_scheduler import PhysicalPlanScheduler
+    from daft.progress import QueryProgressTracker
+    from daft.runners.otel import ExecutionTelemetry
     from daft.runners.ray_tracing import RunnerTracer
 
 _RAY_FROM_ARROW_REFS_AVAILABLE = True
@@ -463,11 +475,12 @@ def _get_ray_task_options(resource_request: ResourceRequest) -> dict[str, Any]:
 
 
 def build_partitions(
-    instruction_stack: list[Instruction], partial_metadatas: list[PartitionMetadata], *inputs: MicroPartition
+    instruction_stack: list[Instruction],
+    partial_metadatas: list[PartitionMetadata],
+    *inputs: MicroPartition,
+    trace_carrier: dict[str, str] | None = None,
 ) -> list[list[PartitionMetadata] | MicroPartition]:
-    partitions = list(inputs)
-    for instruction in instruction_stack:
-        partitions = instruction.run(partitions)
+    partitions = run_instructions(instruction_stack, list(inputs), trace_carrier)
 
     assert len(partial_metadatas) == len(partitions), f"{len(partial_metadatas)} vs {len(partitions)}"
 
@@ -481,6 +494,8 @@ class PartitionTaskContext:
     job_id: str
     task_id: str
     stage_id: int
+    # Propagates the OpenTelemetry span of the task to the worker, if OpenTelemetry is enabled
+    trace_carrier: dict[str, str] | None = None
 
 
 # Give the same function different names to aid in profiling data distribution.
@@ -500,7 +515,9 @@ def single_partition_pipeline(
     ), ray_tracing.collect_ray_task_metrics(
         task_context.job_id, task_context.task_id, task_context.stage_id, daft_execution_config
     ):
-        return build_partitions(instruction_stack, partial_metadatas, *inputs)
+        return build_partitions(
+            instruction_stack, partial_metadatas, *inputs, trace_carrier=task_context.trace_carrier
+        )
 
 
 @ray_tracing.ray_remote_traced
@@ -515,7 +532,9 @@ def fanout_pipeline(
     with execution_config_ctx(config=daft_execution_config), ray_tracing.collect_ray_task_metrics(
         task_context.job_id, task_context.task_id, task_context.stage_id, daft_execution_config
     ):
-        return build_partitions(instruction_stack, partial_metadatas, *inputs)
+        return build_partitions(
+            instruction_stack, partial_metadatas, *inputs, trace_carrier=task_context.trace_carrier
+        )
 
 
 @ray_tracing.ray_remote_traced
@@ -532,7 +551,9 @@ def reduce_pipeline(
     with execution_config_ctx(config=daft_execution_config), ray_tracing.collect_ray_task_metrics(
         task_context.job_id, task_context.task_id, task_context.stage_id, daft_execution_config
     ):
-        return build_partitions(instruction_stack, partial_metadatas, *ray.get(inputs))
+        return build_partitions(
+            instruction_stack, partial_metadatas, *ray.get(inputs), trace_carrier=task_context.trace_carrier
+        )
 
 
 @ray_tracing.ray_remote_traced
@@ -549,7 +570,9 @@ def reduce_and_fanout(
     with execution_config_ctx(config=daft_execution_config), ray_tracing.collect_ray_task_metrics(
         task_context.job_id, task_context.task_id, task_context.stage_id, daft_execution_config
     ):
-        return build_partitions(instruction_stack, partial_metadatas, *ray.get(inputs))
+        return build_partitions(
+            instruction_stack, partial_metadatas, *ray.get(inputs), trace_carrier=task_context.trace_carrier
+        )
 
 
 @ray.remote
@@ -557,6 +580,19 @@ def get_metas(*partitions: MicroPartition) -> list[PartitionMetadata]:
     return [PartitionMetadata.from_table(partition) for partition in partitions]
 
 
+@ray.remote
+def _write_checkpoint_partition(
+    path: str, partition: MicroPartition, metadata: PartitionMetadata, io_config: IOConfig | None
+) -> None:
+    write_checkpoint_partition(path, partition, metadata, io_config)
+
+
+@ray.remote(num_returns=2)
+def _read_checkpoint_partition(path: str, io_config: IOConfig | None) -> tuple[list[PartitionMetadata], MicroPartition]:
+    partition, metadata = read_checkpoint_partition(path, io_config)
+    return [metadata], partition
+
+
 def _ray_num_cpus_provider(ttl_seconds: int = 1) -> Generator[int, None, None]:
     """Helper that gets the number of CPUs from Ray.
 
@@ -625,6 +661,8 @@ class Scheduler(ActorPoolManager):
         result_uuid: str,
         daft_execution_config: PyDaftExecutionConfig,
         results_buffer_size: int | None = None,
+        trace_carrier: dict[str, str] | None = None,
+        progress: QueryProgressTracker | None = None,
     ) -> None:
         self.results_by_df[result_uuid] = Queue(maxsize=1 if results_buffer_size is not None else -1)
         self.active_by_df[result_uuid] = True
@@ -638,6 +676,8 @@ class Scheduler(ActorPoolManager):
                 "psets": psets,
                 "result_uuid": result_uuid,
                 "daft_execution_config": daft_execution_config,
+                "trace_carrier": trace_carrier,
+                "progress": progress,
             },
         )
         t.start()
@@ -756,12 +796,17 @@ class Scheduler(ActorPoolManager):
         tasks_to_dispatch: list[PartitionTask],
         daft_execution_config_objref: ray.ObjectRef,
         runner_tracer: RunnerTracer,
+        telemetry: ExecutionTelemetry | None = None,
+        trace_carrier: dict[str, str] | None = None,
     ) -> Iterator[tuple[PartitionTask, list[ray.ObjectRef]]]:
         """Iteratively Dispatches a batch of tasks to the Ray backend."""
         with runner_tracer.dispatching():
             for task in tasks_to_dispatch:
+                task_carrier = telemetry.task_started(task, trace_carrier) if telemetry is not None else None
                 if task.actor_pool_id is None:
-                    results = _build_partitions(execution_id, daft_execution_config_objref, task, runner_tracer)
+                    results = _build_partitions(
+                        execution_id, daft_execution_config_objref, task, runner_tracer, task_carrier
+                    )
                 else:
                     actor_pool = self._actor_pools.get(task.actor_pool_id)
                     assert actor_pool is not None, "Ray actor pool must live for as long as the tasks."
@@ -776,20 +821,22 @@ class Scheduler(ActorPoolManager):
         inflight_ref_to_task_id: dict[ray.ObjectRef, str],
         inflight_tasks: dict[str, PartitionTask],
         runner_tracer: RunnerTracer,
+        first_timeout: float | None = None,
     ) -> list[ray.ObjectRef]:
         """Awaits for tasks to be completed. Returns tasks that are ready.
 
-        NOTE: This method blocks until at least 1 task is ready. Then it will return as many ready tasks as it can.
+        NOTE: This method blocks until at least 1 task is ready, or until `first_timeout` seconds have passed if it is set.
+        Then it will return as many ready tasks as it can.
         """
         if len(inflight_ref_to_task_id) == 0:
             return []
 
-        # Await on (any) task to be ready with an unlimited timeout
-        with runner_tracer.awaiting(1, None):
+        # Await on (any) task to be ready, with an unlimited timeout unless one is given
+        with runner_tracer.awaiting(1, first_timeout):
             ray.wait(
                 list(inflight_ref_to_task_id.keys()),
                 num_returns=1,
-                timeout=None,
+                timeout=first_timeout,
                 fetch_local=False,
             )
 
@@ -812,6 +859,134 @@ class Scheduler(ActorPoolManager):
 
         return readies
 
+    def _speculate_stragglers(
+        self,
+        execution_id: str,
+        speculation: SpeculationTracker,
+        inflight_tasks: dict[str, PartitionTask[ray.ObjectRef]],
+        inflight_ref_to_task: dict[ray.ObjectRef, str],
+        task_inputs: dict[str, list[ray.ObjectRef]],
+        speculative_results: dict[str, list[RayMaterializedResult]],
+        daft_execution_config_objref: ray.ObjectRef,
+        runner_tracer: RunnerTracer,
+        telemetry: ExecutionTelemetry | None,
+    ) -> None:
+        """Submits a second copy of each straggling task, whose result is used instead if it finishes first."""
+        for task_id in speculation.stragglers():
+            task = inflight_tasks[task_id]
+            task_carrier = telemetry.task_speculated(task) if telemetry is not None else None
+            results = _submit_partition_task(
+                execution_id,
+                daft_execution_config_objref,
+                task,
+                task_inputs[task_id],
+                runner_tracer,
+                task_carrier,
+            )
+            logger.debug("Speculatively running a copy of straggling task %s", task)
+            speculative_results[task_id] = results
+            for result in results:
+                inflight_ref_to_task[result.partition()] = task_id
+            speculation.task_speculated(task_id)
+
+    def _settle_speculation(
+        self,
+        task: PartitionTask[ray.ObjectRef],
+        ready: ray.ObjectRef,
+        speculative_results: dict[str, list[RayMaterializedResult]],
+    ) -> list[ray.ObjectRef]:
+        """Uses the result of the speculative copy of the task if it's the one that finished first.
+
+        Returns the partitions of the copy of the task that didn't finish first, which are no longer needed.
+        """
+        results = speculative_results.pop(task.id(), None)
+        if results is None:
+            return []
+        speculative_partitions = [result.partition() for result in results]
+        if ready not in speculative_partitions:
+            return speculative_partitions
+        if isinstance(task, SingleOutputPartitionTask):
+            original_partitions = [task.partition()]
+        else:
+            assert isinstance(task, MultiOutputPartitionTask)
+            original_partitions = task.partitions()
+        task.replace_result(results)
+        return original_partitions
+
+    def _drop_failed_attempt(
+        self,
+        task: PartitionTask[ray.ObjectRef],
+        ready: ray.ObjectRef,
+        retries: TaskRetries,
+        inflight_ref_to_task: dict[ray.ObjectRef, str],
+        speculative_results: dict[str, list[RayMaterializedResult]],
+        speculation: SpeculationTracker | None,
+    ) -> bool:
+        """Checks whether the attempt of the task that `ready` belongs to failed, and if so, drops it.
+
+        If another copy of the task is still running, that one is used instead. Otherwise, if the failure is likely
+        transient, the task is run again once its backoff is over. Returns whether the attempt was dropped, and raises
+        a PartitionTaskFailedError if the task failed again after it was retried, and won't be retried anymore.
+        """
+        own_results = [task.result()] if isinstance(task, SingleOutputPartitionTask) else task.results()
+        speculative = speculative_results.get(task.id())
+        if speculative is not None and ready in [result.partition() for result in speculative]:
+            failed, other = speculative, own_results
+        else:
+            failed, other = own_results, speculative
+        try:
+            failed[0].metadata()
+            return False
+        except Exception as e:
+            error = e
+
+        if other is None and not retries.task_failed(task.id(), error):
+            attempts = retries.attempts(task.id())
+            if len(attempts) > 1:
+                raise PartitionTaskFailedError(FailedTaskReport.for_task(task, attempts)) from error
+            # The error isn't worth retrying, so it is raised wherever the result of the task is used
+            return False
+
+        for result in failed:
+            inflight_ref_to_task.pop(result.partition(), None)
+        if other is not None:
+            del speculative_results[task.id()]
+            if failed is own_results:
+                task.replace_result(other)
+        else:
+            logger.warning("Retrying task %s, which failed with %r", task, error)
+            # Don't speculate a task while it waits to be retried
+            if speculation is not None:
+                speculation.task_speculated(task.id())
+        return True
+
+    def _retry_tasks(
+        self,
+        execution_id: str,
+        retries: TaskRetries,
+        inflight_tasks: dict[str, PartitionTask[ray.ObjectRef]],
+        inflight_ref_to_task: dict[ray.ObjectRef, str],
+        task_inputs: dict[str, list[ray.ObjectRef]],
+        daft_execution_config_objref: ray.ObjectRef,
+        runner_tracer: RunnerTracer,
+        telemetry: ExecutionTelemetry | None,
+    ) -> None:
+        """Runs the failed tasks whose backoff is over again, from the same inputs."""
+        for task_id in retries.due_retries():
+            task = inflight_tasks[task_id]
+            task_carrier = telemetry.task_retried(task) if telemetry is not None else None
+            results = _submit_partition_task(
+                execution_id,
+                daft_execution_config_objref,
+                task,
+                task_inputs[task_id],
+                runner_tracer,
+                task_carrier,
+            )
+            task.replace_result(results)
+            for result in results:
+                inflight_ref_to_task[result.partition()] = task_id
+
     def _is_active(self, execution_id: str):
         """Checks if the execution for the provided `execution_id` is still active."""
         return self.active_by_df.get(execution_id, False)
@@ -835,6 +1010,8 @@ class Scheduler(ActorPoolManager):
         psets: dict[str, ray.ObjectRef],
         result_uuid: str,
         daft_execution_config: PyDaftExecutionConfig,
+        trace_carrier: dict[str, str] | None = None,
+        progress: QueryProgressTracker | None = None,
     ) -> None:
         # Put execution config into cluster once to share it amongst all tasks
         daft_execution_config_objref = ray.put(daft_execution_config)
@@ -844,7 +1021,15 @@ class Scheduler(ActorPoolManager):
 
         inflight_tasks: dict[str, PartitionTask[ray.ObjectRef]] = dict()
         inflight_ref_to_task: dict[ray.ObjectRef, str] = dict()
-        pbar = ProgressBar(use_ray_tqdm=self.use_ray_tqdm)
+        speculation = SpeculationTracker.from_config(daft_execution_config)
+        retry_policy = TaskRetryPolicy.from_config(daft_execution_config)
+        retries = TaskRetries(retry_policy) if retry_policy is not None else None
+        # Inputs of the inflight tasks that may be speculated or retried, and the results of the speculative copies of tasks
+        task_inputs: dict[str, list[ray.ObjectRef]] = dict()
+        speculative_results: dict[str, list[RayMaterializedResult]] = dict()
+        # Records the spans of tasks as children of the stage or query that the plan runs, if OpenTelemetry is enabled
+        telemetry = get_execution_telemetry() if trace_carrier is not None else None
+        pbar = ProgressBar(use_ray_tqdm=self.use_ray_tqdm, progress=progress)
         num_cpus_provider = _ray_num_cpus_provider()
 
         start = datetime.now()
@@ -911,16 +1096,28 @@ class Scheduler(ActorPoolManager):
                             if not self._is_active(result_uuid):
                                 break
 
+                            # Keep the inputs of tasks that may be speculated or retried, since dispatching a task
+                            # releases them
+                            for task in tasks_to_dispatch:
+                                if (speculation is not None and _is_speculatable(task)) or (
+                                    retries is not None and task.actor_pool_id is None
+                                ):
+                                    task_inputs[task.id()] = list(task.inputs)
+
                             # Dispatch
                             for task, result_obj_refs in self._dispatch_tasks(
                                 result_uuid,
                                 tasks_to_dispatch,
                                 daft_execution_config_objref,
                                 runner_tracer,
+                                telemetry,
+                                trace_carrier,
                             ):
                                 inflight_tasks[task.id()] = task
                                 for result in result_obj_refs:
                                     inflight_ref_to_task[result] = task.id()
+                                if speculation is not None and _is_speculatable(task):
+                                    speculation.task_started(task.id(), task.stage_id)
 
                                 pbar.mark_task_start(task)
 
@@ -934,17 +1131,31 @@ class Scheduler(ActorPoolManager):
                         # Wait for some work to be completed from the current wave's dispatch
                         # Then we perform the necessary record-keeping on tasks that were retrieved as ready.
                         ###
+                        if retries is not None and not inflight_ref_to_task:
+                            # All the inflight tasks failed, and are waiting to be retried
+                            time.sleep(retries.seconds_until_next_retry() or 0)
                         readies = self._await_tasks(
                             inflight_ref_to_task,
                             inflight_tasks,
                             runner_tracer,
+                            # Wake up periodically to look for stragglers, and when tasks are due to be retried
+                            first_timeout=_first_await_timeout(speculation, retries),
                         )
                         for ready in readies:
                             if ready in inflight_ref_to_task:
                                 task_id = inflight_ref_to_task[ready]
+                                task = inflight_tasks[task_id]
+                                if (
+                                    retries is not None
+                                    and task_id in task_inputs
+                                    and self._drop_failed_attempt(
+                                        task, ready, retries, inflight_ref_to_task, speculative_results, speculation
+                                    )
+                                ):
+                                    continue
 
                                 # Mark the entire task associated with the result as done.
-                                task = inflight_tasks[task_id]
+                                unneeded_partitions = self._settle_speculation(task, ready, speculative_results)
                                 task.set_done()
 
                                 if isinstance(task, SingleOutputPartitionTask):
@@ -953,9 +1164,47 @@ class Scheduler(ActorPoolManager):
                                     for partition in task.partitions():
                                         del inflight_ref_to_task[partition]
 
+                                # Cancel the copy of a speculated task that didn't finish first
+                                for partition in unneeded_partitions:
+                                    del inflight_ref_to_task[partition]
+                                if unneeded_partitions:
+                                    ray.cancel(unneeded_partitions[0])
+
+                                if speculation is not None:
+                                    speculation.task_finished(task_id)
+                                if retries is not None:
+                                    retries.task_finished(task_id)
+                                task_inputs.pop(task_id, None)
+                                if telemetry is not None:
+                                    telemetry.task_finished(task)
+
                                 pbar.mark_task_done(task)
                                 del inflight_tasks[task_id]
 
+                        if retries is not None:
+                            self._retry_tasks(
+                                result_uuid,
+                                retries,
+                                inflight_tasks,
+                                inflight_ref_to_task,
+                                task_inputs,
+                                daft_execution_config_objref,
+                                runner_tracer,
+                                telemetry,
+                            )
+                        if speculation is not None:
+                            self._speculate_stragglers(
+                                result_uuid,
+                                speculation,
+                                inflight_tasks,
+                                inflight_ref_to_task,
+                                task_inputs,
+                                speculative_results,
+                                daft_execution_config_objref,
+                                runner_tracer,
+                                telemetry,
+                            )
+
             except StopIteration as e:
                 self._place_in_queue(result_uuid, e)
 
@@ -965,6 +1214,14 @@ class Scheduler(ActorPoolManager):
                 pbar.close()
                 raise
 
+            finally:
+                # Cancel the tasks that are still running if the plan failed or was stopped before it finished, e.g.
+                # because its results were abandoned after an interrupt, rather than leaving them to run to completion
+                for ref in inflight_ref_to_task:
+                    ray.cancel(ref)
+                if telemetry is not None:
+                    telemetry.end_task_spans()
+
         pbar.close()
 
     @contextlib.contextmanager
@@ -986,6 +1243,28 @@ class Scheduler(ActorPoolManager):
             self.teardown_actor_pool(name)
 
 
+# How often the scheduler looks for stragglers to speculate, when speculative execution is enabled
+SPECULATION_CHECK_INTERVAL_SECONDS = 1.0
+
+
+def _first_await_timeout(speculation: SpeculationTracker | None, retries: TaskRetries | None) -> float | None:
+    """How long the scheduler may wait for a task to be ready before it needs to look for stragglers or retry tasks."""
+    timeouts = []
+    if speculation is not None:
+        timeouts.append(SPECULATION_CHECK_INTERVAL_SECONDS)
+    if retries is not None and (retry_in := retries.seconds_until_next_retry()) is not None:
+        timeouts.append(retry_in)
+    return min(timeouts, default=None)
+
+
+def _is_speculatable(task: PartitionTask[ray.ObjectRef]) -> bool:
+    """Whether a second copy of the task may run alongside it, which needs it to be free of side effects."""
+    return task.actor_pool_id is None and not any(
+        isinstance(instruction, (WriteFile, WriteIceberg, WriteDeltaLake, WriteLance, WriteShuffleMapOutput))
+        for instruction in task.instructions
+    )
+
+
 SCHEDULER_ACTOR_NAME = "scheduler"
 SCHEDULER_ACTOR_NAMESPACE = "daft"
 
@@ -1002,8 +1281,26 @@ def _build_partitions(
     daft_execution_config_objref: ray.ObjectRef,
     task: PartitionTask[ray.ObjectRef],
     runner_tracer: RunnerTracer,
+    trace_carrier: dict[str, str] | None = None,
 ) -> list[ray.ObjectRef]:
     """Run a PartitionTask and return the resulting list of partitions."""
+    results = _submit_partition_task(
+        job_id, daft_execution_config_objref, task, task.inputs, runner_tracer, trace_carrier
+    )
+    task.inputs.clear()
+    task.set_result(results)
+    return [result.partition() for result in results]
+
+
+def _submit_partition_task(
+    job_id: str,
+    daft_execution_config_objref: ray.ObjectRef,
+    task: PartitionTask[ray.ObjectRef],
+    inputs: list[ray.ObjectRef],
+    runner_tracer: RunnerTracer,
+    trace_carrier: dict[str, str] | None = None,
+) -> list[RayMaterializedResult]:
+    """Submit a Ray task that runs the instructions of a PartitionTask over the given inputs."""
     ray_options: dict[str, Any] = {"num_returns": task.num_results + 1, "name": task.name()}
 
     if task.resource_request is not None:
@@ -1023,11 +1320,13 @@ def _build_partitions(
             ray_options["scheduling_strategy"] = "SPREAD"
         build_remote = build_remote.options(**ray_options).with_tracing(runner_tracer, task)
         [metadatas_ref, *partitions] = build_remote.remote(
-            PartitionTaskContext(job_id=job_id, task_id=task.id(), stage_id=task.stage_id),
+            PartitionTaskContext(
+                job_id=job_id, task_id=task.id(), stage_id=task.stage_id, trace_carrier=trace_carrier
+            ),
             daft_execution_config_objref,
             task.instructions,
             task.partial_metadatas,
-            task.inputs,
+            inputs,
         )
 
     else:
@@ -1040,27 +1339,24 @@ def _build_partitions(
             ray_options["scheduling_strategy"] = "SPREAD"
         build_remote = build_remote.options(**ray_options).with_tracing(runner_tracer, task)
         [metadatas_ref, *partitions] = build_remote.remote(
-            PartitionTaskContext(job_id=job_id, task_id=task.id(), stage_id=task.stage_id),
+            PartitionTaskContext(
+                job_id=job_id, task_id=task.id(), stage_id=task.stage_id, trace_carrier=trace_carrier
+            ),
             daft_execution_config_objref,
             task.instructions,
             task.partial_metadatas,
-            *task.inputs,
+            *inputs,
         )
 
-    task.inputs.clear()
     metadatas_accessor = PartitionMetadataAccessor(metadatas_ref)
-    task.set_result(
-        [
-            RayMaterializedResult(
-                partition=partition,
-                metadatas=metadatas_accessor,
-                metadata_idx=i,
-            )
-            for i, partition in enumerate(partitions)
-        ]
-    )
-
-    return partitions
+    return [
+        RayMaterializedResult(
+            partition=partition,
+            metadatas=metadatas_accessor,
+            metadata_idx=i,
+        )
+        for i, partition in enumerate(partitions)
+    ]
 
 
 def _build_partitions_on_actor_pool(
@@ -1229,10 +1525,13 @@ class RayRunner(Runner[ray.ObjectRef]):
         plan_scheduler: PhysicalPlanScheduler,
         daft_execution_config: PyDaftExecutionConfig,
         results_buffer_size: int | None = None,
+        trace_carrier: dict[str, str] | None = None,
+        progress: QueryProgressTracker | None = None,
     ) -> str:
         psets = {k: v.values() for k, v in self._part_set_cache.get_all_partition_sets().items()}
         result_uuid = str(uuid.uuid4())
         if self.ray_client_mode:
+            # The scheduler actor runs in the cluster, where the progress callbacks of this process can't be called
             ray.get(
                 self.scheduler_actor.start_plan.remote(
                     daft_execution_config=daft_execution_config,
@@ -1240,6 +1539,7 @@ class RayRunner(Runner[ray.ObjectRef]):
                     psets=psets,
                     result_uuid=result_uuid,
                     results_buffer_size=results_buffer_size,
+                    trace_carrier=trace_carrier,
                 )
             )
         else:
@@ -1249,6 +1549,8 @@ class RayRunner(Runner[ray.ObjectRef]):
                 psets=psets,
                 result_uuid=result_uuid,
                 results_buffer_size=results_buffer_size,
+                trace_carrier=trace_carrier,
+                progress=progress,
             )
         return result_uuid
 
@@ -1281,63 +1583,93 @@ class RayRunner(Runner[ray.ObjectRef]):
         # Grab and freeze the current DaftExecutionConfig
         daft_execution_config = get_context().daft_execution_config
 
-        # Optimize the logical plan.
-        builder = builder.optimize()
+        with query_span(self.name, daft_execution_config) as query_carrier, query_progress() as progress:
+            # Optimize the logical plan.
+            builder = self.optimize(builder)
+            checkpoints = StageCheckpoints.for_query(
+                builder, daft_execution_config, get_context().daft_planning_config.default_io_config
+            )
 
-        if daft_execution_config.enable_aqe:
-            adaptive_planner = builder.to_adaptive_physical_plan_scheduler(daft_execution_config)
-            while not adaptive_planner.is_done():
-                stage_id, plan_scheduler = adaptive_planner.next()
-                start_time = time.time()
-                # don't store partition sets in variable to avoid reference
-                result_uuid = self._start_plan(
-                    plan_scheduler, daft_execution_config, results_buffer_size=results_buffer_size
+            if daft_execution_config.enable_aqe or checkpoints is not None:
+                adaptive_planner = builder.to_adaptive_physical_plan_scheduler(daft_execution_config)
+                while not adaptive_planner.is_done():
+                    stage_id, plan_scheduler = adaptive_planner.next()
+                    with stage_span(query_carrier, stage_id) as stage_carrier:
+                        start_time = time.time()
+                        if stage_id is not None and checkpoints is not None:
+                            cache_entry = self._restore_checkpointed_stage(checkpoints, stage_id)
+                            if cache_entry is not None:
+                                adaptive_planner.update_stats(
+                                    time.time() - start_time,
+                                    cache_entry.size_bytes(),
+                                    cache_entry.num_rows(),
+                                    stage_id,
+                                )
+                                adaptive_planner.update(stage_id, cache_entry)
+                                del cache_entry
+                                continue
+                        # don't store partition sets in variable to avoid reference
+                        result_uuid = self._start_plan(
+                            plan_scheduler,
+                            daft_execution_config,
+                            results_buffer_size=results_buffer_size,
+                            trace_carrier=stage_carrier,
+                            progress=progress,
+                        )
+                        del plan_scheduler
+                        results_iter = self._stream_plan(result_uuid)
+                        # if stage_id is None that means this is the final stage
+                        if stage_id is None:
+                            num_rows_processed = 0
+                            bytes_processed = 0
+
+                            for result in results_iter:
+                                num_rows_processed += result.metadata().num_rows
+                                size_bytes = result.metadata().size_bytes
+                                if size_bytes is not None:
+                                    bytes_processed += size_bytes
+                                yield result
+                            adaptive_planner.update_stats(
+                                time.time() - start_time, bytes_processed, num_rows_processed, stage_id
+                            )
+                        else:
+                            cache_entry = self._collect_into_cache(results_iter)
+                            if checkpoints is not None:
+                                self._checkpoint_stage(checkpoints, stage_id, cache_entry)
+                            adaptive_planner.update_stats(
+                                time.time() - start_time,
+                                cache_entry.size_bytes(),
+                                cache_entry.num_rows(),
+                                stage_id,
+                            )
+                            adaptive_planner.update(stage_id, cache_entry)
+                            del cache_entry
+
+                enable_explain_analyze = os.getenv("DAFT_DEV_ENABLE_EXPLAIN_ANALYZE")
+                ray_logs_location = ray_tracing.get_log_location()
+                should_explain_analyze = (
+                    ray_logs_location.exists()
+                    and enable_explain_analyze is not None
+                    and enable_explain_analyze in ["1", "true"]
                 )
-                del plan_scheduler
-                results_iter = self._stream_plan(result_uuid)
-                # if stage_id is None that means this is the final stage
-                if stage_id is None:
-                    num_rows_processed = 0
-                    bytes_processed = 0
-
-                    for result in results_iter:
-                        num_rows_processed += result.metadata().num_rows
-                        size_bytes = result.metadata().size_bytes
-                        if size_bytes is not None:
-                            bytes_processed += size_bytes
-                        yield result
-                    adaptive_planner.update_stats(
-                        time.time() - start_time, bytes_processed, num_rows_processed, stage_id
-                    )
-                else:
-                    cache_entry = self._collect_into_cache(results_iter)
-                    adaptive_planner.update_stats(
-                        time.time() - start_time, cache_entry.size_bytes(), cache_entry.num_rows(), stage_id
-                    )
-                    adaptive_planner.update(stage_id, cache_entry)
-                    del cache_entry
-
-            enable_explain_analyze = os.getenv("DAFT_DEV_ENABLE_EXPLAIN_ANALYZE")
-            ray_logs_location = ray_tracing.get_log_location()
-            should_explain_analyze = (
-                ray_logs_location.exists()
-                and enable_explain_analyze is not None
-                and enable_explain_analyze in ["1", "true"]
-            )
-            if should_explain_analyze:
-                explain_analyze_dir = ray_tracing.get_daft_trace_location(ray_logs_location)
-                explain_analyze_dir.mkdir(exist_ok=True, parents=True)
-                adaptive_planner.explain_analyze(str(explain_analyze_dir))
-        else:
-            # Finalize the logical plan and get a physical plan scheduler for translating the
-            # physical plan to executable tasks.
-            plan_scheduler = builder.to_physical_plan_scheduler(daft_execution_config)
+                if should_explain_analyze:
+                    explain_analyze_dir = ray_tracing.get_daft_trace_location(ray_logs_location)
+                    explain_analyze_dir.mkdir(exist_ok=True, parents=True)
+                    adaptive_planner.explain_analyze(str(explain_analyze_dir))
+            else:
+                # Finalize the logical plan and get a physical plan scheduler for translating the
+                # physical plan to executable tasks.
+                plan_scheduler = builder.to_physical_plan_scheduler(daft_execution_config)
 
-            result_uuid = self._start_plan(
-                plan_scheduler, daft_execution_config, results_buffer_size=results_buffer_size
-            )
+                result_uuid = self._start_plan(
+                    plan_scheduler,
+                    daft_execution_config,
+                    results_buffer_size=results_buffer_size,
+                    trace_carrier=query_carrier,
+                    progress=progress,
+                )
 
-            yield from self._stream_plan(result_uuid)
+                yield from self._stream_plan(result_uuid)
 
     def run_iter_tables(
         self, builder: LogicalPlanBuilder, results_buffer_size: int | None = None
@@ -1345,6 +1677,32 @@ class RayRunner(Runner[ray.ObjectRef]):
         for result in self.run_iter(builder, results_buffer_size=results_buffer_size):
             yield ray.get(result.partition())
 
+    def _checkpoint_stage(self, checkpoints: StageCheckpoints, stage_id: int, cache_entry: PartitionCacheEntry) -> None:
+        assert isinstance(cache_entry.value, RayPartitionSet)
+        # Write the partitions from the workers that hold them
+        ray.get(
+            [
+                _write_checkpoint_partition.remote(
+                    checkpoints.partition_path(stage_id, i), result.partition(), result.metadata(), checkpoints.io_config
+                )
+                for i, result in cache_entry.value.items()
+            ]
+        )
+        checkpoints.mark_completed(stage_id, cache_entry.value.num_partitions())
+
+    def _restore_checkpointed_stage(self, checkpoints: StageCheckpoints, stage_id: int) -> PartitionCacheEntry | None:
+        num_partitions = checkpoints.completed_num_partitions(stage_id)
+        if num_partitions is None:
+            return None
+        logger.info("Restoring the output of stage %s from its checkpoint", stage_id)
+        pset = RayPartitionSet()
+        for i in range(num_partitions):
+            metadatas_ref, partition_ref = _read_checkpoint_partition.remote(
+                checkpoints.partition_path(stage_id, i), checkpoints.io_config
+            )
+            pset.set_partition(i, RayMaterializedResult(partition_ref, PartitionMetadataAccessor(metadatas_ref), 0))
+        return self._part_set_cache.put_partition_set(pset)
+
     def _collect_into_cache(self, results_iter: Iterator[RayMaterializedResult]) -> PartitionCacheEntry:
         result_pset = RayPartitionSet()
 
@@ -1356,8 +1714,14 @@ class RayRunner(Runner[ray.ObjectRef]):
         return pset_entry
 
     def run(self, builder: LogicalPlanBuilder) -> PartitionCacheEntry:
+        cached_result = self.get_cached_result(builder)
+        if cached_result is not None:
+            return cached_result
+
         results_iter = self.run_iter(builder)
-        return self._collect_into_cache(results_iter)
+        pset_entry = self._collect_into_cache(results_iter)
+        self.put_cached_result(builder, pset_entry)
+        return pset_entry
 
     def put_partition_set_into_cache(self, pset: PartitionSet) -> PartitionCacheEntry:
         if isinstance(pset, LocalPartitionSet):
@@ -0,0 +1,165 @@
+from __future__ import annotations
+
+import heapq
+import time
+from dataclasses import dataclass, field
+from typing import TYPE_CHECKING, Callable
+
+from daft.exceptions import DaftTransientError
+from daft.execution.execution_step import ScanWithTask
+
+if TYPE_CHECKING:
+    from daft.daft import PyDaftExecutionConfig
+    from daft.execution.execution_step import PartitionTask
+
+
+def is_transient_error(error: BaseException) -> bool:
+    """Whether a task that failed with the error is likely to succeed if it is run again.
+
+    That is, whether the worker running it or its inputs were lost, or it failed with a transient IO error.
+    """
+    import ray.exceptions  # noqa: TID253
+
+    if isinstance(
+        error,
+        (
+            ray.exceptions.WorkerCrashedError,
+            ray.exceptions.NodeDiedError,
+            ray.exceptions.ObjectLostError,
+            ray.exceptions.OwnerDiedError,
+        ),
+    ):
+        return True
+    if isinstance(error, ray.exceptions.RayTaskError):
+        error = error.cause
+    return isinstance(error, (DaftTransientError, ConnectionError, TimeoutError))
+
+
+@dataclass(frozen=True)
+class FailedAttempt:
+    attempt: int
+    error: str
+
+
+@dataclass(frozen=True)
+class FailedTaskReport:
+    """Which task ultimately failed, what it read, and how each of its attempts failed."""
+
+    task_id: str
+    task_name: str
+    stage_id: int
+    # The files or other sources that the task scanned, and the upstream partitions that it read
+    inputs: list[str]
+    attempts: list[FailedAttempt]
+
+    @classmethod
+    def for_task(cls, task: PartitionTask, attempts: list[FailedAttempt]) -> FailedTaskReport:
+        return cls(
+            task_id=task.id(),
+            task_name=task.name(),
+            stage_id=task.stage_id,
+            inputs=describe_task_inputs(task),
+            attempts=list(attempts),
+        )
+
+    def __str__(self) -> str:
+        lines = [f"Task {self.task_name} ({self.task_id}) failed after {len(self.attempts)} attempts."]
+        lines.append("Inputs:")
+        lines.extend(f"  {task_input}" for task_input in self.inputs)
+        lines.append("Attempts:")
+        lines.extend(f"  {attempt.attempt}: {attempt.error}" for attempt in self.attempts)
+        return "\n".join(lines)
+
+
+def describe_task_inputs(task: PartitionTask) -> list[str]:
+    inputs = [
+        path
+        for instruction in task.instructions
+        if isinstance(instruction, ScanWithTask)
+        for path in instruction.scan_task.paths()
+    ]
+    if task.partial_metadatas:
+        inputs.append(f"{len(task.partial_metadatas)} partition(s) output by upstream tasks")
+    return inputs
+
+
+class PartitionTaskFailedError(Exception):
+    """Raised when a task keeps failing after being retried as many times as the retry policy allows."""
+
+    def __init__(self, report: FailedTaskReport) -> None:
+        super().__init__(str(report))
+        self.report = report
+
+
+class TaskRetryPolicy:
+    """Decides whether a failed task is run again, and how long to wait before doing so.
+
+    Only failures that are likely transient are retried, at most until the task has been attempted `max_attempts`
+    times. The wait before the n-th retry is `initial_backoff_s * 2 ** (n - 1)`, capped at `max_backoff_s`.
+    """
+
+    def __init__(self, max_attempts: int, initial_backoff_s: float, max_backoff_s: float) -> None:
+        self.max_attempts = max_attempts
+        self.initial_backoff_s = initial_backoff_s
+        self.max_backoff_s = max_backoff_s
+
+    @classmethod
+    def from_config(cls, config: PyDaftExecutionConfig) -> TaskRetryPolicy | None:
+        """Returns the retry policy of the config, or None if tasks aren't retried."""
+        if config.task_max_attempts <= 1:
+            return None
+        return cls(
+            max_attempts=config.task_max_attempts,
+            initial_backoff_s=config.task_retry_initial_backoff_ms / 1000,
+            max_backoff_s=config.task_retry_max_backoff_ms / 1000,
+        )
+
+    def backoff_s(self, num_failed_attempts: int) -> float:
+        return min(self.initial_backoff_s * 2 ** (num_failed_attempts - 1), self.max_backoff_s)
+
+    def should_retry(self, error: BaseException, num_failed_attempts: int) -> bool:
+        return num_failed_attempts < self.max_attempts and is_transient_error(error)
+
+
+@dataclass(order=True)
+class _PendingRetry:
+    due: float
+    task_id: str = field(compare=False)
+
+
+class TaskRetries:
+    """Tracks the failed attempts of tasks, and the tasks that are waiting out their backoff before being retried."""
+
+    def __init__(self, policy: TaskRetryPolicy, clock: Callable[[], float] = time.monotonic) -> None:
+        self.policy = policy
+        self._clock = clock
+        self._attempts: dict[str, list[FailedAttempt]] = {}
+        self._pending: list[_PendingRetry] = []
+
+    def task_failed(self, task_id: str, error: BaseException) -> bool:
+        """Records a failed attempt of a task, and returns whether the task will be retried."""
+        attempts = self._attempts.setdefault(task_id, [])
+        attempts.append(FailedAttempt(attempt=len(attempts) + 1, error=repr(error)))
+        if not self.policy.should_retry(error, len(attempts)):
+            return False
+        heapq.heappush(self._pending, _PendingRetry(self._clock() + self.policy.backoff_s(len(attempts)), task_id))
+        return True
+
+    def task_finished(self, task_id: str) -> None:
+        self._attempts.pop(task_id, None)
+
+    def attempts(self, task_id: str) -> list[FailedAttempt]:
+        return self._attempts.get(task_id, [])
+
+    def due_retries(self) -> list[str]:
+        """Pops the tasks whose backoff is over, which should be run again now."""
+        now = self._clock()
+        due = []
+        while self._pending and self._pending[0].due <= now:
+            due.append(heapq.heappop(self._pending).task_id)
+        return due
+
+    def seconds_until_next_retry(self) -> float | None:
+        if not self._pending:
+            return None
+        return max(self._pending[0].due - self._clock(), 0.0)
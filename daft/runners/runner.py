@@ -1,9 +1,13 @@
 from __future__ import annotations
 
+import tempfile
 from abc import abstractmethod
 from typing import TYPE_CHECKING, ClassVar, Generic, Iterator, Literal
 
+from daft.context import get_context
 from daft.runners.partitioning import (
+    LocalMaterializedResult,
+    LocalPartitionSet,
     MaterializedResult,
     PartitionCacheEntry,
     PartitionSet,
@@ -24,6 +28,85 @@ class Runner(Generic[PartitionT]):
 
     def __init__(self) -> None:
         self._part_set_cache = self.initialize_partition_set_cache()
+        # Optimized plans and results of previously executed queries, keyed by plan fingerprint. These are only
+        # populated when `enable_plan_cache` is set in the planning config.
+        self._optimized_plan_cache: dict[int, LogicalPlanBuilder] = {}
+        self._result_cache: dict[int, PartitionCacheEntry] = {}
+        # Results of persisted DataFrames and the number of DataFrames that persisted them, keyed by cache key.
+        self._persisted: dict[str, tuple[PartitionCacheEntry, int]] = {}
+        self._persisted_memory_bytes = 0
+        self._persist_spill_dir: str | None = None
+
+    def optimize(self, builder: LogicalPlanBuilder) -> LogicalPlanBuilder:
+        """Optimizes the logical plan, reusing the optimized plan of a previous query with the same fingerprint if plan caching is enabled."""
+        if not get_context().daft_planning_config.enable_plan_cache:
+            return builder.optimize()
+        fingerprint = builder.fingerprint()
+        if fingerprint is None:
+            return builder.optimize()
+        optimized = self._optimized_plan_cache.get(fingerprint)
+        if optimized is None:
+            optimized = builder.optimize()
+            self._optimized_plan_cache[fingerprint] = optimized
+        return optimized
+
+    def get_cached_result(self, builder: LogicalPlanBuilder) -> PartitionCacheEntry | None:
+        """Returns the result of a previous query whose optimized plan has the same fingerprint, if plan caching is enabled."""
+        if not get_context().daft_planning_config.enable_plan_cache:
+            return None
+        fingerprint = self.optimize(builder).fingerprint()
+        return self._result_cache.get(fingerprint) if fingerprint is not None else None
+
+    def put_cached_result(self, builder: LogicalPlanBuilder, result: PartitionCacheEntry) -> None:
+        if not get_context().daft_planning_config.enable_plan_cache:
+            return
+        fingerprint = self.optimize(builder).fingerprint()
+        if fingerprint is not None:
+            self._result_cache[fingerprint] = result
+
+    def persist(self, entry: PartitionCacheEntry) -> None:
+        """Retains a materialized result in the session until it is unpersisted as many times as it was persisted.
+
+        Partitions of local results that don't fit under `persist_memory_limit_bytes` are spilled to disk.
+        """
+        if entry.key in self._persisted:
+            entry, refcount = self._persisted[entry.key]
+            self._persisted[entry.key] = (entry, refcount + 1)
+            return
+        self._persisted[entry.key] = (entry, 1)
+        pset = entry.value
+        if not isinstance(pset, LocalPartitionSet):
+            return
+        memory_limit_bytes = get_context().daft_execution_config.persist_memory_limit_bytes
+        for idx, part in pset.items():
+            size_bytes = part.metadata().size_bytes or 0
+            if self._persisted_memory_bytes + size_bytes <= memory_limit_bytes:
+                self._persisted_memory_bytes += size_bytes
+            else:
+                if self._persist_spill_dir is None:
+                    self._persist_spill_dir = tempfile.mkdtemp(prefix="daft-persist-")
+                pset.spill_partition(idx, self._persist_spill_dir)
+
+    def unpersist(self, entry: PartitionCacheEntry) -> bool:
+        """Releases one reference to a persisted result, returning whether it is no longer persisted.
+
+        Once released, the result is freed (and its spilled partitions deleted) as soon as no DataFrame scans it anymore.
+        """
+        if entry.key not in self._persisted:
+            return True
+        entry, refcount = self._persisted.pop(entry.key)
+        if refcount > 1:
+            self._persisted[entry.key] = (entry, refcount - 1)
+            return False
+        pset = entry.value
+        if isinstance(pset, LocalPartitionSet):
+            for _, part in pset.items():
+                if isinstance(part, LocalMaterializedResult):
+                    self._persisted_memory_bytes -= part.metadata().size_bytes or 0
+        return True
+
+    def is_persisted(self, entry: PartitionCacheEntry) -> bool:
+        return entry.key in self._persisted
 
     def get_partition_set_from_cache(self, pset_id: str) -> PartitionCacheEntry:
         return self._part_set_cache.get_partition_set(pset_id=pset_id)
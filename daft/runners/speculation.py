@@ -0,0 +1,83 @@
+from __future__ import annotations
+
+import statistics
+import time
+from dataclasses import dataclass, field
+from typing import TYPE_CHECKING, Callable
+
+if TYPE_CHECKING:
+    from daft.daft import PyDaftExecutionConfig
+
+
+@dataclass
+class _StageRuntimes:
+    # Start times of the tasks of the stage that are still running
+    running: dict[str, float] = field(default_factory=dict)
+    # Runtimes in seconds of the tasks of the stage that are done
+    finished: list[float] = field(default_factory=list)
+
+
+class SpeculationTracker:
+    """Tracks the runtimes of the tasks of each stage to find stragglers, which are worth running a second time.
+
+    A running task is a straggler once at least `quantile` of the tasks of its stage are done, and it has been running
+    for longer than `multiplier` times the median runtime of the tasks that are done, and for at least `min_runtime_s`.
+    Each task is speculated at most once.
+    """
+
+    def __init__(
+        self,
+        quantile: float,
+        multiplier: float,
+        min_runtime_s: float,
+        clock: Callable[[], float] = time.monotonic,
+    ) -> None:
+        self.quantile = quantile
+        self.multiplier = multiplier
+        self.min_runtime_s = min_runtime_s
+        self._clock = clock
+        self._stages: dict[int, _StageRuntimes] = {}
+        self._stage_of_task: dict[str, int] = {}
+        self._speculated: set[str] = set()
+
+    @classmethod
+    def from_config(cls, config: PyDaftExecutionConfig) -> SpeculationTracker | None:
+        """Returns a tracker for the speculation settings of the config, or None if speculation is disabled."""
+        if not config.enable_speculative_execution:
+            return None
+        return cls(
+            quantile=config.speculation_quantile,
+            multiplier=config.speculation_multiplier,
+            min_runtime_s=config.speculation_min_runtime_ms / 1000,
+        )
+
+    def task_started(self, task_id: str, stage_id: int) -> None:
+        self._stages.setdefault(stage_id, _StageRuntimes()).running[task_id] = self._clock()
+        self._stage_of_task[task_id] = stage_id
+
+    def task_finished(self, task_id: str) -> None:
+        stage_id = self._stage_of_task.pop(task_id, None)
+        if stage_id is None:
+            return
+        stage = self._stages[stage_id]
+        stage.finished.append(self._clock() - stage.running.pop(task_id))
+        self._speculated.discard(task_id)
+
+    def task_speculated(self, task_id: str) -> None:
+        self._speculated.add(task_id)
+
+    def stragglers(self) -> list[str]:
+        """The running tasks that should be speculated, which haven't been already."""
+        now = self._clock()
+        stragglers = []
+        for stage in self._stages.values():
+            num_tasks = len(stage.finished) + len(stage.running)
+            if not stage.finished or len(stage.finished) < self.quantile * num_tasks:
+                continue
+            threshold = max(self.min_runtime_s, self.multiplier * statistics.median(stage.finished))
+            stragglers.extend(
+                task_id
+                for task_id, start in stage.running.items()
+                if task_id not in self._speculated and now - start > threshold
+            )
+        return stragglers
@@ -65,11 +65,30 @@ class Series:
     def from_arrow(array: pa.Array | pa.ChunkedArray, name: str = "arrow_series") -> Series:
         """Construct a Series from an pyarrow array or chunked array.
 
+        Arrays of other Arrow libraries, such as polars or nanoarrow, are accepted if they implement the
+        Arrow PyCapsule interface (``__arrow_c_array__`` or ``__arrow_c_stream__``).
+
         Args:
             array: The pyarrow (chunked) array whose data we wish to put in the Series.
             name: The name associated with the Series; this is usually the column name.
         """
         _ensure_registered_super_ext_type()
+        if not isinstance(array, (pa.Array, pa.ChunkedArray)):
+            # Objects of other Arrow libraries are imported through the Arrow PyCapsule interface.
+            if hasattr(array, "__arrow_c_array__"):
+                return Series._from_pyseries(PySeries.from_arrow_c_array(name, *array.__arrow_c_array__()))
+            elif hasattr(array, "__arrow_c_stream__"):
+                return Series._from_pyseries(PySeries.from_arrow_c_stream(name, array.__arrow_c_stream__()))
+            raise TypeError(f"expected either PyArrow Array or Chunked Array, got {type(array)}")
+        if (
+            isinstance(array, pa.Array)
+            and pa.types.is_dictionary(array.type)
+            and (pa.types.is_string(array.type.value_type) or pa.types.is_large_string(array.type.value_type))
+        ):
+            # Dictionaries of strings are read as categoricals, with the dictionary as their categories.
+            categories = list(dict.fromkeys(c for c in array.dictionary.to_pylist() if c is not None))
+            strings = Series.from_arrow(array.dictionary_decode(), name=name)
+            return strings.cast(DataType.categorical(categories))
         if DataType.from_arrow_type(array.type) == DataType.python():
             # If the Arrow type is not natively supported, go through the Python list path.
             return Series.from_pylist(array.to_pylist(), name=name, pyobj="force")
@@ -142,12 +161,19 @@ class Series:
         in a pyarrow Array. If the ndarray has more than 1 dimension OR storing the 1D array in Arrow failed,
         Daft will store the ndarray data as a Python list of NumPy ndarrays.
 
+        Contiguous 1-dimensional ndarrays of integer or floating point dtypes are ingested without copying
+        their data, so later mutations of the ndarray are visible in the Series.
+
         Args:
             data: The NumPy ndarray whose data we wish to put in the Series.
             name: The name associated with the Series; this is usually the column name.
         """
         if not isinstance(data, np.ndarray):
             raise TypeError(f"Expected a NumPy ndarray, got {type(data)}")
+        if data.ndim == 1:
+            pys = PySeries.from_numpy(name, data)
+            if pys is not None:
+                return Series._from_pyseries(pys)
         if data.ndim <= 1:
             try:
                 arrow_array = pa.array(data)
@@ -199,6 +225,15 @@ class Series:
     def cast(self, dtype: DataType) -> Series:
         return Series._from_pyseries(self._series.cast(dtype._dtype))
 
+    def cast_to_categorical(self, categories: list[str] | None = None) -> Series:
+        """Casts this Series to a ``DataType.categorical`` of ``categories``, nulling out values that are not one of them.
+
+        Args:
+            categories: The distinct strings that values can take, in the order of their codes. Defaults to the sorted
+                distinct values of this Series, which are specific to it.
+        """
+        return Series._from_pyseries(self._series.cast_to_categorical(categories))
+
     def _cast_to_python(self) -> Series:
         """Convert this Series into a Series of Python objects.
 
@@ -259,8 +294,26 @@ class Series:
             arrow_series = self._series.to_arrow()
             return pa.ExtensionArray.from_storage(pyarrow_dtype, arrow_series.storage)
 
+        if dtype.is_categorical():
+            return pa.DictionaryArray.from_arrays(
+                arrow_arr.storage, pa.array(dtype.categories, type=pa.large_string())
+            )
+
         return arrow_arr
 
+    def __arrow_c_schema__(self) -> object:
+        """Export the type of this Series as an ``arrow_schema`` PyCapsule of the Arrow PyCapsule interface."""
+        return self._series.__arrow_c_schema__()
+
+    def __arrow_c_array__(self, requested_schema: object | None = None) -> tuple[object, object]:
+        """Export this Series through the Arrow PyCapsule interface, e.g. for ``pyarrow.array(series)``.
+
+        Args:
+            requested_schema: An ``arrow_schema`` PyCapsule of the type preferred by the consumer. The Series is
+                cast to it if possible, and exported as is otherwise.
+        """
+        return self._series.__arrow_c_array__(requested_schema)
+
     def to_pylist(self) -> list:
         """Convert this Series to a Python list."""
         if self.datatype().is_python():
@@ -270,6 +323,63 @@ class Series:
         else:
             return self._series.to_arrow().to_pylist()
 
+    def __dlpack__(
+        self,
+        *,
+        stream: int | None = None,
+        max_version: tuple[int, int] | None = None,
+        dl_device: tuple[int, int] | None = None,
+        copy: bool | None = None,
+    ) -> Any:
+        """Export an embedding or fixed-shape tensor Series as a DLPack capsule of shape ``(len(series), *shape)``.
+
+        The capsule shares the memory of the Series on CPU, so that libraries such as PyTorch and JAX can consume
+        the Series without a copy, e.g. with ``torch.from_dlpack(series)``.
+
+        DLPack has no notion of nulls, so a Series with null rows or null elements (or with boolean elements,
+        which Arrow stores as bits) is copied instead, with nulls filled with zeros. Pass ``copy=False`` to raise
+        a ``BufferError`` rather than copy, or ``copy=True`` to always copy.
+        """
+        ndarray = self._to_dlpack_ndarray(copy)
+        kwargs: dict[str, Any] = {}
+        if max_version is not None:
+            kwargs["max_version"] = max_version
+        if dl_device is not None:
+            kwargs["dl_device"] = dl_device
+        return ndarray.__dlpack__(stream=stream, **kwargs)
+
+    def __dlpack_device__(self) -> tuple[int, int]:
+        # Series always live in CPU memory (kDLCPU)
+        return (1, 0)
+
+    def _to_dlpack_ndarray(self, copy: bool | None) -> np.ndarray:
+        dtype = self.datatype()
+        if dtype.is_embedding():
+            shape: tuple[int, ...] = (dtype.size,)
+        elif dtype.is_fixed_shape_tensor():
+            shape = dtype.shape
+        else:
+            raise ValueError(f"Only embedding and fixed-shape tensor Series can be exported with DLPack, got {dtype}")
+
+        storage = self._series.to_arrow()
+        if isinstance(storage, pa.ExtensionArray):
+            storage = storage.storage
+        size = storage.type.list_size
+        values = storage.values.slice(storage.offset * size, len(storage) * size)
+
+        needs_copy = storage.null_count > 0 or values.null_count > 0 or pa.types.is_boolean(values.type)
+        if not (needs_copy or copy):
+            return values.to_numpy(zero_copy_only=True).reshape((len(storage), *shape))
+        if copy is False:
+            raise BufferError(
+                f"Series {self.name()} contains nulls or booleans, which can not be exported with DLPack without a copy"
+            )
+        fill_value = False if pa.types.is_boolean(values.type) else 0
+        ndarray = np.array(values.fill_null(fill_value).to_numpy(zero_copy_only=False)).reshape((len(storage), *shape))
+        if storage.null_count > 0:
+            ndarray[storage.is_null().to_numpy(zero_copy_only=False)] = 0
+        return ndarray
+
     def filter(self, mask: Series) -> Series:
         if not isinstance(mask, Series):
             raise TypeError(f"expected another Series but got {type(mask)}")
@@ -296,6 +406,43 @@ class Series:
 
         return Series._from_pyseries(self._series.argsort(descending, nulls_first))
 
+    def rank(
+        self,
+        method: Literal["min", "max", "dense", "average", "ordinal"] = "min",
+        descending: bool = False,
+        nulls: Literal["keep", "first", "last"] = "keep",
+    ) -> Series:
+        """Ranks the values of the Series, starting at 1.
+
+        Args:
+            method: How tied values are ranked. ``"min"`` and ``"max"`` give every tied value the lowest or highest
+                rank of the group, ``"dense"`` is like ``"min"`` without gaps between groups, ``"average"`` gives the
+                average rank of the group and ``"ordinal"`` breaks ties by position.
+            descending: Whether the largest value gets rank 1.
+            nulls: ``"keep"`` leaves nulls unranked with a null rank, while ``"first"`` and ``"last"`` rank them as
+                tied values before or after every other value.
+
+        Returns:
+            Series: ``UInt64`` ranks, or ``Float64`` ranks for ``method="average"``.
+        """
+        if not isinstance(descending, bool):
+            raise TypeError(f"expected `descending` to be bool, got {type(descending)}")
+        if nulls not in ("keep", "first", "last"):
+            raise ValueError(f"expected `nulls` to be one of 'keep', 'first' or 'last', got {nulls!r}")
+        nulls_first = None if nulls == "keep" else nulls == "first"
+        return Series._from_pyseries(self._series.rank(method, descending, nulls_first))
+
+    def value_counts(self) -> Series:
+        """Counts the occurrences of each distinct non-null value of the Series.
+
+        Lists and structs are counted by value, like any other type.
+
+        Returns:
+            Series: A struct Series with the distinct values in a field named after this Series and their counts in a
+                ``count`` field, sorted by descending count and then by first occurrence.
+        """
+        return Series._from_pyseries(self._series.value_counts())
+
     def sort(self, descending: bool = False, nulls_first: bool | None = None) -> Series:
         if not isinstance(descending, bool):
             raise TypeError(f"expected `descending` to be bool, got {type(descending)}")
@@ -596,6 +743,17 @@ class Series:
         assert self._series is not None
         return Series._from_pyseries(self._series.max())
 
+    def approx_quantiles(self, quantiles: list[float]) -> Series:
+        """Calculates approximate quantiles of the numeric values of the Series, ignoring nulls.
+
+        See [Expression.approx_quantiles][daft.expressions.Expression.approx_quantiles].
+
+        Returns:
+            Series: A single ``FixedSizeList[Float64; len(quantiles)]`` row, which is null if every value is null.
+        """
+        assert self._series is not None
+        return Series._from_pyseries(self._series.approx_quantiles(list(quantiles)))
+
     def mean(self) -> Series:
         assert self._series is not None
         return Series._from_pyseries(self._series.mean())
@@ -703,10 +861,18 @@ class Series:
     def map(self) -> SeriesMapNamespace:
         return SeriesMapNamespace.from_series(self)
 
+    @property
+    def embedding(self) -> SeriesEmbeddingNamespace:
+        return SeriesEmbeddingNamespace.from_series(self)
+
     @property
     def image(self) -> SeriesImageNamespace:
         return SeriesImageNamespace.from_series(self)
 
+    @property
+    def tensor(self) -> SeriesTensorNamespace:
+        return SeriesTensorNamespace.from_series(self)
+
     @property
     def partitioning(self) -> SeriesPartitioningNamespace:
         return SeriesPartitioningNamespace.from_series(self)
@@ -863,6 +1029,10 @@ class SeriesStringNamespace(SeriesNamespace):
         assert self._series is not None
         return Series._from_pyseries(self._series.utf8_capitalize())
 
+    def to_titlecase(self) -> Series:
+        assert self._series is not None
+        return Series._from_pyseries(self._series.utf8_to_titlecase())
+
     def left(self, nchars: Series) -> Series:
         if not isinstance(nchars, Series):
             raise ValueError(f"expected another Series but got {type(nchars)}")
@@ -1027,6 +1197,18 @@ class SeriesDateNamespace(SeriesNamespace):
     def strftime(self, fmt: str | None = None) -> Series:
         return Series._from_pyseries(self._series.dt_strftime(fmt))
 
+    def convert_time_zone(self, tz: str) -> Series:
+        """Converts timezone-aware timestamps to the timezone ``tz``, keeping the instants they represent."""
+        return Series._from_pyseries(self._series.dt_convert_time_zone(tz))
+
+    def replace_time_zone(self, tz: str | None = None) -> Series:
+        """Replaces the timezone of timestamps with ``tz``, keeping their wall clock time.
+
+        Naive timestamps are localized into ``tz`` and ``None`` makes the timestamps naive. Ambiguous local times
+        resolve to their earliest instant and local times that do not exist in ``tz`` become null.
+        """
+        return Series._from_pyseries(self._series.dt_replace_time_zone(tz))
+
 
 class SeriesPartitioningNamespace(SeriesNamespace):
     def days(self) -> Series:
@@ -1078,6 +1260,61 @@ class SeriesMapNamespace(SeriesNamespace):
     def get(self, key: Series) -> Series:
         return Series._from_pyseries(self._series.map_get(key._series))
 
+    def keys(self) -> Series:
+        """Returns the keys of each map as a list."""
+        return Series._from_pyseries(self._series.map_keys())
+
+    def values(self) -> Series:
+        """Returns the values of each map as a list."""
+        return Series._from_pyseries(self._series.map_values())
+
+
+class SeriesEmbeddingNamespace(SeriesNamespace):
+    def dot_product(self, other: Series) -> Series:
+        """Computes the dot product of each embedding with the matching embedding of `other`.
+
+        Either side is broadcast if it has a single row, e.g. a query vector.
+        """
+        if not isinstance(other, Series):
+            raise ValueError(f"expected another Series but got {type(other)}")
+        return Series._from_pyseries(self._series.embedding_dot_product(other._series))
+
+    def cosine_similarity(self, other: Series, normalized: bool = False) -> Series:
+        """Computes the cosine similarity of each embedding with the matching embedding of `other`.
+
+        Either side is broadcast if it has a single row, e.g. a query vector. With `normalized=True`,
+        both sides are assumed to have been normalized already with :meth:`normalize`, which skips
+        recomputing their norms. Embeddings with a norm of 0 have a null similarity.
+        """
+        if not isinstance(other, Series):
+            raise ValueError(f"expected another Series but got {type(other)}")
+        return Series._from_pyseries(self._series.embedding_cosine_similarity(other._series, normalized))
+
+    def normalize(self) -> Series:
+        """Scales each embedding to a norm of 1, so that it can be reused across cosine similarities."""
+        return Series._from_pyseries(self._series.embedding_normalize())
+
+
+class SeriesTensorNamespace(SeriesNamespace):
+    def sum(self, axis: int | list[int] | None = None) -> Series:
+        """Sums the elements of each tensor over the given axes, or over all of them if `axis` is None.
+
+        Reducing over every axis returns a Float64 Series, otherwise a Series of Float64 tensors with the
+        reduced axes removed.
+        """
+        if isinstance(axis, int):
+            axis = [axis]
+        return Series._from_pyseries(self._series.tensor_sum(axis))
+
+    def mean(self, axis: int | list[int] | None = None) -> Series:
+        """Averages the elements of each tensor over the given axes, or over all of them if `axis` is None.
+
+        See :meth:`sum` for the type of the result.
+        """
+        if isinstance(axis, int):
+            axis = [axis]
+        return Series._from_pyseries(self._series.tensor_mean(axis))
+
 
 class SeriesImageNamespace(SeriesNamespace):
     def decode(
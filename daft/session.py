@@ -1,16 +1,20 @@
 from __future__ import annotations
 
-from typing import Literal
+from typing import TYPE_CHECKING, Literal
 
 from daft.catalog import Catalog, Identifier, Table
 from daft.context import get_context
 from daft.daft import LogicalPlanBuilder as PyBuilder
-from daft.daft import PySession, PyTableSource, sql_exec
+from daft.daft import PySession, PyTableSource, flight_sql_start, pgwire_start, sql_exec
 from daft.dataframe import DataFrame
 from daft.logical.builder import LogicalPlanBuilder
 from daft.logical.schema import Schema
 from daft.udf import UDF
 
+if TYPE_CHECKING:
+    from daft.daft import FlightSqlServerHandle, PgWireServerHandle
+    from daft.sql.sql import SQLParams
+
 __all__ = [
     "Session",
     "attach",
@@ -75,18 +79,21 @@ class Session:
     # exec
     ###
 
-    def sql(self, sql: str) -> DataFrame | None:
+    def sql(self, sql: str, params: SQLParams | None = None) -> DataFrame | None:
         """Executes the SQL statement using this session.
 
         Args:
             sql (str): input SQL statement
+            params (list | dict, optional): values bound to the positional (`?`, `$1`) or named (`:name`) parameters of the statement
 
         Returns:
             DataFrame: dataframe instance if this was a data statement (DQL, DDL, DML).
         """
+        from daft.sql.sql import _sql_params
+
         py_sess = self._session
         py_config = get_context().daft_planning_config
-        py_object = sql_exec(sql, py_sess, py_config)
+        py_object = sql_exec(sql, py_sess, py_config, **_sql_params(params))
         if py_object is None:
             return None
         elif isinstance(py_object, PyBuilder):
@@ -94,6 +101,46 @@ class Session:
         else:
             raise ValueError(f"Unsupported return type from sql exec: {type(py_object)}")
 
+    def serve_flight_sql(self, host: str = "0.0.0.0", port: int = 0) -> FlightSqlServerHandle:
+        """Serves this session over Arrow Flight SQL, so that Flight SQL clients such as ADBC can run queries against it.
+
+        Args:
+            host (str): the host to listen on
+            port (int): the port to listen on, or 0 for any free port
+
+        Returns:
+            FlightSqlServerHandle: the handle of the server, whose `port()` is the port it listens on and whose `shutdown()` stops it.
+
+        Examples:
+            >>> import daft
+            >>> sess = daft.Session()
+            >>> server = sess.serve_flight_sql(host="127.0.0.1")
+            >>> server.port() > 0
+            True
+            >>> server.shutdown()
+        """
+        return flight_sql_start(self._session, host, port)
+
+    def serve_pgwire(self, host: str = "0.0.0.0", port: int = 0) -> PgWireServerHandle:
+        """Serves this session over the PostgreSQL wire protocol, so that Postgres clients such as psql can run queries against it.
+
+        Args:
+            host (str): the host to listen on
+            port (int): the port to listen on, or 0 for any free port
+
+        Returns:
+            PgWireServerHandle: the handle of the server, whose `port()` is the port it listens on and whose `shutdown()` stops it.
+
+        Examples:
+            >>> import daft
+            >>> sess = daft.Session()
+            >>> server = sess.serve_pgwire(host="127.0.0.1")
+            >>> server.port() > 0
+            True
+            >>> server.shutdown()
+        """
+        return pgwire_start(self._session, host, port)
+
     ###
     # attach & detach
     ###
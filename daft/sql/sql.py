@@ -1,7 +1,8 @@
 # isort: dont-add-import: from __future__ import annotations
 
 import inspect
-from typing import Optional
+from collections.abc import Mapping, Sequence
+from typing import Any, Optional, Union
 
 import daft
 from daft.api_annotations import PublicAPI
@@ -11,7 +12,7 @@ from daft.daft import sql as _sql
 from daft.daft import sql_expr as _sql_expr
 from daft.dataframe import DataFrame
 from daft.exceptions import DaftCoreException
-from daft.expressions import Expression
+from daft.expressions import Expression, lit
 from daft.logical.builder import LogicalPlanBuilder
 
 
@@ -39,6 +40,21 @@ class SQLCatalog:
         self._catalog.copy_from(other._catalog)
 
 
+SQLParams = Union[Sequence[Any], Mapping[str, Any]]
+
+
+def _sql_params(params: Optional[SQLParams]) -> dict:
+    """Converts the values bound to the parameters of a SQL statement into literals, as the keyword arguments of the native planner."""
+    if params is None:
+        return {}
+    elif isinstance(params, Mapping):
+        return {"named_params": {name: lit(value)._expr for name, value in params.items()}}
+    elif isinstance(params, (list, tuple)):
+        return {"positional_params": [lit(value)._expr for value in params]}
+    else:
+        raise TypeError(f"SQL parameters must be a list, tuple or dict, but got: {type(params)}")
+
+
 @PublicAPI
 def sql_expr(sql: str) -> Expression:
     """Parses a SQL string into a Daft Expression.
@@ -98,7 +114,12 @@ def sql_expr(sql: str) -> Expression:
 
 
 @PublicAPI
-def sql(sql: str, catalog: Optional[SQLCatalog] = None, register_globals: bool = True) -> DataFrame:
+def sql(
+    sql: str,
+    catalog: Optional[SQLCatalog] = None,
+    register_globals: bool = True,
+    params: Optional[SQLParams] = None,
+) -> DataFrame:
     """Run a SQL query, returning the results as a DataFrame.
 
     Args:
@@ -109,6 +130,9 @@ def sql(sql: str, catalog: Optional[SQLCatalog] = None, register_globals: bool =
         register_globals (bool, optional): Whether to incorporate global
             variables into the supplied catalog, in which case a copy of the
             catalog will be made and the original not modified. Defaults to True.
+        params (list | dict, optional): Values bound to the parameters of the query, which become typed
+            literals in its plan. A list or tuple binds the positional parameters `?` in order, or `$1`,
+            `$2`, etc., and a dict binds the named parameters `:name`. Defaults to None.
 
     Returns:
         DataFrame: Dataframe containing the results of the query
@@ -166,6 +190,16 @@ def sql(sql: str, catalog: Optional[SQLCatalog] = None, register_globals: bool =
         ╰───────╯
         <BLANKLINE>
         (Showing first 3 of 3 rows)
+
+        Values can be bound to parameters of the query, rather than interpolated into it.
+
+        >>> df = daft.from_pydict({"a": [1, 2, 3], "b": ["foo", "bar", "baz"]})
+        >>> daft.sql("SELECT b FROM df WHERE a > ? AND b != ?", params=[1, "baz"]).to_pydict()
+        {'b': ['bar']}
+        >>> daft.sql("SELECT b FROM df WHERE a >= $1 AND b != $2", params=[2, "baz"]).to_pydict()
+        {'b': ['bar']}
+        >>> daft.sql("SELECT b FROM df WHERE b = :name", params={"name": "foo"}).to_pydict()
+        {'b': ['foo']}
     """
     if register_globals:
         try:
@@ -189,5 +223,5 @@ def sql(sql: str, catalog: Optional[SQLCatalog] = None, register_globals: bool =
 
     sess = daft.current_session()._session
 
-    _py_logical = _sql(sql, _py_catalog, sess, planning_config)
+    _py_logical = _sql(sql, _py_catalog, sess, planning_config, **_sql_params(params))
     return DataFrame(LogicalPlanBuilder(_py_logical))
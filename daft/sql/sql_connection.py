@@ -15,11 +15,14 @@ logger = logging.getLogger(__name__)
 
 
 class SQLConnection:
-    def __init__(self, conn: str | Callable[[], Connection], driver: str, dialect: str, url: str) -> None:
+    def __init__(
+        self, conn: str | Callable[[], Connection], driver: str, dialect: str, url: str, is_adbc: bool = False
+    ) -> None:
         self.conn = conn
         self.dialect = dialect
         self.driver = driver
         self.url = url
+        self.is_adbc = is_adbc
 
     def __repr__(self) -> str:
         return f"SQLConnection(conn={self.conn})"
@@ -39,9 +42,15 @@ class SQLConnection:
 
         try:
             with conn_factory() as connection:
+                # ADBC connections are DBAPI connections that report their database and return results as Arrow.
+                if hasattr(connection, "adbc_get_info"):
+                    info = connection.adbc_get_info()
+                    dialect = str(info.get("vendor_name", "")).strip().lower().replace(" ", "")
+                    driver = str(info.get("driver_name", "adbc"))
+                    return cls(conn_factory, driver, dialect, f"{dialect}+adbc://", is_adbc=True)
                 if not isinstance(connection, Connection):
                     raise ValueError(
-                        f"Connection factory must return a SQLAlchemy connection object, got: {type(connection)}"
+                        f"Connection factory must return a SQLAlchemy or ADBC connection object, got: {type(connection)}"
                     )
                 dialect = connection.engine.dialect.name
                 driver = connection.engine.driver
@@ -124,11 +133,25 @@ class SQLConnection:
         return False
 
     def execute_sql_query(self, sql: str) -> pa.Table:
-        if self._should_use_connectorx():
+        if self.is_adbc:
+            return self._execute_sql_query_with_adbc(sql)
+        elif self._should_use_connectorx():
             return self._execute_sql_query_with_connectorx(sql)
         else:
             return self._execute_sql_query_with_sqlalchemy(sql)
 
+    def _execute_sql_query_with_adbc(self, sql: str) -> pa.Table:
+        assert callable(self.conn)
+        logger.info("Using ADBC to execute sql: %s", sql)
+        try:
+            with self.conn() as connection, connection.cursor() as cursor:
+                cursor.execute(sql)
+                return cursor.fetch_arrow_table()
+        except Exception as e:
+            raise RuntimeError(
+                f"Failed to execute sql: {sql} from connection: {self.conn.__name__}, error: {e}"
+            ) from e
+
     def _execute_sql_query_with_connectorx(self, sql: str) -> pa.Table:
         import connectorx as cx
 
@@ -0,0 +1,141 @@
+"""Conversion between Daft expressions and plans and Substrait extended expressions and plans.
+
+An extended expression bundles a set of named expressions with the schema they are evaluated over, so
+that filters and projections can be exchanged with other engines that speak Substrait. A plan is a
+query over named tables, so that Daft can execute queries from external planners and services can
+ship queries to each other.
+"""
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from daft.daft import (
+    substrait_capability_report,
+    substrait_from_expressions,
+    substrait_from_plan,
+    substrait_plan_tables,
+    substrait_to_expressions,
+    substrait_to_plan,
+)
+from daft.expressions import Expression
+from daft.logical.builder import LogicalPlanBuilder
+from daft.logical.schema import Schema
+
+if TYPE_CHECKING:
+    from daft.dataframe import DataFrame
+
+
+def to_substrait(exprs: list[Expression], schema: Schema) -> bytes:
+    """Serializes expressions over `schema` to a Substrait `ExtendedExpression` message.
+
+    Args:
+        exprs: The expressions to serialize. Each is named after its output column.
+        schema: The schema the expressions are evaluated over.
+
+    Returns:
+        bytes: The serialized protobuf message.
+
+    Raises:
+        DaftCoreException: If any expression uses a function, literal or type that has no Substrait
+            equivalent. Use :func:`capability_report` to list them up front.
+
+    Examples:
+        >>> import daft
+        >>> from daft.substrait import from_substrait, to_substrait
+        >>> schema = daft.from_pydict({"x": [1, 2, 3]}).schema()
+        >>> data = to_substrait([(daft.col("x") + 1).alias("y")], schema)
+        >>> exprs, _ = from_substrait(data)
+        >>> [e.name() for e in exprs]
+        ['y']
+    """
+    return substrait_from_expressions([e._expr for e in exprs], schema._schema)
+
+
+def from_substrait(data: bytes) -> tuple[list[Expression], Schema]:
+    """Deserializes a Substrait `ExtendedExpression` message into Daft expressions.
+
+    Args:
+        data: The serialized protobuf message.
+
+    Returns:
+        tuple[list[Expression], Schema]: The expressions, each aliased to its output name, and the schema
+            they are evaluated over.
+
+    Raises:
+        DaftCoreException: If any expression uses a function or construct that Daft doesn't support.
+    """
+    pyexprs, pyschema = substrait_to_expressions(data)
+    return [Expression._from_pyexpr(e) for e in pyexprs], Schema._from_pyschema(pyschema)
+
+
+def capability_report(exprs: list[Expression], schema: Schema) -> list[str]:
+    """Lists the parts of `exprs` that can't be serialized to Substrait.
+
+    Args:
+        exprs: The expressions to check.
+        schema: The schema the expressions are evaluated over.
+
+    Returns:
+        list[str]: A description of each unsupported function, literal or type. Empty if every expression
+            can be serialized.
+    """
+    return substrait_capability_report([e._expr for e in exprs], schema._schema)
+
+
+def plan_to_substrait(df: DataFrame, tables: dict[str, DataFrame]) -> bytes:
+    """Serializes the optimized plan of a DataFrame to a Substrait `Plan` message.
+
+    The sources of the plan are serialized as reads of named tables, so every source must be one of
+    `tables`. Filters, projections and limits that the optimizer pushes into a source are serialized
+    as part of its read.
+
+    Args:
+        df: The DataFrame whose plan to serialize.
+        tables: The tables that the plan reads by name, each a DataFrame that scans a single source,
+            such as files or in-memory data. Dotted names are serialized as qualified names.
+
+    Returns:
+        bytes: The serialized protobuf message.
+
+    Raises:
+        DaftCoreException: If the plan uses an operation, function, literal or type that has no Substrait
+            equivalent, or reads a source that isn't one of `tables`.
+
+    Examples:
+        >>> import daft
+        >>> from daft.substrait import plan_from_substrait, plan_to_substrait
+        >>> people = daft.from_pydict({"name": ["a", "b"], "age": [30, 40]})
+        >>> data = plan_to_substrait(people.where(daft.col("age") > 35), {"people": people})
+        >>> plan_from_substrait(data, {"people": people}).to_pydict()
+        {'name': ['b'], 'age': [40]}
+    """
+    return substrait_from_plan(df._builder._builder, [(name, t._builder._builder) for name, t in tables.items()])
+
+
+def plan_from_substrait(data: bytes, tables: dict[str, DataFrame] | None = None) -> DataFrame:
+    """Deserializes a Substrait `Plan` message into a DataFrame.
+
+    Args:
+        data: The serialized protobuf message, whose only relation is the root of the plan.
+        tables (optional): The tables that the plan reads by name, with the parts of qualified names joined
+            by `.`. Tables that aren't given are read from the current session.
+
+    Returns:
+        DataFrame: A DataFrame that executes the plan, with columns named after the root of the plan.
+
+    Raises:
+        DaftCoreException: If the plan uses a relation, function or construct that Daft doesn't support.
+    """
+    from daft.dataframe import DataFrame
+    from daft.session import read_table
+
+    tables = dict(tables or {})
+    for name in substrait_plan_tables(data):
+        if name not in tables:
+            tables[name] = read_table(name)
+    builder = substrait_to_plan(data, {name: t._builder._builder for name, t in tables.items()})
+    return DataFrame(LogicalPlanBuilder(builder))
+
+
+__all__ = ["capability_report", "from_substrait", "plan_from_substrait", "plan_to_substrait", "to_substrait"]
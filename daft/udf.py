@@ -1,8 +1,11 @@
 from __future__ import annotations
 
+import asyncio
 import dataclasses
 import functools
 import inspect
+import os
+import threading
 from typing import Any, Callable, Dict, Optional, Tuple, Union
 
 import daft
@@ -18,16 +21,106 @@ UserDefinedPyFunc = Callable[..., UdfReturnType]
 UserDefinedPyFuncLike = Union[UserDefinedPyFunc, type]
 
 
+# Default number of calls of an async UDF that may be awaited at once in each process
+DEFAULT_MAX_IN_FLIGHT = 8
+
+
+@dataclasses.dataclass(frozen=True)
+class AsyncOptions:
+    """How calls of an `async def` UDF are driven."""
+
+    name: str
+    max_in_flight: int
+    timeout: float | None
+
+
+class AsyncBatchError(Exception):
+    """Raised when a call of an async UDF on one of its batches fails or times out."""
+
+    def __init__(self, index: int, cause: BaseException):
+        super().__init__(index, cause)
+        self.index = index
+        self.cause = cause
+
+
+# Async UDFs are driven on a single event loop running in a background thread of each process, so that
+# clients created by a UDF (e.g. HTTP sessions) stay bound to the same loop across calls.
+_async_loop: asyncio.AbstractEventLoop | None = None
+_async_loop_pid: int | None = None
+_async_loop_lock = threading.Lock()
+# Semaphores bounding the calls in flight of each async UDF, owned by the event loop
+_async_semaphores: dict[AsyncOptions, asyncio.Semaphore] = {}
+
+
+def _get_async_loop() -> asyncio.AbstractEventLoop:
+    global _async_loop, _async_loop_pid
+    with _async_loop_lock:
+        # a forked process doesn't inherit the thread running the loop, so it starts its own
+        if _async_loop is None or _async_loop_pid != os.getpid():
+            loop = asyncio.new_event_loop()
+            threading.Thread(target=loop.run_forever, name="daft-async-udf", daemon=True).start()
+            _async_loop = loop
+            _async_loop_pid = os.getpid()
+            _async_semaphores.clear()
+        return _async_loop
+
+
+@dataclasses.dataclass(frozen=True)
+class AsyncUdf:
+    """An initialized `async def` UDF, whose calls on each batch of a partition are awaited concurrently."""
+
+    func: Callable[..., Any]
+    options: AsyncOptions
+
+    def __repr__(self) -> str:
+        return repr(self.func)
+
+    async def _run_batches(self, batches: list[tuple[list[Any], dict[str, Any]]]) -> list[Any]:
+        semaphore = _async_semaphores.get(self.options)
+        if semaphore is None:
+            semaphore = _async_semaphores[self.options] = asyncio.Semaphore(self.options.max_in_flight)
+
+        async def call(index: int, args: list[Any], kwargs: dict[str, Any]) -> Any:
+            async with semaphore:
+                try:
+                    return await asyncio.wait_for(self.func(*args, **kwargs), self.options.timeout)
+                except Exception as e:
+                    raise AsyncBatchError(index, e) from e
+
+        tasks = [asyncio.ensure_future(call(i, args, kwargs)) for i, (args, kwargs) in enumerate(batches)]
+        try:
+            return await asyncio.gather(*tasks)
+        finally:
+            # stop the remaining calls as soon as one fails
+            for task in tasks:
+                task.cancel()
+
+    def run_batches(self, batches: list[tuple[list[Any], dict[str, Any]]]) -> list[UdfReturnType]:
+        """Runs the UDF on each batch of arguments, raising an `AsyncBatchError` for the first batch that fails."""
+        return asyncio.run_coroutine_threadsafe(self._run_batches(batches), _get_async_loop()).result()
+
+
+def is_async_udf(inner: UserDefinedPyFuncLike) -> bool:
+    """Whether `inner` is an `async def` function, or a class with an `async def __call__`."""
+    if isinstance(inner, type):
+        return inspect.iscoroutinefunction(getattr(inner, "__call__", None))
+    return inspect.iscoroutinefunction(inner)
+
+
 @dataclasses.dataclass(frozen=True)
 class UninitializedUdf:
     inner: Callable[..., UserDefinedPyFunc]
+    async_options: AsyncOptions | None = None
 
     def initialize(self, init_args: InitArgsType) -> UserDefinedPyFunc:
         if init_args is None:
-            return self.inner()
+            func = self.inner()
         else:
             args, kwargs = init_args
-            return self.inner(*args, **kwargs)
+            func = self.inner(*args, **kwargs)
+        if self.async_options is not None:
+            return AsyncUdf(func, self.async_options)
+        return func
 
 
 @dataclasses.dataclass(frozen=True)
@@ -128,7 +221,27 @@ def run_udf(
 
         return args, kwargs
 
-    if batch_size is None or len(evaluated_expressions[0]) <= batch_size:
+    if isinstance(func, AsyncUdf):
+        num_rows = len(evaluated_expressions[0])
+        if batch_size is not None and len(set(len(s) for s in evaluated_expressions)) != 1:
+            raise RuntimeError(
+                f"User-defined function `{func}` failed: cannot run in batches when inputs are different lengths: {tuple(len(series) for series in evaluated_expressions)}"
+            )
+        step = num_rows if batch_size is None else batch_size
+        bounds = [(start, min(start + step, num_rows)) for start in range(0, max(num_rows, 1), max(step, 1))]
+        try:
+            results = func.run_batches([get_args_for_slice(start, end) for start, end in bounds])
+        except AsyncBatchError as e:
+            start, end = bounds[e.index]
+            lengths = tuple(end - start for _ in evaluated_expressions)
+            if isinstance(e.cause, asyncio.TimeoutError):
+                raise RuntimeError(
+                    f"User-defined function `{func}` timed out after {func.options.timeout}s when executing on inputs with lengths: {lengths}"
+                ) from e.cause
+            raise RuntimeError(
+                f"User-defined function `{func}` failed when executing on inputs with lengths: {lengths}"
+            ) from e.cause
+    elif batch_size is None or len(evaluated_expressions[0]) <= batch_size:
         args, kwargs = get_args_for_slice(0, len(evaluated_expressions[0]))
         try:
             results = [func(*args, **kwargs)]
@@ -221,6 +334,8 @@ class UDF:
     concurrency: int | None = None
     resource_request: ResourceRequest | None = None
     batch_size: int | None = None
+    max_in_flight: int | None = None
+    timeout: float | None = None
 
     def __post_init__(self):
         # Analogous to the @functools.wraps(self.inner) pattern
@@ -228,11 +343,25 @@ class UDF:
         # self.__module__ and self.__qualname__, which is used in `__reduce__` during serialization.
         functools.update_wrapper(self, self.inner)
 
+        async_options = None
+        if is_async_udf(self.inner):
+            if self.max_in_flight is not None and self.max_in_flight <= 0:
+                raise ValueError(f"max_in_flight for UDF must be a positive integer, got {self.max_in_flight}")
+            if self.timeout is not None and self.timeout <= 0:
+                raise ValueError(f"timeout for UDF must be a positive number of seconds, got {self.timeout}")
+            async_options = AsyncOptions(
+                name=self.name,
+                max_in_flight=DEFAULT_MAX_IN_FLIGHT if self.max_in_flight is None else self.max_in_flight,
+                timeout=self.timeout,
+            )
+        elif self.max_in_flight is not None or self.timeout is not None:
+            raise ValueError("max_in_flight and timeout can only be set for `async def` UDFs.")
+
         # construct the UninitializedUdf here so that the constructed expressions can maintain equality
         if isinstance(self.inner, type):
-            self.wrapped_inner = UninitializedUdf(self.inner)
+            self.wrapped_inner = UninitializedUdf(self.inner, async_options)
         else:
-            self.wrapped_inner = UninitializedUdf(lambda: self.inner)
+            self.wrapped_inner = UninitializedUdf(lambda: self.inner, async_options)
 
     def __call__(self, *args, **kwargs) -> Expression:
         self._validate_init_args()
@@ -259,6 +388,8 @@ class UDF:
         num_gpus: float | None = _UnsetMarker,
         memory_bytes: int | None = _UnsetMarker,
         batch_size: int | None = _UnsetMarker,
+        max_in_flight: int | None = _UnsetMarker,
+        timeout: float | None = _UnsetMarker,
     ) -> UDF:
         """Replace the resource requests for running each instance of your UDF.
 
@@ -270,6 +401,8 @@ class UDF:
             memory_bytes: Amount of memory to allocate each running instance of your UDF in bytes. If your UDF is experiencing out-of-memory errors,
                 this parameter can help hint Daft that each UDF requires a certain amount of heap memory for execution.
             batch_size: Enables batching of the input into batches of at most this size. Results between batches are concatenated.
+            max_in_flight: For `async def` UDFs, the maximum number of calls awaited at once in each process.
+            timeout: For `async def` UDFs, the number of seconds each call may take before it is cancelled and the query fails.
 
         Examples:
             For instance, if your UDF requires 4 CPUs to run, you can configure it like so:
@@ -294,8 +427,16 @@ class UDF:
             new_resource_request = new_resource_request.with_memory_bytes(memory_bytes)
 
         new_batch_size = self.batch_size if batch_size is _UnsetMarker else batch_size
-
-        return dataclasses.replace(self, resource_request=new_resource_request, batch_size=new_batch_size)
+        new_max_in_flight = self.max_in_flight if max_in_flight is _UnsetMarker else max_in_flight
+        new_timeout = self.timeout if timeout is _UnsetMarker else timeout
+
+        return dataclasses.replace(
+            self,
+            resource_request=new_resource_request,
+            batch_size=new_batch_size,
+            max_in_flight=new_max_in_flight,
+            timeout=new_timeout,
+        )
 
     def _validate_init_args(self):
         if isinstance(self.inner, type):
@@ -405,6 +546,8 @@ def udf(
     memory_bytes: int | None = None,
     batch_size: int | None = None,
     concurrency: int | None = None,
+    max_in_flight: int | None = None,
+    timeout: float | None = None,
 ) -> Callable[[UserDefinedPyFuncLike], UDF]:
     """`@udf` Decorator to convert a Python function/class into a `UDF`.
 
@@ -423,6 +566,9 @@ def udf(
         concurrency: Spin up `N` number of persistent replicas of the UDF to process all partitions. Defaults to `None` which will spin up one
             UDF per partition. This is especially useful for expensive initializations that need to be amortized across partitions such as
             loading model weights for model batch inference.
+        max_in_flight: For `async def` UDFs, the maximum number of calls awaited at once in each process. Defaults to 8.
+        timeout: For `async def` UDFs, the number of seconds each call may take before it is cancelled and the query fails.
+            Defaults to `None`, which never times out.
 
     Returns:
         Callable[[UserDefinedPyFuncLike], UDF]: UDF decorator - converts a user-provided Python function as a UDF that can be called on Expressions
@@ -518,6 +664,35 @@ def udf(
         <BLANKLINE>
         (Showing first 3 of 3 rows)
 
+    Tip: "Async UDFs"
+        UDFs that spend most of their time waiting on I/O, such as calls to a model or feature-store API, can be
+        written as `async def`. Daft awaits the calls on each batch of a partition concurrently on a shared event loop,
+        with at most `max_in_flight` calls in flight at once, each cancelled if it takes longer than `timeout` seconds.
+
+        >>> import asyncio
+        >>> import daft
+        >>> @daft.udf(return_dtype=daft.DataType.int64(), batch_size=1, max_in_flight=4, timeout=10)
+        ... async def slow_double(x: daft.Series):
+        ...     await asyncio.sleep(0.01)
+        ...     return [v * 2 for v in x]
+        >>>
+        >>> df = daft.from_pydict({"x": [1, 2, 3]})
+        >>> df = df.with_column("doubled", slow_double(df["x"]))
+        >>> df.show()
+        ╭───────┬─────────╮
+        │ x     ┆ doubled │
+        │ ---   ┆ ---     │
+        │ Int64 ┆ Int64   │
+        ╞═══════╪═════════╡
+        │ 1     ┆ 2       │
+        ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┤
+        │ 2     ┆ 4       │
+        ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┤
+        │ 3     ┆ 6       │
+        ╰───────┴─────────╯
+        <BLANKLINE>
+        (Showing first 3 of 3 rows)
+
     """
     inferred_return_dtype = DataType._infer_type(return_dtype)
 
@@ -547,6 +722,8 @@ def udf(
             resource_request=resource_request,
             batch_size=batch_size,
             concurrency=concurrency,
+            max_in_flight=max_in_flight,
+            timeout=timeout,
         )
 
         daft.attach_function(udf)
@@ -7,7 +7,7 @@ from urllib.parse import urlparse
 
 import unitycatalog
 
-from daft.io import AzureConfig, IOConfig, S3Config
+from daft.io import AzureConfig, GCSConfig, IOConfig, S3Config
 
 if TYPE_CHECKING:
     from unitycatalog.types import TableInfo
@@ -168,9 +168,12 @@ class UnityCatalog:
                 else None
             )
         elif scheme == "gcs" or scheme == "gs":
-            # TO-DO: gather GCS credential vending assets from Unity and construct 'io_config``
-            warnings.warn("GCS credential vending from Unity Catalog is not yet supported.")
-            io_config = None
+            gcp_oauth_token = temp_table_credentials.gcp_oauth_token
+            io_config = (
+                IOConfig(gcs=GCSConfig(token=gcp_oauth_token.oauth_token))
+                if gcp_oauth_token is not None and gcp_oauth_token.oauth_token is not None
+                else None
+            )
         elif scheme == "az" or scheme == "abfs" or scheme == "abfss":
             io_config = IOConfig(
                 azure=AzureConfig(sas_token=temp_table_credentials.azure_user_delegation_sas.get("sas_token"))
@@ -140,12 +140,29 @@ class Window:
     ) -> Window:
         """Restricts each window to a range-based frame between start and end boundaries.
 
+        Range frames include every row whose order by value is within the offsets of the current row's value,
+        so they require a single numeric or temporal order by column. Temporal offsets are in the units of the
+        column, such as days for dates.
+
         Args:
-            start: Boundary definitions (unbounded_preceding, unbounded_following, current_row, or numeric/time offsets)
+            start: Boundary definitions (unbounded_preceding, unbounded_following, current_row, or integer offsets)
             end: Boundary definitions
             min_periods: Minimum rows required to compute a result (default = 1)
 
         Returns:
             Window: A window specification with the given frame bounds.
         """
-        raise NotImplementedError("Window.range_between is not implemented yet")
+        if isinstance(start, int):
+            start = _WindowBoundary.offset(start)
+        if isinstance(end, int):
+            end = _WindowBoundary.offset(end)
+
+        frame = _WindowFrame(
+            frame_type=_WindowFrameType.Range,
+            start=start,
+            end=end,
+        )
+
+        new_window = self
+        new_window._spec = self._spec.with_frame(frame).with_min_periods(min_periods)
+        return new_window
@@ -1,7 +1,8 @@
 use std::sync::Arc;
 
 use crate::{
-    array::{Array, PrimitiveArray}, bitmap::MutableBitmap, datatypes::DataType, types::NativeType
+    array::{Array, PrimitiveArray}, bitmap::MutableBitmap, buffer::allocator::AllocatedVec,
+    datatypes::DataType, types::NativeType,
 };
 
 use super::{
@@ -14,7 +15,7 @@ pub struct GrowablePrimitive<'a, T: NativeType> {
     data_type: DataType,
     arrays: Vec<&'a [T]>,
     validity: MutableBitmap,
-    values: Vec<T>,
+    values: AllocatedVec<T>,
     extend_null_bits: Vec<ExtendNullBits<'a>>,
 }
 
@@ -48,7 +49,7 @@ impl<'a, T: NativeType> GrowablePrimitive<'a, T> {
         Self {
             data_type,
             arrays,
-            values: Vec::with_capacity(capacity),
+            values: AllocatedVec::with_capacity(capacity),
             validity: MutableBitmap::with_capacity(capacity),
             extend_null_bits,
         }
@@ -74,8 +75,8 @@ impl<'a, T: NativeType> Growable<'a> for GrowablePrimitive<'a, T> {
 
     #[inline]
     fn extend_validity(&mut self, additional: usize) {
-        self.values
-            .resize(self.values.len() + additional, T::default());
+        let len = self.values.len();
+        self.values.resize(len + additional, T::default());
         self.validity.extend_constant(additional, false);
     }
 
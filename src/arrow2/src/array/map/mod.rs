@@ -12,6 +12,9 @@ mod iterator;
 #[allow(unused)]
 pub use iterator::*;
 
+mod mutable;
+pub use mutable::*;
+
 /// An array representing a (key, value), both of arbitrary logical types.
 #[derive(Clone)]
 pub struct MapArray {
@@ -111,6 +114,16 @@ impl MapArray {
         let field = new_empty_array(Self::get_field(&data_type).data_type().clone());
         Self::new(data_type, OffsetsBuffer::default(), field, None)
     }
+
+    /// Returns the default [`DataType`] of a [`MapArray`] with the given key and value types:
+    /// `Map<entries: Struct<key: non-null, value>>`.
+    pub fn default_datatype(key_type: DataType, value_type: DataType, keys_sorted: bool) -> DataType {
+        let entries = DataType::Struct(vec![
+            Field::new("key", key_type, false),
+            Field::new("value", value_type, true),
+        ]);
+        DataType::Map(Box::new(Field::new("entries", entries, false)), keys_sorted)
+    }
 }
 
 impl MapArray {
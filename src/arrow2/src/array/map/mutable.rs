@@ -0,0 +1,304 @@
+use std::{cmp::Ordering, sync::Arc};
+
+use crate::{
+    array::{Array, MutableArray, StructArray, TryPush},
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::{Error, Result},
+    offset::Offsets,
+};
+
+use super::MapArray;
+
+/// The mutable version of [`MapArray`], built from a [`MutableArray`] of keys and one of values.
+///
+/// Keys are never null, values may be. When created with [`MutableMapArray::with_sorted_keys`],
+/// every map pushed is validated to have its keys in strictly increasing order.
+#[derive(Debug, Clone)]
+pub struct MutableMapArray<K: MutableArray, V: MutableArray> {
+    data_type: DataType,
+    offsets: Offsets<i32>,
+    keys: K,
+    values: V,
+    validity: Option<MutableBitmap>,
+}
+
+impl<K: MutableArray + Default, V: MutableArray + Default> MutableMapArray<K, V> {
+    /// Creates a new empty [`MutableMapArray`].
+    pub fn new() -> Self {
+        Self::new_from(K::default(), V::default(), false)
+    }
+
+    /// Creates a new empty [`MutableMapArray`] whose maps must have sorted keys.
+    pub fn with_sorted_keys() -> Self {
+        Self::new_from(K::default(), V::default(), true)
+    }
+}
+
+impl<K: MutableArray + Default, V: MutableArray + Default> Default for MutableMapArray<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: MutableArray, V: MutableArray> MutableMapArray<K, V> {
+    /// Creates a new [`MutableMapArray`] from empty [`MutableArray`]s of keys and values.
+    /// # Panics
+    /// Panics iff `keys` or `values` is not empty.
+    pub fn new_from(keys: K, values: V, keys_sorted: bool) -> Self {
+        assert_eq!(keys.len(), 0);
+        assert_eq!(values.len(), 0);
+        let data_type = MapArray::default_datatype(
+            keys.data_type().clone(),
+            values.data_type().clone(),
+            keys_sorted,
+        );
+        Self {
+            data_type,
+            offsets: Offsets::new(),
+            keys,
+            values,
+            validity: None,
+        }
+    }
+
+    /// Whether the keys of every map are validated to be sorted.
+    pub fn keys_sorted(&self) -> bool {
+        matches!(self.data_type, DataType::Map(_, true))
+    }
+
+    /// Returns the length of this array
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.offsets.len_proxy()
+    }
+
+    /// The offsets
+    pub fn offsets(&self) -> &Offsets<i32> {
+        &self.offsets
+    }
+
+    /// The keys
+    pub fn keys(&self) -> &K {
+        &self.keys
+    }
+
+    /// The values
+    pub fn values(&self) -> &V {
+        &self.values
+    }
+
+    /// Pushes a null map.
+    #[inline]
+    pub fn push_null(&mut self) {
+        self.offsets.extend_constant(1);
+        match &mut self.validity {
+            Some(validity) => validity.push(false),
+            None => self.init_validity(),
+        }
+    }
+
+    /// Pushes a map with the given `(key, value)` entries.
+    /// # Errors
+    /// Errors iff pushing the keys or values errors, or the array was created with sorted keys and
+    /// the keys are not strictly increasing, in which case nothing is pushed.
+    pub fn try_push_entries<I, KT, VT>(&mut self, entries: I) -> Result<()>
+    where
+        I: IntoIterator<Item = (KT, Option<VT>)>,
+        KT: PartialOrd,
+        K: TryPush<Option<KT>>,
+        V: TryPush<Option<VT>>,
+    {
+        if !self.keys_sorted() {
+            return self.push_entries_unchecked(entries);
+        }
+        let entries = entries.into_iter().collect::<Vec<_>>();
+        let unsorted = entries
+            .windows(2)
+            .position(|w| w[0].0.partial_cmp(&w[1].0) != Some(Ordering::Less));
+        if let Some(position) = unsorted {
+            return Err(Error::InvalidArgumentError(format!(
+                "MutableMapArray expects sorted keys, but the key at position {} of map {} is not greater than the key before it",
+                position + 1,
+                self.len()
+            )));
+        }
+        self.push_entries_unchecked(entries)
+    }
+
+    fn push_entries_unchecked<I, KT, VT>(&mut self, entries: I) -> Result<()>
+    where
+        I: IntoIterator<Item = (KT, Option<VT>)>,
+        K: TryPush<Option<KT>>,
+        V: TryPush<Option<VT>>,
+    {
+        let mut length = 0;
+        for (key, value) in entries {
+            self.keys.try_push(Some(key))?;
+            self.values.try_push(value)?;
+            length += 1;
+        }
+        self.offsets.try_push_usize(length)?;
+        if let Some(validity) = &mut self.validity {
+            validity.push(true)
+        }
+        Ok(())
+    }
+
+    /// Pushes a map with the given `(key, value)` entries, or a null map for `None`.
+    pub fn try_push_map<I, KT, VT>(&mut self, entries: Option<I>) -> Result<()>
+    where
+        I: IntoIterator<Item = (KT, Option<VT>)>,
+        KT: PartialOrd,
+        K: TryPush<Option<KT>>,
+        V: TryPush<Option<VT>>,
+    {
+        match entries {
+            Some(entries) => self.try_push_entries(entries),
+            None => {
+                self.push_null();
+                Ok(())
+            }
+        }
+    }
+
+    fn init_validity(&mut self) {
+        let len = self.offsets.len_proxy();
+
+        let mut validity = MutableBitmap::with_capacity(self.offsets.capacity());
+        validity.extend_constant(len, true);
+        validity.set(len - 1, false);
+        self.validity = Some(validity)
+    }
+
+    /// Reserves `additional` maps.
+    pub fn reserve(&mut self, additional: usize) {
+        self.offsets.reserve(additional);
+        if let Some(x) = self.validity.as_mut() {
+            x.reserve(additional)
+        }
+    }
+
+    /// Shrinks the capacity of the [`MutableMapArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.keys.shrink_to_fit();
+        self.values.shrink_to_fit();
+        self.offsets.shrink_to_fit();
+        if let Some(validity) = &mut self.validity {
+            validity.shrink_to_fit()
+        }
+    }
+
+    fn take_into_map(&mut self) -> MapArray {
+        let field = MapArray::get_field(&self.data_type);
+        let entries = StructArray::new(
+            field.data_type().clone(),
+            vec![self.keys.as_box(), self.values.as_box()],
+            None,
+        );
+        MapArray::new(
+            self.data_type.clone(),
+            std::mem::take(&mut self.offsets).into(),
+            entries.boxed(),
+            std::mem::take(&mut self.validity).map(|x| x.into()),
+        )
+    }
+}
+
+impl<K: MutableArray, V: MutableArray> From<MutableMapArray<K, V>> for MapArray {
+    fn from(mut other: MutableMapArray<K, V>) -> Self {
+        other.take_into_map()
+    }
+}
+
+impl<K: MutableArray + 'static, V: MutableArray + 'static> MutableArray for MutableMapArray<K, V> {
+    fn len(&self) -> usize {
+        MutableMapArray::len(self)
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        self.validity.as_ref()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        self.take_into_map().boxed()
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        self.take_into_map().arced()
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Int32Array, MutablePrimitiveArray, MutableUtf8Array, Utf8Array};
+
+    #[test]
+    fn push_entries_and_nulls() -> Result<()> {
+        let mut array = MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::new();
+        array.try_push_entries([("a", Some(1)), ("b", None)])?;
+        array.push_null();
+        array.try_push_entries(Vec::<(&str, Option<i32>)>::new())?;
+        array.try_push_map(Some([("c", Some(3))]))?;
+
+        let array: MapArray = array.into();
+        assert_eq!(array.len(), 4);
+        assert_eq!(array.offsets().as_slice(), &[0, 2, 2, 2, 3]);
+        assert_eq!(
+            array.validity().map(|v| v.iter().collect::<Vec<_>>()),
+            Some(vec![true, false, true, true])
+        );
+
+        let entries = array.field().as_any().downcast_ref::<StructArray>().unwrap();
+        let keys = entries.values()[0].as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+        let values = entries.values()[1].as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(keys, &Utf8Array::<i32>::from_slice(["a", "b", "c"]));
+        assert_eq!(values, &Int32Array::from([Some(1), None, Some(3)]));
+        Ok(())
+    }
+
+    #[test]
+    fn sorted_keys_are_validated() -> Result<()> {
+        let mut array =
+            MutableMapArray::<MutablePrimitiveArray<i32>, MutableUtf8Array<i32>>::with_sorted_keys();
+        array.try_push_entries([(1, Some("a")), (2, Some("b"))])?;
+        assert!(array.try_push_entries([(2, Some("a")), (1, Some("b"))]).is_err());
+        assert!(array.try_push_entries([(1, Some("a")), (1, Some("b"))]).is_err());
+        assert_eq!(array.len(), 1);
+        assert_eq!(array.keys().len(), 2);
+
+        let array: MapArray = array.into();
+        assert_eq!(array.data_type(), &MapArray::default_datatype(
+            DataType::Int32,
+            DataType::Utf8,
+            true
+        ));
+        Ok(())
+    }
+}
@@ -2,7 +2,13 @@
 
 use std::cmp::Ordering;
 
-use crate::{array::*, datatypes::*, error::Error, offset::Offset, types::NativeType};
+use crate::{
+    array::*,
+    datatypes::*,
+    error::Error,
+    offset::Offset,
+    types::{months_days_ns, NativeType},
+};
 
 /// Compare the values at two arbitrary indices in two arrays.
 pub type DynComparator = Box<dyn Fn(usize, usize) -> Ordering + Send + Sync>;
@@ -224,6 +230,9 @@ pub fn build_compare(left: &dyn Array, right: &dyn Array) -> Result<DynComparato
         {
             compare_primitives::<i64>(left, right)
         }
+        (Interval(MonthDayNano), Interval(MonthDayNano)) => {
+            compare_primitives::<months_days_ns>(left, right)
+        }
         (Float32, Float32) => compare_f32(left, right),
         (Float64, Float64) => compare_f64(left, right),
         (Decimal(_, _), Decimal(_, _)) => compare_primitives::<i128>(left, right),
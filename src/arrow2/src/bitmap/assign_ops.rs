@@ -113,6 +113,7 @@ fn or_assign<T: BitChunk>(lhs: &mut MutableBitmap, rhs: &Bitmap) {
         lhs.extend_constant(rhs.len(), true);
     } else if rhs.unset_bits() == rhs.len() {
         // bitmap remains
+        assert_eq!(lhs.len(), rhs.len());
     } else {
         binary_assign(lhs, rhs, |x: T, y| x | y)
     }
@@ -125,6 +126,22 @@ impl<'a> std::ops::BitOrAssign<&'a Bitmap> for &mut MutableBitmap {
     }
 }
 
+impl<'a> std::ops::BitOrAssign<&'a Bitmap> for MutableBitmap {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: &'a Bitmap) {
+        or_assign::<u64>(self, rhs)
+    }
+}
+
+impl<'a> std::ops::BitOrAssign<&'a MutableBitmap> for MutableBitmap {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: &'a MutableBitmap) {
+        assert_eq!(self.len(), rhs.len());
+        let rhs = BitChunksExact::<u64>::new(rhs.as_slice(), rhs.len());
+        binary_assign_impl(self, rhs, |x: u64, y| x | y)
+    }
+}
+
 impl<'a> std::ops::BitOr<&'a Bitmap> for MutableBitmap {
     type Output = Self;
 
@@ -140,8 +157,8 @@ impl<'a> std::ops::BitOr<&'a Bitmap> for MutableBitmap {
 fn and_assign<T: BitChunk>(lhs: &mut MutableBitmap, rhs: &Bitmap) {
     if rhs.unset_bits() == 0 {
         // bitmap remains
-    }
-    if rhs.unset_bits() == rhs.len() {
+        assert_eq!(lhs.len(), rhs.len());
+    } else if rhs.unset_bits() == rhs.len() {
         assert_eq!(lhs.len(), rhs.len());
         lhs.clear();
         lhs.extend_constant(rhs.len(), false);
@@ -157,6 +174,22 @@ impl<'a> std::ops::BitAndAssign<&'a Bitmap> for &mut MutableBitmap {
     }
 }
 
+impl<'a> std::ops::BitAndAssign<&'a Bitmap> for MutableBitmap {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: &'a Bitmap) {
+        and_assign::<u64>(self, rhs)
+    }
+}
+
+impl<'a> std::ops::BitAndAssign<&'a MutableBitmap> for MutableBitmap {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: &'a MutableBitmap) {
+        assert_eq!(self.len(), rhs.len());
+        let rhs = BitChunksExact::<u64>::new(rhs.as_slice(), rhs.len());
+        binary_assign_impl(self, rhs, |x: u64, y| x & y)
+    }
+}
+
 impl<'a> std::ops::BitAnd<&'a Bitmap> for MutableBitmap {
     type Output = Self;
 
@@ -189,3 +222,39 @@ impl<'a> std::ops::BitXor<&'a Bitmap> for MutableBitmap {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn assign_with_bitmaps() {
+        let lhs = [true, false, true, true, false, false, true, false, true, true];
+        let rhs = [false, false, true, false, true, false, true, true, false, true];
+        let expected_and = lhs.iter().zip(rhs).map(|(l, r)| *l & r);
+        let expected_or = lhs.iter().zip(rhs).map(|(l, r)| *l | r);
+
+        let mut and = MutableBitmap::from(lhs);
+        and &= &Bitmap::from(rhs);
+        assert_eq!(and, expected_and.clone().collect());
+
+        let mut and = MutableBitmap::from(lhs);
+        and &= &MutableBitmap::from(rhs);
+        assert_eq!(and, expected_and.collect());
+
+        let mut or = MutableBitmap::from(lhs);
+        or |= &Bitmap::from(rhs);
+        assert_eq!(or, expected_or.clone().collect());
+
+        let mut or = MutableBitmap::from(lhs);
+        or |= &MutableBitmap::from(rhs);
+        assert_eq!(or, expected_or.collect());
+
+        // a sliced rhs is not byte aligned
+        let sliced = Bitmap::from([true, true, false, true, true, true, true, true, true, true, true])
+            .sliced(1, 10);
+        let mut and = MutableBitmap::from(lhs);
+        and &= &sliced;
+        assert_eq!(and, sliced.iter().zip(lhs).map(|(r, l)| l & r).collect());
+    }
+}
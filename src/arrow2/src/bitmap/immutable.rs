@@ -316,6 +316,15 @@ impl Bitmap {
         count_zeros(&self.bytes, self.offset + offset, length)
     }
 
+    /// Counts the set bits starting from `offset` bits and for `length` bits.
+    /// # Panics
+    /// Panics iff `offset + length > self.len()`.
+    #[inline]
+    pub fn set_bits_range(&self, offset: usize, length: usize) -> usize {
+        assert!(offset + length <= self.length);
+        length - self.null_count_range(offset, length)
+    }
+
     /// Creates a new [`Bitmap`] from a slice and length.
     /// # Panic
     /// Panics iff `length <= bytes.len() * 8`
@@ -233,6 +233,57 @@ impl MutableBitmap {
         count_zeros(&self.buffer, 0, self.length)
     }
 
+    /// Returns the number of unset bits in the range of `length` bits starting at `offset`.
+    /// # Panics
+    /// Panics iff `offset + length > self.len()`.
+    #[inline]
+    pub fn unset_bits_range(&self, offset: usize, length: usize) -> usize {
+        assert!(offset + length <= self.length);
+        count_zeros(&self.buffer, offset, length)
+    }
+
+    /// Returns the number of set bits in the range of `length` bits starting at `offset`.
+    /// # Panics
+    /// Panics iff `offset + length > self.len()`.
+    #[inline]
+    pub fn set_bits_range(&self, offset: usize, length: usize) -> usize {
+        length - self.unset_bits_range(offset, length)
+    }
+
+    /// Sets the `length` bits starting at `offset` to `value`.
+    /// # Implementation
+    /// Only the first and last bytes of the range are updated bit-wise, all other bytes are
+    /// filled at once.
+    /// # Panics
+    /// Panics iff `offset + length > self.len()`.
+    pub fn set_range(&mut self, offset: usize, length: usize, value: bool) {
+        assert!(offset + length <= self.length);
+        if length == 0 {
+            return;
+        }
+        let end = offset + length;
+        let first_byte = offset / 8;
+        let last_byte = (end - 1) / 8;
+
+        let head_mask = u8::MAX << (offset % 8);
+        let tail_mask = u8::MAX >> ((8 - end % 8) % 8);
+        let apply = |byte: &mut u8, mask: u8| {
+            if value {
+                *byte |= mask
+            } else {
+                *byte &= !mask
+            }
+        };
+
+        if first_byte == last_byte {
+            apply(&mut self.buffer[first_byte], head_mask & tail_mask);
+            return;
+        }
+        apply(&mut self.buffer[first_byte], head_mask);
+        self.buffer[first_byte + 1..last_byte].fill(if value { u8::MAX } else { 0 });
+        apply(&mut self.buffer[last_byte], tail_mask);
+    }
+
     /// Returns the number of unset bits on this [`MutableBitmap`].
     #[deprecated(since = "0.13.0", note = "use `unset_bits` instead")]
     pub fn null_count(&self) -> usize {
@@ -754,3 +805,42 @@ impl<'a> IntoIterator for &'a MutableBitmap {
         BitmapIter::<'a>::new(&self.buffer, 0, self.length)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_range() {
+        for (offset, length) in [(0, 0), (3, 2), (0, 8), (5, 11), (7, 17), (8, 16), (2, 21)] {
+            for value in [true, false] {
+                let mut bitmap = if value {
+                    MutableBitmap::from_len_zeroed(24)
+                } else {
+                    MutableBitmap::from_len_set(24)
+                };
+                bitmap.set_range(offset, length, value);
+
+                let expected = (0..24)
+                    .map(|i| (offset..offset + length).contains(&i) == value)
+                    .collect::<MutableBitmap>();
+                assert_eq!(bitmap, expected, "offset {offset}, length {length}");
+            }
+        }
+    }
+
+    #[test]
+    fn count_bits_in_range() {
+        let bitmap = MutableBitmap::from([
+            true, false, true, true, false, false, true, false, true, true, false,
+        ]);
+        assert_eq!(bitmap.set_bits_range(0, 11), 6);
+        assert_eq!(bitmap.set_bits_range(2, 7), 4);
+        assert_eq!(bitmap.unset_bits_range(2, 7), 3);
+        assert_eq!(bitmap.set_bits_range(9, 0), 0);
+
+        let immutable = Bitmap::from(bitmap).sliced(1, 10);
+        assert_eq!(immutable.set_bits_range(1, 7), 4);
+        assert_eq!(immutable.null_count_range(1, 7), 3);
+    }
+}
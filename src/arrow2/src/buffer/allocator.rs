@@ -0,0 +1,358 @@
+//! Pluggable allocation of the memory backing [`Buffer`]s.
+//!
+//! By default, the memory of a [`Buffer`] is allocated from and returned to the global allocator.
+//! A [`BufferAllocator`] can instead keep released allocations around to serve later requests of
+//! the same size ([`PooledAllocator`]), or prepare fresh allocations before they are used, e.g. to
+//! be backed by transparent huge pages ([`HugePageAllocator`]).
+//!
+//! Code that builds large buffers opts in by allocating them as an [`AllocatedVec`].
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    mem::size_of,
+    ops::{Deref, DerefMut},
+    sync::{Arc, LazyLock, Mutex, RwLock},
+};
+
+use super::{Buffer, Bytes, BytesAllocator};
+
+/// A type-erased allocation, i.e. an empty `Vec<T>` with spare capacity.
+pub type Allocation = Box<dyn Any + Send + Sync>;
+
+/// A source of memory for [`AllocatedVec`]s.
+pub trait BufferAllocator: Send + Sync + std::fmt::Debug {
+    /// Returns the number of bytes to allocate for a request of `bytes` bytes.
+    fn size_class(&self, bytes: usize) -> usize {
+        bytes
+    }
+
+    /// Whether allocations should be handed back to [`BufferAllocator::release`] once they are no
+    /// longer used, instead of being freed.
+    fn recycles(&self) -> bool {
+        false
+    }
+
+    /// Returns a previously released allocation for elements of type `type_id` whose capacity is
+    /// `size_class` bytes, if there is one.
+    fn reuse(&self, _type_id: TypeId, _size_class: usize) -> Option<Allocation> {
+        None
+    }
+
+    /// Takes back an allocation for elements of type `type_id` whose capacity is `size_class`
+    /// bytes. Dropping `allocation` frees its memory.
+    fn release(&self, _type_id: TypeId, _size_class: usize, allocation: Allocation) {
+        drop(allocation)
+    }
+
+    /// Called with every region of `len` bytes that is newly allocated from the global allocator.
+    fn prepare(&self, _ptr: *mut u8, _len: usize) {}
+}
+
+/// A [`BufferAllocator`] that allocates from the global allocator.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HeapAllocator;
+
+impl BufferAllocator for HeapAllocator {}
+
+/// Allocations smaller than this are not worth pooling.
+const MIN_POOLED_BYTES: usize = 64 * 1024;
+
+#[derive(Debug, Default)]
+struct Pool {
+    pooled_bytes: usize,
+    allocations: HashMap<(TypeId, usize), Vec<Allocation>>,
+}
+
+/// A [`BufferAllocator`] that keeps up to `max_pooled_bytes` of released allocations to reuse
+/// them for later allocations of the same element type and size class.
+///
+/// Allocations of at least 64 KiB are rounded up to the next power of two, so that buffers of
+/// similar sizes share the same size class.
+#[derive(Debug)]
+pub struct PooledAllocator {
+    max_pooled_bytes: usize,
+    pool: Mutex<Pool>,
+}
+
+impl PooledAllocator {
+    /// Creates a new [`PooledAllocator`] that keeps at most `max_pooled_bytes` of released
+    /// allocations.
+    pub fn new(max_pooled_bytes: usize) -> Self {
+        Self {
+            max_pooled_bytes,
+            pool: Mutex::new(Pool::default()),
+        }
+    }
+
+    /// Returns the number of bytes currently held by the pool.
+    pub fn pooled_bytes(&self) -> usize {
+        self.pool.lock().unwrap().pooled_bytes
+    }
+
+    /// Frees all allocations held by the pool.
+    pub fn clear(&self) {
+        let mut pool = self.pool.lock().unwrap();
+        pool.allocations.clear();
+        pool.pooled_bytes = 0;
+    }
+}
+
+impl BufferAllocator for PooledAllocator {
+    fn size_class(&self, bytes: usize) -> usize {
+        if bytes < MIN_POOLED_BYTES {
+            bytes
+        } else {
+            bytes.checked_next_power_of_two().unwrap_or(bytes)
+        }
+    }
+
+    fn recycles(&self) -> bool {
+        true
+    }
+
+    fn reuse(&self, type_id: TypeId, size_class: usize) -> Option<Allocation> {
+        let mut pool = self.pool.lock().unwrap();
+        let allocation = pool.allocations.get_mut(&(type_id, size_class))?.pop()?;
+        pool.pooled_bytes -= size_class;
+        Some(allocation)
+    }
+
+    fn release(&self, type_id: TypeId, size_class: usize, allocation: Allocation) {
+        if size_class < MIN_POOLED_BYTES || self.size_class(size_class) != size_class {
+            return;
+        }
+        let mut pool = self.pool.lock().unwrap();
+        if pool.pooled_bytes + size_class > self.max_pooled_bytes {
+            return;
+        }
+        pool.pooled_bytes += size_class;
+        pool.allocations
+            .entry((type_id, size_class))
+            .or_default()
+            .push(allocation);
+    }
+}
+
+/// The size of a transparent huge page on x86-64 and aarch64 with 4 KiB base pages.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+const HUGE_PAGE_BYTES: usize = 2 * 1024 * 1024;
+
+/// A [`BufferAllocator`] that asks the operating system to back allocations spanning at least one
+/// huge page by transparent huge pages, and otherwise defers to `inner`.
+///
+/// This is a no-op on platforms other than Linux.
+#[derive(Debug)]
+pub struct HugePageAllocator {
+    inner: Arc<dyn BufferAllocator>,
+}
+
+impl HugePageAllocator {
+    /// Creates a new [`HugePageAllocator`] on top of `inner`.
+    pub fn new(inner: Arc<dyn BufferAllocator>) -> Self {
+        Self { inner }
+    }
+}
+
+impl BufferAllocator for HugePageAllocator {
+    fn size_class(&self, bytes: usize) -> usize {
+        self.inner.size_class(bytes)
+    }
+
+    fn recycles(&self) -> bool {
+        self.inner.recycles()
+    }
+
+    fn reuse(&self, type_id: TypeId, size_class: usize) -> Option<Allocation> {
+        self.inner.reuse(type_id, size_class)
+    }
+
+    fn release(&self, type_id: TypeId, size_class: usize, allocation: Allocation) {
+        self.inner.release(type_id, size_class, allocation)
+    }
+
+    fn prepare(&self, ptr: *mut u8, len: usize) {
+        #[cfg(target_os = "linux")]
+        {
+            // `madvise` requires a page-aligned start, so only advise the huge pages that lie
+            // entirely within the region.
+            let start = (ptr as usize).next_multiple_of(HUGE_PAGE_BYTES);
+            let end = (ptr as usize + len) / HUGE_PAGE_BYTES * HUGE_PAGE_BYTES;
+            if end > start {
+                // safety: `[start, end)` lies within the allocation. The advice is only a hint, so
+                // failures are ignored.
+                unsafe {
+                    libc::madvise(start as *mut libc::c_void, end - start, libc::MADV_HUGEPAGE);
+                }
+            }
+        }
+        self.inner.prepare(ptr, len)
+    }
+}
+
+static DEFAULT_ALLOCATOR: LazyLock<RwLock<Arc<dyn BufferAllocator>>> =
+    LazyLock::new(|| RwLock::new(Arc::new(HeapAllocator)));
+
+/// Returns the process-wide [`BufferAllocator`] used by [`AllocatedVec::with_capacity`].
+pub fn default_allocator() -> Arc<dyn BufferAllocator> {
+    DEFAULT_ALLOCATOR.read().unwrap().clone()
+}
+
+/// Replaces the process-wide [`BufferAllocator`], returning the previous one.
+///
+/// Allocations made by the previous allocator are still released to it.
+pub fn set_default_allocator(allocator: Arc<dyn BufferAllocator>) -> Arc<dyn BufferAllocator> {
+    std::mem::replace(&mut *DEFAULT_ALLOCATOR.write().unwrap(), allocator)
+}
+
+/// A [`Vec<T>`] whose memory comes from a [`BufferAllocator`] and is released back to it once
+/// neither the vector nor a [`Buffer`] created from it uses it anymore.
+pub struct AllocatedVec<T: Send + Sync + 'static> {
+    vec: Vec<T>,
+    /// The allocator to release `vec` to, if it recycles allocations.
+    allocator: Option<Arc<dyn BufferAllocator>>,
+}
+
+impl<T: Send + Sync + 'static> AllocatedVec<T> {
+    /// Creates an empty vector with capacity for at least `capacity` elements from the
+    /// [`default_allocator`].
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, &default_allocator())
+    }
+
+    /// Creates an empty vector with capacity for at least `capacity` elements from `allocator`.
+    pub fn with_capacity_in(capacity: usize, allocator: &Arc<dyn BufferAllocator>) -> Self {
+        let element_size = size_of::<T>();
+        if element_size == 0 || capacity == 0 {
+            return Self::default();
+        }
+        let size_class = allocator.size_class(capacity.saturating_mul(element_size));
+        let reused = allocator
+            .reuse(TypeId::of::<T>(), size_class)
+            .and_then(|allocation| allocation.downcast::<Vec<T>>().ok());
+        let vec = match reused {
+            Some(vec) => {
+                debug_assert!(vec.is_empty());
+                *vec
+            }
+            None => {
+                let mut vec = Vec::<T>::with_capacity(size_class / element_size);
+                allocator.prepare(vec.as_mut_ptr().cast(), vec.capacity() * element_size);
+                vec
+            }
+        };
+        Self {
+            vec,
+            allocator: allocator.recycles().then(|| allocator.clone()),
+        }
+    }
+
+    /// Converts this vector into a [`Buffer`] without copying.
+    ///
+    /// If the allocator recycles allocations, the memory is released to it once the [`Buffer`]
+    /// and all its clones are dropped; such a [`Buffer`] can not be converted back into a
+    /// [`Vec<T>`] without copying.
+    pub fn into_buffer(mut self) -> Buffer<T> {
+        if self.allocator.is_none() || self.vec.capacity() == 0 {
+            return std::mem::take(&mut self.vec).into();
+        }
+        let ptr = self.vec.as_ptr();
+        let len = self.vec.len();
+        // safety: the region `[ptr, ptr + len)` is owned by `self`, which is moved into the owner
+        // of the bytes and no longer mutated. Moving `self` does not move the heap allocation.
+        let bytes = unsafe {
+            Bytes::from_foreign(ptr, len, BytesAllocator::Allocated(Box::new(self)))
+        };
+        Buffer::from_bytes(bytes)
+    }
+}
+
+impl<T: Send + Sync + 'static> Default for AllocatedVec<T> {
+    fn default() -> Self {
+        Self {
+            vec: Vec::new(),
+            allocator: None,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Deref for AllocatedVec<T> {
+    type Target = Vec<T>;
+
+    #[inline]
+    fn deref(&self) -> &Vec<T> {
+        &self.vec
+    }
+}
+
+impl<T: Send + Sync + 'static> DerefMut for AllocatedVec<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Vec<T> {
+        &mut self.vec
+    }
+}
+
+impl<T: Send + Sync + 'static> Drop for AllocatedVec<T> {
+    fn drop(&mut self) {
+        if let Some(allocator) = self.allocator.take() {
+            let mut vec = std::mem::take(&mut self.vec);
+            let size_class = vec.capacity() * size_of::<T>();
+            if size_class > 0 {
+                vec.clear();
+                allocator.release(TypeId::of::<T>(), size_class, Box::new(vec));
+            }
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> From<AllocatedVec<T>> for Buffer<T> {
+    #[inline]
+    fn from(vec: AllocatedVec<T>) -> Self {
+        vec.into_buffer()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pooled_allocations_are_reused() {
+        let allocator: Arc<dyn BufferAllocator> = Arc::new(PooledAllocator::new(1024 * 1024));
+        let pool = || allocator.reuse(TypeId::of::<i64>(), 128 * 1024);
+
+        let mut values = AllocatedVec::<i64>::with_capacity_in(10_000, &allocator);
+        values.extend(0..10_000);
+        let ptr = values.as_ptr();
+        // 80_000 bytes are rounded up to the next power of two
+        assert_eq!(values.capacity(), 16 * 1024);
+
+        let buffer = values.into_buffer();
+        let sliced = buffer.clone().sliced(1, 2);
+        assert_eq!(sliced.as_slice(), &[1, 2]);
+        drop(buffer);
+        assert!(pool().is_none());
+
+        // the memory is released once the last buffer referencing it is dropped
+        drop(sliced);
+        let allocation = pool().expect("allocation was released to the pool");
+        allocator.release(TypeId::of::<i64>(), 128 * 1024, allocation);
+
+        let reused = AllocatedVec::<i64>::with_capacity_in(9_000, &allocator);
+        assert_eq!(reused.as_ptr(), ptr);
+        assert!(reused.is_empty());
+    }
+
+    #[test]
+    fn small_and_heap_allocations_are_not_pooled() {
+        let pooled = Arc::new(PooledAllocator::new(1024 * 1024));
+        let allocator: Arc<dyn BufferAllocator> = pooled.clone();
+        drop(AllocatedVec::<u8>::with_capacity_in(1024, &allocator).into_buffer());
+        assert_eq!(pooled.pooled_bytes(), 0);
+
+        let heap: Arc<dyn BufferAllocator> = Arc::new(HeapAllocator);
+        let mut values = AllocatedVec::<u32>::with_capacity_in(3, &heap);
+        values.extend([1, 2, 3]);
+        // heap allocated buffers stay convertible back into a `Vec`
+        assert_eq!(values.into_buffer().into_mut().right(), Some(vec![1, 2, 3]));
+    }
+}
@@ -2,8 +2,8 @@ use std::{ops::Deref, sync::Arc};
 
 use either::Either;
 
-use super::Bytes;
 use super::IntoIter;
+use super::{Bytes, BytesAllocator, DeallocationHook};
 
 /// [`Buffer`] is a contiguous memory region that can be shared across
 /// thread boundaries.
@@ -256,6 +256,32 @@ impl<T> Buffer<T> {
         (data, offset, length)
     }
 
+    /// Creates a [`Buffer`] of `length` values at `ptr` in memory owned by someone else, e.g. a
+    /// NumPy array, without copying it.
+    ///
+    /// `deallocate` is called once the returned buffer and all of its clones and slices are
+    /// dropped, and should release the memory or the reference that keeps it alive.
+    ///
+    /// # Safety
+    /// `ptr` must be aligned for `T` and valid for reads of `length` values until `deallocate` is
+    /// called, and the values must not be mutated in the meantime.
+    pub unsafe fn from_foreign<F>(ptr: *const T, length: usize, deallocate: F) -> Self
+    where
+        F: FnOnce() + Send + Sync + 'static,
+    {
+        if length == 0 || ptr.is_null() {
+            deallocate();
+            return Self::new();
+        }
+        let owner = BytesAllocator::Foreign(DeallocationHook::new(deallocate));
+        let bytes = Bytes::from_foreign(ptr, length, owner);
+        Self {
+            data: Arc::new(bytes),
+            offset: 0,
+            length,
+        }
+    }
+
     /// Creates a `[Bitmap]` from its internal representation.
     /// This is the inverted from `[Bitmap::into_inner]`
     ///
@@ -307,3 +333,36 @@ impl<T: Copy> IntoIterator for Buffer<T> {
         IntoIter::new(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[test]
+    fn foreign_memory_is_released_once_all_clones_are_dropped() {
+        static RELEASED: AtomicUsize = AtomicUsize::new(0);
+
+        let values = Box::new([1u32, 2, 3, 4]);
+        let ptr = values.as_ptr();
+        let buffer = unsafe {
+            Buffer::from_foreign(ptr, values.len(), move || {
+                drop(values);
+                RELEASED.fetch_add(1, Ordering::SeqCst);
+            })
+        };
+        assert_eq!(buffer.as_ptr(), ptr);
+
+        let sliced = buffer.clone().sliced(1, 2);
+        drop(buffer);
+        assert_eq!(sliced.as_slice(), &[2, 3]);
+        assert_eq!(RELEASED.load(Ordering::SeqCst), 0);
+
+        // foreign memory is never mutated in place
+        let mut sliced = sliced;
+        assert!(sliced.get_mut_slice().is_none());
+        drop(sliced);
+        assert_eq!(RELEASED.load(Ordering::SeqCst), 1);
+    }
+}
@@ -1,5 +1,6 @@
 //! Contains [`Buffer`], an immutable container for all Arrow physical types (e.g. i32, f64).
 
+pub mod allocator;
 mod immutable;
 mod iterator;
 
@@ -9,6 +10,29 @@ use std::ops::Deref;
 pub(crate) enum BytesAllocator {
     #[allow(dead_code)]
     InternalArrowArray(InternalArrowArray),
+    /// The region is owned by an [`allocator::AllocatedVec`], which releases it to its allocator when dropped.
+    #[allow(dead_code)]
+    Allocated(Box<dyn std::any::Any + Send + Sync>),
+    /// The region is owned by someone else, e.g. a NumPy array, and is released by the hook.
+    #[allow(dead_code)]
+    Foreign(DeallocationHook),
+}
+
+/// Calls a custom deallocation hook of a foreign memory region when dropped.
+pub(crate) struct DeallocationHook(Option<Box<dyn FnOnce() + Send + Sync>>);
+
+impl DeallocationHook {
+    pub(crate) fn new(deallocate: impl FnOnce() + Send + Sync + 'static) -> Self {
+        Self(Some(Box::new(deallocate)))
+    }
+}
+
+impl Drop for DeallocationHook {
+    fn drop(&mut self) {
+        if let Some(deallocate) = self.0.take() {
+            deallocate()
+        }
+    }
 }
 pub(crate) type BytesInner<T> = foreign_vec::ForeignVec<BytesAllocator, T>;
 
@@ -5,7 +5,10 @@ use crate::compute::take;
 use crate::datatypes::*;
 use crate::error::{Error, Result};
 use crate::offset::Offset;
-use crate::{array::*, types::Index};
+use crate::{
+    array::*,
+    types::{months_days_ns, Index},
+};
 
 mod binary;
 mod boolean;
@@ -60,6 +63,9 @@ pub fn sort(
         DataType::UInt16 => dyn_sort!(u16, values, ord::total_cmp, options, limit),
         DataType::UInt32 => dyn_sort!(u32, values, ord::total_cmp, options, limit),
         DataType::UInt64 => dyn_sort!(u64, values, ord::total_cmp, options, limit),
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            dyn_sort!(months_days_ns, values, ord::total_cmp, options, limit)
+        }
         DataType::Float32 => dyn_sort!(f32, values, ord::total_cmp_f32, options, limit),
         DataType::Float64 => dyn_sort!(f64, values, ord::total_cmp_f64, options, limit),
         _ => {
@@ -128,6 +134,9 @@ pub fn sort_to_indices<I: Index>(
         DataType::UInt16 => dyn_sort_indices!(I, u16, values, ord::total_cmp, options, limit),
         DataType::UInt32 => dyn_sort_indices!(I, u32, values, ord::total_cmp, options, limit),
         DataType::UInt64 => dyn_sort_indices!(I, u64, values, ord::total_cmp, options, limit),
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            dyn_sort_indices!(I, months_days_ns, values, ord::total_cmp, options, limit)
+        }
         DataType::Float32 => dyn_sort_indices!(I, f32, values, ord::total_cmp_f32, options, limit),
         DataType::Float64 => dyn_sort_indices!(I, f64, values, ord::total_cmp_f64, options, limit),
         DataType::Utf8 => Ok(utf8::indices_sorted_unstable_by::<I, i32>(
@@ -0,0 +1,91 @@
+//! Arrow [canonical extension types](https://arrow.apache.org/docs/format/CanonicalExtensions.html).
+//!
+//! Canonical extensions are regular [`DataType::Extension`]s whose name, storage type and
+//! metadata are standardized by the Arrow specification, so that other Arrow implementations
+//! can recognize them.
+use super::DataType;
+
+/// Name of the canonical UUID extension type, stored as `FixedSizeBinary(16)`.
+pub const UUID_EXTENSION_NAME: &str = "arrow.uuid";
+
+/// Name of the canonical JSON extension type, stored as `Utf8` or `LargeUtf8`.
+pub const JSON_EXTENSION_NAME: &str = "arrow.json";
+
+/// The canonical extension types known to this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CanonicalExtension {
+    /// `arrow.uuid`: a 128-bit UUID stored as 16 big-endian bytes.
+    Uuid,
+    /// `arrow.json`: a UTF-8 encoded JSON document.
+    Json,
+}
+
+impl CanonicalExtension {
+    /// Returns the [`CanonicalExtension`] registered under `name`, if any.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            UUID_EXTENSION_NAME => Some(Self::Uuid),
+            JSON_EXTENSION_NAME => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    /// The extension name of this type, as stored in `ARROW:extension:name`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Uuid => UUID_EXTENSION_NAME,
+            Self::Json => JSON_EXTENSION_NAME,
+        }
+    }
+
+    /// Whether `storage` is a valid storage type for this extension.
+    pub fn is_valid_storage(&self, storage: &DataType) -> bool {
+        match self {
+            Self::Uuid => matches!(storage, DataType::FixedSizeBinary(16)),
+            Self::Json => matches!(storage, DataType::Utf8 | DataType::LargeUtf8),
+        }
+    }
+}
+
+impl DataType {
+    /// Returns the [`CanonicalExtension`] of this [`DataType`], if it is a
+    /// [`DataType::Extension`] with a canonical name and a valid storage type.
+    pub fn canonical_extension(&self) -> Option<CanonicalExtension> {
+        match self {
+            DataType::Extension(name, storage, _) => CanonicalExtension::from_name(name)
+                .filter(|extension| extension.is_valid_storage(storage)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_extension_requires_valid_storage() {
+        let uuid = DataType::Extension(
+            UUID_EXTENSION_NAME.to_string(),
+            Box::new(DataType::FixedSizeBinary(16)),
+            None,
+        );
+        assert_eq!(uuid.canonical_extension(), Some(CanonicalExtension::Uuid));
+
+        let bad_uuid = DataType::Extension(
+            UUID_EXTENSION_NAME.to_string(),
+            Box::new(DataType::FixedSizeBinary(8)),
+            None,
+        );
+        assert_eq!(bad_uuid.canonical_extension(), None);
+
+        let json = DataType::Extension(
+            JSON_EXTENSION_NAME.to_string(),
+            Box::new(DataType::LargeUtf8),
+            None,
+        );
+        assert_eq!(json.canonical_extension(), Some(CanonicalExtension::Json));
+
+        assert_eq!(DataType::LargeUtf8.canonical_extension(), None);
+    }
+}
@@ -1,12 +1,14 @@
 #![forbid(unsafe_code)]
 //! Contains all metadata, such as [`PhysicalType`], [`DataType`], [`Field`] and [`Schema`].
 
+mod canonical;
 mod field;
 mod physical_type;
 mod schema;
 
 use std::{collections::BTreeMap, sync::Arc};
 
+pub use canonical::{CanonicalExtension, JSON_EXTENSION_NAME, UUID_EXTENSION_NAME};
 pub use field::Field;
 pub use physical_type::*;
 pub use schema::Schema;
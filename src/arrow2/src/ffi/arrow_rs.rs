@@ -0,0 +1,189 @@
+//! Conversions between [`Array`]s, [`Field`]s and [`Schema`]s and their equivalents of the
+//! official [arrow-rs](https://docs.rs/arrow) crates.
+//!
+//! Arrays are passed through the C data interface, which both implementations speak natively, so
+//! their buffers are shared rather than copied.
+use std::sync::Arc;
+
+use arrow_array::ffi::{from_ffi, to_ffi, FFI_ArrowArray};
+use arrow_schema::ffi::FFI_ArrowSchema;
+
+use super::{
+    export_array_to_c, export_field_to_c, import_array_from_c, import_field_from_c, ArrowArray,
+    ArrowSchema,
+};
+use crate::{
+    array::Array,
+    datatypes::{DataType, Field, Schema},
+    error::{Error, Result},
+};
+
+fn external(error: arrow_schema::ArrowError) -> Error {
+    Error::External("arrow-rs".to_string(), Box::new(error))
+}
+
+// Safety of the conversions below: `ArrowSchema` and `FFI_ArrowSchema` (resp. `ArrowArray` and
+// `FFI_ArrowArray`) are both `#[repr(C)]` definitions of the same struct of the C data interface.
+// Ownership moves with the value, so the struct is released exactly once.
+fn to_ffi_schema(schema: ArrowSchema) -> FFI_ArrowSchema {
+    unsafe { std::mem::transmute::<ArrowSchema, FFI_ArrowSchema>(schema) }
+}
+
+fn from_ffi_schema(schema: FFI_ArrowSchema) -> ArrowSchema {
+    unsafe { std::mem::transmute::<FFI_ArrowSchema, ArrowSchema>(schema) }
+}
+
+fn to_ffi_array(array: ArrowArray) -> FFI_ArrowArray {
+    unsafe { std::mem::transmute::<ArrowArray, FFI_ArrowArray>(array) }
+}
+
+fn from_ffi_array(array: FFI_ArrowArray) -> ArrowArray {
+    unsafe { std::mem::transmute::<FFI_ArrowArray, ArrowArray>(array) }
+}
+
+/// Converts an [`Array`] to an arrow-rs [`arrow_array::ArrayRef`] without copying its buffers.
+pub fn array_to_arrow_rs(array: Box<dyn Array>) -> Result<arrow_array::ArrayRef> {
+    let schema = to_ffi_schema(export_field_to_c(&Field::new(
+        "",
+        array.data_type().clone(),
+        true,
+    )));
+    let array = to_ffi_array(export_array_to_c(array));
+    // Safety: both structs were just exported following the C data interface
+    let data = unsafe { from_ffi(array, &schema) }.map_err(external)?;
+    Ok(arrow_array::make_array(data))
+}
+
+/// Converts an arrow-rs [`arrow_array::Array`] to an [`Array`] without copying its buffers.
+pub fn array_from_arrow_rs(array: &dyn arrow_array::Array) -> Result<Box<dyn Array>> {
+    let (array, schema) = to_ffi(&array.to_data()).map_err(external)?;
+    let schema = from_ffi_schema(schema);
+    // Safety: both structs were just exported following the C data interface
+    unsafe {
+        let field = import_field_from_c(&schema)?;
+        import_array_from_c(from_ffi_array(array), field.data_type)
+    }
+}
+
+/// Converts a [`Field`] to an arrow-rs [`arrow_schema::Field`].
+pub fn field_to_arrow_rs(field: &Field) -> Result<arrow_schema::Field> {
+    let schema = to_ffi_schema(export_field_to_c(field));
+    arrow_schema::Field::try_from(&schema).map_err(external)
+}
+
+/// Converts an arrow-rs [`arrow_schema::Field`] to a [`Field`].
+pub fn field_from_arrow_rs(field: &arrow_schema::Field) -> Result<Field> {
+    let schema = from_ffi_schema(FFI_ArrowSchema::try_from(field).map_err(external)?);
+    // Safety: the struct was just exported following the C data interface
+    unsafe { import_field_from_c(&schema) }
+}
+
+/// Converts a [`DataType`] to an arrow-rs [`arrow_schema::DataType`].
+pub fn data_type_to_arrow_rs(data_type: &DataType) -> Result<arrow_schema::DataType> {
+    field_to_arrow_rs(&Field::new("", data_type.clone(), true)).map(|f| f.data_type().clone())
+}
+
+/// Converts an arrow-rs [`arrow_schema::DataType`] to a [`DataType`].
+pub fn data_type_from_arrow_rs(data_type: &arrow_schema::DataType) -> Result<DataType> {
+    field_from_arrow_rs(&arrow_schema::Field::new("", data_type.clone(), true))
+        .map(|f| f.data_type)
+}
+
+/// Converts a [`Schema`] to an arrow-rs [`arrow_schema::SchemaRef`].
+pub fn schema_to_arrow_rs(schema: &Schema) -> Result<arrow_schema::SchemaRef> {
+    let fields = schema
+        .fields
+        .iter()
+        .map(field_to_arrow_rs)
+        .collect::<Result<Vec<_>>>()?;
+    let metadata = schema
+        .metadata
+        .iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+    Ok(Arc::new(arrow_schema::Schema::new_with_metadata(
+        fields, metadata,
+    )))
+}
+
+/// Converts an arrow-rs [`arrow_schema::Schema`] to a [`Schema`].
+pub fn schema_from_arrow_rs(schema: &arrow_schema::Schema) -> Result<Schema> {
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|field| field_from_arrow_rs(field))
+        .collect::<Result<Vec<_>>>()?;
+    let metadata = schema
+        .metadata()
+        .iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+    Ok(Schema::from(fields).with_metadata(metadata))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Int32Array, PrimitiveArray, StructArray, Utf8Array};
+
+    #[test]
+    fn arrays_round_trip_without_copies() -> Result<()> {
+        let ints = Int32Array::from([Some(1), None, Some(3)]);
+        let strings = Utf8Array::<i64>::from([Some("a"), Some("bc"), None]);
+        let data_type = DataType::Struct(vec![
+            Field::new("ints", ints.data_type().clone(), true),
+            Field::new("strings", strings.data_type().clone(), true),
+        ]);
+        let array = StructArray::new(data_type, vec![ints.clone().boxed(), strings.boxed()], None);
+
+        let converted = array_to_arrow_rs(array.clone().boxed())?;
+        assert_eq!(converted.len(), 3);
+        let converted_ints = converted
+            .as_any()
+            .downcast_ref::<arrow_array::StructArray>()
+            .unwrap()
+            .column(0)
+            .as_any()
+            .downcast_ref::<arrow_array::Int32Array>()
+            .unwrap()
+            .clone();
+        assert_eq!(converted_ints.values().as_ptr(), ints.values().as_ptr());
+
+        let round_tripped = array_from_arrow_rs(converted.as_ref())?;
+        assert_eq!(round_tripped.as_ref(), &array as &dyn Array);
+
+        let ints_back = array_from_arrow_rs(&converted_ints)?;
+        let ints_back = ints_back
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i32>>()
+            .unwrap();
+        assert_eq!(ints_back, &ints);
+        assert_eq!(ints_back.values().as_ptr(), ints.values().as_ptr());
+        Ok(())
+    }
+
+    #[test]
+    fn schemas_round_trip() -> Result<()> {
+        let schema = Schema::from(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new(
+                "b",
+                DataType::LargeList(Box::new(Field::new("item", DataType::Utf8, true))),
+                true,
+            ),
+        ])
+        .with_metadata([("key".to_string(), "value".to_string())].into());
+
+        let converted = schema_to_arrow_rs(&schema)?;
+        assert_eq!(converted.field(0).data_type(), &arrow_schema::DataType::Int64);
+        assert!(!converted.field(0).is_nullable());
+        assert_eq!(converted.metadata()["key"], "value");
+        assert_eq!(schema_from_arrow_rs(&converted)?, schema);
+
+        assert_eq!(
+            data_type_from_arrow_rs(&data_type_to_arrow_rs(&DataType::Float16)?)?,
+            DataType::Float16
+        );
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
 //! contains FFI bindings to import and export [`Array`](crate::array::Array) via
 //! Arrow's [C Data Interface](https://arrow.apache.org/docs/format/CDataInterface.html)
 mod array;
+#[cfg(feature = "arrow")]
+pub mod arrow_rs;
 mod bridge;
 mod generated;
 pub mod mmap;
@@ -17,7 +19,7 @@ use crate::error::Result;
 use self::schema::to_field;
 
 pub use generated::{ArrowArray, ArrowArrayStream, ArrowSchema};
-pub use stream::{export_iterator, ArrowArrayStreamReader};
+pub use stream::{export_chunk_iterator, export_iterator, ArrowArrayStreamReader};
 
 /// Exports an [`Box<dyn Array>`] to the C data interface.
 pub fn export_array_to_c(array: Box<dyn Array>) -> ArrowArray {
@@ -181,6 +181,10 @@ impl ArrowSchema {
     }
 }
 
+// Sound for the same reasons as `ArrowArray`'s: the C data interface allows the struct to be
+// released from any thread.
+unsafe impl Send for ArrowSchema {}
+
 impl Drop for ArrowSchema {
     fn drop(&mut self) {
         match self.release {
@@ -1,11 +1,21 @@
 use std::ffi::{CStr, CString};
 use std::ops::DerefMut;
+use std::panic::{catch_unwind, AssertUnwindSafe};
 
-use crate::{array::Array, datatypes::Field, error::Error};
+use crate::{
+    array::{Array, StructArray},
+    chunk::Chunk,
+    datatypes::{DataType, Field, Schema},
+    error::Error,
+};
 
 use super::{export_array_to_c, export_field_to_c, import_array_from_c, import_field_from_c};
 use super::{ArrowArray, ArrowArrayStream, ArrowSchema};
 
+// Sound because the C stream interface allows a stream to be consumed and released from any
+// thread, as long as it is not used by several threads at once.
+unsafe impl Send for ArrowArrayStream {}
+
 impl Drop for ArrowArrayStream {
     fn drop(&mut self) {
         match self.release {
@@ -41,10 +51,25 @@ unsafe fn handle_error(iter: &mut ArrowArrayStream) -> Error {
     let error = unsafe { CStr::from_ptr(error) };
     Error::External(
         "C stream".to_string(),
-        Box::new(Error::ExternalFormat(error.to_str().unwrap().to_string())),
+        Box::new(Error::ExternalFormat(error.to_string_lossy().into_owned())),
     )
 }
 
+/// Converts a message to a [`CString`], replacing interior nul bytes so that it never fails.
+fn to_c_string(message: String) -> CString {
+    CString::new(message.replace('\0', "\u{fffd}")).unwrap()
+}
+
+/// Returns a human readable message of the payload of a panic.
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string());
+    format!("The producer of the C stream panicked: {message}")
+}
+
 /// Implements an iterator of [`Array`] consumed from the [C stream interface](https://arrow.apache.org/docs/format/CStreamInterface.html).
 pub struct ArrowArrayStreamReader<Iter: DerefMut<Target = ArrowArrayStream>> {
     iter: Iter,
@@ -104,6 +129,21 @@ impl<Iter: DerefMut<Target = ArrowArrayStream>> ArrowArrayStreamReader<Iter> {
         &self.field
     }
 
+    /// Returns the [`Schema`] of a stream of record batches, i.e. of a stream whose field is a
+    /// [`DataType::Struct`] with one child field per column.
+    /// # Error
+    /// Errors iff the field of the stream is not a struct.
+    pub fn schema(&self) -> Result<Schema, Error> {
+        match self.field.data_type.to_logical_type() {
+            DataType::Struct(fields) => {
+                Ok(Schema::from(fields.clone()).with_metadata(self.field.metadata.clone()))
+            }
+            other => Err(Error::OutOfSpec(format!(
+                "A C stream of record batches MUST have a struct schema, but its schema is {other:?}"
+            ))),
+        }
+    }
+
     /// Advances this iterator by one array
     /// # Error
     /// Errors iff:
@@ -128,28 +168,82 @@ impl<Iter: DerefMut<Target = ArrowArrayStream>> ArrowArrayStreamReader<Iter> {
             .map(Some)
             .transpose()
     }
+
+    /// Advances this iterator by one record batch, i.e. by one struct array whose children are
+    /// the columns of the batch.
+    /// # Error
+    /// Errors iff:
+    /// * The C stream interface returns an error or an invalid array (see [`Self::next`])
+    /// * The array is not a struct array, or has null rows
+    /// # Safety
+    /// Same as [`Self::next`]
+    pub unsafe fn next_chunk(&mut self) -> Option<Result<Chunk<Box<dyn Array>>, Error>> {
+        let array = match unsafe { self.next() }? {
+            Ok(array) => array,
+            Err(err) => return Some(Err(err)),
+        };
+        let Some(array) = array.as_any().downcast_ref::<StructArray>() else {
+            return Some(Err(Error::OutOfSpec(format!(
+                "A C stream of record batches MUST produce struct arrays, but it produced an array of data type {:?}",
+                array.data_type()
+            ))));
+        };
+        if array.null_count() > 0 {
+            return Some(Err(Error::OutOfSpec(
+                "A C stream of record batches MUST NOT produce struct arrays with null rows"
+                    .to_string(),
+            )));
+        }
+        let (_, values, _) = array.clone().into_data();
+        Some(Chunk::try_new(values))
+    }
 }
 
+// errno-compatible error codes, as required by the C stream interface
+const EINVAL: i32 = 22;
+const EIO: i32 = 5;
+
 struct PrivateData {
     iter: Box<dyn Iterator<Item = Result<Box<dyn Array>, Error>>>,
     field: Field,
     error: Option<CString>,
 }
 
+/// Returns the private data of a stream exported by [`export_iterator`], or `None` if the stream is
+/// null or was already released.
+unsafe fn private_data<'a>(iter: *mut ArrowArrayStream) -> Option<&'a mut PrivateData> {
+    if iter.is_null() || (*iter).release.is_none() || (*iter).private_data.is_null() {
+        return None;
+    }
+    Some(&mut *((*iter).private_data as *mut PrivateData))
+}
+
 unsafe extern "C" fn get_next(iter: *mut ArrowArrayStream, array: *mut ArrowArray) -> i32 {
-    if iter.is_null() {
-        return 2001;
+    let Some(private) = private_data(iter) else {
+        return EINVAL;
+    };
+    if array.is_null() {
+        return EINVAL;
     }
-    let private = &mut *((*iter).private_data as *mut PrivateData);
 
-    match private.iter.next() {
+    // unwinding across the FFI boundary is undefined behavior, so panics of the iterator are
+    // reported as errors of the stream instead
+    let next = match catch_unwind(AssertUnwindSafe(|| private.iter.next())) {
+        Ok(next) => next,
+        Err(payload) => {
+            private.error = Some(to_c_string(panic_message(payload)));
+            return EIO;
+        }
+    };
+
+    match next {
         Some(Ok(item)) => {
             // check that the array has the same data_type as field
             let item_dt = item.data_type();
             let expected_dt = private.field.data_type();
             if item_dt != expected_dt {
-                private.error = Some(CString::new(format!("The iterator produced an item of data type {item_dt:?} but the producer expects data type {expected_dt:?}").as_bytes().to_vec()).unwrap());
-                return 2001; // custom application specific error (since this is never a result of this interface)
+                private.error = Some(to_c_string(format!("The iterator produced an item of data type {item_dt:?} but the producer expects data type {expected_dt:?}")));
+                return EINVAL;
             }
 
             std::ptr::write(array, export_array_to_c(item));
@@ -158,8 +252,8 @@ unsafe extern "C" fn get_next(iter: *mut ArrowArrayStream, array: *mut ArrowArra
             0
         }
         Some(Err(err)) => {
-            private.error = Some(CString::new(err.to_string().as_bytes().to_vec()).unwrap());
-            2001 // custom application specific error (since this is never a result of this interface)
+            private.error = Some(to_c_string(err.to_string()));
+            EIO
         }
         None => {
             let a = ArrowArray::empty();
@@ -171,20 +265,30 @@ unsafe extern "C" fn get_next(iter: *mut ArrowArrayStream, array: *mut ArrowArra
 }
 
 unsafe extern "C" fn get_schema(iter: *mut ArrowArrayStream, schema: *mut ArrowSchema) -> i32 {
-    if iter.is_null() {
-        return 2001;
+    let Some(private) = private_data(iter) else {
+        return EINVAL;
+    };
+    if schema.is_null() {
+        return EINVAL;
     }
-    let private = &mut *((*iter).private_data as *mut PrivateData);
 
-    std::ptr::write(schema, export_field_to_c(&private.field));
-    0
+    match catch_unwind(AssertUnwindSafe(|| export_field_to_c(&private.field))) {
+        Ok(field) => {
+            std::ptr::write(schema, field);
+            private.error = None;
+            0
+        }
+        Err(payload) => {
+            private.error = Some(to_c_string(panic_message(payload)));
+            EIO
+        }
+    }
 }
 
 unsafe extern "C" fn get_last_error(iter: *mut ArrowArrayStream) -> *const ::std::os::raw::c_char {
-    if iter.is_null() {
+    let Some(private) = private_data(iter) else {
         return std::ptr::null();
-    }
-    let private = &mut *((*iter).private_data as *mut PrivateData);
+    };
 
     private
         .error
@@ -194,12 +298,16 @@ unsafe extern "C" fn get_last_error(iter: *mut ArrowArrayStream) -> *const ::std
 }
 
 unsafe extern "C" fn release(iter: *mut ArrowArrayStream) {
-    if iter.is_null() {
+    if iter.is_null() || (*iter).release.is_none() {
         return;
     }
-    let _ = Box::from_raw((*iter).private_data as *mut PrivateData);
+    if !(*iter).private_data.is_null() {
+        let private = Box::from_raw((*iter).private_data as *mut PrivateData);
+        // the iterator may own arbitrary state, whose drop must not unwind into the consumer
+        let _ = catch_unwind(AssertUnwindSafe(move || drop(private)));
+    }
+    (*iter).private_data = std::ptr::null_mut();
     (*iter).release = None;
-    // private drops automatically
 }
 
 /// Exports an iterator to the [C stream interface](https://arrow.apache.org/docs/format/CStreamInterface.html)
@@ -221,3 +329,81 @@ pub fn export_iterator(
         private_data: Box::into_raw(private_data) as *mut ::std::os::raw::c_void,
     }
 }
+
+/// Exports an iterator of record batches to the [C stream interface](https://arrow.apache.org/docs/format/CStreamInterface.html).
+///
+/// Following the C stream interface, each [`Chunk`] is exported as a struct array with one child
+/// per column of `schema`, and the schema of the stream is the corresponding struct field.
+pub fn export_chunk_iterator(
+    iter: Box<dyn Iterator<Item = Result<Chunk<Box<dyn Array>>, Error>>>,
+    schema: Schema,
+) -> ArrowArrayStream {
+    let data_type = DataType::Struct(schema.fields);
+    let field = Field::new("", data_type.clone(), false).with_metadata(schema.metadata);
+
+    let iter = iter.map(move |chunk| {
+        let chunk = chunk?;
+        StructArray::try_new(data_type.clone(), chunk.into_arrays(), None)
+            .map(|array| array.boxed())
+    });
+    export_iterator(Box::new(iter), field)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Int32Array, Utf8Array};
+
+    fn schema() -> Schema {
+        Schema::from(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ])
+    }
+
+    fn chunk(values: &[i32]) -> Chunk<Box<dyn Array>> {
+        let strings = values.iter().map(|v| Some(v.to_string())).collect::<Vec<_>>();
+        Chunk::new(vec![
+            Int32Array::from_slice(values).boxed(),
+            Utf8Array::<i32>::from(strings).boxed(),
+        ])
+    }
+
+    #[test]
+    fn chunks_round_trip() -> Result<(), Error> {
+        let chunks = vec![Ok(chunk(&[1, 2])), Ok(chunk(&[3]))];
+        let stream = Box::new(export_chunk_iterator(
+            Box::new(chunks.into_iter()),
+            schema(),
+        ));
+
+        let mut reader = unsafe { ArrowArrayStreamReader::try_new(stream)? };
+        assert_eq!(reader.schema()?, schema());
+
+        let mut imported = vec![];
+        while let Some(chunk) = unsafe { reader.next_chunk() } {
+            imported.push(chunk?);
+        }
+        assert_eq!(imported, vec![chunk(&[1, 2]), chunk(&[3])]);
+        Ok(())
+    }
+
+    #[test]
+    fn producer_errors_and_panics_are_reported() -> Result<(), Error> {
+        let iter = vec![Ok(chunk(&[1])), Err(Error::InvalidArgumentError("boom".to_string()))];
+        let stream = Box::new(export_chunk_iterator(Box::new(iter.into_iter()), schema()));
+        let mut reader = unsafe { ArrowArrayStreamReader::try_new(stream)? };
+        assert!(unsafe { reader.next_chunk() }.unwrap().is_ok());
+        let err = unsafe { reader.next_chunk() }.unwrap().unwrap_err();
+        assert!(err.to_string().contains("boom"));
+
+        let iter = std::iter::from_fn(|| -> Option<Result<Chunk<Box<dyn Array>>, Error>> {
+            panic!("producer panicked")
+        });
+        let stream = Box::new(export_chunk_iterator(Box::new(iter), schema()));
+        let mut reader = unsafe { ArrowArrayStreamReader::try_new(stream)? };
+        let err = unsafe { reader.next_chunk() }.unwrap().unwrap_err();
+        assert!(err.to_string().contains("producer panicked"));
+        Ok(())
+    }
+}
@@ -44,7 +44,17 @@ pub fn compress_lz4(input_buf: &[u8], output_buf: &mut Vec<u8>) -> Result<()> {
 #[cfg(feature = "io_ipc_compression")]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_compression")))]
 pub fn compress_zstd(input_buf: &[u8], output_buf: &mut Vec<u8>) -> Result<()> {
-    zstd::stream::copy_encode(input_buf, output_buf, 0).map_err(|e| e.into())
+    compress_zstd_with_level(input_buf, output_buf, 0)
+}
+
+#[cfg(feature = "io_ipc_compression")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_compression")))]
+pub fn compress_zstd_with_level(
+    input_buf: &[u8],
+    output_buf: &mut Vec<u8>,
+    level: i32,
+) -> Result<()> {
+    zstd::stream::copy_encode(input_buf, output_buf, level).map_err(|e| e.into())
 }
 
 #[cfg(not(feature = "io_ipc_compression"))]
@@ -59,6 +69,12 @@ pub fn compress_zstd(_input_buf: &[u8], _output_buf: &[u8]) -> Result<()> {
     Err(Error::OutOfSpec("The crate was compiled without IPC compression. Use `io_ipc_compression` to write compressed IPC.".to_string()))
 }
 
+#[cfg(not(feature = "io_ipc_compression"))]
+pub fn compress_zstd_with_level(_input_buf: &[u8], _output_buf: &[u8], _level: i32) -> Result<()> {
+    use crate::error::Error;
+    Err(Error::OutOfSpec("The crate was compiled without IPC compression. Use `io_ipc_compression` to write compressed IPC.".to_string()))
+}
+
 #[cfg(test)]
 #[cfg(feature = "io_ipc_compression")]
 mod tests {
@@ -76,6 +92,18 @@ mod tests {
         assert_eq!(data, result);
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)] // ZSTD uses foreign calls that miri does not support
+    fn round_trip_zstd_with_level() {
+        let data: Vec<u8> = (0..200u8).map(|x| x % 10).collect();
+        let mut buffer = vec![];
+        compress_zstd_with_level(&data, &mut buffer, 19).unwrap();
+
+        let mut result = vec![0; 200];
+        decompress_zstd(&buffer, &mut result).unwrap();
+        assert_eq!(data, result);
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)] // LZ4 uses foreign calls that miri does not support
     fn round_trip_lz4() {
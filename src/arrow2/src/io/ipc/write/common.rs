@@ -19,6 +19,8 @@ pub enum Compression {
     LZ4,
     /// ZSTD
     ZSTD,
+    /// ZSTD with the given compression level, where 0 is the codec's default
+    ZSTDWithLevel(i32),
 }
 
 /// Options declaring the behaviour of writing to IPC
@@ -219,7 +221,9 @@ fn serialize_compression(
     if let Some(compression) = compression {
         let codec = match compression {
             Compression::LZ4 => arrow_format::ipc::CompressionType::Lz4Frame,
-            Compression::ZSTD => arrow_format::ipc::CompressionType::Zstd,
+            Compression::ZSTD | Compression::ZSTDWithLevel(_) => {
+                arrow_format::ipc::CompressionType::Zstd
+            }
         };
         Some(Box::new(arrow_format::ipc::BodyCompression {
             codec,
@@ -593,6 +593,9 @@ fn write_bytes(
             Compression::ZSTD => {
                 compression::compress_zstd(bytes, arrow_data).unwrap();
             }
+            Compression::ZSTDWithLevel(level) => {
+                compression::compress_zstd_with_level(bytes, arrow_data, level).unwrap();
+            }
         }
     } else {
         arrow_data.extend_from_slice(bytes);
@@ -695,6 +698,9 @@ fn _write_compressed_buffer_from_iter<T: NativeType, I: TrustedLen<Item = T>>(
         Compression::ZSTD => {
             compression::compress_zstd(&swapped, arrow_data).unwrap();
         }
+        Compression::ZSTDWithLevel(level) => {
+            compression::compress_zstd_with_level(&swapped, arrow_data, level).unwrap();
+        }
     }
 }
 
@@ -724,6 +730,9 @@ fn _write_compressed_buffer<T: NativeType>(
             Compression::ZSTD => {
                 compression::compress_zstd(bytes, arrow_data).unwrap();
             }
+            Compression::ZSTDWithLevel(level) => {
+                compression::compress_zstd_with_level(bytes, arrow_data, level).unwrap();
+            }
         }
     } else {
         todo!()
@@ -77,6 +77,14 @@ fn convert_days_ms(value: &[u8]) -> crate::types::days_ms {
     )
 }
 
+fn convert_months_days_ns(value: &[u8]) -> crate::types::months_days_ns {
+    crate::types::months_days_ns(
+        i32::from_le_bytes(value[0..4].try_into().unwrap()),
+        i32::from_le_bytes(value[4..8].try_into().unwrap()),
+        i64::from_le_bytes(value[8..16].try_into().unwrap()),
+    )
+}
+
 fn convert_i128(value: &[u8], n: usize) -> i128 {
     // Copy the fixed-size byte value to the start of a 16 byte stack
     // allocated buffer, then use an arithmetic right shift to fill in
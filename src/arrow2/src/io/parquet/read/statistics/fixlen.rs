@@ -4,9 +4,9 @@ use parquet2::statistics::{FixedLenStatistics, Statistics as ParquetStatistics};
 use crate::array::*;
 use crate::error::Result;
 use crate::io::parquet::read::convert_i256;
-use crate::types::{days_ms, i256};
+use crate::types::{days_ms, i256, months_days_ns};
 
-use super::super::{convert_days_ms, convert_i128};
+use super::super::{convert_days_ms, convert_i128, convert_months_days_ns};
 
 pub(super) fn push_i128(
     from: Option<&dyn ParquetStatistics>,
@@ -145,3 +145,24 @@ pub(super) fn push_days_ms(
 
     Ok(())
 }
+
+pub(super) fn push_months_days_ns(
+    from: Option<&dyn ParquetStatistics>,
+    min: &mut dyn MutableArray,
+    max: &mut dyn MutableArray,
+) -> Result<()> {
+    let min = min
+        .as_mut_any()
+        .downcast_mut::<MutablePrimitiveArray<months_days_ns>>()
+        .unwrap();
+    let max = max
+        .as_mut_any()
+        .downcast_mut::<MutablePrimitiveArray<months_days_ns>>()
+        .unwrap();
+    let from = from.map(|s| s.as_any().downcast_ref::<FixedLenStatistics>().unwrap());
+
+    min.push(from.and_then(|s| s.min_value.as_deref().map(convert_months_days_ns)));
+    max.push(from.and_then(|s| s.max_value.as_deref().map(convert_months_days_ns)));
+
+    Ok(())
+}
@@ -449,6 +449,7 @@ fn push(
         Date32 | Time32(_) => primitive::push::<i32, i32, _>(from, min, max, Ok),
         Interval(IntervalUnit::YearMonth) => fixlen::push_year_month(from, min, max),
         Interval(IntervalUnit::DayTime) => fixlen::push_days_ms(from, min, max),
+        Interval(IntervalUnit::MonthDayNano) => fixlen::push_months_days_ns(from, min, max),
         UInt8 => primitive::push(from, min, max, |x: i32| Ok(x as u8)),
         UInt16 => primitive::push(from, min, max, |x: i32| Ok(x as u16)),
         UInt32 => match physical_type {
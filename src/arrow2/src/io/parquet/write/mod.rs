@@ -30,7 +30,7 @@ mod utils;
 use crate::array::*;
 use crate::datatypes::*;
 use crate::error::{Error, Result};
-use crate::types::days_ms;
+use crate::types::{days_ms, months_days_ns};
 use crate::types::i256;
 use crate::types::NativeType;
 
@@ -454,6 +454,24 @@ pub fn array_to_page_simple(
             };
             fixed_len_bytes::array_to_page(&array, options, type_, statistics)
         }
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<months_days_ns>>()
+                .unwrap();
+            let mut values = Vec::<u8>::with_capacity(16 * array.len());
+            array.values().iter().for_each(|x| {
+                values.extend_from_slice(&x.to_le_bytes()); // months, days and nanoseconds
+            });
+            let array = FixedSizeBinaryArray::new(
+                DataType::FixedSizeBinary(16),
+                values.into(),
+                array.validity().cloned(),
+            );
+            // the byte-wise ordering of the encoded values is not the ordering of the intervals,
+            // so no min/max statistics are written.
+            fixed_len_bytes::array_to_page(&array, options, type_, None)
+        }
         DataType::FixedSizeBinary(_) => {
             let array = array.as_any().downcast_ref().unwrap();
             let statistics = if options.write_statistics {
@@ -11,7 +11,7 @@ use parquet2::{
 };
 
 use crate::{
-    datatypes::{DataType, Field, Schema, TimeUnit},
+    datatypes::{CanonicalExtension, DataType, Field, IntervalUnit, Schema, TimeUnit},
     error::{Error, Result},
     io::ipc::write::default_ipc_fields,
     io::ipc::write::schema_to_bytes,
@@ -47,6 +47,18 @@ pub fn to_parquet_type(field: &Field) -> Result<ParquetType> {
     } else {
         Repetition::Required
     };
+    // canonical extensions with a native parquet logical type are annotated with it, so
+    // that readers which do not understand the arrow schema still recognize them
+    if let Some(CanonicalExtension::Uuid) = field.data_type().canonical_extension() {
+        return Ok(ParquetType::try_from_primitive(
+            name,
+            PhysicalType::FixedLenByteArray(16),
+            repetition,
+            None,
+            Some(PrimitiveLogicalType::Uuid),
+            None,
+        )?);
+    }
     // create type from field
     match field.data_type().to_logical_type() {
         DataType::Null => Ok(ParquetType::try_from_primitive(
@@ -339,6 +351,16 @@ pub fn to_parquet_type(field: &Field) -> Result<ParquetType> {
                 )?)
             }
         }
+        // Parquet's INTERVAL only holds milliseconds, so MonthDayNano is stored losslessly as
+        // 16 raw bytes and restored from the embedded Arrow schema on read.
+        DataType::Interval(IntervalUnit::MonthDayNano) => Ok(ParquetType::try_from_primitive(
+            name,
+            PhysicalType::FixedLenByteArray(16),
+            repetition,
+            None,
+            None,
+            None,
+        )?),
         DataType::Interval(_) => Ok(ParquetType::try_from_primitive(
             name,
             PhysicalType::FixedLenByteArray(12),
@@ -520,29 +520,7 @@ pub fn add_naive_interval(timestamp: i64, time_unit: TimeUnit, interval: months_
 /// Subtracts an `interval` from a `timestamp` in `time_unit` units without timezone.
 #[inline]
 pub fn sub_naive_interval(timestamp: i64, time_unit: TimeUnit, interval: months_days_ns) -> i64 {
-    // convert seconds to a DateTime of a given offset.
-    let datetime = match time_unit {
-        TimeUnit::Second => timestamp_s_to_datetime(timestamp),
-        TimeUnit::Millisecond => timestamp_ms_to_datetime(timestamp),
-        TimeUnit::Microsecond => timestamp_us_to_datetime(timestamp),
-        TimeUnit::Nanosecond => timestamp_ns_to_datetime(timestamp),
-    };
-
-    // compute the number of days in the interval, which depends on the particular year and month (leap days)
-    let delta_days = get_days_between_months(datetime.year(), datetime.month(), interval.months())
-        - interval.days() as i64;
-
-    // subtract; no leap hours are considered
-    let new_datetime_tz = datetime
-        - chrono::Duration::nanoseconds(delta_days * 24 * 60 * 60 * 1_000_000_000 + interval.ns());
-
-    // convert back to the target unit
-    match time_unit {
-        TimeUnit::Second => new_datetime_tz.and_utc().timestamp_millis() / 1000,
-        TimeUnit::Millisecond => new_datetime_tz.and_utc().timestamp_millis(),
-        TimeUnit::Microsecond => new_datetime_tz.and_utc().timestamp_nanos_opt().unwrap() / 1000,
-        TimeUnit::Nanosecond => new_datetime_tz.and_utc().timestamp_nanos_opt().unwrap(),
-    }
+    add_naive_interval(timestamp, time_unit, -interval)
 }
 
 /// Adds an `interval` to a `timestamp` in `time_unit` units and timezone `timezone`.
@@ -582,23 +560,5 @@ pub fn sub_interval<T: chrono::TimeZone>(
     interval: months_days_ns,
     timezone: &T,
 ) -> i64 {
-    // convert seconds to a DateTime of a given offset.
-    let datetime_tz = timestamp_to_datetime(timestamp, time_unit, timezone);
-
-    // compute the number of days in the interval, which depends on the particular year and month (leap days)
-    let delta_days =
-        get_days_between_months(datetime_tz.year(), datetime_tz.month(), interval.months())
-            - interval.days() as i64;
-
-    // subtract; tz will take care of leap hours
-    let new_datetime_tz = datetime_tz
-        - chrono::Duration::nanoseconds(delta_days * 24 * 60 * 60 * 1_000_000_000 + interval.ns());
-
-    // convert back to the target unit
-    match time_unit {
-        TimeUnit::Second => new_datetime_tz.timestamp_millis() / 1000,
-        TimeUnit::Millisecond => new_datetime_tz.timestamp_millis(),
-        TimeUnit::Microsecond => new_datetime_tz.timestamp_nanos_opt().unwrap() / 1000,
-        TimeUnit::Nanosecond => new_datetime_tz.timestamp_nanos_opt().unwrap(),
-    }
+    add_interval(timestamp, time_unit, -interval, timezone)
 }
@@ -221,6 +221,33 @@ impl months_days_ns {
     pub fn ns(&self) -> i64 {
         self.2
     }
+
+    /// The approximate length of this interval in nanoseconds, counting a month as 30 days
+    /// and a day as 24 hours.
+    #[inline]
+    pub fn normalized_ns(&self) -> i128 {
+        const NANOS_PER_DAY: i128 = 24 * 60 * 60 * 1_000_000_000;
+        (self.0 as i128 * 30 + self.1 as i128) * NANOS_PER_DAY + self.2 as i128
+    }
+}
+
+/// Intervals are ordered by their [`months_days_ns::normalized_ns`] length, ties being broken by
+/// comparing months, days and nanoseconds in turn. This is a total order that is consistent with
+/// [`PartialEq`], i.e. `1 month` sorts right next to, but is not equal to, `30 days`.
+impl Ord for months_days_ns {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.normalized_ns()
+            .cmp(&other.normalized_ns())
+            .then_with(|| (self.0, self.1, self.2).cmp(&(other.0, other.1, other.2)))
+    }
+}
+
+impl PartialOrd for months_days_ns {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 impl From<(i32, i32, i64)> for months_days_ns {
@@ -661,6 +688,20 @@ impl NativeType for i256 {
 #[cfg(test)]
 mod test {
     use super::*;
+
+    #[test]
+    fn test_months_days_ns_ordering() {
+        let month = months_days_ns::new(1, 0, 0);
+        let thirty_days = months_days_ns::new(0, 30, 0);
+        let day = months_days_ns::new(0, 1, 0);
+        let day_in_nanos = months_days_ns::new(0, 0, 24 * 60 * 60 * 1_000_000_000);
+
+        assert!(day < month);
+        assert!(day_in_nanos < day);
+        assert!(thirty_days < month);
+        assert_ne!(thirty_days, month);
+        assert!(months_days_ns::new(0, -1, 0) < months_days_ns::new(0, 0, 0));
+    }
     #[test]
     fn test_f16_to_f32() {
         let f = f16::from_f32(7.0);
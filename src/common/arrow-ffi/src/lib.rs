@@ -1,10 +1,16 @@
+#[cfg(feature = "python")]
+use std::ffi::CStr;
 use std::io::Cursor;
 
 use arrow2::{array::Array, datatypes::Field, ffi};
 #[cfg(feature = "python")]
 use pyo3::ffi::Py_uintptr_t;
 #[cfg(feature = "python")]
-use pyo3::prelude::*;
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyCapsule, PyCapsuleMethods},
+};
 
 pub type ArrayRef = Box<dyn Array>;
 
@@ -94,6 +100,94 @@ pub fn dtype_to_py<'py>(
     field.getattr(pyo3::intern!(py, "type"))
 }
 
+// The names of the PyCapsules of the Arrow PyCapsule interface, see
+// https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html
+// A capsule owns the struct it holds: the struct is released when the capsule is destroyed, unless
+// a consumer moved it out of the capsule and left a released struct behind.
+#[cfg(feature = "python")]
+const SCHEMA_CAPSULE_NAME: &CStr = c"arrow_schema";
+#[cfg(feature = "python")]
+const ARRAY_CAPSULE_NAME: &CStr = c"arrow_array";
+#[cfg(feature = "python")]
+const STREAM_CAPSULE_NAME: &CStr = c"arrow_array_stream";
+
+#[cfg(feature = "python")]
+fn capsule_pointer<T>(capsule: &Bound<PyCapsule>, name: &CStr) -> PyResult<*mut T> {
+    if capsule.name()? != Some(name) {
+        return Err(PyValueError::new_err(format!(
+            "Expected a PyCapsule named {name:?}, but found one named {:?}",
+            capsule.name()?
+        )));
+    }
+    Ok(capsule.pointer().cast())
+}
+
+/// Exports `field` as an `arrow_schema` PyCapsule.
+#[cfg(feature = "python")]
+pub fn field_to_schema_capsule<'py>(
+    py: Python<'py>,
+    field: &Field,
+) -> PyResult<Bound<'py, PyCapsule>> {
+    let schema = ffi::export_field_to_c(field);
+    PyCapsule::new(py, schema, Some(SCHEMA_CAPSULE_NAME.to_owned()))
+}
+
+/// Exports `array` as a pair of `arrow_schema` and `arrow_array` PyCapsules, with `field`
+/// describing the array.
+#[cfg(feature = "python")]
+pub fn array_to_capsules<'py>(
+    py: Python<'py>,
+    field: &Field,
+    array: ArrayRef,
+) -> PyResult<(Bound<'py, PyCapsule>, Bound<'py, PyCapsule>)> {
+    let schema = field_to_schema_capsule(py, field)?;
+    let array = ffi::export_array_to_c(fix_child_array_slice_offsets(array));
+    let array = PyCapsule::new(py, array, Some(ARRAY_CAPSULE_NAME.to_owned()))?;
+    Ok((schema, array))
+}
+
+/// Exports `stream` as an `arrow_array_stream` PyCapsule.
+#[cfg(feature = "python")]
+pub fn stream_to_capsule(py: Python, stream: ffi::ArrowArrayStream) -> PyResult<Bound<PyCapsule>> {
+    PyCapsule::new(py, stream, Some(STREAM_CAPSULE_NAME.to_owned()))
+}
+
+/// Imports the [`Field`] of an `arrow_schema` PyCapsule, which keeps ownership of its schema.
+#[cfg(feature = "python")]
+pub fn field_from_schema_capsule(capsule: &Bound<PyCapsule>) -> PyResult<Field> {
+    let schema = capsule_pointer::<ffi::ArrowSchema>(capsule, SCHEMA_CAPSULE_NAME)?;
+    // Safety: a capsule with this name holds a struct of the C data interface
+    unsafe { ffi::import_field_from_c(&*schema) }.map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// Imports a pair of `arrow_schema` and `arrow_array` PyCapsules, moving the array out of its
+/// capsule.
+#[cfg(feature = "python")]
+pub fn array_from_capsules(
+    schema: &Bound<PyCapsule>,
+    array: &Bound<PyCapsule>,
+) -> PyResult<(Field, ArrayRef)> {
+    let field = field_from_schema_capsule(schema)?;
+    let array = capsule_pointer::<ffi::ArrowArray>(array, ARRAY_CAPSULE_NAME)?;
+    // Safety: a capsule with this name holds a struct of the C data interface. Moving it out
+    // leaves a released struct behind, so that the capsule's destructor does not release it again.
+    let array = unsafe {
+        let array = std::ptr::replace(array, ffi::ArrowArray::empty());
+        ffi::import_array_from_c(array, field.data_type.clone())
+    }
+    .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    Ok((field, array))
+}
+
+/// Imports an `arrow_array_stream` PyCapsule, moving the stream out of the capsule.
+#[cfg(feature = "python")]
+pub fn stream_from_capsule(capsule: &Bound<PyCapsule>) -> PyResult<Box<ffi::ArrowArrayStream>> {
+    let stream = capsule_pointer::<ffi::ArrowArrayStream>(capsule, STREAM_CAPSULE_NAME)?;
+    // Safety: as in `array_from_capsules`
+    let stream = unsafe { std::ptr::replace(stream, ffi::ArrowArrayStream::empty()) };
+    Ok(Box::new(stream))
+}
+
 fn fix_child_array_slice_offsets(array: ArrayRef) -> ArrayRef {
     /* Zero-copy slices of arrow2 struct/fixed-size list arrays are currently not correctly
     converted to pyarrow struct/fixed-size list arrays when going over the FFI boundary;
@@ -11,6 +11,8 @@ use serde::{Deserialize, Serialize};
 pub struct DaftPlanningConfig {
     pub default_io_config: IOConfig,
     pub disable_join_reordering: bool,
+    pub enable_cost_based_planning: bool,
+    pub enable_plan_cache: bool,
 }
 
 impl DaftPlanningConfig {
@@ -23,6 +25,18 @@ impl DaftPlanningConfig {
         {
             cfg.disable_join_reordering = true;
         }
+        let enable_cost_based_planning_var_name = "DAFT_ENABLE_COST_BASED_PLANNING";
+        if let Ok(val) = std::env::var(enable_cost_based_planning_var_name)
+            && matches!(val.trim().to_lowercase().as_str(), "1" | "true")
+        {
+            cfg.enable_cost_based_planning = true;
+        }
+        let enable_plan_cache_var_name = "DAFT_ENABLE_PLAN_CACHE";
+        if let Ok(val) = std::env::var(enable_plan_cache_var_name)
+            && matches!(val.trim().to_lowercase().as_str(), "1" | "true")
+        {
+            cfg.enable_plan_cache = true;
+        }
         cfg
     }
 }
@@ -43,7 +57,9 @@ pub struct DaftExecutionConfig {
     pub max_sources_per_scan_task: usize,
     pub broadcast_join_size_bytes_threshold: usize,
     pub sort_merge_join_sort_with_aligned_boundaries: bool,
+    pub sort_merge_join_size_bytes_threshold: usize,
     pub hash_join_partition_size_leniency: f64,
+    pub dynamic_partition_pruning_max_keys: usize,
     pub sample_size_for_sort: usize,
     pub parquet_split_row_groups_max_files: usize,
     pub num_preview_rows: usize,
@@ -57,6 +73,7 @@ pub struct DaftExecutionConfig {
     pub high_cardinality_aggregation_threshold: f64,
     pub read_sql_partition_size_bytes: usize,
     pub enable_aqe: bool,
+    pub aqe_target_partition_size_bytes: usize,
     pub enable_native_executor: bool,
     pub default_morsel_size: usize,
     pub shuffle_algorithm: String,
@@ -64,6 +81,29 @@ pub struct DaftExecutionConfig {
     pub flight_shuffle_dirs: Vec<String>,
     pub enable_ray_tracing: bool,
     pub scantask_splitting_level: i32,
+    pub buffer_allocator: String,
+    pub buffer_pool_max_bytes: usize,
+    pub persist_memory_limit_bytes: usize,
+    pub operator_memory_limit_bytes: usize,
+    pub query_memory_limit_bytes: usize,
+    pub compute_threads: usize,
+    pub io_threads: usize,
+    pub thread_affinity: String,
+    pub shuffle_compression: String,
+    pub shuffle_compression_level: i32,
+    pub external_shuffle_dir: String,
+    pub enable_speculative_execution: bool,
+    pub speculation_quantile: f64,
+    pub speculation_multiplier: f64,
+    pub speculation_min_runtime_ms: usize,
+    pub checkpoint_dir: String,
+    pub enable_opentelemetry: bool,
+    pub upload_part_size: usize,
+    pub max_inflight_upload_parts: usize,
+    pub task_max_attempts: usize,
+    pub task_retry_initial_backoff_ms: usize,
+    pub task_retry_max_backoff_ms: usize,
+    pub deterministic: bool,
 }
 
 impl Default for DaftExecutionConfig {
@@ -74,7 +114,9 @@ impl Default for DaftExecutionConfig {
             max_sources_per_scan_task: 10,
             broadcast_join_size_bytes_threshold: 10 * 1024 * 1024, // 10 MiB
             sort_merge_join_sort_with_aligned_boundaries: false,
+            sort_merge_join_size_bytes_threshold: 1024 * 1024 * 1024, // 1 GiB
             hash_join_partition_size_leniency: 0.5,
+            dynamic_partition_pruning_max_keys: 10_000,
             sample_size_for_sort: 20,
             parquet_split_row_groups_max_files: 10,
             num_preview_rows: 8,
@@ -88,6 +130,7 @@ impl Default for DaftExecutionConfig {
             high_cardinality_aggregation_threshold: 0.8,
             read_sql_partition_size_bytes: 512 * 1024 * 1024, // 512MB
             enable_aqe: false,
+            aqe_target_partition_size_bytes: 128 * 1024 * 1024, // 128MB
             enable_native_executor: false,
             default_morsel_size: 128 * 1024,
             shuffle_algorithm: "auto".to_string(),
@@ -95,6 +138,29 @@ impl Default for DaftExecutionConfig {
             flight_shuffle_dirs: vec!["/tmp".to_string()],
             enable_ray_tracing: false,
             scantask_splitting_level: 1,
+            buffer_allocator: "heap".to_string(),
+            buffer_pool_max_bytes: 1024 * 1024 * 1024, // 1GB
+            persist_memory_limit_bytes: 4 * 1024 * 1024 * 1024, // 4GB
+            operator_memory_limit_bytes: 4 * 1024 * 1024 * 1024, // 4GB
+            query_memory_limit_bytes: 0,
+            compute_threads: 0,
+            io_threads: 0,
+            thread_affinity: "none".to_string(),
+            shuffle_compression: "none".to_string(),
+            shuffle_compression_level: 0,
+            external_shuffle_dir: String::new(),
+            enable_speculative_execution: false,
+            speculation_quantile: 0.75,
+            speculation_multiplier: 1.5,
+            speculation_min_runtime_ms: 10_000, // 10s
+            checkpoint_dir: String::new(),
+            enable_opentelemetry: false,
+            upload_part_size: 8 * 1024 * 1024, // 8MB
+            max_inflight_upload_parts: 4,
+            task_max_attempts: 3,
+            task_retry_initial_backoff_ms: 1_000, // 1s
+            task_retry_max_backoff_ms: 30_000,    // 30s
+            deterministic: false,
         }
     }
 }
@@ -136,6 +202,16 @@ impl DaftExecutionConfig {
         if let Ok(val) = std::env::var(enable_aggressive_scantask_splitting_env_var_name) {
             cfg.scantask_splitting_level = val.parse::<i32>().unwrap_or(0);
         }
+        let buffer_allocator_env_var_name = "DAFT_BUFFER_ALLOCATOR";
+        if let Ok(val) = std::env::var(buffer_allocator_env_var_name) {
+            cfg.buffer_allocator = val.trim().to_lowercase();
+        }
+        let deterministic_env_var_name = "DAFT_DETERMINISTIC";
+        if let Ok(val) = std::env::var(deterministic_env_var_name)
+            && matches!(val.trim().to_lowercase().as_str(), "1" | "true")
+        {
+            cfg.deterministic = true;
+        }
         cfg
     }
 }
@@ -29,14 +29,27 @@ impl PyDaftPlanningConfig {
         }
     }
 
-    #[pyo3(signature = (default_io_config=None))]
-    fn with_config_values(&mut self, default_io_config: Option<PyIOConfig>) -> PyResult<Self> {
+    #[pyo3(signature = (default_io_config=None, enable_cost_based_planning=None, enable_plan_cache=None))]
+    fn with_config_values(
+        &mut self,
+        default_io_config: Option<PyIOConfig>,
+        enable_cost_based_planning: Option<bool>,
+        enable_plan_cache: Option<bool>,
+    ) -> PyResult<Self> {
         let mut config = self.config.as_ref().clone();
 
         if let Some(default_io_config) = default_io_config {
             config.default_io_config = default_io_config.config;
         }
 
+        if let Some(enable_cost_based_planning) = enable_cost_based_planning {
+            config.enable_cost_based_planning = enable_cost_based_planning;
+        }
+
+        if let Some(enable_plan_cache) = enable_plan_cache {
+            config.enable_plan_cache = enable_plan_cache;
+        }
+
         Ok(Self {
             config: Arc::new(config),
         })
@@ -48,6 +61,16 @@ impl PyDaftPlanningConfig {
             config: self.config.default_io_config.clone(),
         })
     }
+
+    #[getter(enable_cost_based_planning)]
+    fn enable_cost_based_planning(&self) -> PyResult<bool> {
+        Ok(self.config.enable_cost_based_planning)
+    }
+
+    #[getter(enable_plan_cache)]
+    fn enable_plan_cache(&self) -> PyResult<bool> {
+        Ok(self.config.enable_plan_cache)
+    }
 }
 
 impl_bincode_py_state_serialization!(PyDaftPlanningConfig);
@@ -82,7 +105,9 @@ impl PyDaftExecutionConfig {
         broadcast_join_size_bytes_threshold=None,
         parquet_split_row_groups_max_files=None,
         sort_merge_join_sort_with_aligned_boundaries=None,
+        sort_merge_join_size_bytes_threshold=None,
         hash_join_partition_size_leniency=None,
+        dynamic_partition_pruning_max_keys=None,
         sample_size_for_sort=None,
         num_preview_rows=None,
         parquet_target_filesize=None,
@@ -95,13 +120,37 @@ impl PyDaftExecutionConfig {
         high_cardinality_aggregation_threshold=None,
         read_sql_partition_size_bytes=None,
         enable_aqe=None,
+        aqe_target_partition_size_bytes=None,
         enable_native_executor=None,
         default_morsel_size=None,
         shuffle_algorithm=None,
         pre_shuffle_merge_threshold=None,
         flight_shuffle_dirs=None,
         enable_ray_tracing=None,
-        scantask_splitting_level=None
+        scantask_splitting_level=None,
+        buffer_allocator=None,
+        buffer_pool_max_bytes=None,
+        persist_memory_limit_bytes=None,
+        operator_memory_limit_bytes=None,
+        query_memory_limit_bytes=None,
+        compute_threads=None,
+        io_threads=None,
+        thread_affinity=None,
+        shuffle_compression=None,
+        shuffle_compression_level=None,
+        external_shuffle_dir=None,
+        enable_speculative_execution=None,
+        speculation_quantile=None,
+        speculation_multiplier=None,
+        speculation_min_runtime_ms=None,
+        checkpoint_dir=None,
+        enable_opentelemetry=None,
+        upload_part_size=None,
+        max_inflight_upload_parts=None,
+        task_max_attempts=None,
+        task_retry_initial_backoff_ms=None,
+        task_retry_max_backoff_ms=None,
+        deterministic=None
     ))]
     fn with_config_values(
         &self,
@@ -111,7 +160,9 @@ impl PyDaftExecutionConfig {
         broadcast_join_size_bytes_threshold: Option<usize>,
         parquet_split_row_groups_max_files: Option<usize>,
         sort_merge_join_sort_with_aligned_boundaries: Option<bool>,
+        sort_merge_join_size_bytes_threshold: Option<usize>,
         hash_join_partition_size_leniency: Option<f64>,
+        dynamic_partition_pruning_max_keys: Option<usize>,
         sample_size_for_sort: Option<usize>,
         num_preview_rows: Option<usize>,
         parquet_target_filesize: Option<usize>,
@@ -124,6 +175,7 @@ impl PyDaftExecutionConfig {
         high_cardinality_aggregation_threshold: Option<f64>,
         read_sql_partition_size_bytes: Option<usize>,
         enable_aqe: Option<bool>,
+        aqe_target_partition_size_bytes: Option<usize>,
         enable_native_executor: Option<bool>,
         default_morsel_size: Option<usize>,
         shuffle_algorithm: Option<&str>,
@@ -131,6 +183,29 @@ impl PyDaftExecutionConfig {
         flight_shuffle_dirs: Option<Vec<String>>,
         enable_ray_tracing: Option<bool>,
         scantask_splitting_level: Option<i32>,
+        buffer_allocator: Option<&str>,
+        buffer_pool_max_bytes: Option<usize>,
+        persist_memory_limit_bytes: Option<usize>,
+        operator_memory_limit_bytes: Option<usize>,
+        query_memory_limit_bytes: Option<usize>,
+        compute_threads: Option<usize>,
+        io_threads: Option<usize>,
+        thread_affinity: Option<&str>,
+        shuffle_compression: Option<&str>,
+        shuffle_compression_level: Option<i32>,
+        external_shuffle_dir: Option<String>,
+        enable_speculative_execution: Option<bool>,
+        speculation_quantile: Option<f64>,
+        speculation_multiplier: Option<f64>,
+        speculation_min_runtime_ms: Option<usize>,
+        checkpoint_dir: Option<String>,
+        enable_opentelemetry: Option<bool>,
+        upload_part_size: Option<usize>,
+        max_inflight_upload_parts: Option<usize>,
+        task_max_attempts: Option<usize>,
+        task_retry_initial_backoff_ms: Option<usize>,
+        task_retry_max_backoff_ms: Option<usize>,
+        deterministic: Option<bool>,
     ) -> PyResult<Self> {
         let mut config = self.config.as_ref().clone();
 
@@ -155,9 +230,15 @@ impl PyDaftExecutionConfig {
             config.sort_merge_join_sort_with_aligned_boundaries =
                 sort_merge_join_sort_with_aligned_boundaries;
         }
+        if let Some(sort_merge_join_size_bytes_threshold) = sort_merge_join_size_bytes_threshold {
+            config.sort_merge_join_size_bytes_threshold = sort_merge_join_size_bytes_threshold;
+        }
         if let Some(hash_join_partition_size_leniency) = hash_join_partition_size_leniency {
             config.hash_join_partition_size_leniency = hash_join_partition_size_leniency;
         }
+        if let Some(dynamic_partition_pruning_max_keys) = dynamic_partition_pruning_max_keys {
+            config.dynamic_partition_pruning_max_keys = dynamic_partition_pruning_max_keys;
+        }
         if let Some(sample_size_for_sort) = sample_size_for_sort {
             config.sample_size_for_sort = sample_size_for_sort;
         }
@@ -197,6 +278,9 @@ impl PyDaftExecutionConfig {
         if let Some(enable_aqe) = enable_aqe {
             config.enable_aqe = enable_aqe;
         }
+        if let Some(aqe_target_partition_size_bytes) = aqe_target_partition_size_bytes {
+            config.aqe_target_partition_size_bytes = aqe_target_partition_size_bytes;
+        }
         if let Some(enable_native_executor) = enable_native_executor {
             config.enable_native_executor = enable_native_executor;
         }
@@ -206,10 +290,10 @@ impl PyDaftExecutionConfig {
         if let Some(shuffle_algorithm) = shuffle_algorithm {
             if !matches!(
                 shuffle_algorithm,
-                "map_reduce" | "pre_shuffle_merge" | "flight_shuffle" | "auto"
+                "map_reduce" | "pre_shuffle_merge" | "flight_shuffle" | "external_shuffle" | "auto"
             ) {
                 return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                    "shuffle_algorithm must be 'auto', 'map_reduce', 'pre_shuffle_merge', or 'flight_shuffle'",
+                    "shuffle_algorithm must be 'auto', 'map_reduce', 'pre_shuffle_merge', 'flight_shuffle', or 'external_shuffle'",
                 ));
             }
             config.shuffle_algorithm = shuffle_algorithm.to_string();
@@ -234,6 +318,117 @@ impl PyDaftExecutionConfig {
             config.scantask_splitting_level = scantask_splitting_level;
         }
 
+        if let Some(buffer_allocator) = buffer_allocator {
+            if !matches!(buffer_allocator, "heap" | "pooled" | "huge_pages") {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "buffer_allocator must be 'heap', 'pooled', or 'huge_pages'",
+                ));
+            }
+            config.buffer_allocator = buffer_allocator.to_string();
+        }
+        if let Some(buffer_pool_max_bytes) = buffer_pool_max_bytes {
+            config.buffer_pool_max_bytes = buffer_pool_max_bytes;
+        }
+        if let Some(persist_memory_limit_bytes) = persist_memory_limit_bytes {
+            config.persist_memory_limit_bytes = persist_memory_limit_bytes;
+        }
+        if let Some(operator_memory_limit_bytes) = operator_memory_limit_bytes {
+            config.operator_memory_limit_bytes = operator_memory_limit_bytes;
+        }
+        if let Some(query_memory_limit_bytes) = query_memory_limit_bytes {
+            config.query_memory_limit_bytes = query_memory_limit_bytes;
+        }
+        if let Some(compute_threads) = compute_threads {
+            config.compute_threads = compute_threads;
+        }
+        if let Some(io_threads) = io_threads {
+            config.io_threads = io_threads;
+        }
+        if let Some(thread_affinity) = thread_affinity {
+            if !matches!(thread_affinity, "none" | "cores" | "numa") {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "thread_affinity must be 'none', 'cores', or 'numa'",
+                ));
+            }
+            config.thread_affinity = thread_affinity.to_string();
+        }
+        if let Some(shuffle_compression) = shuffle_compression {
+            if !matches!(shuffle_compression, "none" | "lz4" | "zstd") {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "shuffle_compression must be 'none', 'lz4', or 'zstd'",
+                ));
+            }
+            config.shuffle_compression = shuffle_compression.to_string();
+        }
+        if let Some(shuffle_compression_level) = shuffle_compression_level {
+            config.shuffle_compression_level = shuffle_compression_level;
+        }
+        if let Some(external_shuffle_dir) = external_shuffle_dir {
+            config.external_shuffle_dir = external_shuffle_dir;
+        }
+        if let Some(enable_speculative_execution) = enable_speculative_execution {
+            config.enable_speculative_execution = enable_speculative_execution;
+        }
+        if let Some(speculation_quantile) = speculation_quantile {
+            if !(0.0..=1.0).contains(&speculation_quantile) {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "speculation_quantile must be between 0.0 and 1.0",
+                ));
+            }
+            config.speculation_quantile = speculation_quantile;
+        }
+        if let Some(speculation_multiplier) = speculation_multiplier {
+            if speculation_multiplier < 1.0 {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "speculation_multiplier must be at least 1.0",
+                ));
+            }
+            config.speculation_multiplier = speculation_multiplier;
+        }
+        if let Some(speculation_min_runtime_ms) = speculation_min_runtime_ms {
+            config.speculation_min_runtime_ms = speculation_min_runtime_ms;
+        }
+        if let Some(checkpoint_dir) = checkpoint_dir {
+            config.checkpoint_dir = checkpoint_dir;
+        }
+        if let Some(enable_opentelemetry) = enable_opentelemetry {
+            config.enable_opentelemetry = enable_opentelemetry;
+        }
+        if let Some(upload_part_size) = upload_part_size {
+            // The minimum part size of S3 multipart uploads
+            if upload_part_size < 5 * 1024 * 1024 {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "upload_part_size must be at least 5MiB",
+                ));
+            }
+            config.upload_part_size = upload_part_size;
+        }
+        if let Some(max_inflight_upload_parts) = max_inflight_upload_parts {
+            if max_inflight_upload_parts == 0 {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "max_inflight_upload_parts must be at least 1",
+                ));
+            }
+            config.max_inflight_upload_parts = max_inflight_upload_parts;
+        }
+        if let Some(task_max_attempts) = task_max_attempts {
+            if task_max_attempts == 0 {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "task_max_attempts must be at least 1",
+                ));
+            }
+            config.task_max_attempts = task_max_attempts;
+        }
+        if let Some(task_retry_initial_backoff_ms) = task_retry_initial_backoff_ms {
+            config.task_retry_initial_backoff_ms = task_retry_initial_backoff_ms;
+        }
+        if let Some(task_retry_max_backoff_ms) = task_retry_max_backoff_ms {
+            config.task_retry_max_backoff_ms = task_retry_max_backoff_ms;
+        }
+        if let Some(deterministic) = deterministic {
+            config.deterministic = deterministic;
+        }
+
         Ok(Self {
             config: Arc::new(config),
         })
@@ -264,11 +459,21 @@ impl PyDaftExecutionConfig {
         Ok(self.config.sort_merge_join_sort_with_aligned_boundaries)
     }
 
+    #[getter]
+    fn get_sort_merge_join_size_bytes_threshold(&self) -> PyResult<usize> {
+        Ok(self.config.sort_merge_join_size_bytes_threshold)
+    }
+
     #[getter]
     fn get_hash_join_partition_size_leniency(&self) -> PyResult<f64> {
         Ok(self.config.hash_join_partition_size_leniency)
     }
 
+    #[getter]
+    fn get_dynamic_partition_pruning_max_keys(&self) -> PyResult<usize> {
+        Ok(self.config.dynamic_partition_pruning_max_keys)
+    }
+
     #[getter]
     fn get_sample_size_for_sort(&self) -> PyResult<usize> {
         Ok(self.config.sample_size_for_sort)
@@ -328,6 +533,10 @@ impl PyDaftExecutionConfig {
         Ok(self.config.enable_aqe)
     }
     #[getter]
+    fn aqe_target_partition_size_bytes(&self) -> PyResult<usize> {
+        Ok(self.config.aqe_target_partition_size_bytes)
+    }
+    #[getter]
     fn enable_native_executor(&self) -> PyResult<bool> {
         Ok(self.config.enable_native_executor)
     }
@@ -353,6 +562,121 @@ impl PyDaftExecutionConfig {
     fn scantask_splitting_level(&self) -> PyResult<i32> {
         Ok(self.config.scantask_splitting_level)
     }
+
+    #[getter]
+    fn buffer_allocator(&self) -> PyResult<&str> {
+        Ok(self.config.buffer_allocator.as_str())
+    }
+
+    #[getter]
+    fn buffer_pool_max_bytes(&self) -> PyResult<usize> {
+        Ok(self.config.buffer_pool_max_bytes)
+    }
+
+    #[getter]
+    fn persist_memory_limit_bytes(&self) -> PyResult<usize> {
+        Ok(self.config.persist_memory_limit_bytes)
+    }
+
+    #[getter]
+    fn operator_memory_limit_bytes(&self) -> PyResult<usize> {
+        Ok(self.config.operator_memory_limit_bytes)
+    }
+
+    #[getter]
+    fn query_memory_limit_bytes(&self) -> PyResult<usize> {
+        Ok(self.config.query_memory_limit_bytes)
+    }
+
+    #[getter]
+    fn compute_threads(&self) -> PyResult<usize> {
+        Ok(self.config.compute_threads)
+    }
+
+    #[getter]
+    fn io_threads(&self) -> PyResult<usize> {
+        Ok(self.config.io_threads)
+    }
+
+    #[getter]
+    fn thread_affinity(&self) -> PyResult<&str> {
+        Ok(self.config.thread_affinity.as_str())
+    }
+
+    #[getter]
+    fn shuffle_compression(&self) -> PyResult<&str> {
+        Ok(self.config.shuffle_compression.as_str())
+    }
+
+    #[getter]
+    fn shuffle_compression_level(&self) -> PyResult<i32> {
+        Ok(self.config.shuffle_compression_level)
+    }
+
+    #[getter]
+    fn external_shuffle_dir(&self) -> PyResult<&str> {
+        Ok(self.config.external_shuffle_dir.as_str())
+    }
+
+    #[getter]
+    fn enable_speculative_execution(&self) -> PyResult<bool> {
+        Ok(self.config.enable_speculative_execution)
+    }
+
+    #[getter]
+    fn speculation_quantile(&self) -> PyResult<f64> {
+        Ok(self.config.speculation_quantile)
+    }
+
+    #[getter]
+    fn speculation_multiplier(&self) -> PyResult<f64> {
+        Ok(self.config.speculation_multiplier)
+    }
+
+    #[getter]
+    fn speculation_min_runtime_ms(&self) -> PyResult<usize> {
+        Ok(self.config.speculation_min_runtime_ms)
+    }
+
+    #[getter]
+    fn checkpoint_dir(&self) -> PyResult<&str> {
+        Ok(self.config.checkpoint_dir.as_str())
+    }
+
+    #[getter]
+    fn enable_opentelemetry(&self) -> PyResult<bool> {
+        Ok(self.config.enable_opentelemetry)
+    }
+
+    #[getter]
+    fn upload_part_size(&self) -> PyResult<usize> {
+        Ok(self.config.upload_part_size)
+    }
+
+    #[getter]
+    fn max_inflight_upload_parts(&self) -> PyResult<usize> {
+        Ok(self.config.max_inflight_upload_parts)
+    }
+
+    #[getter]
+    fn task_max_attempts(&self) -> PyResult<usize> {
+        Ok(self.config.task_max_attempts)
+    }
+
+    #[getter]
+    fn task_retry_initial_backoff_ms(&self) -> PyResult<usize> {
+        Ok(self.config.task_retry_initial_backoff_ms)
+    }
+
+    #[getter]
+    fn task_retry_max_backoff_ms(&self) -> PyResult<usize> {
+        Ok(self.config.task_retry_max_backoff_ms)
+    }
+
+    #[getter]
+    fn deterministic(&self) -> PyResult<bool> {
+        Ok(self.config.deterministic)
+    }
 }
 
 impl_bincode_py_state_serialization!(PyDaftExecutionConfig);
@@ -57,9 +57,23 @@ pub fn make_comfy_table<S: AsRef<str>>(
     num_rows: Option<usize>,
     max_col_width: Option<usize>,
 ) -> comfy_table::Table {
-    const DOTS: &str = "…";
     const TOTAL_ROWS: usize = 10;
 
+    make_comfy_table_with_limits(fields, columns, num_rows, max_col_width, TOTAL_ROWS, None)
+}
+
+/// Makes a table that shows up to `max_rows` rows and `max_cols` columns, half from the start and half from the end
+/// when there are more, or as many columns as fit the terminal when `max_cols` is `None`.
+pub fn make_comfy_table_with_limits<S: AsRef<str>>(
+    fields: &[S],
+    columns: Option<&[&dyn StrValue]>,
+    num_rows: Option<usize>,
+    max_col_width: Option<usize>,
+    max_rows: usize,
+    max_cols: Option<usize>,
+) -> comfy_table::Table {
+    const DOTS: &str = "…";
+
     let mut table = comfy_table::Table::new();
 
     let default_width_if_no_tty = 120usize;
@@ -77,7 +91,9 @@ pub fn make_comfy_table<S: AsRef<str>>(
 
     let expected_col_width = 18usize;
 
-    let max_cols = (terminal_width.div_ceil(expected_col_width) - 1).max(1);
+    let max_cols = max_cols
+        .unwrap_or_else(|| terminal_width.div_ceil(expected_col_width) - 1)
+        .max(1);
     let num_columns = fields.len();
 
     let (head_cols, tail_cols, total_cols) = if num_columns > max_cols {
@@ -93,8 +109,8 @@ pub fn make_comfy_table<S: AsRef<str>>(
         .take(head_cols)
         .map(|field| create_table_cell(field.as_ref()))
         .collect::<Vec<_>>();
-    if tail_cols > 0 {
-        let unseen_cols = num_columns - (head_cols + tail_cols);
+    let unseen_cols = num_columns - (head_cols + tail_cols);
+    if unseen_cols > 0 {
         header.push(
             create_table_cell(&format!("{DOTS}\n\n({unseen_cols} hidden)"))
                 .set_alignment(comfy_table::CellAlignment::Center),
@@ -112,8 +128,9 @@ pub fn make_comfy_table<S: AsRef<str>>(
     {
         table.set_header(header);
         let len = num_rows.expect("if columns are set, so should `num_rows`");
-        let (head_rows, tail_rows) = if len > TOTAL_ROWS {
-            (TOTAL_ROWS / 2, TOTAL_ROWS / 2)
+        let max_rows = max_rows.max(1);
+        let (head_rows, tail_rows) = if len > max_rows {
+            (max_rows.div_ceil(2), max_rows / 2)
         } else {
             (len, 0)
         };
@@ -124,12 +141,12 @@ pub fn make_comfy_table<S: AsRef<str>>(
                 .map(|s| {
                     let mut str_val = s.str_value(i);
                     if let Some(max_col_width) = max_col_width {
-                        if str_val.len() > max_col_width - DOTS.len() {
+                        if str_val.len() > max_col_width.saturating_sub(DOTS.len()) {
                             str_val = format!(
                                 "{}{DOTS}",
                                 &str_val
                                     .char_indices()
-                                    .take(max_col_width - DOTS.len())
+                                    .take(max_col_width.saturating_sub(DOTS.len()))
                                     .map(|(_, c)| c)
                                     .collect::<String>()
                             );
@@ -139,7 +156,7 @@ pub fn make_comfy_table<S: AsRef<str>>(
                 })
                 .collect::<Vec<_>>();
 
-            if tail_cols > 0 {
+            if unseen_cols > 0 {
                 let mut final_row = all_cols.iter().take(head_cols).cloned().collect::<Vec<_>>();
                 final_row.push(DOTS.into());
                 final_row.extend(all_cols.iter().skip(num_columns - tail_cols).cloned());
@@ -158,12 +175,12 @@ pub fn make_comfy_table<S: AsRef<str>>(
                 .map(|s| {
                     let mut str_val = s.str_value(i);
                     if let Some(max_col_width) = max_col_width {
-                        if str_val.len() > max_col_width - DOTS.len() {
+                        if str_val.len() > max_col_width.saturating_sub(DOTS.len()) {
                             str_val = format!(
                                 "{}{DOTS}",
                                 &str_val
                                     .char_indices()
-                                    .take(max_col_width - DOTS.len())
+                                    .take(max_col_width.saturating_sub(DOTS.len()))
                                     .map(|(_, c)| c)
                                     .collect::<String>()
                             );
@@ -173,7 +190,7 @@ pub fn make_comfy_table<S: AsRef<str>>(
                 })
                 .collect::<Vec<_>>();
 
-            if tail_cols > 0 {
+            if unseen_cols > 0 {
                 let mut final_row = all_cols.iter().take(head_cols).cloned().collect::<Vec<_>>();
                 final_row.push(DOTS.into());
                 final_row.extend(all_cols.iter().skip(num_columns - tail_cols).cloned());
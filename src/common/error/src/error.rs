@@ -54,6 +54,8 @@ pub enum DaftError {
     NotImplemented(String),
     #[error("DaftError::CatalogError {0}")]
     CatalogError(String),
+    #[error("DaftError::Cancelled {0}")]
+    Cancelled(String),
 }
 
 impl DaftError {
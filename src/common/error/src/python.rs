@@ -10,6 +10,7 @@ import_exception!(daft.exceptions, ByteStreamError);
 import_exception!(daft.exceptions, SocketError);
 import_exception!(daft.exceptions, ThrottleError);
 import_exception!(daft.exceptions, MiscTransientError);
+import_exception!(daft.exceptions, QueryCancelledError);
 
 impl std::convert::From<DaftError> for pyo3::PyErr {
     fn from(err: DaftError) -> Self {
@@ -25,6 +26,7 @@ impl std::convert::From<DaftError> for pyo3::PyErr {
             DaftError::SocketError(err) => SocketError::new_err(err.to_string()),
             DaftError::ThrottledIo(err) => ThrottleError::new_err(err.to_string()),
             DaftError::MiscTransient(err) => MiscTransientError::new_err(err.to_string()),
+            DaftError::Cancelled(err) => QueryCancelledError::new_err(err),
             _ => DaftCoreException::new_err(err.to_string()),
         }
     }
@@ -2,7 +2,7 @@ use std::fmt::{Display, Formatter};
 
 use serde::{Deserialize, Serialize};
 
-use crate::ObfuscatedString;
+use crate::{BearerTokenProviderWrapper, ObfuscatedString};
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct AzureConfig {
@@ -10,9 +10,11 @@ pub struct AzureConfig {
     pub access_key: Option<ObfuscatedString>,
     pub sas_token: Option<String>,
     pub bearer_token: Option<String>,
+    pub token_provider: Option<BearerTokenProviderWrapper>,
     pub tenant_id: Option<String>,
     pub client_id: Option<String>,
     pub client_secret: Option<ObfuscatedString>,
+    pub use_managed_identity: bool,
     pub use_fabric_endpoint: bool,
     pub anonymous: bool,
     pub endpoint_url: Option<String>,
@@ -26,9 +28,11 @@ impl Default for AzureConfig {
             access_key: None,
             sas_token: None,
             bearer_token: None,
+            token_provider: None,
             tenant_id: None,
             client_id: None,
             client_secret: None,
+            use_managed_identity: false,
             use_fabric_endpoint: false,
             anonymous: false,
             endpoint_url: None,
@@ -53,6 +57,9 @@ impl AzureConfig {
         if let Some(bearer_token) = &self.bearer_token {
             res.push(format!("Bearer Token = {bearer_token}"));
         }
+        if let Some(token_provider) = &self.token_provider {
+            res.push(format!("Token Provider = {token_provider:?}"));
+        }
         if let Some(tenant_id) = &self.tenant_id {
             res.push(format!("Tenant ID = {tenant_id}"));
         }
@@ -62,6 +69,10 @@ impl AzureConfig {
         if let Some(client_secret) = &self.client_secret {
             res.push(format!("Client Secret = {client_secret}"));
         }
+        res.push(format!(
+            "Use Managed Identity = {}",
+            self.use_managed_identity
+        ));
         res.push(format!(
             "Use Fabric Endpoint = {}",
             self.use_fabric_endpoint
@@ -84,9 +95,11 @@ impl Display for AzureConfig {
     access_key: {:?}
     sas_token: {:?}
     bearer_token: {:?}
+    token_provider: {:?}
     tenant_id: {:?}
     client_id: {:?}
     client_secret: {:?}
+    use_managed_identity: {:?}
     use_fabric_endpoint: {:?}
     anonymous: {:?}
     endpoint_url: {:?}
@@ -95,9 +108,11 @@ impl Display for AzureConfig {
             self.access_key,
             self.sas_token,
             self.bearer_token,
+            self.token_provider,
             self.tenant_id,
             self.client_id,
             self.client_secret,
+            self.use_managed_identity,
             self.use_fabric_endpoint,
             self.anonymous,
             self.endpoint_url,
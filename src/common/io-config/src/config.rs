@@ -2,13 +2,21 @@ use std::fmt::{Display, Formatter};
 
 use serde::{Deserialize, Serialize};
 
-use crate::{AzureConfig, GCSConfig, HTTPConfig, S3Config};
+use crate::{
+    AzureConfig, DiskCacheConfig, GCSConfig, HDFSConfig, HTTPConfig, RateLimitConfig, RetryConfig,
+    S3Config, SFTPConfig,
+};
 #[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct IOConfig {
     pub s3: S3Config,
     pub azure: AzureConfig,
     pub gcs: GCSConfig,
     pub http: HTTPConfig,
+    pub hdfs: HDFSConfig,
+    pub sftp: SFTPConfig,
+    pub retry: RetryConfig,
+    pub disk_cache: DiskCacheConfig,
+    pub rate_limit: RateLimitConfig,
 }
 
 impl IOConfig {
@@ -31,6 +39,26 @@ impl IOConfig {
             "HTTP config = {{ {} }}",
             self.http.multiline_display().join(", ")
         ));
+        res.push(format!(
+            "HDFS config = {{ {} }}",
+            self.hdfs.multiline_display().join(", ")
+        ));
+        res.push(format!(
+            "SFTP config = {{ {} }}",
+            self.sftp.multiline_display().join(", ")
+        ));
+        res.push(format!(
+            "Retry config = {{ {} }}",
+            self.retry.multiline_display().join(", ")
+        ));
+        res.push(format!(
+            "Disk cache config = {{ {} }}",
+            self.disk_cache.multiline_display().join(", ")
+        ));
+        res.push(format!(
+            "Rate limit config = {{ {} }}",
+            self.rate_limit.multiline_display().join(", ")
+        ));
         res
     }
 }
@@ -43,8 +71,21 @@ impl Display for IOConfig {
 {}
 {}
 {}
+{}
+{}
+{}
+{}
+{}
 {}",
-            self.s3, self.azure, self.gcs, self.http,
+            self.s3,
+            self.azure,
+            self.gcs,
+            self.http,
+            self.hdfs,
+            self.sftp,
+            self.retry,
+            self.disk_cache,
+            self.rate_limit,
         )
     }
 }
@@ -0,0 +1,41 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Read-through cache of data read from object stores, on local disk.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Display)]
+#[display(
+    "DiskCacheConfig
+    enabled: {enabled}
+    directory: {directory:?}
+    max_size_bytes: {max_size_bytes}"
+)]
+pub struct DiskCacheConfig {
+    pub enabled: bool,
+    /// Directory to cache data in, which may be shared by processes on the same machine. Defaults to a `daft-cache`
+    /// directory in the temporary directory if None.
+    pub directory: Option<String>,
+    /// Size that the cache is kept under by evicting the data that was least recently used.
+    pub max_size_bytes: u64,
+}
+
+impl Default for DiskCacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            directory: None,
+            max_size_bytes: 100 * 1024 * 1024 * 1024,
+        }
+    }
+}
+
+impl DiskCacheConfig {
+    #[must_use]
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![format!("Enabled = {}", self.enabled)];
+        if let Some(directory) = &self.directory {
+            res.push(format!("Directory = {directory}"));
+        }
+        res.push(format!("Max size bytes = {}", self.max_size_bytes));
+        res
+    }
+}
@@ -1,7 +1,7 @@
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
-use crate::ObfuscatedString;
+use crate::{BearerTokenProviderWrapper, ObfuscatedString};
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Display)]
 #[display(
@@ -18,6 +18,7 @@ pub struct GCSConfig {
     pub project_id: Option<String>,
     pub credentials: Option<ObfuscatedString>,
     pub token: Option<String>,
+    pub token_provider: Option<BearerTokenProviderWrapper>,
     pub anonymous: bool,
     pub max_connections_per_io_thread: u32,
     pub retry_initial_backoff_ms: u64,
@@ -32,6 +33,7 @@ impl Default for GCSConfig {
             project_id: None,
             credentials: None,
             token: None,
+            token_provider: None,
             anonymous: false,
             max_connections_per_io_thread: 8,
             retry_initial_backoff_ms: 1000,
@@ -49,6 +51,9 @@ impl GCSConfig {
         if let Some(project_id) = &self.project_id {
             res.push(format!("Project ID = {project_id}"));
         }
+        if let Some(token_provider) = &self.token_provider {
+            res.push(format!("Token provider = {token_provider:?}"));
+        }
         res.push(format!("Anonymous = {}", self.anonymous));
         res.push(format!(
             "Max connections = {}",
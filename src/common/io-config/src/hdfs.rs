@@ -0,0 +1,60 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::ObfuscatedString;
+
+/// How HDFS is accessed, through the WebHDFS REST API of its namenodes.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Display)]
+#[display(
+    "HDFSConfig
+    webhdfs_address: {webhdfs_address:?}
+    webhdfs_port: {webhdfs_port}
+    use_https: {use_https}
+    user: {user:?}
+    delegation_token: {delegation_token:?}"
+)]
+pub struct HDFSConfig {
+    /// Address of the WebHDFS endpoint to send all requests to, such as `http://namenode:9870`, instead of the one
+    /// derived from the host of each path. Needed for paths that name a logical HA nameservice rather than a host.
+    pub webhdfs_address: Option<String>,
+    /// Port of the WebHDFS endpoint of the namenode named by `hdfs://` paths, whose own port is the RPC port.
+    pub webhdfs_port: u16,
+    /// Whether to connect to WebHDFS endpoints over HTTPS, which `swebhdfs://` paths always do.
+    pub use_https: bool,
+    /// User to act as on clusters that use simple authentication.
+    pub user: Option<String>,
+    /// Delegation token to authenticate with on clusters secured by Kerberos, such as one fetched with the
+    /// `GETDELEGATIONTOKEN` operation of WebHDFS after a `kinit`.
+    pub delegation_token: Option<ObfuscatedString>,
+}
+
+impl Default for HDFSConfig {
+    fn default() -> Self {
+        Self {
+            webhdfs_address: None,
+            webhdfs_port: 9870,
+            use_https: false,
+            user: None,
+            delegation_token: None,
+        }
+    }
+}
+
+impl HDFSConfig {
+    #[must_use]
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        if let Some(webhdfs_address) = &self.webhdfs_address {
+            res.push(format!("WebHDFS address = {webhdfs_address}"));
+        }
+        res.push(format!("WebHDFS port = {}", self.webhdfs_port));
+        res.push(format!("Use HTTPS = {}", self.use_https));
+        if let Some(user) = &self.user {
+            res.push(format!("User = {user}"));
+        }
+        if let Some(delegation_token) = &self.delegation_token {
+            res.push(format!("Delegation token = {delegation_token}"));
+        }
+        res
+    }
+}
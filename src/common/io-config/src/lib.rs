@@ -5,9 +5,15 @@ pub mod python;
 
 mod azure;
 mod config;
+mod disk_cache;
 mod gcs;
+mod hdfs;
 mod http;
+mod rate_limit;
+mod retry;
 mod s3;
+mod sftp;
+mod token;
 
 use std::{
     fmt::{Debug, Display},
@@ -20,9 +26,15 @@ use serde::{Deserialize, Deserializer, Serialize};
 pub use crate::{
     azure::AzureConfig,
     config::IOConfig,
+    disk_cache::DiskCacheConfig,
     gcs::GCSConfig,
+    hdfs::HDFSConfig,
     http::HTTPConfig,
+    rate_limit::RateLimitConfig,
+    retry::RetryConfig,
     s3::{S3Config, S3Credentials},
+    sftp::SFTPConfig,
+    token::{BearerToken, BearerTokenProvider, BearerTokenProviderWrapper},
 };
 
 #[derive(Clone)]
@@ -15,6 +15,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     config,
     s3::{S3CredentialsProvider, S3CredentialsProviderWrapper},
+    BearerTokenProvider, BearerTokenProviderWrapper,
 };
 
 /// Create configurations to be used when accessing an S3-compatible system
@@ -74,6 +75,26 @@ pub struct S3Credentials {
     pub credentials: crate::S3Credentials,
 }
 
+/// Create an OAuth2 bearer token to be used when accessing Google Cloud Storage or Azure Blob Storage
+///
+/// Args:
+///     token (str): The bearer token
+///     expiry (datetime.datetime, optional): Expiry time of the token, the token is assumed to never expire if not provided
+///
+/// Examples:
+///     >>> from datetime import datetime, timedelta, timezone
+///     >>> get_token = lambda: BearerToken(
+///     ...     token=fetch_token_from_vault(),
+///     ...     expiry=(datetime.now(timezone.utc) + timedelta(hours=1))
+///     ... )
+///     >>> io_config = IOConfig(gcs=GCSConfig(token_provider=get_token))
+///     >>> daft.read_parquet("gs://some-path", io_config=io_config)
+#[derive(Clone)]
+#[pyclass(module = "daft.daft")]
+pub struct BearerToken {
+    pub token: crate::BearerToken,
+}
+
 /// Create configurations to be used when accessing Azure Blob Storage.
 ///
 /// To authenticate with Microsoft Entra ID, `tenant_id`, `client_id`, and `client_secret` must be provided.
@@ -84,9 +105,11 @@ pub struct S3Credentials {
 ///     access_key (str, optional): Azure Secret Access Key, defaults to reading from `AZURE_STORAGE_KEY` environment variable
 ///     sas_token (str, optional): Shared Access Signature token, defaults to reading from `AZURE_STORAGE_SAS_TOKEN` environment variable
 ///     bearer_token (str, optional): Bearer Token, defaults to reading from `AZURE_STORAGE_TOKEN` environment variable
+///     token_provider (Callable[[], BearerToken], optional): Custom token provider function, should return a `BearerToken` object. It is called again for a new token when the last one expires
 ///     tenant_id (str, optional): Azure Tenant ID
 ///     client_id (str, optional): Azure Client ID
 ///     client_secret (str, optional): Azure Client Secret
+///     use_managed_identity (bool, optional): Whether to authenticate with the managed identity of the Azure VM or service Daft runs on, using `client_id` to pick a user-assigned identity. Defaults to false
 ///     use_fabric_endpoint (bool, optional): Whether to use Microsoft Fabric, you may want to set this if your URLs are from "fabric.microsoft.com". Defaults to false
 ///     anonymous (bool, optional): Whether or not to use "anonymous mode", which will access Azure without any credentials
 ///     endpoint_url (str, optional): Custom URL to the Azure endpoint, e.g. ``https://my-account-name.blob.core.windows.net``. Overrides `use_fabric_endpoint` if set
@@ -109,6 +132,7 @@ pub struct AzureConfig {
 ///     project_id (str, optional): Google Project ID, defaults to value in credentials file or Google Cloud metadata service
 ///     credentials (str, optional): Path to credentials file or JSON string with credentials
 ///     token (str, optional): OAuth2 token to use for authentication. You likely want to use `credentials` instead, since it can be used to refresh the token. This value is used when vended by a data catalog.
+///     token_provider (Callable[[], BearerToken], optional): Custom token provider function, should return a `BearerToken` object. It is called again for a new token when the last one expires
 ///     anonymous (bool, optional): Whether or not to use "anonymous mode", which will access Google Storage without any credentials. Defaults to false
 ///     max_connections (int, optional): Maximum number of connections to GCS at any time per io thread, defaults to 8
 ///     retry_initial_backoff_ms (int, optional): Initial backoff duration in milliseconds for an GCS retry, defaults to 1000ms
@@ -131,6 +155,12 @@ pub struct GCSConfig {
 ///     s3: Configuration to use when accessing URLs with the `s3://` scheme
 ///     azure: Configuration to use when accessing URLs with the `az://` or `abfs://` scheme
 ///     gcs: Configuration to use when accessing URLs with the `gs://` or `gcs://` scheme
+///     http: Configuration to use when accessing URLs with the `http://` or `https://` scheme
+///     hdfs: Configuration to use when accessing URLs with the `hdfs://`, `webhdfs://` or `swebhdfs://` scheme
+///     sftp: Configuration to use when accessing URLs with the `sftp://` scheme
+///     retry: Configuration of how requests that fail with throttling or transient errors are retried
+///     disk_cache: Configuration of the cache of data read from object stores on local disk
+///     rate_limit: Configuration of the caps on the requests made to each endpoint
 ///
 /// Examples:
 ///     >>> io_config = IOConfig(s3=S3Config(key_id="xxx", access_key="xxx", num_tries=10), azure=AzureConfig(anonymous=True), gcs=GCSConfig(...))
@@ -156,16 +186,130 @@ pub struct HTTPConfig {
     pub config: crate::HTTPConfig,
 }
 
+/// Create configurations to be used when accessing HDFS, which is done through the WebHDFS REST API of its namenodes
+///
+/// Clusters secured by Kerberos are accessed with a delegation token, such as one fetched with the `GETDELEGATIONTOKEN` operation of WebHDFS after a `kinit`.
+///
+/// Args:
+///     webhdfs_address (str, optional): Address of the WebHDFS endpoint to send all requests to, such as "http://namenode:9870", instead of the one
+///         derived from the host of each path. Needed for paths that name an HA nameservice rather than a host
+///     webhdfs_port (int, optional): Port of the WebHDFS endpoint of the namenode named by `hdfs://` paths, defaults to 9870
+///     use_https (bool, optional): Whether to connect to WebHDFS over HTTPS, which `swebhdfs://` paths always do, defaults to False
+///     user (str, optional): User to act as on clusters that use simple authentication
+///     delegation_token (str, optional): Delegation token to authenticate with on clusters secured by Kerberos
+///
+/// Examples:
+///     >>> io_config = IOConfig(hdfs=HDFSConfig(user="etl", webhdfs_port=50070))
+///     >>> daft.read_parquet("hdfs://namenode:8020/warehouse/events", io_config=io_config)
+#[derive(Clone, Default)]
+#[pyclass(module = "daft.daft")]
+pub struct HDFSConfig {
+    pub config: crate::HDFSConfig,
+}
+
+/// Create configurations to be used when accessing SFTP servers
+///
+/// Servers are authenticated with the private key if one is set, and otherwise or if that fails, with the password. Their keys are checked
+/// against the `known_hosts` file of OpenSSH.
+///
+/// Args:
+///     user (str, optional): User to log in as, unless the path names one, such as "sftp://user@host/path"
+///     password (str, optional): Password to authenticate with
+///     private_key (str, optional): Private key to authenticate with, in OpenSSH or PEM format
+///     private_key_path (str, optional): Path of a file that holds the private key to authenticate with, if `private_key` is not set
+///     private_key_passphrase (str, optional): Passphrase that the private key is encrypted with, if it is
+///     known_hosts_path (str, optional): Path of the `known_hosts` file that the keys of servers are checked against, defaults to "~/.ssh/known_hosts"
+///     skip_host_key_check (bool, optional): Whether to connect to servers without checking that their key is known, which leaves connections
+///         open to being intercepted, defaults to False
+///
+/// Examples:
+///     >>> io_config = IOConfig(sftp=SFTPConfig(user="partner", private_key_path="/secrets/id_ed25519"))
+///     >>> daft.read_csv("sftp://drop.example.com/incoming/*.csv", io_config=io_config)
+#[derive(Clone, Default)]
+#[pyclass(module = "daft.daft")]
+pub struct SFTPConfig {
+    pub config: crate::SFTPConfig,
+}
+
+/// Create configurations for how requests to object stores that fail with throttling or transient errors are retried
+///
+/// These retries apply to all stores, on top of the retries that the S3 and GCS clients make by themselves as configured by
+/// `num_tries` on `S3Config` and `GCSConfig`.
+///
+/// Args:
+///     max_attempts (int, optional): Maximum number of times a request is attempted, including the first attempt, defaults to 3
+///     initial_backoff_ms (int, optional): Backoff in milliseconds before the first retry, which doubles with every retry after that, defaults to 2500
+///     max_backoff_ms (int, optional): Maximum backoff in milliseconds before a retry, defaults to 20000
+///     jitter (bool, optional): Whether to wait a random time of up to the backoff rather than the backoff itself, defaults to True
+///     retry_budget (int, optional): Size of the budget of tokens that the retries of all requests take from, one per retry of a transient error
+///         and two per retry of a throttled request, which requests give back once they succeed. Defaults to no limit
+///
+/// Examples:
+///     >>> io_config = IOConfig(retry=RetryConfig(max_attempts=5, retry_budget=100))
+///     >>> daft.read_parquet("s3://some-path", io_config=io_config)
+#[derive(Clone, Default)]
+#[pyclass(module = "daft.daft")]
+pub struct RetryConfig {
+    pub config: crate::RetryConfig,
+}
+
+/// Create configurations for caching data read from object stores on local disk
+///
+/// Reads of files that are cached are served from local disk for as long as the file is unchanged in the object store, which is checked
+/// with a metadata request. Entire files and the ranges of files that are read are cached separately.
+///
+/// Args:
+///     enabled (bool, optional): Whether to cache data, defaults to False
+///     directory (str, optional): Directory to cache data in, which may be shared by processes on the same machine, defaults to a `daft-cache`
+///         directory in the temporary directory
+///     max_size_bytes (int, optional): Size that the cache is kept under by evicting the data that was least recently used, defaults to 100GiB
+///
+/// Examples:
+///     >>> io_config = IOConfig(disk_cache=DiskCacheConfig(enabled=True, directory="/mnt/nvme/daft-cache"))
+///     >>> daft.read_parquet("s3://some-path", io_config=io_config)
+#[derive(Clone, Default)]
+#[pyclass(module = "daft.daft")]
+pub struct DiskCacheConfig {
+    pub config: crate::DiskCacheConfig,
+}
+
+/// Create configurations for capping the requests made to each endpoint, i.e. each bucket of an object store or each host
+///
+/// The caps are shared by all queries of the process that use the same configuration, which take turns to make requests once they are
+/// reached, so that wide scans do not trip the throttling of object stores such as S3 or the request limits of on-prem stores such as MinIO.
+/// Listing files is not capped.
+///
+/// Args:
+///     max_concurrent_requests (int, optional): Maximum number of requests in flight to an endpoint at once, counting reads until their
+///         data is read. Defaults to no limit
+///     max_requests_per_second (int, optional): Maximum number of requests started per second to an endpoint, which may burst up to a
+///         second's worth of requests at once. Defaults to no limit
+///
+/// Examples:
+///     >>> io_config = IOConfig(rate_limit=RateLimitConfig(max_concurrent_requests=64, max_requests_per_second=3000))
+///     >>> daft.read_parquet("s3://some-path", io_config=io_config)
+#[derive(Clone, Default)]
+#[pyclass(module = "daft.daft")]
+pub struct RateLimitConfig {
+    pub config: crate::RateLimitConfig,
+}
+
 #[pymethods]
 impl IOConfig {
     #[new]
     #[must_use]
-    #[pyo3(signature = (s3=None, azure=None, gcs=None, http=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (s3=None, azure=None, gcs=None, http=None, hdfs=None, sftp=None, retry=None, disk_cache=None, rate_limit=None))]
     pub fn new(
         s3: Option<S3Config>,
         azure: Option<AzureConfig>,
         gcs: Option<GCSConfig>,
         http: Option<HTTPConfig>,
+        hdfs: Option<HDFSConfig>,
+        sftp: Option<SFTPConfig>,
+        retry: Option<RetryConfig>,
+        disk_cache: Option<DiskCacheConfig>,
+        rate_limit: Option<RateLimitConfig>,
     ) -> Self {
         Self {
             config: config::IOConfig {
@@ -173,18 +317,29 @@ impl IOConfig {
                 azure: azure.unwrap_or_default().config,
                 gcs: gcs.unwrap_or_default().config,
                 http: http.unwrap_or_default().config,
+                hdfs: hdfs.unwrap_or_default().config,
+                sftp: sftp.unwrap_or_default().config,
+                retry: retry.unwrap_or_default().config,
+                disk_cache: disk_cache.unwrap_or_default().config,
+                rate_limit: rate_limit.unwrap_or_default().config,
             },
         }
     }
 
     #[must_use]
-    #[pyo3(signature = (s3=None, azure=None, gcs=None, http=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (s3=None, azure=None, gcs=None, http=None, hdfs=None, sftp=None, retry=None, disk_cache=None, rate_limit=None))]
     pub fn replace(
         &self,
         s3: Option<S3Config>,
         azure: Option<AzureConfig>,
         gcs: Option<GCSConfig>,
         http: Option<HTTPConfig>,
+        hdfs: Option<HDFSConfig>,
+        sftp: Option<SFTPConfig>,
+        retry: Option<RetryConfig>,
+        disk_cache: Option<DiskCacheConfig>,
+        rate_limit: Option<RateLimitConfig>,
     ) -> Self {
         Self {
             config: config::IOConfig {
@@ -200,6 +355,21 @@ impl IOConfig {
                 http: http
                     .map(|http| http.config)
                     .unwrap_or_else(|| self.config.http.clone()),
+                hdfs: hdfs
+                    .map(|hdfs| hdfs.config)
+                    .unwrap_or_else(|| self.config.hdfs.clone()),
+                sftp: sftp
+                    .map(|sftp| sftp.config)
+                    .unwrap_or_else(|| self.config.sftp.clone()),
+                retry: retry
+                    .map(|retry| retry.config)
+                    .unwrap_or_else(|| self.config.retry.clone()),
+                disk_cache: disk_cache
+                    .map(|disk_cache| disk_cache.config)
+                    .unwrap_or_else(|| self.config.disk_cache.clone()),
+                rate_limit: rate_limit
+                    .map(|rate_limit| rate_limit.config)
+                    .unwrap_or_else(|| self.config.rate_limit.clone()),
             },
         }
     }
@@ -240,6 +410,46 @@ impl IOConfig {
         })
     }
 
+    /// Configuration to be used when accessing HDFS URLs
+    #[getter]
+    pub fn hdfs(&self) -> PyResult<HDFSConfig> {
+        Ok(HDFSConfig {
+            config: self.config.hdfs.clone(),
+        })
+    }
+
+    /// Configuration to be used when accessing SFTP URLs
+    #[getter]
+    pub fn sftp(&self) -> PyResult<SFTPConfig> {
+        Ok(SFTPConfig {
+            config: self.config.sftp.clone(),
+        })
+    }
+
+    /// Configuration of how failed requests are retried
+    #[getter]
+    pub fn retry(&self) -> PyResult<RetryConfig> {
+        Ok(RetryConfig {
+            config: self.config.retry.clone(),
+        })
+    }
+
+    /// Configuration of the cache of data read from object stores on local disk
+    #[getter]
+    pub fn disk_cache(&self) -> PyResult<DiskCacheConfig> {
+        Ok(DiskCacheConfig {
+            config: self.config.disk_cache.clone(),
+        })
+    }
+
+    /// Configuration of the caps on the requests made to each endpoint
+    #[getter]
+    pub fn rate_limit(&self) -> PyResult<RateLimitConfig> {
+        Ok(RateLimitConfig {
+            config: self.config.rate_limit.clone(),
+        })
+    }
+
     pub fn __hash__(&self) -> PyResult<u64> {
         use std::{collections::hash_map::DefaultHasher, hash::Hash};
 
@@ -700,6 +910,106 @@ impl S3CredentialsProvider for PyS3CredentialsProvider {
     }
 }
 
+#[pymethods]
+impl BearerToken {
+    #[new]
+    #[pyo3(signature = (token, expiry=None))]
+    pub fn new(token: String, expiry: Option<DateTime<Utc>>) -> Self {
+        Self {
+            token: crate::BearerToken { token, expiry },
+        }
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("{}", self.token)
+    }
+
+    /// OAuth2 Bearer Token
+    #[getter]
+    pub fn token(&self) -> &str {
+        &self.token.token
+    }
+
+    /// Bearer Token Expiry
+    #[getter]
+    pub fn expiry(&self) -> Option<DateTime<Utc>> {
+        self.token.expiry
+    }
+}
+
+/// The Python function that provides the tokens of `token_provider`, if it is one.
+fn py_token_provider(
+    py: Python,
+    token_provider: Option<&BearerTokenProviderWrapper>,
+) -> Option<Py<PyAny>> {
+    token_provider.and_then(|p| {
+        p.provider
+            .as_any()
+            .downcast_ref::<PyBearerTokenProvider>()
+            .map(|p| p.provider.clone_ref(py))
+    })
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PyBearerTokenProvider {
+    #[serde(
+        serialize_with = "serialize_py_object",
+        deserialize_with = "deserialize_py_object"
+    )]
+    pub provider: Arc<PyObject>,
+    pub hash: isize,
+}
+
+impl PyBearerTokenProvider {
+    pub fn new(provider: Bound<PyAny>) -> PyResult<Self> {
+        let hash = provider.hash()?;
+        Ok(Self {
+            provider: Arc::new(provider.into()),
+            hash,
+        })
+    }
+}
+
+impl PartialEq for PyBearerTokenProvider {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash
+    }
+}
+
+impl Eq for PyBearerTokenProvider {}
+
+impl Hash for PyBearerTokenProvider {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hash.hash(state);
+    }
+}
+
+#[typetag::serde]
+impl BearerTokenProvider for PyBearerTokenProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn BearerTokenProvider> {
+        Box::new(self.clone())
+    }
+
+    fn dyn_eq(&self, other: &dyn BearerTokenProvider) -> bool {
+        other.as_any().downcast_ref::<Self>() == Some(self)
+    }
+
+    fn dyn_hash(&self, mut state: &mut dyn Hasher) {
+        self.hash(&mut state);
+    }
+
+    fn provide_token(&self) -> DaftResult<crate::BearerToken> {
+        Python::with_gil(|py| {
+            let py_token = self.provider.call0(py)?;
+            Ok(py_token.extract::<BearerToken>(py)?.token)
+        })
+    }
+}
+
 #[pymethods]
 impl AzureConfig {
     #[allow(clippy::too_many_arguments)]
@@ -710,9 +1020,11 @@ impl AzureConfig {
         access_key=None,
         sas_token=None,
         bearer_token=None,
+        token_provider=None,
         tenant_id=None,
         client_id=None,
         client_secret=None,
+        use_managed_identity=None,
         use_fabric_endpoint=None,
         anonymous=None,
         endpoint_url=None,
@@ -723,32 +1035,43 @@ impl AzureConfig {
         access_key: Option<String>,
         sas_token: Option<String>,
         bearer_token: Option<String>,
+        token_provider: Option<Bound<PyAny>>,
         tenant_id: Option<String>,
         client_id: Option<String>,
         client_secret: Option<String>,
+        use_managed_identity: Option<bool>,
         use_fabric_endpoint: Option<bool>,
         anonymous: Option<bool>,
         endpoint_url: Option<String>,
         use_ssl: Option<bool>,
-    ) -> Self {
+    ) -> PyResult<Self> {
         let def = crate::AzureConfig::default();
-        Self {
+        Ok(Self {
             config: crate::AzureConfig {
                 storage_account: storage_account.or(def.storage_account),
                 access_key: access_key.map(std::convert::Into::into).or(def.access_key),
                 sas_token: sas_token.or(def.sas_token),
                 bearer_token: bearer_token.or(def.bearer_token),
+                token_provider: token_provider
+                    .map(|p| {
+                        Ok::<_, PyErr>(BearerTokenProviderWrapper::new(PyBearerTokenProvider::new(
+                            p,
+                        )?))
+                    })
+                    .transpose()?
+                    .or(def.token_provider),
                 tenant_id: tenant_id.or(def.tenant_id),
                 client_id: client_id.or(def.client_id),
                 client_secret: client_secret
                     .map(std::convert::Into::into)
                     .or(def.client_secret),
+                use_managed_identity: use_managed_identity.unwrap_or(def.use_managed_identity),
                 use_fabric_endpoint: use_fabric_endpoint.unwrap_or(def.use_fabric_endpoint),
                 anonymous: anonymous.unwrap_or(def.anonymous),
                 endpoint_url: endpoint_url.or(def.endpoint_url),
                 use_ssl: use_ssl.unwrap_or(def.use_ssl),
             },
-        }
+        })
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -758,9 +1081,11 @@ impl AzureConfig {
         access_key=None,
         sas_token=None,
         bearer_token=None,
+        token_provider=None,
         tenant_id=None,
         client_id=None,
         client_secret=None,
+        use_managed_identity=None,
         use_fabric_endpoint=None,
         anonymous=None,
         endpoint_url=None,
@@ -772,15 +1097,17 @@ impl AzureConfig {
         access_key: Option<String>,
         sas_token: Option<String>,
         bearer_token: Option<String>,
+        token_provider: Option<Bound<PyAny>>,
         tenant_id: Option<String>,
         client_id: Option<String>,
         client_secret: Option<String>,
+        use_managed_identity: Option<bool>,
         use_fabric_endpoint: Option<bool>,
         anonymous: Option<bool>,
         endpoint_url: Option<String>,
         use_ssl: Option<bool>,
-    ) -> Self {
-        Self {
+    ) -> PyResult<Self> {
+        Ok(Self {
             config: crate::AzureConfig {
                 storage_account: storage_account.or_else(|| self.config.storage_account.clone()),
                 access_key: access_key
@@ -788,17 +1115,27 @@ impl AzureConfig {
                     .or_else(|| self.config.access_key.clone()),
                 sas_token: sas_token.or_else(|| self.config.sas_token.clone()),
                 bearer_token: bearer_token.or_else(|| self.config.bearer_token.clone()),
+                token_provider: token_provider
+                    .map(|p| {
+                        Ok::<_, PyErr>(BearerTokenProviderWrapper::new(PyBearerTokenProvider::new(
+                            p,
+                        )?))
+                    })
+                    .transpose()?
+                    .or_else(|| self.config.token_provider.clone()),
                 tenant_id: tenant_id.or_else(|| self.config.tenant_id.clone()),
                 client_id: client_id.or_else(|| self.config.client_id.clone()),
                 client_secret: client_secret
                     .map(std::convert::Into::into)
                     .or_else(|| self.config.client_secret.clone()),
+                use_managed_identity: use_managed_identity
+                    .unwrap_or(self.config.use_managed_identity),
                 use_fabric_endpoint: use_fabric_endpoint.unwrap_or(self.config.use_fabric_endpoint),
                 anonymous: anonymous.unwrap_or(self.config.anonymous),
                 endpoint_url: endpoint_url.or_else(|| self.config.endpoint_url.clone()),
                 use_ssl: use_ssl.unwrap_or(self.config.use_ssl),
             },
-        }
+        })
     }
 
     pub fn __repr__(&self) -> PyResult<String> {
@@ -834,6 +1171,12 @@ impl AzureConfig {
         Ok(self.config.bearer_token.clone())
     }
 
+    /// Custom token provider function
+    #[getter]
+    pub fn token_provider(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(py_token_provider(py, self.config.token_provider.as_ref()))
+    }
+
     #[getter]
     pub fn tenant_id(&self) -> PyResult<Option<String>> {
         Ok(self.config.tenant_id.clone())
@@ -854,6 +1197,12 @@ impl AzureConfig {
             .cloned())
     }
 
+    /// Whether to authenticate with a managed identity
+    #[getter]
+    pub fn use_managed_identity(&self) -> PyResult<bool> {
+        Ok(self.config.use_managed_identity)
+    }
+
     /// Whether to use Microsoft Fabric
     #[getter]
     pub fn use_fabric_endpoint(&self) -> PyResult<bool> {
@@ -888,6 +1237,7 @@ impl GCSConfig {
         project_id=None,
         credentials=None,
         token=None,
+        token_provider=None,
         anonymous=None,
         max_connections=None,
         retry_initial_backoff_ms=None,
@@ -899,21 +1249,30 @@ impl GCSConfig {
         project_id: Option<String>,
         credentials: Option<String>,
         token: Option<String>,
+        token_provider: Option<Bound<PyAny>>,
         anonymous: Option<bool>,
         max_connections: Option<u32>,
         retry_initial_backoff_ms: Option<u64>,
         connect_timeout_ms: Option<u64>,
         read_timeout_ms: Option<u64>,
         num_tries: Option<u32>,
-    ) -> Self {
+    ) -> PyResult<Self> {
         let def = crate::GCSConfig::default();
-        Self {
+        Ok(Self {
             config: crate::GCSConfig {
                 project_id: project_id.or(def.project_id),
                 credentials: credentials
                     .map(std::convert::Into::into)
                     .or(def.credentials),
                 token: token.or(def.token),
+                token_provider: token_provider
+                    .map(|p| {
+                        Ok::<_, PyErr>(BearerTokenProviderWrapper::new(PyBearerTokenProvider::new(
+                            p,
+                        )?))
+                    })
+                    .transpose()?
+                    .or(def.token_provider),
                 anonymous: anonymous.unwrap_or(def.anonymous),
                 max_connections_per_io_thread: max_connections
                     .unwrap_or(def.max_connections_per_io_thread),
@@ -923,7 +1282,7 @@ impl GCSConfig {
                 read_timeout_ms: read_timeout_ms.unwrap_or(def.read_timeout_ms),
                 num_tries: num_tries.unwrap_or(def.num_tries),
             },
-        }
+        })
     }
     #[allow(clippy::too_many_arguments)]
     #[must_use]
@@ -931,6 +1290,7 @@ impl GCSConfig {
         project_id=None,
         credentials=None,
         token=None,
+        token_provider=None,
         anonymous=None,
         max_connections=None,
         retry_initial_backoff_ms=None,
@@ -943,20 +1303,29 @@ impl GCSConfig {
         project_id: Option<String>,
         credentials: Option<String>,
         token: Option<String>,
+        token_provider: Option<Bound<PyAny>>,
         anonymous: Option<bool>,
         max_connections: Option<u32>,
         retry_initial_backoff_ms: Option<u64>,
         connect_timeout_ms: Option<u64>,
         read_timeout_ms: Option<u64>,
         num_tries: Option<u32>,
-    ) -> Self {
-        Self {
+    ) -> PyResult<Self> {
+        Ok(Self {
             config: crate::GCSConfig {
                 project_id: project_id.or_else(|| self.config.project_id.clone()),
                 credentials: credentials
                     .map(std::convert::Into::into)
                     .or_else(|| self.config.credentials.clone()),
                 token: token.or_else(|| self.config.token.clone()),
+                token_provider: token_provider
+                    .map(|p| {
+                        Ok::<_, PyErr>(BearerTokenProviderWrapper::new(PyBearerTokenProvider::new(
+                            p,
+                        )?))
+                    })
+                    .transpose()?
+                    .or_else(|| self.config.token_provider.clone()),
                 anonymous: anonymous.unwrap_or(self.config.anonymous),
                 max_connections_per_io_thread: max_connections
                     .unwrap_or(self.config.max_connections_per_io_thread),
@@ -966,7 +1335,7 @@ impl GCSConfig {
                 read_timeout_ms: read_timeout_ms.unwrap_or(self.config.read_timeout_ms),
                 num_tries: num_tries.unwrap_or(self.config.num_tries),
             },
-        }
+        })
     }
 
     pub fn __repr__(&self) -> PyResult<String> {
@@ -995,6 +1364,12 @@ impl GCSConfig {
         Ok(self.config.token.clone())
     }
 
+    /// Custom token provider function
+    #[getter]
+    pub fn token_provider(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(py_token_provider(py, self.config.token_provider.as_ref()))
+    }
+
     /// Whether to use anonymous mode
     #[getter]
     pub fn anonymous(&self) -> PyResult<bool> {
@@ -1049,12 +1424,439 @@ impl HTTPConfig {
     }
 }
 
+#[pymethods]
+impl HDFSConfig {
+    #[new]
+    #[must_use]
+    #[pyo3(signature = (
+        webhdfs_address=None,
+        webhdfs_port=None,
+        use_https=None,
+        user=None,
+        delegation_token=None
+    ))]
+    pub fn new(
+        webhdfs_address: Option<String>,
+        webhdfs_port: Option<u16>,
+        use_https: Option<bool>,
+        user: Option<String>,
+        delegation_token: Option<String>,
+    ) -> Self {
+        let def = crate::HDFSConfig::default();
+        Self {
+            config: crate::HDFSConfig {
+                webhdfs_address: webhdfs_address.or(def.webhdfs_address),
+                webhdfs_port: webhdfs_port.unwrap_or(def.webhdfs_port),
+                use_https: use_https.unwrap_or(def.use_https),
+                user: user.or(def.user),
+                delegation_token: delegation_token
+                    .map(std::convert::Into::into)
+                    .or(def.delegation_token),
+            },
+        }
+    }
+
+    #[must_use]
+    #[pyo3(signature = (
+        webhdfs_address=None,
+        webhdfs_port=None,
+        use_https=None,
+        user=None,
+        delegation_token=None
+    ))]
+    pub fn replace(
+        &self,
+        webhdfs_address: Option<String>,
+        webhdfs_port: Option<u16>,
+        use_https: Option<bool>,
+        user: Option<String>,
+        delegation_token: Option<String>,
+    ) -> Self {
+        Self {
+            config: crate::HDFSConfig {
+                webhdfs_address: webhdfs_address.or_else(|| self.config.webhdfs_address.clone()),
+                webhdfs_port: webhdfs_port.unwrap_or(self.config.webhdfs_port),
+                use_https: use_https.unwrap_or(self.config.use_https),
+                user: user.or_else(|| self.config.user.clone()),
+                delegation_token: delegation_token
+                    .map(std::convert::Into::into)
+                    .or_else(|| self.config.delegation_token.clone()),
+            },
+        }
+    }
+
+    pub fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("{}", self.config))
+    }
+
+    #[getter]
+    pub fn webhdfs_address(&self) -> PyResult<Option<String>> {
+        Ok(self.config.webhdfs_address.clone())
+    }
+
+    #[getter]
+    pub fn webhdfs_port(&self) -> PyResult<u16> {
+        Ok(self.config.webhdfs_port)
+    }
+
+    #[getter]
+    pub fn use_https(&self) -> PyResult<bool> {
+        Ok(self.config.use_https)
+    }
+
+    #[getter]
+    pub fn user(&self) -> PyResult<Option<String>> {
+        Ok(self.config.user.clone())
+    }
+
+    #[getter]
+    pub fn delegation_token(&self) -> PyResult<Option<String>> {
+        Ok(self
+            .config
+            .delegation_token
+            .as_ref()
+            .map(super::ObfuscatedString::as_string)
+            .cloned())
+    }
+}
+
+#[pymethods]
+impl SFTPConfig {
+    #[new]
+    #[must_use]
+    #[pyo3(signature = (
+        user=None,
+        password=None,
+        private_key=None,
+        private_key_path=None,
+        private_key_passphrase=None,
+        known_hosts_path=None,
+        skip_host_key_check=None
+    ))]
+    pub fn new(
+        user: Option<String>,
+        password: Option<String>,
+        private_key: Option<String>,
+        private_key_path: Option<String>,
+        private_key_passphrase: Option<String>,
+        known_hosts_path: Option<String>,
+        skip_host_key_check: Option<bool>,
+    ) -> Self {
+        let def = crate::SFTPConfig::default();
+        Self {
+            config: crate::SFTPConfig {
+                user: user.or(def.user),
+                password: password.map(std::convert::Into::into).or(def.password),
+                private_key: private_key
+                    .map(std::convert::Into::into)
+                    .or(def.private_key),
+                private_key_path: private_key_path.or(def.private_key_path),
+                private_key_passphrase: private_key_passphrase
+                    .map(std::convert::Into::into)
+                    .or(def.private_key_passphrase),
+                known_hosts_path: known_hosts_path.or(def.known_hosts_path),
+                skip_host_key_check: skip_host_key_check.unwrap_or(def.skip_host_key_check),
+            },
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (
+        user=None,
+        password=None,
+        private_key=None,
+        private_key_path=None,
+        private_key_passphrase=None,
+        known_hosts_path=None,
+        skip_host_key_check=None
+    ))]
+    pub fn replace(
+        &self,
+        user: Option<String>,
+        password: Option<String>,
+        private_key: Option<String>,
+        private_key_path: Option<String>,
+        private_key_passphrase: Option<String>,
+        known_hosts_path: Option<String>,
+        skip_host_key_check: Option<bool>,
+    ) -> Self {
+        Self {
+            config: crate::SFTPConfig {
+                user: user.or_else(|| self.config.user.clone()),
+                password: password
+                    .map(std::convert::Into::into)
+                    .or_else(|| self.config.password.clone()),
+                private_key: private_key
+                    .map(std::convert::Into::into)
+                    .or_else(|| self.config.private_key.clone()),
+                private_key_path: private_key_path.or_else(|| self.config.private_key_path.clone()),
+                private_key_passphrase: private_key_passphrase
+                    .map(std::convert::Into::into)
+                    .or_else(|| self.config.private_key_passphrase.clone()),
+                known_hosts_path: known_hosts_path.or_else(|| self.config.known_hosts_path.clone()),
+                skip_host_key_check: skip_host_key_check.unwrap_or(self.config.skip_host_key_check),
+            },
+        }
+    }
+
+    pub fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("{}", self.config))
+    }
+
+    #[getter]
+    pub fn user(&self) -> PyResult<Option<String>> {
+        Ok(self.config.user.clone())
+    }
+
+    #[getter]
+    pub fn password(&self) -> PyResult<Option<String>> {
+        Ok(self
+            .config
+            .password
+            .as_ref()
+            .map(super::ObfuscatedString::as_string)
+            .cloned())
+    }
+
+    #[getter]
+    pub fn private_key(&self) -> PyResult<Option<String>> {
+        Ok(self
+            .config
+            .private_key
+            .as_ref()
+            .map(super::ObfuscatedString::as_string)
+            .cloned())
+    }
+
+    #[getter]
+    pub fn private_key_path(&self) -> PyResult<Option<String>> {
+        Ok(self.config.private_key_path.clone())
+    }
+
+    #[getter]
+    pub fn private_key_passphrase(&self) -> PyResult<Option<String>> {
+        Ok(self
+            .config
+            .private_key_passphrase
+            .as_ref()
+            .map(super::ObfuscatedString::as_string)
+            .cloned())
+    }
+
+    #[getter]
+    pub fn known_hosts_path(&self) -> PyResult<Option<String>> {
+        Ok(self.config.known_hosts_path.clone())
+    }
+
+    #[getter]
+    pub fn skip_host_key_check(&self) -> PyResult<bool> {
+        Ok(self.config.skip_host_key_check)
+    }
+}
+
+#[pymethods]
+impl RetryConfig {
+    #[new]
+    #[must_use]
+    #[pyo3(signature = (
+        max_attempts=None,
+        initial_backoff_ms=None,
+        max_backoff_ms=None,
+        jitter=None,
+        retry_budget=None
+    ))]
+    pub fn new(
+        max_attempts: Option<u32>,
+        initial_backoff_ms: Option<u64>,
+        max_backoff_ms: Option<u64>,
+        jitter: Option<bool>,
+        retry_budget: Option<u32>,
+    ) -> Self {
+        let def = crate::RetryConfig::default();
+        Self {
+            config: crate::RetryConfig {
+                max_attempts: max_attempts.unwrap_or(def.max_attempts),
+                initial_backoff_ms: initial_backoff_ms.unwrap_or(def.initial_backoff_ms),
+                max_backoff_ms: max_backoff_ms.unwrap_or(def.max_backoff_ms),
+                jitter: jitter.unwrap_or(def.jitter),
+                retry_budget: retry_budget.or(def.retry_budget),
+            },
+        }
+    }
+
+    #[must_use]
+    #[pyo3(signature = (
+        max_attempts=None,
+        initial_backoff_ms=None,
+        max_backoff_ms=None,
+        jitter=None,
+        retry_budget=None
+    ))]
+    pub fn replace(
+        &self,
+        max_attempts: Option<u32>,
+        initial_backoff_ms: Option<u64>,
+        max_backoff_ms: Option<u64>,
+        jitter: Option<bool>,
+        retry_budget: Option<u32>,
+    ) -> Self {
+        Self {
+            config: crate::RetryConfig {
+                max_attempts: max_attempts.unwrap_or(self.config.max_attempts),
+                initial_backoff_ms: initial_backoff_ms.unwrap_or(self.config.initial_backoff_ms),
+                max_backoff_ms: max_backoff_ms.unwrap_or(self.config.max_backoff_ms),
+                jitter: jitter.unwrap_or(self.config.jitter),
+                retry_budget: retry_budget.or(self.config.retry_budget),
+            },
+        }
+    }
+
+    pub fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("{}", self.config))
+    }
+
+    #[getter]
+    pub fn max_attempts(&self) -> PyResult<u32> {
+        Ok(self.config.max_attempts)
+    }
+
+    #[getter]
+    pub fn initial_backoff_ms(&self) -> PyResult<u64> {
+        Ok(self.config.initial_backoff_ms)
+    }
+
+    #[getter]
+    pub fn max_backoff_ms(&self) -> PyResult<u64> {
+        Ok(self.config.max_backoff_ms)
+    }
+
+    #[getter]
+    pub fn jitter(&self) -> PyResult<bool> {
+        Ok(self.config.jitter)
+    }
+
+    #[getter]
+    pub fn retry_budget(&self) -> PyResult<Option<u32>> {
+        Ok(self.config.retry_budget)
+    }
+}
+
+#[pymethods]
+impl DiskCacheConfig {
+    #[new]
+    #[must_use]
+    #[pyo3(signature = (enabled=None, directory=None, max_size_bytes=None))]
+    pub fn new(
+        enabled: Option<bool>,
+        directory: Option<String>,
+        max_size_bytes: Option<u64>,
+    ) -> Self {
+        let def = crate::DiskCacheConfig::default();
+        Self {
+            config: crate::DiskCacheConfig {
+                enabled: enabled.unwrap_or(def.enabled),
+                directory: directory.or(def.directory),
+                max_size_bytes: max_size_bytes.unwrap_or(def.max_size_bytes),
+            },
+        }
+    }
+
+    #[must_use]
+    #[pyo3(signature = (enabled=None, directory=None, max_size_bytes=None))]
+    pub fn replace(
+        &self,
+        enabled: Option<bool>,
+        directory: Option<String>,
+        max_size_bytes: Option<u64>,
+    ) -> Self {
+        Self {
+            config: crate::DiskCacheConfig {
+                enabled: enabled.unwrap_or(self.config.enabled),
+                directory: directory.or_else(|| self.config.directory.clone()),
+                max_size_bytes: max_size_bytes.unwrap_or(self.config.max_size_bytes),
+            },
+        }
+    }
+
+    pub fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("{}", self.config))
+    }
+
+    #[getter]
+    pub fn enabled(&self) -> PyResult<bool> {
+        Ok(self.config.enabled)
+    }
+
+    #[getter]
+    pub fn directory(&self) -> PyResult<Option<String>> {
+        Ok(self.config.directory.clone())
+    }
+
+    #[getter]
+    pub fn max_size_bytes(&self) -> PyResult<u64> {
+        Ok(self.config.max_size_bytes)
+    }
+}
+
+#[pymethods]
+impl RateLimitConfig {
+    #[new]
+    #[must_use]
+    #[pyo3(signature = (max_concurrent_requests=None, max_requests_per_second=None))]
+    pub fn new(max_concurrent_requests: Option<u32>, max_requests_per_second: Option<u32>) -> Self {
+        Self {
+            config: crate::RateLimitConfig {
+                max_concurrent_requests,
+                max_requests_per_second,
+            },
+        }
+    }
+
+    #[must_use]
+    #[pyo3(signature = (max_concurrent_requests=None, max_requests_per_second=None))]
+    pub fn replace(
+        &self,
+        max_concurrent_requests: Option<u32>,
+        max_requests_per_second: Option<u32>,
+    ) -> Self {
+        Self {
+            config: crate::RateLimitConfig {
+                max_concurrent_requests: max_concurrent_requests
+                    .or(self.config.max_concurrent_requests),
+                max_requests_per_second: max_requests_per_second
+                    .or(self.config.max_requests_per_second),
+            },
+        }
+    }
+
+    pub fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("{}", self.config))
+    }
+
+    #[getter]
+    pub fn max_concurrent_requests(&self) -> PyResult<Option<u32>> {
+        Ok(self.config.max_concurrent_requests)
+    }
+
+    #[getter]
+    pub fn max_requests_per_second(&self) -> PyResult<Option<u32>> {
+        Ok(self.config.max_requests_per_second)
+    }
+}
+
 pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_class::<AzureConfig>()?;
     parent.add_class::<GCSConfig>()?;
     parent.add_class::<S3Config>()?;
     parent.add_class::<HTTPConfig>()?;
+    parent.add_class::<HDFSConfig>()?;
+    parent.add_class::<SFTPConfig>()?;
+    parent.add_class::<RetryConfig>()?;
+    parent.add_class::<DiskCacheConfig>()?;
+    parent.add_class::<RateLimitConfig>()?;
     parent.add_class::<S3Credentials>()?;
+    parent.add_class::<BearerToken>()?;
     parent.add_class::<IOConfig>()?;
     Ok(())
 }
@@ -0,0 +1,43 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Caps on the requests made to each endpoint, i.e. each bucket of an object store or each host of other stores, which
+/// all queries of the process share fairly.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash, Display)]
+#[display(
+    "RateLimitConfig
+    max_concurrent_requests: {max_concurrent_requests:?}
+    max_requests_per_second: {max_requests_per_second:?}"
+)]
+pub struct RateLimitConfig {
+    /// Maximum number of requests in flight to an endpoint at once, counting reads until their data is read. Unbounded
+    /// if None.
+    pub max_concurrent_requests: Option<u32>,
+    /// Maximum number of requests started per second to an endpoint, which may burst up to a second's worth of
+    /// requests at once. Unbounded if None.
+    pub max_requests_per_second: Option<u32>,
+}
+
+impl RateLimitConfig {
+    #[must_use]
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        if let Some(max_concurrent_requests) = self.max_concurrent_requests {
+            res.push(format!(
+                "Max concurrent requests = {max_concurrent_requests}"
+            ));
+        }
+        if let Some(max_requests_per_second) = self.max_requests_per_second {
+            res.push(format!(
+                "Max requests per second = {max_requests_per_second}"
+            ));
+        }
+        res
+    }
+
+    /// Whether requests are limited at all.
+    #[must_use]
+    pub fn is_limited(&self) -> bool {
+        self.max_concurrent_requests.is_some() || self.max_requests_per_second.is_some()
+    }
+}
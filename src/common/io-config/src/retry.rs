@@ -0,0 +1,56 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// How Daft retries requests to object stores that fail with throttling or transient errors, on top of the retries
+/// that the clients of some stores make by themselves.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Display)]
+#[display(
+    "RetryConfig
+    max_attempts: {max_attempts}
+    initial_backoff_ms: {initial_backoff_ms}
+    max_backoff_ms: {max_backoff_ms}
+    jitter: {jitter}
+    retry_budget: {retry_budget:?}"
+)]
+pub struct RetryConfig {
+    /// Maximum number of times a request is attempted, including the first attempt.
+    pub max_attempts: u32,
+    /// Backoff before the first retry, which doubles with every retry after that.
+    pub initial_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+    /// Whether to wait a random time of up to the backoff, rather than the backoff itself, so that clients that fail
+    /// at once don't all retry at once.
+    pub jitter: bool,
+    /// Size of the budget of tokens that retries of all requests of a client take from, one per retry of a transient
+    /// error and two per retry of a throttled request, and that requests give back once they succeed. Bounds how hard
+    /// a store that is down or throttling is retried. Unbounded if None.
+    pub retry_budget: Option<u32>,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff_ms: 2_500,
+            max_backoff_ms: 20_000,
+            jitter: true,
+            retry_budget: None,
+        }
+    }
+}
+
+impl RetryConfig {
+    #[must_use]
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![
+            format!("Max attempts = {}", self.max_attempts),
+            format!("Initial backoff ms = {}", self.initial_backoff_ms),
+            format!("Max backoff ms = {}", self.max_backoff_ms),
+            format!("Jitter = {}", self.jitter),
+        ];
+        if let Some(retry_budget) = self.retry_budget {
+            res.push(format!("Retry budget = {retry_budget}"));
+        }
+        res
+    }
+}
@@ -0,0 +1,64 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::ObfuscatedString;
+
+/// How SFTP servers are connected to and authenticated with.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash, Display)]
+#[display(
+    "SFTPConfig
+    user: {user:?}
+    password: {password:?}
+    private_key: {private_key:?}
+    private_key_path: {private_key_path:?}
+    private_key_passphrase: {private_key_passphrase:?}
+    known_hosts_path: {known_hosts_path:?}
+    skip_host_key_check: {skip_host_key_check}"
+)]
+pub struct SFTPConfig {
+    /// User to log in as, unless the path names one, such as `sftp://user@host/path`.
+    pub user: Option<String>,
+    /// Password to authenticate with, if the server accepts passwords.
+    pub password: Option<ObfuscatedString>,
+    /// Private key to authenticate with, in OpenSSH or PEM format, which is tried before the password.
+    pub private_key: Option<ObfuscatedString>,
+    /// Path of a file that holds the private key to authenticate with, if `private_key` is not set.
+    pub private_key_path: Option<String>,
+    /// Passphrase that the private key is encrypted with, if it is.
+    pub private_key_passphrase: Option<ObfuscatedString>,
+    /// Path of the `known_hosts` file that the keys of servers are checked against, instead of `~/.ssh/known_hosts`.
+    pub known_hosts_path: Option<String>,
+    /// Whether to connect to servers without checking that their key is known, which leaves connections open to
+    /// being intercepted.
+    pub skip_host_key_check: bool,
+}
+
+impl SFTPConfig {
+    #[must_use]
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        if let Some(user) = &self.user {
+            res.push(format!("User = {user}"));
+        }
+        if let Some(password) = &self.password {
+            res.push(format!("Password = {password}"));
+        }
+        if let Some(private_key) = &self.private_key {
+            res.push(format!("Private key = {private_key}"));
+        }
+        if let Some(private_key_path) = &self.private_key_path {
+            res.push(format!("Private key path = {private_key_path}"));
+        }
+        if let Some(private_key_passphrase) = &self.private_key_passphrase {
+            res.push(format!("Private key passphrase = {private_key_passphrase}"));
+        }
+        if let Some(known_hosts_path) = &self.known_hosts_path {
+            res.push(format!("Known hosts path = {known_hosts_path}"));
+        }
+        res.push(format!(
+            "Skip host key check = {}",
+            self.skip_host_key_check
+        ));
+        res
+    }
+}
@@ -0,0 +1,100 @@
+use std::{
+    any::Any,
+    fmt::{Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    sync::{Arc, Mutex},
+};
+
+use chrono::{offset::Utc, DateTime, TimeDelta};
+use common_error::DaftResult;
+use derivative::Derivative;
+use serde::{Deserialize, Serialize};
+
+/// How long before it expires that a token is considered expired, so that requests are not sent with a token that
+/// expires before they arrive.
+const TOKEN_EXPIRY_BUFFER: TimeDelta = TimeDelta::seconds(10);
+
+/// An OAuth 2.0 bearer token, and when it expires.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BearerToken {
+    pub token: String,
+    pub expiry: Option<DateTime<Utc>>,
+}
+
+/// Provides bearer tokens to authenticate with, such as ones exchanged for workload identities or fetched from a
+/// vault, which is called again for a new token whenever the last one it provided expires.
+#[typetag::serde(tag = "type")]
+pub trait BearerTokenProvider: Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+    fn clone_box(&self) -> Box<dyn BearerTokenProvider>;
+    fn dyn_eq(&self, other: &dyn BearerTokenProvider) -> bool;
+    fn dyn_hash(&self, state: &mut dyn Hasher);
+    fn provide_token(&self) -> DaftResult<BearerToken>;
+}
+
+#[derive(Derivative, Clone, Debug, Deserialize, Serialize)]
+#[derivative(PartialEq, Eq, Hash)]
+pub struct BearerTokenProviderWrapper {
+    pub provider: Box<dyn BearerTokenProvider>,
+    #[derivative(PartialEq = "ignore")]
+    #[derivative(Hash = "ignore")]
+    cached_token: Arc<Mutex<Option<BearerToken>>>,
+}
+
+impl BearerTokenProviderWrapper {
+    pub fn new(provider: impl BearerTokenProvider + 'static) -> Self {
+        Self {
+            provider: Box::new(provider),
+            cached_token: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// The last token provided, or a new one if it is about to expire.
+    pub fn get_cached_token(&self) -> DaftResult<BearerToken> {
+        let mut cached_token = self.cached_token.lock().unwrap();
+
+        if let Some(token) = cached_token.clone()
+            && token
+                .expiry
+                .is_none_or(|expiry| expiry - TOKEN_EXPIRY_BUFFER > Utc::now())
+        {
+            Ok(token)
+        } else {
+            let token = self.provider.provide_token()?;
+            *cached_token = Some(token.clone());
+            Ok(token)
+        }
+    }
+}
+
+impl Clone for Box<dyn BearerTokenProvider> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+impl PartialEq for Box<dyn BearerTokenProvider> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_eq(other.as_ref())
+    }
+}
+
+impl Eq for Box<dyn BearerTokenProvider> {}
+
+impl Hash for Box<dyn BearerTokenProvider> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.dyn_hash(state);
+    }
+}
+
+impl Display for BearerToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "BearerToken
+    token: ** redacted **
+    expiry: {:?}",
+            self.expiry,
+        )
+    }
+}
@@ -3,4 +3,7 @@ mod python;
 pub use bincode;
 
 #[cfg(feature = "python")]
-pub use crate::{python::deserialize_py_object, python::pickle_dumps, python::serialize_py_object};
+pub use crate::{
+    python::deserialize_py_object, python::deserialize_py_object_optional, python::pickle_dumps,
+    python::serialize_py_object, python::serialize_py_object_optional,
+};
@@ -78,6 +78,30 @@ where
     d.deserialize_bytes(PyObjectVisitor).map(Into::into)
 }
 
+#[cfg(feature = "python")]
+pub fn serialize_py_object_optional<S>(obj: &Option<Arc<PyObject>>, s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let bytes = obj
+        .as_ref()
+        .map(|obj| pickle_dumps(obj))
+        .transpose()
+        .map_err(|e| SerError::custom(e.to_string()))?;
+    serde::Serialize::serialize(&bytes, s)
+}
+
+#[cfg(feature = "python")]
+pub fn deserialize_py_object_optional<'de, D>(d: D) -> Result<Option<Arc<PyObject>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let bytes: Option<Vec<u8>> = serde::Deserialize::deserialize(d)?;
+    bytes
+        .map(|bytes| PyObjectVisitor.visit_byte_buf(bytes).map(Into::into))
+        .transpose()
+}
+
 #[macro_export]
 macro_rules! impl_bincode_py_state_serialization {
     ($ty:ty) => {
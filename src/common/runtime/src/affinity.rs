@@ -0,0 +1,158 @@
+use std::{str::FromStr, sync::LazyLock};
+
+use common_error::DaftError;
+
+/// How the worker threads of the compute runtime are placed on the cores of the machine.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ThreadAffinity {
+    /// Threads are placed by the operating system.
+    #[default]
+    None,
+    /// Each thread is pinned to a core, in the order of the core ids.
+    Cores,
+    /// Each thread is pinned to a core, filling up the cores of one NUMA node before moving on to the next, so that a
+    /// pool with no more threads than a node has cores stays on a single node.
+    Numa,
+}
+
+impl FromStr for ThreadAffinity {
+    type Err = DaftError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self::None),
+            "cores" => Ok(Self::Cores),
+            "numa" => Ok(Self::Numa),
+            other => Err(DaftError::ValueError(format!(
+                "Unknown thread affinity {other}, expected 'none', 'cores', or 'numa'"
+            ))),
+        }
+    }
+}
+
+impl ThreadAffinity {
+    /// The cores that worker threads are pinned to, in the order that they are started in, or None if they shouldn't be
+    /// pinned.
+    pub(crate) fn core_order(self) -> Option<Vec<usize>> {
+        match self {
+            Self::None => None,
+            Self::Cores => PROCESS_CORES.clone(),
+            Self::Numa => numa_core_order().or_else(|| PROCESS_CORES.clone()),
+        }
+    }
+}
+
+// Read once, since threads started later may already be pinned to a single core.
+static PROCESS_CORES: LazyLock<Option<Vec<usize>>> = LazyLock::new(available_cores);
+
+/// The ids of the cores that the current thread may run on.
+#[cfg(target_os = "linux")]
+fn available_cores() -> Option<Vec<usize>> {
+    // safety: `cpu_set_t` is plain data, which `sched_getaffinity` fills in for the current thread.
+    unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        if libc::sched_getaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &mut set) != 0 {
+            log::warn!(
+                "Failed to get the cores of the process, so threads won't be pinned: {}",
+                std::io::Error::last_os_error()
+            );
+            return None;
+        }
+        let cores = (0..libc::CPU_SETSIZE as usize)
+            .filter(|core| libc::CPU_ISSET(*core, &set))
+            .collect::<Vec<_>>();
+        (!cores.is_empty()).then_some(cores)
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn available_cores() -> Option<Vec<usize>> {
+    log::warn!("Pinning threads to cores is only supported on Linux");
+    None
+}
+
+/// The cores of the process, grouped by the NUMA node they belong to.
+fn numa_core_order() -> Option<Vec<usize>> {
+    let process_cores = PROCESS_CORES.as_ref()?;
+    let mut nodes = std::fs::read_dir("/sys/devices/system/node")
+        .ok()?
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let node = entry
+                .file_name()
+                .to_str()?
+                .strip_prefix("node")?
+                .parse::<usize>()
+                .ok()?;
+            let cpu_list = std::fs::read_to_string(entry.path().join("cpulist")).ok()?;
+            Some((node, parse_cpu_list(&cpu_list)?))
+        })
+        .collect::<Vec<_>>();
+    nodes.sort_by_key(|(node, _)| *node);
+    let cores = nodes
+        .into_iter()
+        .flat_map(|(_, cores)| cores)
+        .filter(|core| process_cores.contains(core))
+        .collect::<Vec<_>>();
+    (!cores.is_empty()).then_some(cores)
+}
+
+/// Parses a list of cores in the format used by sysfs, e.g. `0-3,8,10-11`.
+fn parse_cpu_list(cpu_list: &str) -> Option<Vec<usize>> {
+    let cpu_list = cpu_list.trim();
+    if cpu_list.is_empty() {
+        return Some(vec![]);
+    }
+    let mut cores = vec![];
+    for range in cpu_list.split(',') {
+        match range.split_once('-') {
+            Some((start, end)) => cores.extend(start.parse::<usize>().ok()?..=end.parse().ok()?),
+            None => cores.push(range.parse().ok()?),
+        }
+    }
+    Some(cores)
+}
+
+/// Pins the current thread to the given core.
+#[cfg(target_os = "linux")]
+pub(crate) fn pin_current_thread(core: usize) {
+    // safety: `cpu_set_t` is plain data, which `sched_setaffinity` only reads.
+    unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        libc::CPU_SET(core, &mut set);
+        if libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set) != 0 {
+            log::warn!(
+                "Failed to pin thread to core {core}: {}",
+                std::io::Error::last_os_error()
+            );
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn pin_current_thread(_core: usize) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cpu_list() {
+        assert_eq!(
+            parse_cpu_list("0-3,8,10-11\n"),
+            Some(vec![0, 1, 2, 3, 8, 10, 11])
+        );
+        assert_eq!(parse_cpu_list("5"), Some(vec![5]));
+        assert_eq!(parse_cpu_list(""), Some(vec![]));
+        assert_eq!(parse_cpu_list("0-x"), None);
+    }
+
+    #[test]
+    fn test_parse_thread_affinity() {
+        assert_eq!(
+            "numa".parse::<ThreadAffinity>().unwrap(),
+            ThreadAffinity::Numa
+        );
+        assert!("sockets".parse::<ThreadAffinity>().is_err());
+    }
+}
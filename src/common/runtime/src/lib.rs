@@ -1,14 +1,18 @@
+mod affinity;
+
 use std::{
+    collections::HashMap,
     future::Future,
     panic::AssertUnwindSafe,
     pin::Pin,
     sync::{
         atomic::{AtomicUsize, Ordering},
-        Arc, LazyLock, OnceLock,
+        Arc, LazyLock, Mutex, OnceLock,
     },
     task::{Context, Poll},
 };
 
+pub use affinity::ThreadAffinity;
 use common_error::{DaftError, DaftResult};
 use futures::FutureExt;
 use tokio::{
@@ -33,9 +37,62 @@ pub fn set_compute_runtime_num_worker_threads(num_threads: usize) -> DaftResult<
         })
 }
 
-static THREADED_IO_RUNTIME: OnceLock<RuntimeRef> = OnceLock::new();
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+struct ComputeRuntimeSettings {
+    // 0 uses the default number of threads.
+    num_worker_threads: usize,
+    affinity: ThreadAffinity,
+}
+
+#[derive(Default)]
+struct Runtimes {
+    compute_settings: ComputeRuntimeSettings,
+    // 0 uses the default number of threads.
+    io_num_worker_threads: usize,
+    // Runtimes are kept once they are built, since dropping one from an async context panics. This also lets queries
+    // that switch back to earlier settings reuse their threads.
+    compute: HashMap<ComputeRuntimeSettings, RuntimeRef>,
+    threaded_io: HashMap<usize, RuntimeRef>,
+}
+
+impl Runtimes {
+    fn compute_settings(&self) -> ComputeRuntimeSettings {
+        ComputeRuntimeSettings {
+            num_worker_threads: match self.compute_settings.num_worker_threads {
+                0 => get_or_init_compute_runtime_num_worker_threads(),
+                n => n,
+            },
+            affinity: self.compute_settings.affinity,
+        }
+    }
+
+    fn io_num_worker_threads(&self) -> usize {
+        match self.io_num_worker_threads {
+            0 => *THREADED_IO_RUNTIME_NUM_WORKER_THREADS,
+            n => n,
+        }
+    }
+}
+
+static RUNTIMES: LazyLock<Mutex<Runtimes>> = LazyLock::new(Default::default);
 static SINGLE_THREADED_IO_RUNTIME: OnceLock<RuntimeRef> = OnceLock::new();
-static COMPUTE_RUNTIME: OnceLock<RuntimeRef> = OnceLock::new();
+
+/// Configures the compute runtime that [`get_compute_runtime`] returns from now on.
+///
+/// The runtime has `num_worker_threads` threads placed according to `affinity`, where a `num_worker_threads` of 0 uses
+/// the default number of threads. Tasks already spawned on the previous compute runtime keep running on it.
+pub fn configure_compute_runtime(num_worker_threads: usize, affinity: ThreadAffinity) {
+    RUNTIMES.lock().unwrap().compute_settings = ComputeRuntimeSettings {
+        num_worker_threads,
+        affinity,
+    };
+}
+
+/// Configures the multi-threaded IO runtime that [`get_io_runtime`] returns from now on, with `num_worker_threads`
+/// threads. A `num_worker_threads` of 0 uses the default number of threads.
+pub fn configure_io_runtime(num_worker_threads: usize) {
+    RUNTIMES.lock().unwrap().io_num_worker_threads = num_worker_threads;
+}
 
 pub type RuntimeRef = Arc<Runtime>;
 
@@ -151,11 +208,19 @@ impl Runtime {
     }
 }
 
-fn init_compute_runtime(num_worker_threads: usize) -> RuntimeRef {
+fn init_compute_runtime(settings: ComputeRuntimeSettings) -> RuntimeRef {
+    let core_order = settings.affinity.core_order();
     std::thread::spawn(move || {
         let mut builder = tokio::runtime::Builder::new_multi_thread();
+        if let Some(core_order) = core_order {
+            let next_thread = AtomicUsize::new(0);
+            builder.on_thread_start(move || {
+                let thread = next_thread.fetch_add(1, Ordering::Relaxed);
+                affinity::pin_current_thread(core_order[thread % core_order.len()]);
+            });
+        }
         builder
-            .worker_threads(num_worker_threads)
+            .worker_threads(settings.num_worker_threads)
             .enable_all()
             .thread_name_fn(move || {
                 static COMPUTE_THREAD_ATOMIC_ID: AtomicUsize = AtomicUsize::new(0);
@@ -169,15 +234,11 @@ fn init_compute_runtime(num_worker_threads: usize) -> RuntimeRef {
     .unwrap()
 }
 
-fn init_io_runtime(multi_thread: bool) -> RuntimeRef {
+fn init_io_runtime(num_worker_threads: usize) -> RuntimeRef {
     std::thread::spawn(move || {
         let mut builder = tokio::runtime::Builder::new_multi_thread();
         builder
-            .worker_threads(if multi_thread {
-                *THREADED_IO_RUNTIME_NUM_WORKER_THREADS
-            } else {
-                1
-            })
+            .worker_threads(num_worker_threads)
             .enable_all()
             .thread_name_fn(move || {
                 static COMPUTE_THREAD_ATOMIC_ID: AtomicUsize = AtomicUsize::new(0);
@@ -191,19 +252,27 @@ fn init_io_runtime(multi_thread: bool) -> RuntimeRef {
 }
 
 pub fn get_compute_runtime() -> RuntimeRef {
-    COMPUTE_RUNTIME
-        .get_or_init(|| init_compute_runtime(get_or_init_compute_runtime_num_worker_threads()))
+    let mut runtimes = RUNTIMES.lock().unwrap();
+    let settings = runtimes.compute_settings();
+    runtimes
+        .compute
+        .entry(settings)
+        .or_insert_with(|| init_compute_runtime(settings))
         .clone()
 }
 
 pub fn get_io_runtime(multi_thread: bool) -> RuntimeRef {
     if !multi_thread {
         SINGLE_THREADED_IO_RUNTIME
-            .get_or_init(|| init_io_runtime(false))
+            .get_or_init(|| init_io_runtime(1))
             .clone()
     } else {
-        THREADED_IO_RUNTIME
-            .get_or_init(|| init_io_runtime(true))
+        let mut runtimes = RUNTIMES.lock().unwrap();
+        let num_worker_threads = runtimes.io_num_worker_threads();
+        runtimes
+            .threaded_io
+            .entry(num_worker_threads)
+            .or_insert_with(|| init_io_runtime(num_worker_threads))
             .clone()
     }
 }
@@ -214,7 +283,9 @@ pub fn get_io_pool_num_threads() -> Option<usize> {
         Ok(handle) => {
             match handle.runtime_flavor() {
                 RuntimeFlavor::CurrentThread => Some(1),
-                RuntimeFlavor::MultiThread => Some(*THREADED_IO_RUNTIME_NUM_WORKER_THREADS),
+                RuntimeFlavor::MultiThread => {
+                    Some(RUNTIMES.lock().unwrap().io_num_worker_threads())
+                }
                 // RuntimeFlavor is #non_exhaustive, so we default to 1 here to be conservative
                 _ => Some(1),
             }
@@ -224,7 +295,11 @@ pub fn get_io_pool_num_threads() -> Option<usize> {
 }
 
 pub fn get_compute_pool_num_threads() -> usize {
-    get_or_init_compute_runtime_num_worker_threads()
+    RUNTIMES
+        .lock()
+        .unwrap()
+        .compute_settings()
+        .num_worker_threads
 }
 
 mod tests {
@@ -8,6 +8,7 @@ mod pushdowns;
 pub mod python;
 mod scan_operator;
 mod scan_task;
+mod statistics;
 pub mod test;
 
 use std::{fmt::Debug, hash::Hash, sync::Arc};
@@ -15,11 +16,12 @@ use std::{fmt::Debug, hash::Hash, sync::Arc};
 use daft_schema::schema::SchemaRef;
 pub use expr_rewriter::{rewrite_predicate_for_partitioning, PredicateGroups};
 pub use partitioning::{PartitionField, PartitionTransform};
-pub use pushdowns::Pushdowns;
+pub use pushdowns::{Pushdowns, SamplePushdown};
 #[cfg(feature = "python")]
 pub use python::register_modules;
 pub use scan_operator::{ScanOperator, ScanOperatorRef};
 pub use scan_task::{ScanTaskLike, ScanTaskLikeRef, SPLIT_AND_MERGE_PASS};
+pub use statistics::{ColumnStatistics, SourceStatistics};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ScanState {
@@ -51,6 +53,8 @@ pub struct PhysicalScanInfo {
     pub source_schema: SchemaRef,
     pub partitioning_keys: Vec<PartitionField>,
     pub pushdowns: Pushdowns,
+    /// Statistics collected for this source by analyzing it, if any.
+    pub statistics: Option<Arc<SourceStatistics>>,
 }
 
 impl PhysicalScanInfo {
@@ -66,6 +70,7 @@ impl PhysicalScanInfo {
             source_schema,
             partitioning_keys,
             pushdowns,
+            statistics: None,
         }
     }
 
@@ -76,6 +81,15 @@ impl PhysicalScanInfo {
             source_schema: self.source_schema.clone(),
             partitioning_keys: self.partitioning_keys.clone(),
             pushdowns,
+            statistics: self.statistics.clone(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_statistics(&self, statistics: Option<Arc<SourceStatistics>>) -> Self {
+        Self {
+            statistics,
+            ..self.clone()
         }
     }
 }
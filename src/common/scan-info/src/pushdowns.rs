@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use common_display::DisplayAs;
+use common_hashable_float_wrapper::FloatWrapper;
 use daft_dsl::{estimated_selectivity, ExprRef};
 use daft_schema::schema::Schema;
 use serde::{Deserialize, Serialize};
@@ -15,11 +16,30 @@ pub struct Pushdowns {
     pub columns: Option<Arc<Vec<String>>>,
     /// Optional number of rows to read.
     pub limit: Option<usize>,
+    /// Optional sample to take of the rows that pass the filters.
+    pub sample: Option<SamplePushdown>,
+}
+
+/// A sample of `fraction` of the rows of each scan task, taken without replacement.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct SamplePushdown {
+    pub fraction: FloatWrapper<f64>,
+    pub seed: Option<u64>,
+}
+
+impl SamplePushdown {
+    #[must_use]
+    pub fn new(fraction: f64, seed: Option<u64>) -> Self {
+        Self {
+            fraction: FloatWrapper(fraction),
+            seed,
+        }
+    }
 }
 
 impl Default for Pushdowns {
     fn default() -> Self {
-        Self::new(None, None, None, None)
+        Self::new(None, None, None, None, None)
     }
 }
 
@@ -30,12 +50,14 @@ impl Pushdowns {
         partition_filters: Option<ExprRef>,
         columns: Option<Arc<Vec<String>>>,
         limit: Option<usize>,
+        sample: Option<SamplePushdown>,
     ) -> Self {
         Self {
             filters,
             partition_filters,
             columns,
             limit,
+            sample,
         }
     }
 
@@ -45,6 +67,7 @@ impl Pushdowns {
             && self.partition_filters.is_none()
             && self.columns.is_none()
             && self.limit.is_none()
+            && self.sample.is_none()
     }
 
     #[must_use]
@@ -54,6 +77,7 @@ impl Pushdowns {
             partition_filters: self.partition_filters.clone(),
             columns: self.columns.clone(),
             limit,
+            sample: self.sample.clone(),
         }
     }
 
@@ -64,6 +88,7 @@ impl Pushdowns {
             partition_filters: self.partition_filters.clone(),
             columns: self.columns.clone(),
             limit: self.limit,
+            sample: self.sample.clone(),
         }
     }
 
@@ -74,6 +99,7 @@ impl Pushdowns {
             partition_filters,
             columns: self.columns.clone(),
             limit: self.limit,
+            sample: self.sample.clone(),
         }
     }
 
@@ -84,6 +110,18 @@ impl Pushdowns {
             partition_filters: self.partition_filters.clone(),
             columns,
             limit: self.limit,
+            sample: self.sample.clone(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_sample(&self, sample: Option<SamplePushdown>) -> Self {
+        Self {
+            filters: self.filters.clone(),
+            partition_filters: self.partition_filters.clone(),
+            columns: self.columns.clone(),
+            limit: self.limit,
+            sample,
         }
     }
 
@@ -102,6 +140,9 @@ impl Pushdowns {
         if let Some(limit) = self.limit {
             res.push(format!("Limit pushdown = {limit}"));
         }
+        if let Some(sample) = &self.sample {
+            res.push(format!("Sample pushdown = {}", sample.fraction.0));
+        }
         res
     }
 
@@ -133,6 +174,9 @@ impl DisplayAs for Pushdowns {
                 if let Some(limit) = self.limit {
                     sub_items.push(format!("limit: {limit}"));
                 }
+                if let Some(sample) = &self.sample {
+                    sub_items.push(format!("sample: {}", sample.fraction.0));
+                }
                 s.push_str(&sub_items.join(", "));
                 s.push('}');
                 s
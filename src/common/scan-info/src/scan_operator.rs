@@ -32,6 +32,18 @@ pub trait ScanOperator: Send + Sync + Debug {
     fn can_absorb_limit(&self) -> bool;
     fn multiline_display(&self) -> Vec<String>;
 
+    /// Whether the scan tasks of this operator carry a sample pushdown through to the reader, which applies it in
+    /// place of the sample operator.
+    fn can_absorb_sample(&self) -> bool {
+        false
+    }
+
+    /// Identifies the version of the data that this operator currently reads, e.g. a table format's snapshot id,
+    /// so that cached plans and results are invalidated when the underlying table changes.
+    fn snapshot_id(&self) -> Option<String> {
+        None
+    }
+
     /// If cfg provided, `to_scan_tasks` should apply the appropriate transformations
     /// (merging, splitting) to the outputted scan tasks
     fn to_scan_tasks(&self, pushdowns: Pushdowns) -> DaftResult<Vec<ScanTaskLikeRef>>;
@@ -0,0 +1,51 @@
+use std::collections::BTreeMap;
+
+use daft_dsl::LiteralValue;
+use serde::{Deserialize, Serialize};
+
+/// Statistics for a single column of an analyzed source.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ColumnStatistics {
+    /// Number of null values in the column.
+    pub null_count: usize,
+    /// Approximate number of distinct non-null values in the column, e.g. from a HyperLogLog sketch.
+    pub approx_distinct_count: usize,
+    /// Minimum non-null value in the column, if the column's type is ordered.
+    pub min: Option<LiteralValue>,
+    /// Maximum non-null value in the column, if the column's type is ordered.
+    pub max: Option<LiteralValue>,
+}
+
+/// Table and column statistics collected for a scanned source, e.g. via `DataFrame.analyze()`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct SourceStatistics {
+    /// Exact number of rows in the source.
+    pub num_rows: usize,
+    /// Statistics for each analyzed column, keyed by column name.
+    pub columns: BTreeMap<String, ColumnStatistics>,
+}
+
+impl SourceStatistics {
+    #[must_use]
+    pub fn new(num_rows: usize, columns: BTreeMap<String, ColumnStatistics>) -> Self {
+        Self { num_rows, columns }
+    }
+
+    /// Fraction of the values in the given column that are null.
+    pub fn null_fraction(&self, column: &str) -> Option<f64> {
+        let stats = self.columns.get(column)?;
+        if self.num_rows == 0 {
+            Some(0.0)
+        } else {
+            Some(stats.null_count as f64 / self.num_rows as f64)
+        }
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        vec![format!(
+            "Analyzed statistics = {{ Num rows = {}, Num columns = {} }}",
+            self.num_rows,
+            self.columns.len()
+        )]
+    }
+}
@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
-use crate::{error::Result, Identifier, Table};
+use crate::{
+    error::{Error, Result},
+    Identifier, Table, TableSource,
+};
 
 /// Catalog implementation reference.
 pub type CatalogRef = Arc<dyn Catalog>;
@@ -10,6 +13,15 @@ pub trait Catalog: Sync + Send + std::fmt::Debug {
     /// Returns the catalog name.
     fn name(&self) -> String;
 
+    /// Creates a table from the source, which for views also writes the rows of the view to the table.
+    fn create_table(&self, ident: &Identifier, _source: &TableSource) -> Result<Box<dyn Table>> {
+        Err(Error::unsupported(format!(
+            "Catalog {} does not support creating table {}.",
+            self.name(),
+            ident
+        )))
+    }
+
     /// Returns the given table if it exists.
     fn get_table(&self, ident: &Identifier) -> Result<Option<Box<dyn Table>>>;
 
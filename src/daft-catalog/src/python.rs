@@ -4,9 +4,25 @@ use std::sync::Arc;
 
 use daft_core::{prelude::SchemaRef, python::PySchema};
 use daft_logical_plan::{LogicalPlanRef, PyLogicalPlanBuilder};
-use pyo3::{exceptions::PyIndexError, intern, prelude::*};
-
-use crate::{error::Result, Catalog, CatalogRef, Identifier, Table, TableRef, TableSource, View};
+use pyo3::{exceptions::PyIndexError, intern, prelude::*, types::PyDict};
+
+use crate::{
+    error::Result, Catalog, CatalogRef, Identifier, Table, TableRef, TableSource, View, WriteMode,
+};
+
+/// Creates a python DataFrame for the logical plan.
+fn py_dataframe(py: Python<'_>, plan: LogicalPlanRef) -> PyResult<Bound<'_, PyAny>> {
+    let builder = PyLogicalPlanBuilder::new(plan.into());
+    // builder = LogicalPlanBuilder.__init__(builder)
+    let builder = py
+        .import(intern!(py, "daft.logical.builder"))?
+        .getattr(intern!(py, "LogicalPlanBuilder"))?
+        .call1((builder,))?;
+    // df = DataFrame.__init__(builder)
+    py.import(intern!(py, "daft.dataframe"))?
+        .getattr(intern!(py, "DataFrame"))?
+        .call1((builder,))
+}
 
 /// PyCatalog implements the Catalog ABC for some Catalog trait impl (rust->py).
 #[pyclass]
@@ -53,6 +69,27 @@ impl Catalog for PyCatalogWrapper {
         })
     }
 
+    fn create_table(&self, ident: &Identifier, source: &TableSource) -> Result<Box<dyn Table>> {
+        Python::with_gil(|py| {
+            // catalog = 'python catalog object'
+            let catalog = self.0.bind(py);
+            // source = Schema._from_pyschema(schema) or DataFrame(view)
+            let source = match source {
+                TableSource::Schema(schema) => py
+                    .import(intern!(py, "daft.logical.schema"))?
+                    .getattr(intern!(py, "Schema"))?
+                    .call_method1(
+                        intern!(py, "_from_pyschema"),
+                        (PySchema::from(schema.clone()),),
+                    )?,
+                TableSource::View(plan) => py_dataframe(py, plan.clone())?,
+            };
+            // table = catalog.create_table(ident, source)
+            let table = catalog.call_method1("create_table", (ident.to_string(), source))?;
+            Ok(Box::new(PyTableWrapper::from(table.unbind())) as Box<dyn Table>)
+        })
+    }
+
     fn get_table(&self, ident: &Identifier) -> Result<Option<Box<dyn Table>>> {
         Python::with_gil(|py| {
             // catalog = 'python catalog object'
@@ -179,19 +216,8 @@ impl PyTable {
 
     /// Creates a python DataFrame for this table, likely easier with python-side helpers.
     fn read(&self, py: Python<'_>) -> PyResult<PyObject> {
-        // builder = 'compiled plan'
-        let builder = self.0.get_logical_plan()?;
-        let builder = PyLogicalPlanBuilder::new(builder.into());
-        // builder = LogicalPlanBuilder.__init__(builder)
-        let builder = py
-            .import(intern!(py, "daft.logical.builder"))?
-            .getattr(intern!(py, "LogicalPlanBuilder"))?
-            .call1((builder,))?;
-        // df = DataFrame.__init__(builder)
-        let df = py
-            .import(intern!(py, "daft.dataframe"))?
-            .getattr(intern!(py, "DataFrame"))?
-            .call1((builder,))?;
+        // df = 'compiled plan' as DataFrame
+        let df = py_dataframe(py, self.0.get_logical_plan()?)?;
         // df as object
         df.extract()
     }
@@ -235,6 +261,19 @@ impl Table for PyTableWrapper {
         })
     }
 
+    fn write(&self, plan: LogicalPlanRef, mode: WriteMode) -> Result<()> {
+        Python::with_gil(|py| {
+            // table = 'python table object'
+            let table = self.0.bind(py);
+            // table.write(df, mode=mode)
+            let df = py_dataframe(py, plan)?;
+            let kwargs = PyDict::new(py);
+            kwargs.set_item("mode", mode.as_str())?;
+            table.call_method("write", (df,), Some(&kwargs))?;
+            Ok(())
+        })
+    }
+
     fn to_py(&self, py: Python<'_>) -> PyResult<PyObject> {
         self.0.extract(py)
     }
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use daft_core::prelude::SchemaRef;
 use daft_logical_plan::{LogicalPlanBuilder, LogicalPlanRef};
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 
 /// Table implementation reference.
 pub type TableRef = Arc<dyn Table>;
@@ -35,6 +35,24 @@ impl From<LogicalPlanBuilder> for TableSource {
     }
 }
 
+/// How writes to a table treat the rows that it already has.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteMode {
+    /// INSERT INTO <table> adds rows to the table.
+    Append,
+    /// INSERT OVERWRITE <table> replaces the rows of the table.
+    Overwrite,
+}
+
+impl WriteMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Append => "append",
+            Self::Overwrite => "overwrite",
+        }
+    }
+}
+
 /// TODO consider moving out to daft-table, but this isn't necessary or helpful right now.
 pub trait Table: Sync + Send + std::fmt::Debug {
     /// Returns the table schema
@@ -43,6 +61,11 @@ pub trait Table: Sync + Send + std::fmt::Debug {
     /// Returns a logical plan for this table.
     fn get_logical_plan(&self) -> Result<LogicalPlanRef>;
 
+    /// Writes the rows of the plan to this table with the table's own format and options.
+    fn write(&self, _plan: LogicalPlanRef, _mode: WriteMode) -> Result<()> {
+        Err(Error::unsupported("This table does not support writes."))
+    }
+
     /// Leverage dynamic dispatch to return the inner object for a PyTableImpl (generics?)
     #[cfg(feature = "python")]
     fn to_py(&self, _: pyo3::Python<'_>) -> pyo3::PyResult<pyo3::PyObject> {
@@ -1,5 +1,9 @@
+#![allow(clippy::useless_conversion)]
+
+use std::path::PathBuf;
+
 use clap::{Parser, Subcommand};
-use pyo3::prelude::*;
+use pyo3::{exceptions::PyIOError, prelude::*};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -12,10 +16,32 @@ struct Cli {
 enum Commands {
     /// Start the Daft dashboard server
     Dashboard,
+    /// Start an interactive SQL shell
+    Sql,
+    /// Run a SQL query, and print its results or write them to a file
+    Query {
+        /// The SQL query to run
+        #[arg(required_unless_present = "file", conflicts_with = "file")]
+        sql: Option<String>,
+        /// Read the SQL query from a file
+        #[arg(short, long)]
+        file: Option<PathBuf>,
+        /// Write the results to a Parquet or CSV file, depending on its extension
+        #[arg(short, long)]
+        output: Option<String>,
+        /// The number of rows to print, when the results aren't written to a file
+        #[arg(short = 'n', long, default_value_t = 20)]
+        limit: usize,
+    },
+    /// Print the schema of a Parquet, CSV or JSON file, which may be remote or a glob
+    Schema {
+        /// The path of the file
+        path: String,
+    },
 }
 
 #[pyfunction]
-pub fn cli(py: Python, args: Vec<String>) {
+pub fn cli(py: Python, args: Vec<String>) -> PyResult<()> {
     let cli = Cli::parse_from(args);
     match cli.command {
         Commands::Dashboard => {
@@ -37,7 +63,31 @@ pub fn cli(py: Python, args: Vec<String>) {
                 }
             }
         }
+        Commands::Sql => {
+            py.import("daft.cli")?.call_method0("run_repl")?;
+        }
+        Commands::Query {
+            sql,
+            file,
+            output,
+            limit,
+        } => {
+            let sql = match (sql, file) {
+                (Some(sql), _) => sql,
+                (None, Some(file)) => std::fs::read_to_string(&file).map_err(|e| {
+                    PyIOError::new_err(format!("Failed to read {}: {e}", file.display()))
+                })?,
+                (None, None) => unreachable!("clap requires a query or a file"),
+            };
+            py.import("daft.cli")?
+                .call_method1("run_query", (sql, output, limit))?;
+        }
+        Commands::Schema { path } => {
+            py.import("daft.cli")?
+                .call_method1("print_schema", (path,))?;
+        }
     }
+    Ok(())
 }
 
 pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
@@ -283,9 +283,34 @@ impl SparkConnectService for DaftSparkConnectService {
     #[tracing::instrument(skip_all)]
     async fn interrupt(
         &self,
-        _request: Request<InterruptRequest>,
+        request: Request<InterruptRequest>,
     ) -> Result<Response<InterruptResponse>, Status> {
-        not_yet_implemented!("interrupt operation")
+        use spark_connect::interrupt_request::{Interrupt, InterruptType};
+
+        let request = request.into_inner();
+        let session = self.get_session(&request.session_id)?;
+
+        let interrupted_ids = match InterruptType::try_from(request.interrupt_type) {
+            Ok(InterruptType::All) => session.interrupt_operations(|_| true),
+            Ok(InterruptType::OperationId) => {
+                let Some(Interrupt::OperationId(operation_id)) = request.interrupt else {
+                    invalid_argument_err!(
+                        "operation_id is required to interrupt an operation by id"
+                    );
+                };
+                session.interrupt_operations(|id| id == operation_id)
+            }
+            Ok(InterruptType::Tag) => not_yet_implemented!("interrupt by operation tag"),
+            Ok(InterruptType::Unspecified) | Err(_) => {
+                invalid_argument_err!("Invalid interrupt type: {}", request.interrupt_type)
+            }
+        };
+
+        Ok(Response::new(InterruptResponse {
+            session_id: session.client_side_session_id().to_string(),
+            server_side_session_id: session.server_side_session_id().to_string(),
+            interrupted_ids,
+        }))
     }
 
     #[tracing::instrument(skip_all)]
@@ -109,6 +109,7 @@ fn type_to_string(dtype: &DataType) -> String {
         DataType::Struct(_) => "struct",
         DataType::Map { .. } => "map",
         DataType::Extension(_, _, _) => "daft.extension",
+        DataType::Categorical(_) => "daft.categorical",
         DataType::Embedding(_, _) => "daft.embedding",
         DataType::Image(_) => "daft.image",
         DataType::FixedShapeImage(_, _, _) => "daft.fixed_shape_image",
@@ -28,6 +28,9 @@ pub enum ConnectError {
     #[snafu(display("Internal error: {msg}"))]
     InternalError { msg: String },
 
+    #[snafu(display("Operation {operation_id} was interrupted"))]
+    Interrupted { operation_id: String },
+
     #[snafu(whatever, display("{message}"))]
     Whatever {
         message: String,
@@ -96,6 +99,7 @@ impl From<ConnectError> for Status {
     fn from(value: ConnectError) -> Self {
         match value {
             ConnectError::TonicError { source } => source,
+            ConnectError::Interrupted { .. } => Self::cancelled(value.to_string()),
             _ => Self::internal(value.to_string()),
         }
     }
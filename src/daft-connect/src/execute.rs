@@ -58,6 +58,7 @@ impl ConnectSession {
 
         let (tx, rx) = tokio::sync::mpsc::channel::<ConnectResult<ExecutePlanResponse>>(1);
         let this = self.clone();
+        let operation = self.start_operation(&res.operation_id);
         self.compute_runtime.runtime.spawn(async move {
             let execution_fut = async {
                 let translator = SparkAnalyzer::new(&this);
@@ -90,7 +91,7 @@ impl ConnectSession {
                     }
                 }
             };
-            if let Err(e) = execution_fut.await {
+            if let Err(e) = operation.run(execution_fut).await {
                 let _ = tx.send(Err(e)).await;
             }
         });
@@ -141,6 +142,7 @@ impl ConnectSession {
         let this = self.clone();
         let mode = SaveMode::try_from(operation.mode)
             .map_err(|_| Status::internal("invalid write mode"))?;
+        let running = self.start_operation(&res.operation_id);
         self.compute_runtime.runtime.spawn(async move {
             let result = async {
                 check_write_operation(&operation)?;
@@ -183,8 +185,15 @@ impl ConnectSession {
                     )
                 })?;
 
-                let plan =
-                    plan.table_write(&path, write_mode, file_format, None, None, Some(io_config))?;
+                let plan = plan.table_write(
+                    &path,
+                    write_mode,
+                    file_format,
+                    None,
+                    None,
+                    Some(io_config),
+                    None,
+                )?;
 
                 let mut result_stream = this.run_query(plan).await?;
 
@@ -198,7 +207,7 @@ impl ConnectSession {
                 Ok(())
             };
 
-            if let Err(e) = result.await {
+            if let Err(e) = running.run(result).await {
                 let _ = tx.send(Err(e)).await;
             }
         });
@@ -303,6 +312,7 @@ impl ConnectSession {
         let (tx, rx) = tokio::sync::mpsc::channel::<ConnectResult<ExecutePlanResponse>>(1);
 
         let this = self.clone();
+        let operation = self.start_operation(&res.operation_id);
 
         tokio::spawn(async move {
             let execution_fut = async {
@@ -319,7 +329,7 @@ impl ConnectSession {
                 }
                 Ok(())
             };
-            if let Err(e) = execution_fut.await {
+            if let Err(e) = operation.run(execution_fut).await {
                 let _ = tx.send(Err(e)).await;
             }
         });
@@ -55,10 +55,22 @@ impl FunctionModule for StringFunctions {
         parent.add_fn("regexp_count", TODO_FUNCTION);
         parent.add_fn("regexp_extract", RegexpExtract);
         parent.add_fn("regexp_extract_all", RegexpExtractAll);
-        parent.add_fn("regexp_replace", Utf8Replace { regex: true });
+        parent.add_fn(
+            "regexp_replace",
+            Utf8Replace {
+                regex: true,
+                max_replacements: None,
+            },
+        );
         parent.add_fn("regexp_substr", TODO_FUNCTION);
         parent.add_fn("regexp_instr", TODO_FUNCTION);
-        parent.add_fn("replace", Utf8Replace { regex: false });
+        parent.add_fn(
+            "replace",
+            Utf8Replace {
+                regex: false,
+                max_replacements: None,
+            },
+        );
         parent.add_fn("right", Utf8Right {});
         parent.add_fn("ucase", TODO_FUNCTION);
         parent.add_fn("unbase64", TODO_FUNCTION);
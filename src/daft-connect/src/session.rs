@@ -6,10 +6,17 @@ use std::{
 use common_error::DaftResult;
 use common_runtime::RuntimeRef;
 use daft_context::get_context;
-use daft_io::{AzureConfig, GCSConfig, HTTPConfig, IOConfig, S3Config};
+use daft_io::{
+    AzureConfig, DiskCacheConfig, GCSConfig, HDFSConfig, HTTPConfig, IOConfig, RateLimitConfig,
+    RetryConfig, S3Config, SFTPConfig,
+};
 use daft_session::Session;
+use dashmap::DashMap;
+use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
+use crate::error::{ConnectError, ConnectResult};
+
 #[derive(Clone)]
 pub struct ConnectSession {
     /// so order is preserved, and so we can efficiently do a prefix search
@@ -21,6 +28,36 @@ pub struct ConnectSession {
     server_side_session_id: String,
     pub(crate) compute_runtime: RuntimeRef,
     pub session: Arc<RwLock<Session>>,
+    /// The operations that are running in the session, by operation id, so that they can be interrupted
+    operations: Arc<DashMap<String, CancellationToken>>,
+}
+
+/// An operation that is running in a session, which is unregistered from it when dropped.
+pub struct RunningOperation {
+    id: String,
+    cancel: CancellationToken,
+    operations: Arc<DashMap<String, CancellationToken>>,
+}
+
+impl RunningOperation {
+    /// Runs the operation until it finishes or is interrupted. Dropping the future of an interrupted operation stops
+    /// the query that it runs.
+    pub async fn run<T>(
+        self,
+        operation: impl std::future::Future<Output = ConnectResult<T>>,
+    ) -> ConnectResult<T> {
+        tokio::select! {
+            biased;
+            () = self.cancel.cancelled() => Err(ConnectError::Interrupted { operation_id: self.id.clone() }),
+            result = operation => result,
+        }
+    }
+}
+
+impl Drop for RunningOperation {
+    fn drop(&mut self) {
+        self.operations.remove(&self.id);
+    }
 }
 
 impl ConnectSession {
@@ -44,9 +81,34 @@ impl ConnectSession {
             server_side_session_id,
             compute_runtime,
             session,
+            operations: Default::default(),
+        }
+    }
+
+    /// Registers an operation as running, until the returned [`RunningOperation`] is dropped.
+    pub fn start_operation(&self, operation_id: &str) -> RunningOperation {
+        let cancel = CancellationToken::new();
+        self.operations
+            .insert(operation_id.to_string(), cancel.clone());
+        RunningOperation {
+            id: operation_id.to_string(),
+            cancel,
+            operations: self.operations.clone(),
         }
     }
 
+    /// Interrupts the running operations that match `predicate`, and returns their ids.
+    pub fn interrupt_operations(&self, predicate: impl Fn(&str) -> bool) -> Vec<String> {
+        self.operations
+            .iter()
+            .filter(|op| predicate(op.key()))
+            .map(|op| {
+                op.value().cancel();
+                op.key().clone()
+            })
+            .collect()
+    }
+
     pub fn client_side_session_id(&self) -> &str {
         &self.id
     }
@@ -71,18 +133,33 @@ impl ConnectSession {
             mut azure,
             mut gcs,
             mut http,
+            mut hdfs,
+            mut sftp,
+            mut retry,
+            mut disk_cache,
+            mut rate_limit,
         } = get_context().io_config();
 
         self.s3_config_helper(&mut s3)?;
         self.azure_config_helper(&mut azure)?;
         self.gcs_config_helper(&mut gcs)?;
         self.http_config_helper(&mut http)?;
+        self.hdfs_config_helper(&mut hdfs)?;
+        self.sftp_config_helper(&mut sftp)?;
+        self.retry_config_helper(&mut retry)?;
+        self.disk_cache_config_helper(&mut disk_cache)?;
+        self.rate_limit_config_helper(&mut rate_limit)?;
 
         Ok(IOConfig {
             s3,
             azure,
             gcs,
             http,
+            hdfs,
+            sftp,
+            retry,
+            disk_cache,
+            rate_limit,
         })
     }
 
@@ -187,6 +264,7 @@ impl ConnectSession {
         set_opt_str!(tenant_id);
         set_opt_str!(client_id);
         set_opt_from_config!(client_secret);
+        set_from_config!(use_managed_identity);
         set_from_config!(use_fabric_endpoint);
         set_from_config!(anonymous);
         set_opt_str!(endpoint_url);
@@ -262,4 +340,168 @@ impl ConnectSession {
 
         Ok(())
     }
+
+    fn hdfs_config_helper(&self, hdfs_conf: &mut HDFSConfig) -> DaftResult<()> {
+        macro_rules! set_opt_str {
+            ($field:ident) => {
+                if let Some(value) = self
+                    .config_values
+                    .get(concat!("daft.io.hdfs.", stringify!($field)))
+                    .map(|s| s.to_string())
+                {
+                    hdfs_conf.$field = Some(value);
+                }
+            };
+        }
+        macro_rules! set_from_config {
+            ($field:ident) => {
+                if let Some(value) = self
+                    .config_values
+                    .get(concat!("daft.io.hdfs.", stringify!($field)))
+                    .map(|s| s.parse().ok())
+                    .flatten()
+                {
+                    hdfs_conf.$field = value;
+                }
+            };
+        }
+
+        set_opt_str!(webhdfs_address);
+        set_from_config!(webhdfs_port);
+        set_from_config!(use_https);
+        set_opt_str!(user);
+
+        if let Some(value) = self
+            .config_values
+            .get("daft.io.hdfs.delegation_token")
+            .map(|s| s.to_string())
+        {
+            hdfs_conf.delegation_token = Some(value.into());
+        }
+
+        Ok(())
+    }
+
+    fn sftp_config_helper(&self, sftp_conf: &mut SFTPConfig) -> DaftResult<()> {
+        macro_rules! set_opt_str {
+            ($field:ident) => {
+                if let Some(value) = self
+                    .config_values
+                    .get(concat!("daft.io.sftp.", stringify!($field)))
+                    .map(|s| s.to_string())
+                {
+                    sftp_conf.$field = Some(value);
+                }
+            };
+        }
+        macro_rules! set_opt_secret {
+            ($field:ident) => {
+                if let Some(value) = self
+                    .config_values
+                    .get(concat!("daft.io.sftp.", stringify!($field)))
+                    .map(|s| s.to_string())
+                {
+                    sftp_conf.$field = Some(value.into());
+                }
+            };
+        }
+
+        set_opt_str!(user);
+        set_opt_secret!(password);
+        set_opt_secret!(private_key);
+        set_opt_str!(private_key_path);
+        set_opt_secret!(private_key_passphrase);
+        set_opt_str!(known_hosts_path);
+
+        if let Some(value) = self
+            .config_values
+            .get("daft.io.sftp.skip_host_key_check")
+            .and_then(|s| s.parse().ok())
+        {
+            sftp_conf.skip_host_key_check = value;
+        }
+
+        Ok(())
+    }
+
+    fn retry_config_helper(&self, retry_conf: &mut RetryConfig) -> DaftResult<()> {
+        macro_rules! set_from_config {
+            ($field:ident) => {
+                if let Some(value) = self
+                    .config_values
+                    .get(concat!("daft.io.retry.", stringify!($field)))
+                    .map(|s| s.parse().ok())
+                    .flatten()
+                {
+                    retry_conf.$field = value;
+                }
+            };
+        }
+        macro_rules! set_opt_from_config {
+            ($field:ident) => {
+                if let Some(value) = self
+                    .config_values
+                    .get(concat!("daft.io.retry.", stringify!($field)))
+                    .map(|s| s.parse().ok())
+                {
+                    retry_conf.$field = value;
+                }
+            };
+        }
+
+        set_from_config!(max_attempts);
+        set_from_config!(initial_backoff_ms);
+        set_from_config!(max_backoff_ms);
+        set_from_config!(jitter);
+        set_opt_from_config!(retry_budget);
+
+        Ok(())
+    }
+
+    fn disk_cache_config_helper(&self, disk_cache_conf: &mut DiskCacheConfig) -> DaftResult<()> {
+        macro_rules! set_from_config {
+            ($field:ident) => {
+                if let Some(value) = self
+                    .config_values
+                    .get(concat!("daft.io.disk_cache.", stringify!($field)))
+                    .map(|s| s.parse().ok())
+                    .flatten()
+                {
+                    disk_cache_conf.$field = value;
+                }
+            };
+        }
+
+        set_from_config!(enabled);
+        set_from_config!(max_size_bytes);
+
+        if let Some(value) = self
+            .config_values
+            .get("daft.io.disk_cache.directory")
+            .map(|s| s.to_string())
+        {
+            disk_cache_conf.directory = Some(value);
+        }
+
+        Ok(())
+    }
+
+    fn rate_limit_config_helper(&self, rate_limit_conf: &mut RateLimitConfig) -> DaftResult<()> {
+        macro_rules! set_opt_from_config {
+            ($field:ident) => {
+                if let Some(value) = self
+                    .config_values
+                    .get(concat!("daft.io.rate_limit.", stringify!($field)))
+                    .map(|s| s.parse().ok())
+                {
+                    rate_limit_conf.$field = value;
+                }
+            };
+        }
+
+        set_opt_from_config!(max_concurrent_requests);
+        set_opt_from_config!(max_requests_per_second);
+
+        Ok(())
+    }
 }
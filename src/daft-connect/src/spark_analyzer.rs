@@ -427,7 +427,7 @@ impl SparkAnalyzer<'_> {
                 let path = paths.first().unwrap();
                 check_unused_options(format, &options)?;
 
-                delta_scan(path, Some(io_config), true)?
+                delta_scan(path, Some(io_config), true, None)?
             }
 
             other => invalid_argument_err!("Unsupported format: {other};"),
@@ -70,6 +70,46 @@ where
     }
 }
 
+/// Helper function to perform a decimal operation which is undefined for some operands, e.g. a
+/// division by zero, or overflows. Such results are null.
+fn decimal_checked_helper<F>(
+    lhs: &Decimal128Array,
+    rhs: &Decimal128Array,
+    operation: F,
+) -> DaftResult<Decimal128Array>
+where
+    F: Fn(i128, i128) -> Option<i128>,
+{
+    let apply = |l: Option<&i128>, r: Option<&i128>| l.zip(r).and_then(|(l, r)| operation(*l, *r));
+    match (lhs.len(), rhs.len()) {
+        (a, b) if a == b => Ok(Decimal128Array::from_iter(
+            lhs.field.clone(),
+            lhs.as_arrow()
+                .iter()
+                .zip(rhs.as_arrow().iter())
+                .map(|(l, r)| apply(l, r)),
+        )),
+        // broadcast right path
+        (_, 1) => {
+            let rhs = rhs.get(0);
+            Ok(Decimal128Array::from_iter(
+                lhs.field.clone(),
+                lhs.as_arrow().iter().map(|l| apply(l, rhs.as_ref())),
+            ))
+        }
+        (1, _) => {
+            let lhs_value = lhs.get(0);
+            Ok(Decimal128Array::from_iter(
+                lhs.field.clone(),
+                rhs.as_arrow().iter().map(|r| apply(lhs_value.as_ref(), r)),
+            ))
+        }
+        (a, b) => Err(DaftError::ValueError(format!(
+            "Cannot apply operation on arrays of different lengths: {a} vs {b}"
+        ))),
+    }
+}
+
 impl<T> Add for &DataArray<T>
 where
     T: DaftNumericType,
@@ -290,58 +330,16 @@ impl Div for &Decimal128Array {
             unreachable!("This should always be a Decimal128")
         };
         let scale = 10i128.pow(*s as u32);
+        decimal_checked_helper(self, rhs, |l, r| l.checked_mul(scale)?.checked_div(r))
+    }
+}
 
-        if rhs.data().null_count() == 0 {
-            arithmetic_helper(
-                self,
-                rhs,
-                arrow2::compute::arithmetics::decimal::div,
-                |l, r| ((l * scale) / r),
-            )
-        } else {
-            match (self.len(), rhs.len()) {
-                (a, b) if a == b => {
-                    let values = self
-                        .as_arrow()
-                        .iter()
-                        .zip(rhs.as_arrow().iter())
-                        .map(|(l, r)| match (l, r) {
-                            (None, _) => None,
-                            (_, None) => None,
-                            (Some(l), Some(r)) => Some((l * scale) / r),
-                        });
-                    Ok(Decimal128Array::from_iter(self.field.clone(), values))
-                }
-                // broadcast right path
-                (_, 1) => {
-                    let opt_rhs = rhs.get(0);
-                    match opt_rhs {
-                        None => Ok(DataArray::full_null(
-                            self.name(),
-                            self.data_type(),
-                            self.len(),
-                        )),
-                        Some(rhs) => self.apply(|lhs| ((lhs * scale) / rhs)),
-                    }
-                }
-                (1, _) => {
-                    let opt_lhs = self.get(0);
-                    Ok(match opt_lhs {
-                        None => DataArray::full_null(rhs.name(), rhs.data_type(), rhs.len()),
-                        Some(lhs) => {
-                            let values_iter = rhs
-                                .as_arrow()
-                                .iter()
-                                .map(|v| v.map(|v| ((lhs * scale) / *v)));
-                            Decimal128Array::from_iter(self.field.clone(), values_iter)
-                        }
-                    })
-                }
-                (a, b) => Err(DaftError::ValueError(format!(
-                    "Cannot apply operation on arrays of different lengths: {a} vs {b}"
-                ))),
-            }
-        }
+impl Rem for &Decimal128Array {
+    type Output = DaftResult<Decimal128Array>;
+    fn rem(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.data_type(), rhs.data_type());
+        // Both sides have the same scale, so the remainder of their values is the value of the remainder.
+        decimal_checked_helper(self, rhs, i128::checked_rem)
     }
 }
 
@@ -9,7 +9,7 @@ use crate::datatypes::PythonArray;
 use crate::{
     array::DataArray,
     datatypes::{
-        logical::{DateArray, DurationArray, TimeArray, TimestampArray},
+        logical::{CategoricalArray, DateArray, DurationArray, TimeArray, TimestampArray},
         BinaryArray, BooleanArray, DaftPrimitiveType, FixedSizeBinaryArray, IntervalArray,
         NullArray, Utf8Array,
     },
@@ -71,4 +71,5 @@ impl_asarrow_dataarray!(PythonArray, PseudoArrowArray<Arc<pyo3::PyObject>>);
 impl_asarrow_logicalarray!(DateArray, array::PrimitiveArray<i32>);
 impl_asarrow_logicalarray!(TimeArray, array::PrimitiveArray<i64>);
 impl_asarrow_logicalarray!(DurationArray, array::PrimitiveArray<i64>);
+impl_asarrow_logicalarray!(CategoricalArray, array::PrimitiveArray<u32>);
 impl_asarrow_logicalarray!(TimestampArray, array::PrimitiveArray<i64>);
@@ -1,4 +1,4 @@
-use std::ops::{BitAnd, BitOr, BitXor};
+use std::ops::{BitAnd, BitOr, BitXor, Not};
 
 use common_error::DaftResult;
 
@@ -28,3 +28,14 @@ where
         self.binary_apply(rhs, |lhs, rhs| lhs.bitxor(rhs))
     }
 }
+
+impl<T> DataArray<T>
+where
+    T: DaftIntegerType,
+    <T as DaftNumericType>::Native: Ord + Not<Output = T::Native>,
+{
+    /// Inverts the bits of each value.
+    pub fn bitwise_not(&self) -> DaftResult<Self> {
+        self.apply(|v| !v)
+    }
+}
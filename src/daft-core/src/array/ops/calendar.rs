@@ -0,0 +1,549 @@
+//! Calendar arithmetic on dates and timestamps.
+//!
+//! Shifting by months, quarters or years keeps the day of the month, clamping it to the last day
+//! of months that are shorter, so that `2024-01-31` plus one month is `2024-02-29`. Shifts and
+//! differences in days or larger units are taken on the local date and time of timezone-aware
+//! timestamps, while smaller units are exact durations between instants.
+use std::str::FromStr;
+
+use arrow2::array::PrimitiveArray;
+use chrono::{Datelike, Duration, Months, NaiveDate, NaiveDateTime, NaiveTime};
+use common_error::{DaftError, DaftResult};
+
+use super::as_arrow::AsArrow;
+use crate::{
+    array::prelude::*,
+    datatypes::{prelude::*, Float64Array},
+};
+
+/// A unit that dates and timestamps can be shifted by and differenced in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum CalendarUnit {
+    Nanosecond,
+    Microsecond,
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl FromStr for CalendarUnit {
+    type Err = DaftError;
+
+    fn from_str(unit: &str) -> DaftResult<Self> {
+        let lower = unit.to_lowercase();
+        match lower.strip_suffix('s').unwrap_or(&lower) {
+            "nanosecond" => Ok(Self::Nanosecond),
+            "microsecond" => Ok(Self::Microsecond),
+            "millisecond" => Ok(Self::Millisecond),
+            "second" => Ok(Self::Second),
+            "minute" => Ok(Self::Minute),
+            "hour" => Ok(Self::Hour),
+            "day" => Ok(Self::Day),
+            "week" => Ok(Self::Week),
+            "month" => Ok(Self::Month),
+            "quarter" => Ok(Self::Quarter),
+            "year" => Ok(Self::Year),
+            _ => Err(DaftError::ValueError(format!(
+                "Invalid unit: {unit}. Expected one of: nanosecond, microsecond, millisecond, second, minute, hour, day, week, month, quarter, year"
+            ))),
+        }
+    }
+}
+
+impl CalendarUnit {
+    /// Whether the unit is a number of local days rather than an exact duration.
+    fn is_calendar(self) -> bool {
+        matches!(
+            self,
+            Self::Day | Self::Week | Self::Month | Self::Quarter | Self::Year
+        )
+    }
+
+    fn months(self) -> Option<i64> {
+        match self {
+            Self::Month => Some(1),
+            Self::Quarter => Some(3),
+            Self::Year => Some(12),
+            _ => None,
+        }
+    }
+
+    fn shift(self, dt: NaiveDateTime, n: i64) -> Option<NaiveDateTime> {
+        let duration = match self {
+            Self::Nanosecond => Duration::nanoseconds(n),
+            Self::Microsecond => Duration::microseconds(n),
+            Self::Millisecond => Duration::try_milliseconds(n)?,
+            Self::Second => Duration::try_seconds(n)?,
+            Self::Minute => Duration::try_minutes(n)?,
+            Self::Hour => Duration::try_hours(n)?,
+            Self::Day => Duration::try_days(n)?,
+            Self::Week => Duration::try_weeks(n)?,
+            Self::Month | Self::Quarter | Self::Year => {
+                return add_months(dt, n.checked_mul(self.months()?)?)
+            }
+        };
+        dt.checked_add_signed(duration)
+    }
+
+    /// The number of whole units from `start` to `end`, truncated towards zero.
+    fn diff(self, start: NaiveDateTime, end: NaiveDateTime) -> Option<i64> {
+        if let Some(months) = self.months() {
+            return Some(months_between(start, end)? / months);
+        }
+        let delta = end - start;
+        match self {
+            Self::Nanosecond => delta.num_nanoseconds(),
+            Self::Microsecond => delta.num_microseconds(),
+            Self::Millisecond => Some(delta.num_milliseconds()),
+            Self::Second => Some(delta.num_seconds()),
+            Self::Minute => Some(delta.num_minutes()),
+            Self::Hour => Some(delta.num_hours()),
+            Self::Day => Some(delta.num_days()),
+            Self::Week => Some(delta.num_weeks()),
+            Self::Month | Self::Quarter | Self::Year => unreachable!(),
+        }
+    }
+}
+
+fn add_months(dt: NaiveDateTime, months: i64) -> Option<NaiveDateTime> {
+    let abs = Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+    if months >= 0 {
+        dt.checked_add_months(abs)
+    } else {
+        dt.checked_sub_months(abs)
+    }
+}
+
+/// The number of whole months from `start` to `end`, where a month is only complete once the day
+/// and time of `start` is reached again.
+fn months_between(start: NaiveDateTime, end: NaiveDateTime) -> Option<i64> {
+    let mut months = (i64::from(end.year()) - i64::from(start.year())) * 12
+        + i64::from(end.month())
+        - i64::from(start.month());
+    if months > 0 && add_months(start, months)? > end {
+        months -= 1;
+    } else if months < 0 && add_months(start, months)? < end {
+        months += 1;
+    }
+    Some(months)
+}
+
+fn epoch() -> NaiveDate {
+    NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()
+}
+
+fn days_to_date(days: i32) -> NaiveDate {
+    epoch() + Duration::days(i64::from(days))
+}
+
+fn date_to_days(date: NaiveDate) -> i32 {
+    (date - epoch()).num_days() as i32
+}
+
+fn out_of_range(op: &str) -> DaftError {
+    DaftError::ComputeError(format!("{op} produced a date out of the supported range"))
+}
+
+impl DateArray {
+    fn dates(&self) -> impl Iterator<Item = Option<NaiveDate>> + '_ {
+        self.physical
+            .as_arrow()
+            .iter()
+            .map(|days| days.map(|days| days_to_date(*days)))
+    }
+
+    fn with_dates(&self, dates: impl Iterator<Item = Option<NaiveDate>>) -> Self {
+        let days = dates
+            .map(|date| date.map(date_to_days))
+            .collect::<PrimitiveArray<i32>>();
+        Self::new(
+            Field::new(self.name(), DataType::Date),
+            Int32Array::from((self.name(), Box::new(days))),
+        )
+    }
+
+    /// The quarter of the year of each date, from 1 to 4.
+    pub fn quarter(&self) -> DaftResult<UInt32Array> {
+        let quarters = self
+            .dates()
+            .map(|date| date.map(|date| date.month0() / 3 + 1))
+            .collect::<PrimitiveArray<u32>>();
+        Ok((self.name(), Box::new(quarters)).into())
+    }
+
+    /// The ISO 8601 week of the year of each date, from 1 to 53.
+    pub fn week_of_year(&self) -> DaftResult<UInt32Array> {
+        let weeks = self
+            .dates()
+            .map(|date| date.map(|date| date.iso_week().week()))
+            .collect::<PrimitiveArray<u32>>();
+        Ok((self.name(), Box::new(weeks)).into())
+    }
+
+    /// The last day of the month of each date.
+    pub fn last_day_of_month(&self) -> DaftResult<Self> {
+        let last_days = self.dates().map(|date| {
+            date.map(|date| {
+                let first = date.with_day(1).unwrap();
+                add_months(first.and_time(NaiveTime::MIN), 1)
+                    .map_or(NaiveDate::MAX, |next| next.date() - Duration::days(1))
+            })
+        });
+        Ok(self.with_dates(last_days))
+    }
+
+    /// Shifts each date forward by `n` of `unit`, which must be days or larger.
+    pub fn add_units(&self, n: &Int64Array, unit: &str) -> DaftResult<Self> {
+        let parsed = unit.parse::<CalendarUnit>()?;
+        if !parsed.is_calendar() {
+            return Err(DaftError::ValueError(format!(
+                "Dates can only be shifted by days or larger units, got {unit}"
+            )));
+        }
+        let unit = parsed;
+        let shifted = self
+            .dates()
+            .zip(n.as_arrow().iter())
+            .map(|(date, n)| match (date, n) {
+                (Some(date), Some(n)) => unit
+                    .shift(date.and_time(NaiveTime::MIN), *n)
+                    .map(|dt| Some(dt.date()))
+                    .ok_or_else(|| out_of_range("date_add")),
+                _ => Ok(None),
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(self.with_dates(shifted.into_iter()))
+    }
+
+    /// The number of whole `unit`s from each date of `start` to each date of this array.
+    pub fn diff_units(&self, start: &Self, unit: &str) -> DaftResult<Int64Array> {
+        let unit = unit.parse::<CalendarUnit>()?;
+        let diffs = self
+            .dates()
+            .zip(start.dates())
+            .map(|(end, start)| match (end, start) {
+                (Some(end), Some(start)) => unit
+                    .diff(start.and_time(NaiveTime::MIN), end.and_time(NaiveTime::MIN))
+                    .map(Some)
+                    .ok_or_else(|| out_of_range("date_diff")),
+                _ => Ok(None),
+            })
+            .collect::<DaftResult<PrimitiveArray<i64>>>()?;
+        Ok((self.name(), Box::new(diffs)).into())
+    }
+
+    /// Builds dates from their year, month and day, failing on days that do not exist.
+    pub fn from_ymd(
+        name: &str,
+        year: &Int32Array,
+        month: &Int32Array,
+        day: &Int32Array,
+    ) -> DaftResult<Self> {
+        let days = year
+            .as_arrow()
+            .iter()
+            .zip(month.as_arrow().iter())
+            .zip(day.as_arrow().iter())
+            .map(|((year, month), day)| match (year, month, day) {
+                (Some(y), Some(m), Some(d)) => make_date(*y, *m, *d).map(|d| Some(date_to_days(d))),
+                _ => Ok(None),
+            })
+            .collect::<DaftResult<PrimitiveArray<i32>>>()?;
+        Ok(Self::new(
+            Field::new(name, DataType::Date),
+            Int32Array::from((name, Box::new(days))),
+        ))
+    }
+}
+
+fn make_date(year: i32, month: i32, day: i32) -> DaftResult<NaiveDate> {
+    u32::try_from(month)
+        .ok()
+        .zip(u32::try_from(day).ok())
+        .and_then(|(month, day)| NaiveDate::from_ymd_opt(year, month, day))
+        .ok_or_else(|| {
+            DaftError::ValueError(format!(
+                "Invalid date: year {year}, month {month}, day {day}"
+            ))
+        })
+}
+
+impl TimestampArray {
+    /// Shifts each timestamp forward by `n` of `unit`.
+    ///
+    /// Shifts by days or larger units keep the local time of timezone-aware timestamps, and
+    /// local times that do not exist after the shift become null.
+    pub fn add_units(&self, n: &Int64Array, unit: &str) -> DaftResult<Self> {
+        let unit = unit.parse::<CalendarUnit>()?;
+        let DataType::Timestamp(timeunit, tz) = self.data_type() else {
+            unreachable!("Timestamp array must have Timestamp datatype")
+        };
+        let clock = self.clock(unit)?;
+        let shifted = clock
+            .into_iter()
+            .zip(n.as_arrow().iter())
+            .map(|(dt, n)| match (dt, n) {
+                (Some(dt), Some(n)) => unit
+                    .shift(dt, *n)
+                    .map(Some)
+                    .ok_or_else(|| out_of_range("date_add")),
+                _ => Ok(None),
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        if unit.is_calendar() {
+            Self::from_wall_clock(self.name(), &shifted, *timeunit, tz.as_deref())
+        } else {
+            let naive = Self::from_wall_clock(self.name(), &shifted, *timeunit, None)?;
+            Ok(Self::new(self.field.clone(), naive.physical))
+        }
+    }
+
+    /// The number of whole `unit`s from each timestamp of `start` to each timestamp of this
+    /// array, which must have the same type.
+    pub fn diff_units(&self, start: &Self, unit: &str) -> DaftResult<Int64Array> {
+        let unit = unit.parse::<CalendarUnit>()?;
+        let diffs = self
+            .clock(unit)?
+            .into_iter()
+            .zip(start.clock(unit)?)
+            .map(|(end, start)| match (end, start) {
+                (Some(end), Some(start)) => unit
+                    .diff(start, end)
+                    .map(Some)
+                    .ok_or_else(|| out_of_range("date_diff")),
+                _ => Ok(None),
+            })
+            .collect::<DaftResult<PrimitiveArray<i64>>>()?;
+        Ok((self.name(), Box::new(diffs)).into())
+    }
+
+    /// Returns the local date and times of the timestamps for calendar units, and their UTC date
+    /// and times otherwise.
+    fn clock(&self, unit: CalendarUnit) -> DaftResult<Vec<Option<NaiveDateTime>>> {
+        let DataType::Timestamp(timeunit, _) = self.data_type() else {
+            unreachable!("Timestamp array must have Timestamp datatype")
+        };
+        if unit.is_calendar() {
+            self.wall_clock()
+        } else {
+            Self::new(
+                Field::new(self.name(), DataType::Timestamp(*timeunit, None)),
+                self.physical.clone(),
+            )
+            .wall_clock()
+        }
+    }
+
+    /// Builds microsecond timestamps from the parts of their local date and time in `tz`, failing
+    /// on dates and times that do not exist.
+    ///
+    /// `second` may have a fractional part. Local times that are ambiguous in `tz` resolve to
+    /// their earliest instant, while local times that are skipped in `tz` become null.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_parts(
+        name: &str,
+        year: &Int32Array,
+        month: &Int32Array,
+        day: &Int32Array,
+        hour: &Int32Array,
+        minute: &Int32Array,
+        second: &Float64Array,
+        tz: Option<&str>,
+    ) -> DaftResult<Self> {
+        let wall_clock = year
+            .as_arrow()
+            .iter()
+            .zip(month.as_arrow().iter())
+            .zip(day.as_arrow().iter())
+            .zip(hour.as_arrow().iter())
+            .zip(minute.as_arrow().iter())
+            .zip(second.as_arrow().iter())
+            .map(|(((((year, month), day), hour), minute), second)| {
+                match (year, month, day, hour, minute, second) {
+                    (Some(y), Some(mo), Some(d), Some(h), Some(mi), Some(s)) => {
+                        let date = make_date(*y, *mo, *d)?;
+                        let time = make_time(*h, *mi, *s)?;
+                        Ok(Some(date.and_time(time)))
+                    }
+                    _ => Ok(None),
+                }
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        if let Some(tz) = tz {
+            // fail on invalid timezones even if there are no values to localize
+            arrow2::temporal_conversions::parse_offset(tz)
+                .map(|_| ())
+                .or_else(|_| arrow2::temporal_conversions::parse_offset_tz(tz).map(|_| ()))
+                .map_err(|_| DaftError::ValueError(format!("Cannot parse timezone: {tz}")))?;
+        }
+        Self::from_wall_clock(name, &wall_clock, TimeUnit::Microseconds, tz)
+    }
+}
+
+fn make_time(hour: i32, minute: i32, second: f64) -> DaftResult<NaiveTime> {
+    let invalid = || {
+        DaftError::ValueError(format!(
+            "Invalid time: hour {hour}, minute {minute}, second {second}"
+        ))
+    };
+    if !(0.0..60.0).contains(&second) {
+        return Err(invalid());
+    }
+    let micros = (second * 1_000_000.0).round() as u32;
+    u32::try_from(hour)
+        .ok()
+        .zip(u32::try_from(minute).ok())
+        .and_then(|(hour, minute)| {
+            NaiveTime::from_hms_micro_opt(hour, minute, micros / 1_000_000, micros % 1_000_000)
+        })
+        .ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use super::{date_to_days, make_date};
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{
+            logical::{DateArray, TimestampArray},
+            DataType, Field, Float64Array, Int32Array, Int64Array,
+        },
+    };
+
+    fn dates(values: &[(i32, i32, i32)]) -> DateArray {
+        let days = values
+            .iter()
+            .map(|(y, m, d)| date_to_days(make_date(*y, *m, *d).unwrap()))
+            .collect::<Vec<_>>();
+        DateArray::new(
+            Field::new("d", DataType::Date),
+            Int32Array::from(("d", days)),
+        )
+    }
+
+    fn days(array: &DateArray) -> Vec<Option<i32>> {
+        array
+            .physical
+            .as_arrow()
+            .iter()
+            .map(|v| v.copied())
+            .collect()
+    }
+
+    #[test]
+    fn month_arithmetic_clamps_to_month_end() -> DaftResult<()> {
+        let start = dates(&[(2024, 1, 31), (2023, 3, 31), (2024, 2, 29)]);
+        let one = Int64Array::from(("n", vec![1, -1, 12]));
+        assert_eq!(
+            days(&start.add_units(&one, "month")?),
+            days(&dates(&[(2024, 2, 29), (2023, 2, 28), (2025, 2, 28)]))
+        );
+        assert_eq!(
+            days(&start.add_units(&one, "years")?),
+            days(&dates(&[(2025, 1, 31), (2022, 3, 31), (2036, 2, 29)]))
+        );
+        assert!(start.add_units(&one, "hour").is_err());
+
+        assert_eq!(
+            days(&start.last_day_of_month()?),
+            days(&dates(&[(2024, 1, 31), (2023, 3, 31), (2024, 2, 29)]))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn diff_counts_whole_units() -> DaftResult<()> {
+        let start = dates(&[(2024, 1, 31), (2024, 1, 15), (2024, 3, 1)]);
+        let end = dates(&[(2024, 2, 29), (2024, 4, 14), (2023, 1, 1)]);
+        let months = end.diff_units(&start, "month")?;
+        assert_eq!(
+            months
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(1), Some(2), Some(-14)]
+        );
+        let quarters = end.diff_units(&start, "quarter")?;
+        assert_eq!(
+            quarters
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(0), Some(0), Some(-4)]
+        );
+        let days = end.diff_units(&start, "days")?;
+        assert_eq!(
+            days.as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(29), Some(90), Some(-425)]
+        );
+        assert!(end.diff_units(&start, "fortnight").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn timestamp_days_keep_local_time_across_dst() -> DaftResult<()> {
+        let part = |v: i32| Int32Array::from(("p", vec![v]));
+        let noon = TimestampArray::from_parts(
+            "ts",
+            &part(2024),
+            &part(3),
+            &part(9),
+            &part(12),
+            &part(0),
+            &Float64Array::from(("s", vec![0.5])),
+            Some("America/New_York"),
+        )?;
+        let utc_micros = |array: &TimestampArray| array.physical.as_arrow().value(0);
+        // noon EST is 17:00 UTC
+        assert_eq!(
+            utc_micros(&noon) % 86_400_000_000,
+            17 * 3_600_000_000 + 500_000
+        );
+
+        let one = Int64Array::from(("n", vec![1]));
+        let next_day = noon.add_units(&one, "day")?;
+        let day_of_hours = noon.add_units(&Int64Array::from(("n", vec![24])), "hours")?;
+        // the next day starts in EDT, so a local day is only 23 hours long
+        assert_eq!(
+            utc_micros(&next_day) - utc_micros(&noon),
+            23 * 3_600_000_000
+        );
+        assert_eq!(
+            utc_micros(&day_of_hours) - utc_micros(&noon),
+            24 * 3_600_000_000
+        );
+
+        let diff = |unit| -> DaftResult<i64> {
+            Ok(day_of_hours.diff_units(&noon, unit)?.as_arrow().value(0))
+        };
+        assert_eq!(diff("day")?, 1);
+        assert_eq!(diff("hour")?, 24);
+        assert_eq!(diff("month")?, 0);
+
+        assert!(TimestampArray::from_parts(
+            "ts",
+            &part(2023),
+            &part(2),
+            &part(29),
+            &part(0),
+            &part(0),
+            &Float64Array::from(("s", vec![0.0])),
+            None,
+        )
+        .is_err());
+        Ok(())
+    }
+}
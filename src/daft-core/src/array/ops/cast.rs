@@ -12,6 +12,7 @@ use arrow2::{
         cast::{can_cast_types, cast, CastOptions},
     },
     offset::Offsets,
+    types::months_days_ns,
 };
 use common_error::{DaftError, DaftResult};
 use indexmap::IndexMap;
@@ -32,23 +33,58 @@ use crate::{
     array::{
         growable::make_growable,
         image_array::ImageArraySidecarData,
-        ops::{from_arrow::FromArrow, full::FullNull, DaftCompare},
+        ops::{extension::try_cast_extension, from_arrow::FromArrow, full::FullNull, DaftCompare},
         DataArray, FixedSizeListArray, ListArray, StructArray,
     },
     datatypes::{
         logical::{
-            DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray,
+            CategoricalArray, DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray,
             FixedShapeSparseTensorArray, FixedShapeTensorArray, ImageArray, LogicalArray, MapArray,
             SparseTensorArray, TensorArray, TimeArray, TimestampArray,
         },
         DaftArrayType, DaftArrowBackedType, DaftLogicalType, DataType, Field, ImageMode,
-        Int32Array, Int64Array, NullArray, TimeUnit, UInt64Array, Utf8Array,
+        Int32Array, Int64Array, IntervalArray, IntervalValue, NullArray, TimeUnit, UInt32Array,
+        UInt64Array, Utf8Array,
     },
     series::{IntoSeries, Series},
-    utils::display::display_time64,
+    utils::display::{display_decimal128, display_time64},
     with_match_numeric_daft_types,
 };
 
+/// Parses a decimal string such as `"-12.345"` into its unscaled value at `scale`, rounding half
+/// away from zero. Returns `None` if the string is not a decimal or does not fit in `precision`.
+fn parse_decimal128(value: &str, precision: usize, scale: usize) -> Option<i128> {
+    let value = value.trim();
+    let (negative, digits) = match value.as_bytes().first()? {
+        b'-' => (true, &value[1..]),
+        b'+' => (false, &value[1..]),
+        _ => (false, value),
+    };
+    let (integral, fractional) = digits.split_once('.').unwrap_or((digits, ""));
+    if integral.is_empty() && fractional.is_empty()
+        || !integral
+            .bytes()
+            .chain(fractional.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let mut unscaled: i128 = 0;
+    for b in integral
+        .bytes()
+        .chain(fractional.bytes().chain(repeat_n(b'0', scale)).take(scale))
+    {
+        unscaled = unscaled.checked_mul(10)?.checked_add((b - b'0') as i128)?;
+    }
+    if fractional.len() > scale && fractional.as_bytes()[scale] >= b'5' {
+        unscaled = unscaled.checked_add(1)?;
+    }
+    if unscaled >= 10i128.checked_pow(precision as u32)? {
+        return None;
+    }
+    Some(if negative { -unscaled } else { unscaled })
+}
+
 impl<T> DataArray<T>
 where
     T: DaftArrowBackedType,
@@ -78,7 +114,102 @@ where
                 })?;
                 Ok(new_pyseries.into())
             }
+            // Arrow has no string representation for intervals, so format them the way they are
+            // displayed.
+            DataType::Utf8 if self.data_type() == &DataType::Interval => {
+                let intervals = self
+                    .data()
+                    .as_any()
+                    .downcast_ref::<arrow2::array::PrimitiveArray<months_days_ns>>()
+                    .unwrap();
+                let strings = intervals
+                    .iter()
+                    .map(|v| v.map(|v| IntervalValue::from(*v).to_string()));
+                Ok(Utf8Array::from_iter(self.name(), strings).into_series())
+            }
+            // Strings that are not valid intervals become null, like strings that are not valid
+            // numbers.
+            DataType::Interval if self.data_type() == &DataType::Utf8 => {
+                let strings = self
+                    .data()
+                    .as_any()
+                    .downcast_ref::<arrow2::array::Utf8Array<i64>>()
+                    .unwrap();
+                let intervals = strings
+                    .iter()
+                    .map(|v| v.and_then(|v| v.parse::<IntervalValue>().ok()));
+                Ok(IntervalArray::from_iter(self.name(), intervals).into_series())
+            }
+            // Arrow2 only casts decimals to and from numbers, so strings and booleans are handled
+            // here.
+            DataType::Utf8 | DataType::Boolean
+                if matches!(self.data_type(), DataType::Decimal128(..)) =>
+            {
+                let DataType::Decimal128(precision, scale) = self.data_type() else {
+                    unreachable!()
+                };
+                let decimals = self
+                    .data()
+                    .as_any()
+                    .downcast_ref::<arrow2::array::PrimitiveArray<i128>>()
+                    .unwrap();
+                let field = Field::new(self.name(), dtype.clone());
+                let array: Box<dyn Array> = if dtype == &DataType::Utf8 {
+                    Box::new(
+                        decimals
+                            .iter()
+                            .map(|v| {
+                                v.map(|v| display_decimal128(*v, *precision as u8, *scale as i8))
+                            })
+                            .collect::<arrow2::array::Utf8Array<i64>>(),
+                    )
+                } else {
+                    Box::new(
+                        decimals
+                            .iter()
+                            .map(|v| v.map(|v| *v != 0))
+                            .collect::<arrow2::array::BooleanArray>(),
+                    )
+                };
+                Series::from_arrow(Arc::new(field), array)
+            }
+            DataType::Decimal128(precision, scale)
+                if matches!(self.data_type(), DataType::Utf8 | DataType::Boolean) =>
+            {
+                let values: Vec<Option<i128>> = if self.data_type() == &DataType::Utf8 {
+                    self.data()
+                        .as_any()
+                        .downcast_ref::<arrow2::array::Utf8Array<i64>>()
+                        .unwrap()
+                        .iter()
+                        .map(|v| v.and_then(|v| parse_decimal128(v, *precision, *scale)))
+                        .collect()
+                } else {
+                    let one = 10i128.pow(*scale as u32);
+                    self.data()
+                        .as_any()
+                        .downcast_ref::<arrow2::array::BooleanArray>()
+                        .unwrap()
+                        .iter()
+                        .map(|v| v.map(|v| if v { one } else { 0 }))
+                        .collect()
+                };
+                let array =
+                    arrow2::array::PrimitiveArray::<i128>::from(values).to(dtype.to_arrow()?);
+                let field = Field::new(self.name(), dtype.clone());
+                Series::from_arrow(Arc::new(field), Box::new(array))
+            }
+            DataType::Categorical(categories) => {
+                let strings = self.cast(&DataType::Utf8)?;
+                encode_categories(strings.utf8()?, categories).map(IntoSeries::into_series)
+            }
             _ => {
+                if let Some(casted) =
+                    try_cast_extension(self.name(), self.data(), self.data_type(), dtype)?
+                {
+                    return Ok(casted);
+                }
+
                 // Cast from DataArray to the target DataType
                 // by using Arrow's casting mechanisms.
 
@@ -153,6 +284,75 @@ where
     }
 }
 
+/// Encodes each string as the index of its category, leaving strings outside of `categories` null.
+fn encode_categories(strings: &Utf8Array, categories: &[String]) -> DaftResult<CategoricalArray> {
+    let codes = categories
+        .iter()
+        .enumerate()
+        .map(|(code, category)| (category.as_str(), code as u32))
+        .collect::<IndexMap<_, _>>();
+    let codes = strings
+        .as_arrow()
+        .iter()
+        .map(|v| v.and_then(|v| codes.get(v).copied()));
+    let physical = UInt32Array::from_iter(Field::new(strings.name(), DataType::UInt32), codes);
+    Ok(CategoricalArray::new(
+        Field::new(strings.name(), DataType::Categorical(categories.to_vec())),
+        physical,
+    ))
+}
+
+impl CategoricalArray {
+    pub fn cast(&self, dtype: &DataType) -> DaftResult<Series> {
+        match dtype {
+            DataType::Null => {
+                Ok(NullArray::full_null(self.name(), dtype, self.len()).into_series())
+            }
+            dtype if dtype == self.data_type() => Ok(self.clone().into_series()),
+            // numbers are the codes, which are also what the physical representation holds
+            dtype if dtype.is_numeric() => self.physical.cast(dtype),
+            DataType::Utf8 => Ok(self.decode()?.into_series()),
+            DataType::Categorical(categories) => Ok(self.recode(categories).into_series()),
+            // everything else, including Python objects, is cast from the strings
+            dtype => self.decode()?.cast(dtype),
+        }
+    }
+
+    /// Maps each code to that of its category in `categories`, without decoding the strings of the values.
+    fn recode(&self, categories: &[String]) -> Self {
+        let codes = categories
+            .iter()
+            .enumerate()
+            .map(|(code, category)| (category.as_str(), code as u32))
+            .collect::<IndexMap<_, _>>();
+        let mapping = self
+            .categories()
+            .iter()
+            .map(|category| codes.get(category.as_str()).copied())
+            .collect::<Vec<_>>();
+        let codes = self
+            .physical
+            .as_arrow()
+            .iter()
+            .map(|code| code.and_then(|code| mapping[*code as usize]));
+        Self::new(
+            Field::new(self.name(), DataType::Categorical(categories.to_vec())),
+            UInt32Array::from_iter(Field::new(self.name(), DataType::UInt32), codes),
+        )
+    }
+
+    /// Replaces each code with the string of its category.
+    fn decode(&self) -> DaftResult<Utf8Array> {
+        let categories = self.categories();
+        let strings = self
+            .physical
+            .as_arrow()
+            .iter()
+            .map(|code| code.map(|code| categories[*code as usize].as_str()));
+        Ok(Utf8Array::from_iter(self.name(), strings))
+    }
+}
+
 impl DateArray {
     pub fn cast(&self, dtype: &DataType) -> DaftResult<Series> {
         match dtype {
@@ -369,6 +569,7 @@ impl DurationArray {
             dtype if dtype == self.data_type() => Ok(self.clone().into_series()),
             dtype if dtype.is_numeric() => self.physical.cast(dtype),
             DataType::Int64 => Ok(self.physical.clone().into_series()),
+            DataType::Interval => self.cast_to_interval().map(IntoSeries::into_series),
             #[cfg(feature = "python")]
             DataType::Python => cast_logical_to_python_array(self, dtype),
             _ => Err(DaftError::TypeError(format!(
@@ -378,6 +579,37 @@ impl DurationArray {
         }
     }
 
+    /// Converts each duration to an interval of the same number of nanoseconds.
+    pub fn cast_to_interval(&self) -> DaftResult<IntervalArray> {
+        let DataType::Duration(tu) = self.data_type() else {
+            panic!("Wrong dtype for DurationArray: {}", self.data_type())
+        };
+        let ns_per_unit = match tu {
+            TimeUnit::Seconds => 1_000_000_000,
+            TimeUnit::Milliseconds => 1_000_000,
+            TimeUnit::Microseconds => 1_000,
+            TimeUnit::Nanoseconds => 1,
+        };
+        let intervals = self
+            .physical
+            .as_arrow()
+            .iter()
+            .map(|v| {
+                v.map(|v| {
+                    v.checked_mul(ns_per_unit)
+                        .map(|ns| months_days_ns::new(0, 0, ns))
+                        .ok_or_else(|| {
+                            DaftError::ComputeError(format!(
+                                "Duration {v} {tu} overflows when cast to Interval"
+                            ))
+                        })
+                })
+                .transpose()
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(IntervalArray::from_iter(self.name(), intervals.into_iter()))
+    }
+
     pub fn cast_to_days(&self) -> DaftResult<Int32Array> {
         let tu = match self.data_type() {
             DataType::Duration(tu) => tu,
@@ -2146,6 +2378,15 @@ impl FixedSizeListArray {
                 )
                 .into_series())
             }
+            DataType::Map { .. } => self.cast(&dtype.to_physical())?.list()?.cast(dtype),
+            DataType::Embedding(..) => {
+                let result = self.cast(&dtype.to_physical())?;
+                let embedding_array = EmbeddingArray::new(
+                    Field::new(self.name(), dtype.clone()),
+                    result.fixed_size_list()?.clone(),
+                );
+                Ok(embedding_array.into_series())
+            }
             _ => unimplemented!("FixedSizeList casting not implemented for dtype: {}", dtype),
         }
     }
@@ -2507,4 +2748,53 @@ mod tests {
             scale,
         );
     }
+
+    #[test]
+    fn test_decimal_to_and_from_utf8() -> DaftResult<()> {
+        let original = create_test_decimal_array(vec![12345, -50, 0], 7, 2);
+        let strings = original.cast(&DataType::Utf8)?;
+        assert_eq!(
+            strings.utf8()?.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("123.45"), Some("-0.50"), Some("0.00")]
+        );
+        assert!(original.into_series() == strings.cast(&DataType::Decimal128(7, 2))?);
+
+        let parsed = Utf8Array::from((
+            "s",
+            ["1.005", "-2", "+.5", "abc", "", "123456.0"].as_slice(),
+        ))
+        .cast(&DataType::Decimal128(7, 2))?;
+        assert_eq!(
+            parsed.decimal128()?.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some(&101), Some(&-200), Some(&50), None, None, None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_to_and_from_boolean() -> DaftResult<()> {
+        let original = create_test_decimal_array(vec![100, 0], 5, 2);
+        let booleans = original.cast(&DataType::Boolean)?;
+        assert_eq!(
+            booleans.bool()?.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some(true), Some(false)]
+        );
+        assert!(original.into_series() == booleans.cast(&DataType::Decimal128(5, 2))?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_utf8_to_interval() -> DaftResult<()> {
+        let parsed = Utf8Array::from(("s", ["3 days 4 hours", "1 month", "soon"].as_slice()))
+            .cast(&DataType::Interval)?;
+        assert_eq!(
+            parsed.interval()?.as_arrow().iter().collect::<Vec<_>>(),
+            vec![
+                Some(&months_days_ns::new(0, 3, 4 * 3_600_000_000_000)),
+                Some(&months_days_ns::new(1, 0, 0)),
+                None
+            ]
+        );
+        Ok(())
+    }
 }
@@ -1,4 +1,4 @@
-use arrow2::array::Array;
+use arrow2::{array::Array, types::months_days_ns};
 use common_error::DaftResult;
 
 use super::{full::FullNull, DaftCompareAggable, GroupIndices};
@@ -56,6 +56,28 @@ where
 
 use super::as_arrow::AsArrow;
 
+/// Whether `l` sorts before `r` in the total order used by sorts, where NaN is greater than every
+/// other value (including +inf) and equal to itself.
+#[allow(clippy::eq_op)]
+fn total_lt<N: PartialOrd>(l: &N, r: &N) -> bool {
+    // NaN is the only value that isn't equal to itself
+    match (l != l, r != r) {
+        (false, false) => l < r,
+        (false, true) => true,
+        (true, _) => false,
+    }
+}
+
+/// Reduces the valid values of `array` with `op`, without the SIMD kernels of arrow2 which leave
+/// the result undefined in the presence of NaN.
+fn reduce_valid<N, F>(array: &arrow2::array::PrimitiveArray<N>, op: F) -> Option<N>
+where
+    N: arrow2::types::NativeType,
+    F: Fn(N, N) -> N,
+{
+    array.iter().flatten().copied().reduce(op)
+}
+
 impl<T> DaftCompareAggable for DataArray<T>
 where
     T: DaftPrimitiveType,
@@ -67,22 +89,30 @@ where
     fn min(&self) -> Self::Output {
         let primitive_arr = self.as_arrow();
 
-        let result = arrow2::compute::aggregate::min_primitive(primitive_arr);
+        let result = if self.data_type().is_floating() {
+            reduce_valid(primitive_arr, |l, r| if total_lt(&r, &l) { r } else { l })
+        } else {
+            arrow2::compute::aggregate::min_primitive(primitive_arr)
+        };
         Ok(Self::from_iter(self.field.clone(), std::iter::once(result)))
     }
 
     fn max(&self) -> Self::Output {
         let primitive_arr = self.as_arrow();
 
-        let result = arrow2::compute::aggregate::max_primitive(primitive_arr);
+        let result = if self.data_type().is_floating() {
+            reduce_valid(primitive_arr, |l, r| if total_lt(&l, &r) { r } else { l })
+        } else {
+            arrow2::compute::aggregate::max_primitive(primitive_arr)
+        };
         Ok(Self::from_iter(self.field.clone(), std::iter::once(result)))
     }
     fn grouped_min(&self, groups: &GroupIndices) -> Self::Output {
         grouped_cmp_native(
             self,
-            |l, r| match l.lt(&r) {
-                true => l,
-                false => r,
+            |l, r| match total_lt(&r, &l) {
+                true => r,
+                false => l,
             },
             groups,
         )
@@ -91,9 +121,9 @@ where
     fn grouped_max(&self, groups: &GroupIndices) -> Self::Output {
         grouped_cmp_native(
             self,
-            |l, r| match l.gt(&r) {
-                true => l,
-                false => r,
+            |l, r| match total_lt(&l, &r) {
+                true => r,
+                false => l,
             },
             groups,
         )
@@ -466,6 +496,55 @@ impl DaftCompareAggable for DataArray<NullType> {
     }
 }
 
+fn cmp_interval<F>(data_array: &IntervalArray, op: F) -> DaftResult<IntervalArray>
+where
+    F: Fn(months_days_ns, months_days_ns) -> months_days_ns,
+{
+    let result = data_array.as_arrow().iter().flatten().copied().reduce(op);
+    Ok(IntervalArray::from_iter(
+        data_array.name(),
+        std::iter::once(result),
+    ))
+}
+
+fn grouped_cmp_interval<F>(
+    data_array: &IntervalArray,
+    op: F,
+    groups: &GroupIndices,
+) -> DaftResult<IntervalArray>
+where
+    F: Fn(months_days_ns, months_days_ns) -> months_days_ns,
+{
+    let arrow_array = data_array.as_arrow();
+    let cmp_per_group = groups.iter().map(|g| {
+        g.iter()
+            .filter(|i| arrow_array.is_valid(**i as usize))
+            .map(|i| arrow_array.value(*i as usize))
+            .reduce(&op)
+    });
+    Ok(IntervalArray::from_iter(data_array.name(), cmp_per_group))
+}
+
+impl DaftCompareAggable for IntervalArray {
+    type Output = DaftResult<Self>;
+
+    fn min(&self) -> Self::Output {
+        cmp_interval(self, |l, r| l.min(r))
+    }
+
+    fn max(&self) -> Self::Output {
+        cmp_interval(self, |l, r| l.max(r))
+    }
+
+    fn grouped_min(&self, groups: &GroupIndices) -> Self::Output {
+        grouped_cmp_interval(self, |l, r| l.min(r), groups)
+    }
+
+    fn grouped_max(&self, groups: &GroupIndices) -> Self::Output {
+        grouped_cmp_interval(self, |l, r| l.max(r), groups)
+    }
+}
+
 macro_rules! impl_todo_daft_comparable {
     ($da:ident) => {
         impl DaftCompareAggable for $da {
@@ -505,7 +584,48 @@ impl_todo_daft_comparable!(StructArray);
 impl_todo_daft_comparable!(FixedSizeListArray);
 impl_todo_daft_comparable!(ListArray);
 impl_todo_daft_comparable!(ExtensionArray);
-impl_todo_daft_comparable!(IntervalArray);
 
 #[cfg(feature = "python")]
 impl_todo_daft_comparable!(PythonArray);
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::{as_arrow::AsArrow, DaftCompareAggable, GroupIndices},
+        datatypes::{DataType, Field, Float64Array},
+    };
+
+    fn values(array: &Float64Array) -> Vec<Option<f64>> {
+        array.as_arrow().iter().map(|v| v.copied()).collect()
+    }
+
+    #[test]
+    fn min_max_order_nan_after_infinity() -> DaftResult<()> {
+        let array = Float64Array::from_iter(
+            Field::new("a", DataType::Float64),
+            vec![
+                Some(f64::NAN),
+                Some(1.0),
+                None,
+                Some(f64::INFINITY),
+                Some(f64::NAN),
+                Some(f64::NAN),
+            ]
+            .into_iter(),
+        );
+        assert_eq!(values(&array.min()?), vec![Some(1.0)]);
+        assert!(values(&array.max()?)[0].unwrap().is_nan());
+
+        // the result of a group doesn't depend on where the NaN appears in it
+        let groups: GroupIndices = vec![vec![0, 1], vec![1, 0], vec![2, 3], vec![4, 5]];
+        let min = values(&array.grouped_min(&groups)?);
+        assert_eq!(&min[..3], &[Some(1.0), Some(1.0), Some(f64::INFINITY)]);
+        assert!(min[3].unwrap().is_nan());
+        let max = values(&array.grouped_max(&groups)?);
+        assert!(max[0].unwrap().is_nan() && max[1].unwrap().is_nan());
+        assert_eq!(max[2], Some(f64::INFINITY));
+        Ok(())
+    }
+}
@@ -1,16 +1,17 @@
-use std::ops::Not;
+use std::{cmp::Ordering, ops::Not};
 
-use arrow2::{compute::comparison, scalar::PrimitiveScalar};
+use arrow2::{compute::comparison, scalar::PrimitiveScalar, types::months_days_ns};
 use common_error::{DaftError, DaftResult};
 use num_traits::{NumCast, ToPrimitive};
 
 use super::{as_arrow::AsArrow, from_arrow::FromArrow, full::FullNull, DaftCompare, DaftLogical};
 use crate::{
-    array::DataArray,
+    array::{DataArray, FixedSizeListArray},
     datatypes::{
         BinaryArray, BooleanArray, DaftArrowBackedType, DaftPrimitiveType, DataType, Field,
-        FixedSizeBinaryArray, NullArray, Utf8Array,
+        FixedSizeBinaryArray, IntervalArray, NullArray, Utf8Array,
     },
+    kernels::search_sorted::build_compare_with_nulls,
     utils::arrow::arrow_bitmap_and_helper,
 };
 
@@ -2237,11 +2238,183 @@ impl DaftCompare<&[u8]> for FixedSizeBinaryArray {
     }
 }
 
+/// Compares two interval arrays elementwise, broadcasting either side if it has a single value.
+///
+/// `op` receives `None` for null values, so that null-safe comparisons can be expressed too.
+fn compare_intervals<F>(lhs: &IntervalArray, rhs: &IntervalArray, op: F) -> DaftResult<BooleanArray>
+where
+    F: Fn(Option<months_days_ns>, Option<months_days_ns>) -> Option<bool>,
+{
+    let lhs_arrow = lhs.as_arrow();
+    let rhs_arrow = rhs.as_arrow();
+    match (lhs.len(), rhs.len()) {
+        (x, y) if x == y => Ok(BooleanArray::from_iter(
+            lhs.name(),
+            lhs_arrow
+                .iter()
+                .zip(rhs_arrow.iter())
+                .map(|(l, r)| op(l.copied(), r.copied())),
+        )),
+        (_, 1) => {
+            let r = rhs.get(0);
+            Ok(BooleanArray::from_iter(
+                lhs.name(),
+                lhs_arrow.iter().map(|l| op(l.copied(), r)),
+            ))
+        }
+        (1, _) => {
+            let l = lhs.get(0);
+            Ok(BooleanArray::from_iter(
+                lhs.name(),
+                rhs_arrow.iter().map(|r| op(l, r.copied())),
+            ))
+        }
+        (l, r) => Err(DaftError::ValueError(format!(
+            "trying to compare different length arrays: {}: {l} vs {}: {r}",
+            lhs.name(),
+            rhs.name()
+        ))),
+    }
+}
+
+impl DaftCompare<&Self> for IntervalArray {
+    type Output = DaftResult<BooleanArray>;
+
+    fn equal(&self, rhs: &Self) -> Self::Output {
+        compare_intervals(self, rhs, |l, r| Some(l? == r?))
+    }
+
+    fn eq_null_safe(&self, rhs: &Self) -> Self::Output {
+        compare_intervals(self, rhs, |l, r| Some(l == r))
+    }
+
+    fn not_equal(&self, rhs: &Self) -> Self::Output {
+        compare_intervals(self, rhs, |l, r| Some(l? != r?))
+    }
+
+    fn lt(&self, rhs: &Self) -> Self::Output {
+        compare_intervals(self, rhs, |l, r| Some(l? < r?))
+    }
+
+    fn lte(&self, rhs: &Self) -> Self::Output {
+        compare_intervals(self, rhs, |l, r| Some(l? <= r?))
+    }
+
+    fn gt(&self, rhs: &Self) -> Self::Output {
+        compare_intervals(self, rhs, |l, r| Some(l? > r?))
+    }
+
+    fn gte(&self, rhs: &Self) -> Self::Output {
+        compare_intervals(self, rhs, |l, r| Some(l? >= r?))
+    }
+}
+
+/// Compares fixed size lists lexicographically, element by element, where null elements sort after
+/// all other elements. Either side may be of length 1, in which case it is broadcast.
+///
+/// Pairs with a null list are null, unless `nulls_equal` is set, in which case two null lists are
+/// equal and a null list is not equal to a valid one.
+fn compare_fixed_size_lists<F>(
+    lhs: &FixedSizeListArray,
+    rhs: &FixedSizeListArray,
+    nulls_equal: bool,
+    op: F,
+) -> DaftResult<BooleanArray>
+where
+    F: Fn(Ordering) -> bool,
+{
+    let size = lhs.fixed_element_len();
+    if size != rhs.fixed_element_len() {
+        return Err(DaftError::TypeError(format!(
+            "Cannot compare fixed size lists of different sizes: {} vs {}",
+            lhs.data_type(),
+            rhs.data_type()
+        )));
+    }
+    let len = match (lhs.len(), rhs.len()) {
+        (x, y) if x == y => x,
+        (x, 1) => x,
+        (1, y) => y,
+        (l, r) => {
+            return Err(DaftError::ValueError(format!(
+                "trying to compare different length arrays: {}: {l} vs {}: {r}",
+                lhs.name(),
+                rhs.name()
+            )))
+        }
+    };
+    let lhs_index = |i: usize| if lhs.len() == 1 { 0 } else { i };
+    let rhs_index = |i: usize| if rhs.len() == 1 { 0 } else { i };
+    let is_valid = |array: &FixedSizeListArray, i: usize| {
+        array.validity().is_none_or(|validity| validity.get_bit(i))
+    };
+
+    let compare_elements = build_compare_with_nulls(
+        lhs.flat_child.to_arrow().as_ref(),
+        rhs.flat_child.to_arrow().as_ref(),
+        false,
+    )?;
+    let results: arrow2::array::BooleanArray = (0..len)
+        .map(|i| {
+            let (l, r) = (lhs_index(i), rhs_index(i));
+            match (is_valid(lhs, l), is_valid(rhs, r)) {
+                (true, true) => {
+                    let ordering = (0..size)
+                        .map(|k| compare_elements(l * size + k, r * size + k))
+                        .find(|ordering| ordering.is_ne())
+                        .unwrap_or(Ordering::Equal);
+                    Some(op(ordering))
+                }
+                (false, false) if nulls_equal => Some(true),
+                _ if nulls_equal => Some(false),
+                _ => None,
+            }
+        })
+        .collect();
+    Ok(BooleanArray::from((lhs.name(), results)))
+}
+
+impl DaftCompare<&Self> for FixedSizeListArray {
+    type Output = DaftResult<BooleanArray>;
+
+    fn equal(&self, rhs: &Self) -> Self::Output {
+        compare_fixed_size_lists(self, rhs, false, Ordering::is_eq)
+    }
+
+    fn eq_null_safe(&self, rhs: &Self) -> Self::Output {
+        compare_fixed_size_lists(self, rhs, true, Ordering::is_eq)
+    }
+
+    fn not_equal(&self, rhs: &Self) -> Self::Output {
+        compare_fixed_size_lists(self, rhs, false, Ordering::is_ne)
+    }
+
+    fn lt(&self, rhs: &Self) -> Self::Output {
+        compare_fixed_size_lists(self, rhs, false, Ordering::is_lt)
+    }
+
+    fn lte(&self, rhs: &Self) -> Self::Output {
+        compare_fixed_size_lists(self, rhs, false, Ordering::is_le)
+    }
+
+    fn gt(&self, rhs: &Self) -> Self::Output {
+        compare_fixed_size_lists(self, rhs, false, Ordering::is_gt)
+    }
+
+    fn gte(&self, rhs: &Self) -> Self::Output {
+        compare_fixed_size_lists(self, rhs, false, Ordering::is_ge)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use common_error::DaftResult;
 
-    use crate::{array::ops::DaftCompare, datatypes::Int64Array};
+    use crate::{
+        array::{ops::DaftCompare, FixedSizeListArray},
+        datatypes::{DataType, Field, Int64Array, IntervalArray},
+        series::IntoSeries,
+    };
 
     #[test]
     fn equal_int64_array_with_scalar() -> DaftResult<()> {
@@ -2414,4 +2587,74 @@ mod tests {
         assert_eq!(result[..], [None, None, Some(false)]);
         Ok(())
     }
+
+    #[test]
+    fn compare_interval_arrays() -> DaftResult<()> {
+        let lhs = IntervalArray::from_iter(
+            "a",
+            vec![Some((1, 0, 0)), Some((0, 30, 0)), Some((0, 1, 0)), None].into_iter(),
+        );
+        let rhs = IntervalArray::from_iter(
+            "b",
+            vec![Some((0, 31, 0)), Some((1, 0, 0)), Some((0, 1, 0)), None].into_iter(),
+        );
+        // a month and 30 days have the same length and are ordered by their components
+        let result: Vec<_> = lhs.lt(&rhs)?.into_iter().collect();
+        assert_eq!(result[..], [Some(true), Some(true), Some(false), None]);
+
+        let result: Vec<_> = lhs.equal(&rhs)?.into_iter().collect();
+        assert_eq!(result[..], [Some(false), Some(false), Some(true), None]);
+
+        let result: Vec<_> = lhs.eq_null_safe(&rhs)?.into_iter().collect();
+        assert_eq!(
+            result[..],
+            [Some(false), Some(false), Some(true), Some(true)]
+        );
+
+        let scalar = IntervalArray::from_iter("b", std::iter::once(Some((0, 1, 0))));
+        let result: Vec<_> = lhs.gt(&scalar)?.into_iter().collect();
+        assert_eq!(result[..], [Some(true), Some(true), Some(false), None]);
+        Ok(())
+    }
+
+    fn fixed_size_list(name: &str, values: Vec<i64>, validity: &[bool]) -> FixedSizeListArray {
+        let flat_child = Int64Array::from(("item", values)).into_series();
+        FixedSizeListArray::new(
+            Field::new(name, DataType::FixedSizeList(Box::new(DataType::Int64), 2)),
+            flat_child,
+            Some(arrow2::bitmap::Bitmap::from(validity)),
+        )
+    }
+
+    #[test]
+    fn compare_fixed_size_list_arrays() -> DaftResult<()> {
+        let lhs = fixed_size_list(
+            "a",
+            vec![1, 2, 1, 3, 0, 0, 2, 0],
+            &[true, true, false, true],
+        );
+        let rhs = fixed_size_list(
+            "b",
+            vec![1, 2, 1, 2, 0, 0, 1, 9],
+            &[true, true, false, true],
+        );
+
+        let result: Vec<_> = lhs.equal(&rhs)?.into_iter().collect();
+        assert_eq!(result[..], [Some(true), Some(false), None, Some(false)]);
+        let result: Vec<_> = lhs.eq_null_safe(&rhs)?.into_iter().collect();
+        assert_eq!(
+            result[..],
+            [Some(true), Some(false), Some(true), Some(false)]
+        );
+        let result: Vec<_> = lhs.gt(&rhs)?.into_iter().collect();
+        assert_eq!(result[..], [Some(false), Some(true), None, Some(true)]);
+        let result: Vec<_> = lhs.lte(&rhs)?.into_iter().collect();
+        assert_eq!(result[..], [Some(true), Some(false), None, Some(false)]);
+
+        // a single list is broadcast against all others
+        let scalar = fixed_size_list("c", vec![1, 3], &[true]);
+        let result: Vec<_> = lhs.equal(&scalar)?.into_iter().collect();
+        assert_eq!(result[..], [Some(false), Some(true), None, Some(false)]);
+        Ok(())
+    }
 }
@@ -0,0 +1,267 @@
+use arrow2::{array::PrimitiveArray, bitmap::Bitmap};
+use common_error::{DaftError, DaftResult};
+
+use super::as_arrow::AsArrow;
+use crate::{
+    array::FixedSizeListArray,
+    datatypes::{DataType, Field, Float64Array},
+    series::IntoSeries,
+};
+
+/// The rows of a numeric [`FixedSizeListArray`], flattened into a single buffer of `f64`s.
+struct Vectors {
+    values: Float64Array,
+    len: usize,
+    size: usize,
+    validity: Option<Bitmap>,
+}
+
+impl Vectors {
+    fn try_new(array: &FixedSizeListArray) -> DaftResult<Self> {
+        if !array.child_data_type().is_numeric() {
+            return Err(DaftError::TypeError(format!(
+                "Expected an embedding of numbers, but received {}",
+                array.data_type()
+            )));
+        }
+        let values = array.flat_child.cast(&DataType::Float64)?.f64()?.clone();
+        let size = array.fixed_element_len();
+        // a row is only usable if the list and every one of its elements are valid
+        let validity = match values.as_arrow().validity() {
+            None => array.validity().cloned(),
+            Some(child_validity) => Some(Bitmap::from_iter((0..array.len()).map(|i| {
+                array.validity().is_none_or(|v| v.get_bit(i))
+                    && (i * size..(i + 1) * size).all(|j| child_validity.get_bit(j))
+            }))),
+        };
+        Ok(Self {
+            values,
+            len: array.len(),
+            size,
+            validity,
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn get(&self, idx: usize) -> Option<&[f64]> {
+        if self.validity.as_ref().is_some_and(|v| !v.get_bit(idx)) {
+            return None;
+        }
+        Some(&self.values.as_arrow().values()[idx * self.size..(idx + 1) * self.size])
+    }
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+fn norm(a: &[f64]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+/// Computes `f` over each pair of rows of `lhs` and `rhs`, broadcasting either side if it has a
+/// single row. `f` is passed the index of the row of each side alongside the rows themselves.
+fn pairwise<F>(lhs: &Vectors, rhs: &Vectors, name: &str, f: F) -> DaftResult<Float64Array>
+where
+    F: Fn((usize, &[f64]), (usize, &[f64])) -> Option<f64>,
+{
+    if lhs.size != rhs.size {
+        return Err(DaftError::ValueError(format!(
+            "Expected embeddings of the same size, but received sizes {} and {}",
+            lhs.size, rhs.size
+        )));
+    }
+    let len = match (lhs.len(), rhs.len()) {
+        (l, r) if l == r => l,
+        (1, r) => r,
+        (l, 1) => l,
+        (l, r) => {
+            return Err(DaftError::ValueError(format!(
+                "Expected embeddings of the same length or of length 1, but received lengths {l} and {r}"
+            )))
+        }
+    };
+    let lhs_idx = |i: usize| if lhs.len() == 1 { 0 } else { i };
+    let rhs_idx = |i: usize| if rhs.len() == 1 { 0 } else { i };
+    let result = (0..len).map(|i| {
+        let (l, r) = (lhs_idx(i), rhs_idx(i));
+        f((l, lhs.get(l)?), (r, rhs.get(r)?))
+    });
+    Ok(Float64Array::from_iter(
+        Field::new(name, DataType::Float64),
+        result.collect::<Vec<_>>().into_iter(),
+    ))
+}
+
+impl FixedSizeListArray {
+    /// The dot product of each row of this array with the matching row of `other`. Either side
+    /// is broadcast if it has a single row.
+    ///
+    /// A row is null if either vector or any of their elements is null.
+    pub fn dot_product(&self, other: &Self) -> DaftResult<Float64Array> {
+        let lhs = Vectors::try_new(self)?;
+        let rhs = Vectors::try_new(other)?;
+        pairwise(&lhs, &rhs, self.name(), |(_, a), (_, b)| Some(dot(a, b)))
+    }
+
+    /// The cosine similarity of each row of this array with the matching row of `other`. Either
+    /// side is broadcast if it has a single row.
+    ///
+    /// With `normalized`, both sides are assumed to have a norm of 1, as returned by
+    /// [`FixedSizeListArray::l2_normalize`], and the similarity is just their dot product.
+    /// Otherwise, the norm of each row is computed once, so a broadcast query is only normalized
+    /// a single time. A row is null if either vector is null or has a norm of 0.
+    pub fn cosine_similarity(&self, other: &Self, normalized: bool) -> DaftResult<Float64Array> {
+        let lhs = Vectors::try_new(self)?;
+        let rhs = Vectors::try_new(other)?;
+        if normalized {
+            return pairwise(&lhs, &rhs, self.name(), |(_, a), (_, b)| Some(dot(a, b)));
+        }
+        let norms = |vectors: &Vectors| {
+            (0..vectors.len())
+                .map(|i| vectors.get(i).map(norm))
+                .collect::<Vec<_>>()
+        };
+        let (lhs_norms, rhs_norms) = (norms(&lhs), norms(&rhs));
+        pairwise(&lhs, &rhs, self.name(), |(i, a), (j, b)| {
+            let norms = lhs_norms[i]? * rhs_norms[j]?;
+            (norms != 0.0).then(|| dot(a, b) / norms)
+        })
+    }
+
+    /// Scales each row of this array to a norm of 1, so that repeated cosine similarities against
+    /// it can skip computing norms.
+    ///
+    /// The result holds `Float32` elements if this array does and `Float64` elements otherwise.
+    /// A row is null if its vector is null or has a norm of 0.
+    pub fn l2_normalize(&self) -> DaftResult<Self> {
+        let vectors = Vectors::try_new(self)?;
+        let mut values = vec![0.0; vectors.values.len()];
+        let validity = Bitmap::from_iter((0..vectors.len()).map(|i| {
+            let Some(vector) = vectors.get(i) else {
+                return false;
+            };
+            let norm = norm(vector);
+            if norm == 0.0 {
+                return false;
+            }
+            for (out, v) in values[i * vectors.size..].iter_mut().zip(vector) {
+                *out = v / norm;
+            }
+            true
+        }));
+        let validity = (validity.unset_bits() > 0).then_some(validity);
+
+        let values = Float64Array::new(
+            Field::new(self.flat_child.name(), DataType::Float64).into(),
+            Box::new(PrimitiveArray::from_vec(values)),
+        )?
+        .into_series();
+        let child_dtype = match self.child_data_type() {
+            DataType::Float32 => DataType::Float32,
+            _ => DataType::Float64,
+        };
+        Ok(Self::new(
+            Field::new(
+                self.name(),
+                DataType::FixedSizeList(Box::new(child_dtype.clone()), vectors.size),
+            ),
+            values.cast(&child_dtype)?,
+            validity,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::{ops::as_arrow::AsArrow, FixedSizeListArray},
+        datatypes::{DataType, Field, Float32Array, Float64Array, Int8Array},
+        series::IntoSeries,
+    };
+
+    fn vectors(name: &str, values: Vec<f32>, validity: Option<Vec<bool>>) -> FixedSizeListArray {
+        let validity = validity.map(|v| arrow2::bitmap::Bitmap::from(v.as_slice()));
+        FixedSizeListArray::new(
+            Field::new(
+                name,
+                DataType::FixedSizeList(Box::new(DataType::Float32), 2),
+            ),
+            Float32Array::from(("item", values)).into_series(),
+            validity,
+        )
+    }
+
+    fn values(array: &Float64Array) -> Vec<Option<f64>> {
+        array.as_arrow().iter().map(|v| v.copied()).collect()
+    }
+
+    #[test]
+    fn dot_product_and_cosine_similarity() -> DaftResult<()> {
+        let source = vectors(
+            "source",
+            vec![3.0, 4.0, 1.0, 0.0, 0.0, 0.0, 2.0, 2.0],
+            Some(vec![true, true, true, false]),
+        );
+        let query = vectors("query", vec![0.0, 2.0], None);
+
+        let dot = source.dot_product(&query)?;
+        assert_eq!(dot.name(), "source");
+        assert_eq!(values(&dot), vec![Some(8.0), Some(0.0), Some(0.0), None]);
+        assert_eq!(values(&query.dot_product(&source)?), values(&dot));
+
+        assert_eq!(
+            values(&source.cosine_similarity(&query, false)?),
+            vec![Some(0.8), Some(0.0), None, None]
+        );
+
+        let pairwise = vectors("other", vec![3.0, 4.0, 0.0, 1.0, 1.0, 1.0, 1.0, 1.0], None);
+        assert_eq!(
+            values(&source.cosine_similarity(&pairwise, false)?),
+            vec![Some(1.0), Some(0.0), None, None]
+        );
+
+        let mismatched = vectors("other", vec![1.0, 1.0, 1.0, 1.0], None);
+        assert!(source.dot_product(&mismatched).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn normalized_cosine_similarity() -> DaftResult<()> {
+        let source = vectors("source", vec![3.0, 4.0, 0.0, 0.0, 0.0, 5.0], None);
+        let query = vectors("query", vec![6.0, 8.0], None);
+
+        let normalized = source.l2_normalize()?;
+        assert_eq!(normalized.child_data_type(), &DataType::Float32);
+        assert_eq!(
+            normalized.flat_child.f32()?.as_arrow().values().as_slice(),
+            &[0.6, 0.8, 0.0, 0.0, 0.0, 1.0]
+        );
+        assert_eq!(
+            normalized.validity().map(|v| v.iter().collect::<Vec<_>>()),
+            Some(vec![true, false, true])
+        );
+
+        let similarity = normalized.cosine_similarity(&query.l2_normalize()?, true)?;
+        let expected = source.cosine_similarity(&query, false)?;
+        for (a, b) in values(&similarity).into_iter().zip(values(&expected)) {
+            match (a, b) {
+                (Some(a), Some(b)) => assert!((a - b).abs() < 1e-6),
+                (a, b) => assert_eq!(a, b),
+            }
+        }
+
+        let ints = FixedSizeListArray::new(
+            Field::new("ints", DataType::FixedSizeList(Box::new(DataType::Int8), 2)),
+            Int8Array::from(("item", vec![3, 4])).into_series(),
+            None,
+        );
+        assert_eq!(ints.l2_normalize()?.child_data_type(), &DataType::Float64);
+        Ok(())
+    }
+}
@@ -0,0 +1,292 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock, RwLock},
+};
+
+use arrow2::array::Array;
+use common_error::{DaftError, DaftResult};
+use uuid::Uuid;
+
+use super::as_arrow::AsArrow;
+use crate::{
+    array::ops::from_arrow::FromArrow,
+    datatypes::{BooleanArray, DataType, ExtensionArray, Field, FixedSizeBinaryArray, Utf8Array},
+    series::{IntoSeries, Series},
+};
+
+const UUID_BYTE_WIDTH: usize = 16;
+
+impl FixedSizeBinaryArray {
+    /// Formats each 16-byte value as a lowercase, hyphenated UUID string.
+    pub fn uuid_to_string(&self) -> DaftResult<Utf8Array> {
+        let arrow_array = self.as_arrow();
+        if arrow_array.size() != UUID_BYTE_WIDTH {
+            return Err(DaftError::TypeError(format!(
+                "Expected FixedSizeBinary[{UUID_BYTE_WIDTH}] to format as UUID, got FixedSizeBinary[{}]",
+                arrow_array.size()
+            )));
+        }
+        let formatted: arrow2::array::Utf8Array<i64> = arrow_array
+            .iter()
+            .map(|bytes| {
+                bytes.map(|bytes| {
+                    let bytes: [u8; UUID_BYTE_WIDTH] = bytes
+                        .try_into()
+                        .expect("FixedSizeBinary values have size 16");
+                    Uuid::from_bytes(bytes).hyphenated().to_string()
+                })
+            })
+            .collect();
+        Ok(Utf8Array::from((self.name(), Box::new(formatted))))
+    }
+}
+
+impl Utf8Array {
+    /// Parses each string as a UUID into its 16-byte representation.
+    ///
+    /// Hyphenated, simple, braced and URN forms are accepted. Strings that are not valid UUIDs become null.
+    pub fn parse_uuid(&self) -> DaftResult<FixedSizeBinaryArray> {
+        let parsed = self.as_arrow().iter().map(|val| {
+            val.and_then(|val| Uuid::try_parse(val).ok())
+                .map(Uuid::into_bytes)
+        });
+        Ok(FixedSizeBinaryArray::from_iter(
+            self.name(),
+            parsed,
+            UUID_BYTE_WIDTH,
+        ))
+    }
+
+    /// Returns whether each string is a well-formed JSON document. Nulls stay null.
+    pub fn is_valid_json(&self) -> DaftResult<BooleanArray> {
+        let is_valid = self
+            .as_arrow()
+            .iter()
+            .map(|val| val.map(|val| serde_json::from_str::<serde::de::IgnoredAny>(val).is_ok()));
+        Ok(BooleanArray::from_iter(self.name(), is_valid))
+    }
+}
+
+/// A custom cast for a user-defined extension type, from or to any other type.
+///
+/// Returning `None` falls back to casting the storage of the extension type.
+pub type ExtensionCastFn = dyn Fn(&Series, &DataType) -> DaftResult<Option<Series>> + Send + Sync;
+
+static EXTENSION_CASTS: LazyLock<RwLock<HashMap<String, Arc<ExtensionCastFn>>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Registers `cast` to be used for casts from or to the extension type `name`, replacing any
+/// previously registered cast.
+///
+/// The extension type itself is registered with [`daft_schema::extension::register_extension_type`].
+pub fn register_extension_cast(name: impl Into<String>, cast: Arc<ExtensionCastFn>) {
+    EXTENSION_CASTS.write().unwrap().insert(name.into(), cast);
+}
+
+/// Removes the cast registered for the extension type `name`, returning whether there was one.
+pub fn unregister_extension_cast(name: &str) -> bool {
+    EXTENSION_CASTS.write().unwrap().remove(name).is_some()
+}
+
+fn get_extension_cast(dtype: &DataType) -> Option<Arc<ExtensionCastFn>> {
+    match dtype {
+        DataType::Extension(name, ..) => EXTENSION_CASTS.read().unwrap().get(name).cloned(),
+        _ => None,
+    }
+}
+
+/// Casts from or to extension types: with a registered [`ExtensionCastFn`] if there is one, with
+/// the dedicated casts of the Arrow canonical extension types, or else as a cast of the storage.
+///
+/// Returns `None` if the cast is not from or to a different extension type.
+pub(crate) fn try_cast_extension(
+    name: &str,
+    data: &dyn Array,
+    from: &DataType,
+    to: &DataType,
+) -> DaftResult<Option<Series>> {
+    if from != to {
+        if let Some(cast) = get_extension_cast(from).or_else(|| get_extension_cast(to)) {
+            let series =
+                Series::from_arrow(Field::new(name, from.clone()).into(), data.to_boxed())?;
+            if let Some(casted) = cast(&series, to)? {
+                return Ok(Some(casted));
+            }
+        }
+    }
+    match (from, to) {
+        (from, DataType::Utf8) if from.is_uuid() => {
+            let storage = FixedSizeBinaryArray::from_arrow(
+                Field::new(name, DataType::FixedSizeBinary(UUID_BYTE_WIDTH)).into(),
+                data.convert_logical_type(arrow2::datatypes::DataType::FixedSizeBinary(
+                    UUID_BYTE_WIDTH,
+                )),
+            )?;
+            Ok(Some(storage.uuid_to_string()?.into_series()))
+        }
+        (DataType::Utf8, to) if to.is_uuid() => {
+            let utf8 =
+                Utf8Array::from_arrow(Field::new(name, DataType::Utf8).into(), data.to_boxed())?;
+            let storage = utf8.parse_uuid()?;
+            let extension = ExtensionArray::new(
+                Field::new(name, to.clone()).into(),
+                storage.data().convert_logical_type(to.to_arrow()?),
+            )?;
+            Ok(Some(extension.into_series()))
+        }
+        (DataType::Utf8, to) if to.is_json() => {
+            let utf8 =
+                Utf8Array::from_arrow(Field::new(name, DataType::Utf8).into(), data.to_boxed())?;
+            let is_valid = utf8.is_valid_json()?;
+            let validity = match utf8.as_arrow().validity() {
+                Some(validity) => is_valid.as_arrow().values() & validity,
+                None => is_valid.as_arrow().values().clone(),
+            };
+            let validity = (validity.unset_bits() > 0).then_some(validity);
+            let extension = ExtensionArray::new(
+                Field::new(name, to.clone()).into(),
+                utf8.data()
+                    .with_validity(validity)
+                    .convert_logical_type(to.to_arrow()?),
+            )?;
+            Ok(Some(extension.into_series()))
+        }
+        // any other cast from or to an extension type is a cast from or to its storage type
+        (from, to) if from != to && (from.is_extension() || to.is_extension()) => {
+            let storage = match from {
+                DataType::Extension(_, storage, _) => Series::from_arrow(
+                    Field::new(name, storage.as_ref().clone()).into(),
+                    data.convert_logical_type(storage.to_arrow()?),
+                )?,
+                _ => Series::from_arrow(Field::new(name, from.clone()).into(), data.to_boxed())?,
+            };
+            match to {
+                DataType::Extension(_, to_storage, _) => {
+                    let casted = storage.cast(to_storage)?;
+                    let extension = ExtensionArray::new(
+                        Field::new(name, to.clone()).into(),
+                        casted.to_arrow().convert_logical_type(to.to_arrow()?),
+                    )?;
+                    Ok(Some(extension.into_series()))
+                }
+                to => Ok(Some(storage.cast(to)?)),
+            }
+        }
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+
+    use super::{register_extension_cast, unregister_extension_cast};
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{DataType, Float64Array, Utf8Array},
+        series::{IntoSeries, Series},
+    };
+
+    #[test]
+    fn uuid_string_round_trip() -> DaftResult<()> {
+        let strings = Utf8Array::from_iter(
+            "a",
+            vec![
+                Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+                Some("67E5504410B1426F9247BB680E5FE0C8"),
+                Some("not a uuid"),
+                None,
+            ]
+            .into_iter(),
+        )
+        .into_series();
+
+        let uuids = strings.cast(&DataType::new_uuid())?;
+        assert!(uuids.data_type().is_uuid());
+        assert_eq!(uuids.validity().map(|v| v.unset_bits()), Some(2));
+
+        let round_tripped = uuids.cast(&DataType::Utf8)?;
+        let round_tripped = round_tripped.utf8()?;
+        assert_eq!(
+            round_tripped.as_arrow().iter().collect::<Vec<_>>(),
+            vec![
+                Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+                Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+                None,
+                None,
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn json_cast_nulls_out_invalid_documents() -> DaftResult<()> {
+        let strings = Utf8Array::from_iter(
+            "a",
+            vec![
+                Some(r#"{"a": [1, 2]}"#),
+                Some("{not json"),
+                Some("null"),
+                None,
+            ]
+            .into_iter(),
+        );
+        assert_eq!(
+            strings
+                .is_valid_json()?
+                .as_arrow()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(true), Some(false), Some(true), None]
+        );
+
+        let json = strings.into_series().cast(&DataType::new_json())?;
+        assert!(json.data_type().is_json());
+        assert_eq!(json.validity().map(|v| v.unset_bits()), Some(2));
+        Ok(())
+    }
+
+    #[test]
+    fn registered_extension_cast_is_used() -> DaftResult<()> {
+        let celsius = DataType::Extension(
+            "test.celsius".to_string(),
+            Box::new(DataType::Float64),
+            None,
+        );
+        register_extension_cast(
+            "test.celsius",
+            Arc::new(
+                |series: &Series, to: &DataType| -> DaftResult<Option<Series>> {
+                    if to != &DataType::Utf8 {
+                        return Ok(None);
+                    }
+                    let storage = series.cast(&DataType::Float64)?;
+                    let formatted = storage
+                        .f64()?
+                        .as_arrow()
+                        .iter()
+                        .map(|v| v.map(|v| format!("{v}°C")));
+                    Ok(Some(
+                        Utf8Array::from_iter(series.name(), formatted).into_series(),
+                    ))
+                },
+            ),
+        );
+
+        let temperatures = Float64Array::from(("t", vec![21.5, -3.0]))
+            .into_series()
+            .cast(&celsius)?;
+        assert_eq!(temperatures.data_type(), &celsius);
+
+        let formatted = temperatures.cast(&DataType::Utf8)?;
+        assert_eq!(
+            formatted.utf8()?.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("21.5°C"), Some("-3°C")]
+        );
+
+        assert!(unregister_extension_cast("test.celsius"));
+        Ok(())
+    }
+}
@@ -199,6 +199,7 @@ macro_rules! impl_logical_from_arrow {
 impl_logical_from_arrow!(DateType);
 impl_logical_from_arrow!(TimeType);
 impl_logical_from_arrow!(DurationType);
+impl_logical_from_arrow!(CategoricalType);
 impl_logical_from_arrow!(ImageType);
 impl_logical_from_arrow!(TimestampType);
 impl_logical_from_arrow!(TensorType);
@@ -7,7 +7,8 @@ use crate::{
     array::{DataArray, FixedSizeListArray, ListArray},
     datatypes::{
         logical::{
-            DateArray, DurationArray, LogicalArrayImpl, MapArray, TimeArray, TimestampArray,
+            CategoricalArray, DateArray, DurationArray, LogicalArrayImpl, MapArray, TimeArray,
+            TimestampArray,
         },
         BinaryArray, BooleanArray, DaftLogicalType, DaftPrimitiveType, ExtensionArray,
         FixedSizeBinaryArray, IntervalArray, NullArray, Utf8Array,
@@ -76,6 +77,7 @@ impl_array_arrow_get!(FixedSizeBinaryArray, &[u8]);
 impl_array_arrow_get!(DateArray, i32);
 impl_array_arrow_get!(TimeArray, i64);
 impl_array_arrow_get!(DurationArray, i64);
+impl_array_arrow_get!(CategoricalArray, u32);
 impl_array_arrow_get!(IntervalArray, months_days_ns);
 impl_array_arrow_get!(TimestampArray, i64);
 
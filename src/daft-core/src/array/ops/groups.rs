@@ -12,7 +12,7 @@ use crate::{
     array::{DataArray, FixedSizeListArray, ListArray, StructArray},
     datatypes::{
         BinaryArray, BooleanArray, DaftIntegerType, DaftNumericType, FixedSizeBinaryArray,
-        Float32Array, Float64Array, NullArray, Utf8Array,
+        Float32Array, Float64Array, IntervalArray, NullArray, Utf8Array,
     },
     prelude::Decimal128Array,
 };
@@ -179,6 +179,17 @@ impl IntoGroups for FixedSizeBinaryArray {
     }
 }
 
+impl IntoGroups for IntervalArray {
+    fn make_groups(&self) -> DaftResult<super::GroupIndicesPair> {
+        let array = self.as_arrow();
+        if array.null_count() > 0 {
+            make_groups(array.iter())
+        } else {
+            make_groups(array.values_iter())
+        }
+    }
+}
+
 impl IntoGroups for BooleanArray {
     fn make_groups(&self) -> DaftResult<super::GroupIndicesPair> {
         let array = self.as_arrow();
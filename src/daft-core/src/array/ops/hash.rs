@@ -11,8 +11,8 @@ use crate::{
     datatypes::{
         logical::{DateArray, TimeArray, TimestampArray},
         BinaryArray, BooleanArray, DaftPrimitiveType, Decimal128Array, FixedSizeBinaryArray,
-        Int16Array, Int32Array, Int64Array, Int8Array, NullArray, UInt16Array, UInt32Array,
-        UInt64Array, UInt8Array, Utf8Array,
+        Int16Array, Int32Array, Int64Array, Int8Array, IntervalArray, NullArray, UInt16Array,
+        UInt32Array, UInt64Array, UInt8Array, Utf8Array,
     },
     kernels,
     series::Series,
@@ -70,6 +70,18 @@ impl FixedSizeBinaryArray {
     }
 }
 
+impl IntervalArray {
+    pub fn hash(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
+        let as_arrowed = self.as_arrow();
+
+        let seed = seed.map(|v| v.as_arrow());
+
+        let result = kernels::hashing::hash(as_arrowed, seed)?;
+
+        Ok(DataArray::from((self.name(), Box::new(result))))
+    }
+}
+
 impl BooleanArray {
     pub fn hash(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
         let as_arrowed = self.as_arrow();
@@ -34,7 +34,15 @@ where
     type Output = DaftResult<BooleanArray>;
 
     fn is_in(&self, rhs: &Self) -> Self::Output {
-        collect_to_set_and_check_membership!(self, rhs)
+        // sorted integers are probed with a binary search, which is cheaper than hashing each one
+        let mut values = rhs.as_arrow().iter().flatten().copied().collect::<Vec<_>>();
+        values.sort_unstable();
+        values.dedup();
+        let result = self
+            .as_arrow()
+            .iter()
+            .map(|option| option.map(|value| values.binary_search(value).is_ok()));
+        Ok(BooleanArray::from_iter(self.name(), result))
     }
 }
 
@@ -77,6 +85,7 @@ impl_is_in_non_numeric_array!(BooleanArray);
 impl_is_in_non_numeric_array!(Utf8Array);
 impl_is_in_non_numeric_array!(BinaryArray);
 impl_is_in_non_numeric_array!(FixedSizeBinaryArray);
+impl_is_in_non_numeric_array!(IntervalArray);
 
 impl DaftIsIn<&Self> for NullArray {
     type Output = DaftResult<BooleanArray>;
@@ -90,3 +99,31 @@ impl DaftIsIn<&Self> for NullArray {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::DaftIsIn,
+        datatypes::{DataType, Field, Int64Array},
+    };
+
+    #[test]
+    fn integer_is_in_with_duplicates_and_nulls() -> DaftResult<()> {
+        let values = Int64Array::from_iter(
+            Field::new("values", DataType::Int64),
+            vec![Some(3), None, Some(-1), Some(7), Some(2)].into_iter(),
+        );
+        let items = Int64Array::from_iter(
+            Field::new("items", DataType::Int64),
+            vec![Some(7), None, Some(3), Some(7), Some(-1)].into_iter(),
+        );
+        let result = values.is_in(&items)?;
+        assert_eq!(
+            result.into_iter().collect::<Vec<_>>(),
+            vec![Some(true), None, Some(true), Some(true), Some(false)]
+        );
+        Ok(())
+    }
+}
@@ -55,6 +55,29 @@ impl MapArray {
         }
     }
 
+    /// Returns the keys of each map as a list, in the order they are stored.
+    pub fn map_keys(&self) -> DaftResult<ListArray> {
+        self.entries_field("key")
+    }
+
+    /// Returns the values of each map as a list, in the order they are stored.
+    pub fn map_values(&self) -> DaftResult<ListArray> {
+        self.entries_field("value")
+    }
+
+    fn entries_field(&self, name: &str) -> DaftResult<ListArray> {
+        let child = self.physical.flat_child.struct_()?.get(name)?;
+        Ok(ListArray::new(
+            Field::new(
+                self.name(),
+                DataType::List(Box::new(child.data_type().clone())),
+            ),
+            child,
+            self.physical.offsets().clone(),
+            self.physical.validity().cloned(),
+        ))
+    }
+
     fn get_single_key(&self, key_to_get: &Series, coerce_value: &DataType) -> DaftResult<Series> {
         let result: Vec<_> = self
             .physical
@@ -91,3 +114,105 @@ impl MapArray {
         Series::concat(&result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use arrow2::array::{MutableMapArray, MutablePrimitiveArray, MutableUtf8Array};
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::{as_arrow::AsArrow, IntoGroups},
+        datatypes::{BooleanArray, DataType, Field, UInt64Array},
+        series::{IntoSeries, Series},
+    };
+
+    fn make_maps() -> DaftResult<Series> {
+        let mut maps = MutableMapArray::<MutableUtf8Array<i64>, MutablePrimitiveArray<i64>>::new();
+        maps.try_push_entries([("a", Some(1)), ("b", None)])?;
+        maps.push_null();
+        maps.try_push_entries([("c", Some(3))])?;
+        maps.try_push_entries([("a", Some(1)), ("b", None)])?;
+        let maps: arrow2::array::MapArray = maps.into();
+        let dtype = DataType::Map {
+            key: Box::new(DataType::Utf8),
+            value: Box::new(DataType::Int64),
+        };
+        Series::from_arrow(Field::new("m", dtype).into(), maps.boxed())
+    }
+
+    #[test]
+    fn map_keys_and_values() -> DaftResult<()> {
+        let maps = make_maps()?;
+        let keys = maps.map_keys()?;
+        assert_eq!(keys.data_type(), &DataType::List(Box::new(DataType::Utf8)));
+        assert_eq!(keys.name(), "m");
+        assert_eq!(keys.len(), 4);
+        assert_eq!(keys.validity(), maps.validity());
+
+        let keys = keys.list()?;
+        assert_eq!(keys.offsets().as_slice(), &[0, 2, 2, 3, 5]);
+        assert_eq!(
+            keys.flat_child
+                .utf8()?
+                .as_arrow()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some("a"), Some("b"), Some("c"), Some("a"), Some("b")]
+        );
+
+        let values = maps.map_values()?;
+        assert_eq!(
+            values.data_type(),
+            &DataType::List(Box::new(DataType::Int64))
+        );
+        assert_eq!(
+            values
+                .list()?
+                .flat_child
+                .i64()?
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(1), None, Some(3), Some(1), None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn maps_survive_filter_take_concat_and_groupby() -> DaftResult<()> {
+        let maps = make_maps()?;
+
+        let mask = BooleanArray::from(("mask", [true, false, true, false].as_slice()));
+        let filtered = maps.filter(&mask)?;
+        assert_eq!(filtered.data_type(), maps.data_type());
+        assert_eq!(
+            filtered.map_keys()?.list()?.offsets().as_slice(),
+            &[0, 2, 3]
+        );
+
+        let taken = maps.take(&UInt64Array::from(("idx", vec![2, 0])).into_series())?;
+        let key = Series::from_arrow(
+            Field::new("key", DataType::Utf8).into(),
+            Box::new(arrow2::array::Utf8Array::<i64>::from_slice(["c"])),
+        )?;
+        assert_eq!(
+            taken
+                .map_get(&key)?
+                .i64()?
+                .as_arrow()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(&3), None]
+        );
+
+        let concatenated = Series::concat(&[&maps, &taken])?;
+        assert_eq!(concatenated.data_type(), maps.data_type());
+        assert_eq!(concatenated.len(), 6);
+
+        let (_, mut groups) = maps.make_groups()?;
+        groups.sort();
+        assert_eq!(groups, vec![vec![0, 3], vec![1], vec![2]]);
+        Ok(())
+    }
+}
@@ -11,6 +11,7 @@ mod binary;
 mod bitwise;
 mod bool_agg;
 pub(crate) mod broadcast;
+mod calendar;
 pub(crate) mod cast;
 mod cbrt;
 mod ceil;
@@ -20,7 +21,9 @@ mod comparison;
 mod concat;
 mod concat_agg;
 mod count;
+mod embedding;
 mod exp;
+pub(crate) mod extension;
 mod filter;
 mod float;
 mod floor;
@@ -65,6 +68,7 @@ mod utf8;
 use std::hash::BuildHasher;
 
 use common_error::DaftResult;
+pub use extension::{register_extension_cast, unregister_extension_cast, ExtensionCastFn};
 pub use hll_sketch::HLL_SKETCH_DTYPE;
 pub use sort::{build_multi_array_bicompare, build_multi_array_compare};
 pub use utf8::{PadPlacement, Utf8NormalizeOptions};
@@ -1,6 +1,6 @@
 use arrow2::{
     array::ord::{self, DynComparator},
-    types::Index,
+    types::{months_days_ns, Index},
 };
 use common_error::DaftResult;
 
@@ -11,7 +11,7 @@ use crate::{
     array::{DataArray, FixedSizeListArray, ListArray, StructArray},
     datatypes::{
         logical::{
-            DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray,
+            CategoricalArray, DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray,
             FixedShapeSparseTensorArray, FixedShapeTensorArray, ImageArray, MapArray,
             SparseTensorArray, TensorArray, TimeArray, TimestampArray,
         },
@@ -734,8 +734,76 @@ impl ExtensionArray {
 }
 
 impl IntervalArray {
-    pub fn sort(&self, _descending: bool, _nulls_first: bool) -> DaftResult<Self> {
-        todo!("impl sort for IntervalArray")
+    pub fn argsort<I>(&self, descending: bool, nulls_first: bool) -> DaftResult<DataArray<I>>
+    where
+        I: DaftIntegerType,
+        <I as DaftNumericType>::Native: arrow2::types::Index,
+    {
+        let arrow_array = self.as_arrow();
+
+        let result =
+            crate::array::ops::arrow2::sort::primitive::indices::indices_sorted_unstable_by::<
+                I::Native,
+                months_days_ns,
+                _,
+            >(arrow_array, ord::total_cmp, descending, nulls_first);
+
+        Ok(DataArray::<I>::from((self.name(), Box::new(result))))
+    }
+
+    pub fn argsort_multikey<I>(
+        &self,
+        others: &[Series],
+        descending: &[bool],
+        nulls_first: &[bool],
+    ) -> DaftResult<DataArray<I>>
+    where
+        I: DaftIntegerType,
+        <I as DaftNumericType>::Native: arrow2::types::Index,
+    {
+        let arrow_array = self.as_arrow();
+        let first_desc = *descending.first().unwrap();
+        let first_nulls_first = *nulls_first.first().unwrap();
+
+        let others_cmp = build_multi_array_compare(others, &descending[1..], &nulls_first[1..])?;
+
+        let values = arrow_array.values().as_slice();
+
+        let result = multi_column_idx_sort(
+            arrow_array.validity(),
+            |a: &I::Native, b: &I::Native| {
+                let a = a.to_usize();
+                let b = b.to_usize();
+                let l = unsafe { values.get_unchecked(a) };
+                let r = unsafe { values.get_unchecked(b) };
+                let ordering = if first_desc { r.cmp(l) } else { l.cmp(r) };
+                match ordering {
+                    std::cmp::Ordering::Equal => others_cmp(a, b),
+                    v => v,
+                }
+            },
+            &others_cmp,
+            arrow_array.len(),
+            first_nulls_first,
+        );
+
+        Ok(DataArray::<I>::from((self.name(), Box::new(result))))
+    }
+
+    pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        let options = arrow2::compute::sort::SortOptions {
+            descending,
+            nulls_first,
+        };
+
+        let result = crate::array::ops::arrow2::sort::primitive::sort::sort_by::<months_days_ns, _>(
+            self.as_arrow(),
+            ord::total_cmp,
+            &options,
+            None,
+        );
+
+        Self::new(self.field.clone(), Box::new(result))
     }
 }
 
@@ -767,6 +835,14 @@ impl DurationArray {
     }
 }
 
+impl CategoricalArray {
+    /// Sorts by code, so values are ordered like their categories are in the data type.
+    pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        let new_array = self.physical.sort(descending, nulls_first)?;
+        Ok(Self::new(self.field.clone(), new_array))
+    }
+}
+
 impl TimestampArray {
     pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
         let new_array = self.physical.sort(descending, nulls_first)?;
@@ -43,7 +43,11 @@ mod tests {
 
     use common_error::DaftResult;
 
-    use crate::{array::prelude::*, datatypes::prelude::*, series::IntoSeries};
+    use crate::{
+        array::prelude::*,
+        datatypes::prelude::*,
+        series::{IntoSeries, Series},
+    };
 
     #[test]
     fn test_sparse_tensor_to_fixed_shape_sparse_tensor_roundtrip() -> DaftResult<()> {
@@ -153,4 +157,48 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_sparse_tensor_take_filter_concat() -> DaftResult<()> {
+        let dense = FixedSizeListArray::new(
+            Field::new(
+                "tensor",
+                DataType::FixedSizeList(Box::new(DataType::Int64), 4),
+            ),
+            Int64Array::from(("item", vec![0, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 3])).into_series(),
+            Some(arrow2::bitmap::Bitmap::from([true, false, true])),
+        )
+        .into_series()
+        .cast(&DataType::FixedShapeTensor(
+            Box::new(DataType::Int64),
+            vec![2, 2],
+        ))?;
+        let cases = [
+            (
+                dense.clone(),
+                DataType::FixedShapeSparseTensor(Box::new(DataType::Int64), vec![2, 2], false),
+            ),
+            (
+                dense.cast(&DataType::Tensor(Box::new(DataType::Int64)))?,
+                DataType::SparseTensor(Box::new(DataType::Int64), false),
+            ),
+        ];
+        let idx = UInt64Array::from(("idx", vec![2u64, 0, 1])).into_series();
+        let mask = BooleanArray::from(("mask", [true, false, true].as_slice()));
+        for (dense, sparse_dtype) in cases {
+            let sparse = dense.cast(&sparse_dtype)?;
+            let to_dense = |s: Series| s.cast(dense.data_type()).map(|s| s.to_arrow());
+
+            assert_eq!(to_dense(sparse.take(&idx)?)?, dense.take(&idx)?.to_arrow());
+            assert_eq!(
+                to_dense(sparse.filter(&mask)?)?,
+                dense.filter(&mask)?.to_arrow()
+            );
+            assert_eq!(
+                to_dense(Series::concat(&[&sparse, &sparse.slice(1, 3)?])?)?,
+                Series::concat(&[&dense, &dense.slice(1, 3)?])?.to_arrow()
+            );
+        }
+        Ok(())
+    }
 }
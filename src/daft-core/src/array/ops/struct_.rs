@@ -1,6 +1,11 @@
 use common_error::{DaftError, DaftResult};
+use itertools::Itertools;
 
-use crate::{array::StructArray, series::Series};
+use crate::{
+    array::StructArray,
+    datatypes::{DataType, Field},
+    series::Series,
+};
 
 impl StructArray {
     pub fn get(&self, name: &str) -> DaftResult<Series> {
@@ -29,6 +34,79 @@ impl StructArray {
                 .collect::<Vec<&str>>()
         )))
     }
+
+    /// Returns a copy of this struct with the field called `name` set to `value`, replacing an
+    /// existing field in place or appending a new one.
+    ///
+    /// `value` must have one value per struct, or a single value to broadcast to all of them.
+    pub fn with_field(&self, name: &str, value: &Series) -> DaftResult<Self> {
+        let value = match value.len() {
+            len if len == self.len() => value.rename(name),
+            1 => value.broadcast(self.len())?.rename(name),
+            len => {
+                return Err(DaftError::ValueError(format!(
+                    "Expected the value of field {name} to have length {} or 1, got {len}",
+                    self.len()
+                )))
+            }
+        };
+        let mut children = self.children.clone();
+        match children.iter().position(|c| c.name() == name) {
+            Some(i) => children[i] = value,
+            None => children.push(value),
+        }
+        Ok(self.with_children(children))
+    }
+
+    /// Returns a copy of this struct without the fields in `names`.
+    pub fn drop_fields(&self, names: &[String]) -> DaftResult<Self> {
+        for name in names {
+            self.get(name)?;
+        }
+        let children = self
+            .children
+            .iter()
+            .filter(|c| !names.iter().any(|name| name == c.name()))
+            .cloned()
+            .collect::<Vec<_>>();
+        if children.is_empty() {
+            return Err(DaftError::ValueError(
+                "Cannot drop every field from a struct".to_string(),
+            ));
+        }
+        Ok(self.with_children(children))
+    }
+
+    /// Returns a copy of this struct with its fields renamed by `mapping`, a list of
+    /// `(old name, new name)` pairs.
+    pub fn rename_fields(&self, mapping: &[(String, String)]) -> DaftResult<Self> {
+        for (old, _) in mapping {
+            self.get(old)?;
+        }
+        let children = self
+            .children
+            .iter()
+            .map(|c| match mapping.iter().find(|(old, _)| old == c.name()) {
+                Some((_, new)) => c.rename(new),
+                None => c.clone(),
+            })
+            .collect::<Vec<_>>();
+        if let Some(name) = children.iter().map(Series::name).duplicates().next() {
+            return Err(DaftError::ValueError(format!(
+                "Renaming struct fields would result in duplicate field {name}"
+            )));
+        }
+        Ok(self.with_children(children))
+    }
+
+    fn with_children(&self, children: Vec<Series>) -> Self {
+        let fields = children.iter().map(|c| c.field().clone()).collect();
+        Self::new(
+            Field::new(self.name(), DataType::Struct(fields)),
+            children,
+            self.validity().cloned(),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -84,4 +162,59 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_struct_modify_fields() -> DaftResult<()> {
+        let a = Int64Array::from(("a", vec![1, 2, 3])).into_series();
+        let b = Utf8Array::from(("b", ["x", "y", "z"].as_slice())).into_series();
+        let parent = StructArray::new(
+            Field::new(
+                "s",
+                DataType::Struct(vec![
+                    Field::new("a", DataType::Int64),
+                    Field::new("b", DataType::Utf8),
+                ]),
+            ),
+            vec![a, b],
+            Some(Bitmap::from(&[true, false, true])),
+        );
+
+        let c = BooleanArray::from(("lit", [true].as_slice())).into_series();
+        let added = parent.with_field("c", &c)?;
+        assert_eq!(
+            added.data_type(),
+            &DataType::Struct(vec![
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::Utf8),
+                Field::new("c", DataType::Boolean),
+            ])
+        );
+        assert_eq!(added.get("c")?.len(), 3);
+        assert_eq!(added.validity(), parent.validity());
+
+        let replaced =
+            parent.with_field("a", &Float64Array::from(("x", vec![0.5])).into_series())?;
+        assert_eq!(
+            replaced.children[0].field(),
+            &Field::new("a", DataType::Float64)
+        );
+        assert_eq!(replaced.children.len(), 2);
+
+        let dropped = parent.drop_fields(&["a".to_string()])?;
+        assert_eq!(
+            dropped.data_type(),
+            &DataType::Struct(vec![Field::new("b", DataType::Utf8)])
+        );
+        assert!(parent.drop_fields(&["missing".to_string()]).is_err());
+        assert!(parent
+            .drop_fields(&["a".to_string(), "b".to_string()])
+            .is_err());
+
+        let renamed = parent.rename_fields(&[("a".to_string(), "z".to_string())])?;
+        assert_eq!(renamed.children[0].name(), "z");
+        assert!(parent
+            .rename_fields(&[("a".to_string(), "b".to_string())])
+            .is_err());
+        Ok(())
+    }
 }
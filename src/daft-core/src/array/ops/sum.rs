@@ -56,3 +56,32 @@ impl_daft_numeric_agg!(UInt64Type, u64);
 impl_daft_numeric_agg!(Float32Type, f32);
 impl_daft_numeric_agg!(Float64Type, f64);
 impl_daft_numeric_agg!(Decimal128Type, i128);
+
+/// Intervals are summed component-wise, without normalizing days into months.
+impl DaftSumAggable for &IntervalArray {
+    type Output = DaftResult<IntervalArray>;
+
+    fn sum(&self) -> Self::Output {
+        let sum_value = self
+            .as_arrow()
+            .iter()
+            .flatten()
+            .copied()
+            .reduce(|l, r| l + r);
+        Ok(IntervalArray::from_iter(
+            self.name(),
+            std::iter::once(sum_value),
+        ))
+    }
+
+    fn grouped_sum(&self, groups: &GroupIndices) -> Self::Output {
+        let arrow_array = self.as_arrow();
+        let sum_per_group = groups.iter().map(|g| {
+            g.iter()
+                .filter(|index| arrow_array.is_valid(**index as usize))
+                .map(|index| arrow_array.value(*index as usize))
+                .reduce(|l, r| l + r)
+        });
+        Ok(IntervalArray::from_iter(self.name(), sum_per_group))
+    }
+}
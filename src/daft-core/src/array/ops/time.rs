@@ -10,7 +10,7 @@ use arrow2::{
     datatypes::ArrowDataType,
     types::months_days_ns,
 };
-use chrono::{Duration, NaiveDate, NaiveTime, Timelike};
+use chrono::{Duration, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike};
 use common_error::{DaftError, DaftResult};
 
 use super::as_arrow::AsArrow;
@@ -62,6 +62,33 @@ fn process_interval(interval: &str, timeunit: TimeUnit) -> DaftResult<i64> {
     }
 }
 
+/// Parses the timezone string `$tz` and evaluates `$body` with it bound to `$parsed`, which is
+/// either a fixed offset or a named timezone.
+macro_rules! with_parsed_timezone {
+    ($tz:expr, |$parsed:ident| $body:expr) => {
+        if let Ok($parsed) = arrow2::temporal_conversions::parse_offset($tz) {
+            $body
+        } else if let Ok($parsed) = arrow2::temporal_conversions::parse_offset_tz($tz) {
+            $body
+        } else {
+            Err(DaftError::TypeError(format!(
+                "Cannot parse timezone in Timestamp datatype: {}",
+                $tz
+            )))
+        }
+    };
+}
+
+fn naive_datetime_to_timestamp(dt: &NaiveDateTime, timeunit: TimeUnit) -> Option<i64> {
+    let dt = dt.and_utc();
+    match timeunit {
+        TimeUnit::Seconds => Some(dt.timestamp()),
+        TimeUnit::Milliseconds => Some(dt.timestamp_millis()),
+        TimeUnit::Microseconds => Some(dt.timestamp_micros()),
+        TimeUnit::Nanoseconds => dt.timestamp_nanos_opt(),
+    }
+}
+
 impl DateArray {
     pub fn day(&self) -> DaftResult<UInt32Array> {
         let input_array = self
@@ -315,7 +342,25 @@ impl TimestampArray {
                         TimeUnit::Nanoseconds => Duration::nanoseconds(truncate_by_amount),
                     };
 
-                    let truncated_dt = original_dt - truncate_by_duration;
+                    // Truncate the wall clock time, then map it back to an instant in `tz` so that
+                    // boundaries stay on local time across DST transitions. Ambiguous local times
+                    // resolve to the latest instant not after the original one, and local times
+                    // skipped by a transition fall back to subtracting the elapsed duration.
+                    let truncated_naive = arrow2::temporal_conversions::timestamp_to_naive_datetime(
+                        naive_ts - truncate_by_amount,
+                        tu_arrow,
+                    );
+                    let truncated_dt = match tz.from_local_datetime(&truncated_naive) {
+                        LocalResult::Single(dt) => dt,
+                        LocalResult::Ambiguous(earliest, latest) => {
+                            if latest <= original_dt {
+                                latest
+                            } else {
+                                earliest
+                            }
+                        }
+                        LocalResult::None => original_dt - truncate_by_duration,
+                    };
                     match tu {
                         TimeUnit::Seconds => Ok(truncated_dt.timestamp()),
                         TimeUnit::Milliseconds => Ok(truncated_dt.timestamp_millis()),
@@ -379,6 +424,103 @@ impl TimestampArray {
         ))
     }
 
+    /// Annotates the timestamps with the timezone `tz`, keeping the instants they represent.
+    ///
+    /// Only timezone-aware timestamps can be converted, since naive timestamps do not represent
+    /// an instant. Use [`TimestampArray::replace_time_zone`] to localize them instead.
+    pub fn convert_time_zone(&self, tz: &str) -> DaftResult<Self> {
+        let DataType::Timestamp(timeunit, from_tz) = self.data_type() else {
+            unreachable!("Timestamp array must have Timestamp datatype")
+        };
+        if from_tz.is_none() {
+            return Err(DaftError::ValueError(format!(
+                "Cannot convert the timezone of a naive timestamp, got {}. Use replace_time_zone to localize it first",
+                self.data_type()
+            )));
+        }
+        with_parsed_timezone!(tz, |_parsed| Ok(()))?;
+        Ok(Self::new(
+            Field::new(
+                self.name(),
+                DataType::Timestamp(*timeunit, Some(tz.to_string())),
+            ),
+            self.physical.clone(),
+        ))
+    }
+
+    /// Replaces the timezone of the timestamps with `tz`, keeping their wall clock time.
+    ///
+    /// Naive timestamps are localized into `tz`, and `None` drops the timezone, leaving the
+    /// local time as a naive timestamp. Local times that are ambiguous in `tz` resolve to their
+    /// earliest instant, while local times that do not exist in `tz` become null.
+    pub fn replace_time_zone(&self, tz: Option<&str>) -> DaftResult<Self> {
+        let DataType::Timestamp(timeunit, _) = self.data_type() else {
+            unreachable!("Timestamp array must have Timestamp datatype")
+        };
+        let wall_clock = self.wall_clock()?;
+        Self::from_wall_clock(self.name(), &wall_clock, *timeunit, tz)
+    }
+
+    /// Returns the local date and time of each timestamp in its timezone, or its UTC date and
+    /// time if it is naive.
+    pub(crate) fn wall_clock(&self) -> DaftResult<Vec<Option<NaiveDateTime>>> {
+        let physical = self.physical.as_arrow();
+        let DataType::Timestamp(timeunit, tz) = self.data_type() else {
+            unreachable!("Timestamp array must have Timestamp datatype")
+        };
+        let tu = timeunit.to_arrow();
+        match tz {
+            Some(tz) => with_parsed_timezone!(tz, |tz| Ok(physical
+                .iter()
+                .map(|ts| {
+                    ts.map(|ts| {
+                        arrow2::temporal_conversions::timestamp_to_datetime(*ts, tu, &tz)
+                            .naive_local()
+                    })
+                })
+                .collect())),
+            None => Ok(physical
+                .iter()
+                .map(|ts| {
+                    ts.map(|ts| arrow2::temporal_conversions::timestamp_to_naive_datetime(*ts, tu))
+                })
+                .collect()),
+        }
+    }
+
+    /// Builds timestamps from local dates and times in `tz`, the inverse of
+    /// [`TimestampArray::wall_clock`].
+    ///
+    /// Local times that are ambiguous in `tz` resolve to their earliest instant, while local
+    /// times that do not exist in `tz` become null.
+    pub(crate) fn from_wall_clock(
+        name: &str,
+        wall_clock: &[Option<NaiveDateTime>],
+        timeunit: TimeUnit,
+        tz: Option<&str>,
+    ) -> DaftResult<Self> {
+        let timestamps = match tz {
+            Some(tz) => with_parsed_timezone!(tz, |tz| Ok(wall_clock
+                .iter()
+                .map(|dt| {
+                    dt.and_then(|dt| {
+                        let dt = tz.from_local_datetime(&dt).earliest()?;
+                        naive_datetime_to_timestamp(&dt.naive_utc(), timeunit)
+                    })
+                })
+                .collect::<arrow2::array::PrimitiveArray<i64>>())),
+            None => Ok(wall_clock
+                .iter()
+                .map(|dt| dt.and_then(|dt| naive_datetime_to_timestamp(&dt, timeunit)))
+                .collect::<arrow2::array::PrimitiveArray<i64>>()),
+        }?;
+
+        Ok(Self::new(
+            Field::new(name, DataType::Timestamp(timeunit, tz.map(str::to_string))),
+            Int64Array::from((name, Box::new(timestamps))),
+        ))
+    }
+
     pub fn add_interval(&self, interval: &IntervalArray) -> DaftResult<Self> {
         self.interval_helper(interval, add_interval)
     }
@@ -588,3 +730,79 @@ impl TimeArray {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{
+            logical::TimestampArray, DaftArrayType, DataType, Field, Int64Array, TimeUnit,
+        },
+    };
+
+    fn micros(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> i64 {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_micros()
+    }
+
+    fn naive_timestamps(values: Vec<i64>) -> TimestampArray {
+        TimestampArray::new(
+            Field::new("ts", DataType::Timestamp(TimeUnit::Microseconds, None)),
+            Int64Array::from(("ts", values)),
+        )
+    }
+
+    fn values(array: &TimestampArray) -> Vec<Option<i64>> {
+        array
+            .physical
+            .as_arrow()
+            .iter()
+            .map(|v| v.copied())
+            .collect()
+    }
+
+    #[test]
+    fn replace_and_convert_time_zone() -> DaftResult<()> {
+        let naive = naive_timestamps(vec![micros(2024, 1, 1, 9, 30), micros(2024, 3, 10, 2, 30)]);
+        let localized = naive.replace_time_zone(Some("America/New_York"))?;
+        assert_eq!(
+            localized.data_type(),
+            &DataType::Timestamp(TimeUnit::Microseconds, Some("America/New_York".to_string()))
+        );
+        // 2:30 is skipped by the DST transition in New York
+        assert_eq!(
+            values(&localized),
+            vec![Some(micros(2024, 1, 1, 14, 30)), None]
+        );
+
+        let converted = localized.convert_time_zone("+09:00")?;
+        assert_eq!(values(&converted), values(&localized));
+        assert_eq!(
+            values(&converted.replace_time_zone(None)?),
+            vec![Some(micros(2024, 1, 1, 23, 30)), None]
+        );
+
+        assert!(naive.convert_time_zone("UTC").is_err());
+        assert!(localized.convert_time_zone("Not/A_Zone").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn truncate_keeps_local_boundaries_across_dst() -> DaftResult<()> {
+        let localized = naive_timestamps(vec![micros(2024, 3, 10, 15, 0)])
+            .replace_time_zone(Some("America/New_York"))?;
+        let truncated = localized.truncate("1 day", None)?;
+        assert_eq!(
+            values(&truncated.replace_time_zone(None)?),
+            vec![Some(micros(2024, 3, 10, 0, 0))]
+        );
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use std::{
     borrow::Cow,
+    collections::HashMap,
     iter::{self, RepeatN},
     sync::Arc,
 };
@@ -241,13 +242,22 @@ fn regex_replace<'a>(
     arr_iter: impl Iterator<Item = Option<&'a str>>,
     regex_iter: impl Iterator<Item = Option<Result<regex::Regex, regex::Error>>>,
     replacement_iter: impl Iterator<Item = Option<&'a str>>,
+    max_replacements: Option<usize>,
     name: &str,
 ) -> DaftResult<Utf8Array> {
     let arrow_result = arr_iter
         .zip(regex_iter)
         .zip(replacement_iter)
         .map(|((val, re), replacement)| match (val, re, replacement) {
-            (Some(val), Some(re), Some(replacement)) => Ok(Some(re?.replace_all(val, replacement))),
+            // `replacen` treats a limit of 0 as unlimited, so it is handled separately
+            (Some(val), Some(_), Some(_)) if max_replacements == Some(0) => {
+                Ok(Some(Cow::Borrowed(val)))
+            }
+            (Some(val), Some(re), Some(replacement)) => Ok(Some(re?.replacen(
+                val,
+                max_replacements.unwrap_or(0),
+                replacement,
+            ))),
             _ => Ok(None),
         })
         .collect::<DaftResult<arrow2::array::Utf8Array<i64>>>();
@@ -259,13 +269,17 @@ fn replace_on_literal<'a>(
     arr_iter: impl Iterator<Item = Option<&'a str>>,
     pattern_iter: impl Iterator<Item = Option<&'a str>>,
     replacement_iter: impl Iterator<Item = Option<&'a str>>,
+    max_replacements: Option<usize>,
     name: &str,
 ) -> DaftResult<Utf8Array> {
     let arrow_result = arr_iter
         .zip(pattern_iter)
         .zip(replacement_iter)
         .map(|((val, pat), replacement)| match (val, pat, replacement) {
-            (Some(val), Some(pat), Some(replacement)) => Ok(Some(val.replace(pat, replacement))),
+            (Some(val), Some(pat), Some(replacement)) => Ok(Some(match max_replacements {
+                Some(n) => val.replacen(pat, replacement, n),
+                None => val.replace(pat, replacement),
+            })),
             _ => Ok(None),
         })
         .collect::<DaftResult<arrow2::array::Utf8Array<i64>>>();
@@ -273,7 +287,16 @@ fn replace_on_literal<'a>(
     Ok(Utf8Array::from((name, Box::new(arrow_result?))))
 }
 
-fn substring(s: &str, start: usize, len: Option<usize>) -> Option<&str> {
+/// Returns the substring of `s` starting at the `start`-th character, where a negative `start`
+/// counts from the end of the string.
+fn substring(s: &str, start: i64, len: Option<usize>) -> Option<&str> {
+    let start = if start < 0 {
+        s.chars()
+            .count()
+            .saturating_sub(start.unsigned_abs() as usize)
+    } else {
+        start as usize
+    };
     let mut char_indices = s.char_indices();
 
     if let Some((start_pos, _)) = char_indices.nth(start) {
@@ -307,7 +330,7 @@ fn substr_compute_result<I, U, E, R>(
     length: U,
 ) -> DaftResult<Utf8Array>
 where
-    I: Iterator<Item = Result<Option<usize>, E>>,
+    I: Iterator<Item = Result<Option<i64>, E>>,
     U: Iterator<Item = Result<Option<usize>, R>>,
 {
     let arrow_result = iter
@@ -554,7 +577,18 @@ impl Utf8Array {
         Ok(result)
     }
 
-    pub fn replace(&self, pattern: &Self, replacement: &Self, regex: bool) -> DaftResult<Self> {
+    /// Replaces occurrences of `pattern` with `replacement`, up to `max_replacements` per string.
+    ///
+    /// In regex mode the replacement may refer to capture groups as `$1`, `${1}` or `${name}`,
+    /// and `$$` stands for a literal `$`. When the patterns come from a column, each distinct
+    /// pattern is only compiled once.
+    pub fn replace(
+        &self,
+        pattern: &Self,
+        replacement: &Self,
+        regex: bool,
+        max_replacements: Option<usize>,
+    ) -> DaftResult<Self> {
         let (is_full_null, expected_size) = parse_inputs(self, &[pattern, replacement])
             .map_err(|e| DaftError::ValueError(format!("Error in replace: {e}")))?;
         if is_full_null {
@@ -571,18 +605,41 @@ impl Utf8Array {
             (true, 1) => {
                 let regex = regex::Regex::new(pattern.get(0).unwrap());
                 let regex_iter = std::iter::repeat_n(Some(regex), expected_size);
-                regex_replace(self_iter, regex_iter, replacement_iter, self.name())?
+                regex_replace(
+                    self_iter,
+                    regex_iter,
+                    replacement_iter,
+                    max_replacements,
+                    self.name(),
+                )?
             }
             (true, _) => {
-                let regex_iter = pattern
-                    .as_arrow()
-                    .iter()
-                    .map(|pat| pat.map(regex::Regex::new));
-                regex_replace(self_iter, regex_iter, replacement_iter, self.name())?
+                let mut cache = HashMap::new();
+                let regex_iter = pattern.as_arrow().iter().map(|pat| {
+                    pat.map(|pat| {
+                        cache
+                            .entry(pat)
+                            .or_insert_with(|| regex::Regex::new(pat))
+                            .clone()
+                    })
+                });
+                regex_replace(
+                    self_iter,
+                    regex_iter,
+                    replacement_iter,
+                    max_replacements,
+                    self.name(),
+                )?
             }
             (false, _) => {
                 let pattern_iter = create_broadcasted_str_iter(pattern, expected_size);
-                replace_on_literal(self_iter, pattern_iter, replacement_iter, self.name())?
+                replace_on_literal(
+                    self_iter,
+                    pattern_iter,
+                    replacement_iter,
+                    max_replacements,
+                    self.name(),
+                )?
             }
         };
         assert_eq!(result.len(), expected_size);
@@ -651,6 +708,24 @@ impl Utf8Array {
         })
     }
 
+    /// Uppercases the first letter of every word and lowercases the others, where a word is a run
+    /// of alphabetic characters, like Python's `str.title`.
+    pub fn to_titlecase(&self) -> DaftResult<Self> {
+        self.unary_broadcasted_op(|val| {
+            let mut res = String::with_capacity(val.len());
+            let mut in_word = false;
+            for c in val.chars() {
+                if in_word {
+                    res.extend(c.to_lowercase());
+                } else {
+                    res.extend(c.to_uppercase());
+                }
+                in_word = c.is_alphabetic();
+            }
+            res.into()
+        })
+    }
+
     pub fn find(&self, substr: &Self) -> DaftResult<Int64Array> {
         let (is_full_null, expected_size) = parse_inputs(self, &[substr])
             .map_err(|e| DaftError::ValueError(format!("Error in find: {e}")))?;
@@ -1104,24 +1179,24 @@ impl Utf8Array {
         let (start_repeat, start_iter) = match start.len() {
             1 => {
                 let start_repeat = start.get(0).unwrap();
-                let start_repeat: usize = NumCast::from(start_repeat).ok_or_else(|| {
+                let start_repeat: i64 = NumCast::from(start_repeat).ok_or_else(|| {
                     DaftError::ComputeError(format!(
-                        "Error in substr: failed to cast start as usize {start_repeat}"
+                        "Error in substr: failed to cast start as i64 {start_repeat}"
                     ))
                 })?;
-                let start_repeat: Result<Option<usize>, ()> = Ok(Some(start_repeat));
+                let start_repeat: Result<Option<i64>, ()> = Ok(Some(start_repeat));
                 let start_repeat = iter::repeat_n(start_repeat, expected_size);
                 (Some(start_repeat), None)
             }
             _ => {
                 let start_iter = start.as_arrow().iter().map(|s| match s {
                     Some(s) => {
-                        let s: usize = NumCast::from(*s).ok_or_else(|| {
+                        let s: i64 = NumCast::from(*s).ok_or_else(|| {
                             DaftError::ComputeError(format!(
-                                "Error in repeat: failed to cast length as usize {s}"
+                                "Error in substr: failed to cast start as i64 {s}"
                             ))
                         })?;
-                        let result: Result<Option<usize>, DaftError> = Ok(Some(s));
+                        let result: Result<Option<i64>, DaftError> = Ok(Some(s));
                         result
                     }
                     None => Ok(None),
@@ -1518,4 +1593,76 @@ mod tests {
         assert!(result.as_arrow().value(2));
         Ok(())
     }
+
+    #[test]
+    fn check_substr_negative_start() -> DaftResult<()> {
+        let data = Utf8Array::from(("data", ["hello", "héllo", "hi"].as_slice()));
+        let start = Int64Array::from(("start", vec![-3]));
+        let result = data.substr(&start, None::<&Int64Array>)?;
+        assert_eq!(
+            result.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("llo"), Some("llo"), Some("hi")]
+        );
+
+        let start = Int64Array::from(("start", vec![-4, -10, 1]));
+        let length = Int64Array::from(("length", vec![2]));
+        let result = data.substr(&start, Some(&length))?;
+        assert_eq!(
+            result.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("el"), Some("hé"), Some("i")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn check_to_titlecase() -> DaftResult<()> {
+        let data = Utf8Array::from((
+            "data",
+            ["hello wORLD-foo", "they're 2nd", "", "élan vital"].as_slice(),
+        ));
+        let result = data.to_titlecase()?;
+        assert_eq!(
+            result.as_arrow().iter().collect::<Vec<_>>(),
+            vec![
+                Some("Hello World-Foo"),
+                Some("They'Re 2Nd"),
+                Some(""),
+                Some("Élan Vital")
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn check_replace_with_capture_groups() -> DaftResult<()> {
+        let data = Utf8Array::from(("data", ["2024-01-02 2025-03-04", "none"].as_slice()));
+        let pattern = Utf8Array::from(("pattern", [r"(?<y>\d{4})-(\d{2})-(\d{2})"].as_slice()));
+        let replacement = Utf8Array::from(("replacement", ["${3}/$2/$y"].as_slice()));
+        let result = data.replace(&pattern, &replacement, true, None)?;
+        assert_eq!(
+            result.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("02/01/2024 04/03/2025"), Some("none")]
+        );
+        let result = data.replace(&pattern, &replacement, true, Some(1))?;
+        assert_eq!(
+            result.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("02/01/2024 2025-03-04"), Some("none")]
+        );
+
+        // a column of patterns, where repeated patterns share a compiled regex
+        let data = Utf8Array::from(("data", ["aaa", "bbb", "aaa"].as_slice()));
+        let pattern = Utf8Array::from(("pattern", ["a", "b", "a"].as_slice()));
+        let replacement = Utf8Array::from(("replacement", ["x"].as_slice()));
+        let result = data.replace(&pattern, &replacement, true, Some(2))?;
+        assert_eq!(
+            result.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("xxa"), Some("xxb"), Some("xxa")]
+        );
+        let result = data.replace(&pattern, &replacement, false, Some(0))?;
+        assert_eq!(
+            result.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("aaa"), Some("bbb"), Some("aaa")]
+        );
+        Ok(())
+    }
 }
@@ -1,9 +1,9 @@
 pub use super::{DataArray, FixedSizeListArray, ListArray, StructArray};
 // Import logical array types
 pub use crate::datatypes::logical::{
-    DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray, FixedShapeSparseTensorArray,
-    FixedShapeTensorArray, ImageArray, LogicalArray, MapArray, SparseTensorArray, TensorArray,
-    TimeArray, TimestampArray,
+    CategoricalArray, DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray,
+    FixedShapeSparseTensorArray, FixedShapeTensorArray, ImageArray, LogicalArray, MapArray,
+    SparseTensorArray, TensorArray, TimeArray, TimestampArray,
 };
 pub use crate::{
     array::ops::{
@@ -15,6 +15,7 @@ pub fn try_sum_supertype(dtype: &DataType) -> DaftResult<DataType> {
         DataType::Float64 => Ok(DataType::Float64),
         // 38 is the maximum precision for Decimal128, while 19 is the max increase based on 2^64 rows
         DataType::Decimal128(p, s) => Ok(DataType::Decimal128(min(38, *p + 19), *s)),
+        DataType::Interval => Ok(DataType::Interval),
         other => Err(DaftError::TypeError(format!(
             "Invalid argument to sum supertype: {}",
             other
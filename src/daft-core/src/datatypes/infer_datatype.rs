@@ -172,6 +172,11 @@ impl InferDataType<'_> {
                 Ok((DataType::Boolean, Some(d_type.clone()), d_type))
             }
 
+            // categoricals of the same categories compare by code above, anything else by string
+            (DataType::Categorical(_), DataType::Utf8 | DataType::Categorical(_))
+            | (DataType::Utf8, DataType::Categorical(_)) => {
+                Ok((DataType::Boolean, Some(DataType::Utf8), DataType::Utf8))
+            }
             (DataType::Utf8, DataType::Date) | (DataType::Date, DataType::Utf8) => {
                 // Date is logical, so we cast to intermediate type (date), then compare on the physical type (i32)
                 Ok((DataType::Boolean, Some(DataType::Date), DataType::Int32))
@@ -322,8 +327,9 @@ impl Sub for InferDataType<'_> {
                     (ts @ DataType::Timestamp(..), du @ DataType::Duration(..)) => Err(DaftError::TypeError(
                     format!("Cannot subtract due to differing precision: {}, {}. Please explicitly cast to the precision you wish to add in.", ts, du)
                 )),
+                    // timezone-aware timestamps are instants, so their difference does not depend on the timezone
                     (DataType::Timestamp(t_unit_self, tz_self), DataType::Timestamp(t_unit_other, tz_other))
-                    if t_unit_self == t_unit_other && tz_self == tz_other => Ok(DataType::Duration(*t_unit_self)),
+                    if t_unit_self == t_unit_other && (tz_self == tz_other || (tz_self.is_some() && tz_other.is_some())) => Ok(DataType::Duration(*t_unit_self)),
                 (ts @ DataType::Timestamp(..), ts_other @ DataType::Timestamp(..)) => Err(DaftError::TypeError(
                     format!("Cannot subtract due to differing precision or timezone: {}, {}. Please explicitly cast to the precision or timezone you wish to add in.", ts, ts_other)
                 )),
@@ -466,9 +472,24 @@ impl Rem for InferDataType<'_> {
                     InferDataType::from(l) % InferDataType::from(r)
                 })
             })
-            .or(match (self.0, other.0) {
+            .or_else(|_| match (self.0, other.0) {
                 #[cfg(feature = "python")]
                 (DataType::Python, _) | (_, DataType::Python) => Ok(DataType::Python),
+                (DataType::Decimal128(..), other) if other.is_integer() => self % InferDataType::from(&integer_to_decimal128(other)?),
+                (left, DataType::Decimal128(..)) if left.is_integer() => InferDataType::from(&integer_to_decimal128(left)?) % other,
+                (DataType::Decimal128(..), DataType::Float32 | DataType::Float64 ) | (DataType::Float32 | DataType::Float64, DataType::Decimal128(..)) => Ok(DataType::Float64),
+                (DataType::Decimal128(p1, s1), DataType::Decimal128(p2, s2)) => {
+                    // The remainder is smaller than both sides, so a type that fits both fits it.
+                    let s_max = *std::cmp::max(s1, s2);
+                    let p_prime = std::cmp::max(p1 - s1, p2 - s2) + s_max;
+                    if p_prime > 38 {
+                        Err(DaftError::TypeError(
+                            format!("Cannot infer supertypes for modulo on types: {}, {} result precision: {p_prime} exceed bounds of [1, 38]", self, other)
+                        ))
+                    } else {
+                        Ok(DataType::Decimal128(p_prime, s_max))
+                    }
+                }
                 _ => Err(DaftError::TypeError(format!(
                     "Cannot modulo types: {}, {}",
                     self, other
@@ -778,9 +799,44 @@ where
                 )))
             }
         }
+        // --- Tensors broadcast numbers over their elements ---
+        (DataType::FixedShapeTensor(ldtype, shape), r) if r.is_numeric() => {
+            tensor_element_datatype(l, r, inner_f(ldtype.as_ref(), r))
+                .map(|dtype| DataType::FixedShapeTensor(Box::new(dtype), shape.clone()))
+        }
+        (l_inner, DataType::FixedShapeTensor(rdtype, shape)) if l_inner.is_numeric() => {
+            tensor_element_datatype(l, r, inner_f(l_inner, rdtype.as_ref()))
+                .map(|dtype| DataType::FixedShapeTensor(Box::new(dtype), shape.clone()))
+        }
+        (DataType::Tensor(ldtype), DataType::Tensor(rdtype)) => {
+            tensor_element_datatype(l, r, inner_f(ldtype.as_ref(), rdtype.as_ref()))
+                .map(|dtype| DataType::Tensor(Box::new(dtype)))
+        }
+        (DataType::Tensor(ldtype), r_inner) if r_inner.is_numeric() => {
+            tensor_element_datatype(l, r, inner_f(ldtype.as_ref(), r_inner))
+                .map(|dtype| DataType::Tensor(Box::new(dtype)))
+        }
+        (l_inner, DataType::Tensor(rdtype)) if l_inner.is_numeric() => {
+            tensor_element_datatype(l, r, inner_f(l_inner, rdtype.as_ref()))
+                .map(|dtype| DataType::Tensor(Box::new(dtype)))
+        }
         _ => Err(DaftError::TypeError(format!(
             "Invalid arguments to numeric supertype: {}, {}",
             l, r
         ))),
     }
 }
+
+fn tensor_element_datatype(
+    l: &DataType,
+    r: &DataType,
+    result_type: DaftResult<DataType>,
+) -> DaftResult<DataType> {
+    match result_type {
+        Ok(result_type) if result_type.is_numeric() => Ok(result_type),
+        _ => Err(DaftError::TypeError(format!(
+            "Cannot add types: {}, {}",
+            l, r
+        ))),
+    }
+}
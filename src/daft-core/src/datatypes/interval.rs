@@ -1,7 +1,7 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
 use arrow2::types::months_days_ns;
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -211,6 +211,78 @@ impl From<months_days_ns> for IntervalValue {
     }
 }
 
+impl From<IntervalValue> for months_days_ns {
+    fn from(value: IntervalValue) -> Self {
+        Self::new(value.months, value.days, value.nanoseconds)
+    }
+}
+
+/// Parses intervals written as a list of `<count> <unit>` pairs, such as `"3 days 4 hours"` or
+/// `"1 year, -2 months"`. Units are case insensitive and may be singular or plural.
+impl FromStr for IntervalValue {
+    type Err = DaftError;
+
+    fn from_str(s: &str) -> DaftResult<Self> {
+        let err =
+            |msg: &str| DaftError::ValueError(format!("Invalid interval string {s:?}: {msg}"));
+        let overflow = || err("the interval overflows");
+
+        let mut months = 0i32;
+        let mut days = 0i32;
+        let mut nanoseconds = 0i64;
+        let mut rest = s.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+        if rest.is_empty() {
+            return Err(err("expected at least one `<count> <unit>` pair"));
+        }
+        while !rest.is_empty() {
+            let count_len = rest
+                .char_indices()
+                .find(|&(i, c)| !(c.is_ascii_digit() || i == 0 && matches!(c, '-' | '+')))
+                .map_or(rest.len(), |(i, _)| i);
+            let count = rest[..count_len]
+                .parse::<i64>()
+                .map_err(|_| err("expected a whole number before each unit"))?;
+            rest = rest[count_len..].trim_start();
+
+            let unit_len = rest
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(rest.len());
+            let unit = rest[..unit_len].to_ascii_lowercase();
+            rest = rest[unit_len..].trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+
+            let (months_per_unit, days_per_unit, nanos_per_unit) = match unit.as_str() {
+                "year" | "years" => (12, 0, 0),
+                "month" | "months" => (1, 0, 0),
+                "week" | "weeks" => (0, 7, 0),
+                "day" | "days" => (0, 1, 0),
+                "hour" | "hours" => (0, 0, Self::NANOS_PER_HOUR),
+                "minute" | "minutes" => (0, 0, Self::NANOS_PER_MINUTE),
+                "second" | "seconds" => (0, 0, Self::NANOS_PER_SECOND),
+                "millisecond" | "milliseconds" => (0, 0, Self::NANOS_PER_MILLIS),
+                "microsecond" | "microseconds" => (0, 0, 1_000),
+                "nanosecond" | "nanoseconds" => (0, 0, 1),
+                "" => return Err(err(&format!("expected a unit after {count}"))),
+                _ => return Err(err(&format!(
+                    "unknown unit {unit:?}, expected one of year, month, week, day, hour, minute, second, millisecond, microsecond or nanosecond"
+                ))),
+            };
+            let count_in = |per_unit: i64| count.checked_mul(per_unit).ok_or_else(overflow);
+            months = i32::try_from(count_in(months_per_unit)?)
+                .ok()
+                .and_then(|n| months.checked_add(n))
+                .ok_or_else(overflow)?;
+            days = i32::try_from(count_in(days_per_unit)?)
+                .ok()
+                .and_then(|n| days.checked_add(n))
+                .ok_or_else(overflow)?;
+            nanoseconds = nanoseconds
+                .checked_add(count_in(nanos_per_unit)?)
+                .ok_or_else(overflow)?;
+        }
+        Ok(Self::new(months, days, nanoseconds))
+    }
+}
+
 impl Display for IntervalValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         // from months, we convert that to years + months
@@ -312,6 +384,43 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_interval() -> DaftResult<()> {
+        let cases = [
+            (
+                "3 days 4 hours",
+                IntervalValue::new(0, 3, 4 * 3_600_000_000_000),
+            ),
+            ("1 Year, -2 months", IntervalValue::new(10, 0, 0)),
+            ("2 weeks", IntervalValue::new(0, 14, 0)),
+            (
+                "1minute 30 seconds",
+                IntervalValue::new(0, 0, 90_000_000_000),
+            ),
+            (
+                "  5 milliseconds 7 nanoseconds ",
+                IntervalValue::new(0, 0, 5_000_007),
+            ),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(s.parse::<IntervalValue>()?, expected);
+        }
+        for s in [
+            "",
+            "3",
+            "days",
+            "3 fortnights",
+            "1.5 days",
+            "3000000000 days",
+        ] {
+            assert!(
+                s.parse::<IntervalValue>().is_err(),
+                "{s:?} should not parse"
+            );
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_display_for_interval() {
         let iv = IntervalValue::new(1, 2, 3);
@@ -3,7 +3,7 @@ use std::{marker::PhantomData, sync::Arc};
 use common_error::DaftResult;
 
 use super::{
-    DaftArrayType, DaftDataType, DataArray, DataType, DurationType, EmbeddingType,
+    CategoricalType, DaftArrayType, DaftDataType, DataArray, DataType, DurationType, EmbeddingType,
     FixedShapeImageType, FixedShapeSparseTensorType, FixedShapeTensorType, FixedSizeListArray,
     ImageType, MapType, SparseTensorType, TensorType, TimeType, TimestampType,
 };
@@ -98,7 +98,7 @@ impl<L: DaftLogicalType> LogicalArrayImpl<L, DataArray<L::PhysicalType>> {
         use crate::datatypes::DataType::*;
         match daft_type {
             // For wrapped primitive types, switch the datatype label on the arrow2 Array.
-            Decimal128(..) | Date | Timestamp(..) | Duration(..) | Time(..) => {
+            Decimal128(..) | Date | Timestamp(..) | Duration(..) | Time(..) | Categorical(..) => {
                 with_match_daft_logical_primitive_types!(daft_type, |$P| {
                     use arrow2::array::Array;
                     physical_arrow_array
@@ -168,6 +168,7 @@ pub type LogicalArray<L> =
 pub type DateArray = LogicalArray<DateType>;
 pub type TimeArray = LogicalArray<TimeType>;
 pub type DurationArray = LogicalArray<DurationType>;
+pub type CategoricalArray = LogicalArray<CategoricalType>;
 pub type ImageArray = LogicalArray<ImageType>;
 pub type TimestampArray = LogicalArray<TimestampType>;
 pub type TensorArray = LogicalArray<TensorType>;
@@ -178,6 +179,13 @@ pub type FixedShapeSparseTensorArray = LogicalArray<FixedShapeSparseTensorType>;
 pub type FixedShapeImageArray = LogicalArray<FixedShapeImageType>;
 pub type MapArray = LogicalArray<MapType>;
 
+impl CategoricalArray {
+    /// The categories that the codes of this array index into.
+    pub fn categories(&self) -> &[String] {
+        self.data_type().categories().unwrap()
+    }
+}
+
 pub trait DaftImageryType: DaftLogicalType {}
 
 impl DaftImageryType for ImageType {}
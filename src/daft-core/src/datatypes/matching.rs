@@ -13,6 +13,7 @@ macro_rules! with_match_daft_types {
             // Float16 => unimplemented!("Array for Float16 DataType not implemented"),
             DataType::Binary => __with_ty__! { BinaryType },
             DataType::Boolean => __with_ty__! { BooleanType },
+            DataType::Categorical(..) => __with_ty__! { CategoricalType },
             DataType::Date => __with_ty__! { DateType },
             DataType::Decimal128(..) => __with_ty__! { Decimal128Type },
             DataType::Duration(_) => __with_ty__! { DurationType },
@@ -160,6 +161,7 @@ macro_rules! with_match_comparable_daft_types {
             DataType::Utf8 => __with_ty__! { Utf8Type },
             DataType::Binary => __with_ty__! { BinaryType },
             DataType::FixedSizeBinary(_) => __with_ty__! { FixedSizeBinaryType },
+            DataType::Interval => __with_ty__! { IntervalType },
 
             _ => panic!("{:?} not implemented", $key_type)
         }
@@ -229,6 +231,7 @@ macro_rules! with_match_hashable_daft_types {
             DataType::List(_) => __with_ty__! { ListType },
             DataType::FixedSizeList(_, _) => __with_ty__! { FixedSizeListType },
             DataType::Struct(_) => __with_ty__! { StructType },
+            DataType::Interval => __with_ty__! { IntervalType },
 
             _ => panic!("{:?} not implemented", $key_type)
         }
@@ -355,6 +358,7 @@ macro_rules! with_match_daft_logical_primitive_types {
             DataType::Date => __with_ty__! { i32 },
             DataType::Time(..) => __with_ty__! { i64 },
             DataType::Timestamp(..) => __with_ty__! { i64 },
+            DataType::Categorical(..) => __with_ty__! { u32 },
 
             _ => panic!("no logical -> primitive conversion available for {:?}", $key_type)
         }
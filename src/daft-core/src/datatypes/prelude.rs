@@ -23,7 +23,7 @@ pub use super::{
     DaftNumericType, DaftPhysicalType,
 };
 pub use crate::datatypes::{
-    logical::DaftImageryType, DateType, Decimal128Type, DurationType, EmbeddingType,
-    FixedShapeImageType, FixedShapeSparseTensorType, FixedShapeTensorType, ImageType, IntervalType,
-    MapType, SparseTensorType, TensorType, TimeType, TimestampType,
+    logical::DaftImageryType, CategoricalType, DateType, Decimal128Type, DurationType,
+    EmbeddingType, FixedShapeImageType, FixedShapeSparseTensorType, FixedShapeTensorType,
+    ImageType, IntervalType, MapType, SparseTensorType, TensorType, TimeType, TimestampType,
 };
@@ -142,6 +142,64 @@ impl FromStr for JoinStrategy {
     }
 }
 
+/// Which right row an as-of join matches with each left row.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", eq, eq_int))]
+pub enum AsofStrategy {
+    /// The last row whose key is less than or equal to the left key.
+    Backward,
+    /// The first row whose key is greater than or equal to the left key.
+    Forward,
+    /// The row whose key is closest to the left key, preferring the backward match on ties.
+    Nearest,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl AsofStrategy {
+    /// Create an AsofStrategy from its string representation.
+    ///
+    /// Args:
+    ///     asof_strategy: String representation of the as-of strategy, e.g. "backward", "forward", or "nearest".
+    #[staticmethod]
+    pub fn from_asof_strategy_str(asof_strategy: &str) -> PyResult<Self> {
+        Self::from_str(asof_strategy).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    pub fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+}
+impl_bincode_py_state_serialization!(AsofStrategy);
+
+impl AsofStrategy {
+    pub fn iterator() -> std::slice::Iter<'static, Self> {
+        static ASOF_STRATEGIES: [AsofStrategy; 3] = [
+            AsofStrategy::Backward,
+            AsofStrategy::Forward,
+            AsofStrategy::Nearest,
+        ];
+        ASOF_STRATEGIES.iter()
+    }
+}
+
+impl FromStr for AsofStrategy {
+    type Err = DaftError;
+
+    fn from_str(asof_strategy: &str) -> DaftResult<Self> {
+        match asof_strategy {
+            "backward" => Ok(Self::Backward),
+            "forward" => Ok(Self::Forward),
+            "nearest" => Ok(Self::Nearest),
+            _ => Err(DaftError::TypeError(format!(
+                "As-of strategy {} is not supported; only the following strategies are supported: {:?}",
+                asof_strategy,
+                Self::iterator().as_slice()
+            ))),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "python", pyclass(module = "daft.daft", eq, eq_int))]
 pub enum JoinSide {
@@ -348,7 +348,7 @@ macro_rules! with_match_searching_primitive_type {(
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use arrow2::datatypes::PrimitiveType::*;
-    // use arrow2::types::{days_ms, months_days_ns};
+    use arrow2::types::months_days_ns;
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
@@ -356,7 +356,7 @@ macro_rules! with_match_searching_primitive_type {(
         Int64 => __with_ty__! { i64 },
         Int128 => __with_ty__! { i128 },
         // DaysMs => __with_ty__! { days_ms },
-        // MonthDayNano => __with_ty__! { months_days_ns },
+        MonthDayNano => __with_ty__! { months_days_ns },
         UInt8 => __with_ty__! { u8 },
         UInt16 => __with_ty__! { u16 },
         UInt32 => __with_ty__! { u32 },
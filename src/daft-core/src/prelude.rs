@@ -12,7 +12,7 @@ pub use crate::count_mode::CountMode;
 // Re-export common data types and arrays
 pub use crate::datatypes::prelude::*;
 // Re-export join-related types
-pub use crate::join::{JoinStrategy, JoinType};
+pub use crate::join::{AsofStrategy, JoinStrategy, JoinType};
 pub use crate::{
     array::prelude::*,
     series::{IntoSeries, Series},
@@ -1,17 +1,20 @@
 use std::{
     hash::BuildHasherDefault,
     ops::{Add, Div, Mul, Rem, Sub},
+    str::FromStr,
     sync::Arc,
 };
 
 use common_arrow_ffi as ffi;
+use common_error::DaftError;
 use daft_hash::{HashFunctionKind, MurBuildHasher, Sha1Hasher};
 use daft_schema::python::{PyDataType, PyTimeUnit};
+use numpy::{PyArray1, PyArrayMethods, PyUntypedArrayMethods};
 use pyo3::{
     exceptions::PyValueError,
     prelude::*,
     pyclass::CompareOp,
-    types::{PyBytes, PyList},
+    types::{PyBytes, PyCapsule, PyList},
 };
 
 use crate::{
@@ -25,8 +28,11 @@ use crate::{
     },
     count_mode::CountMode,
     datatypes::{DataType, Field, ImageMode, PythonType},
-    series::{self, IntoSeries, Series},
-    utils::arrow::{cast_array_for_daft_if_needed, cast_array_from_daft_if_needed},
+    series::{self, IntoSeries, RankMethod, Series},
+    utils::arrow::{
+        cast_array_for_daft_if_needed, cast_array_from_daft_if_needed,
+        coerce_from_daft_compatible_type,
+    },
 };
 
 #[pyclass]
@@ -45,6 +51,60 @@ impl PySeries {
         Ok(series.into())
     }
 
+    /// Ingests a 1-dimensional, contiguous NumPy array of a primitive dtype without copying its data,
+    /// keeping the NumPy array alive for as long as the data is referenced.
+    ///
+    /// Returns `None` if the NumPy array can not be ingested without a copy.
+    #[staticmethod]
+    pub fn from_numpy(name: &str, array: Bound<PyAny>) -> PyResult<Option<Self>> {
+        macro_rules! try_from_numpy {
+            ($($T:ty),+) => {
+                $(
+                    if let Some(arrow_array) = primitive_array_from_numpy::<$T>(&array) {
+                        let series = series::Series::try_from((name, arrow_array))?;
+                        return Ok(Some(series.into()));
+                    }
+                )+
+            };
+        }
+        try_from_numpy!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
+        Ok(None)
+    }
+
+    /// Imports the `arrow_schema` and `arrow_array` PyCapsules of the Arrow PyCapsule interface.
+    #[staticmethod]
+    pub fn from_arrow_c_array(
+        name: &str,
+        schema_capsule: Bound<PyCapsule>,
+        array_capsule: Bound<PyCapsule>,
+    ) -> PyResult<Self> {
+        let (_, arrow_array) = ffi::array_from_capsules(&schema_capsule, &array_capsule)?;
+        let arrow_array = cast_array_for_daft_if_needed(arrow_array);
+        let series = series::Series::try_from((name, arrow_array))?;
+        Ok(series.into())
+    }
+
+    /// Imports the `arrow_array_stream` PyCapsule of the Arrow PyCapsule interface, concatenating
+    /// the arrays of the stream.
+    #[staticmethod]
+    pub fn from_arrow_c_stream(name: &str, stream_capsule: Bound<PyCapsule>) -> PyResult<Self> {
+        let stream = ffi::stream_from_capsule(&stream_capsule)?;
+        // Safety: a capsule with this name holds a struct of the C stream interface
+        let mut reader = unsafe { arrow2::ffi::ArrowArrayStreamReader::try_new(stream) }
+            .map_err(DaftError::from)?;
+        let mut chunks = vec![];
+        while let Some(array) = unsafe { reader.next() } {
+            let array = cast_array_for_daft_if_needed(array.map_err(DaftError::from)?);
+            chunks.push(series::Series::try_from((name, array))?);
+        }
+        let series = match chunks.as_slice() {
+            [] => series::Series::empty(name, &Field::from(reader.field()).dtype),
+            [series] => series.clone(),
+            chunks => series::Series::concat(&chunks.iter().collect::<Vec<_>>())?,
+        };
+        Ok(series.into())
+    }
+
     // This ingests a Python list[object] directly into a Rust PythonArray.
     #[staticmethod]
     pub fn from_pylist(
@@ -93,6 +153,44 @@ impl PySeries {
         })
     }
 
+    pub fn __arrow_c_schema__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyCapsule>> {
+        let field = self.series.field().to_arrow()?;
+        let field = match coerce_from_daft_compatible_type(field.data_type()) {
+            Some(dtype) => arrow2::datatypes::Field::new(field.name, dtype, field.is_nullable),
+            None => field,
+        };
+        ffi::field_to_schema_capsule(py, &field)
+    }
+
+    /// Exports this Series through the Arrow PyCapsule interface.
+    ///
+    /// If a `requested_schema` is given, the array is cast to its type when possible; otherwise it
+    /// is exported as is, which the interface allows.
+    #[pyo3(signature = (requested_schema=None))]
+    pub fn __arrow_c_array__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<Bound<'py, PyCapsule>>,
+    ) -> PyResult<(Bound<'py, PyCapsule>, Bound<'py, PyCapsule>)> {
+        let mut arrow_array = cast_array_from_daft_if_needed(self.series.to_arrow());
+        if let Some(requested_schema) = requested_schema {
+            let requested = ffi::field_from_schema_capsule(&requested_schema)?;
+            if let Ok(cast) = arrow2::compute::cast::cast(
+                arrow_array.as_ref(),
+                requested.data_type(),
+                Default::default(),
+            ) {
+                arrow_array = cast;
+            }
+        }
+        let field = arrow2::datatypes::Field::new(
+            self.series.name(),
+            arrow_array.data_type().clone(),
+            true,
+        );
+        ffi::array_to_capsules(py, &field, arrow_array)
+    }
+
     pub fn __abs__(&self) -> PyResult<Self> {
         Ok(self.series.abs()?.into())
     }
@@ -369,6 +467,21 @@ impl PySeries {
         Ok(self.series.argsort(descending, nulls_first)?.into())
     }
 
+    #[pyo3(signature = (method, descending, nulls_first=None))]
+    pub fn rank(
+        &self,
+        method: &str,
+        descending: bool,
+        nulls_first: Option<bool>,
+    ) -> PyResult<Self> {
+        let method = RankMethod::from_str(method)?;
+        Ok(self.series.rank(method, descending, nulls_first)?.into())
+    }
+
+    pub fn value_counts(&self) -> PyResult<Self> {
+        Ok(self.series.value_counts()?.into())
+    }
+
     #[pyo3(signature = (seed=None))]
     pub fn hash(&self, seed: Option<Self>) -> PyResult<Self> {
         let seed_series;
@@ -461,6 +574,10 @@ impl PySeries {
         Ok((self.series).min(None)?.into())
     }
 
+    pub fn approx_quantiles(&self, quantiles: Vec<f64>) -> PyResult<Self> {
+        Ok(self.series.approx_quantiles(&quantiles, None)?.into())
+    }
+
     pub fn max(&self) -> PyResult<Self> {
         Ok((self.series).max(None)?.into())
     }
@@ -485,6 +602,11 @@ impl PySeries {
         Ok(self.series.cast(&dtype.into())?.into())
     }
 
+    #[pyo3(signature = (categories=None))]
+    pub fn cast_to_categorical(&self, categories: Option<Vec<String>>) -> PyResult<Self> {
+        Ok(self.series.cast_to_categorical(categories)?.into())
+    }
+
     #[staticmethod]
     pub fn concat(series: Vec<Self>) -> PyResult<Self> {
         let series: Vec<_> = series.iter().map(|s| &s.series).collect();
@@ -546,7 +668,7 @@ impl PySeries {
     pub fn utf8_replace(&self, pattern: &Self, replacement: &Self, regex: bool) -> PyResult<Self> {
         Ok(self
             .series
-            .utf8_replace(&pattern.series, &replacement.series, regex)?
+            .utf8_replace(&pattern.series, &replacement.series, regex, None)?
             .into())
     }
 
@@ -582,6 +704,10 @@ impl PySeries {
         Ok(self.series.utf8_capitalize()?.into())
     }
 
+    pub fn utf8_to_titlecase(&self) -> PyResult<Self> {
+        Ok(self.series.utf8_to_titlecase()?.into())
+    }
+
     pub fn utf8_left(&self, nchars: &Self) -> PyResult<Self> {
         Ok(self.series.utf8_left(&nchars.series)?.into())
     }
@@ -740,6 +866,15 @@ impl PySeries {
             .into())
     }
 
+    pub fn dt_convert_time_zone(&self, tz: &str) -> PyResult<Self> {
+        Ok(self.series.dt_convert_time_zone(tz)?.into())
+    }
+
+    #[pyo3(signature = (tz=None))]
+    pub fn dt_replace_time_zone(&self, tz: Option<&str>) -> PyResult<Self> {
+        Ok(self.series.dt_replace_time_zone(tz)?.into())
+    }
+
     pub fn dt_to_unix_epoch(&self, unit: PyTimeUnit) -> PyResult<Self> {
         Ok(self.series.dt_to_unix_epoch(unit.timeunit)?.into())
     }
@@ -800,6 +935,39 @@ impl PySeries {
         Ok(self.series.map_get(&key.series)?.into())
     }
 
+    pub fn map_keys(&self) -> PyResult<Self> {
+        Ok(self.series.map_keys()?.into())
+    }
+
+    pub fn map_values(&self) -> PyResult<Self> {
+        Ok(self.series.map_values()?.into())
+    }
+
+    pub fn embedding_dot_product(&self, other: &Self) -> PyResult<Self> {
+        Ok(self.series.embedding_dot_product(&other.series)?.into())
+    }
+
+    pub fn embedding_cosine_similarity(&self, other: &Self, normalized: bool) -> PyResult<Self> {
+        Ok(self
+            .series
+            .embedding_cosine_similarity(&other.series, normalized)?
+            .into())
+    }
+
+    pub fn embedding_normalize(&self) -> PyResult<Self> {
+        Ok(self.series.embedding_normalize()?.into())
+    }
+
+    #[pyo3(signature = (axes=None))]
+    pub fn tensor_sum(&self, axes: Option<Vec<usize>>) -> PyResult<Self> {
+        Ok(self.series.tensor_sum(axes.as_deref())?.into())
+    }
+
+    #[pyo3(signature = (axes=None))]
+    pub fn tensor_mean(&self, axes: Option<Vec<usize>>) -> PyResult<Self> {
+        Ok(self.series.tensor_mean(axes.as_deref())?.into())
+    }
+
     pub fn if_else(&self, other: &Self, predicate: &Self) -> PyResult<Self> {
         Ok(self
             .series
@@ -947,3 +1115,27 @@ fn infer_daft_dtype_for_sequence(
     }
     Ok(dtype)
 }
+
+/// Wraps the data of `array` in an Arrow array without copying it, if `array` is a 1-dimensional NumPy
+/// array of `T` with native byte order, whose data is contiguous and aligned.
+fn primitive_array_from_numpy<T>(array: &Bound<PyAny>) -> Option<Box<dyn arrow2::array::Array>>
+where
+    T: numpy::Element + arrow2::types::NativeType,
+{
+    // the dtype check of the downcast also rejects non-native byte orders
+    let array = array.downcast::<PyArray1<T>>().ok()?;
+    let ptr = array.data() as *const T;
+    if !array.is_c_contiguous() || !ptr.is_aligned() {
+        return None;
+    }
+    let owner = array.clone().unbind();
+    // Safety: the data is valid and aligned for `array.len()` values, and stays alive until the
+    // NumPy array is released. Like pyarrow, mutations of the NumPy array made after ingestion are
+    // visible in the series.
+    let buffer = unsafe {
+        arrow2::buffer::Buffer::from_foreign(ptr, array.len(), move || {
+            Python::with_gil(|_| drop(owner));
+        })
+    };
+    Some(arrow2::array::PrimitiveArray::<T>::new(T::PRIMITIVE.into(), buffer, None).boxed())
+}
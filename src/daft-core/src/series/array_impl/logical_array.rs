@@ -188,6 +188,7 @@ macro_rules! impl_series_like_for_logical_array {
 impl_series_like_for_logical_array!(DateArray);
 impl_series_like_for_logical_array!(TimeArray);
 impl_series_like_for_logical_array!(DurationArray);
+impl_series_like_for_logical_array!(CategoricalArray);
 
 impl_series_like_for_logical_array!(TimestampArray);
 impl_series_like_for_logical_array!(ImageArray);
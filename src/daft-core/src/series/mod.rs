@@ -11,7 +11,11 @@ use common_display::table_display::{make_comfy_table, StrValue};
 use common_error::DaftResult;
 use derive_more::Display;
 use indexmap::{map::RawEntryApiV1, IndexMap};
-pub use ops::cast_series_to_supertype;
+pub use ops::{
+    approx_top_k::{space_saving_dtype, space_saving_values_dtype, top_k_capacity},
+    cast_series_to_supertype,
+    rank::RankMethod,
+};
 
 pub(crate) use self::series_like::SeriesLike;
 use crate::{
@@ -17,6 +17,7 @@ impl Series {
             }
             DataType::Float32 => Ok(self.f32().unwrap().abs()?.into_series()),
             DataType::Float64 => Ok(self.f64().unwrap().abs()?.into_series()),
+            DataType::Decimal128(..) => Ok(self.decimal128()?.apply(i128::abs)?.into_series()),
             dt => Err(DaftError::TypeError(format!(
                 "abs not implemented for {}",
                 dt
@@ -5,8 +5,8 @@ use crate::{
     array::{
         growable::make_growable,
         ops::{
-            DaftApproxSketchAggable, DaftCountAggable, DaftHllMergeAggable, DaftMeanAggable,
-            DaftSetAggable, DaftStddevAggable, DaftSumAggable, GroupIndices,
+            as_arrow::AsArrow, DaftApproxSketchAggable, DaftCountAggable, DaftHllMergeAggable,
+            DaftMeanAggable, DaftSetAggable, DaftStddevAggable, DaftSumAggable, GroupIndices,
         },
         ListArray,
     },
@@ -92,6 +92,12 @@ impl Series {
                     }
                 }
             }
+            DataType::Interval => match groups {
+                Some(groups) => {
+                    Ok(DaftSumAggable::grouped_sum(&self.interval()?, groups)?.into_series())
+                }
+                None => Ok(DaftSumAggable::sum(&self.interval()?)?.into_series()),
+            },
             other => Err(DaftError::TypeError(format!(
                 "Numeric sum is not implemented for type {}",
                 other
@@ -249,6 +255,62 @@ impl Series {
         self.inner.agg_set(groups)
     }
 
+    /// Gathers each group's values into a list like [`Self::agg_list`], optionally ordered by
+    /// `order_by` (with nulls last when ascending), deduplicated and capped at `limit` values.
+    ///
+    /// Deduplication keeps the first occurrence of each value in the order and drops nulls, like
+    /// [`Self::agg_set`].
+    pub fn agg_collect(
+        &self,
+        groups: Option<&GroupIndices>,
+        order_by: Option<(&Self, bool)>,
+        distinct: bool,
+        limit: Option<usize>,
+    ) -> DaftResult<Self> {
+        let whole;
+        let groups = match groups {
+            Some(groups) => groups,
+            None => {
+                whole = vec![(0..self.len() as u64).collect()];
+                &whole
+            }
+        };
+        // position of each row in the ordering, so that groups can be sorted independently
+        let ranks = order_by
+            .map(|(order_by, descending)| -> DaftResult<Vec<u64>> {
+                let sorted = order_by.argsort(descending, descending)?;
+                let mut ranks = vec![0; order_by.len()];
+                for (rank, idx) in sorted.u64()?.as_arrow().values().iter().enumerate() {
+                    ranks[*idx as usize] = rank as u64;
+                }
+                Ok(ranks)
+            })
+            .transpose()?;
+        let groups = groups
+            .iter()
+            .map(|group| {
+                let mut group = group.clone();
+                if let Some(ranks) = &ranks {
+                    group.sort_unstable_by_key(|idx| ranks[*idx as usize]);
+                }
+                if distinct {
+                    let group_series =
+                        self.take(&UInt64Array::from(("", group.clone())).into_series())?;
+                    // unique indices are in order of first occurrence
+                    group = deduplicate_indices(&group_series)?
+                        .into_iter()
+                        .map(|local_idx| group[local_idx as usize])
+                        .collect();
+                }
+                if let Some(limit) = limit {
+                    group.truncate(limit);
+                }
+                Ok(group)
+            })
+            .collect::<DaftResult<GroupIndices>>()?;
+        self.agg_list(Some(&groups))
+    }
+
     pub fn agg_concat(&self, groups: Option<&GroupIndices>) -> DaftResult<Self> {
         use crate::array::ops::DaftConcatAggable;
         match self.data_type() {
@@ -261,6 +323,9 @@ impl Series {
                     None => Ok(DaftConcatAggable::concat(downcasted)?.into_series()),
                 }
             }
+            DataType::FixedSizeList(child_dtype, _) => self
+                .cast(&DataType::List(child_dtype.clone()))?
+                .agg_concat(groups),
             #[cfg(feature = "python")]
             DataType::Python => {
                 let downcasted = self.downcast::<PythonArray>()?;
@@ -281,7 +346,7 @@ impl Series {
                 }
             }
             _ => Err(DaftError::TypeError(format!(
-                "concat aggregation is only valid for List, Python types, or Utf8, got {}",
+                "concat aggregation is only valid for List, FixedSizeList, Python types, or Utf8, got {}",
                 self.data_type()
             ))),
         }
@@ -0,0 +1,277 @@
+use std::collections::{hash_map::Entry, HashMap};
+
+use arrow2::offset::OffsetsBuffer;
+use common_error::{DaftError, DaftResult};
+
+use crate::{
+    array::{
+        ops::{as_arrow::AsArrow, GroupIndices},
+        ListArray, StructArray,
+    },
+    datatypes::{DataType, Field, UInt64Array},
+    series::{IntoSeries, Series},
+};
+
+/// The dtype of a space-saving sketch of values of `dtype`: a list of the tracked values and
+/// their approximate counts, in descending order of count.
+pub fn space_saving_dtype(dtype: &DataType) -> DataType {
+    DataType::List(Box::new(DataType::Struct(vec![
+        Field::new("value", dtype.clone()),
+        Field::new("count", DataType::UInt64),
+    ])))
+}
+
+/// The capacity of the space-saving sketches used to find the `k` most frequent values. Tracking
+/// more values than are returned keeps the counts of the top `k` accurate across merged sketches.
+pub fn top_k_capacity(k: usize) -> usize {
+    k.saturating_mul(10).max(100)
+}
+
+/// The dtype of the values tracked by a space-saving sketch of dtype `dtype`, or `None` if it
+/// isn't one.
+pub fn space_saving_values_dtype(dtype: &DataType) -> Option<&DataType> {
+    match dtype {
+        DataType::List(item) => match item.as_ref() {
+            DataType::Struct(fields) if *dtype == space_saving_dtype(&fields.first()?.dtype) => {
+                Some(&fields[0].dtype)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The counters of a space-saving sketch, keyed by value hash, with the index of a row holding
+/// the value and its approximate count.
+///
+/// Values are identified by their 64-bit hashes, so distinct values that collide are counted
+/// together; at the sketch sizes used this is far rarer than the sketch's own error.
+struct Counters {
+    capacity: usize,
+    limit: usize,
+    slots: HashMap<u64, (u64, u64)>,
+}
+
+impl Counters {
+    fn new(capacity: usize, limit: usize) -> Self {
+        Self {
+            capacity,
+            limit,
+            slots: HashMap::new(),
+        }
+    }
+
+    /// Counts a value, evicting the least frequent value once the sketch is full. The new value
+    /// inherits the evicted count, which bounds how much any count is overestimated.
+    fn insert(&mut self, hash: u64, idx: u64) {
+        if let Some((_, count)) = self.slots.get_mut(&hash) {
+            *count += 1;
+        } else if self.slots.len() < self.capacity {
+            self.slots.insert(hash, (idx, 1));
+        } else if let Some((&evicted, &(_, min_count))) =
+            self.slots.iter().min_by_key(|(_, (_, count))| *count)
+        {
+            self.slots.remove(&evicted);
+            self.slots.insert(hash, (idx, min_count + 1));
+        }
+    }
+
+    /// Adds a counter of another sketch to this one, without evicting anything.
+    fn merge(&mut self, hash: u64, idx: u64, count: u64) {
+        match self.slots.entry(hash) {
+            Entry::Occupied(mut entry) => entry.get_mut().1 += count,
+            Entry::Vacant(entry) => {
+                entry.insert((idx, count));
+            }
+        }
+    }
+
+    /// The `limit` most frequent values, with ties broken by row order so that results are stable.
+    fn into_sorted(self) -> Vec<(u64, u64)> {
+        let mut counters = self.slots.into_values().collect::<Vec<_>>();
+        counters.sort_unstable_by(|(l_idx, l_count), (r_idx, r_count)| {
+            r_count.cmp(l_count).then(l_idx.cmp(r_idx))
+        });
+        counters.truncate(self.limit);
+        counters
+    }
+}
+
+/// Builds a list of `(value, count)` structs for each group's counters.
+fn build_sketches(
+    name: &str,
+    values: &Series,
+    counters: Vec<Vec<(u64, u64)>>,
+) -> DaftResult<Series> {
+    let mut offsets = Vec::with_capacity(counters.len() + 1);
+    offsets.push(0i64);
+    let mut indices = Vec::new();
+    let mut counts = Vec::new();
+    for group in counters {
+        for (idx, count) in group {
+            indices.push(idx);
+            counts.push(count);
+        }
+        offsets.push(indices.len() as i64);
+    }
+    let values = values
+        .take(&UInt64Array::from(("", indices)).into_series())?
+        .rename("value");
+    let counts = UInt64Array::from(("count", counts)).into_series();
+    let struct_field = Field::new(
+        "item",
+        DataType::Struct(vec![values.field().clone(), counts.field().clone()]),
+    );
+    let field = Field::new(name, space_saving_dtype(values.data_type()));
+    let structs = StructArray::new(struct_field, vec![values, counts], None).into_series();
+    Ok(ListArray::new(field, structs, OffsetsBuffer::try_from(offsets)?, None).into_series())
+}
+
+fn all_rows(len: usize) -> GroupIndices {
+    vec![(0..len as u64).collect()]
+}
+
+impl Series {
+    /// Builds a space-saving sketch of each group's most frequent non-null values, tracking at
+    /// most `capacity` values per group.
+    ///
+    /// Each count overestimates the true count by at most the group's size divided by
+    /// `capacity`, and every value more frequent than that is tracked.
+    pub fn space_saving_sketch(
+        &self,
+        groups: Option<&GroupIndices>,
+        capacity: usize,
+    ) -> DaftResult<Self> {
+        self.space_saving_top(groups, capacity, capacity)
+    }
+
+    /// The approximate `k` most frequent non-null values of each group and their counts.
+    pub fn approx_top_k(&self, groups: Option<&GroupIndices>, k: usize) -> DaftResult<Self> {
+        self.space_saving_top(groups, top_k_capacity(k), k)
+    }
+
+    fn space_saving_top(
+        &self,
+        groups: Option<&GroupIndices>,
+        capacity: usize,
+        limit: usize,
+    ) -> DaftResult<Self> {
+        let whole;
+        let groups = match groups {
+            Some(groups) => groups,
+            None => {
+                whole = all_rows(self.len());
+                &whole
+            }
+        };
+        let hashes = self.hash_with_validity(None)?;
+        let counters = groups
+            .iter()
+            .map(|group| {
+                let mut counters = Counters::new(capacity, limit);
+                for &idx in group {
+                    if let Some(hash) = hashes.get(idx as usize) {
+                        counters.insert(hash, idx);
+                    }
+                }
+                counters.into_sorted()
+            })
+            .collect();
+        build_sketches(self.name(), self, counters)
+    }
+
+    /// Merges each group's space-saving sketches, keeping the `capacity` most frequent values.
+    pub fn space_saving_merge(
+        &self,
+        groups: Option<&GroupIndices>,
+        capacity: usize,
+    ) -> DaftResult<Self> {
+        let whole;
+        let groups = match groups {
+            Some(groups) => groups,
+            None => {
+                whole = all_rows(self.len());
+                &whole
+            }
+        };
+        if space_saving_values_dtype(self.data_type()).is_none() {
+            return Err(DaftError::TypeError(format!(
+                "Expected a space-saving sketch to merge, got {}",
+                self.data_type()
+            )));
+        }
+        let sketches = self.list()?;
+        let items = sketches.flat_child.struct_()?;
+        let values = &items.children[0];
+        let counts = items.children[1].u64()?.as_arrow();
+        let hashes = values.hash_with_validity(None)?;
+        let offsets = sketches.offsets();
+        let counters = groups
+            .iter()
+            .map(|group| {
+                let mut counters = Counters::new(capacity, capacity);
+                for &sketch in group {
+                    let (start, end) = offsets.start_end(sketch as usize);
+                    for idx in start..end {
+                        if let Some(hash) = hashes.get(idx) {
+                            counters.merge(hash, idx as u64, counts.value(idx));
+                        }
+                    }
+                }
+                counters.into_sorted()
+            })
+            .collect();
+        build_sketches(self.name(), values, counters)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{DataType, Int64Array},
+        series::{IntoSeries, Series},
+    };
+
+    fn top(sketch: &Series) -> DaftResult<Vec<(i64, u64)>> {
+        let items = sketch.list()?.flat_child.struct_()?.clone();
+        let values = items.children[0].i64()?.as_arrow().values().to_vec();
+        let counts = items.children[1].u64()?.as_arrow().values().to_vec();
+        Ok(values.into_iter().zip(counts).collect())
+    }
+
+    #[test]
+    fn space_saving_sketch_counts_heavy_hitters() -> DaftResult<()> {
+        let values = Int64Array::from_iter(
+            crate::datatypes::Field::new("x", DataType::Int64),
+            vec![Some(1), Some(2), Some(1), None, Some(3), Some(1), Some(2)].into_iter(),
+        )
+        .into_series();
+        let exact = values.space_saving_sketch(None, 10)?;
+        assert_eq!(
+            exact.data_type(),
+            &super::space_saving_dtype(&DataType::Int64)
+        );
+        assert_eq!(top(&exact)?, vec![(1, 3), (2, 2), (3, 1)]);
+
+        // with two counters, 3 evicts 2 and then 2 evicts 3, each inheriting the evicted count
+        let approx = values.space_saving_sketch(None, 2)?;
+        assert_eq!(top(&approx)?, vec![(1, 3), (2, 3)]);
+
+        let top_one = values.approx_top_k(None, 1)?;
+        assert_eq!(top(&top_one)?, vec![(1, 3)]);
+        Ok(())
+    }
+
+    #[test]
+    fn space_saving_merge_sums_counts() -> DaftResult<()> {
+        let values = Int64Array::from(("x", vec![1, 2, 2, 3, 3, 3, 1, 1])).into_series();
+        let sketches =
+            values.space_saving_sketch(Some(&vec![vec![0, 1, 2], vec![3, 4, 5, 6, 7]]), 10)?;
+        let merged = sketches.space_saving_merge(None, 2)?;
+        assert_eq!(top(&merged)?, vec![(1, 3), (3, 3)]);
+        Ok(())
+    }
+}
@@ -8,7 +8,7 @@ use crate::series::utils::python_fn::run_python_binary_operator_fn;
 use crate::{
     array::prelude::*,
     datatypes::{InferDataType, Utf8Array},
-    series::{utils::cast::cast_downcast_op, IntoSeries, Series},
+    series::{ops::tensor::tensor_binary_op, utils::cast::cast_downcast_op, IntoSeries, Series},
     with_match_integer_daft_types, with_match_numeric_daft_types,
 };
 
@@ -73,6 +73,7 @@ impl Add for &Series {
             output_type if output_type.is_fixed_size_numeric() => {
                 fixed_size_binary_op(lhs, rhs, output_type, FixedSizeBinaryOp::Add)
             }
+            DataType::Tensor(..) => tensor_binary_op(lhs, rhs, &output_type, |l, r| l.add(r)),
             // ----------------
             // Temporal types
             // ----------------
@@ -165,6 +166,7 @@ impl Sub for &Series {
             output_type if output_type.is_fixed_size_numeric() => {
                 fixed_size_binary_op(lhs, rhs, output_type, FixedSizeBinaryOp::Sub)
             }
+            DataType::Tensor(..) => tensor_binary_op(lhs, rhs, &output_type, |l, r| l.sub(r)),
             // ----------------
             // Decimal Types
             // ----------------
@@ -268,6 +270,7 @@ impl Mul for &Series {
             output_type if output_type.is_fixed_size_numeric() => {
                 fixed_size_binary_op(lhs, rhs, output_type, FixedSizeBinaryOp::Mul)
             }
+            DataType::Tensor(..) => tensor_binary_op(lhs, rhs, &output_type, |l, r| l.mul(r)),
             _ => arithmetic_op_not_implemented!(self, "*", rhs, output_type),
         }
     }
@@ -306,6 +309,7 @@ impl Div for &Series {
             output_type if output_type.is_fixed_size_numeric() => {
                 fixed_size_binary_op(lhs, rhs, output_type, FixedSizeBinaryOp::Div)
             }
+            DataType::Tensor(..) => tensor_binary_op(lhs, rhs, &output_type, |l, r| l.div(r)),
             _ => arithmetic_op_not_implemented!(self, "/", rhs, output_type),
         }
     }
@@ -321,6 +325,9 @@ impl Rem for &Series {
         match &output_type {
             #[cfg(feature = "python")]
             DataType::Python => run_python_binary_operator_fn(lhs, rhs, "mod"),
+            DataType::Decimal128(..) => {
+                Ok(cast_downcast_op!(lhs, rhs, &output_type, Decimal128Array, rem)?.into_series())
+            }
             output_type if output_type.is_numeric() => {
                 with_match_numeric_daft_types!(output_type, |$T| {
                     Ok(cast_downcast_op!(lhs, rhs, output_type, <$T as DaftDataType>::ArrayType, rem)?.into_series())
@@ -329,6 +336,7 @@ impl Rem for &Series {
             output_type if output_type.is_fixed_size_numeric() => {
                 fixed_size_binary_op(lhs, rhs, output_type, FixedSizeBinaryOp::Rem)
             }
+            DataType::Tensor(..) => tensor_binary_op(lhs, rhs, &output_type, |l, r| l.rem(r)),
             _ => arithmetic_op_not_implemented!(self, "%", rhs, output_type),
         }
     }
@@ -356,6 +364,7 @@ impl Series {
     }
 }
 
+#[derive(Clone, Copy)]
 enum FixedSizeBinaryOp {
     Add,
     Sub,
@@ -415,6 +424,11 @@ fn fixed_size_binary_op(
                 FixedShapeTensorArray::new(Field::new(left.name(), output_type.clone()), physical);
             Ok(array.into_series())
         }
+        (DataType::FixedShapeTensor(..), _) | (_, DataType::FixedShapeTensor(..)) => {
+            tensor_binary_op(left, right, output_type, |l, r| {
+                run_fixed_size_binary_op(l, r, op)
+            })
+        }
         (left, right) => unimplemented!("cannot add {left} and {right} types"),
     }
 }
@@ -526,4 +540,33 @@ mod tests {
         assert_eq!(*c?.data_type(), DataType::Utf8);
         Ok(())
     }
+
+    #[test]
+    fn rem_and_div_decimals() -> DaftResult<()> {
+        let a = Int64Array::from(("a", vec![7, -7, 5]))
+            .into_series()
+            .cast(&DataType::Decimal128(10, 2))?;
+        let b = Int64Array::from(("b", vec![2, 2, 0]))
+            .into_series()
+            .cast(&DataType::Decimal128(5, 1))?;
+
+        let rem = (&a % &b)?;
+        assert_eq!(*rem.data_type(), DataType::Decimal128(10, 2));
+        let expected = Int64Array::from(("a", vec![1, -1, 0]))
+            .into_series()
+            .cast(&DataType::Decimal128(10, 2))?;
+        assert_eq!(rem.decimal128()?.get(0), expected.decimal128()?.get(0));
+        assert_eq!(rem.decimal128()?.get(1), expected.decimal128()?.get(1));
+        assert_eq!(rem.decimal128()?.get(2), None);
+
+        // dividing by zero yields null rather than panicking
+        let div = (&a / &b)?;
+        assert_eq!(div.decimal128()?.get(2), None);
+        assert_eq!(div.negate()?.abs()?.decimal128()?.get(2), None);
+        assert_eq!(
+            a.negate()?.abs()?.decimal128()?.get(1),
+            a.decimal128()?.get(0)
+        );
+        Ok(())
+    }
 }
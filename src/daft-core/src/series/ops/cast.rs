@@ -1,3 +1,5 @@
+use std::collections::BTreeSet;
+
 use common_error::DaftResult;
 
 use crate::{datatypes::DataType, series::Series};
@@ -6,4 +8,115 @@ impl Series {
     pub fn cast(&self, datatype: &DataType) -> DaftResult<Self> {
         self.inner.cast(datatype)
     }
+
+    /// Casts this series to a `Categorical` of `categories`, nulling out values that are not one
+    /// of them.
+    ///
+    /// Without `categories`, the distinct non-null values of this series are used in sorted
+    /// order. Those only describe this series, so series that need to share codes, such as the
+    /// partitions of a column, should be given the same categories.
+    pub fn cast_to_categorical(&self, categories: Option<Vec<String>>) -> DaftResult<Self> {
+        let categories = match categories {
+            Some(categories) => categories,
+            None => {
+                let strings = self.cast(&DataType::Utf8)?;
+                let distinct = strings
+                    .utf8()?
+                    .into_iter()
+                    .flatten()
+                    .collect::<BTreeSet<_>>();
+                distinct.into_iter().map(str::to_string).collect()
+            }
+        };
+        self.cast(&DataType::Categorical(categories))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::{DaftCompare, IntoGroups},
+        datatypes::{DataType, Utf8Array},
+        series::{IntoSeries, Series},
+    };
+
+    #[test]
+    fn categorical_round_trip() -> DaftResult<()> {
+        let s = Utf8Array::from_iter(
+            "s",
+            vec![Some("b"), None, Some("a"), Some("b"), Some("c")].into_iter(),
+        )
+        .into_series();
+
+        let inferred = s.cast_to_categorical(None)?;
+        assert_eq!(
+            inferred.data_type(),
+            &DataType::Categorical(vec!["a".into(), "b".into(), "c".into()])
+        );
+        let codes = inferred.as_physical()?;
+        assert_eq!(
+            codes
+                .u32()?
+                .into_iter()
+                .map(|c| c.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(1), None, Some(0), Some(1), Some(2)]
+        );
+        let decoded = inferred.cast(&DataType::Utf8)?;
+        assert_eq!(
+            decoded.utf8()?.into_iter().collect::<Vec<_>>(),
+            s.utf8()?.into_iter().collect::<Vec<_>>()
+        );
+
+        // values outside of the given categories become null
+        let given = s.cast_to_categorical(Some(vec!["b".into(), "a".into()]))?;
+        assert_eq!(
+            given
+                .as_physical()?
+                .u32()?
+                .into_iter()
+                .map(|c| c.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(0), None, Some(1), Some(0), None]
+        );
+        assert_eq!(given.categorical()?.str_value(0)?, "b");
+
+        // comparisons with strings go through the strings of the categories
+        let is_b = inferred.equal(&Utf8Array::from(("b", &["b"][..])).into_series())?;
+        assert_eq!(
+            is_b.into_iter().collect::<Vec<_>>(),
+            vec![Some(true), None, Some(false), Some(true), Some(false)]
+        );
+
+        let (_, groups) = inferred.make_groups()?;
+        assert_eq!(groups.len(), 4);
+        Ok(())
+    }
+    #[test]
+    fn categorical_concat_unifies_categories() -> DaftResult<()> {
+        let left = Utf8Array::from_iter("s", vec![Some("b"), Some("a")].into_iter())
+            .into_series()
+            .cast_to_categorical(None)?;
+        let right = Utf8Array::from_iter("s", vec![Some("c"), None, Some("a")].into_iter())
+            .into_series()
+            .cast_to_categorical(None)?;
+
+        let concatted = Series::concat(&[&left, &right])?;
+        assert_eq!(
+            concatted.data_type(),
+            &DataType::Categorical(vec!["a".into(), "b".into(), "c".into()])
+        );
+        assert_eq!(
+            concatted
+                .as_physical()?
+                .u32()?
+                .into_iter()
+                .map(|c| c.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(1), Some(0), Some(2), None, Some(0)]
+        );
+        Ok(())
+    }
 }
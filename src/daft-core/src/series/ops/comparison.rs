@@ -43,6 +43,11 @@ macro_rules! impl_compare_method {
                         .expect("We expected a Boolean Series from this Python Comparison");
                     Ok(bool_array.clone())
                 }
+                DataType::FixedSizeList(..) => {
+                    let lhs = lhs.cast(&comparison_type)?;
+                    let rhs = rhs.cast(&comparison_type)?;
+                    lhs.fixed_size_list()?.$fname(rhs.fixed_size_list()?)
+                }
                 _ => with_match_comparable_daft_types!(comparison_type, |$T| {
                     cast_downcast_op!(
                         lhs,
@@ -3,6 +3,7 @@ use daft_schema::dtype::DataType;
 
 use crate::{
     series::{IntoSeries, Series},
+    utils::supertype::try_get_collection_supertype,
     with_match_daft_types,
 };
 
@@ -15,6 +16,27 @@ impl Series {
                 "Need at least 1 series to perform concat".to_string(),
             )),
             [single_series] => Ok((*single_series).clone()),
+            // categoricals of different categories are recoded into the union of their categories
+            // rather than decoded to strings
+            [first, ..]
+                if first.data_type().is_categorical()
+                    && all_types.iter().any(|dtype| dtype != first.data_type()) =>
+            {
+                let dtype = try_get_collection_supertype(
+                    all_types.iter().filter(|dtype| **dtype != DataType::Null),
+                )?;
+                if !dtype.is_categorical() {
+                    return Err(DaftError::TypeError(format!(
+                        "Series concat requires all data types to match. Found mismatched types. All types: {:?}",
+                        all_types
+                    )));
+                }
+                let recoded = series
+                    .iter()
+                    .map(|s| s.cast(&dtype))
+                    .collect::<DaftResult<Vec<_>>>()?;
+                Self::concat(&recoded.iter().collect::<Vec<_>>())
+            }
             [first, rest @ ..] => {
                 let mut series = vec![(*first).clone()];
 
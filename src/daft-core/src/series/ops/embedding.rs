@@ -0,0 +1,102 @@
+use common_error::{DaftError, DaftResult};
+
+use crate::{
+    array::FixedSizeListArray,
+    datatypes::{logical::EmbeddingArray, DataType, Field},
+    series::{IntoSeries, Series},
+};
+
+impl Series {
+    /// The physical vectors of an `Embedding` or numeric `FixedSizeList` series.
+    fn embedding_vectors(&self, op: &str) -> DaftResult<&FixedSizeListArray> {
+        match self.data_type() {
+            DataType::Embedding(..) if self.data_type().is_fixed_size_numeric() => {
+                Ok(&self.embedding()?.physical)
+            }
+            DataType::FixedSizeList(..) if self.data_type().is_fixed_size_numeric() => {
+                self.fixed_size_list()
+            }
+            dt => Err(DaftError::TypeError(format!(
+                "embedding.{op} not implemented for {dt}"
+            ))),
+        }
+    }
+
+    pub fn embedding_dot_product(&self, other: &Self) -> DaftResult<Self> {
+        let lhs = self.embedding_vectors("dot_product")?;
+        let rhs = other.embedding_vectors("dot_product")?;
+        Ok(lhs.dot_product(rhs)?.into_series())
+    }
+
+    pub fn embedding_cosine_similarity(&self, other: &Self, normalized: bool) -> DaftResult<Self> {
+        let lhs = self.embedding_vectors("cosine_similarity")?;
+        let rhs = other.embedding_vectors("cosine_similarity")?;
+        Ok(lhs.cosine_similarity(rhs, normalized)?.into_series())
+    }
+
+    pub fn embedding_normalize(&self) -> DaftResult<Self> {
+        let normalized = self.embedding_vectors("normalize")?.l2_normalize()?;
+        match self.data_type() {
+            DataType::Embedding(_, size) => {
+                let child_dtype = normalized.child_data_type().clone();
+                let field = Field::new(
+                    self.name(),
+                    DataType::Embedding(Box::new(child_dtype), *size),
+                );
+                Ok(EmbeddingArray::new(field, normalized).into_series())
+            }
+            _ => Ok(normalized.into_series()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::{ops::as_arrow::AsArrow, FixedSizeListArray},
+        datatypes::{DataType, Field, Float64Array},
+        series::{IntoSeries, Series},
+    };
+
+    fn embeddings(name: &str, values: Vec<f64>) -> DaftResult<Series> {
+        let dtype = DataType::Embedding(Box::new(DataType::Float64), 2);
+        FixedSizeListArray::new(
+            Field::new(name, dtype.to_physical()),
+            Float64Array::from(("item", values)).into_series(),
+            None,
+        )
+        .into_series()
+        .cast(&dtype)
+    }
+
+    #[test]
+    fn embedding_similarity_ops() -> DaftResult<()> {
+        let source = embeddings("source", vec![3.0, 4.0, 1.0, 0.0])?;
+        let query = embeddings("query", vec![0.0, 2.0])?;
+
+        let dot = source.embedding_dot_product(&query)?;
+        assert_eq!(dot.data_type(), &DataType::Float64);
+        assert_eq!(dot.f64()?.as_arrow().values().as_slice(), &[8.0, 0.0]);
+
+        let normalized = source.embedding_normalize()?;
+        assert_eq!(normalized.data_type(), source.data_type());
+        let similarity =
+            normalized.embedding_cosine_similarity(&query.embedding_normalize()?, true)?;
+        let expected = source.embedding_cosine_similarity(&query, false)?;
+        for (a, b) in similarity
+            .f64()?
+            .as_arrow()
+            .values_iter()
+            .zip(expected.f64()?.as_arrow().values_iter())
+        {
+            assert!((a - b).abs() < 1e-9);
+        }
+
+        assert!(query
+            .embedding_dot_product(&Float64Array::from(("x", vec![1.0])).into_series())
+            .is_err());
+        Ok(())
+    }
+}
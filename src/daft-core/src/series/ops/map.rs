@@ -1,6 +1,9 @@
 use common_error::{DaftError, DaftResult};
 
-use crate::{datatypes::DataType, series::Series};
+use crate::{
+    datatypes::DataType,
+    series::{IntoSeries, Series},
+};
 
 impl Series {
     pub fn map_get(&self, key: &Self) -> DaftResult<Self> {
@@ -13,4 +16,26 @@ impl Series {
 
         self.map()?.map_get(key)
     }
+
+    pub fn map_keys(&self) -> DaftResult<Self> {
+        let DataType::Map { .. } = self.data_type() else {
+            return Err(DaftError::TypeError(format!(
+                "map.keys not implemented for {}",
+                self.data_type()
+            )));
+        };
+
+        Ok(self.map()?.map_keys()?.into_series())
+    }
+
+    pub fn map_values(&self) -> DaftResult<Self> {
+        let DataType::Map { .. } = self.data_type() else {
+            return Err(DaftError::TypeError(format!(
+                "map.values not implemented for {}",
+                self.data_type()
+            )));
+        };
+
+        Ok(self.map()?.map_values()?.into_series())
+    }
 }
@@ -5,6 +5,7 @@ use crate::utils::supertype::try_get_supertype;
 
 pub mod abs;
 pub mod agg;
+pub mod approx_top_k;
 pub mod arithmetic;
 pub mod between;
 pub mod binary;
@@ -16,6 +17,7 @@ pub mod clip;
 pub mod comparison;
 pub mod concat;
 pub mod downcast;
+pub mod embedding;
 mod exp;
 pub mod filter;
 pub mod float;
@@ -33,6 +35,7 @@ pub mod minhash;
 pub mod not;
 pub mod null;
 pub mod partitioning;
+pub mod rank;
 pub mod repeat;
 pub mod round;
 pub mod search_sorted;
@@ -43,9 +46,11 @@ pub mod sort;
 pub mod sqrt;
 pub mod struct_;
 pub mod take;
+pub mod tensor;
 pub mod time;
 mod trigonometry;
 pub mod utf8;
+pub mod value_counts;
 pub mod zip;
 
 pub fn cast_series_to_supertype(series: &[&Series]) -> DaftResult<Vec<Series>> {
@@ -3,13 +3,23 @@ use std::ops::Not;
 use common_error::DaftResult;
 
 use crate::{
-    datatypes::BooleanArray,
+    datatypes::{BooleanArray, DaftDataType},
     series::{array_impl::IntoSeries, Series},
+    with_match_integer_daft_types,
 };
 
 impl Not for &Series {
     type Output = DaftResult<Series>;
     fn not(self) -> Self::Output {
+        // integers are inverted bitwise
+        if self.data_type().is_integer() {
+            return with_match_integer_daft_types!(self.data_type(), |$T| {
+                Ok(self
+                    .downcast::<<$T as DaftDataType>::ArrayType>()?
+                    .bitwise_not()?
+                    .into_series())
+            });
+        }
         let array = self.downcast::<BooleanArray>()?;
         Ok((!array)?.into_series())
     }
@@ -21,3 +31,36 @@ impl Not for Series {
         (&self).not()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        datatypes::{Int8Array, UInt8Array},
+        series::IntoSeries,
+    };
+
+    #[test]
+    fn not_inverts_integer_bits() -> DaftResult<()> {
+        let signed = (!Int8Array::from(("a", vec![0, -1, 5])).into_series())?;
+        assert_eq!(
+            signed
+                .i8()?
+                .into_iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(-1), Some(0), Some(-6)]
+        );
+        let unsigned = (!UInt8Array::from(("a", vec![0, 0b1111_0000])).into_series())?;
+        assert_eq!(
+            unsigned
+                .u8()?
+                .into_iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(255), Some(0b0000_1111)]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+use std::str::FromStr;
+
+use arrow2::array::PrimitiveArray;
+use common_error::{DaftError, DaftResult};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    array::ops::{as_arrow::AsArrow, DaftCompare},
+    datatypes::{DataType, Field, Float64Array, UInt64Array},
+    series::{IntoSeries, Series},
+};
+
+/// How tied values are ranked.
+///
+/// | Min      - Every tied value gets the lowest rank of the group, as in SQL's `RANK`.
+/// | Max      - Every tied value gets the highest rank of the group.
+/// | Dense    - Like `Min`, but ranks of consecutive groups differ by 1, as in SQL's `DENSE_RANK`.
+/// | Average  - Every tied value gets the average rank of the group.
+/// | Ordinal  - Tied values get distinct ranks in the order they appear, as in SQL's `ROW_NUMBER`.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Serialize, Deserialize, Hash)]
+pub enum RankMethod {
+    Min,
+    Max,
+    Dense,
+    Average,
+    Ordinal,
+}
+
+impl RankMethod {
+    pub fn iterator() -> std::slice::Iter<'static, Self> {
+        static RANK_METHODS: [RankMethod; 5] = [
+            RankMethod::Min,
+            RankMethod::Max,
+            RankMethod::Dense,
+            RankMethod::Average,
+            RankMethod::Ordinal,
+        ];
+        RANK_METHODS.iter()
+    }
+}
+
+impl FromStr for RankMethod {
+    type Err = DaftError;
+
+    fn from_str(method: &str) -> DaftResult<Self> {
+        match method.to_lowercase().as_str() {
+            "min" => Ok(Self::Min),
+            "max" => Ok(Self::Max),
+            "dense" => Ok(Self::Dense),
+            "average" => Ok(Self::Average),
+            "ordinal" => Ok(Self::Ordinal),
+            _ => Err(DaftError::ValueError(format!(
+                "Rank method {} is not supported; only the following methods are supported: {:?}",
+                method,
+                Self::iterator().as_slice()
+            ))),
+        }
+    }
+}
+
+impl Series {
+    /// Ranks the values of this series, starting at 1.
+    ///
+    /// `nulls_first` places nulls before (`Some(true)`) or after (`Some(false)`) every other
+    /// value, ranking them as ties of each other. With `None`, nulls are left out of the ranking
+    /// and get a null rank. Ranks are `UInt64`, except for [`RankMethod::Average`] which
+    /// returns `Float64`.
+    pub fn rank(
+        &self,
+        method: RankMethod,
+        descending: bool,
+        nulls_first: Option<bool>,
+    ) -> DaftResult<Self> {
+        let len = self.len();
+        let order = self.argsort(descending, nulls_first.unwrap_or(false))?;
+        let order = order.u64()?.as_arrow().values();
+        let sorted = self.take(&UInt64Array::from(("order", order.to_vec())).into_series())?;
+
+        // ties[i] is whether the i-th smallest value is equal to the one before it
+        let ties = if len > 1 {
+            let ties = sorted
+                .slice(1, len)?
+                .eq_null_safe(&sorted.slice(0, len - 1)?)?;
+            let ties = ties.as_arrow();
+            ties.iter()
+                .map(|tie| tie.unwrap_or(false))
+                .collect::<Vec<_>>()
+        } else {
+            vec![]
+        };
+
+        // average ranks are the only fractional ones, so they are tracked separately
+        let mut ranks = vec![0u64; len];
+        let mut average_ranks = vec![
+            0f64;
+            if method == RankMethod::Average {
+                len
+            } else {
+                0
+            }
+        ];
+        let mut start = 0;
+        let mut dense_rank = 0;
+        while start < len {
+            let mut end = start + 1;
+            while end < len && ties[end - 1] {
+                end += 1;
+            }
+            dense_rank += 1;
+            // ordinal ranks break ties by position, independently of how the sort ordered them
+            let mut group = order[start..end].to_vec();
+            group.sort_unstable();
+            for (i, &idx) in group.iter().enumerate() {
+                let idx = idx as usize;
+                match method {
+                    RankMethod::Min => ranks[idx] = (start + 1) as u64,
+                    RankMethod::Max => ranks[idx] = end as u64,
+                    RankMethod::Dense => ranks[idx] = dense_rank,
+                    RankMethod::Average => average_ranks[idx] = (start + 1 + end) as f64 / 2.0,
+                    RankMethod::Ordinal => ranks[idx] = (start + i + 1) as u64,
+                }
+            }
+            start = end;
+        }
+
+        let validity = match nulls_first {
+            Some(_) => None,
+            None => self.validity().cloned(),
+        };
+        if method == RankMethod::Average {
+            let field = Field::new(self.name(), DataType::Float64);
+            let ranks = PrimitiveArray::from_vec(average_ranks).with_validity(validity);
+            Ok(Float64Array::new(field.into(), Box::new(ranks))?.into_series())
+        } else {
+            let field = Field::new(self.name(), DataType::UInt64);
+            let ranks = PrimitiveArray::from_vec(ranks).with_validity(validity);
+            Ok(UInt64Array::new(field.into(), Box::new(ranks))?.into_series())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use super::RankMethod;
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{DataType, Field, Int64Array},
+        series::{IntoSeries, Series},
+    };
+
+    fn series() -> Series {
+        Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(3), None, Some(1), Some(3), Some(2), Some(3)].into_iter(),
+        )
+        .into_series()
+    }
+
+    fn u64_ranks(s: &Series) -> DaftResult<Vec<Option<u64>>> {
+        Ok(s.u64()?.as_arrow().iter().map(|v| v.copied()).collect())
+    }
+
+    #[test]
+    fn rank_tie_methods() -> DaftResult<()> {
+        let s = series();
+        let cases = [
+            (RankMethod::Min, [3, 1, 2, 3, 3]),
+            (RankMethod::Max, [5, 1, 2, 5, 5]),
+            (RankMethod::Dense, [3, 1, 2, 3, 3]),
+            (RankMethod::Ordinal, [3, 1, 2, 4, 5]),
+        ];
+        for (method, [a, b, c, d, e]) in cases {
+            let ranks = s.rank(method, false, None)?;
+            assert_eq!(ranks.data_type(), &DataType::UInt64);
+            assert_eq!(
+                u64_ranks(&ranks)?,
+                vec![Some(a), None, Some(b), Some(d), Some(c), Some(e)],
+                "{method}"
+            );
+        }
+
+        let average = s.rank(RankMethod::Average, false, None)?;
+        assert_eq!(average.data_type(), &DataType::Float64);
+        assert_eq!(
+            average
+                .f64()?
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(4.0), None, Some(1.0), Some(4.0), Some(2.0), Some(4.0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rank_descending_and_null_placement() -> DaftResult<()> {
+        let s = series();
+        assert_eq!(
+            u64_ranks(&s.rank(RankMethod::Dense, true, None)?)?,
+            vec![Some(1), None, Some(3), Some(1), Some(2), Some(1)]
+        );
+        assert_eq!(
+            u64_ranks(&s.rank(RankMethod::Min, false, Some(true))?)?,
+            vec![Some(4), Some(1), Some(2), Some(4), Some(3), Some(4)]
+        );
+        assert_eq!(
+            u64_ranks(&s.rank(RankMethod::Min, false, Some(false))?)?,
+            vec![Some(3), Some(6), Some(1), Some(3), Some(2), Some(3)]
+        );
+        assert_eq!(
+            u64_ranks(&Series::empty("a", &DataType::Int64).rank(RankMethod::Min, false, None)?)?,
+            vec![]
+        );
+        Ok(())
+    }
+}
@@ -62,6 +62,7 @@ impl Series {
             DataType::Int64 => Ok(self.i64().unwrap().negative()?.into_series()),
             DataType::Float32 => Ok(self.f32().unwrap().negative()?.into_series()),
             DataType::Float64 => Ok(self.f64().unwrap().negative()?.into_series()),
+            DataType::Decimal128(..) => Ok(self.decimal128()?.apply(|v| -v)?.into_series()),
             dt => Err(DaftError::TypeError(format!(
                 "negate not implemented for {}",
                 dt
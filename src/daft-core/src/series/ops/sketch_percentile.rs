@@ -1,6 +1,6 @@
 use common_error::{DaftError, DaftResult};
 
-use crate::series::Series;
+use crate::{array::ops::GroupIndices, series::Series};
 
 impl Series {
     pub fn sketch_percentile(
@@ -20,4 +20,65 @@ impl Series {
             ))),
         }
     }
+    /// Computes approximate quantiles of the numeric values of this series, or of each of its
+    /// `groups`, as `FixedSizeList[Float64; N]` rows where `N` is the number of `quantiles`.
+    ///
+    /// Values are summarized with a mergeable sketch, so results are the same as those of the
+    /// `approx_percentiles` aggregation.
+    pub fn approx_quantiles(
+        &self,
+        quantiles: &[f64],
+        groups: Option<&GroupIndices>,
+    ) -> DaftResult<Self> {
+        if let Some(q) = quantiles.iter().find(|q| !(0. ..=1.).contains(*q)) {
+            return Err(DaftError::ValueError(format!(
+                "Provided quantile must be between 0 and 1: {q}"
+            )));
+        }
+        self.approx_sketch(groups)?
+            .sketch_percentile(quantiles, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        datatypes::{DataType, Field, Int64Array},
+        series::IntoSeries,
+    };
+
+    #[test]
+    fn approx_quantiles_per_group() -> DaftResult<()> {
+        let s = Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(1), Some(100), Some(2), None, Some(3)].into_iter(),
+        )
+        .into_series();
+        let groups = vec![vec![0, 2, 4], vec![1], vec![3]];
+        let result = s.approx_quantiles(&[0.0, 1.0], Some(&groups))?;
+        assert_eq!(
+            result.data_type(),
+            &DataType::FixedSizeList(Box::new(DataType::Float64), 2)
+        );
+
+        let result = result.fixed_size_list()?;
+        assert_eq!(
+            result.validity().map(|v| v.iter().collect::<Vec<_>>()),
+            Some(vec![true, true, false])
+        );
+        let values = result.flat_child.f64()?;
+        for (i, expected) in [1.0, 3.0, 100.0, 100.0].into_iter().enumerate() {
+            let value = values.get(i).unwrap();
+            assert!(
+                (value - expected).abs() / expected < 0.02,
+                "{value} vs {expected}"
+            );
+        }
+
+        assert_eq!(s.approx_quantiles(&[0.5], None)?.len(), 1);
+        assert!(s.approx_quantiles(&[1.5], None).is_err());
+        Ok(())
+    }
 }
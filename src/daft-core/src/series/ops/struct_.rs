@@ -1,6 +1,9 @@
 use common_error::{DaftError, DaftResult};
 
-use crate::{datatypes::DataType, series::Series};
+use crate::{
+    datatypes::DataType,
+    series::{IntoSeries, Series},
+};
 
 impl Series {
     pub fn struct_get(&self, name: &str) -> DaftResult<Self> {
@@ -12,4 +15,31 @@ impl Series {
             ))),
         }
     }
+
+    pub fn struct_with_field(&self, name: &str, value: &Self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Struct(_) => Ok(self.struct_()?.with_field(name, value)?.into_series()),
+            dt => Err(DaftError::TypeError(format!(
+                "with_field not implemented for {dt}"
+            ))),
+        }
+    }
+
+    pub fn struct_drop_fields(&self, names: &[String]) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Struct(_) => Ok(self.struct_()?.drop_fields(names)?.into_series()),
+            dt => Err(DaftError::TypeError(format!(
+                "drop not implemented for {dt}"
+            ))),
+        }
+    }
+
+    pub fn struct_rename_fields(&self, mapping: &[(String, String)]) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Struct(_) => Ok(self.struct_()?.rename_fields(mapping)?.into_series()),
+            dt => Err(DaftError::TypeError(format!(
+                "rename not implemented for {dt}"
+            ))),
+        }
+    }
 }
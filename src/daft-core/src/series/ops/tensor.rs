@@ -0,0 +1,459 @@
+use arrow2::{array::PrimitiveArray, bitmap::Bitmap, offset::OffsetsBuffer};
+use common_error::{DaftError, DaftResult};
+
+use crate::{
+    array::{ops::as_arrow::AsArrow, FixedSizeListArray, ListArray, StructArray},
+    datatypes::{
+        logical::{FixedShapeTensorArray, TensorArray},
+        DataType, Field, Float64Array, UInt64Array,
+    },
+    series::{IntoSeries, Series},
+};
+
+/// A view of a tensor series as rows of elements in a flat child, each with its own shape.
+///
+/// Numeric series are viewed as tensors of rank 0, so that they can be broadcast against tensors.
+struct TensorRows<'a> {
+    flat: &'a Series,
+    /// The start of each row in `flat`
+    starts: Vec<usize>,
+    shapes: Vec<Vec<u64>>,
+    validity: Option<&'a Bitmap>,
+}
+
+impl<'a> TensorRows<'a> {
+    fn try_new(series: &'a Series) -> DaftResult<Self> {
+        match series.data_type() {
+            DataType::FixedShapeTensor(_, shape) => {
+                let physical = &series.downcast::<FixedShapeTensorArray>()?.physical;
+                let size = physical.fixed_element_len();
+                Ok(Self {
+                    flat: &physical.flat_child,
+                    starts: (0..physical.len()).map(|i| i * size).collect(),
+                    shapes: vec![shape.clone(); physical.len()],
+                    validity: physical.validity(),
+                })
+            }
+            DataType::Tensor(_) => {
+                let array = series.downcast::<TensorArray>()?;
+                let data = array.data_array();
+                let shape_array = array.shape_array();
+                let shape_values = shape_array.flat_child.u64()?.as_arrow().values();
+                let shapes = shape_array
+                    .offsets()
+                    .buffer()
+                    .windows(2)
+                    .map(|w| shape_values[w[0] as usize..w[1] as usize].to_vec())
+                    .collect();
+                Ok(Self {
+                    flat: &data.flat_child,
+                    starts: data
+                        .offsets()
+                        .buffer()
+                        .iter()
+                        .map(|o| *o as usize)
+                        .collect(),
+                    shapes,
+                    validity: array.physical.validity(),
+                })
+            }
+            dtype if dtype.is_numeric() => Ok(Self {
+                flat: series,
+                starts: (0..series.len()).collect(),
+                shapes: vec![vec![]; series.len()],
+                validity: series.validity(),
+            }),
+            dtype => Err(DaftError::TypeError(format!(
+                "Expected a tensor or numeric series, but received {dtype}"
+            ))),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    fn is_valid(&self, idx: usize) -> bool {
+        self.validity.is_none_or(|v| v.get_bit(idx))
+    }
+}
+
+fn num_elements(shape: &[u64]) -> usize {
+    shape.iter().product::<u64>() as usize
+}
+
+/// Builds a series of the tensor `dtype` from the flattened elements and shape of each row.
+fn tensor_from_rows(
+    name: &str,
+    dtype: &DataType,
+    flat: Series,
+    shapes: Vec<Vec<u64>>,
+    validity: Option<Bitmap>,
+) -> DaftResult<Series> {
+    match dtype {
+        DataType::FixedShapeTensor(..) => {
+            let physical =
+                FixedSizeListArray::new(Field::new(name, dtype.to_physical()), flat, validity);
+            Ok(FixedShapeTensorArray::new(Field::new(name, dtype.clone()), physical).into_series())
+        }
+        DataType::Tensor(inner) => {
+            let data_offsets = OffsetsBuffer::try_from(
+                std::iter::once(0)
+                    .chain(shapes.iter().enumerate().scan(0i64, |end, (i, shape)| {
+                        // null rows hold no elements
+                        if validity.as_ref().is_none_or(|v| v.get_bit(i)) {
+                            *end += num_elements(shape) as i64;
+                        }
+                        Some(*end)
+                    }))
+                    .collect::<Vec<_>>(),
+            )?;
+            let shape_offsets = OffsetsBuffer::try_from(
+                std::iter::once(0)
+                    .chain(shapes.iter().scan(0i64, |end, shape| {
+                        *end += shape.len() as i64;
+                        Some(*end)
+                    }))
+                    .collect::<Vec<_>>(),
+            )?;
+            let data = ListArray::new(
+                Field::new("data", DataType::List(Box::new(inner.as_ref().clone()))),
+                flat.rename("data"),
+                data_offsets,
+                validity.clone(),
+            );
+            let shape = ListArray::new(
+                Field::new("shape", DataType::List(Box::new(DataType::UInt64))),
+                UInt64Array::from(("shape", shapes.into_iter().flatten().collect::<Vec<_>>()))
+                    .into_series(),
+                shape_offsets,
+                validity.clone(),
+            );
+            let physical = StructArray::new(
+                Field::new(name, dtype.to_physical()),
+                vec![data.into_series(), shape.into_series()],
+                validity,
+            );
+            Ok(TensorArray::new(Field::new(name, dtype.clone()), physical).into_series())
+        }
+        _ => unreachable!("tensor_from_rows expects a tensor type, but received {dtype}"),
+    }
+}
+
+/// Applies the element-wise `kernel` on the tensors of `lhs` and `rhs`, where either side may be
+/// a numeric series that is broadcast over the elements of the other's tensors, and either side
+/// is broadcast if it has a single row.
+pub(super) fn tensor_binary_op<Kernel>(
+    lhs: &Series,
+    rhs: &Series,
+    output_type: &DataType,
+    kernel: Kernel,
+) -> DaftResult<Series>
+where
+    Kernel: Fn(&Series, &Series) -> DaftResult<Series>,
+{
+    let left = TensorRows::try_new(lhs)?;
+    let right = TensorRows::try_new(rhs)?;
+    let len = match (left.len(), right.len()) {
+        (l, r) if l == r => l,
+        (1, r) => r,
+        (l, 1) => l,
+        (l, r) => {
+            return Err(DaftError::ValueError(format!(
+                "Cannot apply operation on arrays of different lengths: {l} vs {r}"
+            )))
+        }
+    };
+    let is_fixed_shape = output_type.is_fixed_shape_tensor();
+
+    let mut shapes = Vec::with_capacity(len);
+    let mut left_idx = vec![];
+    let mut right_idx = vec![];
+    let mut validity = Vec::with_capacity(len);
+    for i in 0..len {
+        let l = if left.len() == 1 { 0 } else { i };
+        let r = if right.len() == 1 { 0 } else { i };
+        let (left_shape, right_shape) = (&left.shapes[l], &right.shapes[r]);
+        let is_valid = left.is_valid(l) && right.is_valid(r);
+        validity.push(is_valid);
+        // fixed shape tensors hold elements for null rows too, the others don't
+        if !is_valid && !is_fixed_shape {
+            shapes.push(vec![]);
+            continue;
+        }
+        let shape = if left_shape.is_empty() {
+            right_shape
+        } else if right_shape.is_empty() || left_shape == right_shape {
+            left_shape
+        } else {
+            return Err(DaftError::ValueError(format!(
+                "Cannot apply operation on tensors of different shapes: {left_shape:?} vs {right_shape:?} at row {i}"
+            )));
+        };
+        for j in 0..num_elements(shape) {
+            let offset = |shape: &Vec<u64>| if shape.is_empty() { 0 } else { j };
+            left_idx.push((left.starts[l] + offset(left_shape)) as u64);
+            right_idx.push((right.starts[r] + offset(right_shape)) as u64);
+        }
+        shapes.push(shape.clone());
+    }
+
+    let left_values = left
+        .flat
+        .take(&UInt64Array::from(("idx", left_idx)).into_series())?;
+    let right_values = right
+        .flat
+        .take(&UInt64Array::from(("idx", right_idx)).into_series())?;
+    let inner = match output_type {
+        DataType::FixedShapeTensor(inner, _) | DataType::Tensor(inner) => inner.as_ref(),
+        _ => unreachable!("tensor_binary_op expects a tensor output type"),
+    };
+    let result = kernel(&left_values, &right_values)?.cast(inner)?;
+    let validity = validity
+        .contains(&false)
+        .then(|| Bitmap::from_iter(validity));
+    tensor_from_rows(lhs.name(), output_type, result, shapes, validity)
+}
+
+impl Series {
+    /// Sums the elements of each tensor over the given `axes`, or over all of them if `None`.
+    ///
+    /// Sums are `Float64`. Reducing a tensor over all of its axes returns one number per row,
+    /// otherwise each row is a tensor of the shape left after removing the reduced axes.
+    pub fn tensor_sum(&self, axes: Option<&[usize]>) -> DaftResult<Self> {
+        self.tensor_reduce(axes, "sum", false)
+    }
+
+    /// Averages the elements of each tensor over the given `axes`, or over all of them if
+    /// `None`. Null elements are left out of the average.
+    ///
+    /// See [`Series::tensor_sum`] for the shape of the result.
+    pub fn tensor_mean(&self, axes: Option<&[usize]>) -> DaftResult<Self> {
+        self.tensor_reduce(axes, "mean", true)
+    }
+
+    fn tensor_reduce(&self, axes: Option<&[usize]>, op: &str, mean: bool) -> DaftResult<Self> {
+        if !matches!(
+            self.data_type(),
+            DataType::Tensor(..) | DataType::FixedShapeTensor(..)
+        ) {
+            return Err(DaftError::TypeError(format!(
+                "tensor.{op} not implemented for {}",
+                self.data_type()
+            )));
+        }
+        let rows = TensorRows::try_new(self)?;
+        let flat = rows.flat.cast(&DataType::Float64)?;
+        let flat = flat.f64()?.as_arrow();
+        let output_type = match (self.data_type(), axes) {
+            (_, None) => DataType::Float64,
+            (DataType::FixedShapeTensor(_, shape), Some(axes)) => {
+                let reduced = reduce_shape(shape, axes, op)?;
+                if reduced.is_empty() {
+                    DataType::Float64
+                } else {
+                    DataType::FixedShapeTensor(Box::new(DataType::Float64), reduced)
+                }
+            }
+            _ => DataType::Tensor(Box::new(DataType::Float64)),
+        };
+
+        let mut values = vec![];
+        let mut shapes = Vec::with_capacity(rows.len());
+        for (i, shape) in rows.shapes.iter().enumerate() {
+            if !rows.is_valid(i) {
+                // null rows still take up elements in outputs of a fixed size
+                match &output_type {
+                    DataType::FixedShapeTensor(_, reduced) => {
+                        values.extend(std::iter::repeat_n(0.0, num_elements(reduced)));
+                    }
+                    DataType::Tensor(_) => {}
+                    _ => values.push(0.0),
+                }
+                shapes.push(vec![]);
+                continue;
+            }
+            let all_axes = (0..shape.len()).collect::<Vec<_>>();
+            let reduced_axes = axes.unwrap_or(&all_axes);
+            let reduced = reduce_shape(shape, reduced_axes, op)?;
+
+            // the stride in the reduced tensor of each axis of the original one, or 0 if reduced
+            let mut strides = vec![0; shape.len()];
+            let mut stride = 1;
+            for axis in (0..shape.len()).rev() {
+                if !reduced_axes.contains(&axis) {
+                    strides[axis] = stride;
+                    stride *= shape[axis] as usize;
+                }
+            }
+            let mut sums = vec![0.0; num_elements(&reduced)];
+            let mut counts = vec![0usize; sums.len()];
+            for j in 0..num_elements(shape) {
+                let Some(value) = flat.get(rows.starts[i] + j) else {
+                    continue;
+                };
+                let (mut out, mut rest) = (0, j);
+                for axis in (0..shape.len()).rev() {
+                    out += (rest % shape[axis] as usize) * strides[axis];
+                    rest /= shape[axis] as usize;
+                }
+                sums[out] += value;
+                counts[out] += 1;
+            }
+            if mean {
+                for (sum, count) in sums.iter_mut().zip(counts) {
+                    *sum /= count as f64;
+                }
+            }
+            values.extend(sums);
+            shapes.push(reduced);
+        }
+
+        let validity = rows.validity.cloned();
+        if output_type.is_numeric() {
+            let field = Field::new(self.name(), DataType::Float64);
+            let values = PrimitiveArray::from_vec(values).with_validity(validity);
+            return Ok(Float64Array::new(field.into(), Box::new(values))?.into_series());
+        }
+        let values = Float64Array::from(("data", values)).into_series();
+        tensor_from_rows(self.name(), &output_type, values, shapes, validity)
+    }
+}
+
+/// The shape of a tensor of `shape` after reducing it over `axes`.
+fn reduce_shape(shape: &[u64], axes: &[usize], op: &str) -> DaftResult<Vec<u64>> {
+    for (i, axis) in axes.iter().enumerate() {
+        if *axis >= shape.len() || axes[..i].contains(axis) {
+            return Err(DaftError::ValueError(format!(
+                "Invalid axes {axes:?} for tensor.{op} of a tensor with shape {shape:?}"
+            )));
+        }
+    }
+    Ok(shape
+        .iter()
+        .enumerate()
+        .filter(|(axis, _)| !axes.contains(axis))
+        .map(|(_, dim)| *dim)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::{ops::as_arrow::AsArrow, FixedSizeListArray},
+        datatypes::{DataType, Field, Int64Array},
+        series::{IntoSeries, Series},
+    };
+
+    /// Two tensors of 0..6 and 6..12, the second of which is null if `with_null`.
+    fn fixed_shape_tensors(shape: Vec<u64>, with_null: bool) -> DaftResult<Series> {
+        let validity = with_null.then(|| arrow2::bitmap::Bitmap::from([true, false]));
+        FixedSizeListArray::new(
+            Field::new("t", DataType::FixedSizeList(Box::new(DataType::Int64), 6)),
+            Int64Array::from(("item", (0..12).collect::<Vec<_>>())).into_series(),
+            validity,
+        )
+        .into_series()
+        .cast(&DataType::FixedShapeTensor(
+            Box::new(DataType::Int64),
+            shape,
+        ))
+    }
+
+    /// Two 2x3 tensors of `dtype`, see [`fixed_shape_tensors`].
+    fn tensors(dtype: &DataType, with_null: bool) -> DaftResult<Series> {
+        fixed_shape_tensors(vec![2, 3], with_null)?.cast(dtype)
+    }
+
+    fn rows(s: &Series) -> DaftResult<Vec<Option<Vec<f64>>>> {
+        let s = match s.data_type() {
+            DataType::FixedShapeTensor(inner, _) => s.cast(&DataType::Tensor(inner.clone()))?,
+            _ => s.clone(),
+        };
+        let data = s.tensor()?.data_array();
+        let values = data.flat_child.cast(&DataType::Float64)?;
+        let values = values.f64()?.as_arrow().values();
+        Ok((0..s.len())
+            .map(|i| {
+                s.is_valid(i).then(|| {
+                    let (start, end) = data.offsets().start_end(i);
+                    values[start..end].to_vec()
+                })
+            })
+            .collect())
+    }
+
+    #[test]
+    fn tensor_arithmetic() -> DaftResult<()> {
+        let fixed = DataType::FixedShapeTensor(Box::new(DataType::Int64), vec![2, 3]);
+        let variable = DataType::Tensor(Box::new(DataType::Int64));
+        for dtype in [&fixed, &variable] {
+            let t = tensors(dtype, true)?;
+            let scalar = Int64Array::from(("s", vec![10])).into_series();
+            let sum = (&t + &scalar)?;
+            assert_eq!(sum.data_type(), dtype);
+            assert_eq!(
+                rows(&sum)?,
+                vec![Some(vec![10.0, 11.0, 12.0, 13.0, 14.0, 15.0]), None]
+            );
+
+            let per_row = Int64Array::from(("s", vec![2, 3])).into_series();
+            assert_eq!(
+                rows(&(&per_row * &tensors(dtype, false)?)?)?,
+                vec![
+                    Some(vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0]),
+                    Some(vec![18.0, 21.0, 24.0, 27.0, 30.0, 33.0])
+                ]
+            );
+
+            let quotient = (&t / &t)?;
+            assert!(
+                quotient.data_type().is_tensor() || quotient.data_type().is_fixed_shape_tensor()
+            );
+            assert_eq!(rows(&(&t - &t)?)?, vec![Some(vec![0.0; 6]), None]);
+        }
+
+        let t = tensors(&variable, false)?;
+        let reshaped = fixed_shape_tensors(vec![3, 2], false)?.cast(&variable)?;
+        assert!((&t + &reshaped).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn tensor_reductions() -> DaftResult<()> {
+        let fixed = DataType::FixedShapeTensor(Box::new(DataType::Int64), vec![2, 3]);
+        let variable = DataType::Tensor(Box::new(DataType::Int64));
+        for dtype in [&fixed, &variable] {
+            let t = tensors(dtype, true)?;
+
+            let total = t.tensor_sum(None)?;
+            assert_eq!(total.data_type(), &DataType::Float64);
+            assert_eq!(
+                total
+                    .f64()?
+                    .as_arrow()
+                    .iter()
+                    .map(|v| v.copied())
+                    .collect::<Vec<_>>(),
+                vec![Some(15.0), None]
+            );
+
+            let columns = t.tensor_sum(Some(&[0]))?;
+            assert_eq!(rows(&columns)?, vec![Some(vec![3.0, 5.0, 7.0]), None]);
+            let row_means = t.tensor_mean(Some(&[1]))?;
+            assert_eq!(rows(&row_means)?, vec![Some(vec![1.0, 4.0]), None]);
+
+            assert!(t.tensor_sum(Some(&[2])).is_err());
+            assert!(t.tensor_sum(Some(&[0, 0])).is_err());
+        }
+
+        let fixed_means = tensors(&fixed, false)?.tensor_mean(Some(&[0, 1]))?;
+        assert_eq!(
+            fixed_means.f64()?.as_arrow().values().as_slice(),
+            &[2.5, 8.5]
+        );
+        Ok(())
+    }
+}
@@ -5,7 +5,10 @@ use common_error::{DaftError, DaftResult};
 use daft_schema::field::Field;
 
 use crate::{
-    datatypes::{DataType, TimeUnit},
+    datatypes::{
+        logical::{DateArray, TimestampArray},
+        DataType, TimeUnit,
+    },
     series::{array_impl::IntoSeries, Series},
 };
 
@@ -254,6 +257,124 @@ impl Series {
         }
     }
 
+    pub fn dt_quarter(&self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Date => Ok(self.date()?.quarter()?.into_series()),
+            DataType::Timestamp(..) => Ok(self.timestamp()?.date()?.quarter()?.into_series()),
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run quarter() operation on temporal types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_week_of_year(&self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Date => Ok(self.date()?.week_of_year()?.into_series()),
+            DataType::Timestamp(..) => Ok(self.timestamp()?.date()?.week_of_year()?.into_series()),
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run week_of_year() operation on temporal types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_last_day_of_month(&self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Date => Ok(self.date()?.last_day_of_month()?.into_series()),
+            DataType::Timestamp(..) => {
+                Ok(self.timestamp()?.date()?.last_day_of_month()?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run last_day_of_month() operation on temporal types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    /// Shifts the dates or timestamps of this series forward by `n` of `unit`, see
+    /// [`TimestampArray::add_units`](crate::datatypes::logical::TimestampArray::add_units).
+    pub fn dt_add(&self, n: &Self, unit: &str) -> DaftResult<Self> {
+        if !n.data_type().is_integer() && !n.data_type().is_null() {
+            return Err(DaftError::TypeError(format!(
+                "Expected the number of units to add to be an integer, got {}",
+                n.data_type()
+            )));
+        }
+        let [input, n] = broadcast_all([self, &n.cast(&DataType::Int64)?])?;
+        match input.data_type() {
+            DataType::Date => Ok(input.date()?.add_units(n.i64()?, unit)?.into_series()),
+            DataType::Timestamp(..) => {
+                Ok(input.timestamp()?.add_units(n.i64()?, unit)?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run date_add() operation on dates and timestamps, got {}",
+                input.data_type()
+            ))),
+        }
+    }
+
+    /// Returns the number of whole `unit`s from `start` to the dates or timestamps of this series,
+    /// casting `start` to the type of this series.
+    pub fn dt_diff(&self, start: &Self, unit: &str) -> DaftResult<Self> {
+        let [end, start] = broadcast_all([self, &start.cast(self.data_type())?])?;
+        match end.data_type() {
+            DataType::Date => Ok(end.date()?.diff_units(start.date()?, unit)?.into_series()),
+            DataType::Timestamp(..) => Ok(end
+                .timestamp()?
+                .diff_units(start.timestamp()?, unit)?
+                .into_series()),
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run date_diff() operation on dates and timestamps, got {}",
+                end.data_type()
+            ))),
+        }
+    }
+
+    /// Builds a date series from integer years, months and days.
+    pub fn make_date(year: &Self, month: &Self, day: &Self) -> DaftResult<Self> {
+        let [year, month, day] = broadcast_all([
+            &year.cast(&DataType::Int32)?,
+            &month.cast(&DataType::Int32)?,
+            &day.cast(&DataType::Int32)?,
+        ])?;
+        Ok(DateArray::from_ymd(year.name(), year.i32()?, month.i32()?, day.i32()?)?.into_series())
+    }
+
+    /// Builds a microsecond timestamp series from the parts of local dates and times in `tz`.
+    ///
+    /// `second` may be fractional.
+    #[allow(clippy::too_many_arguments)]
+    pub fn make_timestamp(
+        year: &Self,
+        month: &Self,
+        day: &Self,
+        hour: &Self,
+        minute: &Self,
+        second: &Self,
+        tz: Option<&str>,
+    ) -> DaftResult<Self> {
+        let [year, month, day, hour, minute, second] = broadcast_all([
+            &year.cast(&DataType::Int32)?,
+            &month.cast(&DataType::Int32)?,
+            &day.cast(&DataType::Int32)?,
+            &hour.cast(&DataType::Int32)?,
+            &minute.cast(&DataType::Int32)?,
+            &second.cast(&DataType::Float64)?,
+        ])?;
+        Ok(TimestampArray::from_parts(
+            year.name(),
+            year.i32()?,
+            month.i32()?,
+            day.i32()?,
+            hour.i32()?,
+            minute.i32()?,
+            second.f64()?,
+            tz,
+        )?
+        .into_series())
+    }
+
     pub fn dt_truncate(&self, interval: &str, relative_to: &Self) -> DaftResult<Self> {
         match (self.data_type(), relative_to.data_type()) {
             (DataType::Timestamp(self_tu,self_tz), DataType::Timestamp(start_tu,start_tz)) if self_tu == start_tu && self_tz == start_tz => {
@@ -286,6 +407,32 @@ impl Series {
         }
     }
 
+    pub fn dt_convert_time_zone(&self, tz: &str) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Timestamp(..) => {
+                let ts_array = self.timestamp()?;
+                Ok(ts_array.convert_time_zone(tz)?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run convert_time_zone() operation on timestamp types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_replace_time_zone(&self, tz: Option<&str>) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Timestamp(..) => {
+                let ts_array = self.timestamp()?;
+                Ok(ts_array.replace_time_zone(tz)?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run replace_time_zone() operation on timestamp types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
     pub fn dt_to_unix_epoch(&self, time_unit: TimeUnit) -> DaftResult<Self> {
         let cast_to = DataType::Timestamp(time_unit, None);
         self.cast(&cast_to)?.cast(&DataType::Int64)
@@ -307,3 +454,23 @@ impl Series {
         }
     }
 }
+
+/// Broadcasts series of length 1 to the length of the longest of `inputs`.
+fn broadcast_all<const N: usize>(inputs: [&Series; N]) -> DaftResult<[Series; N]> {
+    let len = inputs.iter().map(|s| s.len()).max().unwrap_or(0);
+    let mut broadcasted = Vec::with_capacity(N);
+    for input in inputs {
+        broadcasted.push(match input.len() {
+            l if l == len => input.clone(),
+            1 => input.broadcast(len)?,
+            l => {
+                return Err(DaftError::ValueError(format!(
+                    "Expected inputs of length 1 or {len}, got {l}"
+                )))
+            }
+        });
+    }
+    Ok(broadcasted
+        .try_into()
+        .unwrap_or_else(|_| unreachable!("one series is broadcasted per input")))
+}
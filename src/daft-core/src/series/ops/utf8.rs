@@ -68,12 +68,13 @@ impl Series {
         pattern: &Self,
         replacement: &Self,
         regex: bool,
+        max_replacements: Option<usize>,
     ) -> DaftResult<Self> {
         self.with_utf8_array(|arr| {
             pattern.with_utf8_array(|pattern_arr| {
                 replacement.with_utf8_array(|replacement_arr| {
                     Ok(arr
-                        .replace(pattern_arr, replacement_arr, regex)?
+                        .replace(pattern_arr, replacement_arr, regex, max_replacements)?
                         .into_series())
                 })
             })
@@ -112,6 +113,10 @@ impl Series {
         self.with_utf8_array(|arr| Ok(arr.capitalize()?.into_series()))
     }
 
+    pub fn utf8_to_titlecase(&self) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| Ok(arr.to_titlecase()?.into_series()))
+    }
+
     pub fn utf8_left(&self, nchars: &Self) -> DaftResult<Self> {
         self.with_utf8_array(|arr| {
             if nchars.data_type().is_integer() {
@@ -0,0 +1,213 @@
+use arrow2::compute::sort::row::{RowConverter, SortField};
+use common_error::DaftResult;
+
+use crate::{
+    array::{ops::IntoGroups, StructArray},
+    datatypes::{BinaryArray, DataType, Field, UInt64Array},
+    series::{IntoSeries, Series},
+};
+
+/// Appends `value` to `out` prefixed with its length, so that a concatenation of encoded values
+/// can only be read back one way.
+fn push_encoded(out: &mut Vec<u8>, value: Option<&[u8]>) {
+    match value {
+        Some(value) => {
+            out.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            out.extend_from_slice(value);
+        }
+        None => out.extend_from_slice(&u32::MAX.to_le_bytes()),
+    }
+}
+
+/// Encodes each row of a physical series into bytes that are equal exactly when the rows are,
+/// so that lists and structs can be grouped like binary values instead of by their hashes.
+fn encode_rows(series: &Series) -> DaftResult<Vec<Option<Vec<u8>>>> {
+    let rows = match series.data_type() {
+        DataType::Struct(..) => {
+            let array = series.struct_()?;
+            let children = array
+                .children
+                .iter()
+                .map(encode_rows)
+                .collect::<DaftResult<Vec<_>>>()?;
+            (0..series.len())
+                .map(|i| {
+                    let mut row = Vec::new();
+                    for child in &children {
+                        push_encoded(&mut row, child[i].as_deref());
+                    }
+                    row
+                })
+                .collect::<Vec<_>>()
+        }
+        DataType::List(..) => {
+            let array = series.list()?;
+            let elements = encode_rows(&array.flat_child)?;
+            (0..series.len())
+                .map(|i| {
+                    let (start, end) = array.offsets().start_end(i);
+                    let mut row = Vec::new();
+                    for element in &elements[start..end] {
+                        push_encoded(&mut row, element.as_deref());
+                    }
+                    row
+                })
+                .collect::<Vec<_>>()
+        }
+        DataType::FixedSizeList(..) => {
+            let array = series.fixed_size_list()?;
+            let size = array.fixed_element_len();
+            let elements = encode_rows(&array.flat_child)?;
+            (0..series.len())
+                .map(|i| {
+                    let mut row = Vec::new();
+                    for element in &elements[i * size..(i + 1) * size] {
+                        push_encoded(&mut row, element.as_deref());
+                    }
+                    row
+                })
+                .collect::<Vec<_>>()
+        }
+        _ => {
+            let array = series.to_arrow();
+            let mut converter = RowConverter::new(vec![SortField::new(array.data_type().clone())]);
+            let rows = converter.convert_columns(&[array])?;
+            rows.iter().map(|row| row.as_ref().to_vec()).collect()
+        }
+    };
+    Ok(rows
+        .into_iter()
+        .enumerate()
+        .map(|(i, row)| series.is_valid(i).then_some(row))
+        .collect())
+}
+
+impl Series {
+    /// Counts the occurrences of each distinct non-null value of this series.
+    ///
+    /// Returns a struct series with the distinct values in a field named after this series and
+    /// their counts in a `count` field, sorted by descending count and then by first occurrence.
+    /// Lists and structs are grouped by an exact byte encoding of their rows.
+    pub fn value_counts(&self) -> DaftResult<Self> {
+        let physical = self.as_physical()?;
+        let (sample_indices, groups) = match physical.data_type() {
+            DataType::List(..) | DataType::FixedSizeList(..) | DataType::Struct(..) => {
+                let rows = encode_rows(&physical)?;
+                BinaryArray::from_iter(self.name(), rows.into_iter()).make_groups()?
+            }
+            _ => self.make_groups()?,
+        };
+
+        let mut counts = sample_indices
+            .into_iter()
+            .zip(groups.iter().map(|g| g.len() as u64))
+            .filter(|(sample, _)| self.is_valid(*sample as usize))
+            .collect::<Vec<_>>();
+        counts.sort_unstable_by(|(l_sample, l_count), (r_sample, r_count)| {
+            r_count.cmp(l_count).then(l_sample.cmp(r_sample))
+        });
+        let (samples, counts): (Vec<_>, Vec<_>) = counts.into_iter().unzip();
+
+        let values = self.take(&UInt64Array::from(("samples", samples)).into_series())?;
+        let counts = UInt64Array::from(("count", counts)).into_series();
+        let field = Field::new(
+            self.name(),
+            DataType::Struct(vec![values.field().clone(), counts.field().clone()]),
+        );
+        Ok(StructArray::new(field, vec![values, counts], None).into_series())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::{ops::as_arrow::AsArrow, ListArray, StructArray},
+        datatypes::{DataType, Field, Int64Array, Utf8Array},
+        series::{IntoSeries, Series},
+    };
+
+    fn counts(value_counts: &Series) -> DaftResult<Vec<u64>> {
+        let counts = &value_counts.struct_()?.children[1];
+        Ok(counts.u64()?.into_iter().map(|c| *c.unwrap()).collect())
+    }
+
+    #[test]
+    fn value_counts_sorted_by_count() -> DaftResult<()> {
+        let s = Utf8Array::from_iter(
+            "s",
+            vec![
+                Some("b"),
+                None,
+                Some("a"),
+                Some("b"),
+                None,
+                Some("c"),
+                Some("a"),
+                Some("b"),
+            ]
+            .into_iter(),
+        )
+        .into_series();
+        let result = s.value_counts()?;
+        assert_eq!(
+            result.data_type(),
+            &DataType::Struct(vec![
+                Field::new("s", DataType::Utf8),
+                Field::new("count", DataType::UInt64)
+            ])
+        );
+        let values = result.struct_()?.children[0].utf8()?.clone();
+        assert_eq!(
+            values.into_iter().collect::<Vec<_>>(),
+            vec![Some("b"), Some("a"), Some("c")]
+        );
+        assert_eq!(counts(&result)?, vec![3, 2, 1]);
+        Ok(())
+    }
+
+    #[test]
+    fn value_counts_nested_values() -> DaftResult<()> {
+        // [1, 2], [2], null, [1, 2], [], [null], []
+        let flat_child = Int64Array::from_iter(
+            Field::new("item", DataType::Int64),
+            vec![Some(1), Some(2), Some(2), Some(1), Some(2), None].into_iter(),
+        );
+        let list = ListArray::new(
+            Field::new("l", DataType::List(Box::new(DataType::Int64))),
+            flat_child.into_series(),
+            arrow2::offset::OffsetsBuffer::try_from(vec![0, 2, 3, 3, 5, 5, 6, 6])?,
+            Some(arrow2::bitmap::Bitmap::from(vec![
+                true, true, false, true, true, true, true,
+            ])),
+        )
+        .into_series();
+        let result = list.value_counts()?;
+        assert_eq!(counts(&result)?, vec![2, 2, 1, 1]);
+        let values = result.struct_()?.children[0].list()?.clone();
+        assert_eq!(
+            values.get(0).unwrap().i64()?.as_arrow().values().as_slice(),
+            &[1, 2]
+        );
+        assert_eq!(values.get(1).unwrap().len(), 0);
+
+        let structs = StructArray::new(
+            Field::new(
+                "st",
+                DataType::Struct(vec![
+                    Field::new("a", DataType::Int64),
+                    Field::new("b", DataType::Utf8),
+                ]),
+            ),
+            vec![
+                Int64Array::from(("a", vec![1, 1, 1])).into_series(),
+                Utf8Array::from(("b", &["x", "y", "x"][..])).into_series(),
+            ],
+            None,
+        )
+        .into_series();
+        assert_eq!(counts(&structs.value_counts()?)?, vec![2, 1]);
+        Ok(())
+    }
+}
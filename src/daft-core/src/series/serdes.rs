@@ -10,7 +10,7 @@ use crate::{
     },
     datatypes::{
         logical::{
-            DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray,
+            CategoricalArray, DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray,
             FixedShapeSparseTensorArray, FixedShapeTensorArray, ImageArray, MapArray,
             SparseTensorArray, TensorArray, TimeArray, TimestampArray,
         },
@@ -256,6 +256,15 @@ impl<'d> serde::Deserialize<'d> for Series {
                             .into_series(),
                         )
                     }
+                    DataType::Categorical(..) => {
+                        type PType = <<CategoricalType as DaftLogicalType>::PhysicalType as DaftDataType>::ArrayType;
+                        let physical = map.next_value::<Series>()?;
+                        Ok(CategoricalArray::new(
+                            field,
+                            physical.downcast::<PType>().unwrap().clone(),
+                        )
+                        .into_series())
+                    }
                     DataType::Interval => Ok(IntervalArray::from_iter(
                         field.name.as_str(),
                         map.next_value::<Vec<Option<months_days_ns>>>()?.into_iter(),
@@ -119,7 +119,7 @@ pub fn cast_array_for_daft_if_needed(
     }
 }
 
-fn coerce_from_daft_compatible_type(
+pub fn coerce_from_daft_compatible_type(
     dtype: &arrow2::datatypes::DataType,
 ) -> Option<arrow2::datatypes::DataType> {
     match dtype {
@@ -1,6 +1,6 @@
 use common_error::{DaftError, DaftResult};
 
-use crate::datatypes::{DataType, TimeUnit};
+use crate::datatypes::{integer_to_decimal128, DataType, TimeUnit};
 
 // TODO: Deprecate this logic soon!
 
@@ -180,6 +180,18 @@ pub fn get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
                 Some(DataType::Timestamp(tu, tz_r.clone()))
             }
 
+            // decimals keep enough integer digits and fractional digits to hold either side
+            (DataType::Decimal128(p_l, s_l), DataType::Decimal128(p_r, s_r)) => {
+                let scale = *s_l.max(s_r);
+                let precision = (p_l - s_l).max(p_r - s_r) + scale;
+                (precision <= 38).then_some(DataType::Decimal128(precision, scale))
+            }
+            (DataType::Decimal128(..), dt) if dt.is_integer() => {
+                let int_decimal = integer_to_decimal128(dt).ok()?;
+                inner(l, &int_decimal)
+            }
+            (DataType::Decimal128(..), DataType::Float32 | DataType::Float64) => Some(DataType::Float64),
+
             //TODO(sammy): add time, struct related dtypes
             (DataType::Boolean, DataType::Float32) => Some(DataType::Float32),
             (DataType::Boolean, DataType::Float64) => Some(DataType::Float64),
@@ -203,6 +215,14 @@ pub fn get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
             //     Some(DataType::List(Box::new(Field::new(inner_left_field.name.clone(), inner_st))))
             // }
 
+            // categoricals with different categories are unified into the categories of both, in order of appearance,
+            // so that they stay encoded
+            (DataType::Categorical(l), DataType::Categorical(r)) => {
+                let mut categories = l.clone();
+                categories.extend(r.iter().filter(|c| !l.contains(c)).cloned());
+                Some(DataType::Categorical(categories))
+            }
+
             // every known type can be casted to a string except binary
             (dt, DataType::Utf8) if !matches!(&dt, &DataType::Binary | &DataType::FixedSizeBinary(_) | &DataType::List(_)) => Some(DataType::Utf8),
             (dt, DataType::Null) => Some(dt.clone()), // Drop DataType::Null Type
@@ -224,4 +244,25 @@ mod tests {
         assert_eq!(result, None);
         Ok(())
     }
+
+    #[test]
+    fn check_decimal_supertypes() -> DaftResult<()> {
+        assert_eq!(
+            try_get_supertype(&DataType::Decimal128(10, 2), &DataType::Decimal128(5, 4))?,
+            DataType::Decimal128(12, 4)
+        );
+        assert_eq!(
+            try_get_supertype(&DataType::Int32, &DataType::Decimal128(10, 2))?,
+            DataType::Decimal128(12, 2)
+        );
+        assert_eq!(
+            try_get_supertype(&DataType::Decimal128(10, 2), &DataType::Float32)?,
+            DataType::Float64
+        );
+        assert_eq!(
+            get_supertype(&DataType::Decimal128(38, 0), &DataType::Decimal128(38, 10)),
+            None
+        );
+        Ok(())
+    }
 }
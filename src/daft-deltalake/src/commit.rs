@@ -0,0 +1,205 @@
+//! Commits the add actions of a write to the log of a table.
+//!
+//! Commits are the log files of consecutive versions of the table, which are written only if they don't exist yet,
+//! so that of concurrent commits of the same version exactly one wins. A write that loses retries at the next
+//! version, unless the winning commit changed the table in a way that conflicts with it.
+
+use std::sync::Arc;
+
+use common_error::{DaftError, DaftResult};
+use common_file_formats::WriteMode;
+use daft_io::IOClient;
+use serde::Serialize;
+use serde_json::{json, Value as Json};
+
+use crate::{
+    spec::{Action, Add, Remove},
+    writer::{create_local_dir, now_ms},
+    DeltaLakeWrite,
+};
+
+/// The number of versions that a commit is attempted at before giving up on it.
+const MAX_COMMIT_ATTEMPTS: usize = 15;
+
+/// A file that a commit adds to or removes from a table.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileOperation {
+    /// `ADD` or `DELETE`.
+    pub operation: &'static str,
+    /// The path of the file relative to the table, as it is in the log.
+    pub path: String,
+    pub rows: Option<i64>,
+    pub size: Option<i64>,
+}
+
+/// A commit of a write, at the version of the table that it created.
+#[derive(Debug, Clone, Serialize)]
+pub struct Commit {
+    pub version: i64,
+    pub operations: Vec<FileOperation>,
+}
+
+fn log_path(table_uri: &str, version: i64) -> String {
+    format!(
+        "{}/_delta_log/{version:020}.json",
+        table_uri.trim_end_matches('/')
+    )
+}
+
+/// Checks whether the commit of `winning` actions at the version that a commit tried to create conflicts with it,
+/// returning whether it was that commit itself, written by an earlier attempt whose response was lost.
+fn check_conflict(write: &DeltaLakeWrite, txn_id: &str, winning: &[Json]) -> DaftResult<bool> {
+    let has_action = |name: &str| winning.iter().any(|action| action.get(name).is_some());
+    if winning.iter().any(|action| {
+        action
+            .get("commitInfo")
+            .and_then(|info| info.get("txnId"))
+            .is_some_and(|id| id == txn_id)
+    }) {
+        return Ok(true);
+    }
+    let conflict = |reason: &str| {
+        Err(DaftError::ValueError(format!(
+            "Failed to commit to the Delta Lake table at {}: {reason}",
+            write.table_uri
+        )))
+    };
+    if write.read_version.is_none() {
+        return conflict("the table was created by a concurrent write");
+    }
+    if has_action("metaData") || has_action("protocol") {
+        return conflict(
+            "the metadata or protocol of the table were changed by a concurrent write",
+        );
+    }
+    if write.mode == WriteMode::Overwrite && (has_action("add") || has_action("remove")) {
+        return conflict(
+            "the files of the table were changed by a concurrent write while overwriting it",
+        );
+    }
+    Ok(false)
+}
+
+/// Commits `adds` to the table of `write`, along with the removes of the files that it overwrites and the metadata
+/// and protocol of the table if it creates the table or overwrites its schema.
+pub(crate) async fn commit(
+    write: &DeltaLakeWrite,
+    adds: Vec<Add>,
+    io_client: Arc<IOClient>,
+) -> DaftResult<Commit> {
+    let txn_id = uuid::Uuid::new_v4().to_string();
+    let timestamp = now_ms();
+    let is_overwrite = write.mode == WriteMode::Overwrite;
+
+    let mut commit_info = json!({
+        "timestamp": timestamp,
+        "operation": "WRITE",
+        "operationParameters": {
+            "mode": if is_overwrite { "Overwrite" } else { "Append" },
+            "partitionBy": serde_json::to_string(&write.metadata.partition_columns)
+                .map_err(|err| DaftError::InternalError(err.to_string()))?,
+        },
+        "engineInfo": format!("Daft/{}", env!("CARGO_PKG_VERSION")),
+        "txnId": txn_id,
+        "isBlindAppend": !is_overwrite && write.read_version.is_some(),
+    });
+    if let Some(read_version) = write.read_version {
+        commit_info["readVersion"] = json!(read_version);
+    }
+    for (key, value) in &write.custom_metadata {
+        commit_info[key] = json!(value);
+    }
+
+    let mut actions = vec![Action::CommitInfo(commit_info)];
+    if write.update_metadata {
+        if write.read_version.is_none() {
+            actions.push(Action::Protocol(write.protocol.clone()));
+        }
+        actions.push(Action::MetaData(write.metadata.clone()));
+    }
+    let mut operations = vec![];
+    if is_overwrite {
+        for file in &write.files {
+            actions.push(Action::Remove(Remove {
+                path: file.path.clone(),
+                deletion_timestamp: timestamp,
+                data_change: true,
+                size: file.size,
+            }));
+        }
+    }
+    for add in adds {
+        operations.push(FileOperation {
+            operation: "ADD",
+            path: add.path.clone(),
+            rows: add
+                .stats
+                .as_deref()
+                .and_then(|stats| serde_json::from_str::<Json>(stats).ok())
+                .and_then(|stats| stats["numRecords"].as_i64()),
+            size: Some(add.size),
+        });
+        actions.push(Action::Add(add));
+    }
+    if is_overwrite {
+        operations.extend(write.files.iter().map(|file| FileOperation {
+            operation: "DELETE",
+            path: file.path.clone(),
+            rows: file.num_records,
+            size: file.size,
+        }));
+    }
+
+    let mut data = vec![];
+    for action in &actions {
+        serde_json::to_writer(&mut data, action)
+            .map_err(|err| DaftError::InternalError(err.to_string()))?;
+        data.push(b'\n');
+    }
+    let data = bytes::Bytes::from(data);
+
+    let mut version = write.read_version.map_or(0, |version| version + 1);
+    create_local_dir(&log_path(&write.table_uri, version))?;
+    for _ in 0..MAX_COMMIT_ATTEMPTS {
+        let path = log_path(&write.table_uri, version);
+        match io_client
+            .single_url_put_if_absent(&path, data.clone(), None)
+            .await?
+        {
+            Some(true) => return Ok(Commit { version, operations }),
+            Some(false) => {
+                let winning = io_client
+                    .single_url_get(path, None, None)
+                    .await?
+                    .bytes()
+                    .await?;
+                let winning = winning
+                    .split(|byte| *byte == b'\n')
+                    .filter(|line| !line.iter().all(u8::is_ascii_whitespace))
+                    .map(|line| {
+                        serde_json::from_slice::<Json>(line).map_err(|err| {
+                            DaftError::ValueError(format!(
+                                "Invalid commit {version} of the Delta Lake table at {}: {err}",
+                                write.table_uri
+                            ))
+                        })
+                    })
+                    .collect::<DaftResult<Vec<_>>>()?;
+                if check_conflict(write, &txn_id, &winning)? {
+                    return Ok(Commit { version, operations });
+                }
+                version += 1;
+            }
+            None => {
+                return Err(DaftError::NotImplemented(format!(
+                    "Committing to the Delta Lake table at {} requires conditional uploads, which its storage doesn't support",
+                    write.table_uri
+                )))
+            }
+        }
+    }
+    Err(DaftError::ValueError(format!(
+        "Failed to commit to the Delta Lake table at {} after {MAX_COMMIT_ATTEMPTS} attempts, as concurrent writes kept committing first",
+        write.table_uri
+    )))
+}
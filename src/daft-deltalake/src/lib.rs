@@ -0,0 +1,401 @@
+#![feature(let_chains)]
+//! Native writes to Delta Lake tables.
+//!
+//! A write is planned from a snapshot of the table it writes to, as a [`DeltaLakeWrite`]: its data is written to
+//! Parquet data files by [`AddFileWriter`]s, whose add actions are then committed to the log of the table by
+//! [`DeltaLakeWrite::commit`]. Commits rely on conditional uploads of log files instead of a locking provider, so
+//! concurrent writers are detected by the storage of the table itself.
+
+mod commit;
+#[cfg(feature = "python")]
+pub mod python;
+mod spec;
+mod writer;
+
+use std::{collections::BTreeMap, sync::Arc};
+
+pub use commit::{Commit, FileOperation};
+use common_error::{DaftError, DaftResult};
+use common_file_formats::WriteMode;
+use daft_io::{IOClient, IOStatsRef};
+use daft_recordbatch::RecordBatch;
+use serde::{Deserialize, Serialize};
+pub use spec::Add;
+use spec::{Format, Metadata, Protocol, StructType};
+pub use writer::AddFileWriter;
+
+/// The name of the column of JSON-serialized [`Add`] actions that the data files of a write are returned in.
+pub const ADD_ACTION_COLUMN_NAME: &str = "add_action";
+
+/// A data file of the snapshot of a table that a write started from, which an overwrite removes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotFile {
+    /// The path of the file, as it is in the log.
+    pub path: String,
+    #[serde(default)]
+    pub size: Option<i64>,
+    #[serde(default)]
+    pub num_records: Option<i64>,
+}
+
+/// The snapshot of a table that a write starts from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Snapshot {
+    pub version: i64,
+    pub protocol: Protocol,
+    pub meta_data: Metadata,
+    #[serde(default)]
+    pub files: Vec<SnapshotFile>,
+}
+
+/// A write to a Delta Lake table, planned from the snapshot of the table when the write started.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeltaLakeWrite {
+    table_uri: String,
+    mode: WriteMode,
+    /// The version of the snapshot that the write started from, or None if it creates the table.
+    read_version: Option<i64>,
+    protocol: Protocol,
+    /// The metadata of the table once the write is committed.
+    metadata: Metadata,
+    /// Whether the metadata is committed with the write, as it is when the write creates the table or overwrites
+    /// its schema.
+    update_metadata: bool,
+    /// The files that an overwrite removes.
+    files: Vec<SnapshotFile>,
+    custom_metadata: BTreeMap<String, String>,
+}
+
+impl DeltaLakeWrite {
+    /// Plans a write to the table at `table_uri` from `snapshot_json`, the JSON of a [`Snapshot`], or None if the
+    /// table doesn't exist yet, in which case it is created with `schema_json`, the Delta schema of the data.
+    ///
+    /// The schema of an existing table is only replaced with `schema_json` if `overwrite_schema` is set, which
+    /// only overwrites can do.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        table_uri: &str,
+        snapshot_json: Option<&str>,
+        schema_json: &str,
+        mode: WriteMode,
+        partition_columns: Vec<String>,
+        overwrite_schema: bool,
+        name: Option<String>,
+        description: Option<String>,
+        configuration: BTreeMap<String, Option<String>>,
+        custom_metadata: BTreeMap<String, String>,
+    ) -> DaftResult<Self> {
+        if mode == WriteMode::OverwritePartitions {
+            return Err(DaftError::NotImplemented(
+                "Overwriting the partitions of Delta Lake tables is not supported".to_string(),
+            ));
+        }
+        if overwrite_schema && mode != WriteMode::Overwrite {
+            return Err(DaftError::ValueError(
+                "The schema of a Delta Lake table can only be overwritten along with its data"
+                    .to_string(),
+            ));
+        }
+        let schema: StructType = serde_json::from_str(schema_json)
+            .map_err(|err| DaftError::ValueError(format!("Invalid Delta Lake schema: {err}")))?;
+
+        let snapshot = snapshot_json
+            .map(|json| {
+                serde_json::from_str::<Snapshot>(json).map_err(|err| {
+                    DaftError::ValueError(format!("Invalid Delta Lake snapshot: {err}"))
+                })
+            })
+            .transpose()?;
+        let (read_version, protocol, metadata, update_metadata, files) = match snapshot {
+            Some(snapshot) => {
+                let mut metadata = snapshot.meta_data;
+                if overwrite_schema {
+                    metadata.schema_string = schema_json.to_string();
+                }
+                let table_schema = metadata.schema()?;
+                spec::check_writable(
+                    &snapshot.protocol,
+                    &metadata,
+                    &table_schema,
+                    mode == WriteMode::Overwrite,
+                )?;
+                if overwrite_schema
+                    && Protocol::for_schema(&table_schema).min_writer_version
+                        > snapshot.protocol.min_writer_version
+                {
+                    return Err(DaftError::NotImplemented(
+                        "Overwriting the schema of a Delta Lake table with one that needs a newer protocol is not supported"
+                            .to_string(),
+                    ));
+                }
+                (
+                    Some(snapshot.version),
+                    snapshot.protocol,
+                    metadata,
+                    overwrite_schema,
+                    snapshot.files,
+                )
+            }
+            None => {
+                let metadata = Metadata {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    name,
+                    description,
+                    format: Format {
+                        provider: "parquet".to_string(),
+                        options: BTreeMap::new(),
+                    },
+                    schema_string: schema_json.to_string(),
+                    partition_columns,
+                    configuration,
+                    created_time: Some(writer::now_ms()),
+                };
+                let protocol = Protocol::for_schema(&schema);
+                spec::check_writable(&protocol, &metadata, &schema, false)?;
+                (None, protocol, metadata, true, vec![])
+            }
+        };
+
+        let table_schema = metadata.schema()?;
+        for column in &metadata.partition_columns {
+            match table_schema.field(column) {
+                Some(field) if matches!(field.data_type, spec::DataType::Primitive(_)) => {}
+                Some(_) => {
+                    return Err(DaftError::ValueError(format!(
+                        "Delta Lake tables can only be partitioned by columns of primitive types, but {column} isn't one"
+                    )))
+                }
+                None => {
+                    return Err(DaftError::ValueError(format!(
+                        "Partition column {column} is not a column of the Delta Lake table"
+                    )))
+                }
+            }
+        }
+        // Fail before any data is written if the schema can't be written
+        table_schema.to_daft_schema()?;
+        metadata.num_indexed_cols()?;
+
+        Ok(Self {
+            table_uri: table_uri.trim_end_matches('/').to_string(),
+            mode,
+            read_version,
+            protocol,
+            metadata,
+            update_metadata,
+            files,
+            custom_metadata,
+        })
+    }
+
+    /// Creates a writer of the data file `file_idx` of the write, in the partition of `partition_values` if the
+    /// table is partitioned.
+    pub fn add_file_writer(
+        &self,
+        file_idx: usize,
+        partition_values: Option<&RecordBatch>,
+        io_client: Arc<IOClient>,
+        part_size: usize,
+        max_inflight_parts: usize,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<AddFileWriter> {
+        let schema = self.metadata.schema()?;
+        let nullable = schema
+            .fields
+            .iter()
+            .map(|field| field.nullable)
+            .collect::<Vec<_>>();
+        AddFileWriter::try_new(
+            &self.table_uri,
+            &schema.to_daft_schema()?,
+            &nullable,
+            &self.metadata.partition_columns,
+            partition_values,
+            self.metadata.num_indexed_cols()?,
+            file_idx,
+            io_client,
+            part_size,
+            max_inflight_parts,
+            io_stats,
+        )
+    }
+
+    /// Commits `adds` to the log of the table as its next version, retrying at later versions if concurrent writes
+    /// that don't conflict with it commit first.
+    pub async fn commit(&self, adds: Vec<Add>, io_client: Arc<IOClient>) -> DaftResult<Commit> {
+        commit::commit(self, adds, io_client).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, sync::Arc};
+
+    use common_file_formats::WriteMode;
+    use common_runtime::get_io_runtime;
+    use daft_core::prelude::*;
+    use daft_io::{IOClient, IOConfig};
+    use daft_recordbatch::RecordBatch;
+
+    use crate::DeltaLakeWrite;
+
+    const SCHEMA_JSON: &str = r#"{"type": "struct", "fields": [
+        {"name": "id", "type": "long", "nullable": false, "metadata": {}},
+        {"name": "name", "type": "string", "nullable": true, "metadata": {}},
+        {"name": "category", "type": "string", "nullable": true, "metadata": {}}
+    ]}"#;
+
+    fn read_log(dir: &std::path::Path, version: i64) -> Vec<serde_json::Value> {
+        std::fs::read_to_string(dir.join(format!("_delta_log/{version:020}.json")))
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    fn write(
+        write: &DeltaLakeWrite,
+        batch: &RecordBatch,
+        io_client: Arc<IOClient>,
+    ) -> common_error::DaftResult<Vec<crate::Add>> {
+        let partition_values = batch.head(1)?.get_columns(&["category"])?;
+        let mut writer = write.add_file_writer(
+            0,
+            Some(&partition_values),
+            io_client,
+            5 * 1024 * 1024,
+            4,
+            None,
+        )?;
+        writer.write(batch)?;
+        Ok(writer.close()?.into_iter().collect())
+    }
+
+    #[test]
+    fn test_write_and_commit_local_table() -> common_error::DaftResult<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let table_uri = dir.path().to_str().unwrap().to_string();
+        let batch = RecordBatch::from_nonempty_columns(vec![
+            Int64Array::from(("id", vec![3, 1, 2])).into_series(),
+            Utf8Array::from(("name", ["c", "a", "b"].as_slice())).into_series(),
+            Utf8Array::from(("category", ["x/y", "x/y", "x/y"].as_slice())).into_series(),
+        ])?;
+        let create = DeltaLakeWrite::try_new(
+            &table_uri,
+            None,
+            SCHEMA_JSON,
+            WriteMode::Append,
+            vec!["category".to_string()],
+            false,
+            Some("table".to_string()),
+            None,
+            BTreeMap::new(),
+            BTreeMap::new(),
+        )?;
+
+        // Writes are serialized to be sent to the writers of their data files
+        let json = serde_json::to_string(&create).unwrap();
+        let create: DeltaLakeWrite = serde_json::from_str(&json).unwrap();
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let adds = write(&create, &batch, io_client.clone())?;
+        assert_eq!(adds.len(), 1);
+        let add = &adds[0];
+        assert!(add.path.starts_with("category=x%252Fy/part-00000-"));
+        assert_eq!(add.partition_values["category"].as_deref(), Some("x/y"));
+        assert!(dir.path().join(add.path.replace("%25", "%")).exists());
+        let stats: serde_json::Value = serde_json::from_str(add.stats.as_ref().unwrap()).unwrap();
+        assert_eq!(stats["numRecords"], 3);
+        assert_eq!(stats["minValues"]["id"], 1);
+        assert_eq!(stats["maxValues"]["name"], "c");
+        assert_eq!(stats["nullCount"]["name"], 0);
+        assert!(stats["nullCount"].get("category").is_none());
+
+        // A concurrent write of the table commits first, which this one can't commit on top of
+        let other = DeltaLakeWrite::try_new(
+            &table_uri,
+            None,
+            SCHEMA_JSON,
+            WriteMode::Append,
+            vec!["category".to_string()],
+            false,
+            None,
+            None,
+            BTreeMap::new(),
+            BTreeMap::new(),
+        )?;
+        let commit = get_io_runtime(true).block_on_current_thread({
+            let io_client = io_client.clone();
+            let adds = adds.clone();
+            async move { create.commit(adds, io_client).await }
+        })?;
+        assert_eq!(commit.version, 0);
+        let actions = read_log(dir.path(), 0);
+        assert!(actions[0].get("commitInfo").is_some());
+        assert_eq!(actions[1]["protocol"]["minWriterVersion"], 2);
+        assert_eq!(actions[2]["metaData"]["partitionColumns"][0], "category");
+        assert_eq!(actions[3]["add"]["path"], add.path.as_str());
+        assert!(get_io_runtime(true)
+            .block_on_current_thread({
+                let io_client = io_client.clone();
+                let adds = adds.clone();
+                async move { other.commit(adds, io_client).await }
+            })
+            .is_err());
+
+        // Appends retry on top of concurrent appends, while overwrites can't
+        let snapshot_json = serde_json::json!({
+            "version": 0,
+            "protocol": actions[1]["protocol"],
+            "metaData": actions[2]["metaData"],
+            "files": [{"path": add.path, "size": add.size, "numRecords": 3}],
+        })
+        .to_string();
+        let plan = |mode| {
+            DeltaLakeWrite::try_new(
+                &table_uri,
+                Some(&snapshot_json),
+                SCHEMA_JSON,
+                mode,
+                vec![],
+                false,
+                None,
+                None,
+                BTreeMap::new(),
+                BTreeMap::new(),
+            )
+        };
+        let append = plan(WriteMode::Append)?;
+        let overwrite = plan(WriteMode::Overwrite)?;
+        let commit = |write: DeltaLakeWrite| {
+            let io_client = io_client.clone();
+            let adds = adds.clone();
+            get_io_runtime(true)
+                .block_on_current_thread(async move { write.commit(adds, io_client).await })
+        };
+        assert_eq!(commit(append.clone())?.version, 1);
+        assert_eq!(commit(append)?.version, 2);
+        assert!(commit(overwrite.clone()).is_err());
+
+        let overwrite_at_head = DeltaLakeWrite {
+            read_version: Some(2),
+            ..overwrite
+        };
+        let result = commit(overwrite_at_head)?;
+        assert_eq!(result.version, 3);
+        assert_eq!(
+            result
+                .operations
+                .iter()
+                .map(|op| op.operation)
+                .collect::<Vec<_>>(),
+            ["ADD", "DELETE"]
+        );
+        let actions = read_log(dir.path(), 3);
+        assert_eq!(actions[1]["remove"]["path"], add.path.as_str());
+        assert_eq!(actions[2]["add"]["path"], add.path.as_str());
+        Ok(())
+    }
+}
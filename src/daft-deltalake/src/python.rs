@@ -0,0 +1,114 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use common_error::DaftError;
+use common_file_formats::WriteMode;
+use common_runtime::get_io_runtime;
+use daft_io::{get_io_client, python::IOConfig};
+use pyo3::{prelude::*, PyTypeInfo};
+
+use crate::{Add, DeltaLakeWrite};
+
+/// A native write to a Delta Lake table, which is planned with a snapshot of the table and committed to its log
+/// once its data files are written.
+#[pyclass(module = "daft.daft", name = "DeltaLakeWrite", frozen)]
+#[derive(Clone)]
+pub struct PyDeltaLakeWrite {
+    pub write: Arc<DeltaLakeWrite>,
+}
+
+#[pymethods]
+impl PyDeltaLakeWrite {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (
+        table_uri,
+        schema_json,
+        mode,
+        partition_columns,
+        snapshot_json=None,
+        overwrite_schema=false,
+        name=None,
+        description=None,
+        configuration=None,
+        custom_metadata=None
+    ))]
+    fn new(
+        table_uri: &str,
+        schema_json: &str,
+        mode: &str,
+        partition_columns: Vec<String>,
+        snapshot_json: Option<&str>,
+        overwrite_schema: bool,
+        name: Option<String>,
+        description: Option<String>,
+        configuration: Option<BTreeMap<String, Option<String>>>,
+        custom_metadata: Option<BTreeMap<String, String>>,
+    ) -> PyResult<Self> {
+        Ok(Self {
+            write: Arc::new(DeltaLakeWrite::try_new(
+                table_uri,
+                snapshot_json,
+                schema_json,
+                mode.parse::<WriteMode>()?,
+                partition_columns,
+                overwrite_schema,
+                name,
+                description,
+                configuration.unwrap_or_default(),
+                custom_metadata.unwrap_or_default(),
+            )?),
+        })
+    }
+
+    /// Commits `add_actions`, the JSON-serialized add actions returned by the write, to the log of the table, and
+    /// returns the version it committed along with the files it adds and removes, as JSON.
+    #[pyo3(signature = (add_actions, io_config=None))]
+    fn commit(
+        &self,
+        py: Python,
+        add_actions: Vec<String>,
+        io_config: Option<IOConfig>,
+    ) -> PyResult<String> {
+        let adds = add_actions
+            .iter()
+            .map(|add| {
+                serde_json::from_str::<Add>(add).map_err(|err| {
+                    DaftError::ValueError(format!("Invalid Delta Lake add action: {err}"))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let write = self.write.clone();
+        py.allow_threads(|| {
+            let io_client = get_io_client(true, Arc::new(io_config.unwrap_or_default().config))?;
+            let commit = get_io_runtime(true)
+                .block_on_current_thread(async move { write.commit(adds, io_client).await })?;
+            Ok(serde_json::to_string(&commit)
+                .map_err(|err| DaftError::InternalError(err.to_string()))?)
+        })
+    }
+
+    pub fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (String,))> {
+        let json = serde_json::to_string(self.write.as_ref())
+            .map_err(|err| DaftError::InternalError(err.to_string()))?;
+        Ok((
+            Self::type_object(py)
+                .getattr(pyo3::intern!(py, "_from_json"))?
+                .into(),
+            (json,),
+        ))
+    }
+
+    #[staticmethod]
+    fn _from_json(json: &str) -> PyResult<Self> {
+        let write =
+            serde_json::from_str(json).map_err(|err| DaftError::InternalError(err.to_string()))?;
+        Ok(Self {
+            write: Arc::new(write),
+        })
+    }
+}
+
+pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
+    parent.add_class::<PyDeltaLakeWrite>()?;
+    Ok(())
+}
@@ -0,0 +1,452 @@
+//! The parts of the [Delta transaction log protocol](https://github.com/delta-io/delta/blob/master/PROTOCOL.md) that
+//! writes need: the actions of a commit and the schemas of tables, as they are serialized in the log.
+
+use std::collections::BTreeMap;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::{
+    DataType as DaftDataType, Field as DaftField, Schema as DaftSchema, TimeUnit,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as Json;
+
+/// The highest reader version of the protocol that tables can be written with.
+const MAX_READER_VERSION: i32 = 3;
+/// The highest writer version of the protocol that tables can be written with.
+const MAX_WRITER_VERSION: i32 = 7;
+
+/// The table features of writer version 7 that writes support, as long as the ones checked by
+/// [`check_writable`] aren't in use.
+const SUPPORTED_WRITER_FEATURES: &[&str] = &[
+    "appendOnly",
+    "invariants",
+    "checkConstraints",
+    "changeDataFeed",
+    "generatedColumns",
+    "columnMapping",
+    "identityColumns",
+    "timestampNtz",
+    "domainMetadata",
+    "v2Checkpoint",
+    "vacuumProtocolCheck",
+    "deletionVectors",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Protocol {
+    pub min_reader_version: i32,
+    pub min_writer_version: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reader_features: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub writer_features: Option<Vec<String>>,
+}
+
+impl Protocol {
+    /// The protocol of a new table of `schema`, which is the oldest one that supports the types of its columns.
+    pub fn for_schema(schema: &StructType) -> Self {
+        if schema
+            .fields
+            .iter()
+            .any(|field| field.data_type.has_timestamp_ntz())
+        {
+            Self {
+                min_reader_version: 3,
+                min_writer_version: 7,
+                reader_features: Some(vec!["timestampNtz".to_string()]),
+                writer_features: Some(vec!["timestampNtz".to_string()]),
+            }
+        } else {
+            Self {
+                min_reader_version: 1,
+                min_writer_version: 2,
+                reader_features: None,
+                writer_features: None,
+            }
+        }
+    }
+
+    fn has_writer_feature(&self, feature: &str) -> bool {
+        self.writer_features
+            .as_ref()
+            .is_some_and(|features| features.iter().any(|f| f == feature))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Format {
+    pub provider: String,
+    #[serde(default)]
+    pub options: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Metadata {
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub format: Format,
+    pub schema_string: String,
+    pub partition_columns: Vec<String>,
+    #[serde(default)]
+    pub configuration: BTreeMap<String, Option<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_time: Option<i64>,
+}
+
+impl Metadata {
+    pub fn schema(&self) -> DaftResult<StructType> {
+        serde_json::from_str(&self.schema_string)
+            .map_err(|err| DaftError::ValueError(format!("Invalid Delta Lake schema: {err}")))
+    }
+
+    pub fn config(&self, key: &str) -> Option<&str> {
+        self.configuration
+            .get(key)
+            .and_then(|value| value.as_deref())
+    }
+
+    /// Whether the table only allows files to be added to it, with `delta.appendOnly`.
+    pub fn is_append_only(&self) -> bool {
+        self.config("delta.appendOnly")
+            .is_some_and(|value| value.eq_ignore_ascii_case("true"))
+    }
+
+    /// The number of leading columns that statistics are collected for, or None if they are for all of them.
+    pub fn num_indexed_cols(&self) -> DaftResult<Option<usize>> {
+        match self.config("delta.dataSkippingNumIndexedCols") {
+            None => Ok(Some(32)),
+            Some(value) => {
+                let num = value.parse::<i64>().map_err(|_| {
+                    DaftError::ValueError(format!(
+                        "Invalid delta.dataSkippingNumIndexedCols of Delta Lake table: {value}"
+                    ))
+                })?;
+                Ok(usize::try_from(num).ok())
+            }
+        }
+    }
+}
+
+/// A data file that a commit adds to a table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Add {
+    /// The path of the file relative to the table, as a URI-encoded path.
+    pub path: String,
+    pub partition_values: BTreeMap<String, Option<String>>,
+    pub size: i64,
+    pub modification_time: i64,
+    pub data_change: bool,
+    /// The statistics of the columns of the file, as JSON.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stats: Option<String>,
+}
+
+/// A data file that a commit removes from a table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Remove {
+    pub path: String,
+    pub deletion_timestamp: i64,
+    pub data_change: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+}
+
+/// An action of a commit, which is serialized as one line of its log file.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Action {
+    CommitInfo(Json),
+    Protocol(Protocol),
+    MetaData(Metadata),
+    Remove(Remove),
+    Add(Add),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StructType {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub fields: Vec<StructField>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StructField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub data_type: DataType,
+    pub nullable: bool,
+    #[serde(default)]
+    pub metadata: BTreeMap<String, Json>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DataType {
+    Primitive(String),
+    Struct(Box<StructType>),
+    Array(Box<ArrayType>),
+    Map(Box<MapType>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArrayType {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub element_type: DataType,
+    pub contains_null: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MapType {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub key_type: DataType,
+    pub value_type: DataType,
+    pub value_contains_null: bool,
+}
+
+impl DataType {
+    fn has_timestamp_ntz(&self) -> bool {
+        match self {
+            Self::Primitive(name) => name == "timestamp_ntz",
+            Self::Struct(ty) => ty.fields.iter().any(|f| f.data_type.has_timestamp_ntz()),
+            Self::Array(ty) => ty.element_type.has_timestamp_ntz(),
+            Self::Map(ty) => ty.key_type.has_timestamp_ntz() || ty.value_type.has_timestamp_ntz(),
+        }
+    }
+
+    /// The Daft type that values of the type are written with.
+    pub fn to_daft_dtype(&self) -> DaftResult<DaftDataType> {
+        Ok(match self {
+            Self::Primitive(name) => match name.as_str() {
+                "string" => DaftDataType::Utf8,
+                "long" => DaftDataType::Int64,
+                "integer" => DaftDataType::Int32,
+                "short" => DaftDataType::Int16,
+                "byte" => DaftDataType::Int8,
+                "float" => DaftDataType::Float32,
+                "double" => DaftDataType::Float64,
+                "boolean" => DaftDataType::Boolean,
+                "binary" => DaftDataType::Binary,
+                "date" => DaftDataType::Date,
+                "timestamp" => {
+                    DaftDataType::Timestamp(TimeUnit::Microseconds, Some("UTC".to_string()))
+                }
+                "timestamp_ntz" => DaftDataType::Timestamp(TimeUnit::Microseconds, None),
+                _ => {
+                    let invalid =
+                        || DaftError::ValueError(format!("Unsupported Delta Lake type: {name}"));
+                    let (precision, scale) = name
+                        .strip_prefix("decimal(")
+                        .and_then(|args| args.strip_suffix(')'))
+                        .and_then(|args| args.split_once(','))
+                        .ok_or_else(invalid)?;
+                    DaftDataType::Decimal128(
+                        precision.trim().parse().map_err(|_| invalid())?,
+                        scale.trim().parse().map_err(|_| invalid())?,
+                    )
+                }
+            },
+            Self::Struct(ty) => DaftDataType::Struct(ty.to_daft_fields()?),
+            Self::Array(ty) => DaftDataType::List(Box::new(ty.element_type.to_daft_dtype()?)),
+            Self::Map(ty) => DaftDataType::Map {
+                key: Box::new(ty.key_type.to_daft_dtype()?),
+                value: Box::new(ty.value_type.to_daft_dtype()?),
+            },
+        })
+    }
+}
+
+impl StructType {
+    fn to_daft_fields(&self) -> DaftResult<Vec<DaftField>> {
+        self.fields
+            .iter()
+            .map(|field| {
+                Ok(DaftField::new(
+                    &field.name,
+                    field.data_type.to_daft_dtype()?,
+                ))
+            })
+            .collect()
+    }
+
+    pub fn to_daft_schema(&self) -> DaftResult<DaftSchema> {
+        Ok(DaftSchema::new(self.to_daft_fields()?))
+    }
+
+    pub fn field(&self, name: &str) -> Option<&StructField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
+
+/// Checks that a table of `protocol` and `metadata` can be written to in `mode` without dropping any of the
+/// guarantees of the features it uses, such as constraints that written rows have to satisfy.
+pub fn check_writable(
+    protocol: &Protocol,
+    metadata: &Metadata,
+    schema: &StructType,
+    overwrite: bool,
+) -> DaftResult<()> {
+    if protocol.min_reader_version > MAX_READER_VERSION
+        || protocol.min_writer_version > MAX_WRITER_VERSION
+    {
+        return Err(DaftError::NotImplemented(format!(
+            "Writing Delta Lake tables of reader version {} and writer version {} is not supported",
+            protocol.min_reader_version, protocol.min_writer_version
+        )));
+    }
+    if protocol.min_writer_version == MAX_WRITER_VERSION
+        && let Some(feature) = protocol
+            .writer_features
+            .iter()
+            .flatten()
+            .find(|feature| !SUPPORTED_WRITER_FEATURES.contains(&feature.as_str()))
+    {
+        return Err(DaftError::NotImplemented(format!(
+            "Writing Delta Lake tables with the {feature} feature is not supported"
+        )));
+    }
+
+    if let Some(mode) = metadata.config("delta.columnMapping.mode")
+        && mode != "none"
+    {
+        return Err(DaftError::NotImplemented(format!(
+            "Writing Delta Lake tables with column mapping mode {mode} is not supported"
+        )));
+    }
+    if metadata
+        .configuration
+        .keys()
+        .any(|key| key.starts_with("delta.constraints."))
+    {
+        return Err(DaftError::NotImplemented(
+            "Writing Delta Lake tables with CHECK constraints is not supported".to_string(),
+        ));
+    }
+    fn check_fields(fields: &[StructField]) -> DaftResult<()> {
+        for field in fields {
+            for (key, feature) in [
+                ("delta.invariants", "column invariants"),
+                ("delta.generationExpression", "generated columns"),
+                ("delta.identity.start", "identity columns"),
+            ] {
+                if field.metadata.contains_key(key) {
+                    return Err(DaftError::NotImplemented(format!(
+                        "Writing Delta Lake tables with {feature} is not supported, but column {} is one",
+                        field.name
+                    )));
+                }
+            }
+            match &field.data_type {
+                DataType::Struct(ty) => check_fields(&ty.fields)?,
+                DataType::Array(_) | DataType::Map(_) | DataType::Primitive(_) => {}
+            }
+        }
+        Ok(())
+    }
+    check_fields(&schema.fields)?;
+
+    if overwrite {
+        if metadata.is_append_only() {
+            return Err(DaftError::ValueError(
+                "The Delta Lake table is append-only (delta.appendOnly), so it can't be overwritten"
+                    .to_string(),
+            ));
+        }
+        // Files with deletion vectors can only be removed along with them, which the files of snapshots don't have
+        if protocol.has_writer_feature("deletionVectors") {
+            return Err(DaftError::NotImplemented(
+                "Overwriting Delta Lake tables with the deletionVectors feature is not supported"
+                    .to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+
+    use super::{Metadata, StructType};
+
+    #[test]
+    fn test_parse_schema() -> common_error::DaftResult<()> {
+        let schema: StructType = serde_json::from_str(
+            r#"{"type": "struct", "fields": [
+                {"name": "id", "type": "long", "nullable": false, "metadata": {}},
+                {"name": "price", "type": "decimal(10,2)", "nullable": true, "metadata": {}},
+                {"name": "ts", "type": "timestamp_ntz", "nullable": true, "metadata": {}},
+                {"name": "tags", "type": {"type": "array", "elementType": "string", "containsNull": true},
+                 "nullable": true, "metadata": {}},
+                {"name": "attrs", "type": {"type": "map", "keyType": "string", "valueType": "integer",
+                 "valueContainsNull": true}, "nullable": true, "metadata": {}},
+                {"name": "point", "type": {"type": "struct", "fields": [
+                    {"name": "x", "type": "double", "nullable": true, "metadata": {}}
+                ]}, "nullable": true, "metadata": {}}
+            ]}"#,
+        )
+        .unwrap();
+        let daft_schema = schema.to_daft_schema()?;
+        assert_eq!(
+            daft_schema,
+            Schema::new(vec![
+                Field::new("id", DataType::Int64),
+                Field::new("price", DataType::Decimal128(10, 2)),
+                Field::new("ts", DataType::Timestamp(TimeUnit::Microseconds, None)),
+                Field::new("tags", DataType::List(Box::new(DataType::Utf8))),
+                Field::new(
+                    "attrs",
+                    DataType::Map {
+                        key: Box::new(DataType::Utf8),
+                        value: Box::new(DataType::Int32),
+                    }
+                ),
+                Field::new(
+                    "point",
+                    DataType::Struct(vec![Field::new("x", DataType::Float64)])
+                ),
+            ])
+        );
+        assert_eq!(
+            super::Protocol::for_schema(&schema).writer_features,
+            Some(vec!["timestampNtz".to_string()])
+        );
+
+        // Schemas are written back to the log unchanged
+        let json = serde_json::to_string(&schema).unwrap();
+        assert_eq!(serde_json::from_str::<StructType>(&json).unwrap(), schema);
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_writable() {
+        let metadata: Metadata = serde_json::from_str(
+            r#"{"id": "1", "format": {"provider": "parquet"}, "partitionColumns": [],
+                "schemaString": "{\"type\": \"struct\", \"fields\": []}",
+                "configuration": {"delta.appendOnly": "true"}}"#,
+        )
+        .unwrap();
+        let schema = metadata.schema().unwrap();
+        let protocol = super::Protocol::for_schema(&schema);
+        assert!(super::check_writable(&protocol, &metadata, &schema, false).is_ok());
+        assert!(super::check_writable(&protocol, &metadata, &schema, true).is_err());
+
+        let protocol = super::Protocol {
+            min_reader_version: 3,
+            min_writer_version: 7,
+            reader_features: None,
+            writer_features: Some(vec!["rowTracking".to_string()]),
+        };
+        assert!(super::check_writable(&protocol, &metadata, &schema, false).is_err());
+    }
+}
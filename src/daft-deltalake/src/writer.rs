@@ -0,0 +1,514 @@
+//! Writes the data files of a table: Parquet files without the partition columns of the table, along with the
+//! partition values and column statistics that their add actions need.
+
+use std::{
+    collections::BTreeMap,
+    io::Write,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use arrow2::io::parquet::write::{
+    transverse, CompressionOptions, Encoding, FileWriter, RowGroupIterator, Version, WriteOptions,
+};
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{AsArrow, DataType, Schema as DaftSchema, TimeUnit},
+    series::Series,
+};
+use daft_io::{parse_url, IOClient, IOStatsRef, ObjectSink, SourceType};
+use daft_recordbatch::RecordBatch;
+use percent_encoding::{AsciiSet, CONTROLS};
+use serde_json::{json, Map, Value as Json};
+
+use crate::spec::Add;
+
+/// String bounds are truncated to this many characters, as other writers do by default.
+const STRING_BOUNDS_LENGTH: usize = 32;
+
+/// The name of the directory of rows whose partition value is null, as in Hive-style partitioning.
+const NULL_PARTITION_DIR: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// The characters that are escaped in the partition directories of paths, as Hive-style partitioning does.
+const PARTITION_DIR_ESCAPED: &AsciiSet = &CONTROLS
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'\'')
+    .add(b'*')
+    .add(b'/')
+    .add(b':')
+    .add(b'=')
+    .add(b'?')
+    .add(b'\\')
+    .add(b'{')
+    .add(b'[')
+    .add(b']')
+    .add(b'^');
+
+/// The characters that are encoded in the paths of add actions, which are URIs relative to the table.
+const PATH_ENCODED: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'[')
+    .add(b'\\')
+    .add(b']')
+    .add(b'^')
+    .add(b'`')
+    .add(b'{')
+    .add(b'|')
+    .add(b'}');
+
+/// Creates the directory of `path` if it is a local path, as local files are written in place, unlike objects.
+pub(crate) fn create_local_dir(path: &str) -> DaftResult<()> {
+    let (source_type, path) = parse_url(path)?;
+    if source_type == SourceType::File
+        && let Some(dir) =
+            std::path::Path::new(path.strip_prefix("file://").unwrap_or(&path)).parent()
+    {
+        std::fs::create_dir_all(dir)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn now_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_millis() as i64)
+}
+
+/// Encodes a path relative to the table as the path of an action.
+pub(crate) fn encode_path(path: &str) -> String {
+    percent_encoding::utf8_percent_encode(path, PATH_ENCODED).to_string()
+}
+
+fn timestamp_micros(series: &Series) -> DaftResult<Option<i64>> {
+    let DataType::Timestamp(unit, _) = series.data_type() else {
+        unreachable!("only called on timestamps")
+    };
+    let unit = *unit;
+    let value = series.cast(&DataType::Int64)?.i64()?.get(0);
+    Ok(value.map(|value| match unit {
+        TimeUnit::Seconds => value * 1_000_000,
+        TimeUnit::Milliseconds => value * 1_000,
+        TimeUnit::Microseconds => value,
+        TimeUnit::Nanoseconds => value.div_euclid(1_000),
+    }))
+}
+
+/// Formats the first value of `series` as a partition value of the log, or None if it is null.
+fn partition_value(series: &Series) -> DaftResult<Option<String>> {
+    if series
+        .validity()
+        .is_some_and(|validity| !validity.get_bit(0))
+    {
+        return Ok(None);
+    }
+    match series.data_type() {
+        DataType::Timestamp(..) => Ok(timestamp_micros(series)?
+            .and_then(chrono::DateTime::from_timestamp_micros)
+            .map(|ts| ts.format("%Y-%m-%d %H:%M:%S%.6f").to_string())),
+        DataType::Binary | DataType::FixedSizeBinary(_) => Err(DaftError::NotImplemented(
+            "Binary partition columns are not yet supported for Delta Lake writes".to_string(),
+        )),
+        dtype
+            if dtype.is_numeric()
+                || matches!(
+                    dtype,
+                    DataType::Boolean | DataType::Utf8 | DataType::Date | DataType::Decimal128(..)
+                ) =>
+        {
+            Ok(series.to_str_values()?.utf8()?.get(0).map(str::to_string))
+        }
+        dtype => Err(DaftError::ValueError(format!(
+            "Delta Lake tables can't be partitioned by a column of type {dtype}"
+        ))),
+    }
+}
+
+/// Formats the first value of `series` as a bound of the statistics of a file, or None if it has no bound, with
+/// timestamps rounded to milliseconds towards the outside of the bounds.
+fn bound_value(series: &Series, is_upper: bool) -> DaftResult<Option<Json>> {
+    if series
+        .validity()
+        .is_some_and(|validity| !validity.get_bit(0))
+    {
+        return Ok(None);
+    }
+    Ok(match series.data_type() {
+        dtype if dtype.is_integer() => series.cast(&DataType::Int64)?.i64()?.get(0).map(Json::from),
+        DataType::Float32 | DataType::Float64 => series
+            .cast(&DataType::Float64)?
+            .f64()?
+            .get(0)
+            .filter(|value| value.is_finite())
+            .map(Json::from),
+        DataType::Utf8 => series.utf8()?.get(0).and_then(|value| {
+            match value.char_indices().nth(STRING_BOUNDS_LENGTH) {
+                None => Some(Json::from(value)),
+                // A truncated lower bound is still lower, but a truncated upper bound wouldn't be upper
+                Some((end, _)) if !is_upper => Some(Json::from(&value[..end])),
+                Some(_) => None,
+            }
+        }),
+        DataType::Date => series.to_str_values()?.utf8()?.get(0).map(Json::from),
+        DataType::Timestamp(_, tz) => {
+            let format = if tz.is_some() {
+                "%Y-%m-%dT%H:%M:%S%.3fZ"
+            } else {
+                "%Y-%m-%dT%H:%M:%S%.3f"
+            };
+            timestamp_micros(series)?
+                .map(|micros| {
+                    if is_upper {
+                        micros.div_euclid(1_000) + i64::from(micros.rem_euclid(1_000) != 0)
+                    } else {
+                        micros.div_euclid(1_000)
+                    }
+                })
+                .and_then(chrono::DateTime::from_timestamp_millis)
+                .map(|ts| Json::from(ts.format(format).to_string()))
+        }
+        _ => None,
+    })
+}
+
+/// The statistics of a top-level column, which are kept up to date as batches are written.
+struct ColumnStats {
+    name: String,
+    null_count: i64,
+    min: Option<Series>,
+    max: Option<Series>,
+    /// Whether bounds can be kept for the column, which they can't for types that have no order in Daft or that
+    /// the log has no bounds for, nor for floats once they have NaNs.
+    has_bounds: bool,
+}
+
+impl ColumnStats {
+    fn new(name: &str, dtype: &DataType, is_indexed: bool) -> Self {
+        let has_bounds = is_indexed
+            && (dtype.is_integer()
+                || dtype.is_floating()
+                || matches!(
+                    dtype,
+                    DataType::Utf8 | DataType::Date | DataType::Timestamp(..)
+                ));
+        Self {
+            name: name.to_string(),
+            null_count: 0,
+            min: None,
+            max: None,
+            has_bounds,
+        }
+    }
+
+    fn update(&mut self, series: &Series) -> DaftResult<()> {
+        self.null_count += series
+            .validity()
+            .map_or(0, |validity| validity.unset_bits()) as i64;
+        if !self.has_bounds {
+            return Ok(());
+        }
+        if series.data_type().is_floating() {
+            let is_nan = series.is_nan()?;
+            if is_nan.bool()?.as_arrow().iter().any(|v| v == Some(true)) {
+                self.has_bounds = false;
+                return Ok(());
+            }
+        }
+        let (Ok(min), Ok(max)) = (series.min(None), series.max(None)) else {
+            self.has_bounds = false;
+            return Ok(());
+        };
+        self.min = Some(match self.min.take() {
+            None => min,
+            Some(current) => Series::concat(&[&current, &min])?.min(None)?,
+        });
+        self.max = Some(match self.max.take() {
+            None => max,
+            Some(current) => Series::concat(&[&current, &max])?.max(None)?,
+        });
+        Ok(())
+    }
+}
+
+/// Forwards writes to an [`ObjectSink`], counting the bytes written while the Parquet writer owns it.
+struct CountingSink {
+    sink: ObjectSink,
+    bytes_written: Arc<AtomicUsize>,
+}
+
+impl Write for CountingSink {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.sink.write(buf)?;
+        self.bytes_written.fetch_add(written, Ordering::Relaxed);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.sink.flush()
+    }
+}
+
+/// Writes a Parquet data file of a table, into the partition of `partition_values` if the table is partitioned.
+pub struct AddFileWriter {
+    /// The path of the file relative to the table.
+    path: String,
+    table_uri: String,
+    partition_values: BTreeMap<String, Option<String>>,
+    /// The schema of the file, which is that of the table without its partition columns.
+    schema: Arc<DaftSchema>,
+    nullable: Vec<bool>,
+    arrow_schema: arrow2::datatypes::Schema,
+    options: WriteOptions,
+    writer: Option<FileWriter<CountingSink>>,
+    bytes_written: Arc<AtomicUsize>,
+    stats: Vec<ColumnStats>,
+    num_records: usize,
+    io_client: Arc<IOClient>,
+    part_size: usize,
+    max_inflight_parts: usize,
+    io_stats: Option<IOStatsRef>,
+}
+
+impl AddFileWriter {
+    /// Creates a writer of the data file `file_idx` of a write to the table at `table_uri`, which is written under
+    /// a unique name. Columns of `schema` are nullable as `nullable` says, and statistics are collected for the
+    /// first `num_indexed_cols` of them, or all of them if it is None. Files are uploaded in parts of `part_size`
+    /// bytes, with at most `max_inflight_parts` of them in flight at once.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        table_uri: &str,
+        schema: &DaftSchema,
+        nullable: &[bool],
+        partition_columns: &[String],
+        partition_values: Option<&RecordBatch>,
+        num_indexed_cols: Option<usize>,
+        file_idx: usize,
+        io_client: Arc<IOClient>,
+        part_size: usize,
+        max_inflight_parts: usize,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<Self> {
+        let mut dir = String::new();
+        let mut values = BTreeMap::new();
+        if !partition_columns.is_empty() {
+            let partition_values = partition_values.ok_or_else(|| {
+                DaftError::InternalError(
+                    "Missing partition values for a data file of a partitioned Delta Lake table"
+                        .to_string(),
+                )
+            })?;
+            for column in partition_columns {
+                let field = schema.get_field(column)?;
+                let series = partition_values.get_column(column)?.cast(&field.dtype)?;
+                let value = partition_value(&series)?;
+                let dir_value = value.as_deref().map_or_else(
+                    || NULL_PARTITION_DIR.to_string(),
+                    |value| {
+                        percent_encoding::utf8_percent_encode(value, PARTITION_DIR_ESCAPED)
+                            .to_string()
+                    },
+                );
+                dir.push_str(&format!("{column}={dir_value}/"));
+                values.insert(column.clone(), value);
+            }
+        }
+        let path = format!(
+            "{dir}part-{file_idx:05}-{}-c000.snappy.parquet",
+            uuid::Uuid::new_v4()
+        );
+
+        let (fields, nullable): (Vec<_>, Vec<_>) = schema
+            .fields()
+            .iter()
+            .zip(nullable)
+            .filter(|(field, _)| !partition_columns.contains(&field.name))
+            .map(|(field, nullable)| (field.clone(), *nullable))
+            .unzip();
+        let stats = fields
+            .iter()
+            .enumerate()
+            .map(|(idx, field)| {
+                ColumnStats::new(
+                    &field.name,
+                    &field.dtype,
+                    num_indexed_cols.is_none_or(|num| idx < num),
+                )
+            })
+            .collect();
+        let schema = Arc::new(DaftSchema::new(fields));
+
+        Ok(Self {
+            path,
+            table_uri: table_uri.trim_end_matches('/').to_string(),
+            partition_values: values,
+            arrow_schema: schema.to_arrow()?,
+            schema,
+            nullable,
+            options: WriteOptions {
+                write_statistics: true,
+                version: Version::V1,
+                compression: CompressionOptions::Snappy,
+                data_pagesize_limit: None,
+            },
+            writer: None,
+            bytes_written: Arc::new(AtomicUsize::new(0)),
+            stats,
+            num_records: 0,
+            io_client,
+            part_size,
+            max_inflight_parts,
+            io_stats,
+        })
+    }
+
+    /// The path of the file relative to the table.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn get_or_create_writer(&mut self) -> DaftResult<&mut FileWriter<CountingSink>> {
+        if self.writer.is_none() {
+            let uri = format!("{}/{}", self.table_uri, self.path);
+            create_local_dir(&uri)?;
+            let sink = CountingSink {
+                sink: ObjectSink::new(
+                    self.io_client.clone(),
+                    uri,
+                    self.part_size,
+                    self.max_inflight_parts,
+                    self.io_stats.clone(),
+                ),
+                bytes_written: self.bytes_written.clone(),
+            };
+            self.writer = Some(FileWriter::try_new(
+                sink,
+                self.arrow_schema.clone(),
+                self.options,
+            )?);
+        }
+        Ok(self.writer.as_mut().unwrap())
+    }
+
+    /// Writes a batch of rows, which are cast to the schema of the file, with nulls for the columns they don't
+    /// have. Returns the number of bytes written to the file so far.
+    pub fn write(&mut self, batch: &RecordBatch) -> DaftResult<usize> {
+        if batch.is_empty() {
+            return Ok(self.bytes_written());
+        }
+        let columns = self
+            .schema
+            .fields()
+            .iter()
+            .zip(&self.nullable)
+            .map(|(field, nullable)| {
+                let column = match batch.get_column(&field.name) {
+                    Ok(column) => column.cast(&field.dtype)?,
+                    Err(_) => Series::full_null(&field.name, &field.dtype, batch.len()),
+                };
+                if !nullable
+                    && column
+                        .validity()
+                        .is_some_and(|validity| validity.unset_bits() > 0)
+                {
+                    return Err(DaftError::ValueError(format!(
+                        "Column {} of the Delta Lake table is not nullable, but the data has nulls in it",
+                        field.name
+                    )));
+                }
+                Ok(column)
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let batch = RecordBatch::new_with_size(self.schema.clone(), columns, batch.len())?;
+
+        for (idx, stats) in self.stats.iter_mut().enumerate() {
+            stats.update(batch.get_column_by_index(idx)?)?;
+        }
+        self.num_records += batch.len();
+
+        let chunk = batch.to_chunk();
+        let encodings = self
+            .arrow_schema
+            .fields
+            .iter()
+            .map(|field| transverse(field.data_type(), |_| Encoding::Plain))
+            .collect();
+        let row_groups = RowGroupIterator::try_new(
+            std::iter::once(Ok(chunk)),
+            &self.arrow_schema,
+            self.options,
+            encodings,
+        )?;
+        let writer = self.get_or_create_writer()?;
+        for row_group in row_groups {
+            writer.write(row_group?)?;
+        }
+        Ok(self.bytes_written())
+    }
+
+    pub fn bytes_written(&self) -> usize {
+        self.bytes_written.load(Ordering::Relaxed)
+    }
+
+    /// Finishes writing the file, returning its add action, or None if nothing was written to it.
+    pub fn close(&mut self) -> DaftResult<Option<Add>> {
+        let Some(mut writer) = self.writer.take() else {
+            return Ok(None);
+        };
+        writer.end(None)?;
+        let mut sink = writer.into_inner();
+        sink.sink.close()?;
+
+        let mut null_count = Map::new();
+        let mut min_values = Map::new();
+        let mut max_values = Map::new();
+        for stats in self.stats.drain(..) {
+            null_count.insert(stats.name.clone(), json!(stats.null_count));
+            if !stats.has_bounds {
+                continue;
+            }
+            if let Some(min) = stats
+                .min
+                .as_ref()
+                .map(|min| bound_value(min, false))
+                .transpose()?
+                .flatten()
+            {
+                min_values.insert(stats.name.clone(), min);
+            }
+            if let Some(max) = stats
+                .max
+                .as_ref()
+                .map(|max| bound_value(max, true))
+                .transpose()?
+                .flatten()
+            {
+                max_values.insert(stats.name.clone(), max);
+            }
+        }
+        let stats = json!({
+            "numRecords": self.num_records,
+            "minValues": min_values,
+            "maxValues": max_values,
+            "nullCount": null_count,
+        });
+
+        Ok(Some(Add {
+            path: encode_path(&self.path),
+            partition_values: self.partition_values.clone(),
+            size: self.bytes_written() as i64,
+            modification_time: now_ms(),
+            data_change: true,
+            stats: Some(stats.to_string()),
+        }))
+    }
+}
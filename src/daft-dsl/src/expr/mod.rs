@@ -24,6 +24,7 @@ use daft_core::{
     },
     join::JoinSide,
     prelude::*,
+    series::{space_saving_dtype, space_saving_values_dtype, top_k_capacity},
     utils::supertype::{try_get_collection_supertype, try_get_supertype},
 };
 use derive_more::Display;
@@ -39,7 +40,7 @@ use crate::{
         struct_::StructExpr,
         FunctionEvaluator, ScalarFunction,
     },
-    lit,
+    lit, null_lit,
     optimization::{get_required_columns, requires_computation},
 };
 
@@ -278,6 +279,32 @@ pub struct ApproxPercentileParams {
     pub force_list_output: bool,
 }
 
+/// Parameters of a collect aggregation, which gathers each group's values into a list.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Hash, Eq)]
+pub struct CollectParams {
+    pub child: ExprRef,
+    /// Whether to keep only the first occurrence of each value. Nulls are dropped when distinct.
+    pub distinct: bool,
+    /// The expression to order each group's values by, in place of their order in the input.
+    pub order_by: Option<ExprRef>,
+    pub descending: bool,
+    /// The maximum number of values to keep for each group, after ordering and deduplicating.
+    pub limit: Option<usize>,
+}
+
+impl std::fmt::Display for CollectParams {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "collect({}, distinct={}", self.child, self.distinct)?;
+        if let Some(order_by) = &self.order_by {
+            write!(f, ", order_by={order_by}, descending={}", self.descending)?;
+        }
+        if let Some(limit) = self.limit {
+            write!(f, ", limit={limit}")?;
+        }
+        write!(f, ")")
+    }
+}
+
 #[derive(Display, Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum AggExpr {
     #[display("count({_0}, {_1})")]
@@ -295,6 +322,9 @@ pub enum AggExpr {
     #[display("approx_count_distinct({_0})")]
     ApproxCountDistinct(ExprRef),
 
+    #[display("approx_top_k({_0}, k={_1})")]
+    ApproxTopK(ExprRef, usize),
+
     #[display("approx_sketch({_0}, sketch_type={_1:?})")]
     ApproxSketch(ExprRef, SketchType),
 
@@ -331,6 +361,9 @@ pub enum AggExpr {
     #[display("list({_0})")]
     Concat(ExprRef),
 
+    #[display("{_0}")]
+    Collect(CollectParams),
+
     #[display("{}", function_display_without_formatter(func, inputs)?)]
     MapGroups {
         func: FunctionExpr,
@@ -345,12 +378,41 @@ pub enum WindowExpr {
 
     #[display("row_number")]
     RowNumber,
+
+    #[display("rank")]
+    Rank,
+
+    #[display("dense_rank")]
+    DenseRank,
+
+    /// The value of `input` at `offset` rows from the current row, or `default` if there is no
+    /// such row. Negative offsets look back (lag) and positive offsets look ahead (lead).
+    #[display("offset({input}, {offset})")]
+    Offset {
+        input: ExprRef,
+        offset: isize,
+        default: Option<ExprRef>,
+    },
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum SketchType {
     DDSketch,
     HyperLogLog,
+    /// A space-saving sketch of the most frequent values, tracking at most `capacity` of them.
+    SpaceSaving {
+        capacity: usize,
+    },
+}
+
+impl SketchType {
+    /// The space-saving sketch used to find the `k` most frequent values.
+    #[must_use]
+    pub fn top_k(k: usize) -> Self {
+        Self::SpaceSaving {
+            capacity: top_k_capacity(k),
+        }
+    }
 }
 
 /// Unresolved column with no associated plan ID or schema.
@@ -390,6 +452,7 @@ impl AggExpr {
             | Self::Sum(expr)
             | Self::ApproxPercentile(ApproxPercentileParams { child: expr, .. })
             | Self::ApproxCountDistinct(expr)
+            | Self::ApproxTopK(expr, _)
             | Self::ApproxSketch(expr, _)
             | Self::MergeSketch(expr, _)
             | Self::Mean(expr)
@@ -401,7 +464,8 @@ impl AggExpr {
             | Self::AnyValue(expr, _)
             | Self::List(expr)
             | Self::Set(expr)
-            | Self::Concat(expr) => expr.name(),
+            | Self::Concat(expr)
+            | Self::Collect(CollectParams { child: expr, .. }) => expr.name(),
             Self::MapGroups { func: _, inputs } => inputs.first().unwrap().name(),
         }
     }
@@ -435,6 +499,10 @@ impl AggExpr {
                 let child_id = expr.semantic_id(schema);
                 FieldID::new(format!("{child_id}.local_approx_count_distinct()"))
             }
+            Self::ApproxTopK(expr, k) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_approx_top_k(k={k})"))
+            }
             Self::ApproxSketch(expr, sketch_type) => {
                 let child_id = expr.semantic_id(schema);
                 FieldID::new(format!(
@@ -489,6 +557,19 @@ impl AggExpr {
                 let child_id = expr.semantic_id(schema);
                 FieldID::new(format!("{child_id}.local_concat()"))
             }
+            Self::Collect(CollectParams {
+                child,
+                distinct,
+                order_by,
+                descending,
+                limit,
+            }) => {
+                let child_id = child.semantic_id(schema);
+                let order_by_id = order_by.as_ref().map(|e| e.semantic_id(schema));
+                FieldID::new(format!(
+                    "{child_id}.local_collect(distinct={distinct},order_by={order_by_id:?},descending={descending},limit={limit:?})"
+                ))
+            }
             Self::MapGroups { func, inputs } => function_semantic_id(func, inputs, schema),
         }
     }
@@ -500,6 +581,7 @@ impl AggExpr {
             | Self::Sum(expr)
             | Self::ApproxPercentile(ApproxPercentileParams { child: expr, .. })
             | Self::ApproxCountDistinct(expr)
+            | Self::ApproxTopK(expr, _)
             | Self::ApproxSketch(expr, _)
             | Self::MergeSketch(expr, _)
             | Self::Mean(expr)
@@ -512,15 +594,22 @@ impl AggExpr {
             | Self::List(expr)
             | Self::Set(expr)
             | Self::Concat(expr) => vec![expr.clone()],
+            Self::Collect(CollectParams {
+                child, order_by, ..
+            }) => std::iter::once(child.clone())
+                .chain(order_by.clone())
+                .collect(),
             Self::MapGroups { func: _, inputs } => inputs.clone(),
         }
     }
 
     pub fn with_new_children(&self, mut children: Vec<ExprRef>) -> Self {
-        if let Self::MapGroups { func: _, inputs } = &self {
-            assert_eq!(children.len(), inputs.len());
-        } else {
-            assert_eq!(children.len(), 1);
+        match &self {
+            Self::MapGroups { func: _, inputs } => assert_eq!(children.len(), inputs.len()),
+            Self::Collect(params) => {
+                assert_eq!(children.len(), 1 + usize::from(params.order_by.is_some()));
+            }
+            _ => assert_eq!(children.len(), 1),
         }
         let mut first_child = || children.pop().unwrap();
         match self {
@@ -537,6 +626,15 @@ impl AggExpr {
             Self::List(_) => Self::List(first_child()),
             Self::Set(_expr) => Self::Set(first_child()),
             Self::Concat(_) => Self::Concat(first_child()),
+            Self::Collect(params) => {
+                // the children are popped from the back, so the ordering expression comes first
+                let order_by = params.order_by.as_ref().map(|_| first_child());
+                Self::Collect(CollectParams {
+                    child: first_child(),
+                    order_by,
+                    ..params.clone()
+                })
+            }
             Self::MapGroups { func, inputs: _ } => Self::MapGroups {
                 func: func.clone(),
                 inputs: children,
@@ -551,6 +649,7 @@ impl AggExpr {
                 force_list_output: *force_list_output,
             }),
             Self::ApproxCountDistinct(_) => Self::ApproxCountDistinct(first_child()),
+            &Self::ApproxTopK(_, k) => Self::ApproxTopK(first_child(), k),
             &Self::ApproxSketch(_, sketch_type) => Self::ApproxSketch(first_child(), sketch_type),
             &Self::MergeSketch(_, sketch_type) => Self::MergeSketch(first_child(), sketch_type),
         }
@@ -597,6 +696,10 @@ impl AggExpr {
                 let field = expr.to_field(schema)?;
                 Ok(Field::new(field.name.as_str(), DataType::UInt64))
             }
+            Self::ApproxTopK(expr, _) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(field.name, space_saving_dtype(&field.dtype)))
+            }
             Self::ApproxSketch(expr, sketch_type) => {
                 let field = expr.to_field(schema)?;
                 let dtype = match sketch_type {
@@ -610,6 +713,7 @@ impl AggExpr {
                         DataType::from(&*daft_sketch::ARROW2_DDSKETCH_DTYPE)
                     }
                     SketchType::HyperLogLog => daft_core::array::ops::HLL_SKETCH_DTYPE,
+                    SketchType::SpaceSaving { .. } => space_saving_dtype(&field.dtype),
                 };
                 Ok(Field::new(field.name, dtype))
             }
@@ -627,6 +731,15 @@ impl AggExpr {
                         }
                     }
                     SketchType::HyperLogLog => DataType::UInt64,
+                    SketchType::SpaceSaving { .. } => {
+                        if space_saving_values_dtype(&field.dtype).is_none() {
+                            return Err(DaftError::TypeError(format!(
+                                "Expected input to merge_sketch() to be a space-saving sketch but received dtype {} for column \"{}\"",
+                                field.dtype, field.name,
+                            )));
+                        }
+                        field.dtype
+                    }
                 };
                 Ok(Field::new(field.name, dtype))
             }
@@ -652,6 +765,15 @@ impl AggExpr {
 
             Self::List(expr) | Self::Set(expr) => expr.to_field(schema)?.to_list_field(),
 
+            Self::Collect(CollectParams {
+                child, order_by, ..
+            }) => {
+                if let Some(order_by) = order_by {
+                    order_by.to_field(schema)?;
+                }
+                child.to_field(schema)?.to_list_field()
+            }
+
             Self::BoolAnd(expr) | Self::BoolOr(expr) => {
                 let field = expr.to_field(schema)?;
                 Ok(Field::new(field.name.as_str(), DataType::Boolean))
@@ -661,6 +783,9 @@ impl AggExpr {
                 let field = expr.to_field(schema)?;
                 match field.dtype {
                     DataType::List(..) => Ok(field),
+                    DataType::FixedSizeList(child_dtype, _) => {
+                        Ok(Field::new(field.name.as_str(), DataType::List(child_dtype)))
+                    }
                     DataType::Utf8 => Ok(field),
                     #[cfg(feature = "python")]
                     DataType::Python => Ok(field),
@@ -673,6 +798,61 @@ impl AggExpr {
             Self::MapGroups { func, inputs } => func.to_field(inputs.as_slice(), schema, func),
         }
     }
+
+    /// Restricts this aggregation to the rows that `predicate` is true for.
+    ///
+    /// The input of the aggregation is nulled out on the other rows rather than filtering the
+    /// rows out, so that each aggregation of a groupby can have its own predicate and the
+    /// aggregation still runs over the whole partition in a single pass.
+    pub fn with_filter(self, predicate: ExprRef) -> DaftResult<Self> {
+        let mask = |expr: ExprRef| predicate.clone().if_else(expr, null_lit());
+        Ok(match self {
+            // counting rows, or rows with nulls, counts the rows that a non-null flag is kept for
+            Self::Count(expr, CountMode::All) => {
+                Self::Count(mask(expr.is_null()), CountMode::Valid)
+            }
+            Self::Count(expr, CountMode::Null) => Self::Count(
+                predicate
+                    .clone()
+                    .and(expr.clone().is_null())
+                    .if_else(expr.is_null(), null_lit()),
+                CountMode::Valid,
+            ),
+            Self::Count(expr, CountMode::Valid) => Self::Count(mask(expr), CountMode::Valid),
+            Self::CountDistinct(expr) => Self::CountDistinct(mask(expr)),
+            Self::Sum(expr) => Self::Sum(mask(expr)),
+            Self::ApproxPercentile(params) => Self::ApproxPercentile(ApproxPercentileParams {
+                child: mask(params.child),
+                ..params
+            }),
+            Self::ApproxCountDistinct(expr) => Self::ApproxCountDistinct(mask(expr)),
+            Self::ApproxTopK(expr, k) => Self::ApproxTopK(mask(expr), k),
+            Self::Mean(expr) => Self::Mean(mask(expr)),
+            Self::Stddev(expr) => Self::Stddev(mask(expr)),
+            Self::Min(expr) => Self::Min(mask(expr)),
+            Self::Max(expr) => Self::Max(mask(expr)),
+            Self::BoolAnd(expr) => Self::BoolAnd(mask(expr)),
+            Self::BoolOr(expr) => Self::BoolOr(mask(expr)),
+            Self::AnyValue(expr, true) => Self::AnyValue(mask(expr), true),
+            Self::Set(expr) => Self::Set(mask(expr)),
+            Self::Collect(params) if params.distinct => Self::Collect(CollectParams {
+                child: mask(params.child),
+                ..params
+            }),
+            // these keep nulls, so the rows that are filtered out would show up as nulls
+            agg @ (Self::AnyValue(_, false)
+            | Self::List(_)
+            | Self::Concat(_)
+            | Self::Collect(_)
+            | Self::ApproxSketch(..)
+            | Self::MergeSketch(..)
+            | Self::MapGroups { .. }) => {
+                return Err(DaftError::ValueError(format!(
+                    "Filtering is not supported for the aggregation {agg}"
+                )))
+            }
+        })
+    }
 }
 
 impl From<&AggExpr> for ExprRef {
@@ -686,6 +866,9 @@ impl WindowExpr {
         match self {
             Self::Agg(agg_expr) => agg_expr.name(),
             Self::RowNumber => "row_number",
+            Self::Rank => "rank",
+            Self::DenseRank => "dense_rank",
+            Self::Offset { input, .. } => input.name(),
         }
     }
 
@@ -693,13 +876,32 @@ impl WindowExpr {
         match self {
             Self::Agg(agg_expr) => agg_expr.semantic_id(schema),
             Self::RowNumber => FieldID::new("row_number"),
+            Self::Rank => FieldID::new("rank"),
+            Self::DenseRank => FieldID::new("dense_rank"),
+            Self::Offset {
+                input,
+                offset,
+                default,
+            } => {
+                let input_id = input.semantic_id(schema);
+                let default_id = default
+                    .as_ref()
+                    .map(|d| d.semantic_id(schema).id.to_string())
+                    .unwrap_or_else(|| "None".to_string());
+                FieldID::new(format!(
+                    "{input_id}.offset(offset={offset},default={default_id})"
+                ))
+            }
         }
     }
 
     pub fn children(&self) -> Vec<ExprRef> {
         match self {
             Self::Agg(agg_expr) => agg_expr.children(),
-            Self::RowNumber => vec![],
+            Self::RowNumber | Self::Rank | Self::DenseRank => vec![],
+            Self::Offset { input, default, .. } => std::iter::once(input.clone())
+                .chain(default.clone())
+                .collect(),
         }
     }
 
@@ -707,6 +909,16 @@ impl WindowExpr {
         match self {
             Self::Agg(agg_expr) => Self::Agg(agg_expr.with_new_children(children)),
             Self::RowNumber => Self::RowNumber,
+            Self::Rank => Self::Rank,
+            Self::DenseRank => Self::DenseRank,
+            Self::Offset { offset, .. } => {
+                let mut children = children.into_iter();
+                Self::Offset {
+                    input: children.next().expect("offset should have an input"),
+                    offset: *offset,
+                    default: children.next(),
+                }
+            }
         }
     }
 
@@ -714,6 +926,9 @@ impl WindowExpr {
         match self {
             Self::Agg(agg_expr) => agg_expr.to_field(schema),
             Self::RowNumber => Ok(Field::new("row_number", DataType::UInt64)),
+            Self::Rank => Ok(Field::new("rank", DataType::UInt64)),
+            Self::DenseRank => Ok(Field::new("dense_rank", DataType::UInt64)),
+            Self::Offset { input, .. } => input.to_field(schema),
         }
     }
 }
@@ -790,6 +1005,18 @@ impl Expr {
         Self::Cast(self, dtype.clone()).into()
     }
 
+    /// Restricts the aggregation in this expression to the rows that `predicate` is true for,
+    /// see [`AggExpr::with_filter`].
+    pub fn agg_filter(self: ExprRef, predicate: ExprRef) -> DaftResult<ExprRef> {
+        match self.as_ref() {
+            Self::Agg(agg) => Ok(Self::Agg(agg.clone().with_filter(predicate)?).into()),
+            Self::Alias(expr, name) => Ok(expr.clone().agg_filter(predicate)?.alias(name.clone())),
+            _ => Err(DaftError::ValueError(format!(
+                "Only aggregations can be filtered, received {self}"
+            ))),
+        }
+    }
+
     pub fn count(self: ExprRef, mode: CountMode) -> ExprRef {
         Self::Agg(AggExpr::Count(self, mode)).into()
     }
@@ -806,6 +1033,10 @@ impl Expr {
         Self::Agg(AggExpr::ApproxCountDistinct(self)).into()
     }
 
+    pub fn approx_top_k(self: ExprRef, k: usize) -> ExprRef {
+        Self::Agg(AggExpr::ApproxTopK(self, k)).into()
+    }
+
     pub fn approx_percentiles(
         self: ExprRef,
         percentiles: &[f64],
@@ -874,10 +1105,44 @@ impl Expr {
         Self::Agg(AggExpr::Concat(self)).into()
     }
 
+    pub fn agg_collect(
+        self: ExprRef,
+        distinct: bool,
+        order_by: Option<ExprRef>,
+        descending: bool,
+        limit: Option<usize>,
+    ) -> ExprRef {
+        Self::Agg(AggExpr::Collect(CollectParams {
+            child: self,
+            distinct,
+            order_by,
+            descending,
+            limit,
+        }))
+        .into()
+    }
+
     pub fn row_number() -> ExprRef {
         Self::WindowFunction(WindowExpr::RowNumber).into()
     }
 
+    pub fn rank() -> ExprRef {
+        Self::WindowFunction(WindowExpr::Rank).into()
+    }
+
+    pub fn dense_rank() -> ExprRef {
+        Self::WindowFunction(WindowExpr::DenseRank).into()
+    }
+
+    pub fn offset(self: ExprRef, offset: isize, default: Option<ExprRef>) -> ExprRef {
+        Self::WindowFunction(WindowExpr::Offset {
+            input: self,
+            offset,
+            default,
+        })
+        .into()
+    }
+
     #[allow(clippy::should_implement_trait)]
     pub fn not(self: ExprRef) -> ExprRef {
         Self::Not(self).into()
@@ -1262,8 +1527,9 @@ impl Expr {
                 let child_field = expr.to_field(schema)?;
                 match child_field.dtype {
                     DataType::Boolean => Ok(Field::new(expr.name(), DataType::Boolean)),
+                    dtype if dtype.is_integer() => Ok(Field::new(expr.name(), dtype)),
                     _ => Err(DaftError::TypeError(format!(
-                        "Expected argument to be a Boolean expression, but received {child_field}",
+                        "Expected argument to be a Boolean or integer expression, but received {child_field}",
                     ))),
                 }
             }
@@ -1459,6 +1725,7 @@ impl Expr {
             Self::ScalarFunction(func) => match func.name() {
                 "struct" => "struct", // FIXME: make struct its own expr variant
                 "monotonically_increasing_id" => "monotonically_increasing_id", // Special case for functions with no inputs
+                "uuid" => "uuid",
                 _ => func.inputs.first().unwrap().name(),
             },
             Self::BinaryOp {
@@ -1703,6 +1970,31 @@ impl Operator {
     pub(crate) fn is_arithmetic(&self) -> bool {
         !(self.is_comparison())
     }
+
+    /// Applies this operator to a pair of evaluated operands.
+    pub fn evaluate(&self, lhs: &Series, rhs: &Series) -> DaftResult<Series> {
+        use daft_core::array::ops::{DaftCompare, DaftLogical};
+        match self {
+            Self::Plus => lhs + rhs,
+            Self::Minus => lhs - rhs,
+            Self::TrueDivide => lhs / rhs,
+            Self::FloorDivide => lhs.floor_div(rhs),
+            Self::Multiply => lhs * rhs,
+            Self::Modulus => lhs % rhs,
+            Self::Lt => Ok(lhs.lt(rhs)?.into_series()),
+            Self::LtEq => Ok(lhs.lte(rhs)?.into_series()),
+            Self::Eq => Ok(lhs.equal(rhs)?.into_series()),
+            Self::EqNullSafe => Ok(lhs.eq_null_safe(rhs)?.into_series()),
+            Self::NotEq => Ok(lhs.not_equal(rhs)?.into_series()),
+            Self::GtEq => Ok(lhs.gte(rhs)?.into_series()),
+            Self::Gt => Ok(lhs.gt(rhs)?.into_series()),
+            Self::And => lhs.and(rhs),
+            Self::Or => lhs.or(rhs),
+            Self::Xor => lhs.xor(rhs),
+            Self::ShiftLeft => lhs.shift_left(rhs),
+            Self::ShiftRight => lhs.shift_right(rhs),
+        }
+    }
 }
 
 impl FromStr for Operator {
@@ -81,3 +81,24 @@ fn check_arithmetic_type_with_columns() -> DaftResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn filtered_aggregations_keep_their_fields() -> DaftResult<()> {
+    let schema = Schema::new(vec![
+        Field::new("x", DataType::Int64),
+        Field::new("flag", DataType::Boolean),
+    ]);
+    let predicate = resolved_col("flag");
+
+    let sum = resolved_col("x").sum().agg_filter(predicate.clone())?;
+    assert_eq!(sum.to_field(&schema)?, Field::new("x", DataType::Int64));
+    let count = resolved_col("x")
+        .count(CountMode::All)
+        .alias("n")
+        .agg_filter(predicate.clone())?;
+    assert_eq!(count.to_field(&schema)?, Field::new("n", DataType::UInt64));
+
+    assert!(resolved_col("x").agg_list().agg_filter(predicate.clone()).is_err());
+    assert!(resolved_col("x").agg_filter(predicate).is_err());
+    Ok(())
+}
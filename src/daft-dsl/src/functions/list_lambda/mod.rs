@@ -0,0 +1,356 @@
+//! Higher-order list functions, which evaluate a lambda expression against the elements of lists.
+//!
+//! The body of a lambda refers to the current element with [`element`], and in a reduction to the
+//! value accumulated so far with [`accumulator`]. Bodies are evaluated by the record batch
+//! evaluator over the flattened child of the list, so a map or filter over a column costs a
+//! single vectorized evaluation no matter how many lists there are, and a reduction costs one per
+//! element of the longest list.
+use std::sync::Arc;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{ScalarFunction, ScalarUDF};
+use crate::{resolved_col, ExprRef};
+
+/// The name of the column that [`element`] refers to in the body of a lambda.
+pub const ELEMENT_NAME: &str = "__element__";
+
+/// The name of the column that [`accumulator`] refers to in the body of a reduction.
+pub const ACCUMULATOR_NAME: &str = "__accumulator__";
+
+/// The current element of a list, in the body of a lambda.
+pub fn element() -> ExprRef {
+    resolved_col(ELEMENT_NAME)
+}
+
+/// The value accumulated so far, in the body of a reduction.
+pub fn accumulator() -> ExprRef {
+    resolved_col(ACCUMULATOR_NAME)
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ListLambdaKind {
+    /// Replaces each element with the body evaluated on it.
+    Map,
+    /// Keeps the elements that the body is true for.
+    Filter,
+    /// Folds the elements of each list from left to right, starting with the first element.
+    Reduce,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ListLambda {
+    pub kind: ListLambdaKind,
+    pub body: ExprRef,
+}
+
+impl ListLambda {
+    fn inner_dtype(dtype: &DataType) -> DaftResult<&DataType> {
+        match dtype {
+            DataType::List(inner) | DataType::FixedSizeList(inner, _) => Ok(inner.as_ref()),
+            _ => Err(DaftError::TypeError(format!(
+                "Expected a list input to list lambda, received {dtype}"
+            ))),
+        }
+    }
+
+    fn body_dtype(&self, fields: Vec<Field>) -> DaftResult<DataType> {
+        self.body
+            .to_field(&Schema::new(fields))
+            .map(|field| field.dtype)
+            .map_err(|e| {
+                DaftError::ValueError(format!(
+                    "Failed to resolve the body of {}, which can only refer to element(){}: {e}",
+                    self.name(),
+                    if self.kind == ListLambdaKind::Reduce {
+                        " and accumulator()"
+                    } else {
+                        ""
+                    }
+                ))
+            })
+    }
+
+    /// Returns the type of the result of this lambda over a list of `input_dtype`.
+    pub fn output_dtype(&self, input_dtype: &DataType) -> DaftResult<DataType> {
+        let inner = Self::inner_dtype(input_dtype)?;
+        let element = Field::new(ELEMENT_NAME, inner.clone());
+        match self.kind {
+            ListLambdaKind::Map => {
+                let dtype = self.body_dtype(vec![element])?;
+                Ok(DataType::List(Box::new(dtype)))
+            }
+            ListLambdaKind::Filter => match self.body_dtype(vec![element])? {
+                DataType::Boolean => Ok(DataType::List(Box::new(inner.clone()))),
+                dtype => Err(DaftError::TypeError(format!(
+                    "Expected the body of list_filter to be a boolean, received {dtype}"
+                ))),
+            },
+            ListLambdaKind::Reduce => {
+                // the accumulator starts out as the first element, and may widen after one step
+                let first = self.body_dtype(vec![
+                    element.clone(),
+                    Field::new(ACCUMULATOR_NAME, inner.clone()),
+                ])?;
+                let second =
+                    self.body_dtype(vec![element, Field::new(ACCUMULATOR_NAME, first.clone())])?;
+                if first != second {
+                    return Err(DaftError::TypeError(format!(
+                        "The body of list_reduce must return the same type as its accumulator, but an accumulator of {first} produced {second}"
+                    )));
+                }
+                Ok(first)
+            }
+        }
+    }
+
+    /// Evaluates this lambda over `input`, using `eval_body` to evaluate the body over columns
+    /// named [`ELEMENT_NAME`] and, for reductions, [`ACCUMULATOR_NAME`].
+    pub fn evaluate_with(
+        &self,
+        input: &Series,
+        eval_body: impl Fn(Vec<Series>) -> DaftResult<Series>,
+    ) -> DaftResult<Series> {
+        let output_dtype = self.output_dtype(input.data_type())?;
+        let input = match input.data_type() {
+            DataType::FixedSizeList(inner, _) => {
+                input.cast(&DataType::List(Box::new(inner.as_ref().clone())))?
+            }
+            _ => input.clone(),
+        };
+        let list = input.list()?;
+        let eval_body = |columns: Vec<Series>| {
+            let len = columns[0].len();
+            let result = eval_body(columns)?;
+            match result.len() {
+                n if n == len => Ok(result),
+                1 => result.broadcast(len),
+                n => Err(DaftError::ComputeError(format!(
+                    "The body of {} must produce a value per element, but produced {n} values for {len} elements",
+                    self.name()
+                ))),
+            }
+        };
+
+        match self.kind {
+            ListLambdaKind::Map => {
+                let elements = list.flat_child.rename(ELEMENT_NAME);
+                let mapped = eval_body(vec![elements])?;
+                Ok(ListArray::new(
+                    Field::new(
+                        input.name(),
+                        DataType::List(Box::new(mapped.data_type().clone())),
+                    ),
+                    mapped,
+                    list.offsets().clone(),
+                    list.validity().cloned(),
+                )
+                .into_series())
+            }
+            ListLambdaKind::Filter => {
+                let elements = list.flat_child.rename(ELEMENT_NAME);
+                let mask = eval_body(vec![elements])?;
+                let mask = mask.bool()?;
+
+                let mut indices = Vec::new();
+                let mut offsets = Vec::with_capacity(list.len() + 1);
+                offsets.push(0i64);
+                for i in 0..list.len() {
+                    let (start, end) = list.offsets().start_end(i);
+                    indices.extend(
+                        (start..end)
+                            .filter(|&j| mask.get(j) == Some(true))
+                            .map(|j| j as u64),
+                    );
+                    offsets.push(indices.len() as i64);
+                }
+                let kept = list
+                    .flat_child
+                    .take(&UInt64Array::from(("indices", indices)).into_series())?;
+                Ok(ListArray::new(
+                    Field::new(input.name(), output_dtype),
+                    kept,
+                    arrow2::offset::OffsetsBuffer::try_from(offsets)?,
+                    list.validity().cloned(),
+                )
+                .into_series())
+            }
+            ListLambdaKind::Reduce => self.reduce(&input, list, &output_dtype, eval_body),
+        }
+    }
+
+    fn reduce(
+        &self,
+        input: &Series,
+        list: &ListArray,
+        output_dtype: &DataType,
+        eval_body: impl Fn(Vec<Series>) -> DaftResult<Series>,
+    ) -> DaftResult<Series> {
+        let take = |series: &Series, indices: Vec<u64>| {
+            series.take(&UInt64Array::from(("indices", indices)).into_series())
+        };
+        let bounds = (0..list.len())
+            .map(|i| {
+                if list.validity().is_none_or(|v| v.get_bit(i)) {
+                    list.offsets().start_end(i)
+                } else {
+                    (0, 0)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // the rows that still have elements left, and their accumulators
+        let mut active = (0..list.len())
+            .filter(|&i| bounds[i].1 > bounds[i].0)
+            .collect::<Vec<_>>();
+        let mut acc = take(
+            &list.flat_child,
+            active.iter().map(|&i| bounds[i].0 as u64).collect(),
+        )?
+        .cast(output_dtype)?;
+        let mut finished = Vec::new();
+        let mut finished_rows = Vec::new();
+
+        let mut step = 1;
+        while !active.is_empty() {
+            let (remaining, done): (Vec<_>, Vec<_>) =
+                (0..active.len()).partition(|&j| bounds[active[j]].1 - bounds[active[j]].0 > step);
+            if !done.is_empty() {
+                finished_rows.extend(done.iter().map(|&j| active[j]));
+                finished.push(take(&acc, done.iter().map(|&j| j as u64).collect())?);
+            }
+            if remaining.is_empty() {
+                break;
+            }
+            let accumulators = take(&acc, remaining.iter().map(|&j| j as u64).collect())?;
+            active = remaining.into_iter().map(|j| active[j]).collect();
+            let elements = take(
+                &list.flat_child,
+                active
+                    .iter()
+                    .map(|&i| (bounds[i].0 + step) as u64)
+                    .collect(),
+            )?;
+            acc = eval_body(vec![
+                elements.rename(ELEMENT_NAME),
+                accumulators.rename(ACCUMULATOR_NAME),
+            ])?
+            .cast(output_dtype)?;
+            step += 1;
+        }
+
+        // empty and null lists reduce to null
+        let mut positions = vec![finished_rows.len() as u64; list.len()];
+        for (position, row) in finished_rows.iter().enumerate() {
+            positions[*row] = position as u64;
+        }
+        finished.push(Series::full_null(input.name(), output_dtype, 1));
+        let finished = finished
+            .iter()
+            .map(|s| s.rename(input.name()))
+            .collect::<Vec<_>>();
+        let finished = Series::concat(&finished.iter().collect::<Vec<_>>())?;
+        take(&finished, positions)
+    }
+}
+
+#[typetag::serde]
+impl ScalarUDF for ListLambda {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        match self.kind {
+            ListLambdaKind::Map => "list_map",
+            ListLambdaKind::Filter => "list_filter",
+            ListLambdaKind::Reduce => "list_reduce",
+        }
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let field = input.to_field(schema)?;
+                Ok(Field::new(field.name, self.output_dtype(&field.dtype)?))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, _inputs: &[Series]) -> DaftResult<Series> {
+        Err(DaftError::ComputeError(format!(
+            "{} has to be evaluated with its body, by a record batch",
+            self.name()
+        )))
+    }
+}
+
+fn list_lambda(kind: ListLambdaKind, input: ExprRef, body: ExprRef) -> ExprRef {
+    ScalarFunction {
+        udf: Arc::new(ListLambda { kind, body }),
+        inputs: vec![input],
+    }
+    .into()
+}
+
+/// Evaluates `body` on each element of the lists in `input`.
+pub fn list_map(input: ExprRef, body: ExprRef) -> ExprRef {
+    list_lambda(ListLambdaKind::Map, input, body)
+}
+
+/// Keeps the elements of the lists in `input` that `body` is true for.
+pub fn list_filter(input: ExprRef, body: ExprRef) -> ExprRef {
+    list_lambda(ListLambdaKind::Filter, input, body)
+}
+
+/// Folds the elements of the lists in `input` with `body`, which combines the [`accumulator`] with
+/// the next [`element`]. Empty lists reduce to null.
+pub fn list_reduce(input: ExprRef, body: ExprRef) -> ExprRef {
+    list_lambda(ListLambdaKind::Reduce, input, body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lit;
+
+    #[test]
+    fn list_lambda_output_types() -> DaftResult<()> {
+        let schema = Schema::new(vec![Field::new(
+            "xs",
+            DataType::List(Box::new(DataType::Int32)),
+        )]);
+        let map = list_map(resolved_col("xs"), element().cast(&DataType::Utf8));
+        assert_eq!(
+            map.to_field(&schema)?,
+            Field::new("xs", DataType::List(Box::new(DataType::Utf8)))
+        );
+
+        let filter = list_filter(resolved_col("xs"), element().gt(lit(1)));
+        assert_eq!(
+            filter.to_field(&schema)?,
+            Field::new("xs", DataType::List(Box::new(DataType::Int32)))
+        );
+        assert!(list_filter(resolved_col("xs"), element())
+            .to_field(&schema)
+            .is_err());
+
+        let reduce = list_reduce(resolved_col("xs"), accumulator().add(element()));
+        assert_eq!(reduce.to_field(&schema)?, Field::new("xs", DataType::Int32));
+
+        // bodies can't refer to other columns
+        assert!(list_map(resolved_col("xs"), resolved_col("xs"))
+            .to_field(&schema)
+            .is_err());
+        assert!(list_map(resolved_col("xs"), accumulator())
+            .to_field(&schema)
+            .is_err());
+        Ok(())
+    }
+}
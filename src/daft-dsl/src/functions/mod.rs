@@ -1,4 +1,5 @@
 pub mod agg;
+pub mod list_lambda;
 pub mod map;
 pub mod partitioning;
 pub mod python;
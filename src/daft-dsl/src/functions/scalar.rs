@@ -44,6 +44,14 @@ pub trait ScalarUDF: Send + Sync + std::fmt::Debug {
     fn as_any(&self) -> &dyn Any;
     fn name(&self) -> &'static str;
     fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series>;
+    /// Evaluates the function over a batch of `num_rows` rows.
+    ///
+    /// Functions with inputs get the number of rows from them, so only functions without inputs,
+    /// such as `uuid`, need to override this.
+    fn evaluate_batch(&self, inputs: &[Series], num_rows: usize) -> DaftResult<Series> {
+        let _ = num_rows;
+        self.evaluate(inputs)
+    }
     fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field>;
 }
 
@@ -0,0 +1,83 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+
+use super::{super::FunctionEvaluator, StructExpr};
+use crate::{functions::FunctionExpr, ExprRef};
+
+pub(super) struct DropEvaluator {}
+
+impl FunctionEvaluator for DropEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "drop"
+    }
+
+    fn to_field(
+        &self,
+        inputs: &[ExprRef],
+        schema: &Schema,
+        expr: &FunctionExpr,
+    ) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+
+                match input_field.dtype {
+                    DataType::Struct(fields) => {
+                        let names = match expr {
+                            FunctionExpr::Struct(StructExpr::Drop(names)) => names,
+                            _ => panic!("Expected Struct Drop Expr, got {expr}"),
+                        };
+
+                        if let Some(name) =
+                            names.iter().find(|n| !fields.iter().any(|f| f.name == **n))
+                        {
+                            return Err(DaftError::FieldNotFound(format!(
+                                "Field {} not found in schema: {:?}",
+                                name,
+                                fields
+                                    .iter()
+                                    .map(|f| f.name.clone())
+                                    .collect::<Vec<String>>()
+                            )));
+                        }
+                        let fields = fields
+                            .into_iter()
+                            .filter(|f| !names.contains(&f.name))
+                            .collect::<Vec<_>>();
+                        if fields.is_empty() {
+                            return Err(DaftError::ValueError(
+                                "Cannot drop every field from a struct".to_string(),
+                            ));
+                        }
+                        Ok(Field::new(input_field.name, DataType::Struct(fields)))
+                    }
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected input to be a struct type, received: {}",
+                        input_field.dtype
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [input] => {
+                let names = match expr {
+                    FunctionExpr::Struct(StructExpr::Drop(names)) => names,
+                    _ => panic!("Expected Struct Drop Expr, got {expr}"),
+                };
+
+                input.struct_drop_fields(names)
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
@@ -1,7 +1,13 @@
+mod drop;
 mod get;
+mod rename;
+mod with_field;
 
+use drop::DropEvaluator;
 use get::GetEvaluator;
+use rename::RenameEvaluator;
 use serde::{Deserialize, Serialize};
+use with_field::WithFieldEvaluator;
 
 use super::FunctionEvaluator;
 use crate::{Expr, ExprRef};
@@ -9,6 +15,10 @@ use crate::{Expr, ExprRef};
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum StructExpr {
     Get(String),
+    WithField(String),
+    Drop(Vec<String>),
+    /// Renames fields by `(old name, new name)` pairs.
+    Rename(Vec<(String, String)>),
 }
 
 impl StructExpr {
@@ -16,6 +26,9 @@ impl StructExpr {
     pub fn get_evaluator(&self) -> &dyn FunctionEvaluator {
         match self {
             Self::Get(_) => &GetEvaluator {},
+            Self::WithField(_) => &WithFieldEvaluator {},
+            Self::Drop(_) => &DropEvaluator {},
+            Self::Rename(_) => &RenameEvaluator {},
         }
     }
 }
@@ -27,3 +40,30 @@ pub fn get(input: ExprRef, name: &str) -> ExprRef {
     }
     .into()
 }
+
+/// Sets the field called `name` of a struct to `value`, adding the field if it doesn't exist.
+pub fn with_field(input: ExprRef, name: &str, value: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Struct(StructExpr::WithField(name.to_string())),
+        inputs: vec![input, value],
+    }
+    .into()
+}
+
+/// Removes the fields in `names` from a struct.
+pub fn drop(input: ExprRef, names: Vec<String>) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Struct(StructExpr::Drop(names)),
+        inputs: vec![input],
+    }
+    .into()
+}
+
+/// Renames the fields of a struct by `(old name, new name)` pairs.
+pub fn rename(input: ExprRef, mapping: Vec<(String, String)>) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Struct(StructExpr::Rename(mapping)),
+        inputs: vec![input],
+    }
+    .into()
+}
@@ -0,0 +1,88 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use itertools::Itertools;
+
+use super::{super::FunctionEvaluator, StructExpr};
+use crate::{functions::FunctionExpr, ExprRef};
+
+pub(super) struct RenameEvaluator {}
+
+impl FunctionEvaluator for RenameEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "rename"
+    }
+
+    fn to_field(
+        &self,
+        inputs: &[ExprRef],
+        schema: &Schema,
+        expr: &FunctionExpr,
+    ) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+
+                match input_field.dtype {
+                    DataType::Struct(fields) => {
+                        let mapping = match expr {
+                            FunctionExpr::Struct(StructExpr::Rename(mapping)) => mapping,
+                            _ => panic!("Expected Struct Rename Expr, got {expr}"),
+                        };
+
+                        if let Some((name, _)) = mapping
+                            .iter()
+                            .find(|(old, _)| !fields.iter().any(|f| f.name == *old))
+                        {
+                            return Err(DaftError::FieldNotFound(format!(
+                                "Field {} not found in schema: {:?}",
+                                name,
+                                fields
+                                    .iter()
+                                    .map(|f| f.name.clone())
+                                    .collect::<Vec<String>>()
+                            )));
+                        }
+                        let fields = fields
+                            .into_iter()
+                            .map(|f| match mapping.iter().find(|(old, _)| *old == f.name) {
+                                Some((_, new)) => f.rename(new),
+                                None => f,
+                            })
+                            .collect::<Vec<_>>();
+                        if let Some(name) = fields.iter().map(|f| &f.name).duplicates().next() {
+                            return Err(DaftError::ValueError(format!(
+                                "Renaming struct fields would result in duplicate field {name}"
+                            )));
+                        }
+                        Ok(Field::new(input_field.name, DataType::Struct(fields)))
+                    }
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected input to be a struct type, received: {}",
+                        input_field.dtype
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [input] => {
+                let mapping = match expr {
+                    FunctionExpr::Struct(StructExpr::Rename(mapping)) => mapping,
+                    _ => panic!("Expected Struct Rename Expr, got {expr}"),
+                };
+
+                input.struct_rename_fields(mapping)
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
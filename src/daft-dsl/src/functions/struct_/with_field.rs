@@ -0,0 +1,68 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+
+use super::{super::FunctionEvaluator, StructExpr};
+use crate::{functions::FunctionExpr, ExprRef};
+
+pub(super) struct WithFieldEvaluator {}
+
+impl FunctionEvaluator for WithFieldEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "with_field"
+    }
+
+    fn to_field(
+        &self,
+        inputs: &[ExprRef],
+        schema: &Schema,
+        expr: &FunctionExpr,
+    ) -> DaftResult<Field> {
+        match inputs {
+            [input, value] => {
+                let input_field = input.to_field(schema)?;
+                let value_field = value.to_field(schema)?;
+
+                match input_field.dtype {
+                    DataType::Struct(mut fields) => {
+                        let name = match expr {
+                            FunctionExpr::Struct(StructExpr::WithField(name)) => name,
+                            _ => panic!("Expected Struct WithField Expr, got {expr}"),
+                        };
+
+                        let value_field = Field::new(name, value_field.dtype);
+                        match fields.iter().position(|f| f.name == *name) {
+                            Some(i) => fields[i] = value_field,
+                            None => fields.push(value_field),
+                        }
+                        Ok(Field::new(input_field.name, DataType::Struct(fields)))
+                    }
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected input to be a struct type, received: {}",
+                        input_field.dtype
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [input, value] => {
+                let name = match expr {
+                    FunctionExpr::Struct(StructExpr::WithField(name)) => name,
+                    _ => panic!("Expected Struct WithField Expr, got {expr}"),
+                };
+
+                input.struct_with_field(name, value)
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
@@ -1,8 +1,8 @@
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 use daft_core::{prelude::*, utils::supertype::try_get_supertype};
 use indexmap::IndexSet;
 
-use crate::{deduplicate_expr_names, ExprRef};
+use crate::{binary_op, deduplicate_expr_names, ExprRef, Operator};
 
 pub fn get_common_join_cols<'a>(
     left_schema: &'a SchemaRef,
@@ -95,3 +95,87 @@ pub fn normalize_join_keys(
 
     Ok((left_on, right_on))
 }
+
+/// An inequality `left op right` between an expression of the left side of a join and an
+/// expression of the right side, where `op` is one of `<`, `<=`, `>` or `>=`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RangeCondition {
+    pub left: ExprRef,
+    pub op: Operator,
+    pub right: ExprRef,
+}
+
+impl RangeCondition {
+    pub fn try_new(left: ExprRef, op: Operator, right: ExprRef) -> DaftResult<Self> {
+        if !matches!(
+            op,
+            Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq
+        ) {
+            return Err(DaftError::ValueError(format!(
+                "Expected a range join condition to be an inequality, received: {left} {op} {right}"
+            )));
+        }
+        Ok(Self { left, op, right })
+    }
+
+    /// The same condition with its sides swapped, so that `a < b` becomes `b > a`.
+    #[must_use]
+    pub fn flipped(&self) -> Self {
+        let op = match self.op {
+            Operator::Lt => Operator::Gt,
+            Operator::LtEq => Operator::GtEq,
+            Operator::Gt => Operator::Lt,
+            Operator::GtEq => Operator::LtEq,
+            op => op,
+        };
+        Self {
+            left: self.right.clone(),
+            op,
+            right: self.left.clone(),
+        }
+    }
+
+    /// The condition as a predicate over the columns of both sides.
+    pub fn to_expr(&self) -> ExprRef {
+        binary_op(self.op, self.left.clone(), self.right.clone())
+    }
+}
+
+impl std::fmt::Display for RangeCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.left, self.op, self.right)
+    }
+}
+
+/// Casts both sides of each range condition to the same type.
+pub fn normalize_range_conditions(
+    conditions: Vec<RangeCondition>,
+    left_schema: &Schema,
+    right_schema: &Schema,
+) -> DaftResult<Vec<RangeCondition>> {
+    conditions
+        .into_iter()
+        .map(
+            |RangeCondition {
+                 mut left,
+                 op,
+                 mut right,
+             }| {
+                let l_dtype = left.to_field(left_schema)?.dtype;
+                let r_dtype = right.to_field(right_schema)?.dtype;
+
+                let supertype = try_get_supertype(&l_dtype, &r_dtype)?;
+
+                if l_dtype != supertype {
+                    left = left.cast(&supertype);
+                }
+
+                if r_dtype != supertype {
+                    right = right.cast(&supertype);
+                }
+
+                Ok(RangeCondition { left, op, right })
+            },
+        )
+        .collect()
+}
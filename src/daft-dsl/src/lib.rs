@@ -18,8 +18,8 @@ pub use expr::{
     exprs_to_schema, has_agg, is_actor_pool_udf, is_partition_compatible, left_col, resolved_col,
     right_col, unresolved_col,
     window::{WindowBoundary, WindowFrame, WindowFrameType, WindowSpec},
-    AggExpr, ApproxPercentileParams, Column, Expr, ExprRef, Operator, PlanRef, ResolvedColumn,
-    SketchType, Subquery, SubqueryPlan, UnresolvedColumn, WindowExpr,
+    AggExpr, ApproxPercentileParams, CollectParams, Column, Expr, ExprRef, Operator, PlanRef,
+    ResolvedColumn, SketchType, Subquery, SubqueryPlan, UnresolvedColumn, WindowExpr,
 };
 pub use lit::{lit, literal_value, literals_to_series, null_lit, Literal, LiteralValue};
 #[cfg(feature = "python")]
@@ -38,11 +38,17 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_function(wrap_pyfunction!(python::resolved_col, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::lit, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::list_, parent)?)?;
+    parent.add_function(wrap_pyfunction!(python::element, parent)?)?;
+    parent.add_function(wrap_pyfunction!(python::accumulator, parent)?)?;
+    parent.add_function(wrap_pyfunction!(python::list_map, parent)?)?;
+    parent.add_function(wrap_pyfunction!(python::list_filter, parent)?)?;
+    parent.add_function(wrap_pyfunction!(python::list_reduce, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::date_lit, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::time_lit, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::timestamp_lit, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::duration_lit, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::interval_lit, parent)?)?;
+    parent.add_function(wrap_pyfunction!(python::interval_lit_from_str, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::decimal_lit, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::series_lit, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::udf, parent)?)?;
@@ -96,6 +96,13 @@ pub fn interval_lit(
     Ok(expr.into())
 }
 
+#[pyfunction]
+pub fn interval_lit_from_str(interval: &str) -> PyResult<PyExpr> {
+    let iv = interval.parse::<IntervalValue>()?;
+    let expr = Expr::Literal(LiteralValue::Interval(iv));
+    Ok(expr.into())
+}
+
 fn decimal_from_digits(digits: Vec<u8>, exp: i32) -> Option<(i128, usize)> {
     const MAX_ABS_DEC: i128 = 10_i128.pow(38) - 1;
     let mut v = 0_i128;
@@ -195,6 +202,31 @@ pub fn list_(items: Vec<PyExpr>) -> PyExpr {
     Expr::List(items.into_iter().map(|item| item.into()).collect()).into()
 }
 
+#[pyfunction]
+pub fn element() -> PyExpr {
+    crate::functions::list_lambda::element().into()
+}
+
+#[pyfunction]
+pub fn accumulator() -> PyExpr {
+    crate::functions::list_lambda::accumulator().into()
+}
+
+#[pyfunction]
+pub fn list_map(expr: PyExpr, body: PyExpr) -> PyExpr {
+    crate::functions::list_lambda::list_map(expr.into(), body.into()).into()
+}
+
+#[pyfunction]
+pub fn list_filter(expr: PyExpr, body: PyExpr) -> PyExpr {
+    crate::functions::list_lambda::list_filter(expr.into(), body.into()).into()
+}
+
+#[pyfunction]
+pub fn list_reduce(expr: PyExpr, body: PyExpr) -> PyExpr {
+    crate::functions::list_lambda::list_reduce(expr.into(), body.into()).into()
+}
+
 #[allow(clippy::too_many_arguments)]
 #[pyfunction(signature = (
     name,
@@ -309,6 +341,10 @@ impl PyExpr {
             .into())
     }
 
+    pub fn agg_filter(&self, predicate: &Self) -> PyResult<Self> {
+        Ok(self.expr.clone().agg_filter(predicate.expr.clone())?.into())
+    }
+
     pub fn count(&self, mode: CountMode) -> PyResult<Self> {
         Ok(self.expr.clone().count(mode).into())
     }
@@ -325,6 +361,10 @@ impl PyExpr {
         Ok(self.expr.clone().approx_count_distinct().into())
     }
 
+    pub fn approx_top_k(&self, k: usize) -> PyResult<Self> {
+        Ok(self.expr.clone().approx_top_k(k).into())
+    }
+
     pub fn approx_percentiles(&self, percentiles: ApproxPercentileInput) -> PyResult<Self> {
         let (percentiles, list_output) = match percentiles {
             ApproxPercentileInput::Single(p) => (vec![p], false),
@@ -387,6 +427,21 @@ impl PyExpr {
         Ok(self.expr.clone().agg_concat().into())
     }
 
+    #[pyo3(signature = (distinct, order_by=None, descending=false, limit=None))]
+    pub fn agg_collect(
+        &self,
+        distinct: bool,
+        order_by: Option<Self>,
+        descending: bool,
+        limit: Option<usize>,
+    ) -> PyResult<Self> {
+        Ok(self
+            .expr
+            .clone()
+            .agg_collect(distinct, order_by.map(|e| e.expr), descending, limit)
+            .into())
+    }
+
     pub fn __add__(&self, other: &Self) -> PyResult<Self> {
         Ok(crate::binary_op(crate::Operator::Plus, self.into(), other.expr.clone()).into())
     }
@@ -506,6 +561,21 @@ impl PyExpr {
         Ok(get(self.into(), name).into())
     }
 
+    pub fn struct_with_field(&self, name: &str, value: &Self) -> PyResult<Self> {
+        use crate::functions::struct_::with_field;
+        Ok(with_field(self.into(), name, value.into()).into())
+    }
+
+    pub fn struct_drop(&self, names: Vec<String>) -> PyResult<Self> {
+        use crate::functions::struct_::drop;
+        Ok(drop(self.into(), names).into())
+    }
+
+    pub fn struct_rename(&self, mapping: Vec<(String, String)>) -> PyResult<Self> {
+        use crate::functions::struct_::rename;
+        Ok(rename(self.into(), mapping).into())
+    }
+
     pub fn map_get(&self, key: &Self) -> PyResult<Self> {
         use crate::functions::map::get;
         Ok(get(self.into(), key.into()).into())
@@ -541,6 +611,15 @@ impl PyExpr {
         Ok(iceberg_truncate(self.into(), w).into())
     }
 
+    #[pyo3(signature = (offset, default=None))]
+    pub fn offset(&self, offset: isize, default: Option<Self>) -> PyResult<Self> {
+        Ok(self
+            .expr
+            .clone()
+            .offset(offset, default.map(|d| d.expr))
+            .into())
+    }
+
     pub fn over(&self, window_spec: &crate::expr::window::WindowSpec) -> PyResult<Self> {
         let window_expr = WindowExpr::try_from(self.expr.clone())?;
         Ok(Self {
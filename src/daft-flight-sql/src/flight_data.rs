@@ -0,0 +1,107 @@
+//! Conversions between Arrow chunks and the flight data that they're streamed as, in the Arrow IPC format.
+
+use arrow2::{
+    array::Array,
+    chunk::Chunk,
+    datatypes::{Field, Schema},
+    error::{Error, Result},
+    io::{
+        flight::{
+            default_ipc_fields, deserialize_message, deserialize_schemas, serialize_batch,
+            serialize_schema, serialize_schema_to_info, WriteOptions,
+        },
+        ipc::IpcField,
+    },
+};
+use arrow_flight::FlightData;
+
+pub type ArrowChunk = Chunk<Box<dyn Array>>;
+
+/// Encodes a schema as the first flight data of a stream.
+pub fn encode_schema(schema: &Schema, ipc_fields: &[IpcField]) -> FlightData {
+    let data = serialize_schema(schema, Some(ipc_fields));
+    FlightData {
+        data_header: data.data_header.into(),
+        ..Default::default()
+    }
+}
+
+/// Encodes a schema as it's described in flight infos and the results of prepared statements.
+pub fn encode_schema_info(schema: &Schema) -> Result<Vec<u8>> {
+    serialize_schema_to_info(schema, None)
+}
+
+/// Encodes a chunk as flight data, which are its dictionaries followed by its record batch.
+pub fn encode_chunk(chunk: &ArrowChunk, ipc_fields: &[IpcField]) -> Result<Vec<FlightData>> {
+    let (dictionaries, batch) =
+        serialize_batch(chunk, ipc_fields, &WriteOptions { compression: None })?;
+    Ok(dictionaries
+        .into_iter()
+        .chain(std::iter::once(batch))
+        .map(|data| FlightData {
+            data_header: data.data_header.into(),
+            data_body: data.data_body.into(),
+            ..Default::default()
+        })
+        .collect())
+}
+
+/// Encodes a schema and a chunk of it as a stream of flight data.
+pub fn encode_stream(schema: &Schema, chunk: &ArrowChunk) -> Result<Vec<FlightData>> {
+    let ipc_fields = default_ipc_fields(&schema.fields);
+    let mut stream = vec![encode_schema(schema, &ipc_fields)];
+    stream.extend(encode_chunk(chunk, &ipc_fields)?);
+    Ok(stream)
+}
+
+/// Decodes a stream of flight data, which starts with its schema, into the fields of the schema and its chunks.
+pub fn decode_stream(stream: &[FlightData]) -> Result<(Vec<Field>, Vec<ArrowChunk>)> {
+    let Some((first, rest)) = stream.split_first() else {
+        return Err(Error::OutOfSpec(
+            "A flight data stream must start with its schema".to_string(),
+        ));
+    };
+    let (schema, ipc_schema) = deserialize_schemas(&first.data_header)?;
+    let mut dictionaries = Default::default();
+    let mut chunks = vec![];
+    for data in rest {
+        let data = arrow_format::flight::data::FlightData {
+            data_header: data.data_header.to_vec(),
+            data_body: data.data_body.to_vec(),
+            ..Default::default()
+        };
+        if let Some(chunk) =
+            deserialize_message(&data, &schema.fields, &ipc_schema, &mut dictionaries)?
+        {
+            chunks.push(chunk);
+        }
+    }
+    Ok((schema.fields, chunks))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow2::{
+        array::{Int64Array, Utf8Array},
+        datatypes::DataType,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_stream_round_trip() -> Result<()> {
+        let schema = Schema::from(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ]);
+        let chunk = Chunk::new(vec![
+            Int64Array::from_slice([1, 2]).boxed(),
+            Utf8Array::<i32>::from([Some("a"), None]).boxed(),
+        ]);
+        let stream = encode_stream(&schema, &chunk)?;
+        let (fields, chunks) = decode_stream(&stream)?;
+        assert_eq!(fields, schema.fields);
+        assert_eq!(chunks, vec![chunk]);
+        Ok(())
+    }
+}
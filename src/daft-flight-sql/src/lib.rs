@@ -0,0 +1,106 @@
+//! A server of the [Arrow Flight SQL](https://arrow.apache.org/docs/format/FlightSql.html) protocol, which lets
+//! clients such as the ADBC and JDBC Flight SQL drivers run SQL queries against a Daft session and read their results
+//! as Arrow record batches.
+
+#[cfg_attr(not(feature = "python"), allow(dead_code))]
+mod flight_data;
+#[cfg(feature = "python")]
+mod metadata;
+#[cfg_attr(not(feature = "python"), allow(dead_code))]
+mod protocol;
+#[cfg(feature = "python")]
+mod service;
+
+#[cfg(feature = "python")]
+use arrow_flight::flight_service_server::FlightServiceServer;
+#[cfg(feature = "python")]
+use common_error::{DaftError, DaftResult};
+#[cfg(feature = "python")]
+use common_runtime::RuntimeTask;
+#[cfg(feature = "python")]
+use daft_session::Session;
+#[cfg(feature = "python")]
+use service::DaftFlightSqlService;
+#[cfg(feature = "python")]
+use tonic::transport::{server::TcpIncoming, Server};
+#[cfg(feature = "python")]
+use tracing::info;
+
+/// The handle of a running Flight SQL server, which shuts it down.
+#[cfg(feature = "python")]
+#[pyo3::pyclass(module = "daft.daft", name = "FlightSqlServerHandle")]
+pub struct FlightSqlServerHandle {
+    shutdown_signal: Option<tokio::sync::oneshot::Sender<()>>,
+    server_task: Option<RuntimeTask<DaftResult<()>>>,
+    port: u16,
+}
+
+#[cfg(feature = "python")]
+#[pyo3::pymethods]
+impl FlightSqlServerHandle {
+    /// Stops the server, once the requests that it's serving finish.
+    pub fn shutdown(&mut self) -> pyo3::PyResult<()> {
+        if let Some(shutdown_signal) = self.shutdown_signal.take() {
+            let _ = shutdown_signal.send(());
+        }
+        if let Some(server_task) = self.server_task.take() {
+            common_runtime::get_io_runtime(true).block_on_current_thread(server_task)??;
+        }
+        Ok(())
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+}
+
+/// Starts a Flight SQL server of a session on `host:port`, where a port of 0 picks any free port.
+#[cfg(feature = "python")]
+pub fn start(session: Session, host: &str, port: u16) -> DaftResult<FlightSqlServerHandle> {
+    let listener = std::net::TcpListener::bind((host, port))?;
+    let port = listener.local_addr()?.port();
+    listener.set_nonblocking(true)?;
+    info!("Daft Flight SQL server listening on {host}:{port}");
+
+    let (shutdown_signal, shutdown_receiver) = tokio::sync::oneshot::channel();
+    let service = DaftFlightSqlService::new(session);
+    let runtime = common_runtime::get_io_runtime(true);
+    let server_task = runtime.spawn(async move {
+        let listener = tokio::net::TcpListener::from_std(listener)?;
+        let incoming = TcpIncoming::from_listener(listener, true, None)
+            .map_err(|e| DaftError::InternalError(e.to_string()))?;
+        Server::builder()
+            .add_service(FlightServiceServer::new(service))
+            .serve_with_incoming_shutdown(incoming, async move {
+                let _ = shutdown_receiver.await;
+            })
+            .await
+            .map_err(|e| DaftError::InternalError(format!("Error serving Flight SQL server: {e}")))
+    });
+
+    Ok(FlightSqlServerHandle {
+        shutdown_signal: Some(shutdown_signal),
+        server_task: Some(server_task),
+        port,
+    })
+}
+
+#[cfg(feature = "python")]
+#[pyo3::pyfunction]
+#[pyo3(name = "flight_sql_start", signature = (session, host = "0.0.0.0", port = 0))]
+pub fn py_flight_sql_start(
+    session: &daft_session::python::PySession,
+    host: &str,
+    port: u16,
+) -> pyo3::PyResult<FlightSqlServerHandle> {
+    Ok(start(session.session().clone_ref(), host, port)?)
+}
+
+#[cfg(feature = "python")]
+pub fn register_modules(parent: &pyo3::Bound<pyo3::types::PyModule>) -> pyo3::PyResult<()> {
+    use pyo3::types::PyModuleMethods;
+
+    parent.add_function(pyo3::wrap_pyfunction!(py_flight_sql_start, parent)?)?;
+    parent.add_class::<FlightSqlServerHandle>()?;
+    Ok(())
+}
@@ -0,0 +1,154 @@
+//! The results of the metadata commands of Flight SQL, which describe the catalogs, namespaces and tables of a
+//! session. Namespaces are the schemas of Flight SQL, and temporary tables have neither a catalog nor a schema.
+
+use std::collections::BTreeSet;
+
+use arrow2::{
+    array::{Array, BinaryArray, Utf8Array},
+    chunk::Chunk,
+    datatypes::{DataType, Field, Schema},
+};
+use common_error::DaftResult;
+use daft_catalog::Identifier;
+use daft_session::Session;
+
+use crate::{
+    flight_data::encode_schema_info,
+    protocol::{matches_pattern, CommandGetDbSchemas, CommandGetTables},
+};
+
+const TABLE_TYPE: &str = "TABLE";
+const TEMPORARY_TABLE_TYPE: &str = "TEMPORARY TABLE";
+
+pub fn catalogs_schema() -> Schema {
+    Schema::from(vec![Field::new("catalog_name", DataType::Utf8, false)])
+}
+
+pub fn db_schemas_schema() -> Schema {
+    Schema::from(vec![
+        Field::new("catalog_name", DataType::Utf8, true),
+        Field::new("db_schema_name", DataType::Utf8, false),
+    ])
+}
+
+pub fn tables_schema(include_schema: bool) -> Schema {
+    let mut fields = vec![
+        Field::new("catalog_name", DataType::Utf8, true),
+        Field::new("db_schema_name", DataType::Utf8, true),
+        Field::new("table_name", DataType::Utf8, false),
+        Field::new("table_type", DataType::Utf8, false),
+    ];
+    if include_schema {
+        fields.push(Field::new("table_schema", DataType::Binary, false));
+    }
+    Schema::from(fields)
+}
+
+fn utf8<T: AsRef<str>>(values: impl IntoIterator<Item = Option<T>>) -> Box<dyn Array> {
+    Utf8Array::<i32>::from_iter(values).boxed()
+}
+
+fn matches_filter(pattern: Option<&String>, value: &str) -> bool {
+    pattern.is_none_or(|pattern| matches_pattern(pattern, value))
+}
+
+/// Whether the objects of a catalog are listed for a catalog filter, which is an empty string for the objects that
+/// aren't in any catalog.
+fn includes_catalog(filter: Option<&String>, catalog: Option<&str>) -> bool {
+    match filter {
+        None => true,
+        Some(filter) if filter.is_empty() => catalog.is_none(),
+        Some(filter) => catalog == Some(filter.as_str()),
+    }
+}
+
+/// The tables of a session, with the catalogs that they're in.
+fn list_tables(
+    sess: &Session,
+    catalog_filter: Option<&String>,
+) -> DaftResult<Vec<(Option<String>, Identifier)>> {
+    let mut tables = vec![];
+    for catalog_name in sess.list_catalogs(None)? {
+        if !includes_catalog(catalog_filter, Some(&catalog_name)) {
+            continue;
+        }
+        let catalog = sess.get_catalog(&catalog_name)?;
+        for ident in catalog.list_tables(None)? {
+            tables.push((Some(catalog_name.clone()), ident));
+        }
+    }
+    if includes_catalog(catalog_filter, None) {
+        for name in sess.list_tables(None)? {
+            tables.push((None, Identifier::simple(name)));
+        }
+    }
+    Ok(tables)
+}
+
+fn namespace(ident: &Identifier) -> Option<String> {
+    ident.qualifier().map(|qualifier| qualifier.join("."))
+}
+
+pub fn catalogs(sess: &Session) -> DaftResult<Chunk<Box<dyn Array>>> {
+    let names = sess.list_catalogs(None)?;
+    Ok(Chunk::new(vec![utf8(names.into_iter().map(Some))]))
+}
+
+pub fn db_schemas(sess: &Session, cmd: &CommandGetDbSchemas) -> DaftResult<Chunk<Box<dyn Array>>> {
+    let schemas = list_tables(sess, cmd.catalog.as_ref())?
+        .into_iter()
+        .filter_map(|(catalog, ident)| Some((catalog?, namespace(&ident)?)))
+        .filter(|(_, namespace)| matches_filter(cmd.db_schema_filter_pattern.as_ref(), namespace))
+        .collect::<BTreeSet<_>>();
+    let (catalogs, namespaces): (Vec<_>, Vec<_>) = schemas
+        .into_iter()
+        .map(|(catalog, namespace)| (Some(catalog), Some(namespace)))
+        .unzip();
+    Ok(Chunk::new(vec![utf8(catalogs), utf8(namespaces)]))
+}
+
+pub fn tables(sess: &Session, cmd: &CommandGetTables) -> DaftResult<Chunk<Box<dyn Array>>> {
+    let mut columns: [Vec<Option<String>>; 4] = Default::default();
+    let mut schemas = vec![];
+    for (catalog, ident) in list_tables(sess, cmd.catalog.as_ref())? {
+        let namespace = namespace(&ident);
+        let table_type = if catalog.is_some() {
+            TABLE_TYPE
+        } else {
+            TEMPORARY_TABLE_TYPE
+        };
+        if !(matches_filter(cmd.table_name_filter_pattern.as_ref(), ident.name())
+            && cmd.db_schema_filter_pattern.as_ref().is_none_or(|pattern| {
+                namespace
+                    .as_ref()
+                    .is_some_and(|ns| matches_pattern(pattern, ns))
+            })
+            && (cmd.table_types.is_empty() || cmd.table_types.iter().any(|t| t == table_type)))
+        {
+            continue;
+        }
+        if cmd.include_schema {
+            let schema = match &catalog {
+                Some(catalog) => sess
+                    .get_catalog(catalog)?
+                    .get_table(&ident)?
+                    .map(|table| table.get_schema()),
+                None => Some(sess.get_table(&ident)?.get_schema()),
+            };
+            // Tables that are dropped while they're listed are skipped.
+            let Some(schema) = schema else {
+                continue;
+            };
+            schemas.push(encode_schema_info(&schema.to_arrow()?)?);
+        }
+        columns[0].push(catalog);
+        columns[1].push(namespace);
+        columns[2].push(Some(ident.name().to_string()));
+        columns[3].push(Some(table_type.to_string()));
+    }
+    let mut arrays = columns.into_iter().map(utf8).collect::<Vec<_>>();
+    if cmd.include_schema {
+        arrays.push(BinaryArray::<i32>::from_iter_values(schemas.into_iter()).boxed());
+    }
+    Ok(Chunk::new(arrays))
+}
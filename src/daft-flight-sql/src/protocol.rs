@@ -0,0 +1,245 @@
+//! The messages of the Flight SQL protocol that the server handles, which clients send and receive wrapped in a
+//! protobuf `Any` in the commands of flight descriptors, tickets and actions.
+//!
+//! See <https://arrow.apache.org/docs/format/FlightSql.html> and `FlightSql.proto` for their definitions.
+
+use prost::{bytes::Bytes, Message};
+use prost_types::Any;
+
+const TYPE_URL_PREFIX: &str = "type.googleapis.com/arrow.flight.protocol.sql.";
+
+/// A message of the Flight SQL protocol, which is named in the type URL of the `Any` that wraps it.
+pub trait ProtocolMessage: Message + Default {
+    const NAME: &'static str;
+
+    fn type_url() -> String {
+        format!("{TYPE_URL_PREFIX}{}", Self::NAME)
+    }
+
+    /// Wraps the message in an `Any` and encodes it.
+    fn as_any_bytes(&self) -> Bytes {
+        Any {
+            type_url: Self::type_url(),
+            value: self.encode_to_vec(),
+        }
+        .encode_to_vec()
+        .into()
+    }
+}
+
+macro_rules! protocol_message {
+    ($name:ident) => {
+        impl ProtocolMessage for $name {
+            const NAME: &'static str = stringify!($name);
+        }
+    };
+}
+
+/// Executes a query, whose results are read with the ticket of the flight info of the command.
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct CommandStatementQuery {
+    #[prost(string, tag = "1")]
+    pub query: String,
+    #[prost(bytes = "bytes", optional, tag = "2")]
+    pub transaction_id: Option<Bytes>,
+}
+protocol_message!(CommandStatementQuery);
+
+/// The ticket of the results of a query.
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct TicketStatementQuery {
+    #[prost(bytes = "bytes", tag = "1")]
+    pub statement_handle: Bytes,
+}
+protocol_message!(TicketStatementQuery);
+
+/// Executes a prepared statement, with the parameters last bound to it.
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct CommandPreparedStatementQuery {
+    #[prost(bytes = "bytes", tag = "1")]
+    pub prepared_statement_handle: Bytes,
+}
+protocol_message!(CommandPreparedStatementQuery);
+
+/// Lists the catalogs of the session.
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct CommandGetCatalogs {}
+protocol_message!(CommandGetCatalogs);
+
+/// Lists the schemas, which are the namespaces of the catalogs of the session.
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct CommandGetDbSchemas {
+    #[prost(string, optional, tag = "1")]
+    pub catalog: Option<String>,
+    #[prost(string, optional, tag = "2")]
+    pub db_schema_filter_pattern: Option<String>,
+}
+protocol_message!(CommandGetDbSchemas);
+
+/// Lists the tables of the catalogs of the session, and its temporary tables.
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct CommandGetTables {
+    #[prost(string, optional, tag = "1")]
+    pub catalog: Option<String>,
+    #[prost(string, optional, tag = "2")]
+    pub db_schema_filter_pattern: Option<String>,
+    #[prost(string, optional, tag = "3")]
+    pub table_name_filter_pattern: Option<String>,
+    #[prost(string, repeated, tag = "4")]
+    pub table_types: Vec<String>,
+    #[prost(bool, tag = "5")]
+    pub include_schema: bool,
+}
+protocol_message!(CommandGetTables);
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct ActionCreatePreparedStatementRequest {
+    #[prost(string, tag = "1")]
+    pub query: String,
+    #[prost(bytes = "bytes", optional, tag = "2")]
+    pub transaction_id: Option<Bytes>,
+}
+protocol_message!(ActionCreatePreparedStatementRequest);
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct ActionCreatePreparedStatementResult {
+    #[prost(bytes = "bytes", tag = "1")]
+    pub prepared_statement_handle: Bytes,
+    #[prost(bytes = "bytes", tag = "2")]
+    pub dataset_schema: Bytes,
+    #[prost(bytes = "bytes", tag = "3")]
+    pub parameter_schema: Bytes,
+}
+protocol_message!(ActionCreatePreparedStatementResult);
+
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct ActionClosePreparedStatementRequest {
+    #[prost(bytes = "bytes", tag = "1")]
+    pub prepared_statement_handle: Bytes,
+}
+protocol_message!(ActionClosePreparedStatementRequest);
+
+/// The metadata of the result of binding parameters to a prepared statement, which isn't wrapped in an `Any`.
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct DoPutPreparedStatementResult {
+    #[prost(bytes = "bytes", optional, tag = "1")]
+    pub prepared_statement_handle: Option<Bytes>,
+}
+
+/// The commands of flight descriptors and tickets that the server handles.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    StatementQuery(CommandStatementQuery),
+    TicketStatementQuery(TicketStatementQuery),
+    PreparedStatementQuery(CommandPreparedStatementQuery),
+    GetCatalogs(CommandGetCatalogs),
+    GetDbSchemas(CommandGetDbSchemas),
+    GetTables(CommandGetTables),
+}
+
+/// Decodes the message wrapped in the `Any` of a command, which is `None` if it isn't a message of type `M`.
+pub fn unpack<M: ProtocolMessage>(any: &Any) -> Result<Option<M>, prost::DecodeError> {
+    if any.type_url == M::type_url() {
+        M::decode(any.value.as_slice()).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+impl Command {
+    pub fn decode(bytes: &[u8]) -> Result<Self, String> {
+        let any = Any::decode(bytes).map_err(|e| format!("Invalid Flight SQL command: {e}"))?;
+        Self::from_any(&any)
+            .map_err(|e| format!("Invalid Flight SQL command: {e}"))?
+            .ok_or_else(|| format!("Unsupported Flight SQL command: {}", any.type_url))
+    }
+
+    fn from_any(any: &Any) -> Result<Option<Self>, prost::DecodeError> {
+        Ok(if let Some(cmd) = unpack(any)? {
+            Some(Self::StatementQuery(cmd))
+        } else if let Some(cmd) = unpack(any)? {
+            Some(Self::TicketStatementQuery(cmd))
+        } else if let Some(cmd) = unpack(any)? {
+            Some(Self::PreparedStatementQuery(cmd))
+        } else if let Some(cmd) = unpack(any)? {
+            Some(Self::GetCatalogs(cmd))
+        } else if let Some(cmd) = unpack(any)? {
+            Some(Self::GetDbSchemas(cmd))
+        } else {
+            unpack(any)?.map(Self::GetTables)
+        })
+    }
+
+    pub fn as_any_bytes(&self) -> Bytes {
+        match self {
+            Self::StatementQuery(cmd) => cmd.as_any_bytes(),
+            Self::TicketStatementQuery(cmd) => cmd.as_any_bytes(),
+            Self::PreparedStatementQuery(cmd) => cmd.as_any_bytes(),
+            Self::GetCatalogs(cmd) => cmd.as_any_bytes(),
+            Self::GetDbSchemas(cmd) => cmd.as_any_bytes(),
+            Self::GetTables(cmd) => cmd.as_any_bytes(),
+        }
+    }
+}
+
+/// Whether a value matches a pattern of the metadata commands, in which `%` matches any characters, `_` matches a
+/// single character and `\` escapes them.
+pub fn matches_pattern(pattern: &str, value: &str) -> bool {
+    fn matches(pattern: &[char], value: &[char]) -> bool {
+        match pattern {
+            [] => value.is_empty(),
+            ['%', rest @ ..] => (0..=value.len()).any(|i| matches(rest, &value[i..])),
+            ['_', rest @ ..] => !value.is_empty() && matches(rest, &value[1..]),
+            ['\\', c, rest @ ..] | [c, rest @ ..] => {
+                value.first() == Some(c) && matches(rest, &value[1..])
+            }
+        }
+    }
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let value = value.chars().collect::<Vec<_>>();
+    matches(&pattern, &value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_round_trip() {
+        let cmd = Command::GetTables(CommandGetTables {
+            catalog: Some("default".to_string()),
+            table_name_filter_pattern: Some("t%".to_string()),
+            include_schema: true,
+            ..Default::default()
+        });
+        assert_eq!(Command::decode(&cmd.as_any_bytes()).unwrap(), cmd);
+
+        // The query of `CommandStatementQuery { query: "SELECT 1" }`, as encoded by other implementations.
+        let mut bytes = vec![0x0a, 67];
+        bytes.extend(b"type.googleapis.com/arrow.flight.protocol.sql.CommandStatementQuery");
+        bytes.extend([0x12, 10, 0x0a, 8]);
+        bytes.extend(b"SELECT 1");
+        assert_eq!(
+            Command::decode(&bytes).unwrap(),
+            Command::StatementQuery(CommandStatementQuery {
+                query: "SELECT 1".to_string(),
+                transaction_id: None,
+            })
+        );
+
+        let err = Command::decode(&CommandStatementQuery::default().encode_to_vec());
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_matches_pattern() {
+        assert!(matches_pattern("%", ""));
+        assert!(matches_pattern("t%", "trips"));
+        assert!(matches_pattern("_rips", "trips"));
+        assert!(matches_pattern("%ip%", "trips"));
+        assert!(!matches_pattern("t_", "trips"));
+        assert!(!matches_pattern("trip", "trips"));
+        assert!(matches_pattern("a\\_b", "a_b"));
+        assert!(!matches_pattern("a\\_b", "acb"));
+    }
+}
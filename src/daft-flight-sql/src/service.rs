@@ -0,0 +1,459 @@
+use std::{collections::HashMap, pin::Pin, sync::Arc};
+
+use arrow2::{array::Array, chunk::Chunk, datatypes::Schema, io::flight::default_ipc_fields};
+use arrow_flight::{
+    flight_service_server::FlightService, Action, ActionType, Criteria, Empty, FlightData,
+    FlightDescriptor, FlightEndpoint, FlightInfo, HandshakeRequest, HandshakeResponse, PollInfo,
+    PutResult, SchemaResult, Ticket,
+};
+use common_error::{DaftError, DaftResult};
+use daft_context::get_context;
+use daft_core::{prelude::DataType, series::Series};
+use daft_dsl::LiteralValue;
+use daft_logical_plan::{LogicalPlan, LogicalPlanBuilder};
+use daft_session::Session;
+use daft_sql::{execute_statement, SQLParams};
+use dashmap::DashMap;
+use futures::{stream, Stream, TryStreamExt};
+use prost::{bytes::Bytes, Message};
+use prost_types::Any;
+use pyo3::Python;
+use tonic::{
+    codegen::tokio_stream::wrappers::ReceiverStream, Request, Response, Status, Streaming,
+};
+use tracing::debug;
+
+use crate::{
+    flight_data::{decode_stream, encode_chunk, encode_schema, encode_schema_info, encode_stream},
+    metadata,
+    protocol::{
+        unpack, ActionClosePreparedStatementRequest, ActionCreatePreparedStatementRequest,
+        ActionCreatePreparedStatementResult, Command, CommandPreparedStatementQuery,
+        DoPutPreparedStatementResult, ProtocolMessage, TicketStatementQuery,
+    },
+};
+
+const CREATE_PREPARED_STATEMENT: &str = "CreatePreparedStatement";
+const CLOSE_PREPARED_STATEMENT: &str = "ClosePreparedStatement";
+
+type BoxStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send + 'static>>;
+
+/// A statement that is prepared by a client, with the parameters that were last bound to it.
+struct PreparedStatement {
+    query: String,
+    params: SQLParams,
+}
+
+/// Serves the queries of a session over Flight SQL.
+///
+/// Queries are planned when their flight info is requested, and run when their results are read with its ticket, which
+/// can only be read once.
+pub struct DaftFlightSqlService {
+    session: Session,
+    /// The plans of the statements whose results haven't been read yet, which are `None` for statements without
+    /// results, such as `CREATE TABLE`.
+    statements: DashMap<Bytes, Option<Arc<LogicalPlan>>>,
+    prepared_statements: DashMap<Bytes, PreparedStatement>,
+}
+
+fn new_handle() -> Bytes {
+    uuid::Uuid::new_v4().to_string().into()
+}
+
+fn invalid_argument(e: impl ToString) -> Status {
+    Status::invalid_argument(e.to_string())
+}
+
+fn internal(e: impl ToString) -> Status {
+    Status::internal(e.to_string())
+}
+
+fn flight_info(
+    descriptor: FlightDescriptor,
+    schema: &Schema,
+    ticket: Bytes,
+) -> Result<FlightInfo, Status> {
+    Ok(FlightInfo {
+        schema: encode_schema_info(schema).map_err(internal)?.into(),
+        flight_descriptor: Some(descriptor),
+        endpoint: vec![FlightEndpoint {
+            ticket: Some(Ticket { ticket }),
+            ..Default::default()
+        }],
+        total_records: -1,
+        total_bytes: -1,
+        ..Default::default()
+    })
+}
+
+fn plan_schema(plan: Option<&Arc<LogicalPlan>>) -> DaftResult<Schema> {
+    match plan {
+        Some(plan) => plan.schema().to_arrow(),
+        None => Ok(Schema::default()),
+    }
+}
+
+/// Converts the value of a parameter, which is the first row of its column in the batch bound to a prepared statement,
+/// to a literal.
+fn param_to_literal(name: &str, array: Box<dyn Array>) -> DaftResult<LiteralValue> {
+    let series = Series::try_from((name, array.sliced(0, 1)))?;
+    let dtype = series.data_type().clone();
+    let value = match &dtype {
+        DataType::Null => None,
+        DataType::Boolean => series.bool()?.get(0).map(LiteralValue::Boolean),
+        DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => series
+            .cast(&DataType::Int64)?
+            .i64()?
+            .get(0)
+            .map(LiteralValue::Int64),
+        DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => series
+            .cast(&DataType::UInt64)?
+            .u64()?
+            .get(0)
+            .map(LiteralValue::UInt64),
+        DataType::Float32 | DataType::Float64 => series
+            .cast(&DataType::Float64)?
+            .f64()?
+            .get(0)
+            .map(LiteralValue::Float64),
+        DataType::Utf8 => series
+            .utf8()?
+            .get(0)
+            .map(|v| LiteralValue::Utf8(v.to_string())),
+        DataType::Binary => series
+            .binary()?
+            .get(0)
+            .map(|v| LiteralValue::Binary(v.to_vec())),
+        DataType::Date => series.date()?.physical.get(0).map(LiteralValue::Date),
+        _ => {
+            return Err(DaftError::ValueError(format!(
+                "Parameters of type {dtype} are not supported, found in parameter {name}"
+            )))
+        }
+    };
+    Ok(value.unwrap_or(LiteralValue::Null))
+}
+
+impl DaftFlightSqlService {
+    pub fn new(session: Session) -> Self {
+        Self {
+            session,
+            statements: DashMap::new(),
+            prepared_statements: DashMap::new(),
+        }
+    }
+
+    /// Plans a statement, and returns the flight info of its results.
+    fn plan_statement(
+        &self,
+        descriptor: FlightDescriptor,
+        query: &str,
+        params: SQLParams,
+    ) -> Result<FlightInfo, Status> {
+        let plan =
+            execute_statement(self.session.clone_ref(), query, params).map_err(invalid_argument)?;
+        let schema = plan_schema(plan.as_ref()).map_err(internal)?;
+        let handle = new_handle();
+        self.statements.insert(handle.clone(), plan);
+        let ticket = TicketStatementQuery {
+            statement_handle: handle,
+        };
+        flight_info(descriptor, &schema, ticket.as_any_bytes())
+    }
+
+    /// Runs the plan of a statement, streaming the flight data of its schema and then of its results.
+    fn run_statement(
+        &self,
+        plan: Option<Arc<LogicalPlan>>,
+    ) -> Result<BoxStream<FlightData>, Status> {
+        let schema = plan_schema(plan.as_ref()).map_err(internal)?;
+        let ipc_fields = default_ipc_fields(&schema.fields);
+        let schema_data = encode_schema(&schema, &ipc_fields);
+        let Some(plan) = plan else {
+            return Ok(Box::pin(stream::iter([Ok(schema_data)])));
+        };
+
+        let runner = get_context().get_or_create_runner().map_err(internal)?;
+        let (tx, rx) = tokio::sync::mpsc::channel(1);
+        tokio::task::spawn_blocking(move || {
+            let run = || -> DaftResult<()> {
+                if tx.blocking_send(Ok(schema_data)).is_err() {
+                    return Ok(());
+                }
+                let builder = LogicalPlanBuilder::new(plan, None);
+                let partitions = Python::with_gil(|py| runner.run_iter_tables(py, builder, None))?;
+                for partition in partitions {
+                    for batch in partition?.get_tables()?.iter() {
+                        let chunk = Chunk::new(batch.get_inner_arrow_arrays().collect());
+                        for data in encode_chunk(&chunk, &ipc_fields)? {
+                            if tx.blocking_send(Ok(data)).is_err() {
+                                return Ok(());
+                            }
+                        }
+                    }
+                }
+                Ok(())
+            };
+            if let Err(e) = run() {
+                let _ = tx.blocking_send(Err(internal(e)));
+            }
+        });
+        Ok(Box::pin(ReceiverStream::new(rx)))
+    }
+
+    fn create_prepared_statement(
+        &self,
+        request: ActionCreatePreparedStatementRequest,
+    ) -> Result<ActionCreatePreparedStatementResult, Status> {
+        let handle = new_handle();
+        self.prepared_statements.insert(
+            handle.clone(),
+            PreparedStatement {
+                query: request.query,
+                params: SQLParams::default(),
+            },
+        );
+        Ok(ActionCreatePreparedStatementResult {
+            prepared_statement_handle: handle,
+            // Statements aren't planned until they're executed, as planning runs DDL statements.
+            dataset_schema: Bytes::new(),
+            parameter_schema: Bytes::new(),
+        })
+    }
+
+    /// Binds the first row of a batch to the parameters of a prepared statement, by position and by name.
+    fn bind_parameters(
+        &self,
+        cmd: CommandPreparedStatementQuery,
+        stream: &[FlightData],
+    ) -> Result<PutResult, Status> {
+        let (fields, chunks) = decode_stream(stream).map_err(invalid_argument)?;
+        let chunk = chunks
+            .into_iter()
+            .find(|chunk| !chunk.is_empty())
+            .ok_or_else(|| {
+                Status::invalid_argument("No parameters were bound to the prepared statement")
+            })?;
+        let mut positional = vec![];
+        let mut named = HashMap::new();
+        for (field, array) in fields.iter().zip(chunk.into_arrays()) {
+            let value = param_to_literal(&field.name, array).map_err(invalid_argument)?;
+            positional.push(value.clone());
+            named.insert(field.name.clone(), value);
+        }
+
+        let mut prepared = self
+            .prepared_statements
+            .get_mut(&cmd.prepared_statement_handle)
+            .ok_or_else(|| Status::not_found("The prepared statement was not found"))?;
+        prepared.params = SQLParams { positional, named };
+        let result = DoPutPreparedStatementResult {
+            prepared_statement_handle: Some(cmd.prepared_statement_handle),
+        };
+        Ok(PutResult {
+            app_metadata: result.encode_to_vec().into(),
+        })
+    }
+}
+
+#[tonic::async_trait]
+impl FlightService for DaftFlightSqlService {
+    type HandshakeStream = BoxStream<HandshakeResponse>;
+    type ListFlightsStream = BoxStream<FlightInfo>;
+    type DoGetStream = BoxStream<FlightData>;
+    type DoPutStream = BoxStream<PutResult>;
+    type DoExchangeStream = BoxStream<FlightData>;
+    type DoActionStream = BoxStream<arrow_flight::Result>;
+    type ListActionsStream = BoxStream<ActionType>;
+
+    async fn handshake(
+        &self,
+        _request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        // Clients aren't authenticated.
+        let response = HandshakeResponse::default();
+        Ok(Response::new(Box::pin(stream::iter([Ok(response)]))))
+    }
+
+    async fn list_flights(
+        &self,
+        _request: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        Err(Status::unimplemented("ListFlights is not supported"))
+    }
+
+    async fn get_flight_info(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let descriptor = request.into_inner();
+        let cmd = Command::decode(&descriptor.cmd).map_err(invalid_argument)?;
+        debug!("Getting the flight info of {cmd:?}");
+        let ticket = cmd.as_any_bytes();
+        let info = match cmd {
+            Command::StatementQuery(cmd) => {
+                self.plan_statement(descriptor, &cmd.query, SQLParams::default())?
+            }
+            Command::PreparedStatementQuery(cmd) => {
+                let (query, params) = self
+                    .prepared_statements
+                    .get(&cmd.prepared_statement_handle)
+                    .map(|prepared| (prepared.query.clone(), prepared.params.clone()))
+                    .ok_or_else(|| Status::not_found("The prepared statement was not found"))?;
+                self.plan_statement(descriptor, &query, params)?
+            }
+            Command::GetCatalogs(_) => {
+                flight_info(descriptor, &metadata::catalogs_schema(), ticket)?
+            }
+            Command::GetDbSchemas(_) => {
+                flight_info(descriptor, &metadata::db_schemas_schema(), ticket)?
+            }
+            Command::GetTables(cmd) => flight_info(
+                descriptor,
+                &metadata::tables_schema(cmd.include_schema),
+                ticket,
+            )?,
+            Command::TicketStatementQuery(_) => {
+                return Err(Status::invalid_argument("Tickets are not commands"))
+            }
+        };
+        Ok(Response::new(info))
+    }
+
+    async fn poll_flight_info(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<PollInfo>, Status> {
+        Err(Status::unimplemented("PollFlightInfo is not supported"))
+    }
+
+    async fn get_schema(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        Err(Status::unimplemented("GetSchema is not supported"))
+    }
+
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> Result<Response<Self::DoGetStream>, Status> {
+        let cmd = Command::decode(&request.into_inner().ticket).map_err(invalid_argument)?;
+        let (schema, chunk) = match cmd {
+            Command::TicketStatementQuery(ticket) => {
+                let (_, plan) = self
+                    .statements
+                    .remove(&ticket.statement_handle)
+                    .ok_or_else(|| {
+                        Status::not_found(
+                            "The statement was not found, or its results were already read",
+                        )
+                    })?;
+                return Ok(Response::new(self.run_statement(plan)?));
+            }
+            Command::GetCatalogs(_) => (
+                metadata::catalogs_schema(),
+                metadata::catalogs(&self.session),
+            ),
+            Command::GetDbSchemas(cmd) => (
+                metadata::db_schemas_schema(),
+                metadata::db_schemas(&self.session, &cmd),
+            ),
+            Command::GetTables(cmd) => (
+                metadata::tables_schema(cmd.include_schema),
+                metadata::tables(&self.session, &cmd),
+            ),
+            Command::StatementQuery(_) | Command::PreparedStatementQuery(_) => {
+                return Err(Status::invalid_argument("Commands are not tickets"))
+            }
+        };
+        let stream = encode_stream(&schema, &chunk.map_err(internal)?).map_err(internal)?;
+        Ok(Response::new(Box::pin(stream::iter(
+            stream.into_iter().map(Ok),
+        ))))
+    }
+
+    async fn do_put(
+        &self,
+        request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        let stream: Vec<FlightData> = request.into_inner().try_collect().await?;
+        let descriptor = stream
+            .first()
+            .and_then(|data| data.flight_descriptor.as_ref())
+            .ok_or_else(|| {
+                Status::invalid_argument("The flight descriptor of the stream is missing")
+            })?;
+        let result = match Command::decode(&descriptor.cmd).map_err(invalid_argument)? {
+            Command::PreparedStatementQuery(cmd) => self.bind_parameters(cmd, &stream)?,
+            _ => {
+                return Err(Status::unimplemented(
+                    "Only the parameters of prepared statements can be put",
+                ))
+            }
+        };
+        Ok(Response::new(Box::pin(stream::iter([Ok(result)]))))
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        Err(Status::unimplemented("DoExchange is not supported"))
+    }
+
+    async fn do_action(
+        &self,
+        request: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        let action = request.into_inner();
+        let any = Any::decode(action.body).map_err(invalid_argument)?;
+        let body = match action.r#type.as_str() {
+            CREATE_PREPARED_STATEMENT => {
+                let request = unpack::<ActionCreatePreparedStatementRequest>(&any)
+                    .map_err(invalid_argument)?
+                    .ok_or_else(|| {
+                        Status::invalid_argument("Invalid CreatePreparedStatement request")
+                    })?;
+                self.create_prepared_statement(request)?.as_any_bytes()
+            }
+            CLOSE_PREPARED_STATEMENT => {
+                let request = unpack::<ActionClosePreparedStatementRequest>(&any)
+                    .map_err(invalid_argument)?
+                    .ok_or_else(|| {
+                        Status::invalid_argument("Invalid ClosePreparedStatement request")
+                    })?;
+                self.prepared_statements
+                    .remove(&request.prepared_statement_handle);
+                return Ok(Response::new(Box::pin(stream::empty())));
+            }
+            other => {
+                return Err(Status::unimplemented(format!(
+                    "Action {other} is not supported"
+                )))
+            }
+        };
+        let result = arrow_flight::Result { body };
+        Ok(Response::new(Box::pin(stream::iter([Ok(result)]))))
+    }
+
+    async fn list_actions(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        let actions = [
+            (
+                CREATE_PREPARED_STATEMENT,
+                "Prepares a statement, whose parameters are bound with DoPut.",
+            ),
+            (CLOSE_PREPARED_STATEMENT, "Closes a prepared statement."),
+        ]
+        .map(|(name, description)| {
+            Ok(ActionType {
+                r#type: name.to_string(),
+                description: description.to_string(),
+            })
+        });
+        Ok(Response::new(Box::pin(stream::iter(actions))))
+    }
+}
@@ -4,7 +4,7 @@ use daft_core::prelude::*;
 use daft_dsl::{functions::ScalarUDF, ExprRef};
 use serde::{Deserialize, Serialize};
 
-use crate::json_query_series;
+use crate::{json_extract_series, json_query_series};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct JsonQuery {
@@ -48,3 +48,50 @@ impl ScalarUDF for JsonQuery {
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct JsonExtract {
+    pub path: String,
+    pub dtype: Option<DataType>,
+}
+
+#[typetag::serde]
+impl ScalarUDF for JsonExtract {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+    fn name(&self) -> &'static str {
+        "json_extract"
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [input] => json_extract_series(input, &self.path, self.dtype.as_ref()),
+
+            _ => Err(DaftError::TypeError(
+                "Json extract expects a single argument".to_string(),
+            )),
+        }
+    }
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+                match input_field.dtype {
+                    DataType::Utf8 => Ok(Field::new(
+                        input_field.name,
+                        self.dtype.clone().unwrap_or(DataType::Utf8),
+                    )),
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected input to be a string type, received: {}",
+                        input_field.dtype
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
@@ -1,16 +1,18 @@
 mod expr;
+mod path;
 
 use std::sync::{LazyLock, Mutex};
 
 use common_error::{DaftError, DaftResult};
 use daft_core::{
-    prelude::{AsArrow, DataType, Utf8Array},
-    series::Series,
+    prelude::{AsArrow, BooleanArray, DataType, Utf8Array},
+    series::{IntoSeries, Series},
 };
 use daft_dsl::{functions::ScalarFunction, ExprRef};
-use expr::JsonQuery;
+use expr::{JsonExtract, JsonQuery};
 use itertools::Itertools;
 use jaq_interpret::{Ctx, Filter, FilterT, ParseCtx, RcIter};
+use path::JsonPath;
 use serde_json::Value;
 
 fn setup_parse_ctx() -> ParseCtx {
@@ -86,7 +88,7 @@ pub fn json_query_series(s: &Series, query: &str) -> DaftResult<Series> {
     match s.data_type() {
         DataType::Utf8 => {
             let arr = s.utf8()?;
-            json_query_impl(arr, query).map(daft_core::series::IntoSeries::into_series)
+            json_query_impl(arr, query).map(IntoSeries::into_series)
         }
         dt => Err(DaftError::TypeError(format!(
             "json query not implemented for {dt}"
@@ -115,6 +117,83 @@ pub fn json_query(input: ExprRef, query: &str) -> ExprRef {
     .into()
 }
 
+fn json_extract_impl(arr: &Utf8Array, path: &str) -> DaftResult<Utf8Array> {
+    let path = JsonPath::parse(path)?;
+    let values = arr
+        .as_arrow()
+        .iter()
+        .map(|opt| {
+            opt.map_or(Ok(None), |s| {
+                let json = serde_json::from_str::<Value>(s)?;
+                let matches = path.evaluate(&json);
+                Ok(if path.is_multi() {
+                    (!matches.is_empty())
+                        .then(|| Value::from_iter(matches.into_iter().cloned()).to_string())
+                } else {
+                    match matches.first() {
+                        None | Some(Value::Null) => None,
+                        Some(Value::String(s)) => Some(s.clone()),
+                        Some(value) => Some(value.to_string()),
+                    }
+                })
+            })
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    Ok(Utf8Array::from_iter(arr.name(), values.into_iter()))
+}
+
+pub fn json_extract_series(s: &Series, path: &str, dtype: Option<&DataType>) -> DaftResult<Series> {
+    let arr = match s.data_type() {
+        DataType::Utf8 => s.utf8()?,
+        dt => {
+            return Err(DaftError::TypeError(format!(
+                "json extract not implemented for {dt}"
+            )))
+        }
+    };
+    let extracted = json_extract_impl(arr, path)?;
+    match dtype {
+        None | Some(DataType::Utf8) => Ok(extracted.into_series()),
+        // strings can't be cast to booleans, so JSON booleans are parsed here
+        Some(DataType::Boolean) => {
+            let values = extracted
+                .as_arrow()
+                .iter()
+                .map(|v| match v {
+                    None => Ok(None),
+                    Some("true") => Ok(Some(true)),
+                    Some("false") => Ok(Some(false)),
+                    Some(v) => Err(DaftError::ComputeError(format!(
+                        "Cannot extract {v} at {path} as a boolean"
+                    ))),
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            Ok(BooleanArray::from_iter(arr.name(), values.into_iter()).into_series())
+        }
+        Some(dtype) => extracted.into_series().cast(dtype),
+    }
+}
+
+/// Extracts the value at a JSONPath, such as `$.a.b[0]`, or a JSON pointer, such as `/a/b/0`,
+/// from a column of JSON strings.
+///
+/// String values are returned without their quotes and other values as JSON. Paths with
+/// wildcards or descendant segments return a JSON array of all the values they match. When
+/// `dtype` is given, the values are cast to it.
+#[must_use]
+pub fn json_extract(input: ExprRef, path: &str, dtype: Option<DataType>) -> ExprRef {
+    ScalarFunction::new(
+        JsonExtract {
+            path: path.to_string(),
+            dtype,
+        },
+        vec![input],
+    )
+    .into()
+}
+
+#[cfg(feature = "python")]
+use daft_core::python::PyDataType;
 #[cfg(feature = "python")]
 use {
     daft_dsl::python::PyExpr,
@@ -128,9 +207,17 @@ pub fn py_json_query(expr: PyExpr, query: &str) -> PyResult<PyExpr> {
     Ok(json_query(expr.into(), query).into())
 }
 
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "json_extract", signature = (expr, path, dtype=None))]
+pub fn py_json_extract(expr: PyExpr, path: &str, dtype: Option<PyDataType>) -> PyResult<PyExpr> {
+    Ok(json_extract(expr.into(), path, dtype.map(|d| d.dtype)).into())
+}
+
 #[cfg(feature = "python")]
 pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_function(wrap_pyfunction!(py_json_query, parent)?)?;
+    parent.add_function(wrap_pyfunction!(py_json_extract, parent)?)?;
     Ok(())
 }
 
@@ -158,4 +245,49 @@ mod tests {
         assert_eq!(result.as_arrow().value(2), "3");
         Ok(())
     }
+
+    #[test]
+    fn test_json_extract() -> DaftResult<()> {
+        let data = Utf8Array::from_iter(
+            "data",
+            vec![
+                Some(r#"{"a": {"b": "x", "c": [1, 2]}}"#),
+                Some(r#"{"a": {"b": 2, "c": []}}"#),
+                Some(r#"{"a": null}"#),
+                None,
+            ]
+            .into_iter(),
+        )
+        .into_series();
+        let strings = |s: Series| -> DaftResult<Vec<Option<String>>> {
+            Ok(s.utf8()?
+                .into_iter()
+                .map(|v| v.map(str::to_string))
+                .collect())
+        };
+        assert_eq!(
+            strings(json_extract_series(&data, "$.a.b", None)?)?,
+            vec![Some("x".into()), Some("2".into()), None, None]
+        );
+        assert_eq!(
+            strings(json_extract_series(&data, "$.a.c", None)?)?,
+            vec![Some("[1,2]".into()), Some("[]".into()), None, None]
+        );
+        assert_eq!(
+            strings(json_extract_series(&data, "$.a.c[*]", None)?)?,
+            vec![Some("[1,2]".into()), None, None, None]
+        );
+        let typed = json_extract_series(&data, "/a/c/0", Some(&DataType::Int64))?;
+        assert_eq!(typed.data_type(), &DataType::Int64);
+        assert_eq!(
+            typed
+                .i64()?
+                .into_iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(1), None, None, None]
+        );
+        assert!(json_extract_series(&data, "a.b", None).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,208 @@
+//! A small JSONPath evaluator for extracting values from JSON strings.
+//!
+//! The supported syntax is the subset of JSONPath that SQL engines commonly accept:
+//! `$` for the root, `.name` and `['name']` for members, `[n]` for array elements (negative
+//! indices count from the end), `[*]` and `.*` for every child, and `..name` for every
+//! descendant called `name`. Paths starting with `/` are treated as JSON pointers
+//! (RFC 6901) instead.
+use common_error::{DaftError, DaftResult};
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Segment {
+    Member(String),
+    Index(i64),
+    Wildcard,
+    Descendant(String),
+}
+
+/// A parsed JSONPath or JSON pointer, which is compiled once and evaluated against every row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum JsonPath {
+    Path(Vec<Segment>),
+    Pointer(String),
+}
+
+impl JsonPath {
+    pub(crate) fn parse(path: &str) -> DaftResult<Self> {
+        if path.is_empty() || path.starts_with('/') {
+            return Ok(Self::Pointer(path.to_string()));
+        }
+        let err = |msg: &str| DaftError::ValueError(format!("Invalid JSONPath ({path}): {msg}"));
+        let Some(rest) = path.strip_prefix('$') else {
+            return Err(err("expected the path to start with `$`"));
+        };
+
+        let chars = rest.chars().collect::<Vec<_>>();
+        let mut segments = Vec::new();
+        let mut i = 0;
+        while let Some(&c) = chars.get(i) {
+            match c {
+                '.' if chars.get(i + 1) == Some(&'.') => {
+                    let (name, next) = read_name(&chars, i + 2);
+                    if name.is_empty() {
+                        return Err(err("expected a member name after `..`"));
+                    }
+                    segments.push(Segment::Descendant(name));
+                    i = next;
+                }
+                '.' if chars.get(i + 1) == Some(&'*') => {
+                    segments.push(Segment::Wildcard);
+                    i += 2;
+                }
+                '.' => {
+                    let (name, next) = read_name(&chars, i + 1);
+                    if name.is_empty() {
+                        return Err(err("expected a member name after `.`"));
+                    }
+                    segments.push(Segment::Member(name));
+                    i = next;
+                }
+                '[' => {
+                    let Some(close) = find_close(&chars, i + 1) else {
+                        return Err(err("unclosed `[`"));
+                    };
+                    let inner = chars[i + 1..close].iter().collect::<String>();
+                    let inner = inner.trim();
+                    let segment = if inner == "*" {
+                        Segment::Wildcard
+                    } else if let Some(name) = unquote(inner) {
+                        Segment::Member(name)
+                    } else {
+                        inner
+                            .parse::<i64>()
+                            .map(Segment::Index)
+                            .map_err(|_| err(&format!("invalid subscript `[{inner}]`")))?
+                    };
+                    segments.push(segment);
+                    i = close + 1;
+                }
+                _ => return Err(err(&format!("unexpected character `{c}`"))),
+            }
+        }
+        Ok(Self::Path(segments))
+    }
+
+    /// Returns every value that the path matches in `json`.
+    pub(crate) fn evaluate<'a>(&self, json: &'a Value) -> Vec<&'a Value> {
+        let segments = match self {
+            Self::Pointer(pointer) => return json.pointer(pointer).into_iter().collect(),
+            Self::Path(segments) => segments,
+        };
+        let mut current = vec![json];
+        for segment in segments {
+            let mut next = Vec::new();
+            for value in current {
+                match (segment, value) {
+                    (Segment::Member(name), Value::Object(map)) => next.extend(map.get(name)),
+                    (Segment::Index(index), Value::Array(values)) => {
+                        let index = if *index < 0 {
+                            values.len() as i64 + index
+                        } else {
+                            *index
+                        };
+                        if index >= 0 {
+                            next.extend(values.get(index as usize));
+                        }
+                    }
+                    (Segment::Wildcard, Value::Object(map)) => next.extend(map.values()),
+                    (Segment::Wildcard, Value::Array(values)) => next.extend(values),
+                    (Segment::Descendant(name), _) => descendants(value, name, &mut next),
+                    _ => {}
+                }
+            }
+            current = next;
+        }
+        current
+    }
+
+    /// Whether the path can match more than one value, in which case matches are collected
+    /// into an array.
+    pub(crate) fn is_multi(&self) -> bool {
+        match self {
+            Self::Pointer(_) => false,
+            Self::Path(segments) => segments
+                .iter()
+                .any(|s| matches!(s, Segment::Wildcard | Segment::Descendant(_))),
+        }
+    }
+}
+
+fn read_name(chars: &[char], start: usize) -> (String, usize) {
+    let end = chars[start..]
+        .iter()
+        .position(|c| matches!(c, '.' | '['))
+        .map_or(chars.len(), |p| start + p);
+    (chars[start..end].iter().collect(), end)
+}
+
+fn find_close(chars: &[char], start: usize) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in chars.iter().enumerate().skip(start) {
+        match (quote, c) {
+            (None, '\'' | '"') => quote = Some(*c),
+            (Some(q), c) if q == *c => quote = None,
+            (None, ']') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn unquote(s: &str) -> Option<String> {
+    ['\'', '"'].into_iter().find_map(|q| {
+        s.strip_prefix(q)
+            .and_then(|s| s.strip_suffix(q))
+            .map(str::to_string)
+    })
+}
+
+fn descendants<'a>(value: &'a Value, name: &str, out: &mut Vec<&'a Value>) {
+    match value {
+        Value::Object(map) => {
+            out.extend(map.get(name));
+            for child in map.values() {
+                descendants(child, name, out);
+            }
+        }
+        Value::Array(values) => {
+            for child in values {
+                descendants(child, name, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn extract(path: &str, json: &Value) -> Vec<Value> {
+        JsonPath::parse(path)
+            .unwrap()
+            .evaluate(json)
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn test_json_path() {
+        let json = json!({"a": {"b": [1, 2, {"c": 3}]}, "d e": "x", "c": 4});
+        assert_eq!(extract("$", &json), vec![json.clone()]);
+        assert_eq!(extract("$.a.b[0]", &json), vec![json!(1)]);
+        assert_eq!(extract("$.a.b[-1].c", &json), vec![json!(3)]);
+        assert_eq!(extract("$['d e']", &json), vec![json!("x")]);
+        assert_eq!(extract("$.a.b[*]", &json).len(), 3);
+        assert_eq!(extract("$..c", &json), vec![json!(4), json!(3)]);
+        assert_eq!(extract("$.a.missing", &json), Vec::<Value>::new());
+        assert_eq!(extract("$.a.b[5]", &json), Vec::<Value>::new());
+        assert_eq!(extract("/a/b/1", &json), vec![json!(2)]);
+        assert!(JsonPath::parse("a.b").is_err());
+        assert!(JsonPath::parse("$.a[").is_err());
+        assert!(JsonPath::parse("$.a[x]").is_err());
+    }
+}
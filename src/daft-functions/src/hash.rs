@@ -68,6 +68,65 @@ impl ScalarUDF for HashFunction {
     }
 }
 
+/// Hashes the values of several columns into a single hash per row.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct HashColumnsFunction {
+    pub seed: u64,
+}
+
+#[typetag::serde]
+impl ScalarUDF for HashColumnsFunction {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "hash_columns"
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        let Some(first) = inputs.first() else {
+            return Err(DaftError::ValueError(
+                "Cannot call hash_columns with no inputs".to_string(),
+            ));
+        };
+        let len = inputs.iter().map(Series::len).max().unwrap_or(0);
+        if let Some(input) = inputs.iter().find(|s| s.len() != len && s.len() != 1) {
+            return Err(DaftError::ValueError(format!(
+                "Expected the inputs to hash_columns to have length {len} or 1, got {}",
+                input.len()
+            )));
+        }
+
+        // each column is hashed with the hashes so far as its seed, starting from `seed`
+        let mut hashes = UInt64Array::from_iter(
+            Field::new("seed", DataType::UInt64),
+            std::iter::repeat_n(Some(self.seed), len),
+        );
+        for input in inputs {
+            let input = if input.len() == len {
+                input.clone()
+            } else {
+                input.broadcast(len)?
+            };
+            hashes = input.hash(Some(&hashes))?;
+        }
+        Ok(hashes.rename(first.name()).into_series())
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        let Some(first) = inputs.first() else {
+            return Err(DaftError::SchemaMismatch(
+                "Cannot call hash_columns with no inputs".to_string(),
+            ));
+        };
+        for input in &inputs[1..] {
+            input.to_field(schema)?;
+        }
+        Ok(Field::new(first.to_field(schema)?.name, DataType::UInt64))
+    }
+}
+
 #[must_use]
 pub fn hash(input: ExprRef, seed: Option<ExprRef>) -> ExprRef {
     let inputs = match seed {
@@ -77,3 +136,60 @@ pub fn hash(input: ExprRef, seed: Option<ExprRef>) -> ExprRef {
 
     ScalarFunction::new(HashFunction {}, inputs).into()
 }
+
+/// Hashes the values of `inputs` into a single `UInt64` hash per row.
+///
+/// The hashes are computed with XXH3 over a little-endian encoding of the values, so they are
+/// the same on every platform and can be persisted, for example as surrogate keys or buckets.
+/// Hashing a single column gives the same hashes as [`hash`] with the same seed.
+#[must_use]
+pub fn hash_columns(inputs: Vec<ExprRef>, seed: u64) -> ExprRef {
+    ScalarFunction::new(HashColumnsFunction { seed }, inputs).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{resolved_col, Expr};
+
+    use super::*;
+
+    fn evaluate(expr: &ExprRef, inputs: &[Series]) -> DaftResult<Vec<Option<u64>>> {
+        let Expr::ScalarFunction(func) = expr.as_ref() else {
+            panic!("expected a scalar function");
+        };
+        Ok(func
+            .udf
+            .evaluate(inputs)?
+            .u64()?
+            .into_iter()
+            .map(|v| v.copied())
+            .collect())
+    }
+
+    #[test]
+    fn hash_columns_is_stable() -> DaftResult<()> {
+        let a = Int64Array::from(("a", vec![1, 2])).into_series();
+        let b = Utf8Array::from(("b", ["x", "y"].as_slice())).into_series();
+        let exprs = vec![resolved_col("a"), resolved_col("b")];
+
+        let hashes = evaluate(&hash_columns(exprs.clone(), 0), &[a.clone(), b.clone()])?;
+        // these values must not change between versions, since users persist them
+        assert_eq!(
+            hashes,
+            vec![Some(7411349167206634625), Some(9031014927790121297)]
+        );
+        assert_ne!(evaluate(&hash_columns(exprs, 1), &[a.clone(), b])?, hashes);
+
+        // a single column hashes the same as `hash`
+        assert_eq!(
+            evaluate(&hash_columns(vec![resolved_col("a")], 0), &[a.clone()])?,
+            a.hash(None)?
+                .into_iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+}
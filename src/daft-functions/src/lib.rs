@@ -7,6 +7,7 @@ pub mod hash;
 pub mod image;
 pub mod list;
 pub mod minhash;
+pub mod native_udf;
 pub mod numeric;
 #[cfg(feature = "python")]
 pub mod python;
@@ -14,6 +15,7 @@ pub mod sequence;
 pub mod temporal;
 pub mod to_struct;
 pub mod tokenize;
+pub mod try_;
 pub mod uri;
 pub mod utf8;
 
@@ -0,0 +1,275 @@
+//! Native Rust UDFs that can be called from the DSL and SQL by name.
+//!
+//! A native UDF is a kernel over [`Series`] written in Rust, which skips the Python UDF path and
+//! its conversions entirely. UDFs are registered in a process-wide registry, either statically
+//! with [`register_native_udf`] or from a shared library with [`load_native_udfs`], and are
+//! referred to by name in expressions so that a plan can be sent to any process that has
+//! registered the same UDFs. SQL function names are case-insensitive, so UDFs that are called
+//! from SQL should have lowercase names.
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+    sync::{Arc, LazyLock, Mutex, RwLock},
+};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A scalar function over [`Series`] implemented in Rust.
+pub trait NativeScalarUdf: Send + Sync {
+    /// The name the UDF is registered and called under.
+    fn name(&self) -> &str;
+
+    /// Infers the field of the output from the fields of the inputs.
+    fn return_field(&self, inputs: &[Field]) -> DaftResult<Field>;
+
+    /// Evaluates the UDF over the inputs, which have the fields passed to
+    /// [`NativeScalarUdf::return_field`].
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series>;
+}
+
+/// The signature of the `daft_register_native_udfs` symbol that [`load_native_udfs`] looks up in a
+/// shared library. It is passed a callback to register each UDF of the library with.
+pub type RegisterNativeUdfs = unsafe fn(&mut dyn FnMut(Arc<dyn NativeScalarUdf>));
+
+/// The name of the symbol that [`load_native_udfs`] looks up in a shared library.
+pub const REGISTER_NATIVE_UDFS_SYMBOL: &str = "daft_register_native_udfs";
+
+/// Exports the `daft_register_native_udfs` symbol from a `cdylib`, registering each of the given
+/// UDFs when the library is loaded with [`load_native_udfs`].
+///
+/// ```ignore
+/// daft_functions::export_native_udfs!(MyUdf, MyOtherUdf::new());
+/// ```
+#[macro_export]
+macro_rules! export_native_udfs {
+    ($($udf:expr),* $(,)?) => {
+        #[no_mangle]
+        pub fn daft_register_native_udfs(
+            register: &mut dyn FnMut(std::sync::Arc<dyn $crate::native_udf::NativeScalarUdf>),
+        ) {
+            $(register(std::sync::Arc::new($udf));)*
+        }
+    };
+}
+
+static REGISTRY: LazyLock<RwLock<HashMap<String, Arc<dyn NativeScalarUdf>>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+// Loaded libraries are never unloaded, since their UDFs may be referenced by any expression.
+static LIBRARIES: LazyLock<Mutex<Vec<libloading::Library>>> =
+    LazyLock::new(|| Mutex::new(Vec::new()));
+
+/// Registers a native UDF, replacing any previous registration under its name.
+pub fn register_native_udf(udf: Arc<dyn NativeScalarUdf>) -> DaftResult<()> {
+    let name = udf.name();
+    if name.is_empty() {
+        return Err(DaftError::ValueError(
+            "Native UDFs must have a non-empty name".to_string(),
+        ));
+    }
+    REGISTRY.write().unwrap().insert(name.to_string(), udf);
+    Ok(())
+}
+
+/// Removes the native UDF registered under `name`, returning whether there was one.
+pub fn unregister_native_udf(name: &str) -> bool {
+    REGISTRY.write().unwrap().remove(name).is_some()
+}
+
+/// Returns the native UDF registered under `name`, if any.
+pub fn get_native_udf(name: &str) -> Option<Arc<dyn NativeScalarUdf>> {
+    REGISTRY.read().unwrap().get(name).cloned()
+}
+
+/// Returns the names of all registered native UDFs, in sorted order.
+pub fn registered_native_udfs() -> Vec<String> {
+    let mut names = REGISTRY.read().unwrap().keys().cloned().collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+/// Loads the shared library at `path` and registers the UDFs it exports with
+/// [`export_native_udfs!`], returning their names.
+///
+/// # Safety
+///
+/// The library runs arbitrary code when it is loaded, and Rust trait objects have no stable ABI,
+/// so it must be built against the same version of Daft with the same compiler.
+pub unsafe fn load_native_udfs(path: impl AsRef<Path>) -> DaftResult<Vec<String>> {
+    let path = path.as_ref();
+    let library = libloading::Library::new(path).map_err(|e| {
+        DaftError::ValueError(format!(
+            "Failed to load native UDFs from {}: {e}",
+            path.display()
+        ))
+    })?;
+    let register = library
+        .get::<RegisterNativeUdfs>(REGISTER_NATIVE_UDFS_SYMBOL.as_bytes())
+        .map_err(|e| {
+            DaftError::ValueError(format!(
+                "{} does not export {REGISTER_NATIVE_UDFS_SYMBOL}: {e}",
+                path.display()
+            ))
+        })?;
+
+    let mut udfs = Vec::new();
+    register(&mut |udf| udfs.push(udf));
+    LIBRARIES.lock().unwrap().push(library);
+
+    udfs.into_iter()
+        .map(|udf| {
+            let name = udf.name().to_string();
+            register_native_udf(udf)?;
+            Ok(name)
+        })
+        .collect()
+}
+
+/// Returns a `'static` copy of `name`, leaking each distinct name once.
+fn intern(name: &str) -> &'static str {
+    static NAMES: LazyLock<Mutex<HashSet<&'static str>>> =
+        LazyLock::new(|| Mutex::new(HashSet::new()));
+    let mut names = NAMES.lock().unwrap();
+    if let Some(interned) = names.get(name) {
+        return interned;
+    }
+    let interned: &'static str = Box::leak(name.to_string().into_boxed_str());
+    names.insert(interned);
+    interned
+}
+
+/// A call to the native UDF registered under `name`, which is looked up when the call is resolved
+/// and evaluated rather than when it is built, so plans only carry the name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NativeUdfCall {
+    name: &'static str,
+}
+
+impl Serialize for NativeUdfCall {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.name.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for NativeUdfCall {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(Self {
+            name: intern(&name),
+        })
+    }
+}
+
+impl NativeUdfCall {
+    fn udf(&self) -> DaftResult<Arc<dyn NativeScalarUdf>> {
+        get_native_udf(self.name).ok_or_else(|| {
+            DaftError::ValueError(format!("Native UDF {} is not registered", self.name))
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarUDF for NativeUdfCall {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        let fields = inputs
+            .iter()
+            .map(|e| e.to_field(schema))
+            .collect::<DaftResult<Vec<_>>>()?;
+        self.udf()?.return_field(&fields)
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        self.udf()?.evaluate(inputs)
+    }
+}
+
+/// Calls the native UDF registered under `name` on `inputs`.
+pub fn native_udf(name: &str, inputs: Vec<ExprRef>) -> DaftResult<ExprRef> {
+    let udf = get_native_udf(name)
+        .ok_or_else(|| DaftError::ValueError(format!("Native UDF {name} is not registered")))?;
+    Ok(ScalarFunction::new(
+        NativeUdfCall {
+            name: intern(udf.name()),
+        },
+        inputs,
+    )
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::resolved_col;
+
+    use super::*;
+
+    struct AddOne;
+
+    impl NativeScalarUdf for AddOne {
+        fn name(&self) -> &str {
+            "test_add_one"
+        }
+
+        fn return_field(&self, inputs: &[Field]) -> DaftResult<Field> {
+            match inputs {
+                [input] if input.dtype.is_integer() => Ok(input.clone()),
+                _ => Err(DaftError::TypeError(format!(
+                    "Expected a single integer input, received {inputs:?}"
+                ))),
+            }
+        }
+
+        fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+            let one = Int64Array::from(("one", vec![1])).into_series();
+            (&inputs[0] + &one)?.cast(inputs[0].data_type())
+        }
+    }
+
+    #[test]
+    fn call_registered_udf() -> DaftResult<()> {
+        assert!(native_udf("test_add_one", vec![resolved_col("a")]).is_err());
+        register_native_udf(Arc::new(AddOne))?;
+        assert!(registered_native_udfs().contains(&"test_add_one".to_string()));
+
+        let expr = native_udf("test_add_one", vec![resolved_col("a")])?;
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32)]);
+        assert_eq!(expr.to_field(&schema)?, Field::new("a", DataType::Int32));
+        let bad_schema = Schema::new(vec![Field::new("a", DataType::Utf8)]);
+        assert!(expr.to_field(&bad_schema).is_err());
+
+        let daft_dsl::Expr::ScalarFunction(func) = expr.as_ref() else {
+            panic!("expected a scalar function");
+        };
+        let input = Int32Array::from(("a", vec![1, 2, 3])).into_series();
+        let result = func.udf.evaluate(&[input])?;
+        assert_eq!(
+            result
+                .i32()?
+                .into_iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(2), Some(3), Some(4)]
+        );
+
+        assert!(unregister_native_udf("test_add_one"));
+        assert!(func.udf.evaluate(&[]).is_err());
+        Ok(())
+    }
+}
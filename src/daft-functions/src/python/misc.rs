@@ -47,3 +47,13 @@ pub fn minhash(
 pub fn hash(expr: PyExpr, seed: Option<PyExpr>) -> PyResult<PyExpr> {
     Ok(crate::hash::hash(expr.into(), seed.map(Into::into)).into())
 }
+
+#[pyfunction(signature = (exprs, seed=0))]
+pub fn hash_columns(exprs: Vec<PyExpr>, seed: u64) -> PyResult<PyExpr> {
+    if exprs.is_empty() {
+        return Err(PyValueError::new_err(
+            "hash requires at least one expression",
+        ));
+    }
+    Ok(crate::hash::hash_columns(exprs.into_iter().map(Into::into).collect(), seed).into())
+}
@@ -19,10 +19,12 @@ mod float;
 mod image;
 mod list;
 mod misc;
+mod native_udf;
 mod numeric;
 mod sequence;
 mod temporal;
 mod tokenize;
+mod try_;
 mod uri;
 mod utf8;
 
@@ -80,6 +82,7 @@ pub fn register(parent: &Bound<PyModule>) -> PyResult<()> {
     add!(misc::to_struct);
     add!(misc::utf8_count_matches);
     add!(misc::hash);
+    add!(misc::hash_columns);
     add!(misc::minhash);
 
     add!(numeric::abs);
@@ -120,7 +123,19 @@ pub fn register(parent: &Bound<PyModule>) -> PyResult<()> {
     add!(numeric::round);
 
     add!(sequence::monotonically_increasing_id);
+    add!(native_udf::native_udf);
+    add!(native_udf::load_native_udfs);
+    add!(native_udf::registered_native_udfs);
+
+    add!(try_::try_expr);
+    add!(try_::try_cast);
+    add!(try_::try_error_count);
+    add!(try_::reset_try_error_count);
+
     add!(sequence::row_number);
+    add!(sequence::rank);
+    add!(sequence::dense_rank);
+    add!(sequence::uuid);
 
     add!(temporal::dt_date);
     add!(temporal::dt_day);
@@ -138,12 +153,27 @@ pub fn register(parent: &Bound<PyModule>) -> PyResult<()> {
     add!(temporal::dt_truncate);
     add!(temporal::dt_to_unix_epoch);
     add!(temporal::dt_strftime);
+    add!(temporal::dt_quarter);
+    add!(temporal::dt_week_of_year);
+    add!(temporal::dt_last_day_of_month);
+    add!(temporal::dt_date_add);
+    add!(temporal::dt_date_sub);
+    add!(temporal::dt_date_diff);
+    add!(temporal::make_date);
+    add!(temporal::make_timestamp);
 
     add!(tokenize::tokenize_encode);
     add!(tokenize::tokenize_decode);
+    add!(tokenize::tokenize);
+    add!(tokenize::detokenize);
 
     add!(uri::url_download);
     add!(uri::url_upload);
+    add!(uri::url_scheme);
+    add!(uri::url_host);
+    add!(uri::url_path);
+    add!(uri::url_query_param);
+    add!(uri::url_decode);
 
     add!(utf8::utf8_capitalize);
     add!(utf8::utf8_contains);
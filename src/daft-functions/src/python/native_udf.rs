@@ -0,0 +1,20 @@
+use daft_dsl::python::PyExpr;
+use pyo3::{pyfunction, PyResult};
+
+#[pyfunction]
+pub fn native_udf(name: &str, inputs: Vec<PyExpr>) -> PyResult<PyExpr> {
+    let inputs = inputs.into_iter().map(Into::into).collect();
+    Ok(crate::native_udf::native_udf(name, inputs)?.into())
+}
+
+#[pyfunction]
+pub fn load_native_udfs(path: &str) -> PyResult<Vec<String>> {
+    // SAFETY: loading a library is an explicit request to run its code, and the requirements on
+    // how it is built are documented on `daft.load_native_udfs`.
+    Ok(unsafe { crate::native_udf::load_native_udfs(path) }?)
+}
+
+#[pyfunction]
+pub fn registered_native_udfs() -> Vec<String> {
+    crate::native_udf::registered_native_udfs()
+}
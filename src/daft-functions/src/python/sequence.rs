@@ -1,6 +1,8 @@
 use daft_dsl::python::PyExpr;
 use pyo3::{pyfunction, PyResult};
 
+use crate::sequence::UuidVersion;
+
 simple_python_wrapper!(
     monotonically_increasing_id,
     crate::sequence::monotonically_increasing_id,
@@ -8,3 +10,11 @@ simple_python_wrapper!(
 );
 
 simple_python_wrapper!(row_number, crate::sequence::row_number, []);
+simple_python_wrapper!(rank, crate::sequence::rank, []);
+simple_python_wrapper!(dense_rank, crate::sequence::dense_rank, []);
+
+#[pyfunction(signature = (version="4"))]
+pub fn uuid(version: &str) -> PyResult<PyExpr> {
+    let version: UuidVersion = version.parse()?;
+    Ok(crate::sequence::uuid(version).into())
+}
@@ -15,6 +15,10 @@ simple_python_wrapper!(dt_microsecond, crate::temporal::dt_microsecond, [expr: P
 simple_python_wrapper!(dt_nanosecond, crate::temporal::dt_nanosecond, [expr: PyExpr]);
 simple_python_wrapper!(dt_time, crate::temporal::dt_time, [expr: PyExpr]);
 simple_python_wrapper!(dt_year, crate::temporal::dt_year, [expr: PyExpr]);
+simple_python_wrapper!(dt_quarter, crate::temporal::dt_quarter, [expr: PyExpr]);
+simple_python_wrapper!(dt_week_of_year, crate::temporal::dt_week_of_year, [expr: PyExpr]);
+simple_python_wrapper!(dt_last_day_of_month, crate::temporal::dt_last_day_of_month, [expr: PyExpr]);
+simple_python_wrapper!(make_date, crate::temporal::calendar::make_date, [year: PyExpr, month: PyExpr, day: PyExpr]);
 
 #[pyfunction]
 pub fn dt_truncate(expr: PyExpr, interval: &str, relative_to: PyExpr) -> PyResult<PyExpr> {
@@ -30,3 +34,40 @@ pub fn dt_to_unix_epoch(expr: PyExpr, time_unit: PyTimeUnit) -> PyResult<PyExpr>
 pub fn dt_strftime(expr: PyExpr, format: Option<&str>) -> PyResult<PyExpr> {
     Ok(crate::temporal::dt_strftime(expr.into(), format).into())
 }
+
+#[pyfunction]
+pub fn dt_date_add(expr: PyExpr, n: PyExpr, unit: &str) -> PyResult<PyExpr> {
+    Ok(crate::temporal::calendar::dt_date_add(expr.into(), n.into(), unit).into())
+}
+
+#[pyfunction]
+pub fn dt_date_sub(expr: PyExpr, n: PyExpr, unit: &str) -> PyResult<PyExpr> {
+    Ok(crate::temporal::calendar::dt_date_sub(expr.into(), n.into(), unit).into())
+}
+
+#[pyfunction]
+pub fn dt_date_diff(expr: PyExpr, start: PyExpr, unit: &str) -> PyResult<PyExpr> {
+    Ok(crate::temporal::calendar::dt_date_diff(expr.into(), start.into(), unit).into())
+}
+
+#[pyfunction(signature = (year, month, day, hour, minute, second, timezone=None))]
+pub fn make_timestamp(
+    year: PyExpr,
+    month: PyExpr,
+    day: PyExpr,
+    hour: PyExpr,
+    minute: PyExpr,
+    second: PyExpr,
+    timezone: Option<&str>,
+) -> PyResult<PyExpr> {
+    Ok(crate::temporal::calendar::make_timestamp(
+        year.into(),
+        month.into(),
+        day.into(),
+        hour.into(),
+        minute.into(),
+        second.into(),
+        timezone,
+    )
+    .into())
+}
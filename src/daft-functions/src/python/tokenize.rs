@@ -52,3 +52,29 @@ pub fn tokenize_decode(
     )
     .into())
 }
+
+#[pyfunction(signature = (expr, tokenizer, use_special_tokens, io_config=None))]
+pub fn tokenize(
+    expr: PyExpr,
+    tokenizer: &str,
+    use_special_tokens: bool,
+    io_config: Option<IOConfig>,
+) -> PyResult<PyExpr> {
+    Ok(crate::tokenize::tokenize(
+        expr.into(),
+        tokenizer,
+        io_config.map(|config| config.config),
+        use_special_tokens,
+    )
+    .into())
+}
+
+#[pyfunction(signature = (expr, tokenizer, io_config=None))]
+pub fn detokenize(expr: PyExpr, tokenizer: &str, io_config: Option<IOConfig>) -> PyResult<PyExpr> {
+    Ok(crate::tokenize::detokenize(
+        expr.into(),
+        tokenizer,
+        io_config.map(|config| config.config),
+    )
+    .into())
+}
@@ -0,0 +1,23 @@
+use daft_core::python::PyDataType;
+use daft_dsl::python::PyExpr;
+use pyo3::{pyfunction, PyResult};
+
+#[pyfunction(name = "try_")]
+pub fn try_expr(expr: PyExpr) -> PyResult<PyExpr> {
+    Ok(crate::try_::try_(expr.into())?.into())
+}
+
+#[pyfunction]
+pub fn try_cast(expr: PyExpr, dtype: PyDataType) -> PyExpr {
+    crate::try_::try_cast(expr.into(), dtype.into()).into()
+}
+
+#[pyfunction]
+pub fn try_error_count() -> u64 {
+    crate::try_::try_error_count()
+}
+
+#[pyfunction]
+pub fn reset_try_error_count() -> u64 {
+    crate::try_::reset_try_error_count()
+}
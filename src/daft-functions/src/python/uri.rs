@@ -58,3 +58,13 @@ pub fn url_upload(
     );
     Ok(uri::upload(expr.into(), folder_location.into(), Some(args)).into())
 }
+
+simple_python_wrapper!(url_scheme, uri::parse::url_scheme, [expr: PyExpr]);
+simple_python_wrapper!(url_host, uri::parse::url_host, [expr: PyExpr]);
+simple_python_wrapper!(url_path, uri::parse::url_path, [expr: PyExpr]);
+simple_python_wrapper!(url_decode, uri::parse::url_decode, [expr: PyExpr]);
+
+#[pyfunction]
+pub fn url_query_param(expr: PyExpr, name: &str) -> PyResult<PyExpr> {
+    Ok(uri::parse::url_query_param(expr.into(), name).into())
+}
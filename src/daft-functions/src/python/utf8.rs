@@ -18,7 +18,7 @@ simple_python_wrapper!(utf8_lpad, crate::utf8::lpad, [input: PyExpr, length: PyE
 simple_python_wrapper!(utf8_lstrip, crate::utf8::lstrip, [input: PyExpr]);
 simple_python_wrapper!(utf8_match, crate::utf8::match_, [input: PyExpr, pattern: PyExpr]);
 simple_python_wrapper!(utf8_repeat, crate::utf8::repeat, [input: PyExpr, ntimes: PyExpr]);
-simple_python_wrapper!(utf8_replace, crate::utf8::replace, [input: PyExpr, pattern: PyExpr, replacement: PyExpr, regex: bool]);
+simple_python_wrapper!(utf8_replace, crate::utf8::replace, [input: PyExpr, pattern: PyExpr, replacement: PyExpr, regex: bool, max_replacements: Option<usize>]);
 simple_python_wrapper!(utf8_reverse, crate::utf8::reverse, [input: PyExpr]);
 simple_python_wrapper!(utf8_right, crate::utf8::right, [input: PyExpr, nchars: PyExpr]);
 simple_python_wrapper!(utf8_rpad, crate::utf8::rpad, [input: PyExpr, length: PyExpr, pad: PyExpr]);
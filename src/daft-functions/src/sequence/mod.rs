@@ -1,5 +1,9 @@
 pub mod monotonically_increasing_id;
+pub mod rank;
 pub mod row_number;
+pub mod uuid;
 
 pub use monotonically_increasing_id::monotonically_increasing_id;
+pub use rank::{dense_rank, rank};
 pub use row_number::row_number;
+pub use uuid::{uuid, UuidVersion};
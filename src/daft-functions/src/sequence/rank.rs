@@ -0,0 +1,11 @@
+use daft_dsl::{Expr, ExprRef, WindowExpr};
+
+#[must_use]
+pub fn rank() -> ExprRef {
+    Expr::WindowFunction(WindowExpr::Rank).into()
+}
+
+#[must_use]
+pub fn dense_rank() -> ExprRef {
+    Expr::WindowFunction(WindowExpr::DenseRank).into()
+}
@@ -0,0 +1,113 @@
+use std::str::FromStr;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum UuidVersion {
+    /// Random UUIDs.
+    V4,
+    /// UUIDs that start with a millisecond timestamp, so that they sort by creation time.
+    V7,
+}
+
+impl FromStr for UuidVersion {
+    type Err = DaftError;
+
+    fn from_str(s: &str) -> DaftResult<Self> {
+        match s {
+            "4" | "v4" => Ok(Self::V4),
+            "7" | "v7" => Ok(Self::V7),
+            _ => Err(DaftError::ValueError(format!(
+                "Unsupported UUID version: {s}, expected 4 or 7"
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Uuid {
+    pub version: UuidVersion,
+}
+
+#[typetag::serde]
+impl ScalarUDF for Uuid {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "uuid"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], _schema: &Schema) -> DaftResult<Field> {
+        if !inputs.is_empty() {
+            return Err(DaftError::ValueError(format!(
+                "Expected 0 input args, got {}",
+                inputs.len()
+            )));
+        }
+        Ok(Field::new("uuid", DataType::Utf8))
+    }
+
+    fn evaluate(&self, _inputs: &[Series]) -> DaftResult<Series> {
+        Err(DaftError::ValueError(
+            "uuid has no inputs, so it must be evaluated over a batch of rows".to_string(),
+        ))
+    }
+
+    fn evaluate_batch(&self, _inputs: &[Series], num_rows: usize) -> DaftResult<Series> {
+        let uuids = std::iter::repeat_n((), num_rows).map(|()| {
+            let uuid = match self.version {
+                UuidVersion::V4 => uuid::Uuid::new_v4(),
+                UuidVersion::V7 => uuid::Uuid::now_v7(),
+            };
+            Some(uuid.to_string())
+        });
+        Ok(Utf8Array::from_iter("uuid", uuids).into_series())
+    }
+}
+
+/// Generates a new UUID for every row, as a string like `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+#[must_use]
+pub fn uuid(version: UuidVersion) -> ExprRef {
+    ScalarFunction::new(Uuid { version }, vec![]).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use common_error::DaftResult;
+    use daft_dsl::Expr;
+
+    use super::*;
+
+    #[test]
+    fn uuid_generates_one_value_per_row() -> DaftResult<()> {
+        for version in [UuidVersion::V4, UuidVersion::V7] {
+            let expr = uuid(version);
+            let Expr::ScalarFunction(func) = expr.as_ref() else {
+                panic!("expected a scalar function");
+            };
+            let result = func.udf.evaluate_batch(&[], 100)?;
+            let values = result
+                .utf8()?
+                .into_iter()
+                .map(|v| uuid::Uuid::parse_str(v.unwrap()).unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(values.iter().collect::<HashSet<_>>().len(), 100);
+            let expected = match version {
+                UuidVersion::V4 => 4,
+                UuidVersion::V7 => 7,
+            };
+            assert!(values.iter().all(|v| v.get_version_num() == expected));
+        }
+        Ok(())
+    }
+}
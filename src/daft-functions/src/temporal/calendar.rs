@@ -0,0 +1,289 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct DateAdd {
+    pub(super) unit: String,
+    pub(super) subtract: bool,
+}
+
+#[typetag::serde]
+impl ScalarUDF for DateAdd {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        if self.subtract {
+            "date_sub"
+        } else {
+            "date_add"
+        }
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [input, n] => {
+                let input_field = input.to_field(schema)?;
+                let n_field = n.to_field(schema)?;
+                if !matches!(input_field.dtype, DataType::Date | DataType::Timestamp(..)) {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected input to {} to be a date or timestamp, got {}",
+                        self.name(),
+                        input_field.dtype
+                    )));
+                }
+                if !n_field.dtype.is_integer() && !n_field.dtype.is_null() {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected the number of units to {} to be an integer, got {}",
+                        self.name(),
+                        n_field.dtype
+                    )));
+                }
+                Ok(input_field)
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [input, n] if self.subtract => {
+                let minus_one = Int64Array::from(("n", vec![-1])).into_series();
+                input.dt_add(&(&n.cast(&DataType::Int64)? * &minus_one)?, &self.unit)
+            }
+            [input, n] => input.dt_add(n, &self.unit),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct DateDiff {
+    pub(super) unit: String,
+}
+
+#[typetag::serde]
+impl ScalarUDF for DateDiff {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "date_diff"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [end, start] => {
+                let end_field = end.to_field(schema)?;
+                let start_field = start.to_field(schema)?;
+                if end_field.dtype.is_temporal()
+                    && (start_field.dtype.is_temporal() || start_field.dtype.is_null())
+                {
+                    Ok(Field::new(end_field.name, DataType::Int64))
+                } else {
+                    Err(DaftError::TypeError(format!(
+                        "Expected date or timestamp inputs to date_diff, got {} and {}",
+                        end_field.dtype, start_field.dtype
+                    )))
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [end, start] => end.dt_diff(start, &self.unit),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct MakeDate;
+
+#[typetag::serde]
+impl ScalarUDF for MakeDate {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "make_date"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [_, _, _] => {
+                let fields = integer_fields(self.name(), inputs, schema)?;
+                Ok(Field::new(&fields[0].name, DataType::Date))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 3 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [year, month, day] => Series::make_date(year, month, day),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 3 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct MakeTimestamp {
+    pub(super) timezone: Option<String>,
+}
+
+#[typetag::serde]
+impl ScalarUDF for MakeTimestamp {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "make_timestamp"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [_, _, _, _, _, second] => {
+                let fields = integer_fields(self.name(), &inputs[..5], schema)?;
+                let second_field = second.to_field(schema)?;
+                if !second_field.dtype.is_numeric() && !second_field.dtype.is_null() {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected the seconds passed to make_timestamp to be numeric, got {}",
+                        second_field.dtype
+                    )));
+                }
+                Ok(Field::new(
+                    &fields[0].name,
+                    DataType::Timestamp(TimeUnit::Microseconds, self.timezone.clone()),
+                ))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 6 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [year, month, day, hour, minute, second] => Series::make_timestamp(
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+                self.timezone.as_deref(),
+            ),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 6 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+fn integer_fields(name: &str, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Vec<Field>> {
+    inputs
+        .iter()
+        .map(|input| {
+            let field = input.to_field(schema)?;
+            if field.dtype.is_integer() || field.dtype.is_null() {
+                Ok(field)
+            } else {
+                Err(DaftError::TypeError(format!(
+                    "Expected the date and time parts passed to {name} to be integers, got {}",
+                    field.dtype
+                )))
+            }
+        })
+        .collect()
+}
+
+/// Shifts a date or timestamp forward by `n` of `unit`, such as `"day"` or `"months"`.
+///
+/// Month, quarter and year arithmetic clamps the day to the end of shorter months.
+#[must_use]
+pub fn dt_date_add<S: Into<String>>(input: ExprRef, n: ExprRef, unit: S) -> ExprRef {
+    ScalarFunction::new(
+        DateAdd {
+            unit: unit.into(),
+            subtract: false,
+        },
+        vec![input, n],
+    )
+    .into()
+}
+
+/// Shifts a date or timestamp back by `n` of `unit`.
+#[must_use]
+pub fn dt_date_sub<S: Into<String>>(input: ExprRef, n: ExprRef, unit: S) -> ExprRef {
+    ScalarFunction::new(
+        DateAdd {
+            unit: unit.into(),
+            subtract: true,
+        },
+        vec![input, n],
+    )
+    .into()
+}
+
+/// The number of whole `unit`s from `start` to `end`, truncated towards zero.
+#[must_use]
+pub fn dt_date_diff<S: Into<String>>(end: ExprRef, start: ExprRef, unit: S) -> ExprRef {
+    ScalarFunction::new(DateDiff { unit: unit.into() }, vec![end, start]).into()
+}
+
+#[must_use]
+pub fn make_date(year: ExprRef, month: ExprRef, day: ExprRef) -> ExprRef {
+    ScalarFunction::new(MakeDate, vec![year, month, day]).into()
+}
+
+/// Builds a microsecond timestamp from the parts of a local date and time in `timezone`.
+#[must_use]
+pub fn make_timestamp(
+    year: ExprRef,
+    month: ExprRef,
+    day: ExprRef,
+    hour: ExprRef,
+    minute: ExprRef,
+    second: ExprRef,
+    timezone: Option<&str>,
+) -> ExprRef {
+    ScalarFunction::new(
+        MakeTimestamp {
+            timezone: timezone.map(str::to_string),
+        },
+        vec![year, month, day, hour, minute, second],
+    )
+    .into()
+}
@@ -1,3 +1,4 @@
+pub mod calendar;
 pub mod truncate;
 
 use common_error::{DaftError, DaftResult};
@@ -79,6 +80,14 @@ impl_temporal!(Millisecond, dt_millisecond, "dt_millisecond", UInt32);
 impl_temporal!(Microsecond, dt_microsecond, "dt_microsecond", UInt32);
 impl_temporal!(Nanosecond, dt_nanosecond, "dt_nanosecond", UInt32);
 impl_temporal!(Year, dt_year, "dt_year", Int32);
+impl_temporal!(Quarter, dt_quarter, "dt_quarter", UInt32);
+impl_temporal!(WeekOfYear, dt_week_of_year, "dt_week_of_year", UInt32);
+impl_temporal!(
+    LastDayOfMonth,
+    dt_last_day_of_month,
+    "dt_last_day_of_month",
+    Date
+);
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct Time;
@@ -247,7 +256,10 @@ pub fn dt_strftime(input: ExprRef, format: Option<&str>) -> ExprRef {
 mod test {
     use std::sync::Arc;
 
-    use super::truncate::Truncate;
+    use super::{
+        calendar::{DateAdd, DateDiff, MakeDate, MakeTimestamp},
+        truncate::Truncate,
+    };
 
     #[test]
     fn test_fn_name() {
@@ -265,6 +277,9 @@ mod test {
             (Arc::new(Nanosecond), "nanosecond"),
             (Arc::new(Time), "time"),
             (Arc::new(Year), "year"),
+            (Arc::new(Quarter), "quarter"),
+            (Arc::new(WeekOfYear), "week_of_year"),
+            (Arc::new(LastDayOfMonth), "last_day_of_month"),
             (
                 Arc::new(Truncate {
                     interval: String::new(),
@@ -277,6 +292,28 @@ mod test {
                 }),
                 "to_unix_epoch",
             ),
+            (
+                Arc::new(DateAdd {
+                    unit: "day".to_string(),
+                    subtract: false,
+                }),
+                "date_add",
+            ),
+            (
+                Arc::new(DateAdd {
+                    unit: "day".to_string(),
+                    subtract: true,
+                }),
+                "date_sub",
+            ),
+            (
+                Arc::new(DateDiff {
+                    unit: "day".to_string(),
+                }),
+                "date_diff",
+            ),
+            (Arc::new(MakeDate), "make_date"),
+            (Arc::new(MakeTimestamp { timezone: None }), "make_timestamp"),
         ];
 
         for (f, name) in cases {
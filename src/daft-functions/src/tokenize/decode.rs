@@ -6,10 +6,13 @@ use daft_dsl::{functions::ScalarUDF, ExprRef};
 use daft_io::IOConfig;
 use serde::{Deserialize, Serialize};
 
-use crate::tokenize::bpe::DaftBPE;
+use crate::tokenize::{bpe::DaftBPE, huggingface::HfTokenizer};
 
 // Helper function that operates on a single Utf8 series
-fn decode_list(series: &Series, bpe: &DaftBPE) -> DaftResult<String> {
+fn decode_list(
+    series: &Series,
+    decode: &impl Fn(&[u32]) -> DaftResult<String>,
+) -> DaftResult<String> {
     if !series.data_type().is_integer() {
         return Err(DaftError::TypeError(format!(
             "expected integer list inner type, got {}",
@@ -19,29 +22,37 @@ fn decode_list(series: &Series, bpe: &DaftBPE) -> DaftResult<String> {
     let series = series.cast(&DataType::UInt32)?;
     let data = series.u32()?.as_arrow();
     let tokens: &[u32] = data.values().as_slice();
-    bpe.decode(tokens)
+    decode(tokens)
 }
 
-fn tokenize_decode_array(
+/// Decodes each list of tokens into a string.
+fn decode_array(
     arr: &ListArray,
-    tokens_path: &str,
-    io_config: Option<Arc<IOConfig>>,
-    pattern: Option<&str>,
-    special_tokens: Option<&str>,
+    decode: impl Fn(&[u32]) -> DaftResult<String>,
 ) -> DaftResult<Utf8Array> {
-    let bpe = DaftBPE::new(tokens_path, io_config, pattern, special_tokens)?;
     let offsets = arr.offsets();
     let strs = (0..offsets.len() - 1)
         .map(|i| {
             let start = offsets[i] as usize;
             let end = offsets[i + 1] as usize;
             let sub_series = arr.flat_child.slice(start, end)?;
-            decode_list(&sub_series, &bpe)
+            decode_list(&sub_series, &decode)
         })
         .collect::<DaftResult<Vec<String>>>()?;
     Utf8Array::from_iter(arr.name(), strs.iter().map(Some)).with_validity(arr.validity().cloned())
 }
 
+fn tokenize_decode_array(
+    arr: &ListArray,
+    tokens_path: &str,
+    io_config: Option<Arc<IOConfig>>,
+    pattern: Option<&str>,
+    special_tokens: Option<&str>,
+) -> DaftResult<Utf8Array> {
+    let bpe = DaftBPE::new(tokens_path, io_config, pattern, special_tokens)?;
+    decode_array(arr, |tokens| bpe.decode(tokens))
+}
+
 fn tokenize_decode_series(
     series: &Series,
     tokens_path: &str,
@@ -118,3 +129,61 @@ impl ScalarUDF for TokenizeDecodeFunction {
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct DetokenizeFunction {
+    pub tokenizer: String,
+    pub io_config: Option<Arc<IOConfig>>,
+}
+
+#[typetag::serde]
+impl ScalarUDF for DetokenizeFunction {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "detokenize"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data] => {
+                let field = data.to_field(schema)?;
+                match &field.dtype {
+                    DataType::List(inner) if inner.is_integer() => {
+                        Ok(Field::new(field.name, DataType::Utf8))
+                    }
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected input to detokenize to be list[integer], but received {field}",
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [data] => match data.data_type() {
+                DataType::List(_) => {
+                    let tokenizer = HfTokenizer::load(&self.tokenizer, self.io_config.clone())?;
+                    Ok(decode_array(data.list()?, |tokens| {
+                        tokenizer.decode(tokens.iter().copied())
+                    })?
+                    .into_series())
+                }
+                dt => Err(DaftError::TypeError(format!(
+                    "Detokenize not implemented for type {dt}"
+                ))),
+            },
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
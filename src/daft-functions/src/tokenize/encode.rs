@@ -10,25 +10,17 @@ use daft_dsl::{functions::ScalarUDF, ExprRef};
 use daft_io::IOConfig;
 use serde::{Deserialize, Serialize};
 
-use crate::tokenize::bpe::DaftBPE;
-
-fn tokenize_encode_array(
-    arr: &Utf8Array,
-    tokens_path: &str,
-    io_config: Option<Arc<IOConfig>>,
-    pattern: Option<&str>,
-    special_tokens: Option<&str>,
-    use_special_tokens: bool,
-) -> DaftResult<ListArray> {
-    let bpe = DaftBPE::new(tokens_path, io_config, pattern, special_tokens)?;
+use crate::tokenize::{bpe::DaftBPE, huggingface::HfTokenizer};
 
+/// Encodes each string as a list of tokens.
+fn encode_array(arr: &Utf8Array, encode: impl Fn(&str) -> Vec<u32>) -> DaftResult<ListArray> {
     let mut flat_child = MutablePrimitiveArray::<u32>::new();
     let mut offsets: Vec<i64> = Vec::with_capacity(arr.len() + 1);
     offsets.push(0);
     let self_arrow = arr.as_arrow();
     for s_opt in self_arrow {
         if let Some(s) = s_opt {
-            let tokens = bpe.encode(s, use_special_tokens);
+            let tokens = encode(s);
             let tokens_iter = tokens.iter().map(|t| Some(*t));
             flat_child.extend(tokens_iter);
         }
@@ -48,6 +40,18 @@ fn tokenize_encode_array(
     ))
 }
 
+fn tokenize_encode_array(
+    arr: &Utf8Array,
+    tokens_path: &str,
+    io_config: Option<Arc<IOConfig>>,
+    pattern: Option<&str>,
+    special_tokens: Option<&str>,
+    use_special_tokens: bool,
+) -> DaftResult<ListArray> {
+    let bpe = DaftBPE::new(tokens_path, io_config, pattern, special_tokens)?;
+    encode_array(arr, |s| bpe.encode(s, use_special_tokens))
+}
+
 fn tokenize_encode_series(
     series: &Series,
     tokens_path: &str,
@@ -126,3 +130,59 @@ impl ScalarUDF for TokenizeEncodeFunction {
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct TokenizeFunction {
+    pub tokenizer: String,
+    pub io_config: Option<Arc<IOConfig>>,
+    pub use_special_tokens: bool,
+}
+
+#[typetag::serde]
+impl ScalarUDF for TokenizeFunction {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "tokenize"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data] => {
+                let field = data.to_field(schema)?;
+                match &field.dtype {
+                    DataType::Utf8 => Ok(Field::new(
+                        field.name,
+                        DataType::List(Box::new(DataType::Int32)),
+                    )),
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expects input to tokenize to be utf8, but received {field}",
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [data] => {
+                let tokenizer = HfTokenizer::load(&self.tokenizer, self.io_config.clone())?;
+                data.with_utf8_array(|arr| {
+                    encode_array(arr, |s| tokenizer.encode(s, self.use_special_tokens))?
+                        .into_series()
+                        .cast(&DataType::List(Box::new(DataType::Int32)))
+                })
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
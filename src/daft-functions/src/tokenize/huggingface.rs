@@ -0,0 +1,410 @@
+//! Tokenizers in the `tokenizer.json` format of HuggingFace's tokenizers library.
+//!
+//! Byte-level BPE models, such as those of GPT-2, Llama 3 and Qwen 2, are run on tiktoken's BPE:
+//! each token is ranked by the merge that produces it, so that tiktoken merges pairs in the same
+//! order as the model, and ranks are mapped to the model's token IDs.
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::{Arc, LazyLock, Mutex},
+};
+
+use common_error::{DaftError, DaftResult};
+use common_runtime::get_io_runtime;
+use daft_io::{get_io_client, IOConfig};
+use serde::Deserialize;
+use serde_json::Value;
+use tiktoken_rs::CoreBPE;
+use unicode_normalization::UnicodeNormalization;
+
+/// The pattern that byte-level pre-tokenizers split text with when `use_regex` is set.
+const BYTE_LEVEL_PATTERN: &str =
+    r"'s|'t|'re|'ve|'m|'ll|'d| ?\p{L}+| ?\p{N}+| ?[^\s\p{L}\p{N}]+|\s+(?!\S)|\s+";
+
+/// Tokenizers by the source they were loaded from, as `tokenizer.json` files are large to parse
+/// for every batch.
+static TOKENIZERS: LazyLock<Mutex<HashMap<String, Arc<HfTokenizer>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Deserialize)]
+struct TokenizerFile {
+    normalizer: Option<Value>,
+    pre_tokenizer: Option<Value>,
+    model: Model,
+    #[serde(default)]
+    added_tokens: Vec<AddedToken>,
+}
+
+#[derive(Deserialize)]
+struct Model {
+    #[serde(rename = "type")]
+    kind: Option<String>,
+    #[serde(default)]
+    vocab: HashMap<String, u32>,
+    #[serde(default)]
+    merges: Vec<Merge>,
+}
+
+/// A merge of two tokens, written as `"left right"` by older versions of tokenizers and as
+/// `["left", "right"]` by newer ones.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Merge {
+    Joined(String),
+    Pair(String, String),
+}
+
+#[derive(Deserialize)]
+struct AddedToken {
+    id: u32,
+    content: String,
+}
+
+fn unsupported(what: impl std::fmt::Display) -> DaftError {
+    DaftError::ValueError(format!(
+        "Only byte-level BPE HuggingFace tokenizers are supported, but the tokenizer has {what}"
+    ))
+}
+
+fn component_type(value: &Value) -> &str {
+    value
+        .get("type")
+        .and_then(Value::as_str)
+        .unwrap_or("unknown")
+}
+
+/// The characters that byte-level tokenizers write each byte as, which are printable so that
+/// tokens can be stored as strings.
+fn byte_level_chars() -> HashMap<char, u8> {
+    let mut chars = HashMap::with_capacity(256);
+    let mut unprintable = 0;
+    for byte in 0..=255u8 {
+        let c = if matches!(byte, b'!'..=b'~' | 0xA1..=0xAC | 0xAE..=0xFF) {
+            char::from(byte)
+        } else {
+            unprintable += 1;
+            char::from_u32(255 + unprintable).expect("code points up to 511 are valid")
+        };
+        chars.insert(c, byte);
+    }
+    chars
+}
+
+/// The pattern that the pre-tokenizer splits text with, and whether it adds a space to the start
+/// of text.
+fn pre_tokenizer_pattern(pre_tokenizer: &Value) -> DaftResult<(String, bool)> {
+    let byte_level = |value: &Value| {
+        let flag = |name| value.get(name).and_then(Value::as_bool);
+        (
+            flag("use_regex").unwrap_or(true),
+            flag("add_prefix_space").unwrap_or(false),
+        )
+    };
+    match component_type(pre_tokenizer) {
+        "ByteLevel" => {
+            let (use_regex, add_prefix_space) = byte_level(pre_tokenizer);
+            if !use_regex {
+                return Err(unsupported("a byte-level pre-tokenizer without a pattern"));
+            }
+            Ok((BYTE_LEVEL_PATTERN.to_string(), add_prefix_space))
+        }
+        // A split by a pattern, whose pieces are then written as bytes, as Llama 3 does.
+        "Sequence" => {
+            let steps = pre_tokenizer
+                .get("pretokenizers")
+                .and_then(Value::as_array)
+                .map_or(&[][..], Vec::as_slice);
+            match steps {
+                [split, byte_level_step]
+                    if component_type(split) == "Split"
+                        && component_type(byte_level_step) == "ByteLevel" =>
+                {
+                    let (use_regex, add_prefix_space) = byte_level(byte_level_step);
+                    let pattern = split
+                        .get("pattern")
+                        .and_then(|pattern| pattern.get("Regex"))
+                        .and_then(Value::as_str);
+                    let behavior = split.get("behavior").and_then(Value::as_str);
+                    let invert = split.get("invert").and_then(Value::as_bool);
+                    match (pattern, behavior, invert, use_regex) {
+                        (Some(pattern), Some("Isolated"), Some(false) | None, false) => {
+                            Ok((pattern.to_string(), add_prefix_space))
+                        }
+                        _ => Err(unsupported(
+                            "a pre-tokenizer that splits in an unsupported way",
+                        )),
+                    }
+                }
+                _ => Err(unsupported(
+                    "a sequence of pre-tokenizers other than a split followed by a byte-level one",
+                )),
+            }
+        }
+        other => Err(unsupported(format!("a {other} pre-tokenizer"))),
+    }
+}
+
+/// A byte-level BPE tokenizer loaded from a HuggingFace `tokenizer.json` file.
+pub struct HfTokenizer {
+    bpe: CoreBPE,
+    /// The token ID of each rank.
+    ids: Vec<u32>,
+    /// The rank of each token ID.
+    ranks: HashMap<u32, usize>,
+    add_prefix_space: bool,
+    nfc: bool,
+}
+
+impl HfTokenizer {
+    /// Loads a tokenizer from a `tokenizer.json` file, a directory with one, or the name of a
+    /// model on the HuggingFace Hub, caching it for later calls.
+    pub fn load(source: &str, io_config: Option<Arc<IOConfig>>) -> DaftResult<Arc<Self>> {
+        if let Some(tokenizer) = TOKENIZERS.lock().unwrap().get(source) {
+            return Ok(tokenizer.clone());
+        }
+        let path = tokenizer_path(source);
+        let client = get_io_client(false, io_config.unwrap_or_default())?;
+        let runtime = get_io_runtime(false);
+        let bytes = runtime.block_on(async move {
+            client.single_url_get(path, None, None).await?.bytes().await
+        })??;
+        let tokenizer = Arc::new(Self::from_json(&bytes)?);
+        TOKENIZERS
+            .lock()
+            .unwrap()
+            .insert(source.to_string(), tokenizer.clone());
+        Ok(tokenizer)
+    }
+
+    pub fn from_json(bytes: &[u8]) -> DaftResult<Self> {
+        let file: TokenizerFile = serde_json::from_slice(bytes).map_err(|e| {
+            DaftError::ValueError(format!("Invalid HuggingFace tokenizer file: {e}"))
+        })?;
+        if file.model.kind.as_deref().unwrap_or("BPE") != "BPE" {
+            return Err(unsupported(format!(
+                "a {} model",
+                file.model.kind.unwrap_or_default()
+            )));
+        }
+        let nfc = match &file.normalizer {
+            None | Some(Value::Null) => false,
+            Some(normalizer) if component_type(normalizer) == "NFC" => true,
+            Some(normalizer) => {
+                return Err(unsupported(format!(
+                    "a {} normalizer",
+                    component_type(normalizer)
+                )))
+            }
+        };
+        let (pattern, add_prefix_space) = match &file.pre_tokenizer {
+            Some(pre_tokenizer) => pre_tokenizer_pattern(pre_tokenizer)?,
+            None => return Err(unsupported("no pre-tokenizer")),
+        };
+
+        let chars = byte_level_chars();
+        let to_bytes = |token: &str| -> Option<Vec<u8>> {
+            token.chars().map(|c| chars.get(&c).copied()).collect()
+        };
+        let is_added = |token: &str| file.added_tokens.iter().any(|t| t.content == token);
+        // Single bytes are ranked first and merged tokens by the merge that produces them, with
+        // tokens that no merge produces ranked last.
+        let mut tokens: Vec<(Vec<u8>, u32)> = Vec::with_capacity(file.model.vocab.len());
+        let mut ranked = HashMap::with_capacity(file.model.vocab.len());
+        let mut vocab = file
+            .model
+            .vocab
+            .iter()
+            .filter(|(token, _)| !is_added(token))
+            .filter_map(|(token, id)| Some((to_bytes(token)?, *id)))
+            .collect::<Vec<(Vec<u8>, u32)>>();
+        vocab.sort_by_key(|(bytes, id)| (bytes.len() > 1, bytes.clone(), *id));
+        let ids: HashMap<&[u8], u32> = vocab.iter().map(|(b, id)| (b.as_slice(), *id)).collect();
+        for (bytes, id) in vocab.iter().filter(|(bytes, _)| bytes.len() == 1) {
+            ranked.insert(bytes.clone(), tokens.len());
+            tokens.push((bytes.clone(), *id));
+        }
+        if tokens.len() != 256 {
+            return Err(unsupported("a vocabulary without a token for every byte"));
+        }
+        for merge in &file.model.merges {
+            let (left, right) = match merge {
+                Merge::Joined(merge) => merge
+                    .split_once(' ')
+                    .ok_or_else(|| DaftError::ValueError(format!("Invalid BPE merge: {merge}")))?,
+                Merge::Pair(left, right) => (left.as_str(), right.as_str()),
+            };
+            let (Some(left), Some(right)) = (to_bytes(left), to_bytes(right)) else {
+                continue;
+            };
+            let merged = [left, right].concat();
+            if let Some(id) = ids.get(merged.as_slice()) {
+                if !ranked.contains_key(&merged) {
+                    ranked.insert(merged.clone(), tokens.len());
+                    tokens.push((merged, *id));
+                }
+            }
+        }
+        for (bytes, id) in &vocab {
+            if !ranked.contains_key(bytes) {
+                ranked.insert(bytes.clone(), tokens.len());
+                tokens.push((bytes.clone(), *id));
+            }
+        }
+
+        let encoder = ranked.into_iter().collect();
+        let mut specials = HashMap::default();
+        let mut ids = tokens.into_iter().map(|(_, id)| id).collect::<Vec<_>>();
+        for token in &file.added_tokens {
+            specials.insert(token.content.clone(), ids.len());
+            ids.push(token.id);
+        }
+        let bpe = CoreBPE::new(encoder, specials, &pattern).map_err(|e| {
+            DaftError::ValueError(format!(
+                "Invalid pre-tokenizer pattern in HuggingFace tokenizer: {e}"
+            ))
+        })?;
+        let ranks = ids
+            .iter()
+            .enumerate()
+            .map(|(rank, id)| (*id, rank))
+            .collect();
+        Ok(Self {
+            bpe,
+            ids,
+            ranks,
+            add_prefix_space,
+            nfc,
+        })
+    }
+
+    /// Encodes text as token IDs, recognizing the tokenizer's added tokens in it if
+    /// `use_special_tokens` is set.
+    pub fn encode(&self, text: &str, use_special_tokens: bool) -> Vec<u32> {
+        let mut normalized = if self.nfc {
+            text.nfc().collect()
+        } else {
+            text.to_string()
+        };
+        if self.add_prefix_space && !normalized.starts_with(' ') {
+            normalized.insert(0, ' ');
+        }
+        let ranks = if use_special_tokens {
+            self.bpe.encode_with_special_tokens(&normalized)
+        } else {
+            self.bpe.encode_ordinary(&normalized)
+        };
+        ranks.into_iter().map(|rank| self.ids[rank]).collect()
+    }
+
+    /// Decodes token IDs into text, replacing byte sequences that aren't valid UTF-8.
+    pub fn decode(&self, ids: impl IntoIterator<Item = u32>) -> DaftResult<String> {
+        let ranks = ids
+            .into_iter()
+            .map(|id| {
+                self.ranks
+                    .get(&id)
+                    .copied()
+                    .ok_or_else(|| DaftError::ValueError(format!("Input has bad token {id}")))
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(String::from_utf8_lossy(&self.bpe._decode_native(&ranks)).into_owned())
+    }
+}
+
+/// The path of the `tokenizer.json` file of a tokenizer source, which is either a path or URL of
+/// the file or of a directory with it, or the name of a model on the HuggingFace Hub.
+fn tokenizer_path(source: &str) -> String {
+    let path = Path::new(source);
+    let is_json = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+    if source.contains("://") || is_json {
+        return source.to_string();
+    }
+    if path.is_dir() {
+        return path.join("tokenizer.json").to_string_lossy().into_owned();
+    }
+    if path.exists() {
+        return source.to_string();
+    }
+    format!("https://huggingface.co/{source}/resolve/main/tokenizer.json")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A byte-level tokenizer whose merges make "ab", "abc" and " ab".
+    fn tokenizer(pre_tokenizer: &str, normalizer: &str) -> HfTokenizer {
+        let chars = byte_level_chars()
+            .into_iter()
+            .map(|(c, byte)| (byte, c))
+            .collect::<HashMap<_, _>>();
+        let space = chars[&b' '];
+        let mut vocab = (0..=255u8)
+            .map(|byte| (chars[&byte].to_string(), Value::from(u32::from(byte) + 10)))
+            .collect::<serde_json::Map<_, _>>();
+        for (i, token) in ["ab", "abc", &format!("{space}ab")].iter().enumerate() {
+            vocab.insert((*token).to_string(), Value::from(300 + i as u32));
+        }
+        vocab.insert("<|end|>".to_string(), Value::from(0));
+        let json = serde_json::json!({
+            "normalizer": serde_json::from_str::<Value>(normalizer).unwrap(),
+            "pre_tokenizer": serde_json::from_str::<Value>(pre_tokenizer).unwrap(),
+            "model": {
+                "type": "BPE",
+                "vocab": vocab,
+                "merges": ["a b", ["ab", "c"], [format!("{space}"), "ab"]],
+            },
+            "added_tokens": [{"id": 0, "content": "<|end|>", "special": true}],
+        });
+        HfTokenizer::from_json(json.to_string().as_bytes()).unwrap()
+    }
+
+    const BYTE_LEVEL: &str =
+        r#"{"type": "ByteLevel", "add_prefix_space": false, "use_regex": true}"#;
+
+    #[test]
+    fn test_encode_and_decode() {
+        let tokenizer = tokenizer(BYTE_LEVEL, "null");
+        let ids = tokenizer.encode("abc ab<|end|>", true);
+        assert_eq!(ids, vec![301, 302, 0]);
+        assert_eq!(tokenizer.decode(ids).unwrap(), "abc ab<|end|>");
+        assert_eq!(tokenizer.encode("<|end|>", false).len(), "<|end|>".len());
+        assert!(tokenizer.decode([9999]).is_err());
+    }
+
+    #[test]
+    fn test_split_pre_tokenizer_and_prefix_space() {
+        let split = r#"{"type": "Sequence", "pretokenizers": [
+            {"type": "Split", "pattern": {"Regex": "\\s?\\w+|\\s+"}, "behavior": "Isolated", "invert": false},
+            {"type": "ByteLevel", "add_prefix_space": true, "use_regex": false}
+        ]}"#;
+        let tokenizer = tokenizer(split, r#"{"type": "NFC"}"#);
+        assert_eq!(tokenizer.encode("ab", false), vec![302]);
+    }
+
+    #[test]
+    fn test_unsupported_tokenizers() {
+        let json = serde_json::json!({
+            "pre_tokenizer": {"type": "Metaspace"},
+            "model": {"type": "Unigram"},
+        });
+        let err = HfTokenizer::from_json(json.to_string().as_bytes())
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("Unigram"));
+    }
+
+    #[test]
+    fn test_tokenizer_path() {
+        assert_eq!(
+            tokenizer_path("meta-llama/Meta-Llama-3-8B"),
+            "https://huggingface.co/meta-llama/Meta-Llama-3-8B/resolve/main/tokenizer.json"
+        );
+        assert_eq!(
+            tokenizer_path("s3://bucket/tok.json"),
+            "s3://bucket/tok.json"
+        );
+    }
+}
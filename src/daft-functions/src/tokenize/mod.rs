@@ -1,11 +1,12 @@
 use daft_dsl::{functions::ScalarFunction, ExprRef};
 use daft_io::IOConfig;
-pub use decode::TokenizeDecodeFunction;
-pub use encode::TokenizeEncodeFunction;
+pub use decode::{DetokenizeFunction, TokenizeDecodeFunction};
+pub use encode::{TokenizeEncodeFunction, TokenizeFunction};
 
 mod bpe;
 mod decode;
 mod encode;
+mod huggingface;
 mod special_tokens;
 
 pub fn tokenize_encode(
@@ -47,3 +48,33 @@ pub fn tokenize_decode(
     )
     .into()
 }
+
+/// Encodes each string as a list of token IDs with a HuggingFace tokenizer.
+pub fn tokenize(
+    data: ExprRef,
+    tokenizer: &str,
+    io_config: Option<IOConfig>,
+    use_special_tokens: bool,
+) -> ExprRef {
+    ScalarFunction::new(
+        TokenizeFunction {
+            tokenizer: tokenizer.to_string(),
+            io_config: io_config.map(std::convert::Into::into),
+            use_special_tokens,
+        },
+        vec![data],
+    )
+    .into()
+}
+
+/// Decodes each list of token IDs into a string with a HuggingFace tokenizer.
+pub fn detokenize(data: ExprRef, tokenizer: &str, io_config: Option<IOConfig>) -> ExprRef {
+    ScalarFunction::new(
+        DetokenizeFunction {
+            tokenizer: tokenizer.to_string(),
+            io_config: io_config.map(std::convert::Into::into),
+        },
+        vec![data],
+    )
+    .into()
+}
@@ -0,0 +1,273 @@
+//! Expressions that yield null for the rows they fail on instead of failing the whole batch.
+//!
+//! Wrapping an expression with [`try_`] first evaluates it over the whole batch as usual. Only if
+//! that fails is the batch split in halves and re-evaluated, down to the single rows that fail,
+//! which become null. A batch without bad rows therefore costs a single evaluation, and one with
+//! `k` bad rows costs `O(k log n)` evaluations instead of one per row.
+//!
+//! The number of rows that were nulled out is added to a process-wide counter, see
+//! [`try_error_count`], so that jobs can check how many bad records they skipped.
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    resolved_col, Expr, ExprRef, Operator,
+};
+use serde::{Deserialize, Serialize};
+
+static TRY_ERROR_COUNT: AtomicU64 = AtomicU64::new(0);
+
+/// Returns the number of rows that `try_` expressions have nulled out in this process.
+///
+/// Each process of a distributed job keeps its own count.
+pub fn try_error_count() -> u64 {
+    TRY_ERROR_COUNT.load(Ordering::Relaxed)
+}
+
+/// Resets the count returned by [`try_error_count`] to zero, returning the previous count.
+pub fn reset_try_error_count() -> u64 {
+    TRY_ERROR_COUNT.swap(0, Ordering::Relaxed)
+}
+
+/// The part of an expression that `try_` nulls out the failed rows of.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum TryOp {
+    Function(Arc<dyn ScalarUDF>),
+    Cast(DataType),
+    BinaryOp(Operator),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TryExpr {
+    op: TryOp,
+}
+
+impl TryExpr {
+    fn expr(&self, inputs: &[ExprRef]) -> DaftResult<ExprRef> {
+        match (&self.op, inputs) {
+            (TryOp::Function(udf), _) => Ok(Expr::ScalarFunction(ScalarFunction {
+                udf: udf.clone(),
+                inputs: inputs.to_vec(),
+            })
+            .into()),
+            (TryOp::Cast(dtype), [input]) => Ok(input.clone().cast(dtype)),
+            (TryOp::BinaryOp(op), [left, right]) => Ok(Expr::BinaryOp {
+                op: *op,
+                left: left.clone(),
+                right: right.clone(),
+            }
+            .into()),
+            _ => Err(DaftError::ValueError(format!(
+                "Unexpected number of inputs to try: {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate_op(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match (&self.op, inputs) {
+            (TryOp::Function(udf), _) => udf.evaluate(inputs),
+            (TryOp::Cast(dtype), [input]) => input.cast(dtype),
+            (TryOp::BinaryOp(op), [left, right]) => op.evaluate(left, right),
+            _ => Err(DaftError::ValueError(format!(
+                "Unexpected number of inputs to try: {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    /// Infers the output field from the inputs alone, for batches where every row failed.
+    fn output_field(&self, inputs: &[Series]) -> DaftResult<Field> {
+        // inputs may share names, such as literals, so they are referred to by position
+        let fields = inputs
+            .iter()
+            .enumerate()
+            .map(|(i, s)| Field::new(i.to_string(), s.data_type().clone()))
+            .collect::<Vec<_>>();
+        let exprs = (0..inputs.len())
+            .map(|i| resolved_col(i.to_string()))
+            .collect::<Vec<_>>();
+        let field = self.expr(&exprs)?.to_field(&Schema::new(fields))?;
+        let name = inputs.first().map_or("try", Series::name);
+        Ok(field.rename(name))
+    }
+
+    /// Evaluates rows `start..end`, splitting the range until the rows that fail are found.
+    fn evaluate_range(
+        &self,
+        inputs: &[Series],
+        len: usize,
+        start: usize,
+        end: usize,
+        out: &mut Vec<Option<Series>>,
+    ) -> DaftResult<()> {
+        let sliced = inputs
+            .iter()
+            .map(|s| {
+                if s.len() == len {
+                    s.slice(start, end)
+                } else {
+                    Ok(s.clone())
+                }
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        match self.evaluate_op(&sliced) {
+            Ok(result) => out.push(Some(result)),
+            Err(_) if end - start == 1 => {
+                TRY_ERROR_COUNT.fetch_add(1, Ordering::Relaxed);
+                out.push(None);
+            }
+            Err(_) => {
+                let mid = start + (end - start) / 2;
+                self.evaluate_range(inputs, len, start, mid, out)?;
+                self.evaluate_range(inputs, len, mid, end, out)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[typetag::serde]
+impl ScalarUDF for TryExpr {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "try"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        self.expr(inputs)?.to_field(schema)
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        let error = match self.evaluate_op(inputs) {
+            Ok(result) => return Ok(result),
+            Err(e) => e,
+        };
+        let len = inputs.iter().map(Series::len).max().unwrap_or(0);
+        if len <= 1 {
+            if len == 0 {
+                return Err(error);
+            }
+            TRY_ERROR_COUNT.fetch_add(1, Ordering::Relaxed);
+            let field = self.output_field(inputs)?;
+            return Ok(Series::full_null(&field.name, &field.dtype, 1));
+        }
+
+        let mut chunks = Vec::new();
+        self.evaluate_range(inputs, len, 0, len, &mut chunks)?;
+        let field = match chunks.iter().flatten().next() {
+            Some(chunk) => chunk.field().clone(),
+            None => self.output_field(inputs)?,
+        };
+        let chunks = chunks
+            .into_iter()
+            .map(|chunk| chunk.unwrap_or_else(|| Series::full_null(&field.name, &field.dtype, 1)))
+            .collect::<Vec<_>>();
+        Series::concat(&chunks.iter().collect::<Vec<_>>())
+    }
+}
+
+/// Makes `expr` yield null for the rows it fails on rather than failing.
+///
+/// `expr` must be a function call, a cast, or a binary operation. Only the outermost of these is
+/// guarded, so errors in its inputs still fail the batch.
+pub fn try_(expr: ExprRef) -> DaftResult<ExprRef> {
+    let (op, inputs) = match expr.as_ref() {
+        Expr::ScalarFunction(func) => (TryOp::Function(func.udf.clone()), func.inputs.clone()),
+        Expr::Cast(input, dtype) => (TryOp::Cast(dtype.clone()), vec![input.clone()]),
+        Expr::BinaryOp { op, left, right } => {
+            (TryOp::BinaryOp(*op), vec![left.clone(), right.clone()])
+        }
+        Expr::Alias(inner, name) => return Ok(try_(inner.clone())?.alias(name.clone())),
+        _ => {
+            return Err(DaftError::ValueError(format!(
+                "try_ can only be applied to function calls, casts and binary operations, received {expr}"
+            )))
+        }
+    };
+    Ok(ScalarFunction::new(TryExpr { op }, inputs).into())
+}
+
+/// Casts `expr` to `dtype`, yielding null for the values that can't be cast.
+pub fn try_cast(expr: ExprRef, dtype: DataType) -> ExprRef {
+    ScalarFunction::new(
+        TryExpr {
+            op: TryOp::Cast(dtype),
+        },
+        vec![expr],
+    )
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{binary_op, lit, resolved_col, Expr, Operator};
+
+    use super::*;
+    use crate::utf8::to_date;
+
+    fn evaluate(expr: &ExprRef, inputs: &[Series]) -> DaftResult<Series> {
+        let Expr::ScalarFunction(func) = expr.as_ref() else {
+            panic!("expected a scalar function");
+        };
+        func.udf.evaluate(inputs)
+    }
+
+    #[test]
+    fn try_nulls_out_failed_rows() -> DaftResult<()> {
+        let expr = try_(to_date(resolved_col("s"), "%Y-%m-%d"))?;
+        let schema = Schema::new(vec![Field::new("s", DataType::Utf8)]);
+        assert_eq!(expr.to_field(&schema)?, Field::new("s", DataType::Date));
+
+        let input = Utf8Array::from_iter(
+            "s",
+            vec![
+                Some("2024-01-01"),
+                Some("bad"),
+                None,
+                Some("2024-01-03"),
+                Some("worse"),
+            ]
+            .into_iter(),
+        )
+        .into_series();
+        let before = try_error_count();
+        let result = evaluate(&expr, &[input])?;
+        assert!(try_error_count() - before >= 2);
+        assert_eq!(result.field(), &Field::new("s", DataType::Date));
+        assert_eq!(
+            result
+                .as_physical()?
+                .i32()?
+                .into_iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(19723), None, None, Some(19725), None]
+        );
+
+        // a batch where every row fails still has the output type
+        let input = Utf8Array::from(("s", &["bad", "worse"][..])).into_series();
+        let result = evaluate(&expr, &[input])?;
+        assert_eq!(result.data_type(), &DataType::Date);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result.validity().map(|v| v.unset_bits()), Some(2));
+        Ok(())
+    }
+
+    #[test]
+    fn try_requires_a_guardable_expression() {
+        assert!(try_(resolved_col("a")).is_err());
+        assert!(try_(binary_op(Operator::Plus, resolved_col("a"), lit(1))).is_ok());
+        assert!(try_(resolved_col("a").cast(&DataType::Int64)).is_ok());
+    }
+}
@@ -1,4 +1,5 @@
 pub mod download;
+pub mod parse;
 pub mod upload;
 
 use daft_dsl::{functions::ScalarFunction, ExprRef};
@@ -0,0 +1,243 @@
+//! Expressions that extract the parts of URLs and decode percent-encoded strings.
+//!
+//! Strings that are not absolute URLs, such as relative paths, have no parts and yield null
+//! rather than failing, since clickstream and log data often contains malformed URLs.
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum UrlPart {
+    Scheme,
+    Host,
+    Path,
+    QueryParam(String),
+}
+
+impl UrlPart {
+    fn extract(&self, url: &str) -> Option<String> {
+        let url = Url::parse(url).ok()?;
+        match self {
+            Self::Scheme => Some(url.scheme().to_string()),
+            Self::Host => url.host_str().map(str::to_string),
+            Self::Path => Some(url.path().to_string()),
+            Self::QueryParam(name) => url
+                .query_pairs()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.into_owned()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct UrlParse {
+    part: UrlPart,
+}
+
+#[typetag::serde]
+impl ScalarUDF for UrlParse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        match self.part {
+            UrlPart::Scheme => "url_scheme",
+            UrlPart::Host => "url_host",
+            UrlPart::Path => "url_path",
+            UrlPart::QueryParam(_) => "url_query_param",
+        }
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        utf8_field(self.name(), inputs, schema)
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [input] => {
+                let array = input.utf8()?;
+                Ok(Utf8Array::from_iter(
+                    array.name(),
+                    array
+                        .as_arrow()
+                        .iter()
+                        .map(|url| url.and_then(|url| self.part.extract(url))),
+                )
+                .into_series())
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct UrlDecode;
+
+#[typetag::serde]
+impl ScalarUDF for UrlDecode {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "url_decode"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        utf8_field(self.name(), inputs, schema)
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [input] => {
+                let array = input.utf8()?;
+                let decoded = array
+                    .as_arrow()
+                    .iter()
+                    .map(|s| {
+                        s.map(|s| {
+                            percent_encoding::percent_decode_str(s)
+                                .decode_utf8()
+                                .map_err(|e| {
+                                    DaftError::ValueError(format!(
+                                        "Failed to decode {s:?} as UTF-8: {e}"
+                                    ))
+                                })
+                        })
+                        .transpose()
+                    })
+                    .collect::<DaftResult<Vec<_>>>()?;
+                Ok(Utf8Array::from_iter(array.name(), decoded.into_iter()).into_series())
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+fn utf8_field(name: &str, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+    match inputs {
+        [input] => match input.to_field(schema)? {
+            field if field.dtype == DataType::Utf8 => Ok(field),
+            field => Err(DaftError::TypeError(format!(
+                "Expected input to {name} to be utf8, got {}",
+                field.dtype
+            ))),
+        },
+        _ => Err(DaftError::SchemaMismatch(format!(
+            "Expected 1 input arg, got {}",
+            inputs.len()
+        ))),
+    }
+}
+
+fn url_part(input: ExprRef, part: UrlPart) -> ExprRef {
+    ScalarFunction::new(UrlParse { part }, vec![input]).into()
+}
+
+/// The scheme of a URL, such as `https`.
+#[must_use]
+pub fn url_scheme(input: ExprRef) -> ExprRef {
+    url_part(input, UrlPart::Scheme)
+}
+
+/// The host of a URL, or null if it has none.
+#[must_use]
+pub fn url_host(input: ExprRef) -> ExprRef {
+    url_part(input, UrlPart::Host)
+}
+
+/// The path of a URL, which is `/` for URLs like `https://example.com`.
+#[must_use]
+pub fn url_path(input: ExprRef) -> ExprRef {
+    url_part(input, UrlPart::Path)
+}
+
+/// The decoded value of the first query parameter of a URL called `name`, or null if it has none.
+#[must_use]
+pub fn url_query_param<S: Into<String>>(input: ExprRef, name: S) -> ExprRef {
+    url_part(input, UrlPart::QueryParam(name.into()))
+}
+
+/// Decodes the percent-encoded characters of a string, such as `%20`.
+#[must_use]
+pub fn url_decode(input: ExprRef) -> ExprRef {
+    ScalarFunction::new(UrlDecode, vec![input]).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{resolved_col, Expr};
+
+    use super::*;
+
+    fn evaluate(expr: &ExprRef, values: &[Option<&str>]) -> DaftResult<Vec<Option<String>>> {
+        let Expr::ScalarFunction(func) = expr.as_ref() else {
+            panic!("expected a scalar function");
+        };
+        let input = Utf8Array::from_iter("url", values.iter().copied()).into_series();
+        let result = func.udf.evaluate(&[input])?;
+        Ok(result
+            .utf8()?
+            .into_iter()
+            .map(|v| v.map(str::to_string))
+            .collect())
+    }
+
+    #[test]
+    fn url_parts() -> DaftResult<()> {
+        let urls = [
+            Some("https://Example.com:8080/a/b%20c?q=daft+rs&page=2&q=other#top"),
+            Some("mailto:someone@example.com"),
+            Some("/relative/path"),
+            None,
+        ];
+        let url = resolved_col("url");
+        assert_eq!(
+            evaluate(&url_scheme(url.clone()), &urls)?,
+            vec![Some("https".into()), Some("mailto".into()), None, None]
+        );
+        assert_eq!(
+            evaluate(&url_host(url.clone()), &urls)?,
+            vec![Some("example.com".into()), None, None, None]
+        );
+        assert_eq!(
+            evaluate(&url_path(url.clone()), &urls)?,
+            vec![
+                Some("/a/b%20c".into()),
+                Some("someone@example.com".into()),
+                None,
+                None
+            ]
+        );
+        assert_eq!(
+            evaluate(&url_query_param(url.clone(), "q"), &urls)?,
+            vec![Some("daft rs".into()), None, None, None]
+        );
+        assert_eq!(
+            evaluate(&url_query_param(url.clone(), "missing"), &urls)?,
+            vec![None, None, None, None]
+        );
+        assert_eq!(
+            evaluate(
+                &url_decode(url),
+                &[Some("a%20b%2Fc+d"), Some("caf%C3%A9"), None]
+            )?,
+            vec![Some("a b/c+d".into()), Some("café".into()), None]
+        );
+        Ok(())
+    }
+}
@@ -12,6 +12,9 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct Utf8Replace {
     pub regex: bool,
+    /// The maximum number of replacements to make in each string, or all of them if `None`.
+    #[serde(default)]
+    pub max_replacements: Option<usize>,
 }
 
 #[typetag::serde]
@@ -51,7 +54,9 @@ impl ScalarUDF for Utf8Replace {
 
     fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
         match inputs {
-            [data, pattern, replacement] => data.utf8_replace(pattern, replacement, self.regex),
+            [data, pattern, replacement] => {
+                data.utf8_replace(pattern, replacement, self.regex, self.max_replacements)
+            }
             _ => Err(DaftError::ValueError(format!(
                 "Expected 3 input args, got {}",
                 inputs.len()
@@ -66,6 +71,14 @@ pub fn utf8_replace(
     pattern: ExprRef,
     replacement: ExprRef,
     regex: bool,
+    max_replacements: Option<usize>,
 ) -> ExprRef {
-    ScalarFunction::new(Utf8Replace { regex }, vec![input, pattern, replacement]).into()
+    ScalarFunction::new(
+        Utf8Replace {
+            regex,
+            max_replacements,
+        },
+        vec![input, pattern, replacement],
+    )
+    .into()
 }
@@ -0,0 +1,623 @@
+//! Reads and writes the Avro object container files that Iceberg manifests and manifest lists are stored in.
+//!
+//! Only what those files use is supported: values are decoded into a generic [`Value`] by the schema of the file
+//! they are read from, and encoded by the schema of the file they are written to, whose fields carry Iceberg field
+//! ids so that readers can match them by id. Blocks are read with the null and deflate codecs, which are the ones
+//! Iceberg writers use, and written uncompressed.
+
+use std::{collections::HashMap, io::Read};
+
+use common_error::{DaftError, DaftResult};
+use serde_json::Value as Json;
+
+const MAGIC: &[u8; 4] = b"Obj\x01";
+const SYNC_SIZE: usize = 16;
+
+fn invalid(msg: impl Into<String>) -> DaftError {
+    DaftError::ValueError(format!("Invalid Avro file: {}", msg.into()))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Schema {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+    Fixed(usize),
+    Enum(Vec<String>),
+    Array(Box<Schema>),
+    Map(Box<Schema>),
+    Union(Vec<Schema>),
+    Record(Vec<RecordField>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordField {
+    pub name: String,
+    pub field_id: Option<i32>,
+    pub schema: Schema,
+}
+
+impl Schema {
+    pub fn parse(json: &Json) -> DaftResult<Self> {
+        Self::parse_with_names(json, &mut HashMap::new())
+    }
+
+    /// Parses `json`, resolving references to the named types defined earlier in the schema from `names`.
+    fn parse_with_names(json: &Json, names: &mut HashMap<String, Self>) -> DaftResult<Self> {
+        match json {
+            Json::String(name) => match name.as_str() {
+                "null" => Ok(Self::Null),
+                "boolean" => Ok(Self::Boolean),
+                "int" => Ok(Self::Int),
+                "long" => Ok(Self::Long),
+                "float" => Ok(Self::Float),
+                "double" => Ok(Self::Double),
+                "bytes" => Ok(Self::Bytes),
+                "string" => Ok(Self::String),
+                _ => names
+                    .get(name)
+                    .or_else(|| names.get(name.rsplit('.').next().unwrap_or(name)))
+                    .cloned()
+                    .ok_or_else(|| invalid(format!("unknown type {name}"))),
+            },
+            Json::Array(branches) => Ok(Self::Union(
+                branches
+                    .iter()
+                    .map(|branch| Self::parse_with_names(branch, names))
+                    .collect::<DaftResult<_>>()?,
+            )),
+            Json::Object(object) => {
+                let type_name = object
+                    .get("type")
+                    .ok_or_else(|| invalid("type without a type name"))?;
+                let schema = match type_name.as_str() {
+                    Some("record" | "error") => {
+                        let fields = object
+                            .get("fields")
+                            .and_then(Json::as_array)
+                            .ok_or_else(|| invalid("record without fields"))?;
+                        Self::Record(
+                            fields
+                                .iter()
+                                .map(|field| {
+                                    Ok(RecordField {
+                                        name: field
+                                            .get("name")
+                                            .and_then(Json::as_str)
+                                            .ok_or_else(|| invalid("record field without a name"))?
+                                            .to_string(),
+                                        field_id: field
+                                            .get("field-id")
+                                            .and_then(Json::as_i64)
+                                            .map(|id| id as i32),
+                                        schema: Self::parse_with_names(
+                                            field.get("type").ok_or_else(|| {
+                                                invalid("record field without a type")
+                                            })?,
+                                            names,
+                                        )?,
+                                    })
+                                })
+                                .collect::<DaftResult<_>>()?,
+                        )
+                    }
+                    Some("enum") => Self::Enum(
+                        object
+                            .get("symbols")
+                            .and_then(Json::as_array)
+                            .ok_or_else(|| invalid("enum without symbols"))?
+                            .iter()
+                            .map(|symbol| symbol.as_str().unwrap_or_default().to_string())
+                            .collect(),
+                    ),
+                    Some("fixed") => Self::Fixed(
+                        object
+                            .get("size")
+                            .and_then(Json::as_u64)
+                            .ok_or_else(|| invalid("fixed without a size"))?
+                            as usize,
+                    ),
+                    Some("array") => Self::Array(Box::new(Self::parse_with_names(
+                        object
+                            .get("items")
+                            .ok_or_else(|| invalid("array without items"))?,
+                        names,
+                    )?)),
+                    Some("map") => Self::Map(Box::new(Self::parse_with_names(
+                        object
+                            .get("values")
+                            .ok_or_else(|| invalid("map without values"))?,
+                        names,
+                    )?)),
+                    // Primitive types with attributes, such as logical types
+                    _ => Self::parse_with_names(type_name, names)?,
+                };
+                if let Some(name) = object.get("name").and_then(Json::as_str) {
+                    names.insert(name.to_string(), schema.clone());
+                    if let Some(namespace) = object.get("namespace").and_then(Json::as_str) {
+                        names.insert(format!("{namespace}.{name}"), schema.clone());
+                    }
+                }
+                Ok(schema)
+            }
+            _ => Err(invalid(format!("invalid schema {json}"))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Boolean(bool),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    Bytes(Vec<u8>),
+    String(String),
+    Array(Vec<Value>),
+    Map(Vec<(String, Value)>),
+    Record(Vec<Field>),
+}
+
+/// A field of a record, which is looked up by its Iceberg field id if it has one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub name: String,
+    pub field_id: Option<i32>,
+    pub value: Value,
+}
+
+impl Value {
+    pub fn record(fields: impl IntoIterator<Item = (&'static str, Self)>) -> Self {
+        Self::Record(
+            fields
+                .into_iter()
+                .map(|(name, value)| Field {
+                    name: name.to_string(),
+                    field_id: None,
+                    value,
+                })
+                .collect(),
+        )
+    }
+
+    /// The value of the field of a record with the field id `field_id`, or named `name` if the record was written
+    /// without field ids. Missing fields are null, as they are for fields added to the schema after the file was
+    /// written.
+    pub fn field(&self, field_id: i32, name: &str) -> &Self {
+        let Self::Record(fields) = self else {
+            return &Self::Null;
+        };
+        fields
+            .iter()
+            .find(|field| field.field_id == Some(field_id))
+            .or_else(|| {
+                fields
+                    .iter()
+                    .find(|field| field.field_id.is_none() && field.name == name)
+            })
+            .map_or(&Self::Null, |field| &field.value)
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Int(v) => Some(*v as i64),
+            Self::Long(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Boolean(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Bytes(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+fn encode_long(value: i64, buf: &mut Vec<u8>) {
+    let mut n = ((value << 1) ^ (value >> 63)) as u64;
+    while n & !0x7f != 0 {
+        buf.push(((n & 0x7f) | 0x80) as u8);
+        n >>= 7;
+    }
+    buf.push(n as u8);
+}
+
+fn encode_bytes(value: &[u8], buf: &mut Vec<u8>) {
+    encode_long(value.len() as i64, buf);
+    buf.extend_from_slice(value);
+}
+
+fn encode(value: &Value, schema: &Schema, buf: &mut Vec<u8>) -> DaftResult<()> {
+    let mismatch =
+        || DaftError::ValueError(format!("Cannot encode {value:?} as Avro type {schema:?}"));
+    match (schema, value) {
+        (Schema::Null, Value::Null) => {}
+        (Schema::Boolean, Value::Boolean(v)) => buf.push(*v as u8),
+        (Schema::Int, Value::Int(v)) => encode_long(*v as i64, buf),
+        (Schema::Long, Value::Long(v)) => encode_long(*v, buf),
+        (Schema::Long, Value::Int(v)) => encode_long(*v as i64, buf),
+        (Schema::Float, Value::Float(v)) => buf.extend_from_slice(&v.to_le_bytes()),
+        (Schema::Double, Value::Double(v)) => buf.extend_from_slice(&v.to_le_bytes()),
+        (Schema::Bytes, Value::Bytes(v)) => encode_bytes(v, buf),
+        (Schema::String, Value::String(v)) => encode_bytes(v.as_bytes(), buf),
+        (Schema::Fixed(size), Value::Bytes(v)) if v.len() == *size => buf.extend_from_slice(v),
+        (Schema::Enum(symbols), Value::String(v)) => encode_long(
+            symbols
+                .iter()
+                .position(|symbol| symbol == v)
+                .ok_or_else(mismatch)? as i64,
+            buf,
+        ),
+        (Schema::Array(items), Value::Array(values)) => {
+            if !values.is_empty() {
+                encode_long(values.len() as i64, buf);
+                for value in values {
+                    encode(value, items, buf)?;
+                }
+            }
+            encode_long(0, buf);
+        }
+        (Schema::Map(values_schema), Value::Map(values)) => {
+            if !values.is_empty() {
+                encode_long(values.len() as i64, buf);
+                for (key, value) in values {
+                    encode_bytes(key.as_bytes(), buf);
+                    encode(value, values_schema, buf)?;
+                }
+            }
+            encode_long(0, buf);
+        }
+        (Schema::Union(branches), value) => {
+            // Values are encoded as the first branch of the union that they are of, which for the optional fields
+            // of Iceberg files is null or the type of the field
+            let index = branches
+                .iter()
+                .position(|branch| match value {
+                    Value::Null => *branch == Schema::Null,
+                    _ => *branch != Schema::Null,
+                })
+                .ok_or_else(mismatch)?;
+            encode_long(index as i64, buf);
+            encode(value, &branches[index], buf)?;
+        }
+        (Schema::Record(fields), Value::Record(values)) if fields.len() == values.len() => {
+            for (field, value) in fields.iter().zip(values) {
+                encode(&value.value, &field.schema, buf)?;
+            }
+        }
+        _ => return Err(mismatch()),
+    }
+    Ok(())
+}
+
+/// Writes `values` to an Avro object container file of `schema`, with `metadata` in its header.
+pub fn write_container(
+    schema: &Json,
+    metadata: &[(&str, String)],
+    values: &[Value],
+) -> DaftResult<Vec<u8>> {
+    let parsed = Schema::parse(schema)?;
+    let mut buf = MAGIC.to_vec();
+
+    let schema_json = schema.to_string();
+    let header = [
+        ("avro.schema", schema_json.as_str()),
+        ("avro.codec", "null"),
+    ]
+    .into_iter()
+    .chain(metadata.iter().map(|(key, value)| (*key, value.as_str())))
+    .collect::<Vec<_>>();
+    encode_long(header.len() as i64, &mut buf);
+    for (key, value) in header {
+        encode_bytes(key.as_bytes(), &mut buf);
+        encode_bytes(value.as_bytes(), &mut buf);
+    }
+    encode_long(0, &mut buf);
+    let sync: [u8; SYNC_SIZE] = rand::random();
+    buf.extend_from_slice(&sync);
+
+    if !values.is_empty() {
+        let mut block = vec![];
+        for value in values {
+            encode(value, &parsed, &mut block)?;
+        }
+        encode_long(values.len() as i64, &mut buf);
+        encode_long(block.len() as i64, &mut buf);
+        buf.extend_from_slice(&block);
+        buf.extend_from_slice(&sync);
+    }
+    Ok(buf)
+}
+
+struct Decoder<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    fn take(&mut self, n: usize) -> DaftResult<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| invalid("unexpected end of data"))?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn long(&mut self) -> DaftResult<i64> {
+        let mut n = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = self.take(1)?[0];
+            n |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+            if shift > 63 {
+                return Err(invalid("varint is too long"));
+            }
+        }
+        Ok(((n >> 1) as i64) ^ -((n & 1) as i64))
+    }
+
+    fn len(&mut self) -> DaftResult<usize> {
+        usize::try_from(self.long()?).map_err(|_| invalid("negative length"))
+    }
+
+    fn bytes(&mut self) -> DaftResult<&'a [u8]> {
+        let len = self.len()?;
+        self.take(len)
+    }
+
+    fn string(&mut self) -> DaftResult<String> {
+        String::from_utf8(self.bytes()?.to_vec()).map_err(|_| invalid("string is not UTF-8"))
+    }
+
+    /// The number of items in the next block of an array or map, or 0 once there are no more blocks.
+    fn block_len(&mut self) -> DaftResult<usize> {
+        let len = self.long()?;
+        if len < 0 {
+            // Blocks with a negative count are followed by their size in bytes
+            self.long()?;
+        }
+        Ok(len.unsigned_abs() as usize)
+    }
+
+    fn value(&mut self, schema: &Schema) -> DaftResult<Value> {
+        Ok(match schema {
+            Schema::Null => Value::Null,
+            Schema::Boolean => Value::Boolean(self.take(1)?[0] != 0),
+            Schema::Int => Value::Int(self.long()? as i32),
+            Schema::Long => Value::Long(self.long()?),
+            Schema::Float => Value::Float(f32::from_le_bytes(self.take(4)?.try_into().unwrap())),
+            Schema::Double => Value::Double(f64::from_le_bytes(self.take(8)?.try_into().unwrap())),
+            Schema::Bytes => Value::Bytes(self.bytes()?.to_vec()),
+            Schema::String => Value::String(self.string()?),
+            Schema::Fixed(size) => Value::Bytes(self.take(*size)?.to_vec()),
+            Schema::Enum(symbols) => Value::String(
+                symbols
+                    .get(self.len()?)
+                    .ok_or_else(|| invalid("enum index out of range"))?
+                    .clone(),
+            ),
+            Schema::Array(items) => {
+                let mut values = vec![];
+                loop {
+                    let len = self.block_len()?;
+                    if len == 0 {
+                        break;
+                    }
+                    for _ in 0..len {
+                        values.push(self.value(items)?);
+                    }
+                }
+                Value::Array(values)
+            }
+            Schema::Map(values_schema) => {
+                let mut values = vec![];
+                loop {
+                    let len = self.block_len()?;
+                    if len == 0 {
+                        break;
+                    }
+                    for _ in 0..len {
+                        let key = self.string()?;
+                        values.push((key, self.value(values_schema)?));
+                    }
+                }
+                Value::Map(values)
+            }
+            Schema::Union(branches) => {
+                let branch = branches
+                    .get(self.len()?)
+                    .ok_or_else(|| invalid("union index out of range"))?;
+                self.value(branch)?
+            }
+            Schema::Record(fields) => Value::Record(
+                fields
+                    .iter()
+                    .map(|field| {
+                        Ok(Field {
+                            name: field.name.clone(),
+                            field_id: field.field_id,
+                            value: self.value(&field.schema)?,
+                        })
+                    })
+                    .collect::<DaftResult<_>>()?,
+            ),
+        })
+    }
+}
+
+/// The metadata in the header of an Avro object container file, by key.
+pub type Metadata = HashMap<String, Vec<u8>>;
+
+/// The values of an Avro object container file, and the metadata in its header.
+pub fn read_container(data: &[u8]) -> DaftResult<(Metadata, Vec<Value>)> {
+    let mut decoder = Decoder::new(data);
+    if decoder.take(MAGIC.len())? != MAGIC {
+        return Err(invalid("not an Avro object container file"));
+    }
+    let mut metadata = HashMap::new();
+    loop {
+        let len = decoder.block_len()?;
+        if len == 0 {
+            break;
+        }
+        for _ in 0..len {
+            let key = decoder.string()?;
+            metadata.insert(key, decoder.bytes()?.to_vec());
+        }
+    }
+    let sync = decoder.take(SYNC_SIZE)?;
+
+    let schema_json: Json = serde_json::from_slice(
+        metadata
+            .get("avro.schema")
+            .ok_or_else(|| invalid("missing schema"))?,
+    )
+    .map_err(|err| invalid(format!("invalid schema: {err}")))?;
+    let schema = Schema::parse(&schema_json)?;
+    let codec = metadata
+        .get("avro.codec")
+        .map(|codec| String::from_utf8_lossy(codec).into_owned())
+        .unwrap_or_else(|| "null".to_string());
+
+    let mut values = vec![];
+    while !decoder.is_empty() {
+        let count = decoder.len()?;
+        let block = decoder.bytes()?;
+        let block = match codec.as_str() {
+            "null" => block.to_vec(),
+            "deflate" => {
+                let mut decompressed = vec![];
+                flate2::read::DeflateDecoder::new(block)
+                    .read_to_end(&mut decompressed)
+                    .map_err(|err| invalid(format!("invalid deflate block: {err}")))?;
+                decompressed
+            }
+            codec => {
+                return Err(DaftError::NotImplemented(format!(
+                    "Reading Avro files compressed with {codec} is not supported"
+                )))
+            }
+        };
+        let mut block_decoder = Decoder::new(&block);
+        for _ in 0..count {
+            values.push(block_decoder.value(&schema)?);
+        }
+        if decoder.take(SYNC_SIZE)? != sync {
+            return Err(invalid("sync marker mismatch"));
+        }
+    }
+    Ok((metadata, values))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{read_container, write_container, Value};
+
+    #[test]
+    fn test_container_roundtrip() {
+        let schema = json!({
+            "type": "record",
+            "name": "entry",
+            "fields": [
+                {"name": "status", "type": "int", "field-id": 0},
+                {"name": "snapshot_id", "type": ["null", "long"], "default": null, "field-id": 1},
+                {"name": "path", "type": "string", "field-id": 100},
+                {"name": "sizes", "type": ["null", {
+                    "type": "array",
+                    "logicalType": "map",
+                    "items": {
+                        "type": "record",
+                        "name": "k117_v118",
+                        "fields": [
+                            {"name": "key", "type": "int", "field-id": 117},
+                            {"name": "value", "type": "long", "field-id": 118}
+                        ]
+                    }
+                }], "default": null, "field-id": 108},
+                {"name": "uuid", "type": {"type": "fixed", "name": "uuid_fixed", "size": 16}, "field-id": 2}
+            ]
+        });
+        let values = (0..3)
+            .map(|i| {
+                Value::record([
+                    ("status", Value::Int(i)),
+                    (
+                        "snapshot_id",
+                        if i == 1 {
+                            Value::Null
+                        } else {
+                            Value::Long(-(i as i64) << 40)
+                        },
+                    ),
+                    ("path", Value::String(format!("s3://bucket/{i}.parquet"))),
+                    (
+                        "sizes",
+                        Value::Array(vec![Value::record([
+                            ("key", Value::Int(i + 1)),
+                            ("value", Value::Long(1024)),
+                        ])]),
+                    ),
+                    ("uuid", Value::Bytes(vec![i as u8; 16])),
+                ])
+            })
+            .collect::<Vec<_>>();
+
+        let data =
+            write_container(&schema, &[("format-version", "2".to_string())], &values).unwrap();
+        let (metadata, read) = read_container(&data).unwrap();
+        assert_eq!(metadata["format-version"], b"2");
+        assert_eq!(read.len(), 3);
+        assert_eq!(read[1].field(1, "snapshot_id"), &Value::Null);
+        assert_eq!(read[2].field(1, "snapshot_id").as_i64(), Some(-2 << 40));
+        assert_eq!(
+            read[0].field(100, "path").as_str(),
+            Some("s3://bucket/0.parquet")
+        );
+        let Value::Array(sizes) = read[2].field(108, "sizes") else {
+            panic!("sizes should be an array");
+        };
+        assert_eq!(sizes[0].field(117, "key").as_i64(), Some(3));
+        assert_eq!(read[1].field(2, "uuid").as_bytes(), Some(&[1u8; 16][..]));
+        // Fields are found by id, not name
+        assert_eq!(read[0].field(0, "renamed").as_i64(), Some(0));
+    }
+}
@@ -0,0 +1,408 @@
+//! Commits the data files of a write as a new snapshot of a table.
+//!
+//! The manifests and manifest list of the snapshot are written here, while the commit itself is left to the catalog
+//! of the table, as the requirements and updates of a catalog commit request. Catalogs only apply them if the table
+//! hasn't changed in ways that conflict with the write since its metadata was read.
+
+use std::{
+    collections::BTreeMap,
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use common_error::{DaftError, DaftResult};
+use common_file_formats::WriteMode;
+use daft_io::IOClient;
+use serde::Serialize;
+use serde_json::{json, Value as Json};
+
+use crate::{
+    datum::Datum,
+    manifest::{
+        manifest_file, partition_primitive_types, read_manifest, read_manifest_list,
+        write_manifest, write_manifest_list, DataFile, EntryStatus, ManifestContent, ManifestEntry,
+        ManifestFile,
+    },
+    spec::{PartitionSpec, Schema, MAIN_BRANCH},
+    writer::create_local_dir,
+    IcebergWrite,
+};
+
+/// A file that a commit adds to or removes from a table.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileOperation {
+    /// `ADD` or `DELETE`.
+    pub operation: &'static str,
+    pub file_path: String,
+    pub record_count: i64,
+    pub file_size_in_bytes: i64,
+    /// The partition values of the file, by partition field name.
+    pub partition: BTreeMap<String, Json>,
+}
+
+/// A commit of a write, as the requirements and updates of a catalog commit request.
+#[derive(Debug, Clone, Serialize)]
+pub struct Commit {
+    pub requirements: Vec<Json>,
+    pub updates: Vec<Json>,
+    pub operations: Vec<FileOperation>,
+}
+
+fn file_operation(
+    operation: &'static str,
+    data_file: &DataFile,
+    spec: &PartitionSpec,
+) -> FileOperation {
+    FileOperation {
+        operation,
+        file_path: data_file.file_path.clone(),
+        record_count: data_file.record_count,
+        file_size_in_bytes: data_file.file_size_in_bytes,
+        partition: spec
+            .fields
+            .iter()
+            .zip(&data_file.partition)
+            .map(|(field, value)| {
+                (
+                    field.name.clone(),
+                    value.as_ref().map_or(Json::Null, Datum::to_json),
+                )
+            })
+            .collect(),
+    }
+}
+
+/// The manifests of a snapshot that a write commits on top of, which are kept, rewritten or dropped depending on
+/// the mode of the write.
+struct ParentManifest {
+    manifest: ManifestFile,
+    schema: Schema,
+    spec: PartitionSpec,
+    entries: Vec<ManifestEntry>,
+}
+
+async fn read_file(io_client: &IOClient, path: &str) -> DaftResult<Vec<u8>> {
+    Ok(io_client
+        .single_url_get(path.to_string(), None, None)
+        .await?
+        .bytes()
+        .await?
+        .to_vec())
+}
+
+async fn write_file(io_client: &IOClient, path: &str, data: Vec<u8>) -> DaftResult<()> {
+    create_local_dir(path)?;
+    io_client.single_url_put(path, data.into(), None).await?;
+    Ok(())
+}
+
+/// Writes the manifests and manifest list of a snapshot that adds `data_files` to the table of `write`, and
+/// removes the files that the mode of the write replaces.
+pub(crate) async fn commit(
+    write: &IcebergWrite,
+    data_files: Vec<DataFile>,
+    io_client: Arc<IOClient>,
+) -> DaftResult<Commit> {
+    let metadata = &write.metadata;
+    let format_version = metadata.format_version;
+    let parent = metadata.main_snapshot();
+    let mut snapshot_id = rand::random::<i64>() & i64::MAX;
+    while snapshot_id == 0
+        || metadata
+            .snapshots
+            .iter()
+            .any(|snapshot| snapshot.snapshot_id == snapshot_id)
+    {
+        snapshot_id = rand::random::<i64>() & i64::MAX;
+    }
+    let sequence_number = if format_version >= 2 {
+        metadata.last_sequence_number + 1
+    } else {
+        0
+    };
+    let metadata_location = metadata.metadata_location();
+    let commit_id = uuid::Uuid::new_v4();
+    let mut num_manifests = 0;
+    let mut next_manifest_path = || {
+        num_manifests += 1;
+        format!(
+            "{metadata_location}/{commit_id}-m{}.avro",
+            num_manifests - 1
+        )
+    };
+
+    let partition_types = partition_primitive_types(&write.spec, &write.schema)?;
+    let written_partitions = data_files
+        .iter()
+        .map(|data_file| data_file.partition.clone())
+        .collect::<Vec<_>>();
+    let mut operations = data_files
+        .iter()
+        .map(|data_file| file_operation("ADD", data_file, &write.spec))
+        .collect::<Vec<_>>();
+    let mut manifests = vec![];
+    if !data_files.is_empty() {
+        let entries = data_files
+            .into_iter()
+            .map(|data_file| ManifestEntry {
+                status: EntryStatus::Added,
+                snapshot_id: Some(snapshot_id),
+                // Added files inherit the sequence number of the snapshot once it is committed
+                sequence_number: None,
+                file_sequence_number: None,
+                data_file,
+            })
+            .collect::<Vec<_>>();
+        let path = next_manifest_path();
+        let data = write_manifest(
+            metadata,
+            &write.schema,
+            &write.spec,
+            ManifestContent::Data,
+            &entries,
+        )?;
+        let length = data.len() as i64;
+        write_file(&io_client, &path, data).await?;
+        manifests.push(manifest_file(
+            path,
+            length,
+            &write.spec,
+            &partition_types,
+            ManifestContent::Data,
+            snapshot_id,
+            sequence_number,
+            &entries,
+        ));
+    }
+
+    // Files are removed by rewriting the manifests that they are in, with entries that mark them as deleted
+    let mut parent_manifests = vec![];
+    if let Some(manifest_list) = parent.and_then(|parent| parent.manifest_list.as_ref()) {
+        for manifest in read_manifest_list(&read_file(&io_client, manifest_list).await?)? {
+            let entries = if write.mode == WriteMode::Append {
+                None
+            } else {
+                Some(read_manifest(
+                    &read_file(&io_client, &manifest.manifest_path).await?,
+                    &manifest,
+                    metadata,
+                )?)
+            };
+            parent_manifests.push((manifest, entries));
+        }
+    }
+    let mut rewritten: Vec<ParentManifest> = vec![];
+    let mut removed_delete_files = 0;
+    for (manifest, entries) in parent_manifests {
+        let Some((schema, spec, entries)) = entries else {
+            manifests.push(manifest);
+            continue;
+        };
+        let is_replaced = |entry: &ManifestEntry| match write.mode {
+            WriteMode::Append => false,
+            WriteMode::Overwrite => true,
+            WriteMode::OverwritePartitions => {
+                written_partitions.contains(&entry.data_file.partition)
+            }
+        };
+        let live = entries
+            .into_iter()
+            .filter(|entry| entry.status != EntryStatus::Deleted)
+            .collect::<Vec<_>>();
+        // Partitions of other specs can't be matched against the written ones
+        if write.mode == WriteMode::OverwritePartitions
+            && !written_partitions.is_empty()
+            && !live.is_empty()
+            && spec.spec_id != write.spec.spec_id
+        {
+            return Err(DaftError::NotImplemented(format!(
+                "Overwriting the partitions of an Iceberg table that has files written with another partition spec than the current one (spec {}) is not supported",
+                spec.spec_id
+            )));
+        }
+        if !live.iter().any(is_replaced) {
+            manifests.push(manifest);
+            continue;
+        }
+        let entries = live
+            .into_iter()
+            .map(|mut entry| {
+                if is_replaced(&entry) {
+                    entry.status = EntryStatus::Deleted;
+                    entry.snapshot_id = Some(snapshot_id);
+                    match manifest.content {
+                        ManifestContent::Data => {
+                            operations.push(file_operation("DELETE", &entry.data_file, &spec));
+                        }
+                        ManifestContent::Deletes => removed_delete_files += 1,
+                    }
+                } else {
+                    entry.status = EntryStatus::Existing;
+                }
+                entry
+            })
+            .collect::<Vec<_>>();
+        // Manifests are rewritten into one per partition spec and content, as deleted entries of a full overwrite
+        // would otherwise double the number of manifests of the table
+        match rewritten.iter_mut().find(|rewritten| {
+            rewritten.spec.spec_id == spec.spec_id && rewritten.manifest.content == manifest.content
+        }) {
+            Some(rewritten) => rewritten.entries.extend(entries),
+            None => rewritten.push(ParentManifest {
+                manifest,
+                schema,
+                spec,
+                entries,
+            }),
+        }
+    }
+    for ParentManifest {
+        manifest,
+        schema,
+        spec,
+        entries,
+    } in rewritten
+    {
+        let path = next_manifest_path();
+        let data = write_manifest(metadata, &schema, &spec, manifest.content, &entries)?;
+        let length = data.len() as i64;
+        write_file(&io_client, &path, data).await?;
+        manifests.push(manifest_file(
+            path,
+            length,
+            &spec,
+            &partition_primitive_types(&spec, &schema)?,
+            manifest.content,
+            snapshot_id,
+            sequence_number,
+            &entries,
+        ));
+    }
+
+    let manifest_list_path = format!("{metadata_location}/snap-{snapshot_id}-1-{commit_id}.avro");
+    let data = write_manifest_list(
+        format_version,
+        snapshot_id,
+        parent.map(|parent| parent.snapshot_id),
+        sequence_number,
+        &manifests,
+    )?;
+    write_file(&io_client, &manifest_list_path, data).await?;
+
+    let summary = summary(
+        parent.map(|parent| &parent.summary),
+        &operations,
+        removed_delete_files,
+    );
+    let timestamp_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_millis() as i64);
+    let mut snapshot = json!({
+        "snapshot-id": snapshot_id,
+        "timestamp-ms": timestamp_ms,
+        "manifest-list": manifest_list_path,
+        "summary": summary,
+        "schema-id": write.schema.schema_id,
+    });
+    if let Some(parent) = parent {
+        snapshot["parent-snapshot-id"] = json!(parent.snapshot_id);
+    }
+    if format_version >= 2 {
+        snapshot["sequence-number"] = json!(sequence_number);
+    }
+
+    let mut requirements = vec![
+        json!({"type": "assert-table-uuid", "uuid": metadata.table_uuid}),
+        json!({
+            "type": "assert-ref-snapshot-id",
+            "ref": MAIN_BRANCH,
+            "snapshot-id": parent.map(|parent| parent.snapshot_id),
+        }),
+    ];
+    let mut updates = vec![];
+    if let Some(last_column_id) = write.last_column_id {
+        requirements.extend([
+            json!({"type": "assert-current-schema-id", "current-schema-id": metadata.current_schema_id}),
+            json!({"type": "assert-last-assigned-field-id", "last-assigned-field-id": metadata.last_column_id}),
+        ]);
+        updates.extend([
+            json!({"action": "add-schema", "schema": write.schema.as_ref(), "last-column-id": last_column_id}),
+            // The schema that was just added
+            json!({"action": "set-current-schema", "schema-id": -1}),
+        ]);
+    }
+    updates.extend([
+        json!({"action": "add-snapshot", "snapshot": snapshot}),
+        json!({
+            "action": "set-snapshot-ref",
+            "ref-name": MAIN_BRANCH,
+            "type": "branch",
+            "snapshot-id": snapshot_id,
+        }),
+    ]);
+
+    Ok(Commit {
+        requirements,
+        updates,
+        operations,
+    })
+}
+
+/// The summary of a snapshot, with the totals of the table carried over from the summary of its parent.
+fn summary(
+    parent_summary: Option<&BTreeMap<String, String>>,
+    operations: &[FileOperation],
+    removed_delete_files: i64,
+) -> BTreeMap<String, String> {
+    let sum = |operation: &str, value: fn(&FileOperation) -> i64| -> (i64, i64) {
+        let files = operations.iter().filter(|op| op.operation == operation);
+        (files.clone().count() as i64, files.map(value).sum())
+    };
+    let (added_files, added_records) = sum("ADD", |op| op.record_count);
+    let (_, added_size) = sum("ADD", |op| op.file_size_in_bytes);
+    let (deleted_files, deleted_records) = sum("DELETE", |op| op.record_count);
+    let (_, removed_size) = sum("DELETE", |op| op.file_size_in_bytes);
+
+    let mut summary = BTreeMap::new();
+    summary.insert(
+        "operation".to_string(),
+        if deleted_files > 0 || removed_delete_files > 0 {
+            "overwrite"
+        } else {
+            "append"
+        }
+        .to_string(),
+    );
+    for (key, value) in [
+        ("added-data-files", added_files),
+        ("added-records", added_records),
+        ("added-files-size", added_size),
+        ("deleted-data-files", deleted_files),
+        ("deleted-records", deleted_records),
+        ("removed-files-size", removed_size),
+        ("removed-delete-files", removed_delete_files),
+    ] {
+        if value > 0 {
+            summary.insert(key.to_string(), value.to_string());
+        }
+    }
+
+    // Totals are only kept if the parent has them, or there is no parent, as they would be wrong otherwise
+    for (key, delta) in [
+        ("total-data-files", added_files - deleted_files),
+        ("total-records", added_records - deleted_records),
+        ("total-files-size", added_size - removed_size),
+        ("total-delete-files", -removed_delete_files),
+    ] {
+        let total = match parent_summary {
+            None => Some(0),
+            Some(parent_summary) => parent_summary
+                .get(key)
+                .and_then(|total| total.parse::<i64>().ok()),
+        };
+        if let Some(total) = total {
+            summary.insert(key.to_string(), (total + delta).max(0).to_string());
+        }
+    }
+    summary
+}
@@ -0,0 +1,318 @@
+//! Single values of Iceberg primitive types, which partition values and column bounds are made of.
+
+use std::cmp::Ordering;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{datatypes::Decimal128Array, prelude::DataType, series::Series};
+use serde::{Deserialize, Serialize};
+
+use crate::{avro, spec::PrimitiveType};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Datum {
+    Boolean(bool),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    Decimal(i128),
+    String(String),
+    Bytes(Vec<u8>),
+}
+
+/// The number of bytes that decimals of `precision` are stored in as Avro fixed values.
+pub(crate) fn decimal_required_bytes(precision: u8) -> usize {
+    (1..=16)
+        .find(|bytes| 2f64.powi(8 * *bytes as i32 - 1) >= 10f64.powi(precision as i32))
+        .unwrap_or(16)
+}
+
+impl Datum {
+    /// The value at `idx` of `series`, whose values are of Iceberg type `ty`, or None if it is null.
+    pub fn from_series(
+        series: &Series,
+        idx: usize,
+        ty: &PrimitiveType,
+    ) -> DaftResult<Option<Self>> {
+        Ok(match ty {
+            PrimitiveType::Boolean => series
+                .cast(&DataType::Boolean)?
+                .bool()?
+                .get(idx)
+                .map(Self::Boolean),
+            PrimitiveType::Int | PrimitiveType::Date => series
+                .cast(&DataType::Int32)?
+                .i32()?
+                .get(idx)
+                .map(Self::Int),
+            PrimitiveType::Long
+            | PrimitiveType::Time
+            | PrimitiveType::Timestamp
+            | PrimitiveType::Timestamptz
+            | PrimitiveType::TimestampNs
+            | PrimitiveType::TimestamptzNs => series
+                .cast(&DataType::Int64)?
+                .i64()?
+                .get(idx)
+                .map(Self::Long),
+            PrimitiveType::Float => series
+                .cast(&DataType::Float32)?
+                .f32()?
+                .get(idx)
+                .map(Self::Float),
+            PrimitiveType::Double => series
+                .cast(&DataType::Float64)?
+                .f64()?
+                .get(idx)
+                .map(Self::Double),
+            PrimitiveType::Decimal { precision, scale } => series
+                .cast(&DataType::Decimal128(*precision as usize, *scale as usize))?
+                .downcast::<Decimal128Array>()?
+                .get(idx)
+                .map(Self::Decimal),
+            PrimitiveType::String => series
+                .cast(&DataType::Utf8)?
+                .utf8()?
+                .get(idx)
+                .map(|v| Self::String(v.to_string())),
+            PrimitiveType::Binary => series
+                .cast(&DataType::Binary)?
+                .binary()?
+                .get(idx)
+                .map(|v| Self::Bytes(v.to_vec())),
+            PrimitiveType::Fixed(_) | PrimitiveType::Uuid => {
+                let array = series.to_arrow();
+                let array = array
+                    .as_any()
+                    .downcast_ref::<arrow2::array::FixedSizeBinaryArray>()
+                    .ok_or_else(|| {
+                        DaftError::TypeError(format!(
+                            "Expected fixed size binary values for Iceberg type {ty}, got {}",
+                            series.data_type()
+                        ))
+                    })?;
+                array.get(idx).map(|v| Self::Bytes(v.to_vec()))
+            }
+        })
+    }
+
+    /// The value serialized as Iceberg serializes single values in column bounds and partition summaries.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Self::Boolean(v) => vec![*v as u8],
+            Self::Int(v) => v.to_le_bytes().to_vec(),
+            Self::Long(v) => v.to_le_bytes().to_vec(),
+            Self::Float(v) => v.to_le_bytes().to_vec(),
+            Self::Double(v) => v.to_le_bytes().to_vec(),
+            Self::Decimal(v) => {
+                // The minimum number of bytes of the two's complement big-endian representation
+                let bytes = v.to_be_bytes();
+                let sign = if *v < 0 { 0xff } else { 0x00 };
+                let start = bytes
+                    .windows(2)
+                    .position(|pair| pair[0] != sign || (pair[1] & 0x80 != 0) != (sign == 0xff))
+                    .unwrap_or(bytes.len() - 1);
+                bytes[start..].to_vec()
+            }
+            Self::String(v) => v.as_bytes().to_vec(),
+            Self::Bytes(v) => v.clone(),
+        }
+    }
+
+    pub fn to_avro(&self, ty: &PrimitiveType) -> avro::Value {
+        match (self, ty) {
+            (Self::Boolean(v), _) => avro::Value::Boolean(*v),
+            (Self::Int(v), _) => avro::Value::Int(*v),
+            (Self::Long(v), _) => avro::Value::Long(*v),
+            (Self::Float(v), _) => avro::Value::Float(*v),
+            (Self::Double(v), _) => avro::Value::Double(*v),
+            (Self::Decimal(v), PrimitiveType::Decimal { precision, .. }) => {
+                let size = decimal_required_bytes(*precision);
+                avro::Value::Bytes(v.to_be_bytes()[16 - size..].to_vec())
+            }
+            (Self::Decimal(v), _) => avro::Value::Bytes(v.to_be_bytes().to_vec()),
+            (Self::String(v), _) => avro::Value::String(v.clone()),
+            (Self::Bytes(v), _) => avro::Value::Bytes(v.clone()),
+        }
+    }
+
+    pub fn from_avro(value: &avro::Value, ty: &PrimitiveType) -> DaftResult<Option<Self>> {
+        let mismatch = || {
+            DaftError::ValueError(format!(
+                "Invalid Iceberg value of type {ty} in manifest: {value:?}"
+            ))
+        };
+        Ok(Some(match (value, ty) {
+            (avro::Value::Null, _) => return Ok(None),
+            (avro::Value::Boolean(v), PrimitiveType::Boolean) => Self::Boolean(*v),
+            (avro::Value::Int(v), PrimitiveType::Int | PrimitiveType::Date) => Self::Int(*v),
+            (
+                avro::Value::Long(v),
+                PrimitiveType::Long
+                | PrimitiveType::Time
+                | PrimitiveType::Timestamp
+                | PrimitiveType::Timestamptz
+                | PrimitiveType::TimestampNs
+                | PrimitiveType::TimestamptzNs,
+            ) => Self::Long(*v),
+            (avro::Value::Float(v), PrimitiveType::Float) => Self::Float(*v),
+            (avro::Value::Double(v), PrimitiveType::Double) => Self::Double(*v),
+            (avro::Value::Bytes(v), PrimitiveType::Decimal { .. }) if v.len() <= 16 => {
+                let sign = if v.first().is_some_and(|b| b & 0x80 != 0) {
+                    0xff
+                } else {
+                    0x00
+                };
+                let mut bytes = [sign; 16];
+                bytes[16 - v.len()..].copy_from_slice(v);
+                Self::Decimal(i128::from_be_bytes(bytes))
+            }
+            (avro::Value::String(v), PrimitiveType::String) => Self::String(v.clone()),
+            (avro::Value::String(v), PrimitiveType::Uuid) => Self::Bytes(
+                uuid::Uuid::parse_str(v)
+                    .map_err(|_| mismatch())?
+                    .as_bytes()
+                    .to_vec(),
+            ),
+            (
+                avro::Value::Bytes(v),
+                PrimitiveType::Binary | PrimitiveType::Fixed(_) | PrimitiveType::Uuid,
+            ) => Self::Bytes(v.clone()),
+            _ => return Err(mismatch()),
+        }))
+    }
+
+    /// The value as JSON, for reporting the partition values of files.
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::Boolean(v) => (*v).into(),
+            Self::Int(v) => (*v).into(),
+            Self::Long(v) => (*v).into(),
+            Self::Float(v) => (*v).into(),
+            Self::Double(v) => (*v).into(),
+            Self::Decimal(v) => v.to_string().into(),
+            Self::String(v) => v.clone().into(),
+            Self::Bytes(v) => v.clone().into(),
+        }
+    }
+
+    /// Compares values of the same type, as Iceberg orders them in bounds.
+    pub fn compare(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Boolean(a), Self::Boolean(b)) => a.partial_cmp(b),
+            (Self::Int(a), Self::Int(b)) => a.partial_cmp(b),
+            (Self::Long(a), Self::Long(b)) => a.partial_cmp(b),
+            (Self::Float(a), Self::Float(b)) => a.partial_cmp(b),
+            (Self::Double(a), Self::Double(b)) => a.partial_cmp(b),
+            (Self::Decimal(a), Self::Decimal(b)) => a.partial_cmp(b),
+            (Self::String(a), Self::String(b)) => a.partial_cmp(b),
+            (Self::Bytes(a), Self::Bytes(b)) => a.partial_cmp(b),
+            _ => None,
+        }
+    }
+
+    pub fn is_nan(&self) -> bool {
+        match self {
+            Self::Float(v) => v.is_nan(),
+            Self::Double(v) => v.is_nan(),
+            _ => false,
+        }
+    }
+
+    /// A lower bound of the value that is at most `len` characters or bytes long.
+    pub fn truncate_lower(self, len: usize) -> Self {
+        match self {
+            Self::String(v) => Self::String(v.chars().take(len).collect()),
+            Self::Bytes(v) => Self::Bytes(v.into_iter().take(len).collect()),
+            _ => self,
+        }
+    }
+
+    /// An upper bound of the value that is at most `len` characters or bytes long, or None if there is none.
+    pub fn truncate_upper(self, len: usize) -> Option<Self> {
+        match self {
+            Self::String(v) => {
+                let mut chars = v.chars().collect::<Vec<_>>();
+                if chars.len() <= len {
+                    return Some(Self::String(v));
+                }
+                chars.truncate(len);
+                // The truncated value is a bound once its last character that can be is incremented
+                while let Some(last) = chars.pop() {
+                    if let Some(next) =
+                        (last as u32 + 1..=char::MAX as u32).find_map(char::from_u32)
+                    {
+                        chars.push(next);
+                        return Some(Self::String(chars.into_iter().collect()));
+                    }
+                }
+                None
+            }
+            Self::Bytes(mut v) => {
+                if v.len() <= len {
+                    return Some(Self::Bytes(v));
+                }
+                v.truncate(len);
+                while let Some(last) = v.pop() {
+                    if last < u8::MAX {
+                        v.push(last + 1);
+                        return Some(Self::Bytes(v));
+                    }
+                }
+                None
+            }
+            _ => Some(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Datum;
+    use crate::{avro, spec::PrimitiveType};
+
+    #[test]
+    fn test_datum_bytes() {
+        assert_eq!(Datum::Int(1).to_bytes(), vec![1, 0, 0, 0]);
+        assert_eq!(Datum::Long(-1).to_bytes(), vec![0xff; 8]);
+        assert_eq!(Datum::Decimal(1234).to_bytes(), vec![0x04, 0xd2]);
+        assert_eq!(Datum::Decimal(-1234).to_bytes(), vec![0xfb, 0x2e]);
+        assert_eq!(Datum::Decimal(128).to_bytes(), vec![0x00, 0x80]);
+        assert_eq!(Datum::Decimal(0).to_bytes(), vec![0x00]);
+        assert_eq!(
+            Datum::String("iceberg".into()).to_bytes(),
+            b"iceberg".to_vec()
+        );
+    }
+
+    #[test]
+    fn test_decimal_avro_roundtrip() {
+        let ty = PrimitiveType::Decimal {
+            precision: 10,
+            scale: 2,
+        };
+        for value in [-123456, 0, 99999] {
+            let avro = Datum::Decimal(value).to_avro(&ty);
+            let avro::Value::Bytes(bytes) = &avro else {
+                panic!("decimals should be fixed values");
+            };
+            assert_eq!(bytes.len(), 5);
+            assert_eq!(
+                Datum::from_avro(&avro, &ty).unwrap(),
+                Some(Datum::Decimal(value))
+            );
+        }
+    }
+
+    #[test]
+    fn test_truncate_bounds() {
+        let value = Datum::String("abcdefgh".into());
+        assert_eq!(value.clone().truncate_lower(3), Datum::String("abc".into()));
+        assert_eq!(value.truncate_upper(3), Some(Datum::String("abd".into())));
+        assert_eq!(
+            Datum::Bytes(vec![1, 0xff, 3]).truncate_upper(2),
+            Some(Datum::Bytes(vec![2]))
+        );
+        assert_eq!(Datum::Bytes(vec![0xff, 0xff, 3]).truncate_upper(2), None);
+    }
+}
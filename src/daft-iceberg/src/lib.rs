@@ -0,0 +1,235 @@
+#![feature(let_chains)]
+//! Native writes to Iceberg tables.
+//!
+//! A write is planned from the metadata of the table it writes to, as an [`IcebergWrite`]: its data is written to
+//! Parquet data files by [`DataFileWriter`]s, whose descriptions are then committed to the table as a new snapshot
+//! by [`IcebergWrite::commit`]. The catalog of the table applies the commit, so the same write works with REST, Glue
+//! and Hive catalogs alike.
+
+mod avro;
+mod commit;
+mod datum;
+mod manifest;
+#[cfg(feature = "python")]
+pub mod python;
+mod schema;
+mod spec;
+mod writer;
+
+use std::sync::Arc;
+
+pub use commit::{Commit, FileOperation};
+use common_error::DaftResult;
+use common_file_formats::WriteMode;
+use daft_core::prelude::Schema as DaftSchema;
+use daft_io::{IOClient, IOStatsRef};
+use daft_recordbatch::RecordBatch;
+pub use manifest::DataFile;
+use serde::{Deserialize, Serialize};
+use spec::{PartitionSpec, Schema, TableMetadata};
+pub use writer::DataFileWriter;
+
+/// The name of the column of JSON-serialized [`DataFile`]s that the data files of a write are returned in.
+pub const DATA_FILE_COLUMN_NAME: &str = "data_file";
+
+/// A write to an Iceberg table, planned from the metadata of the table when the write started.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IcebergWrite {
+    metadata: TableMetadata,
+    /// The schema that data is written with, which has the columns of the data that the table didn't have yet if
+    /// the schema is merged.
+    schema: Arc<Schema>,
+    /// The id of the last column of the table once the schema is updated, or None if the schema is unchanged.
+    last_column_id: Option<i32>,
+    spec: PartitionSpec,
+    mode: WriteMode,
+}
+
+impl IcebergWrite {
+    /// Plans a write of data of `data_schema` to the table of `metadata_json`, the JSON of its metadata file.
+    ///
+    /// Columns of the data that the table doesn't have are dropped, unless `merge_schema` is set, in which case they
+    /// are added to the table as optional columns when the write is committed.
+    pub fn try_new(
+        metadata_json: &str,
+        data_schema: &DaftSchema,
+        mode: WriteMode,
+        merge_schema: bool,
+    ) -> DaftResult<Self> {
+        let metadata = TableMetadata::from_json(metadata_json)?;
+        let current = metadata.current_schema()?;
+        let next_schema_id = metadata
+            .schemas
+            .iter()
+            .map(|schema| schema.schema_id)
+            .max()
+            .unwrap_or(current.schema_id)
+            + 1;
+        let (schema, last_column_id) = match schema::write_schema(
+            current,
+            metadata.last_column_id,
+            next_schema_id,
+            data_schema,
+            merge_schema,
+        )? {
+            Some((schema, last_column_id)) => (schema, Some(last_column_id)),
+            None => (current.clone(), None),
+        };
+        let spec = metadata.default_spec()?;
+        // Fail before any data is written if the partition values or the compression can't be written
+        manifest::partition_primitive_types(&spec, &schema)?;
+        writer::parquet_compression(&metadata.properties)?;
+        Ok(Self {
+            schema: Arc::new(schema),
+            last_column_id,
+            spec,
+            mode,
+            metadata,
+        })
+    }
+
+    /// Creates a writer of the data file `file_idx` of the write, in the partition of `partition_values` if the
+    /// table is partitioned.
+    pub fn data_file_writer(
+        &self,
+        file_idx: usize,
+        partition_values: Option<&RecordBatch>,
+        io_client: Arc<IOClient>,
+        part_size: usize,
+        max_inflight_parts: usize,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<DataFileWriter> {
+        DataFileWriter::try_new(
+            self.schema.clone(),
+            &self.spec,
+            partition_values,
+            &self.metadata.data_location(),
+            file_idx,
+            writer::parquet_compression(&self.metadata.properties)?,
+            io_client,
+            part_size,
+            max_inflight_parts,
+            io_stats,
+        )
+    }
+
+    /// Writes the manifests of a snapshot of the table with `data_files`, returning the commit that the catalog of
+    /// the table has to apply to make it the current snapshot.
+    pub async fn commit(
+        &self,
+        data_files: Vec<DataFile>,
+        io_client: Arc<IOClient>,
+    ) -> DaftResult<Commit> {
+        commit::commit(self, data_files, io_client).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_file_formats::WriteMode;
+    use common_runtime::get_io_runtime;
+    use daft_core::prelude::*;
+    use daft_io::{IOClient, IOConfig};
+    use daft_recordbatch::RecordBatch;
+
+    use crate::{
+        manifest::{read_manifest, read_manifest_list, EntryStatus},
+        IcebergWrite,
+    };
+
+    fn metadata_json(location: &str) -> String {
+        format!(
+            r#"{{
+                "format-version": 2,
+                "table-uuid": "9c12d441-03fe-4693-9a96-a0705ddf69c1",
+                "location": "{location}",
+                "last-sequence-number": 0,
+                "last-updated-ms": 1602638573590,
+                "last-column-id": 2,
+                "current-schema-id": 0,
+                "schemas": [{{"type": "struct", "schema-id": 0, "fields": [
+                    {{"id": 1, "name": "id", "required": true, "type": "long"}},
+                    {{"id": 2, "name": "category", "required": false, "type": "string"}}
+                ]}}],
+                "default-spec-id": 0,
+                "partition-specs": [{{"spec-id": 0, "fields": [
+                    {{"name": "category", "transform": "identity", "source-id": 2, "field-id": 1000}}
+                ]}}],
+                "last-partition-id": 1000,
+                "properties": {{}},
+                "snapshots": [],
+                "refs": {{}}
+            }}"#
+        )
+    }
+
+    #[test]
+    fn test_write_and_commit_local_table() -> common_error::DaftResult<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let location = dir.path().to_str().unwrap().to_string();
+        let batch = RecordBatch::from_nonempty_columns(vec![
+            Int64Array::from(("id", vec![3, 1, 2])).into_series(),
+            Utf8Array::from(("category", ["a", "a", "a"].as_slice())).into_series(),
+        ])?;
+        let write = IcebergWrite::try_new(
+            &metadata_json(&location),
+            &batch.schema,
+            WriteMode::Append,
+            false,
+        )?;
+
+        // Writes are serialized to be sent to the writers of their data files
+        let json = serde_json::to_string(&write).unwrap();
+        let write: IcebergWrite = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&write).unwrap(), json);
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let partition_values = batch.head(1)?.get_columns(&["category"])?;
+        let mut writer = write.data_file_writer(
+            0,
+            Some(&partition_values),
+            io_client.clone(),
+            5 * 1024 * 1024,
+            4,
+            None,
+        )?;
+        writer.write(&batch)?;
+        let data_file = writer.close()?.unwrap();
+        assert!(data_file.file_path.contains("/data/category=a/"));
+        assert_eq!(data_file.record_count, 3);
+        assert!(std::path::Path::new(&data_file.file_path).exists());
+
+        let commit = get_io_runtime(true).block_on_current_thread({
+            let io_client = io_client.clone();
+            let write = write.clone();
+            async move { write.commit(vec![data_file], io_client).await }
+        })?;
+        assert_eq!(commit.operations.len(), 1);
+        assert_eq!(commit.operations[0].operation, "ADD");
+        let snapshot = &commit.updates[0]["snapshot"];
+        assert_eq!(snapshot["sequence-number"], 1);
+        assert_eq!(snapshot["summary"]["operation"], "append");
+        assert_eq!(snapshot["summary"]["total-records"], "3");
+
+        let manifest_list = std::fs::read(snapshot["manifest-list"].as_str().unwrap()).unwrap();
+        let manifests = read_manifest_list(&manifest_list)?;
+        assert_eq!(manifests.len(), 1);
+        assert_eq!(manifests[0].added_rows_count, 3);
+        let (_, spec, entries) = read_manifest(
+            &std::fs::read(&manifests[0].manifest_path).unwrap(),
+            &manifests[0],
+            &write.metadata,
+        )?;
+        assert_eq!(spec.spec_id, 0);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].status, EntryStatus::Added);
+        assert_eq!(entries[0].sequence_number, Some(1));
+        let data_file = &entries[0].data_file;
+        assert_eq!(data_file.record_count, 3);
+        assert_eq!(data_file.lower_bounds[&1], 1i64.to_le_bytes());
+        assert_eq!(data_file.upper_bounds[&1], 3i64.to_le_bytes());
+        Ok(())
+    }
+}
@@ -0,0 +1,987 @@
+//! Manifests, which list the data files of a table along with their partition values and column statistics, and
+//! manifest lists, which list the manifests of a snapshot.
+//!
+//! Both are Avro files whose schemas are fixed by the spec, apart from the partition values of manifest entries,
+//! whose record has a field for each field of the partition spec of the manifest.
+
+use std::collections::BTreeMap;
+
+use common_error::{DaftError, DaftResult};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as Json};
+
+use crate::{
+    avro::{self, Value},
+    datum::Datum,
+    spec::{PartitionField, PartitionSpec, PrimitiveType, Schema, TableMetadata, Type},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ManifestContent {
+    Data = 0,
+    Deletes = 1,
+}
+
+impl ManifestContent {
+    fn from_i64(value: i64) -> DaftResult<Self> {
+        match value {
+            0 => Ok(Self::Data),
+            1 => Ok(Self::Deletes),
+            _ => Err(DaftError::ValueError(format!(
+                "Invalid Iceberg manifest content: {value}"
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryStatus {
+    Existing = 0,
+    Added = 1,
+    Deleted = 2,
+}
+
+/// A data or delete file of a table, as described in manifests.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataFile {
+    /// 0 for data files, 1 for position delete files and 2 for equality delete files.
+    pub content: i32,
+    pub file_path: String,
+    pub file_format: String,
+    /// The partition values of the file, one for each field of the partition spec it was written with.
+    pub partition: Vec<Option<Datum>>,
+    pub record_count: i64,
+    pub file_size_in_bytes: i64,
+    pub column_sizes: BTreeMap<i32, i64>,
+    pub value_counts: BTreeMap<i32, i64>,
+    pub null_value_counts: BTreeMap<i32, i64>,
+    pub nan_value_counts: BTreeMap<i32, i64>,
+    pub lower_bounds: BTreeMap<i32, Vec<u8>>,
+    pub upper_bounds: BTreeMap<i32, Vec<u8>>,
+    pub key_metadata: Option<Vec<u8>>,
+    pub split_offsets: Option<Vec<i64>>,
+    pub equality_ids: Option<Vec<i32>>,
+    pub sort_order_id: Option<i32>,
+    pub referenced_data_file: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManifestEntry {
+    pub status: EntryStatus,
+    pub snapshot_id: Option<i64>,
+    /// The sequence number of the snapshot that added the file, which is inherited when the entry is read if the
+    /// file was added by the snapshot of the manifest.
+    pub sequence_number: Option<i64>,
+    pub file_sequence_number: Option<i64>,
+    pub data_file: DataFile,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldSummary {
+    pub contains_null: bool,
+    pub contains_nan: Option<bool>,
+    pub lower_bound: Option<Vec<u8>>,
+    pub upper_bound: Option<Vec<u8>>,
+}
+
+/// A manifest, as listed in manifest lists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestFile {
+    pub manifest_path: String,
+    pub manifest_length: i64,
+    pub partition_spec_id: i32,
+    pub content: ManifestContent,
+    pub sequence_number: i64,
+    pub min_sequence_number: i64,
+    pub added_snapshot_id: i64,
+    pub added_files_count: i32,
+    pub existing_files_count: i32,
+    pub deleted_files_count: i32,
+    pub added_rows_count: i64,
+    pub existing_rows_count: i64,
+    pub deleted_rows_count: i64,
+    pub partitions: Option<Vec<FieldSummary>>,
+    pub key_metadata: Option<Vec<u8>>,
+}
+
+fn optional(schema: Json) -> Json {
+    json!(["null", schema])
+}
+
+fn field(name: &str, field_id: i32, schema: Json) -> Json {
+    json!({"name": name, "type": schema, "field-id": field_id})
+}
+
+fn optional_field(name: &str, field_id: i32, schema: Json) -> Json {
+    json!({"name": name, "type": optional(schema), "default": null, "field-id": field_id})
+}
+
+/// Maps with keys that aren't strings are stored as arrays of key-value records.
+fn map_schema(key_id: i32, key: Json, value_id: i32, value: Json) -> Json {
+    json!({
+        "type": "array",
+        "logicalType": "map",
+        "items": {
+            "type": "record",
+            "name": format!("k{key_id}_v{value_id}"),
+            "fields": [field("key", key_id, key), field("value", value_id, value)]
+        }
+    })
+}
+
+fn list_schema(element_id: i32, element: Json) -> Json {
+    json!({"type": "array", "items": element, "element-id": element_id})
+}
+
+/// The Avro schema of partition values of `ty`, which is named after `field_id` when it is a named type.
+fn partition_value_schema(ty: &PrimitiveType, field_id: i32) -> Json {
+    match ty {
+        PrimitiveType::Boolean => json!("boolean"),
+        PrimitiveType::Int => json!("int"),
+        PrimitiveType::Long => json!("long"),
+        PrimitiveType::Float => json!("float"),
+        PrimitiveType::Double => json!("double"),
+        PrimitiveType::Decimal { precision, scale } => json!({
+            "type": "fixed",
+            "name": format!("decimal_{field_id}"),
+            "size": crate::datum::decimal_required_bytes(*precision),
+            "logicalType": "decimal",
+            "precision": precision,
+            "scale": scale
+        }),
+        PrimitiveType::Date => json!({"type": "int", "logicalType": "date"}),
+        PrimitiveType::Time => json!({"type": "long", "logicalType": "time-micros"}),
+        PrimitiveType::Timestamp => {
+            json!({"type": "long", "logicalType": "timestamp-micros", "adjust-to-utc": false})
+        }
+        PrimitiveType::Timestamptz => {
+            json!({"type": "long", "logicalType": "timestamp-micros", "adjust-to-utc": true})
+        }
+        PrimitiveType::TimestampNs => {
+            json!({"type": "long", "logicalType": "timestamp-nanos", "adjust-to-utc": false})
+        }
+        PrimitiveType::TimestamptzNs => {
+            json!({"type": "long", "logicalType": "timestamp-nanos", "adjust-to-utc": true})
+        }
+        PrimitiveType::String => json!("string"),
+        PrimitiveType::Uuid => json!({
+            "type": "fixed",
+            "name": format!("uuid_{field_id}"),
+            "size": 16,
+            "logicalType": "uuid"
+        }),
+        PrimitiveType::Fixed(size) => {
+            json!({"type": "fixed", "name": format!("fixed_{field_id}"), "size": size})
+        }
+        PrimitiveType::Binary => json!("bytes"),
+    }
+}
+
+/// Avro names may only contain letters, digits and underscores, and may not start with a digit.
+fn sanitize_name(name: &str) -> String {
+    let mut sanitized = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c.to_string()
+            } else {
+                format!("_x{:X}", c as u32)
+            }
+        })
+        .collect::<String>();
+    if sanitized.is_empty() || sanitized.starts_with(|c: char| c.is_ascii_digit()) {
+        sanitized.insert(0, '_');
+    }
+    sanitized
+}
+
+/// The types of the partition values of the fields of `spec`, which must all be primitive.
+pub fn partition_primitive_types(
+    spec: &PartitionSpec,
+    schema: &Schema,
+) -> DaftResult<Vec<PrimitiveType>> {
+    spec.partition_types(schema)?
+        .into_iter()
+        .map(|ty| match ty {
+            Type::Primitive(primitive) => Ok(primitive),
+            ty => Err(DaftError::ValueError(format!(
+                "Iceberg partition values of type {ty} are not supported"
+            ))),
+        })
+        .collect()
+}
+
+fn manifest_entry_schema(
+    format_version: u8,
+    fields: &[PartitionField],
+    partition_types: &[PrimitiveType],
+) -> Json {
+    let partition = json!({
+        "type": "record",
+        "name": "r102",
+        "fields": fields
+            .iter()
+            .zip(partition_types)
+            .map(|(field, ty)| optional_field(
+                &sanitize_name(&field.name),
+                field.field_id,
+                partition_value_schema(ty, field.field_id)
+            ))
+            .collect::<Vec<_>>()
+    });
+    let counts = |key_id: i32| map_schema(key_id, json!("int"), key_id + 1, json!("long"));
+    let bounds = |key_id: i32| map_schema(key_id, json!("int"), key_id + 1, json!("bytes"));
+
+    let mut data_file_fields = vec![];
+    if format_version >= 2 {
+        data_file_fields.push(field("content", 134, json!("int")));
+    }
+    data_file_fields.extend([
+        field("file_path", 100, json!("string")),
+        field("file_format", 101, json!("string")),
+        field("partition", 102, partition),
+        field("record_count", 103, json!("long")),
+        field("file_size_in_bytes", 104, json!("long")),
+    ]);
+    if format_version == 1 {
+        data_file_fields.push(field("block_size_in_bytes", 105, json!("long")));
+    }
+    data_file_fields.extend([
+        optional_field("column_sizes", 108, counts(117)),
+        optional_field("value_counts", 109, counts(119)),
+        optional_field("null_value_counts", 110, counts(121)),
+        optional_field("nan_value_counts", 137, counts(138)),
+        optional_field("lower_bounds", 125, bounds(126)),
+        optional_field("upper_bounds", 128, bounds(129)),
+        optional_field("key_metadata", 131, json!("bytes")),
+        optional_field("split_offsets", 132, list_schema(133, json!("long"))),
+    ]);
+    if format_version >= 2 {
+        data_file_fields.push(optional_field(
+            "equality_ids",
+            135,
+            list_schema(136, json!("int")),
+        ));
+    }
+    data_file_fields.push(optional_field("sort_order_id", 140, json!("int")));
+    if format_version >= 2 {
+        data_file_fields.push(optional_field("referenced_data_file", 143, json!("string")));
+    }
+
+    let data_file = json!({"type": "record", "name": "r2", "fields": data_file_fields});
+    let entry_fields = if format_version >= 2 {
+        vec![
+            field("status", 0, json!("int")),
+            optional_field("snapshot_id", 1, json!("long")),
+            optional_field("sequence_number", 3, json!("long")),
+            optional_field("file_sequence_number", 4, json!("long")),
+            field("data_file", 2, data_file),
+        ]
+    } else {
+        vec![
+            field("status", 0, json!("int")),
+            field("snapshot_id", 1, json!("long")),
+            field("data_file", 2, data_file),
+        ]
+    };
+    json!({"type": "record", "name": "manifest_entry", "fields": entry_fields})
+}
+
+fn manifest_file_schema(format_version: u8) -> Json {
+    let partitions = list_schema(
+        508,
+        json!({
+            "type": "record",
+            "name": "r508",
+            "fields": [
+                field("contains_null", 509, json!("boolean")),
+                optional_field("contains_nan", 518, json!("boolean")),
+                optional_field("lower_bound", 510, json!("bytes")),
+                optional_field("upper_bound", 511, json!("bytes")),
+            ]
+        }),
+    );
+    let fields = if format_version >= 2 {
+        vec![
+            field("manifest_path", 500, json!("string")),
+            field("manifest_length", 501, json!("long")),
+            field("partition_spec_id", 502, json!("int")),
+            field("content", 517, json!("int")),
+            field("sequence_number", 515, json!("long")),
+            field("min_sequence_number", 516, json!("long")),
+            field("added_snapshot_id", 503, json!("long")),
+            field("added_files_count", 504, json!("int")),
+            field("existing_files_count", 505, json!("int")),
+            field("deleted_files_count", 506, json!("int")),
+            field("added_rows_count", 512, json!("long")),
+            field("existing_rows_count", 513, json!("long")),
+            field("deleted_rows_count", 514, json!("long")),
+            optional_field("partitions", 507, partitions),
+            optional_field("key_metadata", 519, json!("bytes")),
+        ]
+    } else {
+        vec![
+            field("manifest_path", 500, json!("string")),
+            field("manifest_length", 501, json!("long")),
+            field("partition_spec_id", 502, json!("int")),
+            field("added_snapshot_id", 503, json!("long")),
+            optional_field("added_data_files_count", 504, json!("int")),
+            optional_field("existing_data_files_count", 505, json!("int")),
+            optional_field("deleted_data_files_count", 506, json!("int")),
+            optional_field("partitions", 507, partitions),
+            optional_field("added_rows_count", 512, json!("long")),
+            optional_field("existing_rows_count", 513, json!("long")),
+            optional_field("deleted_rows_count", 514, json!("long")),
+            optional_field("key_metadata", 519, json!("bytes")),
+        ]
+    };
+    json!({"type": "record", "name": "manifest_file", "fields": fields})
+}
+
+fn to_json(value: &impl Serialize) -> DaftResult<String> {
+    serde_json::to_string(value).map_err(|err| DaftError::ValueError(err.to_string()))
+}
+
+fn optional_value<T>(value: Option<T>, to_value: impl FnOnce(T) -> Value) -> Value {
+    value.map_or(Value::Null, to_value)
+}
+
+fn counts_value(counts: &BTreeMap<i32, i64>) -> Value {
+    Value::Array(
+        counts
+            .iter()
+            .map(|(key, value)| {
+                Value::record([("key", Value::Int(*key)), ("value", Value::Long(*value))])
+            })
+            .collect(),
+    )
+}
+
+fn bounds_value(bounds: &BTreeMap<i32, Vec<u8>>) -> Value {
+    Value::Array(
+        bounds
+            .iter()
+            .map(|(key, value)| {
+                Value::record([
+                    ("key", Value::Int(*key)),
+                    ("value", Value::Bytes(value.clone())),
+                ])
+            })
+            .collect(),
+    )
+}
+
+fn data_file_value(
+    format_version: u8,
+    data_file: &DataFile,
+    fields: &[PartitionField],
+    partition_types: &[PrimitiveType],
+) -> DaftResult<Value> {
+    if data_file.partition.len() != fields.len() {
+        return Err(DaftError::ValueError(format!(
+            "Data file {} has {} partition values, but its partition spec has {} fields",
+            data_file.file_path,
+            data_file.partition.len(),
+            fields.len()
+        )));
+    }
+    let partition = Value::Record(
+        fields
+            .iter()
+            .zip(partition_types)
+            .zip(&data_file.partition)
+            .map(|((field, ty), value)| avro::Field {
+                name: sanitize_name(&field.name),
+                field_id: Some(field.field_id),
+                value: optional_value(value.as_ref(), |value| value.to_avro(ty)),
+            })
+            .collect(),
+    );
+
+    let mut values = vec![];
+    if format_version >= 2 {
+        values.push(("content", Value::Int(data_file.content)));
+    }
+    values.extend([
+        ("file_path", Value::String(data_file.file_path.clone())),
+        ("file_format", Value::String(data_file.file_format.clone())),
+        ("partition", partition),
+        ("record_count", Value::Long(data_file.record_count)),
+        (
+            "file_size_in_bytes",
+            Value::Long(data_file.file_size_in_bytes),
+        ),
+    ]);
+    if format_version == 1 {
+        // Unused, but required by the first version of the format
+        values.push(("block_size_in_bytes", Value::Long(64 * 1024 * 1024)));
+    }
+    values.extend([
+        ("column_sizes", counts_value(&data_file.column_sizes)),
+        ("value_counts", counts_value(&data_file.value_counts)),
+        (
+            "null_value_counts",
+            counts_value(&data_file.null_value_counts),
+        ),
+        (
+            "nan_value_counts",
+            counts_value(&data_file.nan_value_counts),
+        ),
+        ("lower_bounds", bounds_value(&data_file.lower_bounds)),
+        ("upper_bounds", bounds_value(&data_file.upper_bounds)),
+        (
+            "key_metadata",
+            optional_value(data_file.key_metadata.clone(), Value::Bytes),
+        ),
+        (
+            "split_offsets",
+            optional_value(data_file.split_offsets.as_ref(), |offsets| {
+                Value::Array(offsets.iter().map(|offset| Value::Long(*offset)).collect())
+            }),
+        ),
+    ]);
+    if format_version >= 2 {
+        values.push((
+            "equality_ids",
+            optional_value(data_file.equality_ids.as_ref(), |ids| {
+                Value::Array(ids.iter().map(|id| Value::Int(*id)).collect())
+            }),
+        ));
+    }
+    values.push((
+        "sort_order_id",
+        optional_value(data_file.sort_order_id, Value::Int),
+    ));
+    if format_version >= 2 {
+        values.push((
+            "referenced_data_file",
+            optional_value(data_file.referenced_data_file.clone(), Value::String),
+        ));
+    }
+    Ok(Value::record(values))
+}
+
+/// Writes a manifest of `entries`, whose files were all written with `spec`.
+pub fn write_manifest(
+    metadata: &TableMetadata,
+    schema: &Schema,
+    spec: &PartitionSpec,
+    content: ManifestContent,
+    entries: &[ManifestEntry],
+) -> DaftResult<Vec<u8>> {
+    let format_version = metadata.format_version;
+    let partition_types = partition_primitive_types(spec, schema)?;
+    let avro_schema = manifest_entry_schema(format_version, &spec.fields, &partition_types);
+    let values = entries
+        .iter()
+        .map(|entry| {
+            let data_file = data_file_value(
+                format_version,
+                &entry.data_file,
+                &spec.fields,
+                &partition_types,
+            )?;
+            Ok(if format_version >= 2 {
+                Value::record([
+                    ("status", Value::Int(entry.status as i32)),
+                    (
+                        "snapshot_id",
+                        optional_value(entry.snapshot_id, Value::Long),
+                    ),
+                    (
+                        "sequence_number",
+                        optional_value(entry.sequence_number, Value::Long),
+                    ),
+                    (
+                        "file_sequence_number",
+                        optional_value(entry.file_sequence_number, Value::Long),
+                    ),
+                    ("data_file", data_file),
+                ])
+            } else {
+                Value::record([
+                    ("status", Value::Int(entry.status as i32)),
+                    (
+                        "snapshot_id",
+                        Value::Long(entry.snapshot_id.unwrap_or_default()),
+                    ),
+                    ("data_file", data_file),
+                ])
+            })
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+
+    let header = [
+        ("schema", to_json(schema)?),
+        ("schema-id", schema.schema_id.to_string()),
+        ("partition-spec", to_json(&spec.fields)?),
+        ("partition-spec-id", spec.spec_id.to_string()),
+        ("format-version", format_version.to_string()),
+        (
+            "content",
+            match content {
+                ManifestContent::Data => "data",
+                ManifestContent::Deletes => "deletes",
+            }
+            .to_string(),
+        ),
+    ];
+    avro::write_container(&avro_schema, &header, &values)
+}
+
+/// The entry of a manifest list for a manifest of `entries` written by the snapshot `snapshot_id` with sequence
+/// number `sequence_number`.
+#[allow(clippy::too_many_arguments)]
+pub fn manifest_file(
+    manifest_path: String,
+    manifest_length: i64,
+    spec: &PartitionSpec,
+    partition_types: &[PrimitiveType],
+    content: ManifestContent,
+    snapshot_id: i64,
+    sequence_number: i64,
+    entries: &[ManifestEntry],
+) -> ManifestFile {
+    let count = |status: EntryStatus| {
+        let files = entries.iter().filter(|entry| entry.status == status);
+        (
+            files.clone().count() as i32,
+            files.map(|entry| entry.data_file.record_count).sum::<i64>(),
+        )
+    };
+    let (added_files_count, added_rows_count) = count(EntryStatus::Added);
+    let (existing_files_count, existing_rows_count) = count(EntryStatus::Existing);
+    let (deleted_files_count, deleted_rows_count) = count(EntryStatus::Deleted);
+    let min_sequence_number = entries
+        .iter()
+        .filter(|entry| entry.status != EntryStatus::Deleted)
+        .map(|entry| entry.sequence_number.unwrap_or(sequence_number))
+        .min()
+        .unwrap_or(sequence_number);
+
+    let partitions = spec
+        .fields
+        .iter()
+        .enumerate()
+        .zip(partition_types)
+        .map(|((i, _), ty)| {
+            let mut summary = FieldSummary {
+                contains_null: false,
+                contains_nan: matches!(ty, PrimitiveType::Float | PrimitiveType::Double)
+                    .then_some(false),
+                lower_bound: None,
+                upper_bound: None,
+            };
+            let mut lower: Option<&Datum> = None;
+            let mut upper: Option<&Datum> = None;
+            for entry in entries {
+                match entry.data_file.partition.get(i).and_then(Option::as_ref) {
+                    None => summary.contains_null = true,
+                    Some(value) if value.is_nan() => summary.contains_nan = Some(true),
+                    Some(value) => {
+                        if lower.is_none_or(|lower| value.compare(lower).is_some_and(|o| o.is_lt()))
+                        {
+                            lower = Some(value);
+                        }
+                        if upper.is_none_or(|upper| value.compare(upper).is_some_and(|o| o.is_gt()))
+                        {
+                            upper = Some(value);
+                        }
+                    }
+                }
+            }
+            summary.lower_bound = lower.map(Datum::to_bytes);
+            summary.upper_bound = upper.map(Datum::to_bytes);
+            summary
+        })
+        .collect();
+
+    ManifestFile {
+        manifest_path,
+        manifest_length,
+        partition_spec_id: spec.spec_id,
+        content,
+        sequence_number,
+        min_sequence_number,
+        added_snapshot_id: snapshot_id,
+        added_files_count,
+        existing_files_count,
+        deleted_files_count,
+        added_rows_count,
+        existing_rows_count,
+        deleted_rows_count,
+        partitions: Some(partitions),
+        key_metadata: None,
+    }
+}
+
+fn manifest_file_value(format_version: u8, manifest: &ManifestFile) -> Value {
+    let partitions = optional_value(manifest.partitions.as_ref(), |partitions| {
+        Value::Array(
+            partitions
+                .iter()
+                .map(|summary| {
+                    Value::record([
+                        ("contains_null", Value::Boolean(summary.contains_null)),
+                        (
+                            "contains_nan",
+                            optional_value(summary.contains_nan, Value::Boolean),
+                        ),
+                        (
+                            "lower_bound",
+                            optional_value(summary.lower_bound.clone(), Value::Bytes),
+                        ),
+                        (
+                            "upper_bound",
+                            optional_value(summary.upper_bound.clone(), Value::Bytes),
+                        ),
+                    ])
+                })
+                .collect(),
+        )
+    });
+    let key_metadata = optional_value(manifest.key_metadata.clone(), Value::Bytes);
+    if format_version >= 2 {
+        Value::record([
+            (
+                "manifest_path",
+                Value::String(manifest.manifest_path.clone()),
+            ),
+            ("manifest_length", Value::Long(manifest.manifest_length)),
+            ("partition_spec_id", Value::Int(manifest.partition_spec_id)),
+            ("content", Value::Int(manifest.content as i32)),
+            ("sequence_number", Value::Long(manifest.sequence_number)),
+            (
+                "min_sequence_number",
+                Value::Long(manifest.min_sequence_number),
+            ),
+            ("added_snapshot_id", Value::Long(manifest.added_snapshot_id)),
+            ("added_files_count", Value::Int(manifest.added_files_count)),
+            (
+                "existing_files_count",
+                Value::Int(manifest.existing_files_count),
+            ),
+            (
+                "deleted_files_count",
+                Value::Int(manifest.deleted_files_count),
+            ),
+            ("added_rows_count", Value::Long(manifest.added_rows_count)),
+            (
+                "existing_rows_count",
+                Value::Long(manifest.existing_rows_count),
+            ),
+            (
+                "deleted_rows_count",
+                Value::Long(manifest.deleted_rows_count),
+            ),
+            ("partitions", partitions),
+            ("key_metadata", key_metadata),
+        ])
+    } else {
+        Value::record([
+            (
+                "manifest_path",
+                Value::String(manifest.manifest_path.clone()),
+            ),
+            ("manifest_length", Value::Long(manifest.manifest_length)),
+            ("partition_spec_id", Value::Int(manifest.partition_spec_id)),
+            ("added_snapshot_id", Value::Long(manifest.added_snapshot_id)),
+            (
+                "added_data_files_count",
+                Value::Int(manifest.added_files_count),
+            ),
+            (
+                "existing_data_files_count",
+                Value::Int(manifest.existing_files_count),
+            ),
+            (
+                "deleted_data_files_count",
+                Value::Int(manifest.deleted_files_count),
+            ),
+            ("partitions", partitions),
+            ("added_rows_count", Value::Long(manifest.added_rows_count)),
+            (
+                "existing_rows_count",
+                Value::Long(manifest.existing_rows_count),
+            ),
+            (
+                "deleted_rows_count",
+                Value::Long(manifest.deleted_rows_count),
+            ),
+            ("key_metadata", key_metadata),
+        ])
+    }
+}
+
+/// Writes the manifest list of the snapshot `snapshot_id`.
+pub fn write_manifest_list(
+    format_version: u8,
+    snapshot_id: i64,
+    parent_snapshot_id: Option<i64>,
+    sequence_number: i64,
+    manifests: &[ManifestFile],
+) -> DaftResult<Vec<u8>> {
+    let mut header = vec![
+        ("snapshot-id", snapshot_id.to_string()),
+        (
+            "parent-snapshot-id",
+            parent_snapshot_id.map_or_else(|| "null".to_string(), |id| id.to_string()),
+        ),
+        ("format-version", format_version.to_string()),
+    ];
+    if format_version >= 2 {
+        header.push(("sequence-number", sequence_number.to_string()));
+    }
+    let values = manifests
+        .iter()
+        .map(|manifest| manifest_file_value(format_version, manifest))
+        .collect::<Vec<_>>();
+    avro::write_container(&manifest_file_schema(format_version), &header, &values)
+}
+
+fn missing(field: &str) -> DaftError {
+    DaftError::ValueError(format!(
+        "Missing required field {field} in Iceberg manifest"
+    ))
+}
+
+fn read_i64(value: &Value, field_id: i32, name: &str) -> DaftResult<i64> {
+    value
+        .field(field_id, name)
+        .as_i64()
+        .ok_or_else(|| missing(name))
+}
+
+fn read_optional_i64(value: &Value, field_id: i32, name: &str) -> Option<i64> {
+    value.field(field_id, name).as_i64()
+}
+
+/// Reads the entries of a manifest list. Manifests of the first version of the format have no sequence numbers,
+/// which makes them 0.
+pub fn read_manifest_list(data: &[u8]) -> DaftResult<Vec<ManifestFile>> {
+    let (_, values) = avro::read_container(data)?;
+    values
+        .iter()
+        .map(|value| {
+            let partitions = match value.field(507, "partitions") {
+                Value::Array(partitions) => Some(
+                    partitions
+                        .iter()
+                        .map(|summary| FieldSummary {
+                            contains_null: summary
+                                .field(509, "contains_null")
+                                .as_bool()
+                                .unwrap_or_default(),
+                            contains_nan: summary.field(518, "contains_nan").as_bool(),
+                            lower_bound: summary
+                                .field(510, "lower_bound")
+                                .as_bytes()
+                                .map(<[u8]>::to_vec),
+                            upper_bound: summary
+                                .field(511, "upper_bound")
+                                .as_bytes()
+                                .map(<[u8]>::to_vec),
+                        })
+                        .collect(),
+                ),
+                _ => None,
+            };
+            Ok(ManifestFile {
+                manifest_path: value
+                    .field(500, "manifest_path")
+                    .as_str()
+                    .ok_or_else(|| missing("manifest_path"))?
+                    .to_string(),
+                manifest_length: read_i64(value, 501, "manifest_length")?,
+                partition_spec_id: read_i64(value, 502, "partition_spec_id")? as i32,
+                content: ManifestContent::from_i64(
+                    read_optional_i64(value, 517, "content").unwrap_or_default(),
+                )?,
+                sequence_number: read_optional_i64(value, 515, "sequence_number")
+                    .unwrap_or_default(),
+                min_sequence_number: read_optional_i64(value, 516, "min_sequence_number")
+                    .unwrap_or_default(),
+                added_snapshot_id: read_i64(value, 503, "added_snapshot_id")?,
+                added_files_count: read_optional_i64(value, 504, "added_files_count")
+                    .unwrap_or_default() as i32,
+                existing_files_count: read_optional_i64(value, 505, "existing_files_count")
+                    .unwrap_or_default() as i32,
+                deleted_files_count: read_optional_i64(value, 506, "deleted_files_count")
+                    .unwrap_or_default() as i32,
+                added_rows_count: read_optional_i64(value, 512, "added_rows_count")
+                    .unwrap_or_default(),
+                existing_rows_count: read_optional_i64(value, 513, "existing_rows_count")
+                    .unwrap_or_default(),
+                deleted_rows_count: read_optional_i64(value, 514, "deleted_rows_count")
+                    .unwrap_or_default(),
+                partitions,
+                key_metadata: value
+                    .field(519, "key_metadata")
+                    .as_bytes()
+                    .map(<[u8]>::to_vec),
+            })
+        })
+        .collect()
+}
+
+fn read_counts(value: &Value, field_id: i32, name: &str, key_id: i32) -> BTreeMap<i32, i64> {
+    match value.field(field_id, name) {
+        Value::Array(items) => items
+            .iter()
+            .filter_map(|item| {
+                Some((
+                    item.field(key_id, "key").as_i64()? as i32,
+                    item.field(key_id + 1, "value").as_i64()?,
+                ))
+            })
+            .collect(),
+        _ => BTreeMap::new(),
+    }
+}
+
+fn read_bounds(value: &Value, field_id: i32, name: &str, key_id: i32) -> BTreeMap<i32, Vec<u8>> {
+    match value.field(field_id, name) {
+        Value::Array(items) => items
+            .iter()
+            .filter_map(|item| {
+                Some((
+                    item.field(key_id, "key").as_i64()? as i32,
+                    item.field(key_id + 1, "value").as_bytes()?.to_vec(),
+                ))
+            })
+            .collect(),
+        _ => BTreeMap::new(),
+    }
+}
+
+fn read_list(value: &Value, field_id: i32, name: &str) -> Option<Vec<i64>> {
+    match value.field(field_id, name) {
+        Value::Array(items) => Some(items.iter().filter_map(Value::as_i64).collect()),
+        _ => None,
+    }
+}
+
+/// The schema and partition spec that a manifest was written with, which are in its header, or those of the
+/// table if a writer left them out.
+fn manifest_spec(
+    header: &std::collections::HashMap<String, Vec<u8>>,
+    manifest: &ManifestFile,
+    metadata: &TableMetadata,
+) -> DaftResult<(Schema, PartitionSpec)> {
+    let schema = match header.get("schema") {
+        Some(schema) => serde_json::from_slice(schema).map_err(|err| {
+            DaftError::ValueError(format!("Invalid schema in Iceberg manifest: {err}"))
+        })?,
+        None => metadata.current_schema()?.clone(),
+    };
+    let spec = match header.get("partition-spec") {
+        Some(fields) => PartitionSpec {
+            spec_id: manifest.partition_spec_id,
+            fields: serde_json::from_slice(fields).map_err(|err| {
+                DaftError::ValueError(format!("Invalid partition spec in Iceberg manifest: {err}"))
+            })?,
+        },
+        None => metadata
+            .partition_specs
+            .iter()
+            .find(|spec| spec.spec_id == manifest.partition_spec_id)
+            .cloned()
+            .map_or_else(|| metadata.default_spec(), Ok)?,
+    };
+    Ok((schema, spec))
+}
+
+/// Reads the entries of `manifest`, filling in the snapshot ids and sequence numbers that entries inherit from it.
+/// Returns the schema and partition spec that the manifest was written with along with them.
+pub fn read_manifest(
+    data: &[u8],
+    manifest: &ManifestFile,
+    metadata: &TableMetadata,
+) -> DaftResult<(Schema, PartitionSpec, Vec<ManifestEntry>)> {
+    let (header, values) = avro::read_container(data)?;
+    let (schema, spec) = manifest_spec(&header, manifest, metadata)?;
+    let partition_types = partition_primitive_types(&spec, &schema)?;
+
+    let entries = values
+        .iter()
+        .map(|value| {
+            let status = match read_i64(value, 0, "status")? {
+                0 => EntryStatus::Existing,
+                1 => EntryStatus::Added,
+                2 => EntryStatus::Deleted,
+                status => {
+                    return Err(DaftError::ValueError(format!(
+                        "Invalid Iceberg manifest entry status: {status}"
+                    )))
+                }
+            };
+            let snapshot_id =
+                read_optional_i64(value, 1, "snapshot_id").or(Some(manifest.added_snapshot_id));
+            // Only files added by the snapshot of the manifest inherit its sequence number
+            let inherited = (status == EntryStatus::Added).then_some(manifest.sequence_number);
+            let sequence_number = read_optional_i64(value, 3, "sequence_number")
+                .or(inherited)
+                .or_else(|| (metadata.format_version == 1).then_some(0));
+            let file_sequence_number = read_optional_i64(value, 4, "file_sequence_number")
+                .or(inherited)
+                .or_else(|| (metadata.format_version == 1).then_some(0));
+
+            let data_file = value.field(2, "data_file");
+            let partition_value = data_file.field(102, "partition");
+            let partition = spec
+                .fields
+                .iter()
+                .zip(&partition_types)
+                .map(|(field, ty)| {
+                    Datum::from_avro(
+                        partition_value.field(field.field_id, &sanitize_name(&field.name)),
+                        ty,
+                    )
+                })
+                .collect::<DaftResult<_>>()?;
+            Ok(ManifestEntry {
+                status,
+                snapshot_id,
+                sequence_number,
+                file_sequence_number,
+                data_file: DataFile {
+                    content: read_optional_i64(data_file, 134, "content").unwrap_or_default()
+                        as i32,
+                    file_path: data_file
+                        .field(100, "file_path")
+                        .as_str()
+                        .ok_or_else(|| missing("file_path"))?
+                        .to_string(),
+                    file_format: data_file
+                        .field(101, "file_format")
+                        .as_str()
+                        .ok_or_else(|| missing("file_format"))?
+                        .to_string(),
+                    partition,
+                    record_count: read_i64(data_file, 103, "record_count")?,
+                    file_size_in_bytes: read_i64(data_file, 104, "file_size_in_bytes")?,
+                    column_sizes: read_counts(data_file, 108, "column_sizes", 117),
+                    value_counts: read_counts(data_file, 109, "value_counts", 119),
+                    null_value_counts: read_counts(data_file, 110, "null_value_counts", 121),
+                    nan_value_counts: read_counts(data_file, 137, "nan_value_counts", 138),
+                    lower_bounds: read_bounds(data_file, 125, "lower_bounds", 126),
+                    upper_bounds: read_bounds(data_file, 128, "upper_bounds", 129),
+                    key_metadata: data_file
+                        .field(131, "key_metadata")
+                        .as_bytes()
+                        .map(<[u8]>::to_vec),
+                    split_offsets: read_list(data_file, 132, "split_offsets"),
+                    equality_ids: read_list(data_file, 135, "equality_ids")
+                        .map(|ids| ids.into_iter().map(|id| id as i32).collect()),
+                    sort_order_id: read_optional_i64(data_file, 140, "sort_order_id")
+                        .map(|id| id as i32),
+                    referenced_data_file: data_file
+                        .field(143, "referenced_data_file")
+                        .as_str()
+                        .map(str::to_string),
+                },
+            })
+        })
+        .collect::<DaftResult<_>>()?;
+    Ok((schema, spec, entries))
+}
@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use common_error::DaftError;
+use common_file_formats::WriteMode;
+use common_runtime::get_io_runtime;
+use daft_core::python::PySchema;
+use daft_io::{get_io_client, python::IOConfig};
+use pyo3::{prelude::*, PyTypeInfo};
+
+use crate::{DataFile, IcebergWrite};
+
+/// A native write to an Iceberg table, which is planned with the metadata of the table and committed once its data
+/// files are written.
+#[pyclass(module = "daft.daft", name = "IcebergWrite", frozen)]
+#[derive(Clone)]
+pub struct PyIcebergWrite {
+    pub write: Arc<IcebergWrite>,
+}
+
+#[pymethods]
+impl PyIcebergWrite {
+    #[new]
+    #[pyo3(signature = (metadata_json, schema, mode, merge_schema=false))]
+    fn new(
+        metadata_json: &str,
+        schema: PySchema,
+        mode: &str,
+        merge_schema: bool,
+    ) -> PyResult<Self> {
+        Ok(Self {
+            write: Arc::new(IcebergWrite::try_new(
+                metadata_json,
+                &schema.schema,
+                mode.parse::<WriteMode>()?,
+                merge_schema,
+            )?),
+        })
+    }
+
+    /// Writes the manifests of a snapshot with `data_files`, the JSON-serialized data files returned by the write,
+    /// and returns the requirements and updates that commit it, along with the files it adds and removes, as JSON.
+    #[pyo3(signature = (data_files, io_config=None))]
+    fn commit(
+        &self,
+        py: Python,
+        data_files: Vec<String>,
+        io_config: Option<IOConfig>,
+    ) -> PyResult<String> {
+        let data_files = data_files
+            .iter()
+            .map(|data_file| {
+                serde_json::from_str::<DataFile>(data_file).map_err(|err| {
+                    DaftError::ValueError(format!("Invalid Iceberg data file: {err}"))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let write = self.write.clone();
+        py.allow_threads(|| {
+            let io_client = get_io_client(true, Arc::new(io_config.unwrap_or_default().config))?;
+            let commit = get_io_runtime(true).block_on_current_thread(async move {
+                write.commit(data_files, io_client).await
+            })?;
+            Ok(serde_json::to_string(&commit)
+                .map_err(|err| DaftError::InternalError(err.to_string()))?)
+        })
+    }
+
+    pub fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (String,))> {
+        let json = serde_json::to_string(self.write.as_ref())
+            .map_err(|err| DaftError::InternalError(err.to_string()))?;
+        Ok((
+            Self::type_object(py)
+                .getattr(pyo3::intern!(py, "_from_json"))?
+                .into(),
+            (json,),
+        ))
+    }
+
+    #[staticmethod]
+    fn _from_json(json: &str) -> PyResult<Self> {
+        let write =
+            serde_json::from_str(json).map_err(|err| DaftError::InternalError(err.to_string()))?;
+        Ok(Self {
+            write: Arc::new(write),
+        })
+    }
+}
+
+pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
+    parent.add_class::<PyIcebergWrite>()?;
+    Ok(())
+}
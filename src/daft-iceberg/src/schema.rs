@@ -0,0 +1,335 @@
+//! Conversions between Iceberg and Daft types, and the schema evolution that writes are allowed to make.
+
+use arrow2::io::parquet::write::to_parquet_type;
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::{DataType, Field, Schema as DaftSchema, TimeUnit};
+use parquet2::schema::{types::ParquetType, Repetition};
+
+use crate::spec::{ListType, MapType, NestedField, PrimitiveType, Schema, StructType, Type};
+
+/// The Daft type that values of an Iceberg type are written from.
+pub fn to_daft_dtype(ty: &Type) -> DaftResult<DataType> {
+    Ok(match ty {
+        Type::Primitive(primitive) => match primitive {
+            PrimitiveType::Boolean => DataType::Boolean,
+            PrimitiveType::Int => DataType::Int32,
+            PrimitiveType::Long => DataType::Int64,
+            PrimitiveType::Float => DataType::Float32,
+            PrimitiveType::Double => DataType::Float64,
+            PrimitiveType::Decimal { precision, scale } => {
+                DataType::Decimal128(*precision as usize, *scale as usize)
+            }
+            PrimitiveType::Date => DataType::Date,
+            PrimitiveType::Time => DataType::Time(TimeUnit::Microseconds),
+            PrimitiveType::Timestamp => DataType::Timestamp(TimeUnit::Microseconds, None),
+            PrimitiveType::Timestamptz => {
+                DataType::Timestamp(TimeUnit::Microseconds, Some("UTC".to_string()))
+            }
+            PrimitiveType::TimestampNs => DataType::Timestamp(TimeUnit::Nanoseconds, None),
+            PrimitiveType::TimestamptzNs => {
+                DataType::Timestamp(TimeUnit::Nanoseconds, Some("UTC".to_string()))
+            }
+            PrimitiveType::String => DataType::Utf8,
+            PrimitiveType::Uuid => DataType::new_uuid(),
+            PrimitiveType::Fixed(length) => DataType::FixedSizeBinary(*length),
+            PrimitiveType::Binary => DataType::Binary,
+        },
+        Type::Struct(struct_type) => DataType::Struct(
+            struct_type
+                .fields
+                .iter()
+                .map(|field| Ok(Field::new(&field.name, to_daft_dtype(&field.field_type)?)))
+                .collect::<DaftResult<_>>()?,
+        ),
+        Type::List(list_type) => DataType::List(Box::new(to_daft_dtype(&list_type.element)?)),
+        Type::Map(map_type) => DataType::Map {
+            key: Box::new(to_daft_dtype(&map_type.key)?),
+            value: Box::new(to_daft_dtype(&map_type.value)?),
+        },
+    })
+}
+
+/// The Iceberg type that a new column of a Daft type is added to a table as, with ids for its nested fields taken
+/// from `last_column_id`.
+pub fn from_daft_dtype(dtype: &DataType, last_column_id: &mut i32) -> DaftResult<Type> {
+    let mut next_id = || {
+        *last_column_id += 1;
+        *last_column_id
+    };
+    Ok(match dtype {
+        DataType::Boolean => Type::Primitive(PrimitiveType::Boolean),
+        DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::UInt8 | DataType::UInt16 => {
+            Type::Primitive(PrimitiveType::Int)
+        }
+        DataType::Int64 | DataType::UInt32 => Type::Primitive(PrimitiveType::Long),
+        DataType::Float32 => Type::Primitive(PrimitiveType::Float),
+        DataType::Float64 => Type::Primitive(PrimitiveType::Double),
+        DataType::Decimal128(precision, scale) => Type::Primitive(PrimitiveType::Decimal {
+            precision: *precision as u8,
+            scale: *scale as u8,
+        }),
+        DataType::Date => Type::Primitive(PrimitiveType::Date),
+        DataType::Time(_) => Type::Primitive(PrimitiveType::Time),
+        // Nanosecond timestamps need format version 3, so all timestamps are added with microseconds
+        DataType::Timestamp(_, None) => Type::Primitive(PrimitiveType::Timestamp),
+        DataType::Timestamp(_, Some(_)) => Type::Primitive(PrimitiveType::Timestamptz),
+        DataType::Utf8 => Type::Primitive(PrimitiveType::String),
+        DataType::Binary => Type::Primitive(PrimitiveType::Binary),
+        DataType::FixedSizeBinary(length) => Type::Primitive(PrimitiveType::Fixed(*length)),
+        dtype if dtype.is_uuid() => Type::Primitive(PrimitiveType::Uuid),
+        DataType::List(element) | DataType::FixedSizeList(element, _) => {
+            let element_id = next_id();
+            Type::List(ListType {
+                element_id,
+                element_required: false,
+                element: Box::new(from_daft_dtype(element, last_column_id)?),
+            })
+        }
+        DataType::Struct(fields) => {
+            let ids = fields.iter().map(|_| next_id()).collect::<Vec<_>>();
+            Type::Struct(StructType {
+                fields: fields
+                    .iter()
+                    .zip(ids)
+                    .map(|(field, id)| {
+                        Ok(NestedField::optional(
+                            id,
+                            &field.name,
+                            from_daft_dtype(&field.dtype, last_column_id)?,
+                        ))
+                    })
+                    .collect::<DaftResult<_>>()?,
+            })
+        }
+        DataType::Map { key, value } => {
+            let key_id = next_id();
+            let value_id = next_id();
+            Type::Map(MapType {
+                key_id,
+                key: Box::new(from_daft_dtype(key, last_column_id)?),
+                value_id,
+                value_required: false,
+                value: Box::new(from_daft_dtype(value, last_column_id)?),
+            })
+        }
+        other => {
+            return Err(DaftError::TypeError(format!(
+                "Columns of type {other} cannot be added to Iceberg tables"
+            )))
+        }
+    })
+}
+
+/// The schema that data of `data_schema` is written to a table of schema `current` in, which is a new schema with
+/// the columns of the data that the table doesn't have yet added to it if `merge` is set, along with the id of the
+/// last column of the table once they are.
+///
+/// Only adding columns is allowed, as that is the only change that doesn't affect data that is already in the
+/// table. Columns of the table that the data doesn't have are written as nulls, so they must be optional.
+pub fn write_schema(
+    current: &Schema,
+    last_column_id: i32,
+    next_schema_id: i32,
+    data_schema: &DaftSchema,
+    merge: bool,
+) -> DaftResult<Option<(Schema, i32)>> {
+    if let Some(missing) = current
+        .fields
+        .iter()
+        .find(|field| field.required && data_schema.get_field(&field.name).is_err())
+    {
+        return Err(DaftError::SchemaMismatch(format!(
+            "Column {} of the Iceberg table is required, but the DataFrame doesn't have it",
+            missing.name
+        )));
+    }
+
+    let added = data_schema
+        .fields()
+        .iter()
+        .filter(|field| current.field_by_name(&field.name).is_none())
+        .collect::<Vec<_>>();
+    // Without merging, the columns that the table doesn't have are dropped, as they are by PyIceberg writes
+    if added.is_empty() || !merge {
+        return Ok(None);
+    }
+
+    let mut last_column_id = last_column_id;
+    let mut schema = current.clone();
+    schema.schema_id = next_schema_id;
+    for field in added {
+        let id = last_column_id + 1;
+        last_column_id += 1;
+        let field_type = from_daft_dtype(&field.dtype, &mut last_column_id)?;
+        schema
+            .fields
+            .push(NestedField::optional(id, &field.name, field_type));
+    }
+    Ok(Some((schema, last_column_id)))
+}
+
+/// The Parquet types of the columns of `schema`, annotated with the ids and requiredness of its fields so that
+/// readers resolve the columns of data files by id.
+pub fn parquet_types(schema: &Schema) -> DaftResult<Vec<ParquetType>> {
+    schema
+        .fields
+        .iter()
+        .map(|field| {
+            let arrow_field =
+                Field::new(&field.name, to_daft_dtype(&field.field_type)?).to_arrow()?;
+            let mut parquet_type = to_parquet_type(&arrow_field)?;
+            annotate(
+                &mut parquet_type,
+                field.id,
+                field.required,
+                &field.field_type,
+            )?;
+            Ok(parquet_type)
+        })
+        .collect()
+}
+
+fn annotate(parquet_type: &mut ParquetType, id: i32, required: bool, ty: &Type) -> DaftResult<()> {
+    let mismatch = || {
+        DaftError::InternalError(format!(
+            "Parquet type of field {id} doesn't match its Iceberg type {ty}"
+        ))
+    };
+    let field_info = match parquet_type {
+        ParquetType::PrimitiveType(primitive) => &mut primitive.field_info,
+        ParquetType::GroupType { field_info, .. } => field_info,
+    };
+    field_info.id = Some(id);
+    if required {
+        field_info.repetition = Repetition::Required;
+    }
+
+    match (ty, parquet_type) {
+        (Type::Primitive(_), ParquetType::PrimitiveType(_)) => Ok(()),
+        (Type::Struct(struct_type), ParquetType::GroupType { fields, .. })
+            if fields.len() == struct_type.fields.len() =>
+        {
+            for (field, parquet_field) in struct_type.fields.iter().zip(fields) {
+                annotate(parquet_field, field.id, field.required, &field.field_type)?;
+            }
+            Ok(())
+        }
+        // Lists are a group of a repeated group of the element
+        (Type::List(list_type), ParquetType::GroupType { fields, .. }) => {
+            let Some(ParquetType::GroupType {
+                fields: elements, ..
+            }) = fields.first_mut()
+            else {
+                return Err(mismatch());
+            };
+            let element = elements.first_mut().ok_or_else(mismatch)?;
+            annotate(
+                element,
+                list_type.element_id,
+                list_type.element_required,
+                &list_type.element,
+            )
+        }
+        (Type::Map(_), _) => Err(DaftError::NotImplemented(
+            "Writing map columns to Iceberg tables natively is not supported yet".to_string(),
+        )),
+        _ => Err(mismatch()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::{DataType, Field, Schema as DaftSchema};
+    use parquet2::schema::{types::ParquetType, Repetition};
+
+    use super::{parquet_types, write_schema};
+    use crate::spec::{NestedField, PrimitiveType, Schema, Type};
+
+    fn table_schema() -> Schema {
+        let mut id = NestedField::optional(1, "id", Type::Primitive(PrimitiveType::Long));
+        id.required = true;
+        Schema {
+            schema_id: 0,
+            identifier_field_ids: vec![],
+            fields: vec![
+                id,
+                NestedField::optional(2, "name", Type::Primitive(PrimitiveType::String)),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_write_schema_adds_new_columns_when_merging() {
+        let data_schema = DaftSchema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("tags", DataType::List(Box::new(DataType::Utf8))),
+            Field::new(
+                "point",
+                DataType::Struct(vec![
+                    Field::new("x", DataType::Float64),
+                    Field::new("y", DataType::Float64),
+                ]),
+            ),
+        ]);
+
+        // New columns are dropped unless the schema is merged
+        assert!(write_schema(&table_schema(), 2, 1, &data_schema, false)
+            .unwrap()
+            .is_none());
+        let (schema, last_column_id) = write_schema(&table_schema(), 2, 1, &data_schema, true)
+            .unwrap()
+            .unwrap();
+        assert_eq!(schema.schema_id, 1);
+        assert_eq!(last_column_id, 7);
+        assert_eq!(
+            schema
+                .fields
+                .iter()
+                .map(|field| (field.id, field.name.as_str(), field.required))
+                .collect::<Vec<_>>(),
+            vec![
+                (1, "id", true),
+                (2, "name", false),
+                (3, "tags", false),
+                (5, "point", false)
+            ]
+        );
+        let Type::List(tags) = &schema.fields[2].field_type else {
+            panic!("tags should be a list");
+        };
+        assert_eq!(tags.element_id, 4);
+        assert!(schema.field_by_id(7).is_some());
+
+        // Nothing changes when the data has no new columns
+        let data_schema = DaftSchema::new(vec![Field::new("id", DataType::Int64)]);
+        assert!(write_schema(&table_schema(), 2, 1, &data_schema, true)
+            .unwrap()
+            .is_none());
+
+        // Required columns can't be written as nulls
+        let data_schema = DaftSchema::new(vec![Field::new("name", DataType::Utf8)]);
+        assert!(write_schema(&table_schema(), 2, 1, &data_schema, true).is_err());
+    }
+
+    #[test]
+    fn test_parquet_types_carry_field_ids() {
+        let data_schema = DaftSchema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("tags", DataType::List(Box::new(DataType::Utf8))),
+        ]);
+        let (schema, _) = write_schema(&table_schema(), 2, 1, &data_schema, true)
+            .unwrap()
+            .unwrap();
+        let types = parquet_types(&schema).unwrap();
+        assert_eq!(types[0].get_field_info().id, Some(1));
+        assert_eq!(types[0].get_field_info().repetition, Repetition::Required);
+        assert_eq!(types[1].get_field_info().repetition, Repetition::Optional);
+        let ParquetType::GroupType { fields, .. } = &types[2] else {
+            panic!("lists should be groups");
+        };
+        let ParquetType::GroupType { fields, .. } = &fields[0] else {
+            panic!("lists should be groups of repeated groups");
+        };
+        assert_eq!(fields[0].get_field_info().id, Some(4));
+    }
+}
@@ -0,0 +1,611 @@
+//! The parts of the [Iceberg table spec](https://iceberg.apache.org/spec/) that writes need: table metadata,
+//! schemas, partition specs and snapshots, as they are serialized in metadata files and catalog requests.
+
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+use common_error::{DaftError, DaftResult};
+use serde::{Deserialize, Serialize};
+
+/// The name of the branch that writes commit to.
+pub const MAIN_BRANCH: &str = "main";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum PrimitiveType {
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Decimal { precision: u8, scale: u8 },
+    Date,
+    Time,
+    Timestamp,
+    Timestamptz,
+    TimestampNs,
+    TimestamptzNs,
+    String,
+    Uuid,
+    Fixed(usize),
+    Binary,
+}
+
+impl fmt::Display for PrimitiveType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Boolean => write!(f, "boolean"),
+            Self::Int => write!(f, "int"),
+            Self::Long => write!(f, "long"),
+            Self::Float => write!(f, "float"),
+            Self::Double => write!(f, "double"),
+            Self::Decimal { precision, scale } => write!(f, "decimal({precision}, {scale})"),
+            Self::Date => write!(f, "date"),
+            Self::Time => write!(f, "time"),
+            Self::Timestamp => write!(f, "timestamp"),
+            Self::Timestamptz => write!(f, "timestamptz"),
+            Self::TimestampNs => write!(f, "timestamp_ns"),
+            Self::TimestamptzNs => write!(f, "timestamptz_ns"),
+            Self::String => write!(f, "string"),
+            Self::Uuid => write!(f, "uuid"),
+            Self::Fixed(length) => write!(f, "fixed[{length}]"),
+            Self::Binary => write!(f, "binary"),
+        }
+    }
+}
+
+impl FromStr for PrimitiveType {
+    type Err = DaftError;
+
+    fn from_str(s: &str) -> DaftResult<Self> {
+        let invalid = || DaftError::ValueError(format!("Invalid Iceberg type: {s}"));
+        Ok(match s {
+            "boolean" => Self::Boolean,
+            "int" => Self::Int,
+            "long" => Self::Long,
+            "float" => Self::Float,
+            "double" => Self::Double,
+            "date" => Self::Date,
+            "time" => Self::Time,
+            "timestamp" => Self::Timestamp,
+            "timestamptz" => Self::Timestamptz,
+            "timestamp_ns" => Self::TimestampNs,
+            "timestamptz_ns" => Self::TimestamptzNs,
+            "string" => Self::String,
+            "uuid" => Self::Uuid,
+            "binary" => Self::Binary,
+            _ => {
+                if let Some(args) = s.strip_prefix("decimal(").and_then(|s| s.strip_suffix(')')) {
+                    let (precision, scale) = args.split_once(',').ok_or_else(invalid)?;
+                    Self::Decimal {
+                        precision: precision.trim().parse().map_err(|_| invalid())?,
+                        scale: scale.trim().parse().map_err(|_| invalid())?,
+                    }
+                } else if let Some(length) =
+                    s.strip_prefix("fixed[").and_then(|s| s.strip_suffix(']'))
+                {
+                    Self::Fixed(length.trim().parse().map_err(|_| invalid())?)
+                } else {
+                    return Err(invalid());
+                }
+            }
+        })
+    }
+}
+
+impl TryFrom<String> for PrimitiveType {
+    type Error = DaftError;
+
+    fn try_from(value: String) -> DaftResult<Self> {
+        value.parse()
+    }
+}
+
+impl From<PrimitiveType> for String {
+    fn from(value: PrimitiveType) -> Self {
+        value.to_string()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "SerdeType", into = "SerdeType")]
+pub enum Type {
+    Primitive(PrimitiveType),
+    Struct(StructType),
+    List(ListType),
+    Map(MapType),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StructType {
+    pub fields: Vec<NestedField>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ListType {
+    pub element_id: i32,
+    pub element_required: bool,
+    pub element: Box<Type>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MapType {
+    pub key_id: i32,
+    pub key: Box<Type>,
+    pub value_id: i32,
+    pub value_required: bool,
+    pub value: Box<Type>,
+}
+
+/// Types are serialized as the name of primitive types, or as objects tagged with the kind of nested types.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum SerdeType {
+    Primitive(PrimitiveType),
+    Nested(NestedType),
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum NestedType {
+    Struct(StructType),
+    List(ListType),
+    Map(MapType),
+}
+
+impl From<SerdeType> for Type {
+    fn from(value: SerdeType) -> Self {
+        match value {
+            SerdeType::Primitive(primitive) => Self::Primitive(primitive),
+            SerdeType::Nested(NestedType::Struct(struct_type)) => Self::Struct(struct_type),
+            SerdeType::Nested(NestedType::List(list_type)) => Self::List(list_type),
+            SerdeType::Nested(NestedType::Map(map_type)) => Self::Map(map_type),
+        }
+    }
+}
+
+impl From<Type> for SerdeType {
+    fn from(value: Type) -> Self {
+        match value {
+            Type::Primitive(primitive) => Self::Primitive(primitive),
+            Type::Struct(struct_type) => Self::Nested(NestedType::Struct(struct_type)),
+            Type::List(list_type) => Self::Nested(NestedType::List(list_type)),
+            Type::Map(map_type) => Self::Nested(NestedType::Map(map_type)),
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Primitive(primitive) => write!(f, "{primitive}"),
+            Self::Struct(struct_type) => {
+                write!(f, "struct<")?;
+                for (i, field) in struct_type.fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", field.name, field.field_type)?;
+                }
+                write!(f, ">")
+            }
+            Self::List(list_type) => write!(f, "list<{}>", list_type.element),
+            Self::Map(map_type) => write!(f, "map<{}, {}>", map_type.key, map_type.value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct NestedField {
+    pub id: i32,
+    pub name: String,
+    pub required: bool,
+    #[serde(rename = "type")]
+    pub field_type: Type,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial_default: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub write_default: Option<serde_json::Value>,
+}
+
+impl NestedField {
+    pub fn optional(id: i32, name: impl Into<String>, field_type: Type) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            required: false,
+            field_type,
+            doc: None,
+            initial_default: None,
+            write_default: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "struct", rename_all = "kebab-case")]
+pub struct Schema {
+    #[serde(default)]
+    pub schema_id: i32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub identifier_field_ids: Vec<i32>,
+    pub fields: Vec<NestedField>,
+}
+
+impl Schema {
+    pub fn field_by_name(&self, name: &str) -> Option<&NestedField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Finds a field by id, including the fields nested in structs, lists and maps.
+    pub fn field_by_id(&self, id: i32) -> Option<&NestedField> {
+        fn find(fields: &[NestedField], id: i32) -> Option<&NestedField> {
+            fields.iter().find_map(|field| {
+                if field.id == id {
+                    return Some(field);
+                }
+                match &field.field_type {
+                    Type::Struct(struct_type) => find(&struct_type.fields, id),
+                    _ => None,
+                }
+            })
+        }
+        find(&self.fields, id)
+    }
+}
+
+/// How the values of a source column are transformed into the values of a partition field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Transform {
+    Identity,
+    Bucket(u32),
+    Truncate(u32),
+    Year,
+    Month,
+    Day,
+    Hour,
+    Void,
+}
+
+impl Transform {
+    /// The type of the partition values that this transform produces from values of `source_type`.
+    pub fn result_type(&self, source_type: &Type) -> DaftResult<Type> {
+        match (self, source_type) {
+            (Self::Identity | Self::Truncate(_) | Self::Void, Type::Primitive(_)) => {
+                Ok(source_type.clone())
+            }
+            (Self::Bucket(_) | Self::Year | Self::Month | Self::Hour, Type::Primitive(_)) => {
+                Ok(Type::Primitive(PrimitiveType::Int))
+            }
+            (Self::Day, Type::Primitive(_)) => Ok(Type::Primitive(PrimitiveType::Date)),
+            _ => Err(DaftError::ValueError(format!(
+                "Iceberg transform {self} cannot be applied to {source_type}"
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Transform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Identity => write!(f, "identity"),
+            Self::Bucket(buckets) => write!(f, "bucket[{buckets}]"),
+            Self::Truncate(width) => write!(f, "truncate[{width}]"),
+            Self::Year => write!(f, "year"),
+            Self::Month => write!(f, "month"),
+            Self::Day => write!(f, "day"),
+            Self::Hour => write!(f, "hour"),
+            Self::Void => write!(f, "void"),
+        }
+    }
+}
+
+impl FromStr for Transform {
+    type Err = DaftError;
+
+    fn from_str(s: &str) -> DaftResult<Self> {
+        let invalid = || DaftError::ValueError(format!("Unsupported Iceberg transform: {s}"));
+        let argument = |prefix: &str| -> Option<DaftResult<u32>> {
+            s.strip_prefix(prefix)
+                .and_then(|s| s.strip_suffix(']'))
+                .map(|arg| arg.trim().parse().map_err(|_| invalid()))
+        };
+        Ok(match s {
+            "identity" => Self::Identity,
+            "year" => Self::Year,
+            "month" => Self::Month,
+            "day" => Self::Day,
+            "hour" => Self::Hour,
+            "void" => Self::Void,
+            _ => {
+                if let Some(buckets) = argument("bucket[") {
+                    Self::Bucket(buckets?)
+                } else if let Some(width) = argument("truncate[") {
+                    Self::Truncate(width?)
+                } else {
+                    return Err(invalid());
+                }
+            }
+        })
+    }
+}
+
+impl TryFrom<String> for Transform {
+    type Error = DaftError;
+
+    fn try_from(value: String) -> DaftResult<Self> {
+        value.parse()
+    }
+}
+
+impl From<Transform> for String {
+    fn from(value: Transform) -> Self {
+        value.to_string()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PartitionField {
+    pub source_id: i32,
+    pub field_id: i32,
+    pub name: String,
+    pub transform: Transform,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PartitionSpec {
+    pub spec_id: i32,
+    pub fields: Vec<PartitionField>,
+}
+
+impl PartitionSpec {
+    /// The types of the partition values of the fields of the spec, for tables with `schema`.
+    pub fn partition_types(&self, schema: &Schema) -> DaftResult<Vec<Type>> {
+        self.fields
+            .iter()
+            .map(|field| {
+                let source = schema.field_by_id(field.source_id).ok_or_else(|| {
+                    DaftError::ValueError(format!(
+                        "Source column {} of Iceberg partition field {} not found in the table schema",
+                        field.source_id, field.name
+                    ))
+                })?;
+                field.transform.result_type(&source.field_type)
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Snapshot {
+    pub snapshot_id: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_snapshot_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sequence_number: Option<i64>,
+    pub timestamp_ms: i64,
+    pub manifest_list: Option<String>,
+    #[serde(default)]
+    pub summary: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct SnapshotRef {
+    pub snapshot_id: i64,
+    #[serde(rename = "type")]
+    pub ref_type: String,
+}
+
+/// The metadata of an Iceberg table, as written to its metadata files. Only the fields that writes need are kept.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct TableMetadata {
+    pub format_version: u8,
+    pub table_uuid: String,
+    pub location: String,
+    #[serde(default)]
+    pub last_sequence_number: i64,
+    pub last_column_id: i32,
+    #[serde(default)]
+    pub schemas: Vec<Schema>,
+    #[serde(default)]
+    pub current_schema_id: i32,
+    /// The schema of tables written before the format kept all schemas.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<Schema>,
+    #[serde(default)]
+    pub partition_specs: Vec<PartitionSpec>,
+    #[serde(default)]
+    pub default_spec_id: i32,
+    /// The partition fields of tables written before the format kept all partition specs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub partition_spec: Option<Vec<PartitionField>>,
+    #[serde(default)]
+    pub properties: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_snapshot_id: Option<i64>,
+    #[serde(default)]
+    pub snapshots: Vec<Snapshot>,
+    #[serde(default)]
+    pub refs: BTreeMap<String, SnapshotRef>,
+}
+
+impl TableMetadata {
+    pub fn from_json(json: &str) -> DaftResult<Self> {
+        let metadata: Self = serde_json::from_str(json).map_err(|err| {
+            DaftError::ValueError(format!("Invalid Iceberg table metadata: {err}"))
+        })?;
+        if !(1..=2).contains(&metadata.format_version) {
+            return Err(DaftError::NotImplemented(format!(
+                "Writing to Iceberg tables of format version {} is not supported",
+                metadata.format_version
+            )));
+        }
+        Ok(metadata)
+    }
+
+    pub fn current_schema(&self) -> DaftResult<&Schema> {
+        self.schemas
+            .iter()
+            .find(|schema| schema.schema_id == self.current_schema_id)
+            .or(self.schema.as_ref())
+            .ok_or_else(|| {
+                DaftError::ValueError(format!(
+                    "Current schema {} not found in Iceberg table metadata",
+                    self.current_schema_id
+                ))
+            })
+    }
+
+    pub fn default_spec(&self) -> DaftResult<PartitionSpec> {
+        if let Some(spec) = self
+            .partition_specs
+            .iter()
+            .find(|spec| spec.spec_id == self.default_spec_id)
+        {
+            return Ok(spec.clone());
+        }
+        match &self.partition_spec {
+            Some(fields) => Ok(PartitionSpec {
+                spec_id: self.default_spec_id,
+                fields: fields.clone(),
+            }),
+            None if self.partition_specs.is_empty() => Ok(PartitionSpec {
+                spec_id: 0,
+                fields: vec![],
+            }),
+            None => Err(DaftError::ValueError(format!(
+                "Default partition spec {} not found in Iceberg table metadata",
+                self.default_spec_id
+            ))),
+        }
+    }
+
+    /// The snapshot that the main branch points to, which writes are committed on top of.
+    pub fn main_snapshot(&self) -> Option<&Snapshot> {
+        let snapshot_id = match self.refs.get(MAIN_BRANCH) {
+            Some(main) => main.snapshot_id,
+            // Snapshots of tables written before the format had branches are always on the main branch
+            None => self.current_snapshot_id.filter(|id| *id >= 0)?,
+        };
+        self.snapshots
+            .iter()
+            .find(|snapshot| snapshot.snapshot_id == snapshot_id)
+    }
+
+    /// Where files of the table are written to: data files to `data` and metadata files to `metadata`, unless
+    /// the table properties place them elsewhere.
+    pub fn data_location(&self) -> String {
+        self.properties
+            .get("write.data.path")
+            .cloned()
+            .unwrap_or_else(|| format!("{}/data", self.location.trim_end_matches('/')))
+    }
+
+    pub fn metadata_location(&self) -> String {
+        self.properties
+            .get("write.metadata.path")
+            .cloned()
+            .unwrap_or_else(|| format!("{}/metadata", self.location.trim_end_matches('/')))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PrimitiveType, TableMetadata, Transform, Type};
+
+    #[test]
+    fn test_parse_types_and_transforms() {
+        assert_eq!(
+            "decimal(10, 2)".parse::<PrimitiveType>().unwrap(),
+            PrimitiveType::Decimal {
+                precision: 10,
+                scale: 2
+            }
+        );
+        assert_eq!(
+            "fixed[16]".parse::<PrimitiveType>().unwrap(),
+            PrimitiveType::Fixed(16)
+        );
+        assert!("varchar".parse::<PrimitiveType>().is_err());
+        assert_eq!(
+            "bucket[16]".parse::<Transform>().unwrap(),
+            Transform::Bucket(16)
+        );
+        assert_eq!(Transform::Truncate(4).to_string(), "truncate[4]");
+        assert!("zorder".parse::<Transform>().is_err());
+    }
+
+    #[test]
+    fn test_table_metadata_from_json() {
+        let metadata = TableMetadata::from_json(
+            r#"{
+                "format-version": 2,
+                "table-uuid": "9c12d441-03fe-4693-9a96-a0705ddf69c1",
+                "location": "s3://bucket/warehouse/db/table",
+                "last-sequence-number": 3,
+                "last-updated-ms": 1602638573590,
+                "last-column-id": 4,
+                "current-schema-id": 0,
+                "schemas": [{
+                    "type": "struct",
+                    "schema-id": 0,
+                    "fields": [
+                        {"id": 1, "name": "id", "required": true, "type": "long"},
+                        {"id": 2, "name": "tags", "required": false, "type": {
+                            "type": "list", "element-id": 3, "element": "string", "element-required": false
+                        }},
+                        {"id": 4, "name": "price", "required": false, "type": "decimal(10, 2)"}
+                    ]
+                }],
+                "default-spec-id": 0,
+                "partition-specs": [{"spec-id": 0, "fields": [
+                    {"name": "id_bucket", "transform": "bucket[16]", "source-id": 1, "field-id": 1000}
+                ]}],
+                "last-partition-id": 1000,
+                "properties": {"write.parquet.compression-codec": "zstd"},
+                "current-snapshot-id": 3051729675574597004,
+                "snapshots": [{
+                    "snapshot-id": 3051729675574597004,
+                    "sequence-number": 3,
+                    "timestamp-ms": 1515100955770,
+                    "summary": {"operation": "append"},
+                    "manifest-list": "s3://bucket/warehouse/db/table/metadata/snap-3051729675574597004.avro"
+                }],
+                "refs": {"main": {"snapshot-id": 3051729675574597004, "type": "branch"}}
+            }"#,
+        )
+        .unwrap();
+
+        let schema = metadata.current_schema().unwrap();
+        assert_eq!(schema.fields.len(), 3);
+        assert!(matches!(schema.fields[1].field_type, Type::List(_)));
+        let spec = metadata.default_spec().unwrap();
+        assert_eq!(
+            spec.partition_types(schema).unwrap(),
+            vec![Type::Primitive(PrimitiveType::Int)]
+        );
+        assert_eq!(metadata.main_snapshot().unwrap().sequence_number, Some(3));
+        assert_eq!(
+            metadata.data_location(),
+            "s3://bucket/warehouse/db/table/data"
+        );
+
+        // Schemas are written back the way they were read
+        let json = serde_json::to_value(schema).unwrap();
+        assert_eq!(json["type"], "struct");
+        assert_eq!(json["fields"][1]["type"]["type"], "list");
+        assert_eq!(json["fields"][2]["type"], "decimal(10, 2)");
+    }
+}
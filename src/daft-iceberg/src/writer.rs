@@ -0,0 +1,466 @@
+//! Writes the data files of a table: Parquet files whose columns carry the field ids of the table schema, along with
+//! the partition values and column statistics that their manifest entries need.
+
+use std::{
+    collections::BTreeMap,
+    io::Write,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use arrow2::io::parquet::write::{row_group_iter, transverse, Encoding, WriteOptions};
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{AsArrow, Schema as DaftSchema},
+    series::Series,
+};
+use daft_io::{parse_url, IOClient, IOStatsRef, ObjectSink, SourceType};
+use daft_recordbatch::RecordBatch;
+use parquet2::{
+    compression::{BrotliLevel, CompressionOptions, GzipLevel, ZstdLevel},
+    metadata::SchemaDescriptor,
+    schema::types::ParquetType,
+    write::{FileWriter, Version},
+};
+
+use crate::{
+    datum::Datum,
+    manifest::DataFile,
+    schema::{parquet_types, to_daft_dtype},
+    spec::{PartitionSpec, PrimitiveType, Schema, Type},
+};
+
+/// String and binary bounds are truncated to this many characters or bytes, as other writers do by default.
+const BOUNDS_TRUNCATE_LENGTH: usize = 16;
+
+/// Parses the compression of the `write.parquet.compression-codec` table property, which is zstd by default.
+pub fn parquet_compression(
+    properties: &BTreeMap<String, String>,
+) -> DaftResult<CompressionOptions> {
+    let level = properties
+        .get("write.parquet.compression-level")
+        .map(|level| {
+            level.parse::<i32>().map_err(|_| {
+                DaftError::ValueError(format!("Invalid Parquet compression level: {level}"))
+            })
+        })
+        .transpose()?;
+    let codec = properties
+        .get("write.parquet.compression-codec")
+        .map_or("zstd", String::as_str);
+    Ok(match codec.to_lowercase().as_str() {
+        "zstd" => CompressionOptions::Zstd(
+            level
+                .map(ZstdLevel::try_new)
+                .transpose()
+                .map_err(arrow2::error::Error::from)?,
+        ),
+        "gzip" => CompressionOptions::Gzip(
+            level
+                .map(|level| GzipLevel::try_new(level as u8))
+                .transpose()
+                .map_err(arrow2::error::Error::from)?,
+        ),
+        "brotli" => CompressionOptions::Brotli(
+            level
+                .map(|level| BrotliLevel::try_new(level as u32))
+                .transpose()
+                .map_err(arrow2::error::Error::from)?,
+        ),
+        "snappy" => CompressionOptions::Snappy,
+        "lz4" => CompressionOptions::Lz4Raw,
+        "uncompressed" | "none" => CompressionOptions::Uncompressed,
+        codec => {
+            return Err(DaftError::ValueError(format!(
+                "Unsupported Parquet compression codec for Iceberg writes: {codec}"
+            )))
+        }
+    })
+}
+
+/// Creates the directory of `path` if it is a local path, as local files are written in place, unlike objects.
+pub(crate) fn create_local_dir(path: &str) -> DaftResult<()> {
+    let (source_type, path) = parse_url(path)?;
+    if source_type == SourceType::File
+        && let Some(dir) =
+            std::path::Path::new(path.strip_prefix("file://").unwrap_or(&path)).parent()
+    {
+        std::fs::create_dir_all(dir)?;
+    }
+    Ok(())
+}
+
+/// The statistics of a top-level column of primitive type, which are kept up to date as batches are written.
+struct ColumnStats {
+    field_id: i32,
+    ty: PrimitiveType,
+    value_count: i64,
+    null_count: i64,
+    nan_count: i64,
+    lower: Option<Datum>,
+    upper: Option<Datum>,
+    /// Whether bounds can be kept for the column, which they can't for types that have no order in Daft.
+    has_bounds: bool,
+}
+
+impl ColumnStats {
+    fn update(&mut self, series: &Series) -> DaftResult<()> {
+        self.value_count += series.len() as i64;
+        self.null_count += series
+            .validity()
+            .map_or(0, |validity| validity.unset_bits()) as i64;
+
+        let mut series = series.clone();
+        if matches!(self.ty, PrimitiveType::Float | PrimitiveType::Double) {
+            let is_nan = series.is_nan()?;
+            let is_nan = is_nan.bool()?;
+            let nan_count = is_nan
+                .as_arrow()
+                .iter()
+                .filter(|v| *v == Some(true))
+                .count();
+            if nan_count > 0 {
+                self.nan_count += nan_count as i64;
+                series = series.filter(&(!is_nan)?)?;
+            }
+        }
+        if !self.has_bounds {
+            return Ok(());
+        }
+        let (Ok(min), Ok(max)) = (series.min(None), series.max(None)) else {
+            self.has_bounds = false;
+            return Ok(());
+        };
+        if let Some(min) = Datum::from_series(&min, 0, &self.ty)?
+            && self
+                .lower
+                .as_ref()
+                .is_none_or(|lower| min.compare(lower).is_some_and(|o| o.is_lt()))
+        {
+            self.lower = Some(min);
+        }
+        if let Some(max) = Datum::from_series(&max, 0, &self.ty)?
+            && self
+                .upper
+                .as_ref()
+                .is_none_or(|upper| max.compare(upper).is_some_and(|o| o.is_gt()))
+        {
+            self.upper = Some(max);
+        }
+        Ok(())
+    }
+}
+
+/// Forwards writes to an [`ObjectSink`], counting the bytes written while the Parquet writer owns it.
+struct CountingSink {
+    sink: ObjectSink,
+    bytes_written: Arc<AtomicUsize>,
+}
+
+impl Write for CountingSink {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.sink.write(buf)?;
+        self.bytes_written.fetch_add(written, Ordering::Relaxed);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.sink.flush()
+    }
+}
+
+/// Writes a Parquet data file of a table, into the partition of `partition_values` if the table is partitioned.
+pub struct DataFileWriter {
+    path: String,
+    schema: Arc<Schema>,
+    daft_schema: Arc<DaftSchema>,
+    parquet_types: Vec<ParquetType>,
+    options: WriteOptions,
+    partition: Vec<Option<Datum>>,
+    writer: Option<FileWriter<CountingSink>>,
+    bytes_written: Arc<AtomicUsize>,
+    stats: Vec<ColumnStats>,
+    record_count: usize,
+    io_client: Arc<IOClient>,
+    part_size: usize,
+    max_inflight_parts: usize,
+    io_stats: Option<IOStatsRef>,
+}
+
+impl DataFileWriter {
+    /// Creates a writer of the data file `file_idx` of a write, which is written to `data_location` under a unique
+    /// name. Files are uploaded in parts of `part_size` bytes, with at most `max_inflight_parts` of them in flight at once.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        schema: Arc<Schema>,
+        spec: &PartitionSpec,
+        partition_values: Option<&RecordBatch>,
+        data_location: &str,
+        file_idx: usize,
+        compression: CompressionOptions,
+        io_client: Arc<IOClient>,
+        part_size: usize,
+        max_inflight_parts: usize,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<Self> {
+        let partition_types = crate::manifest::partition_primitive_types(spec, &schema)?;
+        let mut dir = data_location.trim_end_matches('/').to_string();
+        let mut partition = vec![];
+        if !spec.fields.is_empty() {
+            let partition_values = partition_values.ok_or_else(|| {
+                DaftError::InternalError(
+                    "Missing partition values for a data file of a partitioned Iceberg table"
+                        .to_string(),
+                )
+            })?;
+            for (field, ty) in spec.fields.iter().zip(&partition_types) {
+                let values = partition_values.get_column(&field.name)?;
+                let values = values.cast(&to_daft_dtype(&Type::Primitive(*ty))?)?;
+                let value = Datum::from_series(&values, 0, ty)?;
+                let path_value = match value {
+                    None => "null".to_string(),
+                    Some(_) => values
+                        .to_str_values()?
+                        .utf8()?
+                        .get(0)
+                        .unwrap_or_default()
+                        .to_string(),
+                };
+                dir.push_str(&format!("/{}={}", field.name, path_value));
+                partition.push(value);
+            }
+        }
+        let path = format!("{dir}/{}-{file_idx}.parquet", uuid::Uuid::new_v4());
+
+        let daft_schema = Arc::new(DaftSchema::new(
+            schema
+                .fields
+                .iter()
+                .map(|field| {
+                    Ok(daft_core::prelude::Field::new(
+                        &field.name,
+                        to_daft_dtype(&field.field_type)?,
+                    ))
+                })
+                .collect::<DaftResult<Vec<_>>>()?,
+        ));
+        let stats = schema
+            .fields
+            .iter()
+            .filter_map(|field| match &field.field_type {
+                Type::Primitive(ty) => Some(ColumnStats {
+                    field_id: field.id,
+                    ty: *ty,
+                    value_count: 0,
+                    null_count: 0,
+                    nan_count: 0,
+                    lower: None,
+                    upper: None,
+                    has_bounds: true,
+                }),
+                _ => None,
+            })
+            .collect();
+
+        Ok(Self {
+            path,
+            parquet_types: parquet_types(&schema)?,
+            schema,
+            daft_schema,
+            options: WriteOptions {
+                write_statistics: true,
+                version: Version::V1,
+                compression,
+                data_pagesize_limit: None,
+            },
+            partition,
+            writer: None,
+            bytes_written: Arc::new(AtomicUsize::new(0)),
+            stats,
+            record_count: 0,
+            io_client,
+            part_size,
+            max_inflight_parts,
+            io_stats,
+        })
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn get_or_create_writer(&mut self) -> DaftResult<&mut FileWriter<CountingSink>> {
+        if self.writer.is_none() {
+            create_local_dir(&self.path)?;
+            let sink = CountingSink {
+                sink: ObjectSink::new(
+                    self.io_client.clone(),
+                    self.path.clone(),
+                    self.part_size,
+                    self.max_inflight_parts,
+                    self.io_stats.clone(),
+                ),
+                bytes_written: self.bytes_written.clone(),
+            };
+            self.writer = Some(FileWriter::new(
+                sink,
+                SchemaDescriptor::new("table".to_string(), self.parquet_types.clone()),
+                parquet2::write::WriteOptions {
+                    write_statistics: self.options.write_statistics,
+                    version: self.options.version,
+                },
+                Some(format!("Daft version {}", env!("CARGO_PKG_VERSION"))),
+            ));
+        }
+        Ok(self.writer.as_mut().unwrap())
+    }
+
+    /// Writes a batch of rows, which are cast to the schema of the table, with nulls for the columns they don't
+    /// have. Returns the number of bytes written to the file so far.
+    pub fn write(&mut self, batch: &RecordBatch) -> DaftResult<usize> {
+        if batch.is_empty() {
+            return Ok(self.bytes_written());
+        }
+        let columns = self
+            .schema
+            .fields
+            .iter()
+            .zip(self.daft_schema.fields())
+            .map(|(field, daft_field)| {
+                let column = match batch.get_column(&field.name) {
+                    Ok(column) => column.cast(&daft_field.dtype)?,
+                    Err(_) => Series::full_null(&field.name, &daft_field.dtype, batch.len()),
+                };
+                if field.required
+                    && column
+                        .validity()
+                        .is_some_and(|validity| validity.unset_bits() > 0)
+                {
+                    return Err(DaftError::ValueError(format!(
+                        "Column {} of the Iceberg table is required, but the data has nulls in it",
+                        field.name
+                    )));
+                }
+                Ok(column)
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let batch = RecordBatch::new_with_size(self.daft_schema.clone(), columns, batch.len())?;
+
+        for stats in &mut self.stats {
+            let field = self
+                .schema
+                .fields
+                .iter()
+                .position(|field| field.id == stats.field_id)
+                .expect("stats are kept for fields of the schema");
+            stats.update(batch.get_column_by_index(field)?)?;
+        }
+        self.record_count += batch.len();
+
+        let chunk = batch.to_chunk();
+        let encodings = chunk
+            .arrays()
+            .iter()
+            .map(|array| transverse(array.data_type(), |_| Encoding::Plain))
+            .collect();
+        let row_group = row_group_iter(chunk, encodings, self.parquet_types.clone(), self.options);
+        let writer = self.get_or_create_writer()?;
+        writer
+            .write(row_group)
+            .map_err(arrow2::error::Error::from)?;
+        Ok(self.bytes_written())
+    }
+
+    pub fn bytes_written(&self) -> usize {
+        self.bytes_written.load(Ordering::Relaxed)
+    }
+
+    /// Finishes writing the file, returning its description for manifests, or None if nothing was written to it.
+    pub fn close(&mut self) -> DaftResult<Option<DataFile>> {
+        let Some(mut writer) = self.writer.take() else {
+            return Ok(None);
+        };
+        let leaf_ids = writer
+            .schema()
+            .columns()
+            .iter()
+            .map(|column| column.descriptor.primitive_type.field_info.id)
+            .collect::<Vec<_>>();
+        writer.end(None).map_err(arrow2::error::Error::from)?;
+        let (mut sink, metadata) = writer.into_inner_and_metadata();
+        sink.sink.close()?;
+
+        let mut column_sizes = BTreeMap::new();
+        let mut split_offsets = vec![];
+        for row_group in &metadata.row_groups {
+            if let Some(offset) = row_group.file_offset.or_else(|| {
+                row_group
+                    .columns
+                    .first()
+                    .and_then(|column| column.meta_data.as_ref())
+                    .map(|meta| meta.data_page_offset)
+            }) {
+                split_offsets.push(offset);
+            }
+            for (column, id) in row_group.columns.iter().zip(&leaf_ids) {
+                if let (Some(meta), Some(id)) = (&column.meta_data, id) {
+                    *column_sizes.entry(*id).or_insert(0) += meta.total_compressed_size;
+                }
+            }
+        }
+
+        let mut data_file = DataFile {
+            content: 0,
+            file_path: self.path.clone(),
+            file_format: "PARQUET".to_string(),
+            partition: self.partition.clone(),
+            record_count: self.record_count as i64,
+            file_size_in_bytes: self.bytes_written() as i64,
+            column_sizes,
+            value_counts: BTreeMap::new(),
+            null_value_counts: BTreeMap::new(),
+            nan_value_counts: BTreeMap::new(),
+            lower_bounds: BTreeMap::new(),
+            upper_bounds: BTreeMap::new(),
+            key_metadata: None,
+            split_offsets: Some(split_offsets),
+            equality_ids: None,
+            sort_order_id: None,
+            referenced_data_file: None,
+        };
+        for stats in self.stats.drain(..) {
+            data_file
+                .value_counts
+                .insert(stats.field_id, stats.value_count);
+            data_file
+                .null_value_counts
+                .insert(stats.field_id, stats.null_count);
+            if matches!(stats.ty, PrimitiveType::Float | PrimitiveType::Double) {
+                data_file
+                    .nan_value_counts
+                    .insert(stats.field_id, stats.nan_count);
+            }
+            // Bounds of floats are left out once there are NaNs, as readers can't tell whether they include them
+            if !stats.has_bounds || stats.nan_count > 0 {
+                continue;
+            }
+            if let Some(lower) = stats.lower {
+                data_file.lower_bounds.insert(
+                    stats.field_id,
+                    lower.truncate_lower(BOUNDS_TRUNCATE_LENGTH).to_bytes(),
+                );
+            }
+            if let Some(upper) = stats
+                .upper
+                .and_then(|upper| upper.truncate_upper(BOUNDS_TRUNCATE_LENGTH))
+            {
+                data_file
+                    .upper_bounds
+                    .insert(stats.field_id, upper.to_bytes());
+            }
+        }
+        Ok(Some(data_file))
+    }
+}
@@ -308,5 +308,6 @@ fn convert_img_fmt(fmt: ImageFormat) -> image::ImageFormat {
         ImageFormat::TIFF => image::ImageFormat::Tiff,
         ImageFormat::GIF => image::ImageFormat::Gif,
         ImageFormat::BMP => image::ImageFormat::Bmp,
+        ImageFormat::WEBP => image::ImageFormat::WebP,
     }
 }
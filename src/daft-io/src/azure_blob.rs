@@ -1,21 +1,37 @@
-use std::{ops::Range, sync::Arc};
+use std::{
+    collections::HashSet,
+    ops::Range,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
 
 use async_trait::async_trait;
-use azure_core::{auth::TokenCredential, new_http_client};
-use azure_identity::{ClientSecretCredential, DefaultAzureCredential, TokenCredentialOptions};
+use azure_core::{
+    auth::{AccessToken, TokenCredential, TokenResponse},
+    error::ErrorKind,
+    new_http_client,
+};
+use azure_identity::{
+    ClientSecretCredential, DefaultAzureCredential, ImdsManagedIdentityCredential,
+    TokenCredentialOptions,
+};
 use azure_storage::{prelude::*, CloudLocation};
 use azure_storage_blobs::{
-    blob::operations::GetBlobResponse,
+    blob::{operations::GetBlobResponse, Blob, BlobBlockType, BlockList},
     container::{operations::BlobItem, Container},
     prelude::*,
 };
-use common_io_config::AzureConfig;
+use common_io_config::{AzureConfig, BearerTokenProviderWrapper};
 use derive_builder::Builder;
 use futures::{stream::BoxStream, StreamExt, TryStreamExt};
 use snafu::{IntoError, ResultExt, Snafu};
+use time::OffsetDateTime;
 
 use crate::{
-    object_io::{FileMetadata, FileType, LSResult, ObjectSource},
+    object_io::{FileMetadata, FileType, LSResult, MultipartUpload, ObjectSource},
+    object_store_glob::GlobOptions,
     stats::IOStatsRef,
     stream_utils::io_stats_on_bytestream,
     FileFormat, GetResult,
@@ -54,6 +70,12 @@ enum Error {
         source: azure_storage::Error,
     },
 
+    #[snafu(display("Unable to write data to {}: {}", path, source))]
+    UnableToWrite {
+        path: String,
+        source: azure_storage::Error,
+    },
+
     #[snafu(display("Unable to read metadata about {}: {}", path, source))]
     RequestFailedForPath {
         path: String,
@@ -67,16 +89,32 @@ enum Error {
     NotAFile { path: String },
 }
 
-#[derive(Builder)]
+#[derive(Builder, Clone)]
 #[builder(setter(into))]
 struct ParsedAzureUri {
     pub protocol: String,
     #[builder(setter(strip_option), default)]
     pub account_name: Option<String>,
+    /// The host of the storage account, if the URI has one, e.g. `account.dfs.core.windows.net`.
+    #[builder(setter(strip_option), default)]
+    pub account_host: Option<String>,
     #[builder(setter(strip_option), default)]
     pub container_and_key: Option<(String, String)>,
 }
 
+impl ParsedAzureUri {
+    /// The URI of a container, in the form that paths listed from it should take.
+    ///
+    /// URIs with a storage account host are normalized to `PROTOCOL://container@account.dfs.core.windows.net`, so
+    /// that listed paths keep the storage account, e.g. for reading them on other machines.
+    fn container_uri(&self, container: &str) -> String {
+        match &self.account_host {
+            Some(host) => format!("{}://{container}@{host}", self.protocol),
+            None => format!("{}://{container}", self.protocol),
+        }
+    }
+}
+
 /// Parse an Azure URI into its components.
 /// Returns (protocol, (container, key) if exists, storage account if exists).
 fn parse_azure_uri(uri: &str) -> super::Result<ParsedAzureUri> {
@@ -98,8 +136,15 @@ fn parse_azure_uri(uri: &str) -> super::Result<ParsedAzureUri> {
         if host.ends_with(AZURE_STORE_SUFFIX) {
             match uri.username() {
                 "" => {
-                    if let Some((container, key)) = uri.path().split_once('/') {
-                        builder.container_and_key((container.into(), key.into()));
+                    // Keys start with the delimiter, like those of the other URI formats
+                    match uri.path().trim_start_matches('/').split_once('/') {
+                        Some((container, key)) => {
+                            builder.container_and_key((container.into(), format!("/{key}")));
+                        }
+                        None if uri.path().len() > 1 => {
+                            builder.container_and_key((uri.path()[1..].into(), String::new()));
+                        }
+                        None => {}
                     }
                 }
                 username => {
@@ -109,6 +154,7 @@ fn parse_azure_uri(uri: &str) -> super::Result<ParsedAzureUri> {
 
             let account_name_len = host.len() - AZURE_STORE_SUFFIX.len();
             builder.account_name(&host[..account_name_len]);
+            builder.account_host(host);
         } else {
             builder.container_and_key((host.into(), uri.path().into()));
         }
@@ -138,6 +184,21 @@ impl From<Error> for super::Error {
                         path,
                         source: source.into(),
                     },
+                    // Azure Storage throttles with 503 Server Busy as well as 429
+                    Some(429 | 503) => Self::Throttled {
+                        path,
+                        source: source.into(),
+                    },
+                    Some(408 | 500 | 502 | 504) => Self::MiscTransient {
+                        path,
+                        source: source.into(),
+                    },
+                    None if matches!(source.kind(), azure_core::error::ErrorKind::Io) => {
+                        Self::SocketError {
+                            path,
+                            source: source.into(),
+                        }
+                    }
                     None | Some(_) => Self::UnableToOpenFile {
                         path,
                         source: source.into(),
@@ -157,6 +218,94 @@ impl From<Error> for super::Error {
     }
 }
 
+/// Whether a listed blob is a directory, which storage accounts with a hierarchical namespace (ADLS Gen2) list
+/// alongside files.
+fn is_directory_blob(blob: &Blob) -> bool {
+    blob.properties.resource_type.as_deref() == Some("directory")
+}
+
+/// Uploads a block blob as blocks that are committed together once all of them are uploaded.
+///
+/// Blocks that are never committed are discarded by Azure after a week, so aborting the upload does nothing.
+struct AzureBlockUpload {
+    uri: String,
+    blob_client: BlobClient,
+    num_parts: AtomicUsize,
+    io_stats: Option<IOStatsRef>,
+}
+
+impl AzureBlockUpload {
+    /// Block IDs must all have the same length within a blob.
+    fn block_id(part_number: usize) -> String {
+        format!("{part_number:010}")
+    }
+}
+
+#[async_trait]
+impl MultipartUpload for AzureBlockUpload {
+    async fn upload_part(&self, part_number: usize, data: bytes::Bytes) -> super::Result<()> {
+        let num_bytes = data.len();
+        self.blob_client
+            .put_block(Self::block_id(part_number), data)
+            .await
+            .context(UnableToWriteSnafu::<String> {
+                path: self.uri.clone(),
+            })?;
+        self.num_parts.fetch_max(part_number, Ordering::Relaxed);
+        if let Some(is) = self.io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_uploaded(num_bytes);
+        }
+        Ok(())
+    }
+
+    async fn complete(&self) -> super::Result<()> {
+        let block_list = BlockList {
+            blocks: (1..=self.num_parts.load(Ordering::Relaxed))
+                .map(|part_number| BlobBlockType::new_uncommitted(Self::block_id(part_number)))
+                .collect(),
+        };
+        self.blob_client.put_block_list(block_list).await.context(
+            UnableToWriteSnafu::<String> {
+                path: self.uri.clone(),
+            },
+        )?;
+        if let Some(is) = self.io_stats.as_ref() {
+            is.mark_put_requests(1);
+        }
+        Ok(())
+    }
+
+    async fn abort(&self) -> super::Result<()> {
+        Ok(())
+    }
+}
+
+/// Tokens from a custom token provider, which is called for a new token whenever the last one expires.
+struct ProvidedTokenCredential {
+    provider: BearerTokenProviderWrapper,
+}
+
+#[async_trait]
+impl TokenCredential for ProvidedTokenCredential {
+    async fn get_token(&self, _resource: &str) -> azure_core::Result<TokenResponse> {
+        let token = self
+            .provider
+            .get_cached_token()
+            .map_err(|e| azure_core::Error::new(ErrorKind::Credential, e))?;
+        // Tokens without an expiry are valid for as long as they are used, and expiring ones are replaced by the
+        // provider itself before they expire
+        let expires_on = token
+            .expiry
+            .and_then(|expiry| OffsetDateTime::from_unix_timestamp(expiry.timestamp()).ok())
+            .unwrap_or_else(|| OffsetDateTime::now_utc() + time::Duration::hours(1));
+        Ok(TokenResponse::new(
+            AccessToken::new(token.token),
+            expires_on,
+        ))
+    }
+}
+
 pub struct AzureBlobSource {
     blob_client: Arc<BlobServiceClient>,
 }
@@ -197,8 +346,20 @@ impl AzureBlobSource {
         } else if let Some(sas_token) = sas_token {
             StorageCredentials::sas_token(sas_token)
                 .map_err(|e| Error::AzureGeneric { source: e })?
+        } else if let Some(token_provider) = &config.token_provider {
+            StorageCredentials::token_credential(Arc::new(ProvidedTokenCredential {
+                provider: token_provider.clone(),
+            }))
         } else if let Some(bearer_token) = bearer_token {
             StorageCredentials::bearer_token(bearer_token)
+        } else if config.use_managed_identity {
+            let credential = ImdsManagedIdentityCredential::new(new_http_client());
+            // A client ID picks a user-assigned identity, rather than the one assigned by the system
+            let credential = match &config.client_id {
+                Some(client_id) => credential.with_client_id(client_id.clone()),
+                None => credential,
+            };
+            StorageCredentials::token_credential(Arc::new(credential))
         } else if let Some(tenant_id) = &config.tenant_id
             && let Some(client_id) = &config.client_id
             && let Some(client_secret) = &config.client_secret
@@ -255,10 +416,10 @@ impl AzureBlobSource {
 
     async fn list_containers_stream(
         &self,
-        protocol: &str,
+        parsed_uri: &ParsedAzureUri,
         io_stats: Option<IOStatsRef>,
     ) -> BoxStream<super::Result<FileMetadata>> {
-        let protocol = protocol.to_string();
+        let parsed_uri = parsed_uri.clone();
 
         // Paginated stream of results from Azure API call.
         let responses_stream = self
@@ -274,12 +435,12 @@ impl AzureBlobSource {
                 if let Some(is) = io_stats.clone() {
                     is.mark_list_requests(1);
                 }
-                (response, protocol.clone())
+                (response, parsed_uri.clone())
             })
-            .flat_map(move |(response, protocol)| match response {
+            .flat_map(move |(response, parsed_uri)| match response {
                 Ok(response) => {
                     let containers = response.containers.into_iter().map(move |container| {
-                        Ok(self.container_to_file_metadata(protocol.as_str(), &container))
+                        Ok(self.container_to_file_metadata(&parsed_uri, &container))
                     });
                     futures::stream::iter(containers).boxed()
                 }
@@ -293,7 +454,7 @@ impl AzureBlobSource {
 
     async fn list_directory_stream(
         &self,
-        protocol: &str,
+        container_uri: &str,
         container_name: &str,
         prefix: &str,
         posix: bool,
@@ -302,8 +463,7 @@ impl AzureBlobSource {
         let container_client = self.blob_client.container_client(container_name);
 
         // Clone and own some references that we need for the lifetime of the stream.
-        let protocol = protocol.to_string();
-        let container_name = container_name.to_string();
+        let container_uri = container_uri.to_string();
         let prefix = prefix.to_string();
 
         // Blob stores expose listing by prefix and delimiter,
@@ -316,17 +476,13 @@ impl AzureBlobSource {
             "{}{AZURE_DELIMITER}",
             prefix.trim_end_matches(&AZURE_DELIMITER)
         );
-        let full_path = format!("{protocol}://{container_name}{prefix}");
-        let full_path_with_trailing_delimiter = format!(
-            "{}://{}{}",
-            protocol, container_name, &prefix_with_delimiter
-        );
+        let full_path = format!("{container_uri}{prefix}");
+        let full_path_with_trailing_delimiter = format!("{container_uri}{prefix_with_delimiter}");
 
         let mut unchecked_results = self
             .list_directory_delimiter_stream(
                 &container_client,
-                &protocol,
-                &container_name,
+                &container_uri,
                 &prefix_with_delimiter,
                 &posix,
                 io_stats.clone(),
@@ -376,8 +532,7 @@ impl AzureBlobSource {
 
                             let upper_results_stream = self.list_directory_delimiter_stream(
                                 &container_client,
-                                &protocol,
-                                &container_name,
+                                &container_uri,
                                 upper_dir,
                                 &posix,
                                 io_stats.clone()
@@ -424,8 +579,7 @@ impl AzureBlobSource {
     async fn list_directory_delimiter_stream(
         &self,
         container_client: &ContainerClient,
-        protocol: &str,
-        container_name: &str,
+        container_uri: &str,
         prefix: &str,
         posix: &bool,
         io_stats: Option<IOStatsRef>,
@@ -434,15 +588,15 @@ impl AzureBlobSource {
         // and returns the result flattened and standardized into FileMetadata.
 
         // Clone and own some references that we need for the lifetime of the stream.
-        let protocol = protocol.to_string();
-        let container_name = container_name.to_string();
+        let container_uri = container_uri.to_string();
         let prefix = prefix.to_string();
+        let posix = *posix;
 
         // Paginated response stream from Azure API.
         let mut responses_stream = container_client.list_blobs().prefix(prefix.clone());
 
         // Setting delimiter will trigger "directory-mode" which is a posix-like ls for the current directory
-        if *posix {
+        if posix {
             responses_stream = responses_stream.delimiter(AZURE_DELIMITER.to_string());
         }
 
@@ -454,18 +608,38 @@ impl AzureBlobSource {
                 if let Some(is) = io_stats.clone() {
                     is.mark_list_requests(1);
                 }
-                (response, protocol.clone(), container_name.clone())
+                (response, container_uri.clone())
             })
-            .flat_map(move |(response, protocol, container_name)| match response {
+            .flat_map(move |(response, container_uri)| match response {
                 Ok(response) => {
-                    let paths_data = response.blobs.items.into_iter().map(move |blob_item| {
-                        Ok(self.blob_item_to_file_metadata(&protocol, &container_name, &blob_item))
-                    });
+                    // With a hierarchical namespace, directories are blobs too. A directory is already listed as a
+                    // prefix if it has anything in it, and isn't listed at all when listing recursively, which only
+                    // lists files.
+                    let prefixes = response
+                        .blobs
+                        .prefixes()
+                        .map(|prefix| prefix.name.clone())
+                        .collect::<HashSet<_>>();
+                    let paths_data = response
+                        .blobs
+                        .items
+                        .into_iter()
+                        .filter(move |blob_item| match blob_item {
+                            BlobItem::Blob(blob) if is_directory_blob(blob) => {
+                                posix
+                                    && !prefixes
+                                        .contains(&format!("{}{AZURE_DELIMITER}", blob.name))
+                            }
+                            _ => true,
+                        })
+                        .map(move |blob_item| {
+                            Ok(self.blob_item_to_file_metadata(&container_uri, &blob_item))
+                        });
                     futures::stream::iter(paths_data).boxed()
                 }
                 Err(error) => {
                     let error = Err(Error::RequestFailedForPath {
-                        path: format!("{}://{}{}", &protocol, &container_name, &prefix),
+                        path: format!("{}{}", &container_uri, &prefix),
                         source: error,
                     }
                     .into());
@@ -475,32 +649,79 @@ impl AzureBlobSource {
             .boxed()
     }
 
-    fn container_to_file_metadata(&self, protocol: &str, container: &Container) -> FileMetadata {
+    fn blob_client(&self, uri: &str) -> super::Result<BlobClient> {
+        let parsed_uri = parse_azure_uri(uri)?;
+        let (container, key) = parsed_uri
+            .container_and_key
+            .ok_or_else(|| Error::InvalidUrl {
+                path: uri.into(),
+                source: url::ParseError::EmptyHost,
+            })?;
+
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+
+        Ok(self
+            .blob_client
+            .container_client(container)
+            .blob_client(key))
+    }
+
+    fn container_to_file_metadata(
+        &self,
+        parsed_uri: &ParsedAzureUri,
+        container: &Container,
+    ) -> FileMetadata {
         // NB: Cannot pass through to Azure client's .url() methods here
         // because they return URIs of a very different format (https://.../container/path).
         FileMetadata {
-            filepath: format!("{protocol}://{}/", &container.name),
+            filepath: format!("{}/", parsed_uri.container_uri(&container.name)),
             size: None,
             filetype: FileType::Directory,
+            last_modified: None,
+            etag: None,
+            version_id: None,
+            storage_class: None,
         }
     }
 
     fn blob_item_to_file_metadata(
         &self,
-        protocol: &str,
-        container_name: &str,
+        container_uri: &str,
         blob_item: &BlobItem,
     ) -> FileMetadata {
         match blob_item {
+            BlobItem::Blob(blob) if is_directory_blob(blob) => FileMetadata {
+                filepath: format!("{container_uri}/{}{AZURE_DELIMITER}", &blob.name),
+                size: None,
+                filetype: FileType::Directory,
+                last_modified: None,
+                etag: None,
+                version_id: None,
+                storage_class: None,
+            },
             BlobItem::Blob(blob) => FileMetadata {
-                filepath: format!("{protocol}://{}/{}", container_name, &blob.name),
+                filepath: format!("{container_uri}/{}", &blob.name),
                 size: Some(blob.properties.content_length),
                 filetype: FileType::File,
+                last_modified: Some(blob.properties.last_modified),
+                etag: Some(blob.properties.etag.to_string()),
+                version_id: blob.version_id.clone(),
+                storage_class: blob
+                    .properties
+                    .access_tier
+                    .as_ref()
+                    .map(ToString::to_string),
             },
             BlobItem::BlobPrefix(prefix) => FileMetadata {
-                filepath: format!("{protocol}://{}/{}", container_name, &prefix.name),
+                filepath: format!("{container_uri}/{}", &prefix.name),
                 size: None,
                 filetype: FileType::Directory,
+                last_modified: None,
+                etag: None,
+                version_id: None,
+                storage_class: None,
             },
         }
     }
@@ -514,20 +735,7 @@ impl ObjectSource for AzureBlobSource {
         range: Option<Range<usize>>,
         io_stats: Option<IOStatsRef>,
     ) -> super::Result<GetResult> {
-        let parsed_uri = parse_azure_uri(uri)?;
-        let (container, key) = parsed_uri
-            .container_and_key
-            .ok_or_else(|| Error::InvalidUrl {
-                path: uri.into(),
-                source: url::ParseError::EmptyHost,
-            })?;
-
-        if key.is_empty() {
-            return Err(Error::NotAFile { path: uri.into() }.into());
-        }
-
-        let container_client = self.blob_client.container_client(container);
-        let blob_client = container_client.blob_client(key);
+        let blob_client = self.blob_client(uri)?;
         let request_builder = blob_client.get();
         let request_builder = if let Some(range) = range {
             request_builder.range(range)
@@ -557,30 +765,57 @@ impl ObjectSource for AzureBlobSource {
         ))
     }
 
+    /// Writes a block blob. With a hierarchical namespace, the directories above it are created if they don't exist.
     async fn put(
         &self,
-        _uri: &str,
-        _data: bytes::Bytes,
-        _io_stats: Option<IOStatsRef>,
+        uri: &str,
+        data: bytes::Bytes,
+        io_stats: Option<IOStatsRef>,
     ) -> super::Result<()> {
-        todo!("PUTs to Azure blob store are not yet supported! Please file an issue.");
+        let num_bytes = data.len();
+        self.blob_client(uri)?
+            .put_block_blob(data)
+            .await
+            .context(UnableToWriteSnafu::<String> { path: uri.into() })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_uploaded(num_bytes);
+        }
+        Ok(())
     }
 
-    async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
-        let parsed_uri = parse_azure_uri(uri)?;
-        let (container, key) = parsed_uri
-            .container_and_key
-            .ok_or_else(|| Error::InvalidUrl {
-                path: uri.into(),
-                source: url::ParseError::EmptyHost,
-            })?;
+    async fn create_multipart_upload(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<Arc<dyn MultipartUpload>>> {
+        Ok(Some(Arc::new(AzureBlockUpload {
+            uri: uri.to_string(),
+            blob_client: self.blob_client(uri)?,
+            num_parts: AtomicUsize::new(0),
+            io_stats,
+        })))
+    }
 
-        if key.is_empty() {
-            return Err(Error::NotAFile { path: uri.into() }.into());
+    async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+        let blob_client = self.blob_client(uri)?;
+        let metadata = blob_client
+            .get_properties()
+            .await
+            .context(UnableToOpenFileSnafu::<String> { path: uri.into() })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_head_requests(1);
         }
 
-        let container_client = self.blob_client.container_client(container);
-        let blob_client = container_client.blob_client(key);
+        Ok(metadata.blob.properties.content_length as usize)
+    }
+
+    async fn get_version(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<String>> {
+        let blob_client = self.blob_client(uri)?;
         let metadata = blob_client
             .get_properties()
             .await
@@ -589,7 +824,7 @@ impl ObjectSource for AzureBlobSource {
             is.mark_head_requests(1);
         }
 
-        Ok(metadata.blob.properties.content_length as usize)
+        Ok(Some(metadata.blob.properties.etag.to_string()))
     }
 
     async fn glob(
@@ -600,6 +835,7 @@ impl ObjectSource for AzureBlobSource {
         limit: Option<usize>,
         io_stats: Option<IOStatsRef>,
         _file_format: Option<FileFormat>,
+        options: &GlobOptions,
     ) -> super::Result<BoxStream<'static, super::Result<FileMetadata>>> {
         use crate::object_store_glob::glob;
 
@@ -613,6 +849,7 @@ impl ObjectSource for AzureBlobSource {
             page_size.or(Some(1000)),
             limit,
             io_stats,
+            options,
         )
         .await
     }
@@ -626,15 +863,13 @@ impl ObjectSource for AzureBlobSource {
     ) -> super::Result<BoxStream<super::Result<FileMetadata>>> {
         let parsed_uri = parse_azure_uri(uri)?;
 
-        match parsed_uri.container_and_key {
+        match &parsed_uri.container_and_key {
             // List containers.
-            None => Ok(self
-                .list_containers_stream(parsed_uri.protocol.as_str(), io_stats)
-                .await),
+            None => Ok(self.list_containers_stream(&parsed_uri, io_stats).await),
             // List a path within a container.
             Some((container_name, key)) => Ok(self
                 .list_directory_stream(
-                    parsed_uri.protocol.as_str(),
+                    &parsed_uri.container_uri(container_name),
                     container_name.as_str(),
                     key.as_str(),
                     posix,
@@ -676,3 +911,56 @@ impl ObjectSource for AzureBlobSource {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::parse_azure_uri;
+
+    #[test]
+    fn test_parse_container_uri() {
+        let parsed = parse_azure_uri("az://container/dir/file.parquet").unwrap();
+        assert_eq!(parsed.account_name, None);
+        assert_eq!(
+            parsed.container_and_key,
+            Some(("container".to_string(), "/dir/file.parquet".to_string()))
+        );
+        assert_eq!(parsed.container_uri("container"), "az://container");
+    }
+
+    #[test]
+    fn test_parse_adls_uri() {
+        let parsed =
+            parse_azure_uri("abfss://container@account.dfs.core.windows.net/dir/file.parquet")
+                .unwrap();
+        assert_eq!(parsed.account_name.as_deref(), Some("account"));
+        assert_eq!(
+            parsed.container_and_key,
+            Some(("container".to_string(), "/dir/file.parquet".to_string()))
+        );
+        assert_eq!(
+            parsed.container_uri("container"),
+            "abfss://container@account.dfs.core.windows.net"
+        );
+    }
+
+    #[test]
+    fn test_parse_account_host_uri() {
+        let parsed =
+            parse_azure_uri("abfss://account.dfs.core.windows.net/container/dir/file.parquet")
+                .unwrap();
+        assert_eq!(parsed.account_name.as_deref(), Some("account"));
+        assert_eq!(
+            parsed.container_and_key,
+            Some(("container".to_string(), "/dir/file.parquet".to_string()))
+        );
+
+        let parsed = parse_azure_uri("abfss://account.dfs.core.windows.net/container").unwrap();
+        assert_eq!(
+            parsed.container_and_key,
+            Some(("container".to_string(), String::new()))
+        );
+
+        let parsed = parse_azure_uri("abfss://account.dfs.core.windows.net/").unwrap();
+        assert_eq!(parsed.container_and_key, None);
+    }
+}
@@ -0,0 +1,257 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::Range,
+    path::{Path, PathBuf},
+    sync::{Arc, LazyLock, Mutex},
+    time::SystemTime,
+};
+
+use bytes::Bytes;
+use common_io_config::DiskCacheConfig;
+
+use crate::local::LocalFile;
+
+const DEFAULT_CACHE_DIRECTORY: &str = "daft-cache";
+const TMP_FILE_EXTENSION: &str = "tmp";
+
+/// Caches are shared by all IO clients that cache in the same directory, so that they agree on its size.
+static DISK_CACHES: LazyLock<Mutex<HashMap<PathBuf, Arc<DiskCache>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// The files in the cache by when they were last used.
+#[derive(Default)]
+struct CacheIndex {
+    /// Size and last use of each file, by name.
+    entries: HashMap<String, (u64, u64)>,
+    /// Names of the files by last use, least recent first.
+    lru: BTreeMap<u64, String>,
+    next_use: u64,
+    size_bytes: u64,
+}
+
+impl CacheIndex {
+    fn touch(&mut self, name: &str) -> bool {
+        let Some((_, last_use)) = self.entries.get_mut(name) else {
+            return false;
+        };
+        self.lru.remove(last_use);
+        *last_use = self.next_use;
+        self.lru.insert(self.next_use, name.to_string());
+        self.next_use += 1;
+        true
+    }
+
+    fn insert(&mut self, name: String, size_bytes: u64) {
+        if let Some((old_size_bytes, last_use)) = self.entries.remove(&name) {
+            self.lru.remove(&last_use);
+            self.size_bytes -= old_size_bytes;
+        }
+        self.entries
+            .insert(name.clone(), (size_bytes, self.next_use));
+        self.lru.insert(self.next_use, name);
+        self.next_use += 1;
+        self.size_bytes += size_bytes;
+    }
+
+    /// Removes the least recently used files until the cache is no larger than `max_size_bytes`, returning their names.
+    fn evict(&mut self, max_size_bytes: u64) -> Vec<String> {
+        let mut evicted = vec![];
+        while self.size_bytes > max_size_bytes
+            && let Some((_, name)) = self.lru.pop_first()
+        {
+            if let Some((size_bytes, _)) = self.entries.remove(&name) {
+                self.size_bytes -= size_bytes;
+            }
+            evicted.push(name);
+        }
+        evicted
+    }
+}
+
+/// Read-through cache of data read from object stores on local disk, with the least recently used data evicted to
+/// keep it under a maximum size.
+///
+/// Data is cached by the URI that it was read from, the version of the object that it was read from, and the range
+/// that was read, so data of objects that have since changed is never read, but is left to be evicted.
+pub(crate) struct DiskCache {
+    directory: PathBuf,
+    max_size_bytes: u64,
+    index: Mutex<CacheIndex>,
+}
+
+impl DiskCache {
+    /// Opens the cache in the configured directory, indexing the data that is already cached there.
+    pub fn open(config: &DiskCacheConfig) -> std::io::Result<Arc<Self>> {
+        let directory = config.directory.as_ref().map_or_else(
+            || std::env::temp_dir().join(DEFAULT_CACHE_DIRECTORY),
+            PathBuf::from,
+        );
+        let mut caches = DISK_CACHES.lock().unwrap();
+        if let Some(cache) = caches.get(&directory) {
+            return Ok(cache.clone());
+        }
+
+        std::fs::create_dir_all(&directory)?;
+        let mut files = vec![];
+        for entry in std::fs::read_dir(&directory)? {
+            let entry = entry?;
+            let path = entry.path();
+            let metadata = entry.metadata()?;
+            if !metadata.is_file() {
+                continue;
+            }
+            // Temporary files are left behind by processes that stopped while caching data
+            if path
+                .extension()
+                .is_some_and(|ext| ext == TMP_FILE_EXTENSION)
+            {
+                let _ = std::fs::remove_file(&path);
+                continue;
+            }
+            let last_used = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            files.push((
+                last_used,
+                entry.file_name().to_string_lossy().to_string(),
+                metadata.len(),
+            ));
+        }
+        files.sort();
+
+        let mut index = CacheIndex::default();
+        for (_, name, size_bytes) in files {
+            index.insert(name, size_bytes);
+        }
+        let cache = Arc::new(Self {
+            directory: directory.clone(),
+            max_size_bytes: config.max_size_bytes,
+            index: Mutex::new(index),
+        });
+        caches.insert(directory, cache.clone());
+        Ok(cache)
+    }
+
+    fn file_name(uri: &str, version: &str, range: Option<&Range<usize>>) -> String {
+        let key = match range {
+            Some(range) => format!("{uri}\n{version}\n{}-{}", range.start, range.end),
+            None => format!("{uri}\n{version}"),
+        };
+        format!("{:x}", md5::compute(key))
+    }
+
+    fn use_file(&self, name: &str) -> Option<PathBuf> {
+        if !self.index.lock().unwrap().touch(name) {
+            return None;
+        }
+        let path = self.directory.join(name);
+        // Record the use on disk as well, for the order of eviction once the cache is opened again
+        if let Ok(file) = std::fs::File::options().write(true).open(&path) {
+            let _ = file.set_modified(SystemTime::now());
+        }
+        Some(path)
+    }
+
+    /// Returns the cached data of `range` of the version of the object at `uri`, either from the cached object or
+    /// from the cached range itself.
+    pub fn get(&self, uri: &str, version: &str, range: Option<&Range<usize>>) -> Option<LocalFile> {
+        if let Some(path) = self.use_file(&Self::file_name(uri, version, None)) {
+            return Some(LocalFile {
+                path,
+                range: range.cloned(),
+            });
+        }
+        let range = range?;
+        self.use_file(&Self::file_name(uri, version, Some(range)))
+            .map(|path| LocalFile { path, range: None })
+    }
+
+    /// Caches the data of `range` of the version of the object at `uri`, evicting other data to make room for it.
+    pub async fn insert(
+        &self,
+        uri: &str,
+        version: &str,
+        range: Option<&Range<usize>>,
+        data: &Bytes,
+    ) -> std::io::Result<()> {
+        let size_bytes = data.len() as u64;
+        if size_bytes > self.max_size_bytes {
+            return Ok(());
+        }
+
+        // Write to a temporary file first, so that other processes never read partially written data
+        let name = Self::file_name(uri, version, range);
+        let tmp_path = self.directory.join(format!(
+            "{name}.{}.{TMP_FILE_EXTENSION}",
+            rand::random::<u64>()
+        ));
+        tokio::fs::write(&tmp_path, data).await?;
+        tokio::fs::rename(&tmp_path, self.directory.join(&name)).await?;
+
+        let evicted = {
+            let mut index = self.index.lock().unwrap();
+            index.insert(name, size_bytes);
+            index.evict(self.max_size_bytes)
+        };
+        for name in evicted {
+            remove_file(&self.directory.join(name)).await?;
+        }
+        Ok(())
+    }
+}
+
+async fn remove_file(path: &Path) -> std::io::Result<()> {
+    match tokio::fs::remove_file(path).await {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use common_io_config::DiskCacheConfig;
+
+    use super::DiskCache;
+    use crate::local::collect_file;
+
+    #[tokio::test]
+    async fn test_disk_cache_serves_ranges_and_evicts_least_recently_used() -> crate::Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = DiskCache::open(&DiskCacheConfig {
+            enabled: true,
+            directory: Some(dir.path().to_string_lossy().to_string()),
+            max_size_bytes: 10,
+        })
+        .unwrap();
+
+        let uri = "s3://bucket/key";
+        cache
+            .insert(uri, "v1", None, &Bytes::from_static(b"hello"))
+            .await
+            .unwrap();
+        let file = cache.get(uri, "v1", Some(&(1..3))).unwrap();
+        assert_eq!(collect_file(file).await?.as_ref(), b"el");
+        assert!(cache.get(uri, "v2", None).is_none());
+
+        cache
+            .insert(uri, "v2", Some(&(0..4)), &Bytes::from_static(b"HELL"))
+            .await
+            .unwrap();
+        let file = cache.get(uri, "v2", Some(&(0..4))).unwrap();
+        assert_eq!(collect_file(file).await?.as_ref(), b"HELL");
+
+        // v1 was used less recently than v2, so it is evicted to make room
+        cache
+            .insert(
+                "s3://bucket/other",
+                "v1",
+                None,
+                &Bytes::from_static(b"world"),
+            )
+            .await
+            .unwrap();
+        assert!(cache.get(uri, "v1", None).is_none());
+        assert!(cache.get(uri, "v2", Some(&(0..4))).is_some());
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 2);
+        Ok(())
+    }
+}
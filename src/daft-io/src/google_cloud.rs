@@ -1,23 +1,39 @@
-use std::{ops::Range, sync::Arc, time::Duration};
+use std::{
+    collections::BTreeMap,
+    ops::Range,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
 use async_trait::async_trait;
-use common_io_config::GCSConfig;
+use bytes::{Bytes, BytesMut};
+use common_io_config::{BearerTokenProviderWrapper, GCSConfig};
 use common_runtime::get_io_pool_num_threads;
 use futures::{stream::BoxStream, TryStreamExt};
 use google_cloud_storage::{
     client::{google_cloud_auth::credentials::CredentialsFile, Client, ClientConfig},
     http::{
-        objects::{get::GetObjectRequest, list::ListObjectsRequest},
+        objects::{
+            get::GetObjectRequest,
+            list::ListObjectsRequest,
+            upload::{Media, UploadObjectRequest, UploadType},
+            Object,
+        },
+        resumable_upload_client::{ChunkSize, ResumableUploadClient, UploadStatus},
         Error as GError,
     },
 };
 use google_cloud_token::{TokenSource, TokenSourceProvider};
 use regex::Regex;
 use snafu::{IntoError, ResultExt, Snafu};
-use tokio::sync::Semaphore;
+use tokio::sync::{Mutex, Semaphore};
 
 use crate::{
-    object_io::{FileMetadata, FileType, LSResult, ObjectSource},
+    object_io::{FileMetadata, FileType, LSResult, MultipartUpload, ObjectSource},
+    object_store_glob::GlobOptions,
     retry::{ExponentialBackoff, RetryError},
     stats::IOStatsRef,
     stream_utils::io_stats_on_bytestream,
@@ -27,6 +43,8 @@ use crate::{
 const GCS_DELIMITER: &str = "/";
 const GCS_SCHEME: &str = "gs";
 const DEFAULT_GLOB_FANOUT_LIMIT: usize = 1024;
+/// All chunks of a resumable upload but the last must be a multiple of this size.
+const RESUMABLE_CHUNK_ALIGNMENT: usize = 256 * 1024;
 
 #[derive(Debug, Snafu)]
 enum Error {
@@ -39,6 +57,22 @@ enum Error {
     #[snafu(display("Unable to read data from {}: {}", path, source))]
     UnableToReadBytes { path: String, source: GError },
 
+    #[snafu(display("Unable to write data to {}: {}", path, source))]
+    UnableToWrite { path: String, source: GError },
+
+    #[snafu(display(
+        "Unable to continue upload to {} after an earlier part of it failed",
+        path
+    ))]
+    UploadFailed { path: String },
+
+    #[snafu(display(
+        "Unable to complete upload to {}: {} parts are missing",
+        path,
+        num_missing
+    ))]
+    MissingUploadParts { path: String, num_missing: usize },
+
     #[snafu(display("Unable to load Credentials: {}", source))]
     UnableToLoadCredentials {
         source: google_cloud_storage::client::google_cloud_auth::error::Error,
@@ -59,12 +93,14 @@ enum Error {
 impl From<Error> for super::Error {
     fn from(error: Error) -> Self {
         use Error::{
-            NotAFile, NotFound, UnableToCreateClient, UnableToGrabSemaphore, UnableToListObjects,
-            UnableToLoadCredentials, UnableToOpenFile, UnableToReadBytes,
+            MissingUploadParts, NotAFile, NotFound, UnableToCreateClient, UnableToGrabSemaphore,
+            UnableToListObjects, UnableToLoadCredentials, UnableToOpenFile, UnableToReadBytes,
+            UnableToWrite, UploadFailed,
         };
         match error {
             UnableToReadBytes { path, source }
             | UnableToOpenFile { path, source }
+            | UnableToWrite { path, source }
             | UnableToListObjects { path, source } => match source {
                 GError::HttpClient(err) => match err.status().map(|s| s.as_u16()) {
                     Some(404 | 410) => Self::NotFound {
@@ -76,6 +112,14 @@ impl From<Error> for super::Error {
                         path,
                         source: err.into(),
                     },
+                    Some(429) => Self::Throttled {
+                        path,
+                        source: err.into(),
+                    },
+                    Some(408 | 500..=599) => Self::MiscTransient {
+                        path,
+                        source: err.into(),
+                    },
                     _ => {
                         if err.is_connect() {
                             Self::ConnectTimeout {
@@ -105,6 +149,14 @@ impl From<Error> for super::Error {
                         path,
                         source: err.into(),
                     },
+                    429 => Self::Throttled {
+                        path,
+                        source: err.into(),
+                    },
+                    408 | 500..=599 => Self::MiscTransient {
+                        path,
+                        source: err.into(),
+                    },
                     _ => Self::UnableToOpenFile {
                         path,
                         source: err.into(),
@@ -130,10 +182,12 @@ impl From<Error> for super::Error {
                 source: source.into(),
             },
             NotAFile { path } => Self::NotAFile { path },
-            UnableToGrabSemaphore { .. } => Self::Generic {
-                store: crate::SourceType::GCS,
-                source: error.into(),
-            },
+            UnableToGrabSemaphore { .. } | UploadFailed { .. } | MissingUploadParts { .. } => {
+                Self::Generic {
+                    store: crate::SourceType::GCS,
+                    source: error.into(),
+                }
+            }
             UnableToCreateClient { .. } => Self::UnableToCreateClient {
                 store: crate::SourceType::GCS,
                 source: error.into(),
@@ -222,7 +276,11 @@ impl GCSClientWrapper {
         ))
     }
 
-    async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+    async fn get_object_metadata(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Object> {
         let (bucket, key) = parse_raw_uri(uri)?;
         if key.is_empty() {
             return Err(Error::NotAFile { path: uri.into() }.into());
@@ -250,8 +308,83 @@ impl GCSClientWrapper {
         if let Some(is) = io_stats.as_ref() {
             is.mark_head_requests(1);
         }
-        Ok(response.size as usize)
+        Ok(response)
+    }
+
+    async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+        let object = self.get_object_metadata(uri, io_stats).await?;
+        Ok(object.size as usize)
+    }
+
+    async fn get_version(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<String> {
+        // The generation of an object changes whenever its data is replaced
+        let object = self.get_object_metadata(uri, io_stats).await?;
+        Ok(object.generation.to_string())
+    }
+
+    async fn put(&self, uri: &str, data: Bytes, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let (bucket, key) = parse_raw_uri(uri)?;
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+
+        let _permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+
+        let req = UploadObjectRequest {
+            bucket: bucket.into(),
+            ..Default::default()
+        };
+        let num_bytes = data.len();
+        self.client
+            .upload_object(&req, data, &UploadType::Simple(Media::new(key.to_string())))
+            .await
+            .context(UnableToWriteSnafu {
+                path: uri.to_string(),
+            })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_uploaded(num_bytes);
+        }
+        Ok(())
+    }
+
+    async fn create_resumable_upload(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<GCSResumableUpload> {
+        let (bucket, key) = parse_raw_uri(uri)?;
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+
+        let req = UploadObjectRequest {
+            bucket: bucket.into(),
+            ..Default::default()
+        };
+        let session = self
+            .client
+            .prepare_resumable_upload(&req, &UploadType::Simple(Media::new(key.to_string())))
+            .await
+            .context(UnableToWriteSnafu {
+                path: uri.to_string(),
+            })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+        }
+        Ok(GCSResumableUpload {
+            uri: uri.to_string(),
+            session,
+            buffer: Mutex::new(ResumableUploadBuffer::new()),
+            offset: AtomicU64::new(0),
+            io_stats,
+        })
     }
+
     #[allow(clippy::too_many_arguments)]
     async fn ls_impl(
         &self,
@@ -291,11 +424,19 @@ impl GCSClientWrapper {
             filepath: format!("{GCS_SCHEME}://{}/{}", bucket, obj.name),
             size: Some(obj.size as u64),
             filetype: FileType::File,
+            last_modified: obj.updated,
+            etag: Some(obj.etag.clone()).filter(|etag| !etag.is_empty()),
+            version_id: Some(obj.generation.to_string()).filter(|_| obj.generation != 0),
+            storage_class: obj.storage_class.clone(),
         });
         let dirs = response_prefixes.iter().map(|pref| FileMetadata {
             filepath: format!("{GCS_SCHEME}://{bucket}/{pref}"),
             size: None,
             filetype: FileType::Directory,
+            last_modified: None,
+            etag: None,
+            version_id: None,
+            storage_class: None,
         });
         Ok(LSResult {
             files: files.chain(dirs).collect(),
@@ -386,6 +527,218 @@ impl GCSClientWrapper {
     }
 }
 
+/// Whether a failed request to GCS is likely to succeed if it is sent again.
+fn is_transient_error(err: &GError) -> bool {
+    match err {
+        GError::HttpClient(err) => {
+            err.is_connect()
+                || err.is_timeout()
+                || err.is_request()
+                || matches!(
+                    err.status().map(|s| s.as_u16()),
+                    Some(408 | 429 | 500..=599)
+                )
+        }
+        GError::Response(err) => matches!(err.code, 408 | 429 | 500..=599),
+        GError::HttpMiddleware(_) => true,
+        GError::TokenSource(_) | GError::InvalidRangeHeader(_) => false,
+    }
+}
+
+/// The parts of a resumable upload that haven't been sent to GCS yet.
+///
+/// GCS appends the chunks of a resumable upload in order, and all chunks but the last must be a multiple of
+/// [`RESUMABLE_CHUNK_ALIGNMENT`], whereas parts may arrive in any order and have any size. So parts are held back until
+/// the parts before them have arrived, and whatever doesn't fill an aligned chunk is sent along with the next part.
+#[derive(Debug)]
+struct ResumableUploadBuffer {
+    next_part_number: usize,
+    /// Parts that arrived before some part before them, by part number.
+    pending_parts: BTreeMap<usize, Bytes>,
+    /// In order data that hasn't been sent yet, which is always less than a chunk once the ready chunks are taken.
+    unsent: BytesMut,
+    /// Whether sending a chunk failed, after which the upload can't continue since GCS is missing data.
+    failed: bool,
+}
+
+impl ResumableUploadBuffer {
+    fn new() -> Self {
+        Self {
+            next_part_number: 1,
+            pending_parts: BTreeMap::new(),
+            unsent: BytesMut::new(),
+            failed: false,
+        }
+    }
+
+    /// Adds a part, and returns the data that is ready to be sent as a chunk, which may be empty.
+    fn add_part(&mut self, part_number: usize, data: Bytes) -> Bytes {
+        self.pending_parts.insert(part_number, data);
+        while let Some(data) = self.pending_parts.remove(&self.next_part_number) {
+            self.unsent.extend_from_slice(&data);
+            self.next_part_number += 1;
+        }
+        let ready = self.unsent.len() - self.unsent.len() % RESUMABLE_CHUNK_ALIGNMENT;
+        self.unsent.split_to(ready).freeze()
+    }
+
+    /// Returns the data to be sent as the last chunk, or the number of parts that never arrived.
+    fn finish(&mut self) -> Result<Bytes, usize> {
+        if self.pending_parts.is_empty() {
+            Ok(std::mem::take(&mut self.unsent).freeze())
+        } else {
+            Err(self.pending_parts.len())
+        }
+    }
+}
+
+/// An upload to GCS with the resumable upload protocol, which streams the parts of a file into a single upload session.
+///
+/// Chunks whose request fails with a transient error are retried from the last byte that GCS persisted, so uploads of
+/// large files survive flaky connections without starting over.
+struct GCSResumableUpload {
+    uri: String,
+    session: ResumableUploadClient,
+    /// Held while sending a chunk, since the chunks of a session must be sent one at a time.
+    buffer: Mutex<ResumableUploadBuffer>,
+    /// The number of bytes that GCS has persisted.
+    offset: AtomicU64,
+    io_stats: Option<IOStatsRef>,
+}
+
+impl GCSResumableUpload {
+    /// Sends a chunk of data starting at the current offset, which is the last chunk of the file if `is_last`.
+    async fn send_chunk(&self, data: Bytes, is_last: bool) -> Result<(), GError> {
+        let start = self.offset.load(Ordering::Acquire);
+        let end = start + data.len() as u64;
+        let total_size = is_last.then_some(end);
+
+        ExponentialBackoff::default()
+            .retry(|| async {
+                loop {
+                    let persisted = self.offset.load(Ordering::Acquire);
+                    let chunk = data.slice((persisted - start) as usize..);
+                    let chunk_size = if chunk.is_empty() {
+                        // Only sent to finish the upload, since there's no data to send with it
+                        ChunkSize::new(persisted, persisted, total_size)
+                    } else {
+                        ChunkSize::new(persisted, end - 1, total_size)
+                    };
+                    let num_bytes = chunk.len();
+                    let status = match self.session.upload_multiple_chunk(chunk, &chunk_size).await
+                    {
+                        Ok(status) => status,
+                        Err(err) if is_transient_error(&err) => {
+                            // GCS may have persisted some of the chunk, so find out where to resume from
+                            if let Ok(status) = self.session.status(total_size).await {
+                                self.record_status(&status, end);
+                            }
+                            log::warn!(
+                                "Failed to upload chunk of {} bytes to {}: {err}. Retrying...",
+                                num_bytes,
+                                self.uri
+                            );
+                            return Err(RetryError::Transient(err));
+                        }
+                        Err(err) => return Err(RetryError::Permanent(err)),
+                    };
+                    if let Some(is) = self.io_stats.as_ref() {
+                        is.mark_put_requests(1);
+                        is.mark_bytes_uploaded(num_bytes);
+                    }
+                    let persisted_before = persisted;
+                    self.record_status(&status, end);
+                    let persisted = self.offset.load(Ordering::Acquire);
+                    if persisted == end && (!is_last || matches!(status, UploadStatus::Ok(_))) {
+                        return Ok(());
+                    }
+                    // GCS may persist only some of a chunk even if the request succeeds, in which case the rest is
+                    // sent again, unless it didn't make any progress at all
+                    if persisted == persisted_before && num_bytes > 0 {
+                        return Err(RetryError::Transient(GError::InvalidRangeHeader(format!(
+                            "GCS persisted no bytes of chunk {chunk_size} of {}",
+                            self.uri
+                        ))));
+                    }
+                }
+            })
+            .await
+    }
+
+    /// Records how much of the file GCS has persisted according to an upload status.
+    fn record_status(&self, status: &UploadStatus, end: u64) {
+        let persisted = match status {
+            UploadStatus::Ok(_) => end,
+            UploadStatus::NotStarted => 0,
+            UploadStatus::ResumeIncomplete(range) => range.last_byte + 1,
+        };
+        self.offset.store(persisted, Ordering::Release);
+    }
+
+    async fn send_or_fail(
+        &self,
+        buffer: &mut ResumableUploadBuffer,
+        data: Bytes,
+        is_last: bool,
+    ) -> super::Result<()> {
+        if let Err(err) = self.send_chunk(data, is_last).await {
+            buffer.failed = true;
+            return Err(UnableToWriteSnafu::<String> {
+                path: self.uri.clone(),
+            }
+            .into_error(err)
+            .into());
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl MultipartUpload for GCSResumableUpload {
+    async fn upload_part(&self, part_number: usize, data: Bytes) -> super::Result<()> {
+        let mut buffer = self.buffer.lock().await;
+        if buffer.failed {
+            return Err(Error::UploadFailed {
+                path: self.uri.clone(),
+            }
+            .into());
+        }
+        let ready = buffer.add_part(part_number, data);
+        if ready.is_empty() {
+            return Ok(());
+        }
+        self.send_or_fail(&mut buffer, ready, false).await
+    }
+
+    async fn complete(&self) -> super::Result<()> {
+        let mut buffer = self.buffer.lock().await;
+        if buffer.failed {
+            return Err(Error::UploadFailed {
+                path: self.uri.clone(),
+            }
+            .into());
+        }
+        let last_chunk = buffer
+            .finish()
+            .map_err(|num_missing| Error::MissingUploadParts {
+                path: self.uri.clone(),
+                num_missing,
+            })?;
+        self.send_or_fail(&mut buffer, last_chunk, true).await
+    }
+
+    async fn abort(&self) -> super::Result<()> {
+        self.session
+            .clone()
+            .cancel()
+            .await
+            .context(UnableToWriteSnafu::<String> {
+                path: self.uri.clone(),
+            })?;
+        Ok(())
+    }
+}
+
 pub struct GCSSource {
     client: GCSClientWrapper,
 }
@@ -408,6 +761,26 @@ impl TokenSource for FixedTokenSource {
     }
 }
 
+/// Tokens from a custom token provider, which is called for a new token whenever the last one expires.
+#[derive(Debug, Clone)]
+struct ProvidedTokenSource {
+    provider: BearerTokenProviderWrapper,
+}
+
+impl TokenSourceProvider for ProvidedTokenSource {
+    fn token_source(&self) -> Arc<dyn TokenSource> {
+        Arc::new(self.clone())
+    }
+}
+
+#[async_trait]
+impl TokenSource for ProvidedTokenSource {
+    async fn token(&self) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+        let token = self.provider.get_cached_token()?;
+        Ok(format!("Bearer {0}", token.token))
+    }
+}
+
 impl GCSSource {
     pub async fn get_client(config: &GCSConfig) -> super::Result<Arc<Self>> {
         let mut client_config = if config.anonymous {
@@ -425,6 +798,13 @@ impl GCSSource {
                 .with_credentials(creds)
                 .await
                 .context(UnableToLoadCredentialsSnafu {})?
+        } else if let Some(token_provider) = &config.token_provider {
+            ClientConfig {
+                token_source_provider: Some(Box::new(ProvidedTokenSource {
+                    provider: token_provider.clone(),
+                })),
+                ..Default::default()
+            }
         } else if let Some(token) = &config.token {
             ClientConfig {
                 token_source_provider: Some(Box::new(FixedTokenSource {
@@ -530,17 +910,35 @@ impl ObjectSource for GCSSource {
 
     async fn put(
         &self,
-        _uri: &str,
-        _data: bytes::Bytes,
-        _io_stats: Option<IOStatsRef>,
+        uri: &str,
+        data: bytes::Bytes,
+        io_stats: Option<IOStatsRef>,
     ) -> super::Result<()> {
-        todo!("PUTS to GCS are not yet supported! Please file an issue.");
+        self.client.put(uri, data, io_stats).await
+    }
+
+    async fn create_multipart_upload(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<Arc<dyn MultipartUpload>>> {
+        Ok(Some(Arc::new(
+            self.client.create_resumable_upload(uri, io_stats).await?,
+        )))
     }
 
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
         self.client.get_size(uri, io_stats).await
     }
 
+    async fn get_version(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<String>> {
+        self.client.get_version(uri, io_stats).await.map(Some)
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
@@ -549,6 +947,7 @@ impl ObjectSource for GCSSource {
         limit: Option<usize>,
         io_stats: Option<IOStatsRef>,
         _file_format: Option<FileFormat>,
+        options: &GlobOptions,
     ) -> super::Result<BoxStream<'static, super::Result<FileMetadata>>> {
         use crate::object_store_glob::glob;
 
@@ -562,6 +961,7 @@ impl ObjectSource for GCSSource {
             page_size.or(Some(1000)),
             limit,
             io_stats,
+            options,
         )
         .await
     }
@@ -579,3 +979,50 @@ impl ObjectSource for GCSSource {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+
+    use super::{ResumableUploadBuffer, RESUMABLE_CHUNK_ALIGNMENT};
+
+    fn part(len: usize, byte: u8) -> Bytes {
+        Bytes::from(vec![byte; len])
+    }
+
+    #[test]
+    fn test_resumable_upload_buffer_sends_aligned_chunks() {
+        let mut buffer = ResumableUploadBuffer::new();
+        let part_size = RESUMABLE_CHUNK_ALIGNMENT + 100;
+
+        let ready = buffer.add_part(1, part(part_size, 1));
+        assert_eq!(ready.len(), RESUMABLE_CHUNK_ALIGNMENT);
+        // The leftover of the first part is sent in front of the second one
+        let ready = buffer.add_part(2, part(part_size, 2));
+        assert_eq!(ready.len(), RESUMABLE_CHUNK_ALIGNMENT);
+        assert_eq!(&ready[..100], &[1; 100]);
+        assert_eq!(ready[100], 2);
+
+        assert_eq!(buffer.finish().unwrap().len(), 200);
+    }
+
+    #[test]
+    fn test_resumable_upload_buffer_holds_back_out_of_order_parts() {
+        let mut buffer = ResumableUploadBuffer::new();
+
+        assert!(buffer
+            .add_part(3, part(RESUMABLE_CHUNK_ALIGNMENT, 3))
+            .is_empty());
+        assert!(buffer
+            .add_part(2, part(RESUMABLE_CHUNK_ALIGNMENT, 2))
+            .is_empty());
+        assert_eq!(buffer.finish().unwrap_err(), 2);
+
+        let ready = buffer.add_part(1, part(RESUMABLE_CHUNK_ALIGNMENT, 1));
+        assert_eq!(ready.len(), 3 * RESUMABLE_CHUNK_ALIGNMENT);
+        assert_eq!(ready[0], 1);
+        assert_eq!(ready[RESUMABLE_CHUNK_ALIGNMENT], 2);
+        assert_eq!(ready[2 * RESUMABLE_CHUNK_ALIGNMENT], 3);
+        assert!(buffer.finish().unwrap().is_empty());
+    }
+}
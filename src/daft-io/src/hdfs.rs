@@ -0,0 +1,520 @@
+use std::{ops::Range, sync::Arc};
+
+use async_trait::async_trait;
+use common_io_config::HDFSConfig;
+use futures::{stream::BoxStream, TryStreamExt};
+use reqwest::header::LOCATION;
+use serde::Deserialize;
+use snafu::{IntoError, ResultExt, Snafu};
+use time::OffsetDateTime;
+use url::Url;
+
+use super::object_io::{GetResult, ObjectSource};
+use crate::{
+    object_io::{FileMetadata, FileType, LSResult},
+    object_store_glob::GlobOptions,
+    stats::IOStatsRef,
+    stream_utils::io_stats_on_bytestream,
+    FileFormat,
+};
+
+const HDFS_DELIMITER: &str = "/";
+const WEBHDFS_PATH_PREFIX: &str = "/webhdfs/v1";
+
+#[derive(Debug, Snafu)]
+enum Error {
+    #[snafu(display("Unable to connect to {}: {}", path, source))]
+    UnableToConnect {
+        path: String,
+        source: reqwest::Error,
+    },
+
+    #[snafu(display("Unable to open {}: {}", path, source))]
+    UnableToOpenFile {
+        path: String,
+        source: reqwest::Error,
+    },
+
+    #[snafu(display("Unable to read data from {}: {}", path, source))]
+    UnableToReadBytes {
+        path: String,
+        source: reqwest::Error,
+    },
+
+    #[snafu(display("Unable to write data to {}: {}", path, source))]
+    UnableToWrite {
+        path: String,
+        source: reqwest::Error,
+    },
+
+    #[snafu(display("Unable to list {}: {}", path, source))]
+    UnableToList {
+        path: String,
+        source: reqwest::Error,
+    },
+
+    #[snafu(display("Unable to parse WebHDFS response for {}: {}", path, source))]
+    UnableToParseResponse {
+        path: String,
+        source: reqwest::Error,
+    },
+
+    #[snafu(display("Unable to create Http Client {}", source))]
+    UnableToCreateClient { source: reqwest::Error },
+
+    #[snafu(display("Unable to parse URL: \"{}\"", path))]
+    InvalidUrl {
+        path: String,
+        source: url::ParseError,
+    },
+
+    #[snafu(display(
+        "HDFS path {} has no namenode host, please set `webhdfs_address` in the HDFSConfig",
+        path
+    ))]
+    MissingNamenode { path: String },
+
+    #[snafu(display("Namenode did not redirect the creation of {} to a datanode", path))]
+    MissingDatanodeRedirect { path: String },
+
+    #[snafu(display("Not a File: \"{}\"", path))]
+    NotAFile { path: String },
+}
+
+impl From<Error> for super::Error {
+    fn from(error: Error) -> Self {
+        use Error::{NotAFile, UnableToList, UnableToOpenFile, UnableToWrite};
+        match error {
+            UnableToOpenFile { path, source }
+            | UnableToList { path, source }
+            | UnableToWrite { path, source } => match source.status().map(|v| v.as_u16()) {
+                Some(404) => Self::NotFound {
+                    path,
+                    source: source.into(),
+                },
+                Some(429) => Self::Throttled {
+                    path,
+                    source: source.into(),
+                },
+                Some(408 | 500 | 502 | 503 | 504) => Self::MiscTransient {
+                    path,
+                    source: source.into(),
+                },
+                None if source.is_timeout() => Self::ReadTimeout {
+                    path,
+                    source: source.into(),
+                },
+                None if source.is_connect() => Self::SocketError {
+                    path,
+                    source: source.into(),
+                },
+                None | Some(_) => Self::UnableToOpenFile {
+                    path,
+                    source: source.into(),
+                },
+            },
+            NotAFile { path } => Self::NotAFile { path },
+            _ => Self::Generic {
+                store: super::SourceType::HDFS,
+                source: error.into(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FileStatus {
+    length: u64,
+    modification_time: u64,
+    /// Name of the file relative to the listed directory, which is empty when listing a file.
+    path_suffix: String,
+    #[serde(rename = "type")]
+    file_type: String,
+}
+
+impl FileStatus {
+    fn is_dir(&self) -> bool {
+        self.file_type == "DIRECTORY"
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct FileStatusResponse {
+    #[serde(rename = "FileStatus")]
+    file_status: FileStatus,
+}
+
+#[derive(Debug, Deserialize)]
+struct FileStatuses {
+    #[serde(rename = "FileStatus")]
+    file_status: Vec<FileStatus>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListStatusResponse {
+    #[serde(rename = "FileStatuses")]
+    file_statuses: FileStatuses,
+}
+
+/// Builds the URL of the WebHDFS REST API that performs `op` on the file at `uri`.
+///
+/// `hdfs://` paths name the namenode by its RPC port, so their requests go to the configured WebHDFS port of the
+/// same host, while `webhdfs://` and `swebhdfs://` paths name the WebHDFS endpoint itself.
+fn webhdfs_url(config: &HDFSConfig, uri: &str, op: &str) -> super::Result<Url> {
+    let parsed = Url::parse(uri).with_context(|_| InvalidUrlSnafu { path: uri })?;
+    let mut url = match &config.webhdfs_address {
+        Some(address) => Url::parse(address).with_context(|_| InvalidUrlSnafu { path: address })?,
+        None => {
+            let host = parsed
+                .host_str()
+                .ok_or_else(|| Error::MissingNamenode { path: uri.into() })?;
+            let (scheme, port) = match parsed.scheme() {
+                "webhdfs" => (
+                    if config.use_https { "https" } else { "http" },
+                    parsed.port().unwrap_or(config.webhdfs_port),
+                ),
+                "swebhdfs" => ("https", parsed.port().unwrap_or(config.webhdfs_port)),
+                _ => (
+                    if config.use_https { "https" } else { "http" },
+                    config.webhdfs_port,
+                ),
+            };
+            let address = format!("{scheme}://{host}:{port}");
+            Url::parse(&address).with_context(|_| InvalidUrlSnafu { path: address })?
+        }
+    };
+    url.set_path(&format!("{WEBHDFS_PATH_PREFIX}{}", parsed.path()));
+    {
+        let mut query = url.query_pairs_mut();
+        query.append_pair("op", op);
+        if let Some(user) = &config.user {
+            query.append_pair("user.name", user);
+        }
+        if let Some(token) = &config.delegation_token {
+            query.append_pair("delegation", token.as_string());
+        }
+    }
+    Ok(url)
+}
+
+pub struct HDFSSource {
+    client: reqwest::Client,
+    /// Client that doesn't follow redirects, since files are created by sending their data to the datanode that the
+    /// namenode redirects to, rather than to the namenode itself.
+    create_client: reqwest::Client,
+    config: HDFSConfig,
+}
+
+impl HDFSSource {
+    pub async fn get_client(config: &HDFSConfig) -> super::Result<Arc<Self>> {
+        Ok(Self {
+            client: reqwest::ClientBuilder::default()
+                .pool_max_idle_per_host(70)
+                .build()
+                .context(UnableToCreateClientSnafu)?,
+            create_client: reqwest::ClientBuilder::default()
+                .redirect(reqwest::redirect::Policy::none())
+                .build()
+                .context(UnableToCreateClientSnafu)?,
+            config: config.clone(),
+        }
+        .into())
+    }
+
+    async fn list_status(
+        &self,
+        uri: &str,
+        io_stats: Option<&IOStatsRef>,
+    ) -> super::Result<Vec<FileStatus>> {
+        let url = webhdfs_url(&self.config, uri, "LISTSTATUS")?;
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .context(UnableToConnectSnafu::<String> { path: uri.into() })?
+            .error_for_status()
+            .context(UnableToListSnafu::<String> { path: uri.into() })?;
+        if let Some(is) = io_stats {
+            is.mark_list_requests(1);
+        }
+        let response = response
+            .json::<ListStatusResponse>()
+            .await
+            .context(UnableToParseResponseSnafu::<String> { path: uri.into() })?;
+        Ok(response.file_statuses.file_status)
+    }
+
+    async fn file_status(
+        &self,
+        uri: &str,
+        io_stats: Option<&IOStatsRef>,
+    ) -> super::Result<FileStatus> {
+        let url = webhdfs_url(&self.config, uri, "GETFILESTATUS")?;
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .context(UnableToConnectSnafu::<String> { path: uri.into() })?
+            .error_for_status()
+            .context(UnableToOpenFileSnafu::<String> { path: uri.into() })?;
+        if let Some(is) = io_stats {
+            is.mark_head_requests(1);
+        }
+        let status = response
+            .json::<FileStatusResponse>()
+            .await
+            .context(UnableToParseResponseSnafu::<String> { path: uri.into() })?
+            .file_status;
+        if status.is_dir() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+        Ok(status)
+    }
+}
+
+#[async_trait]
+impl ObjectSource for HDFSSource {
+    async fn get(
+        &self,
+        uri: &str,
+        range: Option<Range<usize>>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<GetResult> {
+        let mut url = webhdfs_url(&self.config, uri, "OPEN")?;
+        if let Some(range) = &range {
+            url.query_pairs_mut()
+                .append_pair("offset", &range.start.to_string())
+                .append_pair("length", &range.len().to_string());
+        }
+
+        // The namenode redirects reads to a datanode that holds the data
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .context(UnableToConnectSnafu::<String> { path: uri.into() })?
+            .error_for_status()
+            .context(UnableToOpenFileSnafu::<String> { path: uri.into() })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_get_requests(1);
+        }
+        let size_bytes = response.content_length().map(|s| s as usize);
+        let owned_string = uri.to_owned();
+        let stream = response.bytes_stream().map_err(move |e| {
+            UnableToReadBytesSnafu::<String> {
+                path: owned_string.clone(),
+            }
+            .into_error(e)
+            .into()
+        });
+        Ok(GetResult::Stream(
+            io_stats_on_bytestream(stream, io_stats),
+            size_bytes,
+            None,
+            None,
+        ))
+    }
+
+    async fn put(
+        &self,
+        uri: &str,
+        data: bytes::Bytes,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let mut url = webhdfs_url(&self.config, uri, "CREATE")?;
+        url.query_pairs_mut().append_pair("overwrite", "true");
+
+        // Creating a file takes two requests: the namenode redirects the first to the datanode that the data of the
+        // file is then sent to
+        let response = self
+            .create_client
+            .put(url)
+            .send()
+            .await
+            .context(UnableToConnectSnafu::<String> { path: uri.into() })?
+            .error_for_status()
+            .context(UnableToWriteSnafu::<String> { path: uri.into() })?;
+        let location = response
+            .headers()
+            .get(LOCATION)
+            .and_then(|v| v.to_str().ok())
+            .ok_or_else(|| Error::MissingDatanodeRedirect { path: uri.into() })?
+            .to_string();
+
+        let num_bytes = data.len();
+        self.create_client
+            .put(location)
+            .header(reqwest::header::CONTENT_TYPE, "application/octet-stream")
+            .body(data)
+            .send()
+            .await
+            .context(UnableToConnectSnafu::<String> { path: uri.into() })?
+            .error_for_status()
+            .context(UnableToWriteSnafu::<String> { path: uri.into() })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_uploaded(num_bytes);
+        }
+        Ok(())
+    }
+
+    async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+        let status = self.file_status(uri, io_stats.as_ref()).await?;
+        Ok(status.length as usize)
+    }
+
+    async fn get_version(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<String>> {
+        // HDFS files can only be replaced or appended to, either of which changes when they were last modified
+        let status = self.file_status(uri, io_stats.as_ref()).await?;
+        Ok(Some(format!(
+            "{}-{}",
+            status.modification_time, status.length
+        )))
+    }
+
+    async fn glob(
+        self: Arc<Self>,
+        glob_path: &str,
+        _fanout_limit: Option<usize>,
+        _page_size: Option<i32>,
+        limit: Option<usize>,
+        io_stats: Option<IOStatsRef>,
+        _file_format: Option<FileFormat>,
+        options: &GlobOptions,
+    ) -> super::Result<BoxStream<'static, super::Result<FileMetadata>>> {
+        use crate::object_store_glob::glob;
+
+        // Ensure fanout_limit is None because HDFS ObjectSource does not support prefix listing
+        let fanout_limit = None;
+        let page_size = None;
+
+        glob(
+            self,
+            glob_path,
+            fanout_limit,
+            page_size,
+            limit,
+            io_stats,
+            options,
+        )
+        .await
+    }
+
+    async fn ls(
+        &self,
+        path: &str,
+        posix: bool,
+        _continuation_token: Option<&str>,
+        _page_size: Option<i32>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<LSResult> {
+        if !posix {
+            unimplemented!("Prefix-listing is not implemented for HDFS listing");
+        }
+
+        let statuses = self.list_status(path, io_stats.as_ref()).await?;
+        let dir_path = path.trim_end_matches(HDFS_DELIMITER);
+        let files = statuses
+            .into_iter()
+            .map(|status| {
+                // Listing a file returns just that file, with an empty path suffix
+                let filepath = if status.path_suffix.is_empty() {
+                    path.to_string()
+                } else if status.is_dir() {
+                    format!(
+                        "{dir_path}{HDFS_DELIMITER}{}{HDFS_DELIMITER}",
+                        status.path_suffix
+                    )
+                } else {
+                    format!("{dir_path}{HDFS_DELIMITER}{}", status.path_suffix)
+                };
+                let (filetype, size) = if status.is_dir() {
+                    (FileType::Directory, None)
+                } else {
+                    (FileType::File, Some(status.length))
+                };
+                FileMetadata {
+                    filepath,
+                    size,
+                    filetype,
+                    last_modified: OffsetDateTime::from_unix_timestamp_nanos(
+                        i128::from(status.modification_time) * 1_000_000,
+                    )
+                    .ok(),
+                    etag: None,
+                    version_id: None,
+                    storage_class: None,
+                }
+            })
+            .collect();
+        Ok(LSResult {
+            files,
+            continuation_token: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_io_config::HDFSConfig;
+
+    use super::webhdfs_url;
+    use crate::Result;
+
+    #[test]
+    fn test_webhdfs_url_from_hdfs_path() -> Result<()> {
+        let config = HDFSConfig {
+            user: Some("etl".to_string()),
+            ..Default::default()
+        };
+        let url = webhdfs_url(&config, "hdfs://namenode:8020/data/file.parquet", "OPEN")?;
+        assert_eq!(
+            url.as_str(),
+            "http://namenode:9870/webhdfs/v1/data/file.parquet?op=OPEN&user.name=etl"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_webhdfs_url_from_webhdfs_paths() -> Result<()> {
+        let config = HDFSConfig {
+            delegation_token: Some("token".to_string().into()),
+            ..Default::default()
+        };
+        let url = webhdfs_url(&config, "webhdfs://namenode:50070/data", "LISTSTATUS")?;
+        assert_eq!(
+            url.as_str(),
+            "http://namenode:50070/webhdfs/v1/data?op=LISTSTATUS&delegation=token"
+        );
+        let url = webhdfs_url(&config, "swebhdfs://namenode/data", "LISTSTATUS")?;
+        assert_eq!(
+            url.as_str(),
+            "https://namenode:9870/webhdfs/v1/data?op=LISTSTATUS&delegation=token"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_webhdfs_url_with_address() -> Result<()> {
+        let config = HDFSConfig {
+            webhdfs_address: Some("https://gateway:8443".to_string()),
+            ..Default::default()
+        };
+        let url = webhdfs_url(&config, "hdfs://nameservice/data", "GETFILESTATUS")?;
+        assert_eq!(
+            url.as_str(),
+            "https://gateway:8443/webhdfs/v1/data?op=GETFILESTATUS"
+        );
+
+        assert!(webhdfs_url(&HDFSConfig::default(), "hdfs:///data", "OPEN").is_err());
+        Ok(())
+    }
+}
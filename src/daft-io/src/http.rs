@@ -6,17 +6,24 @@ use std::{
 };
 
 use async_trait::async_trait;
+use bytes::Bytes;
 use common_io_config::HTTPConfig;
-use futures::{stream::BoxStream, TryStreamExt};
+use futures::{stream::BoxStream, StreamExt, TryStreamExt};
 use hyper::header;
 use regex::Regex;
-use reqwest::header::{CONTENT_LENGTH, RANGE};
+use reqwest::{
+    header::{
+        ACCEPT_RANGES, AUTHORIZATION, CONTENT_LENGTH, CONTENT_RANGE, ETAG, LAST_MODIFIED, RANGE,
+    },
+    StatusCode,
+};
 use snafu::{IntoError, ResultExt, Snafu};
 use url::Position;
 
 use super::object_io::{GetResult, ObjectSource};
 use crate::{
     object_io::{FileMetadata, FileType, LSResult},
+    object_store_glob::GlobOptions,
     stats::IOStatsRef,
     stream_utils::io_stats_on_bytestream,
     FileFormat,
@@ -24,6 +31,12 @@ use crate::{
 
 const HTTP_DELIMITER: &str = "/";
 
+/// Files at least this large are downloaded as ranges in parallel, if the server supports ranged requests.
+const PARALLEL_DOWNLOAD_THRESHOLD: usize = 32 * 1024 * 1024;
+const PARALLEL_DOWNLOAD_CHUNK_SIZE: usize = 8 * 1024 * 1024;
+const PARALLEL_DOWNLOAD_CONCURRENCY: usize = 8;
+const MAX_REDIRECTS: usize = 10;
+
 static HTML_A_TAG_HREF_RE: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r#"<(a|A)\s+(?:[^>]*?\s+)?(href|HREF)=["'](?P<url>[^"']+)"#).unwrap()
 });
@@ -80,6 +93,62 @@ enum Error {
 
     #[snafu(display("Unable to create HTTP header: {source}"))]
     UnableToCreateHeader { source: header::InvalidHeaderValue },
+
+    #[snafu(display(
+        "Expected {expected} bytes for range {start}..{end} of {path} but received {received}"
+    ))]
+    UnexpectedRangeLength {
+        path: String,
+        start: usize,
+        end: usize,
+        expected: usize,
+        received: usize,
+    },
+}
+
+/// The size of a file, its version and whether the server that hosts it accepts ranged requests, as reported by a HEAD
+/// request.
+struct HeadResult {
+    size: Option<usize>,
+    /// The ETag of the file, or when it was last modified if the server doesn't report ETags.
+    version: Option<String>,
+    accepts_ranges: bool,
+}
+
+fn range_header(range: &Range<usize>) -> String {
+    format!("bytes={}-{}", range.start, range.end.saturating_sub(1))
+}
+
+/// Splits a file of `size` bytes into the ranges that are downloaded in parallel.
+fn parallel_download_ranges(size: usize) -> impl Iterator<Item = Range<usize>> {
+    (0..size)
+        .step_by(PARALLEL_DOWNLOAD_CHUNK_SIZE)
+        .map(move |start| start..(start + PARALLEL_DOWNLOAD_CHUNK_SIZE).min(size))
+}
+
+/// Trims a stream of the whole file down to `range`, for servers that ignore the Range header and respond with the
+/// whole file instead.
+fn trim_stream_to_range(
+    stream: BoxStream<'static, super::Result<Bytes>>,
+    range: Range<usize>,
+) -> BoxStream<'static, super::Result<Bytes>> {
+    stream
+        .scan(0, move |offset, bytes| {
+            // Stop reading once the end of the range has been reached
+            if *offset >= range.end {
+                return futures::future::ready(None);
+            }
+            let bytes = bytes.map(|bytes| {
+                let start = *offset;
+                *offset += bytes.len();
+                let trim_start = range.start.saturating_sub(start).min(bytes.len());
+                let trim_end = range.end.saturating_sub(start).min(bytes.len());
+                (trim_start < trim_end).then(|| bytes.slice(trim_start..trim_end))
+            });
+            futures::future::ready(Some(bytes))
+        })
+        .try_filter_map(|bytes| futures::future::ready(Ok(bytes)))
+        .boxed()
 }
 
 /// Finds and retrieves FileMetadata from HTML text
@@ -133,6 +202,10 @@ fn get_file_metadata_from_html(path: &str, text: &str) -> super::Result<Vec<File
                 // for populating `size` if necessary
                 size: None,
                 filetype,
+                last_modified: None,
+                etag: None,
+                version_id: None,
+                storage_class: None,
             }))
         })
         .collect::<super::Result<Vec<_>>>()?;
@@ -144,6 +217,132 @@ pub struct HttpSource {
     pub(crate) client: reqwest::Client,
 }
 
+impl HttpSource {
+    async fn head(&self, uri: &str, io_stats: Option<&IOStatsRef>) -> super::Result<HeadResult> {
+        let response = self
+            .client
+            .head(uri)
+            .send()
+            .await
+            .context(UnableToConnectSnafu::<String> { path: uri.into() })?
+            .error_for_status()
+            .context(UnableToOpenFileSnafu::<String> { path: uri.into() })?;
+        if let Some(is) = io_stats {
+            is.mark_head_requests(1);
+        }
+
+        let headers = response.headers();
+        let size = match headers.get(CONTENT_LENGTH) {
+            Some(v) => {
+                let size_bytes = String::from_utf8(v.as_bytes().to_vec()).with_context(|_| {
+                    UnableToParseUtf8HeaderSnafu::<String> { path: uri.into() }
+                })?;
+                Some(
+                    size_bytes
+                        .parse()
+                        .with_context(|_| UnableToParseIntegerSnafu::<String> {
+                            path: uri.into(),
+                        })?,
+                )
+            }
+            None => None,
+        };
+        let version = headers
+            .get(ETAG)
+            .or_else(|| headers.get(LAST_MODIFIED))
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let accepts_ranges = headers
+            .get(ACCEPT_RANGES)
+            .is_some_and(|v| v.to_str().is_ok_and(|v| v.eq_ignore_ascii_case("bytes")));
+        Ok(HeadResult {
+            size,
+            version,
+            accepts_ranges,
+        })
+    }
+
+    /// Determines the size of a file from the Content-Range of a request for its first byte, for servers that don't
+    /// report the size of the file in response to HEAD requests.
+    async fn get_size_from_range(
+        &self,
+        uri: &str,
+        io_stats: Option<&IOStatsRef>,
+    ) -> super::Result<usize> {
+        let response = self
+            .client
+            .get(uri)
+            .header(RANGE, range_header(&(0..1)))
+            .send()
+            .await
+            .context(UnableToConnectSnafu::<String> { path: uri.into() })?
+            .error_for_status()
+            .context(UnableToOpenFileSnafu::<String> { path: uri.into() })?;
+        if let Some(is) = io_stats {
+            is.mark_get_requests(1);
+        }
+
+        // The Content-Range of a partial response looks like `bytes 0-0/1234`, where the size may be `*` if unknown
+        response
+            .headers()
+            .get(CONTENT_RANGE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.rsplit_once('/'))
+            .and_then(|(_, size)| size.parse().ok())
+            .ok_or_else(|| Error::UnableToDetermineSize { path: uri.into() }.into())
+    }
+
+    async fn get_range_bytes(
+        client: reqwest::Client,
+        uri: String,
+        range: Range<usize>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Bytes> {
+        let response = client
+            .get(&uri)
+            .header(RANGE, range_header(&range))
+            .send()
+            .await
+            .with_context(|_| UnableToConnectSnafu { path: uri.clone() })?
+            .error_for_status()
+            .with_context(|_| UnableToOpenFileSnafu { path: uri.clone() })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_get_requests(1);
+        }
+        let bytes = response
+            .bytes()
+            .await
+            .with_context(|_| UnableToReadBytesSnafu { path: uri.clone() })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_bytes_read(bytes.len());
+        }
+
+        if bytes.len() != range.len() {
+            return Err(Error::UnexpectedRangeLength {
+                path: uri,
+                start: range.start,
+                end: range.end,
+                expected: range.len(),
+                received: bytes.len(),
+            }
+            .into());
+        }
+        Ok(bytes)
+    }
+
+    /// Downloads a file as ranges in parallel, which are streamed in order.
+    fn get_parallel(&self, uri: &str, size: usize, io_stats: Option<IOStatsRef>) -> GetResult {
+        let client = self.client.clone();
+        let uri = uri.to_string();
+        let stream = futures::stream::iter(parallel_download_ranges(size))
+            .map(move |range| {
+                Self::get_range_bytes(client.clone(), uri.clone(), range, io_stats.clone())
+            })
+            .buffered(PARALLEL_DOWNLOAD_CONCURRENCY);
+        GetResult::Stream(stream.boxed(), Some(size), None, None)
+    }
+}
+
 impl From<Error> for super::Error {
     fn from(error: Error) -> Self {
         use Error::{UnableToDetermineSize, UnableToOpenFile};
@@ -153,6 +352,22 @@ impl From<Error> for super::Error {
                     path,
                     source: source.into(),
                 },
+                Some(429) => Self::Throttled {
+                    path,
+                    source: source.into(),
+                },
+                Some(408 | 500 | 502 | 503 | 504) => Self::MiscTransient {
+                    path,
+                    source: source.into(),
+                },
+                None if source.is_timeout() => Self::ReadTimeout {
+                    path,
+                    source: source.into(),
+                },
+                None if source.is_connect() => Self::SocketError {
+                    path,
+                    source: source.into(),
+                },
                 None | Some(_) => Self::UnableToOpenFile {
                     path,
                     source: source.into(),
@@ -176,10 +391,19 @@ impl HttpSource {
                 .context(UnableToCreateHeaderSnafu)?,
         );
 
+        if let Some(token) = &config.bearer_token {
+            let mut value = header::HeaderValue::from_str(&format!("Bearer {}", token.as_string()))
+                .context(UnableToCreateHeaderSnafu)?;
+            value.set_sensitive(true);
+            default_headers.append(AUTHORIZATION, value);
+        }
+
         Ok(Self {
+            // reqwest drops the Authorization header when following a redirect to a different host
             client: reqwest::ClientBuilder::default()
                 .pool_max_idle_per_host(70)
                 .default_headers(default_headers)
+                .redirect(reqwest::redirect::Policy::limited(MAX_REDIRECTS))
                 .build()
                 .context(UnableToCreateClientSnafu)?,
         }
@@ -195,13 +419,20 @@ impl ObjectSource for HttpSource {
         range: Option<Range<usize>>,
         io_stats: Option<IOStatsRef>,
     ) -> super::Result<GetResult> {
+        if range.is_none() {
+            let head = self.head(uri, io_stats.as_ref()).await?;
+            match head.size {
+                Some(size) if head.accepts_ranges && size >= PARALLEL_DOWNLOAD_THRESHOLD => {
+                    return Ok(self.get_parallel(uri, size, io_stats));
+                }
+                _ => {}
+            }
+        }
+
         let request = self.client.get(uri);
-        let request = match range {
+        let request = match &range {
             None => request,
-            Some(range) => request.header(
-                RANGE,
-                format!("bytes={}-{}", range.start, range.end.saturating_sub(1)),
-            ),
+            Some(range) => request.header(RANGE, range_header(range)),
         };
 
         let response = request
@@ -214,16 +445,27 @@ impl ObjectSource for HttpSource {
         if let Some(is) = io_stats.as_ref() {
             is.mark_get_requests(1);
         }
+        let status = response.status();
         let size_bytes = response.content_length().map(|s| s as usize);
         let stream = response.bytes_stream();
         let owned_string = uri.to_owned();
-        let stream = stream.map_err(move |e| {
-            UnableToReadBytesSnafu::<String> {
-                path: owned_string.clone(),
+        let stream = stream
+            .map_err(move |e| {
+                UnableToReadBytesSnafu::<String> {
+                    path: owned_string.clone(),
+                }
+                .into_error(e)
+                .into()
+            })
+            .boxed();
+        let (stream, size_bytes) = match range {
+            Some(range) if status != StatusCode::PARTIAL_CONTENT => {
+                let size_bytes =
+                    size_bytes.map(|size| range.end.min(size).saturating_sub(range.start));
+                (trim_stream_to_range(stream, range), size_bytes)
             }
-            .into_error(e)
-            .into()
-        });
+            _ => (stream, size_bytes),
+        };
         Ok(GetResult::Stream(
             io_stats_on_bytestream(stream, io_stats),
             size_bytes,
@@ -242,32 +484,18 @@ impl ObjectSource for HttpSource {
     }
 
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
-        let request = self.client.head(uri);
-        let response = request
-            .send()
-            .await
-            .context(UnableToConnectSnafu::<String> { path: uri.into() })?;
-        let response = response
-            .error_for_status()
-            .context(UnableToOpenFileSnafu::<String> { path: uri.into() })?;
-
-        if let Some(is) = io_stats.as_ref() {
-            is.mark_head_requests(1);
+        match self.head(uri, io_stats.as_ref()).await?.size {
+            Some(size) => Ok(size),
+            None => self.get_size_from_range(uri, io_stats.as_ref()).await,
         }
+    }
 
-        let headers = response.headers();
-        match headers.get(CONTENT_LENGTH) {
-            Some(v) => {
-                let size_bytes = String::from_utf8(v.as_bytes().to_vec()).with_context(|_| {
-                    UnableToParseUtf8HeaderSnafu::<String> { path: uri.into() }
-                })?;
-
-                Ok(size_bytes
-                    .parse()
-                    .with_context(|_| UnableToParseIntegerSnafu::<String> { path: uri.into() })?)
-            }
-            None => Err(Error::UnableToDetermineSize { path: uri.into() }.into()),
-        }
+    async fn get_version(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<String>> {
+        Ok(self.head(uri, io_stats.as_ref()).await?.version)
     }
 
     async fn glob(
@@ -278,6 +506,7 @@ impl ObjectSource for HttpSource {
         limit: Option<usize>,
         io_stats: Option<IOStatsRef>,
         _file_format: Option<FileFormat>,
+        options: &GlobOptions,
     ) -> super::Result<BoxStream<'static, super::Result<FileMetadata>>> {
         use crate::object_store_glob::glob;
 
@@ -285,7 +514,16 @@ impl ObjectSource for HttpSource {
         let fanout_limit = None;
         let page_size = None;
 
-        glob(self, glob_path, fanout_limit, page_size, limit, io_stats).await
+        glob(
+            self,
+            glob_path,
+            fanout_limit,
+            page_size,
+            limit,
+            io_stats,
+            options,
+        )
+        .await
     }
 
     async fn ls(
@@ -341,6 +579,10 @@ impl ObjectSource for HttpSource {
                     filepath: path.to_string(),
                     filetype: FileType::File,
                     size: response.content_length(),
+                    last_modified: None,
+                    etag: None,
+                    version_id: None,
+                    storage_class: None,
                 }],
                 continuation_token: None,
             }),
@@ -353,8 +595,39 @@ mod tests {
 
     use std::default;
 
+    use bytes::Bytes;
+    use futures::{StreamExt, TryStreamExt};
+
+    use super::{parallel_download_ranges, trim_stream_to_range, PARALLEL_DOWNLOAD_CHUNK_SIZE};
     use crate::{object_io::ObjectSource, HttpSource, Result};
 
+    #[test]
+    fn test_parallel_download_ranges_cover_file() {
+        let size = 2 * PARALLEL_DOWNLOAD_CHUNK_SIZE + 10;
+        let ranges = parallel_download_ranges(size).collect::<Vec<_>>();
+        assert_eq!(
+            ranges,
+            vec![
+                0..PARALLEL_DOWNLOAD_CHUNK_SIZE,
+                PARALLEL_DOWNLOAD_CHUNK_SIZE..2 * PARALLEL_DOWNLOAD_CHUNK_SIZE,
+                2 * PARALLEL_DOWNLOAD_CHUNK_SIZE..size,
+            ]
+        );
+        assert_eq!(parallel_download_ranges(0).count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_trim_stream_to_range() -> Result<()> {
+        let chunks = ["hello", " ", "world", "!"].map(|c| Ok(Bytes::from_static(c.as_bytes())));
+        let stream = futures::stream::iter(chunks).boxed();
+        let trimmed = trim_stream_to_range(stream, 3..9)
+            .try_collect::<Vec<_>>()
+            .await?
+            .concat();
+        assert_eq!(trimmed, b"lo wor");
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_full_get_from_http() -> Result<()> {
         let parquet_file_path = "https://daft-public-data.s3.us-west-2.amazonaws.com/test_fixtures/parquet_small/0dad4c3f-da0d-49db-90d8-98684571391b-0.parquet";
@@ -21,6 +21,7 @@ use super::object_io::{GetResult, ObjectSource};
 use crate::{
     http::HttpSource,
     object_io::{FileMetadata, FileType, LSResult},
+    object_store_glob::GlobOptions,
     stats::IOStatsRef,
     stream_utils::io_stats_on_bytestream,
     FileFormat,
@@ -381,6 +382,7 @@ impl ObjectSource for HFSource {
         limit: Option<usize>,
         io_stats: Option<IOStatsRef>,
         file_format: Option<FileFormat>,
+        options: &GlobOptions,
     ) -> super::Result<BoxStream<'static, super::Result<FileMetadata>>> {
         use crate::object_store_glob::glob;
 
@@ -406,7 +408,7 @@ impl ObjectSource for HFSource {
             }
         }
 
-        glob(self, glob_path, None, None, limit, io_stats).await
+        glob(self, glob_path, None, None, limit, io_stats, options).await
     }
 
     async fn ls(
@@ -478,6 +480,10 @@ impl ObjectSource for HFSource {
                     filepath,
                     size,
                     filetype,
+                    last_modified: None,
+                    etag: None,
+                    version_id: None,
+                    storage_class: None,
                 }
             })
             .collect();
@@ -548,6 +554,10 @@ async fn try_parquet_api(
                     filepath: uri,
                     size: None,
                     filetype: FileType::File,
+                    last_modified: None,
+                    etag: None,
+                    version_id: None,
+                    storage_class: None,
                 })
             });
 
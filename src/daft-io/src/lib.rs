@@ -3,14 +3,20 @@
 #![feature(if_let_guard)]
 mod azure_blob;
 mod counting_reader;
+mod disk_cache;
 mod google_cloud;
+mod hdfs;
 mod http;
 mod huggingface;
 mod local;
 mod object_io;
 mod object_store_glob;
+mod rate_limit;
 mod retry;
+mod s3_express;
 mod s3_like;
+mod sftp;
+mod sink;
 mod stats;
 mod stream_utils;
 
@@ -19,24 +25,37 @@ use std::sync::LazyLock;
 use azure_blob::AzureBlobSource;
 use common_file_formats::FileFormat;
 pub use counting_reader::CountingReader;
+use disk_cache::DiskCache;
 use google_cloud::GCSSource;
+use hdfs::HDFSSource;
 use huggingface::HFSource;
 #[cfg(feature = "python")]
 pub mod python;
 
-use std::{borrow::Cow, collections::HashMap, hash::Hash, ops::Range, sync::Arc};
+use std::{borrow::Cow, collections::HashMap, hash::Hash, ops::Range, sync::Arc, time::Instant};
 
 use common_error::{DaftError, DaftResult};
-pub use common_io_config::{AzureConfig, GCSConfig, HTTPConfig, IOConfig, S3Config};
-use futures::stream::BoxStream;
+pub use common_io_config::{
+    AzureConfig, DiskCacheConfig, GCSConfig, HDFSConfig, HTTPConfig, IOConfig, RateLimitConfig,
+    RetryConfig, S3Config, SFTPConfig,
+};
+use futures::{stream::BoxStream, StreamExt};
 use object_io::StreamingRetryParams;
-pub use object_io::{FileMetadata, GetResult};
+pub use object_io::{FileMetadata, GetResult, MultipartUpload};
+pub use object_store_glob::GlobOptions;
 #[cfg(feature = "python")]
 pub use python::register_modules;
+use rate_limit::{acquire, RequestLimiter};
+pub use retry::ErrorClass;
+use retry::RetryPolicy;
 pub use s3_like::s3_config_from_env;
 use s3_like::S3LikeSource;
+use sftp::SFTPSource;
+pub use sink::{AsyncObjectSink, ObjectSink};
 use snafu::{prelude::*, Snafu};
-pub use stats::{IOStatsContext, IOStatsRef};
+pub use stats::{
+    IOStatsContext, IOStatsRef, IOStatsSnapshot, QueryIOStats, TIME_TO_FIRST_BYTE_BUCKETS_MS,
+};
 use url::ParseError;
 
 use self::{http::HttpSource, local::LocalSource, object_io::ObjectSource};
@@ -187,12 +206,25 @@ type Result<T, E = Error> = std::result::Result<T, E>;
 pub struct IOClient {
     source_type_to_store: tokio::sync::RwLock<HashMap<SourceType, Arc<dyn ObjectSource>>>,
     config: Arc<IOConfig>,
+    retry_policy: Arc<RetryPolicy>,
+    disk_cache: Option<Arc<DiskCache>>,
 }
 
 impl IOClient {
     pub fn new(config: Arc<IOConfig>) -> Result<Self> {
+        let disk_cache = if config.disk_cache.enabled {
+            Some(
+                DiskCache::open(&config.disk_cache).with_context(|_| UnableToCreateDirSnafu {
+                    path: format!("{:?}", config.disk_cache.directory),
+                })?,
+            )
+        } else {
+            None
+        };
         Ok(Self {
             source_type_to_store: tokio::sync::RwLock::new(HashMap::new()),
+            retry_policy: Arc::new(RetryPolicy::new(config.retry.clone())),
+            disk_cache,
             config,
         })
     }
@@ -230,6 +262,12 @@ impl IOClient {
             SourceType::HF => {
                 HFSource::get_client(&self.config.http).await? as Arc<dyn ObjectSource>
             }
+            SourceType::HDFS => {
+                HDFSSource::get_client(&self.config.hdfs).await? as Arc<dyn ObjectSource>
+            }
+            SourceType::SFTP => {
+                SFTPSource::get_client(&self.config.sftp).await? as Arc<dyn ObjectSource>
+            }
         };
 
         if w_handle.get(&source_type).is_none() {
@@ -238,6 +276,7 @@ impl IOClient {
         Ok(new_source)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn glob(
         &self,
         input: String,
@@ -246,7 +285,9 @@ impl IOClient {
         limit: Option<usize>,
         io_stats: Option<Arc<IOStatsContext>>,
         file_format: Option<FileFormat>,
+        options: &GlobOptions,
     ) -> Result<BoxStream<'static, Result<FileMetadata>>> {
+        let (source_type, _) = parse_url(&input)?;
         let source = self.get_source(&input).await?;
         let files = source
             .glob(
@@ -254,8 +295,9 @@ impl IOClient {
                 fanout_limit,
                 page_size,
                 limit,
-                io_stats,
+                io_stats.map(|io_stats| io_stats.store(source_type)),
                 file_format,
+                options,
             )
             .await?;
         Ok(files)
@@ -267,12 +309,89 @@ impl IOClient {
         range: Option<Range<usize>>,
         io_stats: Option<IOStatsRef>,
     ) -> Result<GetResult> {
-        let (_, path) = parse_url(&input)?;
+        let (source_type, path) = parse_url(&input)?;
         let source = self.get_source(&input).await?;
-        let get_result = source
-            .get(path.as_ref(), range.clone(), io_stats.clone())
+        let io_stats = io_stats.map(|io_stats| io_stats.store(source_type));
+        let limiter = RequestLimiter::new(
+            &self.config.rate_limit,
+            source_type,
+            &input,
+            io_stats.as_deref(),
+        );
+        if let Some(disk_cache) = &self.disk_cache
+            && source_type != SourceType::File
+        {
+            let version = self
+                .retry_policy
+                .retry(io_stats.as_deref(), || async {
+                    let _permit = acquire(limiter.as_ref()).await;
+                    source.get_version(path.as_ref(), io_stats.clone()).await
+                })
+                .await?;
+            if let Some(version) = version {
+                if let Some(file) = disk_cache.get(&input, &version, range.as_ref()) {
+                    return Ok(GetResult::File(file));
+                }
+                let bytes = self
+                    .get_from_source(
+                        source,
+                        path.as_ref(),
+                        &input,
+                        range.clone(),
+                        io_stats,
+                        limiter,
+                    )
+                    .await?
+                    .bytes()
+                    .await?;
+                if let Err(err) = disk_cache
+                    .insert(&input, &version, range.as_ref(), &bytes)
+                    .await
+                {
+                    log::warn!("Failed to cache data read from {input} on disk: {err}");
+                }
+                let size = bytes.len();
+                return Ok(GetResult::Stream(
+                    futures::stream::once(async { Ok(bytes) }).boxed(),
+                    Some(size),
+                    None,
+                    None,
+                ));
+            }
+        }
+        self.get_from_source(source, path.as_ref(), &input, range, io_stats, limiter)
+            .await
+    }
+
+    async fn get_from_source(
+        &self,
+        source: Arc<dyn ObjectSource>,
+        path: &str,
+        input: &str,
+        range: Option<Range<usize>>,
+        io_stats: Option<IOStatsRef>,
+        limiter: Option<RequestLimiter>,
+    ) -> Result<GetResult> {
+        let get_result = self
+            .retry_policy
+            .retry(io_stats.as_deref(), || async {
+                let permit = acquire(limiter.as_ref()).await;
+                let start = Instant::now();
+                let get_result = source.get(path, range.clone(), io_stats.clone()).await?;
+                if let Some(io_stats) = &io_stats {
+                    io_stats.mark_time_to_first_byte(start.elapsed());
+                }
+                Ok(get_result.with_request_permit(permit))
+            })
             .await?;
-        Ok(get_result.with_retry(StreamingRetryParams::new(source, input, range, io_stats)))
+        Ok(get_result.with_retry(StreamingRetryParams::new(
+            source,
+            input.to_string(),
+            range,
+            io_stats,
+            self.retry_policy.clone(),
+            limiter,
+        )))
     }
 
     pub async fn single_url_put(
@@ -281,9 +400,67 @@ impl IOClient {
         data: bytes::Bytes,
         io_stats: Option<IOStatsRef>,
     ) -> Result<()> {
-        let (_, path) = parse_url(dest)?;
+        let (source_type, path) = parse_url(dest)?;
         let source = self.get_source(dest).await?;
-        source.put(path.as_ref(), data, io_stats.clone()).await
+        let io_stats = io_stats.map(|io_stats| io_stats.store(source_type));
+        let limiter = RequestLimiter::new(
+            &self.config.rate_limit,
+            source_type,
+            dest,
+            io_stats.as_deref(),
+        );
+        self.retry_policy
+            .retry(io_stats.as_deref(), || async {
+                let _permit = acquire(limiter.as_ref()).await;
+                source
+                    .put(path.as_ref(), data.clone(), io_stats.clone())
+                    .await
+            })
+            .await
+    }
+
+    /// Uploads `data` to `dest` unless a file already exists there, returning whether it was uploaded, or None if its
+    /// store can't make uploads conditional.
+    pub async fn single_url_put_if_absent(
+        &self,
+        dest: &str,
+        data: bytes::Bytes,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<Option<bool>> {
+        let (source_type, path) = parse_url(dest)?;
+        let source = self.get_source(dest).await?;
+        let io_stats = io_stats.map(|io_stats| io_stats.store(source_type));
+        let limiter = RequestLimiter::new(
+            &self.config.rate_limit,
+            source_type,
+            dest,
+            io_stats.as_deref(),
+        );
+        self.retry_policy
+            .retry(io_stats.as_deref(), || async {
+                let _permit = acquire(limiter.as_ref()).await;
+                source
+                    .put_if_absent(path.as_ref(), data.clone(), io_stats.clone())
+                    .await
+            })
+            .await
+    }
+
+    /// Starts a multipart upload to `dest`, or returns None if its store doesn't support multipart uploads. The
+    /// requests of multipart uploads are not rate limited.
+    pub async fn create_multipart_upload(
+        &self,
+        dest: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<Option<Arc<dyn MultipartUpload>>> {
+        let (source_type, path) = parse_url(dest)?;
+        let source = self.get_source(dest).await?;
+        source
+            .create_multipart_upload(
+                path.as_ref(),
+                io_stats.map(|io_stats| io_stats.store(source_type)),
+            )
+            .await
     }
 
     pub async fn single_url_get_size(
@@ -291,9 +468,21 @@ impl IOClient {
         input: String,
         io_stats: Option<IOStatsRef>,
     ) -> Result<usize> {
-        let (_, path) = parse_url(&input)?;
+        let (source_type, path) = parse_url(&input)?;
         let source = self.get_source(&input).await?;
-        source.get_size(path.as_ref(), io_stats).await
+        let io_stats = io_stats.map(|io_stats| io_stats.store(source_type));
+        let limiter = RequestLimiter::new(
+            &self.config.rate_limit,
+            source_type,
+            &input,
+            io_stats.as_deref(),
+        );
+        self.retry_policy
+            .retry(io_stats.as_deref(), || async {
+                let _permit = acquire(limiter.as_ref()).await;
+                source.get_size(path.as_ref(), io_stats.clone()).await
+            })
+            .await
     }
 
     pub async fn single_url_download(
@@ -372,6 +561,8 @@ pub enum SourceType {
     AzureBlob,
     GCS,
     HF,
+    HDFS,
+    SFTP,
 }
 
 impl std::fmt::Display for SourceType {
@@ -383,6 +574,8 @@ impl std::fmt::Display for SourceType {
             Self::AzureBlob => write!(f, "AzureBlob"),
             Self::GCS => write!(f, "gcs"),
             Self::HF => write!(f, "hf"),
+            Self::HDFS => write!(f, "hdfs"),
+            Self::SFTP => write!(f, "sftp"),
         }
     }
 }
@@ -422,6 +615,8 @@ pub fn parse_url(input: &str) -> Result<(SourceType, Cow<'_, str>)> {
         "az" | "abfs" | "abfss" => Ok((SourceType::AzureBlob, fixed_input)),
         "gcs" | "gs" => Ok((SourceType::GCS, fixed_input)),
         "hf" => Ok((SourceType::HF, fixed_input)),
+        "hdfs" | "webhdfs" | "swebhdfs" => Ok((SourceType::HDFS, fixed_input)),
+        "sftp" => Ok((SourceType::SFTP, fixed_input)),
         #[cfg(target_env = "msvc")]
         _ if scheme.len() == 1 && ("a" <= scheme.as_str() && (scheme.as_str() <= "z")) => {
             Ok((SourceType::File, Cow::Owned(format!("file://{input}"))))
@@ -10,6 +10,7 @@ use bytes::Bytes;
 use common_error::DaftError;
 use futures::{stream::BoxStream, StreamExt, TryStreamExt};
 use snafu::{ResultExt, Snafu};
+use time::OffsetDateTime;
 use tokio::io::{AsyncReadExt, AsyncSeekExt};
 
 use super::{
@@ -18,6 +19,7 @@ use super::{
 };
 use crate::{
     object_io::{self, FileMetadata, LSResult},
+    object_store_glob::GlobOptions,
     stats::IOStatsRef,
     FileFormat,
 };
@@ -177,6 +179,40 @@ impl ObjectSource for LocalSource {
         }
     }
 
+    async fn put_if_absent(
+        &self,
+        uri: &str,
+        data: bytes::Bytes,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<bool>> {
+        const LOCAL_PROTOCOL: &str = "file://";
+        let Some(stripped_uri) = uri.strip_prefix(LOCAL_PROTOCOL) else {
+            return Err(Error::InvalidFilePath { path: uri.into() }.into());
+        };
+        // The data is written to a temporary file first, which is then linked to the destination, as linking fails
+        // if the destination exists, so that the file is never seen half-written.
+        let temp_path = format!("{stripped_uri}.{:016x}.tmp", rand::random::<u64>());
+        let mut file = std::fs::OpenOptions::new()
+            .create_new(true)
+            .write(true)
+            .open(&temp_path)
+            .with_context(|_| UnableToOpenFileForWritingSnafu { path: uri })?;
+        let linked = file
+            .write_all(&data)
+            .and_then(|()| file.sync_all())
+            .and_then(|()| std::fs::hard_link(&temp_path, stripped_uri));
+        let _ = std::fs::remove_file(&temp_path);
+        match linked {
+            Ok(()) => Ok(Some(true)),
+            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => Ok(Some(false)),
+            Err(err) => Err(Error::UnableToWriteToFile {
+                path: uri.into(),
+                source: err,
+            }
+            .into()),
+        }
+    }
+
     async fn get_size(&self, uri: &str, _io_stats: Option<IOStatsRef>) -> super::Result<usize> {
         const LOCAL_PROTOCOL: &str = "file://";
         let Some(uri) = uri.strip_prefix(LOCAL_PROTOCOL) else {
@@ -205,6 +241,7 @@ impl ObjectSource for LocalSource {
         limit: Option<usize>,
         io_stats: Option<IOStatsRef>,
         _file_format: Option<FileFormat>,
+        options: &GlobOptions,
     ) -> super::Result<BoxStream<'static, super::Result<FileMetadata>>> {
         use crate::object_store_glob::glob;
 
@@ -224,11 +261,21 @@ impl ObjectSource for LocalSource {
                 page_size,
                 limit,
                 io_stats,
+                options,
             )
             .await;
         }
 
-        glob(self, glob_path, fanout_limit, page_size, limit, io_stats).await
+        glob(
+            self,
+            glob_path,
+            fanout_limit,
+            page_size,
+            limit,
+            io_stats,
+            options,
+        )
+        .await
     }
 
     async fn ls(
@@ -283,6 +330,10 @@ impl ObjectSource for LocalSource {
                 filepath: format!("{LOCAL_PROTOCOL}{uri}"),
                 size: Some(meta.len()),
                 filetype: object_io::FileType::File,
+                last_modified: meta.modified().ok().map(OffsetDateTime::from),
+                etag: None,
+                version_id: None,
+                storage_class: None,
             })])
             .boxed());
         }
@@ -330,6 +381,10 @@ impl ObjectSource for LocalSource {
                             path: entry.path().to_string_lossy().to_string(),
                         }
                     })?,
+                    last_modified: meta.modified().ok().map(OffsetDateTime::from),
+                    etag: None,
+                    version_id: None,
+                    storage_class: None,
                 })
             }
         });
@@ -379,6 +434,8 @@ pub async fn collect_file(local_file: LocalFile) -> Result<Bytes> {
 mod tests {
     use std::{default, io::Write};
 
+    use time::OffsetDateTime;
+
     use crate::{
         object_io::{FileMetadata, FileType, ObjectSource},
         HttpSource, LocalSource, Result,
@@ -476,6 +533,16 @@ mod tests {
                 ),
                 size: Some(file1.as_file().metadata().unwrap().len()),
                 filetype: FileType::File,
+                last_modified: file1
+                    .as_file()
+                    .metadata()
+                    .unwrap()
+                    .modified()
+                    .ok()
+                    .map(OffsetDateTime::from),
+                etag: None,
+                version_id: None,
+                storage_class: None,
             },
             FileMetadata {
                 filepath: format!(
@@ -485,6 +552,16 @@ mod tests {
                 ),
                 size: Some(file2.as_file().metadata().unwrap().len()),
                 filetype: FileType::File,
+                last_modified: file2
+                    .as_file()
+                    .metadata()
+                    .unwrap()
+                    .modified()
+                    .ok()
+                    .map(OffsetDateTime::from),
+                etag: None,
+                version_id: None,
+                storage_class: None,
             },
             FileMetadata {
                 filepath: format!(
@@ -494,6 +571,16 @@ mod tests {
                 ),
                 size: Some(file3.as_file().metadata().unwrap().len()),
                 filetype: FileType::File,
+                last_modified: file3
+                    .as_file()
+                    .metadata()
+                    .unwrap()
+                    .modified()
+                    .ok()
+                    .map(OffsetDateTime::from),
+                etag: None,
+                version_id: None,
+                storage_class: None,
             },
         ];
         expected.sort_by(|a, b| a.filepath.cmp(&b.filepath));
@@ -502,4 +589,26 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_local_put_if_absent() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = format!("file://{}/00.json", dir.path().to_string_lossy());
+
+        let client = LocalSource::get_client().await?;
+        let put = client
+            .put_if_absent(&path, bytes::Bytes::from_static(b"first"), None)
+            .await?;
+        assert_eq!(put, Some(true));
+        let put = client
+            .put_if_absent(&path, bytes::Bytes::from_static(b"second"), None)
+            .await?;
+        assert_eq!(put, Some(false));
+
+        let contents = client.get(&path, None, None).await?.bytes().await?;
+        assert_eq!(contents.as_ref(), b"first");
+        // Only the file itself is left behind
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+        Ok(())
+    }
 }
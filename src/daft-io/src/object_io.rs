@@ -1,4 +1,7 @@
-use std::{ops::Range, sync::Arc, time::Duration};
+use std::{
+    ops::Range,
+    sync::{Arc, Mutex},
+};
 
 use async_trait::async_trait;
 use bytes::Bytes;
@@ -7,10 +10,14 @@ use futures::{
     stream::{BoxStream, Stream},
     StreamExt,
 };
+use time::OffsetDateTime;
 use tokio::sync::OwnedSemaphorePermit;
 
 use crate::{
     local::{collect_file, LocalFile},
+    object_store_glob::GlobOptions,
+    rate_limit::{acquire, FairPermit, RequestLimiter},
+    retry::RetryPolicy,
     stats::IOStatsRef,
     FileFormat,
 };
@@ -20,6 +27,8 @@ pub struct StreamingRetryParams {
     input: String,
     range: Option<Range<usize>>,
     io_stats: Option<IOStatsRef>,
+    retry_policy: Arc<RetryPolicy>,
+    limiter: Option<RequestLimiter>,
 }
 
 impl StreamingRetryParams {
@@ -28,12 +37,16 @@ impl StreamingRetryParams {
         input: String,
         range: Option<Range<usize>>,
         io_stats: Option<IOStatsRef>,
+        retry_policy: Arc<RetryPolicy>,
+        limiter: Option<RequestLimiter>,
     ) -> Self {
         Self {
             source,
             input,
             range,
             io_stats,
+            retry_policy,
+            limiter,
         }
     }
 }
@@ -78,51 +91,63 @@ where
 impl GetResult {
     pub async fn bytes(self) -> super::Result<Bytes> {
         use GetResult::{File, Stream};
-        let mut get_result = self;
-        match get_result {
+        match self {
             File(f) => collect_file(f).await,
             Stream(stream, size, permit, retry_params) => {
-                use rand::Rng;
-                const NUM_TRIES: u64 = 3;
-                const JITTER_MS: u64 = 2_500;
-                const MAX_BACKOFF_MS: u64 = 20_000;
-
-                let mut result = collect_bytes(stream, size, permit).await; // drop permit to ensure quota
-                for attempt in 1..NUM_TRIES {
-                    match result {
-                        Err(
-                            super::Error::SocketError { .. }
-                            | super::Error::UnableToReadBytes { .. },
-                        ) if let Some(rp) = &retry_params => {
-                            let jitter = rand::thread_rng()
-                                .gen_range(0..((1 << (attempt - 1)) * JITTER_MS))
-                                as u64;
-                            let jitter = jitter.min(MAX_BACKOFF_MS);
-
-                            log::warn!(
-                                "Received Socket Error when streaming bytes! Attempt {attempt} out of {NUM_TRIES} tries. Trying again in {jitter}ms\nDetails\n{}",
-                                result.err().unwrap()
-                            );
-                            tokio::time::sleep(Duration::from_millis(jitter)).await;
-
-                            get_result = rp
-                                .source
-                                .get(&rp.input, rp.range.clone(), rp.io_stats.clone())
-                                .await?;
-                            if let Self::Stream(stream, size, permit, _) = get_result {
-                                result = collect_bytes(stream, size, permit).await;
-                            } else {
-                                unreachable!("Retrying a stream should always be a stream");
-                            }
+                let Some(rp) = retry_params else {
+                    return collect_bytes(stream, size, permit).await; // drop permit to ensure quota
+                };
+                // The first attempt reads the stream that was already opened, and retries get the object again
+                let first_attempt = Mutex::new(Some((stream, size, permit)));
+                rp.retry_policy
+                    .retry(rp.io_stats.as_deref(), || {
+                        let first_attempt = first_attempt.lock().unwrap().take();
+                        let rp = &rp;
+                        async move {
+                            let (stream, size, permit) = match first_attempt {
+                                Some(first_attempt) => first_attempt,
+                                None => {
+                                    let request_permit = acquire(rp.limiter.as_ref()).await;
+                                    match rp
+                                        .source
+                                        .get(&rp.input, rp.range.clone(), rp.io_stats.clone())
+                                        .await?
+                                        .with_request_permit(request_permit)
+                                    {
+                                        Self::Stream(stream, size, permit, _) => {
+                                            (stream, size, permit)
+                                        }
+                                        Self::File(..) => unreachable!(
+                                            "Retrying a stream should always be a stream"
+                                        ),
+                                    }
+                                }
+                            };
+                            collect_bytes(stream, size, permit).await
                         }
-                        _ => break,
-                    }
-                }
-                result
+                    })
+                    .await
             }
         }
     }
 
+    /// Holds on to `permit` until the data of the result is read, so that the request counts as in flight until then.
+    #[must_use]
+    pub(crate) fn with_request_permit(self, permit: Option<FairPermit>) -> Self {
+        match (self, permit) {
+            (Self::Stream(s, size, semaphore_permit, retry_params), Some(permit)) => {
+                let s = s
+                    .map(move |bytes| {
+                        let _permit = &permit;
+                        bytes
+                    })
+                    .boxed();
+                Self::Stream(s, size, semaphore_permit, retry_params)
+            }
+            (get_result, _) => get_result,
+        }
+    }
+
     #[must_use]
     pub fn with_retry(self, params: StreamingRetryParams) -> Self {
         match self {
@@ -164,6 +189,11 @@ pub struct FileMetadata {
     pub filepath: String,
     pub size: Option<u64>,
     pub filetype: FileType,
+    /// The metadata below is only known for sources whose listings report it.
+    pub last_modified: Option<OffsetDateTime>,
+    pub etag: Option<String>,
+    pub version_id: Option<String>,
+    pub storage_class: Option<String>,
 }
 #[derive(Debug)]
 pub struct LSResult {
@@ -171,6 +201,23 @@ pub struct LSResult {
     pub continuation_token: Option<String>,
 }
 
+/// An upload of a file whose data is sent to the object store in parts, so that the whole file never has to be in
+/// memory at once.
+#[async_trait]
+pub trait MultipartUpload: Sync + Send {
+    /// Uploads part `part_number` of the file, counting from 1.
+    ///
+    /// Parts may be uploaded concurrently and in any order. All parts but the last must be at least as large as the
+    /// minimum part size of the object store.
+    async fn upload_part(&self, part_number: usize, data: Bytes) -> super::Result<()>;
+
+    /// Assembles the file from its uploaded parts, once all of them are done.
+    async fn complete(&self) -> super::Result<()>;
+
+    /// Cancels the upload and discards its uploaded parts.
+    async fn abort(&self) -> super::Result<()>;
+}
+
 use async_stream::stream;
 
 #[async_trait]
@@ -189,8 +236,42 @@ pub trait ObjectSource: Sync + Send {
         io_stats: Option<IOStatsRef>,
     ) -> super::Result<()>;
 
+    /// Starts a multipart upload to `uri`, or returns None if the source doesn't support multipart uploads, in which
+    /// case files must be uploaded whole with [`Self::put`].
+    async fn create_multipart_upload(
+        &self,
+        _uri: &str,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<Arc<dyn MultipartUpload>>> {
+        Ok(None)
+    }
+
+    /// Uploads `data` to `uri` unless an object already exists there, checking and uploading in one atomic step, and
+    /// returns whether it was uploaded. Returns None if the source can't make uploads conditional, in which case
+    /// nothing is uploaded.
+    async fn put_if_absent(
+        &self,
+        _uri: &str,
+        _data: bytes::Bytes,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<bool>> {
+        Ok(None)
+    }
+
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize>;
 
+    /// Returns an identifier of the current version of the object at `uri`, such as its ETag, which changes whenever
+    /// the object does. Returns None if the source can't tell versions of objects apart, in which case reads of the
+    /// object aren't cached.
+    async fn get_version(
+        &self,
+        _uri: &str,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<String>> {
+        Ok(None)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
@@ -199,6 +280,7 @@ pub trait ObjectSource: Sync + Send {
         limit: Option<usize>,
         io_stats: Option<IOStatsRef>,
         file_format: Option<FileFormat>,
+        options: &GlobOptions,
     ) -> super::Result<BoxStream<'static, super::Result<FileMetadata>>>;
 
     async fn ls(
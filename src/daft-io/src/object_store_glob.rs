@@ -6,7 +6,7 @@ use std::{
 
 use async_stream::stream;
 use futures::stream::{BoxStream, StreamExt};
-use globset::{GlobBuilder, GlobMatcher};
+use globset::{GlobBuilder, GlobMatcher, GlobSet, GlobSetBuilder};
 use itertools::Itertools;
 use tokio::sync::mpsc::Sender;
 
@@ -35,6 +35,130 @@ const MARKER_FILES: [&str; 3] = ["_metadata", "_common_metadata", "_success"];
 // NOTE: We use the following prefixes to filter out Spark/Databricks marker files
 const MARKER_PREFIXES: [&str; 2] = ["_started", "_committed"];
 
+/// Options of a glob beyond its pattern.
+#[derive(Clone, Debug, Default)]
+pub struct GlobOptions {
+    /// Patterns of files and directories to leave out, whose directories are not even listed. Patterns without a
+    /// delimiter are matched against the name of each file and directory, e.g. `*.crc` or `_temporary`, and others
+    /// against the whole path, e.g. `s3://bucket/**/_temporary`.
+    pub exclude: Vec<String>,
+    /// The most levels of directories that a `**` descends into, e.g. 0 to only match the files of the directory that
+    /// it starts in, or None to descend into all of them.
+    pub max_depth: Option<usize>,
+}
+
+/// [`GlobOptions`] that are ready to filter the files and directories found while globbing.
+#[derive(Debug)]
+pub struct GlobFilter {
+    excluded_names: GlobSet,
+    excluded_paths: GlobSet,
+    max_depth: Option<usize>,
+}
+
+impl GlobFilter {
+    fn new(options: &GlobOptions) -> super::Result<Self> {
+        let mut excluded_names = GlobSetBuilder::new();
+        let mut excluded_paths = GlobSetBuilder::new();
+        for pattern in &options.exclude {
+            let glob = GlobBuilder::new(pattern.trim_end_matches(GLOB_DELIMITER))
+                .literal_separator(true)
+                .backslash_escape(true)
+                .build()
+                .map_err(|err| super::Error::InvalidArgument {
+                    msg: format!("Cannot parse provided exclusion pattern {pattern}: {err}"),
+                })?;
+            if pattern
+                .trim_end_matches(GLOB_DELIMITER)
+                .contains(GLOB_DELIMITER)
+            {
+                excluded_paths.add(glob);
+            } else {
+                excluded_names.add(glob);
+            }
+        }
+        let build_error = |err: globset::Error| super::Error::InvalidArgument {
+            msg: format!("Cannot parse provided exclusion patterns: {err}"),
+        };
+        Ok(Self {
+            excluded_names: excluded_names.build().map_err(build_error)?,
+            excluded_paths: excluded_paths.build().map_err(build_error)?,
+            max_depth: options.max_depth,
+        })
+    }
+
+    /// Whether the file or directory at `path` is excluded, either itself or because one of its directories is.
+    fn is_excluded(&self, path: &str) -> bool {
+        if self.excluded_names.is_empty() && self.excluded_paths.is_empty() {
+            return false;
+        }
+        let start = path.find("://").map_or(0, |idx| idx + SCHEME_SUFFIX_LEN);
+        let mut prefix = path.trim_end_matches(GLOB_DELIMITER);
+        while prefix.len() > start {
+            let name = prefix[start..]
+                .rsplit(GLOB_DELIMITER)
+                .next()
+                .unwrap_or_default();
+            if self.excluded_names.is_match(name) || self.excluded_paths.is_match(prefix) {
+                return true;
+            }
+            prefix = &prefix[..start + prefix[start..].rfind(GLOB_DELIMITER).unwrap_or(0)];
+        }
+        false
+    }
+
+    /// Whether files and directories `depth` levels of directories below where a `**` starts may be globbed.
+    fn within_depth(&self, depth: usize) -> bool {
+        self.max_depth.is_none_or(|max_depth| depth <= max_depth)
+    }
+}
+
+/// Expands the braces of a glob into a glob for each of their alternatives, e.g. `a/{b,c}/*` into `a/b/*` and `a/c/*`,
+/// so that the directory of each alternative is listed on its own, rather than their parent directory.
+fn expand_braces(glob: &str) -> Vec<String> {
+    let bytes = glob.as_bytes();
+    let mut open = None;
+    let mut depth = 0;
+    let mut commas = vec![];
+    let mut idx = 0;
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b'\\' => idx += 1,
+            // Braces are literal in character classes
+            b'[' => {
+                if let Some(end) = glob[idx + 1..].find(']') {
+                    idx += end + 1;
+                }
+            }
+            b'{' => {
+                open.get_or_insert(idx);
+                depth += 1;
+            }
+            b',' if depth == 1 => commas.push(idx),
+            b'}' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    let start = open.unwrap();
+                    let (prefix, suffix) = (&glob[..start], &glob[idx + 1..]);
+                    let mut bounds = vec![start];
+                    bounds.extend(commas);
+                    bounds.push(idx);
+                    return bounds
+                        .windows(2)
+                        .flat_map(|window| {
+                            let alternative = &glob[window[0] + 1..window[1]];
+                            expand_braces(&format!("{prefix}{alternative}{suffix}"))
+                        })
+                        .unique()
+                        .collect();
+                }
+            }
+            _ => (),
+        }
+        idx += 1;
+    }
+    vec![glob.to_string()]
+}
+
 #[derive(Clone)]
 pub struct GlobState {
     // Current path in dirtree and glob_fragments
@@ -52,6 +176,10 @@ pub struct GlobState {
     pub full_glob_matcher: Arc<GlobMatcher>,
     pub fanout_limit: Option<usize>,
     pub page_size: Option<i32>,
+
+    // How many levels of directories the current `**` fragment has descended into
+    pub depth: usize,
+    pub filter: Arc<GlobFilter>,
 }
 
 impl GlobState {
@@ -68,6 +196,22 @@ impl GlobState {
         }
     }
 
+    /// How many levels of directories below where the current `**` fragment starts a file at `path` is, which
+    /// includes the directories below the current path that a prefix listing returns files of.
+    fn depth_of(&self, path: &str) -> usize {
+        let current_path = self.current_path.trim_end_matches(GLOB_DELIMITER);
+        self.depth
+            + path
+                .get(current_path.len() + GLOB_DELIMITER.len()..)
+                .map_or(0, |relative_path| {
+                    relative_path.matches(GLOB_DELIMITER).count()
+                })
+    }
+
+    pub fn with_depth(self, depth: usize) -> Self {
+        Self { depth, ..self }
+    }
+
     pub fn with_wildcard_mode(self) -> Self {
         Self {
             wildcard_mode: true,
@@ -383,6 +527,9 @@ fn verify_glob(glob: &str) -> super::Result<()> {
 ///   parallel connections (usually defaulting to 64).
 /// * page_size: control the returned results page size, or None to use the ObjectSource's defaults. Usually only used for testing
 ///   but may yield some performance improvements depending on the workload.
+/// * options: files and directories to exclude, and how deep `**` descends.
+///
+/// Braces are expanded before globbing, and each of the globs that they expand into is globbed on its own.
 pub async fn glob(
     source: Arc<dyn ObjectSource>,
     glob: &str,
@@ -390,6 +537,55 @@ pub async fn glob(
     page_size: Option<i32>,
     limit: Option<usize>,
     io_stats: Option<IOStatsRef>,
+    options: &GlobOptions,
+) -> super::Result<BoxStream<'static, super::Result<FileMetadata>>> {
+    let filter = Arc::new(GlobFilter::new(options)?);
+    let globs = expand_braces(glob);
+    let num_globs = globs.len();
+    let mut streams = Vec::with_capacity(num_globs);
+    for glob in &globs {
+        streams.push(
+            glob_one(
+                source.clone(),
+                glob,
+                fanout_limit,
+                page_size,
+                limit,
+                io_stats.clone(),
+                filter.clone(),
+            )
+            .await?,
+        );
+    }
+
+    let to_rtn_stream = stream! {
+        let mut remaining_results = limit;
+        // The alternatives of braces may match the same files, e.g. `{a,a*}`
+        let mut seen = HashSet::new();
+        let mut results = futures::stream::select_all(streams);
+        while remaining_results.is_none_or(|rr| rr > 0) && let Some(v) = results.next().await {
+            if let Ok(fm) = &v
+                && (filter.is_excluded(&fm.filepath)
+                    || (num_globs > 1 && !seen.insert(fm.filepath.clone())))
+            {
+                continue;
+            }
+
+            remaining_results = remaining_results.map(|rr| rr - 1);
+            yield v
+        }
+    };
+    Ok(to_rtn_stream.boxed())
+}
+
+async fn glob_one(
+    source: Arc<dyn ObjectSource>,
+    glob: &str,
+    fanout_limit: Option<usize>,
+    page_size: Option<i32>,
+    limit: Option<usize>,
+    io_stats: Option<IOStatsRef>,
+    filter: Arc<GlobFilter>,
 ) -> super::Result<BoxStream<'static, super::Result<FileMetadata>>> {
     // If no special characters, we fall back to ls behavior
     let full_fragment = GlobFragment::new(glob);
@@ -404,7 +600,7 @@ pub async fn glob(
                 // If doesn't have a glob character and doesn't end with a delimiter, assume its a file first.
                 let maybe_size = source.get_size(&glob, io_stats.clone()).await;
                 match maybe_size {
-                    Ok(size_bytes) => yield Ok(FileMetadata{filepath: glob.clone(), size: Some(size_bytes as u64), filetype: FileType::File  }),
+                    Ok(size_bytes) => yield Ok(FileMetadata{filepath: glob.clone(), size: Some(size_bytes as u64), filetype: FileType::File, last_modified: None, etag: None, version_id: None, storage_class: None }),
                     Err(crate::Error::NotAFile {..} | crate::Error::NotFound { .. } | crate::Error::UnableToDetermineSize { .. }) => {attempt_as_dir = true;},
                     Err(err) => yield Err(err),
                 }
@@ -490,23 +686,34 @@ pub async fn glob(
                     match val {
                         Ok(fm) => {
                             match fm.filetype {
-                                // Recursively visit each sub-directory
+                                // Recursively visit each sub-directory, unless it is excluded or too deep
                                 FileType::Directory => {
-                                    visit(
-                                        result_tx.clone(),
-                                        source.clone(),
-                                        // Do not increment `current_fragment_idx` so as to keep visiting the "**" fragmemt
-                                        state.clone().advance(
-                                            fm.filepath.clone(),
-                                            state.current_fragment_idx,
-                                            stream_dir_count,
-                                        ),
-                                        io_stats.clone(),
-                                    );
+                                    if state.filter.within_depth(state.depth + 1)
+                                        && !state.filter.is_excluded(&fm.filepath)
+                                    {
+                                        visit(
+                                            result_tx.clone(),
+                                            source.clone(),
+                                            // Do not increment `current_fragment_idx` so as to keep visiting the "**" fragmemt
+                                            state
+                                                .clone()
+                                                .advance(
+                                                    fm.filepath.clone(),
+                                                    state.current_fragment_idx,
+                                                    stream_dir_count,
+                                                )
+                                                .with_depth(state.depth + 1),
+                                            io_stats.clone(),
+                                        );
+                                    }
                                 }
-                                // Return any Files that match
+                                // Return any Files that match, which are in sub-directories if the directory was
+                                // prefix listed
                                 FileType::File
-                                    if state.full_glob_matcher.is_match(fm.filepath.as_str()) =>
+                                    if state.full_glob_matcher.is_match(fm.filepath.as_str())
+                                        && state
+                                            .filter
+                                            .within_depth(state.depth_of(&fm.filepath)) =>
                                 {
                                     if let Some(e) = result_tx.send(Ok(fm)).await.err() {
                                         log::debug!("Sender unable to send results into channel during glob (this is expected if a limit was applied, which results in early termination): {e}");
@@ -617,7 +824,7 @@ pub async fn glob(
                             FileType::Directory
                                 if partial_glob_matcher.is_match(
                                     fm.filepath.as_str().trim_end_matches(GLOB_DELIMITER),
-                                ) =>
+                                ) && !state.filter.is_excluded(&fm.filepath) =>
                             {
                                 visit(
                                     result_tx.clone(),
@@ -679,6 +886,8 @@ pub async fn glob(
             current_fanout: 1,
             fanout_limit,
             page_size,
+            depth: 0,
+            filter,
         },
         io_stats,
     );
@@ -701,6 +910,8 @@ pub async fn glob(
 
 #[cfg(test)]
 mod tests {
+    use futures::TryStreamExt;
+
     use super::*;
 
     #[test]
@@ -731,4 +942,133 @@ mod tests {
         let err = verify_glob("invalid/\\**blahblah**.txt").unwrap_err();
         assert!(err.to_string().contains("invalid/\\\\**blahblah/**/*.txt")); // Suggests adding delimiters around '**'
     }
+
+    #[test]
+    fn test_expand_braces() {
+        assert_eq!(
+            expand_braces("s3://bucket/*.parquet"),
+            vec!["s3://bucket/*.parquet"]
+        );
+        assert_eq!(
+            expand_braces("s3://bucket/{2023,2024/01}/*.{csv,tsv}"),
+            vec![
+                "s3://bucket/2023/*.csv",
+                "s3://bucket/2023/*.tsv",
+                "s3://bucket/2024/01/*.csv",
+                "s3://bucket/2024/01/*.tsv",
+            ]
+        );
+        // Nested braces, and escaped braces and braces in character classes which are literal
+        assert_eq!(
+            expand_braces("s3://bucket/{a,b{c,d}}"),
+            vec!["s3://bucket/a", "s3://bucket/bc", "s3://bucket/bd"]
+        );
+        assert_eq!(
+            expand_braces(r"s3://bucket/\{a,b\}"),
+            vec![r"s3://bucket/\{a,b\}"]
+        );
+        assert_eq!(
+            expand_braces("s3://bucket/[{]a,b}"),
+            vec!["s3://bucket/[{]a,b}"]
+        );
+    }
+
+    #[test]
+    fn test_glob_filter_excludes_names_paths_and_their_contents() -> crate::Result<()> {
+        let filter = GlobFilter::new(&GlobOptions {
+            exclude: vec![
+                "_temporary/".to_string(),
+                "*.crc".to_string(),
+                "s3://bucket/**/staging".to_string(),
+            ],
+            max_depth: None,
+        })?;
+        assert!(filter.is_excluded("s3://bucket/data/_temporary/"));
+        assert!(filter.is_excluded("s3://bucket/data/_temporary/0/part-0.parquet"));
+        assert!(filter.is_excluded("s3://bucket/data/part-0.parquet.crc"));
+        assert!(filter.is_excluded("s3://bucket/a/b/staging/part-0.parquet"));
+        assert!(!filter.is_excluded("s3://bucket/data/part-0.parquet"));
+        assert!(!filter.is_excluded("s3://bucket/data/staging_area/part-0.parquet"));
+        assert!(!filter.is_excluded("file:///tmp/data/part-0.parquet"));
+        Ok(())
+    }
+
+    /// The paths of the files under `root` that `pattern` matches, relative to `root`.
+    async fn glob_paths(
+        root: &str,
+        pattern: &str,
+        options: GlobOptions,
+    ) -> crate::Result<Vec<String>> {
+        let source = crate::local::LocalSource::get_client().await?;
+        let prefix = format!("file://{root}/");
+        let mut paths = glob(
+            source,
+            &format!("{prefix}{pattern}"),
+            None,
+            None,
+            None,
+            None,
+            &options,
+        )
+        .await?
+        .map(|fm| fm.map(|fm| fm.filepath.replace(&prefix, "")))
+        .try_collect::<Vec<_>>()
+        .await?;
+        paths.sort();
+        Ok(paths)
+    }
+
+    #[tokio::test]
+    async fn test_glob_with_braces_exclusions_and_max_depth() -> crate::Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        for path in [
+            "a/0.parquet",
+            "a/0.parquet.crc",
+            "a/b/1.parquet",
+            "a/b/c/2.parquet",
+            "a/_temporary/3.parquet",
+            "d/4.parquet",
+            "e/5.parquet",
+        ] {
+            let path = dir.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, b"").unwrap();
+        }
+        let root = dir.path().to_string_lossy().to_string();
+        assert_eq!(
+            glob_paths(&root, "{a/b,d}/*.parquet", GlobOptions::default()).await?,
+            vec!["a/b/1.parquet", "d/4.parquet"]
+        );
+        assert_eq!(
+            glob_paths(
+                &root,
+                "a/**/*.parquet",
+                GlobOptions {
+                    exclude: vec!["_temporary".to_string()],
+                    max_depth: Some(1),
+                }
+            )
+            .await?,
+            vec!["a/0.parquet", "a/b/1.parquet"]
+        );
+        assert_eq!(
+            glob_paths(
+                &root,
+                "**/*",
+                GlobOptions {
+                    exclude: vec!["_temporary".to_string(), "*.crc".to_string()],
+                    max_depth: None,
+                }
+            )
+            .await?,
+            vec![
+                "a/0.parquet",
+                "a/b/1.parquet",
+                "a/b/c/2.parquet",
+                "d/4.parquet",
+                "e/5.parquet"
+            ]
+        );
+        Ok(())
+    }
 }
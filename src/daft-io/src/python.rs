@@ -2,12 +2,20 @@ pub use common_io_config::python::{AzureConfig, GCSConfig, IOConfig};
 pub use py::register_modules;
 
 mod py {
+    use std::io::Write;
+
     use common_error::DaftResult;
     use common_runtime::get_io_runtime;
     use futures::TryStreamExt;
-    use pyo3::{prelude::*, types::PyDict};
+    use pyo3::{
+        prelude::*,
+        types::{PyBytes, PyDict},
+        PyTypeInfo,
+    };
 
-    use crate::{get_io_client, parse_url, s3_like, stats::IOStatsContext};
+    use crate::{
+        get_io_client, parse_url, s3_like, stats::IOStatsContext, GlobOptions, ObjectSink,
+    };
 
     #[pyfunction(signature = (
         input,
@@ -15,8 +23,11 @@ mod py {
         io_config=None,
         fanout_limit=None,
         page_size=None,
-        limit=None
+        limit=None,
+        exclude=None,
+        max_depth=None
     ))]
+    #[allow(clippy::too_many_arguments)]
     fn io_glob(
         py: Python,
         input: String,
@@ -25,6 +36,8 @@ mod py {
         fanout_limit: Option<usize>,
         page_size: Option<i32>,
         limit: Option<usize>,
+        exclude: Option<Vec<String>>,
+        max_depth: Option<usize>,
     ) -> PyResult<Vec<Bound<PyDict>>> {
         let multithreaded_io = multithreaded_io.unwrap_or(true);
         let io_stats = IOStatsContext::new(format!("io_glob for {input}"));
@@ -48,6 +61,10 @@ mod py {
                         limit,
                         Some(io_stats_handle),
                         None,
+                        &GlobOptions {
+                            exclude: exclude.unwrap_or_default(),
+                            max_depth,
+                        },
                     )
                     .await?
                     .try_collect()
@@ -61,6 +78,15 @@ mod py {
             dict.set_item("type", format!("{:?}", file.filetype))?;
             dict.set_item("path", file.filepath)?;
             dict.set_item("size", file.size)?;
+            // Modification times are passed as microseconds since the epoch, in UTC.
+            dict.set_item(
+                "last_modified",
+                file.last_modified
+                    .map(|t| t.unix_timestamp_nanos().div_euclid(1_000) as i64),
+            )?;
+            dict.set_item("etag", file.etag)?;
+            dict.set_item("version_id", file.version_id)?;
+            dict.set_item("storage_class", file.storage_class)?;
             to_rtn.push(dict);
         }
         Ok(to_rtn)
@@ -77,8 +103,89 @@ mod py {
         Ok(common_io_config::python::S3Config { config: s3_config? })
     }
 
+    /// A writable file-like object that streams what is written to it to an object store, in parts of `part_size`
+    /// bytes with at most `max_inflight_parts` uploads at once. Writes block while that many are in flight.
+    #[pyclass(module = "daft.daft", name = "ObjectSink")]
+    pub struct PyObjectSink {
+        sink: ObjectSink,
+        closed: bool,
+    }
+
+    #[pymethods]
+    impl PyObjectSink {
+        #[new]
+        #[pyo3(signature = (uri, part_size, max_inflight_parts, io_config=None))]
+        fn new(
+            uri: String,
+            part_size: usize,
+            max_inflight_parts: usize,
+            io_config: Option<common_io_config::python::IOConfig>,
+        ) -> PyResult<Self> {
+            let io_client = get_io_client(true, io_config.unwrap_or_default().config.into())?;
+            let io_stats = IOStatsContext::new(format!("ObjectSink for {uri}"));
+            Ok(Self {
+                sink: ObjectSink::new(
+                    io_client,
+                    uri,
+                    part_size,
+                    max_inflight_parts,
+                    Some(io_stats),
+                ),
+                closed: false,
+            })
+        }
+
+        fn write(&mut self, py: Python, data: Bound<PyAny>) -> PyResult<usize> {
+            // Buffers can't be read directly with the limited API, so they are copied into bytes
+            let data = PyBytes::type_object(py).call1((data,))?;
+            let data = data.downcast::<PyBytes>()?.as_bytes();
+            py.allow_threads(|| self.sink.write_all(data))?;
+            Ok(data.len())
+        }
+
+        fn tell(&self) -> usize {
+            self.sink.bytes_written()
+        }
+
+        fn flush(&self) {}
+
+        fn close(&mut self, py: Python) -> PyResult<()> {
+            if !self.closed {
+                self.closed = true;
+                py.allow_threads(|| self.sink.close())?;
+            }
+            Ok(())
+        }
+
+        /// Abandons the file, so that nothing of it is uploaded.
+        fn abort(&mut self) {
+            if !self.closed {
+                self.closed = true;
+                self.sink.abort();
+            }
+        }
+
+        #[getter]
+        fn closed(&self) -> bool {
+            self.closed
+        }
+
+        fn writable(&self) -> bool {
+            true
+        }
+
+        fn readable(&self) -> bool {
+            false
+        }
+
+        fn seekable(&self) -> bool {
+            false
+        }
+    }
+
     pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
         common_io_config::python::register_modules(parent)?;
+        parent.add_class::<PyObjectSink>()?;
         parent.add_function(wrap_pyfunction!(io_glob, parent)?)?;
         parent.add_function(wrap_pyfunction!(s3_config_from_env, parent)?)?;
         Ok(())
@@ -0,0 +1,301 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, LazyLock, Mutex},
+    time::Duration,
+};
+
+use common_io_config::RateLimitConfig;
+use tokio::{sync::oneshot, time::Instant};
+
+use crate::{IOStatsContext, SourceType};
+
+/// How many requests per second may be made at once after an endpoint has been idle, as a duration's worth of them.
+const BURST: Duration = Duration::from_secs(1);
+
+/// An endpoint, and how requests to it are limited.
+type LimiterKey = (String, RateLimitConfig);
+
+/// Limiters are shared by all IO clients of the process that limit requests to the same endpoint in the same way, so
+/// that concurrent queries share the caps rather than each getting their own.
+static ENDPOINT_LIMITERS: LazyLock<Mutex<HashMap<LimiterKey, Arc<EndpointLimiter>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// The endpoint that requests to `input` are made to: the bucket of object stores, or the host of other stores.
+fn endpoint(input: &str) -> Option<String> {
+    let url = url::Url::parse(input).ok()?;
+    Some(format!("{}://{}", url.scheme(), url.host_str()?))
+}
+
+struct SemaphoreState {
+    available: usize,
+    /// Requests waiting for a permit by requester, with the requesters in the order that they take turns in.
+    waiters: VecDeque<(usize, VecDeque<oneshot::Sender<FairPermit>>)>,
+}
+
+impl SemaphoreState {
+    /// The next request to hand a permit to, taking turns between requesters.
+    fn next_waiter(&mut self) -> Option<oneshot::Sender<FairPermit>> {
+        let (requester, mut queue) = self.waiters.pop_front()?;
+        let waiter = queue.pop_front();
+        if !queue.is_empty() {
+            self.waiters.push_back((requester, queue));
+        }
+        waiter
+    }
+}
+
+/// Semaphore whose permits are handed to the requests of each requester in turn once they run out, so that a
+/// requester that makes many requests at once does not starve others.
+struct FairSemaphore {
+    state: Mutex<SemaphoreState>,
+}
+
+/// Permit of a [`FairSemaphore`], which is handed to the next waiting request once dropped.
+pub(crate) struct FairPermit {
+    semaphore: Option<Arc<FairSemaphore>>,
+}
+
+impl Drop for FairPermit {
+    fn drop(&mut self) {
+        if let Some(semaphore) = self.semaphore.take() {
+            semaphore.release();
+        }
+    }
+}
+
+impl FairSemaphore {
+    fn new(permits: usize) -> Arc<Self> {
+        Arc::new(Self {
+            state: Mutex::new(SemaphoreState {
+                available: permits,
+                waiters: VecDeque::new(),
+            }),
+        })
+    }
+
+    async fn acquire(self: &Arc<Self>, requester: usize) -> FairPermit {
+        let receiver = {
+            let mut state = self.state.lock().unwrap();
+            if state.available > 0 && state.waiters.is_empty() {
+                state.available -= 1;
+                return FairPermit {
+                    semaphore: Some(self.clone()),
+                };
+            }
+            let (sender, receiver) = oneshot::channel();
+            match state.waiters.iter_mut().find(|(r, _)| *r == requester) {
+                Some((_, queue)) => queue.push_back(sender),
+                None => state
+                    .waiters
+                    .push_back((requester, VecDeque::from([sender]))),
+            }
+            receiver
+        };
+        // Permits are only dropped along with their sender once they are handed out, which hands them on
+        receiver
+            .await
+            .expect("Waiting requests are always handed a permit")
+    }
+
+    fn release(self: &Arc<Self>) {
+        let mut state = self.state.lock().unwrap();
+        while let Some(waiter) = state.next_waiter() {
+            let permit = FairPermit {
+                semaphore: Some(self.clone()),
+            };
+            // A request that stopped waiting does not take the permit, so it is handed to the next one instead.
+            // A request that stops waiting after it was handed a permit drops it, which releases it again.
+            match waiter.send(permit) {
+                Ok(()) => return,
+                Err(mut permit) => permit.semaphore = None,
+            }
+        }
+        state.available += 1;
+    }
+}
+
+/// Paces the requests to an endpoint to a rate, allowing bursts of up to [`BURST`]'s worth of requests.
+struct RequestRate {
+    interval: Duration,
+    /// When the endpoint would be idle again if requests were made at exactly the rate.
+    idle_at: Mutex<Instant>,
+}
+
+impl RequestRate {
+    fn new(requests_per_second: u32) -> Self {
+        Self {
+            interval: Duration::from_secs(1) / requests_per_second.max(1),
+            idle_at: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Reserves the next request, returning when it may be made.
+    fn reserve(&self) -> Instant {
+        let now = Instant::now();
+        let mut idle_at = self.idle_at.lock().unwrap();
+        *idle_at = (*idle_at).max(now) + self.interval;
+        (*idle_at).checked_sub(BURST).unwrap_or(now).max(now)
+    }
+}
+
+/// Caps the requests made to an endpoint as configured by a [`RateLimitConfig`], taking turns between requesters once
+/// the caps are reached.
+struct EndpointLimiter {
+    concurrency: Option<Arc<FairSemaphore>>,
+    /// Requests take turns to be paced one at a time, so that a requester that makes many requests at once does not
+    /// reserve the rate far ahead of others.
+    pacing: Option<(Arc<FairSemaphore>, RequestRate)>,
+}
+
+impl EndpointLimiter {
+    fn get(config: &RateLimitConfig, endpoint: String) -> Arc<Self> {
+        let mut limiters = ENDPOINT_LIMITERS.lock().unwrap();
+        limiters
+            .entry((endpoint, config.clone()))
+            .or_insert_with(|| {
+                Arc::new(Self {
+                    concurrency: config
+                        .max_concurrent_requests
+                        .map(|permits| FairSemaphore::new(permits.max(1) as usize)),
+                    pacing: config
+                        .max_requests_per_second
+                        .map(|rate| (FairSemaphore::new(1), RequestRate::new(rate))),
+                })
+            })
+            .clone()
+    }
+
+    async fn acquire(&self, requester: usize) -> Option<FairPermit> {
+        let permit = match &self.concurrency {
+            Some(semaphore) => Some(semaphore.acquire(requester).await),
+            None => None,
+        };
+        if let Some((turns, rate)) = &self.pacing {
+            let _turn = turns.acquire(requester).await;
+            tokio::time::sleep_until(rate.reserve()).await;
+        }
+        permit
+    }
+}
+
+/// Limits the requests that a requester makes to an endpoint.
+#[derive(Clone)]
+pub(crate) struct RequestLimiter {
+    endpoint: Arc<EndpointLimiter>,
+    requester: usize,
+}
+
+impl RequestLimiter {
+    /// The limiter of requests to the endpoint of `input` that are counted in `io_stats`, or None if they are not
+    /// limited. Requests to local files never are.
+    pub fn new(
+        config: &RateLimitConfig,
+        source_type: SourceType,
+        input: &str,
+        io_stats: Option<&IOStatsContext>,
+    ) -> Option<Self> {
+        if !config.is_limited() || source_type == SourceType::File {
+            return None;
+        }
+        Some(Self {
+            endpoint: EndpointLimiter::get(config, endpoint(input)?),
+            requester: io_stats.map_or(0, IOStatsContext::requester),
+        })
+    }
+
+    /// Waits for the turn of the requester to make a request, returning the permit that counts the request as in
+    /// flight until it is dropped, if concurrent requests are capped.
+    pub async fn acquire(&self) -> Option<FairPermit> {
+        self.endpoint.acquire(self.requester).await
+    }
+}
+
+/// Waits for a turn to make a request if requests are limited, returning the permit to hold while it is in flight.
+pub(crate) async fn acquire(limiter: Option<&RequestLimiter>) -> Option<FairPermit> {
+    match limiter {
+        Some(limiter) => limiter.acquire().await,
+        None => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use common_io_config::RateLimitConfig;
+    use tokio::time::Instant;
+
+    use super::{endpoint, FairSemaphore, RequestLimiter};
+    use crate::SourceType;
+
+    #[test]
+    fn test_endpoint() {
+        assert_eq!(
+            endpoint("s3://bucket/path/to/file.parquet").as_deref(),
+            Some("s3://bucket")
+        );
+        assert_eq!(
+            endpoint("https://minio.internal:9000/bucket/key").as_deref(),
+            Some("https://minio.internal")
+        );
+        assert_eq!(endpoint("/local/path"), None);
+    }
+
+    #[tokio::test]
+    async fn test_fair_semaphore_takes_turns_between_requesters() {
+        let semaphore = FairSemaphore::new(1);
+        let permit = semaphore.acquire(1).await;
+
+        // Requester 1 queues up many requests before requester 2 makes any
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let mut handles = vec![];
+        for requester in [1, 1, 1, 2, 2] {
+            let semaphore = semaphore.clone();
+            let sender = sender.clone();
+            handles.push(tokio::spawn(async move {
+                let _permit = semaphore.acquire(requester).await;
+                sender.send(requester).unwrap();
+            }));
+            tokio::task::yield_now().await;
+        }
+        // A request that stops waiting gives up its turn
+        let cancelled = tokio::spawn({
+            let semaphore = semaphore.clone();
+            async move { semaphore.acquire(3).await }
+        });
+        tokio::task::yield_now().await;
+        cancelled.abort();
+        let _ = cancelled.await;
+
+        drop(permit);
+        for handle in handles {
+            handle.await.unwrap();
+        }
+        drop(sender);
+        let mut order = vec![];
+        while let Some(requester) = receiver.recv().await {
+            order.push(requester);
+        }
+        assert_eq!(order, vec![1, 2, 1, 2, 1]);
+        assert_eq!(semaphore.state.lock().unwrap().available, 1);
+    }
+
+    #[tokio::test]
+    async fn test_request_limiter_paces_requests_after_burst() {
+        let config = RateLimitConfig {
+            max_concurrent_requests: None,
+            max_requests_per_second: Some(20),
+        };
+        let limiter =
+            RequestLimiter::new(&config, SourceType::S3, "s3://paced-bucket/key", None).unwrap();
+        let start = Instant::now();
+        for _ in 0..20 {
+            assert!(limiter.acquire().await.is_none());
+        }
+        assert!(start.elapsed() < Duration::from_millis(50));
+        for _ in 0..2 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+}
@@ -1,7 +1,14 @@
-use std::{future::Future, time::Duration};
+use std::{
+    future::Future,
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
 
+use common_io_config::RetryConfig;
 use rand::Rng;
 
+use crate::{Error, IOStatsContext};
+
 pub enum RetryError<T> {
     /// Error that should not be retried
     Permanent(T),
@@ -67,3 +74,270 @@ impl ExponentialBackoff {
         }
     }
 }
+
+/// How likely a failed request is to succeed if it is made again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorClass {
+    /// The store is rate limiting requests, so retries back off for longer than for transient errors.
+    Throttled,
+    /// E.g. timeouts, dropped connections and internal errors of the store.
+    Transient,
+    Permanent,
+}
+
+impl ErrorClass {
+    /// The number of tokens that a retry of a request that failed with an error of this class takes from the budget.
+    fn retry_cost(self) -> u32 {
+        match self {
+            Self::Throttled => 2,
+            Self::Transient => 1,
+            Self::Permanent => unreachable!("Permanent errors are never retried"),
+        }
+    }
+}
+
+impl Error {
+    /// Classifies the error. Each store maps the errors of its client to the variants classified here, e.g. S3's
+    /// `SlowDown` and GCS's 429 responses to [`Error::Throttled`], and connection resets to [`Error::SocketError`].
+    pub fn class(&self) -> ErrorClass {
+        match self {
+            Self::Throttled { .. } => ErrorClass::Throttled,
+            Self::ConnectTimeout { .. }
+            | Self::ReadTimeout { .. }
+            | Self::SocketError { .. }
+            | Self::MiscTransient { .. }
+            | Self::UnableToReadBytes { .. } => ErrorClass::Transient,
+            Self::CachedError { source } => source.class(),
+            _ => ErrorClass::Permanent,
+        }
+    }
+}
+
+/// Retries requests that fail with throttling or transient errors as configured by a [`RetryConfig`].
+///
+/// A policy is shared by all requests of an [`crate::IOClient`], so that its retry budget bounds the retries across
+/// all of them. Retries take tokens from the budget, which requests give back once they succeed, along with a token
+/// for succeeding.
+#[derive(Debug, Default)]
+pub struct RetryPolicy {
+    config: RetryConfig,
+    /// The tokens left in the retry budget, if it is bounded.
+    budget_tokens: Option<AtomicU32>,
+}
+
+impl RetryPolicy {
+    pub fn new(config: RetryConfig) -> Self {
+        Self {
+            budget_tokens: config.retry_budget.map(AtomicU32::new),
+            config,
+        }
+    }
+
+    /// The backoff before retry number `retry` of a request that failed with an error of `class`.
+    fn backoff(&self, retry: u32, class: ErrorClass) -> Duration {
+        // Throttled requests back off as if they had been retried once more
+        let doublings = match class {
+            ErrorClass::Throttled => retry,
+            _ => retry - 1,
+        };
+        let backoff_ms = self
+            .config
+            .initial_backoff_ms
+            .saturating_mul(1 << doublings.min(32))
+            .min(self.config.max_backoff_ms);
+        let backoff_ms = if self.config.jitter && backoff_ms > 0 {
+            rand::thread_rng().gen_range(0..=backoff_ms)
+        } else {
+            backoff_ms
+        };
+        Duration::from_millis(backoff_ms)
+    }
+
+    fn take_tokens(&self, cost: u32) -> bool {
+        let Some(tokens) = &self.budget_tokens else {
+            return true;
+        };
+        tokens
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |tokens| {
+                tokens.checked_sub(cost)
+            })
+            .is_ok()
+    }
+
+    fn give_back_tokens(&self, num_tokens: u32) {
+        if let (Some(tokens), Some(capacity)) = (&self.budget_tokens, self.config.retry_budget) {
+            let _ = tokens.fetch_update(Ordering::AcqRel, Ordering::Acquire, |tokens| {
+                Some(tokens.saturating_add(num_tokens).min(capacity))
+            });
+        }
+    }
+
+    /// Makes a request with `f`, retrying it while it fails with a retryable error, as many times as the config and
+    /// the budget allow. Retries, and requests that were throttled, are counted in `io_stats`.
+    pub async fn retry<T, Fut>(
+        &self,
+        io_stats: Option<&IOStatsContext>,
+        mut f: impl FnMut() -> Fut,
+    ) -> crate::Result<T>
+    where
+        Fut: Future<Output = crate::Result<T>>,
+    {
+        let mut attempt = 1;
+        let mut tokens_taken = 0;
+        loop {
+            let err = match f().await {
+                Ok(value) => {
+                    self.give_back_tokens(tokens_taken + 1);
+                    return Ok(value);
+                }
+                Err(err) => err,
+            };
+            let class = err.class();
+            if class == ErrorClass::Throttled
+                && let Some(io_stats) = io_stats
+            {
+                io_stats.mark_throttles(1);
+            }
+            if class == ErrorClass::Permanent || attempt >= self.config.max_attempts {
+                return Err(err);
+            }
+            if !self.take_tokens(class.retry_cost()) {
+                log::warn!("Not retrying failed request since the retry budget is used up: {err}");
+                return Err(err);
+            }
+            tokens_taken += class.retry_cost();
+
+            let backoff = self.backoff(attempt, class);
+            log::warn!(
+                "Request failed with {class:?} error on attempt {attempt} out of {}. Retrying in {}ms\nDetails\n{err}",
+                self.config.max_attempts,
+                backoff.as_millis()
+            );
+            tokio::time::sleep(backoff).await;
+            if let Some(io_stats) = io_stats {
+                io_stats.mark_retries(1);
+            }
+            attempt += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use common_io_config::RetryConfig;
+
+    use super::{ErrorClass, RetryPolicy};
+    use crate::Error;
+
+    fn no_backoff(max_attempts: u32, retry_budget: Option<u32>) -> RetryPolicy {
+        RetryPolicy::new(RetryConfig {
+            max_attempts,
+            initial_backoff_ms: 0,
+            max_backoff_ms: 0,
+            jitter: false,
+            retry_budget,
+        })
+    }
+
+    fn throttled() -> Error {
+        Error::Throttled {
+            path: "s3://bucket/key".into(),
+            source: "SlowDown".into(),
+        }
+    }
+
+    fn socket_error() -> Error {
+        Error::SocketError {
+            path: "s3://bucket/key".into(),
+            source: "connection reset".into(),
+        }
+    }
+
+    async fn fail_times(
+        policy: &RetryPolicy,
+        num_failures: u32,
+        error: fn() -> Error,
+    ) -> (crate::Result<()>, u32) {
+        let attempts = AtomicU32::new(0);
+        let result = policy
+            .retry(None, || async {
+                if attempts.fetch_add(1, Ordering::Relaxed) < num_failures {
+                    Err(error())
+                } else {
+                    Ok(())
+                }
+            })
+            .await;
+        (result, attempts.load(Ordering::Relaxed))
+    }
+
+    #[test]
+    fn test_error_classes() {
+        assert_eq!(throttled().class(), ErrorClass::Throttled);
+        assert_eq!(socket_error().class(), ErrorClass::Transient);
+        assert_eq!(
+            Error::NotAFile {
+                path: "s3://bucket/".into()
+            }
+            .class(),
+            ErrorClass::Permanent
+        );
+    }
+
+    #[test]
+    fn test_backoff_is_capped_and_longer_when_throttled() {
+        let policy = RetryPolicy::new(RetryConfig {
+            initial_backoff_ms: 100,
+            max_backoff_ms: 1_000,
+            jitter: false,
+            ..Default::default()
+        });
+        let backoffs = (1..=5)
+            .map(|retry| policy.backoff(retry, ErrorClass::Transient).as_millis())
+            .collect::<Vec<_>>();
+        assert_eq!(backoffs, vec![100, 200, 400, 800, 1_000]);
+        assert_eq!(policy.backoff(1, ErrorClass::Throttled).as_millis(), 200);
+    }
+
+    #[tokio::test]
+    async fn test_retries_until_max_attempts() {
+        let policy = no_backoff(3, None);
+        let (result, attempts) = fail_times(&policy, 2, socket_error).await;
+        assert!(result.is_ok());
+        assert_eq!(attempts, 3);
+
+        let (result, attempts) = fail_times(&policy, 5, socket_error).await;
+        assert!(matches!(result, Err(Error::SocketError { .. })));
+        assert_eq!(attempts, 3);
+    }
+
+    #[tokio::test]
+    async fn test_permanent_errors_are_not_retried() {
+        let policy = no_backoff(3, None);
+        let (result, attempts) = fail_times(&policy, 5, || Error::NotAFile {
+            path: "s3://bucket/".into(),
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_budget() {
+        // Throttled retries take two tokens, so the budget allows a single one
+        let policy = no_backoff(5, Some(3));
+        let (result, attempts) = fail_times(&policy, 5, throttled).await;
+        assert!(result.is_err());
+        assert_eq!(attempts, 2);
+
+        // One token is left, and a success gives back another one
+        let (result, attempts) = fail_times(&policy, 0, socket_error).await;
+        assert!(result.is_ok());
+        assert_eq!(attempts, 1);
+        let (result, attempts) = fail_times(&policy, 1, throttled).await;
+        assert!(result.is_ok());
+        assert_eq!(attempts, 2);
+    }
+}
@@ -0,0 +1,301 @@
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant, SystemTime},
+};
+
+use aws_credential_types::{
+    cache::{ProvideCachedCredentials, SharedCredentialsCache},
+    provider::{error::CredentialsError, future},
+    Credentials,
+};
+use aws_sdk_s3::{self as s3, client::customize::Operation, config::Region};
+use aws_sigv4::http_request::{
+    sign, PayloadChecksumKind, SignableRequest, SigningParams, SigningSettings,
+};
+use aws_smithy_types::{endpoint::Endpoint, Document};
+use snafu::{ResultExt, Snafu};
+
+/// Directory buckets are named `{base name}--{zone id}--x-s3`.
+const DIRECTORY_BUCKET_SUFFIX: &str = "--x-s3";
+const SIGNING_NAME: &str = "s3express";
+const SESSION_MODE_HEADER: &str = "x-amz-create-session-mode";
+const SESSION_TOKEN_HEADER: &str = "x-amz-s3session-token";
+
+/// Sessions expire 5 minutes after they are created, so they are renewed a minute before then.
+const SESSION_LIFETIME: Duration = Duration::from_secs(4 * 60);
+
+/// How many more connections are made to directory buckets than to general purpose buckets, as they are built to
+/// serve many more requests per second at lower latency.
+pub(crate) const DIRECTORY_BUCKET_CONNECTIONS_MULTIPLIER: usize = 4;
+
+#[derive(Debug, Snafu)]
+enum Error {
+    #[snafu(display(
+        "Unable to load credentials to create a session for {}: {}",
+        bucket,
+        source
+    ))]
+    UnableToLoadCredentials {
+        bucket: String,
+        source: CredentialsError,
+    },
+
+    #[snafu(display("Unable to sign request to create a session for {}: {}", bucket, msg))]
+    UnableToSignRequest { bucket: String, msg: String },
+
+    #[snafu(display("Unable to create a session for {}: {}", bucket, source))]
+    UnableToCreateSession {
+        bucket: String,
+        source: reqwest::Error,
+    },
+
+    #[snafu(display("Invalid session of {}, missing {}", bucket, element))]
+    InvalidSession { bucket: String, element: String },
+}
+
+impl From<Error> for super::Error {
+    fn from(error: Error) -> Self {
+        use Error::{UnableToCreateSession, UnableToLoadCredentials};
+        match error {
+            UnableToCreateSession { bucket, source } => match source.status().map(|v| v.as_u16()) {
+                Some(503) => Self::Throttled {
+                    path: bucket,
+                    source: source.into(),
+                },
+                Some(408 | 500 | 502 | 504) => Self::MiscTransient {
+                    path: bucket,
+                    source: source.into(),
+                },
+                None if source.is_timeout() => Self::ReadTimeout {
+                    path: bucket,
+                    source: source.into(),
+                },
+                None if source.is_connect() => Self::SocketError {
+                    path: bucket,
+                    source: source.into(),
+                },
+                None | Some(_) => Self::Unhandled {
+                    path: bucket,
+                    msg: source.to_string(),
+                },
+            },
+            UnableToLoadCredentials { source, .. } => Self::UnableToLoadCredentials {
+                store: super::SourceType::S3,
+                source: source.into(),
+            },
+            _ => Self::Generic {
+                store: super::SourceType::S3,
+                source: error.into(),
+            },
+        }
+    }
+}
+
+/// The availability zone of the S3 Express One Zone directory bucket named `bucket`, if it is one.
+pub(crate) fn directory_bucket_zone(bucket: &str) -> Option<&str> {
+    let (_, zone) = bucket
+        .strip_suffix(DIRECTORY_BUCKET_SUFFIX)?
+        .rsplit_once("--")?;
+    (!zone.is_empty()).then_some(zone)
+}
+
+/// The zonal endpoint that requests to a directory bucket are sent to.
+fn zonal_endpoint(bucket: &str, zone: &str, region: &Region) -> String {
+    format!("https://{bucket}.s3express-{zone}.{region}.amazonaws.com")
+}
+
+/// The text of the first `element` of `xml`, which is enough to read the few elements of the CreateSession response.
+fn xml_element<'a>(xml: &'a str, element: &str) -> Option<&'a str> {
+    let start = xml.find(&format!("<{element}>"))? + element.len() + 2;
+    let end = start + xml[start..].find(&format!("</{element}>"))?;
+    Some(&xml[start..end])
+}
+
+/// Credentials of a session, which are never refreshed, as a new session is created when they expire.
+#[derive(Debug)]
+struct SessionCredentials(Credentials);
+
+impl ProvideCachedCredentials for SessionCredentials {
+    fn provide_cached_credentials<'a>(&'a self) -> future::ProvideCredentials<'a>
+    where
+        Self: 'a,
+    {
+        future::ProvideCredentials::ready(Ok(self.0.clone()))
+    }
+}
+
+/// A session with a directory bucket, created with CreateSession, that requests to the bucket are authenticated with
+/// instead of signing each with the credentials of the client, which saves the bucket authorizing each of them.
+pub(crate) struct ExpressSession {
+    endpoint: Endpoint,
+    credentials: SharedCredentialsCache,
+}
+
+impl ExpressSession {
+    /// Sends `operation` to the zonal endpoint of the bucket of the session, signed with the session credentials.
+    pub fn apply<O, R>(&self, mut operation: Operation<O, R>) -> Operation<O, R> {
+        {
+            let mut properties = operation.properties_mut();
+            properties.insert::<aws_smithy_http::endpoint::Result>(Ok(self.endpoint.clone()));
+            properties.insert(self.credentials.clone());
+        }
+        operation
+    }
+}
+
+/// Sessions with the directory buckets that a client has made requests to, which are created on first use and
+/// recreated once expired.
+pub(crate) struct ExpressSessions {
+    http_client: reqwest::Client,
+    sessions: tokio::sync::Mutex<HashMap<String, (Instant, Arc<ExpressSession>)>>,
+}
+
+impl ExpressSessions {
+    pub fn new(connect_timeout: Duration) -> super::Result<Self> {
+        let http_client = reqwest::Client::builder()
+            .connect_timeout(connect_timeout)
+            .build()
+            .map_err(|err| super::Error::Generic {
+                store: super::SourceType::S3,
+                source: err.into(),
+            })?;
+        Ok(Self {
+            http_client,
+            sessions: tokio::sync::Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// The session to make requests to `bucket` with, if it is a directory bucket, created with the credentials of
+    /// `client`.
+    pub async fn get(
+        &self,
+        client: &s3::Client,
+        bucket: &str,
+        region: &Region,
+    ) -> super::Result<Option<Arc<ExpressSession>>> {
+        let Some(zone) = directory_bucket_zone(bucket) else {
+            return Ok(None);
+        };
+        let mut sessions = self.sessions.lock().await;
+        if let Some((created, session)) = sessions.get(bucket)
+            && created.elapsed() < SESSION_LIFETIME
+        {
+            return Ok(Some(session.clone()));
+        }
+
+        let created = Instant::now();
+        let session = Arc::new(self.create_session(client, bucket, zone, region).await?);
+        sessions.insert(bucket.to_string(), (created, session.clone()));
+        Ok(Some(session))
+    }
+
+    async fn create_session(
+        &self,
+        client: &s3::Client,
+        bucket: &str,
+        zone: &str,
+        region: &Region,
+    ) -> super::Result<ExpressSession> {
+        log::debug!("S3 Express create session for Bucket: {bucket} in region: {region}");
+        let endpoint = zonal_endpoint(bucket, zone, region);
+        let credentials = client
+            .conf()
+            .credentials_cache()
+            .provide_cached_credentials()
+            .await
+            .context(UnableToLoadCredentialsSnafu { bucket })?;
+
+        let sign_error = |msg: String| Error::UnableToSignRequest {
+            bucket: bucket.to_string(),
+            msg,
+        };
+        let mut request = http::Request::builder()
+            .method("GET")
+            .uri(format!("{endpoint}/?session"))
+            .header(SESSION_MODE_HEADER, "ReadWrite")
+            .body(vec![])
+            .map_err(|err| sign_error(err.to_string()))?;
+        let mut settings = SigningSettings::default();
+        settings.payload_checksum_kind = PayloadChecksumKind::XAmzSha256;
+        let mut params = SigningParams::builder()
+            .access_key(credentials.access_key_id())
+            .secret_key(credentials.secret_access_key())
+            .region(region.as_ref())
+            .service_name(SIGNING_NAME)
+            .time(SystemTime::now())
+            .settings(settings);
+        params.set_security_token(credentials.session_token());
+        let params = params.build().map_err(|err| sign_error(err.to_string()))?;
+        let (instructions, _) = sign(SignableRequest::from(&request), &params)
+            .map_err(|err| sign_error(err.to_string()))?
+            .into_parts();
+        instructions.apply_to_request(&mut request);
+
+        let request =
+            reqwest::Request::try_from(request).context(UnableToCreateSessionSnafu { bucket })?;
+        let response = self
+            .http_client
+            .execute(request)
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .context(UnableToCreateSessionSnafu { bucket })?;
+        let body = response
+            .text()
+            .await
+            .context(UnableToCreateSessionSnafu { bucket })?;
+
+        let element = |element: &str| {
+            xml_element(&body, element).ok_or_else(|| Error::InvalidSession {
+                bucket: bucket.to_string(),
+                element: element.to_string(),
+            })
+        };
+        let session_credentials =
+            Credentials::from_keys(element("AccessKeyId")?, element("SecretAccessKey")?, None);
+        let endpoint = Endpoint::builder()
+            .url(endpoint)
+            .header(SESSION_TOKEN_HEADER, element("SessionToken")?.to_string())
+            .property(
+                "authSchemes",
+                vec![Document::Object(HashMap::from([
+                    ("name".to_string(), "sigv4".into()),
+                    ("signingName".to_string(), SIGNING_NAME.into()),
+                    ("signingRegion".to_string(), region.to_string().into()),
+                ]))],
+            )
+            .build();
+        Ok(ExpressSession {
+            endpoint,
+            credentials: SharedCredentialsCache::new(SessionCredentials(session_credentials)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{directory_bucket_zone, xml_element};
+
+    #[test]
+    fn test_directory_bucket_zone() {
+        assert_eq!(
+            directory_bucket_zone("staging--usw2-az1--x-s3"),
+            Some("usw2-az1")
+        );
+        assert_eq!(
+            directory_bucket_zone("my--staging--use1-az4--x-s3"),
+            Some("use1-az4")
+        );
+        assert_eq!(directory_bucket_zone("staging"), None);
+        assert_eq!(directory_bucket_zone("staging--x-s3"), None);
+    }
+
+    #[test]
+    fn test_xml_element() {
+        let xml = "<CreateSessionResult><Credentials><SessionToken>token</SessionToken>\
+            <AccessKeyId>key</AccessKeyId></Credentials></CreateSessionResult>";
+        assert_eq!(xml_element(xml, "SessionToken"), Some("token"));
+        assert_eq!(xml_element(xml, "AccessKeyId"), Some("key"));
+        assert_eq!(xml_element(xml, "SecretAccessKey"), None);
+    }
+}
@@ -1,4 +1,10 @@
-use std::{collections::HashMap, ops::Range, string::FromUtf8Error, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    ops::Range,
+    string::FromUtf8Error,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use async_recursion::async_recursion;
 use async_trait::async_trait;
@@ -25,18 +31,28 @@ use s3::{
     config::{Credentials, Region},
     error::{DisplayErrorContext, SdkError},
     operation::{
-        get_object::GetObjectError, head_object::HeadObjectError,
-        list_objects_v2::ListObjectsV2Error,
+        abort_multipart_upload::AbortMultipartUploadError,
+        complete_multipart_upload::CompleteMultipartUploadError,
+        create_multipart_upload::CreateMultipartUploadError, get_object::GetObjectError,
+        head_object::HeadObjectError, list_objects_v2::ListObjectsV2Error,
+        upload_part::UploadPartError,
     },
+    types::{CompletedMultipartUpload, CompletedPart},
 };
 use snafu::{ensure, IntoError, ResultExt, Snafu};
+use time::OffsetDateTime;
 use tokio::sync::{OwnedSemaphorePermit, SemaphorePermit};
 use url::{ParseError, Position};
 
 use super::object_io::{GetResult, ObjectSource};
 use crate::{
-    object_io::{FileMetadata, FileType, LSResult},
+    object_io::{FileMetadata, FileType, LSResult, MultipartUpload},
+    object_store_glob::GlobOptions,
     retry::{ExponentialBackoff, RetryError},
+    s3_express::{
+        directory_bucket_zone, ExpressSession, ExpressSessions,
+        DIRECTORY_BUCKET_CONNECTIONS_MULTIPLIER,
+    },
     stats::IOStatsRef,
     stream_utils::io_stats_on_bytestream,
     FileFormat, InvalidArgumentSnafu, SourceType,
@@ -47,11 +63,18 @@ const DEFAULT_GLOB_FANOUT_LIMIT: usize = 1024;
 pub struct S3LikeSource {
     region_to_client_map: tokio::sync::RwLock<HashMap<Region, Arc<s3::Client>>>,
     connection_pool_sema: Arc<tokio::sync::Semaphore>,
+    directory_bucket_connection_pool_sema: Arc<tokio::sync::Semaphore>,
+    express_sessions: Option<Arc<ExpressSessions>>,
     default_region: Region,
     s3_config: S3Config,
     anonymous: bool,
 }
 
+struct ObjectHead {
+    size: usize,
+    e_tag: Option<String>,
+}
+
 #[derive(Debug, Snafu)]
 enum Error {
     #[snafu(display("Unable to open {}: {}", path, s3::error::DisplayErrorContext(source)))]
@@ -70,6 +93,48 @@ enum Error {
         source: SdkError<PutObjectError, Response>,
     },
 
+    #[snafu(display(
+        "Unable to start multipart upload to {}: {}",
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToCreateMultipartUpload {
+        path: String,
+        source: SdkError<CreateMultipartUploadError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to upload part {} to {}: {}",
+        part_number,
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToUploadPart {
+        path: String,
+        part_number: usize,
+        source: SdkError<UploadPartError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to complete multipart upload to {}: {}",
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToCompleteMultipartUpload {
+        path: String,
+        source: SdkError<CompleteMultipartUploadError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to abort multipart upload to {}: {}",
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToAbortMultipartUpload {
+        path: String,
+        source: SdkError<AbortMultipartUploadError, Response>,
+    },
+
     #[snafu(display("Unable to head {}: {}", path, s3::error::DisplayErrorContext(source)))]
     UnableToHeadFile {
         path: String,
@@ -154,10 +219,12 @@ impl From<Error> for super::Error {
             err: E,
         ) -> super::Error {
             match err.code() {
-                Some("InternalError") => super::Error::MiscTransient {
-                    path,
-                    source: err.into(),
-                },
+                Some("InternalError" | "ServiceUnavailable" | "RequestTimeout") => {
+                    super::Error::MiscTransient {
+                        path,
+                        source: err.into(),
+                    }
+                }
                 Some(code) if THROTTLING_ERRORS.contains(&code) => super::Error::Throttled {
                     path,
                     source: err.into(),
@@ -561,12 +628,23 @@ async fn build_client(config: &S3Config) -> super::Result<S3LikeSource> {
     let mut client_map = HashMap::new();
     let default_region = client.conf().region().unwrap().clone();
     client_map.insert(default_region.clone(), client.into());
+    let max_connections = (config.max_connections_per_io_thread as usize)
+        * get_io_pool_num_threads().expect("Should be running in tokio pool");
+    // Sessions are only created with the zonal endpoints of AWS, which requests must be signed for
+    let express_sessions = if anonymous || config.endpoint_url.is_some() {
+        None
+    } else {
+        Some(Arc::new(ExpressSessions::new(Duration::from_millis(
+            config.connect_timeout_ms,
+        ))?))
+    };
     Ok(S3LikeSource {
         region_to_client_map: tokio::sync::RwLock::new(client_map),
-        connection_pool_sema: Arc::new(tokio::sync::Semaphore::new(
-            (config.max_connections_per_io_thread as usize)
-                * get_io_pool_num_threads().expect("Should be running in tokio pool"),
+        connection_pool_sema: Arc::new(tokio::sync::Semaphore::new(max_connections)),
+        directory_bucket_connection_pool_sema: Arc::new(tokio::sync::Semaphore::new(
+            max_connections * DIRECTORY_BUCKET_CONNECTIONS_MULTIPLIER,
         )),
+        express_sessions,
         s3_config: config.clone(),
         default_region,
         anonymous,
@@ -607,6 +685,31 @@ impl S3LikeSource {
         Ok(w_handle.get(region).unwrap().clone())
     }
 
+    /// The pool of connections to `bucket`, which is larger for directory buckets.
+    fn connection_pool_sema_for(&self, bucket: &str) -> &Arc<tokio::sync::Semaphore> {
+        if directory_bucket_zone(bucket).is_some() {
+            &self.directory_bucket_connection_pool_sema
+        } else {
+            &self.connection_pool_sema
+        }
+    }
+
+    /// The S3 Express session to make requests to `bucket` with, if it is a directory bucket.
+    async fn express_session(
+        &self,
+        bucket: &str,
+        region: &Region,
+    ) -> super::Result<Option<Arc<ExpressSession>>> {
+        match &self.express_sessions {
+            Some(sessions) => {
+                sessions
+                    .get(&*self.get_s3_client(region).await?, bucket, region)
+                    .await
+            }
+            None => Ok(None),
+        }
+    }
+
     #[async_recursion]
     async fn get_impl(
         &self,
@@ -626,7 +729,7 @@ impl S3LikeSource {
                 .get_s3_client(region)
                 .await?
                 .get_object()
-                .bucket(bucket)
+                .bucket(&bucket)
                 .key(key);
 
             let request = if self.s3_config.requester_pays {
@@ -664,6 +767,15 @@ impl S3LikeSource {
                     .unwrap()
                     .send()
                     .await
+            } else if let Some(session) = self.express_session(&bucket, region).await? {
+                request
+                    .customize_middleware()
+                    .await
+                    .unwrap()
+                    .map_operation::<Error>(|o| Ok(session.apply(o)))
+                    .unwrap()
+                    .send()
+                    .await
             } else {
                 request.send().await
             };
@@ -725,7 +837,7 @@ impl S3LikeSource {
         permit: SemaphorePermit<'async_recursion>,
         uri: &str,
         region: &Region,
-    ) -> super::Result<usize> {
+    ) -> super::Result<ObjectHead> {
         log::debug!("S3 head at {uri} in region: {region}");
         let (_scheme, bucket, key) = parse_url(uri)?;
 
@@ -737,7 +849,7 @@ impl S3LikeSource {
                 .get_s3_client(region)
                 .await?
                 .head_object()
-                .bucket(bucket)
+                .bucket(&bucket)
                 .key(key);
 
             let request = if self.s3_config.requester_pays {
@@ -766,12 +878,24 @@ impl S3LikeSource {
                     .unwrap()
                     .send()
                     .await
+            } else if let Some(session) = self.express_session(&bucket, region).await? {
+                request
+                    .customize_middleware()
+                    .await
+                    .unwrap()
+                    .map_operation::<Error>(|o| Ok(session.apply(o)))
+                    .unwrap()
+                    .send()
+                    .await
             } else {
                 request.send().await
             };
 
             match response {
-                Ok(v) => Ok(v.content_length() as usize),
+                Ok(v) => Ok(ObjectHead {
+                    size: v.content_length() as usize,
+                    e_tag: v.e_tag().map(str::to_string),
+                }),
                 Err(SdkError::ServiceError(err)) => {
                     let bad_response = err.raw().http();
                     match bad_response.status() {
@@ -816,12 +940,19 @@ impl S3LikeSource {
         page_size: Option<i32>,
     ) -> super::Result<LSResult> {
         log::debug!("S3 list_objects: Bucket: {bucket}, Key: {key}, continuation_token: {continuation_token:?} in region: {region}");
+        // Directory buckets can only list prefixes that end in the delimiter, so other prefixes are listed from their
+        // directory, and the entries of it that do not start with the prefix are skipped
+        let prefix = if directory_bucket_zone(bucket).is_some() && !key.ends_with(S3_DELIMITER) {
+            key.rfind(S3_DELIMITER).map_or("", |i| &key[..=i])
+        } else {
+            key
+        };
         let request = self
             .get_s3_client(region)
             .await?
             .list_objects_v2()
             .bucket(bucket)
-            .prefix(key);
+            .prefix(prefix);
         let request = if let Some(delimiter) = delimiter.as_ref() {
             request.delimiter(delimiter)
         } else {
@@ -863,6 +994,15 @@ impl S3LikeSource {
                 .unwrap()
                 .send()
                 .await
+        } else if let Some(session) = self.express_session(bucket, region).await? {
+            request
+                .customize_middleware()
+                .await
+                .unwrap()
+                .map_operation::<Error>(|o| Ok(session.apply(o)))
+                .unwrap()
+                .send()
+                .await
         } else {
             request.send().await
         };
@@ -884,6 +1024,9 @@ impl S3LikeSource {
                 let mut all_files = Vec::with_capacity(total_len);
                 if let Some(dirs) = dirs {
                     for d in dirs {
+                        if !d.prefix().unwrap_or_default().starts_with(key) {
+                            continue;
+                        }
                         let fmeta = FileMetadata {
                             filepath: format!(
                                 "{scheme}://{bucket}/{}",
@@ -891,12 +1034,19 @@ impl S3LikeSource {
                             ),
                             size: None,
                             filetype: FileType::Directory,
+                            last_modified: None,
+                            etag: None,
+                            version_id: None,
+                            storage_class: None,
                         };
                         all_files.push(fmeta);
                     }
                 }
                 if let Some(files) = files {
                     for f in files {
+                        if !f.key().unwrap_or_default().starts_with(key) {
+                            continue;
+                        }
                         let fmeta = FileMetadata {
                             filepath: format!(
                                 "{scheme}://{bucket}/{}",
@@ -904,6 +1054,16 @@ impl S3LikeSource {
                             ),
                             size: Some(f.size() as u64),
                             filetype: FileType::File,
+                            last_modified: f.last_modified().and_then(|t| {
+                                OffsetDateTime::from_unix_timestamp_nanos(
+                                    i128::from(t.secs()) * 1_000_000_000
+                                        + i128::from(t.subsec_nanos()),
+                                )
+                                .ok()
+                            }),
+                            etag: f.e_tag().map(ToString::to_string),
+                            version_id: None,
+                            storage_class: f.storage_class().map(|c| c.as_str().to_string()),
                         };
                         all_files.push(fmeta);
                     }
@@ -955,13 +1115,16 @@ impl S3LikeSource {
     }
 
     #[async_recursion]
+    /// Uploads `data` to `uri`, or only if no object exists there yet if `if_absent` is set, returning whether it was
+    /// uploaded.
     async fn put_impl(
         &self,
         _permit: OwnedSemaphorePermit,
         uri: &str,
         data: bytes::Bytes,
         region: &Region,
-    ) -> super::Result<()> {
+        if_absent: bool,
+    ) -> super::Result<bool> {
         log::debug!(
             "S3 put at {uri}, num_bytes: {}, in region: {region}",
             data.len()
@@ -976,9 +1139,15 @@ impl S3LikeSource {
                 .get_s3_client(region)
                 .await?
                 .put_object()
-                .body(data.into())
-                .bucket(bucket)
+                .bucket(&bucket)
                 .key(key);
+            // Directory buckets do not accept Content-MD5
+            let request = if directory_bucket_zone(&bucket).is_none() {
+                request.content_md5(content_md5(&data))
+            } else {
+                request
+            };
+            let request = request.body(data.into());
 
             let request = if self.s3_config.requester_pays {
                 request.request_payer(s3::types::RequestPayer::Requester)
@@ -986,20 +1155,217 @@ impl S3LikeSource {
                 request
             };
 
-            let response = if self.anonymous {
+            if self.anonymous {
                 return Err(Error::UploadsCannotBeAnonymous {}.into());
+            }
+            let session = self.express_session(&bucket, region).await?;
+            let response = if session.is_some() || if_absent {
+                request
+                    .customize_middleware()
+                    .await
+                    .unwrap()
+                    .map_operation::<Error>(|mut o| {
+                        if if_absent {
+                            o.request_mut().headers_mut().insert(
+                                http::header::IF_NONE_MATCH,
+                                http::HeaderValue::from_static("*"),
+                            );
+                        }
+                        Ok(match &session {
+                            Some(session) => session.apply(o),
+                            None => o,
+                        })
+                    })
+                    .unwrap()
+                    .send()
+                    .await
             } else {
                 request.send().await
             };
 
             match response {
-                Ok(_) => Ok(()),
+                Ok(_) => Ok(true),
+                // The object exists, or another conditional upload of it is in progress
+                Err(err)
+                    if if_absent
+                        && err.raw_response().is_some_and(|response| {
+                            matches!(response.http().status().as_u16(), 409 | 412)
+                        }) =>
+                {
+                    Ok(false)
+                }
                 Err(err) => Err(UnableToPutFileSnafu { path: uri }.into_error(err).into()),
             }
         }
     }
 }
 
+/// The base64 encoded MD5 digest of `data`, which S3 validates uploaded data against when sent as `Content-MD5`, so
+/// that data corrupted on its way is rejected rather than stored.
+fn content_md5(data: &[u8]) -> String {
+    use base64::Engine;
+
+    base64::engine::general_purpose::STANDARD.encode(md5::compute(data).0)
+}
+
+/// A multipart upload to S3, whose parts must be at least 5MiB, except for the last.
+struct S3MultipartUpload {
+    client: Arc<s3::Client>,
+    connection_pool_sema: Arc<tokio::sync::Semaphore>,
+    express_sessions: Option<Arc<ExpressSessions>>,
+    region: Region,
+    uri: String,
+    bucket: String,
+    key: String,
+    upload_id: String,
+    requester_pays: bool,
+    io_stats: Option<IOStatsRef>,
+    completed_parts: Mutex<Vec<CompletedPart>>,
+}
+
+impl S3MultipartUpload {
+    /// The S3 Express session to make requests with, if the upload is to a directory bucket.
+    async fn express_session(&self) -> super::Result<Option<Arc<ExpressSession>>> {
+        match &self.express_sessions {
+            Some(sessions) => sessions.get(&self.client, &self.bucket, &self.region).await,
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl MultipartUpload for S3MultipartUpload {
+    async fn upload_part(&self, part_number: usize, data: bytes::Bytes) -> super::Result<()> {
+        let _permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        let data_len = data.len();
+        log::debug!(
+            "S3 upload part {part_number} of {}, num_bytes: {data_len}",
+            self.uri
+        );
+        let request = self
+            .client
+            .upload_part()
+            .bucket(&self.bucket)
+            .key(&self.key)
+            .upload_id(&self.upload_id)
+            .part_number(part_number as i32);
+        // Directory buckets do not accept Content-MD5
+        let request = if directory_bucket_zone(&self.bucket).is_none() {
+            request.content_md5(content_md5(&data))
+        } else {
+            request
+        };
+        let request = request.body(data.into());
+        let request = if self.requester_pays {
+            request.request_payer(s3::types::RequestPayer::Requester)
+        } else {
+            request
+        };
+        let response = match self.express_session().await? {
+            Some(session) => {
+                request
+                    .customize_middleware()
+                    .await
+                    .unwrap()
+                    .map_operation::<Error>(|o| Ok(session.apply(o)))
+                    .unwrap()
+                    .send()
+                    .await
+            }
+            None => request.send().await,
+        }
+        .with_context(|_| UnableToUploadPartSnafu {
+            path: &self.uri,
+            part_number,
+        })?;
+        let e_tag = response.e_tag().ok_or_else(|| Error::MissingHeader {
+            path: self.uri.clone(),
+            header: "ETag".into(),
+        })?;
+        self.completed_parts.lock().unwrap().push(
+            CompletedPart::builder()
+                .e_tag(e_tag)
+                .part_number(part_number as i32)
+                .build(),
+        );
+
+        if let Some(io_stats) = &self.io_stats {
+            io_stats.mark_put_requests(1);
+            io_stats.mark_bytes_uploaded(data_len);
+        }
+        Ok(())
+    }
+
+    async fn complete(&self) -> super::Result<()> {
+        let mut parts = std::mem::take(&mut *self.completed_parts.lock().unwrap());
+        parts.sort_by_key(CompletedPart::part_number);
+        let request = self
+            .client
+            .complete_multipart_upload()
+            .bucket(&self.bucket)
+            .key(&self.key)
+            .upload_id(&self.upload_id)
+            .multipart_upload(
+                CompletedMultipartUpload::builder()
+                    .set_parts(Some(parts))
+                    .build(),
+            );
+        let request = if self.requester_pays {
+            request.request_payer(s3::types::RequestPayer::Requester)
+        } else {
+            request
+        };
+        match self.express_session().await? {
+            Some(session) => {
+                request
+                    .customize_middleware()
+                    .await
+                    .unwrap()
+                    .map_operation::<Error>(|o| Ok(session.apply(o)))
+                    .unwrap()
+                    .send()
+                    .await
+            }
+            None => request.send().await,
+        }
+        .with_context(|_| UnableToCompleteMultipartUploadSnafu { path: &self.uri })?;
+        Ok(())
+    }
+
+    async fn abort(&self) -> super::Result<()> {
+        let request = self
+            .client
+            .abort_multipart_upload()
+            .bucket(&self.bucket)
+            .key(&self.key)
+            .upload_id(&self.upload_id);
+        let request = if self.requester_pays {
+            request.request_payer(s3::types::RequestPayer::Requester)
+        } else {
+            request
+        };
+        match self.express_session().await? {
+            Some(session) => {
+                request
+                    .customize_middleware()
+                    .await
+                    .unwrap()
+                    .map_operation::<Error>(|o| Ok(session.apply(o)))
+                    .unwrap()
+                    .send()
+                    .await
+            }
+            None => request.send().await,
+        }
+        .with_context(|_| UnableToAbortMultipartUploadSnafu { path: &self.uri })?;
+        Ok(())
+    }
+}
+
 #[async_trait]
 impl ObjectSource for S3LikeSource {
     async fn get(
@@ -1008,8 +1374,9 @@ impl ObjectSource for S3LikeSource {
         range: Option<Range<usize>>,
         io_stats: Option<IOStatsRef>,
     ) -> super::Result<GetResult> {
+        let (_scheme, bucket, _key) = parse_url(uri)?;
         let permit = self
-            .connection_pool_sema
+            .connection_pool_sema_for(&bucket)
             .clone()
             .acquire_owned()
             .await
@@ -1044,13 +1411,14 @@ impl ObjectSource for S3LikeSource {
         io_stats: Option<IOStatsRef>,
     ) -> super::Result<()> {
         let data_len = data.len();
+        let (_scheme, bucket, _key) = parse_url(uri)?;
         let permit = self
-            .connection_pool_sema
+            .connection_pool_sema_for(&bucket)
             .clone()
             .acquire_owned()
             .await
             .context(UnableToGrabSemaphoreSnafu)?;
-        self.put_impl(permit, uri, data, &self.default_region)
+        self.put_impl(permit, uri, data, &self.default_region, false)
             .await?;
 
         if let Some(io_stats) = io_stats {
@@ -1061,9 +1429,111 @@ impl ObjectSource for S3LikeSource {
         Ok(())
     }
 
+    async fn put_if_absent(
+        &self,
+        uri: &str,
+        data: bytes::Bytes,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<bool>> {
+        let data_len = data.len();
+        let (_scheme, bucket, _key) = parse_url(uri)?;
+        let permit = self
+            .connection_pool_sema_for(&bucket)
+            .clone()
+            .acquire_owned()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        let uploaded = self
+            .put_impl(permit, uri, data, &self.default_region, true)
+            .await?;
+
+        if let Some(io_stats) = io_stats {
+            io_stats.as_ref().mark_put_requests(1);
+            if uploaded {
+                io_stats.as_ref().mark_bytes_uploaded(data_len);
+            }
+        }
+
+        Ok(Some(uploaded))
+    }
+
+    async fn create_multipart_upload(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<Arc<dyn MultipartUpload>>> {
+        if self.anonymous {
+            return Err(Error::UploadsCannotBeAnonymous {}.into());
+        }
+        let (_scheme, bucket, key) = parse_url(uri)?;
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+        let client = self.get_s3_client(&self.default_region).await?;
+        let request = client.create_multipart_upload().bucket(&bucket).key(&key);
+        let request = if self.s3_config.requester_pays {
+            request.request_payer(s3::types::RequestPayer::Requester)
+        } else {
+            request
+        };
+        let response = match self.express_session(&bucket, &self.default_region).await? {
+            Some(session) => {
+                request
+                    .customize_middleware()
+                    .await
+                    .unwrap()
+                    .map_operation::<Error>(|o| Ok(session.apply(o)))
+                    .unwrap()
+                    .send()
+                    .await
+            }
+            None => request.send().await,
+        }
+        .with_context(|_| UnableToCreateMultipartUploadSnafu { path: uri })?;
+        let upload_id = response
+            .upload_id()
+            .ok_or_else(|| Error::MissingHeader {
+                path: uri.into(),
+                header: "UploadId".into(),
+            })?
+            .to_string();
+        Ok(Some(Arc::new(S3MultipartUpload {
+            client,
+            connection_pool_sema: self.connection_pool_sema_for(&bucket).clone(),
+            express_sessions: self.express_sessions.clone(),
+            region: self.default_region.clone(),
+            uri: uri.to_string(),
+            bucket,
+            key,
+            upload_id,
+            requester_pays: self.s3_config.requester_pays,
+            io_stats,
+            completed_parts: Mutex::new(vec![]),
+        })))
+    }
+
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+        let (_scheme, bucket, _key) = parse_url(uri)?;
         let permit = self
-            .connection_pool_sema
+            .connection_pool_sema_for(&bucket)
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        let head_result = self.head_impl(permit, uri, &self.default_region).await?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_head_requests(1);
+        }
+        Ok(head_result.size)
+    }
+
+    async fn get_version(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<String>> {
+        let (_scheme, bucket, _key) = parse_url(uri)?;
+        let permit = self
+            .connection_pool_sema_for(&bucket)
             .acquire()
             .await
             .context(UnableToGrabSemaphoreSnafu)?;
@@ -1071,7 +1541,7 @@ impl ObjectSource for S3LikeSource {
         if let Some(is) = io_stats.as_ref() {
             is.mark_head_requests(1);
         }
-        Ok(head_result)
+        Ok(head_result.e_tag)
     }
 
     async fn glob(
@@ -1082,6 +1552,7 @@ impl ObjectSource for S3LikeSource {
         limit: Option<usize>,
         io_stats: Option<IOStatsRef>,
         _file_format: Option<FileFormat>,
+        options: &GlobOptions,
     ) -> super::Result<BoxStream<'static, super::Result<FileMetadata>>> {
         use crate::object_store_glob::glob;
 
@@ -1095,6 +1566,7 @@ impl ObjectSource for S3LikeSource {
             page_size.or(Some(1000)),
             limit,
             io_stats,
+            options,
         )
         .await
     }
@@ -1119,7 +1591,7 @@ impl ObjectSource for S3LikeSource {
             };
             let lsr = {
                 let permit = self
-                    .connection_pool_sema
+                    .connection_pool_sema_for(&bucket)
                     .acquire()
                     .await
                     .context(UnableToGrabSemaphoreSnafu)?;
@@ -1142,12 +1614,35 @@ impl ObjectSource for S3LikeSource {
 
             if lsr.files.is_empty() && key.contains(S3_DELIMITER) {
                 let permit = self
-                    .connection_pool_sema
+                    .connection_pool_sema_for(&bucket)
                     .acquire()
                     .await
                     .context(UnableToGrabSemaphoreSnafu)?;
                 // Might be a File
                 let key = key.trim_end_matches(S3_DELIMITER);
+                let target_path = format!("{scheme}://{bucket}/{key}");
+                if directory_bucket_zone(&bucket).is_some() {
+                    // Listings of directory buckets are not sorted, so the file could be on any page of the listing of
+                    // its directory, and is looked up directly instead
+                    let head = self
+                        .head_impl(permit, &target_path, &self.default_region)
+                        .await?;
+                    if let Some(is) = io_stats.as_ref() {
+                        is.mark_head_requests(1);
+                    }
+                    return Ok(LSResult {
+                        files: vec![FileMetadata {
+                            filepath: target_path,
+                            size: Some(head.size as u64),
+                            filetype: FileType::File,
+                            last_modified: None,
+                            etag: None,
+                            version_id: None,
+                            storage_class: None,
+                        }],
+                        continuation_token: None,
+                    });
+                }
                 let mut lsr = self
                     .list_impl(
                         permit,
@@ -1163,7 +1658,6 @@ impl ObjectSource for S3LikeSource {
                 if let Some(is) = io_stats.as_ref() {
                     is.mark_list_requests(1);
                 }
-                let target_path = format!("{scheme}://{bucket}/{key}");
                 lsr.files.retain(|f| f.filepath == target_path);
 
                 if lsr.files.is_empty() {
@@ -1178,7 +1672,7 @@ impl ObjectSource for S3LikeSource {
             // Perform a prefix-based list of all entries with this prefix
             let lsr = {
                 let permit = self
-                    .connection_pool_sema
+                    .connection_pool_sema_for(&bucket)
                     .acquire()
                     .await
                     .context(UnableToGrabSemaphoreSnafu)?;
@@ -1208,8 +1702,18 @@ impl ObjectSource for S3LikeSource {
 mod tests {
     use common_io_config::S3Config;
 
+    use super::content_md5;
     use crate::{object_io::ObjectSource, Result, S3LikeSource};
 
+    #[test]
+    fn test_content_md5() {
+        assert_eq!(content_md5(b""), "1B2M2Y8AsgTpgAmY7PhCfg==");
+        assert_eq!(
+            content_md5(b"The quick brown fox jumps over the lazy dog"),
+            "nhB9nTcrtoJr2B01QqQZ1g=="
+        );
+    }
+
     #[tokio::test]
     async fn test_full_get_from_s3() -> Result<()> {
         let parquet_file_path = "s3://daft-public-data/test_fixtures/parquet_small/0dad4c3f-da0d-49db-90d8-98684571391b-0.parquet";
@@ -0,0 +1,664 @@
+use std::{collections::HashMap, io::SeekFrom, ops::Range, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use common_io_config::SFTPConfig;
+use futures::{stream::BoxStream, StreamExt, TryStreamExt};
+use russh::{
+    client::{self, Handle},
+    keys::{self, key},
+};
+use russh_sftp::{
+    client::{error::Error as SftpError, SftpSession},
+    protocol::StatusCode,
+};
+use snafu::{IntoError, ResultExt, Snafu};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use url::Url;
+
+use super::object_io::{GetResult, ObjectSource};
+use crate::{
+    object_io::{FileMetadata, FileType, LSResult},
+    object_store_glob::GlobOptions,
+    stats::IOStatsRef,
+    stream_utils::io_stats_on_bytestream,
+    FileFormat,
+};
+
+const SFTP_DELIMITER: &str = "/";
+const SFTP_PROTOCOL: &str = "sftp://";
+const DEFAULT_PORT: u16 = 22;
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+/// Size of the reads of files, which is about the largest read that servers answer in full.
+const READ_CHUNK_SIZE: usize = 256 * 1024;
+
+#[derive(Debug, Snafu)]
+enum Error {
+    #[snafu(display("Unable to connect to {}: {}", host, source))]
+    UnableToConnect { host: String, source: russh::Error },
+
+    #[snafu(display("Timed out connecting to {}", host))]
+    ConnectTimeout { host: String },
+
+    #[snafu(display(
+        "Key of {} is not known, please add it to the known_hosts file or set `known_hosts_path` in the SFTPConfig",
+        host
+    ))]
+    UnknownHostKey { host: String },
+
+    #[snafu(display("Unable to load private key: {}", source))]
+    UnableToLoadKey { source: keys::Error },
+
+    #[snafu(display(
+        "Unable to authenticate to {} as {}, please set a `private_key` or `password` in the SFTPConfig",
+        host,
+        user
+    ))]
+    UnableToAuthenticate { host: String, user: String },
+
+    #[snafu(display("Unable to start an SFTP session with {}: {}", host, msg))]
+    UnableToStartSession { host: String, msg: String },
+
+    #[snafu(display("Unable to open {}: {}", path, source))]
+    UnableToOpenFile { path: String, source: SftpError },
+
+    #[snafu(display("Unable to read data from {}: {}", path, source))]
+    UnableToReadBytes {
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[snafu(display("Unable to create {}: {}", path, source))]
+    UnableToCreateFile { path: String, source: SftpError },
+
+    #[snafu(display("Unable to write data to {}: {}", path, source))]
+    UnableToWriteBytes {
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[snafu(display("Unable to list {}: {}", path, source))]
+    UnableToList { path: String, source: SftpError },
+
+    #[snafu(display("Unable to parse URL: \"{}\"", path))]
+    InvalidUrl {
+        path: String,
+        source: url::ParseError,
+    },
+
+    #[snafu(display("SFTP path {} has no host", path))]
+    MissingHost { path: String },
+
+    #[snafu(display(
+        "SFTP path {} has no user, please name one in the path or set `user` in the SFTPConfig",
+        path
+    ))]
+    MissingUser { path: String },
+
+    #[snafu(display("Not a File: \"{}\"", path))]
+    NotAFile { path: String },
+}
+
+impl From<Error> for super::Error {
+    fn from(error: Error) -> Self {
+        use Error::{
+            ConnectTimeout, NotAFile, UnableToAuthenticate, UnableToConnect, UnableToCreateFile,
+            UnableToList, UnableToOpenFile, UnableToReadBytes, UnableToWriteBytes,
+        };
+        match error {
+            UnableToOpenFile { path, source }
+            | UnableToCreateFile { path, source }
+            | UnableToList { path, source } => match source {
+                SftpError::Status(ref status) if status.status_code == StatusCode::NoSuchFile => {
+                    Self::NotFound {
+                        path,
+                        source: source.into(),
+                    }
+                }
+                SftpError::Status(ref status)
+                    if status.status_code == StatusCode::PermissionDenied =>
+                {
+                    Self::Unauthorized {
+                        store: super::SourceType::SFTP,
+                        path,
+                        source: source.into(),
+                    }
+                }
+                SftpError::Timeout => Self::ReadTimeout {
+                    path,
+                    source: source.into(),
+                },
+                SftpError::IO(_) => Self::SocketError {
+                    path,
+                    source: source.into(),
+                },
+                _ => Self::UnableToOpenFile {
+                    path,
+                    source: source.into(),
+                },
+            },
+            UnableToConnect { host, source } => match source {
+                russh::Error::IO(_)
+                | russh::Error::ConnectionTimeout
+                | russh::Error::Disconnect => Self::SocketError {
+                    path: host,
+                    source: source.into(),
+                },
+                _ => Self::UnableToCreateClient {
+                    store: super::SourceType::SFTP,
+                    source: UnableToConnect { host, source }.into(),
+                },
+            },
+            ConnectTimeout { ref host } => Self::ConnectTimeout {
+                path: host.clone(),
+                source: error.into(),
+            },
+            UnableToAuthenticate { ref host, .. } => Self::Unauthorized {
+                store: super::SourceType::SFTP,
+                path: host.clone(),
+                source: error.into(),
+            },
+            UnableToReadBytes { path, source } => Self::UnableToReadBytes { path, source },
+            UnableToWriteBytes { path, source } => Self::UnableToWriteToFile { path, source },
+            NotAFile { path } => Self::NotAFile { path },
+            _ => Self::Generic {
+                store: super::SourceType::SFTP,
+                source: error.into(),
+            },
+        }
+    }
+}
+
+/// A server, and the user that is logged in to it as.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ServerLogin {
+    host: String,
+    port: u16,
+    user: String,
+}
+
+/// Splits `uri` into the server that it is on and the absolute path of the file on the server. Paths are taken as
+/// they are rather than decoded, like those of local files.
+fn parse_sftp_uri(config: &SFTPConfig, uri: &str) -> super::Result<(ServerLogin, String)> {
+    let rest = uri
+        .strip_prefix(SFTP_PROTOCOL)
+        .ok_or_else(|| Error::MissingHost { path: uri.into() })?;
+    let (authority, path) = match rest.find(SFTP_DELIMITER) {
+        Some(i) => rest.split_at(i),
+        None => (rest, SFTP_DELIMITER),
+    };
+    let url = Url::parse(&format!("{SFTP_PROTOCOL}{authority}"))
+        .with_context(|_| InvalidUrlSnafu { path: uri })?;
+    let host = url
+        .host_str()
+        .filter(|host| !host.is_empty())
+        .ok_or_else(|| Error::MissingHost { path: uri.into() })?;
+    let user = match url.username() {
+        "" => config
+            .user
+            .clone()
+            .ok_or_else(|| Error::MissingUser { path: uri.into() })?,
+        user => user.to_string(),
+    };
+    Ok((
+        ServerLogin {
+            host: host.to_string(),
+            port: url.port().unwrap_or(DEFAULT_PORT),
+            user,
+        },
+        path.to_string(),
+    ))
+}
+
+/// Checks the keys of servers against the `known_hosts` file, unless configured not to.
+struct HostKeyCheck {
+    host: String,
+    port: u16,
+    known_hosts_path: Option<String>,
+    skip: bool,
+}
+
+#[async_trait]
+impl client::Handler for HostKeyCheck {
+    type Error = russh::Error;
+
+    async fn check_server_key(
+        &mut self,
+        server_public_key: &key::PublicKey,
+    ) -> Result<bool, Self::Error> {
+        if self.skip {
+            return Ok(true);
+        }
+        let known = match &self.known_hosts_path {
+            Some(path) => {
+                keys::check_known_hosts_path(&self.host, self.port, server_public_key, path)
+            }
+            None => keys::check_known_hosts(&self.host, self.port, server_public_key),
+        };
+        Ok(known?)
+    }
+}
+
+/// An SFTP session with a server, over the SSH connection that it is held open by.
+struct Connection {
+    handle: Handle<HostKeyCheck>,
+    sftp: SftpSession,
+}
+
+pub struct SFTPSource {
+    config: SFTPConfig,
+    /// Connections to the servers that files have been accessed on, which are made on first use and made again once
+    /// they close. Each serves many requests at once.
+    connections: tokio::sync::Mutex<HashMap<ServerLogin, Arc<Connection>>>,
+}
+
+impl SFTPSource {
+    pub async fn get_client(config: &SFTPConfig) -> super::Result<Arc<Self>> {
+        Ok(Self {
+            config: config.clone(),
+            connections: tokio::sync::Mutex::new(HashMap::new()),
+        }
+        .into())
+    }
+
+    /// The connection to the server of `uri`, and the path of its file on the server.
+    async fn connection(&self, uri: &str) -> super::Result<(Arc<Connection>, String)> {
+        let (login, path) = parse_sftp_uri(&self.config, uri)?;
+        let mut connections = self.connections.lock().await;
+        if let Some(connection) = connections.get(&login)
+            && !connection.handle.is_closed()
+        {
+            return Ok((connection.clone(), path));
+        }
+
+        let connection = Arc::new(self.connect(&login).await?);
+        connections.insert(login, connection.clone());
+        Ok((connection, path))
+    }
+
+    async fn connect(&self, login: &ServerLogin) -> super::Result<Connection> {
+        log::debug!(
+            "SFTP connect to {}:{} as {}",
+            login.host,
+            login.port,
+            login.user
+        );
+        let host = login.host.clone();
+        let handler = HostKeyCheck {
+            host: login.host.clone(),
+            port: login.port,
+            known_hosts_path: self.config.known_hosts_path.clone(),
+            skip: self.config.skip_host_key_check,
+        };
+        let mut handle = tokio::time::timeout(
+            CONNECT_TIMEOUT,
+            client::connect(
+                Arc::new(client::Config::default()),
+                (login.host.as_str(), login.port),
+                handler,
+            ),
+        )
+        .await
+        .map_err(|_| Error::ConnectTimeout { host: host.clone() })?
+        .map_err(|err| match err {
+            russh::Error::UnknownKey => Error::UnknownHostKey { host: host.clone() },
+            err => UnableToConnectSnafu { host: host.clone() }.into_error(err),
+        })?;
+
+        if !self.authenticate(&mut handle, login).await? {
+            return Err(Error::UnableToAuthenticate {
+                host,
+                user: login.user.clone(),
+            }
+            .into());
+        }
+
+        let session_error = |msg: String| Error::UnableToStartSession {
+            host: host.clone(),
+            msg,
+        };
+        let channel = handle
+            .channel_open_session()
+            .await
+            .map_err(|err| session_error(err.to_string()))?;
+        channel
+            .request_subsystem(true, "sftp")
+            .await
+            .map_err(|err| session_error(err.to_string()))?;
+        let sftp = SftpSession::new(channel.into_stream())
+            .await
+            .map_err(|err| session_error(err.to_string()))?;
+        Ok(Connection { handle, sftp })
+    }
+
+    /// Authenticates with the private key if one is configured, and otherwise or if that fails, with the password.
+    async fn authenticate(
+        &self,
+        handle: &mut Handle<HostKeyCheck>,
+        login: &ServerLogin,
+    ) -> super::Result<bool> {
+        let passphrase = self
+            .config
+            .private_key_passphrase
+            .as_ref()
+            .map(|passphrase| passphrase.as_string().as_str());
+        let key = match (&self.config.private_key, &self.config.private_key_path) {
+            (Some(key), _) => Some(keys::decode_secret_key(key.as_string(), passphrase)),
+            (None, Some(path)) => Some(keys::load_secret_key(path, passphrase)),
+            (None, None) => None,
+        };
+        let connect_error = |err| {
+            UnableToConnectSnafu {
+                host: login.host.clone(),
+            }
+            .into_error(err)
+        };
+        if let Some(key) = key {
+            let key = key.context(UnableToLoadKeySnafu)?;
+            if handle
+                .authenticate_publickey(&login.user, Arc::new(key))
+                .await
+                .map_err(connect_error)?
+            {
+                return Ok(true);
+            }
+        }
+        if let Some(password) = &self.config.password {
+            return Ok(handle
+                .authenticate_password(&login.user, password.as_string())
+                .await
+                .map_err(connect_error)?);
+        }
+        Ok(false)
+    }
+
+    async fn file_metadata(
+        &self,
+        uri: &str,
+        io_stats: Option<&IOStatsRef>,
+    ) -> super::Result<russh_sftp::client::fs::Metadata> {
+        let (connection, path) = self.connection(uri).await?;
+        let metadata = connection
+            .sftp
+            .metadata(path)
+            .await
+            .context(UnableToOpenFileSnafu { path: uri })?;
+        if let Some(is) = io_stats {
+            is.mark_head_requests(1);
+        }
+        if metadata.is_dir() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+        Ok(metadata)
+    }
+}
+
+/// Creates the directory at `path` on the server, and any of its parents that don't exist yet.
+async fn create_dir_all(sftp: &SftpSession, path: &str) -> Result<(), SftpError> {
+    let mut dir = String::new();
+    for component in path.split(SFTP_DELIMITER).filter(|c| !c.is_empty()) {
+        dir.push_str(SFTP_DELIMITER);
+        dir.push_str(component);
+        if !sftp.try_exists(dir.as_str()).await? {
+            sftp.create_dir(dir.as_str()).await?;
+        }
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl ObjectSource for SFTPSource {
+    async fn get(
+        &self,
+        uri: &str,
+        range: Option<Range<usize>>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<GetResult> {
+        let (connection, path) = self.connection(uri).await?;
+        let mut file = connection
+            .sftp
+            .open(path)
+            .await
+            .context(UnableToOpenFileSnafu { path: uri })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_get_requests(1);
+        }
+
+        // Every read of a file on an SFTP server is made at an offset, so ranges are read directly
+        let (reader, size_bytes): (Box<dyn AsyncRead + Send + Unpin>, _) = match &range {
+            Some(range) => {
+                file.seek(SeekFrom::Start(range.start as u64))
+                    .await
+                    .context(UnableToReadBytesSnafu { path: uri })?;
+                (Box::new(file.take(range.len() as u64)), Some(range.len()))
+            }
+            None => (Box::new(file), None),
+        };
+        let owned_string = uri.to_owned();
+        // The connection is held on to until the file is read, as the file is read over it
+        let stream = futures::stream::try_unfold(
+            (reader, connection),
+            |(mut reader, connection)| async move {
+                let mut buf = vec![0; READ_CHUNK_SIZE];
+                let num_bytes = reader.read(&mut buf).await?;
+                if num_bytes == 0 {
+                    return Ok(None);
+                }
+                buf.truncate(num_bytes);
+                Ok(Some((Bytes::from(buf), (reader, connection))))
+            },
+        )
+        .map_err(move |e| {
+            UnableToReadBytesSnafu::<String> {
+                path: owned_string.clone(),
+            }
+            .into_error(e)
+            .into()
+        })
+        .boxed();
+        Ok(GetResult::Stream(
+            io_stats_on_bytestream(stream, io_stats),
+            size_bytes,
+            None,
+            None,
+        ))
+    }
+
+    async fn put(
+        &self,
+        uri: &str,
+        data: bytes::Bytes,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let (connection, path) = self.connection(uri).await?;
+        let mut file = match connection.sftp.create(path.as_str()).await {
+            // The directory of the file is created only once creating the file fails, to save checking for it first
+            Err(SftpError::Status(status)) if status.status_code == StatusCode::NoSuchFile => {
+                let (dir, _) = path.rsplit_once(SFTP_DELIMITER).unwrap_or_default();
+                create_dir_all(&connection.sftp, dir)
+                    .await
+                    .context(UnableToCreateFileSnafu { path: uri })?;
+                connection.sftp.create(path.as_str()).await
+            }
+            file => file,
+        }
+        .context(UnableToCreateFileSnafu { path: uri })?;
+
+        let num_bytes = data.len();
+        file.write_all(&data)
+            .await
+            .context(UnableToWriteBytesSnafu { path: uri })?;
+        file.shutdown()
+            .await
+            .context(UnableToWriteBytesSnafu { path: uri })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_uploaded(num_bytes);
+        }
+        Ok(())
+    }
+
+    async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+        let metadata = self.file_metadata(uri, io_stats.as_ref()).await?;
+        Ok(metadata.len() as usize)
+    }
+
+    async fn get_version(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<String>> {
+        // Servers only report when files were last modified to the second, so their size is part of the version too
+        let metadata = self.file_metadata(uri, io_stats.as_ref()).await?;
+        Ok(metadata
+            .mtime
+            .map(|mtime| format!("{mtime}-{}", metadata.len())))
+    }
+
+    async fn glob(
+        self: Arc<Self>,
+        glob_path: &str,
+        _fanout_limit: Option<usize>,
+        _page_size: Option<i32>,
+        limit: Option<usize>,
+        io_stats: Option<IOStatsRef>,
+        _file_format: Option<FileFormat>,
+        options: &GlobOptions,
+    ) -> super::Result<BoxStream<'static, super::Result<FileMetadata>>> {
+        use crate::object_store_glob::glob;
+
+        // Ensure fanout_limit is None because SFTP ObjectSource does not support prefix listing
+        let fanout_limit = None;
+        let page_size = None;
+
+        glob(
+            self,
+            glob_path,
+            fanout_limit,
+            page_size,
+            limit,
+            io_stats,
+            options,
+        )
+        .await
+    }
+
+    async fn ls(
+        &self,
+        path: &str,
+        posix: bool,
+        _continuation_token: Option<&str>,
+        _page_size: Option<i32>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<LSResult> {
+        if !posix {
+            unimplemented!("Prefix-listing is not implemented for SFTP listing");
+        }
+
+        let (connection, dir) = self.connection(path).await?;
+        let entries = match connection.sftp.read_dir(dir).await {
+            Ok(entries) => entries,
+            // Listing a file returns just that file
+            Err(err) => {
+                return match self.file_metadata(path, io_stats.as_ref()).await {
+                    Ok(metadata) => Ok(LSResult {
+                        files: vec![FileMetadata {
+                            filepath: path.to_string(),
+                            size: Some(metadata.len()),
+                            filetype: FileType::File,
+                            last_modified: None,
+                            etag: None,
+                            version_id: None,
+                            storage_class: None,
+                        }],
+                        continuation_token: None,
+                    }),
+                    Err(_) => Err(Error::UnableToList {
+                        path: path.into(),
+                        source: err,
+                    }
+                    .into()),
+                };
+            }
+        };
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_list_requests(1);
+        }
+
+        let dir_path = path.trim_end_matches(SFTP_DELIMITER);
+        let files = entries
+            .map(|entry| {
+                let metadata = entry.metadata();
+                if metadata.is_dir() {
+                    FileMetadata {
+                        filepath: format!(
+                            "{dir_path}{SFTP_DELIMITER}{}{SFTP_DELIMITER}",
+                            entry.file_name()
+                        ),
+                        size: None,
+                        filetype: FileType::Directory,
+                        last_modified: None,
+                        etag: None,
+                        version_id: None,
+                        storage_class: None,
+                    }
+                } else {
+                    FileMetadata {
+                        filepath: format!("{dir_path}{SFTP_DELIMITER}{}", entry.file_name()),
+                        size: Some(metadata.len()),
+                        filetype: FileType::File,
+                        last_modified: None,
+                        etag: None,
+                        version_id: None,
+                        storage_class: None,
+                    }
+                }
+            })
+            .collect();
+        Ok(LSResult {
+            files,
+            continuation_token: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_io_config::SFTPConfig;
+
+    use super::{parse_sftp_uri, ServerLogin};
+    use crate::Result;
+
+    #[test]
+    fn test_parse_sftp_uri() -> Result<()> {
+        let config = SFTPConfig {
+            user: Some("partner".to_string()),
+            ..Default::default()
+        };
+        let (login, path) = parse_sftp_uri(&config, "sftp://drop.example.com/incoming/a b.csv")?;
+        assert_eq!(
+            login,
+            ServerLogin {
+                host: "drop.example.com".to_string(),
+                port: 22,
+                user: "partner".to_string(),
+            }
+        );
+        assert_eq!(path, "/incoming/a b.csv");
+
+        let (login, path) = parse_sftp_uri(&config, "sftp://etl@drop.example.com:2222")?;
+        assert_eq!(
+            login,
+            ServerLogin {
+                host: "drop.example.com".to_string(),
+                port: 2222,
+                user: "etl".to_string(),
+            }
+        );
+        assert_eq!(path, "/");
+
+        assert!(parse_sftp_uri(&SFTPConfig::default(), "sftp://drop.example.com/data").is_err());
+        assert!(parse_sftp_uri(&config, "sftp:///data").is_err());
+        Ok(())
+    }
+}
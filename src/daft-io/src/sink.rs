@@ -0,0 +1,550 @@
+use std::{
+    collections::VecDeque,
+    future::Future,
+    io::Write,
+    pin::Pin,
+    sync::Arc,
+    task::{ready, Context, Poll},
+};
+
+use bytes::Bytes;
+use common_error::{DaftError, DaftResult};
+use common_runtime::{get_io_runtime, RuntimeRef, RuntimeTask};
+use futures::AsyncWrite;
+
+use crate::{object_io::MultipartUpload, IOClient, IOStatsRef};
+
+enum UploadState {
+    /// No part has been uploaded yet.
+    NotStarted,
+    /// Parts are being uploaded as they fill up.
+    Multipart(Arc<dyn MultipartUpload>),
+    /// The store doesn't support multipart uploads, so the file is uploaded whole once the sink is closed.
+    Whole,
+    Closed,
+}
+
+/// Streams a file to an object store while it is being written, uploading it in parts of `part_size` bytes.
+///
+/// At most `max_inflight_parts` parts are uploaded at once. Writing blocks while that many are in flight, so that a
+/// slow object store throttles whatever produces the data, rather than parts piling up in memory. Files smaller than a
+/// part, and files on stores without multipart uploads, are uploaded whole when the sink is closed.
+///
+/// A sink that is dropped without being closed aborts its upload, so a partially written file never shows up.
+pub struct ObjectSink {
+    io_client: Arc<IOClient>,
+    uri: String,
+    part_size: usize,
+    max_inflight_parts: usize,
+    io_stats: Option<IOStatsRef>,
+    runtime: RuntimeRef,
+    buffer: Vec<u8>,
+    state: UploadState,
+    inflight_parts: VecDeque<RuntimeTask<crate::Result<()>>>,
+    num_parts: usize,
+    bytes_written: usize,
+}
+
+impl ObjectSink {
+    pub fn new(
+        io_client: Arc<IOClient>,
+        uri: String,
+        part_size: usize,
+        max_inflight_parts: usize,
+        io_stats: Option<IOStatsRef>,
+    ) -> Self {
+        assert!(part_size > 0 && max_inflight_parts > 0);
+        Self {
+            io_client,
+            uri,
+            part_size,
+            max_inflight_parts,
+            io_stats,
+            runtime: get_io_runtime(true),
+            buffer: Vec::with_capacity(part_size),
+            state: UploadState::NotStarted,
+            inflight_parts: VecDeque::new(),
+            num_parts: 0,
+            bytes_written: 0,
+        }
+    }
+
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+
+    /// The number of bytes written to the sink so far, whether or not they have been uploaded yet.
+    pub fn bytes_written(&self) -> usize {
+        self.bytes_written
+    }
+
+    /// Finishes uploading the file. The sink must not be written to afterwards.
+    pub fn close(&mut self) -> DaftResult<()> {
+        match std::mem::replace(&mut self.state, UploadState::Closed) {
+            UploadState::Multipart(upload) => {
+                let result = self.complete_multipart(upload.clone());
+                if result.is_err() {
+                    abort_in_background(&self.runtime, self.uri.clone(), upload);
+                }
+                result
+            }
+            UploadState::NotStarted | UploadState::Whole => {
+                let data = Bytes::from(std::mem::take(&mut self.buffer));
+                let io_client = self.io_client.clone();
+                let uri = self.uri.clone();
+                let io_stats = self.io_stats.clone();
+                self.runtime.block_on(async move {
+                    io_client.single_url_put(&uri, data, io_stats).await
+                })??;
+                Ok(())
+            }
+            UploadState::Closed => Ok(()),
+        }
+    }
+
+    /// Abandons the file, e.g. because the query writing it was cancelled, so that nothing of it shows up in the
+    /// store. The sink must not be written to afterwards.
+    pub fn abort(&mut self) {
+        self.buffer.clear();
+        if let UploadState::Multipart(upload) =
+            std::mem::replace(&mut self.state, UploadState::Closed)
+        {
+            self.inflight_parts.clear();
+            abort_in_background(&self.runtime, self.uri.clone(), upload);
+        }
+    }
+
+    fn upload_full_parts(&mut self) -> DaftResult<()> {
+        while self.buffer.len() >= self.part_size {
+            if matches!(self.state, UploadState::NotStarted) {
+                self.start_upload()?;
+            }
+            let upload = match &self.state {
+                UploadState::Multipart(upload) => upload.clone(),
+                UploadState::Whole => return Ok(()),
+                UploadState::NotStarted | UploadState::Closed => unreachable!(),
+            };
+            let rest = self.buffer.split_off(self.part_size);
+            let part = std::mem::replace(&mut self.buffer, rest);
+            self.upload_part(upload, part.into())?;
+        }
+        Ok(())
+    }
+
+    fn start_upload(&mut self) -> DaftResult<()> {
+        let io_client = self.io_client.clone();
+        let uri = self.uri.clone();
+        let io_stats = self.io_stats.clone();
+        let upload = self
+            .runtime
+            .block_on(async move { io_client.create_multipart_upload(&uri, io_stats).await })??;
+        self.state = match upload {
+            Some(upload) => UploadState::Multipart(upload),
+            None => UploadState::Whole,
+        };
+        Ok(())
+    }
+
+    fn upload_part(&mut self, upload: Arc<dyn MultipartUpload>, data: Bytes) -> DaftResult<()> {
+        // Backpressure: wait for a part to finish uploading before buffering up another one
+        while self.inflight_parts.len() >= self.max_inflight_parts {
+            self.wait_for_oldest_part()?;
+        }
+        self.num_parts += 1;
+        let part_number = self.num_parts;
+        self.inflight_parts.push_back(
+            self.runtime
+                .spawn(async move { upload.upload_part(part_number, data).await }),
+        );
+        Ok(())
+    }
+
+    fn wait_for_oldest_part(&mut self) -> DaftResult<()> {
+        if let Some(part) = self.inflight_parts.pop_front() {
+            self.runtime.block_on(part)???;
+        }
+        Ok(())
+    }
+
+    fn complete_multipart(&mut self, upload: Arc<dyn MultipartUpload>) -> DaftResult<()> {
+        if !self.buffer.is_empty() {
+            let last_part = Bytes::from(std::mem::take(&mut self.buffer));
+            self.upload_part(upload.clone(), last_part)?;
+        }
+        while !self.inflight_parts.is_empty() {
+            self.wait_for_oldest_part()?;
+        }
+        self.runtime
+            .block_on(async move { upload.complete().await })??;
+        Ok(())
+    }
+}
+
+fn abort_in_background(runtime: &RuntimeRef, uri: String, upload: Arc<dyn MultipartUpload>) {
+    // Detached, since dropping a `RuntimeTask` cancels it
+    runtime.runtime.spawn(async move {
+        if let Err(err) = upload.abort().await {
+            log::warn!("Failed to abort multipart upload to {uri}: {err}");
+        }
+    });
+}
+
+impl Write for ObjectSink {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if matches!(self.state, UploadState::Closed) {
+            return Err(std::io::Error::other(DaftError::InternalError(format!(
+                "Cannot write to closed sink for {}",
+                self.uri
+            ))));
+        }
+        self.buffer.extend_from_slice(buf);
+        self.bytes_written += buf.len();
+        self.upload_full_parts().map_err(std::io::Error::other)?;
+        Ok(buf.len())
+    }
+
+    /// Parts are only uploaded once they are full, so flushing does nothing.
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for ObjectSink {
+    fn drop(&mut self) {
+        self.abort();
+    }
+}
+
+enum AsyncUploadState {
+    NotStarted,
+    /// The multipart upload is being created.
+    Starting(RuntimeTask<crate::Result<Option<Arc<dyn MultipartUpload>>>>),
+    Multipart(Arc<dyn MultipartUpload>),
+    Whole,
+    /// The last part is being uploaded and the upload completed.
+    Closing(RuntimeTask<DaftResult<()>>),
+    Closed,
+}
+
+/// The async counterpart of [`ObjectSink`], for writers that write to an [`AsyncWrite`], such as the async Parquet and
+/// IPC writers of arrow2.
+///
+/// Uploads work the same way: parts of `part_size` bytes are uploaded while the file is being written, with at most
+/// `max_inflight_parts` of them in flight, and the upload is completed when the sink is closed. Writes are pending
+/// rather than blocking while that many parts are in flight. If uploading a part fails, or the sink is dropped before
+/// it is closed, the upload is aborted.
+pub struct AsyncObjectSink {
+    io_client: Arc<IOClient>,
+    uri: String,
+    part_size: usize,
+    max_inflight_parts: usize,
+    io_stats: Option<IOStatsRef>,
+    runtime: RuntimeRef,
+    buffer: Vec<u8>,
+    state: AsyncUploadState,
+    inflight_parts: VecDeque<RuntimeTask<crate::Result<()>>>,
+    num_parts: usize,
+    bytes_written: usize,
+}
+
+impl AsyncObjectSink {
+    pub fn new(
+        io_client: Arc<IOClient>,
+        uri: String,
+        part_size: usize,
+        max_inflight_parts: usize,
+        io_stats: Option<IOStatsRef>,
+    ) -> Self {
+        assert!(part_size > 0 && max_inflight_parts > 0);
+        Self {
+            io_client,
+            uri,
+            part_size,
+            max_inflight_parts,
+            io_stats,
+            runtime: get_io_runtime(true),
+            buffer: Vec::with_capacity(part_size),
+            state: AsyncUploadState::NotStarted,
+            inflight_parts: VecDeque::new(),
+            num_parts: 0,
+            bytes_written: 0,
+        }
+    }
+
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+
+    /// The number of bytes written to the sink so far, whether or not they have been uploaded yet.
+    pub fn bytes_written(&self) -> usize {
+        self.bytes_written
+    }
+
+    /// Abandons the file, so that nothing of it shows up in the store. The sink must not be written to afterwards.
+    pub fn abort(&mut self) {
+        self.buffer.clear();
+        self.inflight_parts.clear();
+        match std::mem::replace(&mut self.state, AsyncUploadState::Closed) {
+            AsyncUploadState::Multipart(upload) => {
+                abort_in_background(&self.runtime, self.uri.clone(), upload);
+            }
+            AsyncUploadState::Starting(task) => {
+                // The upload may be created after all, in which case it is aborted right away
+                let uri = self.uri.clone();
+                self.runtime.runtime.spawn(async move {
+                    if let Ok(Ok(Some(upload))) = task.await {
+                        if let Err(err) = upload.abort().await {
+                            log::warn!("Failed to abort multipart upload to {uri}: {err}");
+                        }
+                    }
+                });
+            }
+            AsyncUploadState::Closing(task) => {
+                // Let the upload finish, since its last part is already on its way
+                self.runtime.runtime.spawn(task);
+            }
+            AsyncUploadState::NotStarted | AsyncUploadState::Whole | AsyncUploadState::Closed => {}
+        }
+    }
+
+    fn closed_error(&self) -> DaftError {
+        DaftError::InternalError(format!("Cannot write to closed sink for {}", self.uri))
+    }
+
+    fn poll_start_upload(&mut self, cx: &mut Context<'_>) -> Poll<DaftResult<()>> {
+        loop {
+            match &mut self.state {
+                AsyncUploadState::NotStarted => {
+                    let io_client = self.io_client.clone();
+                    let uri = self.uri.clone();
+                    let io_stats = self.io_stats.clone();
+                    self.state = AsyncUploadState::Starting(self.runtime.spawn(async move {
+                        io_client.create_multipart_upload(&uri, io_stats).await
+                    }));
+                }
+                AsyncUploadState::Starting(task) => {
+                    let upload = ready!(Pin::new(task).poll(cx))??;
+                    self.state = match upload {
+                        Some(upload) => AsyncUploadState::Multipart(upload),
+                        None => AsyncUploadState::Whole,
+                    };
+                    return Poll::Ready(Ok(()));
+                }
+                AsyncUploadState::Multipart(_) | AsyncUploadState::Whole => {
+                    return Poll::Ready(Ok(()));
+                }
+                AsyncUploadState::Closing(_) | AsyncUploadState::Closed => {
+                    return Poll::Ready(Err(self.closed_error()));
+                }
+            }
+        }
+    }
+
+    fn poll_upload_full_parts(&mut self, cx: &mut Context<'_>) -> Poll<DaftResult<()>> {
+        while self.buffer.len() >= self.part_size {
+            ready!(self.poll_start_upload(cx))?;
+            let upload = match &self.state {
+                AsyncUploadState::Multipart(upload) => upload.clone(),
+                _ => return Poll::Ready(Ok(())),
+            };
+            // Backpressure: wait for a part to finish uploading before buffering up another one
+            if self.inflight_parts.len() >= self.max_inflight_parts {
+                let oldest = self.inflight_parts.front_mut().unwrap();
+                ready!(Pin::new(oldest).poll(cx))??;
+                self.inflight_parts.pop_front();
+                continue;
+            }
+            let rest = self.buffer.split_off(self.part_size);
+            let part = Bytes::from(std::mem::replace(&mut self.buffer, rest));
+            self.num_parts += 1;
+            let part_number = self.num_parts;
+            self.inflight_parts.push_back(
+                self.runtime
+                    .spawn(async move { upload.upload_part(part_number, part).await }),
+            );
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_closing(&mut self) {
+        let last_part = Bytes::from(std::mem::take(&mut self.buffer));
+        let task = match std::mem::replace(&mut self.state, AsyncUploadState::Closed) {
+            AsyncUploadState::Multipart(upload) => {
+                let inflight_parts = std::mem::take(&mut self.inflight_parts);
+                let part_number = self.num_parts + 1;
+                let uri = self.uri.clone();
+                self.runtime.spawn(async move {
+                    let result: DaftResult<()> = async {
+                        for part in inflight_parts {
+                            part.await??;
+                        }
+                        if !last_part.is_empty() {
+                            upload.upload_part(part_number, last_part).await?;
+                        }
+                        upload.complete().await?;
+                        Ok(())
+                    }
+                    .await;
+                    if result.is_err() {
+                        if let Err(err) = upload.abort().await {
+                            log::warn!("Failed to abort multipart upload to {uri}: {err}");
+                        }
+                    }
+                    result
+                })
+            }
+            AsyncUploadState::NotStarted | AsyncUploadState::Whole => {
+                let io_client = self.io_client.clone();
+                let uri = self.uri.clone();
+                let io_stats = self.io_stats.clone();
+                self.runtime.spawn(async move {
+                    io_client.single_url_put(&uri, last_part, io_stats).await?;
+                    Ok(())
+                })
+            }
+            AsyncUploadState::Starting(_)
+            | AsyncUploadState::Closing(_)
+            | AsyncUploadState::Closed => unreachable!(),
+        };
+        self.state = AsyncUploadState::Closing(task);
+    }
+}
+
+impl AsyncWrite for AsyncObjectSink {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let this = self.get_mut();
+        if matches!(
+            this.state,
+            AsyncUploadState::Closing(_) | AsyncUploadState::Closed
+        ) {
+            return Poll::Ready(Err(std::io::Error::other(this.closed_error())));
+        }
+        if let Err(err) = ready!(this.poll_upload_full_parts(cx)) {
+            this.abort();
+            return Poll::Ready(Err(std::io::Error::other(err)));
+        }
+        this.buffer.extend_from_slice(buf);
+        this.bytes_written += buf.len();
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    /// Parts are only uploaded once they are full, so flushing does nothing.
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    /// Finishes uploading the file.
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+        loop {
+            match &mut this.state {
+                AsyncUploadState::Starting(_) => {
+                    if let Err(err) = ready!(this.poll_start_upload(cx)) {
+                        this.abort();
+                        return Poll::Ready(Err(std::io::Error::other(err)));
+                    }
+                }
+                AsyncUploadState::NotStarted
+                | AsyncUploadState::Multipart(_)
+                | AsyncUploadState::Whole => this.start_closing(),
+                AsyncUploadState::Closing(task) => {
+                    let result = ready!(Pin::new(task).poll(cx)).and_then(|result| result);
+                    this.state = AsyncUploadState::Closed;
+                    return Poll::Ready(result.map_err(std::io::Error::other));
+                }
+                AsyncUploadState::Closed => return Poll::Ready(Ok(())),
+            }
+        }
+    }
+}
+
+impl Drop for AsyncObjectSink {
+    fn drop(&mut self) {
+        self.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use common_error::DaftResult;
+    use futures::AsyncWriteExt;
+
+    use super::{AsyncObjectSink, ObjectSink};
+    use crate::{get_io_client, IOConfig};
+
+    #[test]
+    fn test_sink_uploads_whole_file_to_store_without_multipart() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.txt");
+        let io_client = get_io_client(true, IOConfig::default().into())?;
+
+        let mut sink = ObjectSink::new(io_client, path.to_string_lossy().into_owned(), 4, 2, None);
+        for chunk in ["hello", " ", "world", "!"] {
+            sink.write_all(chunk.as_bytes())?;
+        }
+        assert_eq!(sink.bytes_written(), 12);
+        assert!(!path.exists());
+        sink.close()?;
+
+        assert_eq!(std::fs::read_to_string(&path)?, "hello world!");
+        Ok(())
+    }
+
+    #[test]
+    fn test_aborted_sink_uploads_nothing() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.txt");
+        let io_client = get_io_client(true, IOConfig::default().into())?;
+
+        let mut sink = ObjectSink::new(io_client, path.to_string_lossy().into_owned(), 4, 2, None);
+        sink.write_all(b"hello world!")?;
+        sink.abort();
+        sink.close()?;
+        assert!(sink.write_all(b"more").is_err());
+
+        assert!(!path.exists());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_async_sink_uploads_whole_file_to_store_without_multipart() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.txt");
+        let io_client = get_io_client(true, IOConfig::default().into())?;
+
+        let mut sink =
+            AsyncObjectSink::new(io_client, path.to_string_lossy().into_owned(), 4, 2, None);
+        for chunk in ["hello", " ", "world", "!"] {
+            sink.write_all(chunk.as_bytes()).await?;
+        }
+        assert_eq!(sink.bytes_written(), 12);
+        assert!(!path.exists());
+        sink.close().await?;
+        assert!(sink.write_all(b"more").await.is_err());
+
+        assert_eq!(std::fs::read_to_string(&path)?, "hello world!");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_aborted_async_sink_uploads_nothing() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.txt");
+        let io_client = get_io_client(true, IOConfig::default().into())?;
+
+        let mut sink =
+            AsyncObjectSink::new(io_client, path.to_string_lossy().into_owned(), 4, 2, None);
+        sink.write_all(b"hello world!").await?;
+        sink.abort();
+        sink.close().await?;
+
+        assert!(!path.exists());
+        Ok(())
+    }
+}
@@ -1,13 +1,21 @@
 use std::{
     borrow::Cow,
+    collections::HashMap,
     sync::{
         atomic::{self},
-        Arc,
+        Arc, Mutex, OnceLock, Weak,
     },
+    time::Duration,
 };
 
+use crate::SourceType;
+
 pub type IOStatsRef = Arc<IOStatsContext>;
 
+/// Upper bounds of the buckets of time to first byte that requests are counted in, the last of which is unbounded.
+pub const TIME_TO_FIRST_BYTE_BUCKETS_MS: [u64; 11] =
+    [5, 10, 25, 50, 100, 250, 500, 1_000, 2_500, 5_000, 10_000];
+
 #[derive(Default, Debug)]
 pub struct IOStatsContext {
     name: Cow<'static, str>,
@@ -17,10 +25,24 @@ pub struct IOStatsContext {
     num_put_requests: atomic::AtomicUsize,
     bytes_read: atomic::AtomicUsize,
     bytes_uploaded: atomic::AtomicUsize,
+    num_retries: atomic::AtomicUsize,
+    num_throttles: atomic::AtomicUsize,
+    time_to_first_byte: [atomic::AtomicUsize; TIME_TO_FIRST_BYTE_BUCKETS_MS.len() + 1],
+    /// The context that this one counts towards as well, if it counts the IO of one store of it. Weak, since the
+    /// parent holds on to the contexts of its stores.
+    parent: Option<Weak<IOStatsContext>>,
+    /// The IO of this context by the store that it was made to.
+    stores: Mutex<HashMap<SourceType, IOStatsRef>>,
+    /// Identifies the query that this context counts the IO of, once it is registered with its [`QueryIOStats`].
+    query: OnceLock<usize>,
 }
 
 impl Drop for IOStatsContext {
     fn drop(&mut self) {
+        // The IO of each store is logged as part of the IO of its parent
+        if self.parent.is_some() {
+            return;
+        }
         let bytes_read = self.load_bytes_read();
         let num_gets = self.load_get_requests();
         let bytes_uploaded = self.load_bytes_uploaded();
@@ -28,7 +50,7 @@ impl Drop for IOStatsContext {
         let mean_get_size = (bytes_read as f64) / (num_gets as f64);
         let mean_put_size = (bytes_uploaded as f64) / (num_puts as f64);
         log::info!(
-            "IOStatsContext: {}, Gets: {}, Heads: {}, Lists: {}, BytesRead: {}, AvgGetSize: {}, BytesUploaded: {}, AvgPutSize: {}",
+            "IOStatsContext: {}, Gets: {}, Heads: {}, Lists: {}, BytesRead: {}, AvgGetSize: {}, BytesUploaded: {}, AvgPutSize: {}, Retries: {}, Throttles: {}",
             self.name,
             num_gets,
             self.load_head_requests(),
@@ -37,6 +59,8 @@ impl Drop for IOStatsContext {
             mean_get_size as i64,
             bytes_uploaded,
             mean_put_size as i64,
+            self.load_retries(),
+            self.load_throttles(),
         );
     }
 }
@@ -49,39 +73,101 @@ pub struct IOStatsByteStreamContextHandle {
 
 impl IOStatsContext {
     pub fn new<S: Into<Cow<'static, str>>>(name: S) -> IOStatsRef {
-        Arc::new(Self {
-            name: name.into(),
+        Arc::new(Self::with_parent(name.into(), None))
+    }
+
+    fn with_parent(name: Cow<'static, str>, parent: Option<Weak<Self>>) -> Self {
+        Self {
+            name,
             num_get_requests: atomic::AtomicUsize::new(0),
             num_head_requests: atomic::AtomicUsize::new(0),
             num_list_requests: atomic::AtomicUsize::new(0),
             num_put_requests: atomic::AtomicUsize::new(0),
             bytes_read: atomic::AtomicUsize::new(0),
             bytes_uploaded: atomic::AtomicUsize::new(0),
-        })
+            num_retries: atomic::AtomicUsize::new(0),
+            num_throttles: atomic::AtomicUsize::new(0),
+            time_to_first_byte: Default::default(),
+            parent,
+            stores: Mutex::new(HashMap::new()),
+            query: OnceLock::new(),
+        }
+    }
+
+    /// Identifies who the requests counted in this context are made for, which requests to each endpoint are shared
+    /// fairly between: the query that it is registered with, or else the context itself.
+    pub(crate) fn requester(&self) -> usize {
+        if let Some(parent) = self.parent.as_ref().and_then(Weak::upgrade) {
+            return parent.requester();
+        }
+        self.query
+            .get()
+            .copied()
+            .unwrap_or_else(|| std::ptr::from_ref(self) as usize)
+    }
+
+    /// The context that counts the IO of this one made to `store`, which counts towards this one as well.
+    pub(crate) fn store(self: &Arc<Self>, store: SourceType) -> IOStatsRef {
+        self.stores
+            .lock()
+            .unwrap()
+            .entry(store)
+            .or_insert_with(|| {
+                Arc::new(Self::with_parent(
+                    format!("{} ({store})", self.name).into(),
+                    Some(Arc::downgrade(self)),
+                ))
+            })
+            .clone()
+    }
+
+    #[inline]
+    fn mark(&self, counter: impl Fn(&Self) -> &atomic::AtomicUsize, value: usize) {
+        counter(self).fetch_add(value, atomic::Ordering::Relaxed);
+        if let Some(parent) = self.parent.as_ref().and_then(Weak::upgrade) {
+            parent.mark(counter, value);
+        }
     }
 
     #[inline]
     pub fn mark_get_requests(&self, num_requests: usize) {
-        self.num_get_requests
-            .fetch_add(num_requests, atomic::Ordering::Relaxed);
+        self.mark(|s| &s.num_get_requests, num_requests);
     }
 
     #[inline]
     pub(crate) fn mark_head_requests(&self, num_requests: usize) {
-        self.num_head_requests
-            .fetch_add(num_requests, atomic::Ordering::Relaxed);
+        self.mark(|s| &s.num_head_requests, num_requests);
     }
 
     #[inline]
     pub(crate) fn mark_list_requests(&self, num_requests: usize) {
-        self.num_list_requests
-            .fetch_add(num_requests, atomic::Ordering::Relaxed);
+        self.mark(|s| &s.num_list_requests, num_requests);
     }
 
     #[inline]
     pub(crate) fn mark_put_requests(&self, num_requests: usize) {
-        self.num_put_requests
-            .fetch_add(num_requests, atomic::Ordering::Relaxed);
+        self.mark(|s| &s.num_put_requests, num_requests);
+    }
+
+    #[inline]
+    pub(crate) fn mark_retries(&self, num_retries: usize) {
+        self.mark(|s| &s.num_retries, num_retries);
+    }
+
+    #[inline]
+    pub(crate) fn mark_throttles(&self, num_throttles: usize) {
+        self.mark(|s| &s.num_throttles, num_throttles);
+    }
+
+    /// Counts a request that took `elapsed` for the first byte of its response to arrive.
+    #[inline]
+    pub(crate) fn mark_time_to_first_byte(&self, elapsed: Duration) {
+        let elapsed_ms = elapsed.as_millis() as u64;
+        let bucket = TIME_TO_FIRST_BYTE_BUCKETS_MS
+            .iter()
+            .position(|upper_bound_ms| elapsed_ms <= *upper_bound_ms)
+            .unwrap_or(TIME_TO_FIRST_BYTE_BUCKETS_MS.len());
+        self.mark(|s| &s.time_to_first_byte[bucket], 1);
     }
 
     #[inline]
@@ -106,14 +192,12 @@ impl IOStatsContext {
 
     #[inline]
     pub fn mark_bytes_read(&self, bytes_read: usize) {
-        self.bytes_read
-            .fetch_add(bytes_read, atomic::Ordering::Relaxed);
+        self.mark(|s| &s.bytes_read, bytes_read);
     }
 
     #[inline]
     pub(crate) fn mark_bytes_uploaded(&self, bytes_uploaded: usize) {
-        self.bytes_uploaded
-            .fetch_add(bytes_uploaded, atomic::Ordering::Relaxed);
+        self.mark(|s| &s.bytes_uploaded, bytes_uploaded);
     }
 
     #[inline]
@@ -125,6 +209,189 @@ impl IOStatsContext {
     pub fn load_bytes_uploaded(&self) -> usize {
         self.bytes_uploaded.load(atomic::Ordering::Acquire)
     }
+
+    #[inline]
+    pub fn load_retries(&self) -> usize {
+        self.num_retries.load(atomic::Ordering::Acquire)
+    }
+
+    #[inline]
+    pub fn load_throttles(&self) -> usize {
+        self.num_throttles.load(atomic::Ordering::Acquire)
+    }
+
+    /// The IO counted by this context so far.
+    pub fn snapshot(&self) -> IOStatsSnapshot {
+        IOStatsSnapshot {
+            get_requests: self.load_get_requests(),
+            head_requests: self.load_head_requests(),
+            list_requests: self.load_list_requests(),
+            put_requests: self.load_put_requests(),
+            bytes_read: self.load_bytes_read(),
+            bytes_uploaded: self.load_bytes_uploaded(),
+            retries: self.load_retries(),
+            throttles: self.load_throttles(),
+            time_to_first_byte: self
+                .time_to_first_byte
+                .iter()
+                .map(|count| count.load(atomic::Ordering::Acquire))
+                .collect(),
+        }
+    }
+
+    /// The IO counted by this context so far by the store that it was made to.
+    pub fn store_snapshots(&self) -> HashMap<SourceType, IOStatsSnapshot> {
+        self.stores
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(store, stats)| (*store, stats.snapshot()))
+            .collect()
+    }
+}
+
+/// The IO counted by an [`IOStatsContext`] at some point, which can be added up with that of other contexts.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IOStatsSnapshot {
+    pub get_requests: usize,
+    pub head_requests: usize,
+    pub list_requests: usize,
+    pub put_requests: usize,
+    pub bytes_read: usize,
+    pub bytes_uploaded: usize,
+    pub retries: usize,
+    pub throttles: usize,
+    /// The number of requests in each bucket of [`TIME_TO_FIRST_BYTE_BUCKETS_MS`], and then of those that took longer.
+    pub time_to_first_byte: Vec<usize>,
+}
+
+impl IOStatsSnapshot {
+    pub fn merge(&mut self, other: &Self) {
+        self.get_requests += other.get_requests;
+        self.head_requests += other.head_requests;
+        self.list_requests += other.list_requests;
+        self.put_requests += other.put_requests;
+        self.bytes_read += other.bytes_read;
+        self.bytes_uploaded += other.bytes_uploaded;
+        self.retries += other.retries;
+        self.throttles += other.throttles;
+        if self.time_to_first_byte.len() < other.time_to_first_byte.len() {
+            self.time_to_first_byte
+                .resize(other.time_to_first_byte.len(), 0);
+        }
+        for (count, other_count) in self
+            .time_to_first_byte
+            .iter_mut()
+            .zip(&other.time_to_first_byte)
+        {
+            *count += other_count;
+        }
+    }
+
+    /// The upper bound of the bucket that the `quantile` of time to first byte falls in, or None if it falls in the
+    /// unbounded bucket or no time to first byte was counted.
+    pub fn time_to_first_byte_quantile(&self, quantile: f64) -> Option<Duration> {
+        let total = self.time_to_first_byte.iter().sum::<usize>();
+        if total == 0 {
+            return None;
+        }
+        let rank = ((total as f64) * quantile).ceil().max(1.0) as usize;
+        let mut seen = 0;
+        for (bucket, count) in self.time_to_first_byte.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                return TIME_TO_FIRST_BYTE_BUCKETS_MS
+                    .get(bucket)
+                    .map(|upper_bound_ms| Duration::from_millis(*upper_bound_ms));
+            }
+        }
+        None
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![format!(
+            "Requests = {} GET, {} HEAD, {} LIST, {} PUT",
+            self.get_requests, self.head_requests, self.list_requests, self.put_requests
+        )];
+        res.push(format!(
+            "Bytes downloaded = {}, Bytes uploaded = {}",
+            self.bytes_read, self.bytes_uploaded
+        ));
+        res.push(format!(
+            "Retries = {}, Throttles = {}",
+            self.retries, self.throttles
+        ));
+        let quantile = |quantile| match self.time_to_first_byte_quantile(quantile) {
+            Some(upper_bound) => format!("<= {}ms", upper_bound.as_millis()),
+            None => format!(
+                "> {}ms",
+                TIME_TO_FIRST_BYTE_BUCKETS_MS[TIME_TO_FIRST_BYTE_BUCKETS_MS.len() - 1]
+            ),
+        };
+        if self.time_to_first_byte.iter().any(|count| *count > 0) {
+            res.push(format!(
+                "Time to first byte = p50 {}, p90 {}, p99 {}",
+                quantile(0.5),
+                quantile(0.9),
+                quantile(0.99)
+            ));
+        }
+        res
+    }
+}
+
+/// The IO of a query, made up of the IO of the contexts of its operators.
+#[derive(Debug, Default)]
+pub struct QueryIOStats {
+    contexts: Mutex<Vec<IOStatsRef>>,
+}
+
+impl QueryIOStats {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Counts the IO of `io_stats` towards the query.
+    pub fn register(&self, io_stats: IOStatsRef) {
+        let _ = io_stats.query.set(std::ptr::from_ref(self) as usize);
+        self.contexts.lock().unwrap().push(io_stats);
+    }
+
+    /// The IO of the query so far.
+    pub fn total(&self) -> IOStatsSnapshot {
+        let mut total = IOStatsSnapshot::default();
+        for io_stats in self.contexts.lock().unwrap().iter() {
+            total.merge(&io_stats.snapshot());
+        }
+        total
+    }
+
+    /// The IO of the query so far by store, sorted by the name of the store.
+    pub fn by_store(&self) -> Vec<(SourceType, IOStatsSnapshot)> {
+        let mut by_store = HashMap::<SourceType, IOStatsSnapshot>::new();
+        for io_stats in self.contexts.lock().unwrap().iter() {
+            for (store, snapshot) in io_stats.store_snapshots() {
+                by_store.entry(store).or_default().merge(&snapshot);
+            }
+        }
+        let mut by_store = by_store.into_iter().collect::<Vec<_>>();
+        by_store.sort_by_key(|(store, _)| store.to_string());
+        by_store
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = self.total().multiline_display();
+        for (store, snapshot) in self.by_store() {
+            res.push(format!("{store}:"));
+            res.extend(
+                snapshot
+                    .multiline_display()
+                    .into_iter()
+                    .map(|line| format!("  {line}")),
+            );
+        }
+        res
+    }
 }
 
 impl IOStatsByteStreamContextHandle {
@@ -146,3 +413,46 @@ impl Drop for IOStatsByteStreamContextHandle {
         self.inner.mark_bytes_read(self.bytes_read);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{IOStatsContext, QueryIOStats};
+    use crate::SourceType;
+
+    #[test]
+    fn test_store_stats_count_towards_query() {
+        let io_stats = IOStatsContext::new("scan");
+        let s3 = io_stats.store(SourceType::S3);
+        s3.mark_get_requests(2);
+        s3.mark_bytes_read(100);
+        s3.mark_throttles(1);
+        s3.mark_retries(1);
+        s3.mark_time_to_first_byte(Duration::from_millis(20));
+        s3.mark_time_to_first_byte(Duration::from_millis(30_000));
+        io_stats.store(SourceType::HF).mark_list_requests(1);
+
+        let query_io_stats = QueryIOStats::new();
+        query_io_stats.register(io_stats);
+        let total = query_io_stats.total();
+        assert_eq!(total.get_requests, 2);
+        assert_eq!(total.list_requests, 1);
+        assert_eq!(total.bytes_read, 100);
+        assert_eq!((total.retries, total.throttles), (1, 1));
+        assert_eq!(
+            total.time_to_first_byte_quantile(0.5),
+            Some(Duration::from_millis(25))
+        );
+        assert_eq!(total.time_to_first_byte_quantile(0.99), None);
+
+        let by_store = query_io_stats.by_store();
+        assert_eq!(
+            by_store
+                .iter()
+                .map(|(store, stats)| (*store, stats.get_requests))
+                .collect::<Vec<_>>(),
+            vec![(SourceType::HF, 0), (SourceType::S3, 2)]
+        );
+    }
+}
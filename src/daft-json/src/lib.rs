@@ -14,12 +14,14 @@ pub mod options;
 pub mod python;
 pub mod read;
 pub mod schema;
+pub mod values;
 
 // pub use metadata::read_json_schema_bulk;
 pub use options::{JsonConvertOptions, JsonParseOptions, JsonReadOptions};
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 pub use read::{read_json, read_json_bulk};
+pub use values::decode_json_values;
 
 #[derive(Debug, Snafu)]
 pub enum Error {
@@ -72,5 +74,6 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_class::<JsonReadOptions>()?;
     parent.add_function(wrap_pyfunction!(python::pylib::read_json, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::pylib::read_json_schema, parent)?)?;
+    parent.add_function(wrap_pyfunction!(python::pylib::decode_json_values, parent)?)?;
     Ok(())
 }
@@ -1,7 +1,7 @@
 pub mod pylib {
     use std::sync::Arc;
 
-    use daft_core::python::PySchema;
+    use daft_core::python::{PySchema, PySeries};
     use daft_io::{get_io_client, python::IOConfig, IOStatsContext};
     use daft_recordbatch::python::PyRecordBatch;
     use pyo3::{pyfunction, PyResult, Python};
@@ -88,4 +88,15 @@ pub mod pylib {
             Ok(Arc::new(schema).into())
         })
     }
+
+    #[pyfunction]
+    pub fn decode_json_values(
+        py: Python,
+        values: &PySeries,
+        schema: &PySchema,
+    ) -> PyResult<PySeries> {
+        py.allow_threads(|| {
+            Ok(crate::values::decode_json_values(&values.series, &schema.schema)?.into())
+        })
+    }
 }
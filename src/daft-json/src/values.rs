@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use arrow2::bitmap::MutableBitmap;
+use common_error::{DaftError, DaftResult};
+use daft_core::{prelude::*, utils::arrow::cast_array_for_daft_if_needed};
+
+use crate::{
+    decoding::{allocate_array, deserialize_into},
+    deserializer::{to_value, Value},
+};
+
+/// Decodes a column of JSON documents, such as the values of messages read from a stream, into a
+/// struct column with the fields of `schema`.
+///
+/// Each document must be a JSON object. Null documents decode to null structs, keys that aren't in
+/// `schema` are dropped, and fields that a document doesn't have decode to nulls.
+pub fn decode_json_values(values: &Series, schema: &Schema) -> DaftResult<Series> {
+    let documents: Vec<Option<&[u8]>> = match values.data_type() {
+        DataType::Utf8 => values
+            .utf8()?
+            .as_arrow()
+            .iter()
+            .map(|v| v.map(str::as_bytes))
+            .collect(),
+        DataType::Binary => values.binary()?.as_arrow().iter().collect(),
+        dtype => {
+            return Err(DaftError::TypeError(format!(
+                "Expected JSON documents to be strings or binary, but received: {dtype}"
+            )))
+        }
+    };
+
+    let arrow_schema = schema.to_arrow()?;
+    let mut columns = arrow_schema
+        .fields
+        .iter()
+        .map(|f| allocate_array(f, documents.len()))
+        .collect::<Vec<_>>();
+    let mut validity = MutableBitmap::with_capacity(documents.len());
+    let mut scratch = vec![];
+    for document in documents {
+        let Some(bytes) = document else {
+            columns.iter_mut().for_each(|column| column.push_null());
+            validity.push(false);
+            continue;
+        };
+        scratch.clear();
+        scratch.extend_from_slice(bytes);
+        let value = to_value(&mut scratch)
+            .map_err(|e| DaftError::ValueError(format!("Invalid JSON document: {e}")))?;
+        let Value::Object(record) = value else {
+            return Err(DaftError::ValueError(format!(
+                "Expected each JSON document to be an object, but got: {value:?}"
+            )));
+        };
+        for (field, column) in arrow_schema.fields.iter().zip(&mut columns) {
+            match record.get(field.name.as_str()) {
+                Some(value) => deserialize_into(column, &[value]),
+                None => column.push_null(),
+            }
+        }
+        validity.push(true);
+    }
+
+    let children = columns
+        .into_iter()
+        .zip(schema)
+        .map(|(mut column, field)| {
+            Series::try_from_field_and_arrow_array(
+                Arc::new(field.clone()),
+                cast_array_for_daft_if_needed(column.as_box()),
+            )
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    let field = Field::new(
+        values.name(),
+        DataType::Struct(schema.into_iter().cloned().collect()),
+    );
+    Ok(StructArray::new(field, children, Some(validity.into())).into_series())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_json_values() -> DaftResult<()> {
+        let values = Utf8Array::from_iter(
+            "value",
+            vec![
+                Some(r#"{"a": 1, "b": "x"}"#),
+                None,
+                Some(r#"{"b": "y", "c": true}"#),
+            ]
+            .into_iter(),
+        )
+        .into_series();
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]);
+
+        let decoded = decode_json_values(&values, &schema)?;
+        assert_eq!(decoded.name(), "value");
+        assert_eq!(decoded.len(), 3);
+        let decoded = decoded.struct_()?;
+        assert_eq!(decoded.null_count(), 1);
+        let a = decoded.children[0].i64()?;
+        assert_eq!(a.get(0), Some(1));
+        assert_eq!(a.get(2), None);
+        let b = decoded.children[1].utf8()?;
+        assert_eq!(b.get(2), Some("y"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_json_values_not_object() {
+        let values = Utf8Array::from_iter("value", vec![Some("[1, 2]")].into_iter()).into_series();
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64)]);
+        assert!(decode_json_values(&values, &schema).is_err());
+    }
+}
@@ -1,6 +1,12 @@
-use std::{sync::Arc, vec};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    vec,
+};
 
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 #[cfg(feature = "python")]
 use daft_dsl::python::PyExpr;
 use daft_dsl::{
@@ -22,7 +28,10 @@ use super::intermediate_op::{
     IntermediateOpExecuteResult, IntermediateOpState, IntermediateOperator,
     IntermediateOperatorResult,
 };
-use crate::{ExecutionRuntimeContext, ExecutionTaskSpawner};
+use crate::{
+    resource_manager::{cuda_visible_devices, GpuAllocation},
+    ExecutionRuntimeContext, ExecutionTaskSpawner,
+};
 
 struct ActorHandle {
     #[cfg(feature = "python")]
@@ -30,7 +39,8 @@ struct ActorHandle {
 }
 
 impl ActorHandle {
-    fn try_new(projection: &[ExprRef]) -> DaftResult<Self> {
+    /// Starts an actor process, which only sees the GPUs in `cuda_visible_devices` if given.
+    fn try_new(projection: &[ExprRef], cuda_visible_devices: Option<String>) -> DaftResult<Self> {
         #[cfg(feature = "python")]
         {
             let handle = Python::with_gil(|py| {
@@ -38,10 +48,13 @@ impl ActorHandle {
                 Ok::<PyObject, PyErr>(
                     py.import(pyo3::intern!(py, "daft.execution.actor_pool_udf"))?
                         .getattr(pyo3::intern!(py, "ActorHandle"))?
-                        .call1((projection
-                            .iter()
-                            .map(|expr| PyExpr::from(expr.clone()))
-                            .collect::<Vec<_>>(),))?
+                        .call1((
+                            projection
+                                .iter()
+                                .map(|expr| PyExpr::from(expr.clone()))
+                                .collect::<Vec<_>>(),
+                            cuda_visible_devices,
+                        ))?
                         .unbind(),
                 )
             })?;
@@ -126,6 +139,9 @@ pub struct ActorPoolProjectOperator {
     concurrency: usize,
     batch_size: Option<usize>,
     memory_request: u64,
+    gpu_allocation: Option<GpuAllocation>,
+    // The number of actors started so far, which picks the GPUs of the next one
+    num_actors_started: AtomicUsize,
 }
 
 impl ActorPoolProjectOperator {
@@ -140,15 +156,33 @@ impl ActorPoolProjectOperator {
         let concurrency = get_concurrency(&projection);
         let batch_size = try_get_batch_size_from_udf(&projection)?;
 
-        let memory_request = get_resource_request(&projection)
+        let resource_request = get_resource_request(&projection);
+        let memory_request = resource_request
+            .as_ref()
             .and_then(|req| req.memory_bytes())
             .map(|m| m as u64)
             .unwrap_or(0);
+        let gpu_allocation = match resource_request.and_then(|req| req.num_gpus()) {
+            Some(num_gpus) if num_gpus > 0.0 => {
+                let devices = cuda_visible_devices()?;
+                let num_devices = devices.len();
+                let gpu_allocation = GpuAllocation::try_new(devices, num_gpus)?;
+                if concurrency > gpu_allocation.max_concurrency() {
+                    return Err(DaftError::ValueError(format!(
+                        "UDF concurrency of {concurrency} with {num_gpus} GPUs per instance needs more GPUs than the {num_devices} available"
+                    )));
+                }
+                Some(gpu_allocation)
+            }
+            _ => None,
+        };
         Ok(Self {
             projection,
             concurrency,
             batch_size,
             memory_request,
+            gpu_allocation,
+            num_actors_started: AtomicUsize::new(0),
         })
     }
 }
@@ -209,9 +243,13 @@ impl IntermediateOperator for ActorPoolProjectOperator {
     }
 
     fn make_state(&self) -> DaftResult<Box<dyn IntermediateOpState>> {
-        // TODO: Pass relevant CUDA_VISIBLE_DEVICES to the actor
+        let actor = self.num_actors_started.fetch_add(1, Ordering::Relaxed);
+        let cuda_visible_devices = self
+            .gpu_allocation
+            .as_ref()
+            .map(|gpu_allocation| gpu_allocation.devices_for_instance(actor));
         Ok(Box::new(ActorPoolProjectState {
-            actor_handle: ActorHandle::try_new(&self.projection)?,
+            actor_handle: ActorHandle::try_new(&self.projection, cuda_visible_devices)?,
         }))
     }
 
@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_core::prelude::SchemaRef;
+use daft_dsl::ExprRef;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::AsofJoinBuild;
+use itertools::Itertools;
+use tracing::{instrument, Span};
+
+use super::intermediate_op::{
+    IntermediateOpExecuteResult, IntermediateOpState, IntermediateOperator,
+    IntermediateOperatorResult,
+};
+use crate::{state_bridge::BroadcastStateBridgeRef, ExecutionTaskSpawner};
+
+struct AsofJoinProbeState(BroadcastStateBridgeRef<AsofJoinBuild>);
+
+impl IntermediateOpState for AsofJoinProbeState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+struct AsofJoinProbeParams {
+    probe_by: Vec<ExprRef>,
+    probe_on: ExprRef,
+}
+
+/// Streams the left side of an as-of join through the sorted right side, matching each row with
+/// at most one right row.
+pub struct AsofJoinProbeOperator {
+    params: Arc<AsofJoinProbeParams>,
+    output_schema: SchemaRef,
+    state_bridge: BroadcastStateBridgeRef<AsofJoinBuild>,
+}
+
+impl AsofJoinProbeOperator {
+    pub(crate) fn new(
+        probe_by: Vec<ExprRef>,
+        probe_on: ExprRef,
+        output_schema: SchemaRef,
+        state_bridge: BroadcastStateBridgeRef<AsofJoinBuild>,
+    ) -> Self {
+        Self {
+            params: Arc::new(AsofJoinProbeParams { probe_by, probe_on }),
+            output_schema,
+            state_bridge,
+        }
+    }
+}
+
+impl IntermediateOperator for AsofJoinProbeOperator {
+    #[instrument(skip_all, name = "AsofJoinProbeOperator::execute")]
+    fn execute(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn IntermediateOpState>,
+        task_spawner: &ExecutionTaskSpawner,
+    ) -> IntermediateOpExecuteResult {
+        if input.is_empty() {
+            let empty = Arc::new(MicroPartition::empty(Some(self.output_schema.clone())));
+            return Ok((
+                state,
+                IntermediateOperatorResult::NeedMoreInput(Some(empty)),
+            ))
+            .into();
+        }
+
+        let params = self.params.clone();
+        let output_schema = self.output_schema.clone();
+        task_spawner
+            .spawn(
+                async move {
+                    let build = state
+                        .as_any_mut()
+                        .downcast_mut::<AsofJoinProbeState>()
+                        .expect("AsofJoinProbeState should be used with AsofJoinProbeOperator")
+                        .0
+                        .get_state()
+                        .await;
+                    let output_tables = input
+                        .get_tables()?
+                        .iter()
+                        .map(|t| build.probe(t, &params.probe_by, &params.probe_on))
+                        .collect::<DaftResult<Vec<_>>>()?;
+                    let output = Arc::new(MicroPartition::new_loaded(
+                        output_schema,
+                        Arc::new(output_tables),
+                        None,
+                    ));
+                    Ok((
+                        state,
+                        IntermediateOperatorResult::NeedMoreInput(Some(output)),
+                    ))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn name(&self) -> &'static str {
+        "AsofJoinProbe"
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![
+            "AsofJoinProbe:".to_string(),
+            format!("Probe on: {}", self.params.probe_on),
+        ];
+        if !self.params.probe_by.is_empty() {
+            res.push(format!(
+                "Probe by: [{}]",
+                self.params
+                    .probe_by
+                    .iter()
+                    .map(|e| e.to_string())
+                    .join(", ")
+            ));
+        }
+        res
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn IntermediateOpState>> {
+        Ok(Box::new(AsofJoinProbeState(self.state_bridge.clone())))
+    }
+}
@@ -14,12 +14,14 @@ use crate::ExecutionTaskSpawner;
 
 pub struct ExplodeOperator {
     to_explode: Arc<Vec<ExprRef>>,
+    unnest_structs: bool,
 }
 
 impl ExplodeOperator {
-    pub fn new(to_explode: Vec<ExprRef>) -> Self {
+    pub fn new(to_explode: Vec<ExprRef>, unnest_structs: bool) -> Self {
         Self {
             to_explode: Arc::new(to_explode.into_iter().map(explode).collect()),
+            unnest_structs,
         }
     }
 }
@@ -33,10 +35,11 @@ impl IntermediateOperator for ExplodeOperator {
         task_spawner: &ExecutionTaskSpawner,
     ) -> IntermediateOpExecuteResult {
         let to_explode = self.to_explode.clone();
+        let unnest_structs = self.unnest_structs;
         task_spawner
             .spawn(
                 async move {
-                    let out = input.explode(&to_explode)?;
+                    let out = input.explode(&to_explode, unnest_structs)?;
                     Ok((
                         state,
                         IntermediateOperatorResult::NeedMoreInput(Some(Arc::new(out))),
@@ -48,10 +51,14 @@ impl IntermediateOperator for ExplodeOperator {
     }
 
     fn multiline_display(&self) -> Vec<String> {
-        vec![format!(
+        let mut res = vec![format!(
             "Explode: {}",
             self.to_explode.iter().map(|e| e.to_string()).join(", ")
-        )]
+        )];
+        if self.unnest_structs {
+            res.push("Unnest structs = true".to_string());
+        }
+        res
     }
 
     fn name(&self) -> &'static str {
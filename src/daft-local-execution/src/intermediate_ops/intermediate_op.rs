@@ -6,6 +6,7 @@ use common_runtime::{get_compute_pool_num_threads, get_compute_runtime};
 use daft_logical_plan::stats::StatsState;
 use daft_micropartition::MicroPartition;
 use snafu::ResultExt;
+use tokio_util::sync::CancellationToken;
 use tracing::{info_span, instrument};
 
 use crate::{
@@ -17,7 +18,7 @@ use crate::{
     pipeline::PipelineNode,
     progress_bar::ProgressBarColor,
     resource_manager::MemoryManager,
-    runtime_stats::{CountingReceiver, CountingSender, RuntimeStatsContext},
+    runtime_stats::{morsel_size_bytes, CountingReceiver, CountingSender, RuntimeStatsContext},
     ExecutionRuntimeContext, ExecutionTaskSpawner, OperatorOutput, PipelineExecutionSnafu,
 };
 
@@ -117,13 +118,21 @@ impl IntermediateNode {
         sender: Sender<Arc<MicroPartition>>,
         rt_context: Arc<RuntimeStatsContext>,
         memory_manager: Arc<MemoryManager>,
+        cancel: CancellationToken,
     ) -> DaftResult<()> {
         let span = info_span!("IntermediateOp::execute");
         let compute_runtime = get_compute_runtime();
-        let task_spawner =
-            ExecutionTaskSpawner::new(compute_runtime, memory_manager, rt_context, span);
+        let task_spawner = ExecutionTaskSpawner::new(
+            compute_runtime,
+            memory_manager,
+            rt_context.clone(),
+            span,
+            cancel,
+        );
         let mut state = op.make_state()?;
         while let Some(morsel) = receiver.recv().await {
+            let morsel_bytes = morsel_size_bytes(&morsel);
+            rt_context.mark_bytes_held(morsel_bytes);
             loop {
                 let result = op.execute(morsel.clone(), state, &task_spawner).await??;
                 state = result.0;
@@ -144,6 +153,7 @@ impl IntermediateNode {
                     }
                 }
             }
+            rt_context.mark_bytes_released(morsel_bytes);
         }
         Ok(())
     }
@@ -165,6 +175,7 @@ impl IntermediateNode {
                     output_sender,
                     self.runtime_stats.clone(),
                     memory_manager.clone(),
+                    runtime_handle.cancellation_token(),
                 ),
                 self.intermediate_op.name(),
             );
@@ -192,7 +203,9 @@ impl TreeDisplay for IntermediateNode {
                 if matches!(level, DisplayLevel::Verbose) {
                     writeln!(display).unwrap();
                     let rt_result = self.runtime_stats.result();
-                    rt_result.display(&mut display, true, true, true).unwrap();
+                    rt_result
+                        .display(&mut display, true, true, true, true)
+                        .unwrap();
                 }
             }
         }
@@ -221,6 +234,8 @@ impl PipelineNode for IntermediateNode {
         maintain_order: bool,
         runtime_handle: &mut ExecutionRuntimeContext,
     ) -> crate::Result<Receiver<Arc<MicroPartition>>> {
+        self.runtime_stats
+            .attach_memory_reservation(runtime_handle.memory_pool().register(self.name(), false));
         let mut child_result_receivers = Vec::with_capacity(self.children.len());
         let progress_bar = runtime_handle.make_progress_bar(
             self.name(),
@@ -1,9 +1,10 @@
 pub mod actor_pool_project;
+pub mod asof_join_probe;
 pub mod cross_join;
 pub mod explode;
 pub mod filter;
-pub mod inner_hash_join_probe;
 pub mod intermediate_op;
 pub mod project;
+pub mod range_join_probe;
 pub mod sample;
 pub mod unpivot;
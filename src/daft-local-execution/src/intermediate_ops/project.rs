@@ -14,7 +14,10 @@ use super::intermediate_op::{
     IntermediateOpExecuteResult, IntermediateOpState, IntermediateOperator,
     IntermediateOperatorResult,
 };
-use crate::{ExecutionRuntimeContext, ExecutionTaskSpawner};
+use crate::{
+    resource_manager::{cuda_visible_devices, GpuAllocation},
+    ExecutionRuntimeContext, ExecutionTaskSpawner,
+};
 fn num_parallel_exprs(projection: &[ExprRef]) -> usize {
     max(
         projection.iter().filter(|expr| expr.has_compute()).count(),
@@ -52,7 +55,7 @@ impl ProjectOperator {
         let resource_request = get_resource_request(projection);
         let num_cpus = get_compute_pool_num_threads();
         // The number of CPUs available for the operator.
-        let available_cpus = match resource_request {
+        let available_cpus = match &resource_request {
             // If the resource request specifies a number of CPUs, the available cpus is the number of actual CPUs
             // divided by the requested number of CPUs, clamped to (1, NUM_CPUS).
             // E.g. if the resource request specifies 2 CPUs and NUM_CPUS is 4, the number of available cpus is 2.
@@ -83,6 +86,15 @@ impl ProjectOperator {
         // This ensures even distribution across concurrent tasks
         let num_parallel_exprs = available_cpus / max_concurrency;
 
+        // UDFs that request GPUs run at most as many at once as fit on the GPUs, so that they don't oversubscribe them
+        let max_concurrency = match resource_request.and_then(|req| req.num_gpus()) {
+            Some(num_gpus) if num_gpus > 0.0 => {
+                let gpu_allocation = GpuAllocation::try_new(cuda_visible_devices()?, num_gpus)?;
+                max_concurrency.min(gpu_allocation.max_concurrency())
+            }
+            _ => max_concurrency,
+        };
+
         Ok((max_concurrency, num_parallel_exprs))
     }
 }
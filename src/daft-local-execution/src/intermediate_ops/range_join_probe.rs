@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_core::prelude::{JoinType, SchemaRef};
+use daft_dsl::ExprRef;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RangeJoinBuild;
+use itertools::Itertools;
+use tracing::{instrument, Span};
+
+use super::intermediate_op::{
+    IntermediateOpExecuteResult, IntermediateOpState, IntermediateOperator,
+    IntermediateOperatorResult,
+};
+use crate::{state_bridge::BroadcastStateBridgeRef, ExecutionTaskSpawner};
+
+struct RangeJoinProbeState(BroadcastStateBridgeRef<RangeJoinBuild>);
+
+impl IntermediateOpState for RangeJoinProbeState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+struct RangeJoinProbeParams {
+    probe_on: Vec<ExprRef>,
+    join_type: JoinType,
+}
+
+/// Streams the left side of a range join through the sorted right side. Every probe row sees
+/// the whole build side, so rows without a match can be emitted for left and anti joins.
+pub struct RangeJoinProbeOperator {
+    params: Arc<RangeJoinProbeParams>,
+    output_schema: SchemaRef,
+    state_bridge: BroadcastStateBridgeRef<RangeJoinBuild>,
+}
+
+impl RangeJoinProbeOperator {
+    pub(crate) fn new(
+        probe_on: Vec<ExprRef>,
+        join_type: JoinType,
+        output_schema: SchemaRef,
+        state_bridge: BroadcastStateBridgeRef<RangeJoinBuild>,
+    ) -> Self {
+        Self {
+            params: Arc::new(RangeJoinProbeParams {
+                probe_on,
+                join_type,
+            }),
+            output_schema,
+            state_bridge,
+        }
+    }
+}
+
+impl IntermediateOperator for RangeJoinProbeOperator {
+    #[instrument(skip_all, name = "RangeJoinProbeOperator::execute")]
+    fn execute(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn IntermediateOpState>,
+        task_spawner: &ExecutionTaskSpawner,
+    ) -> IntermediateOpExecuteResult {
+        if input.is_empty() {
+            let empty = Arc::new(MicroPartition::empty(Some(self.output_schema.clone())));
+            return Ok((
+                state,
+                IntermediateOperatorResult::NeedMoreInput(Some(empty)),
+            ))
+            .into();
+        }
+
+        let params = self.params.clone();
+        let output_schema = self.output_schema.clone();
+        task_spawner
+            .spawn(
+                async move {
+                    let build = state
+                        .as_any_mut()
+                        .downcast_mut::<RangeJoinProbeState>()
+                        .expect("RangeJoinProbeState should be used with RangeJoinProbeOperator")
+                        .0
+                        .get_state()
+                        .await;
+                    let output_tables = input
+                        .get_tables()?
+                        .iter()
+                        .map(|t| build.probe(t, &params.probe_on, params.join_type))
+                        .collect::<DaftResult<Vec<_>>>()?;
+                    let output = Arc::new(MicroPartition::new_loaded(
+                        output_schema,
+                        Arc::new(output_tables),
+                        None,
+                    ));
+                    Ok((
+                        state,
+                        IntermediateOperatorResult::NeedMoreInput(Some(output)),
+                    ))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn name(&self) -> &'static str {
+        "RangeJoinProbe"
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![
+            "RangeJoinProbe:".to_string(),
+            format!("Join type: {}", self.params.join_type),
+        ];
+        if !self.params.probe_on.is_empty() {
+            res.push(format!(
+                "Probe on: [{}]",
+                self.params
+                    .probe_on
+                    .iter()
+                    .map(|e| e.to_string())
+                    .join(", ")
+            ));
+        }
+        res
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn IntermediateOpState>> {
+        Ok(Box::new(RangeJoinProbeState(self.state_bridge.clone())))
+    }
+}
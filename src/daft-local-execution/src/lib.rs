@@ -11,6 +11,7 @@ mod run;
 mod runtime_stats;
 mod sinks;
 mod sources;
+mod spill;
 mod state_bridge;
 
 use std::{
@@ -22,11 +23,13 @@ use std::{
 
 use common_error::{DaftError, DaftResult};
 use common_runtime::{RuntimeRef, RuntimeTask};
+use daft_io::QueryIOStats;
 use progress_bar::{OperatorProgressBar, ProgressBarColor, ProgressBarManager};
-use resource_manager::MemoryManager;
+use resource_manager::{MemoryManager, MemoryPool};
 pub use run::{ExecutionEngineResult, NativeExecutor};
 use runtime_stats::{RuntimeStatsContext, TimedFuture};
 use snafu::{futures::TryFutureExt, ResultExt, Snafu};
+use tokio_util::sync::CancellationToken;
 use tracing::Instrument;
 
 /// The `OperatorOutput` enum represents the output of an operator.
@@ -117,22 +120,34 @@ impl RuntimeHandle {
 pub(crate) struct ExecutionRuntimeContext {
     worker_set: TaskSet<crate::Result<()>>,
     default_morsel_size: usize,
+    deterministic: bool,
     memory_manager: Arc<MemoryManager>,
+    memory_pool: Arc<MemoryPool>,
     progress_bar_manager: Option<Arc<dyn ProgressBarManager>>,
+    cancel: CancellationToken,
+    io_stats: Arc<QueryIOStats>,
 }
 
 impl ExecutionRuntimeContext {
     #[must_use]
     pub fn new(
         default_morsel_size: usize,
+        deterministic: bool,
         memory_manager: Arc<MemoryManager>,
+        memory_pool: Arc<MemoryPool>,
         progress_bar_manager: Option<Arc<dyn ProgressBarManager>>,
+        cancel: CancellationToken,
+        io_stats: Arc<QueryIOStats>,
     ) -> Self {
         Self {
             worker_set: TaskSet::new(),
             default_morsel_size,
+            deterministic,
             memory_manager,
+            memory_pool,
             progress_bar_manager,
+            cancel,
+            io_stats,
         }
     }
     pub fn spawn(
@@ -158,6 +173,12 @@ impl ExecutionRuntimeContext {
         self.default_morsel_size
     }
 
+    /// Whether the query must produce the same results on every run, which makes operators that would otherwise
+    /// consume their inputs in any order consume them in order.
+    pub fn deterministic(&self) -> bool {
+        self.deterministic
+    }
+
     pub fn make_progress_bar(
         &self,
         prefix: &str,
@@ -185,6 +206,29 @@ impl ExecutionRuntimeContext {
     pub(crate) fn memory_manager(&self) -> Arc<MemoryManager> {
         self.memory_manager.clone()
     }
+
+    /// The memory pool of the query, which its operators account the memory they hold against.
+    #[must_use]
+    pub(crate) fn memory_pool(&self) -> Arc<MemoryPool> {
+        self.memory_pool.clone()
+    }
+
+    /// The token that is cancelled when the query is, which operators stop scanning, computing and writing on.
+    #[must_use]
+    pub(crate) fn cancellation_token(&self) -> CancellationToken {
+        self.cancel.clone()
+    }
+
+    /// The IO of the query, which operators that read or write data count theirs towards.
+    #[must_use]
+    pub(crate) fn io_stats(&self) -> &QueryIOStats {
+        &self.io_stats
+    }
+}
+
+/// The error of operators that stopped because their query was cancelled.
+pub(crate) fn query_cancelled() -> DaftError {
+    DaftError::Cancelled("Query was cancelled".to_string())
 }
 
 impl Drop for ExecutionRuntimeContext {
@@ -195,11 +239,14 @@ impl Drop for ExecutionRuntimeContext {
     }
 }
 
+/// Spawns the tasks of an operator on a runtime. Tasks stop at their next await point once the query is cancelled,
+/// and aren't started at all after that.
 pub(crate) struct ExecutionTaskSpawner {
     runtime_ref: RuntimeRef,
     memory_manager: Arc<MemoryManager>,
     runtime_context: Arc<RuntimeStatsContext>,
     outer_span: tracing::Span,
+    cancel: CancellationToken,
 }
 
 impl ExecutionTaskSpawner {
@@ -208,12 +255,14 @@ impl ExecutionTaskSpawner {
         memory_manager: Arc<MemoryManager>,
         runtime_context: Arc<RuntimeStatsContext>,
         span: tracing::Span,
+        cancel: CancellationToken,
     ) -> Self {
         Self {
             runtime_ref,
             memory_manager,
             runtime_context,
             outer_span: span,
+            cancel,
         }
     }
 
@@ -234,9 +283,16 @@ impl ExecutionTaskSpawner {
             self.outer_span.clone(),
         );
         let memory_manager = self.memory_manager.clone();
+        let cancel = self.cancel.clone();
         self.runtime_ref.spawn(async move {
-            let _permit = memory_manager.request_bytes(memory_request).await?;
-            timed_fut.await
+            tokio::select! {
+                biased;
+                () = cancel.cancelled() => Err(query_cancelled()),
+                result = async {
+                    let _permit = memory_manager.request_bytes(memory_request).await?;
+                    timed_fut.await
+                } => result,
+            }
         })
     }
 
@@ -251,7 +307,19 @@ impl ExecutionTaskSpawner {
             self.runtime_context.clone(),
             self.outer_span.clone(),
         );
-        self.runtime_ref.spawn(timed_fut)
+        let cancel = self.cancel.clone();
+        self.runtime_ref.spawn(async move {
+            tokio::select! {
+                biased;
+                () = cancel.cancelled() => Err(query_cancelled()),
+                result = timed_fut => result,
+            }
+        })
+    }
+
+    /// The runtime stats of the operator that spawns the tasks, for the tasks to report on.
+    pub(crate) fn runtime_stats(&self) -> Arc<RuntimeStatsContext> {
+        self.runtime_context.clone()
     }
 }
 
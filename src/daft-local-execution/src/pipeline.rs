@@ -10,12 +10,12 @@ use common_display::{
 use common_error::{DaftError, DaftResult};
 use common_file_formats::FileFormat;
 use daft_core::{join::JoinSide, prelude::Schema};
-use daft_dsl::{join::get_common_join_cols, resolved_col};
+use daft_dsl::{join::get_common_join_cols, resolved_col, Expr, ExprRef};
 use daft_local_plan::{
-    ActorPoolProject, Concat, CrossJoin, EmptyScan, Explode, Filter, HashAggregate, HashJoin,
-    InMemoryScan, Limit, LocalPhysicalPlan, MonotonicallyIncreasingId, PhysicalWrite, Pivot,
-    Project, Sample, Sort, UnGroupedAggregate, Unpivot, WindowPartitionAndOrderBy,
-    WindowPartitionOnly,
+    ActorPoolProject, AsofJoin, Concat, CrossJoin, EmptyScan, Explode, Filter, HashAggregate,
+    HashJoin, InMemoryScan, Limit, LocalPhysicalPlan, MonotonicallyIncreasingId, PhysicalScan,
+    PhysicalWrite, Pivot, Project, RangeJoin, Sample, Sort, TopN, UnGroupedAggregate, Unpivot,
+    WindowPartitionAndOrderBy, WindowPartitionOnly,
 };
 use daft_logical_plan::{stats::StatsState, JoinType};
 use daft_micropartition::{
@@ -30,30 +30,37 @@ use snafu::ResultExt;
 use crate::{
     channel::Receiver,
     intermediate_ops::{
-        actor_pool_project::ActorPoolProjectOperator, cross_join::CrossJoinOperator,
-        explode::ExplodeOperator, filter::FilterOperator,
-        inner_hash_join_probe::InnerHashJoinProbeOperator, intermediate_op::IntermediateNode,
-        project::ProjectOperator, sample::SampleOperator, unpivot::UnpivotOperator,
+        actor_pool_project::ActorPoolProjectOperator, asof_join_probe::AsofJoinProbeOperator,
+        cross_join::CrossJoinOperator, explode::ExplodeOperator, filter::FilterOperator,
+        intermediate_op::IntermediateNode, project::ProjectOperator,
+        range_join_probe::RangeJoinProbeOperator, sample::SampleOperator, unpivot::UnpivotOperator,
     },
     sinks::{
         aggregate::AggregateSink,
         anti_semi_hash_join_probe::AntiSemiProbeSink,
+        asof_join_build::AsofJoinBuildSink,
         blocking_sink::BlockingSinkNode,
         concat::ConcatSink,
         cross_join_collect::CrossJoinCollectSink,
         grouped_aggregate::GroupedAggregateSink,
         hash_join_build::HashJoinBuildSink,
+        inner_hash_join_probe::InnerHashJoinProbeSink,
         limit::LimitSink,
         monotonically_increasing_id::MonotonicallyIncreasingIdSink,
         outer_hash_join_probe::OuterHashJoinProbeSink,
         pivot::PivotSink,
+        range_join_build::RangeJoinBuildSink,
         sort::SortSink,
         streaming_sink::StreamingSinkNode,
+        top_n::TopNSink,
         window_partition_and_order_by::WindowPartitionAndOrderBySink,
         window_partition_only::WindowPartitionOnlySink,
         write::{WriteFormat, WriteSink},
     },
-    sources::{empty_scan::EmptyScanSource, in_memory::InMemorySource, source::SourceNode},
+    sources::{
+        empty_scan::EmptyScanSource, in_memory::InMemorySource, scan_task::ScanTaskSource,
+        source::SourceNode,
+    },
     state_bridge::BroadcastStateBridge,
     ExecutionRuntimeContext, PipelineCreationSnafu,
 };
@@ -83,25 +90,66 @@ pub fn viz_pipeline_mermaid(
     output
 }
 
-pub fn viz_pipeline_ascii(root: &dyn PipelineNode, simple: bool) -> String {
+pub fn viz_pipeline_ascii(root: &dyn PipelineNode, level: DisplayLevel) -> String {
     let mut s = String::new();
-    let level = if simple {
-        DisplayLevel::Compact
-    } else {
-        DisplayLevel::Default
-    };
     fmt_tree_gitstyle(root.as_tree_display(), 0, &mut s, level).unwrap();
     s
 }
 
+fn scan_task_source(
+    PhysicalScan {
+        scan_tasks,
+        pushdowns,
+        schema,
+        ..
+    }: &PhysicalScan,
+    cfg: &DaftExecutionConfig,
+) -> ScanTaskSource {
+    let scan_tasks = scan_tasks
+        .iter()
+        .map(|task| task.clone().as_any_arc().downcast().unwrap())
+        .collect::<Vec<ScanTaskRef>>();
+    ScanTaskSource::new(scan_tasks, pushdowns.clone(), schema.clone(), cfg)
+}
+
+/// Finds a join key that can be used for dynamic partition pruning, i.e. a key whose probe side is a partition
+/// column of a scan directly below the join. Returns the index of the key and the name of the partition column.
+fn find_partition_pruning_key(
+    probe_child: &LocalPhysicalPlan,
+    probe_on: &[ExprRef],
+    null_equals_null: Option<&Vec<bool>>,
+) -> Option<(usize, String)> {
+    let LocalPhysicalPlan::PhysicalScan(PhysicalScan {
+        scan_tasks,
+        pushdowns,
+        ..
+    }) = probe_child
+    else {
+        return None;
+    };
+    // Pruning a limited scan would change which rows fall within the limit.
+    if pushdowns.limit.is_some() {
+        return None;
+    }
+    // All scan tasks of a scan share the same partition columns.
+    let scan_task: ScanTaskRef = scan_tasks.first()?.clone().as_any_arc().downcast().ok()?;
+    let partition_schema = scan_task.partition_spec()?.keys.schema.clone();
+    probe_on.iter().enumerate().find_map(|(idx, key)| {
+        // Null partition values are never considered to match, so we can't prune on null-safe keys.
+        let nulls_equal = null_equals_null.is_some_and(|nulls_equal| nulls_equal[idx]);
+        let name = key.name();
+        (matches!(key.as_ref(), Expr::Column(_))
+            && !nulls_equal
+            && partition_schema.has_field(name))
+        .then(|| (idx, name.to_string()))
+    })
+}
+
 pub fn physical_plan_to_pipeline(
     physical_plan: &LocalPhysicalPlan,
     psets: &(impl PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>> + ?Sized),
     cfg: &Arc<DaftExecutionConfig>,
 ) -> crate::Result<Box<dyn PipelineNode>> {
-    use daft_local_plan::PhysicalScan;
-
-    use crate::sources::scan_task::ScanTaskSource;
     let out: Box<dyn PipelineNode> = match physical_plan {
         LocalPhysicalPlan::EmptyScan(EmptyScan {
             schema,
@@ -110,20 +158,9 @@ pub fn physical_plan_to_pipeline(
             let source = EmptyScanSource::new(schema.clone());
             SourceNode::new(source.arced(), stats_state.clone()).boxed()
         }
-        LocalPhysicalPlan::PhysicalScan(PhysicalScan {
-            scan_tasks,
-            pushdowns,
-            schema,
-            stats_state,
-        }) => {
-            let scan_tasks = scan_tasks
-                .iter()
-                .map(|task| task.clone().as_any_arc().downcast().unwrap())
-                .collect::<Vec<ScanTaskRef>>();
-
-            let scan_task_source =
-                ScanTaskSource::new(scan_tasks, pushdowns.clone(), schema.clone(), cfg);
-            SourceNode::new(scan_task_source.arced(), stats_state.clone()).boxed()
+        LocalPhysicalPlan::PhysicalScan(scan) => {
+            let scan_task_source = scan_task_source(scan, cfg);
+            SourceNode::new(scan_task_source.arced(), scan.stats_state.clone()).boxed()
         }
         LocalPhysicalPlan::WindowPartitionOnly(WindowPartitionOnly {
             input,
@@ -151,6 +188,8 @@ pub fn physical_plan_to_pipeline(
             partition_by,
             order_by,
             descending,
+            frame,
+            min_periods,
             schema,
             stats_state,
             functions,
@@ -163,6 +202,8 @@ pub fn physical_plan_to_pipeline(
                 partition_by,
                 order_by,
                 descending,
+                frame.as_ref(),
+                *min_periods,
                 schema,
             )
             .with_context(|_| PipelineCreationSnafu {
@@ -183,6 +224,7 @@ pub fn physical_plan_to_pipeline(
                 materialized_pset,
                 info.source_schema.clone(),
                 info.size_bytes,
+                info.persisted,
             )
             .arced();
             SourceNode::new(in_memory_source, stats_state.clone()).boxed()
@@ -243,10 +285,11 @@ pub fn physical_plan_to_pipeline(
         LocalPhysicalPlan::Explode(Explode {
             input,
             to_explode,
+            unnest_structs,
             stats_state,
             ..
         }) => {
-            let explode_op = ExplodeOperator::new(to_explode.clone());
+            let explode_op = ExplodeOperator::new(to_explode.clone(), *unnest_structs);
             let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
             IntermediateNode::new(Arc::new(explode_op), vec![child_node], stats_state.clone())
                 .boxed()
@@ -354,10 +397,35 @@ pub fn physical_plan_to_pipeline(
             stats_state,
             ..
         }) => {
-            let sort_sink = SortSink::new(sort_by.clone(), descending.clone(), nulls_first.clone());
+            let sort_sink = SortSink::new(
+                sort_by.clone(),
+                descending.clone(),
+                nulls_first.clone(),
+                cfg.deterministic,
+            );
             let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
             BlockingSinkNode::new(Arc::new(sort_sink), child_node, stats_state.clone()).boxed()
         }
+        LocalPhysicalPlan::TopN(TopN {
+            input,
+            sort_by,
+            descending,
+            nulls_first,
+            limit,
+            schema,
+            stats_state,
+        }) => {
+            let top_n_sink = TopNSink::new(
+                sort_by.clone(),
+                descending.clone(),
+                nulls_first.clone(),
+                *limit as usize,
+                schema.clone(),
+                cfg.deterministic,
+            );
+            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            BlockingSinkNode::new(Arc::new(top_n_sink), child_node, stats_state.clone()).boxed()
+        }
         LocalPhysicalPlan::MonotonicallyIncreasingId(MonotonicallyIncreasingId {
             input,
             column_name,
@@ -509,13 +577,48 @@ pub fn physical_plan_to_pipeline(
                 } else {
                     true
                 };
-                let build_sink = HashJoinBuildSink::new(
+                let mut build_sink = HashJoinBuildSink::new(
                     key_schema,
                     build_on.clone(),
                     null_equals_null.clone(),
                     track_indices,
                     probe_state_bridge.clone(),
                 )?;
+
+                // If the probe side is a partitioned scan, we can skip its partitions that can't match any key on
+                // the build side, unless the join needs to keep unmatched rows from the probe side.
+                let keeps_unmatched_probe_rows = match join_type {
+                    JoinType::Inner | JoinType::Semi => false,
+                    JoinType::Left | JoinType::Anti => !build_on_left,
+                    JoinType::Right => build_on_left,
+                    JoinType::Outer => true,
+                };
+                let partition_pruning = if cfg.dynamic_partition_pruning_max_keys > 0
+                    && !keeps_unmatched_probe_rows
+                {
+                    find_partition_pruning_key(probe_child, probe_on, null_equals_null.as_ref())
+                        .map(|(key_idx, column)| (key_idx, column, BroadcastStateBridge::new()))
+                } else {
+                    None
+                };
+                if let Some((key_idx, _, bridge)) = &partition_pruning {
+                    build_sink = build_sink.with_partition_pruning(
+                        build_on[*key_idx].clone(),
+                        cfg.dynamic_partition_pruning_max_keys,
+                        bridge.clone(),
+                    );
+                }
+
+                // Inner joins can spill partitions of the build side to disk and join them after the probe side is
+                // done, since each row of the output only depends on rows with the same join keys.
+                let spilled_build_bridge = BroadcastStateBridge::new();
+                if *join_type == JoinType::Inner {
+                    build_sink = build_sink.with_spilling(
+                        cfg.operator_memory_limit_bytes,
+                        spilled_build_bridge.clone(),
+                    );
+                }
+
                 let build_child_node = physical_plan_to_pipeline(build_child, psets, cfg)?;
                 let build_node = BlockingSinkNode::new(
                     Arc::new(build_sink),
@@ -524,7 +627,14 @@ pub fn physical_plan_to_pipeline(
                 )
                 .boxed();
 
-                let probe_child_node = physical_plan_to_pipeline(probe_child, psets, cfg)?;
+                let probe_child_node = match (partition_pruning, probe_child.as_ref()) {
+                    (Some((_, column, bridge)), LocalPhysicalPlan::PhysicalScan(scan)) => {
+                        let scan_task_source =
+                            scan_task_source(scan, cfg).with_partition_pruning(column, bridge);
+                        SourceNode::new(scan_task_source.arced(), scan.stats_state.clone()).boxed()
+                    }
+                    _ => physical_plan_to_pipeline(probe_child, psets, cfg)?,
+                };
 
                 match join_type {
                     JoinType::Anti | JoinType::Semi => Ok(StreamingSinkNode::new(
@@ -539,8 +649,8 @@ pub fn physical_plan_to_pipeline(
                         stats_state.clone(),
                     )
                     .boxed()),
-                    JoinType::Inner => Ok(IntermediateNode::new(
-                        Arc::new(InnerHashJoinProbeOperator::new(
+                    JoinType::Inner => Ok(StreamingSinkNode::new(
+                        Arc::new(InnerHashJoinProbeSink::new(
                             probe_on.clone(),
                             left_schema,
                             right_schema,
@@ -548,6 +658,7 @@ pub fn physical_plan_to_pipeline(
                             common_join_cols,
                             schema,
                             probe_state_bridge,
+                            spilled_build_bridge,
                         )),
                         vec![build_node, probe_child_node],
                         stats_state.clone(),
@@ -634,6 +745,91 @@ pub fn physical_plan_to_pipeline(
             )
             .boxed()
         }
+        LocalPhysicalPlan::RangeJoin(RangeJoin {
+            left,
+            right,
+            left_on,
+            right_on,
+            conditions,
+            residual,
+            join_type,
+            schema,
+            stats_state,
+        }) => {
+            // The right side is always built, since the left side's rows must each see all of it
+            // for left, semi and anti joins.
+            let probe_child_node = physical_plan_to_pipeline(left, psets, cfg)?;
+            let build_child_node = physical_plan_to_pipeline(right, psets, cfg)?;
+
+            let state_bridge = BroadcastStateBridge::new();
+            let build_node = BlockingSinkNode::new(
+                Arc::new(RangeJoinBuildSink::new(
+                    right.schema().clone(),
+                    right_on.clone(),
+                    conditions.clone(),
+                    residual.clone(),
+                    state_bridge.clone(),
+                )),
+                build_child_node,
+                right.get_stats_state().clone(),
+            )
+            .boxed();
+
+            IntermediateNode::new(
+                Arc::new(RangeJoinProbeOperator::new(
+                    left_on.clone(),
+                    *join_type,
+                    schema.clone(),
+                    state_bridge,
+                )),
+                vec![build_node, probe_child_node],
+                stats_state.clone(),
+            )
+            .boxed()
+        }
+        LocalPhysicalPlan::AsofJoin(AsofJoin {
+            left,
+            right,
+            left_on,
+            right_on,
+            left_by,
+            right_by,
+            strategy,
+            tolerance,
+            schema,
+            stats_state,
+        }) => {
+            // The right side is always built, since each left row keeps its nearest right row.
+            let probe_child_node = physical_plan_to_pipeline(left, psets, cfg)?;
+            let build_child_node = physical_plan_to_pipeline(right, psets, cfg)?;
+
+            let state_bridge = BroadcastStateBridge::new();
+            let build_node = BlockingSinkNode::new(
+                Arc::new(AsofJoinBuildSink::new(
+                    right.schema().clone(),
+                    right_by.clone(),
+                    right_on.clone(),
+                    *strategy,
+                    tolerance.clone(),
+                    state_bridge.clone(),
+                )),
+                build_child_node,
+                right.get_stats_state().clone(),
+            )
+            .boxed();
+
+            IntermediateNode::new(
+                Arc::new(AsofJoinProbeOperator::new(
+                    left_by.clone(),
+                    left_on.clone(),
+                    schema.clone(),
+                    state_bridge,
+                )),
+                vec![build_node, probe_child_node],
+                stats_state.clone(),
+            )
+            .boxed()
+        }
         LocalPhysicalPlan::PhysicalWrite(PhysicalWrite {
             input,
             file_info,
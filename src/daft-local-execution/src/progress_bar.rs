@@ -13,6 +13,11 @@ use crate::runtime_stats::RuntimeStatsContext;
 
 pub trait ProgressBar: Send + Sync {
     fn set_message(&self, message: String) -> DaftResult<()>;
+    /// Reports the number of rows that the operator has emitted so far, for bars that track progress rather than
+    /// only display it.
+    fn set_rows_emitted(&self, _rows_emitted: u64) -> DaftResult<()> {
+        Ok(())
+    }
     fn close(&self) -> DaftResult<()>;
 }
 
@@ -104,12 +109,16 @@ impl OperatorProgressBar {
                 format!("{} rows emitted", HumanCount(rows_emitted))
             };
             let _ = self.inner_progress_bar.set_message(msg);
+            let _ = self.inner_progress_bar.set_rows_emitted(rows_emitted);
         }
     }
 }
 
 impl Drop for OperatorProgressBar {
     fn drop(&mut self) {
+        let _ = self
+            .inner_progress_bar
+            .set_rows_emitted(self.runtime_stats.get_rows_emitted());
         let _ = self.inner_progress_bar.close();
     }
 }
@@ -186,8 +195,20 @@ pub fn make_progress_bar_manager() -> Arc<dyn ProgressBarManager> {
     }
 }
 
+/// Makes a progress bar manager that reports the progress of operators to a `daft.progress.QueryProgressTracker`,
+/// as well as showing it on the bars of `inner`, if any.
+#[cfg(feature = "python")]
+pub fn make_reporting_progress_bar_manager(
+    tracker: pyo3::PyObject,
+    inner: Option<Arc<dyn ProgressBarManager>>,
+) -> Arc<dyn ProgressBarManager> {
+    Arc::new(python::ReportingProgressBarManager::new(tracker, inner))
+}
+
 #[cfg(feature = "python")]
 mod python {
+    use std::sync::atomic::AtomicUsize;
+
     use pyo3::{types::PyAnyMethods, PyObject, Python};
 
     use super::*;
@@ -277,4 +298,88 @@ mod python {
             })
         }
     }
+
+    /// Reports each operator to the tracker as a stage with a single task, which finishes when the operator does.
+    struct ReportingProgressBar {
+        id: usize,
+        tracker: Arc<PyObject>,
+        inner: Option<Box<dyn ProgressBar>>,
+    }
+
+    impl ProgressBar for ReportingProgressBar {
+        fn set_message(&self, message: String) -> DaftResult<()> {
+            match &self.inner {
+                Some(inner) => inner.set_message(message),
+                None => Ok(()),
+            }
+        }
+
+        fn set_rows_emitted(&self, rows_emitted: u64) -> DaftResult<()> {
+            Python::with_gil(|py| {
+                self.tracker
+                    .call_method1(py, "set_rows_processed", (self.id, rows_emitted))?;
+                DaftResult::Ok(())
+            })
+        }
+
+        fn close(&self) -> DaftResult<()> {
+            if let Some(inner) = &self.inner {
+                inner.close()?;
+            }
+            Python::with_gil(|py| {
+                self.tracker
+                    .call_method1(py, "task_finished", (self.id, 0, py.None()))?;
+                DaftResult::Ok(())
+            })
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct ReportingProgressBarManager {
+        tracker: Arc<PyObject>,
+        inner: Option<Arc<dyn ProgressBarManager>>,
+        next_id: AtomicUsize,
+    }
+
+    impl ReportingProgressBarManager {
+        pub fn new(tracker: PyObject, inner: Option<Arc<dyn ProgressBarManager>>) -> Self {
+            Self {
+                tracker: Arc::new(tracker),
+                inner,
+                next_id: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    impl ProgressBarManager for ReportingProgressBarManager {
+        fn make_new_bar(
+            &self,
+            color: ProgressBarColor,
+            prefix: &str,
+        ) -> DaftResult<Box<dyn ProgressBar>> {
+            let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+            Python::with_gil(|py| {
+                self.tracker
+                    .call_method1(py, "task_started", (id, prefix))?;
+                DaftResult::Ok(())
+            })?;
+            let inner = self
+                .inner
+                .as_ref()
+                .map(|inner| inner.make_new_bar(color, prefix))
+                .transpose()?;
+            Ok(Box::new(ReportingProgressBar {
+                id,
+                tracker: self.tracker.clone(),
+                inner,
+            }))
+        }
+
+        fn close_all(&self) -> DaftResult<()> {
+            match &self.inner {
+                Some(inner) => inner.close_all(),
+                None => Ok(()),
+            }
+        }
+    }
 }
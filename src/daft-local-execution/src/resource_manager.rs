@@ -1,4 +1,7 @@
-use std::sync::{Arc, Mutex, OnceLock};
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, Mutex, OnceLock,
+};
 
 use common_error::{DaftError, DaftResult};
 use common_system_info::SystemInfo;
@@ -62,6 +65,10 @@ impl Default for MemoryManager {
 }
 
 impl MemoryManager {
+    pub fn total_bytes(&self) -> u64 {
+        self.total_bytes
+    }
+
     pub fn new() -> Self {
         if let Some(custom_limit) = custom_memory_limit() {
             Self {
@@ -113,6 +120,193 @@ impl MemoryManager {
     }
 }
 
+#[derive(Default)]
+struct MemoryPoolState {
+    unspillable_bytes: u64,
+    spillable_bytes: u64,
+    num_spillable: usize,
+}
+
+impl MemoryPoolState {
+    /// The number of bytes held by all operators of the query.
+    fn reserved_bytes(&self) -> u64 {
+        self.unspillable_bytes + self.spillable_bytes
+    }
+}
+
+/// The memory budget of a single query, shared by the operators of its pipeline.
+///
+/// Operators account for the memory they hold with a [`MemoryReservation`]. Operators that can't spill always get the
+/// memory they hold, and what they leave of the budget is shared fairly between the operators that can spill, which
+/// are asked to spill once they hold more than their share.
+pub(crate) struct MemoryPool {
+    limit_bytes: u64,
+    state: Mutex<MemoryPoolState>,
+}
+
+impl MemoryPool {
+    pub fn new(limit_bytes: u64) -> Arc<Self> {
+        Arc::new(Self {
+            limit_bytes,
+            state: Mutex::new(MemoryPoolState::default()),
+        })
+    }
+
+    pub fn register(self: &Arc<Self>, name: &str, spillable: bool) -> Arc<MemoryReservation> {
+        if spillable {
+            self.state.lock().unwrap().num_spillable += 1;
+        }
+        Arc::new(MemoryReservation {
+            pool: self.clone(),
+            name: name.to_string(),
+            spillable,
+            bytes: AtomicU64::new(0),
+        })
+    }
+
+    fn fair_share_bytes(&self, state: &MemoryPoolState) -> u64 {
+        self.limit_bytes.saturating_sub(state.unspillable_bytes) / state.num_spillable.max(1) as u64
+    }
+}
+
+/// The memory held by an operator of a query, accounted against the query's [`MemoryPool`].
+pub(crate) struct MemoryReservation {
+    pool: Arc<MemoryPool>,
+    name: String,
+    spillable: bool,
+    bytes: AtomicU64,
+}
+
+impl MemoryReservation {
+    pub fn grow(&self, bytes: u64) {
+        let mut state = self.pool.state.lock().unwrap();
+        if self.spillable {
+            state.spillable_bytes += bytes;
+        } else {
+            state.unspillable_bytes += bytes;
+        }
+        let held = self.bytes.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        if state.reserved_bytes() > self.pool.limit_bytes {
+            log::debug!(
+                "{} holds {} bytes, which puts its query over its memory limit of {} bytes",
+                self.name,
+                held,
+                self.pool.limit_bytes
+            );
+        }
+    }
+
+    pub fn shrink(&self, bytes: u64) {
+        let mut state = self.pool.state.lock().unwrap();
+        // Saturate instead of wrapping, since the bytes may be released after they were all freed.
+        let released = self
+            .bytes
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |held| {
+                Some(held.saturating_sub(bytes))
+            })
+            .unwrap()
+            .min(bytes);
+        if self.spillable {
+            state.spillable_bytes -= released;
+        } else {
+            state.unspillable_bytes -= released;
+        }
+    }
+
+    pub fn free(&self) {
+        self.shrink(u64::MAX);
+    }
+
+    pub fn size(&self) -> u64 {
+        self.bytes.load(Ordering::Relaxed)
+    }
+
+    /// Whether the operator holds more than its fair share of the query's memory, and should spill. Always false for
+    /// operators that can't spill.
+    pub fn exceeds_fair_share(&self) -> bool {
+        if !self.spillable {
+            return false;
+        }
+        let state = self.pool.state.lock().unwrap();
+        self.size() > self.pool.fair_share_bytes(&state)
+    }
+}
+
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        self.free();
+        if self.spillable {
+            self.pool.state.lock().unwrap().num_spillable -= 1;
+        }
+    }
+}
+
+/// The CUDA devices visible to this process, which UDFs that request GPUs are scheduled onto.
+pub(crate) fn cuda_visible_devices() -> DaftResult<Vec<String>> {
+    #[cfg(feature = "python")]
+    {
+        use pyo3::prelude::*;
+
+        Ok(Python::with_gil(|py| {
+            py.import(pyo3::intern!(py, "daft.internal.gpu"))?
+                .call_method0(pyo3::intern!(py, "cuda_visible_devices"))?
+                .extract::<Vec<String>>()
+        })?)
+    }
+
+    #[cfg(not(feature = "python"))]
+    {
+        Ok(vec![])
+    }
+}
+
+/// Assigns GPUs to the instances of an operator that each request `num_gpus` GPUs, so that no GPU is oversubscribed.
+///
+/// Like Ray, requests of more than one GPU are for whole GPUs, while each GPU is shared by as many instances requesting a
+/// fraction of one as fit on it.
+pub(crate) struct GpuAllocation {
+    devices: Vec<String>,
+    num_gpus: f64,
+}
+
+impl GpuAllocation {
+    pub fn try_new(devices: Vec<String>, num_gpus: f64) -> DaftResult<Self> {
+        if num_gpus > devices.len() as f64 {
+            return Err(DaftError::ValueError(format!(
+                "Requested {} GPUs but found only {} available",
+                num_gpus,
+                devices.len()
+            )));
+        }
+        Ok(Self { devices, num_gpus })
+    }
+
+    fn instances_per_gpu(&self) -> usize {
+        // Tolerate rounding, e.g. so that requests of 0.1 GPUs fit 10 instances on a GPU
+        (1.0 / self.num_gpus + 1e-9).floor() as usize
+    }
+
+    /// The most instances that can run at once without oversubscribing a GPU.
+    pub fn max_concurrency(&self) -> usize {
+        if self.num_gpus >= 1.0 {
+            self.devices.len() / self.num_gpus as usize
+        } else {
+            self.devices.len() * self.instances_per_gpu()
+        }
+    }
+
+    /// The GPUs of the `instance`-th instance, as a `CUDA_VISIBLE_DEVICES` value.
+    pub fn devices_for_instance(&self, instance: usize) -> String {
+        let instance = instance % self.max_concurrency();
+        if self.num_gpus >= 1.0 {
+            let per_instance = self.num_gpus as usize;
+            self.devices[instance * per_instance..(instance + 1) * per_instance].join(",")
+        } else {
+            self.devices[instance / self.instances_per_gpu()].clone()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::time::Duration;
@@ -247,4 +441,87 @@ mod tests {
 
         task_set.join_all().await;
     }
+
+    #[test]
+    fn test_memory_pool_accounts_reservations() {
+        let pool = MemoryPool::new(100);
+        let scan = pool.register("Scan", false);
+        let sort = pool.register("Sort", false);
+        scan.grow(10);
+        sort.grow(30);
+        assert_eq!(pool.state.lock().unwrap().reserved_bytes(), 40);
+
+        sort.shrink(50);
+        assert_eq!(sort.size(), 0);
+        assert_eq!(pool.state.lock().unwrap().reserved_bytes(), 10);
+
+        drop(scan);
+        assert_eq!(pool.state.lock().unwrap().reserved_bytes(), 0);
+    }
+
+    #[test]
+    fn test_memory_pool_shares_fairly_between_spillable_reservations() {
+        let pool = MemoryPool::new(100);
+        let scan = pool.register("Scan", false);
+        let agg = pool.register("GroupedAggregate", true);
+        scan.grow(20);
+
+        // A single spillable reservation gets everything the unspillable ones leave.
+        agg.grow(80);
+        assert!(!agg.exceeds_fair_share());
+
+        // Which is split once another one registers.
+        let join = pool.register("HashJoinBuild", true);
+        assert!(agg.exceeds_fair_share());
+        join.grow(40);
+        assert!(!join.exceeds_fair_share());
+
+        agg.shrink(40);
+        assert!(!agg.exceeds_fair_share());
+
+        // Unspillable reservations shrink the shares, but are never asked to spill.
+        scan.grow(100);
+        assert!(!scan.exceeds_fair_share());
+        assert!(agg.exceeds_fair_share());
+        assert!(join.exceeds_fair_share());
+
+        drop(join);
+        scan.free();
+        assert!(!agg.exceeds_fair_share());
+    }
+
+    fn gpus(n: usize) -> Vec<String> {
+        (0..n).map(|i| i.to_string()).collect()
+    }
+
+    #[test]
+    fn test_gpu_allocation_whole_gpus() {
+        let allocation = GpuAllocation::try_new(gpus(4), 2.0).unwrap();
+        assert_eq!(allocation.max_concurrency(), 2);
+        assert_eq!(allocation.devices_for_instance(0), "0,1");
+        assert_eq!(allocation.devices_for_instance(1), "2,3");
+    }
+
+    #[test]
+    fn test_gpu_allocation_fractional_gpus() {
+        let allocation = GpuAllocation::try_new(gpus(2), 0.3).unwrap();
+        assert_eq!(allocation.max_concurrency(), 6);
+        let devices = (0..6)
+            .map(|i| allocation.devices_for_instance(i))
+            .collect::<Vec<_>>();
+        assert_eq!(devices, ["0", "0", "0", "1", "1", "1"]);
+
+        assert_eq!(
+            GpuAllocation::try_new(gpus(1), 0.1)
+                .unwrap()
+                .max_concurrency(),
+            10
+        );
+    }
+
+    #[test]
+    fn test_gpu_allocation_more_gpus_than_available() {
+        assert!(GpuAllocation::try_new(gpus(1), 2.0).is_err());
+        assert!(GpuAllocation::try_new(gpus(0), 0.5).is_err());
+    }
 }
@@ -2,21 +2,31 @@ use std::{
     collections::HashMap,
     fs::File,
     io::Write,
-    sync::Arc,
-    time::{SystemTime, UNIX_EPOCH},
+    sync::{Arc, Mutex},
+    task::Poll,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use arrow2::buffer::allocator::{
+    set_default_allocator, BufferAllocator, HeapAllocator, HugePageAllocator, PooledAllocator,
 };
 
 use common_daft_config::DaftExecutionConfig;
 use common_display::{mermaid::MermaidDisplayOptions, DisplayLevel};
 use common_error::DaftResult;
+use common_runtime::{configure_compute_runtime, configure_io_runtime, ThreadAffinity};
 use common_tracing::refresh_chrome_trace;
+use daft_io::{IOStatsContext, QueryIOStats};
+#[cfg(feature = "python")]
+use daft_io::{IOStatsSnapshot, TIME_TO_FIRST_BYTE_BUCKETS_MS};
 use daft_local_plan::translate;
 use daft_logical_plan::LogicalPlanBuilder;
 use daft_micropartition::{
     partitioning::{InMemoryPartitionSetCache, MicroPartitionSet, PartitionSetCache},
     MicroPartition, MicroPartitionRef,
 };
-use futures::Stream;
+use daft_recordbatch::RecordBatch;
+use futures::{Stream, StreamExt};
 use tokio_util::sync::CancellationToken;
 #[cfg(feature = "python")]
 use {
@@ -24,22 +34,30 @@ use {
     daft_logical_plan::PyLogicalPlanBuilder,
     daft_micropartition::python::PyMicroPartition,
     pyo3::{
-        pyclass, pymethods, Bound, IntoPyObject, PyAny, PyObject, PyRef, PyRefMut, PyResult, Python,
+        pyclass, pymethods,
+        types::{PyDict, PyDictMethods},
+        Bound, IntoPyObject, PyAny, PyObject, PyRef, PyRefMut, PyResult, Python,
     },
 };
 
 use crate::{
+    buffer::RowBasedBuffer,
     channel::{create_channel, Receiver},
-    pipeline::{physical_plan_to_pipeline, viz_pipeline_ascii, viz_pipeline_mermaid},
+    pipeline::{physical_plan_to_pipeline, viz_pipeline_ascii, viz_pipeline_mermaid, PipelineNode},
     progress_bar::{make_progress_bar_manager, ProgressBarManager},
-    resource_manager::get_or_init_memory_manager,
+    query_cancelled,
+    resource_manager::{get_or_init_memory_manager, MemoryPool},
     Error, ExecutionRuntimeContext,
 };
 
+/// How often a Python thread that waits for the results of a query checks whether it was interrupted, e.g. by Ctrl-C.
+#[cfg(feature = "python")]
+const SIGNAL_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
 #[cfg(feature = "python")]
 #[pyclass]
 struct LocalPartitionIterator {
-    iter: Box<dyn Iterator<Item = DaftResult<PyObject>> + Send + Sync>,
+    iter: ExecutionEngineReceiverIterator,
 }
 
 #[cfg(feature = "python")]
@@ -48,10 +66,92 @@ impl LocalPartitionIterator {
     fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
         slf
     }
+
     fn __next__(mut slf: PyRefMut<'_, Self>, py: Python) -> PyResult<Option<PyObject>> {
         let iter = &mut slf.iter;
-        Ok(py.allow_threads(|| iter.next().transpose())?)
+        loop {
+            match py.allow_threads(|| iter.next_timeout(SIGNAL_CHECK_INTERVAL)) {
+                Poll::Ready(part) => {
+                    return Ok(part
+                        .transpose()?
+                        .map(|part| PyMicroPartition::from(part).into_pyobject(py))
+                        .transpose()?
+                        .map(|part| part.unbind().into_any()));
+                }
+                // Cancel the query if waiting for it was interrupted, and raise the interrupt, e.g. KeyboardInterrupt
+                Poll::Pending => {
+                    if let Err(err) = py.check_signals() {
+                        iter.cancel();
+                        return Err(err);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Cancels the query, which stops its operators and ends iteration with a `QueryCancelledError`.
+    fn cancel(&self) {
+        self.iter.cancel();
     }
+
+    /// The IO of the query so far, in total and by store.
+    fn io_stats<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let io_stats = self.iter.io_stats();
+        let stores = PyDict::new(py);
+        for (store, snapshot) in io_stats.by_store() {
+            stores.set_item(store.to_string(), io_stats_to_pydict(py, &snapshot)?)?;
+        }
+        let dict = PyDict::new(py);
+        dict.set_item("total", io_stats_to_pydict(py, &io_stats.total())?)?;
+        dict.set_item("stores", stores)?;
+        Ok(dict)
+    }
+}
+
+#[cfg(feature = "python")]
+fn io_stats_to_pydict<'py>(
+    py: Python<'py>,
+    snapshot: &IOStatsSnapshot,
+) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("get_requests", snapshot.get_requests)?;
+    dict.set_item("head_requests", snapshot.head_requests)?;
+    dict.set_item("list_requests", snapshot.list_requests)?;
+    dict.set_item("put_requests", snapshot.put_requests)?;
+    dict.set_item("bytes_downloaded", snapshot.bytes_read)?;
+    dict.set_item("bytes_uploaded", snapshot.bytes_uploaded)?;
+    dict.set_item("retries", snapshot.retries)?;
+    dict.set_item("throttles", snapshot.throttles)?;
+    // Pairs of the upper bound in milliseconds of each bucket, None for the last one, and the requests in it
+    let histogram = snapshot
+        .time_to_first_byte
+        .iter()
+        .enumerate()
+        .map(|(bucket, count)| (TIME_TO_FIRST_BYTE_BUCKETS_MS.get(bucket).copied(), *count))
+        .collect::<Vec<_>>();
+    dict.set_item("time_to_first_byte_ms", histogram)?;
+    Ok(dict)
+}
+
+#[cfg(feature = "python")]
+fn to_native_psets(
+    psets: HashMap<String, Vec<PyMicroPartition>>,
+) -> HashMap<String, Arc<MicroPartitionSet>> {
+    psets
+        .into_iter()
+        .map(|(part_id, parts)| {
+            (
+                part_id,
+                Arc::new(
+                    parts
+                        .into_iter()
+                        .map(std::convert::Into::into)
+                        .collect::<Vec<Arc<MicroPartition>>>()
+                        .into(),
+                ),
+            )
+        })
+        .collect()
 }
 
 #[cfg_attr(
@@ -65,7 +165,7 @@ pub struct PyNativeExecutor {
 #[cfg(feature = "python")]
 impl Default for PyNativeExecutor {
     fn default() -> Self {
-        Self::new()
+        Self::new(None)
     }
 }
 
@@ -73,9 +173,14 @@ impl Default for PyNativeExecutor {
 #[pymethods]
 impl PyNativeExecutor {
     #[new]
-    pub fn new() -> Self {
+    #[pyo3(signature = (progress=None))]
+    pub fn new(progress: Option<PyObject>) -> Self {
+        let executor = NativeExecutor::new();
         Self {
-            executor: NativeExecutor::new(),
+            executor: match progress {
+                Some(tracker) => executor.with_progress_tracker(tracker),
+                None => executor,
+            },
         }
     }
 
@@ -88,23 +193,9 @@ impl PyNativeExecutor {
         cfg: PyDaftExecutionConfig,
         results_buffer_size: Option<usize>,
     ) -> PyResult<Bound<'a, PyAny>> {
-        let native_psets: HashMap<String, Arc<MicroPartitionSet>> = psets
-            .into_iter()
-            .map(|(part_id, parts)| {
-                (
-                    part_id,
-                    Arc::new(
-                        parts
-                            .into_iter()
-                            .map(std::convert::Into::into)
-                            .collect::<Vec<Arc<MicroPartition>>>()
-                            .into(),
-                    ),
-                )
-            })
-            .collect();
+        let native_psets = to_native_psets(psets);
         let psets = InMemoryPartitionSetCache::new(&native_psets);
-        let out = py.allow_threads(|| {
+        let iter = py.allow_threads(|| {
             self.executor
                 .run(
                     &logical_plan_builder.builder,
@@ -114,18 +205,25 @@ impl PyNativeExecutor {
                 )
                 .map(|res| res.into_iter())
         })?;
-        let iter = Box::new(out.map(|part| {
-            pyo3::Python::with_gil(|py| {
-                Ok(PyMicroPartition::from(part?)
-                    .into_pyobject(py)?
-                    .unbind()
-                    .into_any())
-            })
-        }));
         let part_iter = LocalPartitionIterator { iter };
         Ok(part_iter.into_pyobject(py)?.into_any())
     }
 
+    pub fn explain_analyze(
+        &self,
+        py: Python,
+        logical_plan_builder: &PyLogicalPlanBuilder,
+        psets: HashMap<String, Vec<PyMicroPartition>>,
+        cfg: PyDaftExecutionConfig,
+    ) -> PyResult<String> {
+        let native_psets = to_native_psets(psets);
+        let psets = InMemoryPartitionSetCache::new(&native_psets);
+        Ok(py.allow_threads(|| {
+            self.executor
+                .explain_analyze(&logical_plan_builder.builder, &psets, cfg.config)
+        })?)
+    }
+
     pub fn repr_ascii(
         &self,
         logical_plan_builder: &PyLogicalPlanBuilder,
@@ -168,6 +266,44 @@ impl Default for NativeExecutor {
     }
 }
 
+/// Installs the buffer allocator configured in `cfg` as the default allocator for new buffers.
+///
+/// The allocator is process-wide, so it is only replaced when the configuration changes, which
+/// lets buffers pooled by one query be reused by the next.
+fn configure_buffer_allocator(cfg: &DaftExecutionConfig) {
+    static CONFIGURED: Mutex<Option<(String, usize)>> = Mutex::new(None);
+
+    let key = (cfg.buffer_allocator.clone(), cfg.buffer_pool_max_bytes);
+    let mut configured = CONFIGURED.lock().unwrap();
+    if configured.as_ref() == Some(&key) {
+        return;
+    }
+    let allocator: Arc<dyn BufferAllocator> = match cfg.buffer_allocator.as_str() {
+        "pooled" => Arc::new(PooledAllocator::new(cfg.buffer_pool_max_bytes)),
+        "huge_pages" => Arc::new(HugePageAllocator::new(Arc::new(PooledAllocator::new(
+            cfg.buffer_pool_max_bytes,
+        )))),
+        "heap" => Arc::new(HeapAllocator),
+        other => {
+            log::warn!("Unknown buffer allocator {other}, falling back to heap allocation");
+            Arc::new(HeapAllocator)
+        }
+    };
+    set_default_allocator(allocator);
+    *configured = Some(key);
+}
+
+/// Configures the compute and IO runtimes from `cfg`. Like the buffer allocator, the runtimes are process-wide, so
+/// the configuration applies to the queries started after it.
+fn configure_runtimes(cfg: &DaftExecutionConfig) {
+    let affinity = cfg.thread_affinity.parse().unwrap_or_else(|e| {
+        log::warn!("{e}, falling back to no thread affinity");
+        ThreadAffinity::None
+    });
+    configure_compute_runtime(cfg.compute_threads, affinity);
+    configure_io_runtime(cfg.io_threads);
+}
+
 impl NativeExecutor {
     pub fn new() -> Self {
         Self::default()
@@ -183,6 +319,17 @@ impl NativeExecutor {
         self
     }
 
+    /// Reports the progress of the operators of queries to a `daft.progress.QueryProgressTracker`, in addition to
+    /// showing it on the progress bars, if enabled.
+    #[cfg(feature = "python")]
+    pub fn with_progress_tracker(mut self, tracker: PyObject) -> Self {
+        self.pb_manager = Some(crate::progress_bar::make_reporting_progress_bar_manager(
+            tracker,
+            self.pb_manager.take(),
+        ));
+        self
+    }
+
     pub fn enable_explain_analyze(mut self, b: bool) -> Self {
         self.enable_explain_analyze = b;
         self
@@ -197,10 +344,51 @@ impl NativeExecutor {
     ) -> DaftResult<ExecutionEngineResult> {
         let logical_plan = logical_plan_builder.build();
         let physical_plan = translate(&logical_plan)?;
-        refresh_chrome_trace();
-        let cancel = self.cancel.clone();
         let pipeline = physical_plan_to_pipeline(&physical_plan, psets, &cfg)?;
+        self.run_pipeline(pipeline.into(), cfg, results_buffer_size)
+    }
+
+    /// Runs the plan to completion, discarding its results, and renders the pipeline annotated with the runtime
+    /// metrics that each operator recorded, e.g. rows in and out, wall time, peak memory and bytes scanned, followed by the
+    /// IO of the query by store.
+    pub fn explain_analyze(
+        &self,
+        logical_plan_builder: &LogicalPlanBuilder,
+        psets: &(impl PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>> + ?Sized),
+        cfg: Arc<DaftExecutionConfig>,
+    ) -> DaftResult<String> {
+        let logical_plan = logical_plan_builder.build();
+        let physical_plan = translate(&logical_plan)?;
+        let pipeline: Arc<dyn PipelineNode> =
+            physical_plan_to_pipeline(&physical_plan, psets, &cfg)?.into();
+        let result = self.run_pipeline(pipeline.clone(), cfg, None)?;
+        let io_stats = result.io_stats();
+        for part in result {
+            part?;
+        }
+        let mut explain = viz_pipeline_ascii(pipeline.as_ref(), DisplayLevel::Verbose);
+        explain.push_str("\nIO\n");
+        for line in io_stats.multiline_display() {
+            explain.push_str(&format!("  {line}\n"));
+        }
+        Ok(explain)
+    }
+
+    fn run_pipeline(
+        &self,
+        pipeline: Arc<dyn PipelineNode>,
+        cfg: Arc<DaftExecutionConfig>,
+        results_buffer_size: Option<usize>,
+    ) -> DaftResult<ExecutionEngineResult> {
+        refresh_chrome_trace();
+        configure_buffer_allocator(&cfg);
+        configure_runtimes(&cfg);
+        // Each query can be cancelled on its own, and all of them are when the executor is dropped
+        let cancel = self.cancel.child_token();
+        let query_cancel = cancel.clone();
         let (tx, rx) = create_channel(results_buffer_size.unwrap_or(0));
+        let io_stats = QueryIOStats::new();
+        let query_io_stats = io_stats.clone();
 
         let rt = self.runtime.clone();
         let pb_manager = self.pb_manager.clone();
@@ -218,10 +406,18 @@ impl NativeExecutor {
             });
             let execution_task = async {
                 let memory_manager = get_or_init_memory_manager();
+                let memory_pool = MemoryPool::new(match cfg.query_memory_limit_bytes {
+                    0 => memory_manager.total_bytes(),
+                    limit => limit as u64,
+                });
                 let mut runtime_handle = ExecutionRuntimeContext::new(
                     cfg.default_morsel_size,
+                    cfg.deterministic,
                     memory_manager.clone(),
+                    memory_pool,
                     pb_manager,
+                    cancel.clone(),
+                    io_stats,
                 );
                 let receiver = pipeline.start(true, &mut runtime_handle)?;
 
@@ -265,17 +461,19 @@ impl NativeExecutor {
                 Ok(())
             };
 
+            // Dropping the execution task on cancellation aborts the tasks of the pipeline, which drops their
+            // state, such as spill files and unfinished writes
             let local_set = tokio::task::LocalSet::new();
             local_set.block_on(&runtime, async {
                 tokio::select! {
                     biased;
                     () = cancel.cancelled() => {
                         log::info!("Execution engine cancelled");
-                        Ok(())
+                        Err(query_cancelled())
                     }
                     _ = tokio::signal::ctrl_c() => {
                         log::info!("Received Ctrl-C, shutting down execution engine");
-                        Ok(())
+                        Err(query_cancelled())
                     }
                     result = execution_task => result,
                 }
@@ -285,6 +483,8 @@ impl NativeExecutor {
         Ok(ExecutionEngineResult {
             handle,
             receiver: rx,
+            cancel: CancelOnDrop(query_cancel),
+            io_stats: query_io_stats,
         })
     }
 
@@ -300,7 +500,12 @@ impl NativeExecutor {
             physical_plan_to_pipeline(&physical_plan, &InMemoryPartitionSetCache::empty(), &cfg)
                 .unwrap();
 
-        viz_pipeline_ascii(pipeline_node.as_ref(), simple)
+        let display_type = if simple {
+            DisplayLevel::Compact
+        } else {
+            DisplayLevel::Default
+        };
+        viz_pipeline_ascii(pipeline_node.as_ref(), display_type)
     }
 
     fn repr_mermaid(
@@ -355,9 +560,52 @@ fn should_enable_progress_bar() -> bool {
     }
 }
 
+/// Cancels a query when dropped, so that abandoning its results, e.g. because iterating over them was interrupted,
+/// stops the query instead of leaving it running in the background.
+struct CancelOnDrop(CancellationToken);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.cancel();
+    }
+}
+
 pub struct ExecutionEngineReceiverIterator {
     receiver: kanal::Receiver<Arc<MicroPartition>>,
     handle: Option<std::thread::JoinHandle<DaftResult<()>>>,
+    cancel: CancelOnDrop,
+    io_stats: Arc<QueryIOStats>,
+}
+
+impl ExecutionEngineReceiverIterator {
+    /// Like [`Iterator::next`], but is pending if no result arrives within `timeout`, rather than waiting for one.
+    pub fn next_timeout(
+        &mut self,
+        timeout: Duration,
+    ) -> Poll<Option<DaftResult<Arc<MicroPartition>>>> {
+        match self.receiver.recv_timeout(timeout) {
+            Ok(part) => Poll::Ready(Some(Ok(part))),
+            Err(kanal::ReceiveErrorTimeout::Timeout) => Poll::Pending,
+            Err(_) => Poll::Ready(self.join()),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancel.0.cancel();
+    }
+
+    /// The IO of the query so far, which is all of it once the results run out.
+    pub fn io_stats(&self) -> Arc<QueryIOStats> {
+        self.io_stats.clone()
+    }
+
+    fn join(&mut self) -> Option<DaftResult<Arc<MicroPartition>>> {
+        let handle = self.handle.take()?;
+        match handle.join().expect("Execution engine thread panicked") {
+            Ok(()) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
 }
 
 impl Iterator for ExecutionEngineReceiverIterator {
@@ -366,22 +614,7 @@ impl Iterator for ExecutionEngineReceiverIterator {
     fn next(&mut self) -> Option<Self::Item> {
         match self.receiver.recv().ok() {
             Some(part) => Some(Ok(part)),
-            None => {
-                if self.handle.is_some() {
-                    let join_result = self
-                        .handle
-                        .take()
-                        .unwrap()
-                        .join()
-                        .expect("Execution engine thread panicked");
-                    match join_result {
-                        Ok(()) => None,
-                        Err(e) => Some(Err(e)),
-                    }
-                } else {
-                    None
-                }
-            }
+            None => self.join(),
         }
     }
 }
@@ -389,18 +622,33 @@ impl Iterator for ExecutionEngineReceiverIterator {
 pub struct ExecutionEngineResult {
     handle: std::thread::JoinHandle<DaftResult<()>>,
     receiver: Receiver<Arc<MicroPartition>>,
+    cancel: CancelOnDrop,
+    io_stats: Arc<QueryIOStats>,
 }
 
 impl ExecutionEngineResult {
+    /// A token that cancels the query. The query is also cancelled when its results are dropped.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancel.0.clone()
+    }
+
+    /// The IO of the query so far by store, e.g. its requests, bytes downloaded and uploaded, retries, throttled
+    /// requests and time to first byte, which is all of it once the results run out.
+    pub fn io_stats(&self) -> Arc<QueryIOStats> {
+        self.io_stats.clone()
+    }
+
     pub fn into_stream(self) -> impl Stream<Item = DaftResult<Arc<MicroPartition>>> {
         struct StreamState {
             receiver: Receiver<Arc<MicroPartition>>,
             handle: Option<std::thread::JoinHandle<DaftResult<()>>>,
+            _cancel: CancelOnDrop,
         }
 
         let state = StreamState {
             receiver: self.receiver,
             handle: Some(self.handle),
+            _cancel: self.cancel,
         };
 
         futures::stream::unfold(state, |mut state| async {
@@ -425,6 +673,52 @@ impl ExecutionEngineResult {
             }
         })
     }
+
+    /// Streams the results as record batches of `batch_size` rows, except for the last one, which may be smaller.
+    ///
+    /// Batches are yielded as soon as enough rows of the results have arrived, so the results never have to be held in
+    /// memory all at once.
+    pub fn into_batch_stream(
+        self,
+        batch_size: usize,
+    ) -> impl Stream<Item = DaftResult<RecordBatch>> {
+        let parts = self
+            .into_stream()
+            .map(Some)
+            .chain(futures::stream::once(async { None }));
+        parts
+            .scan(RowBasedBuffer::new(batch_size), |buffer, part| {
+                let batches = match part {
+                    Some(part) => part.and_then(|part| {
+                        buffer.push(&part);
+                        Ok(buffer.pop_enough()?.unwrap_or_default())
+                    }),
+                    // The results are done, so the rows left over make up the last batch
+                    None => buffer.pop_all().map(|part| part.into_iter().collect()),
+                };
+                futures::future::ready(Some(batches))
+            })
+            .flat_map(|batches| {
+                futures::stream::iter(match batches {
+                    Ok(parts) => parts
+                        .into_iter()
+                        .filter(|part| !part.is_empty())
+                        .map(|part| to_record_batch(&part))
+                        .collect(),
+                    Err(e) => vec![Err(e)],
+                })
+            })
+    }
+}
+
+fn to_record_batch(part: &MicroPartition) -> DaftResult<RecordBatch> {
+    let batches = part.concat_or_get(IOStatsContext::new(
+        "ExecutionEngineResult::into_batch_stream",
+    ))?;
+    match batches.first() {
+        Some(batch) => Ok(batch.clone()),
+        None => RecordBatch::empty(Some(part.schema())),
+    }
 }
 
 impl IntoIterator for ExecutionEngineResult {
@@ -435,6 +729,8 @@ impl IntoIterator for ExecutionEngineResult {
         ExecutionEngineReceiverIterator {
             receiver: self.receiver.into_inner().to_sync(),
             handle: Some(self.handle),
+            cancel: self.cancel,
+            io_stats: self.io_stats,
         }
     }
 }
@@ -3,11 +3,12 @@ use std::{
     fmt::Write,
     future::Future,
     pin::Pin,
-    sync::{atomic::AtomicU64, Arc},
+    sync::{atomic::AtomicU64, Arc, OnceLock},
     task::{Context, Poll},
     time::Instant,
 };
 
+use common_display::utils::bytes_to_human_readable;
 use daft_micropartition::MicroPartition;
 use kanal::SendError;
 use tracing::{instrument::Instrumented, Instrument};
@@ -15,6 +16,7 @@ use tracing::{instrument::Instrumented, Instrument};
 use crate::{
     channel::{Receiver, Sender},
     progress_bar::OperatorProgressBar,
+    resource_manager::MemoryReservation,
 };
 
 #[derive(Default)]
@@ -22,6 +24,15 @@ pub struct RuntimeStatsContext {
     rows_received: AtomicU64,
     rows_emitted: AtomicU64,
     cpu_us: AtomicU64,
+    // Wall time is measured from the first time the operator does any work to the last time it does any work.
+    first_active: OnceLock<Instant>,
+    last_active_us: AtomicU64,
+    // Bytes of input morsels currently held by the operator, and the high water mark of that amount.
+    bytes_held: AtomicU64,
+    peak_bytes_held: AtomicU64,
+    bytes_spilled: AtomicU64,
+    // The bytes held are accounted against the memory pool of the query through this, once the operator is started.
+    memory_reservation: OnceLock<Arc<MemoryReservation>>,
 }
 
 #[derive(Debug)]
@@ -29,6 +40,9 @@ pub struct RuntimeStats {
     pub rows_received: u64,
     pub rows_emitted: u64,
     pub cpu_us: u64,
+    pub wall_us: u64,
+    pub peak_memory_bytes: u64,
+    pub bytes_spilled: u64,
 }
 
 impl RuntimeStats {
@@ -38,6 +52,7 @@ impl RuntimeStats {
         received: bool,
         emitted: bool,
         cpu_time: bool,
+        memory: bool,
     ) -> Result<(), fmt::Error> {
         use num_format::{Locale, ToFormattedString};
         if received {
@@ -61,17 +76,29 @@ impl RuntimeStats {
             writeln!(w, "CPU Time = {tms:.2}ms")?;
         }
 
+        let wall_ms = (self.wall_us as f32) / 1000f32;
+        writeln!(w, "Wall Time = {wall_ms:.2}ms")?;
+
+        if memory {
+            writeln!(
+                w,
+                "Peak memory = {}",
+                bytes_to_human_readable(self.peak_memory_bytes as usize)
+            )?;
+            writeln!(
+                w,
+                "Bytes spilled = {}",
+                bytes_to_human_readable(self.bytes_spilled as usize)
+            )?;
+        }
+
         Ok(())
     }
 }
 
 impl RuntimeStatsContext {
     pub(crate) fn new() -> Arc<Self> {
-        Arc::new(Self {
-            rows_received: AtomicU64::new(0),
-            rows_emitted: AtomicU64::new(0),
-            cpu_us: AtomicU64::new(0),
-        })
+        Arc::new(Self::default())
     }
     pub(crate) fn record_elapsed_cpu_time(&self, elapsed: std::time::Duration) {
         self.cpu_us.fetch_add(
@@ -80,6 +107,65 @@ impl RuntimeStatsContext {
         );
     }
 
+    pub(crate) fn record_activity(&self, now: Instant) {
+        let first_active = *self.first_active.get_or_init(|| now);
+        let elapsed = now.saturating_duration_since(first_active);
+        self.last_active_us.fetch_max(
+            elapsed.as_micros() as u64,
+            std::sync::atomic::Ordering::Relaxed,
+        );
+    }
+
+    pub(crate) fn attach_memory_reservation(&self, reservation: Arc<MemoryReservation>) {
+        let _ = self.memory_reservation.set(reservation);
+    }
+
+    /// Whether the operator holds more than its share of the query's memory, and should spill what it can.
+    pub(crate) fn exceeds_memory_share(&self) -> bool {
+        self.memory_reservation
+            .get()
+            .is_some_and(|reservation| reservation.exceeds_fair_share())
+    }
+
+    /// Marks `bytes` of input as held by the operator until a matching call to `mark_bytes_released`.
+    pub(crate) fn mark_bytes_held(&self, bytes: u64) {
+        if let Some(reservation) = self.memory_reservation.get() {
+            reservation.grow(bytes);
+        }
+        let held = self
+            .bytes_held
+            .fetch_add(bytes, std::sync::atomic::Ordering::Relaxed)
+            + bytes;
+        self.peak_bytes_held
+            .fetch_max(held, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    pub(crate) fn mark_bytes_released(&self, bytes: u64) {
+        if let Some(reservation) = self.memory_reservation.get() {
+            reservation.shrink(bytes);
+        }
+        // Saturate instead of wrapping, since morsels may be released after the stats are reset.
+        let _ = self.bytes_held.fetch_update(
+            std::sync::atomic::Ordering::Relaxed,
+            std::sync::atomic::Ordering::Relaxed,
+            |held| Some(held.saturating_sub(bytes)),
+        );
+    }
+
+    pub(crate) fn mark_all_bytes_released(&self) {
+        if let Some(reservation) = self.memory_reservation.get() {
+            reservation.free();
+        }
+        self.bytes_held
+            .store(0, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    /// Marks `bytes` of in-memory state as spilled to disk by the operator.
+    pub(crate) fn mark_bytes_spilled(&self, bytes: u64) {
+        self.bytes_spilled
+            .fetch_add(bytes, std::sync::atomic::Ordering::Relaxed);
+    }
+
     pub(crate) fn mark_rows_received(&self, rows: u64) {
         self.rows_received
             .fetch_add(rows, std::sync::atomic::Ordering::Relaxed);
@@ -106,6 +192,13 @@ impl RuntimeStatsContext {
         self.rows_emitted
             .store(0, std::sync::atomic::Ordering::Release);
         self.cpu_us.store(0, std::sync::atomic::Ordering::Release);
+        self.last_active_us
+            .store(0, std::sync::atomic::Ordering::Release);
+        self.mark_all_bytes_released();
+        self.peak_bytes_held
+            .store(0, std::sync::atomic::Ordering::Release);
+        self.bytes_spilled
+            .store(0, std::sync::atomic::Ordering::Release);
     }
 
     pub(crate) fn result(&self) -> RuntimeStats {
@@ -115,6 +208,15 @@ impl RuntimeStatsContext {
                 .load(std::sync::atomic::Ordering::Relaxed),
             rows_emitted: self.rows_emitted.load(std::sync::atomic::Ordering::Relaxed),
             cpu_us: self.cpu_us.load(std::sync::atomic::Ordering::Relaxed),
+            wall_us: self
+                .last_active_us
+                .load(std::sync::atomic::Ordering::Relaxed),
+            peak_memory_bytes: self
+                .peak_bytes_held
+                .load(std::sync::atomic::Ordering::Relaxed),
+            bytes_spilled: self
+                .bytes_spilled
+                .load(std::sync::atomic::Ordering::Relaxed),
         }
     }
 }
@@ -147,6 +249,8 @@ impl<F: Future> Future for TimedFuture<F> {
         let inner_poll = this.future.as_mut().poll(cx);
         let elapsed = start.elapsed();
         this.runtime_context.record_elapsed_cpu_time(elapsed);
+        this.runtime_context.record_activity(*start);
+        this.runtime_context.record_activity(Instant::now());
 
         match inner_poll {
             Poll::Pending => Poll::Pending,
@@ -176,6 +280,7 @@ impl CountingSender {
     #[inline]
     pub(crate) async fn send(&self, v: Arc<MicroPartition>) -> Result<(), SendError> {
         self.rt.mark_rows_emitted(v.len() as u64);
+        self.rt.record_activity(Instant::now());
         if let Some(ref pb) = self.progress_bar {
             pb.render();
         }
@@ -207,6 +312,7 @@ impl CountingReceiver {
         let v = self.receiver.recv().await;
         if let Some(ref v) = v {
             self.rt.mark_rows_received(v.len() as u64);
+            self.rt.record_activity(Instant::now());
             if let Some(ref pb) = self.progress_bar {
                 pb.render();
             }
@@ -214,3 +320,8 @@ impl CountingReceiver {
         v
     }
 }
+
+/// Approximate in-memory size of a morsel, for tracking how much memory an operator holds.
+pub(crate) fn morsel_size_bytes(morsel: &MicroPartition) -> u64 {
+    morsel.size_bytes().ok().flatten().unwrap_or(0) as u64
+}
@@ -101,7 +101,7 @@ impl BlockingSink for AggregateSink {
                         .as_any_mut()
                         .downcast_mut::<AggregateState>()
                         .expect("AggregateSink should have AggregateState");
-                    let agged = Arc::new(input.agg(&params.sink_agg_exprs, &[])?);
+                    let agged = Arc::new(input.partial_agg(&params.sink_agg_exprs, &[])?);
                     agg_state.push(agged);
                     Ok(BlockingSinkStatus::NeedMoreInput(state))
                 },
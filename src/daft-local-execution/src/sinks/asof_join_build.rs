@@ -0,0 +1,163 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_core::prelude::{AsofStrategy, SchemaRef};
+use daft_dsl::ExprRef;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::{AsofJoinBuild, RecordBatch};
+use itertools::Itertools;
+use tracing::{info_span, instrument};
+
+use super::blocking_sink::{
+    BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
+    BlockingSinkStatus,
+};
+use crate::{state_bridge::BroadcastStateBridgeRef, ExecutionTaskSpawner};
+
+struct AsofJoinBuildState(Option<Vec<RecordBatch>>);
+
+impl BlockingSinkState for AsofJoinBuildState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+struct AsofJoinBuildParams {
+    schema: SchemaRef,
+    build_by: Vec<ExprRef>,
+    build_on: ExprRef,
+    strategy: AsofStrategy,
+    tolerance: Option<ExprRef>,
+}
+
+/// Collects the right side of an as-of join and sorts it once all of it has arrived.
+pub struct AsofJoinBuildSink {
+    params: Arc<AsofJoinBuildParams>,
+    state_bridge: BroadcastStateBridgeRef<AsofJoinBuild>,
+}
+
+impl AsofJoinBuildSink {
+    pub(crate) fn new(
+        schema: SchemaRef,
+        build_by: Vec<ExprRef>,
+        build_on: ExprRef,
+        strategy: AsofStrategy,
+        tolerance: Option<ExprRef>,
+        state_bridge: BroadcastStateBridgeRef<AsofJoinBuild>,
+    ) -> Self {
+        Self {
+            params: Arc::new(AsofJoinBuildParams {
+                schema,
+                build_by,
+                build_on,
+                strategy,
+                tolerance,
+            }),
+            state_bridge,
+        }
+    }
+}
+
+impl BlockingSink for AsofJoinBuildSink {
+    fn name(&self) -> &'static str {
+        "AsofJoinBuild"
+    }
+
+    fn sink(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn BlockingSinkState>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkSinkResult {
+        if input.is_empty() {
+            return Ok(BlockingSinkStatus::NeedMoreInput(state)).into();
+        }
+
+        spawner
+            .spawn(
+                async move {
+                    let asof_join_build_state = state
+                        .as_any_mut()
+                        .downcast_mut::<AsofJoinBuildState>()
+                        .expect("AsofJoinBuildSink should have AsofJoinBuildState");
+
+                    asof_join_build_state
+                        .0
+                        .as_mut()
+                        .expect("Collected tables should not be consumed before sink stage is done")
+                        .extend(input.get_tables()?.iter().cloned());
+
+                    Ok(BlockingSinkStatus::NeedMoreInput(state))
+                },
+                info_span!("AsofJoinBuildSink::sink"),
+            )
+            .into()
+    }
+
+    #[instrument(skip_all, name = "AsofJoinBuildSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Box<dyn BlockingSinkState>>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkFinalizeResult {
+        let mut state = states.into_iter().next().unwrap();
+        let tables = state
+            .as_any_mut()
+            .downcast_mut::<AsofJoinBuildState>()
+            .expect("AsofJoinBuildSink should have AsofJoinBuildState")
+            .0
+            .take()
+            .expect("As-of join build state should have tables before finalize is called");
+
+        let params = self.params.clone();
+        let state_bridge = self.state_bridge.clone();
+        spawner
+            .spawn(
+                async move {
+                    let table = if tables.is_empty() {
+                        RecordBatch::empty(Some(params.schema.clone()))?
+                    } else {
+                        RecordBatch::concat(&tables)?
+                    };
+                    let build = AsofJoinBuild::try_new(
+                        &table,
+                        &params.build_by,
+                        &params.build_on,
+                        params.strategy,
+                        params.tolerance.as_ref(),
+                    )?;
+                    state_bridge.set_state(Arc::new(build));
+                    Ok(None)
+                },
+                info_span!("AsofJoinBuildSink::finalize"),
+            )
+            .into()
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
+        Ok(Box::new(AsofJoinBuildState(Some(Vec::new()))))
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![
+            "AsofJoinBuild:".to_string(),
+            format!("Strategy: {}", self.params.strategy),
+            format!("Build on: {}", self.params.build_on),
+        ];
+        if !self.params.build_by.is_empty() {
+            res.push(format!(
+                "Build by: [{}]",
+                self.params
+                    .build_by
+                    .iter()
+                    .map(|e| e.to_string())
+                    .join(", ")
+            ));
+        }
+        res
+    }
+
+    fn max_concurrency(&self) -> usize {
+        1
+    }
+}
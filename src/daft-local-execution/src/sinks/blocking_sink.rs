@@ -6,15 +6,16 @@ use common_runtime::{get_compute_pool_num_threads, get_compute_runtime};
 use daft_logical_plan::stats::StatsState;
 use daft_micropartition::MicroPartition;
 use snafu::ResultExt;
+use tokio_util::sync::CancellationToken;
 use tracing::{info_span, instrument};
 
 use crate::{
     channel::{create_channel, Receiver},
-    dispatcher::{DispatchSpawner, UnorderedDispatcher},
+    dispatcher::{DispatchSpawner, RoundRobinDispatcher, UnorderedDispatcher},
     pipeline::PipelineNode,
     progress_bar::ProgressBarColor,
     resource_manager::MemoryManager,
-    runtime_stats::{CountingReceiver, CountingSender, RuntimeStatsContext},
+    runtime_stats::{morsel_size_bytes, CountingReceiver, CountingSender, RuntimeStatsContext},
     ExecutionRuntimeContext, ExecutionTaskSpawner, JoinSnafu, OperatorOutput, TaskSet,
 };
 pub trait BlockingSinkState: Send + Sync {
@@ -49,13 +50,21 @@ pub trait BlockingSink: Send + Sync {
         &self,
         runtime_handle: &ExecutionRuntimeContext,
     ) -> Arc<dyn DispatchSpawner> {
-        Arc::new(UnorderedDispatcher::new(Some(
-            runtime_handle.default_morsel_size(),
-        )))
+        let morsel_size = Some(runtime_handle.default_morsel_size());
+        if runtime_handle.deterministic() {
+            Arc::new(RoundRobinDispatcher::new(morsel_size))
+        } else {
+            Arc::new(UnorderedDispatcher::new(morsel_size))
+        }
     }
     fn max_concurrency(&self) -> usize {
         get_compute_pool_num_threads()
     }
+    /// Whether the sink can spill its state to disk. Sinks that can spill account for the memory they hold themselves,
+    /// and should spill once [`RuntimeStatsContext::exceeds_memory_share`] is true.
+    fn can_spill(&self) -> bool {
+        false
+    }
 }
 
 pub struct BlockingSinkNode {
@@ -88,42 +97,57 @@ impl BlockingSinkNode {
     #[instrument(level = "info", skip_all, name = "BlockingSink::run_worker")]
     async fn run_worker(
         op: Arc<dyn BlockingSink>,
+        worker_idx: usize,
         input_receiver: Receiver<Arc<MicroPartition>>,
         rt_context: Arc<RuntimeStatsContext>,
         memory_manager: Arc<MemoryManager>,
-    ) -> DaftResult<Box<dyn BlockingSinkState>> {
+        cancel: CancellationToken,
+    ) -> DaftResult<(usize, Box<dyn BlockingSinkState>)> {
         let span = info_span!("BlockingSink::Sink");
         let compute_runtime = get_compute_runtime();
-        let spawner = ExecutionTaskSpawner::new(compute_runtime, memory_manager, rt_context, span);
+        let spawner = ExecutionTaskSpawner::new(
+            compute_runtime,
+            memory_manager,
+            rt_context.clone(),
+            span,
+            cancel,
+        );
         let mut state = op.make_state()?;
         while let Some(morsel) = input_receiver.recv().await {
+            // Blocking sinks may hold on to their inputs until they are finalized.
+            if !op.can_spill() {
+                rt_context.mark_bytes_held(morsel_size_bytes(&morsel));
+            }
             let result = op.sink(morsel, state, &spawner).await??;
             match result {
                 BlockingSinkStatus::NeedMoreInput(new_state) => {
                     state = new_state;
                 }
                 BlockingSinkStatus::Finished(new_state) => {
-                    return Ok(new_state);
+                    return Ok((worker_idx, new_state));
                 }
             }
         }
 
-        Ok(state)
+        Ok((worker_idx, state))
     }
 
     fn spawn_workers(
         op: Arc<dyn BlockingSink>,
         input_receivers: Vec<Receiver<Arc<MicroPartition>>>,
-        task_set: &mut TaskSet<DaftResult<Box<dyn BlockingSinkState>>>,
+        task_set: &mut TaskSet<DaftResult<(usize, Box<dyn BlockingSinkState>)>>,
         stats: Arc<RuntimeStatsContext>,
         memory_manager: Arc<MemoryManager>,
+        cancel: CancellationToken,
     ) {
-        for input_receiver in input_receivers {
+        for (worker_idx, input_receiver) in input_receivers.into_iter().enumerate() {
             task_set.spawn(Self::run_worker(
                 op.clone(),
+                worker_idx,
                 input_receiver,
                 stats.clone(),
                 memory_manager.clone(),
+                cancel.clone(),
             ));
         }
     }
@@ -147,7 +171,9 @@ impl TreeDisplay for BlockingSinkNode {
                 }
                 if matches!(level, DisplayLevel::Verbose) {
                     let rt_result = self.runtime_stats.result();
-                    rt_result.display(&mut display, true, true, true).unwrap();
+                    rt_result
+                        .display(&mut display, true, true, true, true)
+                        .unwrap();
                 }
             }
         }
@@ -173,13 +199,20 @@ impl PipelineNode for BlockingSinkNode {
         _maintain_order: bool,
         runtime_handle: &mut ExecutionRuntimeContext,
     ) -> crate::Result<Receiver<Arc<MicroPartition>>> {
+        self.runtime_stats.attach_memory_reservation(
+            runtime_handle
+                .memory_pool()
+                .register(self.name(), self.op.can_spill()),
+        );
         let progress_bar = runtime_handle.make_progress_bar(
             self.name(),
             ProgressBarColor::Cyan,
             true,
             self.runtime_stats.clone(),
         );
-        let child_results_receiver = self.child.start(false, runtime_handle)?;
+        let child_results_receiver = self
+            .child
+            .start(runtime_handle.deterministic(), runtime_handle)?;
         let counting_receiver = CountingReceiver::new(
             child_results_receiver,
             self.runtime_stats.clone(),
@@ -193,6 +226,7 @@ impl PipelineNode for BlockingSinkNode {
         let op = self.op.clone();
         let runtime_stats = self.runtime_stats.clone();
         let num_workers = op.max_concurrency();
+        let morsel_size = runtime_handle.default_morsel_size();
 
         let dispatch_spawner = op.dispatch_spawner(runtime_handle);
         let spawned_dispatch_result = dispatch_spawner.spawn_dispatch(
@@ -206,6 +240,7 @@ impl PipelineNode for BlockingSinkNode {
         );
 
         let memory_manager = runtime_handle.memory_manager();
+        let cancel = runtime_handle.cancellation_token();
         runtime_handle.spawn(
             async move {
                 let mut task_set = TaskSet::new();
@@ -215,13 +250,20 @@ impl PipelineNode for BlockingSinkNode {
                     &mut task_set,
                     runtime_stats.clone(),
                     memory_manager.clone(),
+                    cancel.clone(),
                 );
 
                 let mut finished_states = Vec::with_capacity(num_workers);
                 while let Some(result) = task_set.join_next().await {
-                    let state = result.context(JoinSnafu)??;
-                    finished_states.push(state);
+                    finished_states.push(result.context(JoinSnafu)??);
                 }
+                // Finalize the states in the order of their workers rather than the order they finished in, so that
+                // the results don't depend on how the workers were scheduled.
+                finished_states.sort_by_key(|(worker_idx, _)| *worker_idx);
+                let finished_states = finished_states
+                    .into_iter()
+                    .map(|(_, state)| state)
+                    .collect();
 
                 let compute_runtime = get_compute_runtime();
                 let spawner = ExecutionTaskSpawner::new(
@@ -229,10 +271,24 @@ impl PipelineNode for BlockingSinkNode {
                     memory_manager,
                     runtime_stats.clone(),
                     info_span!("BlockingSink::Finalize"),
+                    cancel,
                 );
                 let finalized_result = op.finalize(finished_states, &spawner).await??;
+                runtime_stats.mark_all_bytes_released();
                 if let Some(res) = finalized_result {
-                    let _ = counting_sender.send(res).await;
+                    // Hand the result downstream in morsels, so that consumers can start on it right away and
+                    // don't have to hold onto all of it at once.
+                    if res.len() <= morsel_size {
+                        let _ = counting_sender.send(res).await;
+                    } else {
+                        for start in (0..res.len()).step_by(morsel_size) {
+                            let end = (start + morsel_size).min(res.len());
+                            let morsel = Arc::new(res.slice(start, end)?);
+                            if counting_sender.send(morsel).await.is_err() {
+                                break;
+                            }
+                        }
+                    }
                 }
                 Ok(())
             },
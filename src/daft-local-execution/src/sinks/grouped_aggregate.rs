@@ -17,7 +17,11 @@ use super::blocking_sink::{
     BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
     BlockingSinkStatus,
 };
-use crate::ExecutionTaskSpawner;
+use crate::{
+    runtime_stats::morsel_size_bytes,
+    spill::{can_spill, SpillFile},
+    ExecutionTaskSpawner,
+};
 
 #[derive(Clone)]
 enum AggStrategy {
@@ -48,7 +52,7 @@ impl AggStrategy {
         input: Arc<MicroPartition>,
         params: &GroupedAggregateParams,
     ) -> DaftResult<()> {
-        let agged = input.agg(
+        let agged = input.partial_agg(
             params.partial_agg_exprs.as_slice(),
             params.group_by.as_slice(),
         )?;
@@ -56,7 +60,7 @@ impl AggStrategy {
             agged.partition_by_hash(params.final_group_by.as_slice(), inner_states.len())?;
         for (p, state) in partitioned.into_iter().zip(inner_states.iter_mut()) {
             let state = state.get_or_insert_default();
-            state.partially_aggregated.push(p);
+            state.push_partially_aggregated(p);
         }
         Ok(())
     }
@@ -72,17 +76,15 @@ impl AggStrategy {
         for (p, state) in partitioned.into_iter().zip(inner_states.iter_mut()) {
             let state = state.get_or_insert_default();
             if state.unaggregated_size + p.len() >= partial_agg_threshold {
-                let unaggregated = std::mem::take(&mut state.unaggregated);
+                let unaggregated = state.take_unaggregated();
                 let aggregated =
                     MicroPartition::concat(unaggregated.iter().chain(std::iter::once(&p)))?.agg(
                         params.partial_agg_exprs.as_slice(),
                         params.group_by.as_slice(),
                     )?;
-                state.partially_aggregated.push(aggregated);
-                state.unaggregated_size = 0;
+                state.push_partially_aggregated(aggregated);
             } else {
-                state.unaggregated_size += p.len();
-                state.unaggregated.push(p);
+                state.push_unaggregated(p);
             }
         }
         Ok(())
@@ -97,8 +99,7 @@ impl AggStrategy {
             input.partition_by_hash(params.group_by.as_slice(), inner_states.len())?;
         for (p, state) in partitioned.into_iter().zip(inner_states.iter_mut()) {
             let state = state.get_or_insert_default();
-            state.unaggregated_size += p.len();
-            state.unaggregated.push(p);
+            state.push_unaggregated(p);
         }
         Ok(())
     }
@@ -109,6 +110,89 @@ struct SinglePartitionAggregateState {
     partially_aggregated: Vec<MicroPartition>,
     unaggregated: Vec<MicroPartition>,
     unaggregated_size: usize,
+    // Approximate in-memory size of the partitions above.
+    size_bytes: u64,
+    // Partitions spilled to disk once the worker went over its memory limit.
+    spilled_partially_aggregated: Option<SpillFile>,
+    spilled_unaggregated: Option<SpillFile>,
+}
+
+impl SinglePartitionAggregateState {
+    fn push_partially_aggregated(&mut self, p: MicroPartition) {
+        self.size_bytes += morsel_size_bytes(&p);
+        self.partially_aggregated.push(p);
+    }
+
+    fn push_unaggregated(&mut self, p: MicroPartition) {
+        self.size_bytes += morsel_size_bytes(&p);
+        self.unaggregated_size += p.len();
+        self.unaggregated.push(p);
+    }
+
+    fn take_unaggregated(&mut self) -> Vec<MicroPartition> {
+        let unaggregated_bytes = self.unaggregated.iter().map(morsel_size_bytes).sum();
+        self.size_bytes = self.size_bytes.saturating_sub(unaggregated_bytes);
+        self.unaggregated_size = 0;
+        std::mem::take(&mut self.unaggregated)
+    }
+
+    fn is_spilled(&self) -> bool {
+        self.spilled_partially_aggregated.is_some() || self.spilled_unaggregated.is_some()
+    }
+
+    /// Spills the partitions held in memory to disk, and returns their in-memory size.
+    fn spill(&mut self) -> DaftResult<u64> {
+        let spilled_bytes =
+            Self::spill_parts(
+                &mut self.partially_aggregated,
+                &mut self.spilled_partially_aggregated,
+            )? + Self::spill_parts(&mut self.unaggregated, &mut self.spilled_unaggregated)?;
+        if self.unaggregated.is_empty() {
+            self.unaggregated_size = 0;
+        }
+        self.size_bytes = self.size_bytes.saturating_sub(spilled_bytes);
+        Ok(spilled_bytes)
+    }
+
+    fn spill_parts(
+        parts: &mut Vec<MicroPartition>,
+        spill_file: &mut Option<SpillFile>,
+    ) -> DaftResult<u64> {
+        let Some(first) = parts.first() else {
+            return Ok(0);
+        };
+        let schema = first.schema();
+        // Partitions that can't be written as Arrow stay in memory.
+        if !can_spill(&schema) {
+            return Ok(0);
+        }
+        let spill_file = match spill_file {
+            Some(spill_file) => spill_file,
+            None => spill_file.insert(SpillFile::new(schema)?),
+        };
+        let mut spilled_bytes = 0;
+        for p in std::mem::take(parts) {
+            spilled_bytes += morsel_size_bytes(&p);
+            spill_file.write(&p.get_tables()?)?;
+        }
+        Ok(spilled_bytes)
+    }
+
+    /// Returns the unaggregated and the partially aggregated partitions, reading back the ones that were spilled.
+    fn into_parts(self) -> DaftResult<(Vec<MicroPartition>, Vec<MicroPartition>)> {
+        let read_back = |parts: Vec<MicroPartition>, spill_file: Option<SpillFile>| {
+            let mut parts = parts;
+            if let Some(mut spill_file) = spill_file {
+                spill_file.finish()?;
+                parts.push(spill_file.read()?);
+            }
+            DaftResult::Ok(parts)
+        };
+        Ok((
+            read_back(self.unaggregated, self.spilled_unaggregated)?,
+            read_back(self.partially_aggregated, self.spilled_partially_aggregated)?,
+        ))
+    }
 }
 
 enum GroupedAggregateState {
@@ -117,6 +201,7 @@ enum GroupedAggregateState {
         strategy: Option<AggStrategy>,
         partial_agg_threshold: usize,
         high_cardinality_threshold_ratio: f64,
+        memory_limit_bytes: u64,
     },
     Done,
 }
@@ -126,6 +211,7 @@ impl GroupedAggregateState {
         num_partitions: usize,
         partial_agg_threshold: usize,
         high_cardinality_threshold_ratio: f64,
+        memory_limit_bytes: u64,
     ) -> Self {
         let inner_states = (0..num_partitions).map(|_| None).collect::<Vec<_>>();
         Self::Accumulating {
@@ -133,6 +219,7 @@ impl GroupedAggregateState {
             strategy: None,
             partial_agg_threshold,
             high_cardinality_threshold_ratio,
+            memory_limit_bytes,
         }
     }
 
@@ -147,6 +234,7 @@ impl GroupedAggregateState {
             strategy,
             partial_agg_threshold,
             high_cardinality_threshold_ratio,
+            ..
         } = self
         else {
             panic!("GroupedAggregateSink should be in Accumulating state");
@@ -170,6 +258,38 @@ impl GroupedAggregateState {
         Ok(())
     }
 
+    /// Approximate in-memory size of the partitions held by the worker.
+    fn size_bytes(&self) -> u64 {
+        match self {
+            Self::Accumulating { inner_states, .. } => {
+                inner_states.iter().flatten().map(|s| s.size_bytes).sum()
+            }
+            Self::Done => 0,
+        }
+    }
+
+    /// Spills all partitions to disk if they take up more than the memory limit, or if the worker is asked to spill
+    /// because the sink holds more than its share of the query's memory. Returns the number of bytes spilled.
+    fn spill_if_over_memory_limit(&mut self, exceeds_memory_share: bool) -> DaftResult<u64> {
+        let size_bytes = self.size_bytes();
+        let Self::Accumulating {
+            ref mut inner_states,
+            memory_limit_bytes,
+            ..
+        } = self
+        else {
+            panic!("GroupedAggregateSink should be in Accumulating state");
+        };
+        if size_bytes == 0 || (size_bytes <= *memory_limit_bytes && !exceeds_memory_share) {
+            return Ok(0);
+        }
+        let mut spilled_bytes = 0;
+        for state in inner_states.iter_mut().flatten() {
+            spilled_bytes += state.spill()?;
+        }
+        Ok(spilled_bytes)
+    }
+
     fn determine_agg_strategy(
         input: &Arc<MicroPartition>,
         params: &GroupedAggregateParams,
@@ -249,6 +369,7 @@ pub struct GroupedAggregateSink {
     grouped_aggregate_params: Arc<GroupedAggregateParams>,
     partial_agg_threshold: usize,
     high_cardinality_threshold_ratio: f64,
+    memory_limit_bytes: usize,
     global_strategy_lock: Arc<Mutex<Option<AggStrategy>>>,
 }
 
@@ -300,6 +421,7 @@ impl GroupedAggregateSink {
             }),
             partial_agg_threshold: cfg.partial_aggregation_threshold,
             high_cardinality_threshold_ratio: cfg.high_cardinality_aggregation_threshold,
+            memory_limit_bytes: cfg.operator_memory_limit_bytes,
             global_strategy_lock: Arc::new(Mutex::new(strategy)),
         })
     }
@@ -307,6 +429,46 @@ impl GroupedAggregateSink {
     fn num_partitions(&self) -> usize {
         self.max_concurrency()
     }
+
+    fn finalize_partition(
+        per_partition_state: Vec<Option<SinglePartitionAggregateState>>,
+        params: &GroupedAggregateParams,
+    ) -> DaftResult<MicroPartition> {
+        let mut unaggregated = vec![];
+        let mut partially_aggregated = vec![];
+        for state in per_partition_state.into_iter().flatten() {
+            let (state_unaggregated, state_partially_aggregated) = state.into_parts()?;
+            unaggregated.extend(state_unaggregated);
+            partially_aggregated.extend(state_partially_aggregated);
+        }
+
+        // If we have no partially aggregated partitions, aggregate the unaggregated partitions using the original aggregations
+        if partially_aggregated.is_empty() {
+            let concated = MicroPartition::concat(&unaggregated)?;
+            let agged = concated.agg(&params.original_aggregations, &params.group_by)?;
+            Ok(agged)
+        }
+        // If we have no unaggregated partitions, finalize the partially aggregated partitions
+        else if unaggregated.is_empty() {
+            let concated = MicroPartition::concat(&partially_aggregated)?;
+            let agged = concated.agg(&params.final_agg_exprs, &params.final_group_by)?;
+            let projected = agged.eval_expression_list(&params.final_projections)?;
+            Ok(projected)
+        }
+        // Otherwise, partially aggregate the unaggregated partitions, concatenate them with the partially aggregated partitions, and finalize the result.
+        else {
+            let leftover_partial_agg = MicroPartition::concat(&unaggregated)?
+                .agg(&params.partial_agg_exprs, &params.group_by)?;
+            let concated = MicroPartition::concat(
+                partially_aggregated
+                    .iter()
+                    .chain(std::iter::once(&leftover_partial_agg)),
+            )?;
+            let agged = concated.agg(&params.final_agg_exprs, &params.final_group_by)?;
+            let projected = agged.eval_expression_list(&params.final_projections)?;
+            Ok(projected)
+        }
+    }
 }
 
 impl BlockingSink for GroupedAggregateSink {
@@ -319,6 +481,7 @@ impl BlockingSink for GroupedAggregateSink {
     ) -> BlockingSinkSinkResult {
         let params = self.grouped_aggregate_params.clone();
         let strategy_lock = self.global_strategy_lock.clone();
+        let runtime_stats = spawner.runtime_stats();
         spawner
             .spawn(
                 async move {
@@ -327,7 +490,21 @@ impl BlockingSink for GroupedAggregateSink {
                         .downcast_mut::<GroupedAggregateState>()
                         .expect("GroupedAggregateSink should have GroupedAggregateState");
 
+                    let size_before = agg_state.size_bytes();
                     agg_state.push(input, &params, &strategy_lock)?;
+                    let size_after = agg_state.size_bytes();
+                    if size_after >= size_before {
+                        runtime_stats.mark_bytes_held(size_after - size_before);
+                    } else {
+                        runtime_stats.mark_bytes_released(size_before - size_after);
+                    }
+
+                    let spilled_bytes = agg_state
+                        .spill_if_over_memory_limit(runtime_stats.exceeds_memory_share())?;
+                    if spilled_bytes > 0 {
+                        runtime_stats.mark_bytes_spilled(spilled_bytes);
+                        runtime_stats.mark_bytes_released(spilled_bytes);
+                    }
                     Ok(BlockingSinkStatus::NeedMoreInput(state))
                 },
                 Span::current(),
@@ -358,64 +535,47 @@ impl BlockingSink for GroupedAggregateSink {
                         })
                         .collect::<Vec<_>>();
 
-                    let mut per_partition_finalize_tasks = tokio::task::JoinSet::new();
-                    for _ in 0..num_partitions {
-                        let per_partition_state = state_iters
-                            .iter_mut()
-                            .map(|state| {
-                                state.next().expect(
-                                "GroupedAggregateState should have SinglePartitionAggregateState",
-                            )
+                    let per_partition_states = (0..num_partitions)
+                        .map(|_| {
+                            state_iters
+                                .iter_mut()
+                                .map(|state| {
+                                    state.next().expect(
+                                    "GroupedAggregateState should have SinglePartitionAggregateState",
+                                )
+                                })
+                                .collect::<Vec<_>>()
+                        })
+                        .collect::<Vec<_>>();
+
+                    let is_spilled = per_partition_states
+                        .iter()
+                        .flatten()
+                        .flatten()
+                        .any(SinglePartitionAggregateState::is_spilled);
+                    let results = if is_spilled {
+                        // Finalize one partition at a time, so that only a single partition's spilled state is read
+                        // back into memory at once.
+                        per_partition_states
+                            .into_iter()
+                            .map(|per_partition_state| {
+                                Self::finalize_partition(per_partition_state, &params)
                             })
-                            .collect::<Vec<_>>();
-                        let params = params.clone();
-                        per_partition_finalize_tasks.spawn(async move {
-                            let mut unaggregated = vec![];
-                            let mut partially_aggregated = vec![];
-                            for state in per_partition_state.into_iter().flatten() {
-                                unaggregated.extend(state.unaggregated);
-                                partially_aggregated.extend(state.partially_aggregated);
-                            }
-
-                            // If we have no partially aggregated partitions, aggregate the unaggregated partitions using the original aggregations
-                            if partially_aggregated.is_empty() {
-                                let concated = MicroPartition::concat(&unaggregated)?;
-                                let agged = concated
-                                    .agg(&params.original_aggregations, &params.group_by)?;
-                                Ok(agged)
-                            }
-                            // If we have no unaggregated partitions, finalize the partially aggregated partitions
-                            else if unaggregated.is_empty() {
-                                let concated = MicroPartition::concat(&partially_aggregated)?;
-                                let agged = concated
-                                    .agg(&params.final_agg_exprs, &params.final_group_by)?;
-                                let projected =
-                                    agged.eval_expression_list(&params.final_projections)?;
-                                Ok(projected)
-                            }
-                            // Otherwise, partially aggregate the unaggregated partitions, concatenate them with the partially aggregated partitions, and finalize the result.
-                            else {
-                                let leftover_partial_agg =
-                                    MicroPartition::concat(&unaggregated)?
-                                        .agg(&params.partial_agg_exprs, &params.group_by)?;
-                                let concated = MicroPartition::concat(
-                                    partially_aggregated
-                                        .iter()
-                                        .chain(std::iter::once(&leftover_partial_agg)),
-                                )?;
-                                let agged = concated
-                                    .agg(&params.final_agg_exprs, &params.final_group_by)?;
-                                let projected =
-                                    agged.eval_expression_list(&params.final_projections)?;
-                                Ok(projected)
-                            }
-                        });
-                    }
-                    let results = per_partition_finalize_tasks
-                        .join_all()
-                        .await
-                        .into_iter()
-                        .collect::<DaftResult<Vec<_>>>()?;
+                            .collect::<DaftResult<Vec<_>>>()?
+                    } else {
+                        let mut per_partition_finalize_tasks = tokio::task::JoinSet::new();
+                        for per_partition_state in per_partition_states {
+                            let params = params.clone();
+                            per_partition_finalize_tasks.spawn(async move {
+                                Self::finalize_partition(per_partition_state, &params)
+                            });
+                        }
+                        per_partition_finalize_tasks
+                            .join_all()
+                            .await
+                            .into_iter()
+                            .collect::<DaftResult<Vec<_>>>()?
+                    };
                     let concated = MicroPartition::concat(&results)?;
                     Ok(Some(Arc::new(concated)))
                 },
@@ -453,11 +613,16 @@ impl BlockingSink for GroupedAggregateSink {
         get_compute_pool_num_threads()
     }
 
+    fn can_spill(&self) -> bool {
+        true
+    }
+
     fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
         Ok(Box::new(GroupedAggregateState::new(
             self.num_partitions(),
             self.partial_agg_threshold,
             self.high_cardinality_threshold_ratio,
+            (self.memory_limit_bytes / self.num_partitions()) as u64,
         )))
     }
 }
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use common_error::DaftResult;
-use daft_core::prelude::SchemaRef;
+use daft_core::prelude::{SchemaRef, Series};
 use daft_dsl::ExprRef;
 use daft_micropartition::MicroPartition;
 use daft_recordbatch::{make_probeable_builder, ProbeState, ProbeableBuilder, RecordBatch};
@@ -12,13 +12,27 @@ use super::blocking_sink::{
     BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
     BlockingSinkStatus,
 };
-use crate::{state_bridge::BroadcastStateBridgeRef, ExecutionTaskSpawner};
+use crate::{
+    runtime_stats::morsel_size_bytes,
+    sources::scan_task::PartitionPruningBridgeRef,
+    spill::{can_spill, SpillFile},
+    state_bridge::BroadcastStateBridgeRef,
+    ExecutionTaskSpawner,
+};
+
+/// Number of partitions the build side is split into once it goes over its memory limit.
+const NUM_SPILL_PARTITIONS: usize = 16;
 
 enum ProbeTableState {
     Building {
         probe_table_builder: Option<Box<dyn ProbeableBuilder>>,
         projection: Vec<ExprRef>,
         tables: Vec<RecordBatch>,
+        // Approximate in-memory size of `tables`.
+        size_bytes: u64,
+        // Once the build side has gone over its memory limit, `tables` only holds the first partition of the build
+        // side, and these hold the others.
+        spill_files: Vec<SpillFile>,
     },
     Done,
 }
@@ -38,46 +52,129 @@ impl ProbeTableState {
             )?),
             projection,
             tables: Vec::new(),
+            size_bytes: 0,
+            spill_files: Vec::new(),
         })
     }
 
-    fn add_tables(&mut self, input: &Arc<MicroPartition>) -> DaftResult<()> {
+    /// Approximate in-memory size of the build side.
+    fn size_bytes(&self) -> u64 {
+        match self {
+            Self::Building { size_bytes, .. } => *size_bytes,
+            Self::Done => 0,
+        }
+    }
+
+    /// Adds the input to the build side, and returns the number of bytes spilled to disk while doing so. The build side
+    /// spills once it goes over its memory limit, or once `exceeds_memory_share` asks it to.
+    fn add_tables(
+        &mut self,
+        input: &Arc<MicroPartition>,
+        spill_params: Option<&SpillParams>,
+        exceeds_memory_share: bool,
+    ) -> DaftResult<u64> {
         if let Self::Building {
             ref mut probe_table_builder,
             projection,
             tables,
+            size_bytes,
+            spill_files,
         } = self
         {
-            let probe_table_builder = probe_table_builder.as_mut().unwrap();
             let input_tables = input.get_tables()?;
+            if !spill_files.is_empty() {
+                return Self::add_partitioned_tables(
+                    input_tables.iter().cloned(),
+                    probe_table_builder.as_mut().unwrap(),
+                    projection,
+                    tables,
+                    size_bytes,
+                    spill_files,
+                );
+            }
+            let builder = probe_table_builder.as_mut().unwrap();
             if input_tables.is_empty() {
                 tables.push(RecordBatch::empty(Some(input.schema()))?);
-                return Ok(());
+                return Ok(0);
             }
             for table in input_tables.iter() {
                 tables.push(table.clone());
                 let join_keys = table.eval_expression_list(projection)?;
 
-                probe_table_builder.add_table(&join_keys)?;
+                builder.add_table(&join_keys)?;
+            }
+            *size_bytes += morsel_size_bytes(input);
+
+            // Over the memory limit, start over with the build side partitioned by the hash of the join keys, and
+            // only keep the first partition in memory.
+            if let Some(spill_params) = spill_params
+                && (*size_bytes > spill_params.memory_limit_bytes || exceeds_memory_share)
+                && can_spill(&input.schema())
+            {
+                *probe_table_builder = Some(spill_params.make_probeable_builder()?);
+                *spill_files = (1..NUM_SPILL_PARTITIONS)
+                    .map(|_| SpillFile::new(input.schema()))
+                    .collect::<DaftResult<_>>()?;
+                *size_bytes = 0;
+                let held_tables = std::mem::take(tables);
+                return Self::add_partitioned_tables(
+                    held_tables.into_iter(),
+                    probe_table_builder.as_mut().unwrap(),
+                    projection,
+                    tables,
+                    size_bytes,
+                    spill_files,
+                );
             }
-            Ok(())
+            Ok(0)
         } else {
             panic!("add_tables can only be used during the Building Phase")
         }
     }
-    fn finalize(&mut self) -> ProbeState {
+
+    fn add_partitioned_tables(
+        input_tables: impl Iterator<Item = RecordBatch>,
+        probe_table_builder: &mut Box<dyn ProbeableBuilder>,
+        projection: &[ExprRef],
+        tables: &mut Vec<RecordBatch>,
+        size_bytes: &mut u64,
+        spill_files: &mut [SpillFile],
+    ) -> DaftResult<u64> {
+        let mut spilled_bytes = 0;
+        for table in input_tables {
+            let mut partitions = table
+                .partition_by_hash(projection, spill_files.len() + 1)?
+                .into_iter();
+            let first = partitions.next().expect("should have the first partition");
+            *size_bytes += first.size_bytes()? as u64;
+            probe_table_builder.add_table(&first.eval_expression_list(projection)?)?;
+            tables.push(first);
+            for (partition, spill_file) in partitions.zip(spill_files.iter_mut()) {
+                spilled_bytes += partition.size_bytes()? as u64;
+                spill_file.write(&[partition])?;
+            }
+        }
+        Ok(spilled_bytes)
+    }
+
+    fn finalize(&mut self) -> DaftResult<(ProbeState, Vec<SpillFile>)> {
         if let Self::Building {
             probe_table_builder,
             tables,
+            spill_files,
             ..
         } = self
         {
             let ptb = std::mem::take(probe_table_builder).expect("should be set in building mode");
             let pt = ptb.build();
 
-            let ps = ProbeState::new(pt, tables.clone().into());
+            let ps = ProbeState::new(pt, std::mem::take(tables).into());
+            let mut spill_files = std::mem::take(spill_files);
+            for spill_file in &mut spill_files {
+                spill_file.finish()?;
+            }
             *self = Self::Done;
-            ps
+            Ok((ps, spill_files))
         } else {
             panic!("finalize can only be used during the Building Phase")
         }
@@ -90,12 +187,95 @@ impl BlockingSinkState for ProbeTableState {
     }
 }
 
+/// How the build side spills to disk once it holds more than `memory_limit_bytes`, and where the spilled partitions are
+/// sent once the build side is done.
+struct SpillParams {
+    memory_limit_bytes: u64,
+    key_schema: SchemaRef,
+    projection: Vec<ExprRef>,
+    nulls_equal_aware: Option<Vec<bool>>,
+    track_indices: bool,
+    bridge: BroadcastStateBridgeRef<SpilledBuildPartitions>,
+}
+
+impl SpillParams {
+    fn make_probeable_builder(&self) -> DaftResult<Box<dyn ProbeableBuilder>> {
+        make_probeable_builder(
+            self.key_schema.clone(),
+            self.nulls_equal_aware.as_ref(),
+            self.track_indices,
+        )
+    }
+}
+
+/// The partitions of the build side of a hash join that were spilled to disk.
+///
+/// A build side that goes over its memory limit is partitioned by the hash of its join keys, and only its first
+/// partition is built into the probe table. The probe side is partitioned the same way, and joins each of the other
+/// partitions with the matching spilled build partition once it is done, one partition at a time.
+pub(crate) struct SpilledBuildPartitions {
+    params: Arc<SpillParams>,
+    spill_files: Vec<SpillFile>,
+}
+
+impl SpilledBuildPartitions {
+    /// The number of partitions of the build side, including the one that is kept in memory.
+    pub(crate) fn num_partitions(&self) -> usize {
+        self.spill_files.len() + 1
+    }
+
+    /// Reads back the spilled build partition with the given index, which is at least 1, and builds its probe table.
+    pub(crate) fn read_probe_state(&self, partition: usize) -> DaftResult<ProbeState> {
+        let spilled = self.spill_files[partition - 1].read()?;
+        let mut probe_table_builder = self.params.make_probeable_builder()?;
+        let mut tables = spilled.get_tables()?.as_ref().clone();
+        if tables.is_empty() {
+            tables.push(RecordBatch::empty(Some(spilled.schema()))?);
+        }
+        for table in &tables {
+            probe_table_builder.add_table(&table.eval_expression_list(&self.params.projection)?)?;
+        }
+        Ok(ProbeState::new(probe_table_builder.build(), tables.into()))
+    }
+}
+
+/// The join key of the build side to send to a partitioned scan on the probe side for dynamic partition pruning.
+struct PartitionPruningKeys {
+    key: ExprRef,
+    max_keys: usize,
+    bridge: PartitionPruningBridgeRef,
+}
+
+impl PartitionPruningKeys {
+    fn send(&self, probe_state: &ProbeState) -> DaftResult<()> {
+        let tables = probe_state.get_tables();
+        let num_rows: usize = tables.iter().map(RecordBatch::len).sum();
+        let keys = if tables.is_empty() || num_rows > self.max_keys {
+            None
+        } else {
+            let key_tables = tables
+                .iter()
+                .map(|table| table.eval_expression_list(std::slice::from_ref(&self.key)))
+                .collect::<DaftResult<Vec<_>>>()?;
+            let key_columns = key_tables
+                .iter()
+                .map(|table| table.get_column_by_index(0))
+                .collect::<DaftResult<Vec<_>>>()?;
+            Some(Series::concat(&key_columns)?)
+        };
+        self.bridge.set_state(keys.into());
+        Ok(())
+    }
+}
+
 pub struct HashJoinBuildSink {
     key_schema: SchemaRef,
     projection: Vec<ExprRef>,
     nulls_equal_aware: Option<Vec<bool>>,
     track_indices: bool,
     probe_state_bridge: BroadcastStateBridgeRef<ProbeState>,
+    partition_pruning_keys: Option<PartitionPruningKeys>,
+    spill_params: Option<Arc<SpillParams>>,
 }
 
 impl HashJoinBuildSink {
@@ -112,8 +292,44 @@ impl HashJoinBuildSink {
             nulls_equal_aware,
             track_indices,
             probe_state_bridge,
+            partition_pruning_keys: None,
+            spill_params: None,
         })
     }
+
+    /// Spills partitions of the build side to disk once it holds more than `memory_limit_bytes`, and sends them through
+    /// `bridge` once the build side is done, with no partitions if it stayed under the limit.
+    pub(crate) fn with_spilling(
+        mut self,
+        memory_limit_bytes: usize,
+        bridge: BroadcastStateBridgeRef<SpilledBuildPartitions>,
+    ) -> Self {
+        self.spill_params = Some(Arc::new(SpillParams {
+            memory_limit_bytes: memory_limit_bytes as u64,
+            key_schema: self.key_schema.clone(),
+            projection: self.projection.clone(),
+            nulls_equal_aware: self.nulls_equal_aware.clone(),
+            track_indices: self.track_indices,
+            bridge,
+        }));
+        self
+    }
+
+    /// Sends the build side's values of `key` through `bridge` once the probe table is built, or `None` if the
+    /// build side has more than `max_keys` rows.
+    pub(crate) fn with_partition_pruning(
+        mut self,
+        key: ExprRef,
+        max_keys: usize,
+        bridge: PartitionPruningBridgeRef,
+    ) -> Self {
+        self.partition_pruning_keys = Some(PartitionPruningKeys {
+            key,
+            max_keys,
+            bridge,
+        });
+        self
+    }
 }
 
 impl BlockingSink for HashJoinBuildSink {
@@ -126,6 +342,12 @@ impl BlockingSink for HashJoinBuildSink {
         display.push("HashJoinBuild:".to_string());
         display.push(format!("Track Indices: {}", self.track_indices));
         display.push(format!("Key Schema: {}", self.key_schema.short_string()));
+        if let Some(partition_pruning_keys) = &self.partition_pruning_keys {
+            display.push(format!(
+                "Dynamic Partition Pruning Key = {}",
+                partition_pruning_keys.key
+            ));
+        }
         if let Some(null_equals_nulls) = &self.nulls_equal_aware {
             display.push(format!(
                 "Null equals Nulls = [{}]",
@@ -141,6 +363,8 @@ impl BlockingSink for HashJoinBuildSink {
         mut state: Box<dyn BlockingSinkState>,
         spawner: &ExecutionTaskSpawner,
     ) -> BlockingSinkSinkResult {
+        let spill_params = self.spill_params.clone();
+        let runtime_stats = spawner.runtime_stats();
         spawner
             .spawn(
                 async move {
@@ -148,7 +372,21 @@ impl BlockingSink for HashJoinBuildSink {
                         .as_any_mut()
                         .downcast_mut::<ProbeTableState>()
                         .expect("HashJoinBuildSink should have ProbeTableState");
-                    probe_table_state.add_tables(&input)?;
+                    let size_before = probe_table_state.size_bytes();
+                    let spilled_bytes = probe_table_state.add_tables(
+                        &input,
+                        spill_params.as_deref(),
+                        runtime_stats.exceeds_memory_share(),
+                    )?;
+                    let size_after = probe_table_state.size_bytes();
+                    if size_after >= size_before {
+                        runtime_stats.mark_bytes_held(size_after - size_before);
+                    } else {
+                        runtime_stats.mark_bytes_released(size_before - size_after);
+                    }
+                    if spilled_bytes > 0 {
+                        runtime_stats.mark_bytes_spilled(spilled_bytes);
+                    }
                     Ok(BlockingSinkStatus::NeedMoreInput(state))
                 },
                 info_span!("HashJoinBuildSink::sink"),
@@ -168,7 +406,31 @@ impl BlockingSink for HashJoinBuildSink {
             .as_any_mut()
             .downcast_mut::<ProbeTableState>()
             .expect("State type mismatch");
-        let finalized_probe_state = probe_table_state.finalize();
+        let (finalized_probe_state, spill_files) = match probe_table_state.finalize() {
+            Ok(finalized) => finalized,
+            Err(e) => return Err(e).into(),
+        };
+        if let Some(partition_pruning_keys) = &self.partition_pruning_keys {
+            // The keys of the spilled partitions aren't in the probe table, so nothing can be pruned.
+            let result = if spill_files.is_empty() {
+                partition_pruning_keys.send(&finalized_probe_state)
+            } else {
+                partition_pruning_keys.bridge.set_state(None.into());
+                Ok(())
+            };
+            if let Err(e) = result {
+                return Err(e).into();
+            }
+        }
+        if let Some(spill_params) = &self.spill_params {
+            spill_params.bridge.set_state(
+                SpilledBuildPartitions {
+                    params: spill_params.clone(),
+                    spill_files,
+                }
+                .into(),
+            );
+        }
         self.probe_state_bridge
             .set_state(finalized_probe_state.into());
         Ok(None).into()
@@ -178,6 +440,10 @@ impl BlockingSink for HashJoinBuildSink {
         1
     }
 
+    fn can_spill(&self) -> bool {
+        self.spill_params.is_some()
+    }
+
     fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
         Ok(Box::new(ProbeTableState::new(
             &self.key_schema,
@@ -0,0 +1,416 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_core::prelude::SchemaRef;
+use daft_dsl::ExprRef;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::{GrowableRecordBatch, ProbeState};
+use indexmap::IndexSet;
+use itertools::Itertools;
+use tracing::{info_span, instrument, Span};
+
+use super::{
+    hash_join_build::SpilledBuildPartitions,
+    streaming_sink::{
+        StreamingSink, StreamingSinkExecuteResult, StreamingSinkFinalizeResult,
+        StreamingSinkOutput, StreamingSinkState,
+    },
+};
+use crate::{
+    dispatcher::{DispatchSpawner, RoundRobinDispatcher, UnorderedDispatcher},
+    runtime_stats::morsel_size_bytes,
+    spill::SpillFile,
+    state_bridge::BroadcastStateBridgeRef,
+    ExecutionRuntimeContext, ExecutionTaskSpawner,
+};
+
+enum InnerHashJoinProbeState {
+    Building(
+        BroadcastStateBridgeRef<ProbeState>,
+        BroadcastStateBridgeRef<SpilledBuildPartitions>,
+    ),
+    Probing {
+        probe_state: Arc<ProbeState>,
+        spilled_build: Arc<SpilledBuildPartitions>,
+        // The rows of the probe side in each spilled build partition, to join once the probe side is done.
+        spill_files: Vec<Option<SpillFile>>,
+    },
+}
+
+impl InnerHashJoinProbeState {
+    async fn get_or_await_probe_state(
+        &mut self,
+    ) -> (
+        Arc<ProbeState>,
+        Arc<SpilledBuildPartitions>,
+        &mut Vec<Option<SpillFile>>,
+    ) {
+        if let Self::Building(probe_state_bridge, spilled_build_bridge) = self {
+            let probe_state = probe_state_bridge.get_state().await;
+            let spilled_build = spilled_build_bridge.get_state().await;
+            let spill_files = (1..spilled_build.num_partitions()).map(|_| None).collect();
+            *self = Self::Probing {
+                probe_state,
+                spilled_build,
+                spill_files,
+            };
+        }
+        match self {
+            Self::Probing {
+                probe_state,
+                spilled_build,
+                spill_files,
+            } => (probe_state.clone(), spilled_build.clone(), spill_files),
+            Self::Building(..) => unreachable!("InnerHashJoinProbeState should be probing"),
+        }
+    }
+}
+
+impl StreamingSinkState for InnerHashJoinProbeState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+struct InnerHashJoinParams {
+    probe_on: Vec<ExprRef>,
+    common_join_keys: Vec<String>,
+    left_non_join_columns: Vec<String>,
+    right_non_join_columns: Vec<String>,
+    build_on_left: bool,
+}
+
+pub struct InnerHashJoinProbeSink {
+    params: Arc<InnerHashJoinParams>,
+    output_schema: SchemaRef,
+    probe_state_bridge: BroadcastStateBridgeRef<ProbeState>,
+    spilled_build_bridge: BroadcastStateBridgeRef<SpilledBuildPartitions>,
+}
+
+impl InnerHashJoinProbeSink {
+    const DEFAULT_GROWABLE_SIZE: usize = 20;
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        probe_on: Vec<ExprRef>,
+        left_schema: &SchemaRef,
+        right_schema: &SchemaRef,
+        build_on_left: bool,
+        common_join_keys: IndexSet<String>,
+        output_schema: &SchemaRef,
+        probe_state_bridge: BroadcastStateBridgeRef<ProbeState>,
+        spilled_build_bridge: BroadcastStateBridgeRef<SpilledBuildPartitions>,
+    ) -> Self {
+        let left_non_join_columns = left_schema
+            .field_names()
+            .filter(|c| !common_join_keys.contains(*c))
+            .map(ToString::to_string)
+            .collect();
+        let right_non_join_columns = right_schema
+            .field_names()
+            .filter(|c| !common_join_keys.contains(*c))
+            .map(ToString::to_string)
+            .collect();
+        let common_join_keys = common_join_keys.into_iter().collect();
+        Self {
+            params: Arc::new(InnerHashJoinParams {
+                probe_on,
+                common_join_keys,
+                left_non_join_columns,
+                right_non_join_columns,
+                build_on_left,
+            }),
+            output_schema: output_schema.clone(),
+            probe_state_bridge,
+            spilled_build_bridge,
+        }
+    }
+
+    /// Probes the part of the input in the build partition that is kept in memory, and spills the rest of it to disk
+    /// by build partition. Returns the probed rows and the number of bytes spilled.
+    fn probe_or_spill(
+        input: &Arc<MicroPartition>,
+        probe_state: &Arc<ProbeState>,
+        num_partitions: usize,
+        spill_files: &mut [Option<SpillFile>],
+        params: &InnerHashJoinParams,
+        output_schema: &SchemaRef,
+    ) -> DaftResult<(Arc<MicroPartition>, u64)> {
+        let mut partitions = input
+            .partition_by_hash(&params.probe_on, num_partitions)?
+            .into_iter();
+        let first = Arc::new(partitions.next().expect("should have the first partition"));
+        let mut spilled_bytes = 0;
+        for (partition, spill_file) in partitions.zip(spill_files.iter_mut()) {
+            if partition.is_empty() {
+                continue;
+            }
+            let spill_file = match spill_file {
+                Some(spill_file) => spill_file,
+                None => spill_file.insert(SpillFile::new(partition.schema())?),
+            };
+            spilled_bytes += morsel_size_bytes(&partition);
+            spill_file.write(&partition.get_tables()?)?;
+        }
+        if first.is_empty() {
+            let empty = Arc::new(MicroPartition::empty(Some(output_schema.clone())));
+            return Ok((empty, spilled_bytes));
+        }
+        let probed = Self::probe_inner(
+            &first,
+            probe_state,
+            &params.probe_on,
+            &params.common_join_keys,
+            &params.left_non_join_columns,
+            &params.right_non_join_columns,
+            params.build_on_left,
+        )?;
+        Ok((probed, spilled_bytes))
+    }
+
+    /// Joins each spilled build partition with the rows of the probe side that were spilled for it, one partition at
+    /// a time.
+    fn join_spilled_partitions(
+        states: Vec<Box<dyn StreamingSinkState>>,
+        params: &InnerHashJoinParams,
+    ) -> DaftResult<Option<Arc<MicroPartition>>> {
+        let mut spilled_build = None;
+        let mut per_worker_spill_files = vec![];
+        for mut state in states {
+            let state = state
+                .as_any_mut()
+                .downcast_mut::<InnerHashJoinProbeState>()
+                .expect("InnerHashJoinProbeSink should have InnerHashJoinProbeState");
+            if let InnerHashJoinProbeState::Probing {
+                spilled_build: worker_spilled_build,
+                spill_files,
+                ..
+            } = state
+            {
+                spilled_build = Some(worker_spilled_build.clone());
+                per_worker_spill_files.push(std::mem::take(spill_files));
+            }
+        }
+        let Some(spilled_build) = spilled_build else {
+            return Ok(None);
+        };
+        if spilled_build.num_partitions() == 1 {
+            return Ok(None);
+        }
+
+        let mut results = vec![];
+        for partition in 1..spilled_build.num_partitions() {
+            let mut probe_side = vec![];
+            for spill_files in &mut per_worker_spill_files {
+                if let Some(mut spill_file) = spill_files[partition - 1].take() {
+                    spill_file.finish()?;
+                    probe_side.push(spill_file.read()?);
+                }
+            }
+            if probe_side.is_empty() {
+                continue;
+            }
+            let probe_state = Arc::new(spilled_build.read_probe_state(partition)?);
+            let probed = Self::probe_inner(
+                &Arc::new(MicroPartition::concat(&probe_side)?),
+                &probe_state,
+                &params.probe_on,
+                &params.common_join_keys,
+                &params.left_non_join_columns,
+                &params.right_non_join_columns,
+                params.build_on_left,
+            )?;
+            results.push(probed);
+        }
+        if results.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Arc::new(MicroPartition::concat(results)?)))
+    }
+
+    fn probe_inner(
+        input: &Arc<MicroPartition>,
+        probe_state: &Arc<ProbeState>,
+        probe_on: &[ExprRef],
+        common_join_keys: &[String],
+        left_non_join_columns: &[String],
+        right_non_join_columns: &[String],
+        build_on_left: bool,
+    ) -> DaftResult<Arc<MicroPartition>> {
+        let probe_table = probe_state.get_probeable();
+        let tables = probe_state.get_tables();
+
+        let _growables = info_span!("InnerHashJoinOperator::build_growables").entered();
+
+        let mut build_side_growable = GrowableRecordBatch::new(
+            &tables.iter().collect::<Vec<_>>(),
+            false,
+            Self::DEFAULT_GROWABLE_SIZE,
+        )?;
+
+        let input_tables = input.get_tables()?;
+
+        let mut probe_side_growable = GrowableRecordBatch::new(
+            &input_tables.iter().collect::<Vec<_>>(),
+            false,
+            Self::DEFAULT_GROWABLE_SIZE,
+        )?;
+
+        drop(_growables);
+        {
+            let _loop = info_span!("InnerHashJoinOperator::eval_and_probe").entered();
+            for (probe_side_table_idx, table) in input_tables.iter().enumerate() {
+                // we should emit one table at a time when this is streaming
+                let join_keys = table.eval_expression_list(probe_on)?;
+                let idx_mapper = probe_table.probe_indices(&join_keys)?;
+
+                for (probe_row_idx, inner_iter) in idx_mapper.make_iter().enumerate() {
+                    if let Some(inner_iter) = inner_iter {
+                        for (build_side_table_idx, build_row_idx) in inner_iter {
+                            build_side_growable.extend(
+                                build_side_table_idx as usize,
+                                build_row_idx as usize,
+                                1,
+                            );
+                            // we can perform run length compression for this to make this more efficient
+                            probe_side_growable.extend(probe_side_table_idx, probe_row_idx, 1);
+                        }
+                    }
+                }
+            }
+        }
+        let build_side_table = build_side_growable.build()?;
+        let probe_side_table = probe_side_growable.build()?;
+
+        let (left_table, right_table) = if build_on_left {
+            (build_side_table, probe_side_table)
+        } else {
+            (probe_side_table, build_side_table)
+        };
+
+        let join_keys_table = left_table.get_columns(common_join_keys)?;
+        let left_non_join_columns = left_table.get_columns(left_non_join_columns)?;
+        let right_non_join_columns = right_table.get_columns(right_non_join_columns)?;
+        let final_table = join_keys_table
+            .union(&left_non_join_columns)?
+            .union(&right_non_join_columns)?;
+
+        Ok(Arc::new(MicroPartition::new_loaded(
+            final_table.schema.clone(),
+            Arc::new(vec![final_table]),
+            None,
+        )))
+    }
+}
+
+impl StreamingSink for InnerHashJoinProbeSink {
+    #[instrument(skip_all, name = "InnerHashJoinProbeSink::execute")]
+    fn execute(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn StreamingSinkState>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> StreamingSinkExecuteResult {
+        if input.is_empty() {
+            let empty = Arc::new(MicroPartition::empty(Some(self.output_schema.clone())));
+            return Ok((state, StreamingSinkOutput::NeedMoreInput(Some(empty)))).into();
+        }
+
+        let params = self.params.clone();
+        let output_schema = self.output_schema.clone();
+        let runtime_stats = spawner.runtime_stats();
+        spawner
+            .spawn(
+                async move {
+                    let inner_join_state = state
+                        .as_any_mut()
+                        .downcast_mut::<InnerHashJoinProbeState>()
+                        .expect("InnerHashJoinProbeSink should have InnerHashJoinProbeState");
+                    let (probe_state, spilled_build, spill_files) =
+                        inner_join_state.get_or_await_probe_state().await;
+                    let res = if spilled_build.num_partitions() == 1 {
+                        Self::probe_inner(
+                            &input,
+                            &probe_state,
+                            &params.probe_on,
+                            &params.common_join_keys,
+                            &params.left_non_join_columns,
+                            &params.right_non_join_columns,
+                            params.build_on_left,
+                        )?
+                    } else {
+                        let (probed, spilled_bytes) = Self::probe_or_spill(
+                            &input,
+                            &probe_state,
+                            spilled_build.num_partitions(),
+                            spill_files,
+                            &params,
+                            &output_schema,
+                        )?;
+                        runtime_stats.mark_bytes_spilled(spilled_bytes);
+                        probed
+                    };
+                    Ok((state, StreamingSinkOutput::NeedMoreInput(Some(res))))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    #[instrument(skip_all, name = "InnerHashJoinProbeSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Box<dyn StreamingSinkState>>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> StreamingSinkFinalizeResult {
+        let params = self.params.clone();
+        spawner
+            .spawn(
+                async move { Self::join_spilled_partitions(states, &params) },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn name(&self) -> &'static str {
+        "InnerHashJoinProbe"
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push("InnerHashJoinProbe:".to_string());
+        res.push(format!(
+            "Probe on: [{}]",
+            self.params
+                .probe_on
+                .iter()
+                .map(|e| e.to_string())
+                .join(", ")
+        ));
+        res.push(format!("Build on left: {}", self.params.build_on_left));
+        res
+    }
+
+    fn make_state(&self) -> Box<dyn StreamingSinkState> {
+        Box::new(InnerHashJoinProbeState::Building(
+            self.probe_state_bridge.clone(),
+            self.spilled_build_bridge.clone(),
+        ))
+    }
+
+    fn dispatch_spawner(
+        &self,
+        runtime_handle: &ExecutionRuntimeContext,
+        maintain_order: bool,
+    ) -> Arc<dyn DispatchSpawner> {
+        if maintain_order {
+            Arc::new(RoundRobinDispatcher::new(Some(
+                runtime_handle.default_morsel_size(),
+            )))
+        } else {
+            Arc::new(UnorderedDispatcher::new(Some(
+                runtime_handle.default_morsel_size(),
+            )))
+        }
+    }
+}
@@ -1,16 +1,20 @@
 pub mod aggregate;
 pub mod anti_semi_hash_join_probe;
+pub mod asof_join_build;
 pub mod blocking_sink;
 pub mod concat;
 pub mod cross_join_collect;
 pub mod grouped_aggregate;
 pub mod hash_join_build;
+pub mod inner_hash_join_probe;
 pub mod limit;
 pub mod monotonically_increasing_id;
 pub mod outer_hash_join_probe;
 pub mod pivot;
+pub mod range_join_build;
 pub mod sort;
 pub mod streaming_sink;
+pub mod top_n;
 pub mod window_partition_and_order_by;
 pub mod window_partition_only;
 pub mod write;
@@ -0,0 +1,163 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_core::prelude::SchemaRef;
+use daft_dsl::{join::RangeCondition, ExprRef};
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::{RangeJoinBuild, RecordBatch};
+use itertools::Itertools;
+use tracing::{info_span, instrument};
+
+use super::blocking_sink::{
+    BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
+    BlockingSinkStatus,
+};
+use crate::{state_bridge::BroadcastStateBridgeRef, ExecutionTaskSpawner};
+
+struct RangeJoinBuildState(Option<Vec<RecordBatch>>);
+
+impl BlockingSinkState for RangeJoinBuildState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+struct RangeJoinBuildParams {
+    schema: SchemaRef,
+    build_on: Vec<ExprRef>,
+    conditions: Vec<RangeCondition>,
+    residual: Option<ExprRef>,
+}
+
+/// Collects the build side of a range join and sorts it once all of it has arrived.
+pub struct RangeJoinBuildSink {
+    params: Arc<RangeJoinBuildParams>,
+    state_bridge: BroadcastStateBridgeRef<RangeJoinBuild>,
+}
+
+impl RangeJoinBuildSink {
+    pub(crate) fn new(
+        schema: SchemaRef,
+        build_on: Vec<ExprRef>,
+        conditions: Vec<RangeCondition>,
+        residual: Option<ExprRef>,
+        state_bridge: BroadcastStateBridgeRef<RangeJoinBuild>,
+    ) -> Self {
+        Self {
+            params: Arc::new(RangeJoinBuildParams {
+                schema,
+                build_on,
+                conditions,
+                residual,
+            }),
+            state_bridge,
+        }
+    }
+}
+
+impl BlockingSink for RangeJoinBuildSink {
+    fn name(&self) -> &'static str {
+        "RangeJoinBuild"
+    }
+
+    fn sink(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn BlockingSinkState>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkSinkResult {
+        if input.is_empty() {
+            return Ok(BlockingSinkStatus::NeedMoreInput(state)).into();
+        }
+
+        spawner
+            .spawn(
+                async move {
+                    let range_join_build_state = state
+                        .as_any_mut()
+                        .downcast_mut::<RangeJoinBuildState>()
+                        .expect("RangeJoinBuildSink should have RangeJoinBuildState");
+
+                    range_join_build_state
+                        .0
+                        .as_mut()
+                        .expect("Collected tables should not be consumed before sink stage is done")
+                        .extend(input.get_tables()?.iter().cloned());
+
+                    Ok(BlockingSinkStatus::NeedMoreInput(state))
+                },
+                info_span!("RangeJoinBuildSink::sink"),
+            )
+            .into()
+    }
+
+    #[instrument(skip_all, name = "RangeJoinBuildSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Box<dyn BlockingSinkState>>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkFinalizeResult {
+        let mut state = states.into_iter().next().unwrap();
+        let tables = state
+            .as_any_mut()
+            .downcast_mut::<RangeJoinBuildState>()
+            .expect("RangeJoinBuildSink should have RangeJoinBuildState")
+            .0
+            .take()
+            .expect("Range join build state should have tables before finalize is called");
+
+        let params = self.params.clone();
+        let state_bridge = self.state_bridge.clone();
+        spawner
+            .spawn(
+                async move {
+                    let table = if tables.is_empty() {
+                        RecordBatch::empty(Some(params.schema.clone()))?
+                    } else {
+                        RecordBatch::concat(&tables)?
+                    };
+                    let build = RangeJoinBuild::try_new(
+                        &table,
+                        &params.build_on,
+                        params.conditions.clone(),
+                        params.residual.clone(),
+                    )?;
+                    state_bridge.set_state(Arc::new(build));
+                    Ok(None)
+                },
+                info_span!("RangeJoinBuildSink::finalize"),
+            )
+            .into()
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
+        Ok(Box::new(RangeJoinBuildState(Some(Vec::new()))))
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec!["RangeJoinBuild:".to_string()];
+        if !self.params.build_on.is_empty() {
+            res.push(format!(
+                "Build on: [{}]",
+                self.params
+                    .build_on
+                    .iter()
+                    .map(|e| e.to_string())
+                    .join(", ")
+            ));
+        }
+        res.push(format!(
+            "Conditions: [{}]",
+            self.params
+                .conditions
+                .iter()
+                .map(|c| c.to_string())
+                .join(", ")
+        ));
+        res
+    }
+
+    fn max_concurrency(&self) -> usize {
+        1
+    }
+}
@@ -1,7 +1,8 @@
 use std::sync::Arc;
 
 use common_error::DaftResult;
-use daft_dsl::ExprRef;
+use common_runtime::get_compute_pool_num_threads;
+use daft_dsl::{resolved_col, ExprRef};
 use daft_micropartition::MicroPartition;
 use itertools::Itertools;
 use tracing::{instrument, Span};
@@ -43,22 +44,72 @@ impl BlockingSinkState for SortState {
     }
 }
 
+/// The name of the column of row numbers that stable sorts break ties with.
+const ROW_NUMBER_COLUMN: &str = "__daft_sort_row_number";
+
+/// Sorts a partition, breaking ties between equal keys by the order of the rows if `stable` is set, which costs an
+/// extra sort key.
+pub(crate) fn sort_partition(
+    part: &MicroPartition,
+    sort_by: &[ExprRef],
+    descending: &[bool],
+    nulls_first: &[bool],
+    stable: bool,
+) -> DaftResult<MicroPartition> {
+    if !stable {
+        return part.sort(sort_by, descending, nulls_first);
+    }
+    let numbered = part.add_monotonically_increasing_id(0, ROW_NUMBER_COLUMN)?;
+    let sort_by = sort_by
+        .iter()
+        .cloned()
+        .chain(std::iter::once(resolved_col(ROW_NUMBER_COLUMN)))
+        .collect::<Vec<_>>();
+    let descending = descending
+        .iter()
+        .copied()
+        .chain(std::iter::once(false))
+        .collect::<Vec<_>>();
+    let nulls_first = nulls_first
+        .iter()
+        .copied()
+        .chain(std::iter::once(false))
+        .collect::<Vec<_>>();
+    let columns = part
+        .schema()
+        .field_names()
+        .map(resolved_col)
+        .collect::<Vec<_>>();
+    numbered
+        .sort(&sort_by, &descending, &nulls_first)?
+        .eval_expression_list(&columns)
+}
+
 struct SortParams {
     sort_by: Vec<ExprRef>,
     descending: Vec<bool>,
     nulls_first: Vec<bool>,
+    stable: bool,
 }
 pub struct SortSink {
     params: Arc<SortParams>,
 }
 
 impl SortSink {
-    pub fn new(sort_by: Vec<ExprRef>, descending: Vec<bool>, nulls_first: Vec<bool>) -> Self {
+    /// Creates a sink that sorts all of its input. Stable sinks take their input on a single worker and in order, and
+    /// keep the order of rows with equal keys.
+    pub fn new(
+        sort_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+        stable: bool,
+    ) -> Self {
         Self {
             params: Arc::new(SortParams {
                 sort_by,
                 descending,
                 nulls_first,
+                stable,
             }),
         }
     }
@@ -98,10 +149,12 @@ impl BlockingSink for SortSink {
                         state.finalize()
                     });
                     let concated = MicroPartition::concat(parts)?;
-                    let sorted = Arc::new(concated.sort(
+                    let sorted = Arc::new(sort_partition(
+                        &concated,
                         &params.sort_by,
                         &params.descending,
                         &params.nulls_first,
+                        params.stable,
                     )?);
                     Ok(Some(sorted))
                 },
@@ -139,4 +192,12 @@ impl BlockingSink for SortSink {
     fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
         Ok(Box::new(SortState::Building(Vec::new())))
     }
+
+    fn max_concurrency(&self) -> usize {
+        if self.params.stable {
+            1
+        } else {
+            get_compute_pool_num_threads()
+        }
+    }
 }
@@ -6,6 +6,7 @@ use common_runtime::{get_compute_pool_num_threads, get_compute_runtime};
 use daft_logical_plan::stats::StatsState;
 use daft_micropartition::MicroPartition;
 use snafu::ResultExt;
+use tokio_util::sync::CancellationToken;
 use tracing::{info_span, instrument};
 
 use crate::{
@@ -17,7 +18,7 @@ use crate::{
     pipeline::PipelineNode,
     progress_bar::ProgressBarColor,
     resource_manager::MemoryManager,
-    runtime_stats::{CountingReceiver, CountingSender, RuntimeStatsContext},
+    runtime_stats::{morsel_size_bytes, CountingReceiver, CountingSender, RuntimeStatsContext},
     ExecutionRuntimeContext, ExecutionTaskSpawner, JoinSnafu, OperatorOutput, TaskSet,
 };
 
@@ -106,16 +107,26 @@ impl StreamingSinkNode {
     #[instrument(level = "info", skip_all, name = "StreamingSink::run_worker")]
     async fn run_worker(
         op: Arc<dyn StreamingSink>,
+        worker_idx: usize,
         input_receiver: Receiver<Arc<MicroPartition>>,
         output_sender: Sender<Arc<MicroPartition>>,
         rt_context: Arc<RuntimeStatsContext>,
         memory_manager: Arc<MemoryManager>,
-    ) -> DaftResult<Box<dyn StreamingSinkState>> {
+        cancel: CancellationToken,
+    ) -> DaftResult<(usize, Box<dyn StreamingSinkState>)> {
         let span = info_span!("StreamingSink::Execute");
         let compute_runtime = get_compute_runtime();
-        let spawner = ExecutionTaskSpawner::new(compute_runtime, memory_manager, rt_context, span);
+        let spawner = ExecutionTaskSpawner::new(
+            compute_runtime,
+            memory_manager,
+            rt_context.clone(),
+            span,
+            cancel,
+        );
         let mut state = op.make_state();
         while let Some(morsel) = input_receiver.recv().await {
+            let morsel_bytes = morsel_size_bytes(&morsel);
+            rt_context.mark_bytes_held(morsel_bytes);
             loop {
                 let result = op.execute(morsel.clone(), state, &spawner).await??;
                 state = result.0;
@@ -123,46 +134,52 @@ impl StreamingSinkNode {
                     StreamingSinkOutput::NeedMoreInput(mp) => {
                         if let Some(mp) = mp {
                             if output_sender.send(mp).await.is_err() {
-                                return Ok(state);
+                                return Ok((worker_idx, state));
                             }
                         }
                         break;
                     }
                     StreamingSinkOutput::HasMoreOutput(mp) => {
                         if output_sender.send(mp).await.is_err() {
-                            return Ok(state);
+                            return Ok((worker_idx, state));
                         }
                     }
                     StreamingSinkOutput::Finished(mp) => {
                         if let Some(mp) = mp {
                             let _ = output_sender.send(mp).await;
                         }
-                        return Ok(state);
+                        return Ok((worker_idx, state));
                     }
                 }
             }
+            rt_context.mark_bytes_released(morsel_bytes);
         }
 
-        Ok(state)
+        Ok((worker_idx, state))
     }
 
     fn spawn_workers(
         op: Arc<dyn StreamingSink>,
         input_receivers: Vec<Receiver<Arc<MicroPartition>>>,
-        task_set: &mut TaskSet<DaftResult<Box<dyn StreamingSinkState>>>,
+        task_set: &mut TaskSet<DaftResult<(usize, Box<dyn StreamingSinkState>)>>,
         stats: Arc<RuntimeStatsContext>,
         maintain_order: bool,
         memory_manager: Arc<MemoryManager>,
+        cancel: CancellationToken,
     ) -> OrderingAwareReceiver<Arc<MicroPartition>> {
         let (output_sender, output_receiver) =
             create_ordering_aware_receiver_channel(maintain_order, input_receivers.len());
-        for (input_receiver, output_sender) in input_receivers.into_iter().zip(output_sender) {
+        for (worker_idx, (input_receiver, output_sender)) in
+            input_receivers.into_iter().zip(output_sender).enumerate()
+        {
             task_set.spawn(Self::run_worker(
                 op.clone(),
+                worker_idx,
                 input_receiver,
                 output_sender,
                 stats.clone(),
                 memory_manager.clone(),
+                cancel.clone(),
             ));
         }
         output_receiver
@@ -187,7 +204,9 @@ impl TreeDisplay for StreamingSinkNode {
                 }
                 if matches!(level, DisplayLevel::Verbose) {
                     let rt_result = self.runtime_stats.result();
-                    rt_result.display(&mut display, true, true, true).unwrap();
+                    rt_result
+                        .display(&mut display, true, true, true, true)
+                        .unwrap();
                 }
             }
         }
@@ -218,6 +237,8 @@ impl PipelineNode for StreamingSinkNode {
         maintain_order: bool,
         runtime_handle: &mut ExecutionRuntimeContext,
     ) -> crate::Result<Receiver<Arc<MicroPartition>>> {
+        self.runtime_stats
+            .attach_memory_reservation(runtime_handle.memory_pool().register(self.name(), false));
         let progress_bar = runtime_handle.make_progress_bar(
             self.name(),
             ProgressBarColor::Cyan,
@@ -254,6 +275,7 @@ impl PipelineNode for StreamingSinkNode {
         );
 
         let memory_manager = runtime_handle.memory_manager();
+        let cancel = runtime_handle.cancellation_token();
         runtime_handle.spawn(
             async move {
                 let mut task_set = TaskSet::new();
@@ -264,6 +286,7 @@ impl PipelineNode for StreamingSinkNode {
                     runtime_stats.clone(),
                     maintain_order,
                     memory_manager.clone(),
+                    cancel.clone(),
                 );
 
                 while let Some(morsel) = output_receiver.recv().await {
@@ -274,9 +297,14 @@ impl PipelineNode for StreamingSinkNode {
 
                 let mut finished_states = Vec::with_capacity(num_workers);
                 while let Some(result) = task_set.join_next().await {
-                    let state = result.context(JoinSnafu)??;
-                    finished_states.push(state);
+                    finished_states.push(result.context(JoinSnafu)??);
                 }
+                // Finalize the states in the order of their workers, as blocking sinks do.
+                finished_states.sort_by_key(|(worker_idx, _)| *worker_idx);
+                let finished_states = finished_states
+                    .into_iter()
+                    .map(|(_, state)| state)
+                    .collect();
 
                 let compute_runtime = get_compute_runtime();
                 let spawner = ExecutionTaskSpawner::new(
@@ -284,6 +312,7 @@ impl PipelineNode for StreamingSinkNode {
                     memory_manager,
                     runtime_stats.clone(),
                     info_span!("StreamingSink::Finalize"),
+                    cancel,
                 );
                 let finalized_result = op.finalize(finished_states, &spawner).await??;
                 if let Some(res) = finalized_result {
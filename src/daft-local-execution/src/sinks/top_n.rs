@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_runtime::get_compute_pool_num_threads;
+use daft_core::prelude::SchemaRef;
+use daft_dsl::ExprRef;
+use daft_micropartition::MicroPartition;
+use itertools::Itertools;
+use tracing::{instrument, Span};
+
+use super::{
+    blocking_sink::{
+        BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
+        BlockingSinkStatus,
+    },
+    sort::sort_partition,
+};
+use crate::ExecutionTaskSpawner;
+
+/// The first `limit` rows seen so far by a worker, in sorted order.
+struct TopNState {
+    top: Option<Arc<MicroPartition>>,
+}
+
+impl BlockingSinkState for TopNState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+struct TopNParams {
+    sort_by: Vec<ExprRef>,
+    descending: Vec<bool>,
+    nulls_first: Vec<bool>,
+    limit: usize,
+    schema: SchemaRef,
+    stable: bool,
+}
+
+impl TopNParams {
+    /// Merges the given partitions into their first `limit` rows in sorted order.
+    fn top_n(&self, parts: Vec<Arc<MicroPartition>>) -> DaftResult<MicroPartition> {
+        if parts.is_empty() {
+            return Ok(MicroPartition::empty(Some(self.schema.clone())));
+        }
+        let concated = MicroPartition::concat(parts)?;
+        sort_partition(
+            &concated,
+            &self.sort_by,
+            &self.descending,
+            &self.nulls_first,
+            self.stable,
+        )?
+        .head(self.limit)
+    }
+}
+
+pub struct TopNSink {
+    params: Arc<TopNParams>,
+}
+
+impl TopNSink {
+    pub fn new(
+        sort_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+        limit: usize,
+        schema: SchemaRef,
+        stable: bool,
+    ) -> Self {
+        Self {
+            params: Arc::new(TopNParams {
+                sort_by,
+                descending,
+                nulls_first,
+                limit,
+                schema,
+                stable,
+            }),
+        }
+    }
+}
+
+impl BlockingSink for TopNSink {
+    #[instrument(skip_all, name = "TopNSink::sink")]
+    fn sink(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn BlockingSinkState>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkSinkResult {
+        let params = self.params.clone();
+        spawner
+            .spawn(
+                async move {
+                    let top_n_state = state
+                        .as_any_mut()
+                        .downcast_mut::<TopNState>()
+                        .expect("TopNSink should have TopNState");
+                    let parts = top_n_state
+                        .top
+                        .take()
+                        .into_iter()
+                        .chain(std::iter::once(input))
+                        .collect();
+                    top_n_state.top = Some(Arc::new(params.top_n(parts)?));
+                    Ok(BlockingSinkStatus::NeedMoreInput(state))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    #[instrument(skip_all, name = "TopNSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Box<dyn BlockingSinkState>>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkFinalizeResult {
+        let params = self.params.clone();
+        spawner
+            .spawn(
+                async move {
+                    let parts = states
+                        .into_iter()
+                        .filter_map(|mut state| {
+                            state
+                                .as_any_mut()
+                                .downcast_mut::<TopNState>()
+                                .expect("State type mismatch")
+                                .top
+                                .take()
+                        })
+                        .collect();
+                    Ok(Some(Arc::new(params.top_n(parts)?)))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn name(&self) -> &'static str {
+        "TopN"
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let mut lines = vec![];
+        assert!(!self.params.sort_by.is_empty());
+        let pairs = self
+            .params
+            .sort_by
+            .iter()
+            .zip(self.params.descending.iter())
+            .zip(self.params.nulls_first.iter())
+            .map(|((sb, d), nf)| {
+                format!(
+                    "({}, {}, {})",
+                    sb,
+                    if *d { "descending" } else { "ascending" },
+                    if *nf { "nulls first" } else { "nulls last" }
+                )
+            })
+            .join(", ");
+        lines.push(format!("TopN: Sort by = {}", pairs));
+        lines.push(format!("Num Rows = {}", self.params.limit));
+        lines
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
+        Ok(Box::new(TopNState { top: None }))
+    }
+
+    fn max_concurrency(&self) -> usize {
+        // A single worker sees all of the input in order, which keeps earlier rows ahead of later ones with equal keys.
+        if self.params.stable {
+            1
+        } else {
+            get_compute_pool_num_threads()
+        }
+    }
+}
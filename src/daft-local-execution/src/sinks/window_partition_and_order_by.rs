@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use common_error::{DaftError, DaftResult};
 use daft_core::{array::ops::IntoGroups, datatypes::UInt64Array, prelude::*};
-use daft_dsl::{resolved_col, ExprRef, WindowExpr};
+use daft_dsl::{expr::window::WindowFrame, resolved_col, ExprRef, WindowExpr};
 use daft_micropartition::MicroPartition;
 use daft_recordbatch::RecordBatch;
 use itertools::Itertools;
@@ -76,6 +76,8 @@ struct WindowPartitionAndOrderByParams {
     partition_by: Vec<ExprRef>,
     order_by: Vec<ExprRef>,
     descending: Vec<bool>,
+    frame: Option<WindowFrame>,
+    min_periods: i64,
     original_schema: SchemaRef,
 }
 
@@ -84,12 +86,15 @@ pub struct WindowPartitionAndOrderBySink {
 }
 
 impl WindowPartitionAndOrderBySink {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         window_exprs: &[WindowExpr],
         aliases: &[String],
         partition_by: &[ExprRef],
         order_by: &[ExprRef],
         descending: &[bool],
+        frame: Option<&WindowFrame>,
+        min_periods: i64,
         schema: &SchemaRef,
     ) -> DaftResult<Self> {
         Ok(Self {
@@ -99,6 +104,8 @@ impl WindowPartitionAndOrderBySink {
                 partition_by: partition_by.to_vec(),
                 order_by: order_by.to_vec(),
                 descending: descending.to_vec(),
+                frame: frame.cloned(),
+                min_periods,
                 original_schema: schema.clone(),
             }),
         })
@@ -210,9 +217,30 @@ impl BlockingSink for WindowPartitionAndOrderBySink {
                                         WindowExpr::RowNumber => {
                                             *partition = partition.window_row_number(name.clone())?;
                                         }
-                                        WindowExpr::Agg(agg_expr) => {
-                                            *partition = partition.window_agg(agg_expr, name.clone())?;
+                                        WindowExpr::Rank => {
+                                            *partition = partition.window_rank(name.clone(), &params.order_by, false)?;
                                         }
+                                        WindowExpr::DenseRank => {
+                                            *partition = partition.window_rank(name.clone(), &params.order_by, true)?;
+                                        }
+                                        WindowExpr::Offset { input, offset, default } => {
+                                            *partition = partition.window_offset(name.clone(), input, *offset, default.as_ref())?;
+                                        }
+                                        WindowExpr::Agg(agg_expr) => match &params.frame {
+                                            Some(frame) => {
+                                                *partition = partition.window_agg_with_frame(
+                                                    agg_expr,
+                                                    name.clone(),
+                                                    frame,
+                                                    params.min_periods,
+                                                    &params.order_by,
+                                                    &params.descending,
+                                                )?;
+                                            }
+                                            None => {
+                                                *partition = partition.window_agg(agg_expr, name.clone())?;
+                                            }
+                                        },
                                     }
                                 }
                             }
@@ -283,6 +311,12 @@ impl BlockingSink for WindowPartitionAndOrderBySink {
                 .map(|e| e.to_string())
                 .join(", ")
         ));
+        if let Some(frame) = &self.window_partition_and_order_by_params.frame {
+            display.push(format!(
+                "Frame: {:?} between {:?} and {:?}",
+                frame.frame_type, frame.start, frame.end
+            ));
+        }
         display
     }
 
@@ -16,7 +16,9 @@ use super::blocking_sink::{
     BlockingSinkStatus,
 };
 use crate::{
-    dispatcher::{DispatchSpawner, PartitionedDispatcher, UnorderedDispatcher},
+    dispatcher::{
+        DispatchSpawner, PartitionedDispatcher, RoundRobinDispatcher, UnorderedDispatcher,
+    },
     ExecutionRuntimeContext, ExecutionTaskSpawner,
 };
 
@@ -211,10 +213,16 @@ impl BlockingSink for WriteSink {
 
     fn dispatch_spawner(
         &self,
-        _runtime_handle: &ExecutionRuntimeContext,
+        runtime_handle: &ExecutionRuntimeContext,
     ) -> Arc<dyn DispatchSpawner> {
         if let Some(partition_by) = &self.partition_by {
             Arc::new(PartitionedDispatcher::new(partition_by.clone()))
+        } else if runtime_handle.deterministic() {
+            // Morsels of a fixed size are handed to the writers in turn, so that each file gets the same rows on every
+            // run.
+            Arc::new(RoundRobinDispatcher::new(Some(
+                runtime_handle.default_morsel_size(),
+            )))
         } else {
             // Unnecessary to buffer by morsel size because we are writing.
             // Writers also have their own internal buffering.
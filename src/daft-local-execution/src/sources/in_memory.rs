@@ -14,6 +14,7 @@ pub struct InMemorySource {
     data: Option<PartitionSetRef<MicroPartitionRef>>,
     size_bytes: usize,
     schema: SchemaRef,
+    persisted: bool,
 }
 
 impl InMemorySource {
@@ -21,11 +22,13 @@ impl InMemorySource {
         data: Option<PartitionSetRef<MicroPartitionRef>>,
         schema: SchemaRef,
         size_bytes: usize,
+        persisted: bool,
     ) -> Self {
         Self {
             data,
             size_bytes,
             schema,
+            persisted,
         }
     }
     pub fn arced(self) -> Arc<dyn Source> {
@@ -57,6 +60,9 @@ impl Source for InMemorySource {
         res.push("InMemorySource:".to_string());
         res.push(format!("Schema = {}", self.schema.short_string()));
         res.push(format!("Size bytes = {}", self.size_bytes));
+        if self.persisted {
+            res.push("Persisted = cache hit".to_string());
+        }
         res
     }
 
@@ -10,24 +10,33 @@ use common_error::DaftResult;
 use common_file_formats::{FileFormatConfig, ParquetSourceConfig};
 use common_runtime::{get_compute_pool_num_threads, get_io_runtime};
 use common_scan_info::{Pushdowns, ScanTaskLike};
-use daft_core::prelude::{AsArrow, Int64Array, SchemaRef, Utf8Array};
+use daft_core::prelude::{AsArrow, Int64Array, SchemaRef, Series, Utf8Array};
 use daft_csv::{CsvConvertOptions, CsvParseOptions, CsvReadOptions};
 use daft_io::IOStatsRef;
 use daft_json::{JsonConvertOptions, JsonParseOptions, JsonReadOptions};
-use daft_micropartition::MicroPartition;
+use daft_micropartition::{read_deletion_vector, EqualityDeletes, MicroPartition};
 use daft_parquet::read::{read_parquet_bulk_async, ParquetSchemaInferenceOptions};
-use daft_scan::{ChunkSpec, ScanTask};
+use daft_scan::{scan_task_iters::apply_limit_row_budgets, ChunkSpec, ScanTask};
 use daft_warc::WarcConvertOptions;
 use futures::{Stream, StreamExt, TryStreamExt};
 use snafu::ResultExt;
 use tracing::instrument;
 
-use crate::sources::source::{Source, SourceStream};
+use crate::{
+    sources::source::{Source, SourceStream},
+    state_bridge::BroadcastStateBridgeRef,
+};
+
+/// Bridge used to send the join keys of a hash join's build side to a scan on its probe side for dynamic
+/// partition pruning. The keys are `None` if the build side is too large to prune with.
+pub(crate) type PartitionPruningBridgeRef = BroadcastStateBridgeRef<Option<Series>>;
 
 pub struct ScanTaskSource {
     scan_tasks: Vec<Arc<ScanTask>>,
     num_parallel_tasks: usize,
+    limit: Option<usize>,
     schema: SchemaRef,
+    partition_pruning: Option<(String, PartitionPruningBridgeRef)>,
 }
 
 impl ScanTaskSource {
@@ -39,6 +48,8 @@ impl ScanTaskSource {
         schema: SchemaRef,
         cfg: &DaftExecutionConfig,
     ) -> Self {
+        // Only read as many scan tasks, and as many rows from each of them, as needed to satisfy the row limit
+        let scan_tasks = apply_limit_row_budgets(scan_tasks, &pushdowns);
         // Determine the number of parallel tasks to run based on available CPU cores and row limits
         let mut num_parallel_tasks = match pushdowns.limit {
             // If we have a row limit, we need to calculate how many parallel tasks we can run
@@ -74,8 +85,38 @@ impl ScanTaskSource {
         Self {
             scan_tasks,
             num_parallel_tasks,
+            limit: pushdowns.limit,
             schema,
+            partition_pruning: None,
+        }
+    }
+
+    /// Waits for the join keys sent through `bridge` before scanning, and skips scan tasks whose value for the
+    /// partition column `column` is not one of those keys.
+    pub(crate) fn with_partition_pruning(
+        mut self,
+        column: String,
+        bridge: PartitionPruningBridgeRef,
+    ) -> Self {
+        self.partition_pruning = Some((column, bridge));
+        self
+    }
+
+    async fn get_pruned_scan_tasks(&self) -> DaftResult<Vec<Arc<ScanTask>>> {
+        let Some((column, bridge)) = &self.partition_pruning else {
+            return Ok(self.scan_tasks.clone());
+        };
+        let keys = bridge.get_state().await;
+        let Some(keys) = keys.as_ref() else {
+            return Ok(self.scan_tasks.clone());
+        };
+        let mut scan_tasks = Vec::with_capacity(self.scan_tasks.len());
+        for scan_task in &self.scan_tasks {
+            if scan_task.may_match_partition_values(column, keys)? {
+                scan_tasks.push(scan_task.clone());
+            }
         }
+        Ok(scan_tasks)
     }
 
     pub fn arced(self) -> Arc<dyn Source> {
@@ -92,9 +133,10 @@ impl Source for ScanTaskSource {
         io_stats: IOStatsRef,
     ) -> DaftResult<SourceStream<'static>> {
         let io_runtime = get_io_runtime(true);
-        let delete_map = get_delete_map(&self.scan_tasks).await?.map(Arc::new);
+        let scan_tasks = self.get_pruned_scan_tasks().await?;
+        let delete_map = get_delete_map(&scan_tasks).await?.map(Arc::new);
         let stream_of_streams =
-            futures::stream::iter(self.scan_tasks.clone().into_iter().map(move |scan_task| {
+            futures::stream::iter(scan_tasks.into_iter().map(move |scan_task| {
                 let io_stats = io_stats.clone();
                 let delete_map = delete_map.clone();
                 io_runtime.spawn(stream_scan_task(
@@ -105,20 +147,39 @@ impl Source for ScanTaskSource {
                 ))
             }));
 
-        match maintain_order {
+        let stream: SourceStream<'static> = match maintain_order {
             true => {
                 let buffered_and_flattened = stream_of_streams
                     .buffered(self.num_parallel_tasks)
                     .map(|r| r?)
                     .try_flatten();
-                Ok(Box::pin(buffered_and_flattened))
+                Box::pin(buffered_and_flattened)
             }
             false => {
                 let buffered_and_flattened = stream_of_streams
                     .then(|r| async { r.await? })
                     .try_flatten_unordered(self.num_parallel_tasks);
-                Ok(Box::pin(buffered_and_flattened))
+                Box::pin(buffered_and_flattened)
             }
+        };
+        match self.limit {
+            // Stop reading scan tasks as soon as the row limit has been reached across all of them.
+            Some(limit) => Ok(Box::pin(stream.scan(limit, |remaining_rows, result| {
+                if *remaining_rows == 0 {
+                    return futures::future::ready(None);
+                }
+                let result = result.and_then(|mp| {
+                    let mp = if mp.len() > *remaining_rows {
+                        Arc::new(mp.head(*remaining_rows)?)
+                    } else {
+                        mp
+                    };
+                    *remaining_rows -= mp.len();
+                    Ok(mp)
+                });
+                futures::future::ready(Some(result))
+            }))),
+            None => Ok(stream),
         }
     }
 
@@ -156,6 +217,9 @@ Num Scan Tasks = {num_scan_tasks}
 Estimated Scan Bytes = {total_bytes}
 "
             );
+            if let Some((column, _)) = &scan.partition_pruning {
+                writeln!(s, "Dynamic Partition Pruning On = {column}").unwrap();
+            }
             #[cfg(feature = "python")]
             if let FileFormatConfig::Database(config) =
                 scan.scan_tasks[0].file_format_config().as_ref()
@@ -343,6 +407,37 @@ async fn stream_scan_task(
             .unwrap_or_default(),
     );
     let io_client = daft_io::get_io_client(scan_task.storage_config.multithreaded_io, io_config)?;
+    let equality_deletes = match (
+        scan_task.file_format_config.as_ref(),
+        source.get_iceberg_equality_delete_files(),
+    ) {
+        (
+            FileFormatConfig::Parquet(ParquetSourceConfig {
+                field_id_mapping, ..
+            }),
+            Some(delete_files),
+        ) if !delete_files.is_empty() => Some(
+            EqualityDeletes::read(
+                delete_files,
+                io_client.clone(),
+                Some(io_stats.clone()),
+                get_compute_pool_num_threads(),
+                field_id_mapping.clone(),
+            )
+            .await?,
+        ),
+        _ => None,
+    };
+    // The columns that equality deletes are matched on are read as well, and dropped once they're applied.
+    let file_column_names = file_column_names.map(|mut columns| {
+        for column in equality_deletes.iter().flat_map(EqualityDeletes::columns) {
+            if !columns.contains(&column) {
+                columns.push(column);
+            }
+        }
+        columns
+    });
+    let mut row_sample = scan_task.sample_parquet_row_groups();
     let table_stream = match scan_task.file_format_config.as_ref() {
         FileFormatConfig::Parquet(ParquetSourceConfig {
             coerce_int96_timestamp_unit,
@@ -353,8 +448,19 @@ async fn stream_scan_task(
             let inference_options =
                 ParquetSchemaInferenceOptions::new(Some(*coerce_int96_timestamp_unit));
 
-            let delete_rows = delete_map.as_ref().and_then(|m| m.get(url).cloned());
-            let row_groups = if let Some(ChunkSpec::Parquet(row_groups)) = source.get_chunk_spec() {
+            let mut delete_rows = delete_map.as_ref().and_then(|m| m.get(url).cloned());
+            if let Some(deletion_vector) = source.get_delta_deletion_vector() {
+                let positions = read_deletion_vector(
+                    deletion_vector,
+                    io_client.clone(),
+                    Some(io_stats.clone()),
+                )
+                .await?;
+                delete_rows.get_or_insert_default().extend(positions);
+            }
+            let row_groups = if let Some(row_sample) = &row_sample {
+                row_sample.row_groups.first().cloned()
+            } else if let Some(ChunkSpec::Parquet(row_groups)) = source.get_chunk_spec() {
                 Some(row_groups.clone())
             } else {
                 None
@@ -366,7 +472,12 @@ async fn stream_scan_task(
             daft_parquet::read::stream_parquet(
                 url,
                 file_column_names.as_deref(),
-                scan_task.pushdowns.limit,
+                // Rows deleted by equality deletes are only dropped once read, so the limit is applied afterwards.
+                if equality_deletes.is_some() {
+                    None
+                } else {
+                    scan_task.pushdowns.limit
+                },
                 row_groups,
                 scan_task.pushdowns.filters.clone(),
                 io_client,
@@ -374,7 +485,8 @@ async fn stream_scan_task(
                 &inference_options,
                 field_id_mapping.clone(),
                 metadata,
-                maintain_order,
+                // The rows picked for a sample are taken by their position in the read order.
+                maintain_order || row_sample.is_some(),
                 delete_rows,
                 *chunk_size,
             )
@@ -492,7 +604,18 @@ async fn stream_scan_task(
     };
 
     Ok(table_stream.map(move |table| {
-        let table = table?;
+        let mut table = table?;
+        if let Some(equality_deletes) = &equality_deletes {
+            table = equality_deletes.apply(&table)?;
+        }
+        // Take the sample pushed into the scan, from the rows picked ahead of the read if possible
+        let table = if let Some(row_sample) = row_sample.as_mut() {
+            row_sample.take(&table)?
+        } else if let Some(sample) = &scan_task.pushdowns.sample {
+            table.sample_by_fraction(sample.fraction.0, false, sample.seed)?
+        } else {
+            table
+        };
         let casted_table = table.cast_to_schema_with_fill(
             scan_task.materialized_schema().as_ref(),
             scan_task
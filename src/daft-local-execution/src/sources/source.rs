@@ -13,7 +13,8 @@ use crate::{
     channel::{create_channel, Receiver},
     pipeline::PipelineNode,
     progress_bar::ProgressBarColor,
-    runtime_stats::{CountingSender, RuntimeStatsContext},
+    query_cancelled,
+    runtime_stats::{morsel_size_bytes, CountingSender, RuntimeStatsContext},
     ExecutionRuntimeContext,
 };
 
@@ -76,11 +77,13 @@ impl TreeDisplay for SourceNode {
                     let rt_result = self.runtime_stats.result();
 
                     writeln!(display).unwrap();
-                    rt_result.display(&mut display, false, true, false).unwrap();
+                    rt_result
+                        .display(&mut display, false, true, false, false)
+                        .unwrap();
                     let bytes_read = self.io_stats.load_bytes_read();
                     writeln!(
                         display,
-                        "Bytes read = {}",
+                        "Bytes scanned = {}",
                         bytes_to_human_readable(bytes_read)
                     )
                     .unwrap();
@@ -109,6 +112,9 @@ impl PipelineNode for SourceNode {
         maintain_order: bool,
         runtime_handle: &mut ExecutionRuntimeContext,
     ) -> crate::Result<Receiver<Arc<MicroPartition>>> {
+        self.runtime_stats
+            .attach_memory_reservation(runtime_handle.memory_pool().register(self.name(), false));
+        runtime_handle.io_stats().register(self.io_stats.clone());
         let progress_bar = runtime_handle.make_progress_bar(
             self.name(),
             ProgressBarColor::Blue,
@@ -118,15 +124,33 @@ impl PipelineNode for SourceNode {
         let source = self.source.clone();
         let io_stats = self.io_stats.clone();
         let (destination_sender, destination_receiver) = create_channel(0);
+        let runtime_stats = self.runtime_stats.clone();
         let counting_sender =
             CountingSender::new(destination_sender, self.runtime_stats.clone(), progress_bar);
+        let cancel = runtime_handle.cancellation_token();
         runtime_handle.spawn(
             async move {
                 let mut has_data = false;
                 let mut source_stream = source.get_data(maintain_order, io_stats).await?;
-                while let Some(part) = source_stream.next().await {
+                loop {
+                    // Stop scanning with an error rather than by ending the stream, so that downstream operators
+                    // don't mistake the data scanned so far for all of it, e.g. writes don't commit it
+                    let part = tokio::select! {
+                        biased;
+                        () = cancel.cancelled() => return Err(query_cancelled()),
+                        part = source_stream.next() => part,
+                    };
+                    let Some(part) = part else {
+                        break;
+                    };
                     has_data = true;
-                    if counting_sender.send(part?).await.is_err() {
+                    // Scanned morsels are held until the downstream operator takes them.
+                    let part = part?;
+                    let part_bytes = morsel_size_bytes(&part);
+                    runtime_stats.mark_bytes_held(part_bytes);
+                    let sent = counting_sender.send(part).await;
+                    runtime_stats.mark_bytes_released(part_bytes);
+                    if sent.is_err() {
                         return Ok(());
                     }
                 }
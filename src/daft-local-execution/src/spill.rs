@@ -0,0 +1,136 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Write},
+    sync::Arc,
+};
+
+use arrow2::io::ipc::{
+    read::{read_stream_metadata, StreamReader, StreamState},
+    write::{Compression, StreamWriter, WriteOptions},
+};
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::{Schema, SchemaRef};
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+use tempfile::NamedTempFile;
+
+/// Whether record batches with the given schema can be spilled to disk, which needs all of their columns to be
+/// convertible to Arrow.
+pub(crate) fn can_spill(schema: &Schema) -> bool {
+    !schema.is_empty() && schema.fields().iter().all(|field| field.dtype.is_arrow())
+}
+
+/// Record batches spilled to a temporary file on local disk, in the Arrow IPC stream format.
+///
+/// Batches are appended with [`Self::write`] until the file is finished, after which they can be read back any number
+/// of times with [`Self::read`]. The file is deleted when this is dropped.
+pub(crate) struct SpillFile {
+    schema: SchemaRef,
+    file: NamedTempFile,
+    writer: Option<StreamWriter<BufWriter<File>>>,
+}
+
+impl SpillFile {
+    pub fn new(schema: SchemaRef) -> DaftResult<Self> {
+        let file = tempfile::Builder::new().prefix("daft-spill-").tempfile()?;
+        let options = WriteOptions {
+            compression: Some(Compression::LZ4),
+        };
+        let mut writer = StreamWriter::new(BufWriter::new(file.reopen()?), options);
+        writer.start(&schema.to_arrow()?, None)?;
+        Ok(Self {
+            schema,
+            file,
+            writer: Some(writer),
+        })
+    }
+
+    /// Appends the given record batches to the file.
+    pub fn write(&mut self, tables: &[RecordBatch]) -> DaftResult<()> {
+        let writer = self
+            .writer
+            .as_mut()
+            .expect("SpillFile should not be written to after it is finished");
+        for table in tables {
+            writer.write(&table.to_chunk(), None)?;
+        }
+        Ok(())
+    }
+
+    /// Marks the end of the file, so that it can be read.
+    pub fn finish(&mut self) -> DaftResult<()> {
+        if let Some(mut writer) = self.writer.take() {
+            writer.finish()?;
+            writer.into_inner().flush()?;
+        }
+        Ok(())
+    }
+
+    /// Reads back all record batches written to the file.
+    pub fn read(&self) -> DaftResult<MicroPartition> {
+        if self.writer.is_some() {
+            return Err(DaftError::InternalError(
+                "SpillFile should be finished before it is read".to_string(),
+            ));
+        }
+        let mut reader = BufReader::new(self.file.reopen()?);
+        let metadata = read_stream_metadata(&mut reader)?;
+        let tables = StreamReader::new(reader, metadata, None)
+            .map(|state| match state? {
+                StreamState::Some(chunk) => {
+                    RecordBatch::from_arrow(self.schema.clone(), chunk.into_arrays())
+                }
+                StreamState::Waiting => Err(DaftError::InternalError(
+                    "SpillFile should not be read while it is being written".to_string(),
+                )),
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(MicroPartition::new_loaded(
+            self.schema.clone(),
+            Arc::new(tables),
+            None,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_recordbatch::RecordBatch;
+
+    use super::SpillFile;
+
+    #[test]
+    fn spill_file_round_trips_record_batches() -> DaftResult<()> {
+        let a = Int64Array::from(("a", vec![1, 2, 3])).into_series();
+        let b = Utf8Array::from(("b", ["x", "y", "z"].as_slice())).into_series();
+        let table = RecordBatch::from_nonempty_columns(vec![a, b])?;
+
+        let mut file = SpillFile::new(table.schema.clone())?;
+        file.write(&[table.clone(), table.slice(1, 3)?])?;
+        assert!(file.read().is_err());
+        file.finish()?;
+
+        let read = file.read()?;
+        assert_eq!(read.schema(), table.schema);
+        let tables = read.get_tables()?;
+        assert_eq!(tables.len(), 2);
+        assert_eq!(tables[0], table);
+        assert_eq!(tables[1], table.slice(1, 3)?);
+        // Finished files can be read again.
+        assert_eq!(file.read()?.len(), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn spill_file_without_batches_reads_empty() -> DaftResult<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64)]));
+        let mut file = SpillFile::new(schema)?;
+        file.finish()?;
+        assert!(file.read()?.is_empty());
+        Ok(())
+    }
+}
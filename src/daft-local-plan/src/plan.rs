@@ -3,7 +3,7 @@ use std::sync::Arc;
 use common_resource_request::ResourceRequest;
 use common_scan_info::{Pushdowns, ScanTaskLikeRef};
 use daft_core::prelude::*;
-use daft_dsl::{AggExpr, ExprRef, WindowExpr};
+use daft_dsl::{expr::window::WindowFrame, join::RangeCondition, AggExpr, ExprRef, WindowExpr};
 use daft_logical_plan::{
     stats::{PlanStats, StatsState},
     InMemoryInfo, OutputFileInfo,
@@ -22,6 +22,7 @@ pub enum LocalPhysicalPlan {
     Explode(Explode),
     Unpivot(Unpivot),
     Sort(Sort),
+    TopN(TopN),
     // Split(Split),
     Sample(Sample),
     MonotonicallyIncreasingId(MonotonicallyIncreasingId),
@@ -37,6 +38,8 @@ pub enum LocalPhysicalPlan {
     Concat(Concat),
     HashJoin(HashJoin),
     CrossJoin(CrossJoin),
+    RangeJoin(RangeJoin),
+    AsofJoin(AsofJoin),
     // SortMergeJoin(SortMergeJoin),
     // BroadcastJoin(BroadcastJoin),
     PhysicalWrite(PhysicalWrite),
@@ -74,6 +77,7 @@ impl LocalPhysicalPlan {
             | Self::Explode(Explode { stats_state, .. })
             | Self::Unpivot(Unpivot { stats_state, .. })
             | Self::Sort(Sort { stats_state, .. })
+            | Self::TopN(TopN { stats_state, .. })
             | Self::Sample(Sample { stats_state, .. })
             | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { stats_state, .. })
             | Self::UnGroupedAggregate(UnGroupedAggregate { stats_state, .. })
@@ -82,6 +86,8 @@ impl LocalPhysicalPlan {
             | Self::Concat(Concat { stats_state, .. })
             | Self::HashJoin(HashJoin { stats_state, .. })
             | Self::CrossJoin(CrossJoin { stats_state, .. })
+            | Self::RangeJoin(RangeJoin { stats_state, .. })
+            | Self::AsofJoin(AsofJoin { stats_state, .. })
             | Self::PhysicalWrite(PhysicalWrite { stats_state, .. })
             | Self::WindowPartitionOnly(WindowPartitionOnly { stats_state, .. })
             | Self::WindowPartitionAndOrderBy(WindowPartitionAndOrderBy { stats_state, .. }) => {
@@ -160,12 +166,14 @@ impl LocalPhysicalPlan {
     pub(crate) fn explode(
         input: LocalPhysicalPlanRef,
         to_explode: Vec<ExprRef>,
+        unnest_structs: bool,
         schema: SchemaRef,
         stats_state: StatsState,
     ) -> LocalPhysicalPlanRef {
         Self::Explode(Explode {
             input,
             to_explode,
+            unnest_structs,
             schema,
             stats_state,
         })
@@ -259,6 +267,8 @@ impl LocalPhysicalPlan {
         partition_by: Vec<ExprRef>,
         order_by: Vec<ExprRef>,
         descending: Vec<bool>,
+        frame: Option<WindowFrame>,
+        min_periods: i64,
         schema: SchemaRef,
         stats_state: StatsState,
         functions: Vec<WindowExpr>,
@@ -269,6 +279,8 @@ impl LocalPhysicalPlan {
             partition_by,
             order_by,
             descending,
+            frame,
+            min_periods,
             schema,
             stats_state,
             functions,
@@ -341,6 +353,27 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    pub(crate) fn top_n(
+        input: LocalPhysicalPlanRef,
+        sort_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+        limit: i64,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        let schema = input.schema().clone();
+        Self::TopN(TopN {
+            input,
+            sort_by,
+            descending,
+            nulls_first,
+            limit,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
     pub(crate) fn sample(
         input: LocalPhysicalPlanRef,
         fraction: f64,
@@ -414,6 +447,60 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn range_join(
+        left: LocalPhysicalPlanRef,
+        right: LocalPhysicalPlanRef,
+        left_on: Vec<ExprRef>,
+        right_on: Vec<ExprRef>,
+        conditions: Vec<RangeCondition>,
+        residual: Option<ExprRef>,
+        join_type: JoinType,
+        schema: SchemaRef,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        Self::RangeJoin(RangeJoin {
+            left,
+            right,
+            left_on,
+            right_on,
+            conditions,
+            residual,
+            join_type,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn asof_join(
+        left: LocalPhysicalPlanRef,
+        right: LocalPhysicalPlanRef,
+        left_on: ExprRef,
+        right_on: ExprRef,
+        left_by: Vec<ExprRef>,
+        right_by: Vec<ExprRef>,
+        strategy: AsofStrategy,
+        tolerance: Option<ExprRef>,
+        schema: SchemaRef,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        Self::AsofJoin(AsofJoin {
+            left,
+            right,
+            left_on,
+            right_on,
+            left_by,
+            right_by,
+            strategy,
+            tolerance,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
     pub(crate) fn concat(
         input: LocalPhysicalPlanRef,
         other: LocalPhysicalPlanRef,
@@ -494,9 +581,12 @@ impl LocalPhysicalPlan {
             | Self::HashAggregate(HashAggregate { schema, .. })
             | Self::Pivot(Pivot { schema, .. })
             | Self::Sort(Sort { schema, .. })
+            | Self::TopN(TopN { schema, .. })
             | Self::Sample(Sample { schema, .. })
             | Self::HashJoin(HashJoin { schema, .. })
             | Self::CrossJoin(CrossJoin { schema, .. })
+            | Self::RangeJoin(RangeJoin { schema, .. })
+            | Self::AsofJoin(AsofJoin { schema, .. })
             | Self::Explode(Explode { schema, .. })
             | Self::Unpivot(Unpivot { schema, .. })
             | Self::Concat(Concat { schema, .. })
@@ -569,6 +659,7 @@ pub struct Limit {
 pub struct Explode {
     pub input: LocalPhysicalPlanRef,
     pub to_explode: Vec<ExprRef>,
+    pub unnest_structs: bool,
     pub schema: SchemaRef,
     pub stats_state: StatsState,
 }
@@ -583,6 +674,17 @@ pub struct Sort {
     pub stats_state: StatsState,
 }
 
+#[derive(Debug)]
+pub struct TopN {
+    pub input: LocalPhysicalPlanRef,
+    pub sort_by: Vec<ExprRef>,
+    pub descending: Vec<bool>,
+    pub nulls_first: Vec<bool>,
+    pub limit: i64,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
 #[derive(Debug)]
 pub struct Sample {
     pub input: LocalPhysicalPlanRef,
@@ -661,6 +763,39 @@ pub struct CrossJoin {
     pub stats_state: StatsState,
 }
 
+/// A join on inequalities between the two sides, optionally along with equality keys.
+///
+/// The right side is collected and sorted, and the left side is streamed through it, so only
+/// inner, left, semi and anti joins are supported.
+#[derive(Debug)]
+pub struct RangeJoin {
+    pub left: LocalPhysicalPlanRef,
+    pub right: LocalPhysicalPlanRef,
+    pub left_on: Vec<ExprRef>,
+    pub right_on: Vec<ExprRef>,
+    pub conditions: Vec<RangeCondition>,
+    pub residual: Option<ExprRef>,
+    pub join_type: JoinType,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
+/// Matches each left row with the right row in the same `by` group whose `on` key is nearest to
+/// it. The right side is collected and sorted, and the left side is streamed through it.
+#[derive(Debug)]
+pub struct AsofJoin {
+    pub left: LocalPhysicalPlanRef,
+    pub right: LocalPhysicalPlanRef,
+    pub left_on: ExprRef,
+    pub right_on: ExprRef,
+    pub left_by: Vec<ExprRef>,
+    pub right_by: Vec<ExprRef>,
+    pub strategy: AsofStrategy,
+    pub tolerance: Option<ExprRef>,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
 #[derive(Debug)]
 pub struct Concat {
     pub input: LocalPhysicalPlanRef,
@@ -714,6 +849,8 @@ pub struct WindowPartitionAndOrderBy {
     pub partition_by: Vec<ExprRef>,
     pub order_by: Vec<ExprRef>,
     pub descending: Vec<bool>,
+    pub frame: Option<WindowFrame>,
+    pub min_periods: i64,
     pub schema: SchemaRef,
     pub stats_state: StatsState,
     pub functions: Vec<WindowExpr>,
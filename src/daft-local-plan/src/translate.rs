@@ -3,8 +3,14 @@ use std::sync::Arc;
 use common_error::{DaftError, DaftResult};
 use common_scan_info::ScanState;
 use daft_core::join::JoinStrategy;
-use daft_dsl::{join::normalize_join_keys, AggExpr, ExprRef, WindowExpr};
-use daft_logical_plan::{JoinType, LogicalPlan, LogicalPlanRef, SourceInfo};
+use daft_dsl::{
+    join::{infer_join_schema, normalize_join_keys, normalize_range_conditions, RangeCondition},
+    resolved_col, AggExpr, ExprRef, WindowExpr,
+};
+use daft_logical_plan::{
+    ops::join::{Join, JoinPredicate},
+    JoinType, LogicalPlan, LogicalPlanRef, SourceInfo,
+};
 
 use super::plan::{LocalPhysicalPlan, LocalPhysicalPlanRef};
 
@@ -49,6 +55,19 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             ))
         }
         LogicalPlan::Limit(limit) => {
+            // A limit over a sort only needs the first `limit` rows of each input morsel, so fuse the two into a
+            // Top-N that never holds more than `limit` rows per worker.
+            if let LogicalPlan::Sort(sort) = limit.input.as_ref() {
+                let input = translate(&sort.input)?;
+                return Ok(LocalPhysicalPlan::top_n(
+                    input,
+                    sort.sort_by.clone(),
+                    sort.descending.clone(),
+                    sort.nulls_first.clone(),
+                    limit.limit,
+                    limit.stats_state.clone(),
+                ));
+            }
             let input = translate(&limit.input)?;
             Ok(LocalPhysicalPlan::limit(
                 input,
@@ -134,13 +153,14 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 ))
             } else if !window.window_spec.partition_by.is_empty()
                 && !window.window_spec.order_by.is_empty()
-                && window.window_spec.frame.is_none()
             {
                 Ok(LocalPhysicalPlan::window_partition_and_order_by(
                     input,
                     window.window_spec.partition_by.clone(),
                     window.window_spec.order_by.clone(),
                     window.window_spec.descending.clone(),
+                    window.window_spec.frame.clone(),
+                    window.window_spec.min_periods,
                     window.schema.clone(),
                     window.stats_state.clone(),
                     window.window_functions.clone(),
@@ -148,7 +168,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 ))
             } else {
                 Err(DaftError::not_implemented(
-                    "Window with order by or frame not yet implemented",
+                    "Window without partition by, or with a frame but no order by, not yet implemented",
                 ))
             }
         }
@@ -199,7 +219,15 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             let (remaining_on, left_on, right_on, null_equals_nulls) = join.on.split_eq_preds();
 
             if !remaining_on.is_empty() {
-                return Err(DaftError::not_implemented("Execution of non-equality join"));
+                return translate_range_join(
+                    join,
+                    left,
+                    right,
+                    remaining_on,
+                    left_on,
+                    right_on,
+                    &null_equals_nulls,
+                );
             }
 
             let (left_on, right_on) =
@@ -225,6 +253,38 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 ))
             }
         }
+        LogicalPlan::AsofJoin(join) => {
+            let left = translate(&join.left)?;
+            let right = translate(&join.right)?;
+            let (mut left_keys, mut right_keys) = normalize_join_keys(
+                join.left_by
+                    .iter()
+                    .chain([&join.left_on])
+                    .cloned()
+                    .collect(),
+                join.right_by
+                    .iter()
+                    .chain([&join.right_on])
+                    .cloned()
+                    .collect(),
+                join.left.schema(),
+                join.right.schema(),
+            )?;
+            let left_on = left_keys.pop().expect("as-of joins have an on key");
+            let right_on = right_keys.pop().expect("as-of joins have an on key");
+            Ok(LocalPhysicalPlan::asof_join(
+                left,
+                right,
+                left_on,
+                right_on,
+                left_keys,
+                right_keys,
+                join.strategy,
+                join.tolerance.clone(),
+                join.output_schema.clone(),
+                join.stats_state.clone(),
+            ))
+        }
         LogicalPlan::Distinct(distinct) => {
             let schema = distinct.input.schema();
             let input = translate(&distinct.input)?;
@@ -305,6 +365,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             Ok(LocalPhysicalPlan::explode(
                 input,
                 explode.to_explode.clone(),
+                explode.unnest_structs,
                 explode.exploded_schema.clone(),
                 explode.stats_state.clone(),
             ))
@@ -320,3 +381,71 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
         )),
     }
 }
+
+/// Translates a join with predicates other than equalities into a range join, which sorts one
+/// side on the inequalities between the two sides rather than comparing every pair of rows.
+fn translate_range_join(
+    join: &Join,
+    left: LocalPhysicalPlanRef,
+    right: LocalPhysicalPlanRef,
+    remaining_on: JoinPredicate,
+    left_on: Vec<ExprRef>,
+    right_on: Vec<ExprRef>,
+    null_equals_nulls: &[bool],
+) -> DaftResult<LocalPhysicalPlanRef> {
+    if null_equals_nulls.iter().any(|b| *b) {
+        return Err(DaftError::not_implemented(
+            "Null-safe equality in a join with non-equality predicates",
+        ));
+    }
+
+    let (residual, conditions) = remaining_on.split_range_preds();
+    let (left_on, right_on) =
+        normalize_join_keys(left_on, right_on, join.left.schema(), join.right.schema())?;
+    let conditions =
+        normalize_range_conditions(conditions, &join.left.schema(), &join.right.schema())?;
+    let residual = residual.to_joined_expr();
+
+    match join.join_type {
+        JoinType::Inner | JoinType::Left | JoinType::Semi | JoinType::Anti => {
+            Ok(LocalPhysicalPlan::range_join(
+                left,
+                right,
+                left_on,
+                right_on,
+                conditions,
+                residual,
+                join.join_type,
+                join.output_schema.clone(),
+                join.stats_state.clone(),
+            ))
+        }
+        // Stream the right side through the left side as a left join, then restore the column order.
+        JoinType::Right => {
+            let conditions = conditions.iter().map(RangeCondition::flipped).collect();
+            let schema =
+                infer_join_schema(&join.right.schema(), &join.left.schema(), JoinType::Left)?;
+            let joined = LocalPhysicalPlan::range_join(
+                right,
+                left,
+                right_on,
+                left_on,
+                conditions,
+                residual,
+                JoinType::Left,
+                schema,
+                join.stats_state.clone(),
+            );
+            let projection = join.output_schema.field_names().map(resolved_col).collect();
+            Ok(LocalPhysicalPlan::project(
+                joined,
+                projection,
+                join.output_schema.clone(),
+                join.stats_state.clone(),
+            ))
+        }
+        JoinType::Outer => Err(DaftError::not_implemented(
+            "Outer joins with non-equality predicates",
+        )),
+    }
+}
@@ -11,9 +11,10 @@ use common_display::mermaid::MermaidDisplayOptions;
 use common_error::{DaftError, DaftResult};
 use common_file_formats::{FileFormat, WriteMode};
 use common_io_config::IOConfig;
-use common_scan_info::{PhysicalScanInfo, Pushdowns, ScanOperatorRef};
+use common_scan_info::{PhysicalScanInfo, Pushdowns, ScanOperatorRef, SourceStatistics};
+use common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
 use daft_algebra::boolean::combine_conjunction;
-use daft_core::join::{JoinStrategy, JoinType};
+use daft_core::join::{AsofStrategy, JoinStrategy, JoinType};
 use daft_dsl::{left_col, resolved_col, right_col, Column, Expr, ExprRef, UnresolvedColumn};
 use daft_schema::schema::{Schema, SchemaRef};
 use indexmap::IndexSet;
@@ -22,7 +23,8 @@ use resolve_expr::ExprResolver;
 use {
     crate::sink_info::{CatalogInfo, IcebergCatalogInfo},
     common_daft_config::PyDaftPlanningConfig,
-    daft_dsl::python::PyExpr,
+    common_scan_info::ColumnStatistics,
+    daft_dsl::{python::PyExpr, LiteralValue, Subquery},
     // daft_scan::python::pylib::ScanOperatorHandle,
     daft_schema::python::schema::PySchema,
     pyo3::intern,
@@ -143,6 +145,7 @@ impl LogicalPlanBuilder {
         num_partitions: usize,
         size_bytes: usize,
         num_rows: usize,
+        persisted: bool,
     ) -> DaftResult<Self> {
         let source_info = SourceInfo::InMemory(InMemoryInfo::new(
             schema.clone(),
@@ -153,6 +156,7 @@ impl LogicalPlanBuilder {
             num_rows,
             None, // TODO(sammy) thread through clustering spec to Python
             None,
+            persisted,
         ));
         let logical_plan: LogicalPlan = ops::Source::new(schema, source_info.into()).into();
 
@@ -316,13 +320,13 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
-    pub fn explode(&self, to_explode: Vec<ExprRef>) -> DaftResult<Self> {
+    pub fn explode(&self, to_explode: Vec<ExprRef>, unnest_structs: bool) -> DaftResult<Self> {
         let expr_resolver = ExprResolver::default();
 
         let to_explode = expr_resolver.resolve(to_explode, self.plan.clone())?;
 
         let logical_plan: LogicalPlan =
-            ops::Explode::try_new(self.plan.clone(), to_explode)?.into();
+            ops::Explode::try_new(self.plan.clone(), to_explode, unnest_structs)?.into();
         Ok(self.with_new_plan(logical_plan))
     }
 
@@ -446,6 +450,39 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(ops::summarize(self)?))
     }
 
+    /// Creates a plan that computes table and column statistics for this plan's source, see [`ops::analyze`]. The plan
+    /// must be a scan of a table or of files, without any other operations applied to it.
+    pub fn analyze(&self) -> DaftResult<Self> {
+        self.physical_scan_source()?;
+        ops::analyze(self)
+    }
+
+    /// Attaches analyzed statistics to the source that this plan scans. The plan must be a scan of a table or of files,
+    /// without any other operations applied to it.
+    pub fn with_source_statistics(&self, statistics: SourceStatistics) -> DaftResult<Self> {
+        let (source, physical_scan_info) = self.physical_scan_source()?;
+        let source_info =
+            SourceInfo::Physical(physical_scan_info.with_statistics(Some(Arc::new(statistics))));
+        let logical_plan: LogicalPlan = ops::Source {
+            source_info: source_info.into(),
+            ..source.clone()
+        }
+        .into();
+        Ok(self.with_new_plan(logical_plan))
+    }
+
+    fn physical_scan_source(&self) -> DaftResult<(&ops::Source, &PhysicalScanInfo)> {
+        if let LogicalPlan::Source(source) = self.plan.as_ref()
+            && let SourceInfo::Physical(physical_scan_info) = source.source_info.as_ref()
+        {
+            Ok((source, physical_scan_info))
+        } else {
+            Err(DaftError::ValueError(
+                "Statistics can only be collected for DataFrames that directly read from a table or files, before any other operations are applied".to_string(),
+            ))
+        }
+    }
+
     pub fn distinct(&self) -> DaftResult<Self> {
         let logical_plan: LogicalPlan = ops::Distinct::new(self.plan.clone()).into();
         Ok(self.with_new_plan(logical_plan))
@@ -478,6 +515,17 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
+    /// Aggregates once for each grouping set, given as indices into `groupby_exprs`, with a
+    /// `grouping_id` column identifying the set of each row.
+    pub fn aggregate_grouping_sets(
+        &self,
+        agg_exprs: Vec<ExprRef>,
+        groupby_exprs: Vec<ExprRef>,
+        grouping_sets: Vec<Vec<usize>>,
+    ) -> DaftResult<Self> {
+        ops::grouping_sets(self, agg_exprs, groupby_exprs, &grouping_sets)
+    }
+
     pub fn pivot(
         &self,
         group_by: Vec<ExprRef>,
@@ -581,6 +629,82 @@ impl LogicalPlanBuilder {
         self.join(right, None, vec![], JoinType::Inner, None, options)
     }
 
+    /// Matches each row with the row of `right` in the same `by` group whose `on` key is nearest
+    /// to it in the direction given by `strategy`, keeping every row of this plan.
+    ///
+    /// Keys that are columns with the same name on both sides are merged.
+    #[allow(clippy::too_many_arguments)]
+    pub fn asof_join<Right: Into<LogicalPlanRef>>(
+        &self,
+        right: Right,
+        left_on: ExprRef,
+        right_on: ExprRef,
+        left_by: Vec<ExprRef>,
+        right_by: Vec<ExprRef>,
+        strategy: AsofStrategy,
+        tolerance: Option<ExprRef>,
+        options: JoinOptions,
+    ) -> DaftResult<Self> {
+        if left_by.len() != right_by.len() {
+            return Err(DaftError::ValueError(format!(
+                "Expected the same number of left and right by keys in as-of join, got {} and {}",
+                left_by.len(),
+                right_by.len()
+            )));
+        }
+        let left_plan = self.plan.clone();
+        let right_plan: LogicalPlanRef = right.into();
+
+        let mut using = Vec::new();
+        let mut key_pairs = Vec::new();
+        for (l, r) in left_by
+            .into_iter()
+            .zip(right_by)
+            .chain([(left_on, right_on)])
+        {
+            if let (
+                Expr::Column(Column::Unresolved(UnresolvedColumn { name: l_name, .. })),
+                Expr::Column(Column::Unresolved(UnresolvedColumn { name: r_name, .. })),
+            ) = (l.as_ref(), r.as_ref())
+                && l_name == r_name
+            {
+                using.push(l_name.to_string());
+            }
+            let l = l.to_left_cols(left_plan.schema())?;
+            let r = r.to_right_cols(right_plan.schema())?;
+            key_pairs.push(l.eq(r));
+        }
+        let num_keys = key_pairs.len();
+
+        let expr_resolver = ExprResolver::default();
+        let on = combine_conjunction(key_pairs)
+            .map(|expr| expr_resolver.resolve_join_on(expr, left_plan.clone(), right_plan.clone()))
+            .transpose()?;
+        let (left_plan, right_plan, on) = ops::join::Join::deduplicate_join_columns(
+            left_plan,
+            right_plan,
+            on,
+            &using,
+            JoinType::Left,
+            options,
+        )?;
+
+        let (_, mut left_keys, mut right_keys, _) = JoinPredicate::try_new(on)?.split_eq_preds();
+        if left_keys.len() != num_keys {
+            return Err(DaftError::ValueError(
+                "Expected each as-of join key to use columns of only one side of the join"
+                    .to_string(),
+            ));
+        }
+        let (left_on, right_on) = (left_keys.pop().unwrap(), right_keys.pop().unwrap());
+
+        let logical_plan: LogicalPlan = ops::AsofJoin::try_new(
+            left_plan, right_plan, left_on, right_on, left_keys, right_keys, strategy, tolerance,
+        )?
+        .into();
+        Ok(self.with_new_plan(logical_plan))
+    }
+
     pub fn concat(&self, other: &Self) -> DaftResult<Self> {
         let logical_plan: LogicalPlan =
             ops::Concat::try_new(self.plan.clone(), other.plan.clone())?.into();
@@ -623,6 +747,7 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn table_write(
         &self,
         root_dir: &str,
@@ -631,6 +756,7 @@ impl LogicalPlanBuilder {
         partition_cols: Option<Vec<ExprRef>>,
         compression: Option<String>,
         io_config: Option<IOConfig>,
+        max_open_writers: Option<usize>,
     ) -> DaftResult<Self> {
         let expr_resolver = ExprResolver::default();
 
@@ -645,6 +771,7 @@ impl LogicalPlanBuilder {
             partition_cols,
             compression,
             io_config,
+            max_open_writers,
         ));
 
         let logical_plan: LogicalPlan =
@@ -664,6 +791,7 @@ impl LogicalPlanBuilder {
         iceberg_properties: Arc<PyObject>,
         io_config: Option<IOConfig>,
         catalog_columns: Vec<String>,
+        native_write: Option<Arc<PyObject>>,
     ) -> DaftResult<Self> {
         let sink_info = SinkInfo::CatalogInfo(CatalogInfo {
             catalog: crate::sink_info::CatalogType::Iceberg(IcebergCatalogInfo {
@@ -674,6 +802,7 @@ impl LogicalPlanBuilder {
                 iceberg_schema,
                 iceberg_properties,
                 io_config,
+                native_write,
             }),
             catalog_columns,
         });
@@ -694,6 +823,7 @@ impl LogicalPlanBuilder {
         large_dtypes: bool,
         partition_cols: Option<Vec<String>>,
         io_config: Option<IOConfig>,
+        native_write: Option<Arc<PyObject>>,
     ) -> DaftResult<Self> {
         use crate::sink_info::DeltaLakeCatalogInfo;
         let sink_info = SinkInfo::CatalogInfo(CatalogInfo {
@@ -704,6 +834,7 @@ impl LogicalPlanBuilder {
                 large_dtypes,
                 partition_cols,
                 io_config,
+                native_write,
             }),
             catalog_columns: columns_name,
         });
@@ -746,7 +877,7 @@ impl LogicalPlanBuilder {
         let cfg = self.config.clone();
 
         // Run LogicalPlan optimizations
-        let unoptimized_plan = self.build();
+        let unoptimized_plan = self.build_for_optimization();
         let (tx, rx) = tokio::sync::oneshot::channel();
 
         std::thread::spawn(move || {
@@ -798,13 +929,25 @@ impl LogicalPlanBuilder {
     /// **Important**: Do not call this method from the main thread as there is a `block_on` call deep within this method
     /// Calling will result in a runtime panic
     pub fn optimize(&self) -> DaftResult<Self> {
+        self.optimize_with(OptimizerBuilder::default())
+    }
+
+    /// Optimize the logical plan without materializing its scans, so that its sources still refer to their scan
+    /// operators rather than to scan tasks, e.g. to serialize the optimized plan.
+    ///
+    /// **Important**: Like `optimize`, do not call this method from the main thread.
+    pub fn optimize_without_scan_materialization(&self) -> DaftResult<Self> {
+        self.optimize_with(OptimizerBuilder::with_default_rules(false))
+    }
+
+    fn optimize_with(&self, optimizer_builder: OptimizerBuilder) -> DaftResult<Self> {
         // TODO: remove the `block_on` to make this method safe to call from the main thread
 
         let cfg = self.config.clone();
 
-        let unoptimized_plan = self.build();
+        let unoptimized_plan = self.build_for_optimization();
 
-        let optimizer = OptimizerBuilder::default()
+        let optimizer = optimizer_builder
             .when(
                 !cfg.as_ref()
                     .is_some_and(|conf| conf.disable_join_reordering),
@@ -843,10 +986,69 @@ impl LogicalPlanBuilder {
         self.plan.clone()
     }
 
+    // Builds the plan to be optimized. Analyzed source statistics are only used for planning when cost-based planning is
+    // enabled, so they're dropped from the plan otherwise.
+    fn build_for_optimization(&self) -> Arc<LogicalPlan> {
+        if self
+            .config
+            .as_ref()
+            .is_some_and(|conf| conf.enable_cost_based_planning)
+        {
+            return self.build();
+        }
+        self.build()
+            .transform_up(|node| match node.as_ref() {
+                LogicalPlan::Source(source)
+                    if let SourceInfo::Physical(physical_scan_info) =
+                        source.source_info.as_ref()
+                        && physical_scan_info.statistics.is_some() =>
+                {
+                    let source_info =
+                        SourceInfo::Physical(physical_scan_info.with_statistics(None));
+                    Ok(Transformed::yes(
+                        LogicalPlan::Source(ops::Source {
+                            source_info: source_info.into(),
+                            ..source.clone()
+                        })
+                        .arced(),
+                    ))
+                }
+                _ => Ok(Transformed::no(node)),
+            })
+            .expect("Dropping source statistics should not fail")
+            .data
+    }
+
     pub fn schema(&self) -> SchemaRef {
         self.plan.schema()
     }
 
+    /// See [`LogicalPlan::fingerprint`].
+    pub fn fingerprint(&self) -> Option<u64> {
+        self.plan.fingerprint()
+    }
+
+    /// See [`LogicalPlan::durable_fingerprint`].
+    pub fn durable_fingerprint(&self) -> Option<String> {
+        self.plan.durable_fingerprint()
+    }
+
+    /// Cache keys of the in-memory sources that this plan scans.
+    pub fn in_memory_cache_keys(&self) -> Vec<String> {
+        let mut keys = vec![];
+        self.plan
+            .apply(|node| {
+                if let LogicalPlan::Source(source) = node.as_ref()
+                    && let SourceInfo::InMemory(info) = source.source_info.as_ref()
+                {
+                    keys.push(info.cache_key.clone());
+                }
+                Ok(TreeNodeRecursion::Continue)
+            })
+            .expect("collecting cache keys should not fail");
+        keys
+    }
+
     pub fn repr_ascii(&self, simple: bool) -> String {
         self.plan.repr_ascii(simple)
     }
@@ -884,6 +1086,7 @@ fn pyexprs_to_exprs(vec: Vec<PyExpr>) -> Vec<ExprRef> {
 #[pymethods]
 impl PyLogicalPlanBuilder {
     #[staticmethod]
+    #[pyo3(signature = (partition_key, cache_entry, schema, num_partitions, size_bytes, num_rows, persisted=false))]
     pub fn in_memory_scan(
         partition_key: &str,
         cache_entry: PyObject,
@@ -891,6 +1094,7 @@ impl PyLogicalPlanBuilder {
         num_partitions: usize,
         size_bytes: usize,
         num_rows: usize,
+        persisted: bool,
     ) -> PyResult<Self> {
         Ok(LogicalPlanBuilder::in_memory_scan(
             partition_key,
@@ -899,6 +1103,7 @@ impl PyLogicalPlanBuilder {
             num_partitions,
             size_bytes,
             num_rows,
+            persisted,
         )?
         .into())
     }
@@ -930,12 +1135,33 @@ impl PyLogicalPlanBuilder {
         Ok(self.builder.filter(predicate.expr)?.into())
     }
 
+    /// Checks whether `expr` is in the single column of this plan. Filtering on it is rewritten
+    /// into a semi join by the optimizer.
+    pub fn in_subquery(&self, expr: PyExpr) -> PyResult<PyExpr> {
+        let schema = self.builder.schema();
+        if schema.len() != 1 {
+            return Err(DaftError::ValueError(format!(
+                "Expected the membership source of is_in to have a single column, but it has columns: {:?}",
+                schema.names()
+            ))
+            .into());
+        }
+        let subquery = Subquery {
+            plan: self.builder.build(),
+        };
+        Ok(expr.expr.in_subquery(subquery).into())
+    }
+
     pub fn limit(&self, limit: i64, eager: bool) -> PyResult<Self> {
         Ok(self.builder.limit(limit, eager)?.into())
     }
 
-    pub fn explode(&self, to_explode: Vec<PyExpr>) -> PyResult<Self> {
-        Ok(self.builder.explode(pyexprs_to_exprs(to_explode))?.into())
+    #[pyo3(signature = (to_explode, unnest_structs=false))]
+    pub fn explode(&self, to_explode: Vec<PyExpr>, unnest_structs: bool) -> PyResult<Self> {
+        Ok(self
+            .builder
+            .explode(pyexprs_to_exprs(to_explode), unnest_structs)?
+            .into())
     }
 
     pub fn unpivot(
@@ -1000,6 +1226,44 @@ impl PyLogicalPlanBuilder {
         Ok(self.builder.summarize()?.into())
     }
 
+    pub fn analyze(&self) -> PyResult<Self> {
+        Ok(self.builder.analyze()?.into())
+    }
+
+    /// Attaches analyzed statistics to the source that this plan scans. `columns` maps each analyzed column's name to
+    /// its (null count, approximate distinct count, min, max).
+    pub fn with_source_statistics(
+        &self,
+        num_rows: usize,
+        columns: HashMap<String, (usize, usize, Option<PyExpr>, Option<PyExpr>)>,
+    ) -> PyResult<Self> {
+        fn to_literal(expr: Option<PyExpr>) -> Option<LiteralValue> {
+            match expr?.expr.as_ref() {
+                Expr::Literal(LiteralValue::Null) => None,
+                Expr::Literal(value) => Some(value.clone()),
+                _ => None,
+            }
+        }
+        let columns = columns
+            .into_iter()
+            .map(|(name, (null_count, approx_distinct_count, min, max))| {
+                (
+                    name,
+                    ColumnStatistics {
+                        null_count,
+                        approx_distinct_count,
+                        min: to_literal(min),
+                        max: to_literal(max),
+                    },
+                )
+            })
+            .collect();
+        Ok(self
+            .builder
+            .with_source_statistics(SourceStatistics::new(num_rows, columns))?
+            .into())
+    }
+
     pub fn distinct(&self) -> PyResult<Self> {
         Ok(self.builder.distinct()?.into())
     }
@@ -1024,6 +1288,22 @@ impl PyLogicalPlanBuilder {
             .into())
     }
 
+    pub fn aggregate_grouping_sets(
+        &self,
+        agg_exprs: Vec<PyExpr>,
+        groupby_exprs: Vec<PyExpr>,
+        grouping_sets: Vec<Vec<usize>>,
+    ) -> PyResult<Self> {
+        Ok(self
+            .builder
+            .aggregate_grouping_sets(
+                pyexprs_to_exprs(agg_exprs),
+                pyexprs_to_exprs(groupby_exprs),
+                grouping_sets,
+            )?
+            .into())
+    }
+
     pub fn pivot(
         &self,
         group_by: Vec<PyExpr>,
@@ -1102,6 +1382,45 @@ impl PyLogicalPlanBuilder {
             .into())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (
+        right,
+        left_on,
+        right_on,
+        left_by,
+        right_by,
+        strategy,
+        tolerance=None,
+        prefix=None,
+        suffix=None
+    ))]
+    pub fn asof_join(
+        &self,
+        right: &Self,
+        left_on: PyExpr,
+        right_on: PyExpr,
+        left_by: Vec<PyExpr>,
+        right_by: Vec<PyExpr>,
+        strategy: AsofStrategy,
+        tolerance: Option<PyExpr>,
+        prefix: Option<String>,
+        suffix: Option<String>,
+    ) -> PyResult<Self> {
+        Ok(self
+            .builder
+            .asof_join(
+                &right.builder,
+                left_on.expr,
+                right_on.expr,
+                pyexprs_to_exprs(left_by),
+                pyexprs_to_exprs(right_by),
+                strategy,
+                tolerance.map(|t| t.expr),
+                JoinOptions { prefix, suffix },
+            )?
+            .into())
+    }
+
     pub fn concat(&self, other: &Self) -> DaftResult<Self> {
         Ok(self.builder.concat(&other.builder)?.into())
     }
@@ -1141,13 +1460,15 @@ impl PyLogicalPlanBuilder {
             .into())
     }
 
+    #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (
         root_dir,
         write_mode,
         file_format,
         partition_cols=None,
         compression=None,
-        io_config=None
+        io_config=None,
+        max_open_writers=None
     ))]
     pub fn table_write(
         &self,
@@ -1157,6 +1478,7 @@ impl PyLogicalPlanBuilder {
         partition_cols: Option<Vec<PyExpr>>,
         compression: Option<String>,
         io_config: Option<common_io_config::python::IOConfig>,
+        max_open_writers: Option<usize>,
     ) -> PyResult<Self> {
         Ok(self
             .builder
@@ -1167,6 +1489,7 @@ impl PyLogicalPlanBuilder {
                 partition_cols.map(pyexprs_to_exprs),
                 compression,
                 io_config.map(|cfg| cfg.config),
+                max_open_writers,
             )?
             .into())
     }
@@ -1180,7 +1503,8 @@ impl PyLogicalPlanBuilder {
         iceberg_schema,
         iceberg_properties,
         catalog_columns,
-        io_config=None
+        io_config=None,
+        native_write=None
     ))]
     pub fn iceberg_write(
         &self,
@@ -1192,6 +1516,7 @@ impl PyLogicalPlanBuilder {
         iceberg_properties: PyObject,
         catalog_columns: Vec<String>,
         io_config: Option<common_io_config::python::IOConfig>,
+        native_write: Option<PyObject>,
     ) -> PyResult<Self> {
         Ok(self
             .builder
@@ -1204,6 +1529,7 @@ impl PyLogicalPlanBuilder {
                 Arc::new(iceberg_properties),
                 io_config.map(|cfg| cfg.config),
                 catalog_columns,
+                native_write.map(Arc::new),
             )?
             .into())
     }
@@ -1216,7 +1542,8 @@ impl PyLogicalPlanBuilder {
         version,
         large_dtypes,
         partition_cols=None,
-        io_config=None
+        io_config=None,
+        native_write=None
     ))]
     pub fn delta_write(
         &self,
@@ -1227,6 +1554,7 @@ impl PyLogicalPlanBuilder {
         large_dtypes: bool,
         partition_cols: Option<Vec<String>>,
         io_config: Option<common_io_config::python::IOConfig>,
+        native_write: Option<PyObject>,
     ) -> PyResult<Self> {
         Ok(self
             .builder
@@ -1238,6 +1566,7 @@ impl PyLogicalPlanBuilder {
                 large_dtypes,
                 partition_cols,
                 io_config.map(|cfg| cfg.config),
+                native_write.map(Arc::new),
             )?
             .into())
     }
@@ -1280,6 +1609,20 @@ impl PyLogicalPlanBuilder {
         py.allow_threads(|| Ok(self.builder.optimize()?.into()))
     }
 
+    /// Stable hash of the underlying logical plan, used as the key for caching its optimized plan and results.
+    pub fn fingerprint(&self) -> Option<u64> {
+        self.builder.fingerprint()
+    }
+
+    /// Hash of the underlying logical plan that is stable across processes, used as the key for checkpointing its stages.
+    pub fn durable_fingerprint(&self) -> Option<String> {
+        self.builder.durable_fingerprint()
+    }
+
+    pub fn in_memory_cache_keys(&self) -> Vec<String> {
+        self.builder.in_memory_cache_keys()
+    }
+
     pub fn repr_ascii(&self, simple: bool) -> PyResult<String> {
         Ok(self.builder.repr_ascii(simple))
     }
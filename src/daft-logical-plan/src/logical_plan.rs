@@ -7,6 +7,7 @@ use std::{
 
 use common_display::ascii::AsciiTreeDisplay;
 use common_error::{DaftError, DaftResult};
+use common_scan_info::{PhysicalScanInfo, ScanState};
 use common_treenode::{TreeNode, TreeNodeRecursion};
 use daft_core::join::JoinSide;
 use daft_dsl::{
@@ -17,7 +18,10 @@ use indexmap::IndexSet;
 use snafu::Snafu;
 
 pub use crate::ops::*;
-use crate::stats::{PlanStats, StatsState};
+use crate::{
+    source_info::SourceInfo,
+    stats::{PlanStats, StatsState},
+};
 
 /// Logical plan for a Daft query.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -38,6 +42,7 @@ pub enum LogicalPlan {
     Intersect(Intersect),
     Union(Union),
     Join(Join),
+    AsofJoin(AsofJoin),
     Sink(Sink),
     Sample(Sample),
     MonotonicallyIncreasingId(MonotonicallyIncreasingId),
@@ -102,6 +107,7 @@ impl LogicalPlan {
             Self::Intersect(Intersect { lhs, .. }) => lhs.schema(),
             Self::Union(Union { lhs, .. }) => lhs.schema(),
             Self::Join(Join { output_schema, .. }) => output_schema.clone(),
+            Self::AsofJoin(AsofJoin { output_schema, .. }) => output_schema.clone(),
             Self::Sink(Sink { schema, .. }) => schema.clone(),
             Self::Sample(Sample { input, .. }) => input.schema(),
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { schema, .. }) => {
@@ -224,6 +230,17 @@ impl LogicalPlan {
                 }
                 vec![left, right]
             }
+            Self::AsofJoin(join) => {
+                let left = std::iter::once(&join.left_on)
+                    .chain(join.left_by.iter())
+                    .flat_map(get_required_columns)
+                    .collect();
+                let right = std::iter::once(&join.right_on)
+                    .chain(join.right_by.iter())
+                    .flat_map(get_required_columns)
+                    .collect();
+                vec![left, right]
+            }
             Self::Intersect(_) => vec![IndexSet::new(), IndexSet::new()],
             Self::Union(_) => vec![IndexSet::new(), IndexSet::new()],
             Self::Source(_) => todo!(),
@@ -258,6 +275,7 @@ impl LogicalPlan {
             Self::Pivot(..) => "Pivot",
             Self::Concat(..) => "Concat",
             Self::Join(..) => "Join",
+            Self::AsofJoin(..) => "AsofJoin",
             Self::Intersect(..) => "Intersect",
             Self::Union(..) => "Union",
             Self::Sink(..) => "Sink",
@@ -284,6 +302,7 @@ impl LogicalPlan {
             | Self::Pivot(Pivot { stats_state, .. })
             | Self::Concat(Concat { stats_state, .. })
             | Self::Join(Join { stats_state, .. })
+            | Self::AsofJoin(AsofJoin { stats_state, .. })
             | Self::Sink(Sink { stats_state, .. })
             | Self::Sample(Sample { stats_state, .. })
             | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { stats_state, .. })
@@ -331,6 +350,7 @@ impl LogicalPlan {
                 panic!("Alias should be optimized away before stats are derived")
             }
             Self::Join(plan) => Self::Join(plan.with_materialized_stats()),
+            Self::AsofJoin(plan) => Self::AsofJoin(plan.with_materialized_stats()),
             Self::Sink(plan) => Self::Sink(plan.with_materialized_stats()),
             Self::Sample(plan) => Self::Sample(plan.with_materialized_stats()),
             Self::MonotonicallyIncreasingId(plan) => {
@@ -358,6 +378,7 @@ impl LogicalPlan {
             Self::Intersect(inner) => inner.multiline_display(),
             Self::Union(inner) => inner.multiline_display(),
             Self::Join(join) => join.multiline_display(),
+            Self::AsofJoin(join) => join.multiline_display(),
             Self::Sink(sink) => sink.multiline_display(),
             Self::Sample(sample) => sample.multiline_display(),
             Self::MonotonicallyIncreasingId(monotonically_increasing_id) => {
@@ -384,6 +405,7 @@ impl LogicalPlan {
             Self::Pivot(Pivot { input, .. }) => vec![input],
             Self::Concat(Concat { input, other, .. }) => vec![input, other],
             Self::Join(Join { left, right, .. }) => vec![left, right],
+            Self::AsofJoin(AsofJoin { left, right, .. }) => vec![left, right],
             Self::Sink(Sink { input, .. }) => vec![input],
             Self::Intersect(Intersect { lhs, rhs, .. }) => vec![lhs, rhs],
             Self::Union(Union { lhs, rhs, .. }) => vec![lhs, rhs],
@@ -406,7 +428,7 @@ impl LogicalPlan {
                 Self::ActorPoolProject(ActorPoolProject {projection, ..}) => Self::ActorPoolProject(ActorPoolProject::try_new(input.clone(), projection.clone()).unwrap()),
                 Self::Filter(Filter { predicate, .. }) => Self::Filter(Filter::try_new(input.clone(), predicate.clone()).unwrap()),
                 Self::Limit(Limit { limit, eager, .. }) => Self::Limit(Limit::new(input.clone(), *limit, *eager)),
-                Self::Explode(Explode { to_explode, .. }) => Self::Explode(Explode::try_new(input.clone(), to_explode.clone()).unwrap()),
+                Self::Explode(Explode { to_explode, unnest_structs, .. }) => Self::Explode(Explode::try_new(input.clone(), to_explode.clone(), *unnest_structs).unwrap()),
                 Self::Sort(Sort { sort_by, descending, nulls_first, .. }) => Self::Sort(Sort::try_new(input.clone(), sort_by.clone(), descending.clone(), nulls_first.clone()).unwrap()),
                 Self::Repartition(Repartition {  repartition_spec: scheme_config, .. }) => Self::Repartition(Repartition::new(input.clone(), scheme_config.clone())),
                 Self::Distinct(_) => Self::Distinct(Distinct::new(input.clone())),
@@ -428,6 +450,7 @@ impl LogicalPlan {
                 Self::Intersect(_) => panic!("Intersect ops should never have only one input, but got one"),
                 Self::Union(_) => panic!("Union ops should never have only one input, but got one"),
                 Self::Join(_) => panic!("Join ops should never have only one input, but got one"),
+                Self::AsofJoin(_) => panic!("AsofJoin ops should never have only one input, but got one"),
             },
             [input1, input2] => match self {
                 Self::Source(_) => panic!("Source nodes don't have children, with_new_children() should never be called for Source ops"),
@@ -441,6 +464,16 @@ impl LogicalPlan {
                     *join_type,
                     *join_strategy,
                 ).unwrap()),
+                Self::AsofJoin(AsofJoin { left_on, right_on, left_by, right_by, strategy, tolerance, .. }) => Self::AsofJoin(AsofJoin::try_new(
+                    input1.clone(),
+                    input2.clone(),
+                    left_on.clone(),
+                    right_on.clone(),
+                    left_by.clone(),
+                    right_by.clone(),
+                    *strategy,
+                    tolerance.clone(),
+                ).unwrap()),
                 _ => panic!("Logical op {} has one input, but got two", self),
             },
             _ => panic!("Logical ops should never have more than 2 inputs, but got: {}", children.len())
@@ -475,6 +508,75 @@ impl LogicalPlan {
         s
     }
 
+    /// A hash of the plan that identifies the data it produces within a session, so that plans with equal
+    /// fingerprints can reuse each other's optimized plans and results.
+    ///
+    /// Besides the plan itself, this includes the current snapshot of every unmaterialized scan, so that the
+    /// fingerprint changes when e.g. a new commit is made to a scanned table. Note that scans are otherwise
+    /// identified by their scan operator, which is only stable for the lifetime of the operator.
+    ///
+    /// Returns None for plans that write data, since reusing them would skip the write.
+    pub fn fingerprint(self: &Arc<Self>) -> Option<u64> {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.hash(&mut hasher);
+        let mut has_sink = false;
+        self.apply(|node| {
+            match node.as_ref() {
+                Self::Sink(..) => has_sink = true,
+                Self::Source(Source { source_info, .. }) => {
+                    if let SourceInfo::Physical(PhysicalScanInfo {
+                        scan_state: ScanState::Operator(scan_op),
+                        ..
+                    }) = source_info.as_ref()
+                    {
+                        scan_op.0.snapshot_id().hash(&mut hasher);
+                    }
+                }
+                _ => {}
+            }
+            Ok(TreeNodeRecursion::Continue)
+        })
+        .expect("Fingerprinting a logical plan should not fail");
+        (!has_sink).then(|| hasher.finish())
+    }
+
+    /// A hash of the plan that is stable across processes, so that a query that is run again, e.g. after a failure,
+    /// can find the checkpointed outputs of the stages that completed before.
+    ///
+    /// Since scan operators are only stable within a session, this hashes the displayed plan instead, along with the
+    /// current snapshot of every unmaterialized scan. Anything that isn't displayed, such as the code of a UDF, isn't
+    /// captured.
+    ///
+    /// Returns None for plans that scan in-memory data, which doesn't outlive the session.
+    pub fn durable_fingerprint(self: &Arc<Self>) -> Option<String> {
+        let mut description = self.repr_ascii(false);
+        let mut has_in_memory_source = false;
+        self.apply(|node| {
+            if let Self::Source(Source { source_info, .. }) = node.as_ref() {
+                match source_info.as_ref() {
+                    SourceInfo::InMemory(..) => has_in_memory_source = true,
+                    SourceInfo::Physical(PhysicalScanInfo {
+                        scan_state: ScanState::Operator(scan_op),
+                        ..
+                    }) => {
+                        if let Some(snapshot_id) = scan_op.0.snapshot_id() {
+                            description.push_str(&snapshot_id);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Ok(TreeNodeRecursion::Continue)
+        })
+        .expect("Fingerprinting a logical plan should not fail");
+        (!has_in_memory_source).then(|| {
+            format!(
+                "{:032x}",
+                xxhash_rust::xxh3::xxh3_128(description.as_bytes())
+            )
+        })
+    }
+
     pub fn get_aliases(self: Arc<Self>) -> Vec<Arc<str>> {
         use common_treenode::TreeNode;
 
@@ -565,6 +667,7 @@ impl LogicalPlan {
             | Self::Intersect(Intersect { plan_id, .. })
             | Self::Union(Union { plan_id, .. })
             | Self::Join(Join { plan_id, .. })
+            | Self::AsofJoin(AsofJoin { plan_id, .. })
             | Self::Sink(Sink { plan_id, .. })
             | Self::Sample(Sample { plan_id, .. })
             | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { plan_id, .. })
@@ -595,6 +698,7 @@ impl LogicalPlan {
             Self::Intersect(intersect) => Self::Intersect(intersect.clone().with_plan_id(plan_id)),
             Self::Union(union) => Self::Union(union.clone().with_plan_id(plan_id)),
             Self::Join(join) => Self::Join(join.clone().with_plan_id(plan_id)),
+            Self::AsofJoin(join) => Self::AsofJoin(join.clone().with_plan_id(plan_id)),
             Self::Sink(sink) => Self::Sink(sink.clone().with_plan_id(plan_id)),
             Self::Sample(sample) => Self::Sample(sample.clone().with_plan_id(plan_id)),
             Self::MonotonicallyIncreasingId(monotonically_increasing_id) => {
@@ -704,7 +808,49 @@ impl_from_data_struct_for_logical_plan!(Concat);
 impl_from_data_struct_for_logical_plan!(Intersect);
 impl_from_data_struct_for_logical_plan!(Union);
 impl_from_data_struct_for_logical_plan!(Join);
+impl_from_data_struct_for_logical_plan!(AsofJoin);
 impl_from_data_struct_for_logical_plan!(Sink);
 impl_from_data_struct_for_logical_plan!(Sample);
 impl_from_data_struct_for_logical_plan!(MonotonicallyIncreasingId);
 impl_from_data_struct_for_logical_plan!(Window);
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+    use daft_dsl::{lit, resolved_col};
+
+    use crate::test::{dummy_scan_node, dummy_scan_operator};
+
+    #[test]
+    fn test_fingerprint_identifies_equal_plans() {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]);
+        let plan = |threshold: i64| {
+            dummy_scan_node(scan_op.clone())
+                .filter(resolved_col("a").lt(lit(threshold)))
+                .unwrap()
+                .build()
+        };
+        let fingerprint = plan(2).fingerprint();
+        assert!(fingerprint.is_some());
+        assert_eq!(fingerprint, plan(2).fingerprint());
+        assert_ne!(fingerprint, plan(3).fingerprint());
+    }
+
+    #[test]
+    fn test_durable_fingerprint_is_independent_of_scan_operator() {
+        let plan = |threshold: i64| {
+            let scan_op = dummy_scan_operator(vec![Field::new("a", DataType::Int64)]);
+            dummy_scan_node(scan_op)
+                .filter(resolved_col("a").lt(lit(threshold)))
+                .unwrap()
+                .build()
+        };
+        let fingerprint = plan(2).durable_fingerprint();
+        assert!(fingerprint.is_some());
+        assert_eq!(fingerprint, plan(2).durable_fingerprint());
+        assert_ne!(fingerprint, plan(3).durable_fingerprint());
+    }
+}
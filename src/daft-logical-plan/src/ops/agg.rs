@@ -73,8 +73,15 @@ impl Aggregate {
                 acc_selectivity,
             }
         } else {
-            // Assume high cardinality for group by columns, and 80% of rows are unique.
-            let est_num_groups = input_stats.approx_stats.num_rows * 4 / 5;
+            // Use the distinct counts of the group by columns if we have them. Otherwise, assume high cardinality for
+            // group by columns, and 80% of rows are unique.
+            let est_num_groups = self
+                .groupby
+                .iter()
+                .map(|expr| input_stats.column_stats_for(expr).map(|_| expr.name()))
+                .collect::<Option<Vec<_>>>()
+                .and_then(|columns| input_stats.approx_num_groups(columns))
+                .unwrap_or(input_stats.approx_stats.num_rows * 4 / 5);
             ApproxStats {
                 num_rows: est_num_groups,
                 size_bytes: est_bytes_per_row * est_num_groups,
@@ -82,7 +89,17 @@ impl Aggregate {
                     / input_stats.approx_stats.num_rows as f64,
             }
         };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        // Group by columns keep their stats, bounded by the number of groups.
+        let column_stats = input_stats
+            .bounded_column_stats(approx_stats.num_rows)
+            .into_iter()
+            .filter(|(name, _)| self.groupby.iter().any(|expr| expr.name() == name))
+            .collect();
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(approx_stats)
+                .with_column_stats(column_stats)
+                .into(),
+        );
         self
     }
 
@@ -0,0 +1,42 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::CountMode;
+use daft_dsl::ExprRef;
+
+use crate::LogicalPlanBuilder;
+
+/// Creates a single row of table and column statistics for the input, to be attached to its source once computed.
+///
+/// The output contains the total row count as `num_rows`, and for the i-th input column, its null count as
+/// `null_count_{i}`, its approximate number of distinct values as `approx_distinct_count_{i}`, and for numeric and
+/// temporal columns, its minimum and maximum values as `min_{i}` and `max_{i}`.
+pub fn analyze(input: &LogicalPlanBuilder) -> DaftResult<LogicalPlanBuilder> {
+    let schema = input.schema();
+    let Some(first_field) = schema.fields().first() else {
+        return Err(DaftError::ValueError(
+            "Cannot analyze a DataFrame without any columns".to_string(),
+        ));
+    };
+    let mut aggs: Vec<ExprRef> = vec![daft_dsl::resolved_col(first_field.name.as_str())
+        .count(CountMode::All)
+        .alias("num_rows")];
+    for (i, field) in schema.as_ref().into_iter().enumerate() {
+        let col = daft_dsl::resolved_col(field.name.as_str());
+        aggs.push(
+            col.clone()
+                .count(CountMode::Null)
+                .alias(format!("null_count_{i}")),
+        );
+        aggs.push(
+            col.clone()
+                .approx_count_distinct()
+                .alias(format!("approx_distinct_count_{i}")),
+        );
+        // Min and max are only used to estimate the selectivity of range predicates, which requires ordered values
+        // that can be interpolated.
+        if field.dtype.is_numeric() || field.dtype.is_temporal() {
+            aggs.push(col.clone().min().alias(format!("min_{i}")));
+            aggs.push(col.max().alias(format!("max_{i}")));
+        }
+    }
+    input.aggregate(aggs, vec![])
+}
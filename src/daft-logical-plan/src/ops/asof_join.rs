@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use daft_core::prelude::*;
+use daft_dsl::{join::infer_join_schema, ExprRef};
+use itertools::Itertools;
+
+use crate::{
+    logical_plan::{self},
+    stats::StatsState,
+    LogicalPlan,
+};
+
+/// Matches each row of `left` with the row of `right` in the same `by` group whose `on` key is
+/// nearest to it, keeping every row of `left`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AsofJoin {
+    pub plan_id: Option<usize>,
+    // Upstream nodes.
+    pub left: Arc<LogicalPlan>,
+    pub right: Arc<LogicalPlan>,
+
+    pub left_on: ExprRef,
+    pub right_on: ExprRef,
+    pub left_by: Vec<ExprRef>,
+    pub right_by: Vec<ExprRef>,
+    pub strategy: AsofStrategy,
+    pub tolerance: Option<ExprRef>,
+    pub output_schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
+impl AsofJoin {
+    /// Create a new as-of join node. The keys must be resolved against their own side.
+    ///
+    /// Columns that have the same name between left and right are assumed to be merged.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn try_new(
+        left: Arc<LogicalPlan>,
+        right: Arc<LogicalPlan>,
+        left_on: ExprRef,
+        right_on: ExprRef,
+        left_by: Vec<ExprRef>,
+        right_by: Vec<ExprRef>,
+        strategy: AsofStrategy,
+        tolerance: Option<ExprRef>,
+    ) -> logical_plan::Result<Self> {
+        let output_schema = infer_join_schema(&left.schema(), &right.schema(), JoinType::Left)?;
+
+        Ok(Self {
+            plan_id: None,
+            left,
+            right,
+            left_on,
+            right_on,
+            left_by,
+            right_by,
+            strategy,
+            tolerance,
+            output_schema,
+            stats_state: StatsState::NotMaterialized,
+        })
+    }
+
+    pub fn with_plan_id(mut self, plan_id: usize) -> Self {
+        self.plan_id = Some(plan_id);
+        self
+    }
+
+    pub(crate) fn with_materialized_stats(mut self) -> Self {
+        // Every left row is kept exactly once.
+        let left_stats = self.left.materialized_stats().clone();
+        self.stats_state = StatsState::Materialized(left_stats.into());
+        self
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!("AsofJoin: Strategy = {}", self.strategy));
+        res.push(format!("Left on = {}", self.left_on));
+        res.push(format!("Right on = {}", self.right_on));
+        if !self.left_by.is_empty() {
+            res.push(format!("Left by = {}", self.left_by.iter().join(", ")));
+            res.push(format!("Right by = {}", self.right_by.iter().join(", ")));
+        }
+        if let Some(tolerance) = &self.tolerance {
+            res.push(format!("Tolerance = {tolerance}"));
+        }
+        res.push(format!(
+            "Output schema = {}",
+            self.output_schema.short_string()
+        ));
+        if let StatsState::Materialized(stats) = &self.stats_state {
+            res.push(format!("Stats = {}", stats));
+        }
+        res
+    }
+}
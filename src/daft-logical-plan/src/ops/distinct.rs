@@ -28,12 +28,15 @@ impl Distinct {
     }
 
     pub(crate) fn with_materialized_stats(mut self) -> Self {
-        // TODO(desmond): We can simply use NDVs here. For now, do a naive estimation.
         let input_stats = self.input.materialized_stats();
         let est_bytes_per_row =
             input_stats.approx_stats.size_bytes / (input_stats.approx_stats.num_rows.max(1));
-        // Assume high cardinality, 80% of rows are distinct.
-        let est_distinct_values = input_stats.approx_stats.num_rows * 4 / 5;
+        // Use the distinct counts of the input columns if we have them. Otherwise, assume high cardinality, and 80% of
+        // rows are distinct.
+        let input_schema = self.input.schema();
+        let est_distinct_values = input_stats
+            .approx_num_groups(input_schema.field_names())
+            .unwrap_or(input_stats.approx_stats.num_rows * 4 / 5);
         let acc_selectivity = if input_stats.approx_stats.num_rows == 0 {
             0.0
         } else {
@@ -45,7 +48,12 @@ impl Distinct {
             size_bytes: est_distinct_values * est_bytes_per_row,
             acc_selectivity,
         };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        let column_stats = input_stats.bounded_column_stats(approx_stats.num_rows);
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(approx_stats)
+                .with_column_stats(column_stats)
+                .into(),
+        );
         self
     }
 
@@ -1,7 +1,11 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
+use common_error::DaftError;
 use daft_dsl::{exprs_to_schema, ExprRef};
-use daft_schema::schema::{Schema, SchemaRef};
+use daft_schema::{
+    dtype::DataType,
+    schema::{Schema, SchemaRef},
+};
 use itertools::Itertools;
 
 use crate::{
@@ -17,6 +21,8 @@ pub struct Explode {
     pub input: Arc<LogicalPlan>,
     // Expressions to explode. e.g. col("a")
     pub to_explode: Vec<ExprRef>,
+    // Whether exploded struct columns are replaced by a column for each of their fields.
+    pub unnest_structs: bool,
     pub exploded_schema: SchemaRef,
     pub stats_state: StatsState,
 }
@@ -25,6 +31,7 @@ impl Explode {
     pub(crate) fn try_new(
         input: Arc<LogicalPlan>,
         to_explode: Vec<ExprRef>,
+        unnest_structs: bool,
     ) -> logical_plan::Result<Self> {
         let exploded_schema = {
             let explode_exprs = to_explode
@@ -41,13 +48,35 @@ impl Explode {
                 .map(|field| explode_schema.get_field(&field.name).unwrap_or(field))
                 .cloned();
 
-            Schema::new(fields).into()
+            if unnest_structs {
+                let mut unnested = Vec::new();
+                for field in fields {
+                    match &field.dtype {
+                        DataType::Struct(children) if explode_schema.has_field(&field.name) => {
+                            unnested.extend(children.iter().cloned());
+                        }
+                        _ => unnested.push(field),
+                    }
+                }
+                let mut names = HashSet::new();
+                if let Some(duplicate) = unnested.iter().find(|f| !names.insert(&f.name)) {
+                    return Err(DaftError::ValueError(format!(
+                        "Unnesting the exploded structs would produce more than one column named {}",
+                        duplicate.name
+                    ))
+                    .into());
+                }
+                Schema::new(unnested).into()
+            } else {
+                Schema::new(fields).into()
+            }
         };
 
         Ok(Self {
             plan_id: None,
             input,
             to_explode,
+            unnest_structs,
             exploded_schema,
             stats_state: StatsState::NotMaterialized,
         })
@@ -82,6 +111,9 @@ impl Explode {
             "Explode: {}",
             self.to_explode.iter().map(|e| e.to_string()).join(", ")
         ));
+        if self.unnest_structs {
+            res.push("Unnest structs = true".to_string());
+        }
         res.push(format!("Schema = {}", self.exploded_schema.short_string()));
         if let StatsState::Materialized(stats) = &self.stats_state {
             res.push(format!("Stats = {}", stats));
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use common_error::DaftError;
 use daft_core::prelude::*;
-use daft_dsl::{estimated_selectivity, ExprRef};
+use daft_dsl::ExprRef;
 use snafu::ResultExt;
 
 use crate::{
@@ -50,9 +50,9 @@ impl Filter {
 
     pub(crate) fn with_materialized_stats(mut self) -> Self {
         // Assume no row/column pruning in cardinality-affecting operations.
-        // TODO(desmond): We can do better estimations here. For now, reuse the old logic.
         let input_stats = self.input.materialized_stats();
-        let estimated_selectivity = estimated_selectivity(&self.predicate, &self.input.schema());
+        let estimated_selectivity =
+            input_stats.estimated_selectivity(&self.predicate, &self.input.schema());
         let approx_stats = ApproxStats {
             num_rows: (input_stats.approx_stats.num_rows as f64 * estimated_selectivity).ceil()
                 as usize,
@@ -60,7 +60,12 @@ impl Filter {
                 as usize,
             acc_selectivity: input_stats.approx_stats.acc_selectivity * estimated_selectivity,
         };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        let column_stats = input_stats.bounded_column_stats(approx_stats.num_rows);
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(approx_stats)
+                .with_column_stats(column_stats)
+                .into(),
+        );
         self
     }
 
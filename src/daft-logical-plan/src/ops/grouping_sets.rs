@@ -0,0 +1,150 @@
+use common_error::{DaftError, DaftResult};
+use daft_dsl::{lit, null_lit, resolved_col, ExprRef};
+use itertools::Itertools;
+
+use crate::LogicalPlanBuilder;
+
+/// The name of the column that identifies the grouping set of each row of a grouping sets
+/// aggregation.
+pub const GROUPING_ID_COLUMN: &str = "grouping_id";
+
+/// The grouping sets of `ROLLUP` over `n` keys: every prefix of the keys, longest first.
+pub fn rollup(n: usize) -> Vec<Vec<usize>> {
+    (0..=n).rev().map(|len| (0..len).collect()).collect()
+}
+
+/// The grouping sets of `CUBE` over `n` keys: every subset of the keys, in increasing order of
+/// grouping id.
+pub fn cube(n: usize) -> Vec<Vec<usize>> {
+    (0..1usize << n)
+        .map(|dropped| {
+            (0..n)
+                .filter(|i| dropped & (1 << (n - 1 - i)) == 0)
+                .collect()
+        })
+        .collect()
+}
+
+/// Aggregates `input` once for each grouping set, where each set lists the indices of the
+/// `groupby` keys it groups by, and concatenates the results.
+///
+/// Keys that aren't in a row's grouping set are null. A `grouping_id` column identifies the set
+/// of each row, with a bit for each key that is set when the key isn't grouped by, the first
+/// key being the most significant bit.
+pub fn grouping_sets(
+    input: &LogicalPlanBuilder,
+    aggs: Vec<ExprRef>,
+    groupby: Vec<ExprRef>,
+    sets: &[Vec<usize>],
+) -> DaftResult<LogicalPlanBuilder> {
+    let num_keys = groupby.len();
+    if sets.is_empty() {
+        return Err(DaftError::ValueError(
+            "Expected at least one grouping set".to_string(),
+        ));
+    }
+    if num_keys >= i64::BITS as usize {
+        return Err(DaftError::ValueError(format!(
+            "Grouping sets support at most {} keys, got {num_keys}",
+            i64::BITS - 1
+        )));
+    }
+    if let Some(idx) = sets.iter().flatten().find(|idx| **idx >= num_keys) {
+        return Err(DaftError::ValueError(format!(
+            "Grouping set key {idx} is out of bounds for {num_keys} keys"
+        )));
+    }
+
+    // aggregating by every key gives the names and types of the output columns
+    let schema = input.aggregate(aggs.clone(), groupby.clone())?.schema();
+    let (key_fields, agg_fields) = schema.fields().split_at(num_keys);
+
+    let mut output: Option<LogicalPlanBuilder> = None;
+    for set in sets {
+        let set = set.iter().copied().sorted().dedup().collect::<Vec<_>>();
+        let set_groupby = set.iter().map(|idx| groupby[*idx].clone()).collect();
+        let grouping_id = (0..num_keys)
+            .filter(|idx| !set.contains(idx))
+            .fold(0i64, |id, idx| id | (1 << (num_keys - 1 - idx)));
+
+        let projection = key_fields
+            .iter()
+            .enumerate()
+            .map(|(idx, field)| {
+                if set.contains(&idx) {
+                    resolved_col(field.name.as_str())
+                } else {
+                    null_lit().cast(&field.dtype).alias(field.name.as_str())
+                }
+            })
+            .chain(
+                agg_fields
+                    .iter()
+                    .map(|field| resolved_col(field.name.as_str())),
+            )
+            .chain(std::iter::once(lit(grouping_id).alias(GROUPING_ID_COLUMN)))
+            .collect();
+        let aggregated = input
+            .aggregate(aggs.clone(), set_groupby)?
+            .select(projection)?;
+
+        output = Some(match output {
+            None => aggregated,
+            Some(output) => output.concat(&aggregated)?,
+        });
+    }
+    Ok(output.expect("there is at least one grouping set"))
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::unresolved_col;
+
+    use super::{cube, grouping_sets, rollup};
+    use crate::test::{dummy_scan_node, dummy_scan_operator};
+
+    #[test]
+    fn rollup_and_cube_sets() {
+        assert_eq!(rollup(2), vec![vec![0, 1], vec![0], vec![]]);
+        assert_eq!(
+            cube(2),
+            vec![vec![0, 1], vec![0], vec![1], Vec::<usize>::new()]
+        );
+        assert_eq!(rollup(0), vec![Vec::<usize>::new()]);
+    }
+
+    #[test]
+    fn grouping_sets_schema() -> DaftResult<()> {
+        let scan = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Utf8),
+            Field::new("b", DataType::Int32),
+            Field::new("x", DataType::Int64),
+        ]));
+        let plan = grouping_sets(
+            &scan,
+            vec![unresolved_col("x").sum()],
+            vec![unresolved_col("a"), unresolved_col("b")],
+            &rollup(2),
+        )?;
+        assert_eq!(
+            plan.schema().as_ref(),
+            &Schema::new(vec![
+                Field::new("a", DataType::Utf8),
+                Field::new("b", DataType::Int32),
+                Field::new("x", DataType::Int64),
+                Field::new("grouping_id", DataType::Int64),
+            ])
+        );
+
+        let out_of_bounds = grouping_sets(
+            &scan,
+            vec![unresolved_col("x").sum()],
+            vec![unresolved_col("a")],
+            &[vec![1]],
+        );
+        assert!(out_of_bounds.is_err());
+        Ok(())
+    }
+}
@@ -8,8 +8,8 @@ use common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
 use daft_algebra::boolean::{combine_conjunction, split_conjunction};
 use daft_core::{join::JoinSide, prelude::*};
 use daft_dsl::{
-    join::infer_join_schema, resolved_col, right_col, Column, Expr, ExprRef, Operator,
-    ResolvedColumn,
+    join::{infer_join_schema, RangeCondition},
+    resolved_col, right_col, Column, Expr, ExprRef, Operator, ResolvedColumn,
 };
 use indexmap::IndexSet;
 #[cfg(feature = "python")]
@@ -18,7 +18,7 @@ use pyo3::prelude::*;
 use crate::{
     logical_plan::{self},
     ops::Project,
-    stats::{ApproxStats, PlanStats, StatsState},
+    stats::{ApproxStats, ColumnStats, PlanStats, StatsState},
     LogicalPlan, LogicalPlanRef,
 };
 
@@ -133,6 +133,65 @@ impl JoinPredicate {
         (remaining, left_keys, right_keys, null_equals_null)
     }
 
+    /// Split out the inequality predicates where one side is all left and the other side is all right columns.
+    ///
+    /// Conditions are oriented so that their left expression uses the left side of the join.
+    ///
+    /// Returns (remaining, range conditions)
+    pub fn split_range_preds(&self) -> (Self, Vec<RangeCondition>) {
+        let Some(pred) = &self.0 else {
+            return (Self::empty(), vec![]);
+        };
+
+        fn extract_range_predicate(expr: &Expr) -> Option<RangeCondition> {
+            match expr {
+                Expr::BinaryOp { op, left, right }
+                    if matches!(
+                        op,
+                        Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq
+                    ) =>
+                {
+                    let condition = RangeCondition::try_new(
+                        JoinPredicate::replace_join_side_cols(left.clone()),
+                        *op,
+                        JoinPredicate::replace_join_side_cols(right.clone()),
+                    )
+                    .ok()?;
+                    match (
+                        JoinPredicate::uses_join_side(left, JoinSide::Left),
+                        JoinPredicate::uses_join_side(left, JoinSide::Right),
+                        JoinPredicate::uses_join_side(right, JoinSide::Left),
+                        JoinPredicate::uses_join_side(right, JoinSide::Right),
+                    ) {
+                        (true, false, false, true) => Some(condition),
+                        (false, true, true, false) => Some(condition.flipped()),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+
+        let mut remaining_exprs = Vec::new();
+        let mut conditions = Vec::new();
+
+        for e in split_conjunction(pred) {
+            if let Some(condition) = extract_range_predicate(&e) {
+                conditions.push(condition);
+            } else {
+                remaining_exprs.push(e);
+            }
+        }
+
+        (Self(combine_conjunction(remaining_exprs)), conditions)
+    }
+
+    /// The predicate with its join side columns replaced by plain columns, for evaluating it on
+    /// joined rows.
+    pub fn to_joined_expr(&self) -> Option<ExprRef> {
+        self.0.clone().map(Self::replace_join_side_cols)
+    }
+
     /// Split out the predicates in the conjunction that only use columns from one side of the join.
     ///
     /// Does not include predicates that use neither join side.
@@ -338,16 +397,102 @@ impl Join {
             left_stats.approx_stats.size_bytes as f64 * right_stats.approx_stats.acc_selectivity;
         let right_size =
             right_stats.approx_stats.size_bytes as f64 * left_stats.approx_stats.acc_selectivity;
-        let approx_stats = ApproxStats {
+        let mut approx_stats = ApproxStats {
             num_rows: left_num_rows.max(right_num_rows).ceil() as usize,
             size_bytes: left_size.max(right_size).ceil() as usize,
             acc_selectivity: left_stats.approx_stats.acc_selectivity
                 * right_stats.approx_stats.acc_selectivity,
         };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        // If we have distinct counts for all the join keys, we can use them instead of assuming a pk-fk join.
+        if let Some(num_rows) = self.approx_num_rows_from_column_stats(left_stats, right_stats) {
+            let left_bytes_per_row = left_stats.approx_stats.size_bytes as f64
+                / left_stats.approx_stats.num_rows.max(1) as f64;
+            let right_bytes_per_row = right_stats.approx_stats.size_bytes as f64
+                / right_stats.approx_stats.num_rows.max(1) as f64;
+            let bytes_per_row = match self.join_type {
+                JoinType::Anti | JoinType::Semi => left_bytes_per_row,
+                _ => left_bytes_per_row + right_bytes_per_row,
+            };
+            approx_stats.num_rows = num_rows;
+            approx_stats.size_bytes = (num_rows as f64 * bytes_per_row).ceil() as usize;
+        }
+        let column_stats = self.output_column_stats(left_stats, right_stats, approx_stats.num_rows);
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(approx_stats)
+                .with_column_stats(column_stats)
+                .into(),
+        );
         self
     }
 
+    // Estimates the output cardinality of an equi-join as |L| * |R| / Π max(NDV(l_i), NDV(r_i)), assuming that the
+    // join keys are independent and that the smaller domain of each key pair is contained in the larger one.
+    // Returns `None` if the join has no equality keys, or if any key is missing column stats.
+    fn approx_num_rows_from_column_stats(
+        &self,
+        left_stats: &PlanStats,
+        right_stats: &PlanStats,
+    ) -> Option<usize> {
+        let (_, left_on, right_on, _) = self.on.split_eq_preds();
+        if left_on.is_empty() {
+            return None;
+        }
+        let mut total_domain = 1usize;
+        for (left_key, right_key) in left_on.iter().zip(right_on.iter()) {
+            left_stats.column_stats_for(left_key)?;
+            right_stats.column_stats_for(right_key)?;
+            let domain = left_stats
+                .approx_distinct_count(left_key.name())?
+                .max(right_stats.approx_distinct_count(right_key.name())?);
+            total_domain = total_domain.saturating_mul(domain);
+        }
+        let left_rows = left_stats.approx_stats.num_rows;
+        let right_rows = right_stats.approx_stats.num_rows;
+        let matched_rows = left_rows.saturating_mul(right_rows) / total_domain.max(1);
+        Some(match self.join_type {
+            JoinType::Inner => matched_rows,
+            JoinType::Left => matched_rows.max(left_rows),
+            JoinType::Right => matched_rows.max(right_rows),
+            JoinType::Outer => matched_rows.max(left_rows).max(right_rows),
+            // Each left row matches with probability NDV(r) / max(NDV(l), NDV(r)), which we approximate by bounding
+            // the matched rows by the left cardinality.
+            JoinType::Semi => matched_rows.min(left_rows),
+            JoinType::Anti => left_rows - matched_rows.min(left_rows),
+        })
+    }
+
+    // Carries over the column stats of both sides to the join's output columns. When a column exists on both sides
+    // (i.e. a merged join key), the stats with the smaller distinct count are used.
+    fn output_column_stats(
+        &self,
+        left_stats: &PlanStats,
+        right_stats: &PlanStats,
+        num_rows: usize,
+    ) -> HashMap<String, ColumnStats> {
+        let left_column_stats = left_stats.bounded_column_stats(num_rows);
+        let right_column_stats = match self.join_type {
+            JoinType::Anti | JoinType::Semi => HashMap::new(),
+            _ => right_stats.bounded_column_stats(num_rows),
+        };
+        self.output_schema
+            .field_names()
+            .filter_map(|name| {
+                let stats = match (left_column_stats.get(name), right_column_stats.get(name)) {
+                    (Some(left), Some(right)) => {
+                        if left.approx_distinct_count <= right.approx_distinct_count {
+                            left
+                        } else {
+                            right
+                        }
+                    }
+                    (Some(stats), None) | (None, Some(stats)) => stats,
+                    (None, None) => return None,
+                };
+                Some((name.to_string(), stats.clone()))
+            })
+            .collect()
+    }
+
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
         res.push(format!("Join: Type = {}", self.join_type));
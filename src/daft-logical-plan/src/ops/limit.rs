@@ -57,7 +57,12 @@ impl Limit {
             },
             acc_selectivity: input_stats.approx_stats.acc_selectivity * limit_selectivity,
         };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        let column_stats = input_stats.bounded_column_stats(approx_stats.num_rows);
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(approx_stats)
+                .with_column_stats(column_stats)
+                .into(),
+        );
         self
     }
 
@@ -1,9 +1,12 @@
 mod actor_pool_project;
 mod agg;
+mod analyze;
+mod asof_join;
 mod concat;
 mod distinct;
 mod explode;
 mod filter;
+mod grouping_sets;
 pub mod join;
 mod limit;
 mod monotonically_increasing_id;
@@ -21,10 +24,13 @@ mod window;
 
 pub use actor_pool_project::ActorPoolProject;
 pub use agg::Aggregate;
+pub use analyze::analyze;
+pub use asof_join::AsofJoin;
 pub use concat::Concat;
 pub use distinct::Distinct;
 pub use explode::Explode;
 pub use filter::Filter;
+pub use grouping_sets::{cube, grouping_sets, rollup, GROUPING_ID_COLUMN};
 pub use join::Join;
 pub use limit::Limit;
 pub use monotonically_increasing_id::MonotonicallyIncreasingId;
@@ -42,7 +42,8 @@ impl Pivot {
         };
 
         let output_schema = {
-            let value_col_dtype = value_column.to_field(&input.schema())?.dtype;
+            // the pivoted columns hold the aggregated values
+            let value_col_dtype = aggregation.to_field(&input.schema())?.dtype;
             let pivot_value_fields = names.iter().map(|f| Field::new(f, value_col_dtype.clone()));
 
             let group_by_fields = group_by
@@ -99,3 +100,37 @@ impl Pivot {
         res
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::unresolved_col;
+
+    use crate::test::{dummy_scan_node, dummy_scan_operator};
+
+    #[test]
+    fn pivot_schema_takes_aggregation_type() -> DaftResult<()> {
+        let scan = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("group", DataType::Utf8),
+            Field::new("pivot", DataType::Int64),
+            Field::new("value", DataType::Int64),
+        ]));
+        let plan = scan.pivot(
+            vec![unresolved_col("group")],
+            unresolved_col("pivot"),
+            unresolved_col("value"),
+            unresolved_col("value").mean(),
+            vec!["1".to_string(), "2".to_string()],
+        )?;
+        assert_eq!(
+            plan.schema().as_ref(),
+            &Schema::new(vec![
+                Field::new("group", DataType::Utf8),
+                Field::new("1", DataType::Float64),
+                Field::new("2", DataType::Float64),
+            ])
+        );
+        Ok(())
+    }
+}
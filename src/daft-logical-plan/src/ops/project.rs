@@ -4,14 +4,15 @@ use common_error::DaftResult;
 use common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
 use daft_core::prelude::*;
 use daft_dsl::{
-    optimization, resolved_col, AggExpr, ApproxPercentileParams, Column, Expr, ExprRef,
+    optimization, resolved_col, AggExpr, ApproxPercentileParams, CollectParams, Column, Expr,
+    ExprRef,
 };
 use indexmap::{IndexMap, IndexSet};
 use itertools::Itertools;
 
 use crate::{
     logical_plan::{self},
-    stats::StatsState,
+    stats::{PlanStats, StatsState},
     LogicalPlan,
 };
 
@@ -67,7 +68,24 @@ impl Project {
     pub(crate) fn with_materialized_stats(mut self) -> Self {
         // TODO(desmond): We can do better estimations with the projection schema. For now, reuse the old logic.
         let input_stats = self.input.materialized_stats();
-        self.stats_state = StatsState::Materialized(input_stats.clone().into());
+        // Carry over the column stats of columns that are passed through or renamed.
+        let column_stats = self
+            .projection
+            .iter()
+            .filter_map(|expr| {
+                let source = match expr.as_ref() {
+                    Expr::Alias(inner, _) => inner,
+                    _ => expr,
+                };
+                let stats = input_stats.column_stats_for(source)?;
+                Some((expr.name().to_string(), stats.clone()))
+            })
+            .collect();
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(input_stats.approx_stats.clone())
+                .with_column_stats(column_stats)
+                .into(),
+        );
         self
     }
 
@@ -505,6 +523,12 @@ fn replace_column_with_semantic_id_aggexpr(
             replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
                 .map_yes_no(AggExpr::ApproxCountDistinct, |_| e.clone())
         }
+        AggExpr::ApproxTopK(ref child, k) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema).map_yes_no(
+                |transformed_child| AggExpr::ApproxTopK(transformed_child, k),
+                |_| e,
+            )
+        }
         AggExpr::ApproxSketch(ref child, sketch_type) => {
             replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema).map_yes_no(
                 |transformed_child| AggExpr::ApproxSketch(transformed_child, sketch_type),
@@ -559,6 +583,22 @@ fn replace_column_with_semantic_id_aggexpr(
             replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
                 .map_yes_no(AggExpr::Concat, |_| e)
         }
+        AggExpr::Collect(ref params) => {
+            let child =
+                replace_column_with_semantic_id(params.child.clone(), subexprs_to_replace, schema);
+            let order_by = params.order_by.clone().map(|order_by| {
+                replace_column_with_semantic_id(order_by, subexprs_to_replace, schema)
+            });
+            if !child.transformed && order_by.as_ref().is_none_or(|e| !e.transformed) {
+                Transformed::no(e)
+            } else {
+                Transformed::yes(AggExpr::Collect(CollectParams {
+                    child: child.data,
+                    order_by: order_by.map(|e| e.data),
+                    ..params.clone()
+                }))
+            }
+        }
         AggExpr::MapGroups { func, inputs } => {
             let transforms = inputs
                 .iter()
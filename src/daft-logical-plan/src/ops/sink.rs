@@ -42,13 +42,25 @@ impl Sink {
             #[cfg(feature = "python")]
             SinkInfo::CatalogInfo(catalog_info) => {
                 match catalog_info.catalog {
-                    CatalogType::Iceberg(_) => {
-                        vec![
-                            // We have to return datafile since PyIceberg Table is not picklable yet
-                            Field::new("data_file", DataType::Python),
-                        ]
+                    CatalogType::Iceberg(ref iceberg_info) => {
+                        if iceberg_info.native_write.is_some() {
+                            // The native writer returns its data files serialized as JSON
+                            vec![Field::new("data_file", DataType::Utf8)]
+                        } else {
+                            vec![
+                                // We have to return datafile since PyIceberg Table is not picklable yet
+                                Field::new("data_file", DataType::Python),
+                            ]
+                        }
+                    }
+                    CatalogType::DeltaLake(ref deltalake_info) => {
+                        if deltalake_info.native_write.is_some() {
+                            // The native writer returns its add actions serialized as JSON
+                            vec![Field::new("add_action", DataType::Utf8)]
+                        } else {
+                            vec![Field::new("add_action", DataType::Python)]
+                        }
                     }
-                    CatalogType::DeltaLake(_) => vec![Field::new("add_action", DataType::Python)],
                     CatalogType::Lance(_) => vec![Field::new("fragments", DataType::Python)],
                 }
             }
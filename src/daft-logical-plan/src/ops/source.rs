@@ -1,12 +1,12 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use common_error::DaftResult;
-use common_scan_info::{PhysicalScanInfo, ScanState};
+use common_scan_info::{PhysicalScanInfo, Pushdowns, ScanState, SourceStatistics};
 use daft_schema::schema::SchemaRef;
 
 use crate::{
     source_info::{InMemoryInfo, PlaceHolderInfo, SourceInfo},
-    stats::{ApproxStats, PlanStats, StatsState},
+    stats::{ApproxStats, ColumnStats, PlanStats, StatsState},
 };
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -94,10 +94,68 @@ impl Source {
             },
             SourceInfo::PlaceHolder(_) => ApproxStats::empty(),
         };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        let stats = match &*self.source_info {
+            SourceInfo::Physical(PhysicalScanInfo {
+                statistics: Some(statistics),
+                pushdowns,
+                ..
+            }) => self.analyzed_stats(statistics, pushdowns, &approx_stats),
+            _ => PlanStats::new(approx_stats),
+        };
+        self.stats_state = StatsState::Materialized(stats.into());
         self
     }
 
+    // Computes the stats of this source from its analyzed statistics, which are exact, so we prefer them over the
+    // estimates from scan task metadata. The scan task estimates are only used for the average row size.
+    fn analyzed_stats(
+        &self,
+        statistics: &SourceStatistics,
+        pushdowns: &Pushdowns,
+        estimated_stats: &ApproxStats,
+    ) -> PlanStats {
+        let column_stats = self
+            .output_schema
+            .field_names()
+            .filter_map(|name| {
+                let stats = statistics.columns.get(name)?;
+                Some((
+                    name.to_string(),
+                    ColumnStats {
+                        approx_distinct_count: stats.approx_distinct_count,
+                        null_fraction: statistics.null_fraction(name)?,
+                        min: stats.min.clone(),
+                        max: stats.max.clone(),
+                    },
+                ))
+            })
+            .collect::<HashMap<_, _>>();
+        let unfiltered_stats = PlanStats::new(ApproxStats {
+            num_rows: statistics.num_rows,
+            ..ApproxStats::empty()
+        })
+        .with_column_stats(column_stats);
+        let selectivity = pushdowns.filters.as_ref().map_or(1.0, |filters| {
+            unfiltered_stats.estimated_selectivity(filters, self.output_schema.as_ref())
+        });
+        let mut num_rows = (statistics.num_rows as f64 * selectivity).ceil() as usize;
+        if let Some(limit) = pushdowns.limit {
+            num_rows = num_rows.min(limit);
+        }
+        if let Some(sample) = &pushdowns.sample {
+            num_rows = (num_rows as f64 * sample.fraction.0).ceil() as usize;
+        }
+        let est_bytes_per_row =
+            estimated_stats.size_bytes as f64 / estimated_stats.num_rows.max(1) as f64;
+        let approx_stats = ApproxStats {
+            num_rows,
+            size_bytes: (num_rows as f64 * est_bytes_per_row).ceil() as usize,
+            acc_selectivity: selectivity,
+        };
+        let column_stats = unfiltered_stats.bounded_column_stats(num_rows);
+        PlanStats::new(approx_stats).with_column_stats(column_stats)
+    }
+
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
 
@@ -107,6 +165,7 @@ impl Source {
                 scan_state,
                 partitioning_keys,
                 pushdowns,
+                statistics,
             }) => {
                 use itertools::Itertools;
                 res.extend(scan_state.multiline_display());
@@ -117,10 +176,20 @@ impl Source {
                     partitioning_keys.iter().map(|k| format!("{k}")).join(" ")
                 ));
                 res.extend(pushdowns.multiline_display());
+                if let Some(statistics) = statistics {
+                    res.extend(statistics.multiline_display());
+                }
             }
-            SourceInfo::InMemory(InMemoryInfo { num_partitions, .. }) => {
+            SourceInfo::InMemory(InMemoryInfo {
+                num_partitions,
+                persisted,
+                ..
+            }) => {
                 res.push("Source:".to_string());
                 res.push(format!("Number of partitions = {}", num_partitions));
+                if *persisted {
+                    res.push("Persisted = cache hit".to_string());
+                }
             }
             SourceInfo::PlaceHolder(PlaceHolderInfo {
                 clustering_spec, ..
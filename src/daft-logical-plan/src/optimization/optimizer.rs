@@ -6,10 +6,10 @@ use common_treenode::Transformed;
 use super::{
     logical_plan_tracker::LogicalPlanTracker,
     rules::{
-        DetectMonotonicId, DropRepartition, EliminateCrossJoin, EliminateSubqueryAliasRule,
-        EnrichWithStats, ExtractWindowFunction, FilterNullJoinKey, LiftProjectFromAgg,
-        MaterializeScans, OptimizerRule, PushDownAntiSemiJoin, PushDownFilter,
-        PushDownJoinPredicate, PushDownLimit, PushDownProjection, ReorderJoins,
+        DetectMonotonicId, DropRepartition, EliminateCommonSubexpressions, EliminateCrossJoin,
+        EliminateSubqueryAliasRule, EnrichWithStats, ExtractWindowFunction, FilterNullJoinKey,
+        LiftProjectFromAgg, MaterializeScans, OptimizerRule, PushDownAntiSemiJoin, PushDownFilter,
+        PushDownJoinPredicate, PushDownLimit, PushDownProjection, PushDownSample, ReorderJoins,
         SimplifyExpressionsRule, SimplifyNullFilteredJoin, SplitActorPoolProjects,
         UnnestPredicateSubquery, UnnestScalarSubquery,
     },
@@ -91,86 +91,108 @@ pub struct OptimizerBuilder {
 
 impl Default for OptimizerBuilder {
     fn default() -> Self {
+        Self::with_default_rules(true)
+    }
+}
+
+impl OptimizerBuilder {
+    /// The default rules, which materialize the scans of the plan into scan tasks unless
+    /// `materialize_scans` is false, in which case its sources still refer to their scan operators,
+    /// e.g. to serialize the optimized plan.
+    pub fn with_default_rules(materialize_scans: bool) -> Self {
+        let mut rule_batches = vec![
+            // --- Rewrite rules ---
+            RuleBatch::new(
+                vec![
+                    Box::new(LiftProjectFromAgg::new()),
+                    Box::new(UnnestScalarSubquery::new()),
+                    Box::new(UnnestPredicateSubquery::new()),
+                    Box::new(EliminateSubqueryAliasRule::new()),
+                    Box::new(SplitActorPoolProjects::new()),
+                    Box::new(DetectMonotonicId::new()),
+                    Box::new(ExtractWindowFunction::new()),
+                ],
+                RuleExecutionStrategy::FixedPoint(None),
+            ),
+            // we want to simplify expressions first to make the rest of the rules easier
+            RuleBatch::new(
+                vec![Box::new(SimplifyExpressionsRule::new())],
+                RuleExecutionStrategy::FixedPoint(None),
+            ),
+            // --- Filter out null join keys ---
+            // This rule should be run once, before any filter pushdown rules.
+            RuleBatch::new(
+                vec![Box::new(FilterNullJoinKey::new())],
+                RuleExecutionStrategy::Once,
+            ),
+            // --- Anti/semi join pushdowns ---
+            // This needs to be separate from PushDownProjection and PushDownFilter
+            // because otherwise they will just keep swapping places.
+            // We ultimately do want filters to go before joins, so we run this rule before PushDownFilter.
+            RuleBatch::new(
+                vec![Box::new(PushDownAntiSemiJoin::new())],
+                RuleExecutionStrategy::FixedPoint(None),
+            ),
+            // --- Bulk of our rules ---
+            RuleBatch::new(
+                vec![
+                    Box::new(DropRepartition::new()),
+                    Box::new(PushDownFilter::new()),
+                    Box::new(PushDownProjection::new()),
+                    Box::new(EliminateCrossJoin::new()),
+                    Box::new(SimplifyNullFilteredJoin::new()),
+                    Box::new(PushDownJoinPredicate::new()),
+                ],
+                // Use a fixed-point policy for the pushdown rules: PushDownProjection can produce a Filter node
+                // at the current node, which would require another batch application in order to have a chance to push
+                // that Filter node through upstream nodes.
+                // TODO(Clark): Refine this fixed-point policy.
+                RuleExecutionStrategy::FixedPoint(None),
+            ),
+            // --- Sample pushdowns ---
+            // This runs after filters are pushed into scans, since the sample has to be taken of the filtered rows.
+            RuleBatch::new(
+                vec![Box::new(PushDownSample::new())],
+                RuleExecutionStrategy::FixedPoint(Some(3)),
+            ),
+            // --- Limit pushdowns ---
+            // This needs to be separate from PushDownProjection because otherwise the limit and
+            // projection just keep swapping places, preventing optimization
+            // (see https://github.com/Eventual-Inc/Daft/issues/2616)
+            RuleBatch::new(
+                vec![Box::new(PushDownLimit::new())],
+                RuleExecutionStrategy::FixedPoint(Some(3)),
+            ),
+            // --- Simplify expressions before scans are materialized ---
+            RuleBatch::new(
+                vec![Box::new(SimplifyExpressionsRule::new())],
+                RuleExecutionStrategy::FixedPoint(None),
+            ),
+            // --- Compute repeated subexpressions once ---
+            // This runs after the pushdowns, once projections have been merged together.
+            RuleBatch::new(
+                vec![Box::new(EliminateCommonSubexpressions::new())],
+                RuleExecutionStrategy::Once,
+            ),
+        ];
+        // --- Materialize scan nodes ---
+        if materialize_scans {
+            rule_batches.push(RuleBatch::new(
+                vec![Box::new(MaterializeScans::new())],
+                RuleExecutionStrategy::Once,
+            ));
+        }
+        // --- Enrich logical plan with stats ---
+        rule_batches.push(RuleBatch::new(
+            vec![Box::new(EnrichWithStats::new())],
+            RuleExecutionStrategy::Once,
+        ));
         Self {
-            rule_batches: vec![
-                // --- Rewrite rules ---
-                RuleBatch::new(
-                    vec![
-                        Box::new(LiftProjectFromAgg::new()),
-                        Box::new(UnnestScalarSubquery::new()),
-                        Box::new(UnnestPredicateSubquery::new()),
-                        Box::new(EliminateSubqueryAliasRule::new()),
-                        Box::new(SplitActorPoolProjects::new()),
-                        Box::new(DetectMonotonicId::new()),
-                        Box::new(ExtractWindowFunction::new()),
-                    ],
-                    RuleExecutionStrategy::FixedPoint(None),
-                ),
-                // we want to simplify expressions first to make the rest of the rules easier
-                RuleBatch::new(
-                    vec![Box::new(SimplifyExpressionsRule::new())],
-                    RuleExecutionStrategy::FixedPoint(None),
-                ),
-                // --- Filter out null join keys ---
-                // This rule should be run once, before any filter pushdown rules.
-                RuleBatch::new(
-                    vec![Box::new(FilterNullJoinKey::new())],
-                    RuleExecutionStrategy::Once,
-                ),
-                // --- Anti/semi join pushdowns ---
-                // This needs to be separate from PushDownProjection and PushDownFilter
-                // because otherwise they will just keep swapping places.
-                // We ultimately do want filters to go before joins, so we run this rule before PushDownFilter.
-                RuleBatch::new(
-                    vec![Box::new(PushDownAntiSemiJoin::new())],
-                    RuleExecutionStrategy::FixedPoint(None),
-                ),
-                // --- Bulk of our rules ---
-                RuleBatch::new(
-                    vec![
-                        Box::new(DropRepartition::new()),
-                        Box::new(PushDownFilter::new()),
-                        Box::new(PushDownProjection::new()),
-                        Box::new(EliminateCrossJoin::new()),
-                        Box::new(SimplifyNullFilteredJoin::new()),
-                        Box::new(PushDownJoinPredicate::new()),
-                    ],
-                    // Use a fixed-point policy for the pushdown rules: PushDownProjection can produce a Filter node
-                    // at the current node, which would require another batch application in order to have a chance to push
-                    // that Filter node through upstream nodes.
-                    // TODO(Clark): Refine this fixed-point policy.
-                    RuleExecutionStrategy::FixedPoint(None),
-                ),
-                // --- Limit pushdowns ---
-                // This needs to be separate from PushDownProjection because otherwise the limit and
-                // projection just keep swapping places, preventing optimization
-                // (see https://github.com/Eventual-Inc/Daft/issues/2616)
-                RuleBatch::new(
-                    vec![Box::new(PushDownLimit::new())],
-                    RuleExecutionStrategy::FixedPoint(Some(3)),
-                ),
-                // --- Simplify expressions before scans are materialized ---
-                RuleBatch::new(
-                    vec![Box::new(SimplifyExpressionsRule::new())],
-                    RuleExecutionStrategy::FixedPoint(None),
-                ),
-                // --- Materialize scan nodes ---
-                RuleBatch::new(
-                    vec![Box::new(MaterializeScans::new())],
-                    RuleExecutionStrategy::Once,
-                ),
-                // --- Enrich logical plan with stats ---
-                RuleBatch::new(
-                    vec![Box::new(EnrichWithStats::new())],
-                    RuleExecutionStrategy::Once,
-                ),
-            ],
+            rule_batches,
             config: Default::default(),
         }
     }
-}
 
-impl OptimizerBuilder {
     pub fn new() -> Self {
         Self {
             rule_batches: vec![],
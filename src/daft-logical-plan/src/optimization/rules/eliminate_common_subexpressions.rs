@@ -0,0 +1,322 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use common_error::DaftResult;
+use common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
+use daft_dsl::{functions::FunctionExpr, resolved_col, Expr, ExprRef};
+use daft_schema::schema::Schema;
+
+use super::OptimizerRule;
+use crate::{
+    ops::{Filter, Project},
+    LogicalPlan,
+};
+
+/// The estimated cost of an expression from which it is worth computing it once into a column
+/// rather than recomputing it, since materializing a column isn't free either.
+const MIN_CSE_COST: usize = 2;
+
+/// The estimated cost of a Python UDF call, which is much more expensive than a native expression.
+const PYTHON_UDF_COST: usize = 10;
+
+/// Optimization rule for computing subexpressions that are repeated within a filter predicate, or
+/// shared between a filter and the projection above it, only once.
+///
+/// Repeated subexpressions within a single projection are already factored out when the
+/// projection is created, but filters can't be split that way. Here, the repeated subexpressions
+/// are computed into columns by a projection below the filter, which the filter and the
+/// projection above it then refer to instead.
+///
+/// Only subexpressions whose estimated cost reaches [`MIN_CSE_COST`] are computed once, so that
+/// trivial ones such as `a + 1` aren't materialized. Subexpressions that don't refer to any column,
+/// such as `uuid()`, are never computed once since they may not be deterministic.
+///
+/// # Example
+/// Input: `Project [f(a) * 2] <- Filter [f(a) > 0] <- Scan`
+///
+/// Output: `Project [col(f(a)) * 2] <- Filter [col(f(a)) > 0] <- Project [a, f(a)] <- Scan`
+#[derive(Default, Debug)]
+pub struct EliminateCommonSubexpressions {}
+
+impl EliminateCommonSubexpressions {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for EliminateCommonSubexpressions {
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        plan.transform_down(|node| match node.as_ref() {
+            LogicalPlan::Project(project) => match project.input.as_ref() {
+                LogicalPlan::Filter(filter) => Self::eliminate(node.clone(), filter, Some(project)),
+                _ => Ok(Transformed::no(node)),
+            },
+            LogicalPlan::Filter(filter) => Self::eliminate(node.clone(), filter, None),
+            _ => Ok(Transformed::no(node)),
+        })
+    }
+}
+
+impl EliminateCommonSubexpressions {
+    /// Rewrites `node`, a filter or a projection over it, to compute the subexpressions of the
+    /// filter's predicate that are repeated within the node once.
+    fn eliminate(
+        node: Arc<LogicalPlan>,
+        filter: &Filter,
+        project: Option<&Project>,
+    ) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        let schema = filter.input.schema();
+        let projection = project.map_or(&[][..], |project| project.projection.as_slice());
+        let exprs = std::iter::once(&filter.predicate)
+            .chain(projection)
+            .collect::<Vec<_>>();
+
+        let repeated = count_subexprs(&exprs, is_cse_candidate, false)
+            .into_iter()
+            .filter(|(e, count)| *count > 1 && !schema.has_field(&cse_name(e, &schema)))
+            .map(|(e, _)| e)
+            .collect::<HashSet<_>>();
+        // Of the repeated subexpressions, only compute the outermost ones once, leaving out those
+        // that are only repeated within them, and only the ones that the filter needs.
+        let is_repeated = |e: &ExprRef| repeated.contains(e);
+        let in_predicate = count_subexprs(&[&filter.predicate], is_repeated, true);
+        let mut cses = count_subexprs(&exprs, is_repeated, true)
+            .into_iter()
+            .filter(|(e, count)| *count > 1 && in_predicate.contains_key(e))
+            .map(|(e, _)| e)
+            .collect::<Vec<_>>();
+        if cses.is_empty() {
+            return Ok(Transformed::no(node));
+        }
+        // keep the computed columns in a deterministic order
+        cses.sort_by_cached_key(|e| cse_name(e, &schema));
+
+        let input = with_columns(filter.input.clone(), &cses, &schema)?;
+        let predicate = replace(filter.predicate.clone(), &cses, &schema)?;
+        let plan: Arc<LogicalPlan> = LogicalPlan::from(Filter::try_new(input, predicate)?).into();
+
+        let output = match project {
+            Some(project) => project
+                .projection
+                .iter()
+                .map(|e| {
+                    let new_expr = replace(e.clone(), &cses, &schema)?;
+                    Ok(if new_expr.name() == e.name() {
+                        new_expr
+                    } else {
+                        new_expr.alias(e.name())
+                    })
+                })
+                .collect::<DaftResult<Vec<_>>>()?,
+            // drop the computed columns to keep the filter's schema
+            None => schema.field_names().map(resolved_col).collect(),
+        };
+        Ok(Transformed::yes(
+            LogicalPlan::from(Project::try_new(plan, output)?).into(),
+        ))
+    }
+}
+
+/// The name of the column that a repeated subexpression is computed into.
+fn cse_name(expr: &ExprRef, schema: &Schema) -> String {
+    expr.semantic_id(schema).id.to_string()
+}
+
+/// Projects the columns of `input` along with the given subexpressions of them.
+fn with_columns(
+    input: Arc<LogicalPlan>,
+    exprs: &[ExprRef],
+    schema: &Schema,
+) -> DaftResult<Arc<LogicalPlan>> {
+    let projection = input
+        .schema()
+        .field_names()
+        .map(resolved_col)
+        .chain(exprs.iter().map(|e| e.alias(cse_name(e, schema))))
+        .collect();
+    Ok(LogicalPlan::from(Project::try_new(input, projection)?).into())
+}
+
+/// Replaces the outermost occurrences of `exprs` within `expr` with their columns.
+fn replace(expr: ExprRef, exprs: &[ExprRef], schema: &Schema) -> DaftResult<ExprRef> {
+    Ok(expr
+        .transform_down(|e| {
+            if exprs.contains(&e) {
+                Ok(Transformed::new(
+                    resolved_col(cse_name(&e, schema)),
+                    true,
+                    TreeNodeRecursion::Jump,
+                ))
+            } else {
+                Ok(Transformed::no(e))
+            }
+        })?
+        .data)
+}
+
+/// Counts the occurrences of the subexpressions matching `matches` within `exprs`. With
+/// `outermost`, subexpressions of the matching ones aren't counted.
+fn count_subexprs(
+    exprs: &[&ExprRef],
+    matches: impl Fn(&ExprRef) -> bool,
+    outermost: bool,
+) -> HashMap<ExprRef, usize> {
+    let mut counts = HashMap::new();
+    for expr in exprs {
+        expr.apply(|e| {
+            if matches(e) {
+                *counts.entry(e.clone()).or_default() += 1;
+                if outermost {
+                    return Ok(TreeNodeRecursion::Jump);
+                }
+            }
+            Ok(TreeNodeRecursion::Continue)
+        })
+        .expect("counting subexpressions doesn't fail");
+    }
+    counts
+}
+
+/// Whether `expr` is worth computing only once if it's repeated.
+fn is_cse_candidate(expr: &ExprRef) -> bool {
+    if matches!(expr.as_ref(), Expr::Alias(..)) {
+        return false;
+    }
+    let mut cost = 0;
+    let mut has_column = false;
+    let mut computable = true;
+    expr.apply(|e| {
+        cost += match e.as_ref() {
+            Expr::Column(_) => {
+                has_column = true;
+                0
+            }
+            Expr::Literal(_) | Expr::Alias(..) => 0,
+            Expr::Function {
+                func: FunctionExpr::Python(_),
+                ..
+            } => PYTHON_UDF_COST,
+            Expr::Function { .. } | Expr::ScalarFunction(_) => 2,
+            Expr::Agg(_)
+            | Expr::Over(..)
+            | Expr::WindowFunction(_)
+            | Expr::Subquery(_)
+            | Expr::InSubquery(..)
+            | Expr::Exists(_) => {
+                computable = false;
+                return Ok(TreeNodeRecursion::Stop);
+            }
+            _ => 1,
+        };
+        Ok(TreeNodeRecursion::Continue)
+    })
+    .expect("estimating the cost of an expression doesn't fail");
+    computable && has_column && cost >= MIN_CSE_COST
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use common_scan_info::ScanOperatorRef;
+    use daft_dsl::{lit, unresolved_col};
+    use daft_schema::{dtype::DataType, field::Field};
+
+    use super::EliminateCommonSubexpressions;
+    use crate::{
+        optimization::{
+            optimizer::{RuleBatch, RuleExecutionStrategy},
+            test::assert_optimized_plan_with_rules_eq,
+        },
+        test::{dummy_scan_node, dummy_scan_operator},
+        LogicalPlan,
+    };
+
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(
+            plan,
+            expected,
+            vec![RuleBatch::new(
+                vec![Box::new(EliminateCommonSubexpressions::new())],
+                RuleExecutionStrategy::Once,
+            )],
+        )
+    }
+
+    fn scan_op() -> ScanOperatorRef {
+        dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ])
+    }
+
+    #[test]
+    fn repeated_subexpression_in_filter() -> DaftResult<()> {
+        let shared = unresolved_col("a").add(lit(1)).mul(unresolved_col("b"));
+        let scan_op = scan_op();
+        let plan = dummy_scan_node(scan_op.clone())
+            .filter(shared.clone().gt(lit(0)).and(shared.clone().lt(lit(10))))?
+            .build();
+
+        let id = shared
+            .semantic_id(&dummy_scan_node(scan_op.clone()).schema())
+            .id;
+        let expected = dummy_scan_node(scan_op)
+            .select(vec![
+                unresolved_col("a"),
+                unresolved_col("b"),
+                shared.alias(id.clone()),
+            ])?
+            .filter(
+                unresolved_col(id.clone())
+                    .gt(lit(0))
+                    .and(unresolved_col(id).lt(lit(10))),
+            )?
+            .select(vec![unresolved_col("a"), unresolved_col("b")])?
+            .build();
+
+        assert_optimized_plan_eq(plan, expected)
+    }
+
+    #[test]
+    fn shared_with_filter_is_computed_below_it() -> DaftResult<()> {
+        let shared = unresolved_col("a").add(lit(1)).mul(unresolved_col("b"));
+        let scan_op = scan_op();
+        let plan = dummy_scan_node(scan_op.clone())
+            .filter(shared.clone().gt(lit(0)))?
+            .select(vec![shared.alias("x")])?
+            .build();
+
+        let id = shared
+            .semantic_id(&dummy_scan_node(scan_op.clone()).schema())
+            .id;
+        let expected = dummy_scan_node(scan_op)
+            .select(vec![
+                unresolved_col("a"),
+                unresolved_col("b"),
+                shared.alias(id.clone()),
+            ])?
+            .filter(unresolved_col(id.clone()).gt(lit(0)))?
+            .select(vec![unresolved_col(id).alias("x")])?
+            .build();
+
+        assert_optimized_plan_eq(plan, expected)
+    }
+
+    #[test]
+    fn trivial_subexpressions_are_not_computed_once() -> DaftResult<()> {
+        let trivial = unresolved_col("a").add(lit(1));
+        let plan = dummy_scan_node(scan_op())
+            .filter(trivial.clone().gt(lit(0)))?
+            .select(vec![trivial.alias("x")])?
+            .build();
+
+        assert_optimized_plan_eq(plan.clone(), plan)
+    }
+}
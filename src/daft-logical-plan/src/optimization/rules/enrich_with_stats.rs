@@ -28,3 +28,120 @@ impl OptimizerRule for EnrichWithStats {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use common_scan_info::{ColumnStatistics, SourceStatistics};
+    use common_treenode::TransformedResult;
+    use daft_dsl::{lit, unresolved_col, LiteralValue};
+    use daft_schema::{dtype::DataType, field::Field};
+
+    use super::EnrichWithStats;
+    use crate::{
+        optimization::rules::{MaterializeScans, OptimizerRule},
+        test::{dummy_scan_node, dummy_scan_operator_with_size},
+        LogicalPlanBuilder, LogicalPlanRef,
+    };
+
+    // Creates a scan node over `num_rows` rows whose columns have the given distinct counts and value ranges.
+    fn analyzed_scan_node(
+        num_rows: usize,
+        columns: &[(&str, usize, i64, i64)],
+    ) -> LogicalPlanBuilder {
+        let fields = columns
+            .iter()
+            .map(|(name, ..)| Field::new(*name, DataType::Int64))
+            .collect();
+        let statistics = columns
+            .iter()
+            .map(|(name, approx_distinct_count, min, max)| {
+                (
+                    (*name).to_string(),
+                    ColumnStatistics {
+                        null_count: 0,
+                        approx_distinct_count: *approx_distinct_count,
+                        min: Some(LiteralValue::Int64(*min)),
+                        max: Some(LiteralValue::Int64(*max)),
+                    },
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+        dummy_scan_node(dummy_scan_operator_with_size(fields, Some(num_rows)))
+            .with_source_statistics(SourceStatistics::new(num_rows, statistics))
+            .unwrap()
+    }
+
+    fn enrich_with_stats(plan: LogicalPlanRef) -> LogicalPlanRef {
+        let plan = MaterializeScans::new().try_optimize(plan).data().unwrap();
+        EnrichWithStats::new().try_optimize(plan).data().unwrap()
+    }
+
+    #[test]
+    fn filter_selectivity_uses_column_stats() {
+        let scan = analyzed_scan_node(1_000, &[("a", 100, 0, 1_000), ("b", 10, 0, 9)]);
+        let eq_plan = enrich_with_stats(
+            scan.filter(unresolved_col("a").eq(lit(10)))
+                .unwrap()
+                .build(),
+        );
+        assert_eq!(eq_plan.materialized_stats().approx_stats.num_rows, 10);
+        let range_plan = enrich_with_stats(
+            scan.filter(
+                unresolved_col("a")
+                    .lt(lit(250))
+                    .and(unresolved_col("b").eq(lit(1))),
+            )
+            .unwrap()
+            .build(),
+        );
+        // The conjuncts are assumed to be independent: 1000 * 0.25 * 0.1 = 25.
+        assert_eq!(range_plan.materialized_stats().approx_stats.num_rows, 25);
+    }
+
+    #[test]
+    fn join_cardinality_uses_distinct_counts() {
+        let left = analyzed_scan_node(1_000, &[("a", 100, 0, 99)]);
+        let right = analyzed_scan_node(500, &[("b", 50, 0, 49)]);
+        let plan = enrich_with_stats(
+            left.inner_join(right, unresolved_col("a").eq(unresolved_col("b")))
+                .unwrap()
+                .build(),
+        );
+        // |L| * |R| / max(NDV(a), NDV(b)) = 1000 * 500 / 100.
+        let stats = plan.materialized_stats();
+        assert_eq!(stats.approx_stats.num_rows, 5_000);
+        assert_eq!(stats.approx_distinct_count("b"), Some(50));
+    }
+
+    #[test]
+    fn aggregate_num_groups_uses_distinct_counts() {
+        let scan = analyzed_scan_node(
+            1_000,
+            &[("a", 20, 0, 19), ("b", 3, 0, 2), ("c", 1_000, 0, 999)],
+        );
+        let plan = enrich_with_stats(
+            scan.aggregate(
+                vec![unresolved_col("c").sum()],
+                vec![unresolved_col("a"), unresolved_col("b")],
+            )
+            .unwrap()
+            .build(),
+        );
+        let stats = plan.materialized_stats();
+        assert_eq!(stats.approx_stats.num_rows, 60);
+        assert_eq!(stats.approx_distinct_count("a"), Some(20));
+        assert_eq!(stats.approx_distinct_count("c"), None);
+    }
+
+    #[test]
+    fn source_statistics_require_scan() {
+        let scan = analyzed_scan_node(1_000, &[("a", 100, 0, 999)]);
+        assert!(scan
+            .filter(unresolved_col("a").eq(lit(1)))
+            .unwrap()
+            .with_source_statistics(SourceStatistics::new(1_000, BTreeMap::new()))
+            .is_err());
+    }
+}
@@ -1,5 +1,6 @@
 mod detect_monotonic_id;
 mod drop_repartition;
+mod eliminate_common_subexpressions;
 mod eliminate_cross_join;
 mod eliminate_subquery_alias;
 mod enrich_with_stats;
@@ -12,6 +13,7 @@ mod push_down_filter;
 mod push_down_join_predicate;
 mod push_down_limit;
 mod push_down_projection;
+mod push_down_sample;
 mod reorder_joins;
 mod rule;
 mod simplify_expressions;
@@ -21,6 +23,7 @@ mod unnest_subquery;
 
 pub use detect_monotonic_id::DetectMonotonicId;
 pub use drop_repartition::DropRepartition;
+pub use eliminate_common_subexpressions::EliminateCommonSubexpressions;
 pub use eliminate_cross_join::EliminateCrossJoin;
 pub use eliminate_subquery_alias::EliminateSubqueryAliasRule;
 pub use enrich_with_stats::EnrichWithStats;
@@ -33,6 +36,7 @@ pub use push_down_filter::PushDownFilter;
 pub use push_down_join_predicate::PushDownJoinPredicate;
 pub use push_down_limit::PushDownLimit;
 pub use push_down_projection::PushDownProjection;
+pub use push_down_sample::PushDownSample;
 pub use reorder_joins::ReorderJoins;
 pub use rule::OptimizerRule;
 pub use simplify_expressions::SimplifyExpressionsRule;
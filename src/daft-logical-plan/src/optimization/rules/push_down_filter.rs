@@ -14,7 +14,7 @@ use daft_dsl::{
 
 use super::OptimizerRule;
 use crate::{
-    ops::{Concat, Filter, Join, Project, Source},
+    ops::{Concat, Explode, Filter, Join, Project, Source},
     source_info::SourceInfo,
     LogicalPlan,
 };
@@ -78,9 +78,10 @@ impl PushDownFilter {
                 match source.source_info.as_ref() {
                     // Filter pushdown is not supported for in-memory sources.
                     SourceInfo::InMemory(_) => return Ok(Transformed::no(plan)),
-                    // Do not pushdown if Source node already has a limit
+                    // Do not pushdown if Source node already has a limit or a sample
                     SourceInfo::Physical(external_info)
-                        if let Some(_) = external_info.pushdowns.limit =>
+                        if external_info.pushdowns.limit.is_some()
+                            || external_info.pushdowns.sample.is_some() =>
                     {
                         return Ok(Transformed::no(plan))
                     }
@@ -236,6 +237,43 @@ impl PushDownFilter {
                     post_projection_filter.into()
                 }
             }
+            LogicalPlan::Explode(Explode {
+                input, to_explode, ..
+            }) => {
+                // Commute filter with explode if predicate only depends on columns that aren't exploded.
+                //
+                // Filter-Explode --> {Filter-}Explode-Filter
+                let exploded_columns = to_explode
+                    .iter()
+                    .map(|e| e.name().to_string())
+                    .collect::<HashSet<_>>();
+                let input_schema = input.schema();
+                let (can_push, can_not_push): (Vec<ExprRef>, Vec<ExprRef>) =
+                    split_conjunction(&filter.predicate)
+                        .into_iter()
+                        .partition(|predicate| {
+                            get_required_columns(predicate).iter().all(|col| {
+                                input_schema.has_field(col) && !exploded_columns.contains(col)
+                            })
+                        });
+                if can_push.is_empty() {
+                    // No predicate expressions can be pushed through explode.
+                    return Ok(Transformed::no(plan));
+                }
+                // Create new Filter with predicates that can be pushed past Explode.
+                let push_down_filter: LogicalPlan =
+                    Filter::try_new(input.clone(), combine_conjunction(can_push).unwrap())?.into();
+                let new_explode = child_plan.with_new_children(&[push_down_filter.into()]);
+                if can_not_push.is_empty() {
+                    new_explode.into()
+                } else {
+                    // Keep the predicate expressions on exploded columns after the Explode.
+                    let post_explode_predicate = combine_conjunction(can_not_push).unwrap();
+                    let post_explode_filter: LogicalPlan =
+                        Filter::try_new(new_explode.into(), post_explode_predicate)?.into();
+                    post_explode_filter.into()
+                }
+            }
             LogicalPlan::Sort(_) | LogicalPlan::Repartition(_) => {
                 // Naive commuting with unary ops.
                 let new_filter = plan
@@ -535,6 +573,79 @@ mod tests {
         Ok(())
     }
 
+    /// Tests that Filter commutes with Explode if the predicate doesn't depend on exploded columns.
+    #[rstest]
+    fn filter_commutes_with_explode(#[values(false, true)] push_into_scan: bool) -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::List(Box::new(DataType::Int64))),
+            Field::new("b", DataType::Utf8),
+        ]);
+        let scan_plan = dummy_scan_node_with_pushdowns(
+            scan_op.clone(),
+            Pushdowns::default().with_limit(if push_into_scan { None } else { Some(1) }),
+        );
+        let pred = resolved_col("b").eq(lit("foo"));
+        let plan = scan_plan
+            .explode(vec![resolved_col("a")], false)?
+            .filter(pred.clone())?
+            .build();
+        let expected_scan_filter = if push_into_scan {
+            dummy_scan_node_with_pushdowns(scan_op, Pushdowns::default().with_filters(Some(pred)))
+        } else {
+            scan_plan.filter(pred)?
+        };
+        let expected = expected_scan_filter
+            .explode(vec![resolved_col("a")], false)?
+            .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that only the predicates that don't depend on exploded columns are pushed through an Explode.
+    #[test]
+    fn filter_partially_commutes_with_explode() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::List(Box::new(DataType::Int64))),
+            Field::new("b", DataType::Utf8),
+        ]);
+        let exploded_pred = resolved_col("a").lt(lit(2));
+        let pushed_pred = resolved_col("b").eq(lit("foo"));
+        let plan = dummy_scan_node(scan_op.clone())
+            .explode(vec![resolved_col("a")], false)?
+            .filter(exploded_pred.clone().and(pushed_pred.clone()))?
+            .build();
+        let expected = dummy_scan_node_with_pushdowns(
+            scan_op,
+            Pushdowns::default().with_filters(Some(pushed_pred)),
+        )
+        .explode(vec![resolved_col("a")], false)?
+        .filter(exploded_pred)?
+        .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that Filter does not commute with Explode if the predicate depends on the fields of unnested structs.
+    #[test]
+    fn filter_does_not_commute_with_explode_on_unnested_fields() -> DaftResult<()> {
+        let plan = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new(
+                "a",
+                DataType::List(Box::new(DataType::Struct(vec![Field::new(
+                    "x",
+                    DataType::Int64,
+                )]))),
+            ),
+            Field::new("b", DataType::Utf8),
+        ]))
+        .explode(vec![resolved_col("a")], true)?
+        .filter(resolved_col("x").lt(lit(2)))?
+        .build();
+        let expected = plan.clone();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
     /// Tests that Filter commutes with Projection if projection expression involves deterministic compute.
     // REASON - No expression attribute indicating whether deterministic && (pure || idempotent).
     #[ignore]
@@ -280,6 +280,7 @@ mod tests {
             Default::default(),
             5,
             3,
+            false,
         )?
         .limit(5, false)?
         .build();
@@ -13,7 +13,7 @@ use itertools::Itertools;
 
 use super::OptimizerRule;
 use crate::{
-    ops::{ActorPoolProject, Aggregate, Join, Pivot, Project, Source},
+    ops::{ActorPoolProject, Aggregate, AsofJoin, Join, Pivot, Project, Source},
     source_info::SourceInfo,
     LogicalPlan, LogicalPlanRef,
 };
@@ -349,18 +349,23 @@ impl PushDownProjection {
             | LogicalPlan::Filter(..)
             | LogicalPlan::Sample(..)
             | LogicalPlan::Explode(..) => {
-                // Get required columns from projection and upstream.
+                let grand_upstream_plan = &upstream_plan.arc_children()[0];
+                let grand_upstream_schema = grand_upstream_plan.schema();
+
+                // Get required columns from projection and upstream. Columns that only exist
+                // downstream, such as the fields of structs unnested by an explode, come from
+                // a column that upstream already requires.
                 let combined_dependencies = plan
                     .required_columns()
                     .iter()
                     .flatten()
                     .chain(upstream_plan.required_columns().iter().flatten())
+                    .filter(|name| grand_upstream_schema.has_field(name))
                     .cloned()
                     .collect::<IndexSet<_>>();
 
                 // Skip optimization if no columns would be pruned.
-                let grand_upstream_plan = &upstream_plan.arc_children()[0];
-                let grand_upstream_columns = grand_upstream_plan.schema().names();
+                let grand_upstream_columns = grand_upstream_schema.names();
 
                 if grand_upstream_columns.len() == combined_dependencies.len() {
                     return Ok(Transformed::no(plan));
@@ -459,7 +464,8 @@ impl PushDownProjection {
                 Ok(new_plan)
             }
             LogicalPlan::Union(_) => unreachable!("Union should have been optimized away"),
-            LogicalPlan::Join(join) => {
+            LogicalPlan::Join(Join { left, right, .. })
+            | LogicalPlan::AsofJoin(AsofJoin { left, right, .. }) => {
                 // Get required columns from projection and both upstreams.
                 let [projection_dependencies] = &plan.required_columns()[..] else {
                     panic!()
@@ -502,13 +508,10 @@ impl PushDownProjection {
                     }
                 }
 
-                let new_left_upstream = maybe_project_upstream_input(
-                    &join.left,
-                    left_dependencies,
-                    projection_dependencies,
-                )?;
+                let new_left_upstream =
+                    maybe_project_upstream_input(left, left_dependencies, projection_dependencies)?;
                 let new_right_upstream = maybe_project_upstream_input(
-                    &join.right,
+                    right,
                     right_dependencies,
                     projection_dependencies,
                 )?;
@@ -568,9 +571,12 @@ impl PushDownProjection {
         let actor_pool_project_required_cols = &plan.required_columns()[0];
         if actor_pool_project_required_cols.len() < upstream_schema.names().len() {
             let new_subprojection: LogicalPlan = {
-                let pushdown_column_exprs = actor_pool_project_required_cols
-                    .iter()
-                    .map(|s| resolved_col(s.as_str()))
+                // Keep the required columns in the upstream's column order, so that the subprojection becomes a no-op
+                // once it is pushed into a scan.
+                let pushdown_column_exprs = upstream_schema
+                    .field_names()
+                    .filter(|name| actor_pool_project_required_cols.contains(*name))
+                    .map(resolved_col)
                     .collect::<Vec<_>>();
 
                 Project::try_new(upstream_plan.clone(), pushdown_column_exprs)?.into()
@@ -883,6 +889,39 @@ mod tests {
         Ok(())
     }
 
+    /// Projection<-Explode with unnested structs: the fields of the struct are taken from the
+    /// exploded column rather than pushed down as columns of their own.
+    #[test]
+    fn test_projection_explode_unnest_structs() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new(
+                "a",
+                DataType::List(Box::new(DataType::Struct(vec![
+                    Field::new("x", DataType::Int64),
+                    Field::new("y", DataType::Int64),
+                ]))),
+            ),
+            Field::new("b", DataType::Int64),
+        ]);
+        let proj = vec![unresolved_col("x")];
+        let plan = dummy_scan_node(scan_op.clone())
+            .explode(vec![unresolved_col("a")], true)?
+            .select(proj.clone())?
+            .build();
+
+        let expected = dummy_scan_node_with_pushdowns(
+            scan_op,
+            Pushdowns::default().with_columns(Some(Arc::new(vec!["a".to_string()]))),
+        )
+        .explode(vec![unresolved_col("a")], true)?
+        .select(proj)?
+        .build();
+
+        assert_optimized_plan_eq(plan, expected)?;
+
+        Ok(())
+    }
+
     /// Projection<-Projection column pruning
     #[test]
     fn test_projection_projection() -> DaftResult<()> {
@@ -1036,6 +1075,97 @@ mod tests {
         Ok(())
     }
 
+    /// Projection<-ActorPoolProject only reads the columns that a multi-column UDF takes as arguments from a wide scan
+    #[test]
+    fn test_projection_pushdown_multi_column_udf_into_actorpoolproject() -> DaftResult<()> {
+        use crate::ops::{ActorPoolProject, Project};
+
+        let scan_op = dummy_scan_operator(
+            (0..200)
+                .map(|i| Field::new(format!("c{i}"), DataType::Int64))
+                .collect(),
+        );
+        let scan_node = dummy_scan_node(scan_op.clone()).build();
+        let mock_udf = create_actor_pool_udf(vec![resolved_col("c3"), resolved_col("c1")]);
+
+        let plan = LogicalPlan::ActorPoolProject(ActorPoolProject::try_new(
+            scan_node.clone(),
+            scan_node
+                .schema()
+                .field_names()
+                .map(resolved_col)
+                .chain(std::iter::once(mock_udf.alias("udf_results")))
+                .collect(),
+        )?)
+        .arced();
+        let plan = LogicalPlan::Project(Project::try_new(
+            plan,
+            vec![resolved_col("udf_results"), resolved_col("c0")],
+        )?)
+        .arced();
+
+        // The scan only reads the UDF's arguments along with the passthrough column, without an extra projection.
+        let expected = LogicalPlan::ActorPoolProject(ActorPoolProject::try_new(
+            dummy_scan_node_with_pushdowns(
+                scan_op,
+                Pushdowns::default().with_columns(Some(Arc::new(vec![
+                    "c0".to_string(),
+                    "c1".to_string(),
+                    "c3".to_string(),
+                ]))),
+            )
+            .build(),
+            vec![mock_udf.alias("udf_results"), resolved_col("c0")],
+        )?)
+        .arced();
+
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Projection<-Projection only reads the columns that a multi-column stateless UDF takes as arguments from a wide scan
+    #[test]
+    fn test_projection_pushdown_multi_column_udf() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(
+            (0..200)
+                .map(|i| Field::new(format!("c{i}"), DataType::Int64))
+                .collect(),
+        );
+        let scan_node = dummy_scan_node(scan_op.clone());
+        let udf = match create_actor_pool_udf(vec![resolved_col("c3"), resolved_col("c1")]).as_ref()
+        {
+            Expr::Function {
+                func: FunctionExpr::Python(python_udf),
+                inputs,
+            } => Expr::Function {
+                func: FunctionExpr::Python(PythonUDF {
+                    resource_request: None,
+                    concurrency: None,
+                    ..python_udf.clone()
+                }),
+                inputs: inputs.clone(),
+            }
+            .arced(),
+            _ => unreachable!(),
+        };
+
+        let plan = scan_node
+            .with_columns(vec![udf.clone().alias("udf_results")])?
+            .select(vec![resolved_col("udf_results")])?
+            .build();
+
+        let expected = dummy_scan_node_with_pushdowns(
+            scan_op,
+            Pushdowns::default()
+                .with_columns(Some(Arc::new(vec!["c3".to_string(), "c1".to_string()]))),
+        )
+        .select(vec![udf.alias("udf_results")])?
+        .build();
+
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
     /// Projection<-ActorPoolProject<-ActorPoolProject prunes columns from both ActorPoolProjects
     #[test]
     fn test_projection_pushdown_into_double_actorpoolproject() -> DaftResult<()> {
@@ -1087,7 +1217,7 @@ mod tests {
             )
             .build(),
             // col("b") is pruned
-            vec![mock_udf.alias("udf_results_0"), resolved_col("a")],
+            vec![resolved_col("a"), mock_udf.alias("udf_results_0")],
         )?)
         .arced();
         let expected = LogicalPlan::ActorPoolProject(ActorPoolProject::try_new(
@@ -1141,6 +1271,7 @@ mod tests {
                 partition_filters: None,
                 columns: Some(Arc::new(vec!["a".to_string()])),
                 filters: None,
+                sample: None,
             },
         )
         .build();
@@ -1185,6 +1316,7 @@ mod tests {
                     "Feb".to_string(),
                 ])),
                 filters: None,
+                sample: None,
             },
         )
         .build();
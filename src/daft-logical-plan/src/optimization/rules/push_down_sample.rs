@@ -0,0 +1,162 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_scan_info::{SamplePushdown, ScanState};
+use common_treenode::{DynTreeNode, Transformed, TreeNode};
+
+use super::OptimizerRule;
+use crate::{
+    ops::{Sample, Source},
+    source_info::SourceInfo,
+    LogicalPlan,
+};
+
+/// Optimization rules for pushing Samples into scans, so that readers can skip the data that is not sampled.
+#[derive(Default, Debug)]
+pub struct PushDownSample {}
+
+impl PushDownSample {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for PushDownSample {
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        plan.transform_down(|node| self.try_optimize_node(node))
+    }
+}
+
+impl PushDownSample {
+    #[allow(clippy::only_used_in_recursion)]
+    fn try_optimize_node(
+        &self,
+        plan: Arc<LogicalPlan>,
+    ) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        let LogicalPlan::Sample(Sample {
+            input,
+            fraction,
+            with_replacement: false,
+            seed,
+            ..
+        }) = plan.as_ref()
+        else {
+            return Ok(Transformed::no(plan));
+        };
+        match input.as_ref() {
+            // Sampling is row-wise, so it commutes with projections.
+            //
+            // Sample-Project -> Project-Sample
+            LogicalPlan::Project(_) => {
+                let new_sample = plan
+                    .with_new_children(&[input.arc_children()[0].clone()])
+                    .into();
+                Ok(Transformed::yes(
+                    input.with_new_children(&[new_sample]).into(),
+                ))
+            }
+            // Push sample into the source, which then takes it in place of the Sample node.
+            //
+            // Sample-Source -> Source[with_sample]
+            LogicalPlan::Source(source) => match source.source_info.as_ref() {
+                SourceInfo::Physical(external_info)
+                    if external_info.pushdowns.limit.is_none()
+                        && external_info.pushdowns.sample.is_none()
+                        && let ScanState::Operator(scan_op) = &external_info.scan_state
+                        && scan_op.0.can_absorb_sample() =>
+                {
+                    let new_pushdowns = external_info
+                        .pushdowns
+                        .with_sample(Some(SamplePushdown::new(*fraction, *seed)));
+                    let new_external_info = external_info.with_pushdowns(new_pushdowns);
+                    Ok(Transformed::yes(
+                        LogicalPlan::Source(Source::new(
+                            source.output_schema.clone(),
+                            SourceInfo::Physical(new_external_info).into(),
+                        ))
+                        .into(),
+                    ))
+                }
+                _ => Ok(Transformed::no(plan)),
+            },
+            _ => Ok(Transformed::no(plan)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use common_scan_info::{Pushdowns, SamplePushdown};
+    use daft_core::prelude::*;
+    use daft_dsl::resolved_col;
+
+    use crate::{
+        optimization::{
+            optimizer::{RuleBatch, RuleExecutionStrategy},
+            rules::PushDownSample,
+            test::assert_optimized_plan_with_rules_eq,
+        },
+        test::{dummy_scan_node, dummy_scan_node_with_pushdowns, dummy_scan_operator},
+        LogicalPlan,
+    };
+
+    /// Helper that creates an optimizer with the PushDownSample rule registered, optimizes
+    /// the provided plan with said optimizer, and compares the optimized plan with
+    /// the provided expected plan.
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(
+            plan,
+            expected,
+            vec![RuleBatch::new(
+                vec![Box::new(PushDownSample::new())],
+                RuleExecutionStrategy::Once,
+            )],
+        )
+    }
+
+    /// Tests that Sample pushes through a Project into an external Source.
+    ///
+    /// Sample-Project-Source -> Project-Source[with_sample]
+    #[test]
+    fn sample_pushes_into_external_source() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]);
+        let plan = dummy_scan_node(scan_op.clone())
+            .select(vec![resolved_col("a")])?
+            .sample(0.1, false, Some(42))?
+            .build();
+        let expected = dummy_scan_node_with_pushdowns(
+            scan_op,
+            Pushdowns::default().with_sample(Some(SamplePushdown::new(0.1, Some(42)))),
+        )
+        .select(vec![resolved_col("a")])?
+        .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that Sample does not push into a scan with a limit, or when sampling with replacement.
+    #[test]
+    fn sample_does_not_push_into_scan() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![Field::new("a", DataType::Int64)]);
+        let plan = dummy_scan_node_with_pushdowns(
+            scan_op.clone(),
+            Pushdowns::default().with_limit(Some(10)),
+        )
+        .sample(0.1, false, None)?
+        .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+
+        let plan = dummy_scan_node(scan_op).sample(0.1, true, None)?.build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+}
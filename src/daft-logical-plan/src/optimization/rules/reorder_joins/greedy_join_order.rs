@@ -0,0 +1,265 @@
+use super::join_graph::{JoinGraph, JoinOrderTree, JoinOrderer};
+
+// The greedy join orderer builds a (possibly bushy) join tree bottom-up by repeatedly joining the pair of
+// connected subtrees whose join produces the smallest estimated intermediate result, i.e. Greedy Operator Ordering.
+//
+// Unlike the brute force join orderer, this algorithm is not guaranteed to find the optimal join order. However,
+// it runs in polynomial time, which lets us reorder join graphs that are too large to enumerate exhaustively.
+pub(crate) struct GreedyJoinOrderer {}
+
+impl GreedyJoinOrderer {
+    // Finds the pair of subtrees with at least one join condition between them that yields the smallest estimated
+    // cardinality when joined. Returns the indices of the pair, along with the resulting joined subtree.
+    fn find_min_cardinality_join(
+        graph: &JoinGraph,
+        subtrees: &[JoinOrderTree],
+    ) -> Option<(usize, usize, JoinOrderTree)> {
+        let mut best: Option<(usize, usize, JoinOrderTree)> = None;
+        for i in 0..subtrees.len() {
+            for j in (i + 1)..subtrees.len() {
+                let left_cardinality = subtrees[i].get_cardinality();
+                let right_cardinality = subtrees[j].get_cardinality();
+                // Ensure that the left subtree always has the smaller cardinality.
+                let (left, right) = if left_cardinality > right_cardinality {
+                    (&subtrees[j], &subtrees[i])
+                } else {
+                    (&subtrees[i], &subtrees[j])
+                };
+                let (connections, total_domain) = graph.adj_list.get_connections(left, right);
+                if connections.is_empty() {
+                    continue;
+                }
+                // Estimate the cardinality of the join the same way the brute force orderer does, i.e. as the product of
+                // the cardinalities of both subtrees divided by the total domain of the join keys.
+                let cardinality = left_cardinality.saturating_mul(right_cardinality) / total_domain;
+                if best
+                    .as_ref()
+                    .is_some_and(|(_, _, tree)| tree.get_cardinality() <= cardinality)
+                {
+                    continue;
+                }
+                best = Some((
+                    i,
+                    j,
+                    left.clone().join(right.clone(), connections, cardinality),
+                ));
+            }
+        }
+        best
+    }
+}
+
+impl JoinOrderer for GreedyJoinOrderer {
+    fn order(&self, graph: &JoinGraph) -> JoinOrderTree {
+        let mut subtrees: Vec<JoinOrderTree> = (0..graph.adj_list.max_id)
+            .map(|id| {
+                let plan = graph
+                    .adj_list
+                    .id_to_plan
+                    .get(&id)
+                    .expect("Got non-existent ID in join graph");
+                let stats = plan.materialized_stats();
+                JoinOrderTree::Relation(id, stats.approx_stats.num_rows)
+            })
+            .collect();
+        while subtrees.len() > 1 {
+            let Some((i, j, joined)) = Self::find_min_cardinality_join(graph, &subtrees) else {
+                panic!("Tried to get join order from non-fully connected join graph")
+            };
+            // Remove the higher index first so that the lower index remains valid.
+            subtrees.swap_remove(j);
+            subtrees.swap_remove(i);
+            subtrees.push(joined);
+        }
+        subtrees
+            .pop()
+            .expect("Tried to get join order from empty join graph")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use common_scan_info::Pushdowns;
+    use common_treenode::TransformedResult;
+    use daft_schema::{dtype::DataType, field::Field};
+
+    use super::{GreedyJoinOrderer, JoinGraph, JoinOrderTree, JoinOrderer};
+    use crate::{
+        optimization::rules::{
+            reorder_joins::join_graph::{JoinAdjList, JoinNode},
+            rule::OptimizerRule,
+            EnrichWithStats, MaterializeScans,
+        },
+        test::{dummy_scan_node_with_pushdowns, dummy_scan_operator_with_size},
+        LogicalPlanRef,
+    };
+
+    const PLACEHOLDER_CARDINALITY: usize = 0;
+
+    // Helper functions to create test trees with placeholder values.
+    fn test_relation(id: usize) -> JoinOrderTree {
+        JoinOrderTree::Relation(id, PLACEHOLDER_CARDINALITY)
+    }
+
+    fn test_join(left: JoinOrderTree, right: JoinOrderTree) -> JoinOrderTree {
+        JoinOrderTree::Join(
+            Box::new(left),
+            Box::new(right),
+            vec![], // Empty join conditions.
+            PLACEHOLDER_CARDINALITY,
+        )
+    }
+
+    fn create_scan_node(size: Option<usize>, columns: &Vec<&str>) -> LogicalPlanRef {
+        let fields = columns
+            .iter()
+            .map(|&col_name| Field::new(col_name, DataType::Int64))
+            .collect();
+        let plan = dummy_scan_node_with_pushdowns(
+            dummy_scan_operator_with_size(fields, size),
+            Pushdowns::default(),
+        )
+        .build();
+        let scan_materializer = MaterializeScans::new();
+        let plan = scan_materializer.try_optimize(plan).data().unwrap();
+        let stats_enricher = EnrichWithStats::new();
+        stats_enricher.try_optimize(plan).data().unwrap()
+    }
+
+    // A helper struct to represent a join edge between two nodes with the total domain of the join columns.
+    // i.e. node1.node1_col_name = node2.node2_col_name.
+    struct JoinEdge {
+        node1: usize,
+        node1_col_name: String,
+        node2: usize,
+        node2_col_name: String,
+        total_domain: usize,
+    }
+
+    fn create_join_graph_with_edges(plans: Vec<LogicalPlanRef>, edges: Vec<JoinEdge>) -> JoinGraph {
+        let mut adj_list = JoinAdjList::empty();
+        // Immediately create plan ids so that they match the ids in the test cases.
+        for plan in &plans {
+            adj_list.get_or_create_plan_id(plan);
+        }
+        for edge in edges {
+            adj_list.add_bidirectional_edge_with_total_domain(
+                JoinNode::new(edge.node1_col_name, plans[edge.node1].clone()),
+                JoinNode::new(edge.node2_col_name, plans[edge.node2].clone()),
+                edge.total_domain,
+            );
+        }
+        JoinGraph::new(adj_list, vec![])
+    }
+
+    fn node_to_id_map(nodes: Vec<(&str, usize, Vec<&str>)>) -> HashMap<String, usize> {
+        nodes
+            .into_iter()
+            .enumerate()
+            .map(|(id, (name, _, _))| (name.to_string(), id))
+            .collect()
+    }
+
+    #[test]
+    fn test_greedy_order_minimal() {
+        let nodes = vec![
+            ("medium", 1_000, vec!["m_medium", "m_small"]),
+            ("large", 500_000, vec!["l_medium", "l_small"]),
+            ("small", 500, vec!["s_small"]),
+        ];
+        let name_to_id = node_to_id_map(nodes.clone());
+        let edges = vec![
+            JoinEdge {
+                node1: name_to_id["medium"],
+                node1_col_name: "m_medium".to_string(),
+                node2: name_to_id["large"],
+                node2_col_name: "l_medium".to_string(),
+                total_domain: 1_000,
+            },
+            JoinEdge {
+                node1: name_to_id["large"],
+                node1_col_name: "l_small".to_string(),
+                node2: name_to_id["small"],
+                node2_col_name: "s_small".to_string(),
+                total_domain: 500,
+            },
+            JoinEdge {
+                node1: name_to_id["medium"],
+                node1_col_name: "m_small".to_string(),
+                node2: name_to_id["small"],
+                node2_col_name: "s_small".to_string(),
+                total_domain: 500,
+            },
+        ];
+        let plans: Vec<LogicalPlanRef> = nodes
+            .iter()
+            .map(|(_, size, columns)| create_scan_node(Some(*size), columns))
+            .collect();
+        let graph = create_join_graph_with_edges(plans, edges);
+        let order = GreedyJoinOrderer {}.order(&graph);
+        let expected_order = test_join(
+            test_join(
+                test_relation(name_to_id["small"]),
+                test_relation(name_to_id["medium"]),
+            ),
+            test_relation(name_to_id["large"]),
+        );
+        assert!(JoinOrderTree::order_eq(&order, &expected_order));
+        assert!(graph.build_joins_from_join_order(&order).is_ok());
+    }
+
+    #[test]
+    fn test_greedy_order_large_star() {
+        // A fact table joined to many dimension tables of varying sizes. This graph is too large for the brute force
+        // orderer, so we only check that the greedy orderer joins the fact table to its most selective dimensions first.
+        const NUM_DIMENSIONS: usize = 12;
+        let dim_columns: Vec<String> = (0..NUM_DIMENSIONS).map(|i| format!("d{i}_key")).collect();
+        let fact_columns: Vec<String> =
+            (0..NUM_DIMENSIONS).map(|i| format!("f_d{i}_key")).collect();
+        let mut plans = vec![create_scan_node(
+            Some(10_000_000),
+            &fact_columns.iter().map(String::as_str).collect(),
+        )];
+        let mut edges = vec![];
+        for i in 0..NUM_DIMENSIONS {
+            // Dimension i has (i + 1) * 100 rows out of a key domain of 100,000, so smaller dimensions are more selective.
+            let size = (i + 1) * 100;
+            plans.push(create_scan_node(Some(size), &vec![dim_columns[i].as_str()]));
+            edges.push(JoinEdge {
+                node1: 0,
+                node1_col_name: fact_columns[i].clone(),
+                node2: i + 1,
+                node2_col_name: dim_columns[i].clone(),
+                total_domain: 100_000,
+            });
+        }
+        let graph = create_join_graph_with_edges(plans, edges);
+        let order = GreedyJoinOrderer {}.order(&graph);
+        for id in 0..=NUM_DIMENSIONS {
+            assert!(
+                order.contains(id),
+                "Graph id {id} not found in order {order:?}"
+            );
+        }
+        // The first join should be between the smallest dimension and the fact table. Since the intermediate result is
+        // always joined to a smaller dimension next, it's the only join whose children are both relations.
+        fn find_first_join(tree: &JoinOrderTree) -> Option<&JoinOrderTree> {
+            match tree {
+                JoinOrderTree::Relation(..) => None,
+                JoinOrderTree::Join(left, right, ..) => match (left.as_ref(), right.as_ref()) {
+                    (JoinOrderTree::Relation(..), JoinOrderTree::Relation(..)) => Some(tree),
+                    _ => find_first_join(left).or_else(|| find_first_join(right)),
+                },
+            }
+        }
+        let first_join = find_first_join(&order).expect("Order should contain at least one join");
+        let expected_first_join = test_join(test_relation(1), test_relation(0));
+        assert!(
+            JoinOrderTree::order_eq(first_join, &expected_first_join),
+            "Unexpected first join in order {order:?}"
+        );
+        assert!(graph.build_joins_from_join_order(&order).is_ok());
+    }
+}
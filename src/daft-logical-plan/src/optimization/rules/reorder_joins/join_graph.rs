@@ -268,11 +268,27 @@ impl JoinAdjList {
         left_rows.min(right_rows).max(1.0) as usize
     }
 
+    // Helper function that gets the total domain for a join between two columns from their distinct counts, if both
+    // columns have column stats. The total domain is the larger of the two distinct counts, since we assume that the
+    // smaller domain is contained in the larger one.
+    fn get_total_domain_from_column_stats(node1: &JoinNode, node2: &JoinNode) -> Option<usize> {
+        let node1_distinct_count = node1
+            .plan
+            .materialized_stats()
+            .approx_distinct_count(&node1.relation_name)?;
+        let node2_distinct_count = node2
+            .plan
+            .materialized_stats()
+            .approx_distinct_count(&node2.relation_name)?;
+        Some(node1_distinct_count.max(node2_distinct_count))
+    }
+
     pub(super) fn add_bidirectional_edge(&mut self, node1: JoinNode, node2: JoinNode) {
         let node1_id = self.get_or_create_plan_id(&node1.plan);
         let node2_id = self.get_or_create_plan_id(&node2.plan);
         // Find the minimal total domain for the join columns, either from the current nodes or from the existing total domains.
-        let mut td = self.get_estimated_total_domain(&node1.plan, &node2.plan);
+        let mut td = Self::get_total_domain_from_column_stats(&node1, &node2)
+            .unwrap_or_else(|| self.get_estimated_total_domain(&node1.plan, &node2.plan));
         if let Some(equivalence_set_id) = self
             .equivalence_set_map
             .get(&(node1_id, node1.relation_name.clone()))
@@ -588,8 +604,11 @@ impl JoinGraph {
     pub(super) fn could_reorder(&self) -> bool {
         // For this join graph to reorder joins, there must be at least 3 relations to join. Otherwise
         // there is only one join to perform and no reordering is needed.
-        // TODO: We should raise the limit once we implement a DP-based join ordering algorithm.
-        self.adj_list.max_id >= 3 && self.adj_list.max_id <= 7
+        self.num_relations() >= 3
+    }
+
+    pub(super) fn num_relations(&self) -> usize {
+        self.adj_list.max_id
     }
 
     /// Test helper function to get the number of edges that the current graph contains.
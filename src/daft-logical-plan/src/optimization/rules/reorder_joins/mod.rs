@@ -1,4 +1,5 @@
 mod brute_force_join_order;
+mod greedy_join_order;
 mod join_graph;
 #[cfg(test)]
 mod naive_left_deep_join_order;
@@ -16,6 +17,7 @@ use std::sync::Arc;
 use brute_force_join_order::BruteForceJoinOrderer;
 use common_error::DaftResult;
 use common_treenode::{Transformed, TreeNode};
+use greedy_join_order::GreedyJoinOrderer;
 use join_graph::JoinGraphBuilder;
 
 use crate::{
@@ -23,6 +25,9 @@ use crate::{
     LogicalPlan,
 };
 
+// The largest number of relations for which we enumerate every possible join order.
+const MAX_RELATIONS_FOR_BRUTE_FORCE: usize = 7;
+
 // Reorder joins in a query tree.
 impl OptimizerRule for ReorderJoins {
     fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
@@ -46,8 +51,13 @@ impl OptimizerRule for ReorderJoins {
             if !join_graph.could_reorder() {
                 return Ok(Transformed::no(plan));
             }
-            let orderer = BruteForceJoinOrderer {};
-            let join_order = orderer.order(&join_graph);
+            // Exhaustively search for the optimal join order when the join graph is small enough, otherwise fall back
+            // to greedily joining the relations that produce the smallest intermediate results first.
+            let join_order = if join_graph.num_relations() <= MAX_RELATIONS_FOR_BRUTE_FORCE {
+                BruteForceJoinOrderer {}.order(&join_graph)
+            } else {
+                GreedyJoinOrderer {}.order(&join_graph)
+            };
             join_graph
                 .build_logical_plan(join_order)
                 .map(Transformed::yes)
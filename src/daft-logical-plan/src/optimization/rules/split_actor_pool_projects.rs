@@ -925,8 +925,8 @@ mod tests {
         let expected = LogicalPlan::ActorPoolProject(ActorPoolProject::try_new(
             expected,
             vec![
-                create_actor_pool_udf(vec![resolved_col("a")]).alias(intermediate_name_0),
                 resolved_col("b"),
+                create_actor_pool_udf(vec![resolved_col("a")]).alias(intermediate_name_0),
             ],
         )?)
         .arced();
@@ -1039,8 +1039,8 @@ mod tests {
         let expected = LogicalPlan::ActorPoolProject(ActorPoolProject::try_new(
             expected,
             vec![
-                create_actor_pool_udf(vec![resolved_col("a")]).alias(intermediate_name_0),
                 resolved_col("b"),
+                create_actor_pool_udf(vec![resolved_col("a")]).alias(intermediate_name_0),
             ],
         )?)
         .arced();
@@ -535,6 +535,7 @@ fn pull_up_correlated_cols(
         | LogicalPlan::Pivot(..)
         | LogicalPlan::Concat(..)
         | LogicalPlan::Join(..)
+        | LogicalPlan::AsofJoin(..)
         | LogicalPlan::Sink(..)
         | LogicalPlan::Window(..) => {
             if subquery_on.is_empty() {
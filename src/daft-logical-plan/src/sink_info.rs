@@ -3,7 +3,10 @@ use std::{hash::Hash, sync::Arc};
 use common_file_formats::{FileFormat, WriteMode};
 use common_io_config::IOConfig;
 #[cfg(feature = "python")]
-use common_py_serde::{deserialize_py_object, serialize_py_object};
+use common_py_serde::{
+    deserialize_py_object, deserialize_py_object_optional, serialize_py_object,
+    serialize_py_object_optional,
+};
 use daft_dsl::ExprRef;
 use derivative::Derivative;
 use itertools::Itertools;
@@ -27,6 +30,8 @@ pub struct OutputFileInfo {
     pub partition_cols: Option<Vec<ExprRef>>,
     pub compression: Option<String>,
     pub io_config: Option<IOConfig>,
+    /// The maximum number of files that a partitioned write keeps open at once in each task.
+    pub max_open_writers: Option<usize>,
 }
 
 #[cfg(feature = "python")]
@@ -67,6 +72,15 @@ pub struct IcebergCatalogInfo {
     #[derivative(Hash = "ignore")]
     pub iceberg_properties: Arc<PyObject>,
     pub io_config: Option<IOConfig>,
+    /// The `IcebergWrite` that writes the table natively, if it isn't written with PyIceberg.
+    #[serde(
+        serialize_with = "serialize_py_object_optional",
+        deserialize_with = "deserialize_py_object_optional",
+        default
+    )]
+    #[derivative(PartialEq = "ignore")]
+    #[derivative(Hash = "ignore")]
+    pub native_write: Option<Arc<PyObject>>,
 }
 
 #[cfg(feature = "python")]
@@ -75,6 +89,9 @@ impl IcebergCatalogInfo {
         let mut res = vec![];
         res.push(format!("Table Name = {}", self.table_name));
         res.push(format!("Table Location = {}", self.table_location));
+        if self.native_write.is_some() {
+            res.push("Native Write = true".to_string());
+        }
         match &self.io_config {
             None => res.push("IOConfig = None".to_string()),
             Some(io_config) => res.push(format!("IOConfig = {}", io_config)),
@@ -84,7 +101,8 @@ impl IcebergCatalogInfo {
 }
 
 #[cfg(feature = "python")]
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Derivative, Debug, Clone, Serialize, Deserialize)]
+#[derivative(PartialEq, Eq, Hash)]
 pub struct DeltaLakeCatalogInfo {
     pub path: String,
     pub mode: String,
@@ -92,6 +110,15 @@ pub struct DeltaLakeCatalogInfo {
     pub large_dtypes: bool,
     pub partition_cols: Option<Vec<String>>,
     pub io_config: Option<IOConfig>,
+    /// The `DeltaLakeWrite` that writes the table natively, if it isn't written with the deltalake package.
+    #[serde(
+        serialize_with = "serialize_py_object_optional",
+        deserialize_with = "deserialize_py_object_optional",
+        default
+    )]
+    #[derivative(PartialEq = "ignore")]
+    #[derivative(Hash = "ignore")]
+    pub native_write: Option<Arc<PyObject>>,
 }
 
 #[cfg(feature = "python")]
@@ -102,6 +129,9 @@ impl DeltaLakeCatalogInfo {
         res.push(format!("Mode = {}", self.mode));
         res.push(format!("Version = {}", self.version));
         res.push(format!("Large Dtypes = {}", self.large_dtypes));
+        if self.native_write.is_some() {
+            res.push("Native Write = true".to_string());
+        }
         if let Some(ref partition_cols) = self.partition_cols {
             res.push(format!(
                 "Partition cols = {}",
@@ -154,6 +184,7 @@ impl OutputFileInfo {
         partition_cols: Option<Vec<ExprRef>>,
         compression: Option<String>,
         io_config: Option<IOConfig>,
+        max_open_writers: Option<usize>,
     ) -> Self {
         Self {
             root_dir,
@@ -162,6 +193,7 @@ impl OutputFileInfo {
             partition_cols,
             compression,
             io_config,
+            max_open_writers,
         }
     }
 
@@ -173,6 +205,9 @@ impl OutputFileInfo {
                 partition_cols.iter().map(|e| e.to_string()).join(", ")
             ));
         }
+        if let Some(max_open_writers) = self.max_open_writers {
+            res.push(format!("Max open writers = {}", max_open_writers));
+        }
         if let Some(ref compression) = self.compression {
             res.push(format!("Compression = {}", compression));
         }
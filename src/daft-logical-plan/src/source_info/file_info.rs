@@ -39,6 +39,11 @@ pub struct FileInfos {
     pub file_paths: Vec<String>,
     pub file_sizes: Vec<Option<i64>>,
     pub num_rows: Vec<Option<i64>>,
+    /// The modification times of the files in microseconds since the epoch, in UTC.
+    pub last_modified: Vec<Option<i64>>,
+    pub etags: Vec<Option<String>>,
+    pub version_ids: Vec<Option<String>>,
+    pub storage_classes: Vec<Option<String>>,
 }
 
 #[cfg(feature = "python")]
@@ -50,12 +55,32 @@ impl FileInfos {
     }
 
     #[staticmethod]
+    #[pyo3(signature = (
+        file_paths,
+        file_sizes,
+        num_rows,
+        last_modified=None,
+        etags=None,
+        version_ids=None,
+        storage_classes=None
+    ))]
     pub fn from_infos(
         file_paths: Vec<String>,
         file_sizes: Vec<Option<i64>>,
         num_rows: Vec<Option<i64>>,
+        last_modified: Option<Vec<Option<i64>>>,
+        etags: Option<Vec<Option<String>>>,
+        version_ids: Option<Vec<Option<String>>>,
+        storage_classes: Option<Vec<Option<String>>>,
     ) -> Self {
-        Self::new_internal(file_paths, file_sizes, num_rows)
+        let infos = Self::new_internal(file_paths, file_sizes, num_rows);
+        Self {
+            last_modified: last_modified.unwrap_or(infos.last_modified),
+            etags: etags.unwrap_or(infos.etags),
+            version_ids: version_ids.unwrap_or(infos.version_ids),
+            storage_classes: storage_classes.unwrap_or(infos.storage_classes),
+            ..infos
+        }
     }
 
     /// Concatenate two FileInfos together.
@@ -63,6 +88,10 @@ impl FileInfos {
         self.file_paths.extend(new_infos.file_paths);
         self.file_sizes.extend(new_infos.file_sizes);
         self.num_rows.extend(new_infos.num_rows);
+        self.last_modified.extend(new_infos.last_modified);
+        self.etags.extend(new_infos.etags);
+        self.version_ids.extend(new_infos.version_ids);
+        self.storage_classes.extend(new_infos.storage_classes);
     }
 
     pub fn __getitem__(&self, idx: isize) -> PyResult<FileInfo> {
@@ -89,10 +118,15 @@ impl FileInfos {
         file_sizes: Vec<Option<i64>>,
         num_rows: Vec<Option<i64>>,
     ) -> Self {
+        let len = file_paths.len();
         Self {
             file_paths,
             file_sizes,
             num_rows,
+            last_modified: vec![None; len],
+            etags: vec![None; len],
+            version_ids: vec![None; len],
+            storage_classes: vec![None; len],
         }
     }
 
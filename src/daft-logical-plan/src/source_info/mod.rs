@@ -26,6 +26,9 @@ pub struct InMemoryInfo {
     pub num_rows: usize,
     pub clustering_spec: Option<ClusteringSpecRef>,
     pub source_stage_id: Option<usize>,
+    /// Whether the partitions are the persisted results of a DataFrame, which are scanned instead of re-executing its
+    /// lineage.
+    pub persisted: bool,
 }
 
 impl InMemoryInfo {
@@ -39,6 +42,7 @@ impl InMemoryInfo {
         num_rows: usize,
         clustering_spec: Option<ClusteringSpecRef>,
         source_stage_id: Option<usize>,
+        persisted: bool,
     ) -> Self {
         Self {
             source_schema,
@@ -49,6 +53,7 @@ impl InMemoryInfo {
             num_rows,
             clustering_spec,
             source_stage_id,
+            persisted,
         }
     }
 }
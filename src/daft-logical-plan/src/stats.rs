@@ -1,6 +1,9 @@
-use std::{fmt::Display, hash::Hash, ops::Deref};
+use std::{collections::HashMap, fmt::Display, hash::Hash, ops::Deref};
 
 use common_display::utils::bytes_to_human_readable;
+use daft_algebra::boolean::split_conjunction;
+use daft_dsl::{estimated_selectivity, Expr, ExprRef, LiteralValue, Operator};
+use daft_schema::schema::Schema;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
@@ -20,21 +23,180 @@ impl StatsState {
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PlanStats {
-    // Currently we're only putting cardinality stats in the plan stats.
-    // In the future we want to start including column stats, including min, max, NDVs, etc.
     pub approx_stats: ApproxStats,
+    // Column stats are only available for columns that can be traced back to an analyzed source, and are only
+    // populated when cost-based planning is enabled.
+    pub column_stats: HashMap<String, ColumnStats>,
 }
 
 impl PlanStats {
     pub fn new(approx_stats: ApproxStats) -> Self {
-        Self { approx_stats }
+        Self {
+            approx_stats,
+            column_stats: HashMap::new(),
+        }
     }
 
     pub fn empty() -> Self {
         Self {
             approx_stats: ApproxStats::empty(),
+            column_stats: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_column_stats(mut self, column_stats: HashMap<String, ColumnStats>) -> Self {
+        self.column_stats = column_stats;
+        self
+    }
+
+    /// Returns the column stats of the given expression if it's a reference to a column with known stats.
+    pub fn column_stats_for(&self, expr: &ExprRef) -> Option<&ColumnStats> {
+        match expr.as_ref() {
+            Expr::Column(_) => self.column_stats.get(expr.name()),
+            _ => None,
+        }
+    }
+
+    /// Returns the approximate number of distinct values in the given column, bounded by the number of rows.
+    pub fn approx_distinct_count(&self, column: &str) -> Option<usize> {
+        self.column_stats.get(column).map(|stats| {
+            stats
+                .approx_distinct_count
+                .clamp(1, self.approx_stats.num_rows.max(1))
+        })
+    }
+
+    /// Returns the approximate number of distinct combinations of values in the given columns, assuming that the
+    /// columns are independent. Returns `None` if any of the columns don't have stats.
+    pub fn approx_num_groups<'a>(
+        &self,
+        columns: impl IntoIterator<Item = &'a str>,
+    ) -> Option<usize> {
+        let mut num_groups = 1usize;
+        for column in columns {
+            num_groups = num_groups.saturating_mul(self.approx_distinct_count(column)?);
+        }
+        Some(num_groups.min(self.approx_stats.num_rows))
+    }
+
+    /// Returns the column stats of this plan, with the distinct counts bounded by the given number of rows. This should
+    /// be used when propagating column stats through operators that reduce cardinality.
+    pub fn bounded_column_stats(&self, num_rows: usize) -> HashMap<String, ColumnStats> {
+        self.column_stats
+            .iter()
+            .map(|(name, stats)| {
+                let mut stats = stats.clone();
+                stats.approx_distinct_count = stats.approx_distinct_count.min(num_rows.max(1));
+                (name.clone(), stats)
+            })
+            .collect()
+    }
+
+    /// Estimates the selectivity of a predicate on this plan. Conjuncts that compare an analyzed column against a
+    /// literal are estimated from the column's stats, and are assumed to be independent. All other conjuncts fall back
+    /// to heuristic estimates.
+    pub fn estimated_selectivity(&self, predicate: &ExprRef, schema: &Schema) -> f64 {
+        if self.column_stats.is_empty() {
+            return estimated_selectivity(predicate, schema);
+        }
+        let mut column_stats_selectivity = 1.0;
+        let mut heuristic_selectivity = 1.0f64;
+        for conjunct in split_conjunction(predicate) {
+            if let Some(selectivity) = self.column_stats_selectivity(&conjunct) {
+                column_stats_selectivity *= selectivity;
+            } else {
+                heuristic_selectivity =
+                    heuristic_selectivity.min(estimated_selectivity(&conjunct, schema));
+            }
         }
+        column_stats_selectivity * heuristic_selectivity
     }
+
+    fn column_stats_selectivity(&self, predicate: &ExprRef) -> Option<f64> {
+        match predicate.as_ref() {
+            Expr::IsNull(child) => Some(self.column_stats_for(child)?.null_fraction),
+            Expr::NotNull(child) => Some(1.0 - self.column_stats_for(child)?.null_fraction),
+            Expr::BinaryOp { op, left, right } => {
+                // Normalize the comparison so that the column is on the left.
+                let (op, column, value) = match (left.as_ref(), right.as_ref()) {
+                    (Expr::Column(_), Expr::Literal(value)) => (*op, left, value),
+                    (Expr::Literal(value), Expr::Column(_)) => {
+                        let op = match op {
+                            Operator::Lt => Operator::Gt,
+                            Operator::LtEq => Operator::GtEq,
+                            Operator::Gt => Operator::Lt,
+                            Operator::GtEq => Operator::LtEq,
+                            op => *op,
+                        };
+                        (op, right, value)
+                    }
+                    _ => return None,
+                };
+                let stats = self.column_stats_for(column)?;
+                let non_null_fraction = 1.0 - stats.null_fraction;
+                match op {
+                    Operator::Eq => {
+                        Some(non_null_fraction / self.approx_distinct_count(column.name())? as f64)
+                    }
+                    Operator::NotEq => Some(
+                        non_null_fraction
+                            * (1.0 - 1.0 / self.approx_distinct_count(column.name())? as f64),
+                    ),
+                    Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq => {
+                        let min = literal_to_f64(stats.min.as_ref()?)?;
+                        let max = literal_to_f64(stats.max.as_ref()?)?;
+                        let value = literal_to_f64(value)?;
+                        let below_fraction = if max > min {
+                            ((value - min) / (max - min)).clamp(0.0, 1.0)
+                        } else if value < min {
+                            0.0
+                        } else {
+                            1.0
+                        };
+                        let fraction = if matches!(op, Operator::Lt | Operator::LtEq) {
+                            below_fraction
+                        } else {
+                            1.0 - below_fraction
+                        };
+                        Some(non_null_fraction * fraction)
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Converts a literal with an ordered numeric or temporal representation into an f64, for interpolating between column
+/// min/max stats.
+fn literal_to_f64(value: &LiteralValue) -> Option<f64> {
+    match value {
+        LiteralValue::Int8(v) => Some(*v as f64),
+        LiteralValue::UInt8(v) => Some(*v as f64),
+        LiteralValue::Int16(v) => Some(*v as f64),
+        LiteralValue::UInt16(v) => Some(*v as f64),
+        LiteralValue::Int32(v) | LiteralValue::Date(v) => Some(*v as f64),
+        LiteralValue::UInt32(v) => Some(*v as f64),
+        LiteralValue::Int64(v)
+        | LiteralValue::Timestamp(v, ..)
+        | LiteralValue::Time(v, _)
+        | LiteralValue::Duration(v, _) => Some(*v as f64),
+        LiteralValue::UInt64(v) => Some(*v as f64),
+        LiteralValue::Float64(v) => Some(*v),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ColumnStats {
+    // Approximate number of distinct non-null values in the column.
+    pub approx_distinct_count: usize,
+    // Fraction of the column's values that are null.
+    pub null_fraction: f64,
+    pub min: Option<LiteralValue>,
+    pub max: Option<LiteralValue>,
 }
 
 impl Default for PlanStats {
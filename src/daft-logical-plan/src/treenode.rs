@@ -153,6 +153,7 @@ impl LogicalPlan {
                 plan_id,
                 input,
                 to_explode,
+                unnest_structs,
                 exploded_schema,
                 stats_state,
             }) => to_explode
@@ -164,6 +165,7 @@ impl LogicalPlan {
                         plan_id: *plan_id,
                         input: input.clone(),
                         to_explode: new_to_explode,
+                        unnest_structs: *unnest_structs,
                         exploded_schema: exploded_schema.clone(),
                         stats_state: stats_state.clone(),
                     })
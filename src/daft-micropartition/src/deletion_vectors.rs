@@ -0,0 +1,304 @@
+use std::{fmt::Write, sync::Arc};
+
+use common_error::{DaftError, DaftResult};
+use daft_io::{IOClient, IOStatsRef};
+use daft_scan::DeltaDeletionVector;
+
+/// The alphabet of the Z85 encoding that inline deletion vectors and the UUIDs of their files are stored in.
+const Z85_ALPHABET: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// The magic number that serialized deletion vectors start with.
+const DELETION_VECTOR_MAGIC: u32 = 1681511377;
+
+/// The cookies of portable Roaring bitmaps with and without run containers.
+const SERIAL_COOKIE: u32 = 12347;
+const SERIAL_COOKIE_NO_RUNCONTAINER: u32 = 12346;
+/// The number of containers from which portable Roaring bitmaps with run containers have an offset header.
+const NO_OFFSET_THRESHOLD: usize = 4;
+/// The largest cardinality of containers that are stored as arrays rather than as bitsets.
+const MAX_ARRAY_CONTAINER_CARDINALITY: usize = 4096;
+
+fn invalid(dv: &DeltaDeletionVector, reason: &str) -> DaftError {
+    DaftError::ValueError(format!(
+        "Invalid Delta Lake deletion vector {} of the table at {}: {reason}",
+        dv.path_or_inline_dv, dv.table_uri
+    ))
+}
+
+fn z85_decode(encoded: &str) -> Option<Vec<u8>> {
+    if encoded.len() % 5 != 0 {
+        return None;
+    }
+    let mut decoded = Vec::with_capacity(encoded.len() / 5 * 4);
+    for chunk in encoded.as_bytes().chunks(5) {
+        let mut value = 0u64;
+        for byte in chunk {
+            let digit = Z85_ALPHABET.iter().position(|c| c == byte)?;
+            value = value * 85 + digit as u64;
+        }
+        decoded.extend_from_slice(&u32::try_from(value).ok()?.to_be_bytes());
+    }
+    Some(decoded)
+}
+
+/// Resolves the path of the file that a deletion vector is stored in, which for deletion vectors stored relative
+/// to the table is derived from the UUID encoded in it, after an optional random prefix.
+fn deletion_vector_path(dv: &DeltaDeletionVector) -> DaftResult<String> {
+    match dv.storage_type.as_str() {
+        "u" => {
+            let split = dv
+                .path_or_inline_dv
+                .len()
+                .checked_sub(20)
+                .filter(|split| dv.path_or_inline_dv.is_char_boundary(*split))
+                .ok_or_else(|| invalid(dv, "its encoded UUID is too short"))?;
+            let (prefix, encoded_uuid) = dv.path_or_inline_dv.split_at(split);
+            let uuid = z85_decode(encoded_uuid)
+                .ok_or_else(|| invalid(dv, "its UUID isn't encoded in Z85"))?;
+            let hex = uuid.iter().fold(String::new(), |mut hex, byte| {
+                let _ = write!(hex, "{byte:02x}");
+                hex
+            });
+            let uuid = format!(
+                "{}-{}-{}-{}-{}",
+                &hex[..8],
+                &hex[8..12],
+                &hex[12..16],
+                &hex[16..20],
+                &hex[20..]
+            );
+            let table_uri = dv.table_uri.trim_end_matches('/');
+            Ok(if prefix.is_empty() {
+                format!("{table_uri}/deletion_vector_{uuid}.bin")
+            } else {
+                format!("{table_uri}/{prefix}/deletion_vector_{uuid}.bin")
+            })
+        }
+        "p" => Ok(dv.path_or_inline_dv.clone()),
+        other => Err(invalid(dv, &format!("unknown storage type {other}"))),
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if len > self.data.len() {
+            return None;
+        }
+        let (taken, rest) = self.data.split_at(len);
+        self.data = rest;
+        Some(taken)
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        Some(u16::from_le_bytes(self.take(2)?.try_into().ok()?))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(self.take(8)?.try_into().ok()?))
+    }
+}
+
+/// Decodes a 32-bit Roaring bitmap in the portable format, pushing its values onto `values` after `high_bits`.
+fn decode_roaring_bitmap(reader: &mut Reader, high_bits: u64, values: &mut Vec<i64>) -> Option<()> {
+    let cookie = reader.u32()?;
+    let (num_containers, run_flags) = if cookie & 0xFFFF == SERIAL_COOKIE {
+        let num_containers = (cookie >> 16) as usize + 1;
+        (
+            num_containers,
+            Some(reader.take(num_containers.div_ceil(8))?),
+        )
+    } else if cookie == SERIAL_COOKIE_NO_RUNCONTAINER {
+        (reader.u32()? as usize, None)
+    } else {
+        return None;
+    };
+    let headers = (0..num_containers)
+        .map(|_| Some((reader.u16()?, reader.u16()? as usize + 1)))
+        .collect::<Option<Vec<_>>>()?;
+    if run_flags.is_none() || num_containers >= NO_OFFSET_THRESHOLD {
+        reader.take(4 * num_containers)?;
+    }
+
+    for (i, (key, cardinality)) in headers.into_iter().enumerate() {
+        let high_bits = high_bits | (u64::from(key) << 16);
+        if run_flags.is_some_and(|flags| flags[i / 8] & (1 << (i % 8)) != 0) {
+            for _ in 0..reader.u16()? {
+                let start = u64::from(reader.u16()?);
+                let length = u64::from(reader.u16()?);
+                values.extend((start..=start + length).map(|low| (high_bits | low) as i64));
+            }
+        } else if cardinality <= MAX_ARRAY_CONTAINER_CARDINALITY {
+            for _ in 0..cardinality {
+                values.push((high_bits | u64::from(reader.u16()?)) as i64);
+            }
+        } else {
+            for word_idx in 0..1024u64 {
+                let mut word = reader.u64()?;
+                while word != 0 {
+                    let low = word_idx * 64 + u64::from(word.trailing_zeros());
+                    values.push((high_bits | low) as i64);
+                    word &= word - 1;
+                }
+            }
+        }
+    }
+    Some(())
+}
+
+/// Decodes a serialized deletion vector, which is a 64-bit Roaring bitmap stored as an array of 32-bit bitmaps, each
+/// of which holds the positions that share the high 32 bits of its key.
+fn decode_deletion_vector(data: &[u8]) -> Option<Vec<i64>> {
+    let mut reader = Reader { data };
+    if reader.u32()? != DELETION_VECTOR_MAGIC {
+        return None;
+    }
+    let mut positions = vec![];
+    for _ in 0..reader.u64()? {
+        let key = u64::from(reader.u32()?);
+        decode_roaring_bitmap(&mut reader, key << 32, &mut positions)?;
+    }
+    Some(positions)
+}
+
+/// Reads the positions of the rows that a Delta Lake deletion vector deletes from its data file.
+pub async fn read_deletion_vector(
+    dv: &DeltaDeletionVector,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Vec<i64>> {
+    let size =
+        usize::try_from(dv.size_in_bytes).map_err(|_| invalid(dv, "its size is negative"))?;
+    let data = if dv.storage_type == "i" {
+        let mut data = z85_decode(&dv.path_or_inline_dv)
+            .ok_or_else(|| invalid(dv, "it isn't encoded in Z85"))?;
+        data.truncate(size);
+        data
+    } else {
+        // Stored deletion vectors are prefixed with their size and followed by their checksum, both big-endian.
+        let offset = usize::try_from(dv.offset.unwrap_or(0))
+            .map_err(|_| invalid(dv, "its offset is negative"))?;
+        let stored = io_client
+            .single_url_get(
+                deletion_vector_path(dv)?,
+                Some(offset..offset + size + 8),
+                io_stats,
+            )
+            .await?
+            .bytes()
+            .await?;
+        if stored.len() != size + 8
+            || u32::from_be_bytes(stored[..4].try_into().unwrap()) as usize != size
+        {
+            return Err(invalid(dv, "its size doesn't match that of its file"));
+        }
+        let data = &stored[4..4 + size];
+        if u32::from_be_bytes(stored[4 + size..].try_into().unwrap()) != crc32fast::hash(data) {
+            return Err(invalid(dv, "its checksum doesn't match"));
+        }
+        data.to_vec()
+    };
+
+    let positions = decode_deletion_vector(&data)
+        .ok_or_else(|| invalid(dv, "its bitmap is corrupt or of an unsupported format"))?;
+    if positions.len() as i64 != dv.cardinality {
+        return Err(invalid(
+            dv,
+            &format!(
+                "it deletes {} rows rather than {}",
+                positions.len(),
+                dv.cardinality
+            ),
+        ));
+    }
+    Ok(positions)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use daft_io::{IOClient, IOConfig};
+    use daft_scan::DeltaDeletionVector;
+
+    use super::{deletion_vector_path, read_deletion_vector, Z85_ALPHABET};
+
+    fn z85_encode(data: &[u8]) -> String {
+        data.chunks(4)
+            .flat_map(|chunk| {
+                let mut value = u32::from_be_bytes(chunk.try_into().unwrap());
+                let mut digits = [0u8; 5];
+                for digit in digits.iter_mut().rev() {
+                    *digit = Z85_ALPHABET[(value % 85) as usize];
+                    value /= 85;
+                }
+                digits
+            })
+            .map(char::from)
+            .collect()
+    }
+
+    fn deletion_vector(storage_type: &str, path_or_inline_dv: String) -> DeltaDeletionVector {
+        DeltaDeletionVector {
+            table_uri: "s3://bucket/table/".to_string(),
+            storage_type: storage_type.to_string(),
+            path_or_inline_dv,
+            offset: None,
+            size_in_bytes: 0,
+            cardinality: 0,
+        }
+    }
+
+    #[test]
+    fn test_deletion_vector_path() -> common_error::DaftResult<()> {
+        // The example of the spec.
+        let dv = deletion_vector("u", "ab^-aqEH.-t@S}K{vb[*k^".to_string());
+        assert_eq!(
+            deletion_vector_path(&dv)?,
+            "s3://bucket/table/ab/deletion_vector_d2c639aa-8816-431a-aaf6-d3fe2512ff61.bin"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_inline_deletion_vector() -> common_error::DaftResult<()> {
+        let mut data = vec![];
+        data.extend(1681511377u32.to_le_bytes());
+        data.extend(2u64.to_le_bytes());
+        // Positions 1, 3 and 70000 in a bitmap without run containers.
+        data.extend(0u32.to_le_bytes());
+        data.extend(12346u32.to_le_bytes());
+        data.extend(2u32.to_le_bytes());
+        data.extend([0u16, 1, 1, 0].iter().flat_map(|v| v.to_le_bytes()));
+        data.extend([0u8; 8]);
+        data.extend([1u16, 3, 4464].iter().flat_map(|v| v.to_le_bytes()));
+        // Positions 2^32 + 10 to 2^32 + 12 in a bitmap with a run container.
+        data.extend(1u32.to_le_bytes());
+        data.extend(12347u32.to_le_bytes());
+        data.push(1);
+        data.extend([0u16, 2, 1, 10, 2].iter().flat_map(|v| v.to_le_bytes()));
+        let size_in_bytes = data.len() as i64;
+        data.resize(data.len().div_ceil(4) * 4, 0);
+
+        let dv = DeltaDeletionVector {
+            size_in_bytes,
+            cardinality: 6,
+            ..deletion_vector("i", z85_encode(&data))
+        };
+        let io_client = Arc::new(IOClient::new(Arc::new(IOConfig::default()))?);
+        let positions = read_deletion_vector(&dv, io_client, None).await?;
+        assert_eq!(
+            positions,
+            vec![1, 3, 70000, (1 << 32) + 10, (1 << 32) + 11, (1 << 32) + 12]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+use common_error::DaftResult;
+use daft_core::{join::JoinType, prelude::*};
+use daft_dsl::{resolved_col, ExprRef};
+use daft_io::{IOClient, IOStatsRef};
+use daft_parquet::read::{read_parquet_bulk_async, ParquetSchemaInferenceOptions};
+use daft_recordbatch::RecordBatch;
+use daft_scan::IcebergEqualityDeleteFile;
+
+/// The rows deleted from a data file by Iceberg equality delete files, grouped by the columns they're matched on.
+///
+/// Deleted rows are dropped from the tables read from the data file with an anti-join on those columns, in which
+/// nulls are equal, as required by the [spec](https://iceberg.apache.org/spec/#equality-delete-files).
+#[derive(Debug)]
+pub struct EqualityDeletes {
+    deletes: Vec<(Vec<String>, RecordBatch)>,
+}
+
+impl EqualityDeletes {
+    /// Reads the equality columns of `delete_files`, naming them with `field_id_mapping` like those of the data file.
+    pub async fn read(
+        delete_files: &[IcebergEqualityDeleteFile],
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+        num_parallel_tasks: usize,
+        field_id_mapping: Option<Arc<BTreeMap<i32, Field>>>,
+    ) -> DaftResult<Self> {
+        let mut files_by_columns: HashMap<&[String], Vec<String>> = HashMap::new();
+        for file in delete_files {
+            files_by_columns
+                .entry(&file.equality_columns)
+                .or_default()
+                .push(file.path.clone());
+        }
+
+        let mut deletes = Vec::with_capacity(files_by_columns.len());
+        for (columns, paths) in files_by_columns {
+            let tables = read_parquet_bulk_async(
+                paths,
+                Some(columns.to_vec()),
+                None,
+                None,
+                None,
+                None,
+                io_client.clone(),
+                io_stats.clone(),
+                num_parallel_tasks,
+                ParquetSchemaInferenceOptions::default(),
+                field_id_mapping.clone(),
+                None,
+                None,
+                None,
+            )
+            .await?
+            .into_iter()
+            .collect::<DaftResult<Vec<_>>>()?;
+            deletes.push((columns.to_vec(), RecordBatch::concat(&tables)?));
+        }
+        Ok(Self { deletes })
+    }
+
+    /// The columns that deleted rows are matched on, which have to be read from the data file to apply the deletes.
+    pub fn columns(&self) -> impl Iterator<Item = &str> {
+        self.deletes
+            .iter()
+            .flat_map(|(columns, _)| columns.iter().map(String::as_str))
+    }
+
+    /// Drops the deleted rows from `table`, a table read from the data file that has all the equality columns.
+    pub fn apply(&self, table: &RecordBatch) -> DaftResult<RecordBatch> {
+        let mut table = table.clone();
+        for (columns, deleted) in &self.deletes {
+            if table.is_empty() {
+                break;
+            }
+            // Delete files may have been written with older types of the columns, which have since been promoted.
+            let deleted = RecordBatch::from_nonempty_columns(
+                columns
+                    .iter()
+                    .map(|column| {
+                        deleted
+                            .get_column(column)?
+                            .cast(table.get_column(column)?.data_type())
+                    })
+                    .collect::<DaftResult<Vec<_>>>()?,
+            )?;
+            let on = columns
+                .iter()
+                .map(|column| resolved_col(column.as_str()))
+                .collect::<Vec<ExprRef>>();
+            table = table.hash_join(&deleted, &on, &on, &vec![true; on.len()], JoinType::Anti)?;
+        }
+        Ok(table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+    use daft_recordbatch::RecordBatch;
+
+    use super::EqualityDeletes;
+
+    #[test]
+    fn test_apply_equality_deletes() -> common_error::DaftResult<()> {
+        let table = RecordBatch::from_nonempty_columns(vec![
+            Int64Array::from(("id", vec![1, 2, 3, 4])).into_series(),
+            Utf8Array::from(("category", ["a", "b", "a", "b"].as_slice())).into_series(),
+        ])?;
+        let deletes = EqualityDeletes {
+            deletes: vec![
+                (
+                    vec!["id".to_string()],
+                    // Written before the column was promoted from int to long
+                    RecordBatch::from_nonempty_columns(vec![
+                        Int32Array::from(("id", vec![1])).into_series()
+                    ])?,
+                ),
+                (
+                    vec!["id".to_string(), "category".to_string()],
+                    RecordBatch::from_nonempty_columns(vec![
+                        Int64Array::from(("id", vec![2, 3])).into_series(),
+                        Utf8Array::from(("category", ["b", "b"].as_slice())).into_series(),
+                    ])?,
+                ),
+            ],
+        };
+        assert_eq!(
+            deletes.columns().collect::<Vec<_>>(),
+            ["id", "id", "category"]
+        );
+
+        let table = deletes.apply(&table)?;
+        assert_eq!(
+            table
+                .get_column("id")?
+                .i64()?
+                .into_iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            [Some(3), Some(4)]
+        );
+        Ok(())
+    }
+}
@@ -4,9 +4,13 @@
 
 use common_error::DaftError;
 use snafu::Snafu;
+mod deletion_vectors;
+mod equality_deletes;
 mod micropartition;
 mod ops;
 
+pub use deletion_vectors::read_deletion_vector;
+pub use equality_deletes::EqualityDeletes;
 pub use micropartition::{MicroPartition, MicroPartitionRef};
 
 #[cfg(feature = "python")]
@@ -29,7 +29,7 @@ use futures::{Future, Stream};
 use parquet2::metadata::FileMetaData;
 use snafu::ResultExt;
 
-use crate::{DaftCSVSnafu, DaftCoreComputeSnafu};
+use crate::{read_deletion_vector, DaftCSVSnafu, DaftCoreComputeSnafu, EqualityDeletes};
 
 #[derive(Debug)]
 pub enum TableState {
@@ -123,6 +123,8 @@ fn materialize_scan_task(
     );
     let io_client = daft_io::get_io_client(multithreaded_io, io_config).unwrap();
 
+    let mut row_sample = scan_task.sample_parquet_row_groups();
+
     let mut table_values = match scan_task.file_format_config.as_ref() {
         // ********************
         // Native Parquet Reads
@@ -152,7 +154,7 @@ fn materialize_scan_task(
                 .into_iter()
                 .collect::<Vec<_>>();
 
-            let delete_map = read_delete_files(
+            let mut delete_map = read_delete_files(
                 iceberg_delete_files.as_slice(),
                 urls.as_slice(),
                 io_client.clone(),
@@ -162,18 +164,76 @@ fn materialize_scan_task(
                 &inference_options,
             )
             .context(DaftCoreComputeSnafu)?;
+            for source in &scan_task.sources {
+                if let Some(deletion_vector) = source.get_delta_deletion_vector() {
+                    let positions = get_io_runtime(multithreaded_io)
+                        .block_on_current_thread(read_deletion_vector(
+                            deletion_vector,
+                            io_client.clone(),
+                            io_stats.clone(),
+                        ))
+                        .context(DaftCoreComputeSnafu)?;
+                    delete_map
+                        .entry(source.get_path().to_string())
+                        .or_default()
+                        .extend(positions);
+                }
+            }
+
+            let equality_deletes = scan_task
+                .sources
+                .iter()
+                .map(|source| match source.get_iceberg_equality_delete_files() {
+                    Some(delete_files) if !delete_files.is_empty() => {
+                        get_io_runtime(multithreaded_io)
+                            .block_on_current_thread(EqualityDeletes::read(
+                                delete_files,
+                                io_client.clone(),
+                                io_stats.clone(),
+                                num_parallel_tasks,
+                                field_id_mapping.clone(),
+                            ))
+                            .map(Some)
+                    }
+                    _ => Ok(None),
+                })
+                .collect::<DaftResult<Vec<_>>>()
+                .context(DaftCoreComputeSnafu)?;
+            let has_equality_deletes = equality_deletes.iter().any(Option::is_some);
+
+            // The columns that equality deletes are matched on are read as well, and dropped once they're applied.
+            let file_column_names = file_column_names.map(|mut columns| {
+                for column in equality_deletes
+                    .iter()
+                    .flatten()
+                    .flat_map(EqualityDeletes::columns)
+                {
+                    if !columns.contains(&column) {
+                        columns.push(column);
+                    }
+                }
+                columns
+            });
 
-            let row_groups = parquet_sources_to_row_groups(scan_task.sources.as_slice());
+            let row_groups = match &row_sample {
+                Some(row_sample) => Some(row_sample.row_groups.iter().cloned().map(Some).collect()),
+                None => parquet_sources_to_row_groups(scan_task.sources.as_slice()),
+            };
             let metadatas = scan_task
                 .sources
                 .iter()
                 .map(|s| s.get_parquet_metadata().cloned())
                 .collect::<Option<Vec<_>>>();
-            daft_parquet::read::read_parquet_bulk(
+            let tables = daft_parquet::read::read_parquet_bulk(
                 urls.as_slice(),
                 file_column_names.as_deref(),
                 None,
-                scan_task.pushdowns.limit,
+                // Rows deleted by equality deletes are only dropped once read, so the limit is applied afterwards.
+                if has_equality_deletes {
+                    None
+                } else {
+                    scan_task.pushdowns.limit
+                },
                 row_groups,
                 scan_task.pushdowns.filters.clone(),
                 io_client,
@@ -186,7 +246,27 @@ fn materialize_scan_task(
                 Some(delete_map),
                 *chunk_size,
             )
-            .context(DaftCoreComputeSnafu)?
+            .context(DaftCoreComputeSnafu)?;
+
+            if has_equality_deletes {
+                let mut remaining_rows = scan_task.pushdowns.limit.unwrap_or(usize::MAX);
+                tables
+                    .iter()
+                    .zip(&equality_deletes)
+                    .map(|(table, deletes)| {
+                        let table = match deletes {
+                            Some(deletes) => deletes.apply(table)?,
+                            None => table.clone(),
+                        };
+                        let table = table.head(remaining_rows.min(table.len()))?;
+                        remaining_rows -= table.len();
+                        Ok(table)
+                    })
+                    .collect::<DaftResult<Vec<_>>>()
+                    .context(DaftCoreComputeSnafu)?
+            } else {
+                tables
+            }
         }
 
         // ****************
@@ -323,6 +403,21 @@ fn materialize_scan_task(
         }
     };
 
+    // Take the sample pushed into the scan, from the rows picked ahead of the read if possible
+    if let Some(row_sample) = row_sample.as_mut() {
+        table_values = table_values
+            .iter()
+            .map(|tbl| row_sample.take(tbl))
+            .collect::<DaftResult<Vec<_>>>()
+            .context(DaftCoreComputeSnafu)?;
+    } else if let Some(sample) = &scan_task.pushdowns.sample {
+        table_values = table_values
+            .iter()
+            .map(|tbl| tbl.sample_by_fraction(sample.fraction.0, false, sample.seed))
+            .collect::<DaftResult<Vec<_>>>()
+            .context(DaftCoreComputeSnafu)?;
+    }
+
     // Ensure that all Tables have the schema as specified by [`ScanTask::materialized_schema`]
     let cast_to_schema = scan_task.materialized_schema();
 
@@ -423,7 +518,11 @@ impl MicroPartition {
         ) {
             // CASE: ScanTask provides all required metadata.
             // If the scan_task provides metadata (e.g. retrieved from a catalog) we can use it to create an unloaded MicroPartition
-            (Some(metadata), Some(statistics), _) if scan_task.pushdowns.filters.is_none() => {
+            (Some(metadata), Some(statistics), _)
+                if scan_task.pushdowns.filters.is_none()
+                    && scan_task.pushdowns.sample.is_none()
+                    && !scan_task.sources.iter().any(DataSource::has_deletes) =>
+            {
                 Ok(Self::new_unloaded(
                     scan_task.clone(),
                     scan_task
@@ -448,7 +547,14 @@ impl MicroPartition {
                     chunk_size,
                     ..
                 }),
-            ) => {
+            ) if scan_task.pushdowns.sample.is_none()
+                && !scan_task.sources.iter().any(|source| {
+                    source
+                        .get_iceberg_equality_delete_files()
+                        .is_some_and(|files| !files.is_empty())
+                        || source.get_delta_deletion_vector().is_some()
+                }) =>
+            {
                 let uris = scan_task
                     .sources
                     .iter()
@@ -1161,6 +1267,8 @@ pub fn read_parquet_into_micropartition<T: AsRef<str>>(
                     chunk_spec: rgs.map(ChunkSpec::Parquet),
                     size_bytes: Some(size_bytes),
                     iceberg_delete_files: None,
+                    iceberg_equality_delete_files: None,
+                    delta_deletion_vector: None,
                     metadata: None,
                     partition_spec: partition_spec.cloned(),
                     statistics: None,
@@ -1187,6 +1295,7 @@ pub fn read_parquet_into_micropartition<T: AsRef<str>>(
                     )
                 }),
                 num_rows,
+                None,
             ),
             generated_fields,
         );
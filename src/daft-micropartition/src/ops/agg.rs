@@ -32,4 +32,25 @@ impl MicroPartition {
             _ => unreachable!(),
         }
     }
+
+    /// Aggregates each record batch of the partition on its own rather than their concatenation, so the result may
+    /// have several rows per group. This is only correct for partial aggregations, whose results are merged again.
+    pub fn partial_agg(&self, to_agg: &[ExprRef], group_by: &[ExprRef]) -> DaftResult<Self> {
+        let io_stats = IOStatsContext::new("MicroPartition::partial_agg");
+
+        let tables = self.tables_or_read(io_stats)?;
+        if tables.len() <= 1 {
+            return self.agg(to_agg, group_by);
+        }
+
+        let agged = tables
+            .iter()
+            .map(|t| t.agg(to_agg, group_by))
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(Self::new_loaded(
+            agged[0].schema.clone(),
+            agged.into(),
+            None,
+        ))
+    }
 }
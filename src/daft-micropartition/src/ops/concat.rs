@@ -42,16 +42,13 @@ impl MicroPartition {
             let tables = m.tables_or_read(io_stats.clone())?;
             all_tables.extend_from_slice(tables.as_slice());
         }
-        let mut all_stats = None;
-
-        for stats in mps.iter().flat_map(|m| &m.deref().borrow().statistics) {
-            if all_stats.is_none() {
-                all_stats = Some(stats.clone());
-            }
-
-            if let Some(curr_stats) = &all_stats {
-                all_stats = Some(curr_stats.union(stats)?);
-            }
+        // The statistics of the parts only describe the whole if every part has them.
+        let mut all_stats = first_table.statistics.clone();
+        for m in mps.iter().skip(1) {
+            all_stats = match (all_stats, &m.deref().borrow().statistics) {
+                (Some(curr_stats), Some(stats)) => Some(curr_stats.union(stats)?),
+                _ => None,
+            };
         }
         let new_len = all_tables
             .iter()
@@ -1,7 +1,7 @@
 use std::{collections::HashSet, sync::Arc};
 
 use common_error::{DaftError, DaftResult};
-use daft_core::prelude::Schema;
+use daft_core::prelude::{DataType, Schema};
 use daft_dsl::ExprRef;
 use daft_io::IOStatsContext;
 use daft_stats::{ColumnRangeStatistics, TableStatistics};
@@ -84,31 +84,57 @@ impl MicroPartition {
         ))
     }
 
-    pub fn explode(&self, exprs: &[ExprRef]) -> DaftResult<Self> {
+    pub fn explode(&self, exprs: &[ExprRef], unnest_structs: bool) -> DaftResult<Self> {
         let io_stats = IOStatsContext::new("MicroPartition::explode");
 
         let tables = self.tables_or_read(io_stats)?;
         let evaluated_tables = tables
             .iter()
-            .map(|t| t.explode(exprs))
+            .map(|t| t.explode(exprs, unnest_structs))
             .collect::<DaftResult<Vec<_>>>()?;
         let expected_new_columns = infer_schema(exprs, &self.schema)?;
+        let mut expected_schema = self.schema.non_distinct_union(&expected_new_columns)?;
+        if unnest_structs {
+            expected_schema =
+                Schema::new(
+                    expected_schema
+                        .into_iter()
+                        .flat_map(|field| match &field.dtype {
+                            DataType::Struct(fields)
+                                if expected_new_columns.has_field(&field.name) =>
+                            {
+                                fields.clone()
+                            }
+                            _ => vec![field.clone()],
+                        }),
+                );
+        }
+
         let eval_stats = if let Some(stats) = &self.statistics {
             let mut new_stats = stats.columns.clone();
-            for name in expected_new_columns.field_names() {
-                if let Some(v) = new_stats.get_mut(name) {
-                    *v = ColumnRangeStatistics::Missing;
-                } else {
+            for name in expected_new_columns
+                .field_names()
+                .chain(expected_schema.field_names())
+            {
+                if expected_new_columns.has_field(name) || !new_stats.contains_key(name) {
                     new_stats.insert(name.to_string(), ColumnRangeStatistics::Missing);
                 }
             }
-            Some(TableStatistics { columns: new_stats })
+            // Exploded columns gain nulls for empty lists, while the other columns only repeat their values.
+            let null_counts = stats
+                .null_counts
+                .iter()
+                .filter(|(name, _)| !expected_new_columns.has_field(name))
+                .map(|(name, null_count)| (name.clone(), *null_count))
+                .collect();
+            Some(TableStatistics {
+                columns: new_stats,
+                null_counts,
+            })
         } else {
             None
         };
 
-        let expected_schema = self.schema.non_distinct_union(&expected_new_columns)?;
-
         Ok(Self::new_loaded(
             Arc::new(expected_schema),
             Arc::new(evaluated_tables),
@@ -12,12 +12,12 @@ impl MicroPartition {
         if predicate.is_empty() {
             return Ok(Self::empty(Some(self.schema.clone())));
         }
+        let folded_expr = predicate
+            .iter()
+            .cloned()
+            .reduce(daft_dsl::Expr::and)
+            .expect("should have at least 1 expr");
         if let Some(statistics) = &self.statistics {
-            let folded_expr = predicate
-                .iter()
-                .cloned()
-                .reduce(daft_dsl::Expr::and)
-                .expect("should have at least 1 expr");
             let eval_result = statistics.eval_expression(&folded_expr)?;
             let tv = eval_result.to_truth_value();
 
@@ -33,10 +33,16 @@ impl MicroPartition {
             .collect::<DaftResult<Vec<_>>>()
             .context(DaftCoreComputeSnafu)?;
 
+        let statistics = self
+            .statistics
+            .as_ref()
+            .map(|statistics| statistics.filter(&folded_expr))
+            .transpose()?;
+
         Ok(Self::new_loaded(
             self.schema.clone(),
             tables.into(),
-            self.statistics.clone(), // update these values based off the filter we just ran
+            statistics,
         ))
     }
 }
@@ -4,14 +4,48 @@ use common_error::DaftResult;
 use daft_core::{
     array::ops::DaftCompare,
     join::{JoinSide, JoinType},
+    prelude::Schema,
 };
 use daft_dsl::{join::infer_join_schema, ExprRef};
 use daft_io::IOStatsContext;
 use daft_recordbatch::RecordBatch;
-use daft_stats::TruthValue;
+use daft_stats::{TableStatistics, TruthValue};
 
 use crate::micropartition::MicroPartition;
 
+/// The statistics of the result of a join, whose rows are those of its sides that match. Inner joins take their
+/// columns from both sides, and semi and anti joins from the left side.
+fn join_statistics(
+    join_schema: &Schema,
+    left: Option<&TableStatistics>,
+    right: Option<&TableStatistics>,
+    how: JoinType,
+) -> Option<TableStatistics> {
+    let sides = match how {
+        JoinType::Inner => vec![left?, right?],
+        JoinType::Semi | JoinType::Anti => vec![left?],
+        JoinType::Left | JoinType::Right | JoinType::Outer => return None,
+    };
+    let columns = join_schema
+        .field_names()
+        .map(|name| {
+            let stats = sides.iter().find_map(|side| side.columns.get(name))?;
+            Some((name.to_string(), stats.clone()))
+        })
+        .collect::<Option<_>>()?;
+    let null_counts = join_schema
+        .field_names()
+        .filter_map(|name| {
+            let null_count = sides.iter().find_map(|side| side.null_counts.get(name))?;
+            Some((name.to_string(), *null_count))
+        })
+        .collect();
+    Some(TableStatistics {
+        columns,
+        null_counts,
+    })
+}
+
 impl MicroPartition {
     fn join<F>(
         &self,
@@ -76,10 +110,16 @@ impl MicroPartition {
             ([], _) | (_, []) => Ok(Self::empty(Some(join_schema))),
             ([lt], [rt]) => {
                 let joined_table = table_join(lt, rt, left_on, right_on, how)?;
+                let statistics = join_statistics(
+                    &join_schema,
+                    self.statistics.as_ref(),
+                    right.statistics.as_ref(),
+                    how,
+                );
                 Ok(Self::new_loaded(
                     join_schema,
                     vec![joined_table].into(),
-                    None,
+                    statistics,
                 ))
             }
             _ => unreachable!(),
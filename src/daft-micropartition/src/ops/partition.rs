@@ -1,6 +1,10 @@
 use std::sync::Arc;
 
 use common_error::DaftResult;
+use daft_core::{
+    array::ops::IntoGroups,
+    prelude::{IntoSeries, UInt64Array},
+};
 use daft_dsl::ExprRef;
 use daft_io::IOStatsContext;
 use daft_recordbatch::RecordBatch;
@@ -106,24 +110,53 @@ impl MicroPartition {
         self.vec_part_tables_to_mps(part_tables)
     }
 
+    /// Splits the partition into one partition per distinct value of `partition_keys`, and returns them along with
+    /// those values.
+    ///
+    /// Each record batch of the partition is split on its own, and its parts are grouped with the parts of the other
+    /// batches that have the same values, so that the batches are never concatenated.
     pub fn partition_by_value(&self, partition_keys: &[ExprRef]) -> DaftResult<(Vec<Self>, Self)> {
         let io_stats = IOStatsContext::new("MicroPartition::partition_by_value");
 
-        let tables = self.concat_or_get(io_stats)?;
+        let tables = self.tables_or_read(io_stats)?;
 
         if tables.is_empty() {
             let empty = Self::empty(Some(self.schema.clone()));
             let pkeys = empty.eval_expression_list(partition_keys)?;
             return Ok((vec![], pkeys));
         }
-        let table = tables.first().unwrap();
-        let (tables, values) = table.partition_by_value(partition_keys)?;
 
-        let mps = tables
+        let (part_tables, part_values): (Vec<_>, Vec<_>) = tables
+            .iter()
+            .map(|t| t.partition_by_value(partition_keys))
+            .collect::<DaftResult<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        let mut part_tables = part_tables
+            .into_iter()
+            .flatten()
+            .map(Some)
+            .collect::<Vec<_>>();
+        let part_values = RecordBatch::concat(&part_values)?;
+
+        let (key_idx, group_idx) = part_values.make_groups()?;
+        let mps = group_idx
             .into_iter()
-            .map(|t| Self::new_loaded(self.schema.clone(), Arc::new(vec![t]), None))
+            .map(|gidx| {
+                let tables = gidx
+                    .into_iter()
+                    .map(|idx| part_tables[idx as usize].take().unwrap())
+                    .collect::<Vec<_>>();
+                Self::new_loaded(
+                    self.schema.clone(),
+                    Arc::new(tables),
+                    self.statistics.clone(),
+                )
+            })
             .collect::<Vec<_>>();
 
+        let key_idx = UInt64Array::from(("idx", key_idx)).into_series();
+        let values = part_values.take(&key_idx)?;
         let values = Self::new_loaded(values.schema.clone(), Arc::new(vec![values]), None);
 
         Ok((mps, values))
@@ -1,7 +1,10 @@
 use std::{collections::BTreeMap, sync::Arc};
 
 use common_error::DaftResult;
-use daft_core::datatypes::Field;
+use daft_core::{
+    datatypes::{DataType, Field},
+    prelude::Schema,
+};
 use daft_dsl::common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
 use daft_io::{IOClient, IOStatsRef};
 pub use parquet2::metadata::{FileMetaData, RowGroupMetaData};
@@ -299,6 +302,70 @@ pub(crate) async fn read_parquet_metadata(
     }
 }
 
+/// Adds the field IDs of a Parquet type and its children to `mapping`, with their names in the file and their types in
+/// `dtype`. The repeated groups of lists and maps have no field IDs, so their children take the types of the elements.
+fn collect_field_ids(
+    pq_type: &ParquetType,
+    dtype: Option<&DataType>,
+    mapping: &mut BTreeMap<i32, Field>,
+) {
+    let field_info = pq_type.get_field_info();
+    if let Some(field_id) = field_info.id {
+        mapping.insert(
+            field_id,
+            Field::new(
+                field_info.name.clone(),
+                dtype.cloned().unwrap_or(DataType::Null),
+            ),
+        );
+    }
+    if let ParquetType::GroupType { fields, .. } = pq_type {
+        for child in fields {
+            let child_name = &child.get_field_info().name;
+            let is_repeated_group = matches!(child, ParquetType::GroupType { .. })
+                && child.get_field_info().id.is_none();
+            let child_dtype = match dtype {
+                Some(DataType::Struct(children)) => children
+                    .iter()
+                    .find(|field| &field.name == child_name)
+                    .map(|field| &field.dtype),
+                Some(list @ (DataType::List(_) | DataType::FixedSizeList(..)))
+                    if is_repeated_group =>
+                {
+                    Some(list)
+                }
+                Some(DataType::List(element) | DataType::FixedSizeList(element, _)) => {
+                    Some(element.as_ref())
+                }
+                Some(map @ DataType::Map { .. }) if is_repeated_group => Some(map),
+                Some(DataType::Map { key, .. }) if child_name == "key" => Some(key.as_ref()),
+                Some(DataType::Map { value, .. }) => Some(value.as_ref()),
+                _ => None,
+            };
+            collect_field_ids(child, child_dtype, mapping);
+        }
+    }
+}
+
+/// Maps the field IDs of the columns of a Parquet file, and of their nested fields, to their fields in the file's `schema`.
+///
+/// Other files whose columns have the same IDs can then be read under these names. Columns without field IDs are left
+/// out.
+pub fn field_id_mapping_from_metadata(
+    metadata: &FileMetaData,
+    schema: &Schema,
+) -> BTreeMap<i32, Field> {
+    let mut mapping = BTreeMap::new();
+    for pq_type in metadata.schema().fields() {
+        let dtype = schema
+            .get_field(&pq_type.get_field_info().name)
+            .ok()
+            .map(|field| &field.dtype);
+        collect_field_ids(pq_type, dtype, &mut mapping);
+    }
+    mapping
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
@@ -2,6 +2,7 @@ use common_error::DaftResult;
 use daft_core::prelude::Schema;
 use daft_stats::{ColumnRangeStatistics, TableStatistics};
 use indexmap::IndexMap;
+use itertools::Itertools;
 use snafu::ResultExt;
 
 use super::column_range::parquet_statistics_to_column_range_statistics;
@@ -24,6 +25,22 @@ pub fn row_group_metadata_to_table_stats(
         })
         .collect();
 
+    // Row groups store the number of nulls of each leaf column regardless of its type, which bounds the number of
+    // nulls of fields that are stored in a single leaf.
+    let null_counts = schema
+        .into_iter()
+        .filter_map(|field| {
+            let column = metadata
+                .columns()
+                .iter()
+                .filter(|col| col.descriptor().path_in_schema.first() == Some(&field.name))
+                .exactly_one()
+                .ok()?;
+            let null_count = column.statistics()?.ok()?.null_count()?;
+            Some((field.name.clone(), u64::try_from(null_count).ok()?))
+        })
+        .collect();
+
     // Iterate through the schema and construct ColumnRangeStatistics per field
     let columns = schema
         .into_iter()
@@ -45,5 +62,8 @@ pub fn row_group_metadata_to_table_stats(
         })
         .collect::<DaftResult<IndexMap<_, _>>>()?;
 
-    Ok(TableStatistics { columns })
+    Ok(TableStatistics {
+        columns,
+        null_counts,
+    })
 }
@@ -0,0 +1,458 @@
+use std::{
+    collections::HashMap,
+    io,
+    sync::{
+        atomic::{AtomicI32, Ordering},
+        Arc,
+    },
+};
+
+use arrow2::array::Array;
+use bytes::{Bytes, BytesMut};
+use common_error::DaftResult;
+use daft_context::get_context;
+use daft_dsl::LiteralValue;
+use daft_logical_plan::{LogicalPlan, LogicalPlanBuilder};
+use daft_session::Session;
+use daft_sql::{execute_statement, SQLParams};
+use pyo3::Python;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt, BufReader},
+    net::TcpStream,
+};
+use tracing::debug;
+
+use crate::{
+    messages::{BackendWriter, ColumnDescription, Format, FrontendMessage, StartupMessage},
+    sql::{command_tag, count_params, is_ignored, is_query, split_statements},
+    types::{column_description, decode_param, encode_text, UNSPECIFIED},
+};
+
+/// The largest message that frontends may send, which bounds the buffers of connections.
+const MAX_MESSAGE_LEN: usize = 1 << 30;
+
+const SYNTAX_ERROR_OR_ACCESS_RULE_VIOLATION: &str = "42000";
+const FEATURE_NOT_SUPPORTED: &str = "0A000";
+const INVALID_SQL_STATEMENT_NAME: &str = "26000";
+const INVALID_CURSOR_NAME: &str = "34000";
+const PROTOCOL_VIOLATION: &str = "08P01";
+const INTERNAL_ERROR: &str = "XX000";
+
+/// The process IDs of connections, which frontends only use to identify them.
+static NEXT_PROCESS_ID: AtomicI32 = AtomicI32::new(1);
+
+/// An error of a message, which is sent to the frontend unless the connection failed.
+enum MessageError {
+    Query { code: &'static str, message: String },
+    Io(io::Error),
+}
+
+impl MessageError {
+    fn new(code: &'static str, message: impl ToString) -> Self {
+        Self::Query {
+            code,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl From<io::Error> for MessageError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+type MessageResult<T> = Result<T, MessageError>;
+
+/// A statement that is prepared by a Parse message.
+struct PreparedStatement {
+    query: String,
+    param_types: Vec<u32>,
+}
+
+/// A prepared statement with the parameters bound to it by a Bind message, whose plan is kept once it's described so
+/// that statements with side effects, such as `CREATE TABLE`, are only planned once.
+struct Portal {
+    query: String,
+    params: SQLParams,
+    plan: PortalPlan,
+}
+
+/// The plan of a portal, which is `None` for statements without results.
+enum PortalPlan {
+    Unplanned,
+    Planned(Option<Arc<LogicalPlan>>),
+}
+
+/// A connection of a frontend, which runs its queries against a session.
+pub struct Connection {
+    session: Session,
+    stream: BufReader<TcpStream>,
+    writer: BackendWriter,
+    statements: HashMap<String, PreparedStatement>,
+    portals: HashMap<String, Portal>,
+}
+
+fn column_descriptions(plan: &LogicalPlan) -> Vec<ColumnDescription> {
+    plan.schema()
+        .fields()
+        .iter()
+        .map(|field| column_description(&field.name, &field.dtype))
+        .collect()
+}
+
+impl Connection {
+    pub fn new(session: Session, stream: TcpStream) -> Self {
+        Self {
+            session,
+            stream: BufReader::new(stream),
+            writer: BackendWriter::default(),
+            statements: HashMap::new(),
+            portals: HashMap::new(),
+        }
+    }
+
+    async fn flush(&mut self) -> io::Result<()> {
+        let buf = self.writer.take();
+        self.stream.write_all(&buf).await?;
+        self.stream.flush().await
+    }
+
+    async fn read_body(&mut self) -> io::Result<Bytes> {
+        let len = self.stream.read_i32().await?;
+        let len = usize::try_from(len)
+            .ok()
+            .and_then(|len| len.checked_sub(4))
+            .filter(|len| *len <= MAX_MESSAGE_LEN)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Invalid message length"))?;
+        let mut body = BytesMut::zeroed(len);
+        self.stream.read_exact(&mut body).await?;
+        Ok(body.freeze())
+    }
+
+    /// Serves the connection until the frontend terminates it.
+    pub async fn serve(mut self) -> io::Result<()> {
+        if !self.startup().await? {
+            return Ok(());
+        }
+        // Once a message of the extended protocol fails, the messages until the next Sync are skipped.
+        let mut failed = false;
+        loop {
+            let tag = match self.stream.read_u8().await {
+                Ok(tag) => tag,
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(()),
+                Err(e) => return Err(e),
+            };
+            let body = self.read_body().await?;
+            let message = match FrontendMessage::decode(tag, body) {
+                Ok(message) => message,
+                Err(e) => {
+                    self.writer.error_response(PROTOCOL_VIOLATION, &e);
+                    return self.flush().await;
+                }
+            };
+            debug!("Received pgwire message {message:?}");
+            let result = match message {
+                FrontendMessage::Terminate => return Ok(()),
+                FrontendMessage::Sync => {
+                    failed = false;
+                    self.writer.ready_for_query();
+                    self.flush().await?;
+                    continue;
+                }
+                FrontendMessage::Flush => {
+                    self.flush().await?;
+                    continue;
+                }
+                FrontendMessage::Query { query } => {
+                    let result = self.simple_query(&query).await;
+                    self.write_result(result)?;
+                    self.writer.ready_for_query();
+                    self.flush().await?;
+                    continue;
+                }
+                _ if failed => continue,
+                message => self.extended_query(message).await,
+            };
+            failed = result.is_err();
+            self.write_result(result)?;
+        }
+    }
+
+    fn write_result(&mut self, result: MessageResult<()>) -> io::Result<()> {
+        match result {
+            Ok(()) => Ok(()),
+            Err(MessageError::Query { code, message }) => {
+                self.writer.error_response(code, &message);
+                Ok(())
+            }
+            Err(MessageError::Io(e)) => Err(e),
+        }
+    }
+
+    /// Reads the startup message, declining encryption requests, and tells the frontend that it's authenticated. Returns
+    /// whether the connection started, which it doesn't for cancel requests since queries can't be cancelled.
+    async fn startup(&mut self) -> io::Result<bool> {
+        loop {
+            let body = self.read_body().await?;
+            match StartupMessage::decode(body) {
+                Ok(StartupMessage::SslRequest | StartupMessage::GssEncRequest) => {
+                    self.writer.encryption_declined();
+                    self.flush().await?;
+                }
+                Ok(StartupMessage::CancelRequest) => return Ok(false),
+                Ok(StartupMessage::Startup { parameters }) => {
+                    debug!("Starting pgwire connection with parameters {parameters:?}");
+                    break;
+                }
+                Err(e) => {
+                    self.writer.error_response(PROTOCOL_VIOLATION, &e);
+                    self.flush().await?;
+                    return Ok(false);
+                }
+            }
+        }
+
+        self.writer.authentication_ok();
+        for (name, value) in [
+            ("server_version", "14.0"),
+            ("server_encoding", "UTF8"),
+            ("client_encoding", "UTF8"),
+            ("DateStyle", "ISO, MDY"),
+            ("integer_datetimes", "on"),
+            ("standard_conforming_strings", "on"),
+        ] {
+            self.writer.parameter_status(name, value);
+        }
+        let process_id = NEXT_PROCESS_ID.fetch_add(1, Ordering::Relaxed);
+        self.writer.backend_key_data(process_id, 0);
+        self.writer.ready_for_query();
+        self.flush().await?;
+        Ok(true)
+    }
+
+    /// Plans a statement, which runs statements without results such as `CREATE TABLE`. Statements that only control
+    /// the state of the connection are acknowledged without being planned.
+    fn plan(&self, query: &str, params: SQLParams) -> MessageResult<Option<Arc<LogicalPlan>>> {
+        if is_ignored(query) {
+            return Ok(None);
+        }
+        execute_statement(self.session.clone_ref(), query, params)
+            .map_err(|e| MessageError::new(SYNTAX_ERROR_OR_ACCESS_RULE_VIOLATION, e))
+    }
+
+    /// Runs the plan of a statement, streaming its rows to the frontend as they're computed, and returns their number.
+    async fn run(&mut self, plan: Arc<LogicalPlan>) -> MessageResult<usize> {
+        let runner = get_context()
+            .get_or_create_runner()
+            .map_err(|e| MessageError::new(INTERNAL_ERROR, e))?;
+        let (tx, mut rx) = tokio::sync::mpsc::channel::<DaftResult<Vec<Box<dyn Array>>>>(1);
+        tokio::task::spawn_blocking(move || {
+            let run = || -> DaftResult<()> {
+                let builder = LogicalPlanBuilder::new(plan, None);
+                let partitions = Python::with_gil(|py| runner.run_iter_tables(py, builder, None))?;
+                for partition in partitions {
+                    for batch in partition?.get_tables()?.iter() {
+                        if tx
+                            .blocking_send(Ok(batch.get_inner_arrow_arrays().collect()))
+                            .is_err()
+                        {
+                            return Ok(());
+                        }
+                    }
+                }
+                Ok(())
+            };
+            if let Err(e) = run() {
+                let _ = tx.blocking_send(Err(e));
+            }
+        });
+
+        let mut rows = 0;
+        while let Some(batch) = rx.recv().await {
+            let columns = batch
+                .and_then(|batch| {
+                    batch
+                        .iter()
+                        .map(|array| encode_text(array.as_ref()))
+                        .collect::<DaftResult<Vec<_>>>()
+                })
+                .map_err(|e| MessageError::new(INTERNAL_ERROR, e))?;
+            let len = columns.first().map_or(0, Vec::len);
+            for row in 0..len {
+                self.writer
+                    .data_row(columns.iter().map(|column| column[row].as_deref()));
+            }
+            rows += len;
+            self.flush().await?;
+        }
+        Ok(rows)
+    }
+
+    /// Runs the statements of a query of the simple protocol, stopping at the first one that fails.
+    async fn simple_query(&mut self, query: &str) -> MessageResult<()> {
+        let statements = split_statements(query);
+        if statements.is_empty() {
+            self.writer.empty_query_response();
+        }
+        for statement in statements {
+            let rows = match self.plan(statement, SQLParams::default())? {
+                Some(plan) => {
+                    self.writer.row_description(&column_descriptions(&plan));
+                    Some(self.run(plan).await?)
+                }
+                None => None,
+            };
+            self.writer.command_complete(&command_tag(statement, rows));
+        }
+        Ok(())
+    }
+
+    /// Handles a message of the extended protocol, which prepares, binds, describes and executes statements.
+    async fn extended_query(&mut self, message: FrontendMessage) -> MessageResult<()> {
+        match message {
+            FrontendMessage::Parse {
+                statement,
+                query,
+                param_types,
+            } => {
+                if split_statements(&query).len() > 1 {
+                    return Err(MessageError::new(
+                        SYNTAX_ERROR_OR_ACCESS_RULE_VIOLATION,
+                        "Cannot prepare multiple statements",
+                    ));
+                }
+                self.statements
+                    .insert(statement, PreparedStatement { query, param_types });
+                self.writer.parse_complete();
+            }
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            } => {
+                let prepared = self.statements.get(&statement).ok_or_else(|| {
+                    MessageError::new(
+                        INVALID_SQL_STATEMENT_NAME,
+                        format!("Prepared statement \"{statement}\" does not exist"),
+                    )
+                })?;
+                if result_formats.contains(&Format::Binary) {
+                    return Err(MessageError::new(
+                        FEATURE_NOT_SUPPORTED,
+                        "Results can only be returned in the text format",
+                    ));
+                }
+                let params = params
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, value)| {
+                        let oid = prepared
+                            .param_types
+                            .get(idx)
+                            .copied()
+                            .unwrap_or(UNSPECIFIED);
+                        decode_param(idx, oid, Format::of(&param_formats, idx), value.as_deref())
+                    })
+                    .collect::<DaftResult<Vec<_>>>()
+                    .map_err(|e| MessageError::new(PROTOCOL_VIOLATION, e))?;
+                let portal_state = Portal {
+                    query: prepared.query.clone(),
+                    params: SQLParams::positional(params),
+                    plan: PortalPlan::Unplanned,
+                };
+                self.portals.insert(portal, portal_state);
+                self.writer.bind_complete();
+            }
+            FrontendMessage::Describe { kind: b'S', name } => {
+                let prepared = self.statements.get(&name).ok_or_else(|| {
+                    MessageError::new(
+                        INVALID_SQL_STATEMENT_NAME,
+                        format!("Prepared statement \"{name}\" does not exist"),
+                    )
+                })?;
+                let num_params = prepared
+                    .param_types
+                    .len()
+                    .max(count_params(&prepared.query));
+                let mut param_types = prepared.param_types.clone();
+                param_types.resize(num_params, UNSPECIFIED);
+                // Only queries are planned to describe their rows, since planning other statements runs them.
+                let columns = if is_query(&prepared.query) {
+                    let params = SQLParams::positional(vec![LiteralValue::Null; num_params]);
+                    self.plan(&prepared.query, params)?
+                        .map(|plan| column_descriptions(&plan))
+                } else {
+                    None
+                };
+                self.writer.parameter_description(&param_types);
+                match columns {
+                    Some(columns) => self.writer.row_description(&columns),
+                    None => self.writer.no_data(),
+                }
+            }
+            FrontendMessage::Describe { name, .. } => {
+                let plan = self.portal_plan(&name)?;
+                match plan {
+                    Some(plan) => self.writer.row_description(&column_descriptions(&plan)),
+                    None => self.writer.no_data(),
+                }
+            }
+            FrontendMessage::Execute { portal, .. } => {
+                let plan = self.portal_plan(&portal)?;
+                let query = self.portals[&portal].query.clone();
+                let rows = match plan {
+                    Some(plan) => Some(self.run(plan).await?),
+                    None => None,
+                };
+                self.writer.command_complete(&command_tag(&query, rows));
+            }
+            FrontendMessage::Close { kind, name } => {
+                if kind == b'S' {
+                    self.statements.remove(&name);
+                } else {
+                    self.portals.remove(&name);
+                }
+                self.writer.close_complete();
+            }
+            FrontendMessage::Password => {
+                return Err(MessageError::new(
+                    PROTOCOL_VIOLATION,
+                    "Unexpected password message",
+                ))
+            }
+            FrontendMessage::Query { .. }
+            | FrontendMessage::Sync
+            | FrontendMessage::Flush
+            | FrontendMessage::Terminate => unreachable!("Handled by Connection::serve"),
+        }
+        Ok(())
+    }
+
+    /// The plan of a portal, which is planned the first time it's described or executed.
+    fn portal_plan(&mut self, name: &str) -> MessageResult<Option<Arc<LogicalPlan>>> {
+        let portal = self.portals.get(name).ok_or_else(|| {
+            MessageError::new(
+                INVALID_CURSOR_NAME,
+                format!("Portal \"{name}\" does not exist"),
+            )
+        })?;
+        if let PortalPlan::Planned(plan) = &portal.plan {
+            return Ok(plan.clone());
+        }
+        let plan = self.plan(&portal.query, portal.params.clone())?;
+        if let Some(portal) = self.portals.get_mut(name) {
+            portal.plan = PortalPlan::Planned(plan.clone());
+        }
+        Ok(plan)
+    }
+}
+
+/// Serves a connection, logging why it failed if it did.
+pub async fn serve_connection(session: Session, stream: TcpStream) {
+    if let Err(e) = Connection::new(session, stream).serve().await {
+        debug!("pgwire connection failed: {e}");
+    }
+}
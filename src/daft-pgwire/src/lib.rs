@@ -0,0 +1,110 @@
+//! A server of the [PostgreSQL frontend/backend protocol](https://www.postgresql.org/docs/current/protocol.html), which
+//! lets Postgres clients such as psql and the ODBC and JDBC drivers run SQL queries against a Daft session.
+//!
+//! Queries of both the simple and the extended protocol are planned by daft-sql, and their results are sent in the text
+//! format with the PostgreSQL types of their columns. Clients aren't authenticated and connections aren't encrypted.
+
+#[cfg(feature = "python")]
+mod connection;
+#[cfg_attr(not(feature = "python"), allow(dead_code))]
+mod messages;
+#[cfg_attr(not(feature = "python"), allow(dead_code))]
+mod sql;
+#[cfg_attr(not(feature = "python"), allow(dead_code))]
+mod types;
+
+#[cfg(feature = "python")]
+use common_error::DaftResult;
+#[cfg(feature = "python")]
+use common_runtime::RuntimeTask;
+#[cfg(feature = "python")]
+use daft_session::Session;
+#[cfg(feature = "python")]
+use tracing::{info, warn};
+
+/// The handle of a running pgwire server, which shuts it down.
+#[cfg(feature = "python")]
+#[pyo3::pyclass(module = "daft.daft", name = "PgWireServerHandle")]
+pub struct PgWireServerHandle {
+    shutdown_signal: Option<tokio::sync::oneshot::Sender<()>>,
+    server_task: Option<RuntimeTask<DaftResult<()>>>,
+    port: u16,
+}
+
+#[cfg(feature = "python")]
+#[pyo3::pymethods]
+impl PgWireServerHandle {
+    /// Stops accepting connections, and closes the connections that are open.
+    pub fn shutdown(&mut self) -> pyo3::PyResult<()> {
+        if let Some(shutdown_signal) = self.shutdown_signal.take() {
+            let _ = shutdown_signal.send(());
+        }
+        if let Some(server_task) = self.server_task.take() {
+            common_runtime::get_io_runtime(true).block_on_current_thread(server_task)??;
+        }
+        Ok(())
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+}
+
+/// Starts a pgwire server of a session on `host:port`, where a port of 0 picks any free port.
+#[cfg(feature = "python")]
+pub fn start(session: Session, host: &str, port: u16) -> DaftResult<PgWireServerHandle> {
+    let listener = std::net::TcpListener::bind((host, port))?;
+    let port = listener.local_addr()?.port();
+    listener.set_nonblocking(true)?;
+    info!("Daft pgwire server listening on {host}:{port}");
+
+    let (shutdown_signal, mut shutdown_receiver) = tokio::sync::oneshot::channel();
+    let runtime = common_runtime::get_io_runtime(true);
+    let server_task = runtime.spawn(async move {
+        let listener = tokio::net::TcpListener::from_std(listener)?;
+        let mut connections = tokio::task::JoinSet::new();
+        loop {
+            tokio::select! {
+                _ = &mut shutdown_receiver => break,
+                // Reaps the connections that were closed.
+                Some(_) = connections.join_next(), if !connections.is_empty() => {}
+                accepted = listener.accept() => match accepted {
+                    Ok((stream, addr)) => {
+                        info!("Accepted pgwire connection from {addr}");
+                        let _ = stream.set_nodelay(true);
+                        connections.spawn(connection::serve_connection(session.clone_ref(), stream));
+                    }
+                    Err(e) => warn!("Failed to accept pgwire connection: {e}"),
+                },
+            }
+        }
+        connections.shutdown().await;
+        Ok(())
+    });
+
+    Ok(PgWireServerHandle {
+        shutdown_signal: Some(shutdown_signal),
+        server_task: Some(server_task),
+        port,
+    })
+}
+
+#[cfg(feature = "python")]
+#[pyo3::pyfunction]
+#[pyo3(name = "pgwire_start", signature = (session, host = "0.0.0.0", port = 0))]
+pub fn py_pgwire_start(
+    session: &daft_session::python::PySession,
+    host: &str,
+    port: u16,
+) -> pyo3::PyResult<PgWireServerHandle> {
+    Ok(start(session.session().clone_ref(), host, port)?)
+}
+
+#[cfg(feature = "python")]
+pub fn register_modules(parent: &pyo3::Bound<pyo3::types::PyModule>) -> pyo3::PyResult<()> {
+    use pyo3::types::PyModuleMethods;
+
+    parent.add_function(pyo3::wrap_pyfunction!(py_pgwire_start, parent)?)?;
+    parent.add_class::<PgWireServerHandle>()?;
+    Ok(())
+}
@@ -0,0 +1,404 @@
+//! The messages of version 3 of the PostgreSQL frontend/backend protocol that the server reads and writes.
+//!
+//! See <https://www.postgresql.org/docs/current/protocol-message-formats.html> for their formats.
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+/// The protocol version of startup messages, which is 3.0.
+pub const PROTOCOL_VERSION: i32 = 196608;
+pub const SSL_REQUEST_CODE: i32 = 80877103;
+pub const GSSENC_REQUEST_CODE: i32 = 80877104;
+pub const CANCEL_REQUEST_CODE: i32 = 80877102;
+
+/// The first message of a connection, which has no tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StartupMessage {
+    Startup { parameters: Vec<(String, String)> },
+    SslRequest,
+    GssEncRequest,
+    CancelRequest,
+}
+
+/// The format of a parameter or a column, which is text or binary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Binary,
+}
+
+impl Format {
+    fn from_code(code: i16) -> Result<Self, String> {
+        match code {
+            0 => Ok(Self::Text),
+            1 => Ok(Self::Binary),
+            _ => Err(format!("Unknown format code {code}")),
+        }
+    }
+
+    /// The format of the value at `idx`, for a list of format codes that has no codes for all text, one code for all
+    /// values or a code for each value.
+    pub fn of(formats: &[Self], idx: usize) -> Self {
+        match formats {
+            [] => Self::Text,
+            [format] => *format,
+            formats => formats.get(idx).copied().unwrap_or(Self::Text),
+        }
+    }
+}
+
+/// The messages that frontends send once a connection has started.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrontendMessage {
+    Query {
+        query: String,
+    },
+    Parse {
+        statement: String,
+        query: String,
+        param_types: Vec<u32>,
+    },
+    Bind {
+        portal: String,
+        statement: String,
+        param_formats: Vec<Format>,
+        params: Vec<Option<Bytes>>,
+        result_formats: Vec<Format>,
+    },
+    /// Describes a prepared statement (`S`) or a portal (`P`).
+    Describe {
+        kind: u8,
+        name: String,
+    },
+    Execute {
+        portal: String,
+        max_rows: i32,
+    },
+    /// Closes a prepared statement (`S`) or a portal (`P`).
+    Close {
+        kind: u8,
+        name: String,
+    },
+    Sync,
+    Flush,
+    Terminate,
+    /// The response to a password request, which is ignored since clients aren't authenticated.
+    Password,
+}
+
+fn invalid(message: &str) -> String {
+    format!("Invalid {message} message")
+}
+
+fn get_cstring(buf: &mut Bytes) -> Result<String, String> {
+    let end = buf
+        .iter()
+        .position(|b| *b == 0)
+        .ok_or_else(|| "Unterminated string".to_string())?;
+    let s = String::from_utf8(buf.split_to(end).to_vec()).map_err(|e| e.to_string())?;
+    buf.advance(1);
+    Ok(s)
+}
+
+fn get_i16(buf: &mut Bytes) -> Result<i16, String> {
+    (buf.remaining() >= 2)
+        .then(|| buf.get_i16())
+        .ok_or_else(|| "Truncated message".to_string())
+}
+
+fn get_i32(buf: &mut Bytes) -> Result<i32, String> {
+    (buf.remaining() >= 4)
+        .then(|| buf.get_i32())
+        .ok_or_else(|| "Truncated message".to_string())
+}
+
+fn get_formats(buf: &mut Bytes) -> Result<Vec<Format>, String> {
+    (0..get_i16(buf)?)
+        .map(|_| Format::from_code(get_i16(buf)?))
+        .collect()
+}
+
+impl StartupMessage {
+    /// Decodes the body of a startup message, after its length.
+    pub fn decode(mut body: Bytes) -> Result<Self, String> {
+        match get_i32(&mut body)? {
+            SSL_REQUEST_CODE => Ok(Self::SslRequest),
+            GSSENC_REQUEST_CODE => Ok(Self::GssEncRequest),
+            CANCEL_REQUEST_CODE => Ok(Self::CancelRequest),
+            PROTOCOL_VERSION => {
+                let mut parameters = vec![];
+                while body.first().is_some_and(|b| *b != 0) {
+                    parameters.push((get_cstring(&mut body)?, get_cstring(&mut body)?));
+                }
+                Ok(Self::Startup { parameters })
+            }
+            version => Err(format!(
+                "Unsupported protocol version {}.{}",
+                version >> 16,
+                version & 0xFFFF
+            )),
+        }
+    }
+}
+
+impl FrontendMessage {
+    /// Decodes a message from its tag and its body, after its length.
+    pub fn decode(tag: u8, mut body: Bytes) -> Result<Self, String> {
+        let buf = &mut body;
+        Ok(match tag {
+            b'Q' => Self::Query {
+                query: get_cstring(buf)?,
+            },
+            b'P' => {
+                let statement = get_cstring(buf)?;
+                let query = get_cstring(buf)?;
+                let param_types = (0..get_i16(buf)?)
+                    .map(|_| get_i32(buf).map(|oid| oid as u32))
+                    .collect::<Result<_, _>>()?;
+                Self::Parse {
+                    statement,
+                    query,
+                    param_types,
+                }
+            }
+            b'B' => {
+                let portal = get_cstring(buf)?;
+                let statement = get_cstring(buf)?;
+                let param_formats = get_formats(buf)?;
+                let params = (0..get_i16(buf)?)
+                    .map(|_| match get_i32(buf)? {
+                        -1 => Ok(None),
+                        len if len >= 0 && buf.remaining() >= len as usize => {
+                            Ok(Some(buf.split_to(len as usize)))
+                        }
+                        _ => Err(invalid("Bind")),
+                    })
+                    .collect::<Result<_, _>>()?;
+                let result_formats = get_formats(buf)?;
+                Self::Bind {
+                    portal,
+                    statement,
+                    param_formats,
+                    params,
+                    result_formats,
+                }
+            }
+            b'D' | b'C' => {
+                if !buf.has_remaining() {
+                    return Err(invalid("Describe or Close"));
+                }
+                let kind = buf.get_u8();
+                let name = get_cstring(buf)?;
+                if tag == b'D' {
+                    Self::Describe { kind, name }
+                } else {
+                    Self::Close { kind, name }
+                }
+            }
+            b'E' => Self::Execute {
+                portal: get_cstring(buf)?,
+                max_rows: get_i32(buf)?,
+            },
+            b'S' => Self::Sync,
+            b'H' => Self::Flush,
+            b'X' => Self::Terminate,
+            b'p' => Self::Password,
+            tag => return Err(format!("Unsupported message type {}", tag as char)),
+        })
+    }
+}
+
+/// The description of a column of the rows of a query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnDescription {
+    pub name: String,
+    pub type_oid: u32,
+    pub type_size: i16,
+}
+
+/// Writes backend messages to a buffer, which is flushed to the connection once a response is complete.
+#[derive(Debug, Default)]
+pub struct BackendWriter {
+    buf: BytesMut,
+}
+
+impl BackendWriter {
+    pub fn take(&mut self) -> BytesMut {
+        self.buf.split()
+    }
+
+    fn message(&mut self, tag: u8, body: impl FnOnce(&mut BytesMut)) {
+        self.buf.put_u8(tag);
+        let len_idx = self.buf.len();
+        self.buf.put_i32(0);
+        body(&mut self.buf);
+        let len = (self.buf.len() - len_idx) as i32;
+        self.buf[len_idx..len_idx + 4].copy_from_slice(&len.to_be_bytes());
+    }
+
+    fn put_cstring(buf: &mut BytesMut, s: &str) {
+        buf.put_slice(s.as_bytes());
+        buf.put_u8(0);
+    }
+
+    /// Answers an SSL or GSSAPI encryption request, which is declined.
+    pub fn encryption_declined(&mut self) {
+        self.buf.put_u8(b'N');
+    }
+
+    pub fn authentication_ok(&mut self) {
+        self.message(b'R', |buf| buf.put_i32(0));
+    }
+
+    pub fn parameter_status(&mut self, name: &str, value: &str) {
+        self.message(b'S', |buf| {
+            Self::put_cstring(buf, name);
+            Self::put_cstring(buf, value);
+        });
+    }
+
+    pub fn backend_key_data(&mut self, process_id: i32, secret_key: i32) {
+        self.message(b'K', |buf| {
+            buf.put_i32(process_id);
+            buf.put_i32(secret_key);
+        });
+    }
+
+    /// Tells the frontend that the server is ready for a query, outside of any transaction.
+    pub fn ready_for_query(&mut self) {
+        self.message(b'Z', |buf| buf.put_u8(b'I'));
+    }
+
+    pub fn row_description(&mut self, columns: &[ColumnDescription]) {
+        self.message(b'T', |buf| {
+            buf.put_i16(columns.len() as i16);
+            for column in columns {
+                Self::put_cstring(buf, &column.name);
+                // The table and the attribute number of the column, which are unknown.
+                buf.put_i32(0);
+                buf.put_i16(0);
+                buf.put_u32(column.type_oid);
+                buf.put_i16(column.type_size);
+                buf.put_i32(-1);
+                buf.put_i16(0);
+            }
+        });
+    }
+
+    /// Writes a row, whose values are `None` for nulls.
+    pub fn data_row<'a>(&mut self, values: impl ExactSizeIterator<Item = Option<&'a [u8]>>) {
+        self.message(b'D', |buf| {
+            buf.put_i16(values.len() as i16);
+            for value in values {
+                match value {
+                    Some(value) => {
+                        buf.put_i32(value.len() as i32);
+                        buf.put_slice(value);
+                    }
+                    None => buf.put_i32(-1),
+                }
+            }
+        });
+    }
+
+    pub fn command_complete(&mut self, tag: &str) {
+        self.message(b'C', |buf| Self::put_cstring(buf, tag));
+    }
+
+    pub fn empty_query_response(&mut self) {
+        self.message(b'I', |_| {});
+    }
+
+    pub fn parse_complete(&mut self) {
+        self.message(b'1', |_| {});
+    }
+
+    pub fn bind_complete(&mut self) {
+        self.message(b'2', |_| {});
+    }
+
+    pub fn close_complete(&mut self) {
+        self.message(b'3', |_| {});
+    }
+
+    pub fn no_data(&mut self) {
+        self.message(b'n', |_| {});
+    }
+
+    pub fn parameter_description(&mut self, types: &[u32]) {
+        self.message(b't', |buf| {
+            buf.put_i16(types.len() as i16);
+            for oid in types {
+                buf.put_u32(*oid);
+            }
+        });
+    }
+
+    /// Writes an error, with its SQLSTATE code.
+    pub fn error_response(&mut self, code: &str, message: &str) {
+        self.message(b'E', |buf| {
+            for (field, value) in [
+                (b'S', "ERROR"),
+                (b'V', "ERROR"),
+                (b'C', code),
+                (b'M', message),
+            ] {
+                buf.put_u8(field);
+                Self::put_cstring(buf, value);
+            }
+            buf.put_u8(0);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_messages() {
+        let mut startup = BytesMut::new();
+        startup.put_i32(PROTOCOL_VERSION);
+        startup.put_slice(b"user\0daft\0database\0db\0\0");
+        assert_eq!(
+            StartupMessage::decode(startup.freeze()).unwrap(),
+            StartupMessage::Startup {
+                parameters: vec![
+                    ("user".to_string(), "daft".to_string()),
+                    ("database".to_string(), "db".to_string())
+                ]
+            }
+        );
+
+        let mut bind = BytesMut::new();
+        bind.put_slice(b"\0stmt\0");
+        bind.put_slice(&[0, 1, 0, 0]);
+        bind.put_i16(2);
+        bind.put_i32(2);
+        bind.put_slice(b"42");
+        bind.put_i32(-1);
+        bind.put_slice(&[0, 0]);
+        assert_eq!(
+            FrontendMessage::decode(b'B', bind.freeze()).unwrap(),
+            FrontendMessage::Bind {
+                portal: String::new(),
+                statement: "stmt".to_string(),
+                param_formats: vec![Format::Text],
+                params: vec![Some(Bytes::from_static(b"42")), None],
+                result_formats: vec![],
+            }
+        );
+
+        assert!(FrontendMessage::decode(b'Q', Bytes::from_static(b"SELECT 1")).is_err());
+    }
+
+    #[test]
+    fn test_encode_messages() {
+        let mut writer = BackendWriter::default();
+        writer.data_row([Some(&b"1"[..]), None].into_iter());
+        writer.ready_for_query();
+        assert_eq!(
+            &writer.take()[..],
+            b"D\0\0\0\x0f\0\x02\0\0\0\x011\xff\xff\xff\xffZ\0\0\0\x05I"
+        );
+    }
+}
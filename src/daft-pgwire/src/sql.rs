@@ -0,0 +1,153 @@
+//! Lexical handling of the SQL that frontends send, which is split into statements and classified before it's
+//! planned by daft-sql.
+
+/// Calls `f` with the index of each character of `sql` that is outside of string literals, quoted identifiers and
+/// comments.
+fn for_each_unquoted(sql: &str, mut f: impl FnMut(usize, char)) {
+    let mut chars = sql.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\'' | '"' => {
+                for (_, next) in chars.by_ref() {
+                    if next == c {
+                        break;
+                    }
+                }
+            }
+            '-' if chars.peek().is_some_and(|(_, next)| *next == '-') => {
+                for (_, next) in chars.by_ref() {
+                    if next == '\n' {
+                        break;
+                    }
+                }
+            }
+            c => f(i, c),
+        }
+    }
+}
+
+/// Splits a query into its statements, which are separated by semicolons.
+pub fn split_statements(sql: &str) -> Vec<&str> {
+    let mut statements = vec![];
+    let mut start = 0;
+    for_each_unquoted(sql, |i, c| {
+        if c == ';' {
+            statements.push(&sql[start..i]);
+            start = i + 1;
+        }
+    });
+    statements.push(&sql[start..]);
+    statements
+        .into_iter()
+        .map(str::trim)
+        .filter(|statement| !statement.is_empty())
+        .collect()
+}
+
+/// The number of `$<n>` parameters of a statement, which is the largest `n` of them.
+pub fn count_params(sql: &str) -> usize {
+    let mut chars = vec![];
+    for_each_unquoted(sql, |_, c| chars.push(c));
+    let mut count = 0;
+    for (i, c) in chars.iter().enumerate() {
+        if *c == '$' {
+            let n = chars[i + 1..]
+                .iter()
+                .map_while(|c| c.to_digit(10))
+                .fold(0, |n, digit| n * 10 + digit as usize);
+            count = count.max(n);
+        }
+    }
+    count
+}
+
+/// The first two keywords of a statement, after its leading comments.
+fn keywords(mut sql: &str) -> Vec<String> {
+    while let Some(comment) = sql.trim_start().strip_prefix("--") {
+        sql = comment.split_once('\n').map_or("", |(_, rest)| rest);
+    }
+    sql.split_whitespace()
+        .take(2)
+        .map(|word| {
+            word.trim_matches(|c: char| !c.is_ascii_alphabetic())
+                .to_ascii_uppercase()
+        })
+        .collect()
+}
+
+/// Whether a statement only controls the state of the connection, such as transactions and settings, which the
+/// server acknowledges without running since it doesn't support them.
+pub fn is_ignored(sql: &str) -> bool {
+    matches!(
+        keywords(sql).first().map(String::as_str),
+        Some(
+            "BEGIN"
+                | "START"
+                | "COMMIT"
+                | "END"
+                | "ROLLBACK"
+                | "SET"
+                | "RESET"
+                | "DISCARD"
+                | "DEALLOCATE"
+        )
+    )
+}
+
+/// Whether a statement is a query, which can be planned without side effects to describe its rows.
+pub fn is_query(sql: &str) -> bool {
+    matches!(
+        keywords(sql).first().map(String::as_str),
+        Some("SELECT" | "WITH" | "VALUES" | "SHOW" | "DESCRIBE")
+    )
+}
+
+/// The tag of the completion of a statement, with the number of rows that it returned if it has results.
+pub fn command_tag(sql: &str, rows: Option<usize>) -> String {
+    let keywords = keywords(sql);
+    let first = keywords.first().map(String::as_str).unwrap_or_default();
+    match (first, rows) {
+        (_, Some(rows)) => format!("SELECT {rows}"),
+        ("INSERT", None) => "INSERT 0 0".to_string(),
+        ("START", None) => "START TRANSACTION".to_string(),
+        ("END", None) => "COMMIT".to_string(),
+        ("CREATE" | "DROP", None) => keywords.join(" "),
+        (first, None) => first.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_statements() {
+        assert_eq!(
+            split_statements("SELECT ';'; -- a; comment\nSELECT \"a;b\" FROM t;;  "),
+            vec!["SELECT ';'", "-- a; comment\nSELECT \"a;b\" FROM t"]
+        );
+        assert!(split_statements(" ; ").is_empty());
+    }
+
+    #[test]
+    fn test_count_params() {
+        assert_eq!(count_params("SELECT * FROM t WHERE a = $1 AND b = $12"), 12);
+        assert_eq!(count_params("SELECT '$3', $2"), 2);
+        assert_eq!(count_params("SELECT 1"), 0);
+    }
+
+    #[test]
+    fn test_command_tag() {
+        assert_eq!(command_tag("select * from t", Some(3)), "SELECT 3");
+        assert_eq!(
+            command_tag("create table t as select 1", None),
+            "CREATE TABLE"
+        );
+        assert_eq!(command_tag("insert into t values (1)", None), "INSERT 0 0");
+        assert_eq!(command_tag("begin;", None), "BEGIN");
+        assert!(is_ignored("SET extra_float_digits = 3"));
+        assert!(!is_ignored("SELECT 1"));
+        assert!(is_query("-- comment\n(SELECT 1)"));
+        assert!(is_query("WITH t AS (SELECT 1) SELECT * FROM t"));
+    }
+}
@@ -0,0 +1,243 @@
+//! The PostgreSQL types of Daft types, and the conversions of values between them in the text format.
+
+use std::fmt::Write;
+
+use arrow2::{
+    array::{get_value_display, Array, BinaryArray, BooleanArray, PrimitiveArray},
+    datatypes::DataType as ArrowType,
+};
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::DataType;
+use daft_dsl::LiteralValue;
+
+use crate::messages::{ColumnDescription, Format};
+
+pub const BOOL: u32 = 16;
+pub const BYTEA: u32 = 17;
+pub const INT8: u32 = 20;
+pub const INT2: u32 = 21;
+pub const INT4: u32 = 23;
+pub const TEXT: u32 = 25;
+pub const FLOAT4: u32 = 700;
+pub const FLOAT8: u32 = 701;
+pub const VARCHAR: u32 = 1043;
+pub const DATE: u32 = 1082;
+pub const TIME: u32 = 1083;
+pub const TIMESTAMP: u32 = 1114;
+pub const TIMESTAMPTZ: u32 = 1184;
+pub const NUMERIC: u32 = 1700;
+/// The type of parameters whose type isn't specified by the frontend.
+pub const UNSPECIFIED: u32 = 0;
+
+/// The type of the values of a Daft type, with its size in bytes or -1 if it has a variable size. Types without a
+/// PostgreSQL equivalent, such as lists and structs, are sent as text.
+pub fn pg_type(dtype: &DataType) -> (u32, i16) {
+    match dtype {
+        DataType::Boolean => (BOOL, 1),
+        DataType::Int8 | DataType::Int16 | DataType::UInt8 => (INT2, 2),
+        DataType::Int32 | DataType::UInt16 => (INT4, 4),
+        DataType::Int64 | DataType::UInt32 => (INT8, 8),
+        DataType::UInt64 | DataType::Decimal128(..) => (NUMERIC, -1),
+        DataType::Float32 => (FLOAT4, 4),
+        DataType::Float64 => (FLOAT8, 8),
+        DataType::Binary | DataType::FixedSizeBinary(_) => (BYTEA, -1),
+        DataType::Date => (DATE, 4),
+        DataType::Time(_) => (TIME, 8),
+        DataType::Timestamp(_, None) => (TIMESTAMP, 8),
+        DataType::Timestamp(_, Some(_)) => (TIMESTAMPTZ, 8),
+        _ => (TEXT, -1),
+    }
+}
+
+pub fn column_description(name: &str, dtype: &DataType) -> ColumnDescription {
+    let (type_oid, type_size) = pg_type(dtype);
+    ColumnDescription {
+        name: name.to_string(),
+        type_oid,
+        type_size,
+    }
+}
+
+fn write_decimal(value: i128, scale: usize) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let digits = format!("{digits:0>width$}", width = scale + 1);
+    let (int, frac) = digits.split_at(digits.len() - scale);
+    let sign = if value < 0 { "-" } else { "" };
+    if frac.is_empty() {
+        format!("{sign}{int}")
+    } else {
+        format!("{sign}{int}.{frac}")
+    }
+}
+
+/// Encodes the values of a column in the text format, which are `None` for nulls.
+pub fn encode_text(array: &dyn Array) -> DaftResult<Vec<Option<Vec<u8>>>> {
+    let encode = |value: &dyn Fn(usize) -> Vec<u8>| {
+        (0..array.len())
+            .map(|i| array.is_valid(i).then(|| value(i)))
+            .collect()
+    };
+    Ok(match array.data_type().to_logical_type() {
+        ArrowType::Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            encode(&|i| if array.value(i) { b"t" } else { b"f" }.to_vec())
+        }
+        ArrowType::Binary | ArrowType::LargeBinary | ArrowType::FixedSizeBinary(_) => {
+            let array =
+                arrow2::compute::cast::cast(array, &ArrowType::LargeBinary, Default::default())?;
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            encode(&|i| {
+                array
+                    .value(i)
+                    .iter()
+                    .fold("\\x".to_string(), |mut hex, byte| {
+                        let _ = write!(hex, "{byte:02x}");
+                        hex
+                    })
+                    .into_bytes()
+            })
+        }
+        ArrowType::Decimal(_, scale) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i128>>()
+                .unwrap();
+            encode(&|i| write_decimal(array.value(i), *scale).into_bytes())
+        }
+        _ => {
+            let display = get_value_display(array, "");
+            encode(&|i| {
+                let mut value = String::new();
+                let _ = display(&mut value, i);
+                value.into_bytes()
+            })
+        }
+    })
+}
+
+fn invalid_param(idx: usize, reason: impl std::fmt::Display) -> DaftError {
+    DaftError::ValueError(format!("Invalid value of parameter ${}: {reason}", idx + 1))
+}
+
+/// Decodes the value of a parameter as a literal. Parameters whose type isn't specified are integers, floats or
+/// strings, depending on the values that they parse as.
+pub fn decode_param(
+    idx: usize,
+    oid: u32,
+    format: Format,
+    value: Option<&[u8]>,
+) -> DaftResult<LiteralValue> {
+    let Some(value) = value else {
+        return Ok(LiteralValue::Null);
+    };
+    if format == Format::Binary {
+        let fixed = |len: usize| -> DaftResult<&[u8]> {
+            (value.len() == len)
+                .then_some(value)
+                .ok_or_else(|| invalid_param(idx, format!("expected {len} bytes")))
+        };
+        return Ok(match oid {
+            BOOL => LiteralValue::Boolean(fixed(1)?[0] != 0),
+            INT2 => LiteralValue::Int64(i16::from_be_bytes(fixed(2)?.try_into().unwrap()).into()),
+            INT4 => LiteralValue::Int64(i32::from_be_bytes(fixed(4)?.try_into().unwrap()).into()),
+            INT8 => LiteralValue::Int64(i64::from_be_bytes(fixed(8)?.try_into().unwrap())),
+            FLOAT4 => {
+                LiteralValue::Float64(f32::from_be_bytes(fixed(4)?.try_into().unwrap()).into())
+            }
+            FLOAT8 => LiteralValue::Float64(f64::from_be_bytes(fixed(8)?.try_into().unwrap())),
+            BYTEA => LiteralValue::Binary(value.to_vec()),
+            TEXT | VARCHAR | UNSPECIFIED => LiteralValue::Utf8(
+                String::from_utf8(value.to_vec()).map_err(|e| invalid_param(idx, e))?,
+            ),
+            oid => {
+                return Err(invalid_param(
+                    idx,
+                    format!("the binary format of type {oid} is not supported"),
+                ))
+            }
+        });
+    }
+
+    let text = std::str::from_utf8(value).map_err(|e| invalid_param(idx, e))?;
+    Ok(match oid {
+        BOOL => match text.to_ascii_lowercase().as_str() {
+            "t" | "true" | "y" | "yes" | "on" | "1" => LiteralValue::Boolean(true),
+            "f" | "false" | "n" | "no" | "off" | "0" => LiteralValue::Boolean(false),
+            _ => return Err(invalid_param(idx, "expected a boolean")),
+        },
+        INT2 | INT4 | INT8 => {
+            LiteralValue::Int64(text.trim().parse().map_err(|e| invalid_param(idx, e))?)
+        }
+        FLOAT4 | FLOAT8 | NUMERIC => {
+            LiteralValue::Float64(text.trim().parse().map_err(|e| invalid_param(idx, e))?)
+        }
+        UNSPECIFIED => {
+            if let Ok(value) = text.parse::<i64>() {
+                LiteralValue::Int64(value)
+            } else if let Ok(value) = text.parse::<f64>() {
+                LiteralValue::Float64(value)
+            } else {
+                LiteralValue::Utf8(text.to_string())
+            }
+        }
+        _ => LiteralValue::Utf8(text.to_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow2::array::{Int64Array, Utf8Array};
+
+    use super::*;
+
+    #[test]
+    fn test_encode_text() -> DaftResult<()> {
+        let ints = Int64Array::from([Some(1), None, Some(-3)]);
+        assert_eq!(
+            encode_text(&ints)?,
+            vec![Some(b"1".to_vec()), None, Some(b"-3".to_vec())]
+        );
+        let bools = BooleanArray::from([Some(true), Some(false)]);
+        assert_eq!(
+            encode_text(&bools)?,
+            vec![Some(b"t".to_vec()), Some(b"f".to_vec())]
+        );
+        let strings = Utf8Array::<i64>::from([Some("a"), None]);
+        assert_eq!(encode_text(&strings)?, vec![Some(b"a".to_vec()), None]);
+        let bytes = BinaryArray::<i64>::from([Some(&[0x01u8, 0xab][..])]);
+        assert_eq!(encode_text(&bytes)?, vec![Some(b"\\x01ab".to_vec())]);
+        let decimals =
+            PrimitiveArray::<i128>::from([Some(105), Some(-5)]).to(ArrowType::Decimal(10, 2));
+        assert_eq!(
+            encode_text(&decimals)?,
+            vec![Some(b"1.05".to_vec()), Some(b"-0.05".to_vec())]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_param() -> DaftResult<()> {
+        assert_eq!(
+            decode_param(0, INT4, Format::Text, Some(b"42"))?,
+            LiteralValue::Int64(42)
+        );
+        assert_eq!(
+            decode_param(0, INT4, Format::Binary, Some(&42i32.to_be_bytes()))?,
+            LiteralValue::Int64(42)
+        );
+        assert_eq!(
+            decode_param(0, UNSPECIFIED, Format::Text, Some(b"1.5"))?,
+            LiteralValue::Float64(1.5)
+        );
+        assert_eq!(
+            decode_param(0, UNSPECIFIED, Format::Text, Some(b"nyc"))?,
+            LiteralValue::Utf8("nyc".to_string())
+        );
+        assert_eq!(
+            decode_param(0, BOOL, Format::Text, None)?,
+            LiteralValue::Null
+        );
+        assert!(decode_param(1, INT8, Format::Text, Some(b"x")).is_err());
+        Ok(())
+    }
+}
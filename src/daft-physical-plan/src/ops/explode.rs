@@ -15,15 +15,21 @@ pub struct Explode {
     // Upstream node.
     pub input: PhysicalPlanRef,
     pub to_explode: Vec<ExprRef>,
+    pub unnest_structs: bool,
     pub clustering_spec: Arc<ClusteringSpec>,
 }
 
 impl Explode {
-    pub(crate) fn try_new(input: PhysicalPlanRef, to_explode: Vec<ExprRef>) -> DaftResult<Self> {
+    pub(crate) fn try_new(
+        input: PhysicalPlanRef,
+        to_explode: Vec<ExprRef>,
+        unnest_structs: bool,
+    ) -> DaftResult<Self> {
         let clustering_spec = Self::translate_clustering_spec(input.clustering_spec(), &to_explode);
         Ok(Self {
             input,
             to_explode,
+            unnest_structs,
             clustering_spec,
         })
     }
@@ -63,6 +69,9 @@ impl Explode {
             "Explode: {}",
             self.to_explode.iter().map(|e| e.to_string()).join(", ")
         ));
+        if self.unnest_structs {
+            res.push("Unnest structs = true".to_string());
+        }
         res.push(format!(
             "Clustering spec = {{ {} }}",
             self.clustering_spec.multiline_display().join(", ")
@@ -99,7 +108,7 @@ mod tests {
             Field::new("c", DataType::Int64),
         ]))
         .hash_repartition(Some(3), vec![unresolved_col("a")])?
-        .explode(vec![unresolved_col("b")])?
+        .explode(vec![unresolved_col("b")], false)?
         .build();
 
         let physical_plan = logical_to_physical(logical_plan, cfg)?;
@@ -126,7 +135,7 @@ mod tests {
             Field::new("c", DataType::Int64),
         ]))
         .hash_repartition(Some(3), vec![unresolved_col("a"), unresolved_col("b")])?
-        .explode(vec![unresolved_col("b")])?
+        .explode(vec![unresolved_col("b")], false)?
         .build();
 
         let physical_plan = logical_to_physical(logical_plan, cfg)?;
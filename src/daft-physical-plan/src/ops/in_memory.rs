@@ -37,6 +37,9 @@ impl InMemoryScan {
         if let Some(source_stage_id) = self.in_memory_info.source_stage_id {
             res.push(format!("Source Stage ID = {}", source_stage_id));
         }
+        if self.in_memory_info.persisted {
+            res.push("Persisted = cache hit".to_string());
+        }
         res
     }
 }
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use common_daft_config::DaftExecutionConfig;
 use common_error::{DaftError, DaftResult};
 use daft_dsl::ExprRef;
-use daft_io::{parse_url, SourceType};
+use daft_io::parse_url;
 use daft_logical_plan::partitioning::{
     ClusteringSpec, HashClusteringConfig, RandomClusteringConfig, RangeClusteringConfig,
     UnknownClusteringConfig,
@@ -34,6 +34,7 @@ impl ShuffleExchange {
                 target_spec.clone()
             }
             ShuffleExchangeStrategy::FlightShuffle { target_spec, .. } => target_spec.clone(),
+            ShuffleExchangeStrategy::ExternalShuffle { target_spec, .. } => target_spec.clone(),
         }
     }
 }
@@ -54,6 +55,17 @@ pub enum ShuffleExchangeStrategy {
     FlightShuffle {
         target_spec: Arc<ClusteringSpec>,
         shuffle_dirs: Vec<String>,
+        compression: Option<String>,
+        compression_level: Option<i32>,
+    },
+
+    /// Map tasks write their outputs and a manifest to a shared directory, which reduce tasks read from, so that the
+    /// shuffle survives the loss of the workers that ran the map tasks.
+    ExternalShuffle {
+        target_spec: Arc<ClusteringSpec>,
+        shuffle_dir: String,
+        compression: Option<String>,
+        compression_level: Option<i32>,
     },
 }
 
@@ -106,18 +118,57 @@ impl ShuffleExchange {
             ShuffleExchangeStrategy::FlightShuffle {
                 target_spec,
                 shuffle_dirs,
+                compression,
+                compression_level,
             } => {
                 res.push("Strategy: FlightShuffle".to_string());
                 res.push(format!("Target Spec: {:?}", target_spec));
                 res.push(format!("Shuffle Dirs: {}", shuffle_dirs.join(", ")));
+                push_compression_display(&mut res, compression.as_ref(), *compression_level);
+            }
+            ShuffleExchangeStrategy::ExternalShuffle {
+                target_spec,
+                shuffle_dir,
+                compression,
+                compression_level,
+            } => {
+                res.push("Strategy: ExternalShuffle".to_string());
+                res.push(format!("Target Spec: {:?}", target_spec));
+                res.push(format!("Shuffle Dir: {}", shuffle_dir));
+                push_compression_display(&mut res, compression.as_ref(), *compression_level);
             }
         }
         res
     }
 }
 
+fn push_compression_display(
+    res: &mut Vec<String>,
+    compression: Option<&String>,
+    compression_level: Option<i32>,
+) {
+    if let Some(compression) = compression {
+        match compression_level {
+            Some(level) => {
+                res.push(format!("Compression: {} (level {})", compression, level));
+            }
+            None => res.push(format!("Compression: {}", compression)),
+        }
+    }
+}
+
 impl_default_tree_display!(ShuffleExchange);
 
+fn shuffle_compression(cfg: &DaftExecutionConfig) -> Option<String> {
+    (cfg.shuffle_compression != "none").then(|| cfg.shuffle_compression.clone())
+}
+
+fn shuffle_compression_level(cfg: &DaftExecutionConfig) -> Option<i32> {
+    // Only zstd takes a level
+    (cfg.shuffle_compression == "zstd" && cfg.shuffle_compression_level != 0)
+        .then_some(cfg.shuffle_compression_level)
+}
+
 /// Factory of ShuffleExchanges
 ///
 /// This provides an abstraction where we can select the most appropriate strategies based on various
@@ -166,18 +217,38 @@ impl ShuffleExchangeFactory {
                         "flight_shuffle_dirs must be non-empty to use flight shuffle".to_string(),
                     ));
                 }
-                if cfg
+                let source_types = cfg
                     .flight_shuffle_dirs
                     .iter()
-                    .any(|dir| !matches!(parse_url(dir).unwrap().0, SourceType::File))
+                    .map(|dir| Ok(parse_url(dir)?.0))
+                    .collect::<DaftResult<Vec<_>>>()?;
+                if source_types
+                    .iter()
+                    .any(|source_type| *source_type != source_types[0])
                 {
                     return Err(DaftError::ValueError(
-                        "Flight_shuffle_dirs must be valid file paths".to_string(),
+                        "Flight_shuffle_dirs must all be file paths or all be on the same object store"
+                            .to_string(),
                     ));
                 }
                 ShuffleExchangeStrategy::FlightShuffle {
                     target_spec: clustering_spec,
                     shuffle_dirs: cfg.flight_shuffle_dirs.clone(),
+                    compression: shuffle_compression(cfg),
+                    compression_level: shuffle_compression_level(cfg),
+                }
+            }
+            Some(cfg) if cfg.shuffle_algorithm == "external_shuffle" => {
+                if cfg.external_shuffle_dir.is_empty() {
+                    return Err(DaftError::ValueError(
+                        "external_shuffle_dir must be set to use external shuffle".to_string(),
+                    ));
+                }
+                ShuffleExchangeStrategy::ExternalShuffle {
+                    target_spec: clustering_spec,
+                    shuffle_dir: cfg.external_shuffle_dir.clone(),
+                    compression: shuffle_compression(cfg),
+                    compression_level: shuffle_compression_level(cfg),
                 }
             }
             Some(cfg) if cfg.shuffle_algorithm == "auto" => {
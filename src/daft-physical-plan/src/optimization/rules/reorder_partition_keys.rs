@@ -96,11 +96,12 @@ impl PhysicalOptimizerRule for ReorderPartitionKeys {
                     });
                     Ok(Transformed::yes(c.with_plan(new_plan.into()).propagate()))
                 }
-                PhysicalPlan::Explode(Explode { input, to_explode, .. }) => {
+                PhysicalPlan::Explode(Explode { input, to_explode, unnest_structs, .. }) => {
                     // can't use try_new because we are setting the clustering spec ourselves
                     let new_plan = PhysicalPlan::Explode(Explode {
                         input: input.clone(),
                         to_explode: to_explode.clone(),
+                        unnest_structs: *unnest_structs,
                         clustering_spec: new_spec.into(),
                     });
                     Ok(Transformed::yes(c.with_plan(new_plan.into()).propagate()))
@@ -139,10 +140,17 @@ impl PhysicalOptimizerRule for ReorderPartitionKeys {
                     });
                     Ok(Transformed::yes(c.with_plan(new_plan.into()).propagate()))
                 }
-                PhysicalPlan::ShuffleExchange(ShuffleExchange{input, strategy: ShuffleExchangeStrategy::FlightShuffle { shuffle_dirs, .. }}) => {
+                PhysicalPlan::ShuffleExchange(ShuffleExchange{input, strategy: ShuffleExchangeStrategy::FlightShuffle { shuffle_dirs, compression, compression_level, .. }}) => {
                     let new_plan = PhysicalPlan::ShuffleExchange(ShuffleExchange {
                         input: input.clone(),
-                        strategy: ShuffleExchangeStrategy::FlightShuffle { target_spec: new_spec.into(), shuffle_dirs: shuffle_dirs.clone() }
+                        strategy: ShuffleExchangeStrategy::FlightShuffle { target_spec: new_spec.into(), shuffle_dirs: shuffle_dirs.clone(), compression: compression.clone(), compression_level: *compression_level }
+                    });
+                    Ok(Transformed::yes(c.with_plan(new_plan.into()).propagate()))
+                }
+                PhysicalPlan::ShuffleExchange(ShuffleExchange{input, strategy: ShuffleExchangeStrategy::ExternalShuffle { shuffle_dir, compression, compression_level, .. }}) => {
+                    let new_plan = PhysicalPlan::ShuffleExchange(ShuffleExchange {
+                        input: input.clone(),
+                        strategy: ShuffleExchangeStrategy::ExternalShuffle { target_spec: new_spec.into(), shuffle_dir: shuffle_dir.clone(), compression: compression.clone(), compression_level: *compression_level }
                     });
                     Ok(Transformed::yes(c.with_plan(new_plan.into()).propagate()))
                 }
@@ -16,6 +16,7 @@ use common_treenode::{
 use daft_logical_plan::{
     ops::Source,
     optimization::OptimizerBuilder,
+    partitioning::{ClusteringSpec, HashClusteringConfig},
     source_info::{InMemoryInfo, SourceInfo},
     LogicalPlan, LogicalPlanRef,
 };
@@ -23,10 +24,13 @@ use serde::{Deserialize, Serialize};
 
 use super::{
     display::StageDisplayMermaidVisitor,
-    translate::{adaptively_translate_single_logical_node, translate_single_logical_node},
+    translate::{
+        adaptively_translate_single_logical_node, coalesced_num_partitions,
+        translate_single_logical_node,
+    },
 };
 use crate::{
-    ops::{InMemoryScan, PreviousStageScan},
+    ops::{InMemoryScan, PreviousStageScan, ShuffleExchangeFactory},
     PhysicalPlan, PhysicalPlanRef,
 };
 
@@ -213,6 +217,67 @@ impl TreeNodeRewriter for ReplacePreviousStageScanWithInMemoryScan {
     }
 }
 
+// Coalesce the output partitions of hash shuffles over the results of a previous stage, whose sizes have now been observed.
+// Shuffles below a node with multiple inputs (e.g. a join) are left alone, since their inputs must stay co-partitioned.
+struct CoalesceShufflePartitions<'a> {
+    cfg: &'a DaftExecutionConfig,
+    multi_input_depth: usize,
+}
+
+impl TreeNodeRewriter for CoalesceShufflePartitions<'_> {
+    type Node = Arc<PhysicalPlan>;
+
+    fn f_down(&mut self, node: Self::Node) -> DaftResult<common_treenode::Transformed<Self::Node>> {
+        if node.children().len() > 1 {
+            self.multi_input_depth += 1;
+        }
+        Ok(Transformed::no(node))
+    }
+
+    fn f_up(&mut self, node: Self::Node) -> DaftResult<common_treenode::Transformed<Self::Node>> {
+        if node.children().len() > 1 {
+            self.multi_input_depth -= 1;
+            return Ok(Transformed::no(node));
+        }
+        if self.multi_input_depth > 0 {
+            return Ok(Transformed::no(node));
+        }
+        let PhysicalPlan::ShuffleExchange(shuffle_exchange) = node.as_ref() else {
+            return Ok(Transformed::no(node));
+        };
+        let PhysicalPlan::InMemoryScan(InMemoryScan { in_memory_info, .. }) =
+            shuffle_exchange.input.as_ref()
+        else {
+            return Ok(Transformed::no(node));
+        };
+        if in_memory_info.source_stage_id.is_none() {
+            return Ok(Transformed::no(node));
+        }
+        let clustering_spec = shuffle_exchange.clustering_spec();
+        let ClusteringSpec::Hash(HashClusteringConfig { num_partitions, by }) =
+            clustering_spec.as_ref()
+        else {
+            return Ok(Transformed::no(node));
+        };
+        let coalesced =
+            coalesced_num_partitions(*num_partitions, in_memory_info.size_bytes, self.cfg);
+        if coalesced >= *num_partitions {
+            return Ok(Transformed::no(node));
+        }
+        log::info!(
+            "Coalescing shuffle of {} from {} to {} partitions",
+            bytes_to_human_readable(in_memory_info.size_bytes),
+            num_partitions,
+            coalesced
+        );
+        let shuffle_exchange = ShuffleExchangeFactory::new(shuffle_exchange.input.clone())
+            .get_hash_partitioning(by.clone(), coalesced, Some(self.cfg))?;
+        Ok(Transformed::yes(
+            PhysicalPlan::ShuffleExchange(shuffle_exchange).arced(),
+        ))
+    }
+}
+
 // Strip the cache entry from the in memory scan, so that we don't hold a reference to the cache entry for plans that we cache for explain purposes
 struct StripCacheEntryFromInMemoryScan {}
 
@@ -473,6 +538,12 @@ impl AdaptivePlanner {
         &self,
         physical_plan: PhysicalPlanRef,
     ) -> DaftResult<(PhysicalPlanRef, Option<PhysicalPlanRef>)> {
+        let physical_plan = physical_plan
+            .rewrite(&mut CoalesceShufflePartitions {
+                cfg: &self.cfg,
+                multi_input_depth: 0,
+            })?
+            .data;
         let mut physical_stage_translator = PhysicalStageTranslator {
             partial_physical_plan: None,
             root: physical_plan.clone(),
@@ -11,11 +11,11 @@ use common_scan_info::{PhysicalScanInfo, ScanState, SPLIT_AND_MERGE_PASS};
 use daft_core::{join::JoinSide, prelude::*};
 use daft_dsl::{
     estimated_selectivity, functions::agg::merge_mean, is_partition_compatible,
-    join::normalize_join_keys, resolved_col, AggExpr, ApproxPercentileParams, Expr, ExprRef,
-    SketchType,
+    join::normalize_join_keys, lit, resolved_col, AggExpr, ApproxPercentileParams, CollectParams,
+    Expr, ExprRef, SketchType,
 };
 use daft_functions::{
-    list::{count_distinct, distinct},
+    list::{count_distinct, distinct, slice},
     numeric::sqrt,
 };
 use daft_logical_plan::{
@@ -33,6 +33,7 @@ use daft_logical_plan::{
     },
     sink_info::{OutputFileInfo, SinkInfo},
     source_info::{PlaceHolderInfo, SourceInfo},
+    stats::StatsState,
 };
 
 use crate::{ops::*, PhysicalPlan, PhysicalPlanRef};
@@ -140,12 +141,18 @@ pub(super) fn translate_single_logical_node(
                     .arced(),
             )
         }
-        LogicalPlan::Explode(LogicalExplode { to_explode, .. }) => {
+        LogicalPlan::Explode(LogicalExplode {
+            to_explode,
+            unnest_structs,
+            ..
+        }) => {
             let input_physical = physical_children.pop().expect("requires 1 input");
-            Ok(
-                PhysicalPlan::Explode(Explode::try_new(input_physical, to_explode.clone())?)
-                    .arced(),
-            )
+            Ok(PhysicalPlan::Explode(Explode::try_new(
+                input_physical,
+                to_explode.clone(),
+                *unnest_structs,
+            )?)
+            .arced())
         }
         LogicalPlan::Unpivot(LogicalUnpivot {
             ids,
@@ -276,6 +283,7 @@ pub(super) fn translate_single_logical_node(
             .arced())
         }
         LogicalPlan::Aggregate(LogicalAggregate {
+            input,
             aggregations,
             groupby,
             ..
@@ -323,9 +331,14 @@ pub(super) fn translate_single_logical_node(
                         PhysicalPlan::ShuffleExchange(
                             ShuffleExchangeFactory::new(first_stage_agg).get_hash_partitioning(
                                 groupby.clone(),
+                                // If we know how many groups there are, don't shuffle into more partitions than that.
                                 min(
-                                    num_input_partitions,
-                                    cfg.shuffle_aggregation_default_partitions,
+                                    min(
+                                        num_input_partitions,
+                                        cfg.shuffle_aggregation_default_partitions,
+                                    ),
+                                    estimated_num_groups(input, &groupby)
+                                        .map_or(usize::MAX, |num_groups| num_groups.max(1)),
                                 ),
                                 Some(cfg),
                             )?,
@@ -520,6 +533,9 @@ pub(super) fn translate_single_logical_node(
         LogicalPlan::Window(_window) => Err(DaftError::NotImplemented(
             "Window functions are currently only supported on the native runner.".to_string(),
         )),
+        LogicalPlan::AsofJoin(_) => Err(DaftError::NotImplemented(
+            "As-of joins are currently only supported on the native runner.".to_string(),
+        )),
     }?;
     // TODO(desmond): We can't perform this check for now because ScanTasks currently provide
     // different size estimations depending on when the approximation is computed. Once we fix
@@ -544,6 +560,19 @@ pub fn adaptively_translate_single_logical_node(
     }
 }
 
+// Estimates the number of groups of an aggregation from the column stats of its input. Returns `None` if the input's
+// stats are not materialized, or if any of the group by columns are missing column stats.
+fn estimated_num_groups(input: &LogicalPlan, groupby: &[ExprRef]) -> Option<usize> {
+    let StatsState::Materialized(stats) = input.stats_state() else {
+        return None;
+    };
+    let columns = groupby
+        .iter()
+        .map(|expr| stats.column_stats_for(expr).map(|_| expr.name()))
+        .collect::<Option<Vec<_>>>()?;
+    stats.approx_num_groups(columns)
+}
+
 pub fn extract_agg_expr(expr: &ExprRef) -> DaftResult<AggExpr> {
     match expr.as_ref() {
         Expr::Agg(agg_expr) => Ok(agg_expr.clone()),
@@ -569,6 +598,9 @@ pub fn extract_agg_expr(expr: &ExprRef) -> DaftResult<AggExpr> {
                 AggExpr::ApproxCountDistinct(e) => {
                     AggExpr::ApproxCountDistinct(Expr::Alias(e, name.clone()).into())
                 }
+                AggExpr::ApproxTopK(e, k) => {
+                    AggExpr::ApproxTopK(Expr::Alias(e, name.clone()).into(), k)
+                }
                 AggExpr::ApproxSketch(e, sketch_type) => {
                     AggExpr::ApproxSketch(Expr::Alias(e, name.clone()).into(), sketch_type)
                 }
@@ -587,6 +619,10 @@ pub fn extract_agg_expr(expr: &ExprRef) -> DaftResult<AggExpr> {
                 AggExpr::List(e) => AggExpr::List(Expr::Alias(e, name.clone()).into()),
                 AggExpr::Set(e) => AggExpr::Set(Expr::Alias(e, name.clone()).into()),
                 AggExpr::Concat(e) => AggExpr::Concat(Expr::Alias(e, name.clone()).into()),
+                AggExpr::Collect(params) => AggExpr::Collect(CollectParams {
+                    child: Expr::Alias(params.child, name.clone()).into(),
+                    ..params
+                }),
                 AggExpr::MapGroups { func, inputs } => AggExpr::MapGroups {
                     func,
                     inputs: inputs
@@ -624,6 +660,26 @@ pub fn populate_aggregation_stages(
     // Project the aggregation results to their final output names
     let mut final_exprs: Vec<ExprRef> = group_by.iter().map(|e| resolved_col(e.name())).collect();
 
+    // Ordered collects can't be merged from partial results, so if there are any, every
+    // aggregation is computed in a single stage over the original rows.
+    if aggregations.iter().any(|agg_expr| {
+        matches!(
+            agg_expr,
+            AggExpr::Collect(CollectParams {
+                order_by: Some(_),
+                ..
+            })
+        )
+    }) {
+        for agg_expr in aggregations {
+            second_stage_aggs
+                .entry(agg_expr.semantic_id(schema).id)
+                .or_insert_with(|| agg_expr.clone());
+            final_exprs.push(resolved_col(agg_expr.name()));
+        }
+        return (first_stage_aggs, second_stage_aggs, final_exprs);
+    }
+
     fn add_to_stage<F>(
         f: F,
         expr: ExprRef,
@@ -918,6 +974,35 @@ pub fn populate_aggregation_stages(
                     ));
                 final_exprs.push(resolved_col(concat_of_concat_id.clone()).alias(output_name));
             }
+            AggExpr::Collect(params) => {
+                // Each partition's partial list is already deduplicated and capped, which keeps
+                // the partial results small, but the concatenated lists need the same again.
+                let collect_id = add_to_stage(
+                    |e| {
+                        AggExpr::Collect(CollectParams {
+                            child: e,
+                            ..params.clone()
+                        })
+                    },
+                    params.child.clone(),
+                    schema,
+                    &mut first_stage_aggs,
+                );
+                let concat_id = add_to_stage(
+                    AggExpr::Concat,
+                    resolved_col(collect_id),
+                    schema,
+                    &mut second_stage_aggs,
+                );
+                let mut result = resolved_col(concat_id);
+                if params.distinct {
+                    result = distinct(result);
+                }
+                if let Some(limit) = params.limit {
+                    result = slice(result, lit(0i64), lit(limit as i64));
+                }
+                final_exprs.push(result.alias(output_name));
+            }
             AggExpr::MapGroups { func, inputs } => {
                 let func_id = agg_expr.semantic_id(schema).id;
                 // No first stage aggregation for MapGroups, do all the work in the second stage.
@@ -980,6 +1065,25 @@ pub fn populate_aggregation_stages(
                     ));
                 final_exprs.push(resolved_col(second_stage_id).alias(output_name));
             }
+            &AggExpr::ApproxTopK(ref e, k) => {
+                let sketch_type = SketchType::top_k(k);
+                let first_stage_id = add_to_stage(
+                    |e| AggExpr::ApproxSketch(e, sketch_type),
+                    e.clone(),
+                    schema,
+                    &mut first_stage_aggs,
+                );
+                let second_stage_id = add_to_stage(
+                    |e| AggExpr::MergeSketch(e, sketch_type),
+                    resolved_col(first_stage_id),
+                    schema,
+                    &mut second_stage_aggs,
+                );
+                final_exprs.push(
+                    slice(resolved_col(second_stage_id), lit(0i64), lit(k as i64))
+                        .alias(output_name),
+                );
+            }
             AggExpr::ApproxSketch(..) => {
                 unimplemented!("User-facing approx_sketch aggregation is not implemented")
             }
@@ -991,6 +1095,19 @@ pub fn populate_aggregation_stages(
     (first_stage_aggs, second_stage_aggs, final_exprs)
 }
 
+/// Number of partitions to shuffle `size_bytes` of materialized data into under adaptive query execution, so that
+/// partitions are coalesced up to roughly the configured target size instead of staying tiny.
+pub(super) fn coalesced_num_partitions(
+    num_partitions: usize,
+    size_bytes: usize,
+    cfg: &DaftExecutionConfig,
+) -> usize {
+    let target_size_bytes = cfg.aqe_target_partition_size_bytes.max(1);
+    num_partitions
+        .min(size_bytes.div_ceil(target_size_bytes))
+        .max(1)
+}
+
 fn translate_join(
     physical_children: &mut Vec<Arc<PhysicalPlan>>,
     join_plan: &LogicalPlan,
@@ -1094,6 +1211,14 @@ fn translate_join(
             })
         }
 
+        // A sort-merge join builds no hash table of either side, so it's also used when the hash
+        // table of each partition of the smaller side is unlikely to fit in memory, as long as
+        // neither side is already hash-partitioned for a hash join to reuse.
+        let build_side_exceeds_memory = !is_left_hash_partitioned
+            && !is_right_hash_partitioned
+            && smaller_size_bytes / num_partitions.max(1)
+                > cfg.sort_merge_join_size_bytes_threshold;
+
         let smaller_side_is_broadcastable = match join_type {
             JoinType::Inner => true,
             JoinType::Left | JoinType::Anti | JoinType::Semi => left_is_larger,
@@ -1107,7 +1232,8 @@ fn translate_join(
             && smaller_side_is_broadcastable
         {
             JoinStrategy::Broadcast
-        // Larger side of join is range-partitioned on the join column, so we use a sort-merge join.
+        // Larger side of join is range-partitioned on the join column, or the smaller side is too
+        // large to build a hash table of, so we use a sort-merge join.
         // TODO(Clark): Support non-primitive dtypes for sort-merge join (e.g. temporal types).
         // TODO(Clark): Also do a sort-merge join if a downstream op needs the table to be sorted on the join key.
         // TODO(Clark): Look into defaulting to sort-merge join over hash join under more input partitioning setups.
@@ -1116,10 +1242,11 @@ fn translate_join(
         } else if *join_type == JoinType::Inner
             && keys_are_primitive(&left_on, &left.schema())
             && keys_are_primitive(&right_on, &right.schema())
-            && (is_left_sort_partitioned || is_right_sort_partitioned)
-            && (!is_larger_partitioned
-                || (left_is_larger && is_left_sort_partitioned
-                    || !left_is_larger && is_right_sort_partitioned))
+            && ((is_left_sort_partitioned || is_right_sort_partitioned)
+                && (!is_larger_partitioned
+                    || (left_is_larger && is_left_sort_partitioned
+                        || !left_is_larger && is_right_sort_partitioned))
+                || build_side_exceeds_memory)
             && !has_null_safe_equals
         {
             JoinStrategy::SortMerge
@@ -1326,6 +1453,22 @@ fn translate_join(
                 }
                 (_, _, a, b) => max(a, b),
             };
+            // If both sides have already been materialized, their observed sizes tell us how many partitions the
+            // shuffle actually needs, rather than inheriting the partition counts of their inputs.
+            let num_partitions = if adaptive
+                && left_is_in_memory
+                && right_is_in_memory
+                && !is_left_hash_partitioned
+                && !is_right_hash_partitioned
+            {
+                coalesced_num_partitions(
+                    num_partitions,
+                    left_stats.size_bytes + right_stats.size_bytes,
+                    cfg,
+                )
+            } else {
+                num_partitions
+            };
             if num_left_partitions != num_partitions
                 || (num_partitions > 1 && !is_left_hash_partitioned)
             {
@@ -1401,13 +1544,16 @@ mod tests {
 
     use common_daft_config::DaftExecutionConfig;
     use common_error::DaftResult;
+    use common_partitioning::PartitionCacheEntry;
     use daft_core::prelude::*;
     use daft_dsl::{lit, resolved_col};
-    use daft_logical_plan::LogicalPlanBuilder;
+    use daft_logical_plan::{source_info::InMemoryInfo, LogicalPlan, LogicalPlanBuilder};
 
     use super::HashJoin;
     use crate::{
-        physical_planner::logical_to_physical,
+        physical_planner::{
+            logical_to_physical, AdaptivePlanner, MaterializedResults, QueryStageOutput,
+        },
         test::{dummy_scan_node, dummy_scan_operator},
         PhysicalPlan, PhysicalPlanRef,
     };
@@ -1681,6 +1827,168 @@ mod tests {
         Ok(())
     }
 
+    fn get_in_memory_join_plan(
+        cfg: Arc<DaftExecutionConfig>,
+        size_bytes: usize,
+        num_partitions: usize,
+    ) -> DaftResult<PhysicalPlanRef> {
+        let scan = |key: &str, other: &str| {
+            LogicalPlanBuilder::in_memory_scan(
+                key,
+                PartitionCacheEntry::new_rust(key.to_string(), Arc::new(())),
+                Arc::new(Schema::new(vec![
+                    Field::new("a", DataType::Int64),
+                    Field::new(other, DataType::Int64),
+                ])),
+                num_partitions,
+                size_bytes,
+                size_bytes / 16,
+                false,
+            )
+        };
+        let logical_plan = scan("left", "b")?
+            .join(
+                scan("right", "c")?,
+                None,
+                vec!["a".to_string()],
+                JoinType::Inner,
+                None,
+                Default::default(),
+            )?
+            .build();
+        logical_to_physical(logical_plan, cfg)
+    }
+
+    /// Tests that a sort-merge join is chosen over a hash join when the build side is too large to hash.
+    #[test]
+    fn sort_merge_join_for_large_build_side() -> DaftResult<()> {
+        let mut cfg = DaftExecutionConfig::default();
+        cfg.sort_merge_join_size_bytes_threshold = 1024 * 1024 * 1024;
+        let cfg = Arc::new(cfg);
+
+        // 4 GiB over 8 partitions is 512 MiB per partition, which can be hashed
+        let plan = get_in_memory_join_plan(cfg.clone(), 4 * 1024 * 1024 * 1024, 8)?;
+        assert_matches!(plan.as_ref(), PhysicalPlan::HashJoin(_));
+
+        // but 4 GiB over 2 partitions is too large
+        let plan = get_in_memory_join_plan(cfg, 4 * 1024 * 1024 * 1024, 2)?;
+        assert_matches!(plan.as_ref(), PhysicalPlan::SortMergeJoin(_));
+        Ok(())
+    }
+
+    fn get_adaptive_first_stage(
+        cfg: Arc<DaftExecutionConfig>,
+        logical_plan: Arc<LogicalPlan>,
+    ) -> DaftResult<(AdaptivePlanner, usize, PhysicalPlanRef)> {
+        let mut planner = AdaptivePlanner::new(logical_plan, cfg);
+        let QueryStageOutput::Partial {
+            physical_plan,
+            stage_id: Some(stage_id),
+        } = planner.next_stage()?
+        else {
+            panic!("expected a partial stage");
+        };
+        Ok((planner, stage_id, physical_plan))
+    }
+
+    /// Tests that adaptive hash joins of materialized inputs shuffle into as many partitions as their observed size needs.
+    #[test]
+    fn adaptive_hash_join_coalesces_partitions() -> DaftResult<()> {
+        let cfg = Arc::new(DaftExecutionConfig {
+            aqe_target_partition_size_bytes: 128 * 1024 * 1024,
+            ..Default::default()
+        });
+
+        let scan = |key: &str, other: &str| {
+            LogicalPlanBuilder::in_memory_scan(
+                key,
+                PartitionCacheEntry::new_rust(key.to_string(), Arc::new(())),
+                Arc::new(Schema::new(vec![
+                    Field::new("a", DataType::Int64),
+                    Field::new(other, DataType::Int64),
+                ])),
+                64,
+                1024 * 1024 * 1024,
+                1024,
+                false,
+            )
+        };
+        let logical_plan = scan("left", "b")?
+            .join(
+                scan("right", "c")?,
+                None,
+                vec!["a".to_string()],
+                JoinType::Inner,
+                Some(JoinStrategy::Hash),
+                Default::default(),
+            )?
+            .build();
+
+        // 2 GiB of inputs only need 16 partitions of 128 MiB, rather than the 64 partitions of the inputs.
+        let (_, _, stage) = get_adaptive_first_stage(cfg.clone(), logical_plan.clone())?;
+        assert_matches!(stage.as_ref(), PhysicalPlan::ShuffleExchange(_));
+        assert_eq!(stage.clustering_spec().num_partitions(), 16);
+
+        // Without AQE, the join keeps the partition count of its inputs.
+        let plan = logical_to_physical(logical_plan, cfg)?;
+        assert_eq!(plan.clustering_spec().num_partitions(), 64);
+        Ok(())
+    }
+
+    /// Tests that the shuffle of an aggregation is coalesced once the size of its materialized input is known.
+    #[test]
+    fn adaptive_shuffle_coalesces_partitions_of_small_stage() -> DaftResult<()> {
+        let cfg: Arc<DaftExecutionConfig> = DaftExecutionConfig::default().into();
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]));
+        let logical_plan = LogicalPlanBuilder::in_memory_scan(
+            "input",
+            PartitionCacheEntry::new_rust("input".to_string(), Arc::new(())),
+            schema.clone(),
+            64,
+            1024 * 1024 * 1024,
+            1024,
+            false,
+        )?
+        .aggregate(vec![resolved_col("a").sum()], vec![resolved_col("b")])?
+        .build();
+
+        // The first stage computes the partial aggregation, ahead of the shuffle.
+        let (mut planner, stage_id, stage) = get_adaptive_first_stage(cfg, logical_plan)?;
+        assert_matches!(stage.as_ref(), PhysicalPlan::Aggregate(_));
+
+        // It turned out to produce only 1 MiB, which fits into a single partition.
+        planner.update(MaterializedResults {
+            stage_id,
+            in_memory_info: InMemoryInfo::new(
+                schema,
+                "partial_agg".to_string(),
+                Some(PartitionCacheEntry::new_rust(
+                    "partial_agg".to_string(),
+                    Arc::new(()),
+                )),
+                64,
+                1024 * 1024,
+                64,
+                None,
+                Some(stage_id),
+                false,
+            ),
+        })?;
+        let QueryStageOutput::Partial {
+            physical_plan: stage,
+            ..
+        } = planner.next_stage()?
+        else {
+            panic!("expected a partial stage");
+        };
+        assert_matches!(stage.as_ref(), PhysicalPlan::ShuffleExchange(_));
+        assert_eq!(stage.clustering_spec().num_partitions(), 1);
+        Ok(())
+    }
+
     /// Tests that single partitions don't repartition.
     #[test]
     fn hash_join_single_partition_tests() -> DaftResult<()> {
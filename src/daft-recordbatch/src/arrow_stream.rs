@@ -0,0 +1,152 @@
+//! Import and export of [`RecordBatch`]es through the
+//! [Arrow C stream interface](https://arrow.apache.org/docs/format/CStreamInterface.html), which
+//! is how Arrow-native engines such as DuckDB, polars and ADBC drivers exchange streams of
+//! batches.
+use arrow2::{
+    datatypes::Field as ArrowField,
+    ffi::{export_chunk_iterator, ArrowArrayStream, ArrowArrayStreamReader},
+};
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{Schema, SchemaRef},
+    series::Series,
+    utils::arrow::{
+        cast_array_for_daft_if_needed, cast_array_from_daft_if_needed,
+        coerce_from_daft_compatible_type,
+    },
+};
+
+use crate::RecordBatch;
+
+/// Exports `batches` as an [`ArrowArrayStream`] of record batches with the given `schema`.
+///
+/// The batches are converted to Arrow lazily, as the consumer pulls them from the stream.
+pub fn export_record_batches<I>(schema: SchemaRef, batches: I) -> DaftResult<ArrowArrayStream>
+where
+    I: IntoIterator<Item = RecordBatch>,
+    I::IntoIter: 'static,
+{
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            let field = field.to_arrow()?;
+            Ok(match coerce_from_daft_compatible_type(field.data_type()) {
+                Some(data_type) => ArrowField::new(field.name, data_type, field.is_nullable),
+                None => field,
+            })
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    let arrow_schema = arrow2::datatypes::Schema::from(fields);
+
+    let chunks = batches.into_iter().map(move |batch| {
+        if batch.schema != schema {
+            return Err(arrow2::error::Error::InvalidArgumentError(format!(
+                "Expected all record batches exported to the stream to have schema {schema}, but found {}",
+                batch.schema
+            )));
+        }
+        let arrays = batch
+            .columns
+            .iter()
+            .map(|column| cast_array_from_daft_if_needed(column.to_arrow()))
+            .collect();
+        Ok(arrow2::chunk::Chunk::new(arrays))
+    });
+    Ok(export_chunk_iterator(Box::new(chunks), arrow_schema))
+}
+
+/// An iterator of [`RecordBatch`]es imported from an [`ArrowArrayStream`].
+///
+/// The stream is released when the reader is dropped.
+pub struct RecordBatchStreamReader {
+    reader: ArrowArrayStreamReader<Box<ArrowArrayStream>>,
+    schema: SchemaRef,
+}
+
+impl RecordBatchStreamReader {
+    /// Returns a reader of the record batches of `stream`.
+    ///
+    /// # Safety
+    /// `stream` must fulfill the invariants of the C stream interface, and produce arrays that
+    /// fulfill the C data interface.
+    pub unsafe fn try_new(stream: Box<ArrowArrayStream>) -> DaftResult<Self> {
+        let reader = unsafe { ArrowArrayStreamReader::try_new(stream)? };
+        let schema = Schema::from(&reader.schema()?);
+        Ok(Self {
+            reader,
+            schema: schema.into(),
+        })
+    }
+
+    /// The schema of the record batches of the stream.
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+}
+
+impl Iterator for RecordBatchStreamReader {
+    type Item = DaftResult<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Safety: upheld by the caller of `RecordBatchStreamReader::try_new`
+        let chunk = match unsafe { self.reader.next_chunk() }? {
+            Ok(chunk) => chunk,
+            Err(err) => return Some(Err(err.into())),
+        };
+        let num_rows = chunk.len();
+        if chunk.arrays().len() != self.schema.len() {
+            return Some(Err(DaftError::ValueError(format!(
+                "Expected {} columns in a record batch of the stream, got {}",
+                self.schema.len(),
+                chunk.arrays().len()
+            ))));
+        }
+        let columns = self
+            .schema
+            .fields()
+            .iter()
+            .zip(chunk.into_arrays())
+            .map(|(field, array)| {
+                Series::try_from((field.name.as_str(), cast_array_for_daft_if_needed(array)))
+            })
+            .collect::<DaftResult<Vec<_>>>();
+        Some(
+            columns.and_then(|columns| {
+                RecordBatch::new_with_size(self.schema.clone(), columns, num_rows)
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+
+    use super::{export_record_batches, RecordBatchStreamReader};
+    use crate::RecordBatch;
+
+    #[test]
+    fn record_batches_round_trip_through_c_stream() -> DaftResult<()> {
+        let batch = |values: Vec<i64>| {
+            let strings = values
+                .iter()
+                .map(|v| Some(v.to_string()))
+                .collect::<Vec<_>>();
+            RecordBatch::from_nonempty_columns(vec![
+                Int64Array::from(("a", values)).into_series(),
+                Utf8Array::from_iter("b", strings.into_iter()).into_series(),
+            ])
+        };
+        let batches = vec![batch(vec![1, 2])?, batch(vec![3])?];
+        let schema = batches[0].schema.clone();
+
+        let stream = export_record_batches(schema.clone(), batches.clone())?;
+        let reader = unsafe { RecordBatchStreamReader::try_new(Box::new(stream))? };
+        assert_eq!(reader.schema(), &schema);
+        let imported = reader.collect::<DaftResult<Vec<_>>>()?;
+        assert_eq!(imported, batches);
+        Ok(())
+    }
+}
@@ -11,7 +11,7 @@ use std::{
 };
 
 use arrow2::{array::Array, chunk::Chunk};
-use common_display::table_display::{make_comfy_table, StrValue};
+use common_display::table_display::{make_comfy_table, make_comfy_table_with_limits, StrValue};
 use common_error::{DaftError, DaftResult};
 use common_runtime::get_compute_runtime;
 use daft_core::{
@@ -21,12 +21,14 @@ use daft_core::{
     prelude::*,
 };
 use daft_dsl::{
-    functions::FunctionEvaluator, null_lit, resolved_col, AggExpr, ApproxPercentileParams, Column,
-    Expr, ExprRef, LiteralValue, PlanRef, ResolvedColumn, SketchType, UnresolvedColumn,
+    functions::{list_lambda::ListLambda, FunctionEvaluator},
+    null_lit, resolved_col, AggExpr, ApproxPercentileParams, CollectParams, Column, Expr, ExprRef,
+    LiteralValue, PlanRef, ResolvedColumn, SketchType, UnresolvedColumn,
 };
 use daft_logical_plan::FileInfos;
 use futures::{StreamExt, TryStreamExt};
 use num_traits::ToPrimitive;
+pub mod arrow_stream;
 #[cfg(feature = "python")]
 pub mod ffi;
 mod growable;
@@ -36,6 +38,7 @@ mod probeable;
 mod repr_html;
 
 pub use growable::GrowableRecordBatch;
+pub use ops::{AsofJoinBuild, RangeJoinBuild};
 pub use probeable::{make_probeable_builder, ProbeState, Probeable, ProbeableBuilder};
 
 #[cfg(feature = "python")]
@@ -43,7 +46,8 @@ pub mod python;
 #[cfg(feature = "python")]
 pub use python::register_modules;
 use rand::seq::index::sample;
-use repr_html::html_value;
+pub use repr_html::ReprOptions;
+use repr_html::{html_column, html_value_with_options};
 
 #[macro_export]
 macro_rules! value_err {
@@ -551,6 +555,9 @@ impl RecordBatch {
                             .into_series();
                         Ok(series)
                     }
+                    SketchType::SpaceSaving { capacity } => {
+                        evaled.space_saving_sketch(groups, capacity)
+                    }
                 }
             }
             &AggExpr::MergeSketch(ref expr, sketch_type) => {
@@ -558,8 +565,14 @@ impl RecordBatch {
                 match sketch_type {
                     SketchType::DDSketch => evaled.merge_sketch(groups),
                     SketchType::HyperLogLog => evaled.hll_merge(groups),
+                    SketchType::SpaceSaving { capacity } => {
+                        evaled.space_saving_merge(groups, capacity)
+                    }
                 }
             }
+            &AggExpr::ApproxTopK(ref expr, k) => {
+                self.eval_expression(expr)?.approx_top_k(groups, k)
+            }
             AggExpr::Mean(expr) => self.eval_expression(expr)?.mean(groups),
             AggExpr::Stddev(expr) => self.eval_expression(expr)?.stddev(groups),
             AggExpr::Min(expr) => self.eval_expression(expr)?.min(groups),
@@ -572,6 +585,24 @@ impl RecordBatch {
             AggExpr::List(expr) => self.eval_expression(expr)?.agg_list(groups),
             AggExpr::Set(expr) => self.eval_expression(expr)?.agg_set(groups),
             AggExpr::Concat(expr) => self.eval_expression(expr)?.agg_concat(groups),
+            AggExpr::Collect(CollectParams {
+                child,
+                distinct,
+                order_by,
+                descending,
+                limit,
+            }) => {
+                let order_by = order_by
+                    .as_ref()
+                    .map(|order_by| self.eval_expression(order_by))
+                    .transpose()?;
+                self.eval_expression(child)?.agg_collect(
+                    groups,
+                    order_by.as_ref().map(|order_by| (order_by, *descending)),
+                    *distinct,
+                    *limit,
+                )
+            }
             AggExpr::MapGroups { .. } => Err(DaftError::ValueError(
                 "MapGroups not supported via aggregation, use map_groups instead".to_string(),
             )),
@@ -629,28 +660,7 @@ impl RecordBatch {
             Expr::BinaryOp { op, left, right } => {
                 let lhs = self.eval_expression(left)?;
                 let rhs = self.eval_expression(right)?;
-                use daft_core::array::ops::{DaftCompare, DaftLogical};
-                use daft_dsl::Operator::*;
-                match op {
-                    Plus => lhs + rhs,
-                    Minus => lhs - rhs,
-                    TrueDivide => lhs / rhs,
-                    FloorDivide => lhs.floor_div(&rhs),
-                    Multiply => lhs * rhs,
-                    Modulus => lhs % rhs,
-                    Lt => Ok(lhs.lt(&rhs)?.into_series()),
-                    LtEq => Ok(lhs.lte(&rhs)?.into_series()),
-                    Eq => Ok(lhs.equal(&rhs)?.into_series()),
-                    EqNullSafe => Ok(lhs.eq_null_safe(&rhs)?.into_series()),
-                    NotEq => Ok(lhs.not_equal(&rhs)?.into_series()),
-                    GtEq => Ok(lhs.gte(&rhs)?.into_series()),
-                    Gt => Ok(lhs.gt(&rhs)?.into_series()),
-                    And => lhs.and(&rhs),
-                    Or => lhs.or(&rhs),
-                    Xor => lhs.xor(&rhs),
-                    ShiftLeft => lhs.shift_left(&rhs),
-                    ShiftRight => lhs.shift_right(&rhs),
-                }
+                op.evaluate(&lhs, &rhs)
             }
             Expr::Function { func, inputs } => {
                 let evaluated_inputs = inputs
@@ -659,13 +669,23 @@ impl RecordBatch {
                     .collect::<DaftResult<Vec<_>>>()?;
                 func.evaluate(evaluated_inputs.as_slice(), func)
             }
+            Expr::ScalarFunction(func) if func.udf.as_any().is::<ListLambda>() => {
+                let lambda = func.udf.as_any().downcast_ref::<ListLambda>().unwrap();
+                let input = match func.inputs.as_slice() {
+                    [input] => self.eval_expression(input)?,
+                    _ => return Err(DaftError::ValueError(format!("Expected 1 input to {}, received {}", func.name(), func.inputs.len()))),
+                };
+                lambda.evaluate_with(&input, |columns| {
+                    Self::from_nonempty_columns(columns)?.eval_expression(&lambda.body)
+                })
+            }
             Expr::ScalarFunction(func) => {
                 let evaluated_inputs = func
                     .inputs
                     .iter()
                     .map(|e| self.eval_expression(e))
                     .collect::<DaftResult<Vec<_>>>()?;
-                func.udf.evaluate(evaluated_inputs.as_slice())
+                func.udf.evaluate_batch(evaluated_inputs.as_slice(), self.len())
             }
             Expr::Literal(lit_value) => Ok(lit_value.to_series()),
             Expr::IfElse {
@@ -866,20 +886,50 @@ impl RecordBatch {
     }
 
     pub fn repr_html(&self) -> String {
+        self.repr_html_with_options(&ReprOptions::default())
+    }
+
+    pub fn repr_html_with_options(&self, options: &ReprOptions) -> String {
         // Produces a <table> HTML element.
 
+        // The indices of the columns and rows that are shown, where `None` stands for the ones that are hidden.
+        let visible = |len: usize, limit: Option<usize>| -> Vec<Option<usize>> {
+            match limit {
+                Some(limit) if len > limit => {
+                    let (head, tail) = (limit.div_ceil(2), limit / 2);
+                    (0..head)
+                        .map(Some)
+                        .chain(std::iter::once(None))
+                        .chain((len - tail..len).map(Some))
+                        .collect()
+                }
+                _ => (0..len).map(Some).collect(),
+            }
+        };
+        let cols = visible(self.num_columns(), options.max_columns);
+        let columns = cols
+            .iter()
+            .map(|idx| idx.map(|idx| html_column(&self.columns[idx], options)))
+            .collect::<Vec<_>>();
+
         let mut res = "<table class=\"dataframe\">\n".to_string();
 
         // Begin the header.
         res.push_str("<thead><tr>");
 
-        for field in self.schema.as_ref() {
+        for idx in &cols {
             res.push_str(
                 "<th style=\"text-wrap: nowrap; max-width:192px; overflow:auto; text-align:left\">",
             );
-            res.push_str(&html_escape::encode_text(&field.name));
-            res.push_str("<br />");
-            res.push_str(&html_escape::encode_text(&format!("{}", field.dtype)));
+            match idx {
+                Some(idx) => {
+                    let field = &self.schema.fields()[*idx];
+                    res.push_str(&html_escape::encode_text(&field.name));
+                    res.push_str("<br />");
+                    res.push_str(&html_escape::encode_text(&format!("{}", field.dtype)));
+                }
+                None => res.push_str("..."),
+            }
             res.push_str("</th>");
         }
 
@@ -889,45 +939,22 @@ impl RecordBatch {
         // Begin the body.
         res.push_str("<tbody>\n");
 
-        let (head_rows, tail_rows) = if self.len() > 10 {
-            (5, 5)
-        } else {
-            (self.len(), 0)
-        };
-
         let styled_td =
             "<td><div style=\"text-align:left; max-width:192px; max-height:64px; overflow:auto\">";
 
-        for i in 0..head_rows {
-            // Begin row.
-            res.push_str("<tr>");
-
-            for col in &*self.columns {
-                res.push_str(styled_td);
-                res.push_str(&html_value(col, i));
-                res.push_str("</div></td>");
-            }
-
-            // End row.
-            res.push_str("</tr>\n");
-        }
-
-        if tail_rows != 0 {
-            res.push_str("<tr>");
-            for _ in &*self.columns {
-                res.push_str("<td>...</td>");
-            }
-            res.push_str("</tr>\n");
-        }
-
-        for i in (self.len() - tail_rows)..(self.len()) {
+        for row in visible(self.len(), Some(options.max_rows.max(1))) {
             // Begin row.
             res.push_str("<tr>");
 
-            for col in &*self.columns {
-                res.push_str(styled_td);
-                res.push_str(&html_value(col, i));
-                res.push_str("</td>");
+            for col in &columns {
+                match (row, col) {
+                    (Some(i), Some(col)) => {
+                        res.push_str(styled_td);
+                        res.push_str(&html_value_with_options(col, i, options));
+                        res.push_str("</div></td>");
+                    }
+                    _ => res.push_str("<td>...</td>"),
+                }
             }
 
             // End row.
@@ -940,6 +967,27 @@ impl RecordBatch {
         res
     }
 
+    pub fn to_comfy_table_with_options(&self, options: &ReprOptions) -> comfy_table::Table {
+        let str_values = self
+            .columns
+            .iter()
+            .map(|s| s as &dyn StrValue)
+            .collect::<Vec<_>>();
+
+        make_comfy_table_with_limits(
+            self.schema
+                .into_iter()
+                .map(|field| format!("{}\n---\n{}", field.name, field.dtype))
+                .collect::<Vec<_>>()
+                .as_slice(),
+            Some(str_values.as_slice()),
+            Some(self.len()),
+            Some(options.max_width.unwrap_or(32)),
+            options.max_rows,
+            options.max_columns,
+        )
+    }
+
     pub fn to_comfy_table(&self, max_col_width: Option<usize>) -> comfy_table::Table {
         let str_values = self
             .columns
@@ -1020,6 +1068,26 @@ impl TryFrom<&FileInfos> for RecordBatch {
                 "num_rows",
                 arrow2::array::PrimitiveArray::<i64>::from(&file_info.num_rows).to_boxed(),
             ))?,
+            Series::try_from((
+                "last_modified",
+                arrow2::array::PrimitiveArray::<i64>::from(&file_info.last_modified).to_boxed(),
+            ))?
+            .cast(&DataType::Timestamp(
+                TimeUnit::Microseconds,
+                Some("UTC".to_string()),
+            ))?,
+            Series::try_from((
+                "etag",
+                arrow2::array::Utf8Array::<i64>::from(&file_info.etags).to_boxed(),
+            ))?,
+            Series::try_from((
+                "version_id",
+                arrow2::array::Utf8Array::<i64>::from(&file_info.version_ids).to_boxed(),
+            ))?,
+            Series::try_from((
+                "storage_class",
+                arrow2::array::Utf8Array::<i64>::from(&file_info.storage_classes).to_boxed(),
+            ))?,
         ];
         Self::from_nonempty_columns(columns)
     }
@@ -1095,4 +1163,97 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn list_lambda_expressions() -> DaftResult<()> {
+        use daft_dsl::{
+            functions::list_lambda::{accumulator, element, list_filter, list_map, list_reduce},
+            lit,
+        };
+
+        // [1, 2, 3], null, [], [4, 5]
+        let xs = ListArray::new(
+            Field::new("xs", DataType::List(Box::new(DataType::Int64))),
+            Int64Array::from(("item", vec![1, 2, 3, 4, 5])).into_series(),
+            arrow2::offset::OffsetsBuffer::try_from(vec![0, 3, 3, 3, 5])?,
+            Some(arrow2::bitmap::Bitmap::from(vec![true, false, true, true])),
+        )
+        .into_series();
+        let table = RecordBatch::from_nonempty_columns(vec![xs])?;
+        let to_vecs = |s: Series| -> DaftResult<Vec<Option<Vec<i64>>>> {
+            Ok(s.list()?
+                .into_iter()
+                .map(|l| l.map(|l| l.i64().unwrap().into_iter().map(|v| *v.unwrap()).collect()))
+                .collect())
+        };
+
+        let doubled =
+            table.eval_expression(&list_map(resolved_col("xs"), element().mul(lit(2))))?;
+        assert_eq!(doubled.name(), "xs");
+        assert_eq!(
+            to_vecs(doubled)?,
+            vec![Some(vec![2, 4, 6]), None, Some(vec![]), Some(vec![8, 10])]
+        );
+
+        let odd = table.eval_expression(&list_filter(
+            resolved_col("xs"),
+            element().rem(lit(2)).eq(lit(1)),
+        ))?;
+        assert_eq!(
+            to_vecs(odd)?,
+            vec![Some(vec![1, 3]), None, Some(vec![]), Some(vec![5])]
+        );
+
+        let summed = table.eval_expression(&list_reduce(
+            resolved_col("xs"),
+            accumulator().add(element()),
+        ))?;
+        assert_eq!(
+            summed
+                .i64()?
+                .into_iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(6), None, None, Some(9)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn collect_aggregations() -> DaftResult<()> {
+        let values = Int64Array::from_iter(
+            Field::new("values", DataType::Int64),
+            vec![Some(3), Some(1), Some(3), None, Some(2)].into_iter(),
+        )
+        .into_series();
+        let keys = Int64Array::from(("keys", vec![5, 4, 3, 2, 1])).into_series();
+        let table = RecordBatch::from_nonempty_columns(vec![values, keys])?;
+        let collect = |distinct, order_by: Option<bool>, limit| -> DaftResult<Vec<Option<i64>>> {
+            let expr = resolved_col("values").agg_collect(
+                distinct,
+                order_by.map(|_| resolved_col("keys")),
+                order_by.unwrap_or(false),
+                limit,
+            );
+            let result = table.eval_expression(&expr)?;
+            let list = result.list()?.get(0).unwrap();
+            Ok(list.i64()?.into_iter().map(|v| v.copied()).collect())
+        };
+
+        assert_eq!(
+            collect(false, None, None)?,
+            vec![Some(3), Some(1), Some(3), None, Some(2)]
+        );
+        assert_eq!(
+            collect(false, Some(false), Some(3))?,
+            vec![Some(2), None, Some(3)]
+        );
+        assert_eq!(collect(false, Some(true), Some(2))?, vec![Some(3), Some(1)]);
+        assert_eq!(
+            collect(true, Some(false), None)?,
+            vec![Some(2), Some(3), Some(1)]
+        );
+        assert_eq!(collect(true, None, Some(2))?, vec![Some(3), Some(1)]);
+        Ok(())
+    }
 }
@@ -21,7 +21,11 @@ fn lengths_to_indices(lengths: &UInt64Array, capacity: usize) -> DaftResult<UInt
 }
 
 impl RecordBatch {
-    pub fn explode<E: AsRef<Expr>>(&self, exprs: &[E]) -> DaftResult<Self> {
+    /// Explodes the list columns of `exprs`, which must all have the same lengths in each row.
+    ///
+    /// With `unnest_structs`, exploded columns of structs are replaced by a column for each of
+    /// their fields, so that a list of structs becomes top-level columns in one pass.
+    pub fn explode<E: AsRef<Expr>>(&self, exprs: &[E], unnest_structs: bool) -> DaftResult<Self> {
         if exprs.is_empty() {
             return Err(DaftError::ValueError(format!(
                 "Explode needs at least 1 expression, received: {}",
@@ -81,6 +85,10 @@ impl RecordBatch {
         let capacity_expected = exploded_columns.first().unwrap().len();
         let take_idx = lengths_to_indices(&first_len, capacity_expected)?.into_series();
 
+        let exploded_names = exploded_columns
+            .iter()
+            .map(|s| s.name().to_string())
+            .collect::<Vec<_>>();
         let mut new_series = Arc::unwrap_or_clone(self.columns.clone());
 
         for i in 0..self.num_columns() {
@@ -96,6 +104,22 @@ impl RecordBatch {
             }
         }
         new_series.extend_from_slice(exploded_columns.as_slice());
+        if unnest_structs {
+            new_series = new_series
+                .into_iter()
+                .map(|s| match s.data_type() {
+                    DataType::Struct(fields) if exploded_names.iter().any(|n| n == s.name()) => {
+                        // go through `get` so that null structs become null fields
+                        let array = s.struct_()?;
+                        fields.iter().map(|f| array.get(&f.name)).collect()
+                    }
+                    _ => Ok(vec![s]),
+                })
+                .collect::<DaftResult<Vec<_>>>()?
+                .into_iter()
+                .flatten()
+                .collect();
+        }
         Self::from_nonempty_columns(new_series)
     }
 }
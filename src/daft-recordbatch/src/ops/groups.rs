@@ -28,6 +28,12 @@ impl RecordBatch {
             return self.columns.first().unwrap().make_groups();
         }
 
+        // composite keys are grouped by their row encodings where they can be
+        let all_equal = vec![true; self.num_columns()];
+        if let Some(keys) = self.encode_keys(&all_equal, &all_equal)? {
+            return keys.columns.first().unwrap().make_groups();
+        }
+
         let probe_table = self.to_probe_hash_table()?;
         let mut key_indices: Vec<u64> = Vec::with_capacity(probe_table.len());
         let mut values_indices: Vec<Vec<u64>> = Vec::with_capacity(probe_table.len());
@@ -0,0 +1,345 @@
+//! As-of joins, which match each left row with the right row whose key is nearest to it.
+//!
+//! The build side is sorted on its `by` keys followed by its `on` key, so the candidates of each
+//! probe row are the two build rows on either side of it within its `by` group, found by binary
+//! search.
+use std::{cmp::Ordering, sync::Arc};
+
+use arrow2::bitmap::MutableBitmap;
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    array::ops::{as_arrow::AsArrow, build_multi_array_bicompare, DaftCompare},
+    prelude::*,
+};
+use daft_dsl::{
+    join::{get_common_join_cols, infer_join_schema, normalize_join_keys},
+    ExprRef,
+};
+
+use super::{
+    add_non_join_key_columns,
+    range_join::{partition_point, sort_build},
+};
+use crate::RecordBatch;
+
+/// The build side of an as-of join, sorted so that it can be probed by binary search.
+#[derive(Debug)]
+pub struct AsofJoinBuild {
+    table: RecordBatch,
+    /// The `by` keys followed by the `on` key, evaluated on `table`.
+    keys: Vec<Series>,
+    strategy: AsofStrategy,
+    tolerance: Option<Series>,
+}
+
+impl AsofJoinBuild {
+    /// Prepares `table` to be joined with probe tables on the `by` keys and the `on` key.
+    ///
+    /// With a `tolerance`, build rows whose key is further than it from the probe row's key
+    /// don't match. Rows with a null key never match, so they're dropped here.
+    pub fn try_new(
+        table: &RecordBatch,
+        by: &[ExprRef],
+        on: &ExprRef,
+        strategy: AsofStrategy,
+        tolerance: Option<&ExprRef>,
+    ) -> DaftResult<Self> {
+        let key_exprs = by.iter().chain(std::iter::once(on)).collect::<Vec<_>>();
+        let (table, keys) = sort_build(table, &key_exprs)?;
+        let tolerance = tolerance.map(|t| table.eval_expression(t)).transpose()?;
+        Ok(Self {
+            table,
+            keys,
+            strategy,
+            tolerance,
+        })
+    }
+
+    /// The build row matched by each probe row, or `None` for the ones without a match.
+    fn matches(
+        &self,
+        probe: &RecordBatch,
+        by: &[ExprRef],
+        on: &ExprRef,
+    ) -> DaftResult<Vec<Option<u64>>> {
+        let num_by = self.keys.len() - 1;
+        if by.len() != num_by {
+            return Err(DaftError::ValueError(format!(
+                "Mismatch of join by clauses: left: {:?} vs right: {:?}",
+                by.len(),
+                num_by
+            )));
+        }
+        let probe_keys = by
+            .iter()
+            .chain(std::iter::once(on))
+            .zip(&self.keys)
+            .map(|(expr, build_key)| {
+                let key = probe.eval_expression(expr)?;
+                if key.data_type() == build_key.data_type() {
+                    Ok(key)
+                } else {
+                    key.cast(build_key.data_type())
+                }
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        let compare = |build_keys: &[Series], probe_keys: &[Series]| {
+            let flags = vec![false; build_keys.len()];
+            build_multi_array_bicompare(build_keys, probe_keys, &flags, &flags)
+        };
+        let cmp = compare(&self.keys, &probe_keys)?;
+        let by_cmp = if num_by == 0 {
+            None
+        } else {
+            Some(compare(&self.keys[..num_by], &probe_keys[..num_by])?)
+        };
+
+        let num_build = self.table.len();
+        let mut backward = Vec::with_capacity(probe.len());
+        let mut forward = Vec::with_capacity(probe.len());
+        for row in 0..probe.len() {
+            if probe_keys
+                .iter()
+                .any(|k| k.data_type().is_null() || !k.is_valid(row))
+            {
+                backward.push(None);
+                forward.push(None);
+                continue;
+            }
+            let (group_start, group_end) = match &by_cmp {
+                Some(by_cmp) => (
+                    partition_point(num_build, |b| by_cmp(b, row) == Ordering::Less),
+                    partition_point(num_build, |b| by_cmp(b, row) != Ordering::Greater),
+                ),
+                None => (0, num_build),
+            };
+            // the last build row at or before the probe key, and the first one at or after it
+            let after = partition_point(num_build, |b| cmp(b, row) != Ordering::Greater);
+            let at = partition_point(num_build, |b| cmp(b, row) == Ordering::Less);
+            backward.push((after > group_start).then(|| after as u64 - 1));
+            forward.push((at < group_end).then_some(at as u64));
+        }
+
+        let on_key = &probe_keys[num_by];
+        let build_key = &self.keys[num_by];
+        let distance = |candidates: &[Option<u64>], backward: bool| -> DaftResult<Series> {
+            let idx = UInt64Array::from_iter(
+                Field::new("idx", DataType::UInt64),
+                candidates.iter().copied(),
+            )
+            .into_series();
+            let candidate_keys = build_key.take(&idx)?;
+            if backward {
+                on_key - &candidate_keys
+            } else {
+                &candidate_keys - on_key
+            }
+        };
+        let (matches, distances) = match self.strategy {
+            AsofStrategy::Backward if self.tolerance.is_none() => return Ok(backward),
+            AsofStrategy::Forward if self.tolerance.is_none() => return Ok(forward),
+            AsofStrategy::Backward => {
+                let distances = distance(&backward, true)?;
+                (backward, distances)
+            }
+            AsofStrategy::Forward => {
+                let distances = distance(&forward, false)?;
+                (forward, distances)
+            }
+            AsofStrategy::Nearest => {
+                let backward_distances = distance(&backward, true)?;
+                let forward_distances = distance(&forward, false)?;
+                // prefer the backward match when both are as near
+                let use_forward = backward
+                    .iter()
+                    .zip(&forward)
+                    .zip(forward_distances.lt(&backward_distances)?.as_arrow())
+                    .map(|((b, f), closer)| f.is_some() && (b.is_none() || closer == Some(true)))
+                    .collect::<Vec<_>>();
+                let distances = forward_distances.if_else(
+                    &backward_distances,
+                    &BooleanArray::from(("use_forward", use_forward.as_slice())).into_series(),
+                )?;
+                let matches = backward
+                    .into_iter()
+                    .zip(forward)
+                    .zip(use_forward)
+                    .map(|((b, f), use_forward)| if use_forward { f } else { b })
+                    .collect();
+                (matches, distances)
+            }
+        };
+        let Some(tolerance) = &self.tolerance else {
+            return Ok(matches);
+        };
+        let tolerance = if tolerance.data_type() == distances.data_type() {
+            tolerance.clone()
+        } else {
+            tolerance.cast(distances.data_type())?
+        };
+        let within = distances.lte(&tolerance)?;
+        Ok(matches
+            .into_iter()
+            .zip(within.as_arrow().iter())
+            .map(|(m, within)| m.filter(|_| within == Some(true)))
+            .collect())
+    }
+
+    /// Left joins `probe` with the build side, matching each probe row with at most one build
+    /// row. `by` and `on` are the keys of `probe`, in the same order as the build side's.
+    pub fn probe(
+        &self,
+        probe: &RecordBatch,
+        by: &[ExprRef],
+        on: &ExprRef,
+    ) -> DaftResult<RecordBatch> {
+        let matches = self.matches(probe, by, on)?;
+        let mut r_valid = MutableBitmap::with_capacity(matches.len());
+        let right_idx = matches
+            .iter()
+            .map(|m| {
+                r_valid.push(m.is_some());
+                m.unwrap_or(0)
+            })
+            .collect::<Vec<_>>();
+        let lidx = UInt64Array::from(("left_indices", (0..probe.len() as u64).collect::<Vec<_>>()))
+            .into_series();
+        let ridx = UInt64Array::from(("right_indices", right_idx))
+            .with_validity(Some(r_valid.into()))?
+            .into_series();
+
+        let join_schema = infer_join_schema(&probe.schema, &self.table.schema, JoinType::Left)?;
+        let common_cols =
+            get_common_join_cols(&probe.schema, &self.table.schema).collect::<Vec<_>>();
+        let join_series = Arc::unwrap_or_clone(probe.get_columns(&common_cols)?.columns);
+        let join_series = add_non_join_key_columns(probe, &self.table, lidx, ridx, join_series)?;
+        RecordBatch::new_with_size(join_schema, join_series, probe.len())
+    }
+}
+
+impl RecordBatch {
+    /// Matches each row of this table with the row of `right` in the same `by` group whose `on`
+    /// key is nearest to it in the direction given by `strategy`, keeping every row of this table.
+    #[allow(clippy::too_many_arguments)]
+    pub fn asof_join(
+        &self,
+        right: &Self,
+        left_on: &ExprRef,
+        right_on: &ExprRef,
+        left_by: &[ExprRef],
+        right_by: &[ExprRef],
+        strategy: AsofStrategy,
+        tolerance: Option<&ExprRef>,
+    ) -> DaftResult<Self> {
+        if left_by.len() != right_by.len() {
+            return Err(DaftError::ValueError(format!(
+                "Mismatch of join by clauses: left: {:?} vs right: {:?}",
+                left_by.len(),
+                right_by.len()
+            )));
+        }
+        let (mut left_keys, mut right_keys) = normalize_join_keys(
+            left_by.iter().chain([left_on]).cloned().collect(),
+            right_by.iter().chain([right_on]).cloned().collect(),
+            self.schema.clone(),
+            right.schema.clone(),
+        )?;
+        let (left_on, right_on) = (left_keys.pop().unwrap(), right_keys.pop().unwrap());
+        AsofJoinBuild::try_new(right, &right_keys, &right_on, strategy, tolerance)?
+            .probe(self, &left_keys, &left_on)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::{array::ops::as_arrow::AsArrow, prelude::*};
+    use daft_dsl::{lit, resolved_col};
+
+    use crate::RecordBatch;
+
+    fn trades() -> DaftResult<RecordBatch> {
+        RecordBatch::from_nonempty_columns(vec![
+            Utf8Array::from(("sym", ["a", "a", "b", "a", "b"].as_slice())).into_series(),
+            Int64Array::from_iter(
+                Field::new("ts", DataType::Int64),
+                vec![Some(1), Some(5), Some(5), Some(12), None].into_iter(),
+            )
+            .into_series(),
+        ])
+    }
+
+    fn quotes() -> DaftResult<RecordBatch> {
+        RecordBatch::from_nonempty_columns(vec![
+            Utf8Array::from(("sym", ["a", "a", "b", "a", "a"].as_slice())).into_series(),
+            Int64Array::from(("quote_ts", vec![4, 0, 6, 10, 3])).into_series(),
+            Int64Array::from(("price", vec![40, 0, 60, 100, 31])).into_series(),
+        ])
+    }
+
+    fn prices(
+        strategy: AsofStrategy,
+        by: bool,
+        tolerance: Option<i64>,
+    ) -> DaftResult<Vec<Option<i64>>> {
+        let by = if by {
+            vec![resolved_col("sym")]
+        } else {
+            vec![]
+        };
+        let tolerance = tolerance.map(lit);
+        let joined = trades()?.asof_join(
+            &quotes()?,
+            &resolved_col("ts"),
+            &resolved_col("quote_ts"),
+            &by,
+            &by,
+            strategy,
+            tolerance.as_ref(),
+        )?;
+        assert_eq!(joined.len(), 5);
+        Ok(joined
+            .get_column("price")?
+            .i64()?
+            .as_arrow()
+            .iter()
+            .map(|p| p.copied())
+            .collect())
+    }
+
+    #[test]
+    fn asof_join_strategies() -> DaftResult<()> {
+        assert_eq!(
+            prices(AsofStrategy::Backward, true, None)?,
+            vec![Some(0), Some(40), None, Some(100), None]
+        );
+        assert_eq!(
+            prices(AsofStrategy::Forward, true, None)?,
+            vec![Some(31), Some(100), Some(60), None, None]
+        );
+        assert_eq!(
+            prices(AsofStrategy::Nearest, true, None)?,
+            vec![Some(0), Some(40), Some(60), Some(100), None]
+        );
+        // without `by`, the `a` trade at 5 is matched with the `b` quote at 6
+        assert_eq!(
+            prices(AsofStrategy::Forward, false, None)?,
+            vec![Some(31), Some(60), Some(60), None, None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn asof_join_tolerance() -> DaftResult<()> {
+        assert_eq!(
+            prices(AsofStrategy::Backward, true, Some(1))?,
+            vec![Some(0), Some(40), None, None, None]
+        );
+        assert_eq!(
+            prices(AsofStrategy::Nearest, true, Some(1))?,
+            vec![Some(0), Some(40), Some(60), None, None]
+        );
+        Ok(())
+    }
+}
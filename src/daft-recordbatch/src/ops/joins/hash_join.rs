@@ -11,7 +11,7 @@ use daft_dsl::{
     ExprRef,
 };
 
-use super::{add_non_join_key_columns, match_types_for_tables};
+use super::{add_non_join_key_columns, encode_join_keys, match_types_for_tables};
 use crate::RecordBatch;
 pub(super) fn hash_inner_join(
     left: &RecordBatch,
@@ -34,6 +34,9 @@ pub(super) fn hash_inner_join(
             UInt64Array::empty("right_indices", &DataType::UInt64).into_series(),
         )
     } else {
+        let (lkeys, rkeys, null_equals_nulls) =
+            encode_join_keys(&lkeys, &rkeys, null_equals_nulls)?;
+
         // probe on the smaller table
         let probe_left = lkeys.len() <= rkeys.len();
 
@@ -50,7 +53,7 @@ pub(super) fn hash_inner_join(
         let is_equal = build_multi_array_is_equal(
             lkeys.columns.as_slice(),
             rkeys.columns.as_slice(),
-            null_equals_nulls,
+            &null_equals_nulls,
             vec![false; lkeys.columns.len()].as_slice(),
         )?;
 
@@ -131,6 +134,8 @@ pub(super) fn hash_left_right_join(
             .into_series(),
         )
     } else {
+        let (lkeys, rkeys, null_equals_nulls) =
+            encode_join_keys(&lkeys, &rkeys, null_equals_nulls)?;
         let probe_table = lkeys.to_probe_hash_table()?;
 
         let r_hashes = rkeys.hash_rows()?;
@@ -138,7 +143,7 @@ pub(super) fn hash_left_right_join(
         let is_equal = build_multi_array_is_equal(
             lkeys.columns.as_slice(),
             rkeys.columns.as_slice(),
-            null_equals_nulls,
+            &null_equals_nulls,
             vec![false; lkeys.columns.len()].as_slice(),
         )?;
 
@@ -231,6 +236,8 @@ pub(super) fn hash_semi_anti_join(
             UInt64Array::empty("left_indices", &DataType::UInt64).into_series()
         }
     } else {
+        let (lkeys, rkeys, null_equals_nulls) =
+            encode_join_keys(&lkeys, &rkeys, null_equals_nulls)?;
         let probe_table = rkeys.to_probe_hash_map_without_idx()?;
 
         let l_hashes = lkeys.hash_rows()?;
@@ -238,7 +245,7 @@ pub(super) fn hash_semi_anti_join(
         let is_equal = build_multi_array_is_equal(
             lkeys.columns.as_slice(),
             rkeys.columns.as_slice(),
-            null_equals_nulls,
+            &null_equals_nulls,
             vec![false; lkeys.columns.len()].as_slice(),
         )?;
         let rows = rkeys.len();
@@ -303,6 +310,9 @@ pub(super) fn hash_outer_join(
             UInt64Array::from(("right_indices", r_arrow)).into_series(),
         )
     } else {
+        let (lkeys, rkeys, null_equals_nulls) =
+            encode_join_keys(&lkeys, &rkeys, null_equals_nulls)?;
+
         // probe on the smaller table
         let probe_left = lkeys.len() <= rkeys.len();
 
@@ -319,7 +329,7 @@ pub(super) fn hash_outer_join(
         let is_equal = build_multi_array_is_equal(
             lkeys.columns.as_slice(),
             rkeys.columns.as_slice(),
-            null_equals_nulls,
+            &null_equals_nulls,
             vec![false; lkeys.columns.len()].as_slice(),
         )?;
 
@@ -12,8 +12,13 @@ use hash_join::hash_semi_anti_join;
 
 use self::hash_join::{hash_inner_join, hash_left_right_join, hash_outer_join};
 use crate::RecordBatch;
+mod asof_join;
 mod hash_join;
 mod merge_join;
+mod range_join;
+
+pub use asof_join::AsofJoinBuild;
+pub use range_join::RangeJoinBuild;
 
 fn match_types_for_tables(
     left: &RecordBatch,
@@ -41,6 +46,23 @@ fn match_types_for_tables(
     ))
 }
 
+/// Row encodes composite join keys of both sides, so that they're hashed and compared as one binary key, returning
+/// the equality of nulls of the keys that are.
+fn encode_join_keys(
+    left: &RecordBatch,
+    right: &RecordBatch,
+    null_equals_nulls: &[bool],
+) -> DaftResult<(RecordBatch, RecordBatch, Vec<bool>)> {
+    let nans_equal = vec![false; null_equals_nulls.len()];
+    match (
+        left.encode_keys(null_equals_nulls, &nans_equal)?,
+        right.encode_keys(null_equals_nulls, &nans_equal)?,
+    ) {
+        (Some(left), Some(right)) => Ok((left, right, vec![false])),
+        _ => Ok((left.clone(), right.clone(), null_equals_nulls.to_vec())),
+    }
+}
+
 fn add_non_join_key_columns(
     left: &RecordBatch,
     right: &RecordBatch,
@@ -0,0 +1,570 @@
+//! Joins on inequality conditions, such as `a.start <= b.ts AND b.ts < a.end`.
+//!
+//! The build side is sorted on its equality keys followed by the build side expression of the
+//! first range condition, so the matches of each probe row are a contiguous run of build rows
+//! that is found by binary search. Conditions that bound a different expression, and any other
+//! predicate, are checked on the matched pairs.
+use std::{cmp::Ordering, sync::Arc};
+
+use arrow2::bitmap::MutableBitmap;
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    array::ops::{as_arrow::AsArrow, build_multi_array_bicompare},
+    prelude::*,
+};
+use daft_dsl::{
+    join::{
+        get_common_join_cols, infer_join_schema, normalize_join_keys, normalize_range_conditions,
+        RangeCondition,
+    },
+    ExprRef, Operator,
+};
+
+use super::add_non_join_key_columns;
+use crate::RecordBatch;
+
+/// A condition that bounds the sort key of the build side.
+#[derive(Clone, Copy, Debug)]
+struct Bound {
+    condition: usize,
+    inclusive: bool,
+}
+
+/// The conditions that bound the sort key from below and above, and the ones that are checked
+/// on each matched pair instead.
+#[derive(Debug)]
+struct Bounds {
+    lower: Option<Bound>,
+    upper: Option<Bound>,
+    rest: Vec<usize>,
+}
+
+impl Bounds {
+    fn new(conditions: &[RangeCondition]) -> Self {
+        let key = conditions.first().map(|c| &c.right);
+        let mut bounds = Self {
+            lower: None,
+            upper: None,
+            rest: Vec::new(),
+        };
+        for (condition, c) in conditions.iter().enumerate() {
+            // `probe < build` bounds the build side from below, `probe > build` from above.
+            let (slot, inclusive) = match c.op {
+                Operator::Lt => (&mut bounds.lower, false),
+                Operator::LtEq => (&mut bounds.lower, true),
+                Operator::Gt => (&mut bounds.upper, false),
+                Operator::GtEq => (&mut bounds.upper, true),
+                _ => {
+                    bounds.rest.push(condition);
+                    continue;
+                }
+            };
+            if slot.is_none() && Some(&c.right) == key {
+                *slot = Some(Bound {
+                    condition,
+                    inclusive,
+                });
+            } else {
+                bounds.rest.push(condition);
+            }
+        }
+        bounds
+    }
+}
+
+/// The first index in `0..len` for which `pred` is false, where `pred` is true for a prefix of
+/// the range.
+pub(super) fn partition_point(len: usize, pred: impl Fn(usize) -> bool) -> usize {
+    let (mut lo, mut hi) = (0, len);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(mid) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Sorts the build side of a join on `key_exprs`, dropping the rows with a null key since they
+/// can't match. Returns the sorted table and its evaluated keys.
+pub(super) fn sort_build(
+    table: &RecordBatch,
+    key_exprs: &[&ExprRef],
+) -> DaftResult<(RecordBatch, Vec<Series>)> {
+    let keys = key_exprs
+        .iter()
+        .map(|e| table.eval_expression(e))
+        .collect::<DaftResult<Vec<_>>>()?;
+
+    let valid = (0..table.len() as u64)
+        .filter(|i| {
+            keys.iter()
+                .all(|k| !k.data_type().is_null() && k.is_valid(*i as usize))
+        })
+        .collect::<Vec<_>>();
+    let (table, keys) = if valid.len() == table.len() {
+        (table.clone(), keys)
+    } else {
+        let idx = UInt64Array::from(("idx", valid)).into_series();
+        let keys = keys
+            .iter()
+            .map(|k| k.take(&idx))
+            .collect::<DaftResult<Vec<_>>>()?;
+        (table.take(&idx)?, keys)
+    };
+
+    let (table, keys) = if keys.is_empty() {
+        (table, keys)
+    } else {
+        let flags = vec![false; keys.len()];
+        let argsort = Series::argsort_multikey(&keys, &flags, &flags)?;
+        let keys = keys
+            .iter()
+            .map(|k| k.take(&argsort))
+            .collect::<DaftResult<Vec<_>>>()?;
+        (table.take(&argsort)?, keys)
+    };
+
+    Ok((table, keys))
+}
+
+/// The build side of a range join, sorted so that it can be probed by binary search.
+#[derive(Debug)]
+pub struct RangeJoinBuild {
+    table: RecordBatch,
+    /// The equality keys followed by the sort key, evaluated on `table`.
+    keys: Vec<Series>,
+    num_eq_keys: usize,
+    conditions: Vec<RangeCondition>,
+    bounds: Bounds,
+    residual: Option<ExprRef>,
+}
+
+impl RangeJoinBuild {
+    /// Prepares `table` to be joined with probe tables.
+    ///
+    /// `on` are the equality keys of `table`, and `conditions` compare an expression of the probe
+    /// side on the left with an expression of `table` on the right. Matched pairs must also
+    /// satisfy `residual`, which is evaluated on the columns of both sides. Rows with a null key
+    /// never match, so they're dropped.
+    pub fn try_new(
+        table: &RecordBatch,
+        on: &[ExprRef],
+        conditions: Vec<RangeCondition>,
+        residual: Option<ExprRef>,
+    ) -> DaftResult<Self> {
+        let bounds = Bounds::new(&conditions);
+        let key_exprs = on
+            .iter()
+            .chain(conditions.first().map(|c| &c.right))
+            .collect::<Vec<_>>();
+        let (table, keys) = sort_build(table, &key_exprs)?;
+
+        Ok(Self {
+            table,
+            keys,
+            num_eq_keys: on.len(),
+            conditions,
+            bounds,
+            residual,
+        })
+    }
+
+    /// Evaluates the probe side of the equality keys and bounds, cast to the types of the build
+    /// side.
+    fn probe_keys(
+        &self,
+        probe: &RecordBatch,
+        on: &[ExprRef],
+    ) -> DaftResult<(Vec<Series>, Option<Series>, Option<Series>)> {
+        if on.len() != self.num_eq_keys {
+            return Err(DaftError::ValueError(format!(
+                "Mismatch of join on clauses: left: {:?} vs right: {:?}",
+                on.len(),
+                self.num_eq_keys
+            )));
+        }
+        let eval = |expr: &ExprRef, build_key: &Series| {
+            let key = probe.eval_expression(expr)?;
+            if key.data_type() == build_key.data_type() {
+                Ok(key)
+            } else {
+                key.cast(build_key.data_type())
+            }
+        };
+        let eq_keys = on
+            .iter()
+            .zip(&self.keys)
+            .map(|(e, k)| eval(e, k))
+            .collect::<DaftResult<Vec<_>>>()?;
+        let bound_key = |bound: Option<Bound>| {
+            bound
+                .map(|b| {
+                    eval(
+                        &self.conditions[b.condition].left,
+                        &self.keys[self.num_eq_keys],
+                    )
+                })
+                .transpose()
+        };
+        Ok((
+            eq_keys,
+            bound_key(self.bounds.lower)?,
+            bound_key(self.bounds.upper)?,
+        ))
+    }
+
+    /// The pairs of probe and build rows that satisfy the equality keys and bounds.
+    fn candidates(&self, probe: &RecordBatch, on: &[ExprRef]) -> DaftResult<(Vec<u64>, Vec<u64>)> {
+        let (eq_keys, lower, upper) = self.probe_keys(probe, on)?;
+        let num_build = self.table.len();
+        let eq_build_keys = &self.keys[..self.num_eq_keys];
+
+        let compare = |build_keys: &[Series], probe_keys: &[Series]| {
+            let flags = vec![false; build_keys.len()];
+            build_multi_array_bicompare(build_keys, probe_keys, &flags, &flags)
+        };
+        let with_bound = |bound: &Option<Series>| {
+            bound
+                .as_ref()
+                .map(|b| {
+                    let mut probe_keys = eq_keys.clone();
+                    probe_keys.push(b.clone());
+                    compare(&self.keys, &probe_keys)
+                })
+                .transpose()
+        };
+        let eq_cmp = if eq_keys.is_empty() {
+            None
+        } else {
+            Some(compare(eq_build_keys, &eq_keys)?)
+        };
+        let lower_cmp = with_bound(&lower)?;
+        let upper_cmp = with_bound(&upper)?;
+        let lower_inclusive = self.bounds.lower.is_some_and(|b| b.inclusive);
+        let upper_inclusive = self.bounds.upper.is_some_and(|b| b.inclusive);
+
+        let probe_keys = eq_keys
+            .iter()
+            .chain(&lower)
+            .chain(&upper)
+            .collect::<Vec<_>>();
+
+        let mut probe_idx = Vec::new();
+        let mut build_idx = Vec::new();
+        for row in 0..probe.len() {
+            if probe_keys
+                .iter()
+                .any(|k| k.data_type().is_null() || !k.is_valid(row))
+            {
+                continue;
+            }
+            let start = match (&lower_cmp, &eq_cmp) {
+                (Some(cmp), _) if lower_inclusive => {
+                    partition_point(num_build, |b| cmp(b, row) == Ordering::Less)
+                }
+                (Some(cmp), _) => partition_point(num_build, |b| cmp(b, row) != Ordering::Greater),
+                (None, Some(cmp)) => partition_point(num_build, |b| cmp(b, row) == Ordering::Less),
+                (None, None) => 0,
+            };
+            let end = match (&upper_cmp, &eq_cmp) {
+                (Some(cmp), _) if upper_inclusive => {
+                    partition_point(num_build, |b| cmp(b, row) != Ordering::Greater)
+                }
+                (Some(cmp), _) => partition_point(num_build, |b| cmp(b, row) == Ordering::Less),
+                (None, Some(cmp)) => {
+                    partition_point(num_build, |b| cmp(b, row) != Ordering::Greater)
+                }
+                (None, None) => num_build,
+            };
+            for b in start..end {
+                probe_idx.push(row as u64);
+                build_idx.push(b as u64);
+            }
+        }
+        Ok((probe_idx, build_idx))
+    }
+
+    /// The pairs of probe and build rows that match, in probe order.
+    fn matches(&self, probe: &RecordBatch, on: &[ExprRef]) -> DaftResult<(Vec<u64>, Vec<u64>)> {
+        let (probe_idx, build_idx) = self.candidates(probe, on)?;
+        let residual = self
+            .bounds
+            .rest
+            .iter()
+            .map(|i| self.conditions[*i].to_expr())
+            .chain(self.residual.clone())
+            .reduce(|acc, e| acc.and(e));
+        let Some(residual) = residual else {
+            return Ok((probe_idx, build_idx));
+        };
+        if probe_idx.is_empty() {
+            return Ok((probe_idx, build_idx));
+        }
+
+        let num_pairs = probe_idx.len();
+        let probe_rows =
+            probe.take(&UInt64Array::from(("idx", probe_idx.clone())).into_series())?;
+        let build_rows = self
+            .table
+            .take(&UInt64Array::from(("idx", build_idx.clone())).into_series())?;
+        let columns = probe_rows
+            .columns
+            .iter()
+            .chain(
+                build_rows
+                    .columns
+                    .iter()
+                    .filter(|c| !probe.schema.has_field(c.name())),
+            )
+            .cloned()
+            .collect::<Vec<_>>();
+        let schema = Schema::new(
+            columns
+                .iter()
+                .map(|c| c.field().clone())
+                .collect::<Vec<_>>(),
+        );
+        let pairs = RecordBatch::new_with_size(schema, columns, num_pairs)?;
+
+        let mask = pairs.eval_expression(&residual)?;
+        let mask = if mask.len() == num_pairs {
+            mask
+        } else {
+            mask.broadcast(num_pairs)?
+        };
+        let (probe_idx, build_idx) = mask
+            .bool()?
+            .as_arrow()
+            .iter()
+            .zip(probe_idx.into_iter().zip(build_idx))
+            .filter_map(|(keep, pair)| (keep == Some(true)).then_some(pair))
+            .unzip();
+        Ok((probe_idx, build_idx))
+    }
+
+    /// Joins `probe`, as the left side, with the build side, as the right side. `on` are the
+    /// equality keys of `probe`, in the same order as the build side's.
+    pub fn probe(
+        &self,
+        probe: &RecordBatch,
+        on: &[ExprRef],
+        how: JoinType,
+    ) -> DaftResult<RecordBatch> {
+        let (probe_idx, build_idx) = self.matches(probe, on)?;
+
+        match how {
+            JoinType::Inner | JoinType::Left => {
+                let (lidx, ridx) = if how == JoinType::Inner {
+                    (
+                        UInt64Array::from(("left_indices", probe_idx)).into_series(),
+                        UInt64Array::from(("right_indices", build_idx)).into_series(),
+                    )
+                } else {
+                    // add a row with nulls on the right for every probe row without a match
+                    let mut left_idx = Vec::with_capacity(probe_idx.len().max(probe.len()));
+                    let mut right_idx = Vec::with_capacity(left_idx.capacity());
+                    let mut r_valid = MutableBitmap::with_capacity(left_idx.capacity());
+                    let mut pairs = probe_idx.into_iter().zip(build_idx).peekable();
+                    for row in 0..probe.len() as u64 {
+                        if pairs.peek().is_some_and(|(l, _)| *l == row) {
+                            while let Some((l, r)) = pairs.next_if(|(l, _)| *l == row) {
+                                left_idx.push(l);
+                                right_idx.push(r);
+                                r_valid.push(true);
+                            }
+                        } else {
+                            left_idx.push(row);
+                            right_idx.push(0);
+                            r_valid.push(false);
+                        }
+                    }
+                    (
+                        UInt64Array::from(("left_indices", left_idx)).into_series(),
+                        UInt64Array::from(("right_indices", right_idx))
+                            .with_validity(Some(r_valid.into()))?
+                            .into_series(),
+                    )
+                };
+
+                let join_schema = infer_join_schema(&probe.schema, &self.table.schema, how)?;
+                let common_cols =
+                    get_common_join_cols(&probe.schema, &self.table.schema).collect::<Vec<_>>();
+                let join_series =
+                    Arc::unwrap_or_clone(probe.get_columns(&common_cols)?.take(&lidx)?.columns);
+
+                let num_rows = lidx.len();
+                let join_series =
+                    add_non_join_key_columns(probe, &self.table, lidx, ridx, join_series)?;
+                RecordBatch::new_with_size(join_schema, join_series, num_rows)
+            }
+            JoinType::Semi | JoinType::Anti => {
+                let mut matched = vec![false; probe.len()];
+                for row in probe_idx {
+                    matched[row as usize] = true;
+                }
+                let is_anti = how == JoinType::Anti;
+                let idx = (0..probe.len() as u64)
+                    .filter(|row| matched[*row as usize] != is_anti)
+                    .collect::<Vec<_>>();
+                probe.take(&UInt64Array::from(("left_indices", idx)).into_series())
+            }
+            JoinType::Right | JoinType::Outer => Err(DaftError::not_implemented(format!(
+                "{how} join from the probe side of a range join"
+            ))),
+        }
+    }
+}
+
+impl RecordBatch {
+    /// Joins on the equality keys `left_on` and `right_on` and the inequalities `conditions`,
+    /// keeping pairs that also satisfy `residual`.
+    ///
+    /// Each condition compares an expression of this table on the left with one of `right` on
+    /// the right. `residual` is evaluated on the columns of both tables.
+    pub fn range_join(
+        &self,
+        right: &Self,
+        left_on: &[ExprRef],
+        right_on: &[ExprRef],
+        conditions: &[RangeCondition],
+        residual: Option<&ExprRef>,
+        how: JoinType,
+    ) -> DaftResult<Self> {
+        if left_on.len() != right_on.len() {
+            return Err(DaftError::ValueError(format!(
+                "Mismatch of join on clauses: left: {:?} vs right: {:?}",
+                left_on.len(),
+                right_on.len()
+            )));
+        }
+
+        match how {
+            // probe from the right side, since its rows are the ones that are kept
+            JoinType::Right => {
+                let conditions = conditions
+                    .iter()
+                    .map(RangeCondition::flipped)
+                    .collect::<Vec<_>>();
+                let joined = right.range_join(
+                    self,
+                    right_on,
+                    left_on,
+                    &conditions,
+                    residual,
+                    JoinType::Left,
+                )?;
+                let join_schema = infer_join_schema(&self.schema, &right.schema, how)?;
+                joined.get_columns(&join_schema.field_names().collect::<Vec<_>>())
+            }
+            JoinType::Outer => Err(DaftError::not_implemented("Outer range joins")),
+            _ => {
+                let (left_on, right_on) = normalize_join_keys(
+                    left_on.to_vec(),
+                    right_on.to_vec(),
+                    self.schema.clone(),
+                    right.schema.clone(),
+                )?;
+                let conditions =
+                    normalize_range_conditions(conditions.to_vec(), &self.schema, &right.schema)?;
+                RangeJoinBuild::try_new(right, &right_on, conditions, residual.cloned())?
+                    .probe(self, &left_on, how)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::{array::ops::as_arrow::AsArrow, prelude::*};
+    use daft_dsl::{join::RangeCondition, lit, resolved_col, Operator};
+
+    use crate::RecordBatch;
+
+    fn intervals() -> DaftResult<RecordBatch> {
+        RecordBatch::from_nonempty_columns(vec![
+            Int64Array::from(("id", vec![1, 2, 3])).into_series(),
+            Int64Array::from(("start", vec![0, 5, 20])).into_series(),
+            Int64Array::from(("end", vec![10, 15, 30])).into_series(),
+        ])
+    }
+
+    fn events() -> DaftResult<RecordBatch> {
+        RecordBatch::from_nonempty_columns(vec![
+            Int64Array::from_iter(
+                Field::new("ts", DataType::Int64),
+                vec![Some(12), Some(0), None, Some(5), Some(10)].into_iter(),
+            )
+            .into_series(),
+            Utf8Array::from(("event", ["a", "b", "c", "d", "e"].as_slice())).into_series(),
+        ])
+    }
+
+    fn interval_conditions() -> DaftResult<Vec<RangeCondition>> {
+        Ok(vec![
+            RangeCondition::try_new(resolved_col("start"), Operator::LtEq, resolved_col("ts"))?,
+            RangeCondition::try_new(resolved_col("end"), Operator::Gt, resolved_col("ts"))?,
+        ])
+    }
+
+    fn pairs(joined: &RecordBatch) -> DaftResult<Vec<(Option<i64>, Option<String>)>> {
+        let ids = joined.get_column("id")?.i64()?.as_arrow().clone();
+        let events = joined.get_column("event")?.utf8()?.as_arrow().clone();
+        Ok(ids
+            .iter()
+            .zip(events.iter())
+            .map(|(id, event)| (id.copied(), event.map(str::to_string)))
+            .collect())
+    }
+
+    #[test]
+    fn range_join_interval_overlap() -> DaftResult<()> {
+        let joined = intervals()?.range_join(
+            &events()?,
+            &[],
+            &[],
+            &interval_conditions()?,
+            None,
+            JoinType::Inner,
+        )?;
+        let mut pairs = pairs(&joined)?;
+        pairs.sort();
+        let expected = [(1, "b"), (1, "d"), (2, "a"), (2, "d"), (2, "e")]
+            .map(|(id, event)| (Some(id), Some(event.to_string())));
+        assert_eq!(pairs, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn range_join_with_residual_and_outer_rows() -> DaftResult<()> {
+        let residual = resolved_col("event").not_eq(lit("d"));
+        let joined = intervals()?.range_join(
+            &events()?,
+            &[],
+            &[],
+            &interval_conditions()?,
+            Some(&residual),
+            JoinType::Left,
+        )?;
+        let expected = [(1, Some("b")), (2, Some("e")), (2, Some("a")), (3, None)]
+            .map(|(id, event)| (Some(id), event.map(str::to_string)));
+        assert_eq!(pairs(&joined)?, expected);
+
+        let anti = intervals()?.range_join(
+            &events()?,
+            &[],
+            &[],
+            &interval_conditions()?,
+            Some(&residual),
+            JoinType::Anti,
+        )?;
+        assert_eq!(
+            anti.get_column("id")?.i64()?.as_arrow().values().as_slice(),
+            &[3]
+        );
+        Ok(())
+    }
+}
@@ -5,7 +5,10 @@ pub mod hash;
 mod joins;
 mod partition;
 mod pivot;
+pub mod row_encoding;
 mod search_sorted;
 mod sort;
 mod unpivot;
 mod window;
+
+pub use joins::{AsofJoinBuild, RangeJoinBuild};
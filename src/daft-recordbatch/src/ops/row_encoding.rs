@@ -0,0 +1,350 @@
+use arrow2::{
+    bitmap::{Bitmap, MutableBitmap},
+    datatypes::DataType as ArrowDataType,
+};
+use common_error::{DaftError, DaftResult};
+use daft_core::{array::ops::as_arrow::AsArrow, prelude::*};
+
+use crate::RecordBatch;
+
+/// The name of the binary column that rows are encoded into.
+const ROW_KEY_NAME: &str = "__row_key__";
+
+/// The byte that valid values are prefixed with. Nulls are a byte below or above it, so that they sort first or last.
+const VALID: u8 = 1;
+
+/// Whether values of `dtype` can be row encoded, which holds for those of a boolean, numeric, string or binary physical
+/// type.
+pub fn can_encode_rows(dtype: &DataType) -> bool {
+    matches!(
+        dtype.to_physical(),
+        DataType::Null
+            | DataType::Boolean
+            | DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Float32
+            | DataType::Float64
+            | DataType::Decimal128(..)
+            | DataType::Utf8
+            | DataType::Binary
+            | DataType::FixedSizeBinary(..)
+    )
+}
+
+/// Whether composite keys of `schema` are row encoded by [`RecordBatch::encode_keys`].
+pub fn can_encode_keys(schema: &Schema) -> bool {
+    schema.len() > 1 && schema.fields().iter().all(|f| can_encode_rows(&f.dtype))
+}
+
+/// Writes variable-length bytes so that their encodings compare like them and none is a prefix of another: zero bytes
+/// are escaped as `0 255` and the bytes end with `0 0`.
+fn write_var_len(bytes: &[u8], out: &mut Vec<u8>) {
+    for &byte in bytes {
+        out.push(byte);
+        if byte == 0 {
+            out.push(u8::MAX);
+        }
+    }
+    out.extend([0, 0]);
+}
+
+type WriteFn<'a> = Box<dyn Fn(usize, &mut Vec<u8>) + 'a>;
+
+/// Writes the values of a physical column as bytes that compare like the values.
+struct ColumnEncoder<'a> {
+    validity: Option<&'a Bitmap>,
+    is_nan: Option<Box<dyn Fn(usize) -> bool + 'a>>,
+    write: WriteFn<'a>,
+}
+
+impl<'a> ColumnEncoder<'a> {
+    fn try_new(series: &'a Series) -> DaftResult<Self> {
+        // Integers are written big-endian with their sign bit flipped, which is a no-op for unsigned integers.
+        macro_rules! integer {
+            ($arr:expr, $t:ty) => {{
+                let arrow = $arr.as_arrow();
+                let values = arrow.values();
+                (
+                    arrow.validity(),
+                    None,
+                    Box::new(move |i: usize, out: &mut Vec<u8>| {
+                        out.extend((values[i] ^ <$t>::MIN).to_be_bytes());
+                    }) as WriteFn,
+                )
+            }};
+        }
+        // Floats are written as their bits, flipped entirely if negative and in the sign bit otherwise, with all NaNs
+        // written alike.
+        macro_rules! float {
+            ($arr:expr, $t:ty, $bits:ty) => {{
+                let arrow = $arr.as_arrow();
+                let values = arrow.values();
+                (
+                    arrow.validity(),
+                    Some(Box::new(move |i: usize| values[i].is_nan()) as Box<dyn Fn(usize) -> bool>),
+                    Box::new(move |i: usize, out: &mut Vec<u8>| {
+                        let bits = if values[i].is_nan() {
+                            <$t>::NAN.to_bits()
+                        } else {
+                            values[i].to_bits()
+                        };
+                        let sign: $bits = 1 << (<$bits>::BITS - 1);
+                        let bits = if bits & sign == 0 { bits | sign } else { !bits };
+                        out.extend(bits.to_be_bytes());
+                    }) as WriteFn,
+                )
+            }};
+        }
+
+        let (validity, is_nan, write) = match series.data_type() {
+            DataType::Null => (
+                None,
+                None,
+                Box::new(|_: usize, _: &mut Vec<u8>| {}) as WriteFn,
+            ),
+            DataType::Boolean => {
+                let arrow = series.bool()?.as_arrow();
+                (
+                    arrow.validity(),
+                    None,
+                    Box::new(move |i: usize, out: &mut Vec<u8>| out.push(u8::from(arrow.value(i))))
+                        as WriteFn,
+                )
+            }
+            DataType::Int8 => integer!(series.i8()?, i8),
+            DataType::Int16 => integer!(series.i16()?, i16),
+            DataType::Int32 => integer!(series.i32()?, i32),
+            DataType::Int64 => integer!(series.i64()?, i64),
+            DataType::UInt8 => integer!(series.u8()?, u8),
+            DataType::UInt16 => integer!(series.u16()?, u16),
+            DataType::UInt32 => integer!(series.u32()?, u32),
+            DataType::UInt64 => integer!(series.u64()?, u64),
+            DataType::Decimal128(..) => integer!(series.decimal128()?, i128),
+            DataType::Float32 => float!(series.f32()?, f32, u32),
+            DataType::Float64 => float!(series.f64()?, f64, u64),
+            DataType::Utf8 => {
+                let arrow = series.utf8()?.as_arrow();
+                (
+                    arrow.validity(),
+                    None,
+                    Box::new(move |i: usize, out: &mut Vec<u8>| {
+                        write_var_len(arrow.value(i).as_bytes(), out);
+                    }) as WriteFn,
+                )
+            }
+            DataType::Binary => {
+                let arrow = series.binary()?.as_arrow();
+                (
+                    arrow.validity(),
+                    None,
+                    Box::new(move |i: usize, out: &mut Vec<u8>| write_var_len(arrow.value(i), out))
+                        as WriteFn,
+                )
+            }
+            DataType::FixedSizeBinary(..) => {
+                let arrow = series.fixed_size_binary()?.as_arrow();
+                (
+                    arrow.validity(),
+                    None,
+                    Box::new(move |i: usize, out: &mut Vec<u8>| out.extend(arrow.value(i)))
+                        as WriteFn,
+                )
+            }
+            other => {
+                return Err(DaftError::TypeError(format!(
+                    "Can not row encode values of type {other}"
+                )))
+            }
+        };
+        Ok(Self {
+            validity,
+            is_nan,
+            write,
+        })
+    }
+
+    fn is_valid(&self, i: usize) -> bool {
+        self.validity.is_none_or(|validity| validity.get_bit(i))
+    }
+}
+
+impl RecordBatch {
+    /// Encodes each row as bytes that compare, byte by byte, like the row does by the sort order of each column. Values
+    /// are prefixed with whether they're null, so that encodings never are, and equal rows have equal encodings.
+    pub fn encode_rows(
+        &self,
+        descending: &[bool],
+        nulls_first: &[bool],
+    ) -> DaftResult<BinaryArray> {
+        self.encode(descending, nulls_first, None)
+    }
+
+    /// Encodes composite keys as a table of a single binary column of the encodings of their rows, which hash and
+    /// compare as one value rather than column by column. Rows are null if they have nulls that don't equal nulls or
+    /// NaNs that don't equal NaNs, so that they don't equal any other row.
+    ///
+    /// Returns `None` for keys of a single column, or of columns that can't be encoded.
+    pub fn encode_keys(
+        &self,
+        nulls_equal: &[bool],
+        nans_equal: &[bool],
+    ) -> DaftResult<Option<Self>> {
+        if !can_encode_keys(&self.schema) {
+            return Ok(None);
+        }
+        let ascending = vec![false; self.num_columns()];
+        let rows = self.encode(&ascending, &ascending, Some((nulls_equal, nans_equal)))?;
+        Ok(Some(Self::from_nonempty_columns(vec![rows.into_series()])?))
+    }
+
+    fn encode(
+        &self,
+        descending: &[bool],
+        nulls_first: &[bool],
+        keys_equality: Option<(&[bool], &[bool])>,
+    ) -> DaftResult<BinaryArray> {
+        let physical = self.as_physical()?;
+        let encoders = physical
+            .columns
+            .iter()
+            .map(ColumnEncoder::try_new)
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        let mut offsets = Vec::with_capacity(self.len() + 1);
+        offsets.push(0i64);
+        let mut data = vec![];
+        let mut validity = keys_equality.map(|_| MutableBitmap::with_capacity(self.len()));
+        for i in 0..self.len() {
+            let mut is_valid = true;
+            for (c, encoder) in encoders.iter().enumerate() {
+                if encoder.is_valid(i) {
+                    data.push(VALID);
+                    let start = data.len();
+                    (encoder.write)(i, &mut data);
+                    if descending[c] {
+                        data[start..].iter_mut().for_each(|byte| *byte = !*byte);
+                    }
+                    if let (Some((_, nans_equal)), Some(is_nan)) = (keys_equality, &encoder.is_nan)
+                    {
+                        is_valid &= nans_equal[c] || !is_nan(i);
+                    }
+                } else {
+                    data.push(if nulls_first[c] { VALID - 1 } else { VALID + 1 });
+                    if let Some((nulls_equal, _)) = keys_equality {
+                        is_valid &= nulls_equal[c];
+                    }
+                }
+            }
+            offsets.push(data.len() as i64);
+            if let Some(validity) = &mut validity {
+                validity.push(is_valid);
+            }
+        }
+
+        let arrow = arrow2::array::BinaryArray::<i64>::new(
+            ArrowDataType::LargeBinary,
+            offsets.try_into()?,
+            data.into(),
+            validity
+                .map(Bitmap::from)
+                .filter(|validity| validity.unset_bits() > 0),
+        );
+        Ok(BinaryArray::from((ROW_KEY_NAME, Box::new(arrow))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+
+    use crate::RecordBatch;
+
+    fn encodings(table: &RecordBatch, descending: &[bool], nulls_first: &[bool]) -> Vec<Vec<u8>> {
+        table
+            .encode_rows(descending, nulls_first)
+            .unwrap()
+            .into_iter()
+            .map(|row| row.unwrap().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn test_encode_rows_compares_like_rows() -> DaftResult<()> {
+        let table = RecordBatch::from_nonempty_columns(vec![
+            Int64Array::from_iter(
+                Field::new("a", DataType::Int64),
+                vec![Some(-1), Some(1), Some(1), None, Some(1)].into_iter(),
+            )
+            .into_series(),
+            Utf8Array::from_iter(
+                "b",
+                vec![Some("z"), Some("a"), Some("a\0"), Some("a"), Some("ab")].into_iter(),
+            )
+            .into_series(),
+            Float64Array::from_iter(
+                Field::new("c", DataType::Float64),
+                vec![Some(0.5), Some(-2.0), Some(1.0), Some(f64::NAN), Some(-0.5)].into_iter(),
+            )
+            .into_series(),
+        ])?;
+
+        let ascending = encodings(&table, &[false; 3], &[false; 3]);
+        // -1 < 1 < null and "a" < "a\0" < "ab" < "z"
+        assert_eq!(sorted_indices(&ascending), vec![0, 1, 2, 4, 3]);
+
+        let descending = encodings(&table, &[true; 3], &[true; 3]);
+        assert_eq!(sorted_indices(&descending), vec![3, 4, 2, 1, 0]);
+        Ok(())
+    }
+
+    fn sorted_indices(rows: &[Vec<u8>]) -> Vec<usize> {
+        let mut indices = (0..rows.len()).collect::<Vec<_>>();
+        indices.sort_by(|i, j| rows[*i].cmp(&rows[*j]));
+        indices
+    }
+
+    #[test]
+    fn test_encode_keys() -> DaftResult<()> {
+        let table = RecordBatch::from_nonempty_columns(vec![
+            Int32Array::from_iter(
+                Field::new("a", DataType::Int32),
+                vec![Some(1), Some(1), None, Some(1)].into_iter(),
+            )
+            .into_series(),
+            Float32Array::from_iter(
+                Field::new("b", DataType::Float32),
+                vec![Some(1.0), Some(1.0), Some(1.0), Some(f32::NAN)].into_iter(),
+            )
+            .into_series(),
+        ])?;
+
+        let keys = table
+            .encode_keys(&[false, false], &[false, false])?
+            .unwrap();
+        let keys = keys.get_column_by_index(0)?.binary()?.clone();
+        assert_eq!(keys.get(0), keys.get(1));
+        assert_eq!(
+            keys.into_iter()
+                .map(|key| key.is_some())
+                .collect::<Vec<_>>(),
+            vec![true, true, false, false]
+        );
+
+        let keys = table.encode_keys(&[true, true], &[true, true])?.unwrap();
+        assert!(keys.get_column_by_index(0)?.validity().is_none());
+
+        // single columns are hashed and compared as they are
+        assert!(table
+            .get_columns(&["a"])?
+            .encode_keys(&[true], &[true])?
+            .is_none());
+        Ok(())
+    }
+}
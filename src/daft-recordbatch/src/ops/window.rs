@@ -1,6 +1,13 @@
+use arrow2::bitmap::Bitmap;
 use common_error::{DaftError, DaftResult};
-use daft_core::{array::ops::IntoGroups, prelude::*};
-use daft_dsl::{AggExpr, ExprRef};
+use daft_core::{
+    array::ops::{as_arrow::AsArrow, GroupIndices, IntoGroups},
+    prelude::*,
+};
+use daft_dsl::{
+    expr::window::{WindowBoundary, WindowFrame, WindowFrameType},
+    AggExpr, ExprRef,
+};
 
 use crate::RecordBatch;
 
@@ -108,4 +115,329 @@ impl RecordBatch {
         // Union the original data with the row number column
         self.union(&row_number_batch)
     }
+
+    /// Ranks the rows of this partition, which must already be sorted by `order_by`.
+    ///
+    /// Rows with equal `order_by` values share a rank. With `dense`, ranks after a tie continue
+    /// from the next integer, and otherwise they skip over the tied rows like `RANK` in SQL.
+    pub fn window_rank(&self, name: String, order_by: &[ExprRef], dense: bool) -> DaftResult<Self> {
+        let order_by_table = self.eval_expression_list(order_by)?;
+        let (_, groups) = order_by_table.make_groups()?;
+
+        let mut row_to_group = vec![0; self.len()];
+        for (group_idx, indices) in groups.iter().enumerate() {
+            for &row_idx in indices {
+                row_to_group[row_idx as usize] = group_idx;
+            }
+        }
+
+        let mut ranks = Vec::with_capacity(self.len());
+        let mut rank = 0u64;
+        for (i, group) in row_to_group.iter().enumerate() {
+            if i == 0 || *group != row_to_group[i - 1] {
+                rank = if dense { rank + 1 } else { i as u64 + 1 };
+            }
+            ranks.push(rank);
+        }
+
+        let rank_series = UInt64Array::from((name.as_str(), ranks)).into_series();
+        let rank_batch = Self::from_nonempty_columns(vec![rank_series])?;
+        self.union(&rank_batch)
+    }
+
+    /// Takes the value of `expr` from `offset` rows after each row of this partition, which must
+    /// already be sorted. Rows whose offset falls outside of the partition get `default`, or null.
+    pub fn window_offset(
+        &self,
+        name: String,
+        expr: &ExprRef,
+        offset: isize,
+        default: Option<&ExprRef>,
+    ) -> DaftResult<Self> {
+        let values = self.eval_expression(expr)?;
+        let len = self.len() as isize;
+        let take_indices = UInt64Array::from_iter(
+            Field::new("indices", DataType::UInt64),
+            (0..self.len())
+                .map(|i| {
+                    let target = i as isize + offset;
+                    (0..len).contains(&target).then_some(target as u64)
+                })
+                .collect::<Vec<_>>()
+                .into_iter(),
+        )
+        .into_series();
+        let mut shifted = values.take(&take_indices)?;
+
+        if let Some(default) = default {
+            let default = self.eval_expression(default)?.cast(values.data_type())?;
+            let in_range = take_indices.not_null()?;
+            shifted = shifted.if_else(&default, &in_range)?;
+        }
+
+        let offset_batch = Self::from_nonempty_columns(vec![shifted.rename(&name)])?;
+        self.union(&offset_batch)
+    }
+
+    /// Computes `to_agg` over the `frame` around each row of this partition, which must already
+    /// be sorted by `order_by`. Rows with fewer than `min_periods` rows in their frame are null.
+    ///
+    /// Row frames are bounded by offsets from the current row. Range frames are bounded by
+    /// offsets from the value of the current row, so they need a single numeric `order_by`.
+    pub fn window_agg_with_frame(
+        &self,
+        to_agg: &AggExpr,
+        name: String,
+        frame: &WindowFrame,
+        min_periods: i64,
+        order_by: &[ExprRef],
+        descending: &[bool],
+    ) -> DaftResult<Self> {
+        if matches!(to_agg, AggExpr::MapGroups { .. }) {
+            return Err(DaftError::ValueError(
+                "MapGroups not supported in window functions".into(),
+            ));
+        }
+        if matches!(frame.start, WindowBoundary::UnboundedFollowing())
+            || matches!(frame.end, WindowBoundary::UnboundedPreceding())
+        {
+            return Err(DaftError::ValueError(format!(
+                "Invalid window frame between {:?} and {:?}",
+                frame.start, frame.end
+            )));
+        }
+
+        let bounds = match frame.frame_type {
+            WindowFrameType::Rows => self.row_frame_bounds(frame),
+            WindowFrameType::Range => self.range_frame_bounds(frame, order_by, descending)?,
+        };
+
+        // Each frame is aggregated as a group of its rows. An empty frame is given a placeholder
+        // row so that every aggregation can handle it, and is nulled out afterwards.
+        let groups: GroupIndices = bounds
+            .iter()
+            .map(|&(start, end)| {
+                if start < end {
+                    (start as u64..end as u64).collect()
+                } else {
+                    vec![0]
+                }
+            })
+            .collect();
+        let agg_result = self.eval_agg_expression(to_agg, Some(&groups))?;
+
+        let min_periods = min_periods.max(1) as usize;
+        let enough_rows = Bitmap::from_iter(
+            bounds
+                .iter()
+                .map(|&(start, end)| end.saturating_sub(start) >= min_periods),
+        );
+        let validity = match agg_result.validity() {
+            Some(validity) => validity & &enough_rows,
+            None => enough_rows,
+        };
+        let window_col = agg_result.with_validity(Some(validity))?.rename(&name);
+
+        let window_result = Self::from_nonempty_columns(vec![window_col])?;
+        self.union(&window_result)
+    }
+
+    /// The half-open range of rows in the row-based `frame` of each row.
+    fn row_frame_bounds(&self, frame: &WindowFrame) -> Vec<(usize, usize)> {
+        let len = self.len() as i64;
+        let clamp = |i: i64| i.clamp(0, len) as usize;
+        (0..len)
+            .map(|i| {
+                let start = match frame.start {
+                    WindowBoundary::Offset(n) => clamp(i + n),
+                    _ => 0,
+                };
+                let end = match frame.end {
+                    WindowBoundary::Offset(n) => clamp(i + n + 1),
+                    _ => len as usize,
+                };
+                (start, end)
+            })
+            .collect()
+    }
+
+    /// The half-open range of rows in the range-based `frame` of each row.
+    ///
+    /// Nulls sort together at one end of the partition and are only within range of each other.
+    fn range_frame_bounds(
+        &self,
+        frame: &WindowFrame,
+        order_by: &[ExprRef],
+        descending: &[bool],
+    ) -> DaftResult<Vec<(usize, usize)>> {
+        let [order_by] = order_by else {
+            return Err(DaftError::ValueError(format!(
+                "Range window frames require exactly one order by expression, received {}",
+                order_by.len()
+            )));
+        };
+        let descending = descending.first().copied().unwrap_or(false);
+        let values = self.eval_expression(order_by)?;
+        if !values.data_type().is_numeric() && !values.data_type().is_temporal() {
+            return Err(DaftError::TypeError(format!(
+                "Range window frames require a numeric or temporal order by expression, received {}",
+                values.data_type()
+            )));
+        }
+        let values = values.as_physical()?.cast(&DataType::Float64)?;
+        let values = values.f64()?.as_arrow();
+
+        let len = self.len();
+        let null_count = values.validity().map_or(0, Bitmap::unset_bits);
+        // nulls are sorted first when descending and last otherwise
+        let (nulls, non_nulls) = if descending {
+            (0..null_count, null_count..len)
+        } else {
+            (len - null_count..len, 0..len - null_count)
+        };
+        let sorted = &values.values()[non_nulls.clone()];
+
+        // The first row of the partition whose value is past `bound` in the sort order, or at it
+        // if `inclusive` is false.
+        let position = |bound: f64, inclusive: bool| {
+            non_nulls.start
+                + sorted.partition_point(|&v| match (descending, inclusive) {
+                    (false, false) => v < bound,
+                    (false, true) => v <= bound,
+                    (true, false) => v > bound,
+                    (true, true) => v >= bound,
+                })
+        };
+        // signed offsets count towards the end of the partition, which holds lower values when
+        // descending
+        let sign = if descending { -1.0 } else { 1.0 };
+
+        Ok((0..len)
+            .map(|i| {
+                if nulls.contains(&i) {
+                    let start = match frame.start {
+                        WindowBoundary::Offset(_) => nulls.start,
+                        _ => 0,
+                    };
+                    let end = match frame.end {
+                        WindowBoundary::Offset(_) => nulls.end,
+                        _ => len,
+                    };
+                    return (start, end);
+                }
+                let value = values.value(i);
+                let start = match frame.start {
+                    WindowBoundary::Offset(n) => position(value + sign * n as f64, false),
+                    _ => 0,
+                };
+                let end = match frame.end {
+                    WindowBoundary::Offset(n) => position(value + sign * n as f64, true),
+                    _ => len,
+                };
+                (start, end)
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{
+        expr::window::{WindowBoundary, WindowFrame, WindowFrameType},
+        lit, resolved_col, AggExpr,
+    };
+
+    use crate::RecordBatch;
+
+    fn sorted_partition() -> DaftResult<RecordBatch> {
+        let values = Int64Array::from_iter(
+            Field::new("v", DataType::Int64),
+            vec![Some(1), Some(2), Some(2), Some(5), None].into_iter(),
+        )
+        .into_series();
+        RecordBatch::from_nonempty_columns(vec![values])
+    }
+
+    fn column(batch: &RecordBatch, name: &str) -> DaftResult<Vec<Option<i64>>> {
+        let column = batch.get_column(name)?.cast(&DataType::Int64)?;
+        Ok(column.i64()?.into_iter().map(|v| v.copied()).collect())
+    }
+
+    #[test]
+    fn rank_and_offset() -> DaftResult<()> {
+        let partition = sorted_partition()?;
+        let order_by = vec![resolved_col("v")];
+
+        let ranked = partition
+            .window_rank("rank".into(), &order_by, false)?
+            .window_rank("dense_rank".into(), &order_by, true)?;
+        assert_eq!(
+            column(&ranked, "rank")?,
+            vec![Some(1), Some(2), Some(2), Some(4), Some(5)]
+        );
+        assert_eq!(
+            column(&ranked, "dense_rank")?,
+            vec![Some(1), Some(2), Some(2), Some(3), Some(4)]
+        );
+
+        let shifted = partition
+            .window_offset("lag".into(), &resolved_col("v"), -1, None)?
+            .window_offset("lead".into(), &resolved_col("v"), 2, Some(&lit(0)))?;
+        assert_eq!(
+            column(&shifted, "lag")?,
+            vec![None, Some(1), Some(2), Some(2), Some(5)]
+        );
+        assert_eq!(
+            column(&shifted, "lead")?,
+            vec![Some(2), Some(5), None, Some(0), Some(0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn framed_aggregations() -> DaftResult<()> {
+        let partition = sorted_partition()?;
+        let order_by = vec![resolved_col("v")];
+        let sum = AggExpr::Sum(resolved_col("v"));
+
+        let running = WindowFrame {
+            frame_type: WindowFrameType::Rows,
+            start: WindowBoundary::UnboundedPreceding(),
+            end: WindowBoundary::Offset(0),
+        };
+        let result =
+            partition.window_agg_with_frame(&sum, "s".into(), &running, 1, &order_by, &[false])?;
+        assert_eq!(
+            column(&result, "s")?,
+            vec![Some(1), Some(3), Some(5), Some(10), Some(10)]
+        );
+
+        let sliding = WindowFrame {
+            frame_type: WindowFrameType::Rows,
+            start: WindowBoundary::Offset(-1),
+            end: WindowBoundary::Offset(1),
+        };
+        let result =
+            partition.window_agg_with_frame(&sum, "s".into(), &sliding, 3, &order_by, &[false])?;
+        assert_eq!(
+            column(&result, "s")?,
+            vec![None, Some(5), Some(9), Some(7), None]
+        );
+
+        // values within 1 of the current value, where the null only sees itself
+        let range = WindowFrame {
+            frame_type: WindowFrameType::Range,
+            start: WindowBoundary::Offset(-1),
+            end: WindowBoundary::Offset(1),
+        };
+        let result =
+            partition.window_agg_with_frame(&sum, "s".into(), &range, 1, &order_by, &[false])?;
+        assert_eq!(
+            column(&result, "s")?,
+            vec![Some(5), Some(5), Some(5), Some(5), None]
+        );
+        Ok(())
+    }
 }
@@ -4,14 +4,63 @@ mod probe_table;
 use std::sync::Arc;
 
 use common_error::DaftResult;
-use daft_core::prelude::SchemaRef;
+use daft_core::{
+    prelude::*,
+    utils::dyn_compare::{build_dyn_multi_array_compare, MultiDynArrayComparator},
+};
 use probe_set::{ProbeSet, ProbeSetBuilder};
 use probe_table::{ProbeTable, ProbeTableBuilder};
 
-use crate::RecordBatch;
+use crate::{ops::row_encoding::can_encode_keys, RecordBatch};
 
 struct ArrowTableEntry(Vec<Box<dyn arrow2::array::Array>>);
 
+/// How probeables hash and compare their keys. Composite keys are row encoded where they can be, so that they're
+/// hashed and compared as one binary value rather than column by column.
+struct ProbeKeys {
+    nulls_equal: Vec<bool>,
+    row_encoded: bool,
+    compare_fn: MultiDynArrayComparator,
+}
+
+impl ProbeKeys {
+    fn new(schema: &Schema, nulls_equal: Vec<bool>) -> DaftResult<Self> {
+        let row_encoded = can_encode_keys(schema);
+        let compare_fn = if row_encoded {
+            // rows whose nulls don't equal nulls are encoded as nulls
+            let key_schema = Schema::new(vec![Field::new("key", DataType::Binary)]);
+            build_dyn_multi_array_compare(&key_schema, &[false], &[false])?
+        } else {
+            build_dyn_multi_array_compare(schema, &nulls_equal, &vec![false; schema.len()])?
+        };
+        Ok(Self {
+            nulls_equal,
+            row_encoded,
+            compare_fn,
+        })
+    }
+
+    /// Hashes the keys of `table`, returning their hashes and the arrays that they're compared by.
+    fn hash_and_arrays(
+        &self,
+        table: &RecordBatch,
+    ) -> DaftResult<(UInt64Array, Vec<Box<dyn arrow2::array::Array>>)> {
+        let encoded = if self.row_encoded {
+            table.encode_keys(&self.nulls_equal, &vec![false; self.nulls_equal.len()])?
+        } else {
+            None
+        };
+        let table = encoded.as_ref().unwrap_or(table);
+        let hashes = table.hash_rows()?;
+        let arrays = table
+            .columns
+            .iter()
+            .map(|s| Ok(s.as_physical()?.to_arrow()))
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok((hashes, arrays))
+    }
+}
+
 pub fn make_probeable_builder(
     schema: SchemaRef,
     nulls_equal_aware: Option<&Vec<bool>>,
@@ -7,20 +7,17 @@ use common_error::{DaftError, DaftResult};
 use daft_core::{
     array::ops::as_arrow::AsArrow,
     prelude::SchemaRef,
-    utils::{
-        dyn_compare::{build_dyn_multi_array_compare, MultiDynArrayComparator},
-        identity_hash_set::{IdentityBuildHasher, IndexHash},
-    },
+    utils::identity_hash_set::{IdentityBuildHasher, IndexHash},
 };
 
-use super::{ArrowTableEntry, IndicesMapper, Probeable, ProbeableBuilder};
+use super::{ArrowTableEntry, IndicesMapper, ProbeKeys, Probeable, ProbeableBuilder};
 use crate::RecordBatch;
 
 pub struct ProbeTable {
     schema: SchemaRef,
     hash_table: HashMap<IndexHash, Vec<u64>, IdentityBuildHasher>,
     tables: Vec<ArrowTableEntry>,
-    compare_fn: MultiDynArrayComparator,
+    keys: ProbeKeys,
     num_groups: usize,
     num_rows: usize,
 }
@@ -45,16 +42,15 @@ impl ProbeTable {
                             schema.len(), null_equal_aware.len())));
             }
         }
-        let default_nulls_equal = vec![false; schema.len()];
-        let nulls_equal = null_equal_aware.unwrap_or_else(|| default_nulls_equal.as_ref());
-        let nans_equal = &vec![false; schema.len()];
-        let compare_fn =
-            build_dyn_multi_array_compare(&schema, nulls_equal.as_slice(), nans_equal.as_slice())?;
+        let nulls_equal = null_equal_aware
+            .cloned()
+            .unwrap_or_else(|| vec![false; schema.len()]);
+        let keys = ProbeKeys::new(&schema, nulls_equal)?;
         Ok(Self {
             schema,
             hash_table,
             tables: vec![],
-            compare_fn,
+            keys,
             num_groups: 0,
             num_rows: 0,
         })
@@ -71,13 +67,7 @@ impl ProbeTable {
             .zip(input.schema.fields())
             .all(|(l, r)| l.dtype == r.dtype));
 
-        let hashes = input.hash_rows()?;
-
-        let input_arrays = input
-            .columns
-            .iter()
-            .map(|s| Ok(s.as_physical()?.to_arrow()))
-            .collect::<DaftResult<Vec<_>>>()?;
+        let (hashes, input_arrays) = self.keys.hash_and_arrays(input)?;
 
         let iter = hashes.as_arrow().clone().into_iter();
 
@@ -93,7 +83,8 @@ impl ProbeTable {
 
                             let other_refs = other_table.0.as_slice();
 
-                            (self.compare_fn)(other_refs, &input_arrays, other_row_idx, idx).is_eq()
+                            (self.keys.compare_fn)(other_refs, &input_arrays, other_row_idx, idx)
+                                .is_eq()
                         }
                     }) {
                     Some(indices.as_slice())
@@ -109,17 +100,12 @@ impl ProbeTable {
     fn add_table(&mut self, table: &RecordBatch) -> DaftResult<()> {
         // we have to cast to the join key schema
         assert_eq!(table.schema, self.schema);
-        let hashes = table.hash_rows()?;
+        let (hashes, current_arrays) = self.keys.hash_and_arrays(table)?;
         let table_idx = self.tables.len();
         let table_offset = table_idx << Self::TABLE_IDX_SHIFT;
 
         assert!(table_idx < (1 << (64 - Self::TABLE_IDX_SHIFT)));
         assert!(table.len() < (1 << Self::TABLE_IDX_SHIFT));
-        let current_arrays = table
-            .columns
-            .iter()
-            .map(|s| Ok(s.as_physical()?.to_arrow()))
-            .collect::<DaftResult<Vec<_>>>()?;
         self.tables.push(ArrowTableEntry(current_arrays));
         let current_array_refs = self.tables.last().unwrap().0.as_slice();
         for (i, h) in hashes.as_arrow().values_iter().enumerate() {
@@ -131,14 +117,14 @@ impl ProbeTable {
                     let j_row_idx = (j_idx & Self::LOWER_MASK) as usize;
 
                     if table_idx == j_table_idx {
-                        (self.compare_fn)(current_array_refs, current_array_refs, i, j_row_idx)
+                        (self.keys.compare_fn)(current_array_refs, current_array_refs, i, j_row_idx)
                             .is_eq()
                     } else {
                         let j_table = self.tables.get(j_table_idx).unwrap();
 
                         let array_refs = j_table.0.as_slice();
 
-                        (self.compare_fn)(current_array_refs, array_refs, i, j_row_idx).is_eq()
+                        (self.keys.compare_fn)(current_array_refs, array_refs, i, j_row_idx).is_eq()
                     }
                 }
             });
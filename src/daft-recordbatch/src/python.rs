@@ -1,4 +1,4 @@
-use common_error::DaftError;
+use common_error::{DaftError, DaftResult};
 use daft_core::{
     join::JoinType,
     prelude::*,
@@ -7,14 +7,20 @@ use daft_core::{
 use daft_dsl::python::PyExpr;
 use daft_logical_plan::FileInfos;
 use indexmap::IndexMap;
-use pyo3::{exceptions::PyValueError, prelude::*};
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyCapsule};
 
 use crate::{
+    arrow_stream::{export_record_batches, RecordBatchStreamReader},
     ffi,
     preview::{Preview, PreviewFormat, PreviewOptions},
-    RecordBatch,
+    RecordBatch, ReprOptions,
 };
 
+fn parse_repr_options(options: &str) -> PyResult<ReprOptions> {
+    serde_json::from_str(options)
+        .map_err(|e| PyValueError::new_err(format!("Invalid display options: {e}")))
+}
+
 #[pyclass]
 #[derive(Clone)]
 pub struct PyRecordBatch {
@@ -189,14 +195,20 @@ impl PyRecordBatch {
         })
     }
 
-    pub fn explode(&self, py: Python, to_explode: Vec<PyExpr>) -> PyResult<Self> {
+    #[pyo3(signature = (to_explode, unnest_structs=false))]
+    pub fn explode(
+        &self,
+        py: Python,
+        to_explode: Vec<PyExpr>,
+        unnest_structs: bool,
+    ) -> PyResult<Self> {
         let converted_to_explode: Vec<daft_dsl::ExprRef> =
             to_explode.into_iter().map(|e| e.expr).collect();
 
         py.allow_threads(|| {
             Ok(self
                 .record_batch
-                .explode(converted_to_explode.as_slice())?
+                .explode(converted_to_explode.as_slice(), unnest_structs)?
                 .into())
         })
     }
@@ -223,6 +235,21 @@ impl PyRecordBatch {
         Ok(self.record_batch.repr_html())
     }
 
+    /// The text repr, with the display options serialized as JSON.
+    pub fn repr_with_options(&self, options: &str) -> PyResult<String> {
+        let options = parse_repr_options(options)?;
+        Ok(format!(
+            "{}\n",
+            self.record_batch.to_comfy_table_with_options(&options)
+        ))
+    }
+
+    /// The HTML repr, with the display options serialized as JSON.
+    pub fn repr_html_with_options(&self, options: &str) -> PyResult<String> {
+        let options = parse_repr_options(options)?;
+        Ok(self.record_batch.repr_html_with_options(&options))
+    }
+
     pub fn head(&self, py: Python, num: i64) -> PyResult<Self> {
         if num < 0 {
             return Err(PyValueError::new_err(format!(
@@ -472,6 +499,39 @@ impl PyRecordBatch {
         Ok(Self { record_batch })
     }
 
+    /// Exports `record_batches` as the `arrow_array_stream` PyCapsule of the Arrow PyCapsule
+    /// interface.
+    #[staticmethod]
+    pub fn to_arrow_c_stream<'py>(
+        py: Python<'py>,
+        record_batches: Vec<Self>,
+        schema: &PySchema,
+    ) -> PyResult<Bound<'py, PyCapsule>> {
+        let stream = export_record_batches(
+            schema.schema.clone(),
+            record_batches.into_iter().map(|rb| rb.record_batch),
+        )?;
+        common_arrow_ffi::stream_to_capsule(py, stream)
+    }
+
+    /// Imports the `arrow_array_stream` PyCapsule of the Arrow PyCapsule interface, returning the
+    /// schema of the stream along with its record batches.
+    #[staticmethod]
+    pub fn from_arrow_c_stream(
+        stream_capsule: Bound<PyCapsule>,
+    ) -> PyResult<(PySchema, Vec<Self>)> {
+        let stream = common_arrow_ffi::stream_from_capsule(&stream_capsule)?;
+        // Safety: a capsule with this name holds a struct of the C stream interface
+        let reader = unsafe { RecordBatchStreamReader::try_new(stream)? };
+        let schema = PySchema {
+            schema: reader.schema().clone(),
+        };
+        let record_batches = reader
+            .map(|rb| rb.map(Self::from))
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok((schema, record_batches))
+    }
+
     #[staticmethod]
     pub fn from_pylist_series(dict: IndexMap<String, PySeries>) -> PyResult<Self> {
         let mut fields: Vec<Field> = Vec::new();
@@ -1,4 +1,75 @@
+use common_display::table_display::StrValue;
 use daft_core::{datatypes::ExtensionArray, prelude::DataType, series::Series};
+use serde::{Deserialize, Serialize};
+
+/// The options of the text and HTML reprs of a record batch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReprOptions {
+    /// The number of rows to show, half from the start and half from the end when there are more.
+    pub max_rows: usize,
+    /// The number of columns to show, half from the start and half from the end when there are more. If `None`, text
+    /// reprs show as many columns as fit the terminal, and HTML reprs show all of them.
+    pub max_columns: Option<usize>,
+    /// The number of characters of values to show. If `None`, text reprs show 32 characters, and HTML reprs don't
+    /// truncate values. HTML reprs only truncate strings, since other values are rendered with markup.
+    pub max_width: Option<usize>,
+    /// Whether HTML reprs render image and tensor values as thumbnails.
+    pub thumbnails: bool,
+}
+
+impl Default for ReprOptions {
+    fn default() -> Self {
+        Self {
+            max_rows: 10,
+            max_columns: None,
+            max_width: None,
+            thumbnails: true,
+        }
+    }
+}
+
+/// Prepares a column for an HTML repr, which casts tensors to images when they're rendered as thumbnails. Tensors whose
+/// shapes aren't those of images are left as they are.
+pub fn html_column(s: &Series, options: &ReprOptions) -> Series {
+    match s.data_type() {
+        DataType::Tensor(_) | DataType::FixedShapeTensor(..) if options.thumbnails => {
+            s.cast(&DataType::Image(None)).unwrap_or_else(|_| s.clone())
+        }
+        _ => s.clone(),
+    }
+}
+
+/// Truncates a string to `max_width` characters, the last of which is an ellipsis if it's longer.
+fn truncate(text: String, max_width: usize) -> String {
+    if text.chars().count() <= max_width {
+        return text;
+    }
+    let mut truncated = text
+        .chars()
+        .take(max_width.saturating_sub(1))
+        .collect::<String>();
+    truncated.push('…');
+    truncated
+}
+
+/// The HTML of a value of a column that was prepared by [`html_column`].
+pub fn html_value_with_options(s: &Series, idx: usize, options: &ReprOptions) -> String {
+    let escaped = |value: String| {
+        html_escape::encode_text(&value)
+            .into_owned()
+            .replace('\n', "<br />")
+    };
+    match (s.data_type(), options.max_width) {
+        (DataType::Utf8, Some(max_width)) if s.is_valid(idx) => {
+            escaped(truncate(s.str_value(idx), max_width))
+        }
+        (DataType::Image(_) | DataType::FixedShapeImage(..), _) if !options.thumbnails => {
+            escaped(s.str_value(idx))
+        }
+        _ => html_value(s, idx),
+    }
+}
 
 pub fn html_value(s: &Series, idx: usize) -> String {
     match s.data_type() {
@@ -74,6 +145,10 @@ pub fn html_value(s: &Series, idx: usize) -> String {
             let arr = s.duration().unwrap();
             arr.html_value(idx)
         }
+        DataType::Categorical(_) => {
+            let arr = s.categorical().unwrap();
+            arr.html_value(idx)
+        }
         DataType::Interval => {
             let arr = s.interval().unwrap();
             arr.html_value(idx)
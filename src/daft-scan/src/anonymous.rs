@@ -60,6 +60,9 @@ impl ScanOperator for AnonymousScanOperator {
     fn can_absorb_limit(&self) -> bool {
         false
     }
+    fn can_absorb_sample(&self) -> bool {
+        true
+    }
 
     fn multiline_display(&self) -> Vec<String> {
         let mut lines = vec![
@@ -100,6 +103,8 @@ impl ScanOperator for AnonymousScanOperator {
                         chunk_spec,
                         size_bytes: None,
                         iceberg_delete_files: None,
+                        iceberg_equality_delete_files: None,
+                        delta_deletion_vector: None,
                         metadata: None,
                         partition_spec: None,
                         statistics: None,
@@ -12,7 +12,9 @@ use daft_schema::{field::Field, schema::SchemaRef};
 #[cfg(feature = "python")]
 use {crate::python::pylib::ScanOperatorHandle, pyo3::prelude::*};
 
-use crate::{glob::GlobScanOperator, storage_config::StorageConfig};
+use crate::{
+    glob::GlobScanOperator, schema_resolution::SchemaResolution, storage_config::StorageConfig,
+};
 
 pub struct ParquetScanBuilder {
     pub glob_paths: Vec<String>,
@@ -26,6 +28,7 @@ pub struct ParquetScanBuilder {
     pub schema: Option<SchemaRef>,
     pub file_path_column: Option<String>,
     pub hive_partitioning: bool,
+    pub schema_resolution: SchemaResolution,
 }
 
 impl ParquetScanBuilder {
@@ -48,6 +51,7 @@ impl ParquetScanBuilder {
             io_config: None,
             file_path_column: None,
             hive_partitioning: false,
+            schema_resolution: SchemaResolution::default(),
         }
     }
     pub fn infer_schema(mut self, infer_schema: bool) -> Self {
@@ -96,6 +100,11 @@ impl ParquetScanBuilder {
         self
     }
 
+    pub fn schema_resolution(mut self, schema_resolution: SchemaResolution) -> Self {
+        self.schema_resolution = schema_resolution;
+        self
+    }
+
     pub async fn finish(self) -> DaftResult<LogicalPlanBuilder> {
         let cfg = ParquetSourceConfig {
             coerce_int96_timestamp_unit: self.coerce_int96_timestamp_unit,
@@ -116,6 +125,7 @@ impl ParquetScanBuilder {
                 self.schema,
                 self.file_path_column,
                 self.hive_partitioning,
+                self.schema_resolution,
             )
             .await?,
         );
@@ -135,6 +145,7 @@ pub struct CsvScanBuilder {
     pub schema: Option<SchemaRef>,
     pub file_path_column: Option<String>,
     pub hive_partitioning: bool,
+    pub schema_resolution: SchemaResolution,
     pub delimiter: Option<char>,
     pub has_headers: bool,
     pub double_quote: bool,
@@ -162,6 +173,7 @@ impl CsvScanBuilder {
             io_config: None,
             file_path_column: None,
             hive_partitioning: false,
+            schema_resolution: SchemaResolution::default(),
             delimiter: None,
             has_headers: true,
             double_quote: true,
@@ -194,6 +206,10 @@ impl CsvScanBuilder {
         self.hive_partitioning = hive_partitioning;
         self
     }
+    pub fn schema_resolution(mut self, schema_resolution: SchemaResolution) -> Self {
+        self.schema_resolution = schema_resolution;
+        self
+    }
     pub fn delimiter(mut self, delimiter: char) -> Self {
         self.delimiter = Some(delimiter);
         self
@@ -257,6 +273,7 @@ impl CsvScanBuilder {
                 self.schema,
                 self.file_path_column,
                 self.hive_partitioning,
+                self.schema_resolution,
             )
             .await?,
         );
@@ -273,6 +290,7 @@ pub struct JsonScanBuilder {
     pub schema: Option<SchemaRef>,
     pub file_path_column: Option<String>,
     pub hive_partitioning: bool,
+    pub schema_resolution: SchemaResolution,
     pub schema_hints: Option<SchemaRef>,
     pub buffer_size: Option<usize>,
     pub chunk_size: Option<usize>,
@@ -292,6 +310,7 @@ impl JsonScanBuilder {
             io_config: None,
             file_path_column: None,
             hive_partitioning: false,
+            schema_resolution: SchemaResolution::default(),
             buffer_size: None,
             chunk_size: None,
             schema_hints: None,
@@ -323,6 +342,11 @@ impl JsonScanBuilder {
         self
     }
 
+    pub fn schema_resolution(mut self, schema_resolution: SchemaResolution) -> Self {
+        self.schema_resolution = schema_resolution;
+        self
+    }
+
     pub fn schema_hints(mut self, schema_hints: SchemaRef) -> Self {
         self.schema_hints = Some(schema_hints);
         self
@@ -353,6 +377,7 @@ impl JsonScanBuilder {
                 self.schema,
                 self.file_path_column,
                 self.hive_partitioning,
+                self.schema_resolution,
             )
             .await?,
         );
@@ -360,12 +385,24 @@ impl JsonScanBuilder {
     }
 }
 
+/// The version of a table format's table to read, so that reads of a table that changes over time are reproducible.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TableVersion {
+    /// The snapshot ID of an Iceberg table, or the version number of a Delta Lake table.
+    Id(i64),
+    /// The version that was current at a point in time, as an RFC 3339 timestamp.
+    AsOf(String),
+}
+
 #[cfg(feature = "python")]
 pub fn delta_scan<T: AsRef<str>>(
     glob_path: T,
     io_config: Option<IOConfig>,
     multithreaded_io: bool,
+    version: Option<TableVersion>,
 ) -> DaftResult<LogicalPlanBuilder> {
+    use pyo3::types::PyDict;
+
     use crate::storage_config::StorageConfig;
 
     Python::with_gil(|py| {
@@ -380,8 +417,14 @@ pub fn delta_scan<T: AsRef<str>>(
         let delta_lake_scan = PyModule::import(py, "daft.delta_lake.delta_lake_scan")?;
         let delta_lake_scan_operator =
             delta_lake_scan.getattr(pyo3::intern!(py, "DeltaLakeScanOperator"))?;
+        let kwargs = PyDict::new(py);
+        match version {
+            Some(TableVersion::Id(version)) => kwargs.set_item("version", version)?,
+            Some(TableVersion::AsOf(timestamp)) => kwargs.set_item("timestamp", timestamp)?,
+            None => {}
+        }
         let delta_lake_operator = delta_lake_scan_operator
-            .call1((glob_path.as_ref(), storage_config))?
+            .call((glob_path.as_ref(), storage_config), Some(&kwargs))?
             .into_pyobject(py)
             .unwrap()
             .into();
@@ -396,6 +439,7 @@ pub fn delta_scan<T: IntoGlobPath>(
     glob_path: T,
     io_config: Option<IOConfig>,
     multithreaded_io: bool,
+    version: Option<TableVersion>,
 ) -> DaftResult<LogicalPlanBuilder> {
     panic!("Delta Lake scan requires the 'python' feature to be enabled.")
 }
@@ -404,15 +448,15 @@ pub fn delta_scan<T: IntoGlobPath>(
 /// ex:
 /// ```python
 /// iceberg_table = pyiceberg.table.StaticTable.from_metadata(metadata_location)
-/// iceberg_scan = daft.iceberg.iceberg_scan.IcebergScanOperator(iceberg_table, snapshot_id, storage_config)
+/// iceberg_scan = daft.iceberg.iceberg_scan.IcebergScanOperator(iceberg_table, snapshot_id, storage_config, as_of=as_of)
 /// ```
 #[cfg(feature = "python")]
 pub fn iceberg_scan<T: AsRef<str>>(
     metadata_location: T,
-    snapshot_id: Option<usize>,
+    version: Option<TableVersion>,
     io_config: Option<IOConfig>,
 ) -> DaftResult<LogicalPlanBuilder> {
-    use pyo3::IntoPyObjectExt;
+    use pyo3::{types::PyDict, IntoPyObjectExt};
     let storage_config: StorageConfig = io_config.unwrap_or_default().into();
     let scan_operator = Python::with_gil(|py| -> DaftResult<ScanOperatorHandle> {
         // iceberg_table = pyiceberg.table.StaticTable.from_metadata(metadata_location)
@@ -420,11 +464,18 @@ pub fn iceberg_scan<T: AsRef<str>>(
         let iceberg_static_table = iceberg_table_module.getattr("StaticTable")?;
         let iceberg_table =
             iceberg_static_table.call_method1("from_metadata", (metadata_location.as_ref(),))?;
-        // iceberg_scan = daft.iceberg.iceberg_scan.IcebergScanOperator(iceberg_table, snapshot_id, storage_config)
+        // iceberg_scan = daft.iceberg.iceberg_scan.IcebergScanOperator(iceberg_table, snapshot_id, storage_config, as_of=as_of)
         let iceberg_scan_module = PyModule::import(py, "daft.iceberg.iceberg_scan")?;
         let iceberg_scan_class = iceberg_scan_module.getattr("IcebergScanOperator")?;
+        let (snapshot_id, as_of) = match version {
+            Some(TableVersion::Id(snapshot_id)) => (Some(snapshot_id), None),
+            Some(TableVersion::AsOf(as_of)) => (None, Some(as_of)),
+            None => (None, None),
+        };
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("as_of", as_of)?;
         let iceberg_scan = iceberg_scan_class
-            .call1((iceberg_table, snapshot_id, storage_config))?
+            .call((iceberg_table, snapshot_id, storage_config), Some(&kwargs))?
             .into_py_any(py)?;
         Ok(ScanOperatorHandle::from_python_scan_operator(
             iceberg_scan,
@@ -437,7 +488,7 @@ pub fn iceberg_scan<T: AsRef<str>>(
 #[cfg(not(feature = "python"))]
 pub fn iceberg_scan<T: AsRef<str>>(
     uri: T,
-    snapshot_id: Option<usize>,
+    version: Option<TableVersion>,
     io_config: Option<IOConfig>,
 ) -> DaftResult<LogicalPlanBuilder> {
     panic!("Iceberg scan requires the 'python' feature to be enabled.")
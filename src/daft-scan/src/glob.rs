@@ -6,8 +6,8 @@ use common_runtime::RuntimeRef;
 use common_scan_info::{PartitionField, Pushdowns, ScanOperator, ScanTaskLike, ScanTaskLikeRef};
 use daft_core::{prelude::Utf8Array, series::IntoSeries};
 use daft_csv::CsvParseOptions;
-use daft_io::{parse_url, FileMetadata, IOClient, IOStatsContext, IOStatsRef};
-use daft_parquet::read::ParquetSchemaInferenceOptions;
+use daft_io::{parse_url, FileMetadata, GlobOptions, IOClient, IOStatsContext, IOStatsRef};
+use daft_parquet::{metadata::field_id_mapping_from_metadata, read::ParquetSchemaInferenceOptions};
 use daft_recordbatch::RecordBatch;
 use daft_schema::{
     dtype::DataType,
@@ -20,12 +20,17 @@ use snafu::Snafu;
 
 use crate::{
     hive::{hive_partitions_to_fields, hive_partitions_to_series, parse_hive_partitioning},
+    schema_resolution::{merge_schemas, ColumnMapping, SchemaMode, SchemaResolution},
     storage_config::StorageConfig,
     ChunkSpec, DataSource, ScanTask,
 };
+/// Glob paths that start with this are patterns of files and directories to exclude from all of the others.
+const EXCLUSION_PREFIX: char = '!';
+
 #[derive(Debug)]
 pub struct GlobScanOperator {
     glob_paths: Vec<String>,
+    exclude: Vec<String>,
     file_format_config: Arc<FileFormatConfig>,
     schema: SchemaRef,
     storage_config: Arc<StorageConfig>,
@@ -36,6 +41,7 @@ pub struct GlobScanOperator {
     // When creating the glob scan operator, we might collect file metadata for the first file during schema inference.
     // Cache this metadata (along with the first filepath) so we can use it to populate the stats for the first scan task.
     first_metadata: Option<(String, TableMetadata)>,
+    schema_resolution: SchemaResolution,
 }
 
 /// Wrapper struct that implements a sync Iterator for a BoxStream
@@ -80,12 +86,21 @@ async fn run_glob(
     io_client: Arc<IOClient>,
     io_stats: Option<IOStatsRef>,
     file_format: FileFormat,
+    options: &GlobOptions,
 ) -> DaftResult<impl Stream<Item = DaftResult<FileMetadata>> + Send> {
     let (_, parsed_glob_path) = parse_url(glob_path)?;
     // Construct a static-lifetime BoxStream returning the FileMetadata
     let glob_input = parsed_glob_path.as_ref().to_string();
     let stream = io_client
-        .glob(glob_input, None, None, limit, io_stats, Some(file_format))
+        .glob(
+            glob_input,
+            None,
+            None,
+            limit,
+            io_stats,
+            Some(file_format),
+            options,
+        )
         .await?;
 
     let stream = stream.map_err(|e| e.into());
@@ -99,6 +114,7 @@ fn run_glob_parallel(
     runtime: RuntimeRef,
     io_stats: Option<IOStatsRef>,
     file_format: FileFormat,
+    options: GlobOptions,
 ) -> DaftResult<impl Iterator<Item = DaftResult<FileMetadata>>> {
     let num_parallel_tasks = 64;
 
@@ -108,10 +124,19 @@ fn run_glob_parallel(
         let glob_input = parsed_glob_path.as_ref().to_string();
         let io_client = io_client.clone();
         let io_stats = io_stats.clone();
+        let options = options.clone();
 
         runtime.spawn(async move {
             let stream = io_client
-                .glob(glob_input, None, None, None, io_stats, Some(file_format))
+                .glob(
+                    glob_input,
+                    None,
+                    None,
+                    None,
+                    io_stats,
+                    Some(file_format),
+                    &options,
+                )
                 .await?;
             let results = stream.map_err(|e| e.into()).collect::<Vec<_>>().await;
             DaftResult::Ok(futures::stream::iter(results))
@@ -130,7 +155,200 @@ fn run_glob_parallel(
     Ok(iterator)
 }
 
+/// Infers the schema of a file, along with its metadata if it's read along the way.
+async fn infer_file_schema(
+    filepath: &str,
+    file_format_config: &FileFormatConfig,
+    io_client: Arc<IOClient>,
+    io_stats: IOStatsRef,
+) -> DaftResult<(Schema, Option<TableMetadata>)> {
+    match file_format_config {
+        &FileFormatConfig::Parquet(ParquetSourceConfig {
+            coerce_int96_timestamp_unit,
+            ref field_id_mapping,
+            ..
+        }) => {
+            let io_stats = IOStatsContext::new(format!(
+                "GlobScanOperator constructor read_parquet_schema: for uri {filepath}"
+            ));
+
+            let (schema, metadata) = daft_parquet::read::read_parquet_schema_and_metadata(
+                filepath,
+                io_client,
+                Some(io_stats),
+                ParquetSchemaInferenceOptions {
+                    coerce_int96_timestamp_unit,
+                    ..Default::default()
+                },
+                field_id_mapping.clone(),
+            )
+            .await?;
+            Ok((
+                schema,
+                Some(TableMetadata {
+                    length: metadata.num_rows,
+                }),
+            ))
+        }
+        FileFormatConfig::Csv(CsvSourceConfig {
+            delimiter,
+            has_headers,
+            double_quote,
+            quote,
+            escape_char,
+            comment,
+            allow_variable_columns,
+            ..
+        }) => {
+            let (schema, _) = daft_csv::metadata::read_csv_schema(
+                filepath,
+                Some(CsvParseOptions::new_with_defaults(
+                    *has_headers,
+                    *delimiter,
+                    *double_quote,
+                    *quote,
+                    *allow_variable_columns,
+                    *escape_char,
+                    *comment,
+                )?),
+                None,
+                io_client,
+                Some(io_stats),
+            )
+            .await?;
+            Ok((schema, None))
+        }
+        FileFormatConfig::Json(_) => {
+            let schema = daft_json::schema::read_json_schema(
+                filepath,
+                None,
+                None,
+                io_client,
+                Some(io_stats),
+            )
+            .await?;
+            Ok((schema, None))
+        }
+        FileFormatConfig::Warc(_) => Err(DaftError::ValueError(
+            "Warc schemas do not need to be inferred".to_string(),
+        )),
+        #[cfg(feature = "python")]
+        FileFormatConfig::Database(_) => Err(DaftError::ValueError(
+            "Cannot glob a database source".to_string(),
+        )),
+        #[cfg(feature = "python")]
+        FileFormatConfig::PythonFunction => Err(DaftError::ValueError(
+            "Cannot glob a PythonFunction source".to_string(),
+        )),
+    }
+}
+
+/// Infers the union of the schemas of all of the files that match the glob paths, given the already-inferred schema of
+/// the first file, widening the types of columns to fit all of the files.
+async fn infer_union_schema(
+    glob_paths: &[String],
+    exclude: &[String],
+    (first_filepath, first_schema): (&str, Schema),
+    file_format_config: &FileFormatConfig,
+    io_client: Arc<IOClient>,
+) -> DaftResult<Schema> {
+    const NUM_PARALLEL_TASKS: usize = 64;
+
+    let io_stats = IOStatsContext::new(format!(
+        "GlobScanOperator::try_new schema union inference for {glob_paths:#?}"
+    ));
+    let options = GlobOptions {
+        exclude: exclude.to_vec(),
+        max_depth: None,
+    };
+    let mut filepaths = vec![];
+    for glob_path in glob_paths {
+        let files = run_glob(
+            glob_path,
+            None,
+            io_client.clone(),
+            Some(io_stats.clone()),
+            file_format_config.file_format(),
+            &options,
+        )
+        .await?;
+        filepaths.extend(
+            files
+                .map_ok(|file_metadata| file_metadata.filepath)
+                .try_collect::<Vec<_>>()
+                .await?,
+        );
+    }
+    let schemas = futures::stream::iter(
+        filepaths
+            .into_iter()
+            .filter(|filepath| filepath != first_filepath),
+    )
+    .map(|filepath| {
+        let io_client = io_client.clone();
+        let io_stats = io_stats.clone();
+        async move {
+            let (schema, _) =
+                infer_file_schema(&filepath, file_format_config, io_client, io_stats).await?;
+            DaftResult::Ok((filepath, schema))
+        }
+    })
+    .buffered(NUM_PARALLEL_TASKS)
+    .try_collect::<Vec<_>>()
+    .await?;
+    merge_schemas(
+        std::iter::once((first_filepath, &first_schema)).chain(
+            schemas
+                .iter()
+                .map(|(filepath, schema)| (filepath.as_str(), schema)),
+        ),
+    )
+}
+
+/// Maps the field IDs of the columns of the first file to their names in a Parquet config, so that the columns of the
+/// other files are matched to them by field ID, unless the config already has a mapping.
+async fn with_field_id_mapping(
+    file_format_config: Arc<FileFormatConfig>,
+    first_filepath: &str,
+    io_client: Arc<IOClient>,
+) -> DaftResult<Arc<FileFormatConfig>> {
+    let FileFormatConfig::Parquet(parquet_config) = file_format_config.as_ref() else {
+        return Err(DaftError::ValueError(format!(
+            "Columns can only be mapped by field ID for Parquet files, not {:?} files",
+            file_format_config.file_format()
+        )));
+    };
+    if parquet_config.field_id_mapping.is_some() {
+        return Ok(file_format_config);
+    }
+    let io_stats = IOStatsContext::new(format!(
+        "GlobScanOperator constructor field ID mapping: for uri {first_filepath}"
+    ));
+    let (schema, metadata) = daft_parquet::read::read_parquet_schema_and_metadata(
+        first_filepath,
+        io_client,
+        Some(io_stats),
+        ParquetSchemaInferenceOptions {
+            coerce_int96_timestamp_unit: parquet_config.coerce_int96_timestamp_unit,
+            ..Default::default()
+        },
+        None,
+    )
+    .await?;
+    let field_id_mapping = field_id_mapping_from_metadata(&metadata, &schema);
+    if field_id_mapping.is_empty() {
+        return Err(DaftError::ValueError(format!(
+            "Cannot map columns by field ID: the columns of {first_filepath} have no field IDs"
+        )));
+    }
+    Ok(Arc::new(FileFormatConfig::Parquet(ParquetSourceConfig {
+        field_id_mapping: Some(Arc::new(field_id_mapping)),
+        ..parquet_config.clone()
+    })))
+}
+
 impl GlobScanOperator {
+    #[allow(clippy::too_many_arguments)]
     pub async fn try_new(
         glob_paths: Vec<String>,
         file_format_config: Arc<FileFormatConfig>,
@@ -139,7 +357,15 @@ impl GlobScanOperator {
         user_provided_schema: Option<SchemaRef>,
         file_path_column: Option<String>,
         hive_partitioning: bool,
+        schema_resolution: SchemaResolution,
     ) -> DaftResult<Self> {
+        let (exclude, glob_paths): (Vec<_>, Vec<_>) = glob_paths
+            .into_iter()
+            .partition(|path| path.starts_with(EXCLUSION_PREFIX));
+        let exclude = exclude
+            .into_iter()
+            .map(|path| path[EXCLUSION_PREFIX.len_utf8()..].to_string())
+            .collect::<Vec<_>>();
         let first_glob_path = match glob_paths.first() {
             None => Err(DaftError::ValueError(
                 "Cannot glob empty list of files".to_string(),
@@ -159,6 +385,10 @@ impl GlobScanOperator {
             io_client.clone(),
             Some(io_stats.clone()),
             file_format,
+            &GlobOptions {
+                exclude: exclude.clone(),
+                max_depth: None,
+            },
         )
         .await?;
 
@@ -200,95 +430,40 @@ impl GlobScanOperator {
             (partitioning_keys, generated_fields)
         };
 
+        // CSV columns are read by position, so they can't be matched to the columns of a union of schemas.
+        if schema_resolution.mode == SchemaMode::Union && file_format == FileFormat::Csv {
+            return Err(DaftError::ValueError(
+                "The union of the schemas of CSV files can't be inferred, since their columns are read by position"
+                    .to_string(),
+            ));
+        }
+        let file_format_config = match schema_resolution.column_mapping {
+            ColumnMapping::Name => file_format_config,
+            ColumnMapping::FieldId => {
+                with_field_id_mapping(file_format_config, &first_filepath, io_client.clone())
+                    .await?
+            }
+        };
         let (schema, first_metadata) = match infer_schema {
             true => {
-                let (inferred_schema, first_metadata) = match file_format_config.as_ref() {
-                    &FileFormatConfig::Parquet(ParquetSourceConfig {
-                        coerce_int96_timestamp_unit,
-                        ref field_id_mapping,
-                        ..
-                    }) => {
-                        let io_stats = IOStatsContext::new(format!(
-                            "GlobScanOperator constructor read_parquet_schema: for uri {first_filepath}"
-                        ));
-
-                        let (schema, metadata) =
-                            daft_parquet::read::read_parquet_schema_and_metadata(
-                                first_filepath.as_str(),
-                                io_client,
-                                Some(io_stats),
-                                ParquetSchemaInferenceOptions {
-                                    coerce_int96_timestamp_unit,
-                                    ..Default::default()
-                                },
-                                field_id_mapping.clone(),
-                            )
-                            .await?;
-                        let metadata = Some((
-                            first_filepath,
-                            TableMetadata {
-                                length: metadata.num_rows,
-                            },
-                        ));
-                        (schema, metadata)
-                    }
-                    FileFormatConfig::Csv(CsvSourceConfig {
-                        delimiter,
-                        has_headers,
-                        double_quote,
-                        quote,
-                        escape_char,
-                        comment,
-                        allow_variable_columns,
-                        ..
-                    }) => {
-                        let (schema, _) = daft_csv::metadata::read_csv_schema(
-                            first_filepath.as_str(),
-                            Some(CsvParseOptions::new_with_defaults(
-                                *has_headers,
-                                *delimiter,
-                                *double_quote,
-                                *quote,
-                                *allow_variable_columns,
-                                *escape_char,
-                                *comment,
-                            )?),
-                            None,
-                            io_client,
-                            Some(io_stats),
-                        )
-                        .await?;
-                        (schema, None)
-                    }
-                    FileFormatConfig::Json(_) => {
-                        let schema = daft_json::schema::read_json_schema(
-                            first_filepath.as_str(),
-                            None,
-                            None,
-                            io_client,
-                            Some(io_stats),
-                        )
-                        .await?;
-                        (schema, None)
-                    }
-                    FileFormatConfig::Warc(_) => {
-                        return Err(DaftError::ValueError(
-                            "Warc schemas do not need to be inferred".to_string(),
-                        ))
-                    }
-                    #[cfg(feature = "python")]
-                    FileFormatConfig::Database(_) => {
-                        return Err(DaftError::ValueError(
-                            "Cannot glob a database source".to_string(),
-                        ))
-                    }
-                    #[cfg(feature = "python")]
-                    FileFormatConfig::PythonFunction => {
-                        return Err(DaftError::ValueError(
-                            "Cannot glob a PythonFunction source".to_string(),
-                        ))
-                    }
-                };
+                let (mut inferred_schema, first_metadata) = infer_file_schema(
+                    &first_filepath,
+                    &file_format_config,
+                    io_client.clone(),
+                    io_stats,
+                )
+                .await?;
+                if schema_resolution.mode == SchemaMode::Union {
+                    inferred_schema = infer_union_schema(
+                        &glob_paths,
+                        &exclude,
+                        (&first_filepath, inferred_schema),
+                        &file_format_config,
+                        io_client,
+                    )
+                    .await?;
+                }
+                let first_metadata = first_metadata.map(|metadata| (first_filepath, metadata));
                 match user_provided_schema {
                     Some(hint) => (
                         Arc::new(inferred_schema.apply_hints(&hint)?),
@@ -304,6 +479,7 @@ impl GlobScanOperator {
         };
         Ok(Self {
             glob_paths,
+            exclude,
             file_format_config,
             schema,
             storage_config,
@@ -312,6 +488,7 @@ impl GlobScanOperator {
             partitioning_keys,
             generated_fields: Arc::new(generated_fields),
             first_metadata,
+            schema_resolution,
         })
     }
 }
@@ -346,6 +523,9 @@ impl ScanOperator for GlobScanOperator {
     fn can_absorb_limit(&self) -> bool {
         false
     }
+    fn can_absorb_sample(&self) -> bool {
+        true
+    }
 
     fn multiline_display(&self) -> Vec<String> {
         let condensed_glob_paths = if self.glob_paths.len() <= 7 {
@@ -371,6 +551,10 @@ impl ScanOperator for GlobScanOperator {
             "GlobScanOperator".to_string(),
             format!("Glob paths = [{}]", condensed_glob_paths),
         ];
+        if !self.exclude.is_empty() {
+            lines.push(format!("Excluded = [{}]", self.exclude.join(", ")));
+        }
+        lines.extend(self.schema_resolution.multiline_display());
         lines.extend(self.file_format_config.multiline_display());
         lines.extend(self.storage_config.multiline_display());
 
@@ -391,6 +575,10 @@ impl ScanOperator for GlobScanOperator {
             io_runtime,
             Some(io_stats),
             file_format,
+            GlobOptions {
+                exclude: self.exclude.clone(),
+                max_depth: None,
+            },
         )?;
 
         let file_format_config = self.file_format_config.clone();
@@ -482,6 +670,8 @@ impl ScanOperator for GlobScanOperator {
                             chunk_spec,
                             size_bytes,
                             iceberg_delete_files: None,
+                            iceberg_equality_delete_files: None,
+                            delta_deletion_vector: None,
                             partition_spec,
                             statistics: None,
                             parquet_metadata: None,
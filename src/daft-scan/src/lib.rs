@@ -10,9 +10,10 @@ use std::{
 };
 
 use common_display::DisplayAs;
-use common_error::DaftError;
+use common_error::{DaftError, DaftResult};
 use common_file_formats::FileFormatConfig;
 use common_scan_info::{Pushdowns, ScanTaskLike, ScanTaskLikeRef};
+use daft_core::series::Series;
 use daft_schema::schema::{Schema, SchemaRef};
 use daft_stats::{PartitionSpec, TableMetadata, TableStatistics};
 use itertools::Itertools;
@@ -29,11 +30,14 @@ pub mod scan_task_iters;
 
 #[cfg(feature = "python")]
 pub mod python;
+mod sample;
+pub mod schema_resolution;
 pub mod storage_config;
 #[cfg(feature = "python")]
 use pyo3::PyErr;
 #[cfg(feature = "python")]
 pub use python::register_modules;
+pub use sample::ParquetRowSample;
 use snafu::Snafu;
 use storage_config::StorageConfig;
 #[derive(Debug, Snafu)]
@@ -126,6 +130,33 @@ impl ChunkSpec {
     }
 }
 
+/// An Iceberg equality delete file, which deletes the rows of the data files it applies to whose values in
+/// `equality_columns` are equal to those of one of its rows.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct IcebergEqualityDeleteFile {
+    pub path: String,
+    pub equality_columns: Vec<String>,
+}
+
+/// A Delta Lake deletion vector of a data file.
+///
+/// It deletes the rows of the data file at the positions in its bitmap, as described in the
+/// [spec](https://github.com/delta-io/delta/blob/master/PROTOCOL.md#deletion-vectors).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DeltaDeletionVector {
+    /// The URI of the table, which the paths of deletion vectors stored relative to it are resolved against.
+    pub table_uri: String,
+    /// `u` if the deletion vector is stored in a file relative to the table, `p` if it's stored at an absolute path,
+    /// or `i` if it's inlined in the log.
+    pub storage_type: String,
+    pub path_or_inline_dv: String,
+    /// The offset of the deletion vector in its file, if it isn't inlined.
+    pub offset: Option<i64>,
+    pub size_in_bytes: i64,
+    /// The number of rows that the deletion vector deletes.
+    pub cardinality: i64,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum DataSource {
     File {
@@ -133,6 +164,8 @@ pub enum DataSource {
         chunk_spec: Option<ChunkSpec>,
         size_bytes: Option<u64>,
         iceberg_delete_files: Option<Vec<String>>,
+        iceberg_equality_delete_files: Option<Vec<IcebergEqualityDeleteFile>>,
+        delta_deletion_vector: Option<Box<DeltaDeletionVector>>,
         metadata: Option<TableMetadata>,
         partition_spec: Option<PartitionSpec>,
         statistics: Option<TableStatistics>,
@@ -165,6 +198,8 @@ impl Hash for DataSource {
                 chunk_spec,
                 size_bytes,
                 iceberg_delete_files,
+                iceberg_equality_delete_files,
+                delta_deletion_vector,
                 metadata,
                 partition_spec,
                 statistics,
@@ -176,6 +211,8 @@ impl Hash for DataSource {
                 }
                 size_bytes.hash(state);
                 iceberg_delete_files.hash(state);
+                iceberg_equality_delete_files.hash(state);
+                delta_deletion_vector.hash(state);
                 metadata.hash(state);
                 partition_spec.hash(state);
                 statistics.hash(state);
@@ -293,6 +330,40 @@ impl DataSource {
         }
     }
 
+    #[must_use]
+    pub fn get_iceberg_equality_delete_files(&self) -> Option<&Vec<IcebergEqualityDeleteFile>> {
+        match self {
+            Self::File {
+                iceberg_equality_delete_files,
+                ..
+            } => iceberg_equality_delete_files.as_ref(),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn get_delta_deletion_vector(&self) -> Option<&DeltaDeletionVector> {
+        match self {
+            Self::File {
+                delta_deletion_vector,
+                ..
+            } => delta_deletion_vector.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Whether any rows of this source are deleted by Iceberg delete files or a Delta Lake deletion vector, which are
+    /// only applied while reading it.
+    #[must_use]
+    pub fn has_deletes(&self) -> bool {
+        self.get_iceberg_delete_files()
+            .is_some_and(|files| !files.is_empty())
+            || self
+                .get_iceberg_equality_delete_files()
+                .is_some_and(|files| !files.is_empty())
+            || self.get_delta_deletion_vector().is_some()
+    }
+
     #[must_use]
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
@@ -302,6 +373,8 @@ impl DataSource {
                 chunk_spec,
                 size_bytes,
                 iceberg_delete_files,
+                iceberg_equality_delete_files,
+                delta_deletion_vector,
                 metadata,
                 partition_spec,
                 statistics,
@@ -320,6 +393,21 @@ impl DataSource {
                 if let Some(iceberg_delete_files) = iceberg_delete_files {
                     res.push(format!("Iceberg delete files = {iceberg_delete_files:?}"));
                 }
+                if let Some(iceberg_equality_delete_files) = iceberg_equality_delete_files {
+                    res.push(format!(
+                        "Iceberg equality delete files = {:?}",
+                        iceberg_equality_delete_files
+                            .iter()
+                            .map(|file| format!("{} on {:?}", file.path, file.equality_columns))
+                            .collect::<Vec<_>>()
+                    ));
+                }
+                if let Some(delta_deletion_vector) = delta_deletion_vector {
+                    res.push(format!(
+                        "Delta deletion vector = {} ({} rows)",
+                        delta_deletion_vector.path_or_inline_dv, delta_deletion_vector.cardinality
+                    ));
+                }
                 if let Some(metadata) = metadata {
                     res.push(format!(
                         "Metadata = {}",
@@ -650,8 +738,8 @@ impl ScanTask {
     /// Obtain an accurate, exact num_rows from the ScanTask, or `None` if this is not possible
     #[must_use]
     pub fn num_rows(&self) -> Option<usize> {
-        if self.pushdowns.filters.is_some() {
-            // Cannot obtain an accurate num_rows if there are filters
+        if self.pushdowns.filters.is_some() || self.pushdowns.sample.is_some() {
+            // Cannot obtain an accurate num_rows if there are filters or samples
             None
         } else {
             // Only can obtain an accurate num_rows if metadata is provided
@@ -711,6 +799,11 @@ impl ScanTask {
                 })
             });
 
+        let approx_total_num_rows_before_pushdowns =
+            approx_total_num_rows_before_pushdowns.map(|num_rows| match &self.pushdowns.sample {
+                Some(sample) => num_rows * sample.fraction.0,
+                None => num_rows,
+            });
         approx_total_num_rows_before_pushdowns.map(|approx_total_num_rows_before_pushdowns| {
             if self.pushdowns.filters.is_some() {
                 // HACK: This might not be a good idea? We could also just return None here
@@ -727,6 +820,22 @@ impl ScanTask {
         })
     }
 
+    /// Returns a copy of this ScanTask that produces at most `limit` rows
+    #[must_use]
+    pub fn with_limit(&self, limit: usize) -> Self {
+        Self {
+            sources: self.sources.clone(),
+            schema: self.schema.clone(),
+            file_format_config: self.file_format_config.clone(),
+            storage_config: self.storage_config.clone(),
+            pushdowns: self.pushdowns.with_limit(Some(limit)),
+            size_bytes_on_disk: self.size_bytes_on_disk,
+            metadata: self.metadata.clone(),
+            statistics: self.statistics.clone(),
+            generated_fields: self.generated_fields.clone(),
+        }
+    }
+
     /// Obtain the absolute maximum number of rows this ScanTask can give, or None if not possible to derive
     #[must_use]
     pub fn upper_bound_rows(&self) -> Option<usize> {
@@ -815,6 +924,23 @@ impl ScanTask {
         }
     }
 
+    /// Returns whether this scan task may contain rows whose value for the partition column `column` is in `values`.
+    ///
+    /// Scan tasks that aren't partitioned on `column` may contain any value. Null partition values never match.
+    pub fn may_match_partition_values(&self, column: &str, values: &Series) -> DaftResult<bool> {
+        let Some(partition_value) = self
+            .partition_spec()
+            .and_then(|spec| spec.keys.get_column(column).ok())
+        else {
+            return Ok(true);
+        };
+        Ok(partition_value
+            .is_in(values)?
+            .bool()?
+            .get(0)
+            .unwrap_or(false))
+    }
+
     #[must_use]
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
@@ -906,7 +1032,10 @@ mod test {
     use common_error::DaftResult;
     use common_file_formats::{FileFormatConfig, ParquetSourceConfig};
     use common_scan_info::{Pushdowns, ScanOperator};
+    use daft_core::prelude::{DataType, Field, Int64Array, IntoSeries};
+    use daft_recordbatch::RecordBatch;
     use daft_schema::{schema::Schema, time_unit::TimeUnit};
+    use daft_stats::PartitionSpec;
     use itertools::Itertools;
 
     use crate::{glob::GlobScanOperator, storage_config::StorageConfig, DataSource, ScanTask};
@@ -918,6 +1047,8 @@ mod test {
                 chunk_spec: None,
                 size_bytes: None,
                 iceberg_delete_files: None,
+                iceberg_equality_delete_files: None,
+                delta_deletion_vector: None,
                 metadata: None,
                 partition_spec: None,
                 statistics: None,
@@ -942,6 +1073,35 @@ mod test {
         )
     }
 
+    fn make_partitioned_scan_task(partition_value: Option<i64>) -> DaftResult<ScanTask> {
+        let field = Field::new("part", DataType::Int64);
+        let keys = RecordBatch::from_nonempty_columns(vec![Int64Array::from_iter(
+            field,
+            std::iter::once(partition_value),
+        )
+        .into_series()])?;
+        let source = DataSource::File {
+            path: "test".to_string(),
+            chunk_spec: None,
+            size_bytes: None,
+            iceberg_delete_files: None,
+            iceberg_equality_delete_files: None,
+            delta_deletion_vector: None,
+            metadata: None,
+            partition_spec: Some(PartitionSpec { keys }),
+            statistics: None,
+            parquet_metadata: None,
+        };
+        Ok(ScanTask::new(
+            vec![source],
+            make_scan_task(1).file_format_config,
+            Arc::new(Schema::empty()),
+            Arc::new(StorageConfig::new_internal(false, None)),
+            Pushdowns::default(),
+            None,
+        ))
+    }
+
     async fn make_glob_scan_operator(num_sources: usize, infer_schema: bool) -> GlobScanOperator {
         let file_format_config: FileFormatConfig = FileFormatConfig::Parquet(ParquetSourceConfig {
             coerce_int96_timestamp_unit: TimeUnit::Seconds,
@@ -964,6 +1124,7 @@ mod test {
             Some(Arc::new(Schema::empty())),
             None,
             false,
+            Default::default(),
         )
         .await
         .unwrap();
@@ -1010,6 +1171,18 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_may_match_partition_values() -> DaftResult<()> {
+        let values = Int64Array::from(("values", vec![1, 3])).into_series();
+        assert!(make_partitioned_scan_task(Some(1))?.may_match_partition_values("part", &values)?);
+        assert!(!make_partitioned_scan_task(Some(2))?.may_match_partition_values("part", &values)?);
+        assert!(!make_partitioned_scan_task(None)?.may_match_partition_values("part", &values)?);
+        // Scan tasks that aren't partitioned on the column may match any value.
+        assert!(make_partitioned_scan_task(Some(2))?.may_match_partition_values("other", &values)?);
+        assert!(make_scan_task(1).may_match_partition_values("part", &values)?);
+        Ok(())
+    }
+
     #[test]
     fn test_display_condenses() -> DaftResult<()> {
         let scan_task = make_scan_task(7);
@@ -85,8 +85,9 @@ pub mod pylib {
 
     use super::PythonTablesFactoryArgs;
     use crate::{
-        anonymous::AnonymousScanOperator, glob::GlobScanOperator, storage_config::StorageConfig,
-        DataSource, ScanTask,
+        anonymous::AnonymousScanOperator, glob::GlobScanOperator,
+        schema_resolution::SchemaResolution, storage_config::StorageConfig, DataSource,
+        DeltaDeletionVector, IcebergEqualityDeleteFile, ScanTask,
     };
     #[pyclass(module = "daft.daft", frozen)]
     #[derive(Debug, Clone)]
@@ -131,7 +132,9 @@ pub mod pylib {
             hive_partitioning,
             infer_schema,
             schema=None,
-            file_path_column=None
+            file_path_column=None,
+            schema_resolution=None,
+            column_mapping=None
         ))]
         pub fn glob_scan(
             py: Python,
@@ -142,7 +145,19 @@ pub mod pylib {
             infer_schema: bool,
             schema: Option<PySchema>,
             file_path_column: Option<String>,
+            schema_resolution: Option<&str>,
+            column_mapping: Option<&str>,
         ) -> PyResult<Self> {
+            let schema_resolution = SchemaResolution::new(
+                schema_resolution
+                    .map(str::parse)
+                    .transpose()?
+                    .unwrap_or_default(),
+                column_mapping
+                    .map(str::parse)
+                    .transpose()?
+                    .unwrap_or_default(),
+            );
             py.allow_threads(|| {
                 let executor = common_runtime::get_io_runtime(true);
 
@@ -154,6 +169,7 @@ pub mod pylib {
                     schema.map(|s| s.schema),
                     file_path_column,
                     hive_partitioning,
+                    schema_resolution,
                 );
 
                 let operator = executor.block_on(task)??;
@@ -223,6 +239,11 @@ pub mod pylib {
                 .extract::<bool>(py)
         }
 
+        fn _snapshot_id(abc: &PyObject, py: Python) -> PyResult<Option<String>> {
+            abc.call_method0(py, pyo3::intern!(py, "snapshot_id"))?
+                .extract::<Option<String>>(py)
+        }
+
         fn _display_name(abc: &PyObject, py: Python) -> PyResult<String> {
             abc.call_method0(py, pyo3::intern!(py, "display_name"))?
                 .extract::<String>(py)
@@ -285,6 +306,13 @@ pub mod pylib {
             lines
         }
 
+        fn snapshot_id(&self) -> Option<String> {
+            // The snapshot is looked up on every call since the operator may read the latest version of a table.
+            Python::with_gil(|py| Self::_snapshot_id(&self.operator, py))
+                .ok()
+                .flatten()
+        }
+
         fn to_scan_tasks(&self, pushdowns: Pushdowns) -> DaftResult<Vec<ScanTaskLikeRef>> {
             let scan_tasks = Python::with_gil(|py| {
                 let pypd = PyPushdowns(pushdowns.clone().into()).into_pyobject(py)?;
@@ -341,6 +369,14 @@ pub mod pylib {
                 .map(i64::try_from)
                 .transpose()?)
         }
+
+        pub fn paths(&self) -> Vec<String> {
+            self.0
+                .sources
+                .iter()
+                .map(|source| source.get_path().to_string())
+                .collect()
+        }
     }
 
     #[pymethods]
@@ -357,7 +393,9 @@ pub mod pylib {
             iceberg_delete_files=None,
             pushdowns=None,
             partition_values=None,
-            stats=None
+            stats=None,
+            iceberg_equality_delete_files=None,
+            delta_deletion_vector=None
         ))]
         pub fn catalog_scan_task(
             file: String,
@@ -370,6 +408,8 @@ pub mod pylib {
             pushdowns: Option<PyPushdowns>,
             partition_values: Option<PyRecordBatch>,
             stats: Option<PyRecordBatch>,
+            iceberg_equality_delete_files: Option<Vec<(String, Vec<String>)>>,
+            delta_deletion_vector: Option<(String, String, String, Option<i64>, i64, i64)>,
         ) -> PyResult<Option<Self>> {
             if let Some(ref pvalues) = partition_values
                 && let Some(Some(ref partition_filters)) =
@@ -405,6 +445,34 @@ pub mod pylib {
                 chunk_spec: None,
                 size_bytes,
                 iceberg_delete_files,
+                iceberg_equality_delete_files: iceberg_equality_delete_files.map(|files| {
+                    files
+                        .into_iter()
+                        .map(|(path, equality_columns)| IcebergEqualityDeleteFile {
+                            path,
+                            equality_columns,
+                        })
+                        .collect()
+                }),
+                delta_deletion_vector: delta_deletion_vector.map(
+                    |(
+                        table_uri,
+                        storage_type,
+                        path_or_inline_dv,
+                        offset,
+                        size_in_bytes,
+                        cardinality,
+                    )| {
+                        Box::new(DeltaDeletionVector {
+                            table_uri,
+                            storage_type,
+                            path_or_inline_dv,
+                            offset,
+                            size_in_bytes,
+                            cardinality,
+                        })
+                    },
+                ),
                 metadata,
                 partition_spec: Some(pspec),
                 statistics,
@@ -584,6 +652,8 @@ pub mod pylib {
             chunk_spec: None,
             size_bytes: Some(file_size),
             iceberg_delete_files: None,
+            iceberg_equality_delete_files: None,
+            delta_deletion_vector: None,
             metadata: if has_metadata.unwrap_or(false) {
                 Some(TableMetadata {
                     length: metadata.num_rows,
@@ -600,7 +670,7 @@ pub mod pylib {
             Arc::new(FileFormatConfig::Parquet(default::Default::default())),
             Arc::new(schema),
             Arc::new(Default::default()),
-            Pushdowns::new(None, None, columns.map(Arc::new), None),
+            Pushdowns::new(None, None, columns.map(Arc::new), None, None),
             None,
         );
         Ok(st.estimate_in_memory_size_bytes(None).unwrap())
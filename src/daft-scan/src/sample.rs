@@ -0,0 +1,162 @@
+use common_error::DaftResult;
+use common_file_formats::FileFormatConfig;
+use daft_core::prelude::{IntoSeries, UInt64Array};
+use daft_recordbatch::RecordBatch;
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::{ChunkSpec, ScanTask};
+
+/// The rows that a sample pushdown picks from a Parquet scan task.
+///
+/// The rows are picked from the row counts in the Parquet metadata before anything is read, so that row groups
+/// without any picked rows are skipped entirely.
+#[derive(Debug)]
+pub struct ParquetRowSample {
+    /// The row groups to read from each source of the scan task.
+    pub row_groups: Vec<Vec<i64>>,
+    /// Sorted indices of the picked rows, relative to the rows of `row_groups` in read order.
+    indices: Vec<u64>,
+    /// Position in `indices` of the first row that has not been taken yet.
+    next: usize,
+    /// Number of rows of `row_groups` that have been passed to [`Self::take`] so far.
+    rows_seen: u64,
+}
+
+impl ParquetRowSample {
+    fn new(row_group_sizes: Vec<Vec<(i64, usize)>>, picked: Vec<usize>) -> Self {
+        let mut picked = picked.into_iter().peekable();
+        let mut start = 0;
+        let mut num_kept_rows = 0;
+        let mut indices = Vec::with_capacity(picked.len());
+        let row_groups = row_group_sizes
+            .into_iter()
+            .map(|sizes| {
+                sizes
+                    .into_iter()
+                    .filter(|(_, num_rows)| {
+                        let end = start + num_rows;
+                        let num_picked_before = indices.len();
+                        while let Some(row) = picked.next_if(|row| *row < end) {
+                            indices.push((row - start + num_kept_rows) as u64);
+                        }
+                        start = end;
+                        let keep = indices.len() > num_picked_before;
+                        if keep {
+                            num_kept_rows += num_rows;
+                        }
+                        keep
+                    })
+                    .map(|(row_group, _)| row_group)
+                    .collect()
+            })
+            .collect();
+        Self {
+            row_groups,
+            indices,
+            next: 0,
+            rows_seen: 0,
+        }
+    }
+
+    /// Takes the picked rows from the next batch read from `row_groups`. Batches must be passed in read order.
+    pub fn take(&mut self, batch: &RecordBatch) -> DaftResult<RecordBatch> {
+        let start = self.next;
+        let end = self.rows_seen + batch.len() as u64;
+        while self.next < self.indices.len() && self.indices[self.next] < end {
+            self.next += 1;
+        }
+        let indices = UInt64Array::from((
+            "idx",
+            self.indices[start..self.next]
+                .iter()
+                .map(|idx| idx - self.rows_seen)
+                .collect::<Vec<_>>(),
+        ));
+        self.rows_seen = end;
+        batch.take(&indices.into_series())
+    }
+}
+
+impl ScanTask {
+    /// Picks the rows of this scan task's sample pushdown ahead of the read, if it can be done from the Parquet
+    /// metadata alone. Takes `ceil(fraction * num_rows)` rows without replacement, as sampling after the read does.
+    #[must_use]
+    pub fn sample_parquet_row_groups(&self) -> Option<ParquetRowSample> {
+        let sample = self.pushdowns.sample.as_ref()?;
+        // With filters, the row counts in the metadata aren't the counts of the rows being sampled.
+        if self.pushdowns.filters.is_some()
+            || !matches!(
+                self.file_format_config.as_ref(),
+                FileFormatConfig::Parquet(_)
+            )
+        {
+            return None;
+        }
+        let row_group_sizes = self
+            .sources
+            .iter()
+            .map(|source| {
+                // Deleted rows are only dropped while reading.
+                if source.has_deletes() {
+                    return None;
+                }
+                let metadata = source.get_parquet_metadata()?;
+                let row_groups = match source.get_chunk_spec() {
+                    Some(ChunkSpec::Parquet(row_groups)) => row_groups.clone(),
+                    None => metadata.row_groups.keys().map(|idx| *idx as i64).collect(),
+                };
+                row_groups
+                    .into_iter()
+                    .map(|row_group| {
+                        let num_rows = metadata.row_groups.get(&(row_group as usize))?.num_rows();
+                        Some((row_group, num_rows))
+                    })
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let num_rows = row_group_sizes.iter().flatten().map(|(_, n)| n).sum();
+        let num_picked = ((sample.fraction.0 * num_rows as f64).ceil() as usize).min(num_rows);
+        let mut rng = match sample.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_rng(rand::thread_rng()).unwrap(),
+        };
+        let mut picked = rand::seq::index::sample(&mut rng, num_rows, num_picked).into_vec();
+        picked.sort_unstable();
+        Some(ParquetRowSample::new(row_group_sizes, picked))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_recordbatch::RecordBatch;
+
+    use super::ParquetRowSample;
+
+    #[test]
+    fn sample_skips_row_groups_without_picked_rows() -> DaftResult<()> {
+        // Two sources with row groups of 3 and 2 rows, and of 4 rows.
+        let row_group_sizes = vec![vec![(0, 3), (1, 2)], vec![(2, 4)]];
+        let mut sample = ParquetRowSample::new(row_group_sizes, vec![1, 2, 6]);
+        assert_eq!(sample.row_groups, vec![vec![0], vec![2]]);
+
+        // The rows of the kept row groups, read in two batches.
+        let values = Int64Array::from(("a", vec![0, 1, 2, 5, 6, 7, 8])).into_series();
+        let first = RecordBatch::from_nonempty_columns(vec![values.slice(0, 4)?])?;
+        let second = RecordBatch::from_nonempty_columns(vec![values.slice(4, 7)?])?;
+
+        let taken = sample.take(&first)?;
+        assert_eq!(
+            taken.get_column("a")?.i64()?.as_arrow().values().as_slice(),
+            &[1, 2]
+        );
+        let taken = sample.take(&second)?;
+        assert_eq!(
+            taken.get_column("a")?.i64()?.as_arrow().values().as_slice(),
+            &[6]
+        );
+        Ok(())
+    }
+}
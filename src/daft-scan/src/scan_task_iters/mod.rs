@@ -223,9 +223,7 @@ fn split_by_row_groups(
                     ) && source
                         .get_size_bytes()
                         .is_none_or(|s| s > max_size_bytes as u64)
-                      && source
-                        .get_iceberg_delete_files()
-                        .is_none_or(std::vec::Vec::is_empty)
+                      && !source.has_deletes()
                     {
                         let (io_runtime, io_client) =
                             t.storage_config.get_io_client_and_runtime()?;
@@ -314,6 +312,56 @@ fn split_by_row_groups(
     }
 }
 
+/// Distributes the row budget of a LIMIT pushdown across ScanTasks, in order.
+///
+/// Each ScanTask is limited to the number of rows that remain in the budget, so that its reader stops as soon as
+/// enough rows have been produced. Once the preceding ScanTasks are known to produce at least `limit` rows, the
+/// remaining ScanTasks are dropped entirely. This is only possible while the exact row counts of the ScanTasks are
+/// known, i.e. they have metadata, no filter pushdowns and no Iceberg delete files.
+#[must_use]
+pub fn apply_limit_row_budgets(
+    scan_tasks: Vec<ScanTaskRef>,
+    pushdowns: &Pushdowns,
+) -> Vec<ScanTaskRef> {
+    let Some(limit) = pushdowns.limit else {
+        return scan_tasks;
+    };
+    if pushdowns.filters.is_some() {
+        return scan_tasks;
+    }
+    let mut remaining_rows = limit;
+    let mut budgeted_tasks = Vec::with_capacity(scan_tasks.len());
+    let mut scan_tasks = scan_tasks.into_iter();
+    for scan_task in scan_tasks.by_ref() {
+        if remaining_rows == 0 {
+            return budgeted_tasks;
+        }
+        let scan_task = if scan_task
+            .pushdowns
+            .limit
+            .is_none_or(|task_limit| task_limit > remaining_rows)
+        {
+            Arc::new(scan_task.with_limit(remaining_rows))
+        } else {
+            scan_task
+        };
+        let has_delete_files = scan_task.sources.iter().any(DataSource::has_deletes);
+        let num_rows = if has_delete_files {
+            None
+        } else {
+            scan_task.num_rows()
+        };
+        budgeted_tasks.push(scan_task);
+        match num_rows {
+            Some(num_rows) => remaining_rows = remaining_rows.saturating_sub(num_rows),
+            // We can't tell how many rows this ScanTask produces, so keep the remaining ScanTasks as is.
+            None => break,
+        }
+    }
+    budgeted_tasks.extend(scan_tasks);
+    budgeted_tasks
+}
+
 fn split_and_merge_pass(
     scan_tasks: Arc<Vec<ScanTaskLikeRef>>,
     pushdowns: &Pushdowns,
@@ -344,9 +392,11 @@ fn split_and_merge_pass(
                 cfg.scan_tasks_max_size_bytes,
             );
             let merged_tasks = merge_by_sizes(split_tasks, pushdowns, cfg);
-            let scan_tasks: Vec<Arc<dyn ScanTaskLike>> = merged_tasks
-                .map(|st| st.map(|task| task as Arc<dyn ScanTaskLike>))
-                .collect::<DaftResult<Vec<_>>>()?;
+            let scan_tasks =
+                apply_limit_row_budgets(merged_tasks.collect::<DaftResult<_>>()?, pushdowns)
+                    .into_iter()
+                    .map(|task| task as Arc<dyn ScanTaskLike>)
+                    .collect();
             Ok(Arc::new(scan_tasks))
         } else if cfg.scantask_splitting_level == 2 {
             let split_tasks = {
@@ -354,9 +404,11 @@ fn split_and_merge_pass(
                 Box::new(splitter.into_iter()) as BoxScanTaskIter
             };
             let merged_tasks = merge_by_sizes(split_tasks, pushdowns, cfg);
-            let scan_tasks: Vec<Arc<dyn ScanTaskLike>> = merged_tasks
-                .map(|st| st.map(|task| task as Arc<dyn ScanTaskLike>))
-                .collect::<DaftResult<Vec<_>>>()?;
+            let scan_tasks =
+                apply_limit_row_budgets(merged_tasks.collect::<DaftResult<_>>()?, pushdowns)
+                    .into_iter()
+                    .map(|task| task as Arc<dyn ScanTaskLike>)
+                    .collect();
             Ok(Arc::new(scan_tasks))
         } else {
             panic!(
@@ -374,3 +426,84 @@ fn split_and_merge_pass(
 fn set_pass() {
     let _ = SPLIT_AND_MERGE_PASS.set(&split_and_merge_pass);
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_file_formats::{FileFormatConfig, ParquetSourceConfig};
+    use common_scan_info::Pushdowns;
+    use daft_schema::{schema::Schema, time_unit::TimeUnit};
+    use daft_stats::TableMetadata;
+
+    use super::apply_limit_row_budgets;
+    use crate::{storage_config::StorageConfig, DataSource, ScanTask, ScanTaskRef};
+
+    fn make_scan_task(path: &str, num_rows: Option<usize>, pushdowns: Pushdowns) -> ScanTaskRef {
+        let source = DataSource::File {
+            path: path.to_string(),
+            chunk_spec: None,
+            size_bytes: None,
+            iceberg_delete_files: None,
+            iceberg_equality_delete_files: None,
+            delta_deletion_vector: None,
+            metadata: num_rows.map(|length| TableMetadata { length }),
+            partition_spec: None,
+            statistics: None,
+            parquet_metadata: None,
+        };
+        let file_format_config = FileFormatConfig::Parquet(ParquetSourceConfig {
+            coerce_int96_timestamp_unit: TimeUnit::Seconds,
+            field_id_mapping: None,
+            row_groups: None,
+            chunk_size: None,
+        });
+        Arc::new(ScanTask::new(
+            vec![source],
+            Arc::new(file_format_config),
+            Arc::new(Schema::empty()),
+            Arc::new(StorageConfig::new_internal(false, None)),
+            pushdowns,
+            None,
+        ))
+    }
+
+    fn paths_and_limits(scan_tasks: &[ScanTaskRef]) -> Vec<(&str, Option<usize>)> {
+        scan_tasks
+            .iter()
+            .map(|st| (st.sources[0].get_path(), st.pushdowns.limit))
+            .collect()
+    }
+
+    #[test]
+    fn test_limit_row_budgets_drop_unneeded_scan_tasks() {
+        let pushdowns = Pushdowns::default().with_limit(Some(25));
+        let scan_tasks = (0..4)
+            .map(|i| make_scan_task(&format!("file{i}"), Some(10), pushdowns.clone()))
+            .collect();
+        let budgeted = apply_limit_row_budgets(scan_tasks, &pushdowns);
+        assert_eq!(
+            paths_and_limits(&budgeted),
+            vec![("file0", Some(25)), ("file1", Some(15)), ("file2", Some(5))]
+        );
+    }
+
+    #[test]
+    fn test_limit_row_budgets_stop_at_unknown_row_count() {
+        let pushdowns = Pushdowns::default().with_limit(Some(25));
+        let scan_tasks = vec![
+            make_scan_task("file0", Some(10), pushdowns.clone()),
+            make_scan_task("file1", None, pushdowns.clone()),
+            make_scan_task("file2", Some(100), pushdowns.clone()),
+        ];
+        let budgeted = apply_limit_row_budgets(scan_tasks, &pushdowns);
+        assert_eq!(
+            paths_and_limits(&budgeted),
+            vec![
+                ("file0", Some(25)),
+                ("file1", Some(15)),
+                ("file2", Some(25))
+            ]
+        );
+    }
+}
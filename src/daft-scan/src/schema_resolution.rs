@@ -0,0 +1,269 @@
+//! Resolution of the schemas of the files of a glob scan, whose schemas may have drifted apart, into the schema of the
+//! scan.
+
+use std::str::FromStr;
+
+use common_error::{DaftError, DaftResult};
+use daft_schema::{dtype::DataType, field::Field, schema::Schema};
+use indexmap::IndexMap;
+
+/// Which files the schema of a glob scan is inferred from.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SchemaMode {
+    /// The schema of the first file. Columns that are missing from other files are filled with nulls, and columns that
+    /// are only in other files are dropped.
+    #[default]
+    First,
+    /// The union of the schemas of all files, with columns in the order they're first seen. Columns that are missing
+    /// from a file are filled with nulls, and the types of columns are widened to fit all files. Not supported for CSV
+    /// files, whose columns are read by position.
+    Union,
+}
+
+impl FromStr for SchemaMode {
+    type Err = DaftError;
+
+    fn from_str(s: &str) -> DaftResult<Self> {
+        match s.to_lowercase().as_str() {
+            "first" => Ok(Self::First),
+            "union" => Ok(Self::Union),
+            _ => Err(DaftError::ValueError(format!(
+                "Unknown schema mode: {s}, expected one of \"first\" or \"union\""
+            ))),
+        }
+    }
+}
+
+/// How the columns of each file are matched to the columns of the schema of a glob scan.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColumnMapping {
+    /// By their names.
+    #[default]
+    Name,
+    /// By their Parquet field IDs, under the names that they have in the first file, which keeps renamed columns
+    /// readable as Iceberg does. Columns without field IDs aren't read.
+    FieldId,
+}
+
+impl FromStr for ColumnMapping {
+    type Err = DaftError;
+
+    fn from_str(s: &str) -> DaftResult<Self> {
+        match s.to_lowercase().as_str() {
+            "name" => Ok(Self::Name),
+            "field_id" => Ok(Self::FieldId),
+            _ => Err(DaftError::ValueError(format!(
+                "Unknown column mapping: {s}, expected one of \"name\" or \"field_id\""
+            ))),
+        }
+    }
+}
+
+/// How a glob scan resolves the schemas of its files into its schema.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SchemaResolution {
+    pub mode: SchemaMode,
+    pub column_mapping: ColumnMapping,
+}
+
+impl SchemaResolution {
+    #[must_use]
+    pub fn new(mode: SchemaMode, column_mapping: ColumnMapping) -> Self {
+        Self {
+            mode,
+            column_mapping,
+        }
+    }
+
+    #[must_use]
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        if self.mode != SchemaMode::First {
+            res.push(format!("Schema mode = {:?}", self.mode));
+        }
+        if self.column_mapping != ColumnMapping::Name {
+            res.push(format!("Column mapping = {:?}", self.column_mapping));
+        }
+        res
+    }
+}
+
+fn int_bits(dtype: &DataType) -> Option<(bool, u32)> {
+    match dtype {
+        DataType::Int8 => Some((true, 8)),
+        DataType::Int16 => Some((true, 16)),
+        DataType::Int32 => Some((true, 32)),
+        DataType::Int64 => Some((true, 64)),
+        DataType::UInt8 => Some((false, 8)),
+        DataType::UInt16 => Some((false, 16)),
+        DataType::UInt32 => Some((false, 32)),
+        DataType::UInt64 => Some((false, 64)),
+        _ => None,
+    }
+}
+
+fn int_type(signed: bool, bits: u32) -> Option<DataType> {
+    match (signed, bits) {
+        (true, 8) => Some(DataType::Int8),
+        (true, 16) => Some(DataType::Int16),
+        (true, 32) => Some(DataType::Int32),
+        (true, 64) => Some(DataType::Int64),
+        (false, 8) => Some(DataType::UInt8),
+        (false, 16) => Some(DataType::UInt16),
+        (false, 32) => Some(DataType::UInt32),
+        (false, 64) => Some(DataType::UInt64),
+        _ => None,
+    }
+}
+
+/// The narrowest type that both types can be cast to without losing values, if there is one.
+///
+/// Integers widen to larger integers, and to floats whose mantissas fit all of their values, and floats widen to larger floats. Nulls widen to
+/// any type, and lists and structs widen their children, with the fields of structs matched by name.
+#[must_use]
+pub fn widen_dtype(left: &DataType, right: &DataType) -> Option<DataType> {
+    if left == right {
+        return Some(left.clone());
+    }
+    match (left, right) {
+        (DataType::Null, other) | (other, DataType::Null) => Some(other.clone()),
+        (DataType::Float32, DataType::Float64) | (DataType::Float64, DataType::Float32) => {
+            Some(DataType::Float64)
+        }
+        (DataType::List(left), DataType::List(right)) => {
+            Some(DataType::List(Box::new(widen_dtype(left, right)?)))
+        }
+        (DataType::Struct(left), DataType::Struct(right)) => {
+            let mut fields = IndexMap::new();
+            for field in left.iter().chain(right) {
+                widen_field(&mut fields, field).ok()?;
+            }
+            Some(DataType::Struct(fields.into_values().collect()))
+        }
+        (float @ (DataType::Float32 | DataType::Float64), int)
+        | (int, float @ (DataType::Float32 | DataType::Float64)) => {
+            let (_, bits) = int_bits(int)?;
+            let mantissa_bits = if *float == DataType::Float32 { 24 } else { 53 };
+            (bits < mantissa_bits).then(|| float.clone())
+        }
+        _ => {
+            let (left_signed, left_bits) = int_bits(left)?;
+            let (right_signed, right_bits) = int_bits(right)?;
+            if left_signed == right_signed {
+                return int_type(left_signed, left_bits.max(right_bits));
+            }
+            // A signed integer fits an unsigned one if it's larger.
+            let (signed_bits, unsigned_bits) = if left_signed {
+                (left_bits, right_bits)
+            } else {
+                (right_bits, left_bits)
+            };
+            int_type(true, signed_bits.max(unsigned_bits * 2))
+        }
+    }
+}
+
+/// Adds a field to a set of fields by name, widening the type of the field of the same name if there is one.
+fn widen_field(fields: &mut IndexMap<String, Field>, field: &Field) -> Result<(), DataType> {
+    match fields.get_mut(&field.name) {
+        Some(existing) => {
+            if existing.dtype != field.dtype {
+                let dtype = widen_dtype(&existing.dtype, &field.dtype)
+                    .ok_or_else(|| existing.dtype.clone())?;
+                *existing = Field::new(field.name.clone(), dtype);
+            }
+        }
+        None => {
+            fields.insert(field.name.clone(), field.clone());
+        }
+    }
+    Ok(())
+}
+
+/// Merges the schemas of files into the union of their columns, widening the types of columns to fit all of the files.
+///
+/// Fails with the names of the files whose types of a column can't be widened to a common type.
+pub fn merge_schemas<'a>(
+    schemas: impl IntoIterator<Item = (&'a str, &'a Schema)>,
+) -> DaftResult<Schema> {
+    let mut fields = IndexMap::new();
+    let mut sources = IndexMap::<String, &str>::new();
+    for (path, schema) in schemas {
+        for field in schema.fields() {
+            if let Err(dtype) = widen_field(&mut fields, field) {
+                return Err(DaftError::SchemaMismatch(format!(
+                    "Cannot merge the schemas of the scanned files: column `{}` has type {} in {} but type {} in {}, which can't be widened to a common type",
+                    field.name, dtype, sources[&field.name], field.dtype, path
+                )));
+            }
+            sources.entry(field.name.clone()).or_insert(path);
+        }
+    }
+    Ok(Schema::new(fields.into_values()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_widen_dtype() {
+        assert_eq!(
+            widen_dtype(&DataType::Int32, &DataType::Int64),
+            Some(DataType::Int64)
+        );
+        assert_eq!(
+            widen_dtype(&DataType::Float32, &DataType::Float64),
+            Some(DataType::Float64)
+        );
+        assert_eq!(
+            widen_dtype(&DataType::UInt32, &DataType::Int32),
+            Some(DataType::Int64)
+        );
+        assert_eq!(
+            widen_dtype(&DataType::Int32, &DataType::Float64),
+            Some(DataType::Float64)
+        );
+        assert_eq!(
+            widen_dtype(&DataType::Null, &DataType::Utf8),
+            Some(DataType::Utf8)
+        );
+        assert_eq!(widen_dtype(&DataType::Int64, &DataType::Float64), None);
+        assert_eq!(widen_dtype(&DataType::UInt64, &DataType::Int64), None);
+        assert_eq!(widen_dtype(&DataType::Int64, &DataType::Utf8), None);
+        assert_eq!(
+            widen_dtype(
+                &DataType::List(Box::new(DataType::Int8)),
+                &DataType::List(Box::new(DataType::Int16))
+            ),
+            Some(DataType::List(Box::new(DataType::Int16)))
+        );
+    }
+
+    #[test]
+    fn test_merge_schemas() -> DaftResult<()> {
+        let first = Schema::new(vec![
+            Field::new("a", DataType::Int32),
+            Field::new("b", DataType::Utf8),
+        ]);
+        let second = Schema::new(vec![
+            Field::new("c", DataType::Float32),
+            Field::new("a", DataType::Int64),
+        ]);
+        let merged = merge_schemas([("first", &first), ("second", &second)])?;
+        assert_eq!(
+            merged,
+            Schema::new(vec![
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::Utf8),
+                Field::new("c", DataType::Float32),
+            ])
+        );
+
+        let third = Schema::new(vec![Field::new("b", DataType::Int64)]);
+        let err = merge_schemas([("first", &first), ("third", &third)]).unwrap_err();
+        assert!(err.to_string().contains("column `b`"));
+        assert!(err.to_string().contains("first") && err.to_string().contains("third"));
+        Ok(())
+    }
+}
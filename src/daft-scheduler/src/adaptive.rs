@@ -73,6 +73,7 @@ impl AdaptivePhysicalPlanScheduler {
                 num_rows,
                 None, // TODO(sammy) thread through clustering spec to Python
                 Some(stage_id),
+                false,
             );
 
             self.planner.update(MaterializedResults {
@@ -401,7 +401,10 @@ fn physical_plan_to_partition_tasks(
             Ok(global_limit_iter.into())
         }
         PhysicalPlan::Explode(Explode {
-            input, to_explode, ..
+            input,
+            to_explode,
+            unnest_structs,
+            ..
         }) => {
             let upstream_iter =
                 physical_plan_to_partition_tasks(input, py, psets, actor_pool_manager)?;
@@ -412,7 +415,7 @@ fn physical_plan_to_partition_tasks(
             let py_iter = py
                 .import(pyo3::intern!(py, "daft.execution.rust_physical_plan_shim"))?
                 .getattr(pyo3::intern!(py, "explode"))?
-                .call1((upstream_iter, explode_pyexprs))?;
+                .call1((upstream_iter, explode_pyexprs, *unnest_structs))?;
             Ok(py_iter.into())
         }
         PhysicalPlan::Unpivot(Unpivot {
@@ -578,6 +581,8 @@ fn physical_plan_to_partition_tasks(
                 ShuffleExchangeStrategy::FlightShuffle {
                     target_spec,
                     shuffle_dirs,
+                    compression,
+                    compression_level,
                 } => {
                     let shuffled = match target_spec.as_ref() {
                         daft_logical_plan::ClusteringSpec::Hash(hash_clustering_config) => {
@@ -596,6 +601,8 @@ fn physical_plan_to_partition_tasks(
                                 hash_clustering_config.num_partitions,
                                 shuffle_dirs,
                                 Some(partition_by_pyexprs),
+                                compression.as_deref(),
+                                *compression_level,
                             ))?
                         }
                         daft_logical_plan::ClusteringSpec::Random(random_clustering_config) => py
@@ -608,6 +615,9 @@ fn physical_plan_to_partition_tasks(
                                 upstream_iter,
                                 random_clustering_config.num_partitions(),
                                 shuffle_dirs,
+                                None::<Vec<PyExpr>>,
+                                compression.as_deref(),
+                                *compression_level,
                             ))?,
                         daft_logical_plan::ClusteringSpec::Range(_) => {
                             unimplemented!("FanoutByRange not implemented, since only use case (sorting) doesn't need it yet.");
@@ -618,6 +628,56 @@ fn physical_plan_to_partition_tasks(
                     };
                     Ok(shuffled.into())
                 }
+                ShuffleExchangeStrategy::ExternalShuffle {
+                    target_spec,
+                    shuffle_dir,
+                    compression,
+                    compression_level,
+                } => {
+                    let shuffled = match target_spec.as_ref() {
+                        daft_logical_plan::ClusteringSpec::Hash(hash_clustering_config) => {
+                            let partition_by_pyexprs: Vec<PyExpr> = hash_clustering_config
+                                .by
+                                .iter()
+                                .map(|expr| PyExpr::from(expr.clone()))
+                                .collect();
+                            py.import(pyo3::intern!(
+                                py,
+                                "daft.execution.shuffles.external_shuffle"
+                            ))?
+                            .getattr(pyo3::intern!(py, "external_shuffle"))?
+                            .call1((
+                                upstream_iter,
+                                hash_clustering_config.num_partitions,
+                                shuffle_dir,
+                                Some(partition_by_pyexprs),
+                                compression.as_deref(),
+                                *compression_level,
+                            ))?
+                        }
+                        daft_logical_plan::ClusteringSpec::Random(random_clustering_config) => py
+                            .import(pyo3::intern!(
+                                py,
+                                "daft.execution.shuffles.external_shuffle"
+                            ))?
+                            .getattr(pyo3::intern!(py, "external_shuffle"))?
+                            .call1((
+                                upstream_iter,
+                                random_clustering_config.num_partitions(),
+                                shuffle_dir,
+                                None::<Vec<PyExpr>>,
+                                compression.as_deref(),
+                                *compression_level,
+                            ))?,
+                        daft_logical_plan::ClusteringSpec::Range(_) => {
+                            unimplemented!("FanoutByRange not implemented, since only use case (sorting) doesn't need it yet.");
+                        }
+                        daft_logical_plan::ClusteringSpec::Unknown(_) => {
+                            unreachable!("Cannot use ExternalShuffle ShuffleExchange to map to an Unknown ClusteringSpec");
+                        }
+                    };
+                    Ok(shuffled.into())
+                }
                 ShuffleExchangeStrategy::SplitOrCoalesceToTargetNum {
                     target_num_partitions,
                 } => {
@@ -858,6 +918,7 @@ fn physical_plan_to_partition_tasks(
                     partition_cols,
                     compression,
                     io_config,
+                    ..
                 },
             input,
         }) => tabular_write(
@@ -881,6 +942,7 @@ fn physical_plan_to_partition_tasks(
                     partition_cols,
                     compression,
                     io_config,
+                    ..
                 },
             input,
         }) => tabular_write(
@@ -904,6 +966,7 @@ fn physical_plan_to_partition_tasks(
                     partition_cols,
                     compression,
                     io_config,
+                    ..
                 },
             input,
         }) => tabular_write(
@@ -1,11 +1,16 @@
 use std::fmt::Write;
 
-use arrow2::datatypes::DataType as ArrowType;
+use arrow2::datatypes::{DataType as ArrowType, JSON_EXTENSION_NAME, UUID_EXTENSION_NAME};
 use common_error::{DaftError, DaftResult};
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
-use crate::{field::Field, image_mode::ImageMode, time_unit::TimeUnit};
+use crate::{
+    extension::{extension_metadata_from_arrow, extension_metadata_to_arrow},
+    field::Field,
+    image_mode::ImageMode,
+    time_unit::TimeUnit,
+};
 
 pub type DaftDataType = DataType;
 
@@ -151,6 +156,11 @@ pub enum DataType {
     #[display("FixedShapeSparseTensor[{_0}; {_1:?}; indices_offset: {_2}]")]
     FixedShapeSparseTensor(Box<DataType>, Vec<u64>, bool),
 
+    /// A logical type for strings drawn from a fixed set of categories, stored as [`u32`] codes
+    /// indexing into the categories.
+    #[display("Categorical[{}]", _0.join(", "))]
+    Categorical(Vec<String>),
+
     #[cfg(feature = "python")]
     Python,
 
@@ -186,7 +196,7 @@ impl DataTypePayload {
         }
     }
 }
-const DAFT_SUPER_EXTENSION_NAME: &str = "daft.super_extension";
+pub(crate) const DAFT_SUPER_EXTENSION_NAME: &str = "daft.super_extension";
 
 impl DataType {
     pub fn new_null() -> Self {
@@ -201,6 +211,20 @@ impl DataType {
         Self::FixedSizeList(Box::new(datatype), size)
     }
 
+    /// The Arrow canonical `arrow.uuid` extension type over `FixedSizeBinary(16)`.
+    pub fn new_uuid() -> Self {
+        Self::Extension(
+            UUID_EXTENSION_NAME.to_string(),
+            Box::new(Self::FixedSizeBinary(16)),
+            None,
+        )
+    }
+
+    /// The Arrow canonical `arrow.json` extension type over `Utf8`.
+    pub fn new_json() -> Self {
+        Self::Extension(JSON_EXTENSION_NAME.to_string(), Box::new(Self::Utf8), None)
+    }
+
     pub fn to_arrow(&self) -> DaftResult<ArrowType> {
         match self {
             Self::Null => Ok(ArrowType::Null),
@@ -274,7 +298,7 @@ impl DataType {
             Self::Extension(name, dtype, metadata) => Ok(ArrowType::Extension(
                 name.clone(),
                 Box::new(dtype.to_arrow()?),
-                metadata.clone(),
+                extension_metadata_to_arrow(name, dtype, metadata.as_deref())?,
             )),
             Self::Embedding(..)
             | Self::Image(..)
@@ -282,7 +306,8 @@ impl DataType {
             | Self::Tensor(..)
             | Self::FixedShapeTensor(..)
             | Self::SparseTensor(..)
-            | Self::FixedShapeSparseTensor(..) => {
+            | Self::FixedShapeSparseTensor(..)
+            | Self::Categorical(..) => {
                 let physical = Box::new(self.to_physical());
                 let logical_extension = Self::Extension(
                     DAFT_SUPER_EXTENSION_NAME.into(),
@@ -362,6 +387,7 @@ impl DataType {
                     Field::new("indices", List(Box::new(minimal_indices_dtype)))
                 },
             ]),
+            Categorical(_) => UInt32,
             _ => {
                 assert!(self.is_physical());
                 self.clone()
@@ -504,6 +530,11 @@ impl DataType {
         matches!(self, Self::FixedShapeImage(..))
     }
 
+    #[inline]
+    pub fn is_categorical(&self) -> bool {
+        matches!(self, Self::Categorical(..))
+    }
+
     #[inline]
     pub fn is_map(&self) -> bool {
         matches!(self, Self::Map { .. })
@@ -638,6 +669,18 @@ impl DataType {
         matches!(self, Self::Extension(..))
     }
 
+    #[inline]
+    pub fn is_uuid(&self) -> bool {
+        matches!(self, Self::Extension(name, storage, _)
+            if name == UUID_EXTENSION_NAME && **storage == Self::FixedSizeBinary(16))
+    }
+
+    #[inline]
+    pub fn is_json(&self) -> bool {
+        matches!(self, Self::Extension(name, storage, _)
+            if name == JSON_EXTENSION_NAME && **storage == Self::Utf8)
+    }
+
     #[inline]
     pub fn is_python(&self) -> bool {
         match self {
@@ -728,6 +771,7 @@ impl DataType {
                 | Self::FixedShapeTensor(..)
                 | Self::SparseTensor(..)
                 | Self::FixedShapeSparseTensor(..)
+                | Self::Categorical(..)
                 | Self::Map { .. }
         )
     }
@@ -892,6 +936,16 @@ impl DataType {
             ))),
         }
     }
+
+    /// if the datatype is a categorical, return its categories in the order of their codes.
+    pub fn categories(&self) -> DaftResult<&[String]> {
+        match self {
+            Self::Categorical(categories) => Ok(categories),
+            _ => Err(DaftError::TypeError(format!(
+                "DataType {self:?} does not have a `categories` property",
+            ))),
+        }
+    }
 }
 
 #[expect(
@@ -967,11 +1021,9 @@ impl From<&ArrowType> for DataType {
                         }
                     }
                 }
-                Self::Extension(
-                    name.clone(),
-                    Box::new(dtype.as_ref().into()),
-                    metadata.clone(),
-                )
+                let storage: Self = dtype.as_ref().into();
+                let metadata = extension_metadata_from_arrow(name, &storage, metadata.as_deref());
+                Self::Extension(name.clone(), Box::new(storage), metadata)
             }
 
             _ => panic!("DataType :{item:?} is not supported"),
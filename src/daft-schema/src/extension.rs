@@ -0,0 +1,248 @@
+//! Process-wide registry of user-defined extension types.
+//!
+//! An extension type is a logical type with a name, optional metadata and an underlying storage
+//! type. Any [`DataType::Extension`] can be used without registration, but registering its name
+//! lets Daft validate the storage type and (de)serialize the metadata whenever the type crosses an
+//! Arrow boundary, which keeps custom logical types intact through IPC and Parquet round-trips.
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock, RwLock},
+};
+
+use arrow2::datatypes::CanonicalExtension;
+use common_error::{DaftError, DaftResult};
+
+use crate::dtype::{DataType, DAFT_SUPER_EXTENSION_NAME};
+
+/// A user-defined extension type.
+pub trait ExtensionTypeDefinition: Send + Sync + std::fmt::Debug {
+    /// The name of the extension type, as stored in `ARROW:extension:name`.
+    fn name(&self) -> &str;
+
+    /// The storage type of the extension type.
+    fn storage_type(&self) -> &DataType;
+
+    /// Serializes the metadata of an extension type before it is written out as Arrow.
+    fn serialize_metadata(&self, metadata: Option<&str>) -> DaftResult<Option<String>> {
+        Ok(metadata.map(ToString::to_string))
+    }
+
+    /// Deserializes the metadata of an extension type that is read in from Arrow.
+    fn deserialize_metadata(&self, serialized: Option<&str>) -> DaftResult<Option<String>> {
+        Ok(serialized.map(ToString::to_string))
+    }
+}
+
+/// An [`ExtensionTypeDefinition`] whose metadata is stored as is.
+#[derive(Debug, Clone)]
+pub struct SimpleExtensionType {
+    name: String,
+    storage_type: DataType,
+}
+
+impl SimpleExtensionType {
+    pub fn new(name: impl Into<String>, storage_type: DataType) -> Self {
+        Self {
+            name: name.into(),
+            storage_type,
+        }
+    }
+}
+
+impl ExtensionTypeDefinition for SimpleExtensionType {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn storage_type(&self) -> &DataType {
+        &self.storage_type
+    }
+}
+
+static REGISTRY: LazyLock<RwLock<HashMap<String, Arc<dyn ExtensionTypeDefinition>>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Registers a user-defined extension type, replacing any previous registration under its name.
+///
+/// Names of Daft's own and Arrow's canonical extension types can not be registered.
+pub fn register_extension_type(definition: Arc<dyn ExtensionTypeDefinition>) -> DaftResult<()> {
+    let name = definition.name();
+    if name == DAFT_SUPER_EXTENSION_NAME || CanonicalExtension::from_name(name).is_some() {
+        return Err(DaftError::ValueError(format!(
+            "Extension type name {name} is reserved and can not be registered"
+        )));
+    }
+    if definition.storage_type().is_extension() {
+        return Err(DaftError::TypeError(format!(
+            "Storage type of extension type {name} can not itself be an extension type, got {}",
+            definition.storage_type()
+        )));
+    }
+    REGISTRY
+        .write()
+        .unwrap()
+        .insert(name.to_string(), definition);
+    Ok(())
+}
+
+/// Removes the extension type registered under `name`, returning whether there was one.
+pub fn unregister_extension_type(name: &str) -> bool {
+    REGISTRY.write().unwrap().remove(name).is_some()
+}
+
+/// Returns the extension type registered under `name`, if any.
+pub fn get_extension_type(name: &str) -> Option<Arc<dyn ExtensionTypeDefinition>> {
+    REGISTRY.read().unwrap().get(name).cloned()
+}
+
+/// Returns the names of all registered extension types, in sorted order.
+pub fn registered_extension_types() -> Vec<String> {
+    let mut names = REGISTRY
+        .read()
+        .unwrap()
+        .keys()
+        .cloned()
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+impl DataType {
+    /// Creates the registered extension type `name` with the given metadata.
+    pub fn new_registered_extension(name: &str, metadata: Option<&str>) -> DaftResult<Self> {
+        let definition = get_extension_type(name).ok_or_else(|| {
+            DaftError::ValueError(format!("Extension type {name} is not registered"))
+        })?;
+        Ok(Self::Extension(
+            name.to_string(),
+            Box::new(definition.storage_type().clone()),
+            metadata.map(ToString::to_string),
+        ))
+    }
+}
+
+/// Converts the metadata of an extension type to its Arrow representation, using the
+/// registered definition of the type if there is one.
+pub(crate) fn extension_metadata_to_arrow(
+    name: &str,
+    storage: &DataType,
+    metadata: Option<&str>,
+) -> DaftResult<Option<String>> {
+    match get_extension_type(name) {
+        Some(definition) => {
+            if definition.storage_type() != storage {
+                return Err(DaftError::TypeError(format!(
+                    "Extension type {name} is registered with storage type {}, got {storage}",
+                    definition.storage_type()
+                )));
+            }
+            definition.serialize_metadata(metadata)
+        }
+        None => Ok(metadata.map(ToString::to_string)),
+    }
+}
+
+/// Converts the metadata of an Arrow extension type back with the registered definition of the
+/// type, if there is one whose storage type matches.
+///
+/// Metadata that fails to deserialize is kept as is rather than dropping the extension type.
+pub(crate) fn extension_metadata_from_arrow(
+    name: &str,
+    storage: &DataType,
+    metadata: Option<&str>,
+) -> Option<String> {
+    let definition =
+        get_extension_type(name).filter(|definition| definition.storage_type() == storage);
+    match definition {
+        Some(definition) => definition
+            .deserialize_metadata(metadata)
+            .unwrap_or_else(|_| metadata.map(ToString::to_string)),
+        None => metadata.map(ToString::to_string),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow2::datatypes::DataType as ArrowType;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct VersionedExtension {
+        storage_type: DataType,
+    }
+
+    impl ExtensionTypeDefinition for VersionedExtension {
+        fn name(&self) -> &str {
+            "test.versioned"
+        }
+
+        fn storage_type(&self) -> &DataType {
+            &self.storage_type
+        }
+
+        fn serialize_metadata(&self, metadata: Option<&str>) -> DaftResult<Option<String>> {
+            Ok(Some(format!("v1:{}", metadata.unwrap_or_default())))
+        }
+
+        fn deserialize_metadata(&self, serialized: Option<&str>) -> DaftResult<Option<String>> {
+            serialized
+                .and_then(|serialized| serialized.strip_prefix("v1:"))
+                .map(|metadata| Some(metadata.to_string()))
+                .ok_or_else(|| DaftError::ValueError("unknown version".to_string()))
+        }
+    }
+
+    #[test]
+    fn registered_extension_round_trips_through_arrow() -> DaftResult<()> {
+        register_extension_type(Arc::new(VersionedExtension {
+            storage_type: DataType::Int64,
+        }))?;
+
+        let dtype = DataType::new_registered_extension("test.versioned", Some("meters"))?;
+        let arrow = dtype.to_arrow()?;
+        assert_eq!(
+            arrow,
+            ArrowType::Extension(
+                "test.versioned".to_string(),
+                Box::new(ArrowType::Int64),
+                Some("v1:meters".to_string())
+            )
+        );
+        assert_eq!(DataType::from(&arrow), dtype);
+
+        // metadata that the definition can't read is kept rather than dropped
+        let unknown = ArrowType::Extension(
+            "test.versioned".to_string(),
+            Box::new(ArrowType::Int64),
+            Some("v2:meters".to_string()),
+        );
+        assert_eq!(
+            DataType::from(&unknown),
+            DataType::Extension(
+                "test.versioned".to_string(),
+                Box::new(DataType::Int64),
+                Some("v2:meters".to_string())
+            )
+        );
+
+        let mismatched = DataType::Extension(
+            "test.versioned".to_string(),
+            Box::new(DataType::Utf8),
+            None,
+        );
+        assert!(mismatched.to_arrow().is_err());
+
+        assert!(unregister_extension_type("test.versioned"));
+        assert!(DataType::new_registered_extension("test.versioned", None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn reserved_extension_names_can_not_be_registered() {
+        for name in [DAFT_SUPER_EXTENSION_NAME, "arrow.uuid", "arrow.json"] {
+            let definition = SimpleExtensionType::new(name, DataType::Utf8);
+            assert!(register_extension_type(Arc::new(definition)).is_err());
+        }
+    }
+}
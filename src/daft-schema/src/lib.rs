@@ -1,4 +1,5 @@
 pub mod dtype;
+pub mod extension;
 pub mod field;
 pub mod image_format;
 pub mod image_mode;
@@ -261,6 +261,16 @@ impl PyDataType {
         .into())
     }
 
+    #[staticmethod]
+    pub fn uuid() -> PyResult<Self> {
+        Ok(DataType::new_uuid().into())
+    }
+
+    #[staticmethod]
+    pub fn json() -> PyResult<Self> {
+        Ok(DataType::new_json().into())
+    }
+
     #[staticmethod]
     pub fn embedding(data_type: Self, size: i64) -> PyResult<Self> {
         if size <= 0 {
@@ -336,6 +346,17 @@ impl PyDataType {
         }
     }
 
+    #[staticmethod]
+    pub fn categorical(categories: Vec<String>) -> PyResult<Self> {
+        let mut seen = std::collections::HashSet::new();
+        if let Some(duplicate) = categories.iter().find(|c| !seen.insert(*c)) {
+            return Err(PyValueError::new_err(format!(
+                "The categories of a categorical type must be unique, but got {duplicate:?} more than once"
+            )));
+        }
+        Ok(DataType::Categorical(categories).into())
+    }
+
     #[staticmethod]
     pub fn python() -> PyResult<Self> {
         Ok(DataType::Python.into())
@@ -469,10 +490,22 @@ impl PyDataType {
         self.dtype.is_map()
     }
 
+    pub fn is_categorical(&self) -> bool {
+        self.dtype.is_categorical()
+    }
+
     pub fn is_extension(&self) -> bool {
         self.dtype.is_extension()
     }
 
+    pub fn is_uuid(&self) -> bool {
+        self.dtype.is_uuid()
+    }
+
+    pub fn is_json(&self) -> bool {
+        self.dtype.is_json()
+    }
+
     pub fn is_image(&self) -> bool {
         self.dtype.is_image()
     }
@@ -599,6 +632,13 @@ impl PyDataType {
             .map_err(|e| PyAttributeError::new_err(e.to_string()))
     }
 
+    pub fn categories(&self) -> PyResult<Vec<String>> {
+        self.dtype
+            .categories()
+            .map(<[String]>::to_vec)
+            .map_err(|e| PyAttributeError::new_err(e.to_string()))
+    }
+
     pub fn is_equal(&self, other: Bound<PyAny>) -> PyResult<bool> {
         if other.is_instance_of::<Self>() {
             let other = other.extract::<Self>()?;
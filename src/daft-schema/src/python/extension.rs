@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use pyo3::prelude::*;
+
+use super::PyDataType;
+use crate::{dtype::DataType, extension::ExtensionTypeDefinition};
+
+/// An extension type registered from Python, whose metadata is (de)serialized by optional Python
+/// callables.
+#[derive(Debug)]
+struct PyExtensionTypeDefinition {
+    name: String,
+    storage_type: DataType,
+    serialize: Option<PyObject>,
+    deserialize: Option<PyObject>,
+}
+
+fn call_metadata_fn(func: Option<&PyObject>, metadata: Option<&str>) -> DaftResult<Option<String>> {
+    match func {
+        Some(func) => Ok(Python::with_gil(|py| {
+            func.call1(py, (metadata,))?.extract::<Option<String>>(py)
+        })?),
+        None => Ok(metadata.map(ToString::to_string)),
+    }
+}
+
+impl ExtensionTypeDefinition for PyExtensionTypeDefinition {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn storage_type(&self) -> &DataType {
+        &self.storage_type
+    }
+
+    fn serialize_metadata(&self, metadata: Option<&str>) -> DaftResult<Option<String>> {
+        call_metadata_fn(self.serialize.as_ref(), metadata)
+    }
+
+    fn deserialize_metadata(&self, serialized: Option<&str>) -> DaftResult<Option<String>> {
+        call_metadata_fn(self.deserialize.as_ref(), serialized)
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (name, storage_data_type, serialize=None, deserialize=None))]
+pub fn register_extension_type(
+    name: &str,
+    storage_data_type: PyDataType,
+    serialize: Option<PyObject>,
+    deserialize: Option<PyObject>,
+) -> PyResult<()> {
+    Ok(crate::extension::register_extension_type(Arc::new(
+        PyExtensionTypeDefinition {
+            name: name.to_string(),
+            storage_type: storage_data_type.dtype,
+            serialize,
+            deserialize,
+        },
+    ))?)
+}
+
+#[pyfunction]
+pub fn unregister_extension_type(name: &str) -> bool {
+    crate::extension::unregister_extension_type(name)
+}
+
+#[pyfunction]
+pub fn registered_extension_types() -> Vec<String> {
+    crate::extension::registered_extension_types()
+}
+
+/// Deserializes the metadata of an Arrow extension type with its registered definition, if any.
+#[pyfunction]
+#[pyo3(signature = (name, storage_data_type, metadata=None))]
+pub fn deserialize_extension_metadata(
+    name: &str,
+    storage_data_type: PyDataType,
+    metadata: Option<&str>,
+) -> Option<String> {
+    crate::extension::extension_metadata_from_arrow(name, &storage_data_type.dtype, metadata)
+}
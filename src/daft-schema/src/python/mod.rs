@@ -1,5 +1,6 @@
 use pyo3::prelude::*;
 pub mod datatype;
+mod extension;
 pub mod field;
 pub mod schema;
 
@@ -14,6 +15,13 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_class::<field::PyField>()?;
     parent.add_class::<ImageMode>()?;
     parent.add_class::<ImageFormat>()?;
+    parent.add_function(wrap_pyfunction!(extension::register_extension_type, parent)?)?;
+    parent.add_function(wrap_pyfunction!(extension::unregister_extension_type, parent)?)?;
+    parent.add_function(wrap_pyfunction!(extension::registered_extension_types, parent)?)?;
+    parent.add_function(wrap_pyfunction!(
+        extension::deserialize_extension_metadata,
+        parent
+    )?)?;
 
     Ok(())
 }
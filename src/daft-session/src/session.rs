@@ -170,6 +170,34 @@ impl Session {
         Ok(table)
     }
 
+    /// Creates a table in the current catalog, qualifying unqualified names with the current namespace.
+    ///
+    /// ```text
+    /// CREATE TABLE [IF NOT EXISTS] <name> <source>;
+    /// ```
+    pub fn create_table(
+        &self,
+        name: &Identifier,
+        source: &TableSource,
+        if_not_exists: bool,
+    ) -> Result<TableRef> {
+        let catalog = match self.current_catalog()? {
+            Some(catalog) => catalog,
+            None => unsupported_err!("Cannot create a table without a current catalog"),
+        };
+        let name = match self.current_namespace()? {
+            Some(namespace) if !name.has_qualifier() => name.qualify(namespace),
+            _ => name.clone(),
+        };
+        if let Some(table) = catalog.get_table(&name)? {
+            if if_not_exists {
+                return Ok(table.into());
+            }
+            obj_already_exists_err!("Table", &name)
+        }
+        Ok(catalog.create_table(&name, source)?.into())
+    }
+
     /// Returns the session's current catalog.
     pub fn current_catalog(&self) -> Result<Option<CatalogRef>> {
         if let Some(catalog) = &self.state().options.curr_catalog {
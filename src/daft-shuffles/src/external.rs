@@ -0,0 +1,269 @@
+use std::sync::Arc;
+
+use arrow2::io::ipc::{
+    read::{read_stream_metadata, StreamReader, StreamState},
+    write::{Compression, StreamWriter, WriteOptions},
+};
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+
+use crate::storage::ShuffleStorage;
+
+const PARTITION_COLUMN: &str = "partition";
+const PATH_COLUMN: &str = "path";
+const NUM_ROWS_COLUMN: &str = "num_rows";
+const SIZE_BYTES_COLUMN: &str = "size_bytes";
+
+/// A file written by a map task of an external shuffle, holding its rows of one output partition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShuffleManifestEntry {
+    pub partition: u64,
+    pub path: String,
+    pub num_rows: u64,
+    pub size_bytes: u64,
+}
+
+/// The files that the map tasks of an external shuffle wrote to the shuffle directory.
+///
+/// Once every map task is done, the manifest is written next to the files, so that reduce tasks only need the
+/// directory to find their inputs, and don't depend on any executor that ran a map task.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShuffleManifest {
+    pub entries: Vec<ShuffleManifestEntry>,
+}
+
+impl ShuffleManifest {
+    pub fn to_record_batch(&self) -> DaftResult<RecordBatch> {
+        let partitions = UInt64Array::from((
+            PARTITION_COLUMN,
+            self.entries.iter().map(|e| e.partition).collect::<Vec<_>>(),
+        ));
+        let paths = Utf8Array::from((
+            PATH_COLUMN,
+            self.entries
+                .iter()
+                .map(|e| e.path.as_str())
+                .collect::<Vec<_>>()
+                .as_slice(),
+        ));
+        let num_rows = UInt64Array::from((
+            NUM_ROWS_COLUMN,
+            self.entries.iter().map(|e| e.num_rows).collect::<Vec<_>>(),
+        ));
+        let size_bytes = UInt64Array::from((
+            SIZE_BYTES_COLUMN,
+            self.entries
+                .iter()
+                .map(|e| e.size_bytes)
+                .collect::<Vec<_>>(),
+        ));
+        RecordBatch::from_nonempty_columns(vec![
+            partitions.into_series(),
+            paths.into_series(),
+            num_rows.into_series(),
+            size_bytes.into_series(),
+        ])
+    }
+
+    pub fn from_record_batches(batches: &[RecordBatch]) -> DaftResult<Self> {
+        let mut entries = vec![];
+        for batch in batches {
+            let partitions = batch.get_column(PARTITION_COLUMN)?.u64()?;
+            let paths = batch.get_column(PATH_COLUMN)?.utf8()?;
+            let num_rows = batch.get_column(NUM_ROWS_COLUMN)?.u64()?;
+            let size_bytes = batch.get_column(SIZE_BYTES_COLUMN)?.u64()?;
+            for idx in 0..batch.len() {
+                let missing =
+                    || DaftError::ValueError(format!("Shuffle manifest has a null in row {idx}"));
+                entries.push(ShuffleManifestEntry {
+                    partition: partitions.get(idx).ok_or_else(missing)?,
+                    path: paths.get(idx).ok_or_else(missing)?.to_string(),
+                    num_rows: num_rows.get(idx).ok_or_else(missing)?,
+                    size_bytes: size_bytes.get(idx).ok_or_else(missing)?,
+                });
+            }
+        }
+        Ok(Self { entries })
+    }
+
+    /// The paths of the files holding the rows of the given output partition.
+    pub fn paths(&self, partition: u64) -> impl Iterator<Item = &str> {
+        self.entries
+            .iter()
+            .filter(move |e| e.partition == partition)
+            .map(|e| e.path.as_str())
+    }
+}
+
+fn manifest_path(dir: &str) -> String {
+    format!("{}/manifest.arrow", dir.trim_end_matches('/'))
+}
+
+fn serialize(
+    schema: &Schema,
+    batches: &[RecordBatch],
+    compression: Option<Compression>,
+) -> DaftResult<Vec<u8>> {
+    let mut writer = StreamWriter::new(vec![], WriteOptions { compression });
+    writer.start(&schema.to_arrow()?, None)?;
+    for batch in batches {
+        writer.write(&batch.to_chunk(), None)?;
+    }
+    writer.finish()?;
+    Ok(writer.into_inner())
+}
+
+async fn deserialize(
+    storage: &dyn ShuffleStorage,
+    path: &str,
+    schema: SchemaRef,
+) -> DaftResult<Vec<RecordBatch>> {
+    let mut reader = storage.read(path).await?;
+    let metadata = read_stream_metadata(&mut reader)?;
+    StreamReader::new(reader, metadata, None)
+        .map(|state| match state? {
+            StreamState::Some(chunk) => {
+                RecordBatch::from_arrow(schema.clone(), chunk.into_arrays())
+            }
+            StreamState::Waiting => Err(DaftError::InternalError(format!(
+                "Shuffle file {path} was read before it was finished"
+            ))),
+        })
+        .collect()
+}
+
+/// Writes the output partitions of one map task to `{dir}/partition_{idx}/map_{map_idx}.arrow`, skipping empty ones,
+/// and returns the manifest of the written files.
+///
+/// A retried map task overwrites the files of the previous attempt.
+pub async fn write_map_output(
+    storage: &dyn ShuffleStorage,
+    dir: &str,
+    map_idx: usize,
+    partitions: &[Arc<MicroPartition>],
+    compression: Option<Compression>,
+) -> DaftResult<ShuffleManifest> {
+    let mut entries = vec![];
+    for (partition_idx, partition) in partitions.iter().enumerate() {
+        if partition.is_empty() {
+            continue;
+        }
+        let path = format!(
+            "{}/partition_{}/map_{}.arrow",
+            dir.trim_end_matches('/'),
+            partition_idx,
+            map_idx
+        );
+        let data = serialize(
+            partition.schema().as_ref(),
+            partition.get_tables()?.as_slice(),
+            compression,
+        )?;
+        let size_bytes = data.len() as u64;
+        storage.write(&path, data).await?;
+        entries.push(ShuffleManifestEntry {
+            partition: partition_idx as u64,
+            path,
+            num_rows: partition.len() as u64,
+            size_bytes,
+        });
+    }
+    Ok(ShuffleManifest { entries })
+}
+
+/// Writes the manifest of all map tasks to the shuffle directory, and returns its path.
+pub async fn write_manifest(
+    storage: &dyn ShuffleStorage,
+    dir: &str,
+    manifest: &ShuffleManifest,
+) -> DaftResult<String> {
+    let batch = manifest.to_record_batch()?;
+    let path = manifest_path(dir);
+    storage
+        .write(
+            &path,
+            serialize(&batch.schema, std::slice::from_ref(&batch), None)?,
+        )
+        .await?;
+    Ok(path)
+}
+
+/// Reads the rows of one output partition, using the manifest at `manifest_path` to find its files.
+pub async fn read_partition(
+    storage: &dyn ShuffleStorage,
+    manifest_path: &str,
+    partition: u64,
+    schema: SchemaRef,
+) -> DaftResult<MicroPartition> {
+    let manifest_schema = ShuffleManifest::default().to_record_batch()?.schema;
+    let manifest = ShuffleManifest::from_record_batches(
+        &deserialize(storage, manifest_path, manifest_schema).await?,
+    )?;
+    let mut batches = vec![];
+    for path in manifest.paths(partition) {
+        batches.extend(deserialize(storage, path, schema.clone()).await?);
+    }
+    Ok(MicroPartition::new_loaded(schema, Arc::new(batches), None))
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_micropartition::MicroPartition;
+    use daft_recordbatch::RecordBatch;
+
+    use super::*;
+    use crate::storage::LocalShuffleStorage;
+
+    fn make_partition(values: Vec<i64>) -> DaftResult<Arc<MicroPartition>> {
+        let batch =
+            RecordBatch::from_nonempty_columns(
+                vec![Int64Array::from(("a", values)).into_series()],
+            )?;
+        Ok(Arc::new(MicroPartition::new_loaded(
+            batch.schema.clone(),
+            Arc::new(vec![batch]),
+            None,
+        )))
+    }
+
+    #[tokio::test]
+    async fn test_external_shuffle_round_trip() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let dir = dir.path().to_string_lossy().into_owned();
+        let storage = LocalShuffleStorage;
+
+        // Two map tasks with three output partitions each, of which the middle one is empty.
+        let mut manifest = ShuffleManifest::default();
+        for (map_idx, offset) in [0, 10].into_iter().enumerate() {
+            let partitions = vec![
+                make_partition(vec![offset, offset + 1])?,
+                make_partition(vec![])?,
+                make_partition(vec![offset + 2])?,
+            ];
+            let output =
+                write_map_output(&storage, &dir, map_idx, &partitions, Some(Compression::LZ4))
+                    .await?;
+            assert_eq!(output.entries.len(), 2);
+            manifest.entries.extend(output.entries);
+        }
+        let manifest_path = write_manifest(&storage, &dir, &manifest).await?;
+
+        let schema = make_partition(vec![])?.schema();
+        let read = |partition| read_partition(&storage, &manifest_path, partition, schema.clone());
+        let values = |mp: MicroPartition| -> DaftResult<Vec<i64>> {
+            let mut values = vec![];
+            for batch in mp.get_tables()?.iter() {
+                values.extend(batch.get_column("a")?.i64()?.as_arrow().values().iter());
+            }
+            Ok(values)
+        };
+        assert_eq!(values(read(0).await?)?, vec![0, 1, 10, 11]);
+        assert!(read(1).await?.is_empty());
+        assert_eq!(values(read(2).await?)?, vec![2, 12]);
+        Ok(())
+    }
+}
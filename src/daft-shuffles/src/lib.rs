@@ -1,4 +1,6 @@
+pub mod external;
 #[cfg(feature = "python")]
 pub mod python;
 pub mod server;
 pub mod shuffle_cache;
+pub mod storage;
@@ -1,8 +1,12 @@
 use std::sync::Arc;
 
+use common_error::DaftResult;
+use common_runtime::get_io_runtime;
 use daft_dsl::python::PyExpr;
-use daft_micropartition::python::PyMicroPartition;
+use daft_io::python::IOConfig;
+use daft_micropartition::{python::PyMicroPartition, MicroPartition};
 use daft_schema::python::schema::PySchema;
+use daft_writers::ipc_compression;
 use pyo3::{
     pyclass, pyfunction, pymethods,
     types::{PyModule, PyModuleMethods},
@@ -10,8 +14,10 @@ use pyo3::{
 };
 
 use crate::{
+    external::{read_partition, write_manifest, write_map_output, ShuffleManifest},
     server::flight_server::{start_flight_server, FlightServerConnectionHandle},
     shuffle_cache::{get_or_init_shuffle_cache_runtime, InProgressShuffleCache, ShuffleCache},
+    storage::shuffle_storage_for_dirs,
 };
 
 #[pyclass(module = "daft.daft", name = "InProgressShuffleCache", frozen)]
@@ -22,20 +28,24 @@ pub struct PyInProgressShuffleCache {
 #[pymethods]
 impl PyInProgressShuffleCache {
     #[staticmethod]
-    #[pyo3(signature = (num_partitions, dirs, target_filesize, compression=None, partition_by=None))]
+    #[pyo3(signature = (num_partitions, dirs, target_filesize, compression=None, compression_level=None, partition_by=None, io_config=None))]
     pub fn try_new(
         num_partitions: usize,
         dirs: Vec<String>,
         target_filesize: usize,
         compression: Option<&str>,
+        compression_level: Option<i32>,
         partition_by: Option<Vec<PyExpr>>,
+        io_config: Option<IOConfig>,
     ) -> PyResult<Self> {
         let shuffle_cache = InProgressShuffleCache::try_new(
             num_partitions,
             dirs.as_slice(),
             target_filesize,
             compression,
+            compression_level,
             partition_by.map(|partition_by| partition_by.into_iter().map(|p| p.into()).collect()),
+            io_config.map(|io_config| io_config.config),
         )?;
         pyo3_async_runtimes::tokio::init_with_runtime(&get_or_init_shuffle_cache_runtime().runtime)
             .unwrap();
@@ -115,10 +125,88 @@ pub fn py_start_flight_server(
     Ok(PyFlightServerConnectionHandle { handle })
 }
 
+fn manifest_to_py(manifest: &ShuffleManifest) -> DaftResult<PyMicroPartition> {
+    let batch = manifest.to_record_batch()?;
+    Ok(MicroPartition::new_loaded(batch.schema.clone(), Arc::new(vec![batch]), None).into())
+}
+
+#[pyfunction(
+    name = "write_shuffle_map_output",
+    signature = (partitions, dir, map_idx, compression=None, compression_level=None, io_config=None)
+)]
+pub fn py_write_shuffle_map_output(
+    py: Python,
+    partitions: Vec<PyMicroPartition>,
+    dir: String,
+    map_idx: usize,
+    compression: Option<&str>,
+    compression_level: Option<i32>,
+    io_config: Option<IOConfig>,
+) -> PyResult<PyMicroPartition> {
+    let compression = ipc_compression(compression, compression_level)?;
+    let storage = shuffle_storage_for_dirs(&[dir.clone()], io_config.map(|c| c.config))?;
+    let partitions = partitions.into_iter().map(|p| p.inner).collect::<Vec<_>>();
+    let manifest = py.allow_threads(|| {
+        get_io_runtime(true).block_on(async move {
+            write_map_output(storage.as_ref(), &dir, map_idx, &partitions, compression).await
+        })
+    })??;
+    Ok(manifest_to_py(&manifest)?)
+}
+
+#[pyfunction(name = "write_shuffle_manifest", signature = (manifests, dir, io_config=None))]
+pub fn py_write_shuffle_manifest(
+    py: Python,
+    manifests: Vec<PyMicroPartition>,
+    dir: String,
+    io_config: Option<IOConfig>,
+) -> PyResult<String> {
+    let mut batches = vec![];
+    for manifest in &manifests {
+        batches.extend(manifest.inner.get_tables()?.iter().cloned());
+    }
+    let manifest = ShuffleManifest::from_record_batches(&batches)?;
+    let storage = shuffle_storage_for_dirs(&[dir.clone()], io_config.map(|c| c.config))?;
+    let path = py.allow_threads(|| {
+        get_io_runtime(true)
+            .block_on(async move { write_manifest(storage.as_ref(), &dir, &manifest).await })
+    })??;
+    Ok(path)
+}
+
+#[pyfunction(
+    name = "read_shuffle_partition",
+    signature = (manifest_path, partition_idx, schema, io_config=None)
+)]
+pub fn py_read_shuffle_partition(
+    py: Python,
+    manifest_path: String,
+    partition_idx: u64,
+    schema: PySchema,
+    io_config: Option<IOConfig>,
+) -> PyResult<PyMicroPartition> {
+    let storage = shuffle_storage_for_dirs(&[manifest_path.clone()], io_config.map(|c| c.config))?;
+    let partition = py.allow_threads(|| {
+        get_io_runtime(true).block_on(async move {
+            read_partition(
+                storage.as_ref(),
+                &manifest_path,
+                partition_idx,
+                schema.schema,
+            )
+            .await
+        })
+    })??;
+    Ok(partition.into())
+}
+
 pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_class::<PyInProgressShuffleCache>()?;
     parent.add_class::<PyShuffleCache>()?;
     parent.add_class::<PyFlightServerConnectionHandle>()?;
     parent.add_function(wrap_pyfunction!(py_start_flight_server, parent)?)?;
+    parent.add_function(wrap_pyfunction!(py_write_shuffle_map_output, parent)?)?;
+    parent.add_function(wrap_pyfunction!(py_write_shuffle_manifest, parent)?)?;
+    parent.add_function(wrap_pyfunction!(py_read_shuffle_partition, parent)?)?;
     Ok(())
 }
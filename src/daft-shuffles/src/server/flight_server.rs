@@ -1,4 +1,4 @@
-use std::{fs::File, pin::Pin, sync::Arc};
+use std::{pin::Pin, sync::Arc};
 
 use arrow2::io::{flight::default_ipc_fields, ipc::write::schema_to_bytes};
 use arrow_flight::{
@@ -86,17 +86,23 @@ impl FlightService for ShuffleFlightServer {
             .map_err(|e| Status::invalid_argument(e.to_string()))?;
         let file_paths = self.shuffle_cache.file_paths(partition_idx);
 
+        let storage = self.shuffle_cache.storage();
         let file_path_stream = futures::stream::iter(file_paths);
         let flight_data_stream = file_path_stream
-            .map(|file_path| {
-                let reader = File::open(file_path)
-                    .map_err(|e| Status::internal(format!("Error opening file: {}", e)))?;
-                let iter = FlightDataStreamReader::try_new(reader).map_err(|e| {
-                    Status::internal(format!("Error creating flight data reader: {}", e))
-                })?;
-                let stream =
-                    futures::stream::iter(iter).map_err(|e| Status::internal(e.to_string()));
-                Ok::<_, Status>(stream)
+            .then(move |file_path| {
+                let storage = storage.clone();
+                async move {
+                    let reader = storage
+                        .read(&file_path)
+                        .await
+                        .map_err(|e| Status::internal(format!("Error opening file: {}", e)))?;
+                    let iter = FlightDataStreamReader::try_new(reader).map_err(|e| {
+                        Status::internal(format!("Error creating flight data reader: {}", e))
+                    })?;
+                    let stream =
+                        futures::stream::iter(iter).map_err(|e| Status::internal(e.to_string()));
+                    Ok::<_, Status>(stream)
+                }
             })
             .try_flatten();
 
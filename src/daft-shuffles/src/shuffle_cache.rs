@@ -3,13 +3,15 @@ use std::sync::{Arc, OnceLock};
 use common_error::{DaftError, DaftResult};
 use common_runtime::{get_compute_runtime, RuntimeRef, RuntimeTask};
 use daft_dsl::ExprRef;
-use daft_io::{parse_url, SourceType};
+use daft_io::IOConfig;
 use daft_micropartition::MicroPartition;
 use daft_recordbatch::RecordBatch;
 use daft_schema::schema::SchemaRef;
 use daft_writers::{make_ipc_writer, FileWriter, RETURN_PATHS_COLUMN_NAME};
 use tokio::sync::Mutex;
 
+use crate::storage::{shuffle_storage_for_dirs, ShuffleStorage};
+
 // Single threaded runtime used for shuffle cache tasks, e.g. partitioner and writer tasks
 static SHUFFLE_CACHE_RUNTIME: OnceLock<RuntimeRef> = OnceLock::new();
 
@@ -38,6 +40,7 @@ struct InProgressShuffleCacheState {
     partitioner_sender: Option<async_channel::Sender<Arc<MicroPartition>>>,
     partitioner_tasks: Vec<RuntimeTask<DaftResult<()>>>,
     writer_tasks: Vec<WriterTask>,
+    storage: Arc<dyn ShuffleStorage>,
     error: Option<String>,
 }
 
@@ -47,48 +50,50 @@ pub struct InProgressShuffleCache {
 }
 
 impl InProgressShuffleCache {
+    /// Creates a shuffle cache that writes each partition to `{dir}/partition_{idx}`, spreading the partitions over
+    /// `dirs`. The dirs can be local paths, including shared volumes, or object store URLs that are accessed with
+    /// `io_config`.
+    #[allow(clippy::too_many_arguments)]
     pub fn try_new(
         num_partitions: usize,
         dirs: &[String],
         target_filesize: usize,
         compression: Option<&str>,
+        compression_level: Option<i32>,
         partition_by: Option<Vec<ExprRef>>,
+        io_config: Option<IOConfig>,
     ) -> DaftResult<Self> {
-        // Create the directories
-        for dir in dirs {
-            // Check that the dir is a file
-            let (source_type, _) = parse_url(dir)?;
-            if source_type != SourceType::File {
-                return Err(DaftError::ValueError(format!(
-                    "ShuffleCache only supports file paths, got: {}",
-                    dir
-                )));
-            }
-
-            // If the directory doesn't exist, create it
-            if std::path::Path::new(dir).exists() {
-                std::fs::remove_dir_all(dir)?;
-            }
-            std::fs::create_dir_all(dir)?;
-        }
+        let storage = shuffle_storage_for_dirs(dirs, io_config)?;
 
         // Create the partition writers
         let mut writers = Vec::with_capacity(num_partitions);
+        let mut partition_dirs = Vec::with_capacity(num_partitions);
         for partition_idx in 0..num_partitions {
             let dir = &dirs[partition_idx % dirs.len()];
-            let partition_dir = format!("{}/partition_{}", dir, partition_idx);
-            std::fs::create_dir_all(&partition_dir)?;
+            let partition_dir =
+                format!("{}/partition_{}", dir.trim_end_matches('/'), partition_idx);
+            let local_dir = storage.prepare_dir(&partition_dir)?;
 
-            let writer = make_ipc_writer(&partition_dir, target_filesize, compression)?;
+            let writer =
+                make_ipc_writer(&local_dir, target_filesize, compression, compression_level)?;
             writers.push(writer);
+            partition_dirs.push(partition_dir);
         }
 
         // Create the InProgressShuffleCache with the writers
-        Self::try_new_with_writers(writers, num_partitions, partition_by)
+        Self::try_new_with_writers(
+            writers,
+            partition_dirs,
+            storage,
+            num_partitions,
+            partition_by,
+        )
     }
 
     fn try_new_with_writers(
         writers: Vec<Box<dyn FileWriter<Input = Arc<MicroPartition>, Result = Vec<RecordBatch>>>>,
+        partition_dirs: Vec<String>,
+        storage: Arc<dyn ShuffleStorage>,
         num_partitions: usize,
         partition_by: Option<Vec<ExprRef>>,
     ) -> DaftResult<Self> {
@@ -97,10 +102,12 @@ impl InProgressShuffleCache {
         // Spawn the writer tasks
         let (writer_tasks, writer_senders): (Vec<_>, Vec<_>) = writers
             .into_iter()
-            .map(|writer| {
+            .zip(partition_dirs)
+            .map(|(writer, partition_dir)| {
                 let (tx, rx) = async_channel::bounded(num_cpus * 2);
+                let storage = storage.clone();
                 let task = get_or_init_shuffle_cache_runtime()
-                    .spawn(async move { writer_task(rx, writer).await });
+                    .spawn(async move { writer_task(rx, writer, storage, partition_dir).await });
                 (task, tx)
             })
             .unzip();
@@ -132,6 +139,7 @@ impl InProgressShuffleCache {
                 partitioner_sender: Some(partitioner_sender),
                 partitioner_tasks,
                 writer_tasks,
+                storage,
                 error: None,
             }),
             partitioner_sender_weak: weak_partitioner_sender,
@@ -200,6 +208,7 @@ impl InProgressShuffleCache {
             schema,
             bytes_per_file_per_partition,
             file_paths_per_partition,
+            state.storage.clone(),
         ))
     }
 
@@ -262,10 +271,13 @@ async fn partitioner_task(
     Ok(())
 }
 
-// Writer task that takes partitions from the partitioner sender, writes them to a file, and returns the schema and file paths
+// Writer task that takes partitions from the partitioner sender, writes them to a file, moves the files into the
+// storage, and returns the schema and stored file paths
 async fn writer_task(
     rx: async_channel::Receiver<Arc<MicroPartition>>,
     mut writer: Box<dyn FileWriter<Input = Arc<MicroPartition>, Result = Vec<RecordBatch>>>,
+    storage: Arc<dyn ShuffleStorage>,
+    partition_dir: String,
 ) -> DaftResult<WriterTaskResult> {
     let compute_runtime = get_compute_runtime();
     let mut schema = None;
@@ -295,6 +307,12 @@ async fn writer_task(
             Ok(path.to_string())
         })
         .collect::<DaftResult<Vec<String>>>()?;
+    let file_paths = futures::future::try_join_all(
+        file_paths
+            .iter()
+            .map(|file_path| storage.store(file_path, &partition_dir)),
+    )
+    .await?;
 
     let bytes_per_file = writer.bytes_per_file();
     assert!(bytes_per_file.len() == file_paths.len());
@@ -310,6 +328,7 @@ pub struct ShuffleCache {
     schema: SchemaRef,
     bytes_per_file_per_partition: Vec<Vec<usize>>,
     file_paths_per_partition: Vec<Vec<String>>,
+    storage: Arc<dyn ShuffleStorage>,
 }
 
 impl ShuffleCache {
@@ -317,14 +336,20 @@ impl ShuffleCache {
         schema: SchemaRef,
         bytes_per_file_per_partition: Vec<Vec<usize>>,
         file_paths_per_partition: Vec<Vec<String>>,
+        storage: Arc<dyn ShuffleStorage>,
     ) -> Self {
         Self {
             schema,
             bytes_per_file_per_partition,
             file_paths_per_partition,
+            storage,
         }
     }
 
+    pub fn storage(&self) -> Arc<dyn ShuffleStorage> {
+        self.storage.clone()
+    }
+
     pub fn schema(&self) -> SchemaRef {
         self.schema.clone()
     }
@@ -349,6 +374,13 @@ mod tests {
     };
 
     use super::*;
+    use crate::storage::LocalShuffleStorage;
+
+    fn local_partition_dirs(num_partitions: usize) -> Vec<String> {
+        (0..num_partitions)
+            .map(|partition_idx| format!("partition_{}", partition_idx))
+            .collect()
+    }
 
     #[tokio::test]
     async fn test_shuffle_cache_basic() -> DaftResult<()> {
@@ -365,6 +397,8 @@ mod tests {
         // Create the cache with dummy writers
         let cache = InProgressShuffleCache::try_new_with_writers(
             writers,
+            local_partition_dirs(num_partitions),
+            Arc::new(LocalShuffleStorage),
             num_partitions,
             None, // No partition by expressions
         )?;
@@ -398,6 +432,34 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_shuffle_cache_with_compression() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let dirs = vec![dir.path().to_string_lossy().into_owned()];
+        let cache =
+            InProgressShuffleCache::try_new(2, &dirs, 1024, Some("zstd"), Some(3), None, None)?;
+        cache
+            .push_partitions(vec![make_dummy_mp(300), make_dummy_mp(300)])
+            .await?;
+        let shuffle_cache = cache.close().await?;
+
+        let mut num_rows = 0;
+        for partition_idx in 0..2 {
+            for file_path in shuffle_cache.file_paths(partition_idx) {
+                assert!(file_path.contains(&format!("partition_{}", partition_idx)));
+                let mut reader = shuffle_cache.storage().read(&file_path).await?;
+                let metadata = arrow2::io::ipc::read::read_stream_metadata(&mut reader)?;
+                for state in arrow2::io::ipc::read::StreamReader::new(reader, metadata, None) {
+                    if let arrow2::io::ipc::read::StreamState::Some(chunk) = state? {
+                        num_rows += chunk.len();
+                    }
+                }
+            }
+        }
+        assert_eq!(num_rows, 600);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_shuffle_cache_with_partition_by() -> DaftResult<()> {
         // Create dummy writers for testing
@@ -417,8 +479,13 @@ mod tests {
         .into()]);
 
         // Create the cache with dummy writers
-        let cache =
-            InProgressShuffleCache::try_new_with_writers(writers, num_partitions, partition_by)?;
+        let cache = InProgressShuffleCache::try_new_with_writers(
+            writers,
+            local_partition_dirs(num_partitions),
+            Arc::new(LocalShuffleStorage),
+            num_partitions,
+            partition_by,
+        )?;
 
         // Create and push some partitions
         let mp = make_dummy_mp(150);
@@ -450,8 +517,13 @@ mod tests {
         )))
         .into()]);
 
-        let cache =
-            InProgressShuffleCache::try_new_with_writers(writers, num_partitions, partition_by)?;
+        let cache = InProgressShuffleCache::try_new_with_writers(
+            writers,
+            local_partition_dirs(num_partitions),
+            Arc::new(LocalShuffleStorage),
+            num_partitions,
+            partition_by,
+        )?;
 
         // 1000 empty partitions
         for _ in 0..1000 {
@@ -490,6 +562,8 @@ mod tests {
         // Create the cache with writers
         let cache = InProgressShuffleCache::try_new_with_writers(
             writers,
+            local_partition_dirs(num_partitions),
+            Arc::new(LocalShuffleStorage),
             num_partitions,
             None, // No partition by expressions
         )?;
@@ -560,6 +634,8 @@ mod tests {
         // Create the cache with writers
         let cache = InProgressShuffleCache::try_new_with_writers(
             writers,
+            local_partition_dirs(num_partitions),
+            Arc::new(LocalShuffleStorage),
             num_partitions,
             None, // No partition by expressions
         )?;
@@ -0,0 +1,213 @@
+use std::{
+    fs::File,
+    io::{Cursor, Read},
+    path::Path,
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use common_error::{DaftError, DaftResult};
+use daft_io::{get_io_client, parse_url, IOClient, IOConfig, SourceType};
+use tempfile::TempDir;
+
+/// Where the files of a shuffle are kept until the reducers have fetched them.
+///
+/// The flight shuffle writes its files to a local directory first, and once a file is finished, [`Self::store`] moves it
+/// to its final location, from which the flight server reads it back with [`Self::read`]. The external shuffle writes
+/// its files directly with [`Self::write`].
+#[async_trait]
+pub trait ShuffleStorage: Send + Sync + std::fmt::Debug {
+    /// Prepares `dir` to receive shuffle files, and returns the local directory that the files should be written to.
+    fn prepare_dir(&self, dir: &str) -> DaftResult<String>;
+
+    /// Moves a finished file from the local directory of `dir` into `dir`, and returns the path to read it from.
+    async fn store(&self, local_path: &str, dir: &str) -> DaftResult<String>;
+
+    /// Writes a whole file to `path`, replacing any existing file.
+    async fn write(&self, path: &str, data: Vec<u8>) -> DaftResult<()>;
+
+    /// Opens a stored file for reading.
+    async fn read(&self, path: &str) -> DaftResult<Box<dyn Read + Send>>;
+}
+
+/// Returns the storage for the given shuffle directories, which must all be local paths or all be object store URLs.
+pub fn shuffle_storage_for_dirs(
+    dirs: &[String],
+    io_config: Option<IOConfig>,
+) -> DaftResult<Arc<dyn ShuffleStorage>> {
+    let source_types = dirs
+        .iter()
+        .map(|dir| Ok(parse_url(dir)?.0))
+        .collect::<DaftResult<Vec<_>>>()?;
+    match source_types.first() {
+        None => Err(DaftError::ValueError(
+            "ShuffleCache needs at least one directory".to_string(),
+        )),
+        Some(first) if source_types.iter().any(|source_type| source_type != first) => {
+            Err(DaftError::ValueError(format!(
+                "ShuffleCache directories must all be on the same storage, got: {:?}",
+                dirs
+            )))
+        }
+        Some(SourceType::File) => Ok(Arc::new(LocalShuffleStorage)),
+        Some(_) => Ok(Arc::new(ObjectStoreShuffleStorage::try_new(
+            io_config.unwrap_or_default(),
+        )?)),
+    }
+}
+
+/// Keeps shuffle files where they are written, on local disk or on a shared volume such as an NVMe array that is
+/// mounted on every node.
+#[derive(Debug)]
+pub struct LocalShuffleStorage;
+
+#[async_trait]
+impl ShuffleStorage for LocalShuffleStorage {
+    fn prepare_dir(&self, dir: &str) -> DaftResult<String> {
+        // Clear out files left behind by a previous shuffle in the same directory
+        if Path::new(dir).exists() {
+            std::fs::remove_dir_all(dir)?;
+        }
+        std::fs::create_dir_all(dir)?;
+        Ok(dir.to_string())
+    }
+
+    async fn store(&self, local_path: &str, _dir: &str) -> DaftResult<String> {
+        Ok(local_path.to_string())
+    }
+
+    async fn write(&self, path: &str, data: Vec<u8>) -> DaftResult<()> {
+        let path = Path::new(strip_file_scheme(path));
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, data)?;
+        Ok(())
+    }
+
+    async fn read(&self, path: &str) -> DaftResult<Box<dyn Read + Send>> {
+        Ok(Box::new(File::open(strip_file_scheme(path))?))
+    }
+}
+
+fn strip_file_scheme(path: &str) -> &str {
+    path.strip_prefix("file://").unwrap_or(path)
+}
+
+/// Uploads shuffle files to an object store, e.g. S3, staging them in a local temporary directory while they are
+/// written.
+///
+/// Uploaded files are not deleted once the shuffle is done, so the bucket should have a lifecycle policy that expires
+/// them.
+pub struct ObjectStoreShuffleStorage {
+    io_client: Arc<IOClient>,
+    staging_dir: TempDir,
+}
+
+impl std::fmt::Debug for ObjectStoreShuffleStorage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ObjectStoreShuffleStorage")
+            .field("staging_dir", &self.staging_dir.path())
+            .finish_non_exhaustive()
+    }
+}
+
+impl ObjectStoreShuffleStorage {
+    pub fn try_new(io_config: IOConfig) -> DaftResult<Self> {
+        Ok(Self {
+            io_client: get_io_client(true, Arc::new(io_config))?,
+            staging_dir: tempfile::Builder::new().prefix("daft-shuffle-").tempdir()?,
+        })
+    }
+
+    fn staging_dir_for(&self, dir: &str) -> String {
+        let name = dir.trim_end_matches('/').replace(['/', ':'], "_");
+        self.staging_dir
+            .path()
+            .join(name)
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
+#[async_trait]
+impl ShuffleStorage for ObjectStoreShuffleStorage {
+    fn prepare_dir(&self, dir: &str) -> DaftResult<String> {
+        let local_dir = self.staging_dir_for(dir);
+        std::fs::create_dir_all(&local_dir)?;
+        Ok(local_dir)
+    }
+
+    async fn store(&self, local_path: &str, dir: &str) -> DaftResult<String> {
+        let file_name = Path::new(local_path)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| {
+                DaftError::InternalError(format!("Invalid shuffle file path: {}", local_path))
+            })?;
+        let path = format!("{}/{}", dir.trim_end_matches('/'), file_name);
+        let data = std::fs::read(local_path)?;
+        self.io_client
+            .single_url_put(&path, data.into(), None)
+            .await?;
+        std::fs::remove_file(local_path)?;
+        Ok(path)
+    }
+
+    async fn write(&self, path: &str, data: Vec<u8>) -> DaftResult<()> {
+        self.io_client
+            .single_url_put(path, data.into(), None)
+            .await?;
+        Ok(())
+    }
+
+    async fn read(&self, path: &str) -> DaftResult<Box<dyn Read + Send>> {
+        let data = self
+            .io_client
+            .single_url_get(path.to_string(), None, None)
+            .await?
+            .bytes()
+            .await?;
+        Ok(Box::new(Cursor::new(data)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use common_error::DaftResult;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_object_store_storage_uploads_files() -> DaftResult<()> {
+        let remote = tempfile::tempdir()?;
+        let remote_dir = format!("file://{}/partition_0", remote.path().display());
+        std::fs::create_dir_all(remote.path().join("partition_0"))?;
+
+        let storage = ObjectStoreShuffleStorage::try_new(IOConfig::default())?;
+        let local_dir = storage.prepare_dir(&remote_dir)?;
+        assert!(!local_dir.starts_with(&remote_dir));
+        let local_path = format!("{}/0.arrow", local_dir);
+        std::fs::write(&local_path, b"shuffle data")?;
+
+        let path = storage.store(&local_path, &remote_dir).await?;
+        assert_eq!(path, format!("{}/0.arrow", remote_dir));
+        assert!(!Path::new(&local_path).exists());
+
+        let mut data = vec![];
+        storage.read(&path).await?.read_to_end(&mut data)?;
+        assert_eq!(data, b"shuffle data");
+        Ok(())
+    }
+
+    #[test]
+    fn test_storage_for_mixed_dirs_errors() {
+        let dirs = vec![
+            "/tmp/shuffle".to_string(),
+            "s3://bucket/shuffle".to_string(),
+        ];
+        assert!(shuffle_storage_for_dirs(&dirs, None).is_err());
+    }
+}
@@ -1,22 +1,32 @@
 use std::{rc::Rc, sync::Arc};
 
 use common_error::DaftResult;
+use daft_catalog::error::Error as CatalogError;
 use daft_logical_plan::LogicalPlan;
 use daft_session::Session;
 
 use crate::{
-    error::PlannerError, statement, statement::Statement, table_provider::in_memory, SQLPlanner,
+    error::PlannerError, statement, statement::Statement, table_provider::in_memory, SQLParams,
+    SQLPlanner,
 };
 
 /// Execute result is always a dataframe.
 pub(crate) type DataFrame = Arc<LogicalPlan>;
 
 /// Execute SQL statements against the session.
-pub(crate) fn execute_statement(sess: Session, statement: &str) -> DaftResult<Option<DataFrame>> {
+pub fn execute_statement(
+    sess: Session,
+    statement: &str,
+    params: SQLParams,
+) -> DaftResult<Option<DataFrame>> {
     let sess: Rc<Session> = Rc::new(sess);
-    let stmt = SQLPlanner::new(sess.clone()).plan(statement)?;
+    let stmt = SQLPlanner::new(sess.clone())
+        .with_params(params)
+        .plan(statement)?;
     match stmt {
         Statement::Select(select) => execute_select(&sess, select),
+        Statement::CreateTable(create) => execute_create_table(&sess, create),
+        Statement::Insert(insert) => execute_insert(&sess, insert),
         Statement::Set(set) => execute_set(&sess, set),
         Statement::Use(use_) => execute_use(&sess, use_),
         Statement::ShowTables(show_tables) => execute_show_tables(&sess, show_tables),
@@ -27,6 +37,28 @@ fn execute_select(_: &Session, select: DataFrame) -> DaftResult<Option<DataFrame
     Ok(Some(select))
 }
 
+fn execute_create_table(
+    sess: &Session,
+    create: statement::CreateTable,
+) -> DaftResult<Option<DataFrame>> {
+    if create.temporary {
+        match sess.create_temp_table(create.name.name(), &create.source, create.replace) {
+            Err(CatalogError::ObjectAlreadyExists { .. }) if create.if_not_exists => {}
+            result => {
+                result?;
+            }
+        }
+    } else {
+        sess.create_table(&create.name, &create.source, create.if_not_exists)?;
+    }
+    Ok(None)
+}
+
+fn execute_insert(_: &Session, insert: statement::Insert) -> DaftResult<Option<DataFrame>> {
+    insert.table.write(insert.source, insert.mode)?;
+    Ok(None)
+}
+
 fn execute_set(_: &Session, _: statement::Set) -> DaftResult<Option<DataFrame>> {
     Err(PlannerError::unsupported_sql(
         "SET statement is not yet supported.".to_string(),
@@ -3,20 +3,26 @@ use std::{
     sync::{Arc, LazyLock},
 };
 
-use daft_dsl::{Expr, ExprRef};
+use daft_core::prelude::DataType;
+use daft_dsl::{
+    expr::window::{WindowBoundary, WindowFrame, WindowFrameType, WindowSpec},
+    Expr, ExprRef, WindowExpr,
+};
 use daft_session::Session;
 use sqlparser::ast::{
     DuplicateTreatment, Function, FunctionArg, FunctionArgExpr, FunctionArgOperator,
-    FunctionArguments,
+    FunctionArguments, WindowFrameBound, WindowFrameUnits, WindowType,
 };
 
 use crate::{
     error::{PlannerError, SQLPlannerResult},
+    invalid_operation_err,
     modules::{
-        coalesce::SQLCoalesce, hashing::SQLModuleHashing, SQLModule, SQLModuleAggs,
-        SQLModuleConfig, SQLModuleFloat, SQLModuleImage, SQLModuleJson, SQLModuleList,
-        SQLModuleMap, SQLModuleNumeric, SQLModulePartitioning, SQLModulePython, SQLModuleSketch,
-        SQLModuleStructs, SQLModuleTemporal, SQLModuleUri, SQLModuleUtf8,
+        coalesce::SQLCoalesce, hashing::SQLModuleHashing, native_udf::SQLNativeUdf, SQLModule,
+        SQLModuleAggs, SQLModuleConfig, SQLModuleFloat, SQLModuleImage, SQLModuleJson,
+        SQLModuleList, SQLModuleMap, SQLModuleNumeric, SQLModulePartitioning, SQLModulePython,
+        SQLModuleSketch, SQLModuleStructs, SQLModuleTemporal, SQLModuleUri, SQLModuleUtf8,
+        SQLModuleWindow,
     },
     planner::SQLPlanner,
     unsupported_sql_err,
@@ -40,6 +46,7 @@ pub(crate) static SQL_FUNCTIONS: LazyLock<SQLFunctions> = LazyLock::new(|| {
     functions.register::<SQLModuleTemporal>();
     functions.register::<SQLModuleUri>();
     functions.register::<SQLModuleUtf8>();
+    functions.register::<SQLModuleWindow>();
     functions.register::<SQLModuleConfig>();
     functions.add_fn("coalesce", SQLCoalesce {});
     functions
@@ -51,10 +58,6 @@ fn check_features(func: &Function) -> SQLPlannerResult<()> {
         // <agg>(..) FILTER (WHERE ..)
         unsupported_sql_err!("Aggregation `FILTER`");
     }
-    if func.over.is_some() {
-        // WINDOW functions
-        unsupported_sql_err!("Window functions `OVER`");
-    }
     if !func.within_group.is_empty() {
         // <agg>(...) WITHIN GROUP
         unsupported_sql_err!("Aggregation `WITHIN GROUP`");
@@ -319,6 +322,13 @@ impl SQLPlanner<'_> {
             }
         }
 
+        fn get_func_from_native_udfs(name: impl AsRef<str>) -> Option<Arc<dyn SQLFunction>> {
+            let udf = daft_functions::native_udf::get_native_udf(name.as_ref())?;
+            Some(Arc::new(SQLNativeUdf {
+                name: udf.name().to_string(),
+            }))
+        }
+
         // lookup function variant(s) by name
         // SQL function names are case-insensitive
         let fn_name = func.name.to_string().to_lowercase();
@@ -327,6 +337,8 @@ impl SQLPlanner<'_> {
             get_func_from_session(&self.context.borrow().session, &fn_name)?
         {
             fn_match
+        } else if let Some(fn_match) = get_func_from_native_udfs(&fn_name) {
+            fn_match
         } else {
             get_func_from_sqlfunctions_registry(fn_name.as_str()).ok_or_else(|| {
                 PlannerError::unsupported_sql(format!("Function `{}` not found", fn_name))
@@ -384,7 +396,124 @@ impl SQLPlanner<'_> {
         };
 
         // validate input argument arity and return the validated expression.
-        fn_match.to_expr(&args, self)
+        let expr = fn_match.to_expr(&args, self)?;
+        match &func.over {
+            Some(over) => self.plan_window(expr, over),
+            None if matches!(expr.as_ref(), Expr::WindowFunction(_)) => {
+                invalid_operation_err!("Window function `{}` requires `OVER`", fn_name)
+            }
+            None => Ok(expr),
+        }
+    }
+
+    /// Plans `<func> OVER (PARTITION BY .. ORDER BY .. <frame>)` as a window function over the window spec.
+    fn plan_window(&self, expr: ExprRef, over: &WindowType) -> SQLPlannerResult<ExprRef> {
+        let spec = match over {
+            WindowType::WindowSpec(spec) if spec.window_name.is_none() => spec,
+            _ => unsupported_sql_err!("Named windows"),
+        };
+        let (expr, alias) = match expr.as_ref() {
+            Expr::Alias(inner, name) => (inner.clone(), Some(name.clone())),
+            _ => (expr, None),
+        };
+        let Ok(window_expr) = WindowExpr::try_from(expr) else {
+            invalid_operation_err!("Only aggregation and window functions can be used with `OVER`")
+        };
+
+        let partition_by = spec
+            .partition_by
+            .iter()
+            .map(|expr| self.plan_expr(expr))
+            .collect::<SQLPlannerResult<Vec<_>>>()?;
+        let (order_by, descending) = if spec.order_by.is_empty() {
+            (vec![], vec![])
+        } else {
+            let order_by = self.plan_order_by_exprs(&spec.order_by)?;
+            // windows sort nulls first when descending and last otherwise
+            if order_by.nulls_first != order_by.descending {
+                unsupported_sql_err!("`NULLS FIRST` and `NULLS LAST` in windows");
+            }
+            (order_by.exprs, order_by.descending)
+        };
+
+        let frame = match &spec.window_frame {
+            Some(frame) => {
+                let frame_type = match frame.units {
+                    WindowFrameUnits::Rows => WindowFrameType::Rows,
+                    WindowFrameUnits::Range => WindowFrameType::Range,
+                    WindowFrameUnits::Groups => unsupported_sql_err!("`GROUPS` window frames"),
+                };
+                Some(WindowFrame {
+                    frame_type,
+                    start: self.plan_window_frame_bound(&frame.start_bound)?,
+                    end: match &frame.end_bound {
+                        Some(bound) => self.plan_window_frame_bound(bound)?,
+                        None => WindowBoundary::Offset(0),
+                    },
+                })
+            }
+            // Aggregations over ordered windows are running aggregations up to the current row, including its
+            // peers where the order can be ranged over.
+            None if matches!(window_expr, WindowExpr::Agg(_)) && !order_by.is_empty() => {
+                let schema = self.current_plan_ref().schema();
+                let is_rangeable = |expr: &ExprRef| {
+                    expr.to_field(&schema).is_ok_and(|field| {
+                        field.dtype.is_numeric()
+                            || field.dtype.is_temporal()
+                            || field.dtype == DataType::Null
+                    })
+                };
+                let frame_type = match order_by.as_slice() {
+                    [order_by] if is_rangeable(order_by) => WindowFrameType::Range,
+                    _ => WindowFrameType::Rows,
+                };
+                Some(WindowFrame {
+                    frame_type,
+                    start: WindowBoundary::UnboundedPreceding(),
+                    end: WindowBoundary::Offset(0),
+                })
+            }
+            None => None,
+        };
+
+        let expr: ExprRef = Expr::Over(
+            window_expr,
+            WindowSpec {
+                partition_by,
+                order_by,
+                descending,
+                frame,
+                ..Default::default()
+            },
+        )
+        .into();
+        Ok(match alias {
+            Some(name) => expr.alias(name),
+            None => expr,
+        })
+    }
+
+    fn plan_window_frame_bound(
+        &self,
+        bound: &WindowFrameBound,
+    ) -> SQLPlannerResult<WindowBoundary> {
+        let offset = |expr: &sqlparser::ast::Expr| {
+            let offset = self.plan_expr(expr)?;
+            match offset.as_literal().and_then(|lit| lit.as_i64()) {
+                Some(offset) if offset >= 0 => Ok(offset),
+                _ => invalid_operation_err!(
+                    "Window frame offsets must be non-negative integers, found {}",
+                    expr
+                ),
+            }
+        };
+        Ok(match bound {
+            WindowFrameBound::CurrentRow => WindowBoundary::Offset(0),
+            WindowFrameBound::Preceding(None) => WindowBoundary::UnboundedPreceding(),
+            WindowFrameBound::Following(None) => WindowBoundary::UnboundedFollowing(),
+            WindowFrameBound::Preceding(Some(expr)) => WindowBoundary::Offset(-offset(expr)?),
+            WindowFrameBound::Following(Some(expr)) => WindowBoundary::Offset(offset(expr)?),
+        })
     }
 
     pub(crate) fn plan_function_args<T>(
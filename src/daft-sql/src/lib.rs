@@ -5,11 +5,14 @@ pub mod functions;
 
 mod exec;
 mod modules;
+mod params;
 mod planner;
 mod schema;
 mod statement;
 mod table_provider;
 
+pub use exec::execute_statement;
+pub use params::SQLParams;
 pub use planner::*;
 #[cfg(feature = "python")]
 pub mod python;
@@ -32,8 +35,11 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
 mod tests {
     use std::sync::Arc;
 
+    use daft_catalog::{Identifier, TableSource, WriteMode};
     use daft_core::prelude::*;
-    use daft_dsl::{lit, unresolved_col, Expr, ExprRef, PlanRef, Subquery, UnresolvedColumn};
+    use daft_dsl::{
+        lit, unresolved_col, Expr, ExprRef, LiteralValue, PlanRef, Subquery, UnresolvedColumn,
+    };
     use daft_logical_plan::{
         logical_plan::Source, source_info::PlaceHolderInfo, ClusteringSpec, JoinOptions,
         LogicalPlan, LogicalPlanBuilder, LogicalPlanRef, SourceInfo,
@@ -43,7 +49,7 @@ mod tests {
     use rstest::{fixture, rstest};
 
     use super::*;
-    use crate::{error::PlannerError, planner::SQLPlanner};
+    use crate::{error::PlannerError, planner::SQLPlanner, statement::Statement};
 
     #[fixture]
     fn tbl_1() -> LogicalPlanRef {
@@ -168,6 +174,12 @@ mod tests {
     #[case::cte("with cte as (select * from tbl1) select * from cte")]
     #[case::double_alias("select * from tbl1 as tbl2, tbl2 as tbl1")]
     #[case::double_alias_qualified("select tbl1.val from tbl1 as tbl2, tbl2 as tbl1")]
+    #[case::window_rank("select rank() over (partition by utf8 order by i32 desc) from tbl1")]
+    #[case::window_agg("select sum(f32) over (partition by utf8 order by i32) from tbl1")]
+    #[case::window_frame(
+        "select sum(f32) over (order by i32 rows between 1 preceding and 1 following) from tbl1"
+    )]
+    #[case::window_lag("select i32 - lag(i32, 2) over (partition by utf8 order by date) from tbl1")]
     fn test_compiles(mut planner: SQLPlanner, #[case] query: &str) -> SQLPlannerResult<()> {
         let plan = planner.plan_sql(query);
         assert!(&plan.is_ok(), "query: {query}\nerror: {plan:?}");
@@ -221,6 +233,37 @@ mod tests {
         assert_eq!(plan, expected);
         Ok(())
     }
+
+    #[rstest]
+    fn test_params(planner: SQLPlanner, tbl_1: LogicalPlanRef) -> SQLPlannerResult<()> {
+        let params = SQLParams {
+            positional: vec![LiteralValue::Utf8("a".to_string()), LiteralValue::Int32(1)],
+            named: [("limit".to_string(), LiteralValue::Int64(2))].into(),
+        };
+        let mut planner = planner.with_params(params);
+        let sql = "select test from tbl1 where test = ? and i32 > ? and i64 < :limit and f64 < $2";
+        let plan = planner.plan_sql(sql)?;
+
+        let expected = LogicalPlanBuilder::from(tbl_1)
+            .alias("tbl1")
+            .filter(
+                unresolved_col("test")
+                    .eq(lit("a"))
+                    .and(unresolved_col("i32").gt(lit(1i32)))
+                    .and(unresolved_col("i64").lt(lit(2i64)))
+                    .and(unresolved_col("f64").lt(lit(1i32))),
+            )?
+            .select(vec![unresolved_col("test")])?
+            .build();
+        assert_eq!(plan, expected);
+
+        let result = planner.plan_sql("select test from tbl1 where i32 > ?3");
+        assert!(result.is_err());
+        let result = planner.plan_sql("select test from tbl1 where i32 > :missing");
+        assert!(result.is_err_and(|e| matches!(e, PlannerError::InvalidOperation { .. })));
+        Ok(())
+    }
+
     #[rstest]
     fn test_limit(mut planner: SQLPlanner, tbl_1: LogicalPlanRef) -> SQLPlannerResult<()> {
         let sql = "select test as a from tbl1 limit 10";
@@ -418,6 +461,63 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    #[case::rollup(
+        "select utf8, i32, sum(i64), grouping_id() from tbl1 group by rollup(utf8, i32)"
+    )]
+    #[case::cube("select utf8, i32, sum(i64), grouping_id() from tbl1 group by cube(utf8, i32)")]
+    #[case::grouping_sets(
+        "select utf8, i32, sum(i64), grouping_id() from tbl1 group by grouping sets ((utf8, i32), (i32), ())"
+    )]
+    #[case::mixed("select utf8, i32, sum(i64), grouping_id() from tbl1 group by utf8, cube(i32)")]
+    fn test_grouping_sets(mut planner: SQLPlanner, #[case] query: &str) -> SQLPlannerResult<()> {
+        let plan = planner.plan_sql(query)?;
+
+        assert_eq!(
+            plan.schema().as_ref(),
+            &Schema::new(vec![
+                Field::new("utf8", DataType::Utf8),
+                Field::new("i32", DataType::Int32),
+                Field::new("i64", DataType::Int64),
+                Field::new("grouping_id", DataType::Int64),
+            ])
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_pivot(mut planner: SQLPlanner) -> SQLPlannerResult<()> {
+        let sql = "select * from (select utf8, i32, i64 from tbl1) pivot (avg(i64) for i32 in (1, 2 as two))";
+        let plan = planner.plan_sql(sql)?;
+
+        assert_eq!(
+            plan.schema().as_ref(),
+            &Schema::new(vec![
+                Field::new("utf8", DataType::Utf8),
+                Field::new("1", DataType::Float64),
+                Field::new("two", DataType::Float64),
+            ])
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_unpivot(mut planner: SQLPlanner) -> SQLPlannerResult<()> {
+        let sql =
+            "select * from (select utf8, i64, f64 from tbl1) unpivot (val for col in (i64, f64))";
+        let plan = planner.plan_sql(sql)?;
+
+        assert_eq!(
+            plan.schema().as_ref(),
+            &Schema::new(vec![
+                Field::new("utf8", DataType::Utf8),
+                Field::new("col", DataType::Utf8),
+                Field::new("val", DataType::Float64),
+            ])
+        );
+        Ok(())
+    }
+
     #[rstest]
     #[case::basic("select utf8 from tbl1 order by utf8")]
     #[case::asc("select utf8 from tbl1 order by utf8 asc")]
@@ -565,4 +665,49 @@ mod tests {
 
         Ok(())
     }
+
+    #[rstest]
+    fn test_insert_aligns_columns(mut planner: SQLPlanner) -> SQLPlannerResult<()> {
+        let Statement::Insert(insert) =
+            planner.plan("insert into tbl3 (id, first_name) select val, text from tbl2")?
+        else {
+            panic!("Expected Insert statement");
+        };
+        assert_eq!(insert.mode, WriteMode::Append);
+        assert_eq!(insert.source.schema(), tbl_3().schema());
+
+        let result = planner.plan("insert overwrite table tbl3 select id from tbl2");
+        assert!(result.is_err_and(|e| matches!(e, PlannerError::InvalidOperation { .. })));
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_create_table(mut planner: SQLPlanner) -> SQLPlannerResult<()> {
+        let Statement::CreateTable(create) =
+            planner.plan("create temp table t as select id from tbl2")?
+        else {
+            panic!("Expected CreateTable statement");
+        };
+        assert!(create.temporary);
+        assert!(matches!(create.source, TableSource::View(_)));
+
+        let Statement::CreateTable(create) =
+            planner.plan("create table if not exists ns.t (a int, b text)")?
+        else {
+            panic!("Expected CreateTable statement");
+        };
+        assert_eq!(create.name, Identifier::try_new(vec!["ns", "t"])?);
+        assert!(create.if_not_exists);
+        let TableSource::Schema(schema) = create.source else {
+            panic!("Expected a schema source");
+        };
+        assert_eq!(
+            schema.as_ref(),
+            &Schema::new(vec![
+                Field::new("a", DataType::Int32),
+                Field::new("b", DataType::Utf8),
+            ])
+        );
+        Ok(())
+    }
 }
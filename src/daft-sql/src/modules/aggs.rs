@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use daft_core::prelude::CountMode;
 use daft_dsl::{unresolved_col, AggExpr, Expr, ExprRef, LiteralValue};
+use daft_logical_plan::ops::GROUPING_ID_COLUMN;
 use sqlparser::ast::{FunctionArg, FunctionArgExpr};
 
 use super::SQLModule;
@@ -31,6 +32,26 @@ impl SQLModule for SQLModuleAggs {
         parent.add_fn("bool_or", AggExpr::BoolOr(nil.clone()));
         parent.add_fn("stddev", AggExpr::Stddev(nil.clone()));
         parent.add_fn("stddev_samp", AggExpr::Stddev(nil));
+        parent.add_fn("grouping_id", SQLGroupingId);
+    }
+}
+
+/// `grouping_id()`, which refers to the column identifying the grouping set of each row of a
+/// GROUP BY with ROLLUP, CUBE or GROUPING SETS.
+pub struct SQLGroupingId;
+
+impl SQLFunction for SQLGroupingId {
+    fn to_expr(&self, inputs: &[FunctionArg], _: &SQLPlanner) -> SQLPlannerResult<ExprRef> {
+        ensure!(inputs.is_empty(), "grouping_id takes no arguments");
+        Ok(unresolved_col(GROUPING_ID_COLUMN))
+    }
+
+    fn docstrings(&self, _: &str) -> String {
+        static_docs::GROUPING_ID_DOCSTRING.to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &[]
     }
 }
 
@@ -127,6 +148,7 @@ fn to_expr(expr: &AggExpr, args: &[ExprRef]) -> SQLPlannerResult<ExprRef> {
         }
         AggExpr::ApproxCountDistinct(_) => unsupported_sql_err!("approx_percentile"),
         AggExpr::ApproxPercentile(_) => unsupported_sql_err!("approx_percentile"),
+        AggExpr::ApproxTopK(_, _) => unsupported_sql_err!("approx_top_k"),
         AggExpr::ApproxSketch(_, _) => unsupported_sql_err!("approx_sketch"),
         AggExpr::MergeSketch(_, _) => unsupported_sql_err!("merge_sketch"),
         AggExpr::Mean(_) => {
@@ -158,10 +180,53 @@ fn to_expr(expr: &AggExpr, args: &[ExprRef]) -> SQLPlannerResult<ExprRef> {
         AggExpr::Concat(_) => unsupported_sql_err!("concat"),
         AggExpr::MapGroups { .. } => unsupported_sql_err!("map_groups"),
         AggExpr::Set(_) => unsupported_sql_err!("set"),
+        AggExpr::Collect(_) => unsupported_sql_err!("collect"),
     }
 }
 
 mod static_docs {
+    pub(crate) const GROUPING_ID_DOCSTRING: &str =
+        "Identifies the grouping set of each row of a GROUP BY with ROLLUP, CUBE or GROUPING SETS.
+
+Each grouping key has a bit that is set when the row isn't grouped by it, with the first key as the most significant bit.
+
+Example:
+
+.. code-block:: sql
+    :caption: SQL
+
+    SELECT x, sum(y), grouping_id() FROM tbl GROUP BY ROLLUP(x)
+
+.. code-block:: text
+    :caption: Input
+
+    ╭───────┬───────╮
+    │ x     ┆ y     │
+    │ ---   ┆ ---   │
+    │ Int64 ┆ Int64 │
+    ╞═══════╪═══════╡
+    │ 1     ┆ 100   │
+    ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+    │ 2     ┆ 200   │
+    ╰───────┴───────╯
+    (Showing first 2 of 2 rows)
+
+.. code-block:: text
+    :caption: Output
+
+    ╭───────┬───────┬─────────────╮
+    │ x     ┆ y     ┆ grouping_id │
+    │ ---   ┆ ---   ┆ ---         │
+    │ Int64 ┆ Int64 ┆ Int64       │
+    ╞═══════╪═══════╪═════════════╡
+    │ 1     ┆ 100   ┆ 0           │
+    ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+    │ 2     ┆ 200   ┆ 0           │
+    ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+    │ null  ┆ 300   ┆ 1           │
+    ╰───────┴───────┴─────────────╯
+    (Showing first 3 of 3 rows)";
+
     pub(crate) const COUNT_DOCSTRING: &str =
         "Counts the number of non-null elements in the input expression.
 
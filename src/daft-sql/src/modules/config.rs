@@ -205,6 +205,7 @@ impl SQLFunction for AzureConfigFunction {
                 "tenant_id",
                 "client_id",
                 "client_secret",
+                "use_managed_identity",
                 "use_fabric_endpoint",
                 "anonymous",
                 "endpoint_url",
@@ -220,6 +221,7 @@ impl SQLFunction for AzureConfigFunction {
         let tenant_id = args.try_get_named::<String>("tenant_id")?;
         let client_id = args.try_get_named::<String>("client_id")?;
         let client_secret = args.try_get_named::<String>("client_secret")?;
+        let use_managed_identity = args.try_get_named::<bool>("use_managed_identity")?;
         let use_fabric_endpoint = args.try_get_named::<bool>("use_fabric_endpoint")?;
         let anonymous = args.try_get_named::<bool>("anonymous")?;
         let endpoint_url = args.try_get_named::<String>("endpoint_url")?;
@@ -237,6 +239,7 @@ impl SQLFunction for AzureConfigFunction {
             item!(tenant_id, Utf8),
             item!(client_id, Utf8),
             item!(client_secret, Utf8),
+            item!(use_managed_identity, Boolean),
             item!(use_fabric_endpoint, Boolean),
             item!(anonymous, Boolean),
             item!(endpoint_url, Utf8),
@@ -261,6 +264,7 @@ impl SQLFunction for AzureConfigFunction {
             "tenant_id",
             "client_id",
             "client_secret",
+            "use_managed_identity",
             "use_fabric_endpoint",
             "anonymous",
             "endpoint_url",
@@ -406,6 +410,7 @@ pub(crate) fn expr_to_iocfg(expr: &ExprRef) -> SQLPlannerResult<IOConfig> {
             let tenant_id = get_value!("tenant_id", Utf8)?;
             let client_id = get_value!("client_id", Utf8)?;
             let client_secret = get_value!("client_secret", Utf8)?;
+            let use_managed_identity = get_value!("use_managed_identity", Boolean)?;
             let use_fabric_endpoint = get_value!("use_fabric_endpoint", Boolean)?;
             let anonymous = get_value!("anonymous", Boolean)?;
             let endpoint_url = get_value!("endpoint_url", Utf8)?;
@@ -419,9 +424,12 @@ pub(crate) fn expr_to_iocfg(expr: &ExprRef) -> SQLPlannerResult<IOConfig> {
                     access_key: access_key.map(|s| s.into()),
                     sas_token,
                     bearer_token,
+                    token_provider: None,
                     tenant_id,
                     client_id,
                     client_secret: client_secret.map(|s| s.into()),
+                    use_managed_identity: use_managed_identity
+                        .unwrap_or(default.use_managed_identity),
                     use_fabric_endpoint: use_fabric_endpoint.unwrap_or(default.use_fabric_endpoint),
                     anonymous: anonymous.unwrap_or(default.anonymous),
                     endpoint_url,
@@ -448,6 +456,7 @@ pub(crate) fn expr_to_iocfg(expr: &ExprRef) -> SQLPlannerResult<IOConfig> {
                     project_id,
                     credentials: credentials.map(|s| s.into()),
                     token,
+                    token_provider: None,
                     anonymous: anonymous.unwrap_or(default.anonymous),
                     max_connections_per_io_thread: max_connections_per_io_thread
                         .unwrap_or(default.max_connections_per_io_thread),
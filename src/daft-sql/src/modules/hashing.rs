@@ -2,6 +2,7 @@ use daft_dsl::ExprRef;
 use daft_functions::{
     hash::hash,
     minhash::{minhash, MinHashFunction},
+    sequence::{monotonically_increasing_id, uuid, UuidVersion},
 };
 use sqlparser::ast::FunctionArg;
 
@@ -18,6 +19,8 @@ impl SQLModule for SQLModuleHashing {
     fn register(parent: &mut SQLFunctions) {
         parent.add_fn("hash", SQLHash);
         parent.add_fn("minhash", SQLMinhash);
+        parent.add_fn("uuid", SQLUuid);
+        parent.add_fn("monotonically_increasing_id", SQLMonotonicallyIncreasingId);
     }
 }
 
@@ -61,6 +64,63 @@ impl SQLFunction for SQLHash {
     }
 }
 
+pub struct SQLUuid;
+
+impl SQLFunction for SQLUuid {
+    fn to_expr(
+        &self,
+        inputs: &[FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        match inputs {
+            [] => Ok(uuid(UuidVersion::V4)),
+            [version] => {
+                let version = planner
+                    .plan_function_arg(version)?
+                    .as_literal()
+                    .and_then(daft_dsl::LiteralValue::as_i64)
+                    .ok_or_else(|| {
+                        PlannerError::invalid_operation("uuid version must be an integer")
+                    })?;
+                Ok(uuid(version.to_string().parse()?))
+            }
+            _ => unsupported_sql_err!("Invalid arguments for uuid: '{inputs:?}'"),
+        }
+    }
+
+    fn docstrings(&self, _: &str) -> String {
+        "Generates a random (version 4) or time-ordered (version 7) UUID string for every row."
+            .to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["version"]
+    }
+}
+
+pub struct SQLMonotonicallyIncreasingId;
+
+impl SQLFunction for SQLMonotonicallyIncreasingId {
+    fn to_expr(
+        &self,
+        inputs: &[FunctionArg],
+        _planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        match inputs {
+            [] => Ok(monotonically_increasing_id()),
+            _ => unsupported_sql_err!("monotonically_increasing_id takes no arguments"),
+        }
+    }
+
+    fn docstrings(&self, _: &str) -> String {
+        "Generates unique, monotonically increasing 64-bit ids, with the partition number in the upper 28 bits and the row number within the partition in the lower 36 bits.".to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+}
+
 pub struct SQLMinhash;
 
 impl TryFrom<SQLFunctionArguments> for MinHashFunction {
@@ -9,6 +9,7 @@ pub struct SQLModuleJson;
 impl SQLModule for SQLModuleJson {
     fn register(parent: &mut SQLFunctions) {
         parent.add_fn("json_query", JsonQuery);
+        parent.add_fn("json_extract", JsonExtract);
     }
 }
 
@@ -45,7 +46,43 @@ impl SQLFunction for JsonQuery {
     }
 }
 
+struct JsonExtract;
+
+impl SQLFunction for JsonExtract {
+    fn to_expr(
+        &self,
+        inputs: &[sqlparser::ast::FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> crate::error::SQLPlannerResult<daft_dsl::ExprRef> {
+        match inputs {
+            [input, path] => {
+                let input = planner.plan_function_arg(input)?;
+                let path = planner.plan_function_arg(path)?;
+                if let Some(p) = path.as_literal().and_then(|l| l.as_str()) {
+                    Ok(daft_functions_json::json_extract(input, p, None))
+                } else {
+                    invalid_operation_err!("Expected a string literal for the path argument")
+                }
+            }
+            _ => invalid_operation_err!(
+                "invalid arguments for json_extract. expected json_extract(input, path)"
+            ),
+        }
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        static_docs::JSON_EXTRACT_DOCSTRING.to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["input", "path"]
+    }
+}
+
 mod static_docs {
     pub(crate) const JSON_QUERY_DOCSTRING: &str =
-        "Extracts a JSON object from a JSON string using a JSONPath expression.";
+        "Extracts a JSON object from a JSON string using a JQ-style filter.";
+
+    pub(crate) const JSON_EXTRACT_DOCSTRING: &str =
+        "Extracts the value at a JSONPath expression, such as '$.a.b[0]', from a JSON string.";
 }
@@ -0,0 +1,58 @@
+use daft_dsl::ExprRef;
+use daft_functions::native_udf::native_udf;
+use sqlparser::ast::FunctionArg;
+
+use crate::{error::SQLPlannerResult, functions::SQLFunction, planner::SQLPlanner};
+
+/// A native Rust UDF, which is looked up by name in the registry of `daft_functions::native_udf`
+/// rather than registered with [`crate::functions::SQLFunctions`].
+pub(crate) struct SQLNativeUdf {
+    pub name: String,
+}
+
+impl SQLFunction for SQLNativeUdf {
+    fn to_expr(&self, inputs: &[FunctionArg], planner: &SQLPlanner) -> SQLPlannerResult<ExprRef> {
+        let inputs = self.args_to_expr_unnamed(inputs, planner)?;
+        Ok(native_udf(&self.name, inputs)?)
+    }
+
+    fn docstrings(&self, alias: &str) -> String {
+        format!("{alias}: Native Rust UDF")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_functions::native_udf::{register_native_udf, NativeScalarUdf};
+
+    use crate::sql_expr;
+
+    struct Identity;
+
+    impl NativeScalarUdf for Identity {
+        fn name(&self) -> &str {
+            "test_sql_identity"
+        }
+
+        fn return_field(&self, inputs: &[Field]) -> DaftResult<Field> {
+            Ok(inputs[0].clone())
+        }
+
+        fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+            Ok(inputs[0].clone())
+        }
+    }
+
+    #[test]
+    fn call_native_udf_by_name() -> DaftResult<()> {
+        assert!(sql_expr("test_sql_identity(a)").is_err());
+        register_native_udf(Arc::new(Identity))?;
+        let expr = sql_expr("TEST_SQL_IDENTITY(a)").unwrap();
+        assert_eq!(expr.to_string(), "test_sql_identity(col(a))");
+        Ok(())
+    }
+}
@@ -62,6 +62,7 @@ impl SQLModule for SQLModuleNumeric {
         parent.add_fn("atanh", SQLNumericExpr::ArcTanh);
         parent.add_fn("acosh", SQLNumericExpr::ArcCosh);
         parent.add_fn("asinh", SQLNumericExpr::ArcSinh);
+        parent.add_fn("bitwise_not", SQLNumericExpr::BitwiseNot);
     }
 }
 enum SQLNumericExpr {
@@ -100,6 +101,7 @@ enum SQLNumericExpr {
     ArcTanh,
     ArcCosh,
     ArcSinh,
+    BitwiseNot,
 }
 
 impl SQLFunction for SQLNumericExpr {
@@ -151,6 +153,7 @@ impl SQLFunction for SQLNumericExpr {
             Self::ArcTanh => "Calculates the inverse hyperbolic tangent of a number.",
             Self::ArcCosh => "Calculates the inverse hyperbolic cosine of a number.",
             Self::ArcSinh => "Calculates the inverse hyperbolic sine of a number.",
+            Self::BitwiseNot => "Inverts the bits of an integer.",
         };
         docstring.to_string()
     }
@@ -187,7 +190,8 @@ impl SQLFunction for SQLNumericExpr {
             | Self::Expm1
             | Self::ArcTanh
             | Self::ArcCosh
-            | Self::ArcSinh => &["input"],
+            | Self::ArcSinh
+            | Self::BitwiseNot => &["input"],
             Self::Log => &["input", "base"],
             Self::Round => &["input", "precision"],
             Self::ArcTan2 => &["y", "x"],
@@ -371,5 +375,9 @@ fn to_expr(expr: &SQLNumericExpr, args: &[ExprRef]) -> SQLPlannerResult<ExprRef>
             ensure!(args.len() == 1, "asinh takes exactly one argument");
             Ok(arcsinh(args[0].clone()))
         }
+        SQLNumericExpr::BitwiseNot => {
+            ensure!(args.len() == 1, "bitwise_not takes exactly one argument");
+            Ok(args[0].clone().not())
+        }
     }
 }
@@ -1,8 +1,10 @@
 use daft_core::prelude::TimeUnit;
 use daft_dsl::ExprRef;
 use daft_functions::temporal::{
-    dt_date, dt_day, dt_day_of_week, dt_day_of_year, dt_hour, dt_microsecond, dt_millisecond,
-    dt_minute, dt_month, dt_nanosecond, dt_second, dt_strftime, dt_time, dt_to_unix_epoch, dt_year,
+    calendar::{dt_date_add, dt_date_diff, dt_date_sub, make_date, make_timestamp},
+    dt_date, dt_day, dt_day_of_week, dt_day_of_year, dt_hour, dt_last_day_of_month, dt_microsecond,
+    dt_millisecond, dt_minute, dt_month, dt_nanosecond, dt_quarter, dt_second, dt_strftime,
+    dt_time, dt_to_unix_epoch, dt_week_of_year, dt_year,
 };
 use sqlparser::ast::FunctionArg;
 
@@ -40,6 +42,14 @@ impl SQLModule for SQLModuleTemporal {
         parent.add_fn("time", SQLTime);
         parent.add_fn("to_unix_epoch", SQLUnixTimestamp);
         parent.add_fn("strftime", SQLStrftime);
+        parent.add_fn("quarter", SQLQuarter);
+        parent.add_fn("week_of_year", SQLWeekOfYear);
+        parent.add_fn("last_day_of_month", SQLLastDayOfMonth);
+        parent.add_fn("date_add", SQLDateAdd);
+        parent.add_fn("date_sub", SQLDateSub);
+        parent.add_fn("date_diff", SQLDateDiff);
+        parent.add_fn("make_date", SQLMakeDate);
+        parent.add_fn("make_timestamp", SQLMakeTimestamp);
 
         // TODO: Add truncate
         // Our `dt_truncate` function has vastly different semantics than SQL `DATE_TRUNCATE` function.
@@ -95,6 +105,8 @@ temporal!(SQLMicrosecond, dt_microsecond);
 temporal!(SQLNanosecond, dt_nanosecond);
 temporal!(SQLYear, dt_year);
 temporal!(SQLTime, dt_time);
+temporal!(SQLQuarter, dt_quarter);
+temporal!(SQLWeekOfYear, dt_week_of_year);
 
 pub struct SQLUnixTimestamp;
 pub struct SQLStrftime;
@@ -170,3 +182,194 @@ impl SQLFunction for SQLStrftime {
         &["input", "format"]
     }
 }
+
+pub struct SQLLastDayOfMonth;
+
+impl SQLFunction for SQLLastDayOfMonth {
+    fn to_expr(
+        &self,
+        inputs: &[FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        match inputs {
+            [input] => Ok(dt_last_day_of_month(planner.plan_function_arg(input)?)),
+            _ => unsupported_sql_err!("Invalid arguments for last_day_of_month: '{inputs:?}'"),
+        }
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Returns the last day of the month of a date or datetime expression.".to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["input"]
+    }
+}
+
+fn plan_unit(
+    name: &str,
+    unit: &FunctionArg,
+    planner: &crate::planner::SQLPlanner,
+) -> SQLPlannerResult<String> {
+    let unit = planner.plan_function_arg(unit)?;
+    match unit.as_literal().and_then(|lit| lit.as_str()) {
+        Some(unit) => Ok(unit.to_string()),
+        None => unsupported_sql_err!("Expected the unit of {name} to be a string literal"),
+    }
+}
+
+pub struct SQLDateAdd;
+pub struct SQLDateSub;
+pub struct SQLDateDiff;
+
+impl SQLFunction for SQLDateAdd {
+    fn to_expr(
+        &self,
+        inputs: &[FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        match inputs {
+            [input, n, unit] => Ok(dt_date_add(
+                planner.plan_function_arg(input)?,
+                planner.plan_function_arg(n)?,
+                plan_unit("date_add", unit, planner)?,
+            )),
+            _ => unsupported_sql_err!("Invalid arguments for date_add: '{inputs:?}'"),
+        }
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Shifts a date or datetime expression forward by a number of units, such as 'day' or 'month'.".to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["input", "n", "unit"]
+    }
+}
+
+impl SQLFunction for SQLDateSub {
+    fn to_expr(
+        &self,
+        inputs: &[FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        match inputs {
+            [input, n, unit] => Ok(dt_date_sub(
+                planner.plan_function_arg(input)?,
+                planner.plan_function_arg(n)?,
+                plan_unit("date_sub", unit, planner)?,
+            )),
+            _ => unsupported_sql_err!("Invalid arguments for date_sub: '{inputs:?}'"),
+        }
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Shifts a date or datetime expression back by a number of units, such as 'day' or 'month'."
+            .to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["input", "n", "unit"]
+    }
+}
+
+impl SQLFunction for SQLDateDiff {
+    fn to_expr(
+        &self,
+        inputs: &[FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        match inputs {
+            [unit, start, end] => Ok(dt_date_diff(
+                planner.plan_function_arg(end)?,
+                planner.plan_function_arg(start)?,
+                plan_unit("date_diff", unit, planner)?,
+            )),
+            _ => unsupported_sql_err!("Invalid arguments for date_diff: '{inputs:?}'"),
+        }
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Returns the number of whole units from start to end.".to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["unit", "start", "end"]
+    }
+}
+
+pub struct SQLMakeDate;
+pub struct SQLMakeTimestamp;
+
+impl SQLFunction for SQLMakeDate {
+    fn to_expr(
+        &self,
+        inputs: &[FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        match inputs {
+            [year, month, day] => Ok(make_date(
+                planner.plan_function_arg(year)?,
+                planner.plan_function_arg(month)?,
+                planner.plan_function_arg(day)?,
+            )),
+            _ => unsupported_sql_err!("Invalid arguments for make_date: '{inputs:?}'"),
+        }
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Builds a date from its year, month and day.".to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["year", "month", "day"]
+    }
+}
+
+impl SQLFunction for SQLMakeTimestamp {
+    fn to_expr(
+        &self,
+        inputs: &[FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        let (parts, timezone) = match inputs {
+            [parts @ .., timezone] if inputs.len() == 7 => {
+                let timezone = planner.plan_function_arg(timezone)?;
+                let Some(timezone) = timezone.as_literal().and_then(|lit| lit.as_str()) else {
+                    unsupported_sql_err!(
+                        "Expected the timezone of make_timestamp to be a string literal"
+                    )
+                };
+                (parts, Some(timezone.to_string()))
+            }
+            parts if inputs.len() == 6 => (parts, None),
+            _ => unsupported_sql_err!("Invalid arguments for make_timestamp: '{inputs:?}'"),
+        };
+        let [year, month, day, hour, minute, second] = parts
+            .iter()
+            .map(|arg| planner.plan_function_arg(arg))
+            .collect::<SQLPlannerResult<Vec<_>>>()?
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("make_timestamp has 6 parts"));
+        Ok(make_timestamp(
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            timezone.as_deref(),
+        ))
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Builds a datetime from the parts of its local date and time, optionally in a timezone."
+            .to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &[
+            "year", "month", "day", "hour", "minute", "second", "timezone",
+        ]
+    }
+}
@@ -2,6 +2,7 @@ use super::SQLModule;
 use crate::functions::SQLFunctions;
 
 mod url_download;
+mod url_parse;
 mod url_upload;
 
 pub struct SQLModuleUri;
@@ -10,5 +11,10 @@ impl SQLModule for SQLModuleUri {
     fn register(parent: &mut SQLFunctions) {
         parent.add_fn("url_download", url_download::SqlUrlDownload);
         parent.add_fn("url_upload", url_upload::SqlUrlUpload);
+        parent.add_fn("url_scheme", url_parse::SqlUrlScheme);
+        parent.add_fn("url_host", url_parse::SqlUrlHost);
+        parent.add_fn("url_path", url_parse::SqlUrlPath);
+        parent.add_fn("url_query_param", url_parse::SqlUrlQueryParam);
+        parent.add_fn("url_decode", url_parse::SqlUrlDecode);
     }
 }
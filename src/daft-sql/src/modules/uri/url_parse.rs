@@ -0,0 +1,84 @@
+use daft_dsl::ExprRef;
+use daft_functions::uri::parse::{url_decode, url_host, url_path, url_query_param, url_scheme};
+use sqlparser::ast::FunctionArg;
+
+use crate::{error::SQLPlannerResult, functions::SQLFunction, unsupported_sql_err, SQLPlanner};
+
+macro_rules! url_part {
+    ($name:ident, $fn_name:ident, $docstring:literal) => {
+        pub struct $name;
+
+        impl SQLFunction for $name {
+            fn to_expr(
+                &self,
+                inputs: &[FunctionArg],
+                planner: &SQLPlanner,
+            ) -> SQLPlannerResult<ExprRef> {
+                match inputs {
+                    [input] => Ok($fn_name(planner.plan_function_arg(input)?)),
+                    _ => unsupported_sql_err!(
+                        "Invalid arguments for {}: '{inputs:?}'",
+                        stringify!($fn_name)
+                    ),
+                }
+            }
+
+            fn docstrings(&self, _alias: &str) -> String {
+                $docstring.to_string()
+            }
+
+            fn arg_names(&self) -> &'static [&'static str] {
+                &["input"]
+            }
+        }
+    };
+}
+
+url_part!(
+    SqlUrlScheme,
+    url_scheme,
+    "Extracts the scheme of a URL, or null if it is not a URL."
+);
+url_part!(
+    SqlUrlHost,
+    url_host,
+    "Extracts the host of a URL, or null if it has none."
+);
+url_part!(
+    SqlUrlPath,
+    url_path,
+    "Extracts the path of a URL, or null if it is not a URL."
+);
+url_part!(
+    SqlUrlDecode,
+    url_decode,
+    "Decodes the percent-encoded characters of a string."
+);
+
+pub struct SqlUrlQueryParam;
+
+impl SQLFunction for SqlUrlQueryParam {
+    fn to_expr(&self, inputs: &[FunctionArg], planner: &SQLPlanner) -> SQLPlannerResult<ExprRef> {
+        match inputs {
+            [input, name] => {
+                let input = planner.plan_function_arg(input)?;
+                let name = planner.plan_function_arg(name)?;
+                let Some(name) = name.as_literal().and_then(|lit| lit.as_str()) else {
+                    unsupported_sql_err!(
+                        "Expected the name passed to url_query_param to be a string literal"
+                    )
+                };
+                Ok(url_query_param(input, name))
+            }
+            _ => unsupported_sql_err!("Invalid arguments for url_query_param: '{inputs:?}'"),
+        }
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Extracts the decoded value of the first query parameter of a URL with the given name, or null if it has none.".to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["input", "name"]
+    }
+}
@@ -230,16 +230,6 @@ utf8_function!(
     "pattern"
 );
 
-utf8_function!(
-    SQLUtf8RegexpReplace,
-    "regexp_replace",
-    |input, pattern, replacement| daft_functions::utf8::replace(input, pattern, replacement, true),
-    "Replaces all occurrences of a substring with a new string",
-    "string_input",
-    "pattern",
-    "replacement"
-);
-
 utf8_function!(
     SQLUtf8RegexpSplit,
     "regexp_split",
@@ -365,6 +355,60 @@ utf8_function!(
     "count"
 );
 
+pub struct SQLUtf8RegexpReplace;
+
+impl SQLFunction for SQLUtf8RegexpReplace {
+    fn to_expr(
+        &self,
+        inputs: &[sqlparser::ast::FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        match inputs {
+            [input, pattern, replacement] => {
+                let input = planner.plan_function_arg(input)?;
+                let pattern = planner.plan_function_arg(pattern)?;
+                let replacement = planner.plan_function_arg(replacement)?;
+                Ok(daft_functions::utf8::replace(
+                    input,
+                    pattern,
+                    replacement,
+                    true,
+                    None,
+                ))
+            }
+            [input, pattern, replacement, max_replacements] => {
+                let input = planner.plan_function_arg(input)?;
+                let pattern = planner.plan_function_arg(pattern)?;
+                let replacement = planner.plan_function_arg(replacement)?;
+                let max_replacements = planner
+                    .plan_function_arg(max_replacements)?
+                    .as_literal()
+                    .and_then(LiteralValue::as_i64)
+                    .and_then(|n| usize::try_from(n).ok())
+                    .ok_or_else(|| {
+                        PlannerError::invalid_operation(format!("Expected a non-negative literal integer for the fourth argument of regexp_replace, found {max_replacements:?}"))
+                    })?;
+                Ok(daft_functions::utf8::replace(
+                    input,
+                    pattern,
+                    replacement,
+                    true,
+                    Some(max_replacements),
+                ))
+            }
+            _ => invalid_operation_err!("regexp_replace takes exactly three or four arguments"),
+        }
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Replaces the substrings that match the specified regular expression pattern with a new string, which can refer to capture groups as $1 or ${name}".to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["string_input", "pattern", "replacement", "max_replacements"]
+    }
+}
+
 pub struct SQLUtf8RegexpExtract;
 
 impl SQLFunction for SQLUtf8RegexpExtract {
@@ -0,0 +1,147 @@
+use daft_dsl::{Expr, ExprRef};
+use sqlparser::ast::FunctionArg;
+
+use super::SQLModule;
+use crate::{
+    ensure,
+    error::SQLPlannerResult,
+    functions::{SQLFunction, SQLFunctions},
+    invalid_operation_err,
+    planner::SQLPlanner,
+};
+
+/// Functions that are only valid in windows, with `OVER`. Aggregations can also be used in windows.
+pub struct SQLModuleWindow;
+
+impl SQLModule for SQLModuleWindow {
+    fn register(parent: &mut SQLFunctions) {
+        parent.add_fn("row_number", SQLRanking::RowNumber);
+        parent.add_fn("rank", SQLRanking::Rank);
+        parent.add_fn("dense_rank", SQLRanking::DenseRank);
+        parent.add_fn("lag", SQLOffset { lead: false });
+        parent.add_fn("lead", SQLOffset { lead: true });
+    }
+}
+
+/// `row_number()`, `rank()` and `dense_rank()`, which number the rows of each window in its order.
+pub enum SQLRanking {
+    RowNumber,
+    Rank,
+    DenseRank,
+}
+
+impl SQLFunction for SQLRanking {
+    fn to_expr(&self, inputs: &[FunctionArg], _: &SQLPlanner) -> SQLPlannerResult<ExprRef> {
+        ensure!(inputs.is_empty(), "ranking functions take no arguments");
+        Ok(match self {
+            Self::RowNumber => Expr::row_number(),
+            Self::Rank => Expr::rank(),
+            Self::DenseRank => Expr::dense_rank(),
+        })
+    }
+
+    fn docstrings(&self, _: &str) -> String {
+        match self {
+            Self::RowNumber => static_docs::ROW_NUMBER_DOCSTRING,
+            Self::Rank => static_docs::RANK_DOCSTRING,
+            Self::DenseRank => static_docs::DENSE_RANK_DOCSTRING,
+        }
+        .to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+}
+
+/// `lag(input[, offset[, default]])` and `lead(input[, offset[, default]])`, which are the value of `input` at
+/// `offset` rows before or after the current row of the window.
+pub struct SQLOffset {
+    lead: bool,
+}
+
+impl SQLFunction for SQLOffset {
+    fn to_expr(&self, inputs: &[FunctionArg], planner: &SQLPlanner) -> SQLPlannerResult<ExprRef> {
+        let inputs = self.args_to_expr_unnamed(inputs, planner)?;
+        let (input, offset, default) = match inputs.as_slice() {
+            [input] => (input, None, None),
+            [input, offset] => (input, Some(offset), None),
+            [input, offset, default] => (input, Some(offset), Some(default)),
+            _ => invalid_operation_err!("lag and lead take between one and three arguments"),
+        };
+        let offset = match offset {
+            Some(offset) => offset.as_literal().and_then(|lit| lit.as_i64()),
+            None => Some(1),
+        };
+        let Some(offset) = offset.filter(|offset| *offset >= 0) else {
+            invalid_operation_err!("the offset of lag and lead must be a non-negative integer")
+        };
+        let offset = if self.lead { offset } else { -offset };
+        Ok(input.clone().offset(offset as isize, default.cloned()))
+    }
+
+    fn docstrings(&self, _: &str) -> String {
+        if self.lead {
+            static_docs::LEAD_DOCSTRING
+        } else {
+            static_docs::LAG_DOCSTRING
+        }
+        .to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["input", "offset", "default"]
+    }
+}
+
+mod static_docs {
+    pub(crate) const ROW_NUMBER_DOCSTRING: &str =
+        "Numbers the rows of each window from 1, in its order.
+
+Example:
+
+.. code-block:: sql
+    :caption: SQL
+
+    SELECT name, row_number() OVER (PARTITION BY team ORDER BY score DESC) AS place FROM scores";
+
+    pub(crate) const RANK_DOCSTRING: &str =
+        "Ranks the rows of each window in its order, giving ties the same rank and skipping the ranks after them.
+
+Example:
+
+.. code-block:: sql
+    :caption: SQL
+
+    SELECT name, rank() OVER (PARTITION BY team ORDER BY score DESC) AS place FROM scores";
+
+    pub(crate) const DENSE_RANK_DOCSTRING: &str =
+        "Ranks the rows of each window in its order, giving ties the same rank without skipping the ranks after them.
+
+Example:
+
+.. code-block:: sql
+    :caption: SQL
+
+    SELECT name, dense_rank() OVER (PARTITION BY team ORDER BY score DESC) AS place FROM scores";
+
+    pub(crate) const LAG_DOCSTRING: &str =
+        "The value of the input `offset` rows before the current row of the window, or `default` if there is no such row. The offset defaults to 1.
+
+Example:
+
+.. code-block:: sql
+    :caption: SQL
+
+    SELECT day, price - lag(price) OVER (ORDER BY day) AS change FROM prices";
+
+    pub(crate) const LEAD_DOCSTRING: &str =
+        "The value of the input `offset` rows after the current row of the window, or `default` if there is no such row. The offset defaults to 1.
+
+Example:
+
+.. code-block:: sql
+    :caption: SQL
+
+    SELECT day, lead(price, 1, 0) OVER (ORDER BY day) AS next_price FROM prices";
+}
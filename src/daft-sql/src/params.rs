@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use daft_dsl::LiteralValue;
+use sqlparser::tokenizer::Token;
+
+use crate::{error::SQLPlannerResult, invalid_operation_err};
+
+/// Values bound to the parameters of a SQL statement, which become literals in its plan.
+///
+/// Positional parameters are `?`, numbered in the order they appear, or `$1`, `$2`, etc., and named parameters are
+/// `:name`.
+#[derive(Debug, Clone, Default)]
+pub struct SQLParams {
+    pub positional: Vec<LiteralValue>,
+    pub named: HashMap<String, LiteralValue>,
+}
+
+impl SQLParams {
+    pub fn positional(values: Vec<LiteralValue>) -> Self {
+        Self {
+            positional: values,
+            named: HashMap::new(),
+        }
+    }
+
+    pub fn named(values: HashMap<String, LiteralValue>) -> Self {
+        Self {
+            positional: vec![],
+            named: values,
+        }
+    }
+
+    /// Returns the value bound to a placeholder of the statement.
+    pub(crate) fn get(&self, placeholder: &str) -> SQLPlannerResult<LiteralValue> {
+        let value = if let Some(name) = placeholder.strip_prefix(':') {
+            self.named.get(name)
+        } else if let Some(index) = placeholder
+            .strip_prefix('?')
+            .or_else(|| placeholder.strip_prefix('$'))
+            .and_then(|index| index.parse::<usize>().ok())
+        {
+            index.checked_sub(1).and_then(|i| self.positional.get(i))
+        } else {
+            invalid_operation_err!(
+                "Unsupported parameter {}, expected `?`, `$<n>` or `:<name>`",
+                placeholder
+            )
+        };
+        match value {
+            Some(value) => Ok(value.clone()),
+            None => invalid_operation_err!("No value is bound to parameter {}", placeholder),
+        }
+    }
+}
+
+/// Numbers the `?` placeholders of a statement in the order they appear, as `?1`, `?2`, etc., since they are planned
+/// in another order.
+pub(crate) fn number_placeholders(tokens: Vec<Token>) -> Vec<Token> {
+    let mut count = 0;
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            Token::Placeholder(placeholder) if placeholder == "?" => {
+                count += 1;
+                Token::Placeholder(format!("?{count}"))
+            }
+            token => token,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_dsl::LiteralValue;
+
+    use super::SQLParams;
+
+    #[test]
+    fn test_get_params() {
+        let params = SQLParams {
+            positional: vec![LiteralValue::Int64(1), LiteralValue::Utf8("a".to_string())],
+            named: [("x".to_string(), LiteralValue::Boolean(true))].into(),
+        };
+        assert_eq!(
+            params.get("?2").unwrap(),
+            LiteralValue::Utf8("a".to_string())
+        );
+        assert_eq!(params.get("$1").unwrap(), LiteralValue::Int64(1));
+        assert_eq!(params.get(":x").unwrap(), LiteralValue::Boolean(true));
+        assert!(params.get("?3").is_err());
+        assert!(params.get("$0").is_err());
+        assert!(params.get(":y").is_err());
+        assert!(params.get("@x").is_err());
+    }
+}
@@ -18,7 +18,7 @@ use daft_functions::{
     utf8::{ilike, like, to_date, to_datetime},
 };
 use daft_logical_plan::{
-    ops::{SetQuantifier, UnionStrategy},
+    ops::{self, SetQuantifier, UnionStrategy},
     JoinOptions, LogicalPlanBuilder, LogicalPlanRef,
 };
 use daft_session::Session;
@@ -36,8 +36,13 @@ use sqlparser::{
 };
 
 use crate::{
-    column_not_found_err, error::*, invalid_operation_err, schema::sql_dtype_to_dtype,
-    statement::Statement, table_not_found_err, unsupported_sql_err,
+    column_not_found_err,
+    error::*,
+    invalid_operation_err,
+    params::{number_placeholders, SQLParams},
+    schema::sql_dtype_to_dtype,
+    statement::Statement,
+    table_not_found_err, unsupported_sql_err,
 };
 
 /// Bindings are used to lookup in-scope tables, views, and columns (targets T).
@@ -69,10 +74,10 @@ impl<T> Default for Bindings<T> {
     }
 }
 
-struct OrderByExprs {
-    exprs: Vec<ExprRef>,
-    descending: Vec<bool>,
-    nulls_first: Vec<bool>,
+pub(crate) struct OrderByExprs {
+    pub(crate) exprs: Vec<ExprRef>,
+    pub(crate) descending: Vec<bool>,
+    pub(crate) nulls_first: Vec<bool>,
 }
 
 /// Context for the planning the statement.
@@ -86,6 +91,8 @@ pub(crate) struct PlannerContext {
     pub(crate) session: Rc<Session>,
     /// Bindings for common table expressions (cte).
     bound_ctes: Bindings<LogicalPlanBuilder>,
+    /// Values bound to the parameters of the statement.
+    params: SQLParams,
 }
 
 impl PlannerContext {
@@ -94,6 +101,7 @@ impl PlannerContext {
         Self {
             session,
             bound_ctes: Bindings::default(),
+            params: SQLParams::default(),
         }
     }
 
@@ -134,6 +142,12 @@ impl<'a> SQLPlanner<'a> {
         }
     }
 
+    /// Binds values to the parameters of the statements that this planner plans.
+    pub fn with_params(self, params: SQLParams) -> Self {
+        self.context_mut().params = params;
+        self
+    }
+
     fn new_child(&'a self) -> Self {
         Self {
             context: self.context.clone(),
@@ -168,7 +182,7 @@ impl<'a> SQLPlanner<'a> {
         Ok(())
     }
 
-    fn current_plan_ref(&self) -> &LogicalPlanBuilder {
+    pub(crate) fn current_plan_ref(&self) -> &LogicalPlanBuilder {
         self.current_plan.as_ref().expect("current plan is set")
     }
 
@@ -188,7 +202,7 @@ impl<'a> SQLPlanner<'a> {
     }
 
     /// Borrow the planning session
-    fn session(&self) -> Ref<'_, Rc<Session>> {
+    pub(crate) fn session(&self) -> Ref<'_, Rc<Session>> {
         Ref::map(self.context.borrow(), |i| &i.session)
     }
 
@@ -225,6 +239,7 @@ impl<'a> SQLPlanner<'a> {
 
     pub fn plan(&mut self, input: &str) -> SQLPlannerResult<Statement> {
         let tokens = Tokenizer::new(&GenericDialect {}, input).tokenize()?;
+        let tokens = number_placeholders(tokens);
 
         let mut parser = Parser::new(&GenericDialect {})
             .with_options(ParserOptions {
@@ -244,9 +259,10 @@ impl<'a> SQLPlanner<'a> {
 
         // plan single statement
         let stmt = &statements[0];
-        let stmt = self.plan_statement(stmt)?;
+        let stmt = self.plan_statement(stmt);
+        // clear the context even on error, as the planner can be reused for other statements
         self.clear_context();
-        Ok(stmt)
+        stmt
     }
 
     pub fn plan_sql(&mut self, sql: &str) -> SQLPlannerResult<LogicalPlanRef> {
@@ -354,7 +370,7 @@ impl<'a> SQLPlanner<'a> {
 
         // GROUP BY
         let mut groupby_exprs = Vec::new();
-        let mut rollup = false;
+        let mut grouping_sets = None;
 
         match &selection.group_by {
             GroupByExpr::All(s) => {
@@ -363,23 +379,23 @@ impl<'a> SQLPlanner<'a> {
                 }
             }
             GroupByExpr::Expressions(expressions, _) => {
-                groupby_exprs = match expressions.as_slice() {
-                    [sqlparser::ast::Expr::Rollup(exprs)] => {
-                        rollup = true;
-                        let mut out = Vec::new();
-                        for expr_list in exprs {
-                            if expr_list.len() > 1 {
-                                unsupported_sql_err!("nested ROLLUP's are not supported");
-                            }
-                            out.push(self.plan_expr(&expr_list[0])?);
-                        }
-                        out
-                    }
-                    exprs => exprs
+                if expressions.iter().any(|expr| {
+                    matches!(
+                        expr,
+                        sqlparser::ast::Expr::Rollup(_)
+                            | sqlparser::ast::Expr::Cube(_)
+                            | sqlparser::ast::Expr::GroupingSets(_)
+                    )
+                }) {
+                    let (exprs, sets) = self.plan_grouping_sets(expressions)?;
+                    groupby_exprs = exprs;
+                    grouping_sets = Some(sets);
+                } else {
+                    groupby_exprs = expressions
                         .iter()
                         .map(|expr| self.plan_expr(expr))
-                        .collect::<SQLPlannerResult<Vec<_>>>()?,
-                };
+                        .collect::<SQLPlannerResult<Vec<_>>>()?;
+                }
             }
         }
 
@@ -405,7 +421,7 @@ impl<'a> SQLPlanner<'a> {
                 .map(|h| self.plan_expr(h))
                 .transpose()?;
 
-            self.plan_aggregate_query(projections, order_by, groupby_exprs, having, rollup)?;
+            self.plan_aggregate_query(projections, order_by, groupby_exprs, having, grouping_sets)?;
         } else {
             self.plan_non_agg_query(projections, order_by)?;
         }
@@ -458,7 +474,7 @@ impl<'a> SQLPlanner<'a> {
         order_by: Option<OrderByExprs>,
         groupby_exprs: Vec<Arc<Expr>>,
         having: Option<Arc<Expr>>,
-        rollup: bool,
+        grouping_sets: Option<Vec<Vec<usize>>>,
     ) -> Result<(), PlannerError> {
         let mut aggs = HashSet::new();
 
@@ -521,41 +537,14 @@ impl<'a> SQLPlanner<'a> {
             },
         );
 
-        if rollup {
-            let mut plans = Vec::new();
-            for i in (0..=groupby_exprs.len()).rev() {
-                let current_groups = groupby_exprs[0..i].to_vec();
-
-                let next_agg = self
-                    .current_plan_ref()
-                    .clone()
-                    .aggregate(aggs.clone().into_iter().collect(), current_groups.clone())?;
-                plans.push(next_agg);
-            }
-            let mut plans = plans.into_iter();
-            let first = plans.next().unwrap();
-            let first_schema = first.schema();
-            self.update_plan(|_| SQLPlannerResult::Ok(first))?;
-
-            for next in plans {
-                let next_schema = next.schema();
-                let nulled_cols = first_schema
-                    .into_iter()
-                    .map(|f| {
-                        if next_schema.has_field(&f.name) {
-                            unresolved_col(f.name.clone())
-                        } else {
-                            null_lit().alias(f.name.clone()).cast(&f.dtype)
-                        }
-                    })
-                    .collect::<Vec<_>>();
-
-                let next = next.select(nulled_cols)?;
-
-                self.update_plan(|plan| {
-                    plan.union(&next, SetQuantifier::All, UnionStrategy::Positional)
-                })?;
-            }
+        if let Some(grouping_sets) = grouping_sets {
+            self.update_plan(|plan| {
+                plan.aggregate_grouping_sets(
+                    aggs.into_iter().collect(),
+                    groupby_exprs,
+                    grouping_sets,
+                )
+            })?;
         } else {
             self.update_plan(|plan| plan.aggregate(aggs.into_iter().collect(), groupby_exprs))?;
         }
@@ -577,7 +566,67 @@ impl<'a> SQLPlanner<'a> {
         Ok(())
     }
 
-    fn plan_order_by_exprs(
+    /// Plans a GROUP BY with ROLLUP, CUBE or GROUPING SETS elements into the distinct grouping
+    /// keys and the grouping sets over them, each given as indices into the keys.
+    ///
+    /// The grouping sets of several GROUP BY elements are the cross product of the sets of each
+    /// element, so `GROUP BY a, ROLLUP(b, c)` groups by `(a, b, c)`, `(a, b)` and `(a)`.
+    fn plan_grouping_sets(
+        &self,
+        expressions: &[sqlparser::ast::Expr],
+    ) -> SQLPlannerResult<(Vec<ExprRef>, Vec<Vec<usize>>)> {
+        let mut keys: Vec<ExprRef> = Vec::new();
+        let mut key_index = |expr: &sqlparser::ast::Expr| -> SQLPlannerResult<usize> {
+            let expr = self.plan_expr(expr)?;
+            Ok(match keys.iter().position(|key| *key == expr) {
+                Some(idx) => idx,
+                None => {
+                    keys.push(expr);
+                    keys.len() - 1
+                }
+            })
+        };
+
+        let mut sets: Vec<Vec<usize>> = vec![vec![]];
+        for expr in expressions {
+            // the grouping sets of this element, each as indices into the keys
+            let element_sets = match expr {
+                sqlparser::ast::Expr::Rollup(lists) | sqlparser::ast::Expr::Cube(lists) => {
+                    let elements = lists
+                        .iter()
+                        .map(|list| list.iter().map(&mut key_index).collect())
+                        .collect::<SQLPlannerResult<Vec<Vec<_>>>>()?;
+                    let subsets = if matches!(expr, sqlparser::ast::Expr::Rollup(_)) {
+                        ops::rollup(elements.len())
+                    } else {
+                        ops::cube(elements.len())
+                    };
+                    subsets
+                        .into_iter()
+                        .map(|subset| {
+                            subset
+                                .into_iter()
+                                .flat_map(|idx| elements[idx].clone())
+                                .collect()
+                        })
+                        .collect()
+                }
+                sqlparser::ast::Expr::GroupingSets(lists) => lists
+                    .iter()
+                    .map(|list| list.iter().map(&mut key_index).collect())
+                    .collect::<SQLPlannerResult<Vec<Vec<_>>>>()?,
+                expr => vec![vec![key_index(expr)?]],
+            };
+            sets = sets
+                .iter()
+                .cartesian_product(&element_sets)
+                .map(|(set, element_set)| set.iter().chain(element_set).copied().collect())
+                .collect();
+        }
+        Ok((keys, sets))
+    }
+
+    pub(crate) fn plan_order_by_exprs(
         &self,
         expr: &[sqlparser::ast::OrderByExpr],
     ) -> SQLPlannerResult<OrderByExprs> {
@@ -801,11 +850,31 @@ impl<'a> SQLPlanner<'a> {
             sqlparser::ast::TableFactor::NestedJoin { .. } => {
                 unsupported_sql_err!("Unsupported table factor: NestedJoin")
             }
-            sqlparser::ast::TableFactor::Pivot { .. } => {
-                unsupported_sql_err!("Unsupported table factor: Pivot")
+            sqlparser::ast::TableFactor::Pivot {
+                table,
+                aggregate_functions,
+                value_column,
+                value_source,
+                default_on_null,
+                alias,
+            } => {
+                if default_on_null.is_some() {
+                    unsupported_sql_err!("PIVOT with DEFAULT ON NULL");
+                }
+                let input = self.plan_relation(table)?;
+                let plan =
+                    self.plan_pivot(input, aggregate_functions, value_column, value_source)?;
+                (plan, alias)
             }
-            sqlparser::ast::TableFactor::Unpivot { .. } => {
-                unsupported_sql_err!("Unsupported table factor: Unpivot")
+            sqlparser::ast::TableFactor::Unpivot {
+                table,
+                value,
+                name,
+                columns,
+                alias,
+            } => {
+                let input = self.plan_relation(table)?;
+                (self.plan_unpivot(input, value, name, columns)?, alias)
             }
             sqlparser::ast::TableFactor::MatchRecognize { .. } => {
                 unsupported_sql_err!("Unsupported table factor: MatchRecognize")
@@ -818,6 +887,120 @@ impl<'a> SQLPlanner<'a> {
         }
     }
 
+    /// Plan `<input> PIVOT(<agg> FOR <column> IN (<values>))`, which groups by every column of
+    /// the input other than the pivot column and the aggregated ones.
+    fn plan_pivot(
+        &self,
+        input: LogicalPlanBuilder,
+        aggregate_functions: &[ast::ExprWithAlias],
+        value_column: &[Ident],
+        value_source: &ast::PivotValueSource,
+    ) -> SQLPlannerResult<LogicalPlanBuilder> {
+        let [agg_function] = aggregate_functions else {
+            unsupported_sql_err!("PIVOT with more than one aggregation");
+        };
+        if agg_function.alias.is_some() {
+            unsupported_sql_err!("PIVOT with an aggregation alias");
+        }
+        let [pivot_column] = value_column else {
+            unsupported_sql_err!("PIVOT on more than one column");
+        };
+        let ast::PivotValueSource::List(values) = value_source else {
+            unsupported_sql_err!("PIVOT without an explicit list of values");
+        };
+
+        let mut planner = self.new_with_context();
+        planner.set_plan(input.clone());
+        let agg_expr = planner.plan_expr(&agg_function.expr)?;
+        let value_column = match agg_expr.as_ref() {
+            Expr::Agg(agg) => match agg.children().as_slice() {
+                [child] => child.clone(),
+                _ => unsupported_sql_err!("PIVOT with aggregation {agg_expr}"),
+            },
+            _ => invalid_operation_err!("PIVOT requires an aggregation, instead got: {agg_expr}"),
+        };
+        let pivot_column =
+            planner.plan_expr(&sqlparser::ast::Expr::Identifier(pivot_column.clone()))?;
+
+        // pivoted columns are named by the string form of their value, as during execution
+        let mut names = Vec::with_capacity(values.len());
+        let mut aliases = HashMap::new();
+        for value in values {
+            let expr = planner.plan_expr(&value.expr)?;
+            let Expr::Literal(literal) = expr.as_ref() else {
+                invalid_operation_err!("PIVOT values must be literals, instead got: {expr}");
+            };
+            let name = literals_to_series(&[literal.clone()])?
+                .to_str_values()?
+                .utf8()?
+                .get(0)
+                .ok_or_else(|| PlannerError::invalid_operation("PIVOT values must not be null"))?
+                .to_string();
+            if let Some(alias) = &value.alias {
+                aliases.insert(name.clone(), alias.value.clone());
+            }
+            names.push(name);
+        }
+
+        fn column_names(expr: &ExprRef, names: &mut HashSet<String>) {
+            if let Expr::Column(_) = expr.as_ref() {
+                names.insert(expr.name().to_string());
+            }
+            for child in expr.children() {
+                column_names(&child, names);
+            }
+        }
+        let mut excluded = HashSet::from([pivot_column.name().to_string()]);
+        column_names(&agg_expr, &mut excluded);
+        let group_by = input
+            .schema()
+            .field_names()
+            .filter(|name| !excluded.contains(*name))
+            .map(unresolved_col)
+            .collect();
+
+        let plan = input.pivot(group_by, pivot_column, value_column, agg_expr, names)?;
+        if aliases.is_empty() {
+            return Ok(plan);
+        }
+        let projection = plan
+            .schema()
+            .field_names()
+            .map(|name| match aliases.get(name) {
+                Some(alias) => unresolved_col(name).alias(alias.as_str()),
+                None => unresolved_col(name),
+            })
+            .collect();
+        Ok(plan.select(projection)?)
+    }
+
+    /// Plan `<input> UNPIVOT(<value> FOR <name> IN (<columns>))`, which keeps every other column
+    /// of the input as an id.
+    fn plan_unpivot(
+        &self,
+        input: LogicalPlanBuilder,
+        value: &Ident,
+        name: &Ident,
+        columns: &[Ident],
+    ) -> SQLPlannerResult<LogicalPlanBuilder> {
+        let mut planner = self.new_with_context();
+        planner.set_plan(input.clone());
+        let values = columns
+            .iter()
+            .map(|column| planner.plan_expr(&sqlparser::ast::Expr::Identifier(column.clone())))
+            .collect::<SQLPlannerResult<Vec<_>>>()?;
+
+        let value_names = values.iter().map(|v| v.name()).collect::<HashSet<_>>();
+        let ids = input
+            .schema()
+            .field_names()
+            .filter(|name| !value_names.contains(name))
+            .map(unresolved_col)
+            .collect();
+
+        Ok(input.unpivot(ids, values, name.value.clone(), value.value.clone())?)
+    }
+
     /// Plan a `FROM <path>` table factor by rewriting to relevant table-value function.
     fn plan_relation_path(&self, path: &str) -> SQLPlannerResult<LogicalPlanBuilder> {
         let func = match Path::new(path).extension() {
@@ -1078,7 +1261,7 @@ impl<'a> SQLPlanner<'a> {
         }
     }
 
-    fn column_to_field(&self, column_def: &ColumnDef) -> SQLPlannerResult<Field> {
+    pub(crate) fn column_to_field(&self, column_def: &ColumnDef) -> SQLPlannerResult<Field> {
         let ColumnDef {
             name,
             data_type,
@@ -1113,6 +1296,7 @@ impl<'a> SQLPlanner<'a> {
                 })?,
             Value::Boolean(b) => LiteralValue::Boolean(*b),
             Value::Null => LiteralValue::Null,
+            Value::Placeholder(placeholder) => self.context.borrow().params.get(placeholder)?,
             _ => {
                 return Err(PlannerError::invalid_operation(
                     format!("Only string, number, boolean and null literals are supported. Instead found: `{value}`"),
@@ -1445,89 +1629,6 @@ impl<'a> SQLPlanner<'a> {
                 Ok(Expr::List(items).into())
             }
             SQLExpr::Interval(interval) => {
-                use regex::Regex;
-
-                /// A private struct represents a single parsed interval unit and its value
-                #[derive(Debug)]
-                struct IntervalPart {
-                    count: i64,
-                    unit: DateTimeField,
-                }
-
-                // Local function to parse interval string to interval parts
-                fn parse_interval_string(expr: &str) -> Result<Vec<IntervalPart>, PlannerError> {
-                    let expr = expr.trim().trim_matches('\'');
-
-                    let re = Regex::new(r"(-?\d+)\s*(year|years|month|months|day|days|hour|hours|minute|minutes|second|seconds|millisecond|milliseconds|microsecond|microseconds|nanosecond|nanoseconds|week|weeks)")
-                        .map_err(|e|PlannerError::invalid_operation(format!("Invalid regex pattern: {}", e)))?;
-
-                    let mut parts = Vec::new();
-
-                    for cap in re.captures_iter(expr) {
-                        let count: i64 = cap[1].parse().map_err(|e| {
-                            PlannerError::invalid_operation(format!("Invalid interval count: {e}"))
-                        })?;
-
-                        let unit = match &cap[2].to_lowercase()[..] {
-                            "year" | "years" => DateTimeField::Year,
-                            "month" | "months" => DateTimeField::Month,
-                            "week" | "weeks" => DateTimeField::Week(None),
-                            "day" | "days" => DateTimeField::Day,
-                            "hour" | "hours" => DateTimeField::Hour,
-                            "minute" | "minutes" => DateTimeField::Minute,
-                            "second" | "seconds" => DateTimeField::Second,
-                            "millisecond" | "milliseconds" => DateTimeField::Millisecond,
-                            "microsecond" | "microseconds" => DateTimeField::Microsecond,
-                            "nanosecond" | "nanoseconds" => DateTimeField::Nanosecond,
-                            _ => {
-                                return Err(PlannerError::invalid_operation(format!(
-                                    "Invalid interval unit: {}",
-                                    &cap[2]
-                                )))
-                            }
-                        };
-
-                        parts.push(IntervalPart { count, unit });
-                    }
-
-                    if parts.is_empty() {
-                        return Err(PlannerError::invalid_operation("Invalid interval format."));
-                    }
-
-                    Ok(parts)
-                }
-
-                // Local function to convert parts to interval values
-                fn interval_parts_to_values(parts: Vec<IntervalPart>) -> (i64, i64, i64) {
-                    let mut total_months = 0i64;
-                    let mut total_days = 0i64;
-                    let mut total_nanos = 0i64;
-
-                    for part in parts {
-                        match part.unit {
-                            DateTimeField::Year => total_months += 12 * part.count,
-                            DateTimeField::Month => total_months += part.count,
-                            DateTimeField::Week(_) => total_days += 7 * part.count,
-                            DateTimeField::Day => total_days += part.count,
-                            DateTimeField::Hour => total_nanos += part.count * 3_600_000_000_000,
-                            DateTimeField::Minute => total_nanos += part.count * 60_000_000_000,
-                            DateTimeField::Second => total_nanos += part.count * 1_000_000_000,
-                            DateTimeField::Millisecond | DateTimeField::Milliseconds => {
-                                total_nanos += part.count * 1_000_000;
-                            }
-                            DateTimeField::Microsecond | DateTimeField::Microseconds => {
-                                total_nanos += part.count * 1_000;
-                            }
-                            DateTimeField::Nanosecond | DateTimeField::Nanoseconds => {
-                                total_nanos += part.count;
-                            }
-                            _ => {}
-                        }
-                    }
-
-                    (total_months, total_days, total_nanos)
-                }
-
                 match interval {
                     // If leading_field is specified, treat it as the old style single-unit interval
                     // e.g., INTERVAL '12' YEAR
@@ -1594,18 +1695,13 @@ impl<'a> SQLPlanner<'a> {
                                     )
                                 })?;
 
-                        let parts = parse_interval_string(expr)
+                        let interval = expr
+                            .trim()
+                            .trim_matches('\'')
+                            .parse::<daft_core::datatypes::IntervalValue>()
                             .map_err(|e| PlannerError::invalid_operation(e.to_string()))?;
 
-                        let (months, days, nanoseconds) = interval_parts_to_values(parts);
-
-                        Ok(Arc::new(Expr::Literal(LiteralValue::Interval(
-                            daft_core::datatypes::IntervalValue::new(
-                                months as i32,
-                                days as i32,
-                                nanoseconds,
-                            ),
-                        ))))
+                        Ok(Arc::new(Expr::Literal(LiteralValue::Interval(interval))))
                     }
                 }
             }
@@ -1638,6 +1734,11 @@ impl<'a> SQLPlanner<'a> {
             BinaryOperator::And => Ok(Operator::And),
             BinaryOperator::Or => Ok(Operator::Or),
             BinaryOperator::DuckIntegerDivide => Ok(Operator::FloorDivide),
+            BinaryOperator::BitwiseAnd => Ok(Operator::And),
+            BinaryOperator::BitwiseOr => Ok(Operator::Or),
+            BinaryOperator::BitwiseXor => Ok(Operator::Xor),
+            BinaryOperator::PGBitwiseShiftLeft => Ok(Operator::ShiftLeft),
+            BinaryOperator::PGBitwiseShiftRight => Ok(Operator::ShiftRight),
             other => unsupported_sql_err!("Unsupported operator: '{other}'"),
         }
     }
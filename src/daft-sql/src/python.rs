@@ -6,10 +6,11 @@ use daft_core::python::PyDataType;
 use daft_dsl::python::PyExpr;
 use daft_logical_plan::{LogicalPlan, LogicalPlanBuilder, PyLogicalPlanBuilder};
 use daft_session::python::PySession;
-use pyo3::{prelude::*, IntoPyObjectExt};
+use pyo3::{exceptions::PyValueError, prelude::*, IntoPyObjectExt};
 
 use crate::{
-    exec::execute_statement, functions::SQL_FUNCTIONS, planner::SQLPlanner, schema::try_parse_dtype,
+    exec::execute_statement, functions::SQL_FUNCTIONS, planner::SQLPlanner,
+    schema::try_parse_dtype, SQLParams,
 };
 
 #[pyclass]
@@ -37,15 +38,44 @@ impl SQLFunctionStub {
     }
 }
 
+/// Converts the literal expressions bound to parameters from python into SQL parameters.
+fn sql_params(
+    positional: Option<Vec<PyExpr>>,
+    named: Option<HashMap<String, PyExpr>>,
+) -> PyResult<SQLParams> {
+    let to_literal = |expr: PyExpr| match expr.expr.as_literal() {
+        Some(value) => Ok(value.clone()),
+        None => Err(PyValueError::new_err(format!(
+            "SQL parameters must be literals, found {}",
+            expr.expr
+        ))),
+    };
+    Ok(SQLParams {
+        positional: positional
+            .unwrap_or_default()
+            .into_iter()
+            .map(to_literal)
+            .collect::<PyResult<_>>()?,
+        named: named
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, expr)| Ok((name, to_literal(expr)?)))
+            .collect::<PyResult<_>>()?,
+    })
+}
+
 /// This method is called via `Session.sql` returns a PyObject (typically a PyLogicalBuilder)
-#[pyfunction]
+#[pyfunction(signature = (sql, session, config, positional_params=None, named_params=None))]
 pub fn sql_exec(
     py: Python<'_>,
     sql: &str,
     session: &PySession,
     config: PyDaftPlanningConfig,
+    positional_params: Option<Vec<PyExpr>>,
+    named_params: Option<HashMap<String, PyExpr>>,
 ) -> PyResult<Option<PyObject>> {
-    if let Some(plan) = execute_statement(session.session().clone_ref(), sql)? {
+    let params = sql_params(positional_params, named_params)?;
+    if let Some(plan) = execute_statement(session.session().clone_ref(), sql, params)? {
         let builder = LogicalPlanBuilder::new(plan, Some(config.config));
         let builder = PyLogicalPlanBuilder::from(builder);
         let builder = builder.into_py_any(py)?;
@@ -54,12 +84,14 @@ pub fn sql_exec(
     Ok(None)
 }
 
-#[pyfunction]
+#[pyfunction(signature = (sql, catalog, py_session, daft_planning_config, positional_params=None, named_params=None))]
 pub fn sql(
     sql: &str,
     catalog: PyCatalog,
     py_session: &PySession,
     daft_planning_config: PyDaftPlanningConfig,
+    positional_params: Option<Vec<PyExpr>>,
+    named_params: Option<HashMap<String, PyExpr>>,
 ) -> PyResult<PyLogicalPlanBuilder> {
     // TODO deprecated catalog APIs #3819
 
@@ -68,7 +100,8 @@ pub fn sql(
     for (name, view) in catalog.tables {
         session.create_temp_table(name, &TableSource::View(view), true)?;
     }
-    let mut planner = SQLPlanner::new(session.into());
+    let params = sql_params(positional_params, named_params)?;
+    let mut planner = SQLPlanner::new(session.into()).with_params(params);
     let plan = planner.plan_sql(sql)?;
     Ok(LogicalPlanBuilder::new(plan, Some(daft_planning_config.config)).into())
 }
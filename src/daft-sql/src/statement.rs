@@ -1,14 +1,25 @@
-use daft_catalog::Identifier;
+use std::collections::HashMap;
+
+use daft_catalog::{Identifier, TableRef, TableSource, WriteMode};
+use daft_core::prelude::Schema;
+use daft_dsl::{null_lit, unresolved_col};
 use daft_logical_plan::{LogicalPlanBuilder, LogicalPlanRef};
 use sqlparser::ast;
 
-use crate::{error::SQLPlannerResult, unsupported_sql_err, SQLPlanner};
+use crate::{
+    column_not_found_err, ensure, error::SQLPlannerResult, invalid_operation_err,
+    unsupported_sql_err, SQLPlanner,
+};
 
 /// Top-level planning structure
 #[derive(Debug, Clone)]
 pub enum Statement {
     /// select .. from
     Select(Select),
+    /// create a table from columns or a query
+    CreateTable(CreateTable),
+    /// insert the rows of a query into a table
+    Insert(Insert),
     /// set a session variable
     Set(Set),
     /// list tables in a catalog
@@ -20,6 +31,25 @@ pub enum Statement {
 /// SELECT ...
 pub type Select = LogicalPlanRef;
 
+/// CREATE [OR REPLACE] [TEMP] TABLE [IF NOT EXISTS] <name> { (<columns>) | AS <select> }
+#[derive(Debug, Clone)]
+pub struct CreateTable {
+    pub name: Identifier,
+    pub source: TableSource,
+    pub temporary: bool,
+    pub replace: bool,
+    pub if_not_exists: bool,
+}
+
+/// INSERT { INTO | OVERWRITE } [TABLE] <name> [(<columns>)] <select>
+#[derive(Debug, Clone)]
+pub struct Insert {
+    pub table: TableRef,
+    /// The rows to insert, with the columns of the table.
+    pub source: Select,
+    pub mode: WriteMode,
+}
+
 /// SET <option> [TO] <value>
 #[derive(Debug, Clone)]
 pub struct Set {
@@ -50,6 +80,8 @@ impl SQLPlanner<'_> {
     ) -> SQLPlannerResult<Statement> {
         match statement {
             ast::Statement::Query(query) => self.plan_select(query).map(Statement::Select),
+            ast::Statement::CreateTable(create) => self.plan_create_table(create),
+            ast::Statement::Insert(insert) => self.plan_insert(insert),
             ast::Statement::Explain {
                 describe_alias,
                 analyze,
@@ -87,6 +119,122 @@ impl SQLPlanner<'_> {
         Ok(self.plan_query(query)?.build())
     }
 
+    /// CREATE TABLE ...
+    fn plan_create_table(&mut self, create: &ast::CreateTable) -> SQLPlannerResult<Statement> {
+        if create.external || create.like.is_some() || create.clone.is_some() {
+            unsupported_sql_err!("CREATE EXTERNAL TABLE, LIKE and CLONE are not supported")
+        }
+        if create.or_replace && !create.temporary {
+            unsupported_sql_err!("CREATE OR REPLACE is only supported for temporary tables")
+        }
+        if create.or_replace && create.if_not_exists {
+            invalid_operation_err!("CREATE TABLE cannot have both OR REPLACE and IF NOT EXISTS")
+        }
+        let name = self.normalize(&create.name)?;
+        if create.temporary && name.has_qualifier() {
+            invalid_operation_err!("Temporary tables cannot be qualified, found {}", name)
+        }
+        let source = match (&create.query, create.columns.as_slice()) {
+            (Some(query), []) => TableSource::View(self.plan_select(query)?),
+            (None, columns) if !columns.is_empty() => {
+                let fields = columns
+                    .iter()
+                    .map(|column| self.column_to_field(column))
+                    .collect::<SQLPlannerResult<Vec<_>>>()?;
+                TableSource::Schema(Schema::new(fields).into())
+            }
+            (Some(_), _) => unsupported_sql_err!("CREATE TABLE with both columns and AS <select>"),
+            (None, _) => invalid_operation_err!("CREATE TABLE requires columns or AS <select>"),
+        };
+        Ok(Statement::CreateTable(CreateTable {
+            name,
+            source,
+            temporary: create.temporary,
+            replace: create.or_replace,
+            if_not_exists: create.if_not_exists,
+        }))
+    }
+
+    /// INSERT INTO <table> ...
+    ///
+    /// The columns of the query are inserted into the listed columns of the table by position, or into all of its
+    /// columns when none are listed, and the other columns of the table are null.
+    fn plan_insert(&mut self, insert: &ast::Insert) -> SQLPlannerResult<Statement> {
+        if insert.or.is_some() || insert.ignore || insert.replace_into || insert.on.is_some() {
+            unsupported_sql_err!("INSERT conflict clauses are not supported")
+        }
+        if insert.partitioned.is_some() || !insert.after_columns.is_empty() {
+            unsupported_sql_err!("INSERT with PARTITION is not supported")
+        }
+        if insert.returning.is_some() {
+            unsupported_sql_err!("INSERT with RETURNING is not supported")
+        }
+        let Some(query) = &insert.source else {
+            unsupported_sql_err!("INSERT requires a query")
+        };
+
+        let table = self
+            .session()
+            .get_table(&self.normalize(&insert.table_name)?)?;
+        let schema = table.get_logical_plan()?.schema();
+        let source = self.plan_query(query)?;
+        let source_schema = source.schema();
+
+        // the name of the source column that each table column is inserted from
+        let columns: HashMap<&str, &str> = if insert.columns.is_empty() {
+            ensure!(
+                source_schema.len() == schema.len(),
+                "INSERT query has {} columns, but the table has {}",
+                source_schema.len(),
+                schema.len()
+            );
+            schema
+                .field_names()
+                .zip(source_schema.field_names())
+                .collect()
+        } else {
+            ensure!(
+                source_schema.len() == insert.columns.len(),
+                "INSERT query has {} columns, but {} are listed",
+                source_schema.len(),
+                insert.columns.len()
+            );
+            let mut columns = HashMap::new();
+            for (column, source_column) in insert.columns.iter().zip(source_schema.field_names()) {
+                let Some(field) = schema.fields().iter().find(|f| f.name == column.value) else {
+                    column_not_found_err!(&column.value, insert.table_name.to_string())
+                };
+                ensure!(
+                    columns.insert(field.name.as_str(), source_column).is_none(),
+                    "Column {} is listed more than once",
+                    column.value
+                );
+            }
+            columns
+        };
+        let projection = schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let value = match columns.get(field.name.as_str()) {
+                    Some(source_column) => unresolved_col(*source_column),
+                    None => null_lit(),
+                };
+                value.cast(&field.dtype).alias(field.name.as_str())
+            })
+            .collect();
+
+        Ok(Statement::Insert(Insert {
+            table,
+            source: source.select(projection)?.build(),
+            mode: if insert.overwrite {
+                WriteMode::Overwrite
+            } else {
+                WriteMode::Append
+            },
+        }))
+    }
+
     /// DESCRIBE <statement>
     fn plan_describe(
         &mut self,
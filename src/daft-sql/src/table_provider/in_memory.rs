@@ -54,6 +54,7 @@ pub fn from_micropartitions(parts: Vec<MicroPartition>) -> DaftResult<LogicalPla
         num_partitions,
         size_bytes,
         num_rows,
+        false,
     )?;
     Ok(builder)
 }
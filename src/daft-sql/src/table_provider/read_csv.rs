@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use daft_logical_plan::LogicalPlanBuilder;
-use daft_scan::builder::CsvScanBuilder;
+use daft_scan::{builder::CsvScanBuilder, schema_resolution::SchemaResolution};
 use sqlparser::ast::TableFunctionArgs;
 
 use super::{try_coerce_list, SQLTableFunction};
@@ -61,6 +61,7 @@ impl TryFrom<SQLFunctionArguments> for CsvScanBuilder {
             schema,
             file_path_column,
             hive_partitioning,
+            schema_resolution: SchemaResolution::default(),
             delimiter,
             has_headers,
             double_quote,
@@ -1,11 +1,56 @@
+use common_io_config::IOConfig;
 use daft_logical_plan::LogicalPlanBuilder;
+use daft_scan::builder::TableVersion;
 use sqlparser::ast::TableFunctionArgs;
 
-use super::{expr_to_iocfg, SQLTableFunction};
-use crate::{error::SQLPlannerResult, unsupported_sql_err, SQLPlanner};
+use super::SQLTableFunction;
+use crate::{
+    error::{PlannerError, SQLPlannerResult},
+    functions::{self, SQLFunctionArguments},
+    invalid_operation_err, unsupported_sql_err, SQLPlanner,
+};
 
 pub(super) struct ReadDeltalakeFunction;
 
+/// The Daft-SQL `read_deltalake` table-value function arguments.
+struct ReadDeltalakeArgs {
+    uri: String,
+    version: Option<TableVersion>,
+    io_config: Option<IOConfig>,
+}
+
+impl ReadDeltalakeArgs {
+    fn try_from(planner: &SQLPlanner, args: &TableFunctionArgs) -> SQLPlannerResult<Self> {
+        planner.plan_function_args(&args.args, &["version", "timestamp", "io_config"], 1)
+    }
+}
+
+impl TryFrom<SQLFunctionArguments> for ReadDeltalakeArgs {
+    type Error = PlannerError;
+
+    fn try_from(args: SQLFunctionArguments) -> Result<Self, Self::Error> {
+        let Some(uri) = args.try_get_positional::<String>(0)? else {
+            unsupported_sql_err!("Expected a string literal for the first argument");
+        };
+        let version: Option<i64> = args.try_get_named("version")?;
+        let timestamp: Option<String> = args.try_get_named("timestamp")?;
+        let version = match (version, timestamp) {
+            (Some(_), Some(_)) => {
+                invalid_operation_err!("read_deltalake accepts only one of version and timestamp")
+            }
+            (Some(version), None) => Some(TableVersion::Id(version)),
+            (None, Some(timestamp)) => Some(TableVersion::AsOf(timestamp)),
+            (None, None) => None,
+        };
+        let io_config: Option<IOConfig> = functions::args::parse_io_config(&args)?.into();
+        Ok(Self {
+            uri,
+            version,
+            io_config,
+        })
+    }
+}
+
 #[cfg(feature = "python")]
 impl SQLTableFunction for ReadDeltalakeFunction {
     fn plan(
@@ -13,22 +58,9 @@ impl SQLTableFunction for ReadDeltalakeFunction {
         planner: &SQLPlanner,
         args: &TableFunctionArgs,
     ) -> SQLPlannerResult<LogicalPlanBuilder> {
-        let (uri, io_config) = match args.args.as_slice() {
-            [uri] => (uri, None),
-            [uri, io_config] => {
-                let args = planner.parse_function_args(&[io_config.clone()], &["io_config"], 0)?;
-                let io_config = args.get_named("io_config").map(expr_to_iocfg).transpose()?;
-                (uri, io_config)
-            }
-            _ => unsupported_sql_err!("Expected one or two arguments"),
-        };
-        let uri = planner.plan_function_arg(uri)?;
-
-        let Some(uri) = uri.as_literal().and_then(|lit| lit.as_str()) else {
-            unsupported_sql_err!("Expected a string literal for the first argument");
-        };
-
-        daft_scan::builder::delta_scan(uri, io_config, true).map_err(From::from)
+        let args = ReadDeltalakeArgs::try_from(planner, args)?;
+        daft_scan::builder::delta_scan(args.uri, args.io_config, true, args.version)
+            .map_err(From::from)
     }
 }
 
@@ -1,12 +1,13 @@
 use common_io_config::IOConfig;
 use daft_logical_plan::LogicalPlanBuilder;
+use daft_scan::builder::TableVersion;
 use sqlparser::ast::TableFunctionArgs;
 
 use super::SQLTableFunction;
 use crate::{
     error::{PlannerError, SQLPlannerResult},
     functions::{self, SQLFunctionArguments},
-    SQLPlanner,
+    invalid_operation_err, SQLPlanner,
 };
 
 /// The Daft-SQL `read_iceberg` table-value function.
@@ -15,14 +16,14 @@ pub(super) struct SqlReadIceberg;
 /// The Daft-SQL `read_iceberg` table-value function arguments.
 struct SqlReadIcebergArgs {
     metadata_location: String,
-    snapshot_id: Option<usize>,
+    version: Option<TableVersion>,
     io_config: Option<IOConfig>,
 }
 
 impl SqlReadIcebergArgs {
     /// Like a TryFrom<SQLFunctionArguments> but from TalbeFunctionArgs directly and passing the planner.
     fn try_from(planner: &SQLPlanner, args: &TableFunctionArgs) -> SQLPlannerResult<Self> {
-        planner.plan_function_args(&args.args, &["snapshot_id", "io_config"], 1)
+        planner.plan_function_args(&args.args, &["snapshot_id", "as_of", "io_config"], 1)
     }
 }
 
@@ -34,11 +35,20 @@ impl TryFrom<SQLFunctionArguments> for SqlReadIcebergArgs {
         let metadata_location: String = args
             .try_get_positional(0)?
             .expect("read_iceberg requires a path");
-        let snapshot_id: Option<usize> = args.try_get_named("snapshot_id")?;
+        let snapshot_id: Option<i64> = args.try_get_named("snapshot_id")?;
+        let as_of: Option<String> = args.try_get_named("as_of")?;
+        let version = match (snapshot_id, as_of) {
+            (Some(_), Some(_)) => {
+                invalid_operation_err!("read_iceberg accepts only one of snapshot_id and as_of")
+            }
+            (Some(snapshot_id), None) => Some(TableVersion::Id(snapshot_id)),
+            (None, Some(as_of)) => Some(TableVersion::AsOf(as_of)),
+            (None, None) => None,
+        };
         let io_config: Option<IOConfig> = functions::args::parse_io_config(&args)?.into();
         Ok(Self {
             metadata_location,
-            snapshot_id,
+            version,
             io_config,
         })
     }
@@ -55,7 +65,7 @@ impl SQLTableFunction for SqlReadIceberg {
         let args = SqlReadIcebergArgs::try_from(planner, args)?;
         Ok(daft_scan::builder::iceberg_scan(
             args.metadata_location,
-            args.snapshot_id,
+            args.version,
             args.io_config,
         )?)
     }
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use daft_scan::builder::JsonScanBuilder;
+use daft_scan::{builder::JsonScanBuilder, schema_resolution::SchemaResolution};
 
 use super::{expr_to_iocfg, try_coerce_list, SQLTableFunction};
 use crate::{
@@ -25,6 +25,7 @@ impl SQLTableFunction for ReadJsonFunction {
                 "io_config",
                 "file_path_column",
                 "hive_partitioning",
+                "schema_resolution",
                 // "schema_hints",
                 "buffer_size",
                 "chunk_size",
@@ -58,6 +59,14 @@ impl TryFrom<SQLFunctionArguments> for JsonScanBuilder {
         let buffer_size = args.try_get_named("buffer_size")?;
         let file_path_column = args.try_get_named("file_path_column")?;
         let hive_partitioning = args.try_get_named("hive_partitioning")?.unwrap_or(false);
+        let schema_resolution = SchemaResolution {
+            mode: args
+                .try_get_named::<String>("schema_resolution")?
+                .map(|mode| mode.parse())
+                .transpose()?
+                .unwrap_or_default(),
+            ..Default::default()
+        };
         let schema = args
             .try_get_named("schema")?
             .map(try_parse_schema)
@@ -73,6 +82,7 @@ impl TryFrom<SQLFunctionArguments> for JsonScanBuilder {
             schema,
             file_path_column,
             hive_partitioning,
+            schema_resolution,
             schema_hints,
             buffer_size,
             chunk_size,
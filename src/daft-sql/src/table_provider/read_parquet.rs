@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use daft_core::prelude::TimeUnit;
 use daft_logical_plan::LogicalPlanBuilder;
-use daft_scan::builder::ParquetScanBuilder;
+use daft_scan::{builder::ParquetScanBuilder, schema_resolution::SchemaResolution};
 use sqlparser::ast::TableFunctionArgs;
 
 use super::{try_coerce_list, SQLTableFunction};
@@ -43,6 +43,16 @@ impl TryFrom<SQLFunctionArguments> for ParquetScanBuilder {
         let file_path_column = args.try_get_named("file_path_column")?;
         let multithreaded = args.try_get_named("multithreaded")?.unwrap_or(true);
         let hive_partitioning = args.try_get_named("hive_partitioning")?.unwrap_or(false);
+        let schema_resolution = SchemaResolution::new(
+            args.try_get_named::<String>("schema_resolution")?
+                .map(|mode| mode.parse())
+                .transpose()?
+                .unwrap_or_default(),
+            args.try_get_named::<String>("column_mapping")?
+                .map(|column_mapping| column_mapping.parse())
+                .transpose()?
+                .unwrap_or_default(),
+        );
 
         let field_id_mapping = None; // TODO
         let row_groups = None; // TODO
@@ -65,6 +75,7 @@ impl TryFrom<SQLFunctionArguments> for ParquetScanBuilder {
             schema,
             file_path_column,
             hive_partitioning,
+            schema_resolution,
         })
     }
 }
@@ -83,6 +94,8 @@ impl SQLTableFunction for ReadParquetFunction {
                 "chunk_size",
                 "multithreaded",
                 "schema",
+                "schema_resolution",
+                "column_mapping",
                 // "field_id_mapping",
                 // "row_groups",
                 "io_config",
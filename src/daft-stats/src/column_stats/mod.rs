@@ -93,7 +93,7 @@ impl ColumnRangeStatistics {
 
             // UNSUPPORTED TYPES:
             // Types that don't support comparisons and can't be used as ColumnRangeStatistics
-            DataType::List(..) | DataType::FixedSizeList(..) | DataType::Image(..) | DataType::FixedShapeImage(..) | DataType::Tensor(..) | DataType::SparseTensor(..) | DataType::FixedShapeSparseTensor(..) | DataType::FixedShapeTensor(..) | DataType::Struct(..) | DataType::Map { .. } | DataType::Extension(..) | DataType::Embedding(..) | DataType::Unknown => false,
+            DataType::List(..) | DataType::FixedSizeList(..) | DataType::Image(..) | DataType::FixedShapeImage(..) | DataType::Tensor(..) | DataType::SparseTensor(..) | DataType::FixedShapeSparseTensor(..) | DataType::FixedShapeTensor(..) | DataType::Struct(..) | DataType::Map { .. } | DataType::Extension(..) | DataType::Embedding(..) | DataType::Categorical(..) | DataType::Unknown => false,
             #[cfg(feature = "python")]
             DataType::Python => false,
         }
@@ -166,6 +166,14 @@ impl ColumnRangeStatistics {
             .get(0)
             .unwrap() as usize;
         let _num_bytes = series.size_bytes().unwrap();
+        // NaN sorts after every other value, but compares false against everything, so bounds
+        // holding it can't be used for pruning
+        if lower.data_type().is_floating() {
+            let is_nan = |s: &Series| s.is_nan().unwrap().bool().unwrap().get(0) == Some(true);
+            if is_nan(&lower) || is_nan(&upper) {
+                return Self::Missing;
+            }
+        }
         Self::Loaded(lower, upper)
     }
 
@@ -287,4 +295,23 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_from_series_with_nan() -> crate::Result<()> {
+        let with_nan = Float64Array::from(("a", vec![1.0, f64::NAN, 3.0])).into_series();
+        assert!(matches!(
+            ColumnRangeStatistics::from_series(&with_nan),
+            ColumnRangeStatistics::Missing
+        ));
+
+        let without_nan = Float64Array::from(("a", vec![1.0, f64::INFINITY])).into_series();
+        let ColumnRangeStatistics::Loaded(lower, upper) =
+            ColumnRangeStatistics::from_series(&without_nan)
+        else {
+            panic!("expected loaded statistics");
+        };
+        assert_eq!(lower.f64().unwrap().get(0), Some(1.0));
+        assert_eq!(upper.f64().unwrap().get(0), Some(f64::INFINITY));
+        Ok(())
+    }
 }
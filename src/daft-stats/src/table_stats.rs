@@ -7,15 +7,22 @@ use std::{
 
 use common_error::{DaftError, DaftResult};
 use daft_core::prelude::*;
-use daft_dsl::{Column, Expr, ExprRef, ResolvedColumn};
+use daft_dsl::{Column, Expr, ExprRef, LiteralValue, Operator, ResolvedColumn};
 use daft_recordbatch::RecordBatch;
 use indexmap::{IndexMap, IndexSet};
+use snafu::ResultExt;
 
-use crate::column_stats::ColumnRangeStatistics;
+use crate::{
+    column_stats::{ColumnRangeStatistics, TruthValue},
+    DaftCoreComputeSnafu,
+};
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct TableStatistics {
     pub columns: IndexMap<String, ColumnRangeStatistics>,
+    /// The numbers of nulls of the columns whose number of nulls is known. Once rows are dropped or repeated, they
+    /// only tell how many nulls there were at most, or whether there are any.
+    pub null_counts: IndexMap<String, u64>,
 }
 
 impl Hash for TableStatistics {
@@ -24,6 +31,19 @@ impl Hash for TableStatistics {
             key.hash(state);
             value.hash(state);
         }
+        for (key, value) in &self.null_counts {
+            key.hash(state);
+            value.hash(state);
+        }
+    }
+}
+
+/// The name of the column that an expression reads as is, if it does.
+fn column_name(expr: &Expr) -> Option<&str> {
+    match expr {
+        Expr::Alias(expr, _) => column_name(expr),
+        Expr::Column(Column::Resolved(ResolvedColumn::Basic(name))) => Some(name.as_ref()),
+        _ => None,
     }
 }
 
@@ -41,18 +61,32 @@ impl TableStatistics {
             let stats = ColumnRangeStatistics::new(Some(col.slice(0, 1)?), Some(col.slice(1, 2)?))?;
             columns.insert(name, stats);
         }
-        Ok(Self { columns })
+        Ok(Self {
+            columns,
+            null_counts: IndexMap::new(),
+        })
     }
 
     #[must_use]
     pub fn from_table(table: &RecordBatch) -> Self {
         let mut columns = IndexMap::with_capacity(table.num_columns());
+        let mut null_counts = IndexMap::with_capacity(table.num_columns());
         for name in table.column_names() {
             let col = table.get_column(&name).unwrap();
             let stats = ColumnRangeStatistics::from_series(col);
-            columns.insert(name, stats);
+            columns.insert(name.clone(), stats);
+            if let Some(null_count) = col
+                .count(None, CountMode::Null)
+                .ok()
+                .and_then(|count| count.u64().ok()?.get(0))
+            {
+                null_counts.insert(name, null_count);
+            }
+        }
+        Self {
+            columns,
+            null_counts,
         }
-        Self { columns }
     }
 
     pub fn union(&self, other: &Self) -> crate::Result<Self> {
@@ -72,7 +106,15 @@ impl TableStatistics {
             }?;
             columns.insert(col.clone(), res_col);
         }
-        Ok(Self { columns })
+        let null_counts = self
+            .null_counts
+            .iter()
+            .filter_map(|(name, l)| Some((name.clone(), l + other.null_counts.get(name)?)))
+            .collect();
+        Ok(Self {
+            columns,
+            null_counts,
+        })
     }
 
     pub fn eval_expression_list(
@@ -90,9 +132,19 @@ impl TableStatistics {
             .map(ToString::to_string)
             .zip(result_cols)
             .collect();
+        // Columns that are selected as is keep their number of nulls.
+        let null_counts = expected_schema
+            .field_names()
+            .zip(exprs)
+            .filter_map(|(name, e)| {
+                let null_count = self.null_counts.get(column_name(e)?)?;
+                Some((name.to_string(), *null_count))
+            })
+            .collect();
 
         Ok(Self {
             columns: new_col_stats,
+            null_counts,
         })
     }
 
@@ -144,6 +196,17 @@ impl TableStatistics {
             }
             Expr::Literal(lit_value) => lit_value.try_into(),
             Expr::Not(col) => self.eval_expression(col)?.not(),
+            Expr::IsNull(col) | Expr::NotNull(col)
+                if column_name(col).and_then(|name| self.null_counts.get(name)) == Some(&0) =>
+            {
+                Ok(ColumnRangeStatistics::from_truth_value(
+                    if matches!(expr, Expr::IsNull(_)) {
+                        TruthValue::False
+                    } else {
+                        TruthValue::True
+                    },
+                ))
+            }
             Expr::BinaryOp { op, left, right } => {
                 let lhs = self.eval_expression(left)?;
                 let rhs = self.eval_expression(right)?;
@@ -176,7 +239,16 @@ impl TableStatistics {
         fill_map: Option<&HashMap<&str, ExprRef>>,
     ) -> crate::Result<Self> {
         let mut columns = IndexMap::new();
+        let mut null_counts = IndexMap::new();
         for field in schema.as_ref() {
+            // Casts may turn values into nulls, so only columns whose statistics are of the type that they're cast
+            // to keep their number of nulls.
+            if let Some(ColumnRangeStatistics::Loaded(lower, _)) = self.columns.get(&field.name)
+                && lower.data_type() == &field.dtype
+                && let Some(null_count) = self.null_counts.get(&field.name)
+            {
+                null_counts.insert(field.name.clone(), *null_count);
+            }
             let crs = match self.columns.get(&field.name) {
                 Some(column_stat) => column_stat
                     .cast(&field.dtype)
@@ -190,7 +262,119 @@ impl TableStatistics {
             };
             columns.insert(field.name.clone(), crs);
         }
-        Ok(Self { columns })
+        Ok(Self {
+            columns,
+            null_counts,
+        })
+    }
+
+    /// Narrows the statistics to the rows that pass `predicate`, from its conjuncts that compare columns to literals
+    /// and that check that columns aren't null. The rows that pass either hold no nulls in those columns.
+    pub fn filter(&self, predicate: &Expr) -> crate::Result<Self> {
+        let mut filtered = self.clone();
+        filtered.narrow(predicate)?;
+        Ok(filtered)
+    }
+
+    fn narrow(&mut self, predicate: &Expr) -> crate::Result<()> {
+        let (name, op, value) = match predicate {
+            Expr::BinaryOp {
+                op: Operator::And,
+                left,
+                right,
+            } => {
+                self.narrow(left)?;
+                return self.narrow(right);
+            }
+            Expr::NotNull(expr) => {
+                if let Some(name) = column_name(expr)
+                    && self.columns.contains_key(name)
+                {
+                    self.null_counts.insert(name.to_string(), 0);
+                }
+                return Ok(());
+            }
+            Expr::BinaryOp { op, left, right } => match (
+                column_name(left),
+                left.as_ref(),
+                column_name(right),
+                right.as_ref(),
+            ) {
+                (Some(name), _, _, Expr::Literal(value)) => (name, *op, value),
+                (_, Expr::Literal(value), Some(name), _) => {
+                    let flipped = match op {
+                        Operator::Lt => Operator::Gt,
+                        Operator::LtEq => Operator::GtEq,
+                        Operator::Gt => Operator::Lt,
+                        Operator::GtEq => Operator::LtEq,
+                        op => *op,
+                    };
+                    (name, flipped, value)
+                }
+                _ => return Ok(()),
+            },
+            _ => return Ok(()),
+        };
+        if !matches!(
+            op,
+            Operator::Eq
+                | Operator::NotEq
+                | Operator::Lt
+                | Operator::LtEq
+                | Operator::Gt
+                | Operator::GtEq
+        ) || matches!(value, LiteralValue::Null)
+            || !self.columns.contains_key(name)
+        {
+            return Ok(());
+        }
+        // Comparisons with nulls are null, which filters them out.
+        self.null_counts.insert(name.to_string(), 0);
+
+        let Some(ColumnRangeStatistics::Loaded(lower, upper)) = self.columns.get(name) else {
+            return Ok(());
+        };
+        let ColumnRangeStatistics::Loaded(bound, _) =
+            ColumnRangeStatistics::try_from(value)?.cast(lower.data_type())?
+        else {
+            return Ok(());
+        };
+        if bound.data_type().is_floating()
+            && bound
+                .is_nan()
+                .context(DaftCoreComputeSnafu)?
+                .bool()
+                .context(DaftCoreComputeSnafu)?
+                .get(0)
+                == Some(true)
+        {
+            return Ok(());
+        }
+        let new_lower = match op {
+            Operator::Eq | Operator::Gt | Operator::GtEq => Series::concat(&[lower, &bound])
+                .and_then(|s| s.max(None))
+                .context(DaftCoreComputeSnafu)?,
+            _ => lower.clone(),
+        };
+        let new_upper = match op {
+            Operator::Eq | Operator::Lt | Operator::LtEq => Series::concat(&[upper, &bound])
+                .and_then(|s| s.min(None))
+                .context(DaftCoreComputeSnafu)?,
+            _ => upper.clone(),
+        };
+        // Conjuncts that no row passes together leave the bounds that they would cross as they are.
+        if new_lower
+            .lte(&new_upper)
+            .context(DaftCoreComputeSnafu)?
+            .get(0)
+            == Some(true)
+        {
+            self.columns.insert(
+                name.to_string(),
+                ColumnRangeStatistics::Loaded(new_lower, new_upper),
+            );
+        }
+        Ok(())
     }
 }
 
@@ -249,4 +433,54 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_filter() -> crate::Result<()> {
+        let table = RecordBatch::from_nonempty_columns(vec![
+            Int64Array::from(("a", vec![1, 2, 3, 4])).into_series(),
+            Int64Array::from_iter(
+                Field::new("b", DataType::Int64),
+                vec![Some(1), None, Some(2), None].into_iter(),
+            )
+            .into_series(),
+        ])
+        .unwrap();
+        let table_stats = TableStatistics::from_table(&table);
+        assert_eq!(table_stats.null_counts["a"], 0);
+
+        let is_null = resolved_col("b").is_null();
+        assert_eq!(
+            table_stats.eval_expression(&is_null)?.to_truth_value(),
+            TruthValue::Maybe
+        );
+
+        // The bounds of `a` are narrowed to the rows that pass, which hold no nulls in `b`.
+        let filtered = table_stats.filter(
+            &resolved_col("a")
+                .gt_eq(lit(3))
+                .and(lit(1).lt(resolved_col("b"))),
+        )?;
+        let expr = resolved_col("a").lt(lit(3));
+        assert_eq!(
+            filtered.eval_expression(&expr)?.to_truth_value(),
+            TruthValue::False
+        );
+        assert_eq!(
+            filtered.eval_expression(&is_null)?.to_truth_value(),
+            TruthValue::False
+        );
+
+        // Conjuncts that no row passes together don't cross the bounds.
+        let filtered = table_stats.filter(
+            &resolved_col("a")
+                .gt(lit(3))
+                .and(resolved_col("a").lt(lit(2))),
+        )?;
+        assert_eq!(
+            filtered.columns["a"],
+            table_stats.filter(&resolved_col("a").gt(lit(3)))?.columns["a"]
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{functions::struct_::get, null_lit, unresolved_col, Expr, ExprRef, LiteralValue};
+use daft_functions::try_::try_cast;
+use substrait::{
+    expression::{
+        cast::FailureBehavior, field_reference, literal::LiteralType, reference_segment,
+        FieldReference, ReferenceSegment, RexType,
+    },
+    expression_reference::ExprType,
+    function_argument::ArgType,
+    simple_extension_declaration::MappingType,
+    Expression, ExtendedExpression, SimpleExtensionDeclaration,
+};
+
+use crate::{
+    extensions::Function,
+    types::{from_named_struct, from_substrait_type, time_unit_of},
+};
+
+/// Converts Substrait expressions over `schema` to Daft, recording what can't be converted rather
+/// than stopping at the first unsupported expression so that the whole set can be reported at
+/// once.
+pub(crate) struct Consumer {
+    pub(crate) schema: Schema,
+    pub(crate) functions: HashMap<u32, String>,
+    pub(crate) unsupported: Vec<String>,
+}
+
+impl Consumer {
+    pub(crate) fn new(schema: Schema, extensions: &[SimpleExtensionDeclaration]) -> Self {
+        let functions = extensions
+            .iter()
+            .filter_map(|extension| match &extension.mapping_type {
+                Some(MappingType::ExtensionFunction(func)) => {
+                    Some((func.function_anchor, func.name.clone()))
+                }
+                Some(MappingType::ExtensionType(_)) | None => None,
+            })
+            .collect();
+        Self {
+            schema,
+            functions,
+            unsupported: Vec::new(),
+        }
+    }
+
+    pub(crate) fn unsupported(&mut self, what: String) -> ExprRef {
+        if !self.unsupported.contains(&what) {
+            self.unsupported.push(what);
+        }
+        null_lit()
+    }
+
+    /// The name of the function declared with the given anchor.
+    pub(crate) fn function_name(&self, anchor: u32) -> DaftResult<String> {
+        self.functions.get(&anchor).cloned().ok_or_else(|| {
+            DaftError::ValueError(format!(
+                "Substrait function reference {anchor} is not declared"
+            ))
+        })
+    }
+
+    pub(crate) fn expression(&mut self, expression: &Expression) -> DaftResult<ExprRef> {
+        let Some(rex_type) = &expression.rex_type else {
+            return Ok(self.unsupported(
+                "expressions other than literals, field references, scalar functions, casts, if-thens and singular-or-lists".to_string(),
+            ));
+        };
+        Ok(match rex_type {
+            RexType::Literal(literal) => match &literal.literal_type {
+                Some(literal_type) => self.literal(literal_type)?,
+                None => self.unsupported("literals of user-defined or compound types".to_string()),
+            },
+            RexType::Selection(reference) => self.field_reference(reference)?,
+            RexType::ScalarFunction(func) => {
+                let name = self.function_name(func.function_reference)?;
+                let Some(function) = Function::from_name(&name) else {
+                    return Ok(self.unsupported(format!("function `{name}`")));
+                };
+                let args = func
+                    .arguments
+                    .iter()
+                    .map(|arg| match &arg.arg_type {
+                        Some(ArgType::Value(value)) => self.expression(value),
+                        None => Ok(self
+                            .unsupported(format!("enum and type arguments to function `{name}`"))),
+                    })
+                    .collect::<DaftResult<Vec<_>>>()?;
+                function.build(args)?
+            }
+            RexType::IfThen(if_then) => {
+                let mut result = match &if_then.r#else {
+                    Some(r#else) => self.expression(r#else)?,
+                    None => null_lit(),
+                };
+                for clause in if_then.ifs.iter().rev() {
+                    let (Some(predicate), Some(then)) = (&clause.r#if, &clause.then) else {
+                        return Err(DaftError::ValueError(
+                            "Substrait if clause is missing its condition or result".into(),
+                        ));
+                    };
+                    result = self
+                        .expression(predicate)?
+                        .if_else(self.expression(then)?, result);
+                }
+                result
+            }
+            RexType::SingularOrList(list) => {
+                let value = list.value.as_deref().ok_or_else(|| {
+                    DaftError::ValueError("Substrait singular-or-list is missing its value".into())
+                })?;
+                let options = list
+                    .options
+                    .iter()
+                    .map(|option| self.expression(option))
+                    .collect::<DaftResult<_>>()?;
+                self.expression(value)?.is_in(options)
+            }
+            RexType::Cast(cast) => {
+                let (Some(ty), Some(input)) = (&cast.r#type, &cast.input) else {
+                    return Err(DaftError::ValueError(
+                        "Substrait cast is missing its type or input".into(),
+                    ));
+                };
+                let dtype = from_substrait_type(ty)?;
+                let input = self.expression(input)?;
+                if cast.failure_behavior == FailureBehavior::ReturnNull as i32 {
+                    try_cast(input, dtype)
+                } else {
+                    input.cast(&dtype)
+                }
+            }
+        })
+    }
+
+    fn literal(&self, literal_type: &LiteralType) -> DaftResult<ExprRef> {
+        let value = match literal_type {
+            LiteralType::Boolean(v) => LiteralValue::Boolean(*v),
+            LiteralType::I8(v) => LiteralValue::Int8(*v as i8),
+            LiteralType::I16(v) => LiteralValue::Int16(*v as i16),
+            LiteralType::I32(v) => LiteralValue::Int32(*v),
+            LiteralType::I64(v) => LiteralValue::Int64(*v),
+            // Daft has no 32-bit float literals.
+            LiteralType::Fp32(v) => {
+                return Ok(Expr::Literal(LiteralValue::Float64(f64::from(*v)))
+                    .arced()
+                    .cast(&DataType::Float32))
+            }
+            LiteralType::Fp64(v) => LiteralValue::Float64(*v),
+            LiteralType::String(v) => LiteralValue::Utf8(v.clone()),
+            LiteralType::Binary(v) => LiteralValue::Binary(v.clone()),
+            LiteralType::Date(v) => LiteralValue::Date(*v),
+            LiteralType::Time(v) => LiteralValue::Time(*v, TimeUnit::Microseconds),
+            LiteralType::PrecisionTimestamp(ts) => {
+                LiteralValue::Timestamp(ts.value, time_unit_of(ts.precision)?, None)
+            }
+            LiteralType::PrecisionTimestampTz(ts) => LiteralValue::Timestamp(
+                ts.value,
+                time_unit_of(ts.precision)?,
+                Some("UTC".to_string()),
+            ),
+            LiteralType::Decimal(decimal) => {
+                let bytes: [u8; 16] = decimal.value.as_slice().try_into().map_err(|_| {
+                    DaftError::ValueError(format!(
+                        "Expected Substrait decimal literals to be 16 bytes, got {}",
+                        decimal.value.len()
+                    ))
+                })?;
+                LiteralValue::Decimal(
+                    i128::from_le_bytes(bytes),
+                    decimal.precision as u8,
+                    decimal.scale as i8,
+                )
+            }
+            LiteralType::Null(ty) => return Ok(null_lit().cast(&from_substrait_type(ty)?)),
+        };
+        Ok(Expr::Literal(value).arced())
+    }
+
+    fn field_reference(&mut self, reference: &FieldReference) -> DaftResult<ExprRef> {
+        let Some(field_reference::ReferenceType::DirectReference(segment)) =
+            &reference.reference_type
+        else {
+            return Ok(self.unsupported("masked field references".to_string()));
+        };
+        let (mut expr, mut dtype, mut segment) = match &reference.root_type {
+            Some(field_reference::RootType::Expression(root)) => {
+                let root = self.expression(root)?;
+                let dtype = root.to_field(&self.schema)?.dtype;
+                (root, dtype, Some(segment))
+            }
+            Some(field_reference::RootType::RootReference(_)) | None => {
+                let (index, child) = struct_field(segment)?;
+                let field = self.schema.fields().get(index).ok_or_else(|| {
+                    DaftError::ValueError(format!(
+                        "Substrait field reference {index} is out of bounds for a schema with {} fields",
+                        self.schema.len()
+                    ))
+                })?;
+                (
+                    unresolved_col(field.name.as_str()),
+                    field.dtype.clone(),
+                    child,
+                )
+            }
+        };
+        while let Some(current) = segment {
+            let (index, child) = struct_field(current)?;
+            let field = match &dtype {
+                DataType::Struct(fields) => fields.get(index),
+                _ => None,
+            }
+            .ok_or_else(|| {
+                DaftError::ValueError(format!(
+                    "Substrait field reference {index} is out of bounds for {dtype}"
+                ))
+            })?;
+            expr = get(expr, &field.name);
+            dtype = field.dtype.clone();
+            segment = child;
+        }
+        Ok(expr)
+    }
+}
+
+fn struct_field(segment: &ReferenceSegment) -> DaftResult<(usize, Option<&ReferenceSegment>)> {
+    match &segment.reference_type {
+        Some(reference_segment::ReferenceType::StructField(field)) => {
+            Ok((field.field as usize, field.child.as_deref()))
+        }
+        None => Err(DaftError::not_implemented(
+            "Substrait map key and list element references",
+        )),
+    }
+}
+
+/// Converts a Substrait extended expression to Daft expressions over its base schema, each
+/// aliased to its output name.
+///
+/// Fails with a list of everything that couldn't be converted if any expression uses a function
+/// or construct that Daft doesn't support.
+pub fn from_substrait(extended: &ExtendedExpression) -> DaftResult<(Vec<ExprRef>, Schema)> {
+    let schema = extended
+        .base_schema
+        .as_ref()
+        .map(from_named_struct)
+        .transpose()?
+        .unwrap_or_else(|| Schema::new(Vec::<Field>::new()));
+    let mut consumer = Consumer::new(schema, &extended.extensions);
+    let mut exprs = Vec::with_capacity(extended.referred_expr.len());
+    for reference in &extended.referred_expr {
+        let expr = match &reference.expr_type {
+            Some(ExprType::Expression(expression)) => consumer.expression(expression)?,
+            None => consumer.unsupported("aggregate measures".to_string()),
+        };
+        exprs.push(match reference.output_names.first() {
+            Some(name) if name != expr.name() => expr.alias(name.as_str()),
+            _ => expr,
+        });
+    }
+    if consumer.unsupported.is_empty() {
+        Ok((exprs, consumer.schema))
+    } else {
+        Err(DaftError::not_implemented(format!(
+            "Converting Substrait {} to Daft",
+            consumer.unsupported.join(", ")
+        )))
+    }
+}
@@ -0,0 +1,307 @@
+//! The Substrait functions that Daft can produce and consume.
+//!
+//! Every function lives in one of Substrait's standard extension files. Consumers look
+//! functions up by name alone, ignoring the URI and any `:signature` suffix, since producers
+//! disagree on both.
+use common_error::{DaftError, DaftResult};
+use daft_core::count_mode::CountMode;
+use daft_dsl::{binary_op, functions::ScalarFunction, AggExpr, Expr, ExprRef, Operator};
+use daft_functions::{
+    coalesce::{coalesce, Coalesce},
+    float::{is_nan, IsNan},
+    numeric::{
+        abs::{abs, Abs},
+        ceil::{ceil, Ceil},
+        floor::{floor, Floor},
+        sqrt::{sqrt, Sqrt},
+    },
+    utf8::{
+        contains, endswith, length, lower, startswith, upper, Utf8Contains, Utf8Endswith,
+        Utf8Length, Utf8Lower, Utf8Startswith, Utf8Upper,
+    },
+};
+
+const AGGREGATE_GENERIC: &str =
+    "https://github.com/substrait-io/substrait/blob/main/extensions/functions_aggregate_generic.yaml";
+const ARITHMETIC: &str =
+    "https://github.com/substrait-io/substrait/blob/main/extensions/functions_arithmetic.yaml";
+const BOOLEAN: &str =
+    "https://github.com/substrait-io/substrait/blob/main/extensions/functions_boolean.yaml";
+const COMPARISON: &str =
+    "https://github.com/substrait-io/substrait/blob/main/extensions/functions_comparison.yaml";
+const ROUNDING: &str =
+    "https://github.com/substrait-io/substrait/blob/main/extensions/functions_rounding.yaml";
+const STRING: &str =
+    "https://github.com/substrait-io/substrait/blob/main/extensions/functions_string.yaml";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Function {
+    Binary(Operator),
+    Not,
+    IsNull,
+    IsNotNull,
+    Between,
+    Coalesce,
+    IsNan,
+    Abs,
+    Sqrt,
+    Ceil,
+    Floor,
+    Upper,
+    Lower,
+    CharLength,
+    Contains,
+    StartsWith,
+    EndsWith,
+}
+
+const FUNCTIONS: &[(&str, &str, Function)] = &[
+    (COMPARISON, "equal", Function::Binary(Operator::Eq)),
+    (COMPARISON, "not_equal", Function::Binary(Operator::NotEq)),
+    (COMPARISON, "lt", Function::Binary(Operator::Lt)),
+    (COMPARISON, "lte", Function::Binary(Operator::LtEq)),
+    (COMPARISON, "gt", Function::Binary(Operator::Gt)),
+    (COMPARISON, "gte", Function::Binary(Operator::GtEq)),
+    (
+        COMPARISON,
+        "is_not_distinct_from",
+        Function::Binary(Operator::EqNullSafe),
+    ),
+    (COMPARISON, "is_null", Function::IsNull),
+    (COMPARISON, "is_not_null", Function::IsNotNull),
+    (COMPARISON, "between", Function::Between),
+    (COMPARISON, "coalesce", Function::Coalesce),
+    (COMPARISON, "is_nan", Function::IsNan),
+    (BOOLEAN, "and", Function::Binary(Operator::And)),
+    (BOOLEAN, "or", Function::Binary(Operator::Or)),
+    (BOOLEAN, "xor", Function::Binary(Operator::Xor)),
+    (BOOLEAN, "not", Function::Not),
+    (ARITHMETIC, "add", Function::Binary(Operator::Plus)),
+    (ARITHMETIC, "subtract", Function::Binary(Operator::Minus)),
+    (ARITHMETIC, "multiply", Function::Binary(Operator::Multiply)),
+    (ARITHMETIC, "divide", Function::Binary(Operator::TrueDivide)),
+    (ARITHMETIC, "modulus", Function::Binary(Operator::Modulus)),
+    (ARITHMETIC, "abs", Function::Abs),
+    (ARITHMETIC, "sqrt", Function::Sqrt),
+    (ROUNDING, "ceil", Function::Ceil),
+    (ROUNDING, "floor", Function::Floor),
+    (STRING, "upper", Function::Upper),
+    (STRING, "lower", Function::Lower),
+    (STRING, "char_length", Function::CharLength),
+    (STRING, "contains", Function::Contains),
+    (STRING, "starts_with", Function::StartsWith),
+    (STRING, "ends_with", Function::EndsWith),
+];
+
+/// The aggregate functions of Substrait's extensions that Daft supports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Aggregate {
+    Count,
+    Sum,
+    Avg,
+    Min,
+    Max,
+    BoolAnd,
+    BoolOr,
+}
+
+const AGGREGATES: &[(&str, &str, Aggregate)] = &[
+    (AGGREGATE_GENERIC, "count", Aggregate::Count),
+    (ARITHMETIC, "sum", Aggregate::Sum),
+    (ARITHMETIC, "avg", Aggregate::Avg),
+    (ARITHMETIC, "min", Aggregate::Min),
+    (ARITHMETIC, "max", Aggregate::Max),
+    (BOOLEAN, "bool_and", Aggregate::BoolAnd),
+    (BOOLEAN, "bool_or", Aggregate::BoolOr),
+];
+
+/// The extension URI and name of every Substrait function that Daft supports, including
+/// aggregate functions.
+#[must_use]
+pub fn supported_functions() -> Vec<(&'static str, &'static str)> {
+    FUNCTIONS
+        .iter()
+        .map(|(uri, name, _)| (*uri, *name))
+        .chain(AGGREGATES.iter().map(|(uri, name, _)| (*uri, *name)))
+        .collect()
+}
+
+impl Aggregate {
+    pub(crate) fn uri_and_name(self) -> (&'static str, &'static str) {
+        AGGREGATES
+            .iter()
+            .find(|(.., f)| *f == self)
+            .map(|(uri, name, _)| (*uri, *name))
+            .expect("every aggregate has an entry in AGGREGATES")
+    }
+
+    /// Looks up an aggregate function by its Substrait name, such as `sum` or `sum:i64`.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        let name = name.split_once(':').map_or(name, |(name, _)| name);
+        AGGREGATES
+            .iter()
+            .find(|(_, n, _)| *n == name)
+            .map(|(.., f)| *f)
+    }
+
+    /// The aggregate function that `agg` is a call to, its arguments, and whether it only
+    /// aggregates distinct values. Counts of all rows, nulls included, take no arguments.
+    pub(crate) fn of(agg: &AggExpr) -> Option<(Self, Vec<ExprRef>, bool)> {
+        Some(match agg {
+            AggExpr::Count(_, CountMode::All) => (Self::Count, vec![], false),
+            AggExpr::Count(input, CountMode::Valid) => (Self::Count, vec![input.clone()], false),
+            AggExpr::CountDistinct(input) => (Self::Count, vec![input.clone()], true),
+            AggExpr::Sum(input) => (Self::Sum, vec![input.clone()], false),
+            AggExpr::Mean(input) => (Self::Avg, vec![input.clone()], false),
+            AggExpr::Min(input) => (Self::Min, vec![input.clone()], false),
+            AggExpr::Max(input) => (Self::Max, vec![input.clone()], false),
+            AggExpr::BoolAnd(input) => (Self::BoolAnd, vec![input.clone()], false),
+            AggExpr::BoolOr(input) => (Self::BoolOr, vec![input.clone()], false),
+            _ => return None,
+        })
+    }
+
+    /// Builds a call to this aggregate function. Counts of all rows are counts of `any_column`
+    /// that include nulls.
+    pub(crate) fn build(
+        self,
+        args: Vec<ExprRef>,
+        distinct: bool,
+        any_column: impl FnOnce() -> DaftResult<ExprRef>,
+    ) -> DaftResult<ExprRef> {
+        Ok(match (self, args.as_slice(), distinct) {
+            (Self::Count, [], false) => any_column()?.count(CountMode::All),
+            (Self::Count, [input], false) => input.clone().count(CountMode::Valid),
+            (Self::Count, [input], true) => input.clone().count_distinct(),
+            (_, _, true) => {
+                return Err(DaftError::not_implemented(format!(
+                    "Substrait aggregate function `{}` over distinct values",
+                    self.uri_and_name().1
+                )))
+            }
+            (Self::Sum, [input], _) => input.clone().sum(),
+            (Self::Avg, [input], _) => input.clone().mean(),
+            (Self::Min, [input], _) => input.clone().min(),
+            (Self::Max, [input], _) => input.clone().max(),
+            (Self::BoolAnd, [input], _) => input.clone().bool_and(),
+            (Self::BoolOr, [input], _) => input.clone().bool_or(),
+            _ => {
+                return Err(DaftError::ValueError(format!(
+                    "Unexpected number of arguments to Substrait aggregate function `{}`: {}",
+                    self.uri_and_name().1,
+                    args.len()
+                )))
+            }
+        })
+    }
+}
+
+impl Function {
+    pub(crate) fn uri_and_name(self) -> (&'static str, &'static str) {
+        FUNCTIONS
+            .iter()
+            .find(|(.., f)| *f == self)
+            .map(|(uri, name, _)| (*uri, *name))
+            .expect("every function has an entry in FUNCTIONS")
+    }
+
+    /// Looks up a function by its Substrait name, such as `add` or `add:i64_i64`.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        let name = name.split_once(':').map_or(name, |(name, _)| name);
+        FUNCTIONS
+            .iter()
+            .find(|(_, n, _)| *n == name)
+            .map(|(.., f)| *f)
+    }
+
+    /// The function that `expr` is a call to, and its arguments.
+    pub(crate) fn of(expr: &Expr) -> Option<(Self, Vec<ExprRef>)> {
+        let (function, args) = match expr {
+            Expr::BinaryOp { op, left, right } => {
+                (Self::Binary(*op), vec![left.clone(), right.clone()])
+            }
+            Expr::Not(input) => (Self::Not, vec![input.clone()]),
+            Expr::IsNull(input) => (Self::IsNull, vec![input.clone()]),
+            Expr::NotNull(input) => (Self::IsNotNull, vec![input.clone()]),
+            Expr::Between(input, lower, upper) => (
+                Self::Between,
+                vec![input.clone(), lower.clone(), upper.clone()],
+            ),
+            Expr::FillNull(input, fill_value) => {
+                (Self::Coalesce, vec![input.clone(), fill_value.clone()])
+            }
+            Expr::ScalarFunction(ScalarFunction { udf, inputs }) => {
+                let udf = udf.as_any();
+                let function = if udf.is::<Coalesce>() {
+                    Self::Coalesce
+                } else if udf.is::<IsNan>() {
+                    Self::IsNan
+                } else if udf.is::<Abs>() {
+                    Self::Abs
+                } else if udf.is::<Sqrt>() {
+                    Self::Sqrt
+                } else if udf.is::<Ceil>() {
+                    Self::Ceil
+                } else if udf.is::<Floor>() {
+                    Self::Floor
+                } else if udf.is::<Utf8Upper>() {
+                    Self::Upper
+                } else if udf.is::<Utf8Lower>() {
+                    Self::Lower
+                } else if udf.is::<Utf8Length>() {
+                    Self::CharLength
+                } else if udf.is::<Utf8Contains>() {
+                    Self::Contains
+                } else if udf.is::<Utf8Startswith>() {
+                    Self::StartsWith
+                } else if udf.is::<Utf8Endswith>() {
+                    Self::EndsWith
+                } else {
+                    return None;
+                };
+                (function, inputs.clone())
+            }
+            _ => return None,
+        };
+        FUNCTIONS
+            .iter()
+            .any(|(.., f)| *f == function)
+            .then_some((function, args))
+    }
+
+    /// Builds a call to this function.
+    pub(crate) fn build(self, args: Vec<ExprRef>) -> DaftResult<ExprRef> {
+        Ok(match (self, args.as_slice()) {
+            // `and` and `or` take any number of arguments in Substrait.
+            (Self::Binary(op @ (Operator::And | Operator::Or)), [first, rest @ ..]) => rest
+                .iter()
+                .fold(first.clone(), |acc, arg| binary_op(op, acc, arg.clone())),
+            (Self::Binary(op), [left, right]) => binary_op(op, left.clone(), right.clone()),
+            (Self::Not, [input]) => input.clone().not(),
+            (Self::IsNull, [input]) => input.clone().is_null(),
+            (Self::IsNotNull, [input]) => input.clone().not_null(),
+            (Self::Between, [input, lower, upper]) => {
+                input.clone().between(lower.clone(), upper.clone())
+            }
+            (Self::Coalesce, [_, ..]) => coalesce(args.clone()),
+            (Self::IsNan, [input]) => is_nan(input.clone()),
+            (Self::Abs, [input]) => abs(input.clone()),
+            (Self::Sqrt, [input]) => sqrt(input.clone()),
+            (Self::Ceil, [input]) => ceil(input.clone()),
+            (Self::Floor, [input]) => floor(input.clone()),
+            (Self::Upper, [input]) => upper(input.clone()),
+            (Self::Lower, [input]) => lower(input.clone()),
+            (Self::CharLength, [input]) => length(input.clone()),
+            (Self::Contains, [input, pattern]) => contains(input.clone(), pattern.clone()),
+            (Self::StartsWith, [input, pattern]) => startswith(input.clone(), pattern.clone()),
+            (Self::EndsWith, [input, pattern]) => endswith(input.clone(), pattern.clone()),
+            _ => {
+                return Err(DaftError::ValueError(format!(
+                    "Unexpected number of arguments to Substrait function `{}`: {}",
+                    self.uri_and_name().1,
+                    args.len()
+                )))
+            }
+        })
+    }
+}
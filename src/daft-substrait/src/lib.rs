@@ -0,0 +1,270 @@
+//! Conversion between Daft expressions and plans and Substrait extended expressions and plans.
+//!
+//! An extended expression bundles a set of named expressions with the schema they're evaluated
+//! over, which lets other engines hand filters and projections to Daft and vice versa. Only a
+//! subset of Daft's functions has Substrait equivalents; [`capability_report`] lists what
+//! can't be converted without failing.
+//!
+//! A plan is a tree of relations over named tables, which lets external planners execute queries
+//! with Daft and services ship queries to each other; see [`plan_to_substrait`] and
+//! [`plan_from_substrait`].
+mod consumer;
+mod extensions;
+mod plan;
+mod producer;
+mod types;
+
+pub use consumer::from_substrait;
+pub use extensions::supported_functions;
+pub use plan::{plan_from_substrait, plan_tables, plan_to_substrait};
+pub use producer::{capability_report, to_substrait, CapabilityReport};
+pub use substrait;
+
+#[cfg(feature = "python")]
+pub mod python;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+#[cfg(feature = "python")]
+pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
+    parent.add_function(wrap_pyfunction!(
+        python::substrait_from_expressions,
+        parent
+    )?)?;
+    parent.add_function(wrap_pyfunction!(python::substrait_to_expressions, parent)?)?;
+    parent.add_function(wrap_pyfunction!(
+        python::substrait_capability_report,
+        parent
+    )?)?;
+    parent.add_function(wrap_pyfunction!(python::substrait_from_plan, parent)?)?;
+    parent.add_function(wrap_pyfunction!(python::substrait_plan_tables, parent)?)?;
+    parent.add_function(wrap_pyfunction!(python::substrait_to_plan, parent)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc};
+
+    use common_error::DaftResult;
+    use daft_core::{count_mode::CountMode, prelude::*};
+    use daft_dsl::{binary_op, lit, null_lit, resolved_col, unresolved_col, ExprRef, Operator};
+    use daft_functions::{hash::hash, utf8::upper};
+    use daft_logical_plan::{
+        ops::Source, InMemoryInfo, JoinOptions, JoinType, LogicalPlan, LogicalPlanBuilder,
+        SourceInfo,
+    };
+    use prost::Message;
+
+    use super::*;
+
+    fn schema() -> Schema {
+        Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+            Field::new(
+                "c",
+                DataType::Struct(vec![Field::new("x", DataType::Float64)]),
+            ),
+        ])
+    }
+
+    fn round_trip(exprs: &[ExprRef]) -> DaftResult<Vec<ExprRef>> {
+        let schema = schema();
+        let bytes = to_substrait(exprs, &schema)?.encode_to_vec();
+        let extended = substrait::ExtendedExpression::decode(bytes.as_slice()).unwrap();
+        let (exprs, decoded_schema) = from_substrait(&extended)?;
+        assert_eq!(decoded_schema, schema);
+        Ok(exprs)
+    }
+
+    #[test]
+    fn test_round_trip() -> DaftResult<()> {
+        let exprs = vec![
+            unresolved_col("a")
+                .gt(lit(1i64))
+                .and(unresolved_col("b").is_null().not()),
+            upper(unresolved_col("b")).alias("upper_b"),
+            unresolved_col("a")
+                .is_in(vec![lit(1i64), lit(2i64)])
+                .if_else(lit("yes"), null_lit().cast(&DataType::Utf8))
+                .alias("label"),
+            daft_dsl::functions::struct_::get(unresolved_col("c"), "x").cast(&DataType::Int32),
+        ];
+        assert_eq!(round_trip(&exprs)?, exprs);
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolved_columns_are_consumed_by_name() -> DaftResult<()> {
+        let exprs = vec![binary_op(Operator::Plus, resolved_col("a"), lit(1i64))];
+        assert_eq!(
+            round_trip(&exprs)?,
+            vec![binary_op(Operator::Plus, unresolved_col("a"), lit(1i64))]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_capability_report() -> DaftResult<()> {
+        let schema = schema();
+        let exprs = vec![
+            binary_op(Operator::Plus, unresolved_col("a"), lit(1i64)),
+            hash(unresolved_col("b"), None).alias("h"),
+            binary_op(Operator::FloorDivide, unresolved_col("a"), lit(2i64)).alias("d"),
+        ];
+        let report = capability_report(&exprs, &schema)?;
+        assert!(!report.is_supported());
+        assert_eq!(report.unsupported.len(), 2);
+        assert!(to_substrait(&exprs, &schema).is_err());
+        assert!(capability_report(&exprs[..1], &schema)?.is_supported());
+        Ok(())
+    }
+
+    #[test]
+    fn test_consuming_unknown_functions_fails() -> DaftResult<()> {
+        let schema = schema();
+        let mut extended = to_substrait(
+            &[binary_op(Operator::Plus, unresolved_col("a"), lit(1i64))],
+            &schema,
+        )?;
+        let Some(substrait::simple_extension_declaration::MappingType::ExtensionFunction(func)) =
+            &mut extended.extensions[0].mapping_type
+        else {
+            unreachable!()
+        };
+        func.name = "factorial:i64".to_string();
+        let err = from_substrait(&extended).unwrap_err();
+        assert!(err.to_string().contains("factorial"));
+        Ok(())
+    }
+
+    fn table(name: &str, fields: Vec<Field>) -> LogicalPlanBuilder {
+        let schema = Arc::new(Schema::new(fields));
+        let source_info = SourceInfo::InMemory(InMemoryInfo::new(
+            schema.clone(),
+            name.to_string(),
+            None,
+            1,
+            0,
+            0,
+            None,
+            None,
+            false,
+        ));
+        LogicalPlanBuilder::new(
+            Arc::new(LogicalPlan::Source(Source::new(
+                schema,
+                Arc::new(source_info),
+            ))),
+            None,
+        )
+    }
+
+    fn tables() -> HashMap<String, LogicalPlanBuilder> {
+        HashMap::from([
+            (
+                "db.a".to_string(),
+                table(
+                    "a",
+                    vec![
+                        Field::new("id", DataType::Int64),
+                        Field::new("x", DataType::Utf8),
+                    ],
+                ),
+            ),
+            (
+                "b".to_string(),
+                table(
+                    "b",
+                    vec![
+                        Field::new("id", DataType::Int64),
+                        Field::new("y", DataType::Float64),
+                    ],
+                ),
+            ),
+        ])
+    }
+
+    fn plan_round_trip(
+        plan: &LogicalPlanBuilder,
+        expected_tables: &[&str],
+    ) -> DaftResult<LogicalPlanBuilder> {
+        let tables = tables();
+        let named = tables
+            .iter()
+            .map(|(name, table)| (name.clone(), table.build()))
+            .collect::<Vec<_>>();
+        let bytes = plan_to_substrait(&plan.build(), &named)?.encode_to_vec();
+        let plan = substrait::Plan::decode(bytes.as_slice()).unwrap();
+        let mut names = plan_tables(&plan)?;
+        names.sort();
+        assert_eq!(names, expected_tables);
+        plan_from_substrait(&plan, &tables)
+    }
+
+    #[test]
+    fn test_plan_round_trip() -> DaftResult<()> {
+        let tables = tables();
+        let plan = tables["db.a"]
+            .filter(unresolved_col("id").gt(lit(1i64)))?
+            .join(
+                tables["b"].clone(),
+                None,
+                vec!["id".to_string()],
+                JoinType::Left,
+                None,
+                JoinOptions::default(),
+            )?
+            .select(vec![
+                unresolved_col("x"),
+                binary_op(Operator::Multiply, unresolved_col("y"), lit(2.0)).alias("y2"),
+            ])?
+            .aggregate(
+                vec![
+                    unresolved_col("y2").sum().alias("total"),
+                    unresolved_col("y2").count(CountMode::Valid).alias("n"),
+                ],
+                vec![unresolved_col("x")],
+            )?
+            .sort(vec![unresolved_col("total")], vec![true], vec![false])?
+            .limit(10, false)?;
+        let consumed = plan_round_trip(&plan, &["b", "db.a"])?;
+        assert_eq!(consumed.schema(), plan.schema());
+
+        let distinct = tables["db.a"]
+            .select(vec![unresolved_col("x")])?
+            .distinct()?;
+        assert_eq!(
+            plan_round_trip(&distinct, &["db.a"])?.schema(),
+            distinct.schema()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_plan_with_unsupported_operations_fails() -> DaftResult<()> {
+        let tables = tables();
+        let plan = tables["db.a"].sample(0.5, false, None)?;
+        let named = vec![("db.a".to_string(), tables["db.a"].build())];
+        let err = plan_to_substrait(&plan.build(), &named).unwrap_err();
+        assert!(err.to_string().contains("Sample"));
+
+        let err = plan_to_substrait(&tables["db.a"].build(), &[]).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("sources other than the given tables"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_consuming_plan_without_table_fails() -> DaftResult<()> {
+        let tables = tables();
+        let named = vec![("db.a".to_string(), tables["db.a"].build())];
+        let plan = plan_to_substrait(&tables["db.a"].build(), &named)?;
+        let err = plan_from_substrait(&plan, &HashMap::new()).unwrap_err();
+        assert!(err.to_string().contains("db.a"));
+        Ok(())
+    }
+}
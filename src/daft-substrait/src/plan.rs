@@ -0,0 +1,1031 @@
+//! Conversion between Daft logical plans and Substrait plans.
+//!
+//! Substrait relations refer to the fields of their inputs by position and only name the fields
+//! of the plan's output, whereas Daft refers to columns by name. Daft plans are produced with
+//! each column at the position of its Substrait field, and consumed by giving the fields of each
+//! relation unique names and renaming the output to the names of the plan's root.
+//!
+//! Tables are exchanged by name: the sources of a produced plan become reads of named tables,
+//! and the named tables of a consumed plan are resolved by the caller.
+use std::collections::HashMap;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{join::JoinSide, prelude::*};
+use daft_dsl::{
+    common_treenode::{Transformed, TreeNode},
+    lit, resolved_col, unresolved_col, Column, Expr, ExprRef, LiteralValue, ResolvedColumn,
+};
+use daft_functions::coalesce::coalesce;
+use daft_logical_plan::{
+    ops::{Aggregate as AggregateOp, Join, SetQuantifier, Source, UnionStrategy},
+    JoinOptions, JoinType, LogicalPlan, LogicalPlanBuilder, LogicalPlanRef, SourceInfo,
+};
+use substrait::{
+    aggregate_function::AggregationInvocation,
+    aggregate_rel::{Grouping, Measure},
+    expression::field_reference,
+    join_rel, plan_rel,
+    read_rel::{
+        self,
+        mask_expression::{StructItem, StructSelect},
+        MaskExpression, NamedTable,
+    },
+    rel::RelType,
+    rel_common::{self, EmitKind},
+    set_rel::SetOp,
+    sort_field::{SortDirection, SortKind},
+    AggregateFunction, AggregateRel, AggregationPhase, Expression, FetchRel, FilterRel,
+    FunctionArgument, JoinRel, Plan, PlanRel, ProjectRel, ReadRel, Rel, RelCommon, RelRoot, SetRel,
+    SortField, SortRel,
+};
+
+use crate::{
+    consumer::Consumer,
+    extensions::Aggregate,
+    producer::{describe, field_reference, version, Producer},
+    types::{from_named_struct, push_nested_names, to_named_struct, to_substrait_type},
+};
+
+/// Builds the relations of a plan, recording what can't be converted rather than stopping at the
+/// first unsupported operation so that the whole plan can be reported at once.
+struct PlanProducer<'a> {
+    producer: Producer,
+    tables: &'a [(String, LogicalPlanRef)],
+}
+
+impl<'a> PlanProducer<'a> {
+    fn unsupported(&mut self, what: String) -> Rel {
+        self.producer.unsupported(what);
+        Rel::default()
+    }
+
+    /// The table that `source_info` reads, and its name.
+    fn table_of(&self, source_info: &SourceInfo) -> Option<(&'a str, &'a Source)> {
+        self.tables.iter().find_map(|(name, plan)| {
+            let LogicalPlan::Source(table) = unaliased(plan) else {
+                return None;
+            };
+            let is_same = match (source_info, table.source_info.as_ref()) {
+                (SourceInfo::InMemory(source), SourceInfo::InMemory(table)) => {
+                    source.cache_key == table.cache_key
+                }
+                (SourceInfo::Physical(source), SourceInfo::Physical(table)) => {
+                    source.scan_state == table.scan_state
+                }
+                _ => false,
+            };
+            is_same.then_some((name.as_str(), table))
+        })
+    }
+
+    fn rel(&mut self, plan: &LogicalPlanRef) -> DaftResult<Rel> {
+        let rel_type = match plan.as_ref() {
+            LogicalPlan::Source(source) => return self.read(source),
+            LogicalPlan::Filter(filter) => {
+                let input = self.rel(&filter.input)?;
+                let condition = self
+                    .producer
+                    .expression(&filter.predicate, &filter.input.schema())?;
+                RelType::Filter(Box::new(FilterRel {
+                    common: None,
+                    input: Some(Box::new(input)),
+                    condition: Some(Box::new(condition)),
+                }))
+            }
+            LogicalPlan::Project(project) => {
+                let input = self.rel(&project.input)?;
+                let schema = project.input.schema();
+                let expressions = project
+                    .projection
+                    .iter()
+                    .map(|expr| self.producer.expression(expr, &schema))
+                    .collect::<DaftResult<_>>()?;
+                return Ok(project_rel(input, schema.len(), expressions));
+            }
+            LogicalPlan::Limit(limit) => {
+                return Ok(fetch_rel(self.rel(&limit.input)?, limit.limit));
+            }
+            LogicalPlan::Sort(sort) => {
+                let input = self.rel(&sort.input)?;
+                let schema = sort.input.schema();
+                let sorts = sort
+                    .sort_by
+                    .iter()
+                    .zip(&sort.descending)
+                    .zip(&sort.nulls_first)
+                    .map(|((expr, descending), nulls_first)| {
+                        let direction = match (descending, nulls_first) {
+                            (false, true) => SortDirection::AscNullsFirst,
+                            (false, false) => SortDirection::AscNullsLast,
+                            (true, true) => SortDirection::DescNullsFirst,
+                            (true, false) => SortDirection::DescNullsLast,
+                        };
+                        Ok(SortField {
+                            expr: Some(self.producer.expression(expr, &schema)?),
+                            sort_kind: Some(SortKind::Direction(direction as i32)),
+                        })
+                    })
+                    .collect::<DaftResult<_>>()?;
+                RelType::Sort(Box::new(SortRel {
+                    common: None,
+                    input: Some(Box::new(input)),
+                    sorts,
+                }))
+            }
+            LogicalPlan::Aggregate(aggregate) => return self.aggregate(aggregate),
+            // A distinct is an aggregate grouped by every column, without measures.
+            LogicalPlan::Distinct(distinct) => {
+                let input = self.rel(&distinct.input)?;
+                let grouping_expressions = (0..distinct.input.schema().len())
+                    .map(input_field)
+                    .collect();
+                RelType::Aggregate(Box::new(AggregateRel {
+                    common: None,
+                    input: Some(Box::new(input)),
+                    groupings: vec![Grouping {
+                        grouping_expressions,
+                        expression_references: Vec::new(),
+                    }],
+                    measures: Vec::new(),
+                    grouping_expressions: Vec::new(),
+                }))
+            }
+            LogicalPlan::Concat(concat) => RelType::Set(SetRel {
+                common: None,
+                inputs: vec![self.rel(&concat.input)?, self.rel(&concat.other)?],
+                op: SetOp::UnionAll as i32,
+            }),
+            LogicalPlan::Join(join) => return self.join(join),
+            LogicalPlan::SubqueryAlias(alias) => return self.rel(&alias.input),
+            other => return Ok(self.unsupported(format!("{} operations", other.name()))),
+        };
+        Ok(Rel {
+            rel_type: Some(rel_type),
+        })
+    }
+
+    fn read(&mut self, source: &Source) -> DaftResult<Rel> {
+        let Some((name, table)) = self.table_of(&source.source_info) else {
+            return Ok(self.unsupported("sources other than the given tables".to_string()));
+        };
+        let base_schema = &table.output_schema;
+        let (filter, limit) = match source.source_info.as_ref() {
+            SourceInfo::Physical(info) => {
+                if info.pushdowns.sample.is_some() {
+                    self.producer.unsupported("samples of sources".to_string());
+                }
+                let filter = match (&info.pushdowns.filters, &info.pushdowns.partition_filters) {
+                    (Some(filters), Some(partition_filters)) => {
+                        Some(filters.clone().and(partition_filters.clone()))
+                    }
+                    (filters, partition_filters) => {
+                        filters.clone().or_else(|| partition_filters.clone())
+                    }
+                };
+                (filter, info.pushdowns.limit)
+            }
+            _ => (None, None),
+        };
+        let filter = filter
+            .map(|filter| self.producer.expression(&filter, base_schema))
+            .transpose()?;
+        let projection = if source.output_schema.names() == base_schema.names() {
+            None
+        } else {
+            let struct_items = source
+                .output_schema
+                .names()
+                .iter()
+                .map(|name| {
+                    Ok(StructItem {
+                        field: base_schema.get_index(name)? as i32,
+                        child: None,
+                    })
+                })
+                .collect::<DaftResult<_>>()?;
+            Some(MaskExpression {
+                select: Some(StructSelect { struct_items }),
+                maintain_singular_struct: false,
+            })
+        };
+        let read = Rel {
+            rel_type: Some(RelType::Read(Box::new(ReadRel {
+                common: None,
+                base_schema: Some(to_named_struct(base_schema)?),
+                filter: filter.map(Box::new),
+                best_effort_filter: None,
+                projection,
+                read_type: Some(read_rel::ReadType::NamedTable(NamedTable {
+                    names: name.split('.').map(String::from).collect(),
+                })),
+            }))),
+        };
+        Ok(match limit {
+            Some(limit) => fetch_rel(read, limit as i64),
+            None => read,
+        })
+    }
+
+    fn aggregate(&mut self, aggregate: &AggregateOp) -> DaftResult<Rel> {
+        let input = self.rel(&aggregate.input)?;
+        let schema = aggregate.input.schema();
+        let grouping_expressions = aggregate
+            .groupby
+            .iter()
+            .map(|expr| self.producer.expression(expr, &schema))
+            .collect::<DaftResult<Vec<_>>>()?;
+        let measures = aggregate
+            .aggregations
+            .iter()
+            .map(|expr| self.measure(expr, &schema))
+            .collect::<DaftResult<_>>()?;
+        // An aggregate without groupings outputs a single row, as a global aggregation does.
+        let groupings = if grouping_expressions.is_empty() {
+            Vec::new()
+        } else {
+            vec![Grouping {
+                grouping_expressions,
+                expression_references: Vec::new(),
+            }]
+        };
+        Ok(Rel {
+            rel_type: Some(RelType::Aggregate(Box::new(AggregateRel {
+                common: None,
+                input: Some(Box::new(input)),
+                groupings,
+                measures,
+                grouping_expressions: Vec::new(),
+            }))),
+        })
+    }
+
+    fn measure(&mut self, expr: &ExprRef, schema: &Schema) -> DaftResult<Measure> {
+        let mut agg_expr = expr;
+        while let Expr::Alias(input, _) = agg_expr.as_ref() {
+            agg_expr = input;
+        }
+        let Expr::Agg(agg) = agg_expr.as_ref() else {
+            self.producer.unsupported(describe(expr));
+            return Ok(Measure::default());
+        };
+        let Some((aggregate, args, distinct)) = Aggregate::of(agg) else {
+            self.producer.unsupported(describe(agg_expr));
+            return Ok(Measure::default());
+        };
+        // Daft counts are unsigned, which Substrait counts aren't.
+        let dtype = match aggregate {
+            Aggregate::Count => DataType::Int64,
+            _ => expr.to_field(schema)?.dtype,
+        };
+        let Some(output_type) = to_substrait_type(&dtype) else {
+            self.producer.unsupported(format!("{dtype} values"));
+            return Ok(Measure::default());
+        };
+        let arguments = args
+            .iter()
+            .map(|arg| {
+                Ok(FunctionArgument {
+                    arg_type: Some(substrait::function_argument::ArgType::Value(
+                        self.producer.expression(arg, schema)?,
+                    )),
+                })
+            })
+            .collect::<DaftResult<_>>()?;
+        let invocation = if distinct {
+            AggregationInvocation::Distinct
+        } else {
+            AggregationInvocation::All
+        };
+        Ok(Measure {
+            measure: Some(AggregateFunction {
+                function_reference: self.producer.function_reference(aggregate.uri_and_name()),
+                arguments,
+                output_type: Some(output_type),
+                phase: AggregationPhase::InitialToResult as i32,
+                invocation: invocation as i32,
+            }),
+            filter: None,
+        })
+    }
+
+    /// Produces a join, whose output in Substrait is the columns of the left side followed by
+    /// those of the right, followed by a projection to Daft's output, which merges the columns of
+    /// the same name on both sides and outputs them first.
+    fn join(&mut self, join: &Join) -> DaftResult<Rel> {
+        let left = self.rel(&join.left)?;
+        let right = self.rel(&join.right)?;
+        let left_schema = join.left.schema();
+        let right_schema = join.right.schema();
+        // The condition and projection are evaluated over the columns of both sides, which are
+        // named after their side to tell apart the columns of the same name.
+        let joined_schema = Schema::new(
+            left_schema
+                .fields()
+                .iter()
+                .map(|field| (JoinSide::Left, field))
+                .chain(
+                    right_schema
+                        .fields()
+                        .iter()
+                        .map(|field| (JoinSide::Right, field)),
+                )
+                .map(|(side, field)| Field::new(side_name(side, &field.name), field.dtype.clone())),
+        );
+        let expression = match join.on.inner() {
+            Some(predicate) => {
+                let predicate = predicate
+                    .clone()
+                    .transform(|e| match e.as_ref() {
+                        Expr::Column(Column::Resolved(ResolvedColumn::JoinSide(field, side))) => {
+                            Ok(Transformed::yes(resolved_col(side_name(
+                                *side,
+                                &field.name,
+                            ))))
+                        }
+                        _ => Ok(Transformed::no(e)),
+                    })?
+                    .data;
+                self.producer.expression(&predicate, &joined_schema)?
+            }
+            // A cross join.
+            None => self.producer.expression(&lit(true), &joined_schema)?,
+        };
+        let join_type = match join.join_type {
+            JoinType::Inner => join_rel::JoinType::Inner,
+            JoinType::Left => join_rel::JoinType::Left,
+            JoinType::Right => join_rel::JoinType::Right,
+            JoinType::Outer => join_rel::JoinType::Outer,
+            JoinType::Semi => join_rel::JoinType::LeftSemi,
+            JoinType::Anti => join_rel::JoinType::LeftAnti,
+        };
+        let join_rel = Rel {
+            rel_type: Some(RelType::Join(Box::new(JoinRel {
+                common: None,
+                left: Some(Box::new(left)),
+                right: Some(Box::new(right)),
+                expression: Some(expression),
+                post_join_filter: None,
+                r#type: join_type as i32,
+            }))),
+        };
+        if matches!(join.join_type, JoinType::Semi | JoinType::Anti) {
+            return Ok(join_rel);
+        }
+        let expressions = join
+            .output_schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let left = resolved_col(side_name(JoinSide::Left, &field.name));
+                let right = resolved_col(side_name(JoinSide::Right, &field.name));
+                let expr = match (
+                    left_schema.has_field(&field.name),
+                    right_schema.has_field(&field.name),
+                ) {
+                    (true, true) => match join.join_type {
+                        JoinType::Right => right,
+                        JoinType::Outer => coalesce(
+                            [left, right]
+                                .into_iter()
+                                .map(|col| {
+                                    if col.to_field(&joined_schema)?.dtype == field.dtype {
+                                        Ok(col)
+                                    } else {
+                                        Ok(col.cast(&field.dtype))
+                                    }
+                                })
+                                .collect::<DaftResult<_>>()?,
+                        ),
+                        _ => left,
+                    },
+                    (true, false) => left,
+                    _ => right,
+                };
+                self.producer.expression(&expr, &joined_schema)
+            })
+            .collect::<DaftResult<_>>()?;
+        Ok(project_rel(join_rel, joined_schema.len(), expressions))
+    }
+}
+
+/// The plan of a table, without the aliases it may be wrapped in.
+fn unaliased(plan: &LogicalPlanRef) -> &LogicalPlan {
+    match plan.as_ref() {
+        LogicalPlan::SubqueryAlias(alias) => unaliased(&alias.input),
+        plan => plan,
+    }
+}
+
+fn side_name(side: JoinSide, name: &str) -> String {
+    format!("{side}.{name}")
+}
+
+/// A reference to the field of the input at `index`.
+fn input_field(index: usize) -> Expression {
+    Expression {
+        rex_type: Some(field_reference(
+            index,
+            field_reference::RootType::RootReference(field_reference::RootReference {}),
+        )),
+    }
+}
+
+/// A projection that outputs only `expressions`, rather than appending them to the `num_inputs`
+/// fields of its input.
+fn project_rel(input: Rel, num_inputs: usize, expressions: Vec<Expression>) -> Rel {
+    let output_mapping = (num_inputs..num_inputs + expressions.len())
+        .map(|index| index as i32)
+        .collect();
+    Rel {
+        rel_type: Some(RelType::Project(Box::new(ProjectRel {
+            common: Some(RelCommon {
+                emit_kind: Some(EmitKind::Emit(rel_common::Emit { output_mapping })),
+            }),
+            input: Some(Box::new(input)),
+            expressions,
+        }))),
+    }
+}
+
+fn fetch_rel(input: Rel, count: i64) -> Rel {
+    Rel {
+        rel_type: Some(RelType::Fetch(Box::new(FetchRel {
+            common: None,
+            input: Some(Box::new(input)),
+            offset: 0,
+            count,
+        }))),
+    }
+}
+
+/// Converts an optimized Daft plan to a Substrait plan, whose root is named after the plan's
+/// output columns.
+///
+/// Every source of the plan must be the source of one of `tables`, each a scan of a single source
+/// along with the name that it's read by. Fails with a list of everything that couldn't be
+/// converted if the plan uses an operation, function, literal or type that has no Substrait
+/// equivalent.
+pub fn plan_to_substrait(
+    plan: &LogicalPlanRef,
+    tables: &[(String, LogicalPlanRef)],
+) -> DaftResult<Plan> {
+    for (name, table) in tables {
+        if !matches!(unaliased(table), LogicalPlan::Source(_)) {
+            return Err(DaftError::ValueError(format!(
+                "Expected table `{name}` to be a scan of a single source, such as files or in-memory data, not a derived DataFrame"
+            )));
+        }
+    }
+    let mut producer = PlanProducer {
+        producer: Producer::default(),
+        tables,
+    };
+    let input = producer.rel(plan)?;
+    if !producer.producer.unsupported.is_empty() {
+        return Err(DaftError::not_implemented(format!(
+            "Converting {} to Substrait",
+            producer.producer.unsupported.join(", ")
+        )));
+    }
+    let mut names = Vec::new();
+    for field in plan.schema().fields() {
+        names.push(field.name.clone());
+        push_nested_names(&field.dtype, &mut names);
+    }
+    Ok(Plan {
+        version: Some(version()),
+        extension_uris: producer.producer.extension_uris,
+        extensions: producer.producer.extensions,
+        relations: vec![PlanRel {
+            rel_type: Some(plan_rel::RelType::Root(RelRoot {
+                input: Some(input),
+                names,
+            })),
+        }],
+        expected_type_urls: Vec::new(),
+    })
+}
+
+fn root(plan: &Plan) -> DaftResult<&RelRoot> {
+    match plan.relations.as_slice() {
+        [PlanRel {
+            rel_type: Some(plan_rel::RelType::Root(root)),
+        }] => Ok(root),
+        _ => Err(DaftError::not_implemented(
+            "Substrait plans other than a single root relation",
+        )),
+    }
+}
+
+/// The names of the tables that a Substrait plan reads, with the parts of qualified names joined
+/// by `.`.
+pub fn plan_tables(plan: &Plan) -> DaftResult<Vec<String>> {
+    fn collect(rel: &Rel, tables: &mut Vec<String>) {
+        let inputs = match &rel.rel_type {
+            Some(RelType::Read(read)) => {
+                if let Some(read_rel::ReadType::NamedTable(table)) = &read.read_type {
+                    let name = table.names.join(".");
+                    if !tables.contains(&name) {
+                        tables.push(name);
+                    }
+                }
+                vec![]
+            }
+            Some(RelType::Filter(filter)) => filter.input.as_deref().into_iter().collect(),
+            Some(RelType::Fetch(fetch)) => fetch.input.as_deref().into_iter().collect(),
+            Some(RelType::Aggregate(aggregate)) => aggregate.input.as_deref().into_iter().collect(),
+            Some(RelType::Sort(sort)) => sort.input.as_deref().into_iter().collect(),
+            Some(RelType::Project(project)) => project.input.as_deref().into_iter().collect(),
+            Some(RelType::Join(join)) => join
+                .left
+                .as_deref()
+                .into_iter()
+                .chain(join.right.as_deref())
+                .collect(),
+            Some(RelType::Set(set)) => set.inputs.iter().collect(),
+            None => vec![],
+        };
+        for input in inputs {
+            collect(input, tables);
+        }
+    }
+
+    let mut tables = Vec::new();
+    if let Some(input) = &root(plan)?.input {
+        collect(input, &mut tables);
+    }
+    Ok(tables)
+}
+
+/// Converts Substrait relations to Daft, giving the columns of each relation unique names.
+struct PlanConsumer<'a> {
+    consumer: Consumer,
+    tables: &'a HashMap<String, LogicalPlanBuilder>,
+}
+
+impl PlanConsumer<'_> {
+    /// Converts expressions over the output of `input`, failing with everything that couldn't
+    /// be converted rather than building a plan from them.
+    fn expressions<'e>(
+        &mut self,
+        expressions: impl IntoIterator<Item = &'e Expression>,
+        input: &LogicalPlanBuilder,
+    ) -> DaftResult<Vec<ExprRef>> {
+        self.expressions_over(expressions, input.schema().fields().iter().cloned())
+    }
+
+    fn expressions_over<'e>(
+        &mut self,
+        expressions: impl IntoIterator<Item = &'e Expression>,
+        fields: impl IntoIterator<Item = Field>,
+    ) -> DaftResult<Vec<ExprRef>> {
+        self.consumer.schema = Schema::new(fields);
+        let exprs = expressions
+            .into_iter()
+            .map(|expression| self.consumer.expression(expression))
+            .collect::<DaftResult<_>>()?;
+        self.check_supported()?;
+        Ok(exprs)
+    }
+
+    fn expression(
+        &mut self,
+        expression: Option<&Expression>,
+        input: &LogicalPlanBuilder,
+        what: &str,
+    ) -> DaftResult<ExprRef> {
+        let expression = expression
+            .ok_or_else(|| DaftError::ValueError(format!("Substrait {what} is missing")))?;
+        Ok(self.expressions([expression], input)?.remove(0))
+    }
+
+    fn check_supported(&self) -> DaftResult<()> {
+        if self.consumer.unsupported.is_empty() {
+            Ok(())
+        } else {
+            Err(DaftError::not_implemented(format!(
+                "Converting Substrait {} to Daft",
+                self.consumer.unsupported.join(", ")
+            )))
+        }
+    }
+
+    fn unsupported<T>(&mut self, what: String) -> DaftResult<T> {
+        self.consumer.unsupported(what);
+        self.check_supported()?;
+        unreachable!("the unsupported construct was just recorded")
+    }
+
+    fn input(&mut self, input: Option<&Rel>, what: &str) -> DaftResult<LogicalPlanBuilder> {
+        let input = input.ok_or_else(|| {
+            DaftError::ValueError(format!("Substrait {what} relation is missing its input"))
+        })?;
+        self.rel(input)
+    }
+
+    fn rel(&mut self, rel: &Rel) -> DaftResult<LogicalPlanBuilder> {
+        let (builder, common) = match &rel.rel_type {
+            Some(RelType::Read(read)) => (self.read(read)?, &read.common),
+            Some(RelType::Filter(filter)) => {
+                let input = self.input(filter.input.as_deref(), "filter")?;
+                let condition =
+                    self.expression(filter.condition.as_deref(), &input, "filter condition")?;
+                (input.filter(condition)?, &filter.common)
+            }
+            Some(RelType::Fetch(fetch)) => {
+                let input = self.input(fetch.input.as_deref(), "fetch")?;
+                if fetch.offset != 0 {
+                    return self.unsupported("fetches with an offset".to_string());
+                }
+                let builder = if fetch.count < 0 {
+                    input
+                } else {
+                    input.limit(fetch.count, false)?
+                };
+                (builder, &fetch.common)
+            }
+            Some(RelType::Sort(sort)) => {
+                let input = self.input(sort.input.as_deref(), "sort")?;
+                let mut sort_by = Vec::with_capacity(sort.sorts.len());
+                let mut descending = Vec::with_capacity(sort.sorts.len());
+                let mut nulls_first = Vec::with_capacity(sort.sorts.len());
+                for field in &sort.sorts {
+                    let direction = match field.sort_kind {
+                        Some(SortKind::Direction(direction)) => SortDirection::try_from(direction)
+                            .unwrap_or(SortDirection::Unspecified),
+                        None => SortDirection::Unspecified,
+                    };
+                    let (desc, first) = match direction {
+                        SortDirection::AscNullsFirst => (false, true),
+                        SortDirection::AscNullsLast => (false, false),
+                        SortDirection::DescNullsFirst => (true, true),
+                        SortDirection::DescNullsLast => (true, false),
+                        SortDirection::Unspecified | SortDirection::Clustered => {
+                            return self.unsupported(format!(
+                                "sorts in direction {}",
+                                direction.as_str_name()
+                            ))
+                        }
+                    };
+                    sort_by.push(self.expression(field.expr.as_ref(), &input, "sort field")?);
+                    descending.push(desc);
+                    nulls_first.push(first);
+                }
+                (input.sort(sort_by, descending, nulls_first)?, &sort.common)
+            }
+            Some(RelType::Project(project)) => {
+                let input = self.input(project.input.as_deref(), "project")?;
+                let exprs = self.expressions(&project.expressions, &input)?;
+                let columns = input
+                    .schema()
+                    .names()
+                    .into_iter()
+                    .map(unresolved_col)
+                    .chain(exprs)
+                    .collect();
+                (select_unique(&input, columns)?, &project.common)
+            }
+            Some(RelType::Aggregate(aggregate)) => (self.aggregate(aggregate)?, &aggregate.common),
+            Some(RelType::Join(join)) => (self.join(join)?, &join.common),
+            Some(RelType::Set(set)) => (self.set(set)?, &set.common),
+            None => {
+                return self.unsupported(
+                    "relations other than reads, filters, fetches, sorts, projections, aggregates, joins and sets"
+                        .to_string(),
+                )
+            }
+        };
+        match common.as_ref().and_then(|common| common.emit_kind.as_ref()) {
+            Some(EmitKind::Emit(emit)) => {
+                let names = builder.schema().names();
+                let columns = emit
+                    .output_mapping
+                    .iter()
+                    .map(|index| {
+                        names
+                            .get(*index as usize)
+                            .map(|name| unresolved_col(name.as_str()))
+                            .ok_or_else(|| {
+                                DaftError::ValueError(format!(
+                                    "Substrait emit index {index} is out of bounds for a relation with {} fields",
+                                    names.len()
+                                ))
+                            })
+                    })
+                    .collect::<DaftResult<_>>()?;
+                select_unique(&builder, columns)
+            }
+            Some(EmitKind::Direct(_)) | None => Ok(builder),
+        }
+    }
+
+    fn read(&mut self, read: &ReadRel) -> DaftResult<LogicalPlanBuilder> {
+        let Some(read_rel::ReadType::NamedTable(table)) = &read.read_type else {
+            return self.unsupported("reads of anything other than named tables".to_string());
+        };
+        let name = table.names.join(".");
+        let mut builder = self
+            .tables
+            .get(&name)
+            .ok_or_else(|| {
+                DaftError::ValueError(format!(
+                    "Substrait plan reads table `{name}`, which wasn't provided"
+                ))
+            })?
+            .clone();
+        // Fields are referred to by their position in the base schema, which may order the
+        // table's columns differently.
+        if let Some(base_schema) = &read.base_schema {
+            let names = from_named_struct(base_schema)?.names();
+            if names != builder.schema().names() {
+                builder = builder.select(names.into_iter().map(unresolved_col).collect())?;
+            }
+        }
+        for filter in [&read.filter, &read.best_effort_filter]
+            .into_iter()
+            .flatten()
+        {
+            let filter = self.expression(Some(filter), &builder, "read filter")?;
+            builder = builder.filter(filter)?;
+        }
+        if let Some(projection) = &read.projection {
+            let names = builder.schema().names();
+            let items = projection
+                .select
+                .as_ref()
+                .map_or(&[][..], |select| select.struct_items.as_slice());
+            let mut columns = Vec::with_capacity(items.len());
+            for item in items {
+                if item.child.is_some() {
+                    return self.unsupported("projections of nested fields".to_string());
+                }
+                let name = names.get(item.field as usize).ok_or_else(|| {
+                    DaftError::ValueError(format!(
+                        "Substrait projection field {} is out of bounds for a table with {} fields",
+                        item.field,
+                        names.len()
+                    ))
+                })?;
+                columns.push(unresolved_col(name.as_str()));
+            }
+            builder = select_unique(&builder, columns)?;
+        }
+        Ok(builder)
+    }
+
+    fn aggregate(&mut self, aggregate: &AggregateRel) -> DaftResult<LogicalPlanBuilder> {
+        let input = self.input(aggregate.input.as_deref(), "aggregate")?;
+        let groupby = match aggregate.groupings.as_slice() {
+            [] => Vec::new(),
+            [grouping] if grouping.expression_references.is_empty() => {
+                self.expressions(&grouping.grouping_expressions, &input)?
+            }
+            [grouping] => {
+                let expressions = grouping
+                    .expression_references
+                    .iter()
+                    .map(|index| {
+                        aggregate
+                            .grouping_expressions
+                            .get(*index as usize)
+                            .ok_or_else(|| {
+                                DaftError::ValueError(format!(
+                                    "Substrait grouping expression reference {index} is out of bounds"
+                                ))
+                            })
+                    })
+                    .collect::<DaftResult<Vec<_>>>()?;
+                self.expressions(expressions, &input)?
+            }
+            _ => return self.unsupported("aggregates with multiple groupings".to_string()),
+        };
+        let mut aggs = Vec::with_capacity(aggregate.measures.len());
+        for measure in &aggregate.measures {
+            let Some(function) = &measure.measure else {
+                return Err(DaftError::ValueError(
+                    "Substrait measure is missing its aggregate function".into(),
+                ));
+            };
+            if measure.filter.is_some() {
+                return self.unsupported("filtered measures".to_string());
+            }
+            let name = self.consumer.function_name(function.function_reference)?;
+            let Some(agg) = Aggregate::from_name(&name) else {
+                return self.unsupported(format!("aggregate function `{name}`"));
+            };
+            let args = function
+                .arguments
+                .iter()
+                .map(|arg| match &arg.arg_type {
+                    Some(substrait::function_argument::ArgType::Value(value)) => Ok(value),
+                    None => Err(DaftError::not_implemented(format!(
+                        "Substrait enum and type arguments to aggregate function `{name}`"
+                    ))),
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            let args = self.expressions(args, &input)?;
+            let distinct = function.invocation == AggregationInvocation::Distinct as i32;
+            aggs.push(agg.build(args, distinct, || {
+                let schema = input.schema();
+                Ok(unresolved_col(schema.get_field_at_index(0)?.name.as_str()))
+            })?);
+        }
+        if aggs.is_empty() {
+            return select_unique(&input, groupby)?.distinct();
+        }
+        let names = unique_names(groupby.iter().chain(&aggs).map(|expr| expr.name()));
+        let (groupby_names, agg_names) = names.split_at(groupby.len());
+        input.aggregate(aliased(aggs, agg_names), aliased(groupby, groupby_names))
+    }
+
+    fn join(&mut self, join: &JoinRel) -> DaftResult<LogicalPlanBuilder> {
+        let left = self.input(join.left.as_deref(), "join")?;
+        let right = self.input(join.right.as_deref(), "join")?;
+        let join_type = match join_rel::JoinType::try_from(join.r#type) {
+            Ok(join_rel::JoinType::Inner) => JoinType::Inner,
+            Ok(join_rel::JoinType::Left) => JoinType::Left,
+            Ok(join_rel::JoinType::Right) => JoinType::Right,
+            Ok(join_rel::JoinType::Outer) => JoinType::Outer,
+            Ok(join_rel::JoinType::LeftSemi) => JoinType::Semi,
+            Ok(join_rel::JoinType::LeftAnti) => JoinType::Anti,
+            Ok(other) => return self.unsupported(format!("joins of type {}", other.as_str_name())),
+            Err(_) => {
+                return Err(DaftError::ValueError(format!(
+                    "Unknown Substrait join type {}",
+                    join.r#type
+                )))
+            }
+        };
+        // Give the columns of the right side names that aren't on the left, so that Daft doesn't
+        // merge them and the output is the columns of the left side followed by those of the
+        // right.
+        let left_names = left.schema().names();
+        let right_names = right.schema().names();
+        let renamed = unique_names(left_names.iter().chain(&right_names).map(String::as_str));
+        let right = right.select(aliased(
+            right_names
+                .iter()
+                .map(|name| unresolved_col(name.as_str()))
+                .collect(),
+            &renamed[left_names.len()..],
+        ))?;
+        let expression = join
+            .expression
+            .as_ref()
+            .ok_or_else(|| DaftError::ValueError("Substrait join condition is missing".into()))?;
+        let condition = self
+            .expressions_over(
+                [expression],
+                left.schema()
+                    .fields()
+                    .iter()
+                    .chain(right.schema().fields())
+                    .cloned(),
+            )?
+            .remove(0);
+        let on = match condition.as_ref() {
+            Expr::Literal(LiteralValue::Boolean(true)) => None,
+            _ => Some(condition),
+        };
+        let mut builder = left.join(right, on, vec![], join_type, None, JoinOptions::default())?;
+        if let Some(filter) = &join.post_join_filter {
+            let filter = self.expression(Some(filter), &builder, "post-join filter")?;
+            builder = builder.filter(filter)?;
+        }
+        Ok(builder)
+    }
+
+    fn set(&mut self, set: &SetRel) -> DaftResult<LogicalPlanBuilder> {
+        let mut inputs = set
+            .inputs
+            .iter()
+            .map(|input| self.rel(input))
+            .collect::<DaftResult<Vec<_>>>()?
+            .into_iter();
+        let Some(mut builder) = inputs.next() else {
+            return Err(DaftError::ValueError(
+                "Substrait set relation has no inputs".into(),
+            ));
+        };
+        let op = SetOp::try_from(set.op).unwrap_or(SetOp::Unspecified);
+        for input in inputs {
+            builder = match op {
+                SetOp::UnionAll => {
+                    builder.union(&input, SetQuantifier::All, UnionStrategy::Positional)?
+                }
+                SetOp::UnionDistinct => {
+                    builder.union(&input, SetQuantifier::Distinct, UnionStrategy::Positional)?
+                }
+                // Intersections and differences match the columns of both sides by name.
+                SetOp::IntersectionPrimary | SetOp::IntersectionMultiset => builder.intersect(
+                    &renamed_like(&input, &builder)?,
+                    op == SetOp::IntersectionMultiset,
+                )?,
+                SetOp::MinusPrimary | SetOp::MinusMultiset => {
+                    builder.except(&renamed_like(&input, &builder)?, op == SetOp::MinusMultiset)?
+                }
+                SetOp::Unspecified => {
+                    return self.unsupported(format!("set operation {}", set.op));
+                }
+            };
+        }
+        Ok(builder)
+    }
+}
+
+/// Names that are unique among each other, made by suffixing repeated names with a number.
+fn unique_names<'a>(names: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut unique: Vec<String> = Vec::new();
+    for name in names {
+        let mut candidate = name.to_string();
+        let mut suffix = 1;
+        while unique.contains(&candidate) {
+            candidate = format!("{name}_{suffix}");
+            suffix += 1;
+        }
+        unique.push(candidate);
+    }
+    unique
+}
+
+fn aliased(exprs: Vec<ExprRef>, names: &[String]) -> Vec<ExprRef> {
+    exprs
+        .into_iter()
+        .zip(names)
+        .map(|(expr, name)| {
+            if expr.name() == name {
+                expr
+            } else {
+                expr.alias(name.as_str())
+            }
+        })
+        .collect()
+}
+
+/// Selects `columns`, renaming any whose name is repeated.
+fn select_unique(
+    builder: &LogicalPlanBuilder,
+    columns: Vec<ExprRef>,
+) -> DaftResult<LogicalPlanBuilder> {
+    let names = unique_names(columns.iter().map(|expr| expr.name()));
+    builder.select(aliased(columns, &names))
+}
+
+/// Renames the columns of `builder` to those of `like`, by position.
+fn renamed_like(
+    builder: &LogicalPlanBuilder,
+    like: &LogicalPlanBuilder,
+) -> DaftResult<LogicalPlanBuilder> {
+    let columns = builder
+        .schema()
+        .names()
+        .into_iter()
+        .map(unresolved_col)
+        .collect();
+    builder.select(aliased(columns, &like.schema().names()))
+}
+
+/// Converts a Substrait plan to a Daft plan, whose columns are named after the plan's root.
+///
+/// The tables that the plan reads are looked up in `tables` by name, with the parts of qualified
+/// names joined by `.`; [`plan_tables`] lists them. Fails with a list of what couldn't be
+/// converted if the plan uses a relation, function or construct that Daft doesn't support.
+pub fn plan_from_substrait(
+    plan: &Plan,
+    tables: &HashMap<String, LogicalPlanBuilder>,
+) -> DaftResult<LogicalPlanBuilder> {
+    let root = root(plan)?;
+    let input = root
+        .input
+        .as_ref()
+        .ok_or_else(|| DaftError::ValueError("Substrait plan root is missing its input".into()))?;
+    let mut consumer = PlanConsumer {
+        consumer: Consumer::new(Schema::new(Vec::<Field>::new()), &plan.extensions),
+        tables,
+    };
+    let builder = consumer.rel(input)?;
+    if root.names.is_empty() {
+        return Ok(builder);
+    }
+    // The root names every field depth-first, of which only the top-level names are used.
+    let schema = builder.schema();
+    let mut names = root.names.iter();
+    let mut columns = Vec::with_capacity(schema.len());
+    for field in schema.fields() {
+        let name = names.next().ok_or_else(|| {
+            DaftError::ValueError("Substrait plan root has fewer names than fields".into())
+        })?;
+        let mut nested = Vec::new();
+        push_nested_names(&field.dtype, &mut nested);
+        if !nested.is_empty() {
+            names.nth(nested.len() - 1);
+        }
+        columns.push(unresolved_col(field.name.as_str()).alias(name.as_str()));
+    }
+    if names.next().is_some() {
+        return Err(DaftError::ValueError(
+            "Substrait plan root has more names than fields".into(),
+        ));
+    }
+    builder.select(columns)
+}
@@ -0,0 +1,313 @@
+use std::collections::HashMap;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{struct_::StructExpr, FunctionExpr},
+    Expr, ExprRef, LiteralValue,
+};
+use substrait::{
+    expression::{
+        self, cast::FailureBehavior, field_reference, if_then::IfClause, literal::LiteralType,
+        reference_segment, FieldReference, IfThen, ReferenceSegment, RexType, SingularOrList,
+    },
+    expression_reference::ExprType,
+    function_argument::ArgType,
+    simple_extension_declaration::{ExtensionFunction, MappingType},
+    Expression, ExpressionReference, ExtendedExpression, FunctionArgument,
+    SimpleExtensionDeclaration, SimpleExtensionUri, Version,
+};
+
+use crate::{
+    extensions::Function,
+    types::{precision_of, push_nested_names, to_named_struct, to_substrait_type},
+};
+
+/// The parts of a set of expressions that can't be represented in Substrait.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CapabilityReport {
+    /// A description of each unsupported function, literal or type, in the order they appear.
+    pub unsupported: Vec<String>,
+}
+
+impl CapabilityReport {
+    /// Whether the expressions can be converted to Substrait in full.
+    #[must_use]
+    pub fn is_supported(&self) -> bool {
+        self.unsupported.is_empty()
+    }
+}
+
+/// Builds Substrait expressions and the extensions they reference, recording what can't be
+/// converted rather than stopping at the first unsupported expression so that the whole set can
+/// be reported at once.
+#[derive(Default)]
+pub(crate) struct Producer {
+    pub(crate) extension_uris: Vec<SimpleExtensionUri>,
+    pub(crate) extensions: Vec<SimpleExtensionDeclaration>,
+    anchors: HashMap<(&'static str, &'static str), u32>,
+    pub(crate) unsupported: Vec<String>,
+}
+
+impl Producer {
+    pub(crate) fn unsupported(&mut self, what: String) -> Expression {
+        if !self.unsupported.contains(&what) {
+            self.unsupported.push(what);
+        }
+        Expression::default()
+    }
+
+    /// The anchor of the function declaration with the given extension URI and name, declaring
+    /// it if it hasn't been yet.
+    pub(crate) fn function_reference(&mut self, (uri, name): (&'static str, &'static str)) -> u32 {
+        if let Some(anchor) = self.anchors.get(&(uri, name)) {
+            return *anchor;
+        }
+        let uri_anchor = match self.extension_uris.iter().find(|u| u.uri == uri) {
+            Some(u) => u.extension_uri_anchor,
+            None => {
+                let anchor = self.extension_uris.len() as u32 + 1;
+                self.extension_uris.push(SimpleExtensionUri {
+                    extension_uri_anchor: anchor,
+                    uri: uri.to_string(),
+                });
+                anchor
+            }
+        };
+        let anchor = self.anchors.len() as u32 + 1;
+        self.extensions.push(SimpleExtensionDeclaration {
+            mapping_type: Some(MappingType::ExtensionFunction(ExtensionFunction {
+                extension_uri_reference: uri_anchor,
+                function_anchor: anchor,
+                name: name.to_string(),
+            })),
+        });
+        self.anchors.insert((uri, name), anchor);
+        anchor
+    }
+
+    /// Converts `expr`, evaluated over `schema`, to a Substrait expression.
+    pub(crate) fn expression(&mut self, expr: &ExprRef, schema: &Schema) -> DaftResult<Expression> {
+        let rex_type = match expr.as_ref() {
+            Expr::Alias(input, _) => return self.expression(input, schema),
+            Expr::Column(_) => {
+                let index = schema.get_index(expr.name())?;
+                field_reference(
+                    index,
+                    field_reference::RootType::RootReference(field_reference::RootReference {}),
+                )
+            }
+            Expr::Function {
+                func: FunctionExpr::Struct(StructExpr::Get(name)),
+                inputs,
+            } if inputs.len() == 1 => {
+                let input = &inputs[0];
+                let DataType::Struct(fields) = input.to_field(schema)?.dtype else {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected input to struct get to be a struct, got {input}"
+                    )));
+                };
+                let index = fields
+                    .iter()
+                    .position(|field| &field.name == name)
+                    .ok_or_else(|| DaftError::FieldNotFound(name.clone()))?;
+                let root = self.expression(input, schema)?;
+                field_reference(index, field_reference::RootType::Expression(Box::new(root)))
+            }
+            Expr::Literal(value) => match literal(value) {
+                Some(literal) => RexType::Literal(literal),
+                None => return Ok(self.unsupported(format!("{} literal", value.get_type()))),
+            },
+            // Substrait null literals are typed, so they're produced from Daft's typed nulls.
+            Expr::Cast(input, dtype)
+                if matches!(input.as_ref(), Expr::Literal(LiteralValue::Null)) =>
+            {
+                let Some(ty) = to_substrait_type(dtype) else {
+                    return Ok(self.unsupported(format!("{dtype} literal")));
+                };
+                RexType::Literal(expression::Literal {
+                    nullable: true,
+                    type_variation_reference: 0,
+                    literal_type: Some(LiteralType::Null(ty)),
+                })
+            }
+            Expr::Cast(input, dtype) => {
+                let Some(ty) = to_substrait_type(dtype) else {
+                    return Ok(self.unsupported(format!("cast to {dtype}")));
+                };
+                RexType::Cast(Box::new(expression::Cast {
+                    r#type: Some(ty),
+                    input: Some(Box::new(self.expression(input, schema)?)),
+                    failure_behavior: FailureBehavior::Unspecified as i32,
+                }))
+            }
+            Expr::IfElse {
+                if_true,
+                if_false,
+                predicate,
+            } => RexType::IfThen(Box::new(IfThen {
+                ifs: vec![IfClause {
+                    r#if: Some(self.expression(predicate, schema)?),
+                    then: Some(self.expression(if_true, schema)?),
+                }],
+                r#else: Some(Box::new(self.expression(if_false, schema)?)),
+            })),
+            Expr::IsIn(input, items) => RexType::SingularOrList(Box::new(SingularOrList {
+                value: Some(Box::new(self.expression(input, schema)?)),
+                options: items
+                    .iter()
+                    .map(|item| self.expression(item, schema))
+                    .collect::<DaftResult<_>>()?,
+            })),
+            _ => {
+                let Some((function, args)) = Function::of(expr) else {
+                    return Ok(self.unsupported(describe(expr)));
+                };
+                let dtype = expr.to_field(schema)?.dtype;
+                let Some(output_type) = to_substrait_type(&dtype) else {
+                    return Ok(self.unsupported(format!("{dtype} values")));
+                };
+                let function_reference = self.function_reference(function.uri_and_name());
+                let arguments = args
+                    .iter()
+                    .map(|arg| {
+                        Ok(FunctionArgument {
+                            arg_type: Some(ArgType::Value(self.expression(arg, schema)?)),
+                        })
+                    })
+                    .collect::<DaftResult<_>>()?;
+                RexType::ScalarFunction(expression::ScalarFunction {
+                    function_reference,
+                    arguments,
+                    output_type: Some(output_type),
+                })
+            }
+        };
+        Ok(Expression {
+            rex_type: Some(rex_type),
+        })
+    }
+}
+
+pub(crate) fn field_reference(index: usize, root_type: field_reference::RootType) -> RexType {
+    let segment = ReferenceSegment {
+        reference_type: Some(reference_segment::ReferenceType::StructField(Box::new(
+            reference_segment::StructField {
+                field: index as i32,
+                child: None,
+            },
+        ))),
+    };
+    RexType::Selection(Box::new(FieldReference {
+        reference_type: Some(field_reference::ReferenceType::DirectReference(segment)),
+        root_type: Some(root_type),
+    }))
+}
+
+fn literal(value: &LiteralValue) -> Option<expression::Literal> {
+    let literal_type = match value {
+        LiteralValue::Boolean(v) => LiteralType::Boolean(*v),
+        LiteralValue::Int8(v) => LiteralType::I8(i32::from(*v)),
+        LiteralValue::Int16(v) => LiteralType::I16(i32::from(*v)),
+        LiteralValue::Int32(v) => LiteralType::I32(*v),
+        LiteralValue::Int64(v) => LiteralType::I64(*v),
+        LiteralValue::Float64(v) => LiteralType::Fp64(*v),
+        LiteralValue::Utf8(v) => LiteralType::String(v.clone()),
+        LiteralValue::Binary(v) => LiteralType::Binary(v.clone()),
+        LiteralValue::Date(v) => LiteralType::Date(*v),
+        LiteralValue::Time(v, TimeUnit::Microseconds) => LiteralType::Time(*v),
+        LiteralValue::Timestamp(v, time_unit, tz) => {
+            let ts = expression::literal::PrecisionTimestamp {
+                precision: precision_of(*time_unit),
+                value: *v,
+            };
+            if tz.is_some() {
+                LiteralType::PrecisionTimestampTz(ts)
+            } else {
+                LiteralType::PrecisionTimestamp(ts)
+            }
+        }
+        LiteralValue::Decimal(v, precision, scale) => {
+            LiteralType::Decimal(expression::literal::Decimal {
+                value: v.to_le_bytes().to_vec(),
+                precision: i32::from(*precision),
+                scale: i32::from(*scale),
+            })
+        }
+        _ => return None,
+    };
+    Some(expression::Literal {
+        nullable: false,
+        type_variation_reference: 0,
+        literal_type: Some(literal_type),
+    })
+}
+
+pub(crate) fn describe(expr: &Expr) -> String {
+    match expr {
+        Expr::BinaryOp { op, .. } => format!("operator `{op}`"),
+        Expr::ScalarFunction(func) => format!("function `{}`", func.name()),
+        Expr::Agg(agg) => format!("aggregation `{agg}`"),
+        Expr::Subquery(_) | Expr::InSubquery(..) | Expr::Exists(_) => "subqueries".to_string(),
+        Expr::Over(..) | Expr::WindowFunction(_) => "window functions".to_string(),
+        _ => format!("expression `{expr}`"),
+    }
+}
+
+/// The Substrait version that Daft produces.
+pub(crate) fn version() -> Version {
+    Version {
+        major_number: 0,
+        minor_number: 53,
+        patch_number: 0,
+        git_hash: String::new(),
+        producer: "daft".to_string(),
+    }
+}
+
+fn produce(exprs: &[ExprRef], schema: &Schema) -> DaftResult<(ExtendedExpression, Vec<String>)> {
+    let mut producer = Producer::default();
+    let mut referred_expr = Vec::with_capacity(exprs.len());
+    for expr in exprs {
+        let expression = producer.expression(expr, schema)?;
+        let field = expr.to_field(schema)?;
+        let mut output_names = vec![field.name];
+        push_nested_names(&field.dtype, &mut output_names);
+        referred_expr.push(ExpressionReference {
+            expr_type: Some(ExprType::Expression(expression)),
+            output_names,
+        });
+    }
+    let extended = ExtendedExpression {
+        version: Some(version()),
+        extension_uris: producer.extension_uris,
+        extensions: producer.extensions,
+        referred_expr,
+        base_schema: Some(to_named_struct(schema)?),
+        expected_type_urls: Vec::new(),
+    };
+    Ok((extended, producer.unsupported))
+}
+
+/// Reports which parts of `exprs`, evaluated over `schema`, can't be converted to Substrait.
+pub fn capability_report(exprs: &[ExprRef], schema: &Schema) -> DaftResult<CapabilityReport> {
+    let (_, unsupported) = produce(exprs, schema)?;
+    Ok(CapabilityReport { unsupported })
+}
+
+/// Converts expressions over `schema` to a Substrait extended expression, each named after its
+/// output column.
+///
+/// Fails with a list of everything that couldn't be converted if any expression uses a function,
+/// literal or type that has no Substrait equivalent.
+pub fn to_substrait(exprs: &[ExprRef], schema: &Schema) -> DaftResult<ExtendedExpression> {
+    let (extended, unsupported) = produce(exprs, schema)?;
+    if unsupported.is_empty() {
+        Ok(extended)
+    } else {
+        Err(DaftError::not_implemented(format!(
+            "Converting {} to Substrait",
+            unsupported.join(", ")
+        )))
+    }
+}
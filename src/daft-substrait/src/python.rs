@@ -0,0 +1,81 @@
+use std::{collections::HashMap, sync::Arc};
+
+use daft_core::python::PySchema;
+use daft_dsl::{python::PyExpr, ExprRef};
+use daft_logical_plan::PyLogicalPlanBuilder;
+use prost::Message;
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyBytes};
+use substrait::{ExtendedExpression, Plan};
+
+#[pyfunction]
+pub fn substrait_from_expressions<'py>(
+    py: Python<'py>,
+    exprs: Vec<PyExpr>,
+    schema: &PySchema,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let exprs = exprs.into_iter().map(ExprRef::from).collect::<Vec<_>>();
+    let extended = crate::to_substrait(&exprs, &schema.schema)?;
+    Ok(PyBytes::new(py, &extended.encode_to_vec()))
+}
+
+#[pyfunction]
+pub fn substrait_to_expressions(bytes: &[u8]) -> PyResult<(Vec<PyExpr>, PySchema)> {
+    let extended = ExtendedExpression::decode(bytes).map_err(|e| {
+        PyValueError::new_err(format!(
+            "Failed to decode Substrait extended expression: {e}"
+        ))
+    })?;
+    let (exprs, schema) = crate::from_substrait(&extended)?;
+    Ok((
+        exprs.into_iter().map(PyExpr::from).collect(),
+        Arc::new(schema).into(),
+    ))
+}
+
+#[pyfunction]
+pub fn substrait_capability_report(exprs: Vec<PyExpr>, schema: &PySchema) -> PyResult<Vec<String>> {
+    let exprs = exprs.into_iter().map(ExprRef::from).collect::<Vec<_>>();
+    Ok(crate::capability_report(&exprs, &schema.schema)?.unsupported)
+}
+
+fn decode_plan(bytes: &[u8]) -> PyResult<Plan> {
+    Plan::decode(bytes)
+        .map_err(|e| PyValueError::new_err(format!("Failed to decode Substrait plan: {e}")))
+}
+
+#[pyfunction]
+pub fn substrait_from_plan<'py>(
+    py: Python<'py>,
+    builder: &PyLogicalPlanBuilder,
+    tables: Vec<(String, PyLogicalPlanBuilder)>,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let plan = py.allow_threads(|| {
+        let plan = builder
+            .builder
+            .optimize_without_scan_materialization()?
+            .build();
+        let tables = tables
+            .into_iter()
+            .map(|(name, table)| (name, table.builder.build()))
+            .collect::<Vec<_>>();
+        crate::plan_to_substrait(&plan, &tables)
+    })?;
+    Ok(PyBytes::new(py, &plan.encode_to_vec()))
+}
+
+#[pyfunction]
+pub fn substrait_plan_tables(bytes: &[u8]) -> PyResult<Vec<String>> {
+    Ok(crate::plan_tables(&decode_plan(bytes)?)?)
+}
+
+#[pyfunction]
+pub fn substrait_to_plan(
+    bytes: &[u8],
+    tables: HashMap<String, PyLogicalPlanBuilder>,
+) -> PyResult<PyLogicalPlanBuilder> {
+    let tables = tables
+        .into_iter()
+        .map(|(name, table)| (name, table.builder))
+        .collect();
+    Ok(crate::plan_from_substrait(&decode_plan(bytes)?, &tables)?.into())
+}
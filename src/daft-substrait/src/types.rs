@@ -0,0 +1,225 @@
+//! Conversions between Daft data types and Substrait types.
+//!
+//! Daft fields are always nullable, so every type is produced as nullable and the nullability
+//! of consumed types is ignored. Timestamps with a timezone become Substrait's
+//! `precision_timestamp_tz`, which has no timezone of its own, and are consumed as UTC.
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use substrait::{r#type, NamedStruct, Type};
+
+const NULLABLE: i32 = r#type::Nullability::Nullable as i32;
+
+pub(crate) fn precision_of(time_unit: TimeUnit) -> i32 {
+    match time_unit {
+        TimeUnit::Seconds => 0,
+        TimeUnit::Milliseconds => 3,
+        TimeUnit::Microseconds => 6,
+        TimeUnit::Nanoseconds => 9,
+    }
+}
+
+pub(crate) fn time_unit_of(precision: i32) -> DaftResult<TimeUnit> {
+    match precision {
+        0 => Ok(TimeUnit::Seconds),
+        3 => Ok(TimeUnit::Milliseconds),
+        6 => Ok(TimeUnit::Microseconds),
+        9 => Ok(TimeUnit::Nanoseconds),
+        _ => Err(DaftError::not_implemented(format!(
+            "Substrait timestamps with a precision of {precision}"
+        ))),
+    }
+}
+
+/// The Substrait type of `dtype`, or `None` if it has no Substrait equivalent.
+pub(crate) fn to_substrait_type(dtype: &DataType) -> Option<Type> {
+    use r#type::Kind;
+
+    macro_rules! simple {
+        ($kind:ident, $ty:ident) => {
+            Kind::$kind(r#type::$ty {
+                type_variation_reference: 0,
+                nullability: NULLABLE,
+            })
+        };
+    }
+
+    let kind = match dtype {
+        DataType::Boolean => simple!(Bool, Boolean),
+        DataType::Int8 => simple!(I8, I8),
+        DataType::Int16 => simple!(I16, I16),
+        DataType::Int32 => simple!(I32, I32),
+        DataType::Int64 => simple!(I64, I64),
+        DataType::Float32 => simple!(Fp32, Fp32),
+        DataType::Float64 => simple!(Fp64, Fp64),
+        DataType::Utf8 => simple!(String, String),
+        DataType::Binary => simple!(Binary, Binary),
+        DataType::Date => simple!(Date, Date),
+        DataType::Time(TimeUnit::Microseconds) => simple!(Time, Time),
+        DataType::Decimal128(precision, scale) => Kind::Decimal(r#type::Decimal {
+            scale: *scale as i32,
+            precision: *precision as i32,
+            type_variation_reference: 0,
+            nullability: NULLABLE,
+        }),
+        DataType::Timestamp(time_unit, None) => {
+            Kind::PrecisionTimestamp(r#type::PrecisionTimestamp {
+                precision: precision_of(*time_unit),
+                type_variation_reference: 0,
+                nullability: NULLABLE,
+            })
+        }
+        DataType::Timestamp(time_unit, Some(_)) => {
+            Kind::PrecisionTimestampTz(r#type::PrecisionTimestampTz {
+                precision: precision_of(*time_unit),
+                type_variation_reference: 0,
+                nullability: NULLABLE,
+            })
+        }
+        DataType::List(child) => Kind::List(Box::new(r#type::List {
+            r#type: Some(Box::new(to_substrait_type(child)?)),
+            type_variation_reference: 0,
+            nullability: NULLABLE,
+        })),
+        DataType::Struct(fields) => Kind::Struct(r#type::Struct {
+            types: fields
+                .iter()
+                .map(|field| to_substrait_type(&field.dtype))
+                .collect::<Option<_>>()?,
+            type_variation_reference: 0,
+            nullability: NULLABLE,
+        }),
+        _ => return None,
+    };
+    Some(Type { kind: Some(kind) })
+}
+
+/// The Daft data type of `ty`. Struct fields are named after their positions, since Substrait
+/// types don't carry field names; [`from_named_struct`] restores the names of schema fields.
+pub(crate) fn from_substrait_type(ty: &Type) -> DaftResult<DataType> {
+    use r#type::Kind;
+
+    let Some(kind) = &ty.kind else {
+        return Err(DaftError::not_implemented(
+            "Substrait types other than booleans, numbers, strings, binaries, dates, times, timestamps, decimals, lists and structs",
+        ));
+    };
+    Ok(match kind {
+        Kind::Bool(_) => DataType::Boolean,
+        Kind::I8(_) => DataType::Int8,
+        Kind::I16(_) => DataType::Int16,
+        Kind::I32(_) => DataType::Int32,
+        Kind::I64(_) => DataType::Int64,
+        Kind::Fp32(_) => DataType::Float32,
+        Kind::Fp64(_) => DataType::Float64,
+        Kind::String(_) => DataType::Utf8,
+        Kind::Binary(_) => DataType::Binary,
+        Kind::Date(_) => DataType::Date,
+        Kind::Time(_) => DataType::Time(TimeUnit::Microseconds),
+        Kind::Decimal(decimal) => {
+            DataType::Decimal128(decimal.precision as usize, decimal.scale as usize)
+        }
+        Kind::PrecisionTimestamp(ts) => DataType::Timestamp(time_unit_of(ts.precision)?, None),
+        Kind::PrecisionTimestampTz(ts) => {
+            DataType::Timestamp(time_unit_of(ts.precision)?, Some("UTC".to_string()))
+        }
+        Kind::List(list) => {
+            let child = list.r#type.as_deref().ok_or_else(|| {
+                DaftError::ValueError("Substrait list type is missing its element type".into())
+            })?;
+            DataType::List(Box::new(from_substrait_type(child)?))
+        }
+        Kind::Struct(st) => DataType::Struct(
+            st.types
+                .iter()
+                .enumerate()
+                .map(|(i, ty)| Ok(Field::new(format!("f{i}"), from_substrait_type(ty)?)))
+                .collect::<DaftResult<_>>()?,
+        ),
+    })
+}
+
+/// Appends the names of `dtype`'s nested struct fields to `names` in depth-first order.
+pub(crate) fn push_nested_names(dtype: &DataType, names: &mut Vec<String>) {
+    match dtype {
+        DataType::Struct(fields) => {
+            for field in fields {
+                names.push(field.name.clone());
+                push_nested_names(&field.dtype, names);
+            }
+        }
+        DataType::List(child) => push_nested_names(child, names),
+        _ => {}
+    }
+}
+
+pub(crate) fn to_named_struct(schema: &Schema) -> DaftResult<NamedStruct> {
+    let mut names = Vec::new();
+    let types = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            names.push(field.name.clone());
+            push_nested_names(&field.dtype, &mut names);
+            to_substrait_type(&field.dtype).ok_or_else(|| {
+                DaftError::not_implemented(format!(
+                    "Substrait has no equivalent of the {} type of column `{}`",
+                    field.dtype, field.name
+                ))
+            })
+        })
+        .collect::<DaftResult<_>>()?;
+    Ok(NamedStruct {
+        names,
+        r#struct: Some(r#type::Struct {
+            types,
+            type_variation_reference: 0,
+            nullability: r#type::Nullability::Required as i32,
+        }),
+    })
+}
+
+/// Replaces the positional names of `dtype`'s nested struct fields with the next of `names`.
+fn rename_nested(
+    dtype: DataType,
+    names: &mut impl Iterator<Item = String>,
+) -> DaftResult<DataType> {
+    Ok(match dtype {
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .into_iter()
+                .map(|field| {
+                    let name = names.next().ok_or_else(too_few_names)?;
+                    Ok(Field::new(name, rename_nested(field.dtype, names)?))
+                })
+                .collect::<DaftResult<_>>()?,
+        ),
+        DataType::List(child) => DataType::List(Box::new(rename_nested(*child, names)?)),
+        dtype => dtype,
+    })
+}
+
+fn too_few_names() -> DaftError {
+    DaftError::ValueError("Substrait schema has fewer names than fields".into())
+}
+
+pub(crate) fn from_named_struct(named_struct: &NamedStruct) -> DaftResult<Schema> {
+    let types = named_struct
+        .r#struct
+        .as_ref()
+        .map_or(&[][..], |st| st.types.as_slice());
+    let mut names = named_struct.names.iter().cloned();
+    let fields = types
+        .iter()
+        .map(|ty| {
+            let name = names.next().ok_or_else(too_few_names)?;
+            let dtype = rename_nested(from_substrait_type(ty)?, &mut names)?;
+            Ok(Field::new(name, dtype))
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    if names.next().is_some() {
+        return Err(DaftError::ValueError(
+            "Substrait schema has more names than fields".into(),
+        ));
+    }
+    Ok(Schema::new(fields))
+}
@@ -0,0 +1,126 @@
+//! Inverted file lists, which cluster the vectors of a shard with k-means so that a search only
+//! has to compare a query with the vectors of the clusters closest to it.
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{squared_l2, Metric};
+
+/// The number of k-means iterations to run if the clusters haven't converged before.
+const MAX_ITERATIONS: usize = 10;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct InvertedLists {
+    /// The centroid of each list, one after another.
+    centroids: Vec<f32>,
+    /// List `i` holds the shard's vectors `offsets[i]..offsets[i + 1]`.
+    offsets: Vec<usize>,
+}
+
+fn nearest_centroid(centroids: &[f32], vector: &[f32]) -> usize {
+    centroids
+        .chunks_exact(vector.len())
+        .map(|centroid| squared_l2(centroid, vector))
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map_or(0, |(i, _)| i)
+}
+
+impl InvertedLists {
+    /// Clusters `vectors` into at most `num_lists` lists, returning the lists and the order to
+    /// put the vectors in so that each list is contiguous.
+    pub(crate) fn build(vectors: &[f32], dimension: usize, num_lists: usize) -> (Self, Vec<usize>) {
+        let len = vectors.len() / dimension.max(1);
+        let num_lists = num_lists.clamp(1, len.max(1));
+        let vector = |i: usize| &vectors[i * dimension..(i + 1) * dimension];
+
+        // Start from evenly spaced vectors so that building is deterministic.
+        let mut centroids = if len == 0 {
+            vec![]
+        } else {
+            (0..num_lists)
+                .flat_map(|list| vector(list * len / num_lists))
+                .copied()
+                .collect::<Vec<_>>()
+        };
+        let mut assignments = vec![0; len];
+        for iteration in 0..MAX_ITERATIONS {
+            let mut changed = false;
+            for (i, assignment) in assignments.iter_mut().enumerate() {
+                let nearest = nearest_centroid(&centroids, vector(i));
+                changed |= nearest != *assignment;
+                *assignment = nearest;
+            }
+            if iteration > 0 && !changed {
+                break;
+            }
+            let mut sums = vec![0.0; centroids.len()];
+            let mut counts = vec![0usize; num_lists];
+            for (i, &list) in assignments.iter().enumerate() {
+                counts[list] += 1;
+                let sum = &mut sums[list * dimension..(list + 1) * dimension];
+                sum.iter_mut().zip(vector(i)).for_each(|(s, x)| *s += x);
+            }
+            // Lists that lost all of their vectors keep their previous centroid.
+            for (list, &count) in counts.iter().enumerate().filter(|(_, &c)| c > 0) {
+                let range = list * dimension..(list + 1) * dimension;
+                for (c, s) in centroids[range.clone()].iter_mut().zip(&sums[range]) {
+                    *c = s / count as f32;
+                }
+            }
+        }
+
+        let mut order = (0..len).collect::<Vec<_>>();
+        order.sort_by_key(|&i| assignments[i]);
+        let mut offsets = vec![0; num_lists + 1];
+        for &list in &assignments {
+            offsets[list + 1] += 1;
+        }
+        for list in 0..num_lists {
+            offsets[list + 1] += offsets[list];
+        }
+        (Self { centroids, offsets }, order)
+    }
+
+    /// The ranges of the vectors in the `num_probes` lists whose centroids are closest to
+    /// `query`.
+    pub(crate) fn probe(
+        &self,
+        query: &[f32],
+        metric: Metric,
+        num_probes: usize,
+    ) -> impl Iterator<Item = Range<usize>> + '_ {
+        let mut lists = self
+            .centroids
+            .chunks_exact(query.len())
+            .map(|centroid| metric.distance(query, centroid))
+            .enumerate()
+            .collect::<Vec<_>>();
+        lists.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        lists
+            .into_iter()
+            .take(num_probes)
+            .map(|(list, _)| self.offsets[list]..self.offsets[list + 1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let vectors = [0.0, 0.0, 10.0, 10.0, 0.1, 0.0, 10.0, 9.9, 0.0, 0.1];
+        let (lists, order) = InvertedLists::build(&vectors, 2, 2);
+        assert_eq!(lists.offsets, vec![0, 3, 5]);
+        assert_eq!(order, vec![0, 2, 4, 1, 3]);
+
+        let probed = lists.probe(&[9.0, 9.0], Metric::L2, 1).collect::<Vec<_>>();
+        assert_eq!(probed, vec![3..5]);
+
+        let (lists, order) = InvertedLists::build(&[], 2, 4);
+        assert_eq!(lists.offsets, vec![0, 0]);
+        assert!(order.is_empty());
+        assert_eq!(lists.probe(&[0.0, 0.0], Metric::L2, 1).count(), 0);
+    }
+}
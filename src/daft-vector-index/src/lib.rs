@@ -0,0 +1,618 @@
+//! Indexes over embedding vectors for nearest-neighbor search.
+//!
+//! A [`VectorIndex`] is built from the partitions of a DataFrame, with one shard per partition,
+//! and answers top-k queries by merging the neighbors found in each shard. A flat index compares
+//! a query with every vector, which is exact. An IVF index clusters the vectors of each shard into
+//! lists and only compares a query with the vectors of the lists closest to it, which is
+//! approximate but much faster on large shards.
+mod ivf;
+#[cfg(feature = "python")]
+pub mod python;
+
+use std::{cmp::Ordering, collections::BinaryHeap, fmt, str::FromStr};
+
+use arrow2::{bitmap::Bitmap, offset::OffsetsBuffer};
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// How the distance between two vectors is measured, where smaller distances are closer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Metric {
+    /// One minus the cosine similarity.
+    Cosine,
+    /// The Euclidean distance.
+    L2,
+    /// The negated dot product.
+    Dot,
+}
+
+impl Metric {
+    fn distance(self, a: &[f32], b: &[f32]) -> f32 {
+        match self {
+            // Vectors are normalized when they're indexed or queried with this metric.
+            Self::Cosine => 1.0 - dot(a, b),
+            Self::L2 => squared_l2(a, b).sqrt(),
+            Self::Dot => -dot(a, b),
+        }
+    }
+}
+
+impl FromStr for Metric {
+    type Err = DaftError;
+
+    fn from_str(s: &str) -> DaftResult<Self> {
+        match s {
+            "cosine" => Ok(Self::Cosine),
+            "l2" => Ok(Self::L2),
+            "dot" => Ok(Self::Dot),
+            _ => Err(DaftError::ValueError(format!(
+                "Unknown vector distance metric: {s}, expected one of: cosine, l2, dot"
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cosine => write!(f, "cosine"),
+            Self::L2 => write!(f, "l2"),
+            Self::Dot => write!(f, "dot"),
+        }
+    }
+}
+
+/// How the vectors of each shard are searched.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IndexKind {
+    /// Compare queries with every vector.
+    Flat,
+    /// Cluster the vectors of each shard into `num_lists` lists, or the square root of the
+    /// shard's size if unset, and compare queries with the vectors of the `num_probes` lists
+    /// whose centroids are closest to them.
+    Ivf {
+        num_lists: Option<usize>,
+        num_probes: usize,
+    },
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+fn squared_l2(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(a, b)| (a - b) * (a - b)).sum()
+}
+
+fn normalize(vector: &mut [f32]) {
+    let norm = dot(vector, vector).sqrt();
+    if norm > 0.0 {
+        vector.iter_mut().for_each(|x| *x /= norm);
+    }
+}
+
+/// The dimension of the vectors of `dtype`, which must be a fixed-size list or embedding of
+/// numbers.
+fn vector_dimension(dtype: &DataType) -> DaftResult<usize> {
+    match dtype {
+        DataType::FixedSizeList(child, size) | DataType::Embedding(child, size)
+            if child.is_numeric() =>
+        {
+            Ok(*size)
+        }
+        _ => Err(DaftError::TypeError(format!(
+            "Expected vectors to be a fixed-size list or embedding of numbers, but received {dtype}"
+        ))),
+    }
+}
+
+/// The rows of a series of vectors, flattened into a single buffer of `f32`s.
+struct Vectors {
+    values: Vec<f32>,
+    validity: Option<Bitmap>,
+    dimension: usize,
+}
+
+impl Vectors {
+    fn try_new(series: &Series, dimension: usize) -> DaftResult<Self> {
+        if vector_dimension(series.data_type())? != dimension {
+            return Err(DaftError::ValueError(format!(
+                "Expected vectors of dimension {dimension}, but received {}",
+                series.data_type()
+            )));
+        }
+        let series = series.as_physical()?.cast(&DataType::FixedSizeList(
+            Box::new(DataType::Float32),
+            dimension,
+        ))?;
+        let array = series.fixed_size_list()?;
+        let values = array.flat_child.f32()?;
+        // a vector is only usable if the list and every one of its elements are valid
+        let validity = match values.validity() {
+            None => array.validity().cloned(),
+            Some(child_validity) => Some(Bitmap::from_iter((0..array.len()).map(|i| {
+                array.validity().is_none_or(|v| v.get_bit(i))
+                    && (i * dimension..(i + 1) * dimension).all(|j| child_validity.get_bit(j))
+            }))),
+        };
+        Ok(Self {
+            values: values.as_slice().to_vec(),
+            validity,
+            dimension,
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.values.len() / self.dimension.max(1)
+    }
+
+    fn is_valid(&self, idx: usize) -> bool {
+        self.validity.as_ref().is_none_or(|v| v.get_bit(idx))
+    }
+
+    fn get(&self, idx: usize) -> &[f32] {
+        &self.values[idx * self.dimension..(idx + 1) * self.dimension]
+    }
+}
+
+/// A candidate neighbor, ordered by distance and then by position so that ties are broken
+/// deterministically.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Neighbor {
+    distance: f32,
+    index: usize,
+}
+
+impl Eq for Neighbor {}
+
+impl PartialOrd for Neighbor {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Neighbor {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then(self.index.cmp(&other.index))
+    }
+}
+
+/// The `k` closest neighbors seen so far, with the farthest of them on top.
+struct TopK {
+    k: usize,
+    heap: BinaryHeap<Neighbor>,
+}
+
+impl TopK {
+    fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    fn push(&mut self, neighbor: Neighbor) {
+        if self.heap.len() < self.k {
+            self.heap.push(neighbor);
+        } else if self
+            .heap
+            .peek()
+            .is_some_and(|farthest| neighbor < *farthest)
+        {
+            self.heap.pop();
+            self.heap.push(neighbor);
+        }
+    }
+
+    fn into_sorted_vec(self) -> Vec<Neighbor> {
+        self.heap.into_sorted_vec()
+    }
+}
+
+/// The vectors of one partition of the indexed data.
+#[derive(Debug, Serialize, Deserialize)]
+struct Shard {
+    /// The position of the shard's first vector among the ids of the index.
+    offset: usize,
+    /// The shard's vectors, one after another.
+    vectors: Vec<f32>,
+    lists: Option<ivf::InvertedLists>,
+}
+
+impl Shard {
+    fn search(&self, query: &[f32], metric: Metric, num_probes: Option<usize>, top_k: &mut TopK) {
+        let dimension = query.len();
+        let mut scan = |range: std::ops::Range<usize>| {
+            for pos in range {
+                let vector = &self.vectors[pos * dimension..(pos + 1) * dimension];
+                top_k.push(Neighbor {
+                    distance: metric.distance(query, vector),
+                    index: self.offset + pos,
+                });
+            }
+        };
+        match (&self.lists, num_probes) {
+            (Some(lists), Some(num_probes)) => {
+                lists.probe(query, metric, num_probes).for_each(scan);
+            }
+            _ => scan(0..self.vectors.len() / dimension.max(1)),
+        }
+    }
+}
+
+/// An index over the vectors of a column, which finds the ids of the rows closest to a query.
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "daft.daft", name = "PyVectorIndex")
+)]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VectorIndex {
+    column: String,
+    dimension: usize,
+    metric: Metric,
+    kind: IndexKind,
+    /// The ids of the indexed rows, in the order of the shards' vectors.
+    ids: Series,
+    shards: Vec<Shard>,
+}
+
+impl VectorIndex {
+    /// Creates an empty index over the vectors of `vector_field`, identified by the values of
+    /// `id_field`.
+    pub fn try_new(
+        vector_field: &Field,
+        id_field: &Field,
+        metric: Metric,
+        kind: IndexKind,
+    ) -> DaftResult<Self> {
+        if matches!(
+            kind,
+            IndexKind::Ivf {
+                num_lists: Some(0),
+                ..
+            } | IndexKind::Ivf { num_probes: 0, .. }
+        ) {
+            return Err(DaftError::ValueError(
+                "An IVF index must have at least one list and probe at least one of them"
+                    .to_string(),
+            ));
+        }
+        Ok(Self {
+            column: vector_field.name.clone(),
+            dimension: vector_dimension(&vector_field.dtype)?,
+            metric,
+            kind,
+            ids: Series::empty(&id_field.name, &id_field.dtype),
+            shards: vec![],
+        })
+    }
+
+    pub fn column(&self) -> &str {
+        &self.column
+    }
+
+    pub fn id_column(&self) -> &str {
+        self.ids.name()
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    pub fn metric(&self) -> Metric {
+        self.metric
+    }
+
+    pub fn kind(&self) -> IndexKind {
+        self.kind
+    }
+
+    /// The number of indexed vectors.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    pub fn num_shards(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// Adds a partition of vectors and their ids to the index as a new shard, skipping null
+    /// vectors.
+    pub fn add_partition(&mut self, vectors: &Series, ids: &Series) -> DaftResult<()> {
+        if vectors.len() != ids.len() {
+            return Err(DaftError::ValueError(format!(
+                "Expected as many ids as vectors, but received {} ids for {} vectors",
+                ids.len(),
+                vectors.len()
+            )));
+        }
+        if ids.data_type() != self.ids.data_type() {
+            return Err(DaftError::TypeError(format!(
+                "Expected ids of type {}, but received {}",
+                self.ids.data_type(),
+                ids.data_type()
+            )));
+        }
+        let vectors = Vectors::try_new(vectors, self.dimension)?;
+        let mut order = (0..vectors.len())
+            .filter(|&i| vectors.is_valid(i))
+            .collect::<Vec<_>>();
+        let mut values = Vec::with_capacity(order.len() * self.dimension);
+        for &i in &order {
+            values.extend_from_slice(vectors.get(i));
+            if self.metric == Metric::Cosine {
+                let start = values.len() - self.dimension;
+                normalize(&mut values[start..]);
+            }
+        }
+
+        let lists = match self.kind {
+            IndexKind::Flat => None,
+            IndexKind::Ivf { num_lists, .. } => {
+                let num_lists = num_lists.unwrap_or_else(|| (order.len() as f64).sqrt() as usize);
+                let (lists, list_order) =
+                    ivf::InvertedLists::build(&values, self.dimension, num_lists);
+                values = list_order
+                    .iter()
+                    .flat_map(|&i| &values[i * self.dimension..(i + 1) * self.dimension])
+                    .copied()
+                    .collect();
+                order = list_order.into_iter().map(|i| order[i]).collect();
+                Some(lists)
+            }
+        };
+
+        let order = UInt64Array::from((
+            "order",
+            order.into_iter().map(|i| i as u64).collect::<Vec<_>>(),
+        ))
+        .into_series();
+        let ids = ids.take(&order)?.rename(self.ids.name());
+        self.shards.push(Shard {
+            offset: self.ids.len(),
+            vectors: values,
+            lists,
+        });
+        self.ids = Series::concat(&[&self.ids, &ids])?;
+        Ok(())
+    }
+
+    /// Finds the `k` nearest neighbors of each query, returning a list of structs with the `id`
+    /// and `distance` of each neighbor, closest first.
+    ///
+    /// `num_probes` overrides the number of lists that an IVF index searches per shard.
+    pub fn search(
+        &self,
+        queries: &Series,
+        k: usize,
+        num_probes: Option<usize>,
+    ) -> DaftResult<Series> {
+        let queries_vectors = Vectors::try_new(queries, self.dimension)?;
+        let num_probes = match (self.kind, num_probes) {
+            (IndexKind::Flat, _) => None,
+            (IndexKind::Ivf { .. }, Some(0)) => {
+                return Err(DaftError::ValueError(
+                    "An IVF index must probe at least one list".to_string(),
+                ))
+            }
+            (IndexKind::Ivf { num_probes, .. }, None) => Some(num_probes),
+            (IndexKind::Ivf { .. }, Some(num_probes)) => Some(num_probes),
+        };
+
+        let mut positions = vec![];
+        let mut distances = vec![];
+        let mut offsets = Vec::with_capacity(queries_vectors.len() + 1);
+        offsets.push(0i64);
+        let mut query = vec![0.0; self.dimension];
+        for i in 0..queries_vectors.len() {
+            if queries_vectors.is_valid(i) {
+                query.copy_from_slice(queries_vectors.get(i));
+                if self.metric == Metric::Cosine {
+                    normalize(&mut query);
+                }
+                let mut top_k = TopK::new(k);
+                for shard in &self.shards {
+                    shard.search(&query, self.metric, num_probes, &mut top_k);
+                }
+                for neighbor in top_k.into_sorted_vec() {
+                    positions.push(neighbor.index as u64);
+                    distances.push(f64::from(neighbor.distance));
+                }
+            }
+            offsets.push(positions.len() as i64);
+        }
+
+        let ids = self
+            .ids
+            .take(&UInt64Array::from(("positions", positions)).into_series())?
+            .rename("id");
+        let distances = Float64Array::from(("distance", distances)).into_series();
+        let neighbor_dtype = DataType::Struct(vec![
+            Field::new("id", ids.data_type().clone()),
+            Field::new("distance", DataType::Float64),
+        ]);
+        let neighbors = StructArray::new(
+            Field::new("neighbors", neighbor_dtype.clone()),
+            vec![ids, distances],
+            None,
+        )
+        .into_series();
+        Ok(ListArray::new(
+            Field::new(queries.name(), DataType::List(Box::new(neighbor_dtype))),
+            neighbors,
+            OffsetsBuffer::try_from(offsets)?,
+            queries_vectors.validity,
+        )
+        .into_series())
+    }
+
+    /// Serializes the index, so that it can be persisted and loaded with [`Self::from_bytes`].
+    pub fn to_bytes(&self) -> DaftResult<Vec<u8>> {
+        bincode::serialize(self)
+            .map_err(|e| DaftError::ValueError(format!("Failed to serialize vector index: {e}")))
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> DaftResult<Self> {
+        bincode::deserialize(bytes)
+            .map_err(|e| DaftError::ValueError(format!("Invalid vector index: {e}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vectors(name: &str, rows: &[Option<[f32; 2]>]) -> Series {
+        let values = rows
+            .iter()
+            .flat_map(|row| row.unwrap_or_default())
+            .collect::<Vec<_>>();
+        let validity = Bitmap::from_iter(rows.iter().map(Option::is_some));
+        FixedSizeListArray::new(
+            Field::new(
+                name,
+                DataType::FixedSizeList(Box::new(DataType::Float32), 2),
+            ),
+            Float32Array::from(("item", values)).into_series(),
+            Some(validity),
+        )
+        .into_series()
+    }
+
+    fn ids(values: Vec<i64>) -> Series {
+        Int64Array::from(("id", values)).into_series()
+    }
+
+    fn index(metric: Metric, kind: IndexKind) -> VectorIndex {
+        let field = Field::new(
+            "embedding",
+            DataType::FixedSizeList(Box::new(DataType::Float32), 2),
+        );
+        let mut index =
+            VectorIndex::try_new(&field, &Field::new("id", DataType::Int64), metric, kind).unwrap();
+        let partition = vectors(
+            "embedding",
+            &[Some([1.0, 0.0]), Some([0.0, 1.0]), None, Some([2.0, 2.0])],
+        );
+        index
+            .add_partition(&partition, &ids(vec![0, 1, 2, 3]))
+            .unwrap();
+        let partition = vectors("embedding", &[Some([-1.0, 0.0]), Some([0.9, 0.1])]);
+        index.add_partition(&partition, &ids(vec![4, 5])).unwrap();
+        index
+    }
+
+    /// The ids and distances of the neighbors of each query.
+    fn search(index: &VectorIndex, queries: &Series, k: usize) -> Vec<Option<Vec<(i64, f64)>>> {
+        let result = index.search(queries, k, None).unwrap();
+        result
+            .list()
+            .unwrap()
+            .into_iter()
+            .map(|neighbors| {
+                let neighbors = neighbors?;
+                let neighbors = neighbors.struct_().unwrap();
+                let ids = neighbors.children[0].i64().unwrap();
+                let distances = neighbors.children[1].f64().unwrap();
+                Some(
+                    (0..neighbors.len())
+                        .map(|i| (ids.get(i).unwrap(), distances.get(i).unwrap()))
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_flat_search() {
+        let index = index(Metric::L2, IndexKind::Flat);
+        assert_eq!(index.len(), 5);
+        assert_eq!(index.num_shards(), 2);
+
+        let queries = vectors("query", &[Some([1.0, 0.0]), None, Some([3.0, 3.0])]);
+        let result = search(&index, &queries, 2);
+        let nearest = result[0].as_ref().unwrap();
+        assert_eq!(
+            nearest.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+            vec![0, 5]
+        );
+        assert_eq!(nearest[0].1, 0.0);
+        assert!((nearest[1].1 - 0.02f64.sqrt()).abs() < 1e-6);
+        assert_eq!(result[1], None);
+        assert_eq!(result[2].as_ref().unwrap()[0].0, 3);
+
+        let all = search(&index, &vectors("query", &[Some([0.0, 0.0])]), 10);
+        assert_eq!(all[0].as_ref().unwrap().len(), 5);
+    }
+
+    #[test]
+    fn test_metrics() {
+        let queries = vectors("query", &[Some([3.0, 0.0])]);
+        let cosine = search(&index(Metric::Cosine, IndexKind::Flat), &queries, 2);
+        assert_eq!(cosine[0].as_ref().unwrap()[0], (0, 0.0));
+        assert_eq!(cosine[0].as_ref().unwrap()[1].0, 5);
+
+        let dot = search(&index(Metric::Dot, IndexKind::Flat), &queries, 1);
+        assert_eq!(dot[0], Some(vec![(3, -6.0)]));
+    }
+
+    #[test]
+    fn test_ivf_search() {
+        let kind = IndexKind::Ivf {
+            num_lists: Some(2),
+            num_probes: 2,
+        };
+        let flat = index(Metric::L2, IndexKind::Flat);
+        let ivf = index(Metric::L2, kind);
+        let queries = vectors("query", &[Some([1.0, 0.0]), Some([-2.0, 1.0]), None]);
+        // probing every list is exact
+        assert_eq!(search(&ivf, &queries, 3), search(&flat, &queries, 3));
+
+        let result = ivf.search(&queries, 3, Some(1)).unwrap();
+        assert_eq!(result.len(), 3);
+        assert!(ivf.search(&queries, 3, Some(0)).is_err());
+    }
+
+    #[test]
+    fn test_serialization() {
+        let kind = IndexKind::Ivf {
+            num_lists: None,
+            num_probes: 1,
+        };
+        let index = index(Metric::Cosine, kind);
+        let loaded = VectorIndex::from_bytes(&index.to_bytes().unwrap()).unwrap();
+        assert_eq!(loaded.column(), "embedding");
+        assert_eq!(loaded.id_column(), "id");
+        assert_eq!(loaded.kind(), kind);
+        let queries = vectors("query", &[Some([1.0, 1.0])]);
+        assert_eq!(search(&loaded, &queries, 4), search(&index, &queries, 4));
+        assert!(VectorIndex::from_bytes(b"not an index").is_err());
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let mut index = index(Metric::L2, IndexKind::Flat);
+        let wrong_dimension = FixedSizeListArray::new(
+            Field::new(
+                "query",
+                DataType::FixedSizeList(Box::new(DataType::Float32), 3),
+            ),
+            Float32Array::from(("item", vec![0.0; 3])).into_series(),
+            None,
+        )
+        .into_series();
+        assert!(index.search(&wrong_dimension, 1, None).is_err());
+        assert!(index
+            .add_partition(&vectors("embedding", &[Some([0.0, 0.0])]), &ids(vec![1, 2]))
+            .is_err());
+        assert!("euclidean".parse::<Metric>().is_err());
+        assert_eq!("dot".parse::<Metric>().unwrap(), Metric::Dot);
+    }
+}
@@ -0,0 +1,126 @@
+use common_py_serde::impl_bincode_py_state_serialization;
+use daft_core::python::PySeries;
+use daft_schema::python::{field::PyField, PyDataType};
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyBytes};
+
+use crate::{IndexKind, VectorIndex};
+
+#[pymethods]
+impl VectorIndex {
+    #[new]
+    #[pyo3(signature = (vector_field, id_field, metric, kind, num_lists=None, num_probes=None))]
+    fn py_new(
+        vector_field: PyField,
+        id_field: PyField,
+        metric: &str,
+        kind: &str,
+        num_lists: Option<usize>,
+        num_probes: Option<usize>,
+    ) -> PyResult<Self> {
+        let kind = match kind {
+            "flat" => IndexKind::Flat,
+            "ivf" => IndexKind::Ivf {
+                num_lists,
+                num_probes: num_probes.unwrap_or(8),
+            },
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown vector index kind: {kind}, expected one of: flat, ivf"
+                )))
+            }
+        };
+        Ok(Self::try_new(
+            &vector_field.field,
+            &id_field.field,
+            metric.parse()?,
+            kind,
+        )?)
+    }
+
+    #[pyo3(name = "add_partition")]
+    fn py_add_partition(&mut self, vectors: &PySeries, ids: &PySeries) -> PyResult<()> {
+        Ok(self.add_partition(&vectors.series, &ids.series)?)
+    }
+
+    #[pyo3(name = "search", signature = (queries, k, num_probes=None))]
+    fn py_search(
+        &self,
+        py: Python,
+        queries: &PySeries,
+        k: usize,
+        num_probes: Option<usize>,
+    ) -> PyResult<PySeries> {
+        Ok(py
+            .allow_threads(|| self.search(&queries.series, k, num_probes))?
+            .into())
+    }
+
+    #[pyo3(name = "to_bytes")]
+    fn py_to_bytes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        Ok(PyBytes::new(py, &self.to_bytes()?))
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_bytes")]
+    fn py_from_bytes(data: &[u8]) -> PyResult<Self> {
+        Ok(Self::from_bytes(data)?)
+    }
+
+    #[getter]
+    #[pyo3(name = "column")]
+    fn py_column(&self) -> &str {
+        self.column()
+    }
+
+    #[getter]
+    #[pyo3(name = "id_column")]
+    fn py_id_column(&self) -> &str {
+        self.id_column()
+    }
+
+    #[getter]
+    #[pyo3(name = "id_dtype")]
+    fn py_id_dtype(&self) -> PyDataType {
+        self.ids.data_type().clone().into()
+    }
+
+    #[getter]
+    #[pyo3(name = "dimension")]
+    fn py_dimension(&self) -> usize {
+        self.dimension()
+    }
+
+    #[getter]
+    #[pyo3(name = "metric")]
+    fn py_metric(&self) -> String {
+        self.metric().to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "kind")]
+    fn py_kind(&self) -> &'static str {
+        match self.kind() {
+            IndexKind::Flat => "flat",
+            IndexKind::Ivf { .. } => "ivf",
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "num_rows")]
+    fn py_num_rows(&self) -> usize {
+        self.len()
+    }
+
+    #[getter]
+    #[pyo3(name = "num_partitions")]
+    fn py_num_partitions(&self) -> usize {
+        self.num_shards()
+    }
+}
+
+impl_bincode_py_state_serialization!(VectorIndex);
+
+pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
+    parent.add_class::<VectorIndex>()?;
+    Ok(())
+}
@@ -1,23 +1,48 @@
 use std::sync::Arc;
 
 use common_error::{DaftError, DaftResult};
+use daft_core::prelude::{IntoSeries, Utf8Array};
+use daft_deltalake::{
+    python::PyDeltaLakeWrite, AddFileWriter, DeltaLakeWrite, ADD_ACTION_COLUMN_NAME,
+};
+use daft_iceberg::{python::PyIcebergWrite, DataFileWriter, IcebergWrite, DATA_FILE_COLUMN_NAME};
+use daft_io::{get_io_client, IOConfig};
 use daft_logical_plan::{CatalogType, DeltaLakeCatalogInfo, IcebergCatalogInfo};
 use daft_micropartition::MicroPartition;
 use daft_recordbatch::RecordBatch;
+use pyo3::Python;
 
 use crate::{pyarrow::PyArrowWriter, FileWriter, WriterFactory};
 
 /// CatalogWriterFactory is a factory for creating Catalog writers, i.e. iceberg, delta writers.
 pub struct CatalogWriterFactory {
     catalog_info: CatalogType,
-    native: bool, // TODO: Implement native writer
+    native: bool,
+    upload_part_size: usize,
+    max_inflight_upload_parts: usize,
 }
 
 impl CatalogWriterFactory {
-    pub fn new(catalog_info: CatalogType) -> Self {
+    pub fn new(
+        catalog_info: CatalogType,
+        upload_part_size: usize,
+        max_inflight_upload_parts: usize,
+    ) -> Self {
+        let native = matches!(
+            &catalog_info,
+            CatalogType::Iceberg(IcebergCatalogInfo {
+                native_write: Some(_),
+                ..
+            }) | CatalogType::DeltaLake(DeltaLakeCatalogInfo {
+                native_write: Some(_),
+                ..
+            })
+        );
         Self {
             catalog_info,
-            native: false,
+            native,
+            upload_part_size,
+            max_inflight_upload_parts,
         }
     }
 }
@@ -32,7 +57,43 @@ impl WriterFactory for CatalogWriterFactory {
         partition_values: Option<&RecordBatch>,
     ) -> DaftResult<Box<dyn FileWriter<Input = Self::Input, Result = Self::Result>>> {
         match self.native {
-            true => unimplemented!(),
+            true => match &self.catalog_info {
+                CatalogType::Iceberg(IcebergCatalogInfo {
+                    native_write: Some(native_write),
+                    io_config,
+                    ..
+                }) => {
+                    let native_write =
+                        Python::with_gil(|py| native_write.extract::<PyIcebergWrite>(py))?;
+                    let writer = IcebergDataFileWriter::try_new(
+                        &native_write.write,
+                        file_idx,
+                        partition_values,
+                        io_config.as_ref(),
+                        self.upload_part_size,
+                        self.max_inflight_upload_parts,
+                    )?;
+                    Ok(Box::new(writer))
+                }
+                CatalogType::DeltaLake(DeltaLakeCatalogInfo {
+                    native_write: Some(native_write),
+                    io_config,
+                    ..
+                }) => {
+                    let native_write =
+                        Python::with_gil(|py| native_write.extract::<PyDeltaLakeWrite>(py))?;
+                    let writer = DeltaLakeAddFileWriter::try_new(
+                        &native_write.write,
+                        file_idx,
+                        partition_values,
+                        io_config.as_ref(),
+                        self.upload_part_size,
+                        self.max_inflight_upload_parts,
+                    )?;
+                    Ok(Box::new(writer))
+                }
+                _ => unreachable!("Only Iceberg and Delta Lake tables are written natively"),
+            },
             false => {
                 let writer =
                     create_pyarrow_catalog_writer(file_idx, partition_values, &self.catalog_info)?;
@@ -89,3 +150,135 @@ pub fn create_pyarrow_catalog_writer(
         )),
     }
 }
+
+/// Writes a data file of a native Iceberg write, returning its description as a JSON-serialized data file, for the
+/// write to commit once all of its data files are written.
+struct IcebergDataFileWriter {
+    writer: DataFileWriter,
+    bytes_written: usize,
+}
+
+impl IcebergDataFileWriter {
+    fn try_new(
+        write: &IcebergWrite,
+        file_idx: usize,
+        partition_values: Option<&RecordBatch>,
+        io_config: Option<&IOConfig>,
+        upload_part_size: usize,
+        max_inflight_upload_parts: usize,
+    ) -> DaftResult<Self> {
+        let io_client = get_io_client(true, Arc::new(io_config.cloned().unwrap_or_default()))?;
+        let writer = write.data_file_writer(
+            file_idx,
+            partition_values,
+            io_client,
+            upload_part_size,
+            max_inflight_upload_parts,
+            None,
+        )?;
+        Ok(Self {
+            writer,
+            bytes_written: 0,
+        })
+    }
+}
+
+impl FileWriter for IcebergDataFileWriter {
+    type Input = Arc<MicroPartition>;
+    type Result = Option<RecordBatch>;
+
+    fn write(&mut self, data: Self::Input) -> DaftResult<usize> {
+        for table in data.get_tables()?.iter() {
+            self.writer.write(table)?;
+        }
+        let bytes_written = self.writer.bytes_written() - self.bytes_written;
+        self.bytes_written += bytes_written;
+        Ok(bytes_written)
+    }
+
+    fn bytes_written(&self) -> usize {
+        self.bytes_written
+    }
+
+    fn bytes_per_file(&self) -> Vec<usize> {
+        vec![self.bytes_written]
+    }
+
+    fn close(&mut self) -> DaftResult<Self::Result> {
+        let Some(data_file) = self.writer.close()? else {
+            return Ok(None);
+        };
+        self.bytes_written = self.writer.bytes_written();
+        let data_file = serde_json::to_string(&data_file)
+            .map_err(|err| DaftError::InternalError(err.to_string()))?;
+        let column =
+            Utf8Array::from_values(DATA_FILE_COLUMN_NAME, std::iter::once(data_file)).into_series();
+        Ok(Some(RecordBatch::from_nonempty_columns(vec![column])?))
+    }
+}
+
+/// Writes a data file of a native Delta Lake write, returning its JSON-serialized add action, for the write to commit
+/// once all of its data files are written.
+struct DeltaLakeAddFileWriter {
+    writer: AddFileWriter,
+    bytes_written: usize,
+}
+
+impl DeltaLakeAddFileWriter {
+    fn try_new(
+        write: &DeltaLakeWrite,
+        file_idx: usize,
+        partition_values: Option<&RecordBatch>,
+        io_config: Option<&IOConfig>,
+        upload_part_size: usize,
+        max_inflight_upload_parts: usize,
+    ) -> DaftResult<Self> {
+        let io_client = get_io_client(true, Arc::new(io_config.cloned().unwrap_or_default()))?;
+        let writer = write.add_file_writer(
+            file_idx,
+            partition_values,
+            io_client,
+            upload_part_size,
+            max_inflight_upload_parts,
+            None,
+        )?;
+        Ok(Self {
+            writer,
+            bytes_written: 0,
+        })
+    }
+}
+
+impl FileWriter for DeltaLakeAddFileWriter {
+    type Input = Arc<MicroPartition>;
+    type Result = Option<RecordBatch>;
+
+    fn write(&mut self, data: Self::Input) -> DaftResult<usize> {
+        for table in data.get_tables()?.iter() {
+            self.writer.write(table)?;
+        }
+        let bytes_written = self.writer.bytes_written() - self.bytes_written;
+        self.bytes_written += bytes_written;
+        Ok(bytes_written)
+    }
+
+    fn bytes_written(&self) -> usize {
+        self.bytes_written
+    }
+
+    fn bytes_per_file(&self) -> Vec<usize> {
+        vec![self.bytes_written]
+    }
+
+    fn close(&mut self) -> DaftResult<Self::Result> {
+        let Some(add) = self.writer.close()? else {
+            return Ok(None);
+        };
+        self.bytes_written = self.writer.bytes_written();
+        let add =
+            serde_json::to_string(&add).map_err(|err| DaftError::InternalError(err.to_string()))?;
+        let column =
+            Utf8Array::from_values(ADD_ACTION_COLUMN_NAME, std::iter::once(add)).into_series();
+        Ok(Some(RecordBatch::from_nonempty_columns(vec![column])?))
+    }
+}
@@ -109,6 +109,7 @@ pub fn make_physical_writer_factory(
                 let partitioned_writer_factory = PartitionedWriterFactory::new(
                     Arc::new(file_writer_factory),
                     partition_cols.clone(),
+                    file_info.max_open_writers,
                 );
                 Arc::new(partitioned_writer_factory)
             } else {
@@ -130,6 +131,7 @@ pub fn make_physical_writer_factory(
                 let partitioned_writer_factory = PartitionedWriterFactory::new(
                     Arc::new(file_writer_factory),
                     partition_cols.clone(),
+                    file_info.max_open_writers,
                 );
                 Arc::new(partitioned_writer_factory)
             } else {
@@ -140,22 +142,32 @@ pub fn make_physical_writer_factory(
     }
 }
 
+/// Parses the codec and level of compression for Arrow IPC files.
+pub fn ipc_compression(
+    compression: Option<&str>,
+    compression_level: Option<i32>,
+) -> DaftResult<Option<arrow2::io::ipc::write::Compression>> {
+    match (compression, compression_level) {
+        (Some("lz4"), _) => Ok(Some(arrow2::io::ipc::write::Compression::LZ4)),
+        (Some("zstd"), None) => Ok(Some(arrow2::io::ipc::write::Compression::ZSTD)),
+        (Some("zstd"), Some(level)) => Ok(Some(
+            arrow2::io::ipc::write::Compression::ZSTDWithLevel(level),
+        )),
+        (Some(c), _) => Err(DaftError::ValueError(format!(
+            "Unsupported compression for ipc writer: {}, only lz4 and zstd are supported",
+            c
+        ))),
+        (None, _) => Ok(None),
+    }
+}
+
 pub fn make_ipc_writer(
     dir: &str,
     target_filesize: usize,
     compression: Option<&str>,
+    compression_level: Option<i32>,
 ) -> DaftResult<Box<dyn FileWriter<Input = Arc<MicroPartition>, Result = Vec<RecordBatch>>>> {
-    let compression = match compression {
-        Some("lz4") => Some(arrow2::io::ipc::write::Compression::LZ4),
-        Some("zstd") => Some(arrow2::io::ipc::write::Compression::ZSTD),
-        Some(c) => {
-            return Err(DaftError::ValueError(format!(
-                "Unsupported compression for ipc writer: {}, only lz4 and zstd are supported",
-                c
-            )));
-        }
-        None => None,
-    };
+    let compression = ipc_compression(compression, compression_level)?;
     let base_writer_factory = IPCWriterFactory::new(dir.to_string(), compression);
     let file_size_calculator = TargetInMemorySizeBytesCalculator::new(
         target_filesize,
@@ -177,7 +189,11 @@ pub fn make_catalog_writer_factory(
 ) -> Arc<dyn WriterFactory<Input = Arc<MicroPartition>, Result = Vec<RecordBatch>>> {
     use catalog::CatalogWriterFactory;
 
-    let base_writer_factory = CatalogWriterFactory::new(catalog_info.clone());
+    let base_writer_factory = CatalogWriterFactory::new(
+        catalog_info.clone(),
+        cfg.upload_part_size,
+        cfg.max_inflight_upload_parts,
+    );
 
     let file_size_calculator = TargetInMemorySizeBytesCalculator::new(
         cfg.parquet_target_filesize,
@@ -200,8 +216,11 @@ pub fn make_catalog_writer_factory(
     );
 
     if let Some(partition_cols) = partition_cols {
-        let partitioned_writer_factory =
-            PartitionedWriterFactory::new(Arc::new(file_writer_factory), partition_cols.clone());
+        let partitioned_writer_factory = PartitionedWriterFactory::new(
+            Arc::new(file_writer_factory),
+            partition_cols.clone(),
+            None,
+        );
         Arc::new(partitioned_writer_factory)
     } else {
         Arc::new(file_writer_factory)
@@ -12,17 +12,25 @@ use daft_recordbatch::RecordBatch;
 
 use crate::{FileWriter, WriterFactory};
 
+type PartitionWriter = Box<dyn FileWriter<Input = Arc<MicroPartition>, Result = Vec<RecordBatch>>>;
+
 /// PartitionedWriter is a writer that partitions the input data by a set of columns, and writes each partition
 /// to a separate file. It uses a map to keep track of the writers for each partition.
+///
+/// If `max_open_writers` is set, the writer of the partition that was least recently written to is closed before
+/// a writer is opened for another partition once that many are open, so that a later write to its partition
+/// starts a new file.
 struct PartitionedWriter {
     // TODO: Figure out a way to NOT use the IndexHash + RawEntryMut pattern here. Ideally we want to store ScalarValues, aka. single Rows of the partition values as keys for the hashmap.
-    per_partition_writers: HashMap<
-        IndexHash,
-        Box<dyn FileWriter<Input = Arc<MicroPartition>, Result = Vec<RecordBatch>>>,
-    >,
+    // Writers are stored along with the number of the write that last wrote to them.
+    per_partition_writers: HashMap<IndexHash, (PartitionWriter, usize)>,
     saved_partition_values: Vec<RecordBatch>,
     writer_factory: Arc<dyn WriterFactory<Input = Arc<MicroPartition>, Result = Vec<RecordBatch>>>,
     partition_by: Vec<ExprRef>,
+    max_open_writers: Option<usize>,
+    num_writes: usize,
+    closed_results: Vec<RecordBatch>,
+    closed_bytes_per_file: Vec<usize>,
     is_closed: bool,
 }
 
@@ -32,24 +40,56 @@ impl PartitionedWriter {
             dyn WriterFactory<Input = Arc<MicroPartition>, Result = Vec<RecordBatch>>,
         >,
         partition_by: Vec<ExprRef>,
+        max_open_writers: Option<usize>,
     ) -> Self {
         Self {
             per_partition_writers: HashMap::new(),
             saved_partition_values: vec![],
             writer_factory,
             partition_by,
+            max_open_writers,
+            num_writes: 0,
+            closed_results: vec![],
+            closed_bytes_per_file: vec![],
             is_closed: false,
         }
     }
 
+    /// Closes the writer of the partition that was least recently written to.
+    fn close_least_recently_written(&mut self) -> DaftResult<()> {
+        let Some((idx, hash)) = self
+            .per_partition_writers
+            .iter()
+            .min_by_key(|(_, (_, last_write))| *last_write)
+            .map(|(key, _)| (key.idx, key.hash))
+        else {
+            return Ok(());
+        };
+        if let RawEntryMut::Occupied(entry) = self
+            .per_partition_writers
+            .raw_entry_mut()
+            .from_hash(hash, |other| other.idx == idx)
+        {
+            let (_, (mut writer, _)) = entry.remove_entry();
+            self.closed_bytes_per_file.extend(writer.bytes_per_file());
+            self.closed_results.extend(writer.close()?);
+        }
+        Ok(())
+    }
+
     fn partition(
         partition_cols: &[ExprRef],
         data: Arc<MicroPartition>,
-    ) -> DaftResult<(Vec<RecordBatch>, RecordBatch)> {
-        let data = data.concat_or_get(IOStatsContext::new("MicroPartition::partition_by_value"))?;
-        let table = data.first().unwrap();
-        let (split_tables, partition_values) = table.partition_by_value(partition_cols)?;
-        Ok((split_tables, partition_values))
+    ) -> DaftResult<(Vec<MicroPartition>, RecordBatch)> {
+        let (split_partitions, partition_values) = data.partition_by_value(partition_cols)?;
+        let partition_values = match partition_values
+            .concat_or_get(IOStatsContext::new("MicroPartition::partition_by_value"))?
+            .as_slice()
+        {
+            [values] => values.clone(),
+            _ => RecordBatch::empty(Some(partition_values.schema()))?,
+        };
+        Ok((split_partitions, partition_values))
     }
 }
 
@@ -63,16 +103,31 @@ impl FileWriter for PartitionedWriter {
             "Cannot write to a closed PartitionedWriter"
         );
 
-        let (split_tables, partition_values) =
+        let (split_partitions, partition_values) =
             Self::partition(self.partition_by.as_slice(), input)?;
         let partition_values_hash = partition_values.hash_rows()?;
         let mut bytes_written = 0;
-        for (idx, (table, partition_value_hash)) in split_tables
+        for (idx, (partition, partition_value_hash)) in split_partitions
             .into_iter()
             .zip(partition_values_hash.as_arrow().values_iter())
             .enumerate()
         {
             let partition_value_row = partition_values.slice(idx, idx + 1)?;
+            self.num_writes += 1;
+            if let Some(max_open_writers) = self.max_open_writers
+                && self.per_partition_writers.len() >= max_open_writers
+                && self
+                    .per_partition_writers
+                    .raw_entry()
+                    .from_hash(*partition_value_hash, |other| {
+                        (*partition_value_hash == other.hash)
+                            && self.saved_partition_values[other.idx as usize]
+                                == partition_value_row
+                    })
+                    .is_none()
+            {
+                self.close_least_recently_written()?;
+            }
             let entry = self.per_partition_writers.raw_entry_mut().from_hash(
                 *partition_value_hash,
                 |other| {
@@ -88,28 +143,21 @@ impl FileWriter for PartitionedWriter {
                     let mut writer = self
                         .writer_factory
                         .create_writer(0, Some(partition_value_row.as_ref()))?;
-                    bytes_written += writer.write(Arc::new(MicroPartition::new_loaded(
-                        table.schema.clone(),
-                        vec![table].into(),
-                        None,
-                    )))?;
+                    bytes_written += writer.write(Arc::new(partition))?;
                     entry.insert_hashed_nocheck(
                         *partition_value_hash,
                         IndexHash {
                             idx: self.saved_partition_values.len() as u64,
                             hash: *partition_value_hash,
                         },
-                        writer,
+                        (writer, self.num_writes),
                     );
                     self.saved_partition_values.push(partition_value_row);
                 }
                 RawEntryMut::Occupied(mut entry) => {
-                    let writer = entry.get_mut();
-                    bytes_written += writer.write(Arc::new(MicroPartition::new_loaded(
-                        table.schema.clone(),
-                        vec![table].into(),
-                        None,
-                    )))?;
+                    let (writer, last_write) = entry.get_mut();
+                    *last_write = self.num_writes;
+                    bytes_written += writer.write(Arc::new(partition))?;
                 }
             }
         }
@@ -117,22 +165,29 @@ impl FileWriter for PartitionedWriter {
     }
 
     fn bytes_written(&self) -> usize {
-        self.per_partition_writers
-            .values()
-            .map(|writer| writer.bytes_written())
-            .sum::<usize>()
+        self.closed_bytes_per_file.iter().sum::<usize>()
+            + self
+                .per_partition_writers
+                .values()
+                .map(|(writer, _)| writer.bytes_written())
+                .sum::<usize>()
     }
 
     fn bytes_per_file(&self) -> Vec<usize> {
-        self.per_partition_writers
-            .values()
-            .flat_map(|writer| writer.bytes_per_file())
+        self.closed_bytes_per_file
+            .iter()
+            .copied()
+            .chain(
+                self.per_partition_writers
+                    .values()
+                    .flat_map(|(writer, _)| writer.bytes_per_file()),
+            )
             .collect()
     }
 
     fn close(&mut self) -> DaftResult<Self::Result> {
-        let mut results = vec![];
-        for (_, mut writer) in self.per_partition_writers.drain() {
+        let mut results = std::mem::take(&mut self.closed_results);
+        for (_, (mut writer, _)) in self.per_partition_writers.drain() {
             results.extend(writer.close()?);
         }
         self.is_closed = true;
@@ -143,6 +198,7 @@ impl FileWriter for PartitionedWriter {
 pub(crate) struct PartitionedWriterFactory {
     writer_factory: Arc<dyn WriterFactory<Input = Arc<MicroPartition>, Result = Vec<RecordBatch>>>,
     partition_cols: Vec<ExprRef>,
+    max_open_writers: Option<usize>,
 }
 
 impl PartitionedWriterFactory {
@@ -151,10 +207,12 @@ impl PartitionedWriterFactory {
             dyn WriterFactory<Input = Arc<MicroPartition>, Result = Vec<RecordBatch>>,
         >,
         partition_cols: Vec<ExprRef>,
+        max_open_writers: Option<usize>,
     ) -> Self {
         Self {
             writer_factory,
             partition_cols,
+            max_open_writers,
         }
     }
 }
@@ -170,9 +228,63 @@ impl WriterFactory for PartitionedWriterFactory {
         Ok(Box::new(PartitionedWriter::new(
             self.writer_factory.clone(),
             self.partition_cols.clone(),
+            self.max_open_writers,
         ))
             as Box<
                 dyn FileWriter<Input = Self::Input, Result = Self::Result>,
             >)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use daft_dsl::resolved_col;
+
+    use super::*;
+    use crate::test::{
+        make_dummy_mp, make_dummy_target_file_size_writer_factory, DummyWriterFactory,
+    };
+
+    fn write_partitions(max_open_writers: Option<usize>) -> DaftResult<Vec<RecordBatch>> {
+        let factory = PartitionedWriterFactory::new(
+            make_dummy_target_file_size_writer_factory(100, 1.0, Arc::new(DummyWriterFactory)),
+            vec![resolved_col("ints")],
+            max_open_writers,
+        );
+        let mut writer = factory.create_writer(0, None)?;
+        let mp = make_dummy_mp(7);
+        for (start, end) in [(5, 6), (6, 7), (5, 6)] {
+            writer.write(Arc::new(mp.slice(start, end)?))?;
+        }
+        writer.close()
+    }
+
+    #[test]
+    fn test_partitioned_writer_keeps_writers_open() -> DaftResult<()> {
+        assert_eq!(write_partitions(None)?.len(), 2);
+        assert_eq!(write_partitions(Some(2))?.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_partitioned_writer_closes_least_recently_written() -> DaftResult<()> {
+        // Each write to a partition other than the last one written to closes its file.
+        assert_eq!(write_partitions(Some(1))?.len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_partitioned_writer_groups_partitions_across_batches() -> DaftResult<()> {
+        let factory = PartitionedWriterFactory::new(
+            make_dummy_target_file_size_writer_factory(100, 1.0, Arc::new(DummyWriterFactory)),
+            vec![resolved_col("ints")],
+            None,
+        );
+        let mut writer = factory.create_writer(0, None)?;
+        // The rows of each partition value are spread over both batches, and are written to a single file.
+        let mp = make_dummy_mp(3);
+        writer.write(Arc::new(MicroPartition::concat([mp.clone(), mp])?))?;
+        assert_eq!(writer.close()?.len(), 3);
+        Ok(())
+    }
+}
@@ -211,3 +211,18 @@ impl FileWriter for PyArrowWriter {
         })
     }
 }
+
+/// A writer that is dropped without being closed, e.g. because its query was cancelled, abandons its file.
+impl Drop for PyArrowWriter {
+    fn drop(&mut self) {
+        if self.is_closed {
+            return;
+        }
+        self.is_closed = true;
+        Python::with_gil(|py| {
+            if let Err(err) = self.py_writer.call_method0(py, pyo3::intern!(py, "abort")) {
+                log::warn!("Failed to abort unfinished write: {err}");
+            }
+        });
+    }
+}
@@ -0,0 +1,1046 @@
+#![allow(clippy::derive_partial_eq_without_eq)]
+#![allow(clippy::large_enum_variant)]
+
+// The Substrait messages needed to exchange extended expressions and plans, in the form
+// prost-build generates them from `substrait/extended_expression.proto`, `substrait/plan.proto`
+// and their imports. Only the messages and fields Daft reads or writes are included; prost skips
+// any other fields when decoding.
+/// A set of expressions over a common base schema, along with the extensions they reference.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExtendedExpression {
+    /// Substrait version of the expressions.
+    #[prost(message, optional, tag = "7")]
+    pub version: ::core::option::Option<Version>,
+    #[prost(message, repeated, tag = "1")]
+    pub extension_uris: ::prost::alloc::vec::Vec<SimpleExtensionUri>,
+    #[prost(message, repeated, tag = "2")]
+    pub extensions: ::prost::alloc::vec::Vec<SimpleExtensionDeclaration>,
+    /// One or more expressions, each of which is named.
+    #[prost(message, repeated, tag = "3")]
+    pub referred_expr: ::prost::alloc::vec::Vec<ExpressionReference>,
+    #[prost(message, optional, tag = "4")]
+    pub base_schema: ::core::option::Option<NamedStruct>,
+    #[prost(string, repeated, tag = "6")]
+    pub expected_type_urls: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExpressionReference {
+    #[prost(oneof = "expression_reference::ExprType", tags = "1")]
+    pub expr_type: ::core::option::Option<expression_reference::ExprType>,
+    /// Field names in depth-first order.
+    #[prost(string, repeated, tag = "3")]
+    pub output_names: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Nested message and enum types in `ExpressionReference`.
+pub mod expression_reference {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum ExprType {
+        #[prost(message, tag = "1")]
+        Expression(super::Expression),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Version {
+    #[prost(uint32, tag = "1")]
+    pub major_number: u32,
+    #[prost(uint32, tag = "2")]
+    pub minor_number: u32,
+    #[prost(uint32, tag = "3")]
+    pub patch_number: u32,
+    #[prost(string, tag = "4")]
+    pub git_hash: ::prost::alloc::string::String,
+    /// The name of the system that produced the message.
+    #[prost(string, tag = "5")]
+    pub producer: ::prost::alloc::string::String,
+}
+/// Identifies an extension YAML file by URI, so that declarations can refer to it by anchor.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimpleExtensionUri {
+    #[prost(uint32, tag = "1")]
+    pub extension_uri_anchor: u32,
+    #[prost(string, tag = "2")]
+    pub uri: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimpleExtensionDeclaration {
+    #[prost(oneof = "simple_extension_declaration::MappingType", tags = "1, 3")]
+    pub mapping_type: ::core::option::Option<simple_extension_declaration::MappingType>,
+}
+/// Nested message and enum types in `SimpleExtensionDeclaration`.
+pub mod simple_extension_declaration {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ExtensionType {
+        #[prost(uint32, tag = "1")]
+        pub extension_uri_reference: u32,
+        #[prost(uint32, tag = "2")]
+        pub type_anchor: u32,
+        #[prost(string, tag = "3")]
+        pub name: ::prost::alloc::string::String,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ExtensionFunction {
+        #[prost(uint32, tag = "1")]
+        pub extension_uri_reference: u32,
+        #[prost(uint32, tag = "2")]
+        pub function_anchor: u32,
+        /// The function name, optionally followed by its signature, such as `add:i64_i64`.
+        #[prost(string, tag = "3")]
+        pub name: ::prost::alloc::string::String,
+    }
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum MappingType {
+        #[prost(message, tag = "1")]
+        ExtensionType(ExtensionType),
+        #[prost(message, tag = "3")]
+        ExtensionFunction(ExtensionFunction),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Type {
+    #[prost(
+        oneof = "r#type::Kind",
+        tags = "1, 2, 3, 5, 7, 10, 11, 12, 13, 16, 17, 24, 25, 27, 33, 34"
+    )]
+    pub kind: ::core::option::Option<r#type::Kind>,
+}
+/// Nested message and enum types in `Type`.
+pub mod r#type {
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Boolean {
+        #[prost(uint32, tag = "1")]
+        pub type_variation_reference: u32,
+        #[prost(enumeration = "Nullability", tag = "2")]
+        pub nullability: i32,
+    }
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct I8 {
+        #[prost(uint32, tag = "1")]
+        pub type_variation_reference: u32,
+        #[prost(enumeration = "Nullability", tag = "2")]
+        pub nullability: i32,
+    }
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct I16 {
+        #[prost(uint32, tag = "1")]
+        pub type_variation_reference: u32,
+        #[prost(enumeration = "Nullability", tag = "2")]
+        pub nullability: i32,
+    }
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct I32 {
+        #[prost(uint32, tag = "1")]
+        pub type_variation_reference: u32,
+        #[prost(enumeration = "Nullability", tag = "2")]
+        pub nullability: i32,
+    }
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct I64 {
+        #[prost(uint32, tag = "1")]
+        pub type_variation_reference: u32,
+        #[prost(enumeration = "Nullability", tag = "2")]
+        pub nullability: i32,
+    }
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Fp32 {
+        #[prost(uint32, tag = "1")]
+        pub type_variation_reference: u32,
+        #[prost(enumeration = "Nullability", tag = "2")]
+        pub nullability: i32,
+    }
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Fp64 {
+        #[prost(uint32, tag = "1")]
+        pub type_variation_reference: u32,
+        #[prost(enumeration = "Nullability", tag = "2")]
+        pub nullability: i32,
+    }
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct String {
+        #[prost(uint32, tag = "1")]
+        pub type_variation_reference: u32,
+        #[prost(enumeration = "Nullability", tag = "2")]
+        pub nullability: i32,
+    }
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Binary {
+        #[prost(uint32, tag = "1")]
+        pub type_variation_reference: u32,
+        #[prost(enumeration = "Nullability", tag = "2")]
+        pub nullability: i32,
+    }
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Date {
+        #[prost(uint32, tag = "1")]
+        pub type_variation_reference: u32,
+        #[prost(enumeration = "Nullability", tag = "2")]
+        pub nullability: i32,
+    }
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Time {
+        #[prost(uint32, tag = "1")]
+        pub type_variation_reference: u32,
+        #[prost(enumeration = "Nullability", tag = "2")]
+        pub nullability: i32,
+    }
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Decimal {
+        #[prost(int32, tag = "1")]
+        pub scale: i32,
+        #[prost(int32, tag = "2")]
+        pub precision: i32,
+        #[prost(uint32, tag = "3")]
+        pub type_variation_reference: u32,
+        #[prost(enumeration = "Nullability", tag = "4")]
+        pub nullability: i32,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Struct {
+        #[prost(message, repeated, tag = "1")]
+        pub types: ::prost::alloc::vec::Vec<super::Type>,
+        #[prost(uint32, tag = "2")]
+        pub type_variation_reference: u32,
+        #[prost(enumeration = "Nullability", tag = "3")]
+        pub nullability: i32,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct List {
+        #[prost(message, optional, boxed, tag = "1")]
+        pub r#type: ::core::option::Option<::prost::alloc::boxed::Box<super::Type>>,
+        #[prost(uint32, tag = "2")]
+        pub type_variation_reference: u32,
+        #[prost(enumeration = "Nullability", tag = "3")]
+        pub nullability: i32,
+    }
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct PrecisionTimestamp {
+        /// The number of digits after the decimal point of the seconds, between 0 and 12.
+        #[prost(int32, tag = "1")]
+        pub precision: i32,
+        #[prost(uint32, tag = "2")]
+        pub type_variation_reference: u32,
+        #[prost(enumeration = "Nullability", tag = "3")]
+        pub nullability: i32,
+    }
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct PrecisionTimestampTz {
+        /// The number of digits after the decimal point of the seconds, between 0 and 12.
+        #[prost(int32, tag = "1")]
+        pub precision: i32,
+        #[prost(uint32, tag = "2")]
+        pub type_variation_reference: u32,
+        #[prost(enumeration = "Nullability", tag = "3")]
+        pub nullability: i32,
+    }
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Nullability {
+        Unspecified = 0,
+        Nullable = 1,
+        Required = 2,
+    }
+    impl Nullability {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "NULLABILITY_UNSPECIFIED",
+                Self::Nullable => "NULLABILITY_NULLABLE",
+                Self::Required => "NULLABILITY_REQUIRED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "NULLABILITY_UNSPECIFIED" => Some(Self::Unspecified),
+                "NULLABILITY_NULLABLE" => Some(Self::Nullable),
+                "NULLABILITY_REQUIRED" => Some(Self::Required),
+                _ => None,
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "1")]
+        Bool(Boolean),
+        #[prost(message, tag = "2")]
+        I8(I8),
+        #[prost(message, tag = "3")]
+        I16(I16),
+        #[prost(message, tag = "5")]
+        I32(I32),
+        #[prost(message, tag = "7")]
+        I64(I64),
+        #[prost(message, tag = "10")]
+        Fp32(Fp32),
+        #[prost(message, tag = "11")]
+        Fp64(Fp64),
+        #[prost(message, tag = "12")]
+        String(String),
+        #[prost(message, tag = "13")]
+        Binary(Binary),
+        #[prost(message, tag = "16")]
+        Date(Date),
+        #[prost(message, tag = "17")]
+        Time(Time),
+        #[prost(message, tag = "24")]
+        Decimal(Decimal),
+        #[prost(message, tag = "25")]
+        Struct(Struct),
+        #[prost(message, tag = "27")]
+        List(::prost::alloc::boxed::Box<List>),
+        #[prost(message, tag = "33")]
+        PrecisionTimestamp(PrecisionTimestamp),
+        #[prost(message, tag = "34")]
+        PrecisionTimestampTz(PrecisionTimestampTz),
+    }
+}
+/// A struct type whose fields are named, such as the schema that expressions are evaluated over.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NamedStruct {
+    /// The field names in depth-first order, including the names of nested struct fields.
+    #[prost(string, repeated, tag = "1")]
+    pub names: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, optional, tag = "2")]
+    pub r#struct: ::core::option::Option<r#type::Struct>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Expression {
+    #[prost(oneof = "expression::RexType", tags = "1, 2, 3, 6, 8, 11")]
+    pub rex_type: ::core::option::Option<expression::RexType>,
+}
+/// Nested message and enum types in `Expression`.
+pub mod expression {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Literal {
+        /// Whether the literal type should be treated as a nullable type.
+        #[prost(bool, tag = "50")]
+        pub nullable: bool,
+        #[prost(uint32, tag = "51")]
+        pub type_variation_reference: u32,
+        #[prost(
+            oneof = "literal::LiteralType",
+            tags = "1, 2, 3, 5, 7, 10, 11, 12, 13, 16, 17, 24, 34, 35, 29"
+        )]
+        pub literal_type: ::core::option::Option<literal::LiteralType>,
+    }
+    /// Nested message and enum types in `Literal`.
+    pub mod literal {
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Decimal {
+            /// Little-endian two's-complement integer representation of the unscaled value,
+            /// which must be exactly 16 bytes.
+            #[prost(bytes = "vec", tag = "1")]
+            pub value: ::prost::alloc::vec::Vec<u8>,
+            #[prost(int32, tag = "2")]
+            pub precision: i32,
+            #[prost(int32, tag = "3")]
+            pub scale: i32,
+        }
+        #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+        pub struct PrecisionTimestamp {
+            #[prost(int32, tag = "1")]
+            pub precision: i32,
+            /// The time since the epoch in units of `10^-precision` seconds.
+            #[prost(int64, tag = "2")]
+            pub value: i64,
+        }
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum LiteralType {
+            #[prost(bool, tag = "1")]
+            Boolean(bool),
+            #[prost(int32, tag = "2")]
+            I8(i32),
+            #[prost(int32, tag = "3")]
+            I16(i32),
+            #[prost(int32, tag = "5")]
+            I32(i32),
+            #[prost(int64, tag = "7")]
+            I64(i64),
+            #[prost(float, tag = "10")]
+            Fp32(f32),
+            #[prost(double, tag = "11")]
+            Fp64(f64),
+            #[prost(string, tag = "12")]
+            String(::prost::alloc::string::String),
+            #[prost(bytes, tag = "13")]
+            Binary(::prost::alloc::vec::Vec<u8>),
+            /// Days since the epoch.
+            #[prost(int32, tag = "16")]
+            Date(i32),
+            /// Microseconds since midnight.
+            #[prost(int64, tag = "17")]
+            Time(i64),
+            #[prost(message, tag = "24")]
+            Decimal(Decimal),
+            #[prost(message, tag = "34")]
+            PrecisionTimestamp(PrecisionTimestamp),
+            #[prost(message, tag = "35")]
+            PrecisionTimestampTz(PrecisionTimestamp),
+            /// A typed null.
+            #[prost(message, tag = "29")]
+            Null(super::super::Type),
+        }
+    }
+    /// A function that produces one output value for each row.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ScalarFunction {
+        /// Points to a function anchor defined in the extension declarations.
+        #[prost(uint32, tag = "1")]
+        pub function_reference: u32,
+        #[prost(message, repeated, tag = "4")]
+        pub arguments: ::prost::alloc::vec::Vec<super::FunctionArgument>,
+        #[prost(message, optional, tag = "3")]
+        pub output_type: ::core::option::Option<super::Type>,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct IfThen {
+        #[prost(message, repeated, tag = "1")]
+        pub ifs: ::prost::alloc::vec::Vec<if_then::IfClause>,
+        #[prost(message, optional, boxed, tag = "2")]
+        pub r#else: ::core::option::Option<::prost::alloc::boxed::Box<super::Expression>>,
+    }
+    /// Nested message and enum types in `IfThen`.
+    pub mod if_then {
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct IfClause {
+            #[prost(message, optional, tag = "1")]
+            pub r#if: ::core::option::Option<super::super::Expression>,
+            #[prost(message, optional, tag = "2")]
+            pub then: ::core::option::Option<super::super::Expression>,
+        }
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Cast {
+        #[prost(message, optional, tag = "1")]
+        pub r#type: ::core::option::Option<super::Type>,
+        #[prost(message, optional, boxed, tag = "2")]
+        pub input: ::core::option::Option<::prost::alloc::boxed::Box<super::Expression>>,
+        #[prost(enumeration = "cast::FailureBehavior", tag = "3")]
+        pub failure_behavior: i32,
+    }
+    /// Nested message and enum types in `Cast`.
+    pub mod cast {
+        #[derive(
+            Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+        )]
+        #[repr(i32)]
+        pub enum FailureBehavior {
+            Unspecified = 0,
+            ReturnNull = 1,
+            ThrowException = 2,
+        }
+        impl FailureBehavior {
+            /// String value of the enum field names used in the ProtoBuf definition.
+            ///
+            /// The values are not transformed in any way and thus are considered stable
+            /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+            pub fn as_str_name(&self) -> &'static str {
+                match self {
+                    Self::Unspecified => "FAILURE_BEHAVIOR_UNSPECIFIED",
+                    Self::ReturnNull => "FAILURE_BEHAVIOR_RETURN_NULL",
+                    Self::ThrowException => "FAILURE_BEHAVIOR_THROW_EXCEPTION",
+                }
+            }
+            /// Creates an enum from field names used in the ProtoBuf definition.
+            pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+                match value {
+                    "FAILURE_BEHAVIOR_UNSPECIFIED" => Some(Self::Unspecified),
+                    "FAILURE_BEHAVIOR_RETURN_NULL" => Some(Self::ReturnNull),
+                    "FAILURE_BEHAVIOR_THROW_EXCEPTION" => Some(Self::ThrowException),
+                    _ => None,
+                }
+            }
+        }
+    }
+    /// Whether `value` is equal to any of `options`.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct SingularOrList {
+        #[prost(message, optional, boxed, tag = "1")]
+        pub value: ::core::option::Option<::prost::alloc::boxed::Box<super::Expression>>,
+        #[prost(message, repeated, tag = "2")]
+        pub options: ::prost::alloc::vec::Vec<super::Expression>,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ReferenceSegment {
+        #[prost(oneof = "reference_segment::ReferenceType", tags = "2")]
+        pub reference_type: ::core::option::Option<reference_segment::ReferenceType>,
+    }
+    /// Nested message and enum types in `ReferenceSegment`.
+    pub mod reference_segment {
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct StructField {
+            /// Zero-indexed ordinal position of the field in the struct.
+            #[prost(int32, tag = "1")]
+            pub field: i32,
+            #[prost(message, optional, boxed, tag = "2")]
+            pub child: ::core::option::Option<::prost::alloc::boxed::Box<super::ReferenceSegment>>,
+        }
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum ReferenceType {
+            #[prost(message, tag = "2")]
+            StructField(::prost::alloc::boxed::Box<StructField>),
+        }
+    }
+    /// A reference to a field of the input, or of the result of another expression.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct FieldReference {
+        #[prost(oneof = "field_reference::ReferenceType", tags = "1")]
+        pub reference_type: ::core::option::Option<field_reference::ReferenceType>,
+        #[prost(oneof = "field_reference::RootType", tags = "3, 4")]
+        pub root_type: ::core::option::Option<field_reference::RootType>,
+    }
+    /// Nested message and enum types in `FieldReference`.
+    pub mod field_reference {
+        /// A reference to the input of the expression.
+        #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+        pub struct RootReference {}
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum ReferenceType {
+            #[prost(message, tag = "1")]
+            DirectReference(super::ReferenceSegment),
+        }
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum RootType {
+            #[prost(message, tag = "3")]
+            Expression(::prost::alloc::boxed::Box<super::super::Expression>),
+            #[prost(message, tag = "4")]
+            RootReference(RootReference),
+        }
+    }
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum RexType {
+        #[prost(message, tag = "1")]
+        Literal(Literal),
+        #[prost(message, tag = "2")]
+        Selection(::prost::alloc::boxed::Box<FieldReference>),
+        #[prost(message, tag = "3")]
+        ScalarFunction(ScalarFunction),
+        #[prost(message, tag = "6")]
+        IfThen(::prost::alloc::boxed::Box<IfThen>),
+        #[prost(message, tag = "8")]
+        SingularOrList(::prost::alloc::boxed::Box<SingularOrList>),
+        #[prost(message, tag = "11")]
+        Cast(::prost::alloc::boxed::Box<Cast>),
+    }
+}
+/// An argument to a function.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FunctionArgument {
+    #[prost(oneof = "function_argument::ArgType", tags = "3")]
+    pub arg_type: ::core::option::Option<function_argument::ArgType>,
+}
+/// Nested message and enum types in `FunctionArgument`.
+pub mod function_argument {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum ArgType {
+        #[prost(message, tag = "3")]
+        Value(super::Expression),
+    }
+}
+/// A tree of relations, along with the extensions they reference.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Plan {
+    /// Substrait version of the plan.
+    #[prost(message, optional, tag = "6")]
+    pub version: ::core::option::Option<Version>,
+    #[prost(message, repeated, tag = "1")]
+    pub extension_uris: ::prost::alloc::vec::Vec<SimpleExtensionUri>,
+    #[prost(message, repeated, tag = "2")]
+    pub extensions: ::prost::alloc::vec::Vec<SimpleExtensionDeclaration>,
+    /// One or more relation trees, of which the roots are the outputs of the plan.
+    #[prost(message, repeated, tag = "3")]
+    pub relations: ::prost::alloc::vec::Vec<PlanRel>,
+    #[prost(string, repeated, tag = "5")]
+    pub expected_type_urls: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PlanRel {
+    #[prost(oneof = "plan_rel::RelType", tags = "1, 2")]
+    pub rel_type: ::core::option::Option<plan_rel::RelType>,
+}
+/// Nested message and enum types in `PlanRel`.
+pub mod plan_rel {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum RelType {
+        /// A relation whose output is shared by other relations of the plan.
+        #[prost(message, tag = "1")]
+        Rel(super::Rel),
+        /// A relation whose output is an output of the plan.
+        #[prost(message, tag = "2")]
+        Root(super::RelRoot),
+    }
+}
+/// The root of a relation tree, which names the fields of its output.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RelRoot {
+    #[prost(message, optional, tag = "1")]
+    pub input: ::core::option::Option<Rel>,
+    /// Field names in depth-first order.
+    #[prost(string, repeated, tag = "2")]
+    pub names: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Rel {
+    #[prost(oneof = "rel::RelType", tags = "1, 2, 3, 4, 5, 6, 7, 8")]
+    pub rel_type: ::core::option::Option<rel::RelType>,
+}
+/// Nested message and enum types in `Rel`.
+pub mod rel {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum RelType {
+        #[prost(message, tag = "1")]
+        Read(::prost::alloc::boxed::Box<super::ReadRel>),
+        #[prost(message, tag = "2")]
+        Filter(::prost::alloc::boxed::Box<super::FilterRel>),
+        #[prost(message, tag = "3")]
+        Fetch(::prost::alloc::boxed::Box<super::FetchRel>),
+        #[prost(message, tag = "4")]
+        Aggregate(::prost::alloc::boxed::Box<super::AggregateRel>),
+        #[prost(message, tag = "5")]
+        Sort(::prost::alloc::boxed::Box<super::SortRel>),
+        #[prost(message, tag = "6")]
+        Join(::prost::alloc::boxed::Box<super::JoinRel>),
+        #[prost(message, tag = "7")]
+        Project(::prost::alloc::boxed::Box<super::ProjectRel>),
+        #[prost(message, tag = "8")]
+        Set(super::SetRel),
+    }
+}
+/// Properties common to all relations.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RelCommon {
+    #[prost(oneof = "rel_common::EmitKind", tags = "1, 2")]
+    pub emit_kind: ::core::option::Option<rel_common::EmitKind>,
+}
+/// Nested message and enum types in `RelCommon`.
+pub mod rel_common {
+    /// The relation outputs all of its fields, in order.
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Direct {}
+    /// The relation outputs the fields at the given indices, in the given order.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Emit {
+        #[prost(int32, repeated, tag = "1")]
+        pub output_mapping: ::prost::alloc::vec::Vec<i32>,
+    }
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum EmitKind {
+        #[prost(message, tag = "1")]
+        Direct(Direct),
+        #[prost(message, tag = "2")]
+        Emit(Emit),
+    }
+}
+/// A scan of a table.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReadRel {
+    #[prost(message, optional, tag = "1")]
+    pub common: ::core::option::Option<RelCommon>,
+    #[prost(message, optional, tag = "2")]
+    pub base_schema: ::core::option::Option<NamedStruct>,
+    /// A filter that must be applied to the rows of the table.
+    #[prost(message, optional, boxed, tag = "3")]
+    pub filter: ::core::option::Option<::prost::alloc::boxed::Box<Expression>>,
+    /// A filter that may be applied to the rows of the table to reduce what's read.
+    #[prost(message, optional, boxed, tag = "11")]
+    pub best_effort_filter: ::core::option::Option<::prost::alloc::boxed::Box<Expression>>,
+    #[prost(message, optional, tag = "4")]
+    pub projection: ::core::option::Option<read_rel::MaskExpression>,
+    #[prost(oneof = "read_rel::ReadType", tags = "7")]
+    pub read_type: ::core::option::Option<read_rel::ReadType>,
+}
+/// Nested message and enum types in `ReadRel`.
+pub mod read_rel {
+    /// A table referred to by name, which the consumer resolves.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct NamedTable {
+        /// The parts of the table's qualified name, such as its namespace and name.
+        #[prost(string, repeated, tag = "1")]
+        pub names: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    }
+    /// The fields of the base schema that a read outputs, which is `Expression.MaskExpression`.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct MaskExpression {
+        #[prost(message, optional, tag = "1")]
+        pub select: ::core::option::Option<mask_expression::StructSelect>,
+        #[prost(bool, tag = "2")]
+        pub maintain_singular_struct: bool,
+    }
+    /// Nested message and enum types in `MaskExpression`.
+    pub mod mask_expression {
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct StructSelect {
+            #[prost(message, repeated, tag = "1")]
+            pub struct_items: ::prost::alloc::vec::Vec<StructItem>,
+        }
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct StructItem {
+            #[prost(int32, tag = "1")]
+            pub field: i32,
+            /// A selection of the field's children, whose contents Daft doesn't read.
+            #[prost(message, optional, tag = "2")]
+            pub child: ::core::option::Option<Select>,
+        }
+        #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+        pub struct Select {}
+    }
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum ReadType {
+        #[prost(message, tag = "7")]
+        NamedTable(NamedTable),
+    }
+}
+/// Appends the values of expressions to the fields of each input row.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProjectRel {
+    #[prost(message, optional, tag = "1")]
+    pub common: ::core::option::Option<RelCommon>,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub input: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
+    #[prost(message, repeated, tag = "3")]
+    pub expressions: ::prost::alloc::vec::Vec<Expression>,
+}
+/// Joins two inputs, outputting the fields of the left input followed by those of the right.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JoinRel {
+    #[prost(message, optional, tag = "1")]
+    pub common: ::core::option::Option<RelCommon>,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub left: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
+    #[prost(message, optional, boxed, tag = "3")]
+    pub right: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
+    /// The join condition, over the fields of both inputs.
+    #[prost(message, optional, tag = "4")]
+    pub expression: ::core::option::Option<Expression>,
+    #[prost(message, optional, tag = "5")]
+    pub post_join_filter: ::core::option::Option<Expression>,
+    #[prost(enumeration = "join_rel::JoinType", tag = "6")]
+    pub r#type: i32,
+}
+/// Nested message and enum types in `JoinRel`.
+pub mod join_rel {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum JoinType {
+        Unspecified = 0,
+        Inner = 1,
+        Outer = 2,
+        Left = 3,
+        Right = 4,
+        LeftSemi = 5,
+        LeftAnti = 6,
+        LeftSingle = 7,
+        RightSemi = 8,
+        RightAnti = 9,
+        RightSingle = 10,
+        LeftMark = 11,
+        RightMark = 12,
+    }
+    impl JoinType {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "JOIN_TYPE_UNSPECIFIED",
+                Self::Inner => "JOIN_TYPE_INNER",
+                Self::Outer => "JOIN_TYPE_OUTER",
+                Self::Left => "JOIN_TYPE_LEFT",
+                Self::Right => "JOIN_TYPE_RIGHT",
+                Self::LeftSemi => "JOIN_TYPE_LEFT_SEMI",
+                Self::LeftAnti => "JOIN_TYPE_LEFT_ANTI",
+                Self::LeftSingle => "JOIN_TYPE_LEFT_SINGLE",
+                Self::RightSemi => "JOIN_TYPE_RIGHT_SEMI",
+                Self::RightAnti => "JOIN_TYPE_RIGHT_ANTI",
+                Self::RightSingle => "JOIN_TYPE_RIGHT_SINGLE",
+                Self::LeftMark => "JOIN_TYPE_LEFT_MARK",
+                Self::RightMark => "JOIN_TYPE_RIGHT_MARK",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "JOIN_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+                "JOIN_TYPE_INNER" => Some(Self::Inner),
+                "JOIN_TYPE_OUTER" => Some(Self::Outer),
+                "JOIN_TYPE_LEFT" => Some(Self::Left),
+                "JOIN_TYPE_RIGHT" => Some(Self::Right),
+                "JOIN_TYPE_LEFT_SEMI" => Some(Self::LeftSemi),
+                "JOIN_TYPE_LEFT_ANTI" => Some(Self::LeftAnti),
+                "JOIN_TYPE_LEFT_SINGLE" => Some(Self::LeftSingle),
+                "JOIN_TYPE_RIGHT_SEMI" => Some(Self::RightSemi),
+                "JOIN_TYPE_RIGHT_ANTI" => Some(Self::RightAnti),
+                "JOIN_TYPE_RIGHT_SINGLE" => Some(Self::RightSingle),
+                "JOIN_TYPE_LEFT_MARK" => Some(Self::LeftMark),
+                "JOIN_TYPE_RIGHT_MARK" => Some(Self::RightMark),
+                _ => None,
+            }
+        }
+    }
+}
+/// Outputs the first `count` input rows after skipping `offset` rows.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FetchRel {
+    #[prost(message, optional, tag = "1")]
+    pub common: ::core::option::Option<RelCommon>,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub input: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
+    #[prost(int64, tag = "3")]
+    pub offset: i64,
+    /// The number of rows to output, or -1 for all of them.
+    #[prost(int64, tag = "4")]
+    pub count: i64,
+}
+/// Groups the input rows and computes measures over each group, outputting the grouping
+/// expressions followed by the measures.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregateRel {
+    #[prost(message, optional, tag = "1")]
+    pub common: ::core::option::Option<RelCommon>,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub input: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
+    #[prost(message, repeated, tag = "3")]
+    pub groupings: ::prost::alloc::vec::Vec<aggregate_rel::Grouping>,
+    #[prost(message, repeated, tag = "4")]
+    pub measures: ::prost::alloc::vec::Vec<aggregate_rel::Measure>,
+    /// The expressions that groupings refer to by index.
+    #[prost(message, repeated, tag = "5")]
+    pub grouping_expressions: ::prost::alloc::vec::Vec<Expression>,
+}
+/// Nested message and enum types in `AggregateRel`.
+pub mod aggregate_rel {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Grouping {
+        #[prost(message, repeated, tag = "1")]
+        pub grouping_expressions: ::prost::alloc::vec::Vec<super::Expression>,
+        /// Indices into the aggregate's grouping expressions, used in place of
+        /// `grouping_expressions`.
+        #[prost(uint32, repeated, tag = "2")]
+        pub expression_references: ::prost::alloc::vec::Vec<u32>,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Measure {
+        #[prost(message, optional, tag = "1")]
+        pub measure: ::core::option::Option<super::AggregateFunction>,
+        /// Only the input rows for which this is true are aggregated.
+        #[prost(message, optional, tag = "2")]
+        pub filter: ::core::option::Option<super::Expression>,
+    }
+}
+/// Sorts the input rows.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SortRel {
+    #[prost(message, optional, tag = "1")]
+    pub common: ::core::option::Option<RelCommon>,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub input: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
+    #[prost(message, repeated, tag = "3")]
+    pub sorts: ::prost::alloc::vec::Vec<SortField>,
+}
+/// Outputs the input rows for which `condition` is true.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FilterRel {
+    #[prost(message, optional, tag = "1")]
+    pub common: ::core::option::Option<RelCommon>,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub input: ::core::option::Option<::prost::alloc::boxed::Box<Rel>>,
+    #[prost(message, optional, boxed, tag = "3")]
+    pub condition: ::core::option::Option<::prost::alloc::boxed::Box<Expression>>,
+}
+/// Combines the rows of inputs with the same schema.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetRel {
+    #[prost(message, optional, tag = "1")]
+    pub common: ::core::option::Option<RelCommon>,
+    #[prost(message, repeated, tag = "2")]
+    pub inputs: ::prost::alloc::vec::Vec<Rel>,
+    #[prost(enumeration = "set_rel::SetOp", tag = "3")]
+    pub op: i32,
+}
+/// Nested message and enum types in `SetRel`.
+pub mod set_rel {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum SetOp {
+        Unspecified = 0,
+        MinusPrimary = 1,
+        MinusMultiset = 2,
+        IntersectionPrimary = 3,
+        IntersectionMultiset = 4,
+        UnionDistinct = 5,
+        UnionAll = 6,
+    }
+    impl SetOp {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "SET_OP_UNSPECIFIED",
+                Self::MinusPrimary => "SET_OP_MINUS_PRIMARY",
+                Self::MinusMultiset => "SET_OP_MINUS_MULTISET",
+                Self::IntersectionPrimary => "SET_OP_INTERSECTION_PRIMARY",
+                Self::IntersectionMultiset => "SET_OP_INTERSECTION_MULTISET",
+                Self::UnionDistinct => "SET_OP_UNION_DISTINCT",
+                Self::UnionAll => "SET_OP_UNION_ALL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "SET_OP_UNSPECIFIED" => Some(Self::Unspecified),
+                "SET_OP_MINUS_PRIMARY" => Some(Self::MinusPrimary),
+                "SET_OP_MINUS_MULTISET" => Some(Self::MinusMultiset),
+                "SET_OP_INTERSECTION_PRIMARY" => Some(Self::IntersectionPrimary),
+                "SET_OP_INTERSECTION_MULTISET" => Some(Self::IntersectionMultiset),
+                "SET_OP_UNION_DISTINCT" => Some(Self::UnionDistinct),
+                "SET_OP_UNION_ALL" => Some(Self::UnionAll),
+                _ => None,
+            }
+        }
+    }
+}
+/// An expression to sort by, and the direction to sort in.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SortField {
+    #[prost(message, optional, tag = "1")]
+    pub expr: ::core::option::Option<Expression>,
+    #[prost(oneof = "sort_field::SortKind", tags = "2")]
+    pub sort_kind: ::core::option::Option<sort_field::SortKind>,
+}
+/// Nested message and enum types in `SortField`.
+pub mod sort_field {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum SortDirection {
+        Unspecified = 0,
+        AscNullsFirst = 1,
+        AscNullsLast = 2,
+        DescNullsFirst = 3,
+        DescNullsLast = 4,
+        Clustered = 5,
+    }
+    impl SortDirection {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "SORT_DIRECTION_UNSPECIFIED",
+                Self::AscNullsFirst => "SORT_DIRECTION_ASC_NULLS_FIRST",
+                Self::AscNullsLast => "SORT_DIRECTION_ASC_NULLS_LAST",
+                Self::DescNullsFirst => "SORT_DIRECTION_DESC_NULLS_FIRST",
+                Self::DescNullsLast => "SORT_DIRECTION_DESC_NULLS_LAST",
+                Self::Clustered => "SORT_DIRECTION_CLUSTERED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "SORT_DIRECTION_UNSPECIFIED" => Some(Self::Unspecified),
+                "SORT_DIRECTION_ASC_NULLS_FIRST" => Some(Self::AscNullsFirst),
+                "SORT_DIRECTION_ASC_NULLS_LAST" => Some(Self::AscNullsLast),
+                "SORT_DIRECTION_DESC_NULLS_FIRST" => Some(Self::DescNullsFirst),
+                "SORT_DIRECTION_DESC_NULLS_LAST" => Some(Self::DescNullsLast),
+                "SORT_DIRECTION_CLUSTERED" => Some(Self::Clustered),
+                _ => None,
+            }
+        }
+    }
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum SortKind {
+        #[prost(enumeration = "SortDirection", tag = "2")]
+        Direction(i32),
+    }
+}
+/// A function that produces one output value for each group of rows.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregateFunction {
+    /// Points to a function anchor defined in the extension declarations.
+    #[prost(uint32, tag = "1")]
+    pub function_reference: u32,
+    #[prost(message, repeated, tag = "7")]
+    pub arguments: ::prost::alloc::vec::Vec<FunctionArgument>,
+    #[prost(message, optional, tag = "5")]
+    pub output_type: ::core::option::Option<Type>,
+    #[prost(enumeration = "AggregationPhase", tag = "4")]
+    pub phase: i32,
+    #[prost(enumeration = "aggregate_function::AggregationInvocation", tag = "6")]
+    pub invocation: i32,
+}
+/// Nested message and enum types in `AggregateFunction`.
+pub mod aggregate_function {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum AggregationInvocation {
+        Unspecified = 0,
+        /// Aggregate all values.
+        All = 1,
+        /// Aggregate only the distinct values.
+        Distinct = 2,
+    }
+    impl AggregationInvocation {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "AGGREGATION_INVOCATION_UNSPECIFIED",
+                Self::All => "AGGREGATION_INVOCATION_ALL",
+                Self::Distinct => "AGGREGATION_INVOCATION_DISTINCT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "AGGREGATION_INVOCATION_UNSPECIFIED" => Some(Self::Unspecified),
+                "AGGREGATION_INVOCATION_ALL" => Some(Self::All),
+                "AGGREGATION_INVOCATION_DISTINCT" => Some(Self::Distinct),
+                _ => None,
+            }
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AggregationPhase {
+    Unspecified = 0,
+    InitialToIntermediate = 1,
+    IntermediateToIntermediate = 2,
+    InitialToResult = 3,
+    IntermediateToResult = 4,
+}
+impl AggregationPhase {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "AGGREGATION_PHASE_UNSPECIFIED",
+            Self::InitialToIntermediate => "AGGREGATION_PHASE_INITIAL_TO_INTERMEDIATE",
+            Self::IntermediateToIntermediate => "AGGREGATION_PHASE_INTERMEDIATE_TO_INTERMEDIATE",
+            Self::InitialToResult => "AGGREGATION_PHASE_INITIAL_TO_RESULT",
+            Self::IntermediateToResult => "AGGREGATION_PHASE_INTERMEDIATE_TO_RESULT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "AGGREGATION_PHASE_UNSPECIFIED" => Some(Self::Unspecified),
+            "AGGREGATION_PHASE_INITIAL_TO_INTERMEDIATE" => Some(Self::InitialToIntermediate),
+            "AGGREGATION_PHASE_INTERMEDIATE_TO_INTERMEDIATE" => {
+                Some(Self::IntermediateToIntermediate)
+            }
+            "AGGREGATION_PHASE_INITIAL_TO_RESULT" => Some(Self::InitialToResult),
+            "AGGREGATION_PHASE_INTERMEDIATE_TO_RESULT" => Some(Self::IntermediateToResult),
+            _ => None,
+        }
+    }
+}
@@ -117,6 +117,7 @@ pub mod pylib {
         daft_core::python::register_modules(m)?;
         daft_csv::register_modules(m)?;
         daft_dsl::register_modules(m)?;
+        daft_flight_sql::register_modules(m)?;
         daft_functions::register_modules(m)?;
         daft_functions_json::register_modules(m)?;
         daft_io::register_modules(m)?;
@@ -124,13 +125,16 @@ pub mod pylib {
         daft_local_execution::register_modules(m)?;
         daft_logical_plan::register_modules(m)?;
         daft_parquet::register_modules(m)?;
+        daft_pgwire::register_modules(m)?;
         daft_micropartition::register_modules(m)?;
         daft_recordbatch::register_modules(m)?;
         daft_scan::register_modules(m)?;
         daft_scheduler::register_modules(m)?;
         daft_session::register_modules(m)?;
         daft_sql::register_modules(m)?;
+        daft_substrait::register_modules(m)?;
         daft_shuffles::python::register_modules(m)?;
+        daft_vector_index::python::register_modules(m)?;
         // Register testing module
         let testing_module = PyModule::new(m.py(), "testing")?;
         m.add_submodule(&testing_module)?;
@@ -141,6 +145,8 @@ pub mod pylib {
         m.add_wrapped(wrap_pyfunction!(refresh_logger))?;
         m.add_wrapped(wrap_pyfunction!(get_max_log_level))?;
         m.add_wrapped(wrap_pyfunction!(set_compute_runtime_num_worker_threads))?;
+        daft_deltalake::python::register_modules(m)?;
+        daft_iceberg::python::register_modules(m)?;
         daft_image::python::register_modules(m)?;
 
         daft_dashboard::register_modules(m)?;
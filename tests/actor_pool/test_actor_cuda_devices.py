@@ -12,11 +12,6 @@ from daft.datatype import DataType
 from daft.internal.gpu import cuda_visible_devices
 from tests.conftest import get_tests_daft_runner_name
 
-pytestmark = pytest.mark.skipif(
-    get_tests_daft_runner_name() == "native", reason="Native runner does not support GPU tests yet"
-)
-
-
 @contextmanager
 def reset_runner_with_gpus(num_gpus, monkeypatch):
     """If current runner does not have enough GPUs, create a new runner with mocked GPU resources."""
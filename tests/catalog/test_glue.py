@@ -17,6 +17,7 @@ from daft.catalog.__glue import (
     load_glue,
 )
 from daft.dataframe import DataFrame
+from daft.io import IOConfig, S3Config
 from daft.logical.schema import DataType, Schema
 
 if TYPE_CHECKING:
@@ -68,11 +69,22 @@ def test_load_glue():
         aws_session_token="test_session_token",
     )
     assert catalog.name == "mock_glue_catalog"
+    assert catalog._io_config.s3.region_name == "us-west-2"
+    assert catalog._io_config.s3.key_id == "test_access_key"
+    assert catalog._io_config.s3.session_token == "test_session_token"
+
+
+def test_load_glue_with_io_config():
+    io_config = IOConfig(s3=S3Config(region_name="us-west-2", key_id="test_access_key", access_key="test_secret_key"))
+    catalog = load_glue("mock_glue_catalog", io_config=io_config)
+    assert catalog._client.meta.region_name == "us-west-2"
+    assert catalog._io_config is io_config
 
 
 def test_catalog_from_client(glue_client):
     assert Catalog.from_glue("gc", client=glue_client)
     assert GlueCatalog.from_client("gc", glue_client)
+    assert GlueCatalog.from_client("gc", glue_client)._io_config.s3.region_name == "us-west-2"
 
 
 def test_catalog_from_session(mock_session):
@@ -357,6 +369,29 @@ def test_glue_csv_table_from_table_info_4(glue_catalog):
         GlueCsvTable.from_table_info(glue_catalog, table_info)
 
 
+def test_glue_csv_table_from_table_info_with_partition_keys(glue_catalog):
+    table_info = {
+        "Name": "test_csv_table",
+        "StorageDescriptor": {
+            "Columns": [
+                {"Name": "col1", "Type": "string"},
+            ],
+            "Location": "s3://bucket/test_csv_table/",
+        },
+        "PartitionKeys": [
+            {"Name": "year", "Type": "int"},
+        ],
+        "Parameters": {
+            "classification": "CSV",
+        },
+    }
+    csv_table = GlueCsvTable.from_table_info(glue_catalog, table_info)
+    assert csv_table._schema == Schema._from_pydict({"col1": DataType.string(), "year": DataType.int32()})
+    assert csv_table._hive_partitioning is True
+    assert csv_table._hive_partitioning_cols == ["year"]
+    assert csv_table._io_config is glue_catalog._io_config
+
+
 ###
 # GlueParquetTable
 ###
@@ -491,3 +526,35 @@ def test_convert_glue_schema():
             "date_col": DataType.date(),
         }
     )
+
+
+def test_convert_glue_schema_parameterized_and_nested_types():
+    columns = [
+        {"Name": "tinyint_col", "Type": "tinyint"},
+        {"Name": "smallint_col", "Type": "smallint"},
+        {"Name": "int_col", "Type": "int"},
+        {"Name": "decimal_col", "Type": "decimal(10,2)"},
+        {"Name": "varchar_col", "Type": "varchar(255)"},
+        {"Name": "binary_col", "Type": "binary"},
+        {"Name": "array_col", "Type": "array<bigint>"},
+        {"Name": "map_col", "Type": "map<string,array<int>>"},
+        {"Name": "struct_col", "Type": "struct<a:int,b:map<string,decimal(5,1)>>"},
+    ]
+    assert _convert_glue_schema(columns) == Schema._from_pydict(
+        {
+            "tinyint_col": DataType.int8(),
+            "smallint_col": DataType.int16(),
+            "int_col": DataType.int32(),
+            "decimal_col": DataType.decimal128(precision=10, scale=2),
+            "varchar_col": DataType.string(),
+            "binary_col": DataType.binary(),
+            "array_col": DataType.list(DataType.int64()),
+            "map_col": DataType.map(DataType.string(), DataType.list(DataType.int32())),
+            "struct_col": DataType.struct(
+                {
+                    "a": DataType.int32(),
+                    "b": DataType.map(DataType.string(), DataType.decimal128(precision=5, scale=1)),
+                }
+            ),
+        }
+    )
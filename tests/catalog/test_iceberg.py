@@ -295,3 +295,41 @@ def test_sess_read_options(sess: Session):
     # invalid options (version is a unity option, not iceberg)
     with pytest.raises(ValueError, match="Unsupported option"):
         sess.read_table("tbl", version="1")
+
+
+###
+# sql tests
+###
+
+
+def test_sql_create_table_as_select_and_insert(iceberg_catalog):
+    sess = Session()
+    sess.attach_catalog(iceberg_catalog, alias="_test_sql_iceberg")
+    sess.create_temp_table("src", daft.from_pydict({"a": [1, 2, 3], "b": ["x", "y", "z"]}))
+
+    ns = "test_sql_create_table_as_select"
+    sess.create_namespace(ns)
+    sess.sql(f"CREATE TABLE {ns}.tbl AS SELECT a, b FROM src WHERE a < 3")
+    assert sess.read_table(f"{ns}.tbl").sort("a").to_pydict() == {"a": [1, 2], "b": ["x", "y"]}
+
+    # creating an existing table fails unless it's IF NOT EXISTS
+    with pytest.raises(Exception, match="already exists"):
+        sess.sql(f"CREATE TABLE {ns}.tbl AS SELECT a, b FROM src")
+    sess.sql(f"CREATE TABLE IF NOT EXISTS {ns}.tbl AS SELECT a, b FROM src")
+
+    # columns are inserted by position, or into the listed columns with the others null
+    sess.sql(f"INSERT INTO {ns}.tbl SELECT a * 10, b FROM src WHERE a = 3")
+    sess.sql(f"INSERT INTO {ns}.tbl (b) SELECT 'w'")
+    assert sess.read_table(f"{ns}.tbl").sort("a").to_pydict() == {
+        "a": [1, 2, 30, None],
+        "b": ["x", "y", "z", "w"],
+    }
+
+    sess.sql(f"INSERT OVERWRITE TABLE {ns}.tbl SELECT a, b FROM src WHERE a = 1")
+    assert sess.read_table(f"{ns}.tbl").to_pydict() == {"a": [1], "b": ["x"]}
+
+    with pytest.raises(Exception, match="columns"):
+        sess.sql(f"INSERT INTO {ns}.tbl SELECT a FROM src")
+
+    iceberg_catalog.drop_table(f"{ns}.tbl")
+    iceberg_catalog.drop_namespace(ns)
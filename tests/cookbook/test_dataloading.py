@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import datetime
 import os
 import pathlib
 
@@ -78,7 +79,7 @@ def test_glob_files(tmpdir):
         bar_filepath = pathlib.Path(tmpdir) / f"file_{i}.bar"
         bar_filepath.write_text("b" * i)
 
-    daft_df = daft.from_glob_path(os.path.join(tmpdir, "*.foo"))
+    daft_df = daft.from_glob_path(os.path.join(tmpdir, "*.foo")).select("path", "size", "num_rows")
     daft_pd_df = daft_df.to_pandas()
 
     pd_df = pd.DataFrame.from_records(
@@ -93,7 +94,7 @@ def test_glob_files(tmpdir):
 def test_glob_files_single_file(tmpdir):
     filepath = pathlib.Path(tmpdir) / "file.foo"
     filepath.write_text("b" * 10)
-    daft_df = daft.from_glob_path(os.path.join(tmpdir, "file.foo"))
+    daft_df = daft.from_glob_path(os.path.join(tmpdir, "file.foo")).select("path", "size", "num_rows")
     daft_pd_df = daft_df.to_pandas()
     pd_df = pd.DataFrame.from_records([{"path": "file://" + str(filepath), "size": 10, "num_rows": None}])
     pd_df = pd_df.astype({"num_rows": float})
@@ -110,7 +111,7 @@ def test_glob_files_directory(tmpdir):
             filepath.write_text("a" * i)
             filepaths.append(filepath)
 
-    daft_df = daft.from_glob_path(str(tmpdir))
+    daft_df = daft.from_glob_path(str(tmpdir)).select("path", "size", "num_rows")
     daft_pd_df = daft_df.to_pandas()
 
     listing_records = [
@@ -134,7 +135,7 @@ def test_glob_files_recursive(tmpdir):
             filepath.write_text("a" * i)
             paths.append(filepath)
 
-    daft_df = daft.from_glob_path(os.path.join(tmpdir, "**"))
+    daft_df = daft.from_glob_path(os.path.join(tmpdir, "**")).select("path", "size", "num_rows")
     daft_pd_df = daft_df.to_pandas()
     listing_records = [
         {"path": "file://" + str(path.as_posix()), "size": size, "num_rows": None}
@@ -145,3 +146,23 @@ def test_glob_files_recursive(tmpdir):
     pd_df = pd_df.astype({"num_rows": float})
 
     assert_df_equals(daft_pd_df, pd_df, sort_key="path")
+
+
+def test_glob_files_metadata(tmpdir):
+    filepaths = []
+    for i in range(3):
+        filepath = pathlib.Path(tmpdir) / f"file_{i}.foo"
+        filepath.write_text("a" * i)
+        filepaths.append(filepath)
+
+    daft_df = daft.from_glob_path(os.path.join(tmpdir, "*.foo")).sort("path")
+    assert daft_df.schema()["last_modified"].dtype == daft.DataType.timestamp("us", "UTC")
+
+    result = daft_df.to_pydict()
+    epoch = datetime.datetime(1970, 1, 1, tzinfo=datetime.timezone.utc)
+    assert result["last_modified"] == [
+        epoch + datetime.timedelta(microseconds=path.stat().st_mtime_ns // 1000) for path in filepaths
+    ]
+    assert result["etag"] == [None] * 3
+    assert result["version_id"] == [None] * 3
+    assert result["storage_class"] == [None] * 3
@@ -866,3 +866,138 @@ def test_bool_agg_type_error(make_df):
     with pytest.raises(Exception) as exc_info:
         df.agg(col("int_col").bool_or()).collect()
     assert "bool_or is not implemented for type Int64" in str(exc_info.value)
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_filtered_aggregations(make_df, repartition_nparts, with_morsel_size):
+    df = make_df(
+        {"k": ["a", "a", "b", "b", "b", "c"], "x": [1, 2, 3, None, 5, 6], "flag": [True, False, True, True, None, False]},
+        repartition=repartition_nparts,
+    )
+    result = (
+        df.groupby("k")
+        .agg(
+            col("x").sum().where(col("flag")).alias("sum"),
+            col("x").mean().where(col("flag")).alias("mean"),
+            col("x").max().where(col("flag")).alias("max"),
+            col("x").count().where(col("flag")).alias("valid"),
+            col("x").count("all").where(col("flag")).alias("all"),
+            col("x").count("null").where(col("flag")).alias("null"),
+            daft.functions.sum_if("x", col("x") > 1).alias("sum_if"),
+            daft.functions.count_if(col("x") > 1).alias("count_if"),
+        )
+        .sort("k")
+        .to_pydict()
+    )
+    assert result == {
+        "k": ["a", "b", "c"],
+        "sum": [1, 3, None],
+        "mean": [1.0, 3.0, None],
+        "max": [1, 3, None],
+        "valid": [1, 1, 0],
+        "all": [1, 2, 0],
+        "null": [0, 1, 0],
+        "sum_if": [2, 8, 6],
+        "count_if": [1, 2, 1],
+    }
+
+
+def test_filtered_aggregations_require_an_aggregation():
+    with pytest.raises(ValueError, match="aggregations"):
+        col("x").where(col("x") > 1)
+    with pytest.raises(ValueError, match="Filtering"):
+        col("x").agg_list().where(col("x") > 1)
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_groupby_collect_ordered(make_df, repartition_nparts, with_morsel_size):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 1, 2, 2, 2],
+            "values": ["a", "b", "a", None, "c", "d", "c"],
+            "ts": [4, 1, 3, 2, 1, 3, 2],
+        },
+        repartition=repartition_nparts,
+    )
+    result = (
+        daft_df.groupby("group")
+        .agg(
+            col("values").collect_list(order_by=col("ts")).alias("list"),
+            col("values").collect_list(order_by=col("ts"), desc=True, limit=2).alias("latest"),
+            col("values").collect_set(order_by=col("ts")).alias("set"),
+            col("values").collect_set(order_by=col("ts"), limit=1).alias("first"),
+            col("ts").sum().alias("sum"),
+        )
+        .sort("group")
+        .to_pydict()
+    )
+    assert result == {
+        "group": [1, 2],
+        "list": [["b", None, "a", "a"], ["c", "c", "d"]],
+        "latest": [["a", "a"], ["d", "c"]],
+        "set": [["b", "a"], ["c", "d"]],
+        "first": [["b"], ["c"]],
+        "sum": [10, 6],
+    }
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_collect_with_limit_across_partitions(make_df, repartition_nparts, with_morsel_size):
+    daft_df = make_df(
+        {"group": [1] * 8 + [2] * 2, "values": [1, 1, 2, 2, 3, 3, 4, None, 5, 5]},
+        repartition=repartition_nparts,
+    )
+    result = (
+        daft_df.groupby("group")
+        .agg(
+            col("values").collect_list(limit=3).alias("list"),
+            col("values").collect_set(limit=3).alias("set"),
+            col("values").collect_set().alias("all"),
+        )
+        .sort("group")
+        .to_pydict()
+    )
+    assert [len(values) for values in result["list"]] == [3, 2]
+    assert [len(set(values)) for values in result["set"]] == [3, 1]
+    assert set(result["set"][0]) <= {1, 2, 3, 4}
+    assert [sorted(values) for values in result["all"]] == [[1, 2, 3, 4], [5]]
+
+    global_result = daft_df.agg(col("values").collect_set(limit=2).alias("set")).to_pydict()
+    assert len(set(global_result["set"][0])) == 2
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_groupby_approx_top_k(make_df, repartition_nparts, with_morsel_size):
+    daft_df = make_df(
+        {
+            "group": [1] * 7 + [2] * 3,
+            "values": ["a", "b", "a", None, "c", "a", "b", "x", "x", "y"],
+        },
+        repartition=repartition_nparts,
+    )
+    result = (
+        daft_df.groupby("group")
+        .agg(col("values").approx_top_k(2).alias("top"))
+        .sort("group")
+        .to_pydict()
+    )
+    assert result["top"] == [
+        [{"value": "a", "count": 3}, {"value": "b", "count": 2}],
+        [{"value": "x", "count": 2}, {"value": "y", "count": 1}],
+    ]
+
+    global_result = daft_df.agg(col("values").approx_top_k(1).alias("top")).to_pydict()
+    assert global_result["top"] == [[{"value": "a", "count": 3}]]
+
+
+def test_approx_top_k_on_many_values():
+    # the heavy hitters are found among far more distinct values than the sketch tracks
+    values = [i % 3 for i in range(3000)] + list(range(10, 10_000))
+    df = daft.from_pydict({"values": values}).into_partitions(4)
+    result = df.agg(col("values").approx_top_k(3).alias("top")).to_pydict()
+    assert sorted(item["value"] for item in result["top"][0]) == [0, 1, 2]
+
+
+def test_approx_top_k_requires_positive_k():
+    with pytest.raises(ValueError, match="positive k"):
+        col("values").approx_top_k(0)
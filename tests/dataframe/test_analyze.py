@@ -0,0 +1,52 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pyarrow.parquet as papq
+import pytest
+
+import daft
+
+
+@pytest.fixture
+def parquet_path(tmp_path):
+    path = tmp_path / "data.parquet"
+    papq.write_table(
+        pa.table(
+            {
+                "a": [1, 2, 3, 3, None],
+                "b": ["x", "y", "x", None, None],
+            }
+        ),
+        path,
+    )
+    return str(path)
+
+
+def test_analyze_preserves_data(parquet_path):
+    df = daft.read_parquet(parquet_path)
+    analyzed = df.analyze()
+    assert analyzed.sort("a").to_pydict() == df.sort("a").to_pydict()
+
+
+def test_analyze_attaches_statistics_to_source(parquet_path):
+    df = daft.read_parquet(parquet_path).analyze()
+    plan = df._builder.pretty_print()
+    assert "Analyzed statistics = { Num rows = 5, Num columns = 2 }" in plan
+
+
+@pytest.mark.parametrize("enable_cost_based_planning", [False, True])
+def test_analyze_cost_based_planning(parquet_path, enable_cost_based_planning):
+    with daft.planning_config_ctx(enable_cost_based_planning=enable_cost_based_planning):
+        left = daft.read_parquet(parquet_path).analyze()
+        right = daft.read_parquet(parquet_path).analyze().with_column_renamed("b", "c")
+        df = left.where(left["a"] > 1).join(right, on="a").groupby("b").agg(daft.col("c").count()).sort("b")
+        assert df.to_pydict() == {"b": ["x", "y", None], "c": [1, 1, 1]}
+
+
+def test_analyze_requires_source(parquet_path):
+    df = daft.read_parquet(parquet_path).where(daft.col("a") > 1)
+    with pytest.raises(Exception, match="Statistics can only be collected"):
+        df.analyze()
+
+    with pytest.raises(Exception, match="Statistics can only be collected"):
+        daft.from_pydict({"a": [1, 2, 3]}).analyze()
@@ -178,3 +178,26 @@ def test_approx_percentiles_groupby_with_alias(make_df, repartition_nparts, perc
         check_exact=False,
         rtol=0.02,
     )
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 5])
+def test_approx_quantiles(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "id": [1, 1, 1, 2, 3],
+            "values": [1, 2, 3, 10, None],
+        },
+        repartition=repartition_nparts,
+    )
+    grouped = daft_df.groupby("id").approx_quantiles([0.0, 1.0], "values").sort("id").to_pydict()
+    expected = daft_df.groupby("id").agg(col("values").approx_percentiles([0.0, 1.0])).sort("id").to_pydict()
+    assert grouped == expected
+    assert grouped["values"][2] is None
+    assert [round(q) for q in grouped["values"][0]] == [1, 3]
+
+    global_agg = daft_df.approx_quantiles([0.5], "values").to_pydict()
+    assert daft_df.agg(col("values").approx_quantiles([0.5])).to_pydict() == global_agg
+    assert len(global_agg["values"][0]) == 1
+
+    with pytest.raises(TypeError):
+        col("values").approx_quantiles(0.5)
@@ -0,0 +1,131 @@
+from __future__ import annotations
+
+import datetime
+
+import pytest
+
+import daft
+from daft import col
+from tests.conftest import get_tests_daft_runner_name
+
+pytestmark = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="As-of joins are only supported on the native runner"
+)
+
+
+@pytest.fixture
+def trades(make_df):
+    return make_df(
+        {
+            "id": [0, 1, 2, 3, 4],
+            "sym": ["a", "a", "b", "a", "b"],
+            "ts": [1, 5, 5, 12, None],
+        }
+    )
+
+
+@pytest.fixture
+def quotes(make_df):
+    return make_df(
+        {
+            "sym": ["a", "a", "b", "a", "a"],
+            "ts": [4, 0, 6, 10, 3],
+            "price": [40, 0, 60, 100, 31],
+        }
+    )
+
+
+@pytest.mark.parametrize(
+    "strategy,expected",
+    [
+        ("backward", [0, 40, None, 100, None]),
+        ("forward", [31, 100, 60, None, None]),
+        ("nearest", [0, 40, 60, 100, None]),
+    ],
+)
+def test_join_asof_strategies(trades, quotes, strategy, expected):
+    joined = trades.join_asof(quotes, on="ts", by="sym", strategy=strategy).sort("id")
+
+    assert joined.column_names == ["id", "sym", "ts", "price"]
+    assert joined.to_pydict()["price"] == expected
+
+
+def test_join_asof_without_by(trades, quotes):
+    joined = trades.join_asof(quotes, on="ts", strategy="forward").sort("id")
+
+    assert joined.to_pydict() == {
+        "id": [0, 1, 2, 3, 4],
+        "sym": ["a", "a", "b", "a", "b"],
+        "ts": [1, 5, 5, 12, None],
+        "right.sym": ["a", "b", "b", None, None],
+        "price": [31, 60, 60, None, None],
+    }
+
+
+@pytest.mark.parametrize(
+    "strategy,expected",
+    [
+        ("backward", [0, 40, None, None, None]),
+        ("nearest", [0, 40, 60, None, None]),
+    ],
+)
+def test_join_asof_tolerance(trades, quotes, strategy, expected):
+    joined = trades.join_asof(quotes, on="ts", by="sym", strategy=strategy, tolerance=1).sort("id")
+
+    assert joined.to_pydict()["price"] == expected
+
+
+def test_join_asof_different_key_names(trades, make_df):
+    quotes = make_df({"symbol": ["a", "b"], "quote_ts": [2, 0], "price": [20, 0]})
+
+    joined = trades.join_asof(quotes, left_on="ts", right_on="quote_ts", left_by="sym", right_by="symbol").sort("id")
+
+    assert joined.to_pydict() == {
+        "id": [0, 1, 2, 3, 4],
+        "sym": ["a", "a", "b", "a", "b"],
+        "ts": [1, 5, 5, 12, None],
+        "symbol": [None, "a", "b", "a", None],
+        "quote_ts": [None, 2, 0, 2, None],
+        "price": [None, 20, 0, 20, None],
+    }
+
+
+def test_join_asof_timestamps_with_timedelta_tolerance(make_df):
+    start = datetime.datetime(2024, 1, 1)
+    events = make_df({"ts": [start + datetime.timedelta(minutes=m) for m in [0, 10, 30]]})
+    readings = make_df(
+        {
+            "ts": [start - datetime.timedelta(minutes=1), start + datetime.timedelta(minutes=25)],
+            "reading": [1.0, 2.0],
+        }
+    )
+
+    joined = events.join_asof(readings, on="ts", tolerance=datetime.timedelta(minutes=5)).sort("ts")
+
+    assert joined.to_pydict()["reading"] == [1.0, None, 2.0]
+
+
+def test_join_asof_invalid_keys(trades, quotes):
+    with pytest.raises(ValueError, match="If `on` is not None"):
+        trades.join_asof(quotes, on="ts", left_on="ts")
+    with pytest.raises(ValueError, match="If `on` is None"):
+        trades.join_asof(quotes, left_on="ts")
+    with pytest.raises(ValueError, match="`left_by` and `right_by`"):
+        trades.join_asof(quotes, on="ts", left_by="sym")
+
+
+def test_join_asof_with_expressions(make_df):
+    left = make_df({"t": [10, 20, 30]})
+    right = make_df({"t_ms": [9000, 25000], "v": ["x", "y"]})
+
+    joined = left.join_asof(right, left_on=col("t") * 1000, right_on="t_ms").sort("t")
+
+    assert joined.to_pydict()["v"] == ["x", "x", "y"]
+
+
+def test_join_asof_empty_right(trades):
+    quotes = daft.from_pydict({"sym": ["a"], "ts": [0], "price": [0]}).where(col("price") > 0)
+
+    joined = trades.join_asof(quotes, on="ts", by="sym").sort("id")
+
+    assert joined.to_pydict()["price"] == [None] * 5
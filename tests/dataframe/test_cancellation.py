@@ -0,0 +1,52 @@
+from __future__ import annotations
+
+import os
+import time
+from functools import partial
+
+import pytest
+
+import daft
+from daft.context import get_context
+from daft.exceptions import QueryCancelledError
+from daft.io._generator import read_generator
+from daft.io.writer import ParquetFileWriter
+from daft.recordbatch import MicroPartition, RecordBatch
+from tests.conftest import get_tests_daft_runner_name
+
+SCHEMA = daft.Schema._from_field_name_and_types([("x", daft.DataType.int64())])
+
+
+def _slow_generator(num_partitions: int):
+    def generate(i: int):
+        time.sleep(0.1)
+        yield RecordBatch.from_pydict({"x": [i]})
+
+    for i in range(num_partitions):
+        yield partial(generate, i)
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() != "native", reason="Needs to run on the native runner")
+def test_cancelled_query_raises_query_cancelled_error():
+    from daft.daft import NativeExecutor
+
+    df = read_generator(_slow_generator(100), SCHEMA)
+    builder = df._builder.optimize()
+    results = NativeExecutor().run(builder._builder, {}, get_context().daft_execution_config, 1)
+
+    results.cancel()
+    start = time.monotonic()
+    with pytest.raises(QueryCancelledError):
+        for _ in results:
+            pass
+    # The query stops instead of scanning the rest of the partitions
+    assert time.monotonic() - start < 5
+
+
+def test_aborted_writer_leaves_no_file(tmp_path):
+    writer = ParquetFileWriter(str(tmp_path), 0)
+    writer.write(MicroPartition.from_pydict({"x": [1, 2, 3]}))
+    writer.abort()
+
+    assert writer.is_closed
+    assert [name for name in os.listdir(tmp_path) if not name.startswith(".")] == []
@@ -0,0 +1,52 @@
+from __future__ import annotations
+
+import glob
+import os
+
+import pytest
+
+import daft
+from daft import col
+from tests.conftest import get_tests_daft_runner_name
+
+
+def test_durable_fingerprint_is_stable_for_equal_plans(tmp_path):
+    path = str(tmp_path / "data.parquet")
+    daft.from_pydict({"a": [1, 2, 3]}).write_parquet(path)
+
+    def fingerprint(threshold):
+        return daft.read_parquet(path).where(col("a") > threshold)._builder.durable_fingerprint()
+
+    assert fingerprint(1) is not None
+    assert fingerprint(1) == fingerprint(1)
+    assert fingerprint(1) != fingerprint(2)
+
+
+def test_durable_fingerprint_is_none_for_in_memory_data():
+    assert daft.from_pydict({"a": [1, 2, 3]})._builder.durable_fingerprint() is None
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() == "native",
+    reason="The native runner doesn't run queries in stages",
+)
+def test_checkpointed_stages_are_restored(tmp_path):
+    data_dir = str(tmp_path / "data")
+    checkpoint_dir = str(tmp_path / "checkpoints")
+    daft.from_pydict({"key": [i % 4 for i in range(100)], "value": list(range(100))}).into_partitions(4).write_parquet(
+        data_dir
+    )
+
+    def run():
+        df = daft.read_parquet(data_dir).groupby("key").agg(col("value").sum()).sort("key")
+        return df.to_pydict()
+
+    with daft.execution_config_ctx(checkpoint_dir=checkpoint_dir):
+        expected = run()
+        markers = glob.glob(os.path.join(checkpoint_dir, "*", "stage_*", "_COMPLETED"))
+        assert len(markers) > 0
+        mtimes = {marker: os.path.getmtime(marker) for marker in markers}
+
+        # The stages are restored from their checkpoints rather than run and checkpointed again
+        assert run() == expected
+        assert {marker: os.path.getmtime(marker) for marker in markers} == mtimes
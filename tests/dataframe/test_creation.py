@@ -280,6 +280,18 @@ def test_create_dataframe_pandas(valid_data: list[dict[str, float]], multiple) -
     pd.testing.assert_frame_equal(df.to_pandas(), pd_df)
 
 
+@pytest.mark.parametrize("multiple", [False, True])
+def test_create_dataframe_polars(valid_data: list[dict[str, float]], multiple) -> None:
+    pl = pytest.importorskip("polars")
+    pl_df = pl.DataFrame(valid_data)
+    df = daft.from_polars([pl_df, pl_df] if multiple else pl_df)
+    expected = pl.concat([pl_df, pl_df]) if multiple else pl_df
+    assert len(df) == len(expected)
+    assert df.column_names == expected.columns
+    # Check roundtrip.
+    assert df.to_polars().equals(expected)
+
+
 def test_create_dataframe_pandas_py_object(valid_data: list[dict[str, float]]) -> None:
     pydict = {k: [item[k] for item in valid_data] for k in valid_data[0].keys()}
     pydict["obj"] = [MyObjWithValue(i) for i in range(len(valid_data))]
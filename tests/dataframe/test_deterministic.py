@@ -0,0 +1,61 @@
+from __future__ import annotations
+
+import pyarrow.parquet as pq
+import pytest
+
+import daft
+from daft import col
+from tests.conftest import get_tests_daft_runner_name
+
+pytestmark = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Deterministic execution is only supported on the native runner"
+)
+
+
+def test_deterministic_config():
+    assert not daft.context.get_context().daft_execution_config.deterministic
+    with daft.execution_config_ctx(deterministic=True):
+        assert daft.context.get_context().daft_execution_config.deterministic
+
+
+@pytest.mark.parametrize("limit", [None, 15])
+def test_deterministic_sort_keeps_order_of_ties(limit):
+    data = {"key": [i % 3 for i in range(60)], "value": list(range(60))}
+    df = daft.from_pydict(data).sort("key", desc=True)
+    if limit is not None:
+        df = df.limit(limit)
+
+    with daft.execution_config_ctx(deterministic=True, default_morsel_size=4):
+        result = df.to_pydict()
+
+    expected = sorted(zip(data["key"], data["value"]), key=lambda row: -row[0])[:limit]
+    assert list(zip(result["key"], result["value"])) == expected
+
+
+def test_deterministic_results_across_runs():
+    df = (
+        daft.from_pydict({"key": [i % 7 for i in range(1000)], "value": list(range(1000))})
+        
+        .sample(fraction=0.5, seed=42)
+        .groupby("key")
+        .agg(col("value").agg_list().alias("values"))
+    )
+
+    with daft.execution_config_ctx(deterministic=True, default_morsel_size=16):
+        results = [df.to_pydict() for _ in range(3)]
+
+    assert results[0] == results[1] == results[2]
+
+
+def test_deterministic_write_assigns_same_rows_to_files(tmp_path):
+    df = daft.from_pydict({"value": list(range(1000))})
+
+    def write(path):
+        df.write_parquet(str(path))
+        return sorted(pq.read_table(file).column("value").to_pylist() for file in path.glob("*.parquet"))
+
+    with daft.execution_config_ctx(deterministic=True, default_morsel_size=16):
+        first = write(tmp_path / "first")
+        second = write(tmp_path / "second")
+
+    assert first == second
@@ -0,0 +1,37 @@
+from __future__ import annotations
+
+import io
+
+import pyarrow as pa
+import pyarrow.parquet as papq
+import pytest
+
+import daft
+from tests.conftest import get_tests_daft_runner_name
+
+pytestmark = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="explain_analyze is only supported on the native runner"
+)
+
+
+def test_explain_analyze_reports_operator_metrics(tmp_path):
+    path = str(tmp_path / "data.parquet")
+    papq.write_table(pa.table({"a": list(range(100)), "b": [i % 5 for i in range(100)]}), path)
+    df = daft.read_parquet(path).where(daft.col("a") >= 50).groupby("b").agg(daft.col("a").sum())
+
+    output = io.StringIO()
+    df.explain_analyze(file=output)
+    plan = output.getvalue()
+
+    assert "Rows received =  50" in plan
+    assert "Rows emitted =  5" in plan
+    assert "Wall Time = " in plan
+    assert "Peak memory = " in plan
+    assert "Bytes spilled = " in plan
+    assert "Bytes scanned = " in plan
+
+
+def test_explain_analyze_does_not_materialize_results():
+    df = daft.from_pydict({"a": [1, 2, 3]})
+    df.explain_analyze(file=io.StringIO())
+    assert df._result is None
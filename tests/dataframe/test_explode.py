@@ -40,3 +40,27 @@ def test_explode_bad_col_type(make_df):
     df = make_df({"a": [1, 2, 3]})
     with pytest.raises(ValueError, match="cannot be exploded"):
         df = df.explode(col("a"))
+
+
+def test_explode_unnest_structs(make_df):
+    df = make_df(
+        {
+            "points": [[{"x": 1, "y": "a"}, {"x": 2, "y": "b"}], None, [{"x": 3, "y": "c"}]],
+            "tags": [["p", "q"], None, ["s"]],
+            "id": [0, 1, 2],
+        }
+    )
+    df = df.explode(col("points"), col("tags"), unnest_structs=True)
+    assert df.column_names == ["x", "y", "tags", "id"]
+    assert df.to_pydict() == {
+        "x": [1, 2, None, 3],
+        "y": ["a", "b", None, "c"],
+        "tags": ["p", "q", None, "s"],
+        "id": [0, 0, 1, 2],
+    }
+
+
+def test_explode_unnest_structs_name_collision(make_df):
+    df = make_df({"points": [[{"id": 1}]], "id": [0]})
+    with pytest.raises(ValueError, match="more than one column named id"):
+        df.explode(col("points"), unnest_structs=True)
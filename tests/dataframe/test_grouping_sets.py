@@ -0,0 +1,73 @@
+from __future__ import annotations
+
+import pytest
+
+from daft import col
+
+
+@pytest.fixture
+def sales(make_df):
+    return make_df(
+        {
+            "region": ["east", "east", "west", "west"],
+            "product": ["a", "b", "a", "b"],
+            "qty": [1, 2, 3, 4],
+        }
+    )
+
+
+def sort_output(df):
+    return df.sort(["grouping_id", "region", "product"]).to_pydict()
+
+
+def test_rollup(sales):
+    result = sort_output(sales.rollup("region", "product").sum("qty"))
+
+    assert result == {
+        "region": ["east", "east", "west", "west", "east", "west", None],
+        "product": ["a", "b", "a", "b", None, None, None],
+        "qty": [1, 2, 3, 4, 3, 7, 10],
+        "grouping_id": [0, 0, 0, 0, 1, 1, 3],
+    }
+
+
+def test_cube(sales):
+    result = sort_output(sales.cube("region", "product").agg(col("qty").sum(), col("qty").count().alias("count")))
+
+    assert result == {
+        "region": ["east", "east", "west", "west", "east", "west", None, None, None],
+        "product": ["a", "b", "a", "b", None, None, "a", "b", None],
+        "qty": [1, 2, 3, 4, 3, 7, 4, 6, 10],
+        "count": [1, 1, 1, 1, 2, 2, 2, 2, 4],
+        "grouping_id": [0, 0, 0, 0, 1, 1, 2, 2, 3],
+    }
+
+
+def test_grouping_sets(sales):
+    result = sort_output(sales.grouping_sets("product", ["region"], []).sum("qty"))
+
+    assert result == {
+        "product": ["a", "b", None, None, None],
+        "region": [None, None, "east", "west", None],
+        "qty": [4, 6, 3, 7, 10],
+        "grouping_id": [1, 1, 2, 2, 3],
+    }
+
+
+def test_grouping_sets_with_null_keys(make_df):
+    df = make_df({"k": ["x", None, "x"], "v": [1, 2, 3]})
+
+    result = df.rollup("k").sum("v").sort(["grouping_id", "k"]).to_pydict()
+
+    # the null group is told apart from the grand total by its grouping id
+    assert result == {"k": ["x", None, None], "v": [4, 2, 6], "grouping_id": [0, 0, 1]}
+
+
+def test_grouping_sets_requires_a_set(sales):
+    with pytest.raises(ValueError, match="at least one set"):
+        sales.grouping_sets()
+
+
+def test_map_groups_with_grouping_sets_unsupported(sales):
+    with pytest.raises(ValueError, match="not supported with grouping sets"):
+        sales.rollup("region").map_groups(col("qty"))
@@ -0,0 +1,25 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import col
+
+
+def test_filter_is_in_dataframe_column() -> None:
+    df = daft.from_pydict({"id": [1, 2, 3, 4, None], "val": ["a", "b", "c", "d", "e"]})
+    allowed = daft.from_pydict({"allowed_id": [2, 4, 4, 6]})
+
+    result = df.where(col("id").is_in(allowed)).sort("id").to_pydict()
+    assert result == {"id": [2, 4], "val": ["b", "d"]}
+
+    result = df.where(col("id").not_null() & ~col("id").is_in(allowed)).sort("id").to_pydict()
+    assert result == {"id": [1, 3], "val": ["a", "c"]}
+
+
+def test_filter_is_in_dataframe_requires_single_column() -> None:
+    df = daft.from_pydict({"id": [1, 2]})
+    source = daft.from_pydict({"a": [1], "b": [2]})
+
+    with pytest.raises(ValueError, match="single column"):
+        df.where(col("id").is_in(source)).collect()
@@ -161,6 +161,41 @@ def test_iter_partitions(make_df, materialized):
         ]
 
 
+@pytest.mark.parametrize("materialized", [False, True])
+@pytest.mark.parametrize("batch_size", [1, 3, 4, 10, 20])
+def test_iter_arrow_batches(make_df, materialized, batch_size):
+    # Test that df.iter_arrow_batches() rebatches partitions of any size into batches of exactly `batch_size` rows.
+
+    df = make_df({"a": list(range(10))}).into_partitions(4).with_column("b", daft.col("a") + 100)
+    if materialized:
+        df = df.collect()
+
+    batches = list(df.iter_arrow_batches(batch_size))
+
+    assert [batch.num_rows for batch in batches[:-1]] == [batch_size] * (len(batches) - 1)
+    assert 0 < batches[-1].num_rows <= batch_size
+    assert pa.Table.from_batches(batches).to_pydict() == {"a": list(range(10)), "b": list(range(100, 110))}
+
+
+def test_iter_arrow_batches_invalid_batch_size():
+    df = daft.from_pydict({"a": [1, 2, 3]})
+
+    with pytest.raises(ValueError):
+        list(df.iter_arrow_batches(0))
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() != "native", reason="Morsels are only observable on the native runner")
+def test_iter_partitions_after_blocking_sink():
+    # Test that the output of a blocking sink is streamed downstream in morsels rather than as a single partition.
+
+    with daft.execution_config_ctx(default_morsel_size=2):
+        df = daft.from_pydict({"a": list(reversed(range(10)))}).sort("a")
+
+        parts = [_.to_pydict() for _ in df.iter_partitions()]
+
+        assert parts == [{"a": [i, i + 1]} for i in range(0, 10, 2)]
+
+
 def test_iter_exception(make_df):
     # Test that df.__iter__ actually returns results before completing execution.
     # We test this by raising an exception in a UDF if too many partitions are executed.
@@ -0,0 +1,59 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import col
+
+
+@pytest.fixture
+def edges():
+    # Undirected graph with the components {1, 2, 3} and {4, 5}
+    return daft.from_pydict({"src": [1, 2, 2, 3, 4, 5], "dst": [2, 1, 3, 2, 5, 4]})
+
+
+def propagate_min_label(edges):
+    def step(labels):
+        neighbors = edges.join(labels.with_column_renamed("node", "src"), on="src")
+        neighbors = neighbors.select(col("dst").alias("node"), col("label"))
+        return labels.concat(neighbors).groupby("node").agg(col("label").min())
+
+    return step
+
+
+def test_iterate_connected_components(edges):
+    labels = daft.from_pydict({"node": [1, 2, 3, 4, 5], "label": [1, 2, 3, 4, 5]})
+    result = labels.iterate(propagate_min_label(edges), invariants=[edges])
+    assert result.sort("node").to_pydict() == {"node": [1, 2, 3, 4, 5], "label": [1, 1, 1, 4, 4]}
+    assert not result._persisted
+    # Already materialized invariants are left as they are.
+    assert edges._result_cache is not None
+
+
+def test_iterate_hoists_invariants(edges):
+    lazy_edges = edges.where(col("src") > 0)
+    labels = daft.from_pydict({"node": [1, 2, 3, 4, 5], "label": [1, 2, 3, 4, 5]})
+    result = labels.iterate(propagate_min_label(lazy_edges), invariants=[lazy_edges])
+    assert result.sort("node").to_pydict() == {"node": [1, 2, 3, 4, 5], "label": [1, 1, 1, 4, 4]}
+    assert lazy_edges._result_cache is None
+
+
+def test_iterate_stops_at_max_iterations():
+    df = daft.from_pydict({"a": [0]})
+    result = df.iterate(lambda df: df.select(col("a") + 1), max_iterations=3)
+    assert result.to_pydict() == {"a": [3]}
+
+
+def test_iterate_until():
+    df = daft.from_pydict({"a": [0]})
+    result = df.iterate(
+        lambda df: df.select(col("a") + 1),
+        until=lambda prev, curr: curr.to_pydict()["a"][0] >= 5,
+    )
+    assert result.to_pydict() == {"a": [5]}
+
+
+def test_iterate_requires_same_schema():
+    df = daft.from_pydict({"a": [0]})
+    with pytest.raises(ValueError, match="must preserve the schema"):
+        df.iterate(lambda df: df.select(col("a").cast(daft.DataType.string())))
@@ -1398,3 +1398,58 @@ def test_self_join_with_projection():
     }
 
     assert pa.Table.from_pydict(result_df.to_pydict()) == pa.Table.from_pydict(expected)
+
+
+@pytest.mark.parametrize(
+    "join_type,broadcast_left,expected",
+    [
+        ("inner", False, {"id": [2, 4], "value": ["b", "d"], "label": ["two", "four"]}),
+        ("inner", True, {"id": [2, 4], "value": ["b", "d"], "label": ["two", "four"]}),
+        ("left", False, {"id": [1, 2, 3, 4], "value": ["a", "b", "c", "d"], "label": [None, "two", None, "four"]}),
+        ("right", True, {"id": [2, 4, 5], "value": ["b", "d", None], "label": ["two", "four", "five"]}),
+        ("semi", False, {"id": [2, 4], "value": ["b", "d"]}),
+        ("anti", False, {"id": [1, 3], "value": ["a", "c"]}),
+    ],
+)
+def test_join_broadcast_hint(join_type, broadcast_left, expected, make_df):
+    left = make_df({"id": [1, 2, 3, 4], "value": ["a", "b", "c", "d"]})
+    right = make_df({"id": [2, 4, 5], "label": ["two", "four", "five"]})
+    if broadcast_left:
+        left = left.broadcast()
+    else:
+        right = right.broadcast()
+
+    joined = left.join(right, on="id", how=join_type).sort("id")
+
+    assert joined.to_pydict() == expected
+
+
+@pytest.mark.parametrize(
+    "join_type,broadcast_left",
+    [("left", True), ("right", False), ("semi", True), ("anti", True), ("outer", False)],
+)
+def test_join_broadcast_hint_invalid_side(join_type, broadcast_left, make_df):
+    left = make_df({"id": [1, 2, 3]})
+    right = make_df({"id": [2, 3, 4]})
+    if broadcast_left:
+        left = left.broadcast()
+    else:
+        right = right.broadcast()
+
+    with pytest.raises(ValueError, match="Cannot broadcast"):
+        left.join(right, on="id", how=join_type)
+
+
+def test_join_broadcast_hint_ignored_with_explicit_strategy(make_df):
+    left = make_df({"id": [1, 2, 3]}).broadcast()
+    right = make_df({"id": [2, 3, 4]})
+
+    joined = left.join(right, on="id", how="left", strategy="hash").sort("id")
+
+    assert joined.to_pydict() == {"id": [1, 2, 3]}
+
+
+def test_broadcast_hint_does_not_propagate(make_df):
+    df = make_df({"id": [1, 2, 3]}).broadcast()
+
+    assert not df.where(col("id") > 1)._broadcast_hint
@@ -2,6 +2,7 @@ from __future__ import annotations
 
 import pytest
 
+import daft
 from daft.datatype import DataType
 from daft.expressions import col
 from daft.functions import monotonically_increasing_id
@@ -543,3 +544,12 @@ def test_monotonically_increasing_id_with_cast(make_df) -> None:
     )
 
     assert df.to_pydict()["id"] == df2.to_pydict()["id_str"]
+
+
+def test_monotonically_increasing_id_sql(make_df) -> None:
+    df = make_df({"a": [1, 2, 3, 4, 5]})
+    actual = daft.sql("SELECT a, monotonically_increasing_id() AS id FROM df").to_pydict()
+    assert actual == {"a": [1, 2, 3, 4, 5], "id": [0, 1, 2, 3, 4]}
+
+    expected = df.with_column("id", monotonically_increasing_id()).to_pydict()
+    assert actual == expected
@@ -0,0 +1,98 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import DataType, VectorIndex, col
+from daft.exceptions import DaftCoreException
+
+DTYPE = DataType.fixed_size_list(DataType.float32(), 2)
+
+
+@pytest.fixture
+def df():
+    return (
+        daft.from_pydict(
+            {
+                "id": [0, 1, 2, 3, 4],
+                "doc": ["a", "b", "c", "d", "e"],
+                "embedding": [[1.0, 0.0], [0.0, 1.0], [1.0, 1.0], None, [-1.0, 0.0]],
+            }
+        )
+        .with_column("embedding", col("embedding").cast(DTYPE))
+        .into_partitions(2)
+    )
+
+
+@pytest.fixture
+def queries():
+    return daft.from_pydict({"query": ["x", "y", "z"], "embedding": [[2.0, 0.1], [-0.5, 2.0], None]})
+
+
+def neighbors(result):
+    """The docs of the neighbors of each query, closest first."""
+    rows = result.sort(["query", "distance"]).to_pydict()
+    docs = {}
+    for query, doc in zip(rows["query"], rows["doc"]):
+        docs.setdefault(query, []).append(doc)
+    return docs
+
+
+def test_nearest_neighbors_exact(df, queries):
+    result = df.nearest_neighbors(queries, on="embedding", k=2, metric="l2")
+    assert result.column_names == ["id", "doc", "embedding", "query", "query_embedding", "distance"]
+    assert neighbors(result) == {"x": ["a", "c"], "y": ["b", "c"]}
+    distances = result.sort(["query", "distance"]).to_pydict()["distance"]
+    assert distances == pytest.approx([1.01**0.5, 1.81**0.5, 1.25**0.5, 3.25**0.5])
+
+
+def test_nearest_neighbors_metrics(df):
+    result = df.nearest_neighbors([[3.0, 3.0]], on="embedding", k=1).to_pydict()
+    assert result["doc"] == ["c"]
+    assert result["distance"] == pytest.approx([0.0], abs=1e-6)
+
+    result = df.nearest_neighbors([[1.0, 0.5]], on="embedding", k=1, metric="dot").to_pydict()
+    assert result["doc"] == ["c"]
+    assert result["distance"] == pytest.approx([-1.5])
+
+
+def test_nearest_neighbors_more_than_rows(df):
+    # Rows with null vectors are never neighbors.
+    result = df.nearest_neighbors([[0.0, 0.0]], on="embedding", k=10, metric="l2").to_pydict()
+    assert sorted(result["doc"]) == ["a", "b", "c", "e"]
+
+
+@pytest.mark.parametrize("kind", ["flat", "ivf"])
+def test_nearest_neighbors_with_index(df, queries, kind, tmp_path):
+    index = VectorIndex.build(df, "embedding", "id", metric="l2", kind=kind, num_lists=2, num_probes=2)
+    assert (index.column, index.id_column, index.metric, index.kind) == ("embedding", "id", "l2", kind)
+    assert (index.dimension, index.num_rows, index.num_partitions) == (2, 4, 2)
+
+    path = str(tmp_path / "index.bin")
+    index.save(path)
+    loaded = VectorIndex.load(path)
+    assert loaded.num_rows == 4
+
+    # Probing every list of an IVF index is exact.
+    result = df.nearest_neighbors(queries, on="embedding", k=2, index=loaded)
+    assert result.column_names == ["id", "doc", "embedding", "query", "query_embedding", "distance"]
+    assert neighbors(result) == {"x": ["a", "c"], "y": ["b", "c"]}
+
+
+def test_vector_index_search_expression(df):
+    index = VectorIndex.build(df, "embedding", "doc", metric="l2")
+    queries = daft.from_pydict({"q": [[2.0, 0.1]]}).with_column("q", col("q").cast(DTYPE))
+    result = queries.select(index.search(col("q"), k=2).alias("neighbors")).to_pydict()["neighbors"][0]
+    assert [neighbor["id"] for neighbor in result] == ["a", "c"]
+
+
+def test_nearest_neighbors_invalid(df, queries):
+    index = VectorIndex.build(df, "embedding", "id", metric="l2")
+    with pytest.raises(ValueError, match="built from column"):
+        df.nearest_neighbors(queries, on="other", index=index)
+    with pytest.raises(ValueError, match="metric"):
+        df.nearest_neighbors(queries, on="embedding", index=index, metric="cosine")
+    with pytest.raises(ValueError, match="must be positive"):
+        df.nearest_neighbors(queries, on="embedding", k=0, index=index)
+    with pytest.raises(DaftCoreException, match="Unknown vector distance metric"):
+        VectorIndex.build(df, "embedding", "id", metric="euclidean")
@@ -0,0 +1,62 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import col
+from tests.conftest import get_tests_daft_runner_name
+
+trace = pytest.importorskip("opentelemetry.trace")
+sdk_trace = pytest.importorskip("opentelemetry.sdk.trace")
+sdk_trace_export = pytest.importorskip("opentelemetry.sdk.trace.export")
+in_memory_span_exporter = pytest.importorskip("opentelemetry.sdk.trace.export.in_memory_span_exporter")
+
+
+@pytest.fixture(scope="module")
+def span_exporter():
+    if not isinstance(trace.get_tracer_provider(), trace.ProxyTracerProvider):
+        pytest.skip("OpenTelemetry was already set up in this process")
+    exporter = in_memory_span_exporter.InMemorySpanExporter()
+    provider = sdk_trace.TracerProvider()
+    provider.add_span_processor(sdk_trace_export.SimpleSpanProcessor(exporter))
+    trace.set_tracer_provider(provider)
+    return exporter
+
+
+def run_query():
+    df = daft.from_pydict({"key": [i % 4 for i in range(100)], "value": list(range(100))}).into_partitions(4)
+    return df.groupby("key").agg(col("value").sum()).sort("key").to_pydict()
+
+
+def test_query_span(span_exporter):
+    span_exporter.clear()
+    with daft.execution_config_ctx(enable_opentelemetry=True):
+        run_query()
+
+    [query_span] = [span for span in span_exporter.get_finished_spans() if span.name == "daft.query"]
+    assert query_span.attributes["daft.runner"] == get_tests_daft_runner_name()
+    assert query_span.status.is_ok
+
+
+def test_no_spans_when_disabled(span_exporter):
+    span_exporter.clear()
+    run_query()
+    assert span_exporter.get_finished_spans() == ()
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "ray",
+    reason="Only the Ray Runner records spans for tasks",
+)
+def test_task_spans_are_part_of_query_trace(span_exporter):
+    span_exporter.clear()
+    with daft.execution_config_ctx(enable_opentelemetry=True):
+        run_query()
+
+    spans = span_exporter.get_finished_spans()
+    [query_span] = [span for span in spans if span.name == "daft.query"]
+    task_spans = [span for span in spans if span.name == "daft.task"]
+    assert len(task_spans) > 0
+    for span in task_spans:
+        assert span.context.trace_id == query_span.context.trace_id
+        assert span.attributes["daft.task.name"]
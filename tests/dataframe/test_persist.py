@@ -0,0 +1,85 @@
+from __future__ import annotations
+
+import io
+
+import pytest
+
+import daft
+from daft.context import get_context
+from daft.runners.partitioning import SpilledMaterializedResult
+from tests.conftest import get_tests_daft_runner_name
+
+pytestmark = pytest.mark.skipif(
+    get_tests_daft_runner_name() == "ray", reason="UDF calls and spilling are only observable on the local runners"
+)
+
+
+def make_counted_df(calls: list[int]) -> daft.DataFrame:
+    @daft.udf(return_dtype=daft.DataType.int64())
+    def record(a):
+        calls.append(len(a))
+        return a
+
+    return daft.from_pydict({"a": [1, 2, 3, 4]}).into_partitions(2).with_column("b", record(daft.col("a")))
+
+
+def test_persist_skips_lineage_of_derived_queries():
+    calls = []
+    df = make_counted_df(calls).persist()
+    num_calls = len(calls)
+
+    assert df.where(daft.col("a") > 2).sort("a").to_pydict() == {"a": [3, 4], "b": [3, 4]}
+    assert df.agg(daft.col("b").sum()).to_pydict() == {"b": [10]}
+    assert len(calls) == num_calls
+
+
+def test_persist_shows_cache_hit_in_explain():
+    df = make_counted_df([]).persist()
+    output = io.StringIO()
+    df.where(daft.col("a") > 2).explain(show_all=True, file=output)
+    assert "Persisted = cache hit" in output.getvalue()
+
+    output = io.StringIO()
+    daft.from_pydict({"a": [1]}).explain(file=output)
+    assert "Persisted = cache hit" not in output.getvalue()
+
+
+def test_persist_spills_beyond_memory_limit():
+    calls = []
+    with daft.execution_config_ctx(persist_memory_limit_bytes=0):
+        df = make_counted_df(calls).persist()
+    num_calls = len(calls)
+
+    parts = df._result.values()
+    assert len(parts) == 2
+    assert all(isinstance(part, SpilledMaterializedResult) for part in parts)
+    assert df.sort("a").to_pydict() == {"a": [1, 2, 3, 4], "b": [1, 2, 3, 4]}
+    assert len(calls) == num_calls
+    df.unpersist()
+
+
+def test_unpersist_reexecutes_lineage():
+    calls = []
+    df = make_counted_df(calls).persist()
+    derived = df.where(daft.col("a") > 2)
+    df.unpersist()
+    num_calls = len(calls)
+
+    # DataFrames derived while it was persisted keep scanning the persisted results.
+    assert derived.sort("a").to_pydict() == {"a": [3, 4], "b": [3, 4]}
+    assert len(calls) == num_calls
+
+    assert df.sort("a").to_pydict() == {"a": [1, 2, 3, 4], "b": [1, 2, 3, 4]}
+    assert len(calls) > num_calls
+
+
+def test_persist_is_reference_counted():
+    df = make_counted_df([]).persist()
+    entry = df._result_cache
+    runner = get_context().get_or_create_runner()
+
+    runner.persist(entry)
+    df.unpersist()
+    assert runner.is_persisted(entry)
+    assert runner.unpersist(entry)
+    assert not runner.is_persisted(entry)
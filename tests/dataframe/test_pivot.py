@@ -1,5 +1,7 @@
 import pytest
 
+from daft import DataType
+
 
 @pytest.mark.parametrize("repartition_nparts", [1, 2, 5])
 def test_pivot(make_df, repartition_nparts, with_morsel_size):
@@ -186,3 +188,30 @@ def test_pivot_with_downstream_ops(make_df, repartition_nparts, with_morsel_size
     daft_df = daft_df.where(daft_df["1"] == 10).select("2")
 
     assert daft_df.to_pydict() == {"2": [20]}
+
+
+def test_pivot_discovered_names_are_sorted(make_df):
+    daft_df = make_df(
+        {
+            "group": ["A", "A", "B", "B"],
+            "pivot": ["z", "x", "y", "x"],
+            "value": [10, 20, 30, 40],
+        },
+    )
+    daft_df = daft_df.pivot(group_by="group", pivot_col="pivot", value_col="value", agg_fn="sum")
+
+    assert daft_df.column_names == ["group", "x", "y", "z"]
+
+
+def test_pivot_schema_takes_aggregation_type(make_df):
+    daft_df = make_df(
+        {
+            "group": ["A", "A", "B"],
+            "pivot": [1, 1, 1],
+            "value": [10, 15, 30],
+        },
+    )
+    daft_df = daft_df.pivot(group_by="group", pivot_col="pivot", value_col="value", agg_fn="mean")
+
+    assert daft_df.schema()["1"].dtype == DataType.float64()
+    assert daft_df.sort("group").to_pydict() == {"group": ["A", "B"], "1": [12.5, 30.0]}
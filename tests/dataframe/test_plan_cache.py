@@ -0,0 +1,54 @@
+from __future__ import annotations
+
+import daft
+from daft.daft import FileFormat, IOConfig, WriteMode
+
+
+def test_fingerprint_is_stable_for_equal_plans():
+    df = daft.from_pydict({"a": [1, 2, 3]})
+    fingerprint = df.where(daft.col("a") > 1)._builder.fingerprint()
+    assert fingerprint is not None
+    assert fingerprint == df.where(daft.col("a") > 1)._builder.fingerprint()
+    assert fingerprint != df.where(daft.col("a") > 2)._builder.fingerprint()
+
+
+def test_fingerprint_is_none_for_writes(tmp_path):
+    df = daft.from_pydict({"a": [1, 2, 3]})
+    builder = df._builder.write_tabular(
+        root_dir=str(tmp_path),
+        write_mode=WriteMode.from_str("append"),
+        file_format=FileFormat.Parquet,
+        io_config=IOConfig(),
+    )
+    assert builder.fingerprint() is None
+
+
+def test_plan_cache_reuses_results():
+    calls = []
+
+    @daft.udf(return_dtype=daft.DataType.int64())
+    def record(a):
+        calls.append(len(a))
+        return a
+
+    df = daft.from_pydict({"a": [1, 2, 3]}).with_column("b", record(daft.col("a")))
+    with daft.planning_config_ctx(enable_plan_cache=True):
+        assert df.to_pydict() == {"a": [1, 2, 3], "b": [1, 2, 3]}
+        num_calls = len(calls)
+        assert df.to_pydict() == {"a": [1, 2, 3], "b": [1, 2, 3]}
+        assert len(calls) == num_calls
+
+
+def test_plan_cache_disabled_by_default():
+    calls = []
+
+    @daft.udf(return_dtype=daft.DataType.int64())
+    def record(a):
+        calls.append(len(a))
+        return a
+
+    df = daft.from_pydict({"a": [1, 2, 3]}).with_column("b", record(daft.col("a")))
+    df.to_pydict()
+    num_calls = len(calls)
+    df.to_pydict()
+    assert len(calls) > num_calls
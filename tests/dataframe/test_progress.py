@@ -0,0 +1,95 @@
+from __future__ import annotations
+
+import threading
+
+import daft
+from daft import col
+from daft.progress import ProgressIterator, QueryProgressTracker, progress_callback
+
+
+class FakeClock:
+    def __init__(self) -> None:
+        self.now = 0.0
+
+    def __call__(self) -> float:
+        return self.now
+
+
+def test_tracker_reports_stage_progress():
+    clock = FakeClock()
+    updates = []
+    tracker = QueryProgressTracker([updates.append], clock=clock)
+
+    tracker.task_started("scan", "ScanWithTask")
+    tracker.task_started("scan", "ScanWithTask")
+    clock.now = 1.0
+    tracker.task_finished("scan", 10, 100)
+    clock.now = 2.0
+    tracker.task_started("agg", "Aggregate")
+    tracker.task_finished("agg", 1, None)
+    tracker.finish()
+
+    # Reports are throttled, except for the last one
+    assert [update.elapsed_seconds for update in updates] == [0.0, 1.0, 2.0, 2.0]
+    last = updates[-1]
+    assert last.finished
+    assert [(stage.stage_id, stage.name) for stage in last.stages] == [(0, "ScanWithTask"), (1, "Aggregate")]
+    scan, agg = last.stages
+    assert (scan.total_tasks, scan.completed_tasks, scan.rows_processed, scan.bytes_processed) == (2, 1, 10, 100)
+    assert (agg.total_tasks, agg.completed_tasks, agg.rows_processed, agg.bytes_processed) == (1, 1, 1, None)
+
+
+def test_estimated_seconds_remaining():
+    clock = FakeClock()
+    tracker = QueryProgressTracker([], clock=clock)
+    for _ in range(4):
+        tracker.task_started("stage", "Project")
+    assert tracker.progress().estimated_seconds_remaining is None
+
+    clock.now = 3.0
+    tracker.task_finished("stage", 1, 1)
+    assert tracker.progress().estimated_seconds_remaining == 9.0
+    assert tracker.progress(finished=True).estimated_seconds_remaining == 0.0
+
+
+def test_failing_callback_does_not_fail_query():
+    def fail(_):
+        raise RuntimeError("callback failed")
+
+    with progress_callback(fail):
+        assert daft.from_pydict({"x": [1, 2, 3]}).to_pydict() == {"x": [1, 2, 3]}
+
+
+def run_query():
+    df = daft.from_pydict({"x": list(range(100))}).into_partitions(4)
+    return df.with_column("y", col("x") * 2).collect()
+
+
+def test_progress_callback_reports_query():
+    updates = []
+    with progress_callback(updates.append):
+        run_query()
+    run_query()
+
+    assert len({update.query_id for update in updates}) == 1
+    last = updates[-1]
+    assert last.finished
+    assert len(last.stages) > 0
+    assert last.completed_tasks == last.total_tasks
+    assert max(stage.rows_processed for stage in last.stages) == 100
+
+
+def test_progress_iterator():
+    progress = ProgressIterator()
+
+    def run():
+        with progress_callback(progress):
+            run_query()
+
+    thread = threading.Thread(target=run)
+    thread.start()
+    updates = list(progress)
+    thread.join()
+
+    assert updates[-1].finished
+    assert all(not update.finished for update in updates[:-1])
@@ -8,6 +8,7 @@ import pytest
 from PIL import Image
 
 import daft
+from daft.dataframe.preview import PreviewFormatter
 from tests.utils import ANSI_ESCAPE, TD_STYLE, TH_STYLE
 
 ROW_DIVIDER_REGEX = re.compile(r"╭─+┬*─*╮|├╌+┼*╌+┤")
@@ -334,3 +335,70 @@ def test_repr_empty_struct():
 (Showing first 2 of 2 rows)"""
 
     assert str(df) == expected_repr
+
+
+def test_repr_display_options_max_columns():
+    df = daft.from_pydict({f"c{i}": [i] for i in range(6)}).collect()
+
+    with daft.display_options_ctx(max_columns=2):
+        text = ANSI_ESCAPE.sub("", repr(df))
+        html = df._repr_html_()
+
+    for text_or_html in (text, html):
+        assert "c0" in text_or_html and "c5" in text_or_html
+        assert "c1" not in text_or_html and "c4" not in text_or_html
+    assert "(4 hidden)" in text
+    assert "<th style=\"text-wrap: nowrap; max-width:192px; overflow:auto; text-align:left\">...</th>" in html
+    assert "c1" in df._repr_html_()
+
+
+def test_repr_display_options_max_width():
+    df = daft.from_pydict({"text": ["a" * 100]}).collect()
+
+    with daft.display_options_ctx(max_width=10):
+        html = df._repr_html_()
+    assert "a" * 9 + "…" in html
+    assert "a" * 10 not in html
+    assert "a" * 100 in df._repr_html_()
+
+
+def test_repr_display_options_max_rows():
+    df = daft.from_pydict({"x": list(range(20))}).collect()
+
+    with daft.display_options_ctx(max_rows=20):
+        html = df._repr_html_()
+    assert "<td>...</td>" not in html
+    assert "<td>...</td>" in df._repr_html_()
+
+
+def test_display_options_invalid():
+    with pytest.raises(ValueError):
+        daft.set_display_options(max_rows=0)
+
+
+def test_show_offset_collected():
+    df = daft.from_pydict({"x": list(range(10))}).collect()
+    preview = df._construct_show_preview(3, offset=4)
+
+    assert preview.partition.to_pydict() == {"x": [4, 5, 6]}
+    assert preview.total_rows == 10
+    assert "(Showing rows 5 to 7 of 10 rows)" in repr(PreviewFormatter(preview, df.schema()))
+
+
+def test_show_offset_lazy():
+    df = daft.from_pydict({"x": list(range(10))}).where(daft.col("x") % 2 == 0)
+
+    preview = df._construct_show_preview(2, offset=3)
+    assert preview.partition.to_pydict() == {"x": [6, 8]}
+
+    preview = df._construct_show_preview(2, offset=8)
+    assert preview.partition.to_pydict() == {"x": []}
+
+
+def test_repr_html_thumbnails():
+    df = daft.from_pydict({"img": [np.ones((3, 3, 3), dtype=np.uint8)]})
+    df = df.with_column("img", df["img"].cast(daft.DataType.image("RGB"))).collect()
+
+    assert "<img" not in df._repr_html_()
+    with daft.display_options_ctx(thumbnails=True):
+        assert "<img" in df._repr_html_()
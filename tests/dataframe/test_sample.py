@@ -1,7 +1,13 @@
 from __future__ import annotations
 
+import io
+
+import pyarrow as pa
+import pyarrow.parquet as papq
 import pytest
 
+import daft
+
 
 def test_sample_fraction(make_df, valid_data: list[dict[str, float]]) -> None:
     df = make_df(valid_data)
@@ -112,3 +118,57 @@ def test_sample_with_concat(make_df, valid_data: list[dict[str, float]]) -> None
     assert df.column_names == list(valid_data[0].keys())
     # Check that the two rows are the same, which should be for this seed.
     assert all(col[:2] == col[2:] for col in df.to_pydict().values())
+
+
+@pytest.mark.parametrize("n_partitions", [1, 3])
+def test_sample_n(make_df, n_partitions) -> None:
+    df = make_df({"a": list(range(100))}, repartition=n_partitions)
+
+    sampled = df.sample_n(10).to_pydict()["a"]
+    assert len(sampled) == 10
+    assert len(set(sampled)) == 10
+    assert set(sampled) <= set(range(100))
+    assert df.sample_n(10).column_names == ["a"]
+
+
+def test_sample_n_with_seed(make_df) -> None:
+    df = make_df({"a": list(range(100))})
+    assert df.sample_n(10, seed=42).to_pydict() == df.sample_n(10, seed=42).to_pydict()
+
+
+def test_sample_n_more_than_rows(make_df) -> None:
+    df = make_df({"a": [1, 2, 3]})
+    assert sorted(df.sample_n(10).to_pydict()["a"]) == [1, 2, 3]
+    assert df.sample_n(0).to_pydict() == {"a": []}
+
+
+def test_sample_n_negative(make_df) -> None:
+    with pytest.raises(ValueError, match="n should be non-negative"):
+        make_df({"a": [1, 2, 3]}).sample_n(-1)
+
+
+@pytest.fixture
+def parquet_path(tmp_path) -> str:
+    path = str(tmp_path / "data.parquet")
+    papq.write_table(pa.table({"a": list(range(1000))}), path, row_group_size=10)
+    return path
+
+
+def test_sample_pushes_into_scan(parquet_path) -> None:
+    df = daft.read_parquet(parquet_path).sample(0.01, seed=42)
+    output = io.StringIO()
+    df.explain(show_all=True, file=output)
+    assert "Sample pushdown = 0.01" in output.getvalue()
+
+    sampled = df.to_pydict()["a"]
+    assert len(sampled) == 10
+    assert len(set(sampled)) == 10
+    assert sampled == daft.read_parquet(parquet_path).sample(0.01, seed=42).to_pydict()["a"]
+
+
+def test_sample_with_filter_pushes_into_scan(parquet_path) -> None:
+    df = daft.read_parquet(parquet_path).where(daft.col("a") < 500).sample(0.1)
+    sampled = df.to_pydict()["a"]
+    # Without row group statistics for the filtered rows, each batch read is sampled on its own.
+    assert len(sampled) >= 50
+    assert all(a < 500 for a in sampled)
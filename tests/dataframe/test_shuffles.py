@@ -51,11 +51,13 @@ def flight_shuffle_ctx():
     """Fixture that provides a context manager for flight shuffle testing with a temporary directory."""
 
     @contextmanager
-    def _ctx():
+    def _ctx(**config):
         # Create a temporary directory that automatically cleans up
         with tempfile.TemporaryDirectory() as temp_dir:
             # Use the temporary directory for flight shuffle
-            with daft.execution_config_ctx(shuffle_algorithm="flight_shuffle", flight_shuffle_dirs=[temp_dir]) as ctx:
+            with daft.execution_config_ctx(
+                shuffle_algorithm="flight_shuffle", flight_shuffle_dirs=[temp_dir], **config
+            ) as ctx:
                 yield ctx
 
     return _ctx
@@ -202,3 +204,58 @@ def test_flight_shuffle(flight_shuffle_ctx, input_partitions, output_partitions)
             .collect()
         )
         assert len(df) == input_partitions * output_partitions
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "ray",
+    reason="shuffle tests are meant for the ray runner",
+)
+@pytest.mark.parametrize("compression, compression_level", [("lz4", 0), ("zstd", 0), ("zstd", 9)])
+def test_flight_shuffle_with_compression(flight_shuffle_ctx, compression, compression_level):
+    """Test that flight shuffle is working with compressed shuffle files."""
+    with flight_shuffle_ctx(shuffle_compression=compression, shuffle_compression_level=compression_level):
+        df = (
+            read_generator(
+                generator(10, lambda: 20, lambda: 200),
+                schema=daft.Schema._from_field_name_and_types(
+                    [
+                        ("ints", daft.DataType.uint64()),
+                        ("bytes", daft.DataType.fixed_size_binary(200)),
+                    ]
+                ),
+            )
+            .repartition(20, "ints")
+            .collect()
+        )
+        assert len(df) == 10 * 20
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "ray",
+    reason="shuffle tests are meant for the ray runner",
+)
+@pytest.mark.parametrize(
+    "input_partitions, output_partitions",
+    [(20, 20), (20, 1), (20, 50)],
+)
+@pytest.mark.parametrize("partition_by", [["ints"], []])
+def test_external_shuffle(input_partitions, output_partitions, partition_by):
+    """Test that external shuffle is working, for both hash and random partitioning."""
+    with tempfile.TemporaryDirectory() as temp_dir:
+        with daft.execution_config_ctx(
+            shuffle_algorithm="external_shuffle", external_shuffle_dir=temp_dir, shuffle_compression="lz4"
+        ):
+            df = (
+                read_generator(
+                    generator(input_partitions, lambda: output_partitions, lambda: 200),
+                    schema=daft.Schema._from_field_name_and_types(
+                        [
+                            ("ints", daft.DataType.uint64()),
+                            ("bytes", daft.DataType.fixed_size_binary(200)),
+                        ]
+                    ),
+                )
+                .repartition(output_partitions, *partition_by)
+                .collect()
+            )
+            assert len(df) == input_partitions * output_partitions
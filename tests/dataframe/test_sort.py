@@ -407,3 +407,35 @@ def test_multi_column_sort_combinations(desc, nulls_first, expected):
     )
     result = df.sort(by=["id", "a"], desc=desc, nulls_first=nulls_first)
     assert result.to_pydict() == expected
+
+
+###
+# Top-N tests
+###
+
+
+@pytest.mark.parametrize(
+    "desc,nulls_first,expected",
+    [
+        (False, False, [0, 1, 2]),
+        (True, False, [99, 98, 97]),
+        (False, True, [None, None, 0]),
+        (True, True, [None, None, 99]),
+    ],
+)
+@pytest.mark.parametrize("n_partitions", [1, 3])
+def test_sort_limit(make_df, desc, nulls_first, expected, n_partitions):
+    values = [(i * 37) % 100 for i in range(100)] + [None, None]
+    df = make_df({"A": values}, repartition=n_partitions)
+    result = df.sort("A", desc=desc, nulls_first=nulls_first).limit(3)
+    assert result.to_pydict() == {"A": expected}
+
+
+def test_sort_limit_larger_than_input(make_df):
+    df = make_df({"A": [3, 1, 2]})
+    assert df.sort("A").limit(10).to_pydict() == {"A": [1, 2, 3]}
+
+
+def test_sort_limit_zero(make_df):
+    df = make_df({"A": [3, 1, 2]})
+    assert df.sort("A").limit(0).to_pydict() == {"A": []}
@@ -0,0 +1,74 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import col
+from tests.conftest import get_tests_daft_runner_name
+
+pytestmark = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Operators only spill to disk on the native runner"
+)
+
+
+@pytest.mark.parametrize("high_cardinality", [False, True])
+def test_grouped_aggregate_spills_beyond_memory_limit(high_cardinality):
+    num_groups = 500 if high_cardinality else 7
+    df = daft.from_pydict({"k": [i % num_groups for i in range(1000)], "v": list(range(1000))})
+
+    with daft.execution_config_ctx(operator_memory_limit_bytes=0, default_morsel_size=100):
+        result = (
+            df.groupby("k")
+            .agg(col("v").sum().alias("sum"), col("v").mean().alias("mean"), col("v").count().alias("count"))
+            .sort("k")
+            .to_pydict()
+        )
+
+    groups = {k: [v for v in range(1000) if v % num_groups == k] for k in range(num_groups)}
+    assert result == {
+        "k": list(groups),
+        "sum": [sum(vs) for vs in groups.values()],
+        "mean": [sum(vs) / len(vs) for vs in groups.values()],
+        "count": [len(vs) for vs in groups.values()],
+    }
+
+
+def test_inner_join_spills_beyond_memory_limit():
+    left = daft.from_pydict({"k": [i % 300 for i in range(600)], "l": list(range(600))})
+    right = daft.from_pydict({"k": list(range(0, 400, 2)), "r": [str(i) for i in range(0, 400, 2)]})
+
+    with daft.execution_config_ctx(operator_memory_limit_bytes=0, default_morsel_size=50):
+        result = left.join(right, on="k").sort("l").to_pydict()
+
+    matches = [i for i in range(600) if (i % 300) % 2 == 0]
+    assert result == {"k": [i % 300 for i in matches], "l": matches, "r": [str(i % 300) for i in matches]}
+
+
+def test_inner_join_with_different_key_names_spills_beyond_memory_limit():
+    left = daft.from_pydict({"a": list(range(100)), "l": list(range(100))})
+    right = daft.from_pydict({"b": list(range(50, 150)), "r": list(range(50, 150))})
+
+    with daft.execution_config_ctx(operator_memory_limit_bytes=0, default_morsel_size=10):
+        result = left.join(right, left_on="a", right_on="b").sort("a").to_pydict()
+
+    assert result == {
+        "a": list(range(50, 100)),
+        "l": list(range(50, 100)),
+        "b": list(range(50, 100)),
+        "r": list(range(50, 100)),
+    }
+
+
+def test_operators_spill_beyond_query_memory_limit():
+    left = daft.from_pydict({"k": [i % 50 for i in range(500)], "v": list(range(500))})
+    right = daft.from_pydict({"k": list(range(0, 50, 5)), "r": list(range(0, 50, 5))})
+
+    # The aggregation and the join build share the query's memory, and both spill once they hold more than their share.
+    with daft.execution_config_ctx(query_memory_limit_bytes=1, default_morsel_size=50):
+        result = left.groupby("k").agg(col("v").sum().alias("sum")).join(right, on="k").sort("k").to_pydict()
+
+    assert result == {
+        "k": list(range(0, 50, 5)),
+        "sum": [sum(v for v in range(500) if v % 50 == k) for k in range(0, 50, 5)],
+        "r": list(range(0, 50, 5)),
+    }
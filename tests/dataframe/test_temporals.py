@@ -377,13 +377,31 @@ def test_join_timestamp_same_timezone(tu1, tu2, tz_repr):
                 datetime(2020, 2, 28, 0, 0, 0, 1000),
             ],
         ),
+        (
+            (col("datetimes") + daft.interval("1 day 2 hours")),
+            [
+                datetime(2021, 1, 2, 2, 0),
+                datetime(2021, 1, 3, 2, 0),
+                datetime(2020, 3, 1, 2, 0),
+                datetime(2020, 2, 29, 2, 0),
+            ],
+        ),
+        (
+            (col("datetimes") - daft.interval("1 month, 1 week")),
+            [
+                datetime(2020, 11, 24, 0, 0),
+                datetime(2020, 11, 25, 0, 0),
+                datetime(2020, 1, 22, 0, 0),
+                datetime(2020, 1, 21, 0, 0),
+            ],
+        ),
         (
             (col("datetimes") - daft.interval(years=1)),
             [
+                datetime(2020, 1, 1, 0, 0),
                 datetime(2020, 1, 2, 0, 0),
-                datetime(2020, 1, 3, 0, 0),
+                datetime(2019, 3, 1, 0, 0),
                 datetime(2019, 2, 28, 0, 0),
-                datetime(2019, 2, 27, 0, 0),
             ],
         ),
         (
@@ -391,17 +409,17 @@ def test_join_timestamp_same_timezone(tu1, tu2, tz_repr):
             [
                 datetime(2020, 12, 1, 0, 0),
                 datetime(2020, 12, 2, 0, 0),
-                datetime(2020, 1, 31, 0, 0),
-                datetime(2020, 1, 30, 0, 0),
+                datetime(2020, 1, 29, 0, 0),
+                datetime(2020, 1, 28, 0, 0),
             ],
         ),
         (
             (col("datetimes") - daft.interval(days=1)),
             [
-                datetime(2021, 1, 2, 0, 0),
-                datetime(2021, 1, 3, 0, 0),
-                datetime(2020, 3, 1, 0, 0),
-                datetime(2020, 2, 29, 0, 0),
+                datetime(2020, 12, 31, 0, 0),
+                datetime(2021, 1, 1, 0, 0),
+                datetime(2020, 2, 28, 0, 0),
+                datetime(2020, 2, 27, 0, 0),
             ],
         ),
         (
@@ -466,6 +484,17 @@ def test_intervals(op, expected):
     assert actual == expected
 
 
+def test_interval_from_string():
+    df = daft.from_pydict({"ts": [datetime(2024, 1, 1)] * 3, "interval": ["3 days 4 hours", "soon", None]})
+    actual = df.select(col("ts") + col("interval").cast(DataType.interval())).to_pydict()
+    assert actual == {"ts": [datetime(2024, 1, 4, 4), None, None]}
+
+    with pytest.raises(Exception, match="unknown unit"):
+        daft.interval("3 fortnights")
+    with pytest.raises(ValueError, match="cannot be combined"):
+        daft.interval("1 day", hours=1)
+
+
 @pytest.mark.parametrize(
     "value",
     [
@@ -689,3 +718,94 @@ def test_datetime_to_string_errors(value):
 
     with pytest.raises(daft.exceptions.DaftCoreException):
         df.select(daft.col("invalid").dt.strftime(format="%Y-%m-%d")).to_pydict()
+
+
+def test_calendar_parts() -> None:
+    df = daft.from_pydict({"date": [date(2024, 1, 1), date(2024, 2, 10), date(2023, 11, 30), date(2021, 1, 3), None]})
+    actual = df.select(
+        col("date").dt.quarter().alias("quarter"),
+        col("date").dt.week_of_year().alias("week"),
+        col("date").dt.day_of_year().alias("day_of_year"),
+        col("date").dt.last_day_of_month().alias("last_day"),
+    ).to_pydict()
+    assert actual == {
+        "quarter": [1, 1, 4, 1, None],
+        # 2021-01-03 is a Sunday in the last ISO week of 2020
+        "week": [1, 6, 48, 53, None],
+        "day_of_year": [1, 41, 334, 3, None],
+        "last_day": [date(2024, 1, 31), date(2024, 2, 29), date(2023, 11, 30), date(2021, 1, 31), None],
+    }
+
+
+def test_date_add_clamps_months() -> None:
+    df = daft.from_pydict(
+        {
+            "date": [date(2024, 1, 31), date(2024, 3, 31), date(2024, 2, 29)],
+            "ts": [datetime(2024, 1, 31, 12), datetime(2024, 3, 31, 12), datetime(2024, 2, 29, 12)],
+            "n": [1, -1, 4],
+        }
+    )
+    actual = df.select(
+        col("date").dt.date_add(1, "month").alias("plus_month"),
+        col("date").dt.date_add(col("n"), "quarters").alias("plus_quarters"),
+        col("date").dt.date_sub(1, "year").alias("minus_year"),
+        col("date").dt.date_add(col("n"), "days").alias("plus_days"),
+        col("ts").dt.date_add(1, "month").alias("ts_plus_month"),
+        col("ts").dt.date_sub(90, "minutes").alias("ts_minus_minutes"),
+    ).to_pydict()
+    assert actual == {
+        "plus_month": [date(2024, 2, 29), date(2024, 4, 30), date(2024, 3, 29)],
+        "plus_quarters": [date(2024, 4, 30), date(2023, 12, 31), date(2025, 2, 28)],
+        "minus_year": [date(2023, 1, 31), date(2023, 3, 31), date(2023, 2, 28)],
+        "plus_days": [date(2024, 2, 1), date(2024, 3, 30), date(2024, 3, 4)],
+        "ts_plus_month": [datetime(2024, 2, 29, 12), datetime(2024, 4, 30, 12), datetime(2024, 3, 29, 12)],
+        "ts_minus_minutes": [
+            datetime(2024, 1, 31, 10, 30),
+            datetime(2024, 3, 31, 10, 30),
+            datetime(2024, 2, 29, 10, 30),
+        ],
+    }
+
+    with pytest.raises(daft.exceptions.DaftCoreException):
+        df.select(col("date").dt.date_add(1, "hour")).collect()
+    with pytest.raises(daft.exceptions.DaftCoreException):
+        df.select(col("date").dt.date_add(1, "fortnight")).collect()
+
+
+def test_date_diff() -> None:
+    df = daft.from_pydict(
+        {
+            "start": [datetime(2024, 1, 31), datetime(2024, 1, 15, 12), datetime(2024, 3, 1)],
+            "end": [datetime(2024, 2, 29), datetime(2024, 4, 15, 6), datetime(2023, 1, 1)],
+        }
+    )
+    units = ["year", "quarter", "month", "day", "hour"]
+    actual = df.select(*[col("end").dt.date_diff(col("start"), unit).alias(unit) for unit in units]).to_pydict()
+    assert actual == {
+        "year": [0, 0, -1],
+        "quarter": [0, 0, -4],
+        "month": [1, 2, -14],
+        "day": [29, 90, -425],
+        "hour": [696, 2178, -10200],
+    }
+
+
+def test_make_date_and_timestamp() -> None:
+    from daft.functions import make_date, make_timestamp
+
+    df = daft.from_pydict({"y": [2024, 2023, None], "m": [2, 12, 1], "d": [29, 31, 1]})
+    actual = df.select(
+        make_date(col("y"), col("m"), col("d")).alias("date"),
+        make_timestamp(col("y"), col("m"), col("d"), 13, 30, 15.5).alias("ts"),
+        make_timestamp(col("y"), col("m"), col("d"), 0, 0, 0, timezone="UTC").alias("ts_utc"),
+    ).to_pydict()
+    assert actual["date"] == [date(2024, 2, 29), date(2023, 12, 31), None]
+    assert actual["ts"] == [datetime(2024, 2, 29, 13, 30, 15, 500000), datetime(2023, 12, 31, 13, 30, 15, 500000), None]
+    assert actual["ts_utc"] == [
+        datetime(2024, 2, 29, tzinfo=pytz.utc),
+        datetime(2023, 12, 31, tzinfo=pytz.utc),
+        None,
+    ]
+
+    with pytest.raises(daft.exceptions.DaftCoreException):
+        daft.from_pydict({"d": [30]}).select(make_date(2023, 2, col("d"))).collect()
@@ -0,0 +1,23 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import col
+
+
+@pytest.mark.parametrize("thread_affinity", ["none", "cores", "numa"])
+def test_query_runs_with_configured_thread_pools(thread_affinity):
+    df = daft.from_pydict({"a": list(range(100))})
+
+    with daft.execution_config_ctx(compute_threads=2, io_threads=2, thread_affinity=thread_affinity):
+        assert daft.context.get_context().daft_execution_config.compute_threads == 2
+        result = df.where(col("a") % 2 == 0).agg(col("a").sum()).to_pydict()
+
+    assert result == {"a": [sum(range(0, 100, 2))]}
+
+
+def test_unknown_thread_affinity_is_rejected():
+    with pytest.raises(ValueError, match="thread_affinity"):
+        with daft.execution_config_ctx(thread_affinity="sockets"):
+            pass
@@ -2,11 +2,14 @@ from __future__ import annotations
 
 import datetime
 
+import numpy as np
 import pandas as pd
 import pyarrow as pa
 import pytest
 
 import daft
+from daft import DataType, Series, col
+from daft.utils import get_arrow_version
 from tests.utils import sort_arrow_table
 
 TEST_DATA_LEN = 16
@@ -56,3 +59,136 @@ def test_to_pandas(n_partitions: int) -> None:
     pd_df = df.to_pandas().sort_values("integers").reset_index(drop=True)
     expected_df = pd.DataFrame(TEST_DATA).sort_values("integers").reset_index(drop=True)
     pd.testing.assert_frame_equal(pd_df, expected_df)
+
+
+def test_to_polars() -> None:
+    pl = pytest.importorskip("polars")
+    df = daft.from_pydict({"integers": [1, 2, None], "strings": ["a", None, "c"]})
+    pl_df = df.to_polars()
+    assert dict(pl_df.schema) == {"integers": pl.Int64, "strings": pl.String}
+    assert pl_df.to_dict(as_series=False) == {"integers": [1, 2, None], "strings": ["a", None, "c"]}
+
+
+def test_to_polars_maps_extension_types_to_storage() -> None:
+    pl = pytest.importorskip("polars")
+    embeddings = [np.arange(3, dtype=np.float32) + i for i in range(2)]
+    images = [np.zeros((1, 2, 3), dtype=np.uint8), np.ones((2, 1, 3), dtype=np.uint8)]
+    df = daft.from_pydict(
+        {
+            "embeddings": Series.from_pylist(embeddings, pyobj="force"),
+            "images": Series.from_pylist(images, pyobj="force"),
+        }
+    )
+    df = df.select(
+        col("embeddings").cast(DataType.embedding(DataType.float32(), 3)),
+        col("images").cast(DataType.image("RGB")),
+    )
+    pl_df = df.to_polars()
+
+    assert pl_df.schema["embeddings"] == pl.Array(pl.Float32, 3)
+    assert pl_df["embeddings"].to_list() == [[0.0, 1.0, 2.0], [1.0, 2.0, 3.0]]
+    assert isinstance(pl_df.schema["images"], pl.Struct)
+    images = pl_df["images"].to_list()
+    assert [(image["height"], image["width"], image["channel"]) for image in images] == [(1, 2, 3), (2, 1, 3)]
+    assert images[1]["data"] == [1] * 6
+
+    # Casting brings the Daft types back.
+    round_tripped = daft.from_polars(pl_df).select(col("embeddings").cast(DataType.embedding(DataType.float32(), 3)))
+    np.testing.assert_equal(round_tripped.to_pydict()["embeddings"], embeddings)
+
+
+class _ArrowStreamWrapper:
+    """Exposes only the Arrow PyCapsule interface of the wrapped object, like non-pyarrow Arrow libraries do."""
+
+    def __init__(self, obj):
+        self.obj = obj
+
+    def __arrow_c_stream__(self, requested_schema=None):
+        return self.obj.__arrow_c_stream__(requested_schema)
+
+
+@pytest.mark.skipif(get_arrow_version() < (14, 0, 0), reason="the Arrow PyCapsule interface requires pyarrow>=14")
+@pytest.mark.parametrize("n_partitions", [1, 2])
+def test_to_arrow_through_pycapsule_interface(n_partitions: int) -> None:
+    df = daft.from_pydict(TEST_DATA).repartition(n_partitions)
+    table = pa.table(df)
+    assert table.schema == TEST_DATA_SCHEMA
+    expected_table = sort_arrow_table(pa.table(TEST_DATA, schema=table.schema), "integers")
+    assert sort_arrow_table(table, "integers").equals(expected_table)
+
+
+@pytest.mark.skipif(get_arrow_version() < (14, 0, 0), reason="the Arrow PyCapsule interface requires pyarrow>=14")
+def test_to_arrow_through_pycapsule_interface_streams_lazy_dataframe() -> None:
+    df = daft.from_pydict(TEST_DATA).into_partitions(2).where(col("integers") > 3)
+    table = pa.table(df)
+    assert df._result is None
+    assert table.schema == TEST_DATA_SCHEMA
+    assert sorted(table["integers"].to_pylist()) == list(range(4, TEST_DATA_LEN - 1))
+
+
+@pytest.mark.parametrize("n_partitions", [1, 2])
+def test_to_arrow_reader(n_partitions: int) -> None:
+    df = daft.from_pydict(TEST_DATA).repartition(n_partitions)
+    reader = df.to_arrow_reader()
+    assert reader.schema == TEST_DATA_SCHEMA
+    table = reader.read_all()
+    expected_table = sort_arrow_table(pa.table(TEST_DATA, schema=table.schema), "integers")
+    assert sort_arrow_table(table, "integers").equals(expected_table)
+
+
+def test_to_arrow_reader_duckdb() -> None:
+    duckdb = pytest.importorskip("duckdb")
+    df = daft.from_pydict({"foo": [1, 2, 3], "bar": ["a", "b", "a"]}).into_partitions(2)
+    reader = df.to_arrow_reader()
+    assert duckdb.sql("SELECT bar, sum(foo) AS s FROM reader GROUP BY bar ORDER BY bar").fetchall() == [
+        ("a", 4),
+        ("b", 2),
+    ]
+
+
+def test_to_arrow_reader_rejects_python_columns() -> None:
+    df = daft.from_pydict({"obj": [object()]})
+    with pytest.raises(ValueError, match="Python type"):
+        df.to_arrow_reader()
+
+
+@pytest.mark.skipif(get_arrow_version() < (14, 0, 0), reason="the Arrow PyCapsule interface requires pyarrow>=14")
+def test_from_arrow_through_pycapsule_interface() -> None:
+    table = pa.table(TEST_DATA, schema=TEST_DATA_SCHEMA)
+    df = daft.from_arrow(_ArrowStreamWrapper(table))
+    assert df.to_arrow().equals(table)
+
+    empty = daft.from_arrow(_ArrowStreamWrapper(table.slice(0, 0)))
+    assert empty.to_arrow().schema == TEST_DATA_SCHEMA
+    assert len(empty.to_arrow()) == 0
+
+
+@pytest.mark.parametrize("prefetch", [None, 2])
+def test_to_torch_iter_dataset(prefetch) -> None:
+    pytest.importorskip("torch")
+    df = daft.from_pydict({"a": list(range(10))}).into_partitions(3)
+    rows = list(df.to_torch_iter_dataset(prefetch=prefetch))
+    assert sorted(row["a"] for row in rows) == list(range(10))
+
+
+def test_to_torch_iter_dataset_splits_across_workers(monkeypatch) -> None:
+    torch = pytest.importorskip("torch")
+    from types import SimpleNamespace
+
+    df = daft.from_pydict({"a": list(range(10))}).into_partitions(5)
+    dataset = df.to_torch_iter_dataset()
+    shards = []
+    for worker_id in range(2):
+        worker_info = SimpleNamespace(id=worker_id, num_workers=2)
+        monkeypatch.setattr(torch.utils.data, "get_worker_info", lambda: worker_info)
+        shards.append([row["a"] for row in dataset])
+
+    assert all(shards)
+    assert set(shards[0]).isdisjoint(shards[1])
+    assert sorted(shards[0] + shards[1]) == list(range(10))
+
+
+def test_to_torch_iter_dataset_invalid_prefetch() -> None:
+    df = daft.from_pydict({"a": [1]})
+    with pytest.raises(ValueError):
+        df.to_torch_iter_dataset(prefetch=0)
@@ -0,0 +1,74 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import Constraint, ValidationError, col
+
+
+@pytest.fixture
+def df():
+    return daft.from_pydict({"id": [1, 2, 3, 4], "age": [30, -1, None, 45], "name": ["a", "b", "c", None]})
+
+
+def test_validate_passes(df):
+    result = df.validate([col("id") > 0, Constraint(col("id") < 10, name="small_id")])
+    assert result.passed
+    assert result.num_rows == 4
+    assert [(s.name, s.severity, s.violations) for s in result.summary] == [
+        ("id", "fail", 0),
+        ("small_id", "fail", 0),
+    ]
+    assert result.df.to_pydict() == df.to_pydict()
+    assert result.quarantine.count_rows() == 0
+    assert result.quarantine.column_names == ["id", "age", "name", "_violations"]
+
+
+def test_validate_fails(df):
+    with pytest.raises(ValidationError, match="non_negative_age") as exc_info:
+        df.validate([Constraint(col("age") >= 0, name="non_negative_age")])
+    assert exc_info.value.num_rows == 4
+    assert exc_info.value.summary[0].violations == 2
+
+
+def test_validate_warns(df):
+    with pytest.warns(UserWarning, match="has_name"):
+        result = df.validate([Constraint(col("name").not_null(), severity="warn", name="has_name")])
+    assert not result.passed
+    assert result.summary[0].violations == 1
+    assert result.df.to_pydict() == df.to_pydict()
+
+
+def test_validate_quarantines(df):
+    result = df.validate(
+        [
+            Constraint(col("age") >= 0, severity="quarantine", name="valid_age"),
+            Constraint(col("name").not_null(), severity="quarantine", name="has_name"),
+            Constraint(col("id") > 0, name="positive_id"),
+        ]
+    )
+    assert [s.violations for s in result.summary] == [2, 1, 0]
+    assert result.df.to_pydict() == {"id": [1], "age": [30], "name": ["a"]}
+    assert result.quarantine.sort("id").to_pydict() == {
+        "id": [2, 3, 4],
+        "age": [-1, None, 45],
+        "name": ["b", "c", None],
+        "_violations": [["valid_age"], ["valid_age"], ["has_name"]],
+    }
+
+
+def test_validate_runs_in_the_same_pass(df):
+    # The constraints are evaluated over the output of the pipeline, so they may refer to its derived columns.
+    pipeline = df.with_column("next_age", col("age") + 1).where(col("id") > 1)
+    result = pipeline.validate([Constraint(col("next_age") > 0, severity="quarantine", name="positive_next_age")])
+    assert result.num_rows == 3
+    assert result.df.to_pydict() == {"id": [4], "age": [45], "name": [None], "next_age": [46]}
+
+
+def test_validate_invalid_constraints(df):
+    with pytest.raises(ValueError, match="boolean"):
+        df.validate([col("id") + 1])
+    with pytest.raises(ValueError, match="unique names"):
+        df.validate([col("id") > 0, col("id") < 10])
+    with pytest.raises(ValueError, match="severity"):
+        Constraint(col("id") > 0, severity="error")
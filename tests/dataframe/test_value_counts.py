@@ -0,0 +1,21 @@
+from __future__ import annotations
+
+import pytest
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 5])
+def test_value_counts(make_df, repartition_nparts, with_morsel_size):
+    df = make_df(
+        {"pet": ["cat", "dog", "dog", None, "dog", "cat", "bird"], "age": [1, 2, 2, 3, 4, 1, 5]},
+        repartition=repartition_nparts,
+    )
+    assert df.value_counts("pet").to_pydict() == {"pet": ["dog", "cat", "bird"], "count": [3, 2, 1]}
+
+    result = df.value_counts("pet", "age").to_pydict()
+    assert result["count"][:2] == [2, 2]
+    assert sorted(zip(result["pet"], result["age"], result["count"])) == [
+        ("bird", 5, 1),
+        ("cat", 1, 2),
+        ("dog", 2, 2),
+        ("dog", 4, 1),
+    ]
@@ -0,0 +1,46 @@
+from __future__ import annotations
+
+import datetime
+
+import pytest
+
+import daft
+from daft import DataType, col
+from daft.functions import reset_try_error_count, try_error_count
+
+
+def test_try_function_nulls_out_failed_rows() -> None:
+    df = daft.from_pydict({"s": ["2024-01-01", "bad", None, "2024-01-03", "worse"]}).into_partitions(2)
+    with pytest.raises(Exception):
+        df.select(col("s").str.to_date("%Y-%m-%d")).collect()
+
+    reset_try_error_count()
+    result = df.select(col("s").str.to_date("%Y-%m-%d").try_()).to_pydict()
+    assert result == {"s": [datetime.date(2024, 1, 1), None, None, datetime.date(2024, 1, 3), None]}
+    assert try_error_count() == 2
+    assert reset_try_error_count() == 2
+    assert try_error_count() == 0
+
+
+def test_try_every_row_fails() -> None:
+    df = daft.from_pydict({"s": ["bad", "worse"]})
+    result = df.select(col("s").str.to_date("%Y-%m-%d").try_().alias("d"))
+    assert result.schema()["d"].dtype == DataType.date()
+    assert result.to_pydict() == {"d": [None, None]}
+
+
+def test_try_keeps_alias() -> None:
+    df = daft.from_pydict({"s": ["2024-01-01", "bad"]})
+    result = df.select(col("s").str.to_date("%Y-%m-%d").alias("d").try_()).to_pydict()
+    assert result == {"d": [datetime.date(2024, 1, 1), None]}
+
+
+def test_try_cast() -> None:
+    df = daft.from_pydict({"s": ["1", "2", "3"]})
+    result = df.select(col("s").try_cast(DataType.int64())).to_pydict()
+    assert result == {"s": [1, 2, 3]}
+
+
+def test_try_rejects_columns() -> None:
+    with pytest.raises(ValueError, match="try_"):
+        col("a").try_()
@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+import asyncio
+
 import numpy as np
 import pyarrow as pa
 import pytest
@@ -439,3 +441,104 @@ def test_udf_empty(batch_size, use_actor_pool):
 
     result = df.select(identity(col("a")))
     assert result.to_pydict() == {"a": []}
+
+
+@pytest.mark.parametrize("batch_size", [None, 1, 2, 10])
+@pytest.mark.parametrize("use_actor_pool", [False, True])
+def test_async_udf(batch_size, use_actor_pool):
+    df = daft.from_pydict({"a": [1, 2, 3, 4, 5]})
+
+    @udf(return_dtype=DataType.int64(), batch_size=batch_size)
+    async def add_1(data):
+        await asyncio.sleep(0.01)
+        return [x + 1 for x in data.to_pylist()]
+
+    if use_actor_pool:
+        add_1 = add_1.with_concurrency(2)
+
+    result = df.select(add_1(col("a")))
+    assert result.to_pydict() == {"a": [2, 3, 4, 5, 6]}
+
+
+def test_async_class_udf():
+    table = MicroPartition.from_pydict({"a": ["foo", "bar", "baz"]})
+
+    @udf(return_dtype=DataType.string(), batch_size=1)
+    class AsyncRepeatN:
+        def __init__(self, n=2):
+            self.n = n
+
+        async def __call__(self, data):
+            await asyncio.sleep(0.01)
+            return [d * self.n for d in data.to_pylist()]
+
+    result = table.eval_expression_list([AsyncRepeatN.with_init_args(n=3)(col("a"))])
+    assert result.to_pydict() == {"a": ["foofoofoo", "barbarbar", "bazbazbaz"]}
+
+
+def test_async_udf_max_in_flight():
+    table = MicroPartition.from_pydict({"a": list(range(12))})
+    in_flight = 0
+    peak = 0
+
+    @udf(return_dtype=DataType.int64(), batch_size=1, max_in_flight=3)
+    async def track(data):
+        nonlocal in_flight, peak
+        in_flight += 1
+        peak = max(peak, in_flight)
+        await asyncio.sleep(0.02)
+        in_flight -= 1
+        return data
+
+    result = table.eval_expression_list([track(col("a"))])
+    assert result.to_pydict() == {"a": list(range(12))}
+    # the batches are awaited concurrently, but never more than max_in_flight at once
+    assert 1 < peak <= 3
+
+
+def test_async_udf_timeout():
+    table = MicroPartition.from_pydict({"a": [1, 2, 3]})
+
+    @udf(return_dtype=DataType.int64(), timeout=0.01)
+    async def hang(data):
+        await asyncio.sleep(10)
+        return data
+
+    with pytest.raises(RuntimeError, match="timed out after 0.01s"):
+        table.eval_expression_list([hang(col("a"))])
+
+    # the timeout can be lifted per call site
+    @udf(return_dtype=DataType.int64(), timeout=0.01)
+    async def quick(data):
+        await asyncio.sleep(0.05)
+        return data
+
+    result = table.eval_expression_list([quick.override_options(timeout=None)(col("a"))])
+    assert result.to_pydict() == {"a": [1, 2, 3]}
+
+
+def test_async_udf_error():
+    table = MicroPartition.from_pydict({"a": [1, 2, 3]})
+
+    @udf(return_dtype=DataType.int64(), batch_size=1)
+    async def fail_on_2(data):
+        if 2 in data.to_pylist():
+            raise ValueError("no twos")
+        return data
+
+    with pytest.raises(RuntimeError, match="failed when executing on inputs with lengths: \\(1,\\)"):
+        table.eval_expression_list([fail_on_2(col("a"))])
+
+
+def test_async_udf_options_require_async():
+    with pytest.raises(ValueError, match="can only be set for `async def` UDFs"):
+
+        @udf(return_dtype=DataType.int64(), max_in_flight=2)
+        def not_async(data):
+            return data
+
+    with pytest.raises(ValueError, match="max_in_flight for UDF must be a positive integer"):
+
+        @udf(return_dtype=DataType.int64(), max_in_flight=0)
+        async def no_calls(data):
+            return data
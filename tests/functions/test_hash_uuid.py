@@ -0,0 +1,55 @@
+from __future__ import annotations
+
+import uuid as pyuuid
+
+import pytest
+
+import daft
+from daft import col
+from daft.functions import hash, uuid
+
+
+def test_hash_columns():
+    df = daft.from_pydict({"a": [1, 2, 1, None], "b": ["x", "y", "x", "x"]})
+    result = df.select(
+        hash("a", "b").alias("ab"),
+        hash(col("a"), col("b"), seed=1).alias("seeded"),
+        hash("a").alias("a_only"),
+        col("a").hash().alias("a_hash"),
+    ).to_pydict()
+    assert result["ab"][0] == result["ab"][2]
+    assert len(set(result["ab"])) == 3
+    assert result["ab"] != result["seeded"]
+    assert result["a_only"] == result["a_hash"]
+    assert result["ab"][3] is not None
+
+
+def test_hash_columns_is_stable():
+    df = daft.from_pydict({"a": [1, 2], "b": ["x", "y"]})
+    assert df.select(hash("a", "b")).to_pydict() == {"a": [7411349167206634625, 9031014927790121297]}
+
+
+def test_hash_requires_columns():
+    with pytest.raises(ValueError):
+        hash()
+
+
+@pytest.mark.parametrize("version", [4, 7])
+def test_uuid(version):
+    df = daft.from_pydict({"a": list(range(100))}).into_partitions(3)
+    ids = df.with_column("id", uuid(version)).to_pydict()["id"]
+    parsed = [pyuuid.UUID(i) for i in ids]
+    assert len(set(parsed)) == 100
+    assert all(u.version == version for u in parsed)
+
+
+def test_uuid_invalid_version():
+    with pytest.raises(Exception, match="Unsupported UUID version"):
+        uuid(5)
+
+
+def test_uuid_sql():
+    df = daft.from_pydict({"a": [1, 2, 3]})
+    ids = daft.sql("SELECT uuid() AS id, uuid(7) AS id7 FROM df").to_pydict()
+    assert len(set(ids["id"])) == 3
+    assert all(pyuuid.UUID(i).version == 7 for i in ids["id7"])
@@ -0,0 +1,17 @@
+from __future__ import annotations
+
+import pytest
+
+from daft import col
+from daft.functions import load_native_udfs, native_udf, registered_native_udfs
+
+
+def test_unregistered_native_udf():
+    assert "not_a_registered_udf" not in registered_native_udfs()
+    with pytest.raises(ValueError, match="not registered"):
+        native_udf("not_a_registered_udf", col("a"))
+
+
+def test_load_native_udfs_missing_library(tmp_path):
+    with pytest.raises(ValueError, match="Failed to load native UDFs"):
+        load_native_udfs(str(tmp_path / "missing.so"))
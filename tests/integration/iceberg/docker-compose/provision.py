@@ -216,6 +216,43 @@ spark.sql(
 """
 )
 
+spark.sql(
+    """
+  CREATE OR REPLACE TABLE default.test_positional_mor_updates (
+    dt     date,
+    number integer,
+    letter string
+  )
+  USING iceberg
+  TBLPROPERTIES (
+    'write.delete.mode'='merge-on-read',
+    'write.update.mode'='merge-on-read',
+    'write.merge.mode'='merge-on-read',
+    'format-version'='2'
+  );
+"""
+)
+
+spark.sql(
+    """
+INSERT INTO default.test_positional_mor_updates
+VALUES
+    (CAST('2023-03-01' AS date), 1, 'a'),
+    (CAST('2023-03-02' AS date), 2, 'b'),
+    (CAST('2023-03-03' AS date), 3, 'c'),
+    (CAST('2023-03-04' AS date), 4, 'd'),
+    (CAST('2023-03-05' AS date), 5, 'e'),
+    (CAST('2023-03-06' AS date), 6, 'f');
+"""
+)
+
+# Updates delete the old rows and add the new ones in the same snapshot
+spark.sql(
+    """
+    UPDATE default.test_positional_mor_updates SET letter = 'z' WHERE number % 2 = 0
+"""
+)
+
 all_types_dataframe = (
     spark.range(0, 5, 1, 5)
     .withColumnRenamed("id", "longCol")
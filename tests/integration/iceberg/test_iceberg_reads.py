@@ -39,6 +39,7 @@ WORKING_SHOW_COLLECT = [
     "test_partitioned_by_years",
     "test_positional_mor_deletes",
     "test_positional_mor_double_deletes",
+    "test_positional_mor_updates",
     # "test_table_sanitized_character", # Bug in scan().to_arrow().to_arrow()
     "test_table_version",  # we have bugs when loading no files
     "test_uuid_and_fixed_unpartitioned",
@@ -180,7 +181,9 @@ def test_daft_iceberg_table_read_table_snapshot(local_iceberg_catalog):
 
 
 @pytest.mark.integration()
-@pytest.mark.parametrize("table_name", ["test_positional_mor_deletes", "test_positional_mor_double_deletes"])
+@pytest.mark.parametrize(
+    "table_name", ["test_positional_mor_deletes", "test_positional_mor_double_deletes", "test_positional_mor_updates"]
+)
 def test_daft_iceberg_table_mor_limit_collect_correct(table_name, local_iceberg_catalog):
     catalog_name, pyiceberg_catalog = local_iceberg_catalog
     tab = pyiceberg_catalog.load_table(f"default.{table_name}")
@@ -197,7 +200,9 @@ def test_daft_iceberg_table_mor_limit_collect_correct(table_name, local_iceberg_
 
 
 @pytest.mark.integration()
-@pytest.mark.parametrize("table_name", ["test_positional_mor_deletes", "test_positional_mor_double_deletes"])
+@pytest.mark.parametrize(
+    "table_name", ["test_positional_mor_deletes", "test_positional_mor_double_deletes", "test_positional_mor_updates"]
+)
 def test_daft_iceberg_table_mor_predicate_collect_correct(table_name, local_iceberg_catalog):
     catalog_name, pyiceberg_catalog = local_iceberg_catalog
     tab = pyiceberg_catalog.load_table(f"default.{table_name}")
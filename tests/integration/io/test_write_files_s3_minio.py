@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import os
 import uuid
 
 import pytest
@@ -41,3 +42,15 @@ def test_writing_parquet(minio_io_config, bucket, protocol):
     )
     results.collect()
     assert len(results) == 3
+
+
+@pytest.mark.integration()
+def test_writing_parquet_in_multiple_parts(minio_io_config, bucket):
+    # Random bytes don't compress, so each file is larger than a part
+    data = {"foo": [os.urandom(1024) for _ in range(12 * 1024)]}
+    path = f"s3://{bucket}/parquet-multipart-writes-{uuid.uuid4()}"
+    with daft.execution_config_ctx(upload_part_size=5 * 1024 * 1024, max_inflight_upload_parts=1):
+        daft.from_pydict(data).write_parquet(path, io_config=minio_io_config)
+
+    read_back = daft.read_parquet(path, io_config=minio_io_config).to_pydict()
+    assert sorted(read_back["foo"]) == sorted(data["foo"])
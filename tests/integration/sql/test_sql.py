@@ -409,6 +409,29 @@ def test_sql_connection_factory_ok(test_db, pdf) -> None:
     assert_df_equals(df.to_pandas(coerce_temporal_nanoseconds=True), pdf, sort_key="id")
 
 
+@pytest.mark.integration()
+@pytest.mark.parametrize("num_partitions", [1, 3])
+def test_sql_adbc_connection_factory_ok(test_db, num_partitions, pdf) -> None:
+    if not test_db.startswith("postgresql"):
+        pytest.skip("The ADBC driver is only installed for PostgreSQL")
+    adbc_driver_postgresql = pytest.importorskip("adbc_driver_postgresql.dbapi")
+
+    def create_conn():
+        return adbc_driver_postgresql.connect(test_db)
+
+    with daft.execution_config_ctx(
+        scan_tasks_min_size_bytes=0,
+        scan_tasks_max_size_bytes=0,
+    ):
+        df = daft.read_sql(
+            f"SELECT * FROM {TEST_TABLE_NAME}", create_conn, partition_col="id", num_partitions=num_partitions
+        )
+        assert df.num_partitions() == num_partitions
+    df = df.where(df["id"] > 100).select("id", "float_col")
+    expected = pdf[pdf["id"] > 100][["id", "float_col"]]
+    assert_df_equals(df.to_pandas(coerce_temporal_nanoseconds=True), expected, sort_key="id")
+
+
 @pytest.mark.integration()
 def test_sql_bad_connection_factory() -> None:
     with pytest.raises(ValueError):
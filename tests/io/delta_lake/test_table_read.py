@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+import datetime
+
 import pyarrow as pa
 import pytest
 
@@ -114,3 +116,40 @@ def test_deltalake_read_versioned(tmp_path, base_table):
     expected_schema = Schema.from_pyarrow_schema(deltalake.DeltaTable(path, version=0).schema().to_pyarrow())
     assert df.schema() == expected_schema
     assert_pyarrow_tables_equal(df.to_arrow(), base_table)
+
+
+def test_deltalake_read_timestamp_and_list_versions(tmp_path):
+    deltalake = pytest.importorskip("deltalake")
+    path = tmp_path / "some_table"
+    deltalake.write_deltalake(path, pa.table({"a": [1, 2, 3]}))
+    deltalake.write_deltalake(path, pa.table({"a": [4, 5]}), mode="append")
+
+    versions = daft.list_deltalake_versions(str(path)).to_pydict()
+    assert versions["version"] == [0, 1]
+    assert versions["is_current"] == [False, True]
+
+    df = daft.read_deltalake(str(path), timestamp=datetime.datetime.now(datetime.timezone.utc))
+    assert df.sort("a").to_pydict() == {"a": [1, 2, 3, 4, 5]}
+
+    sql_df = daft.sql(f"SELECT * FROM read_deltalake('{path}', version:=0)")
+    assert sql_df.sort("a").to_pydict() == {"a": [1, 2, 3]}
+
+    with pytest.raises(ValueError, match="Only one of version and timestamp"):
+        daft.read_deltalake(str(path), version=0, timestamp=datetime.datetime.now(datetime.timezone.utc))
+
+
+def test_deltalake_read_changes(tmp_path):
+    deltalake = pytest.importorskip("deltalake")
+    path = tmp_path / "some_table"
+    deltalake.write_deltalake(path, pa.table({"a": [1, 2, 3]}))
+    deltalake.write_deltalake(path, pa.table({"a": [4, 5]}), mode="append")
+    deltalake.write_deltalake(path, pa.table({"a": [6]}), mode="overwrite")
+
+    appended = daft.read_deltalake(str(path), version=1, start_version=0)
+    assert appended.sort("a").to_pydict() == {"a": [4, 5], "_change_type": ["insert", "insert"]}
+
+    changes = daft.read_deltalake(str(path), start_version=0).sort(["_change_type", "a"]).to_pydict()
+    assert changes == {"a": [1, 2, 3, 6], "_change_type": ["delete", "delete", "delete", "insert"]}
+
+    with pytest.raises(ValueError, match="after their end version"):
+        daft.read_deltalake(str(path), version=0, start_version=1)
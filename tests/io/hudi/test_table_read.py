@@ -65,3 +65,61 @@ def test_read_empty_table(get_empty_table):
     table_path = get_empty_table
     df = daft.read_hudi(table_path)
     assert len(df.collect()) == 0
+
+
+def _write_hudi_table(table_path, table_type, base_files, instants):
+    import json
+
+    import pyarrow.parquet as pq
+
+    hoodie_path = table_path / ".hoodie"
+    hoodie_path.mkdir(parents=True)
+    (hoodie_path / "hoodie.properties").write_text(
+        "\n".join(
+            [
+                "hoodie.table.name=test_table",
+                f"hoodie.table.type={table_type}",
+                "hoodie.table.version=6",
+                "hoodie.timeline.layout.version=1",
+                "hoodie.table.keygenerator.class=org.apache.hudi.keygen.NonpartitionedKeyGenerator",
+                "hoodie.datasource.write.drop.partition.columns=false",
+            ]
+        )
+    )
+    for file_name, ids in base_files.items():
+        pq.write_table(pa.table({"id": pa.array(ids, type=pa.int64())}), table_path / file_name)
+    for file_name, metadata in instants.items():
+        (hoodie_path / file_name).write_text(json.dumps(metadata) if metadata is not None else "")
+
+
+def test_read_table_skips_uncommitted_and_replaced_file_groups(tmp_path):
+    _write_hudi_table(
+        tmp_path,
+        "MERGE_ON_READ",
+        {
+            "fg1-0_0-1-1_001.parquet": [1, 2],
+            "fg2-0_0-1-1_001.parquet": [3],
+            "fg3-0_0-2-2_002.parquet": [4],
+            # Written by a compaction that's still pending.
+            "fg1-0_0-3-3_003.parquet": [1, 2, 5],
+            # Written by a failed commit.
+            "fg4-0_0-4-4_004.parquet": [6],
+        },
+        {
+            "001.deltacommit": {
+                "partitionToWriteStats": {
+                    "": [{"path": "fg1-0_0-1-1_001.parquet"}, {"path": "fg2-0_0-1-1_001.parquet"}]
+                }
+            },
+            "002.replacecommit": {
+                "partitionToWriteStats": {"": [{"path": "fg3-0_0-2-2_002.parquet"}]},
+                "partitionToReplaceFileIds": {"": ["fg2-0"]},
+            },
+            "003.compaction.requested": None,
+            "004.commit.requested": None,
+            "004.inflight": None,
+        },
+    )
+
+    df = daft.read_hudi(str(tmp_path))
+    assert df.sort("id").to_pydict() == {"id": [1, 2, 4]}
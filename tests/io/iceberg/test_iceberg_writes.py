@@ -2,6 +2,7 @@ from __future__ import annotations
 
 import datetime
 import decimal
+import time
 
 import pyarrow as pa
 import pytest
@@ -137,6 +138,59 @@ def test_read_after_write_overwrite(simple_local_table):
     assert as_arrow == read_back.to_arrow().sort_by("x")
 
 
+def test_read_changes_between_snapshots(simple_local_table):
+    table, _ = simple_local_table
+
+    daft.from_pydict({"x": [1, 2, 3]}).write_iceberg(table)
+    table.refresh()
+    first_snapshot_id = table.current_snapshot().snapshot_id
+    daft.from_pydict({"x": [4, 5]}).write_iceberg(table)
+    table.refresh()
+    second_snapshot_id = table.current_snapshot().snapshot_id
+    daft.from_pydict({"x": [6]}).write_iceberg(table, mode="overwrite")
+    table.refresh()
+
+    appended = daft.read_iceberg(table, snapshot_id=second_snapshot_id, start_snapshot_id=first_snapshot_id)
+    assert appended.sort("x").to_pydict() == {"x": [4, 5], "_change_type": ["insert", "insert"]}
+
+    # The rows 4 and 5 were inserted and deleted again after the start snapshot, so they didn't change the table.
+    changes = daft.read_iceberg(table, start_snapshot_id=first_snapshot_id)
+    assert changes.sort(["_change_type", "x"]).to_pydict() == {
+        "x": [1, 2, 3, 6],
+        "_change_type": ["delete", "delete", "delete", "insert"],
+    }
+    inserts = changes.where(changes["_change_type"] == "insert")
+    assert inserts.to_pydict() == {"x": [6], "_change_type": ["insert"]}
+
+
+def test_read_as_of_and_list_snapshots(simple_local_table):
+    table, _ = simple_local_table
+
+    daft.from_pydict({"x": [1, 2, 3]}).write_iceberg(table)
+    # Sleep so that the snapshots have distinct timestamps.
+    time.sleep(0.01)
+    daft.from_pydict({"x": [4, 5]}).write_iceberg(table)
+    table.refresh()
+
+    snapshots = daft.list_iceberg_snapshots(table).to_pydict()
+    assert snapshots["snapshot_id"] == [entry.snapshot_id for entry in table.history()]
+    assert snapshots["parent_snapshot_id"] == [None, snapshots["snapshot_id"][0]]
+    assert snapshots["is_current"] == [False, True]
+
+    first_snapshot_time = snapshots["timestamp"][0]
+    as_of_first = daft.read_iceberg(table, as_of=first_snapshot_time)
+    assert as_of_first.sort("x").to_pydict() == {"x": [1, 2, 3]}
+    as_of_first_iso = daft.read_iceberg(table, as_of=first_snapshot_time.isoformat())
+    assert as_of_first_iso.sort("x").to_pydict() == {"x": [1, 2, 3]}
+    latest = daft.read_iceberg(table, as_of=datetime.datetime.now(datetime.timezone.utc))
+    assert latest.sort("x").to_pydict() == {"x": [1, 2, 3, 4, 5]}
+
+    with pytest.raises(ValueError, match="no snapshot as of"):
+        daft.read_iceberg(table, as_of=first_snapshot_time - datetime.timedelta(days=1))
+    with pytest.raises(ValueError, match="Only one of snapshot_id and as_of"):
+        daft.read_iceberg(table, snapshot_id=snapshots["snapshot_id"][0], as_of=first_snapshot_time)
+
+
 def test_read_and_overwrite(simple_local_table):
     table, num_partitions = simple_local_table
 
@@ -190,6 +244,45 @@ def test_too_many_columns_write(simple_local_table):
     assert as_arrow == read_back.to_arrow().sort_by("x")
 
 
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native",
+    reason="Partition overwrites are only supported by native Iceberg writes",
+)
+def test_overwrite_partitions(local_catalog):
+    schema = Schema(
+        NestedField(field_id=1, name="x", type=LongType()),
+        NestedField(field_id=2, name="y", type=StringType()),
+    )
+    partition_spec = PartitionSpec(PartitionField(source_id=2, field_id=1000, transform=IdentityTransform(), name="y"))
+    table = local_catalog.create_table("default.test", schema, partition_spec=partition_spec)
+
+    daft.from_pydict({"x": [1, 2, 3], "y": ["a", "b", "c"]}).write_iceberg(table)
+
+    result = daft.from_pydict({"x": [4, 5], "y": ["b", "d"]}).write_iceberg(table, mode="overwrite-partitions")
+    as_dict = result.to_pydict()
+    assert sorted(zip(as_dict["operation"], as_dict["rows"])) == [("ADD", 1), ("ADD", 1), ("DELETE", 1)]
+
+    read_back = daft.read_iceberg(table).sort("x").to_pydict()
+    assert read_back == {"x": [1, 3, 4, 5], "y": ["a", "c", "b", "d"]}
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native",
+    reason="Schema merges are only supported by native Iceberg writes",
+)
+def test_write_with_schema_merge(local_catalog):
+    schema = Schema(NestedField(field_id=1, name="x", type=LongType()))
+    table = local_catalog.create_table("default.test", schema)
+
+    daft.from_pydict({"x": [1, 2]}).write_iceberg(table)
+    daft.from_pydict({"x": [3], "y": ["c"]}).write_iceberg(table, schema_mode="merge")
+
+    assert [field.name for field in table.schema().fields] == ["x", "y"]
+    assert not table.schema().find_field("y").required
+    read_back = daft.read_iceberg(table).sort("x").to_pydict()
+    assert read_back == {"x": [1, 2, 3], "y": [None, None, "c"]}
+
+
 def test_read_after_write_nested_fields(local_catalog):
     # We need to cast Large Types such as LargeList and LargeString to the i32 variants
     df = daft.from_pydict({"x": [["a", "b"], ["c", "d", "e"]]})
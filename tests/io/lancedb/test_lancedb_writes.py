@@ -50,3 +50,25 @@ def test_lancedb_minio(minio_io_config):
         df2.write_lance(s3_path, mode="append", io_config=minio_io_config)
         df_loaded = daft.read_lance(s3_path, io_config=minio_io_config)
         assert df_loaded.to_pydict() == df1.concat(df2).to_pydict()
+
+
+def test_lancedb_write_embeddings_with_vector_index(lance_dataset_path):
+    import lance
+
+    df = daft.from_pydict({"vector": [[float(i), float(i % 7)] for i in range(512)]})
+    df = df.with_column("vector", df["vector"].cast(daft.DataType.embedding(daft.DataType.float32(), 2)))
+    df.write_lance(
+        lance_dataset_path,
+        vector_index={"column": "vector", "num_partitions": 2, "num_sub_vectors": 1},
+    )
+
+    dataset = lance.dataset(lance_dataset_path)
+    assert dataset.schema.field("vector").type == pa.list_(pa.float32(), 2)
+    assert [index["fields"] for index in dataset.list_indices()] == [["vector"]]
+    assert daft.read_lance(lance_dataset_path).count_rows() == 512
+
+
+def test_lancedb_write_vector_index_on_missing_column(lance_dataset_path):
+    df = daft.from_pydict(data1)
+    with pytest.raises(ValueError, match="vector index"):
+        df.write_lance(lance_dataset_path, vector_index={"column": "embedding"})
@@ -0,0 +1,78 @@
+from __future__ import annotations
+
+import pyarrow as pa
+
+import daft
+from daft.io import DataSource, DataSourcePushdowns, DataSourceTask
+from daft.logical.schema import Schema
+
+SCHEMA = Schema.from_pyarrow_schema(pa.schema([("id", pa.int64()), ("name", pa.string())]))
+
+
+class ListTask(DataSourceTask):
+    def __init__(self, rows: list[tuple[int, str]], columns: list[str] | None):
+        self._rows = rows
+        self._columns = columns
+
+    def get_batches(self):
+        table = pa.table(
+            {
+                "id": pa.array([row[0] for row in self._rows], type=pa.int64()),
+                "name": pa.array([row[1] for row in self._rows], type=pa.string()),
+            }
+        )
+        yield table.select(self._columns) if self._columns is not None else table
+
+    @property
+    def num_rows(self) -> int | None:
+        return len(self._rows)
+
+
+class ListSource(DataSource):
+    def __init__(self, parts: list[list[tuple[int, str]]]):
+        self._parts = parts
+        self.pushdowns: list[DataSourcePushdowns] = []
+
+    @property
+    def name(self) -> str:
+        return "ListSource"
+
+    @property
+    def schema(self) -> Schema:
+        return SCHEMA
+
+    def get_tasks(self, pushdowns: DataSourcePushdowns):
+        self.pushdowns.append(pushdowns)
+        for part in self._parts:
+            yield ListTask(part, pushdowns.columns)
+
+
+PARTS = [[(1, "a"), (2, "b")], [(3, "c")], [(4, "d"), (5, "e")]]
+
+
+def test_data_source_read():
+    df = ListSource(PARTS).read()
+    assert df.schema() == SCHEMA
+    assert df.num_partitions() == 3
+    assert df.sort("id").to_pydict() == {"id": [1, 2, 3, 4, 5], "name": ["a", "b", "c", "d", "e"]}
+
+
+def test_data_source_projection_pushdown():
+    source = ListSource(PARTS)
+    df = source.read().select("name")
+    assert sorted(df.to_pydict()["name"]) == ["a", "b", "c", "d", "e"]
+    assert source.pushdowns[-1].columns == ["name"]
+
+
+def test_data_source_filter_and_limit_are_applied_after_tasks():
+    source = ListSource(PARTS)
+    df = source.read().where(daft.col("id") > 2)
+    assert df.sort("id").to_pydict() == {"id": [3, 4, 5], "name": ["c", "d", "e"]}
+    assert source.pushdowns[-1].filters is not None
+
+    assert len(ListSource(PARTS).read().limit(2).to_pydict()["id"]) == 2
+
+
+def test_data_source_explain():
+    df = ListSource(PARTS).read()
+    assert "DataSource(ListSource)" in df._builder.pretty_print(simple=False)
@@ -194,3 +194,30 @@ def test_hive_daft_roundtrip(tmpdir, partition_by, file_format, filter):
         source = source.where(daft.col(first_col) == sample_value)
         target = target.where(daft.col(first_col) == sample_value)
     assert_tables_equal(target.to_arrow(), source.to_arrow())
+
+
+@pytest.mark.parametrize("how", ["inner", "left", "right", "semi", "anti"])
+@pytest.mark.parametrize("dynamic_partition_pruning_max_keys", [0, 10_000])
+def test_hive_join_on_partition_column(tmpdir, how, dynamic_partition_pruning_max_keys):
+    ds.write_dataset(
+        SAMPLE_DATA.select(["id", "int_col", "nullable_int"]),
+        tmpdir,
+        format="parquet",
+        partitioning=ds.partitioning(pa.schema([SAMPLE_DATA.schema.field("nullable_int")]), flavor="hive"),
+    )
+    fact = daft.read_parquet(os.path.join(tmpdir, "**"), hive_partitioning=True)
+    dim = daft.from_pydict({"key": [1, 2, 5], "name": ["one", "two", "five"]}).where(daft.col("key") < 5)
+
+    with daft.execution_config_ctx(dynamic_partition_pruning_max_keys=dynamic_partition_pruning_max_keys):
+        result = fact.join(dim, left_on="nullable_int", right_on="key", how=how).to_pydict()
+
+    expected = (
+        daft.from_arrow(SAMPLE_DATA.select(["id", "int_col", "nullable_int"]))
+        .join(dim, left_on="nullable_int", right_on="key", how=how)
+        .to_pydict()
+    )
+
+    def sorted_rows(table):
+        return sorted(zip(*(table[col] for col in sorted(table))), key=repr)
+
+    assert sorted_rows(result) == sorted_rows(expected)
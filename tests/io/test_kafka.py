@@ -0,0 +1,213 @@
+from __future__ import annotations
+
+import io
+import json
+
+import pytest
+
+import daft
+from daft import DataType
+from daft.io import _kafka
+from tests.conftest import get_tests_daft_runner_name
+
+# The fake cluster is patched into this process, where only the native runner reads the tasks of the stream.
+pytestmark = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Kafka tests read from an in-process fake cluster"
+)
+
+
+class FakeCluster:
+    def __init__(self):
+        # The first offset and the messages of each partition of each topic.
+        self.partitions: dict[str, dict[int, tuple[int, list[_kafka._Message]]]] = {}
+
+    def create_topic(self, topic, num_partitions):
+        self.partitions[topic] = {partition: (0, []) for partition in range(num_partitions)}
+
+    def produce(self, topic, partition, value, key=None):
+        first, messages = self.partitions[topic][partition]
+        offset = first + len(messages)
+        messages.append(_kafka._Message(offset, 1_700_000_000_000 + offset, key, value))
+
+    def expire(self, topic, partition, num_messages):
+        first, messages = self.partitions[topic][partition]
+        self.partitions[topic][partition] = (first + num_messages, messages[num_messages:])
+
+
+class FakeClient:
+    def __init__(self, cluster):
+        self._cluster = cluster
+
+    def partitions(self, topic):
+        return sorted(self._cluster.partitions[topic])
+
+    def watermarks(self, topic, partition):
+        first, messages = self._cluster.partitions[topic][partition]
+        return first, first + len(messages)
+
+    def read(self, topic, partition, start, end):
+        first, messages = self._cluster.partitions[topic][partition]
+        yield from messages[start - first : end - first]
+
+    def close(self):
+        pass
+
+
+@pytest.fixture
+def cluster(monkeypatch):
+    cluster = FakeCluster()
+    cluster.create_topic("events", 2)
+    monkeypatch.setattr(_kafka, "_connect", lambda config: FakeClient(cluster))
+    return cluster
+
+
+def rows(df):
+    return df.sort(["partition", "offset"]).select("partition", "offset", "value").to_pydict()
+
+
+def test_read_kafka_batches(cluster):
+    for i in range(3):
+        cluster.produce("events", i % 2, f"m{i}".encode(), key=b"k")
+    stream = daft.read_kafka("localhost:9092", "events")
+    assert stream.schema.column_names() == ["topic", "partition", "offset", "timestamp", "key", "value"]
+
+    batch = stream.next_batch()
+    assert batch.schema() == stream.schema
+    assert rows(batch) == {"partition": [0, 0, 1], "offset": [0, 1, 0], "value": [b"m0", b"m2", b"m1"]}
+    assert batch.to_pydict()["key"] == [b"k"] * 3
+    stream.commit()
+    assert stream.offsets == {"events": {0: 2, 1: 1}}
+    assert stream.batch_id == 1
+    assert stream.next_batch() is None
+
+    cluster.produce("events", 1, b"m3")
+    assert rows(stream.next_batch()) == {"partition": [1], "offset": [1], "value": [b"m3"]}
+
+
+def test_read_kafka_uncommitted_batch_is_read_again(cluster):
+    cluster.produce("events", 0, b"m0")
+    stream = daft.read_kafka("localhost:9092", "events")
+    assert rows(stream.next_batch())["value"] == [b"m0"]
+    cluster.produce("events", 0, b"m1")
+    assert rows(stream.next_batch())["value"] == [b"m0", b"m1"]
+    stream.commit()
+    with pytest.raises(ValueError, match="no batch"):
+        stream.commit()
+
+
+def test_read_kafka_starting_offsets(cluster):
+    for i in range(4):
+        cluster.produce("events", 0, f"m{i}".encode())
+    cluster.produce("events", 1, b"n0")
+
+    assert daft.read_kafka("localhost:9092", "events", starting_offsets="latest").next_batch() is None
+    stream = daft.read_kafka("localhost:9092", "events", starting_offsets={"events": {0: 2}})
+    assert rows(stream.next_batch())["value"] == [b"m2", b"m3", b"n0"]
+
+    # Messages that the cluster no longer has are skipped.
+    cluster.expire("events", 0, 3)
+    stream = daft.read_kafka("localhost:9092", "events", starting_offsets={"events": {0: 1, 1: 1}})
+    assert rows(stream.next_batch())["value"] == [b"m3"]
+
+
+def test_read_kafka_foreach_batch_checkpoint(cluster, tmp_path):
+    for i in range(5):
+        cluster.produce("events", 0, f"m{i}".encode())
+    checkpoint = str(tmp_path / "checkpoint.json")
+    seen = []
+
+    def process(df, batch_id):
+        seen.append((batch_id, rows(df)["value"]))
+
+    stream = daft.read_kafka("localhost:9092", "events", max_records_per_partition=2, checkpoint=checkpoint)
+    assert stream.foreach_batch(process, max_batches=2) == 2
+    assert seen == [(0, [b"m0", b"m1"]), (1, [b"m2", b"m3"])]
+    with open(checkpoint) as f:
+        assert json.load(f) == {"version": 1, "batch_id": 2, "offsets": {"events": {"0": 4, "1": 0}}}
+
+    # A stream restarted from the checkpoint resumes after the last committed batch.
+    cluster.produce("events", 1, b"n0")
+    stream = daft.read_kafka("localhost:9092", "events", max_records_per_partition=2, checkpoint=checkpoint)
+    assert stream.foreach_batch(process, stop_when_idle=True) == 1
+    assert seen[2:] == [(2, [b"m4", b"n0"])]
+
+
+def test_read_kafka_failed_batch_is_not_committed(cluster, tmp_path):
+    cluster.produce("events", 0, b"m0")
+    checkpoint = str(tmp_path / "checkpoint.json")
+
+    def fail(df, batch_id):
+        raise RuntimeError("failed")
+
+    stream = daft.read_kafka("localhost:9092", "events", checkpoint=checkpoint)
+    with pytest.raises(RuntimeError, match="failed"):
+        stream.foreach_batch(fail)
+    stream = daft.read_kafka("localhost:9092", "events", checkpoint=checkpoint)
+    assert rows(stream.next_batch())["value"] == [b"m0"]
+
+
+def test_read_kafka_json_values(cluster):
+    cluster.produce("events", 0, b'{"user": "a", "amount": 1.5, "extra": true}')
+    cluster.produce("events", 0, None)
+    cluster.produce("events", 0, b'{"user": "b"}')
+    schema = {"user": DataType.string(), "amount": DataType.float64()}
+    stream = daft.read_kafka("localhost:9092", "events", value_format="json", value_schema=schema)
+    assert stream.schema["value"].dtype == DataType.struct(schema)
+
+    result = stream.next_batch().sort("offset").to_pydict()["value"]
+    assert result == [{"user": "a", "amount": 1.5}, None, {"user": "b", "amount": None}]
+
+
+def test_read_kafka_avro_values(cluster):
+    fastavro = pytest.importorskip("fastavro")
+    avro_schema = {
+        "type": "record",
+        "name": "Event",
+        "fields": [
+            {"name": "user", "type": "string"},
+            {"name": "amount", "type": ["null", "double"]},
+            {"name": "tags", "type": {"type": "array", "items": "string"}},
+        ],
+    }
+
+    def encode(record, header=b""):
+        buf = io.BytesIO(header)
+        buf.seek(len(header))
+        fastavro.schemaless_writer(buf, fastavro.parse_schema(avro_schema), record)
+        return buf.getvalue()
+
+    cluster.produce("events", 0, encode({"user": "a", "amount": 1.5, "tags": ["x"]}))
+    cluster.produce("events", 1, encode({"user": "b", "amount": None, "tags": []}, header=b"\x00\x00\x00\x00\x01"))
+
+    # Each stream skips the partition whose values are in the other format.
+    stream = daft.read_kafka(
+        "localhost:9092", "events", starting_offsets={"events": {1: 1}}, value_format="avro", avro_schema=avro_schema
+    )
+    assert stream.schema["value"].dtype == DataType.struct(
+        {"user": DataType.string(), "amount": DataType.float64(), "tags": DataType.list(DataType.string())}
+    )
+    result = stream.next_batch().to_pydict()["value"]
+    assert result == [{"user": "a", "amount": 1.5, "tags": ["x"]}]
+
+    stream = daft.read_kafka(
+        "localhost:9092",
+        "events",
+        starting_offsets={"events": {0: 1}},
+        value_format="avro-confluent",
+        avro_schema=json.dumps(avro_schema),
+    )
+    result = stream.next_batch().to_pydict()["value"]
+    assert result == [{"user": "b", "amount": None, "tags": []}]
+
+
+def test_read_kafka_invalid(cluster):
+    with pytest.raises(ValueError, match="at least one topic"):
+        daft.read_kafka("localhost:9092", [])
+    with pytest.raises(ValueError, match="must be positive"):
+        daft.read_kafka("localhost:9092", "events", max_records_per_partition=0)
+    with pytest.raises(ValueError, match="requires a value_schema"):
+        daft.read_kafka("localhost:9092", "events", value_format="json")
+    with pytest.raises(ValueError, match="requires an avro_schema"):
+        daft.read_kafka("localhost:9092", "events", value_format="avro")
+    with pytest.raises(ValueError, match="Unsupported Kafka value format"):
+        daft.read_kafka("localhost:9092", "events", value_format="csv")
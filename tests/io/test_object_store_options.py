@@ -0,0 +1,13 @@
+from __future__ import annotations
+
+from daft.io import IOConfig, S3Config
+from daft.io.object_store_options import io_config_to_storage_options
+
+
+def test_s3_requester_pays_storage_options():
+    io_config = IOConfig(s3=S3Config(region_name="us-east-1", requester_pays=True))
+    storage_options = io_config_to_storage_options(io_config, "s3://bucket/table")
+    assert storage_options["request_payer"] == "true"
+
+    io_config = IOConfig(s3=S3Config(region_name="us-east-1"))
+    assert "request_payer" not in io_config_to_storage_options(io_config, "s3://bucket/table")
@@ -373,6 +373,16 @@ def test_parquet_limits_across_row_groups(tmpdir, minio_io_config):
     daft.set_execution_config(parquet_target_row_group_size=default_row_group_size)
 
 
+@pytest.mark.parametrize("limit", [0, 5, 10, 25, 40, 100])
+def test_parquet_limits_across_files(tmpdir, limit):
+    # Identical files let the scan know the exact row count of each file, so that it can stop reading files early.
+    table = pa.table({"col": pa.array(range(10), type=pa.int64())})
+    for i in range(4):
+        papq.write_table(table, f"{tmpdir}/file{i}.parquet")
+    df = daft.read_parquet(f"{tmpdir}/*.parquet").limit(limit)
+    assert len(df.to_pydict()["col"]) == min(limit, 40)
+
+
 @pytest.mark.parametrize("optional_outer_struct", [True, False])
 @pytest.mark.parametrize("optional_inner_struct", [True, False])
 def test_parquet_nested_optional_or_required_fields(tmpdir, optional_outer_struct, optional_inner_struct):
@@ -0,0 +1,105 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pyarrow.parquet as papq
+import pytest
+
+import daft
+from daft import DataType
+
+
+def sort_by_id(pydict):
+    order = sorted(range(len(pydict["id"])), key=lambda i: pydict["id"][i])
+    return {name: [values[i] for i in order] for name, values in pydict.items()}
+
+
+@pytest.fixture
+def drifted_parquet_files(tmp_path):
+    papq.write_table(
+        pa.table({"id": pa.array([1, 2], pa.int32()), "x": pa.array([1.5, 2.5], pa.float32())}),
+        tmp_path / "0.parquet",
+    )
+    papq.write_table(
+        pa.table(
+            {
+                "y": pa.array(["c", "d"]),
+                "id": pa.array([3, 4], pa.int64()),
+                "x": pa.array([3.5, 4.5], pa.float64()),
+            }
+        ),
+        tmp_path / "1.parquet",
+    )
+    return str(tmp_path / "*.parquet")
+
+
+def test_parquet_first_schema(drifted_parquet_files):
+    df = daft.read_parquet(drifted_parquet_files)
+    assert df.schema().column_names() == ["id", "x"]
+    assert df.schema()["id"].dtype == DataType.int32()
+
+
+def test_parquet_union_schema(drifted_parquet_files):
+    df = daft.read_parquet(drifted_parquet_files, schema_resolution="union")
+    assert df.schema().column_names() == ["id", "x", "y"]
+    assert df.schema()["id"].dtype == DataType.int64()
+    assert df.schema()["x"].dtype == DataType.float64()
+    assert sort_by_id(df.to_pydict()) == {
+        "id": [1, 2, 3, 4],
+        "x": [1.5, 2.5, 3.5, 4.5],
+        "y": [None, None, "c", "d"],
+    }
+
+
+def test_parquet_union_schema_incompatible_types(tmp_path):
+    papq.write_table(pa.table({"id": pa.array([1], pa.int64())}), tmp_path / "0.parquet")
+    papq.write_table(pa.table({"id": pa.array(["a"])}), tmp_path / "1.parquet")
+    with pytest.raises(Exception, match="id"):
+        daft.read_parquet(str(tmp_path / "*.parquet"), schema_resolution="union")
+
+
+def test_json_union_schema(tmp_path):
+    (tmp_path / "0.jsonl").write_text('{"id": 1, "a": "x"}\n')
+    (tmp_path / "1.jsonl").write_text('{"id": 2, "b": true}\n')
+    df = daft.read_json(str(tmp_path / "*.jsonl"), schema_resolution="union")
+    assert df.schema().column_names() == ["id", "a", "b"]
+    assert sort_by_id(df.to_pydict()) == {"id": [1, 2], "a": ["x", None], "b": [None, True]}
+
+
+def test_parquet_field_id_mapping(tmp_path):
+    def field(name, type, field_id):
+        return pa.field(name, type, metadata={b"PARQUET:field_id": str(field_id).encode()})
+
+    papq.write_table(
+        pa.table(
+            {"id": [1, 2], "value": ["a", "b"]},
+            schema=pa.schema([field("id", pa.int64(), 1), field("value", pa.string(), 2)]),
+        ),
+        tmp_path / "0.parquet",
+    )
+    # The second file renames "value" to "renamed_value" and swaps the order of the columns.
+    papq.write_table(
+        pa.table(
+            {"renamed_value": ["c", "d"], "id": [3, 4]},
+            schema=pa.schema([field("renamed_value", pa.string(), 2), field("id", pa.int64(), 1)]),
+        ),
+        tmp_path / "1.parquet",
+    )
+
+    df = daft.read_parquet(str(tmp_path / "*.parquet"), column_mapping="field_id")
+    assert df.schema().column_names() == ["id", "value"]
+    assert sort_by_id(df.to_pydict()) == {"id": [1, 2, 3, 4], "value": ["a", "b", "c", "d"]}
+
+    by_name = daft.read_parquet(str(tmp_path / "*.parquet"))
+    assert sort_by_id(by_name.to_pydict()) == {"id": [1, 2, 3, 4], "value": ["a", "b", None, None]}
+
+
+def test_field_id_mapping_requires_field_ids(drifted_parquet_files):
+    with pytest.raises(Exception, match="field ID"):
+        daft.read_parquet(drifted_parquet_files, column_mapping="field_id")
+
+
+def test_invalid_schema_resolution(drifted_parquet_files):
+    with pytest.raises(Exception, match="schema mode"):
+        daft.read_parquet(drifted_parquet_files, schema_resolution="last")
+    with pytest.raises(Exception, match="column mapping"):
+        daft.read_parquet(drifted_parquet_files, column_mapping="position")
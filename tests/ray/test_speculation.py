@@ -0,0 +1,88 @@
+from __future__ import annotations
+
+import os
+import tempfile
+import time
+
+import pytest
+
+import daft
+from daft.runners.speculation import SpeculationTracker
+from tests.conftest import get_tests_daft_runner_name
+
+
+class FakeClock:
+    def __init__(self) -> None:
+        self.now = 0.0
+
+    def __call__(self) -> float:
+        return self.now
+
+
+def test_speculation_tracker_finds_stragglers():
+    clock = FakeClock()
+    tracker = SpeculationTracker(quantile=0.75, multiplier=1.5, min_runtime_s=1.0, clock=clock)
+    for i in range(4):
+        tracker.task_started(f"task_{i}", stage_id=0)
+
+    clock.now = 2.0
+    tracker.task_finished("task_0")
+    tracker.task_finished("task_1")
+    clock.now = 100.0
+    # Only half of the stage is done
+    assert tracker.stragglers() == []
+
+    clock.now = 4.0
+    tracker.task_finished("task_2")
+    # The median runtime is 2s, so task_3 is a straggler after 3s
+    clock.now = 3.0
+    assert tracker.stragglers() == []
+    clock.now = 3.5
+    assert tracker.stragglers() == ["task_3"]
+
+    tracker.task_speculated("task_3")
+    assert tracker.stragglers() == []
+
+
+def test_speculation_tracker_respects_min_runtime():
+    clock = FakeClock()
+    tracker = SpeculationTracker(quantile=0.5, multiplier=1.5, min_runtime_s=10.0, clock=clock)
+    tracker.task_started("fast", stage_id=0)
+    tracker.task_started("slow", stage_id=0)
+    clock.now = 1.0
+    tracker.task_finished("fast")
+
+    clock.now = 5.0
+    assert tracker.stragglers() == []
+    clock.now = 11.0
+    assert tracker.stragglers() == ["slow"]
+
+
+def test_speculation_tracker_disabled_by_default():
+    assert SpeculationTracker.from_config(daft.context.get_context().daft_execution_config) is None
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() != "ray", reason="Needs to run on Ray runner")
+def test_speculative_execution_finishes_stragglers():
+    with tempfile.TemporaryDirectory() as marker_dir:
+
+        @daft.udf(return_dtype=daft.DataType.int64())
+        def slow_once(values):
+            values = values.to_pylist()
+            # The first attempt of the task for the first partition hangs, and its speculative copy is fast
+            marker = os.path.join(marker_dir, "started")
+            if values[0] == 0 and not os.path.exists(marker):
+                open(marker, "w").close()
+                time.sleep(600)
+            return values
+
+        with daft.execution_config_ctx(
+            enable_speculative_execution=True,
+            speculation_quantile=0.5,
+            speculation_min_runtime_ms=1000,
+        ):
+            df = daft.from_pydict({"x": list(range(8))}).into_partitions(4)
+            df = df.with_column("y", slow_once(df["x"])).sort("x")
+            start = time.monotonic()
+            assert df.to_pydict()["y"] == list(range(8))
+            assert time.monotonic() - start < 300
@@ -0,0 +1,99 @@
+from __future__ import annotations
+
+import os
+import tempfile
+from functools import partial
+
+import pytest
+
+import daft
+from daft.exceptions import ReadTimeoutError
+from daft.io._generator import read_generator
+from daft.recordbatch import RecordBatch
+from daft.runners.retry import PartitionTaskFailedError, TaskRetries, TaskRetryPolicy
+from tests.conftest import get_tests_daft_runner_name
+
+SCHEMA = daft.Schema._from_field_name_and_types([("x", daft.DataType.int64())])
+
+
+class FakeClock:
+    def __init__(self) -> None:
+        self.now = 0.0
+
+    def __call__(self) -> float:
+        return self.now
+
+
+def test_retry_policy_backs_off_exponentially():
+    policy = TaskRetryPolicy(max_attempts=10, initial_backoff_s=1.0, max_backoff_s=5.0)
+    assert [policy.backoff_s(n) for n in range(1, 6)] == [1.0, 2.0, 4.0, 5.0, 5.0]
+
+
+def test_retry_policy_only_retries_transient_errors():
+    policy = TaskRetryPolicy(max_attempts=3, initial_backoff_s=1.0, max_backoff_s=5.0)
+    assert policy.should_retry(ReadTimeoutError("timed out"), 1)
+    assert policy.should_retry(ConnectionResetError(), 2)
+    assert not policy.should_retry(ConnectionResetError(), 3)
+    assert not policy.should_retry(ValueError("bad data"), 1)
+
+
+def test_task_retries_wait_out_backoff():
+    clock = FakeClock()
+    retries = TaskRetries(TaskRetryPolicy(max_attempts=3, initial_backoff_s=1.0, max_backoff_s=5.0), clock=clock)
+
+    assert retries.task_failed("task_0", ConnectionError())
+    assert retries.seconds_until_next_retry() == 1.0
+    assert retries.due_retries() == []
+    clock.now = 1.0
+    assert retries.due_retries() == ["task_0"]
+    assert retries.seconds_until_next_retry() is None
+
+    assert retries.task_failed("task_0", ConnectionError())
+    assert retries.seconds_until_next_retry() == 2.0
+    clock.now = 3.0
+    assert retries.due_retries() == ["task_0"]
+
+    assert not retries.task_failed("task_0", ConnectionError())
+    assert [attempt.attempt for attempt in retries.attempts("task_0")] == [1, 2, 3]
+    retries.task_finished("task_0")
+    assert retries.attempts("task_0") == []
+
+
+def test_task_retries_disabled_with_single_attempt():
+    with daft.execution_config_ctx(task_max_attempts=1):
+        assert TaskRetryPolicy.from_config(daft.context.get_context().daft_execution_config) is None
+
+
+def _flaky_generator(marker_dir: str, num_partitions: int, fail_every_attempt: bool):
+    def generate(i: int):
+        # The first attempt of the task for the first partition fails, or every attempt if `fail_every_attempt`
+        marker = os.path.join(marker_dir, "failed")
+        if i == 0 and (fail_every_attempt or not os.path.exists(marker)):
+            open(marker, "w").close()
+            raise ReadTimeoutError("timed out reading from object store")
+        yield RecordBatch.from_pydict({"x": [i]})
+
+    for i in range(num_partitions):
+        yield partial(generate, i)
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() != "ray", reason="Needs to run on Ray runner")
+def test_task_with_transient_error_is_retried():
+    with tempfile.TemporaryDirectory() as marker_dir, daft.execution_config_ctx(task_retry_initial_backoff_ms=10):
+        df = read_generator(_flaky_generator(marker_dir, 4, fail_every_attempt=False), SCHEMA)
+        assert sorted(df.to_pydict()["x"]) == [0, 1, 2, 3]
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() != "ray", reason="Needs to run on Ray runner")
+def test_task_failing_every_attempt_reports_its_attempts():
+    with tempfile.TemporaryDirectory() as marker_dir, daft.execution_config_ctx(
+        task_max_attempts=2, task_retry_initial_backoff_ms=10
+    ):
+        df = read_generator(_flaky_generator(marker_dir, 4, fail_every_attempt=True), SCHEMA)
+        with pytest.raises(PartitionTaskFailedError) as exc_info:
+            df.collect()
+
+    report = exc_info.value.report
+    assert [attempt.attempt for attempt in report.attempts] == [1, 2]
+    assert "ReadTimeoutError" in report.attempts[-1].error
+    assert len(report.inputs) == 1
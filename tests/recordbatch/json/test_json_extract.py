@@ -0,0 +1,54 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import DataType
+from daft.expressions import col
+from daft.recordbatch import MicroPartition
+
+DATA = ['{"a": {"b": "x", "c": [1, 2, 3]}}', '{"a": {"b": 2, "c": []}}', '{"a": null}', None]
+
+
+@pytest.mark.parametrize(
+    "path, expected",
+    [
+        pytest.param("$.a.b", ["x", "2", None, None], id="member"),
+        pytest.param("$['a']['b']", ["x", "2", None, None], id="bracket member"),
+        pytest.param("$.a.c", ["[1,2,3]", "[]", None, None], id="array"),
+        pytest.param("$.a.c[1]", ["2", None, None, None], id="index"),
+        pytest.param("$.a.c[-1]", ["3", None, None, None], id="negative index"),
+        pytest.param("$.a.c[*]", ["[1,2,3]", None, None, None], id="wildcard"),
+        pytest.param("$..b", ['["x"]', "[2]", None, None], id="descendant"),
+        pytest.param("/a/c/0", ["1", None, None, None], id="json pointer"),
+        pytest.param("$.missing", [None, None, None, None], id="missing"),
+    ],
+)
+def test_json_extract(path, expected):
+    mp = MicroPartition.from_pydict({"col": DATA})
+    result = mp.eval_expression_list([col("col").json.extract(path)])
+    assert result.to_pydict() == {"col": expected}
+
+
+def test_json_extract_typed():
+    mp = MicroPartition.from_pydict({"col": ['{"n": 1, "ok": true}', '{"n": 2, "ok": false}', "{}"]})
+    result = mp.eval_expression_list(
+        [
+            col("col").json.extract("$.n", DataType.int64()).alias("n"),
+            col("col").json.extract("$.ok", DataType.bool()).alias("ok"),
+        ]
+    )
+    assert result.schema()["n"].dtype == DataType.int64()
+    assert result.to_pydict() == {"n": [1, 2, None], "ok": [True, False, None]}
+
+
+def test_json_extract_invalid_path():
+    mp = MicroPartition.from_pydict({"col": DATA})
+    with pytest.raises(Exception, match="Invalid JSONPath"):
+        mp.eval_expression_list([col("col").json.extract("a.b")])
+
+
+def test_json_extract_sql():
+    df = daft.from_pydict({"col": DATA})
+    actual = daft.sql("SELECT json_extract(col, '$.a.c[0]') AS c FROM df").to_pydict()
+    assert actual == {"c": ["1", None, None, None]}
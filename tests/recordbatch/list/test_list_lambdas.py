@@ -0,0 +1,67 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import accumulator, col, element
+from daft.datatype import DataType
+from daft.recordbatch import MicroPartition
+
+table = MicroPartition.from_pydict({"a": [[1, 2, 3], None, [], [4, None, 5]]})
+
+
+def test_list_map():
+    result = table.eval_expression_list([col("a").list.map(element() * 2)])
+    assert result.to_pydict() == {"a": [[2, 4, 6], None, [], [8, None, 10]]}
+
+    result = table.eval_expression_list([col("a").list.map(element().cast(DataType.string()))])
+    assert result.schema()["a"].dtype == DataType.list(DataType.string())
+    assert result.to_pydict() == {"a": [["1", "2", "3"], None, [], ["4", None, "5"]]}
+
+
+def test_list_map_literal_body():
+    result = table.eval_expression_list([col("a").list.map(daft.lit("x"))])
+    assert result.to_pydict() == {"a": [["x", "x", "x"], None, [], ["x", "x", "x"]]}
+
+
+def test_list_filter():
+    result = table.eval_expression_list([col("a").list.filter(element() % 2 == 1)])
+    assert result.to_pydict() == {"a": [[1, 3], None, [], [5]]}
+
+
+def test_list_reduce():
+    result = table.eval_expression_list([col("a").list.reduce(accumulator() + element())])
+    # nulls propagate through the accumulator
+    assert result.to_pydict() == {"a": [6, None, None, None]}
+
+    strings = MicroPartition.from_pydict({"s": [["a", "b", "c"], ["d"]]})
+    result = strings.eval_expression_list([col("s").list.reduce(accumulator() + "-" + element())])
+    assert result.to_pydict() == {"s": ["a-b-c", "d"]}
+
+
+def test_fixed_size_list_lambdas():
+    fixed = MicroPartition.from_pydict({"a": [[1, 2], [3, 4]]}).eval_expression_list(
+        [col("a").cast(DataType.fixed_size_list(DataType.int64(), 2))]
+    )
+    result = fixed.eval_expression_list([col("a").list.map(element() + 1)])
+    assert result.to_pydict() == {"a": [[2, 3], [4, 5]]}
+    result = fixed.eval_expression_list([col("a").list.reduce(accumulator() * element())])
+    assert result.to_pydict() == {"a": [2, 12]}
+
+
+def test_list_lambda_bodies_only_see_elements():
+    df = daft.from_pydict({"a": [[1, 2]], "b": [1]})
+    with pytest.raises(Exception, match="element"):
+        df.select(col("a").list.map(element() + col("b"))).collect()
+    with pytest.raises(Exception, match="boolean"):
+        df.select(col("a").list.filter(element() + 1)).collect()
+
+
+def test_list_lambdas_in_dataframe():
+    df = daft.from_pydict({"xs": [[1, 2, 3], [4, 5]], "ys": [["a"], []]}).into_partitions(2)
+    result = df.select(
+        col("xs").list.map(element() * element()).alias("squares"),
+        col("xs").list.filter(element() > 2).alias("big"),
+        col("xs").list.reduce(accumulator() + element()).alias("total"),
+    ).to_pydict()
+    assert result == {"squares": [[1, 4, 9], [16, 25]], "big": [[3], [4, 5]], "total": [6, 9]}
@@ -0,0 +1,57 @@
+from __future__ import annotations
+
+import pytest
+
+from daft import DataType
+from daft.expressions import col, lit
+from daft.recordbatch import MicroPartition
+
+
+@pytest.fixture
+def table():
+    return MicroPartition.from_pydict(
+        {
+            "col": [{"foo": 1, "bar": "a"}, {"foo": None, "bar": "b"}, None],
+            "baz": [1.5, 2.5, 3.5],
+        }
+    )
+
+
+def test_struct_with_field(table):
+    result = table.eval_expression_list(
+        [
+            col("col").struct.with_field("baz", col("baz")).alias("added"),
+            col("col").struct.with_field("foo", lit("x")).alias("replaced"),
+        ]
+    )
+    assert result.schema()["added"].dtype == DataType.struct(
+        {"foo": DataType.int64(), "bar": DataType.string(), "baz": DataType.float64()}
+    )
+    assert result.schema()["replaced"].dtype == DataType.struct({"foo": DataType.string(), "bar": DataType.string()})
+    assert result.to_pydict() == {
+        "added": [{"foo": 1, "bar": "a", "baz": 1.5}, {"foo": None, "bar": "b", "baz": 2.5}, None],
+        "replaced": [{"foo": "x", "bar": "a"}, {"foo": "x", "bar": "b"}, None],
+    }
+
+
+def test_struct_drop(table):
+    result = table.eval_expression_list([col("col").struct.drop("foo")])
+    assert result.to_pydict() == {"col": [{"bar": "a"}, {"bar": "b"}, None]}
+
+    with pytest.raises(Exception, match="not found"):
+        table.eval_expression_list([col("col").struct.drop("missing")])
+    with pytest.raises(Exception, match="every field"):
+        table.eval_expression_list([col("col").struct.drop("foo", "bar")])
+
+
+def test_struct_rename(table):
+    result = table.eval_expression_list([col("col").struct.rename({"foo": "qux"})])
+    assert result.to_pydict() == {"col": [{"qux": 1, "bar": "a"}, {"qux": None, "bar": "b"}, None]}
+
+    with pytest.raises(Exception, match="duplicate"):
+        table.eval_expression_list([col("col").struct.rename({"foo": "bar"})])
+
+
+def test_struct_with_field_non_struct(table):
+    with pytest.raises(Exception, match="struct"):
+        table.eval_expression_list([col("baz").struct.with_field("a", lit(1))])
@@ -67,3 +67,30 @@ def test_bitwise_scalar(op, data, scalar):
     result = table.eval_expression_list([op(col("data"), scalar)])
     expected = [op(d, scalar) if d is not None and scalar is not None else None for d in data]
     assert result.to_pydict()["data"] == expected
+
+
+@pytest.mark.parametrize("expression", [~col("data"), col("data").bitwise_not()])
+def test_bitwise_not(expression):
+    table = MicroPartition.from_pydict({"data": [0b1100, None, -1, 0]})
+
+    result = table.eval_expression_list([expression])
+    assert result.to_pydict()["data"] == [~0b1100, None, 0, -1]
+
+
+def test_bitwise_reflected_operators():
+    table = MicroPartition.from_pydict({"data": [0b1100, None, 0b1001]})
+
+    result = table.eval_expression_list(
+        [
+            (0b1010 & col("data")).alias("and"),
+            (0b1010 | col("data")).alias("or"),
+            (0b1010 ^ col("data")).alias("xor"),
+            (1 << col("data")).alias("shl"),
+            (4096 >> col("data")).alias("shr"),
+        ]
+    ).to_pydict()
+    assert result["and"] == [0b1000, None, 0b1000]
+    assert result["or"] == [0b1110, None, 0b1011]
+    assert result["xor"] == [0b0110, None, 0b0011]
+    assert result["shl"] == [4096, None, 512]
+    assert result["shr"] == [1, None, 8]
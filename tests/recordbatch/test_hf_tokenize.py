@@ -0,0 +1,96 @@
+from __future__ import annotations
+
+import json
+
+import pytest
+
+import daft
+from daft import col
+from daft.exceptions import DaftCoreException
+
+tokenizers = pytest.importorskip("tokenizers")
+
+CORPUS = [
+    "hello world",
+    "Hello, world! How are you doing today?",
+    "The quick brown fox jumps over the lazy dog.",
+    "üțf-8 ťèştìňġ and some emoji 🦀🐍",
+    "123 234 345 4567",
+] * 10
+
+TEST_DATA = [
+    "hello world",
+    "Hello, world!",
+    "",
+    None,
+    "A bit of a longer sentence with words the tokenizer has never seen",
+    "      ",
+    "üțf-8 ťèştìňġ",
+    "<|endoftext|> marks the end",
+]
+
+
+@pytest.fixture(scope="module", params=[False, True], ids=["no_prefix_space", "prefix_space"])
+def tokenizer(request, tmp_path_factory) -> tuple[tokenizers.Tokenizer, str]:
+    tok = tokenizers.Tokenizer(tokenizers.models.BPE())
+    tok.pre_tokenizer = tokenizers.pre_tokenizers.ByteLevel(add_prefix_space=request.param)
+    tok.decoder = tokenizers.decoders.ByteLevel()
+    trainer = tokenizers.trainers.BpeTrainer(
+        vocab_size=400,
+        special_tokens=["<|endoftext|>"],
+        initial_alphabet=tokenizers.pre_tokenizers.ByteLevel.alphabet(),
+    )
+    tok.train_from_iterator(CORPUS, trainer=trainer)
+    path = tmp_path_factory.mktemp("tokenizer") / "tokenizer.json"
+    tok.save(str(path))
+    return tok, str(path)
+
+
+def test_tokenize(tokenizer) -> None:
+    tok, path = tokenizer
+    df = daft.from_pydict({"a": TEST_DATA})
+    result = df.select(col("a").str.tokenize(path)).to_pydict()["a"]
+    expected = [None if t is None else tok.encode(t).ids for t in TEST_DATA]
+    assert result == expected
+
+
+def test_tokenize_directory(tokenizer) -> None:
+    tok, path = tokenizer
+    directory = path.rsplit("/", 1)[0]
+    df = daft.from_pydict({"a": ["hello world"]})
+    assert df.select(col("a").str.tokenize(directory)).to_pydict()["a"] == [tok.encode("hello world").ids]
+
+
+def test_tokenize_without_special_tokens(tokenizer) -> None:
+    tok, path = tokenizer
+    df = daft.from_pydict({"a": ["<|endoftext|>"]})
+    ids = df.select(col("a").str.tokenize(path, use_special_tokens=False)).to_pydict()["a"][0]
+    assert ids != [tok.token_to_id("<|endoftext|>")]
+    assert tok.decode(ids) == tok.decode(tok.encode("<|endoftext|>").ids, skip_special_tokens=False)
+
+
+def test_detokenize(tokenizer) -> None:
+    tok, path = tokenizer
+    df = daft.from_pydict({"a": TEST_DATA})
+    result = df.select(col("a").str.tokenize(path).list.detokenize(path)).to_pydict()["a"]
+    expected = [None if t is None else tok.decode(tok.encode(t).ids, skip_special_tokens=False) for t in TEST_DATA]
+    assert result == expected
+
+
+def test_detokenize_bad_token(tokenizer) -> None:
+    _, path = tokenizer
+    df = daft.from_pydict({"a": [[1, 2, 1_000_000]]})
+    with pytest.raises(DaftCoreException, match="bad token"):
+        df.select(col("a").list.detokenize(path)).collect()
+
+
+def test_tokenize_unsupported_tokenizer(tmp_path) -> None:
+    tok = tokenizers.Tokenizer(tokenizers.models.WordPiece({"[UNK]": 0, "hello": 1}, unk_token="[UNK]"))
+    tok.pre_tokenizer = tokenizers.pre_tokenizers.Whitespace()
+    path = tmp_path / "tokenizer.json"
+    tok.save(str(path))
+    assert json.loads(path.read_text())["model"]["type"] == "WordPiece"
+
+    df = daft.from_pydict({"a": ["hello"]})
+    with pytest.raises(DaftCoreException, match="Only byte-level BPE"):
+        df.select(col("a").str.tokenize(str(path))).collect()
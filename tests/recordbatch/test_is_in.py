@@ -171,3 +171,11 @@ def test_table_expr_is_in_items_invalid_input() -> None:
 
     with pytest.raises(ValueError, match="Creating a Series from data of type"):
         daft_recordbatch.eval_expression_list([col("input").is_in(1)])
+
+
+def test_table_expr_is_in_many_items() -> None:
+    daft_recordbatch = MicroPartition.from_pydict({"input": [0, 99_999, 100_000, -1, None]})
+    is_in = col("input").is_in(list(range(100_000)))
+    daft_recordbatch = daft_recordbatch.eval_expression_list([is_in])
+
+    assert daft_recordbatch.to_pydict()["input"] == [True, True, False, False, None]
@@ -903,6 +903,16 @@ def test_global_concat_aggs_pyobj() -> None:
     assert concatted.to_pydict()["concat"] == [expected]
 
 
+def test_global_concat_aggs_fixed_size_list() -> None:
+    input = [[1, 2], None, [3, 4]]
+    table = MicroPartition.from_pydict({"input": input}).eval_expression_list(
+        [col("input").cast(DataType.fixed_size_list(DataType.int64(), 2))]
+    )
+    concatted = table.agg([col("input").alias("concat").agg_concat()])
+    assert concatted.get_column("concat").datatype() == DataType.list(DataType.int64())
+    assert concatted.to_pydict() == {"concat": [[1, 2, 3, 4]]}
+
+
 @pytest.mark.parametrize(
     "dtype", daft_nonnull_types + daft_null_types, ids=[f"{_}" for _ in daft_nonnull_types + daft_null_types]
 )
@@ -26,9 +26,32 @@ from daft.recordbatch import MicroPartition
             ["aaa", "ab", "c"],
             ["b", "bb", "c"],
         ),
+        # capture groups and max replacements
+        (
+            col("col").str.replace(r"(\w+)@(\w+)", "$2 at ${1}_", regex=True),
+            ["me@home", "a@b c@d", "none"],
+            ["home at me_", "b at a_ d at c_", "none"],
+        ),
+        (
+            col("col").str.replace(r"(?P<x>a)", "[$x]", regex=True, max_replacements=2),
+            ["aaa", "ab", "c"],
+            ["[a][a]a", "[a]b", "c"],
+        ),
+        (col("col").str.replace("a", "b", max_replacements=1), ["aaa", "ab", "c"], ["baa", "bb", "c"]),
+        (col("col").str.replace("a", "b", max_replacements=0), ["aaa", "ab", "c"], ["aaa", "ab", "c"]),
+        (
+            col("col").str.replace(col("col").str.left(1), "$$", regex=True),
+            ["aaa", "bab", "aaa"],
+            ["$$$", "$a$", "$$$"],
+        ),
     ],
 )
 def test_series_utf8_replace(expr, data, expected) -> None:
     table = MicroPartition.from_pydict({"col": data, "emptystrings": [""] * len(data)})
     result = table.eval_expression_list([expr])
     assert result.to_pydict() == {"col": expected}
+
+
+def test_series_utf8_replace_negative_max_replacements() -> None:
+    with pytest.raises(ValueError, match="non-negative"):
+        col("col").str.replace("a", "b", max_replacements=-1)
@@ -1163,6 +1163,25 @@ def test_series_cast_fixed_size_list_to_list() -> None:
     assert casted.to_pylist() == [[1, 2], [3, 4], [5, 6]]
 
 
+def test_series_cast_fixed_size_list_to_embedding() -> None:
+    data = Series.from_pylist([[1, 2], None, [5, 6]]).cast(DataType.fixed_size_list(DataType.int64(), 2))
+    target_dtype = DataType.embedding(DataType.float32(), 2)
+    casted = data.cast(target_dtype)
+    assert casted.datatype() == target_dtype
+    assert casted.to_pylist()[1] is None
+    assert casted.list.length().to_pylist() == [2, None, 2]
+
+
+def test_series_cast_fixed_size_list_to_map() -> None:
+    struct_dtype = DataType.struct({"key": DataType.string(), "value": DataType.int64()})
+    data = Series.from_pylist([[{"key": "a", "value": 1}], [{"key": "b", "value": 2}]]).cast(
+        DataType.fixed_size_list(struct_dtype, 1)
+    )
+    casted = data.cast(DataType.map(DataType.string(), DataType.int64()))
+    assert casted.datatype() == DataType.map(DataType.string(), DataType.int64())
+    assert casted.to_pylist() == [[("a", 1)], [("b", 2)]]
+
+
 ### Sparse ###
 
 
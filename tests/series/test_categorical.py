@@ -0,0 +1,105 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pytest
+
+import daft
+from daft import DataType, col
+from daft.series import Series
+
+
+def test_cast_to_categorical_infers_categories() -> None:
+    s = Series.from_pylist(["b", None, "a", "b", "c"], name="s")
+    categorical = s.cast_to_categorical()
+    assert categorical.datatype() == DataType.categorical(["a", "b", "c"])
+    assert categorical.datatype().categories == ["a", "b", "c"]
+    assert categorical.to_pylist() == ["b", None, "a", "b", "c"]
+    assert categorical.cast(DataType.uint32()).to_pylist() == [1, None, 0, 1, 2]
+    assert categorical.cast(DataType.string()).to_pylist() == s.to_pylist()
+
+
+def test_cast_to_categorical_with_categories() -> None:
+    s = Series.from_pylist(["b", "a", "d"], name="s")
+    categorical = s.cast_to_categorical(["b", "a", "c"])
+    assert categorical.to_pylist() == ["b", "a", None]
+    assert categorical.cast(DataType.uint32()).to_pylist() == [0, 1, None]
+
+
+def test_categorical_duplicate_categories() -> None:
+    with pytest.raises(ValueError):
+        DataType.categorical(["a", "a"])
+
+
+def test_categorical_arrow_round_trip() -> None:
+    arrow = pa.array(["x", "y", None, "x"]).dictionary_encode()
+    s = Series.from_arrow(arrow, name="s")
+    assert s.datatype() == DataType.categorical(["x", "y"])
+    assert s.to_pylist() == ["x", "y", None, "x"]
+
+    exported = s.to_arrow()
+    assert pa.types.is_dictionary(exported.type)
+    assert exported.dictionary.to_pylist() == ["x", "y"]
+    assert exported.indices.to_pylist() == [0, 1, None, 0]
+
+
+def test_categorical_concat_keeps_encoding() -> None:
+    left = Series.from_arrow(pa.array(["b", "a"]).dictionary_encode(), name="s")
+    right = Series.from_arrow(pa.array(["c", None, "b"]).dictionary_encode(), name="s")
+    concatted = Series.concat([left, right])
+    assert concatted.datatype() == DataType.categorical(["b", "a", "c"])
+    assert concatted.cast(DataType.uint32()).to_pylist() == [0, 1, 2, None, 0]
+    assert concatted.to_pylist() == ["b", "a", "c", None, "b"]
+
+
+def test_categorical_union_keeps_encoding() -> None:
+    left = daft.from_pydict({"s": ["x", "y"]}).with_column("s", col("s").cast_to_categorical(["x", "y"]))
+    right = daft.from_pydict({"s": ["z", "x"]}).with_column("s", col("s").cast_to_categorical(["z", "x"]))
+    unioned = left.union_all(right)
+    assert unioned.schema()["s"].dtype == DataType.categorical(["x", "y", "z"])
+    assert sorted(unioned.to_pydict()["s"]) == ["x", "x", "y", "z"]
+
+
+def test_categorical_sort_follows_categories() -> None:
+    s = Series.from_pylist(["low", "high", "mid", None], name="s").cast_to_categorical(["low", "mid", "high"])
+    assert s.sort().to_pylist() == ["low", "mid", "high", None]
+
+
+def test_categorical_dataframe_ops() -> None:
+    categories = ["red", "green", "blue"]
+    df = daft.from_pydict({"color": ["red", "blue", "red", "green", None], "n": [1, 2, 3, 4, 5]}).into_partitions(2)
+    df = df.with_column("color", col("color").cast_to_categorical(categories))
+    assert df.schema()["color"].dtype == DataType.categorical(categories)
+
+    grouped = df.groupby("color").agg(col("n").sum()).sort("color").to_pydict()
+    assert grouped == {"color": ["red", "green", "blue", None], "n": [4, 4, 2, 5]}
+
+    filtered = df.where(col("color") == "red").to_pydict()
+    assert filtered["n"] == [1, 3]
+
+    other = daft.from_pydict({"color": ["red", "blue"], "name": ["r", "b"]})
+    other = other.with_column("color", col("color").cast_to_categorical(categories))
+    joined = df.join(other, on="color").sort("n").to_pydict()
+    assert joined == {"color": ["red", "blue", "red"], "n": [1, 2, 3], "name": ["r", "b", "r"]}
+
+
+def test_expression_cast_to_categorical_requires_list() -> None:
+    with pytest.raises(TypeError):
+        col("a").cast_to_categorical("abc")
+
+
+def test_intern_strings() -> None:
+    df = daft.from_pydict({"country": ["US", "FR", None, "US", "DE"], "n": [1, 2, 3, 4, 5]}).into_partitions(3)
+    interned = df.intern_strings("country")
+    assert interned.schema()["country"].dtype == DataType.categorical(["DE", "FR", "US"])
+    assert interned.sort("n").to_pydict() == df.sort("n").to_pydict()
+
+    grouped = interned.groupby("country").agg(col("n").sum()).to_pydict()
+    assert dict(zip(grouped["country"], grouped["n"])) == {"US": 5, "FR": 2, None: 3, "DE": 5}
+
+
+def test_intern_strings_validates_columns() -> None:
+    df = daft.from_pydict({"country": ["US", "FR"], "n": [1, 2]})
+    with pytest.raises(ValueError, match="Only string columns"):
+        df.intern_strings("n")
+    with pytest.raises(ValueError, match="more than the 1"):
+        df.intern_strings("country", max_categories=1)
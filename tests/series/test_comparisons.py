@@ -891,3 +891,24 @@ def test_numeric_and_string_compare_raises_error(op):
 
     with pytest.raises(ValueError, match="Cannot perform comparison on types:"):
         op(right, left)
+
+
+def test_compare_fixed_size_lists():
+    dtype = DataType.fixed_size_list(DataType.int64(), 2)
+    left = Series.from_pylist([[1, 2], [1, 3], None, [2, 0]]).cast(dtype)
+    right = Series.from_pylist([[1, 2], [1, 2], None, [1, 9]]).cast(dtype)
+
+    assert (left == right).to_pylist() == [True, False, None, False]
+    assert (left != right).to_pylist() == [False, True, None, True]
+    assert (left < right).to_pylist() == [False, False, None, False]
+    assert (left >= right).to_pylist() == [True, True, None, True]
+
+    scalar = Series.from_pylist([[1, 3]]).cast(dtype)
+    assert (left == scalar).to_pylist() == [False, True, None, False]
+
+
+def test_compare_embeddings():
+    dtype = DataType.embedding(DataType.float32(), 2)
+    left = Series.from_pylist([[1.0, 2.0], [0.5, 0.5]]).cast(dtype)
+    right = Series.from_pylist([[1.0, 2.0], [0.5, 1.0]]).cast(dtype)
+    assert (left == right).to_pylist() == [True, False]
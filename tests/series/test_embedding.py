@@ -4,6 +4,7 @@ import copy
 
 import numpy as np
 import pandas as pd
+import pytest
 
 from daft.datatype import DataType, get_super_ext_type
 from daft.series import Series
@@ -32,3 +33,64 @@ def test_embedding_arrow_round_trip():
     t_copy = copy.deepcopy(t)
     assert t_copy.datatype() == t.datatype()
     np.testing.assert_equal(t_copy.to_pylist(), t.to_pylist())
+
+
+def test_embedding_dlpack_is_zero_copy():
+    data = np.arange(12, dtype=np.float32).reshape(4, 3)
+    s = Series.from_pylist(list(data), pyobj="force").cast(DataType.embedding(DataType.float32(), 3)).slice(1, 4)
+
+    exported = np.from_dlpack(s)
+    np.testing.assert_equal(exported, data[1:])
+    assert s.__dlpack_device__() == (1, 0)
+
+    # the exported array shares memory with the series
+    assert np.from_dlpack(s).ctypes.data == exported.ctypes.data
+
+
+def test_embedding_dlpack_copies_nulls():
+    data = [[1, 2], None, [5, 6]]
+    s = Series.from_pylist(data, pyobj="force").cast(DataType.embedding(DataType.int64(), 2))
+
+    np.testing.assert_equal(np.from_dlpack(s), np.array([[1, 2], [0, 0], [5, 6]]))
+    with pytest.raises(BufferError):
+        s.__dlpack__(copy=False)
+
+
+def _embeddings(data, dtype=DataType.float32()) -> Series:
+    return Series.from_pylist(data, pyobj="force").cast(DataType.embedding(dtype, 2))
+
+
+def test_embedding_dot_product():
+    source = _embeddings([[3, 4], [1, 0], None])
+    query = _embeddings([[0, 2]])
+
+    assert source.embedding.dot_product(query).to_pylist() == [8.0, 0.0, None]
+    assert query.embedding.dot_product(source).to_pylist() == [8.0, 0.0, None]
+    assert source.embedding.dot_product(source).to_pylist() == [25.0, 1.0, None]
+
+    with pytest.raises(ValueError):
+        source.embedding.dot_product(_embeddings([[1, 2], [3, 4]]))
+
+
+@pytest.mark.parametrize("dtype", [DataType.int8(), DataType.float32(), DataType.float64()])
+def test_embedding_cosine_similarity(dtype):
+    data = [[3, 4], [1, 0], [0, 0], None]
+    source = _embeddings(data, dtype)
+    query = _embeddings([[1, 1]], dtype)
+
+    result = source.embedding.cosine_similarity(query)
+    assert result.datatype() == DataType.float64()
+    expected = [
+        None if x is None or not any(x) else np.dot(x, [1, 1]) / (np.linalg.norm(x) * np.linalg.norm([1, 1]))
+        for x in data
+    ]
+    np.testing.assert_allclose(
+        np.array(result.to_pylist(), dtype=np.float64), np.array(expected, dtype=np.float64), rtol=1e-6
+    )
+
+    normalized = source.embedding.normalize()
+    assert normalized.to_pylist()[2:] == [None, None]
+    result = normalized.embedding.cosine_similarity(query.embedding.normalize(), normalized=True)
+    np.testing.assert_allclose(
+        np.array(result.to_pylist(), dtype=np.float64), np.array(expected, dtype=np.float64), rtol=1e-6
+    )
@@ -340,6 +340,25 @@ def test_image_decode_pil(mode, file_format):
     np.testing.assert_equal(out, expected_arrs)
 
 
+@pytest.mark.parametrize("mode", ["RGB", "RGBA"])
+def test_image_encode_decode_webp(mode):
+    shape = (4, 4, MODE_TO_NUM_CHANNELS[mode])
+    arr = np.arange(np.prod(shape)).reshape(shape).astype(np.uint8)
+    img_bytes = io.BytesIO()
+    Image.fromarray(arr, mode=mode).save(img_bytes, "webp", lossless=True)
+    s = Series.from_arrow(pa.array([img_bytes.getvalue(), None], type=pa.binary()))
+
+    t = s.image.decode()
+    np.testing.assert_equal(t.cast(DataType.python()).to_pylist(), [arr, None])
+
+    # WebP is encoded losslessly.
+    u = t.image.encode("WEBP")
+    pil_decoded_imgs = [
+        np.asarray(Image.open(io.BytesIO(bytes_))) if bytes_ is not None else None for bytes_ in u.to_pylist()
+    ]
+    np.testing.assert_equal(pil_decoded_imgs, [arr, None])
+
+
 @pytest.mark.parametrize("output_mode", ["L", "LA", "RGB", "RGBA"])
 def test_image_decode_pil_multi_mode(output_mode):
     imgs = []
@@ -0,0 +1,51 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pytest
+
+from daft import DataType
+from daft.series import Series
+
+
+@pytest.fixture
+def maps() -> Series:
+    data = pa.array(
+        [[("a", 1), ("b", None)], None, [], [("c", 3)]],
+        type=pa.map_(pa.large_string(), pa.int64()),
+    )
+    return Series.from_arrow(data, name="m")
+
+
+def test_map_keys(maps):
+    keys = maps.map.keys()
+    assert keys.name() == "m"
+    assert keys.datatype() == DataType.list(DataType.string())
+    assert keys.to_pylist() == [["a", "b"], None, [], ["c"]]
+
+
+def test_map_values(maps):
+    values = maps.map.values()
+    assert values.datatype() == DataType.list(DataType.int64())
+    assert values.to_pylist() == [[1, None], None, [], [3]]
+
+
+def test_map_get(maps):
+    assert maps.map.get(Series.from_pylist(["c"])).to_pylist() == [None, None, None, 3]
+
+
+def test_map_filter_take_concat(maps):
+    filtered = maps.filter(Series.from_pylist([True, False, False, True]))
+    assert filtered.datatype() == maps.datatype()
+    assert filtered.map.keys().to_pylist() == [["a", "b"], ["c"]]
+
+    taken = maps.take(Series.from_pylist([3, 1]))
+    assert taken.map.values().to_pylist() == [[3], None]
+
+    concatenated = Series.concat([maps, taken])
+    assert concatenated.datatype() == maps.datatype()
+    assert concatenated.map.keys().to_pylist() == [["a", "b"], None, [], ["c"], ["c"], None]
+
+
+def test_map_keys_on_non_map():
+    with pytest.raises(ValueError):
+        Series.from_pylist([1, 2]).map.keys()
@@ -0,0 +1,44 @@
+from __future__ import annotations
+
+import pytest
+
+from daft import DataType
+from daft.series import Series
+
+
+@pytest.mark.parametrize(
+    "method,expected",
+    [
+        ("min", [3, None, 1, 3, 2, 3]),
+        ("max", [5, None, 1, 5, 2, 5]),
+        ("dense", [3, None, 1, 3, 2, 3]),
+        ("ordinal", [3, None, 1, 4, 2, 5]),
+    ],
+)
+def test_rank_methods(method, expected):
+    s = Series.from_pylist([3, None, 1, 3, 2, 3])
+    ranks = s.rank(method)
+    assert ranks.datatype() == DataType.uint64()
+    assert ranks.to_pylist() == expected
+
+
+def test_rank_average():
+    s = Series.from_pylist(["b", "a", "b", None])
+    ranks = s.rank("average")
+    assert ranks.datatype() == DataType.float64()
+    assert ranks.to_pylist() == [2.5, 1.0, 2.5, None]
+
+
+def test_rank_descending_and_nulls():
+    s = Series.from_pylist([3, None, 1, 3])
+    assert s.rank("dense", descending=True).to_pylist() == [1, None, 2, 1]
+    assert s.rank("min", nulls="first").to_pylist() == [3, 1, 2, 3]
+    assert s.rank("min", nulls="last").to_pylist() == [2, 4, 1, 2]
+
+
+def test_rank_invalid_arguments():
+    s = Series.from_pylist([1, 2])
+    with pytest.raises(ValueError):
+        s.rank("foo")
+    with pytest.raises(ValueError):
+        s.rank(nulls="middle")
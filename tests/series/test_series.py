@@ -12,6 +12,7 @@ import pyarrow as pa
 import pytest
 
 from daft import DataType, Series
+from daft.utils import get_arrow_version
 from tests.series import ARROW_FLOAT_TYPES, ARROW_INT_TYPES, ARROW_STRING_TYPES
 
 
@@ -256,6 +257,33 @@ def test_series_numpy_datetime64_date():
         assert s4.to_pylist() == py_dates
 
 
+@pytest.mark.parametrize("dtype", [np.int8, np.int32, np.uint16, np.uint64, np.float32, np.float64])
+def test_series_from_numpy_is_zero_copy(dtype) -> None:
+    data = np.arange(6, dtype=dtype)
+    s = Series.from_numpy(data)
+    assert s.datatype() == DataType.from_numpy_dtype(data.dtype)
+    assert s.to_pylist() == data.tolist()
+
+    # the series shares memory with the ndarray, which is kept alive by the series
+    data[0] = 42
+    del data
+    gc.collect()
+    assert s.to_pylist()[0] == 42
+
+
+@pytest.mark.parametrize(
+    "data",
+    [
+        np.arange(10, dtype=np.int64)[::2],
+        np.array([True, False, True]),
+    ],
+    ids=["strided", "bool"],
+)
+def test_series_from_numpy_falls_back_to_copy(data) -> None:
+    s = Series.from_numpy(data)
+    assert s.to_pylist() == data.tolist()
+
+
 def test_series_iter() -> None:
     arrow = pa.array([1, 2, 3, None, 5, None])
     s = Series.from_arrow(arrow)
@@ -314,3 +342,37 @@ def test_series_iter_memory_efficiency() -> None:
     assert sum_using_iter == sum_using_pylist
     # Assert that the iterator uses less memory.
     assert iter_peak_memory < pylist_peak_memory, "Iterator should use less memory than to_pylist()"
+
+
+class _ArrowArrayWrapper:
+    """Exposes only the Arrow PyCapsule interface of the wrapped array, like non-pyarrow Arrow libraries do."""
+
+    def __init__(self, array, stream=False):
+        if stream:
+            self.__arrow_c_stream__ = array.__arrow_c_stream__
+        else:
+            self.__arrow_c_array__ = array.__arrow_c_array__
+
+
+@pytest.mark.skipif(get_arrow_version() < (15, 0, 0), reason="the Arrow PyCapsule interface of chunked arrays requires pyarrow>=15")
+def test_series_arrow_pycapsule_interface() -> None:
+    s = Series.from_pylist([1, None, 3], name="a")
+    assert pa.array(s).equals(pa.array([1, None, 3], type=pa.int64()))
+    assert pa.array(s, type=pa.int32()).equals(pa.array([1, None, 3], type=pa.int32()))
+
+    array = pa.array(["x", None, "z"])
+    s = Series.from_arrow(_ArrowArrayWrapper(array), name="b")
+    assert s.name() == "b"
+    assert s.to_pylist() == ["x", None, "z"]
+
+    chunked = pa.chunked_array([[1, 2], [3]])
+    s = Series.from_arrow(_ArrowArrayWrapper(chunked, stream=True), name="c")
+    assert s.to_pylist() == [1, 2, 3]
+
+
+def test_series_approx_quantiles() -> None:
+    s = Series.from_pylist([1, 2, None, 3], name="a")
+    result = s.approx_quantiles([0.0, 1.0])
+    assert result.datatype() == DataType.fixed_size_list(DataType.float64(), 2)
+    assert [round(q) for q in result.to_pylist()[0]] == [1, 3]
+    assert Series.from_pylist([None], name="a").cast(DataType.int64()).approx_quantiles([0.5]).to_pylist() == [None]
@@ -229,3 +229,17 @@ def test_series_boolean_sorting() -> None:
     taken = s.take(s_argsorted)
     assert len(taken) == len(s)
     assert taken.to_pylist() == sorted_order[::-1]
+
+
+def test_series_float_min_max_match_sort_order() -> None:
+    data = [5.0, float("nan"), None, float("inf"), -float("inf"), 1.0]
+    s = Series.from_arrow(pa.array(data))
+    s_sorted = s.sort().to_pylist()
+
+    assert s.min().to_pylist() == [s_sorted[0]]
+    assert math.isnan(s.max().to_pylist()[0])
+    assert math.isnan(s_sorted[4])
+
+    all_nan = Series.from_arrow(pa.array([float("nan"), None, float("nan")]))
+    assert math.isnan(all_nan.min().to_pylist()[0])
+    assert math.isnan(all_nan.max().to_pylist()[0])
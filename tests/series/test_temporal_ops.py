@@ -500,3 +500,92 @@ def test_series_timestamp_to_string():
     expected_series = Series.from_pylist(["2024-01-01T00:00:00"])
     date_as_string = input_series.dt.strftime()
     assert expected_series.to_pylist() == date_as_string.to_pylist()
+
+
+def test_series_interval_comparison_and_aggregation() -> None:
+    import pyarrow as pa
+
+    intervals = [(1, 0, 0), (0, 1, 0), None, (0, 0, 1_000), (0, 31, 0)]
+    s = Series.from_arrow(pa.array(intervals, type=pa.month_day_nano_interval()))
+    assert s.datatype() == DataType.interval()
+
+    one_day = Series.from_arrow(pa.array([(0, 1, 0)], type=pa.month_day_nano_interval()))
+    assert (s > one_day).to_pylist() == [True, False, None, False, True]
+
+    sorted_values = s.sort().to_arrow().to_pylist()
+    assert [(v.months, v.days, v.nanoseconds) if v else None for v in sorted_values] == [
+        (0, 0, 1_000),
+        (0, 1, 0),
+        (1, 0, 0),
+        (0, 31, 0),
+        None,
+    ]
+
+    def to_tuple(series: Series):
+        [value] = series.to_arrow().to_pylist()
+        return (value.months, value.days, value.nanoseconds)
+
+    assert to_tuple(s.min()) == (0, 0, 1_000)
+    assert to_tuple(s.max()) == (0, 31, 0)
+    assert to_tuple(s.sum()) == (1, 32, 1_000)
+
+    assert s.cast(DataType.string()).to_pylist()[2] is None
+
+
+def test_series_duration_cast_to_interval() -> None:
+    from datetime import timedelta
+
+    s = Series.from_pylist([timedelta(seconds=1), None])
+    intervals = s.cast(DataType.interval()).to_arrow().to_pylist()
+    assert intervals[0].nanoseconds == 1_000_000_000
+    assert intervals[1] is None
+
+
+def test_series_timestamp_convert_time_zone() -> None:
+    from datetime import datetime, timezone
+
+    s = Series.from_pylist([datetime(2024, 3, 10, 12, 0, tzinfo=timezone.utc), None])
+    converted = s.dt.convert_time_zone("America/New_York")
+    assert converted.datatype() == DataType.timestamp(TimeUnit.us(), "America/New_York")
+    # the instants are unchanged, only the wall clock time used by the extraction ops moves
+    assert (converted == s).to_pylist() == [True, None]
+    assert converted.dt.hour().to_pylist() == [8, None]
+    assert converted.dt.day().to_pylist() == [10, None]
+
+    with pytest.raises(ValueError):
+        Series.from_pylist([datetime(2024, 1, 1)]).dt.convert_time_zone("UTC")
+
+
+def test_series_timestamp_replace_time_zone() -> None:
+    from datetime import datetime
+
+    naive = Series.from_pylist([datetime(2024, 1, 1, 9, 30), datetime(2024, 3, 10, 2, 30), None])
+    localized = naive.dt.replace_time_zone("America/New_York")
+    assert localized.datatype() == DataType.timestamp(TimeUnit.us(), "America/New_York")
+    # 2:30 does not exist in New York on 2024-03-10
+    assert localized.dt.hour().to_pylist() == [9, None, None]
+    assert localized.dt.convert_time_zone("UTC").dt.hour().to_pylist() == [14, None, None]
+
+    round_tripped = localized.dt.replace_time_zone()
+    assert round_tripped.datatype() == DataType.timestamp(TimeUnit.us())
+    assert round_tripped.to_pylist() == [datetime(2024, 1, 1, 9, 30), None, None]
+
+
+def test_series_timestamp_diff_across_time_zones() -> None:
+    from datetime import datetime, timedelta, timezone
+
+    utc = Series.from_pylist([datetime(2024, 1, 1, 12, tzinfo=timezone.utc)])
+    tokyo = utc.dt.convert_time_zone("Asia/Tokyo")
+    later = (tokyo + Series.from_pylist([timedelta(hours=1)])).dt.convert_time_zone("Europe/Paris")
+    assert (later - utc).to_pylist() == [timedelta(hours=1)]
+    assert tokyo.dt.hour().to_pylist() == [21]
+
+
+def test_series_timestamp_truncate_across_dst() -> None:
+    from datetime import datetime
+
+    # New York moves from UTC-5 to UTC-4 on 2024-03-10 at 2:00 local time
+    s = Series.from_pylist([datetime(2024, 3, 10, 15, 0)]).dt.replace_time_zone("America/New_York")
+    truncated = s.dt.truncate("1 day")
+    assert truncated.dt.replace_time_zone().to_pylist() == [datetime(2024, 3, 10)]
+    assert truncated.dt.convert_time_zone("UTC").dt.hour().to_pylist() == [5]
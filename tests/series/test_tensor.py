@@ -141,3 +141,65 @@ def test_tensor_repr():
 ╰───────────────────────╯
 """
     )
+
+
+def test_fixed_shape_tensor_dlpack():
+    data = np.arange(24, dtype=np.int32).reshape(4, 2, 3)
+    s = Series.from_pylist(list(data), pyobj="force").cast(DataType.tensor(DataType.int32(), (2, 3)))
+
+    exported = np.from_dlpack(s)
+    assert exported.shape == (4, 2, 3)
+    np.testing.assert_equal(exported, data)
+
+
+def test_variable_shape_tensor_dlpack_is_unsupported():
+    s = Series.from_pylist([np.ones((1, 2)), np.ones((2, 2))], pyobj="force").cast(DataType.tensor(DataType.float64()))
+    with pytest.raises(ValueError, match="DLPack"):
+        s.__dlpack__()
+
+
+def _tensors(fixed_shape: bool) -> Series:
+    data = [np.arange(6, dtype=np.int64).reshape(2, 3), None, np.arange(6, 12, dtype=np.int64).reshape(2, 3)]
+    dtype = DataType.tensor(DataType.int64(), (2, 3)) if fixed_shape else DataType.tensor(DataType.int64())
+    return Series.from_pylist(data, pyobj="force").cast(dtype)
+
+
+@pytest.mark.parametrize("fixed_shape", [True, False])
+def test_tensor_arithmetic(fixed_shape):
+    t = _tensors(fixed_shape)
+    arrs = [np.arange(6).reshape(2, 3), None, np.arange(6, 12).reshape(2, 3)]
+
+    result = t + Series.from_pylist([10])
+    assert result.datatype() == t.datatype()
+    np.testing.assert_equal(result.to_pylist(), [None if a is None else a + 10 for a in arrs])
+
+    result = Series.from_pylist([1, 2, 3]) * t
+    np.testing.assert_equal(result.to_pylist(), [arrs[0], None, arrs[2] * 3])
+
+    np.testing.assert_equal((t - t).to_pylist(), [np.zeros((2, 3)), None, np.zeros((2, 3))])
+    result = t / Series.from_pylist([2.0])
+    np.testing.assert_equal(result.to_pylist(), [None if a is None else a / 2 for a in arrs])
+
+
+def test_tensor_arithmetic_shape_mismatch():
+    t = Series.from_pylist([np.ones((2, 3)), np.ones((1, 2))], pyobj="force").cast(DataType.tensor(DataType.float64()))
+    other = Series.from_pylist([np.ones((2, 3)), np.ones((2, 1))], pyobj="force").cast(
+        DataType.tensor(DataType.float64())
+    )
+    with pytest.raises(ValueError, match="different shapes"):
+        t + other
+
+
+@pytest.mark.parametrize("fixed_shape", [True, False])
+def test_tensor_reductions(fixed_shape):
+    t = _tensors(fixed_shape)
+    arrs = [np.arange(6).reshape(2, 3), None, np.arange(6, 12).reshape(2, 3)]
+
+    assert t.tensor.sum().datatype() == DataType.float64()
+    assert t.tensor.sum().to_pylist() == [15.0, None, 51.0]
+    np.testing.assert_equal(t.tensor.mean(axis=[0, 1]).to_pylist(), [2.5, None, 8.5])
+    np.testing.assert_equal(t.tensor.sum(axis=0).to_pylist(), [None if a is None else a.sum(axis=0) for a in arrs])
+    np.testing.assert_equal(t.tensor.mean(axis=1).to_pylist(), [None if a is None else a.mean(axis=1) for a in arrs])
+
+    with pytest.raises(ValueError):
+        t.tensor.sum(axis=2)
@@ -393,6 +393,25 @@ def test_series_utf8_capitalize(data, expected) -> None:
     assert result.to_pylist() == expected
 
 
+@pytest.mark.parametrize(
+    ["data", "expected"],
+    [
+        (["hello wORLD-foo", "they're 2nd"], ["Hello World-Foo", "They'Re 2Nd"]),
+        # With at least one null
+        (["foo bar", None], ["Foo Bar", None]),
+        # With all nulls
+        ([None] * 4, [None] * 4),
+        # With empty string
+        (["", "élan vital"], ["", "Élan Vital"]),
+    ],
+)
+def test_series_utf8_to_titlecase(data, expected) -> None:
+    s = Series.from_arrow(pa.array(data, type=pa.string()))
+    result = s.str.to_titlecase()
+    assert result.to_pylist() == expected
+    assert result.to_pylist() == [None if x is None else x.title() for x in data]
+
+
 @pytest.mark.parametrize(
     ["data", "pattern", "expected"],
     [
@@ -1230,6 +1249,22 @@ def test_series_utf8_substr(data, start, length, expected) -> None:
     assert result.to_pylist() == expected
 
 
+@pytest.mark.parametrize(
+    ["data", "start", "length", "expected"],
+    [
+        pytest.param(["hello", "héllo", "hi"], [-3], [None], ["llo", "llo", "hi"], id="Negative start"),
+        pytest.param(["hello", "héllo", "hi"], [-4, -10, 1], [2], ["el", "hé", "i"], id="Negative start with length"),
+        pytest.param(["😃😌😝"], [-2], [1], ["😌"], id="Negative start with emojis"),
+    ],
+)
+def test_series_utf8_substr_negative_start(data, start, length, expected) -> None:
+    s = Series.from_arrow(pa.array(data, type=pa.string()))
+    start = Series.from_arrow(pa.array(start, type=pa.int64()))
+    length = Series.from_arrow(pa.array(length, type=pa.int64()))
+    result = s.str.substr(start, length)
+    assert result.to_pylist() == expected
+
+
 def test_series_utf8_substr_length_is_none() -> None:
     s = Series.from_arrow(pa.array(["foo", "bar", "baz"], type=pa.string()))
     start = Series.from_arrow(pa.array([0, 1, 2], type=pa.uint32()))
@@ -0,0 +1,35 @@
+from __future__ import annotations
+
+from daft import DataType
+from daft.series import Series
+
+
+def test_series_value_counts() -> None:
+    s = Series.from_pylist(["b", None, "a", "b", None, "c", "a", "b"], name="s")
+    result = s.value_counts()
+    assert result.datatype() == DataType.struct({"s": DataType.string(), "count": DataType.uint64()})
+    assert result.to_pylist() == [
+        {"s": "b", "count": 3},
+        {"s": "a", "count": 2},
+        {"s": "c", "count": 1},
+    ]
+
+
+def test_series_value_counts_nested() -> None:
+    lists = Series.from_pylist([[1, 2], [2], None, [1, 2], [], [None], []], name="l")
+    assert lists.value_counts().to_pylist() == [
+        {"l": [1, 2], "count": 2},
+        {"l": [], "count": 2},
+        {"l": [2], "count": 1},
+        {"l": [None], "count": 1},
+    ]
+
+    structs = Series.from_pylist([{"a": 1, "b": "x"}, {"a": 1, "b": "y"}, {"a": 1, "b": "x"}], name="st")
+    assert structs.value_counts().to_pylist() == [
+        {"st": {"a": 1, "b": "x"}, "count": 2},
+        {"st": {"a": 1, "b": "y"}, "count": 1},
+    ]
+
+
+def test_series_value_counts_empty() -> None:
+    assert Series.from_pylist([None, None], name="s").value_counts().to_pylist() == []
@@ -212,3 +212,62 @@ def test_groupby_rollup():
     """
     actual = daft.sql(sql, catalog).to_pydict()
     assert actual == expected
+
+
+def test_cube_with_grouping_id():
+    data = {"dept": ["IT", "IT", "HR"], "year": [2022, 2023, 2022], "cost": [100, 200, 300]}
+    df = daft.from_pydict(data)
+    catalog = SQLCatalog({"df": df})
+
+    sql = """
+    SELECT dept, year, SUM(cost) as total, grouping_id() as gid
+    FROM df
+    GROUP BY CUBE(dept, year)
+    ORDER BY gid, dept, year
+    """
+    actual = daft.sql(sql, catalog).to_pydict()
+    assert actual == {
+        "dept": ["HR", "IT", "IT", "HR", "IT", None, None, None],
+        "year": [2022, 2022, 2023, None, None, 2022, 2023, None],
+        "total": [300, 100, 200, 300, 300, 400, 200, 600],
+        "gid": [0, 0, 0, 1, 1, 2, 2, 3],
+    }
+
+
+def test_grouping_sets():
+    data = {"dept": ["IT", "IT", "HR"], "year": [2022, 2023, 2022], "cost": [100, 200, 300]}
+    df = daft.from_pydict(data)
+    catalog = SQLCatalog({"df": df})
+
+    sql = """
+    SELECT dept, year, SUM(cost) as total
+    FROM df
+    GROUP BY GROUPING SETS ((dept), (year), ())
+    ORDER BY grouping_id(), dept, year
+    """
+    actual = daft.sql(sql, catalog).to_pydict()
+    assert actual == {
+        "dept": ["HR", "IT", None, None, None],
+        "year": [None, None, 2022, 2023, None],
+        "total": [300, 300, 400, 200, 600],
+    }
+
+
+def test_groupby_with_rollup():
+    data = {"dept": ["IT", "IT", "HR"], "year": [2022, 2023, 2022], "cost": [100, 200, 300]}
+    df = daft.from_pydict(data)
+    catalog = SQLCatalog({"df": df})
+
+    sql = """
+    SELECT dept, year, SUM(cost) as total, grouping_id() as gid
+    FROM df
+    GROUP BY dept, ROLLUP(year)
+    ORDER BY gid, dept, year
+    """
+    actual = daft.sql(sql, catalog).to_pydict()
+    assert actual == {
+        "dept": ["HR", "IT", "IT", "HR", "IT"],
+        "year": [2022, 2022, 2023, None, None],
+        "total": [300, 100, 200, 300, 300],
+        "gid": [0, 0, 0, 1, 1],
+    }
@@ -92,3 +92,32 @@ def test_unsupported_div_floor():
         lambda: df.select(daft.col("C") // daft.col("B")).collect(),
         "Cannot perform floor divide on types: Boolean, Float64",
     )
+
+
+def test_bitwise_operators():
+    df = daft.from_pydict({"a": [0b1100, 0b1010, None], "b": [0b1010, 0b0110, 1]})
+
+    actual = (
+        daft.sql(
+            """
+            SELECT
+                a & b AS and_,
+                a | b AS or_,
+                a ^ b AS xor_,
+                a << 1 AS shl,
+                a >> 2 AS shr,
+                bitwise_not(a) AS not_
+            FROM df
+            """
+        )
+        .collect()
+        .to_pydict()
+    )
+    assert actual == {
+        "and_": [0b1000, 0b0010, None],
+        "or_": [0b1110, 0b1110, None],
+        "xor_": [0b0110, 0b1100, None],
+        "shl": [0b11000, 0b10100, None],
+        "shr": [0b11, 0b10, None],
+        "not_": [~0b1100, ~0b1010, None],
+    }
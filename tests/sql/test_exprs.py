@@ -165,12 +165,12 @@ def test_is_in_edge_cases():
                 ],
                 "date_sub_month": [
                     datetime.date(2021, 12, 1),
-                    datetime.date(2020, 1, 31),
-                    datetime.date(2029, 4, 14),
+                    datetime.date(2020, 1, 29),
+                    datetime.date(2029, 4, 15),
                 ],
                 "ts_sub_year": [
                     datetime.datetime(2021, 1, 1, 10),
-                    datetime.datetime(2019, 2, 28, 23, 59, 59),
+                    datetime.datetime(2019, 3, 1, 23, 59, 59),
                     datetime.datetime(2028, 5, 15, 12, 34, 56),
                 ],
                 "ts_add_hour": [
@@ -0,0 +1,108 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pytest
+
+import daft
+
+flight = pytest.importorskip("pyarrow.flight")
+
+TYPE_URL_PREFIX = "type.googleapis.com/arrow.flight.protocol.sql."
+
+
+def _varint(n: int) -> bytes:
+    out = bytearray()
+    while True:
+        byte, n = n & 0x7F, n >> 7
+        out.append(byte | (0x80 if n else 0))
+        if not n:
+            return bytes(out)
+
+
+def _field(tag: int, data: bytes) -> bytes:
+    return _varint(tag << 3 | 2) + _varint(len(data)) + data
+
+
+def _command(name: str, *fields: bytes) -> bytes:
+    """Encodes a Flight SQL message wrapped in a protobuf `Any`."""
+    return _field(1, (TYPE_URL_PREFIX + name).encode()) + _field(2, b"".join(fields))
+
+
+def _parse(data: bytes) -> dict[int, bytes]:
+    """Decodes the length-delimited fields of a protobuf message."""
+    fields, i = {}, 0
+    while i < len(data):
+        key, i = _read_varint(data, i)
+        length, i = _read_varint(data, i)
+        fields[key >> 3] = data[i : i + length]
+        i += length
+    return fields
+
+
+def _read_varint(data: bytes, i: int) -> tuple[int, int]:
+    n, shift = 0, 0
+    while True:
+        byte = data[i]
+        i += 1
+        n |= (byte & 0x7F) << shift
+        shift += 7
+        if not byte & 0x80:
+            return n, i
+
+
+@pytest.fixture
+def client():
+    sess = daft.Session()
+    sess.create_temp_table("trips", daft.from_pydict({"id": [1, 2, 3], "city": ["nyc", "sf", "nyc"]}))
+    server = sess.serve_flight_sql(host="127.0.0.1")
+    client = flight.FlightClient(f"grpc://127.0.0.1:{server.port()}")
+    yield client
+    client.close()
+    server.shutdown()
+
+
+def _read(client, cmd: bytes) -> pa.Table:
+    info = client.get_flight_info(flight.FlightDescriptor.for_command(cmd))
+    return client.do_get(info.endpoints[0].ticket).read_all()
+
+
+def test_statement_query(client):
+    cmd = _command("CommandStatementQuery", _field(1, b"SELECT id, city FROM trips WHERE city = 'nyc' ORDER BY id"))
+    assert _read(client, cmd).to_pydict() == {"id": [1, 3], "city": ["nyc", "nyc"]}
+
+
+def test_statement_query_invalid(client):
+    cmd = _command("CommandStatementQuery", _field(1, b"SELECT * FROM missing"))
+    with pytest.raises(flight.FlightError):
+        client.get_flight_info(flight.FlightDescriptor.for_command(cmd))
+
+
+def test_get_tables(client):
+    catalogs = _read(client, _command("CommandGetCatalogs"))
+    assert catalogs.column_names == ["catalog_name"]
+
+    tables = _read(client, _command("CommandGetTables", _field(3, b"tr%")))
+    assert tables.to_pydict() == {
+        "catalog_name": [None],
+        "db_schema_name": [None],
+        "table_name": ["trips"],
+        "table_type": ["TEMPORARY TABLE"],
+    }
+
+
+def test_prepared_statement(client):
+    request = _command("ActionCreatePreparedStatementRequest", _field(1, b"SELECT id FROM trips WHERE city = ?"))
+    [result] = client.do_action(flight.Action("CreatePreparedStatement", request))
+    handle = _parse(_parse(result.body.to_pybytes())[2])[1]
+    cmd = _command("CommandPreparedStatementQuery", _field(1, handle))
+
+    params = pa.table({"city": ["sf"]})
+    writer, _ = client.do_put(flight.FlightDescriptor.for_command(cmd), params.schema)
+    writer.write_table(params)
+    writer.close()
+    assert _read(client, cmd).to_pydict() == {"id": [2]}
+
+    close = _command("ActionClosePreparedStatementRequest", _field(1, handle))
+    list(client.do_action(flight.Action("ClosePreparedStatement", close)))
+    with pytest.raises(flight.FlightError):
+        client.get_flight_info(flight.FlightDescriptor.for_command(cmd))
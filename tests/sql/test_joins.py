@@ -2,6 +2,7 @@ import pytest
 
 import daft
 from daft.sql import SQLCatalog
+from tests.conftest import get_tests_daft_runner_name
 
 
 def test_joins_using():
@@ -195,3 +196,70 @@ def test_cross_join():
         "C": [2, 4, 6, 8, 2, 4, 6, 8, 2, 4, 6, 8],
         "D": ["d", "e", "f", "g", "d", "e", "f", "g", "d", "e", "f", "g"],
     }
+
+
+@pytest.fixture
+def range_join_catalog():
+    intervals = daft.from_pydict(
+        {"id": [1, 2, 3], "team": ["x", "x", "y"], "begin_ts": [0, 5, 20], "end_ts": [10, 15, 30]}
+    )
+    events = daft.from_pydict(
+        {
+            "ts": [12, 0, None, 5, 10, 25],
+            "event": ["a", "b", "c", "d", "e", "f"],
+            "owner": ["x", "x", "x", "y", "x", "x"],
+        }
+    )
+    return SQLCatalog({"intervals": intervals, "events": events})
+
+
+range_join_only_native = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Range joins are only supported on the native runner"
+)
+
+
+@range_join_only_native
+def test_range_join_interval_overlap(range_join_catalog):
+    df = daft.sql(
+        """
+        select id, event from intervals i join events e on i.begin_ts <= e.ts and e.ts < i.end_ts
+        order by id, event
+        """,
+        range_join_catalog,
+    )
+
+    assert df.to_pydict() == {"id": [1, 1, 2, 2, 2, 3], "event": ["b", "d", "a", "d", "e", "f"]}
+
+
+@range_join_only_native
+@pytest.mark.parametrize(
+    "from_clause",
+    [
+        "intervals i left join events e",
+        "events e right join intervals i",
+    ],
+)
+def test_range_join_outer_side(from_clause, range_join_catalog):
+    df = daft.sql(
+        f"""
+        select id, event from {from_clause} on e.ts >= i.begin_ts and i.end_ts > e.ts and e.event != 'd'
+        order by id, event
+        """,
+        range_join_catalog,
+    )
+
+    assert df.to_pydict() == {"id": [1, 2, 2, 3], "event": ["b", "a", "e", "f"]}
+
+
+@range_join_only_native
+def test_range_join_with_equality_keys(range_join_catalog):
+    df = daft.sql(
+        """
+        select id, event from intervals i left join events e
+        on i.team = e.owner and i.begin_ts <= e.ts and e.ts < i.end_ts
+        order by id, event
+        """,
+        range_join_catalog,
+    )
+
+    assert df.to_pydict() == {"id": [1, 2, 2, 3], "event": ["b", "a", "e", None]}
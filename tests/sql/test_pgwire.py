@@ -0,0 +1,62 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+
+psycopg2 = pytest.importorskip("psycopg2")
+
+INT8_OID = 20
+TEXT_OID = 25
+
+
+@pytest.fixture
+def sess():
+    sess = daft.Session()
+    sess.create_temp_table("trips", daft.from_pydict({"id": [1, 2, 3], "city": ["nyc", "sf", None]}))
+    return sess
+
+
+@pytest.fixture
+def conn(sess):
+    server = sess.serve_pgwire(host="127.0.0.1")
+    conn = psycopg2.connect(host="127.0.0.1", port=server.port(), user="daft", dbname="daft", sslmode="disable")
+    conn.autocommit = True
+    yield conn
+    conn.close()
+    server.shutdown()
+
+
+def test_query(conn):
+    with conn.cursor() as cur:
+        cur.execute("SELECT id, city FROM trips ORDER BY id")
+        assert cur.fetchall() == [(1, "nyc"), (2, "sf"), (3, None)]
+        assert [(col.name, col.type_code) for col in cur.description] == [("id", INT8_OID), ("city", TEXT_OID)]
+        assert cur.rowcount == 3
+
+
+def test_query_params(conn):
+    with conn.cursor() as cur:
+        cur.execute("SELECT id FROM trips WHERE city = %s", ("sf",))
+        assert cur.fetchall() == [(2,)]
+
+
+def test_query_invalid(conn):
+    with conn.cursor() as cur:
+        with pytest.raises(psycopg2.Error):
+            cur.execute("SELECT * FROM missing")
+        cur.execute("SELECT 1 AS one")
+        assert cur.fetchall() == [(1,)]
+
+
+def test_transaction(sess):
+    """Transactions are acknowledged without effect, so that clients which wrap queries in them work."""
+    server = sess.serve_pgwire(host="127.0.0.1")
+    conn = psycopg2.connect(host="127.0.0.1", port=server.port(), user="daft", sslmode="disable")
+    try:
+        with conn, conn.cursor() as cur:
+            cur.execute("SELECT count(*) AS n FROM trips")
+            assert cur.fetchone() == (3,)
+    finally:
+        conn.close()
+        server.shutdown()
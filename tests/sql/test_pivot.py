@@ -0,0 +1,58 @@
+import pytest
+
+import daft
+from daft.sql import SQLCatalog
+
+
+@pytest.fixture
+def catalog():
+    df = daft.from_pydict(
+        {
+            "grp": ["A", "A", "B", "B"],
+            "kind": [1, 2, 1, 2],
+            "amount": [10, 20, 30, 40],
+        }
+    )
+    return SQLCatalog({"df": df})
+
+
+def test_sql_pivot(catalog):
+    actual = daft.sql(
+        """
+        SELECT * FROM df PIVOT (SUM(amount) FOR kind IN (1, 2 AS two))
+        ORDER BY grp
+        """,
+        catalog,
+    ).to_pydict()
+
+    assert actual == {"grp": ["A", "B"], "1": [10, 30], "two": [20, 40]}
+
+
+def test_sql_pivot_missing_value(catalog):
+    actual = daft.sql(
+        "SELECT * FROM df PIVOT (AVG(amount) FOR kind IN (1, 3)) ORDER BY grp",
+        catalog,
+    ).to_pydict()
+
+    assert actual == {"grp": ["A", "B"], "1": [10.0, 30.0], "3": [None, None]}
+
+
+def test_sql_pivot_requires_value_list(catalog):
+    with pytest.raises(Exception, match="PIVOT without an explicit list of values"):
+        daft.sql("SELECT * FROM df PIVOT (SUM(amount) FOR kind IN (ANY))", catalog)
+
+
+def test_sql_unpivot():
+    df = daft.from_pydict({"id": [1, 2], "q1": [10, 20], "q2": [30, 40]})
+    catalog = SQLCatalog({"df": df})
+
+    actual = daft.sql(
+        "SELECT * FROM df UNPIVOT (sales FOR quarter IN (q1, q2)) ORDER BY id, quarter",
+        catalog,
+    ).to_pydict()
+
+    assert actual == {
+        "id": [1, 1, 2, 2],
+        "quarter": ["q1", "q2", "q1", "q2"],
+        "sales": [10, 30, 20, 40],
+    }
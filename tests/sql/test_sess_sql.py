@@ -142,3 +142,18 @@ def test_pydict_catalog():
     table_t = sess.sql("SELECT * FROM T")
     table_s = sess.read_table("S")
     assert_eq(table_t, table_s)
+
+
+def test_create_temp_table_as_select():
+    sess = Session()
+    sess.create_temp_table("T", daft.from_pydict({"a": [1, 2, 3]}))
+    sess.sql("CREATE TEMP TABLE S AS SELECT a * 2 AS b FROM T")
+    assert sess.read_table("S").sort("b").to_pydict() == {"b": [2, 4, 6]}
+    # temporary tables are views, so they can be replaced but not inserted into
+    with pytest.raises(Exception, match="already exists"):
+        sess.sql("CREATE TEMP TABLE S AS SELECT a FROM T")
+    sess.sql("CREATE TEMP TABLE IF NOT EXISTS S AS SELECT a FROM T")
+    sess.sql("CREATE OR REPLACE TEMP TABLE S AS SELECT a FROM T")
+    assert sess.read_table("S").sort("a").to_pydict() == {"a": [1, 2, 3]}
+    with pytest.raises(Exception, match="does not support writes"):
+        sess.sql("INSERT INTO S SELECT a FROM T")
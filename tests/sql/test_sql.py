@@ -325,3 +325,42 @@ def test_cast_image():
     df = daft.from_pydict({"img": s})
     actual = daft.sql("select cast(img as image(RGB)) from df", catalog=SQLCatalog({"df": df})).collect()
     assert actual.schema()["img"].dtype == DataType.image("RGB")
+
+
+def test_sql_params():
+    import datetime
+
+    df = daft.from_pydict(
+        {
+            "a": [1, 2, 3],
+            "b": ["x", "it's", "z"],
+            "d": [datetime.date(2024, 1, 1), datetime.date(2024, 6, 1), datetime.date(2025, 1, 1)],
+        }
+    )
+    catalog = SQLCatalog({"df": df})
+
+    # positional parameters are bound in order, and values are never parsed as SQL
+    actual = daft.sql("SELECT a FROM df WHERE a > ? AND b = ?", catalog=catalog, params=[1, "it's"])
+    assert actual.to_pydict() == {"a": [2]}
+    actual = daft.sql("SELECT a FROM df WHERE a >= $2 AND a <= $1", catalog=catalog, params=(2, 2))
+    assert actual.to_pydict() == {"a": [2]}
+
+    # named parameters keep their python types as typed literals
+    actual = daft.sql(
+        "SELECT a, :tag AS tag FROM df WHERE d < :day",
+        catalog=catalog,
+        params={"day": datetime.date(2024, 12, 31), "tag": 1.5},
+    )
+    assert actual.schema()["tag"].dtype == DataType.float64()
+    assert actual.to_pydict() == {"a": [1, 2], "tag": [1.5, 1.5]}
+
+    with pytest.raises(Exception, match="No value is bound"):
+        daft.sql("SELECT a FROM df WHERE a > ?", catalog=catalog, params={"a": 1})
+    with pytest.raises(TypeError):
+        daft.sql("SELECT a FROM df WHERE a > ?", catalog=catalog, params="1")
+
+
+def test_session_sql_params():
+    sess = daft.Session()
+    sess.create_temp_table("T", daft.from_pydict({"a": [1, 2, 3]}))
+    assert sess.sql("SELECT a FROM T WHERE a < ?", params=[3]).to_pydict() == {"a": [1, 2]}
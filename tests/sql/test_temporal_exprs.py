@@ -142,3 +142,27 @@ def test_date_comparison():
     expected = date_df.filter(daft.col("date") == "2020-01-01").select("date").to_pydict()
     actual = daft.sql("select date from date_df where date == '2020-01-01'").to_pydict()
     assert actual == expected
+
+
+def test_calendar_functions():
+    df = daft.from_pydict({"d": [datetime.date(2024, 1, 31), datetime.date(2024, 11, 15)]})
+    actual = daft.sql("""
+    SELECT
+        quarter(d) AS quarter,
+        week_of_year(d) AS week,
+        last_day_of_month(d) AS last_day,
+        date_add(d, 1, 'month') AS next_month,
+        date_sub(d, 2, 'weeks') AS two_weeks_ago,
+        date_diff('day', make_date(2024, 1, 1), d) AS days,
+        make_timestamp(2024, 1, 1, 12, 0, 0) AS ts
+    FROM df
+    """).to_pydict()
+    assert actual == {
+        "quarter": [1, 4],
+        "week": [5, 46],
+        "last_day": [datetime.date(2024, 1, 31), datetime.date(2024, 11, 30)],
+        "next_month": [datetime.date(2024, 2, 29), datetime.date(2024, 12, 15)],
+        "two_weeks_ago": [datetime.date(2024, 1, 17), datetime.date(2024, 11, 1)],
+        "days": [30, 319],
+        "ts": [datetime.datetime(2024, 1, 1, 12), datetime.datetime(2024, 1, 1, 12)],
+    }
@@ -94,3 +94,25 @@ def test_url_upload():
         # Verify files were created
         assert os.path.exists(os.path.join(tmp_dir, "test1.txt"))
         assert os.path.exists(os.path.join(tmp_dir, "test2.txt"))
+
+
+def test_url_parse():
+    df = daft.from_pydict({"url": ["https://example.com/search?q=daft+rs", "not a url"]})
+    actual = daft.sql(
+        """
+        SELECT
+            url_scheme(url) AS scheme,
+            url_host(url) AS host,
+            url_path(url) AS path,
+            url_query_param(url, 'q') AS q,
+            url_decode('a%20b') AS decoded
+        FROM df
+        """
+    ).to_pydict()
+    assert actual == {
+        "scheme": ["https", None],
+        "host": ["example.com", None],
+        "path": ["/search", None],
+        "q": ["daft rs", None],
+        "decoded": ["a b", "a b"],
+    }
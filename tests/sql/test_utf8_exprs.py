@@ -120,3 +120,19 @@ def test_utf8_exprs():
     )
     actual = actual.to_pydict()
     assert actual == expected
+
+
+def test_regexp_replace_with_capture_groups():
+    df = daft.from_pydict({"a": ["2024-01-02", "x-y-z-w", None]})
+    actual = daft.sql(
+        r"""
+        SELECT
+            regexp_replace(a, '(\d+)-(\d+)-(\d+)', '$3/$2/$1') AS dates,
+            regexp_replace(a, '-', '+', 1) AS first_dash
+        FROM df
+        """
+    ).to_pydict()
+    assert actual == {
+        "dates": ["02/01/2024", "x-y-z-w", None],
+        "first_dash": ["2024+01-02", "x+y-z-w", None],
+    }
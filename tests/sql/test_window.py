@@ -0,0 +1,102 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import Window, col
+
+
+@pytest.fixture
+def df():
+    return daft.from_pydict(
+        {
+            "team": ["a", "a", "a", "b", "b"],
+            "day": [1, 2, 3, 1, 2],
+            "score": [10, 30, 30, 5, 7],
+        }
+    )
+
+
+def test_window_ranking(df):
+    actual = daft.sql(
+        """
+        SELECT
+            team,
+            day,
+            row_number() OVER (PARTITION BY team ORDER BY score DESC, day) AS row_number,
+            rank() OVER (PARTITION BY team ORDER BY score DESC) AS rank,
+            dense_rank() OVER (PARTITION BY team ORDER BY score DESC) AS dense_rank
+        FROM df
+        ORDER BY team, day
+        """
+    ).to_pydict()
+    assert actual["row_number"] == [3, 1, 2, 2, 1]
+    assert actual["rank"] == [3, 1, 1, 2, 1]
+    assert actual["dense_rank"] == [2, 1, 1, 2, 1]
+
+
+def test_window_aggregations(df):
+    actual = daft.sql(
+        """
+        SELECT
+            team,
+            day,
+            sum(score) OVER (PARTITION BY team) AS total,
+            sum(score) OVER (PARTITION BY team ORDER BY day) AS running,
+            sum(score) OVER (PARTITION BY team ORDER BY day ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) AS pair,
+            count(*) OVER (PARTITION BY team ORDER BY day ROWS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING) AS rest
+        FROM df
+        ORDER BY team, day
+        """
+    ).to_pydict()
+    assert actual["total"] == [70, 70, 70, 12, 12]
+    assert actual["running"] == [10, 40, 70, 5, 12]
+    assert actual["pair"] == [10, 40, 60, 5, 12]
+    assert actual["rest"] == [3, 2, 1, 2, 1]
+
+
+def test_window_lag_lead(df):
+    actual = daft.sql(
+        """
+        SELECT
+            team,
+            day,
+            score - lag(score) OVER (PARTITION BY team ORDER BY day) AS change,
+            lead(score, 1, 0) OVER (PARTITION BY team ORDER BY day) AS next_score
+        FROM df
+        ORDER BY team, day
+        """
+    ).to_pydict()
+    assert actual["change"] == [None, 20, 0, None, 2]
+    assert actual["next_score"] == [30, 30, 0, 7, 0]
+
+
+def test_window_matches_dataframe(df):
+    window = Window().partition_by("team").order_by("day")
+    expected = (
+        df.select("team", "day", col("score").sum().over(window.rows_between(-1, 0)).alias("pair"))
+        .sort(["team", "day"])
+        .to_pydict()
+    )
+    actual = daft.sql(
+        """
+        SELECT team, day, sum(score) OVER (PARTITION BY team ORDER BY day ROWS 1 PRECEDING) AS pair
+        FROM df
+        ORDER BY team, day
+        """
+    ).to_pydict()
+    assert actual == expected
+
+
+@pytest.mark.parametrize(
+    "query",
+    [
+        "SELECT row_number() FROM df",
+        "SELECT upper(team) OVER (ORDER BY day) FROM df",
+        "SELECT sum(score) OVER (ORDER BY day GROUPS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM df",
+        "SELECT sum(score) OVER (ORDER BY day NULLS FIRST) FROM df",
+    ],
+)
+def test_window_invalid(df, query):
+    with pytest.raises(Exception):
+        daft.sql(query).collect()
@@ -0,0 +1,44 @@
+from __future__ import annotations
+
+import pyarrow.csv as pacsv
+import pyarrow.parquet as pq
+import pytest
+
+import daft
+from daft.cli import print_schema, run_query
+
+
+@pytest.fixture
+def parquet_path(tmp_path):
+    path = str(tmp_path / "trips.parquet")
+    pq.write_table(daft.from_pydict({"id": [1, 2, 3], "city": ["nyc", "sf", "nyc"]}).to_arrow(), path)
+    return path
+
+
+def test_query_output_parquet(parquet_path, tmp_path):
+    output = str(tmp_path / "out.parquet")
+    run_query(f"SELECT id FROM read_parquet('{parquet_path}') WHERE city = 'nyc' ORDER BY id;\n", output=output)
+    assert pq.read_table(output).to_pydict() == {"id": [1, 3]}
+
+
+def test_query_output_csv(parquet_path, tmp_path):
+    output = str(tmp_path / "out.csv")
+    run_query(f"SELECT city, count(*) AS n FROM read_parquet('{parquet_path}') GROUP BY city ORDER BY city", output)
+    assert pacsv.read_csv(output).to_pydict() == {"city": ["nyc", "sf"], "n": [2, 1]}
+
+
+def test_query_output_unsupported(parquet_path, tmp_path):
+    with pytest.raises(ValueError, match="Unsupported output format"):
+        run_query(f"SELECT * FROM read_parquet('{parquet_path}')", str(tmp_path / "out.xlsx"))
+
+
+def test_query_print(parquet_path, capsys):
+    run_query(f"SELECT city FROM read_parquet('{parquet_path}') WHERE id = 2", limit=5)
+    assert "sf" in capsys.readouterr().out
+
+
+def test_schema(parquet_path, capsys):
+    print_schema(parquet_path)
+    out = capsys.readouterr().out
+    assert "id" in out and "Int64" in out
+    assert "city" in out and "Utf8" in out
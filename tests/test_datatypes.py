@@ -408,3 +408,41 @@ def test_map_value_property(test_type: DataType):
             pytest.fail("Expected AttributeError")
         except AttributeError:
             assert True
+
+
+def test_registered_extension_type_round_trips_through_parquet(tmp_path):
+    import daft
+    from daft.datatype import register_extension_type, registered_extension_types, unregister_extension_type
+
+    register_extension_type(
+        "test.versioned",
+        DataType.int64(),
+        serialize=lambda metadata: f"v1:{metadata}",
+        deserialize=lambda serialized: serialized.removeprefix("v1:"),
+    )
+    try:
+        assert "test.versioned" in registered_extension_types()
+        dtype = DataType.extension("test.versioned", DataType.int64(), "meters")
+
+        df = daft.from_pydict({"a": [1, 2, None]}).with_column("a", daft.col("a").cast(dtype))
+        assert df.schema()["a"].dtype == dtype
+
+        arrow_type = dtype.to_arrow_dtype()
+        assert arrow_type.extension_name == "test.versioned"
+        assert arrow_type.__arrow_ext_serialize__() == b"v1:meters"
+
+        df.write_parquet(str(tmp_path))
+        read_back = daft.read_parquet(str(tmp_path))
+        assert read_back.schema()["a"].dtype == dtype
+        assert read_back.to_pydict() == {"a": [1, 2, None]}
+    finally:
+        assert unregister_extension_type("test.versioned")
+    assert "test.versioned" not in registered_extension_types()
+
+
+@pytest.mark.parametrize("name", ["daft.super_extension", "arrow.uuid", "arrow.json"])
+def test_reserved_extension_type_names_can_not_be_registered(name):
+    from daft.datatype import register_extension_type
+
+    with pytest.raises(ValueError, match="reserved"):
+        register_extension_type(name, DataType.string())
@@ -103,6 +103,19 @@ def test_requesting_too_many_gpus():
         df.collect()
 
 
+@pytest.mark.skipif(get_tests_daft_runner_name() not in {"native"}, reason="requires Native Runner to be in use")
+@pytest.mark.parametrize("num_gpus", [0.5, 1, 2])
+def test_requesting_too_many_gpus_native(monkeypatch, num_gpus):
+    monkeypatch.setenv("CUDA_VISIBLE_DEVICES", "")
+    df = daft.from_pydict(DATA)
+
+    my_udf_parametrized = my_udf.override_options(num_gpus=num_gpus)
+    df = df.with_column("foo", my_udf_parametrized(col("id")))
+
+    with pytest.raises(ValueError, match="GPUs"):
+        df.collect()
+
+
 @pytest.mark.skipif(get_tests_daft_runner_name() not in {"py", "native"}, reason="requires PyRunner to be in use")
 def test_requesting_too_much_memory():
     df = daft.from_pydict(DATA)
@@ -0,0 +1,103 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import col, lit
+from daft.exceptions import DaftCoreException
+from daft.substrait import (
+    capability_report,
+    from_substrait,
+    plan_from_substrait,
+    plan_to_substrait,
+    to_substrait,
+)
+
+
+@pytest.fixture
+def df():
+    return daft.from_pydict({"a": [1, 2, 3, None], "b": ["x", "yy", None, "zzz"]})
+
+
+def test_round_trip_filter_and_projection(df):
+    exprs = [
+        ((col("a") > 1) & col("b").not_null()).alias("keep"),
+        col("b").str.upper().alias("upper_b"),
+        (col("a") * 2 + 1).alias("c"),
+    ]
+    data = to_substrait(exprs, df.schema())
+    assert isinstance(data, bytes)
+
+    decoded, schema = from_substrait(data)
+    assert schema == df.schema()
+    assert [e.name() for e in decoded] == ["keep", "upper_b", "c"]
+    assert df.select(*decoded).to_pydict() == df.select(*exprs).to_pydict()
+
+
+def test_capability_report(df):
+    exprs = [col("a") + 1, col("b").hash().alias("h")]
+    report = capability_report(exprs, df.schema())
+    assert len(report) == 1
+    assert "hash" in report[0]
+    assert capability_report(exprs[:1], df.schema()) == []
+
+    with pytest.raises(DaftCoreException, match="hash"):
+        to_substrait(exprs, df.schema())
+
+
+def test_round_trip_literals(df):
+    exprs = [lit(1.5).alias("f"), lit("s").alias("s"), lit(None).cast(daft.DataType.int64()).alias("n")]
+    decoded, _ = from_substrait(to_substrait(exprs, df.schema()))
+    assert df.select(*decoded).to_pydict() == df.select(*exprs).to_pydict()
+
+
+@pytest.fixture
+def tables():
+    return {
+        "db.orders": daft.from_pydict({"id": [1, 2, 3, 4], "customer": [1, 1, 2, 3], "amount": [10.0, 20.0, 5.0, 7.5]}),
+        "customers": daft.from_pydict({"customer": [1, 2, 3], "name": ["a", "b", "c"]}),
+    }
+
+
+def test_plan_round_trip(tables):
+    orders, customers = tables["db.orders"], tables["customers"]
+    df = (
+        orders.where(col("amount") > 6)
+        .join(customers, on="customer")
+        .select(col("name"), (col("amount") * 2).alias("doubled"))
+        .groupby("name")
+        .agg(col("doubled").sum().alias("total"), col("doubled").count().alias("n"))
+        .sort("total", desc=True)
+        .limit(2)
+    )
+    data = plan_to_substrait(df, tables)
+    assert isinstance(data, bytes)
+
+    decoded = plan_from_substrait(data, tables)
+    assert decoded.schema() == df.schema()
+    assert decoded.to_pydict() == df.to_pydict()
+
+
+def test_plan_reads_tables_from_session(tables):
+    customers = tables["customers"]
+    data = plan_to_substrait(customers.where(col("customer") > 1), {"customers": customers})
+
+    sess = daft.Session()
+    sess.create_temp_table("customers", customers)
+    previous = daft.current_session()
+    daft.set_session(sess)
+    try:
+        assert plan_from_substrait(data).to_pydict() == {"customer": [2, 3], "name": ["b", "c"]}
+    finally:
+        daft.set_session(previous)
+
+
+def test_plan_with_unknown_source_fails(tables):
+    with pytest.raises(DaftCoreException, match="sources other than the given tables"):
+        plan_to_substrait(tables["customers"], {"db.orders": tables["db.orders"]})
+
+
+def test_plan_with_missing_table_fails(tables):
+    data = plan_to_substrait(tables["customers"], {"customers": tables["customers"]})
+    with pytest.raises(Exception, match="customers"):
+        plan_from_substrait(data, {})
@@ -0,0 +1,143 @@
+from __future__ import annotations
+
+from datetime import datetime, timedelta
+
+import pytest
+
+import daft
+from daft import DataType, StreamingWindowAggregation, col
+
+
+def sorted_pydict(df, keys):
+    return df.sort(keys).to_pydict()
+
+
+def test_window_agg_tumbling(make_df):
+    df = make_df({"key": ["a", "b", "a", "a", "b"], "ts": [1, 5, 9, 10, 25], "x": [1, 2, 3, 4, 5]})
+    result = df.window_agg("ts", 10, col("x").sum(), col("x").count().alias("n"), by="key")
+    assert result.column_names == ["window_start", "window_end", "key", "x", "n"]
+    assert sorted_pydict(result, ["key", "window_start"]) == {
+        "window_start": [0, 10, 0, 20],
+        "window_end": [10, 20, 10, 30],
+        "key": ["a", "a", "b", "b"],
+        "x": [4, 4, 2, 5],
+        "n": [2, 1, 1, 1],
+    }
+
+
+def test_window_agg_sliding(make_df):
+    # Windows of 10 that start every 4 overlap, so each event falls in two or three of them.
+    df = make_df({"ts": [1, 9], "x": [1, 10]})
+    result = df.window_agg("ts", 10, col("x").sum(), slide=4)
+    assert sorted_pydict(result, ["window_start"]) == {
+        "window_start": [-8, -4, 0, 4, 8],
+        "window_end": [2, 6, 10, 14, 18],
+        "x": [1, 1, 11, 10, 10],
+    }
+
+
+def test_window_agg_negative_and_null_event_times(make_df):
+    df = make_df({"ts": [-3, -5, 0, None], "x": [1, 2, 3, 4]})
+    result = df.window_agg("ts", 5, col("x").sum())
+    assert sorted_pydict(result, ["window_start"]) == {"window_start": [-5, 0], "window_end": [0, 5], "x": [3, 3]}
+
+
+def test_window_agg_timestamps(make_df):
+    start = datetime(2024, 1, 1)
+    df = make_df(
+        {
+            "ts": [start, start + timedelta(minutes=3), start + timedelta(minutes=7)],
+            "x": [1, 2, 3],
+        }
+    ).with_column("ts", col("ts").cast(DataType.timestamp("ms")))
+    result = df.window_agg("ts", timedelta(minutes=5), col("x").sum())
+    assert result.schema()["window_start"].dtype == DataType.timestamp("ms")
+    assert sorted_pydict(result, ["window_start"]) == {
+        "window_start": [start, start + timedelta(minutes=5)],
+        "window_end": [start + timedelta(minutes=5), start + timedelta(minutes=10)],
+        "x": [3, 3],
+    }
+
+
+def test_window_agg_invalid(make_df):
+    df = make_df({"ts": [1, 2], "name": ["a", "b"], "x": [1, 2]})
+    with pytest.raises(TypeError, match="must be an integer"):
+        df.window_agg("ts", timedelta(seconds=1), col("x").sum())
+    with pytest.raises(ValueError, match="must be positive"):
+        df.window_agg("ts", 0, col("x").sum())
+    with pytest.raises(ValueError, match="greater than their size"):
+        df.window_agg("ts", 5, col("x").sum(), slide=10)
+    with pytest.raises(ValueError, match="timestamp or integer"):
+        df.window_agg("name", 5, col("x").sum())
+    with pytest.raises(ValueError, match="at least one aggregation"):
+        df.window_agg("ts", 5)
+
+
+def test_streaming_window_aggregation():
+    agg = StreamingWindowAggregation("ts", 10, col("x").sum(), by="key", watermark_delay=5)
+    assert agg.watermark is None
+
+    emitted = agg.update(daft.from_pydict({"ts": [1, 4, 12], "key": ["a", "a", "b"], "x": [1, 2, 3]}))
+    assert emitted.column_names == ["window_start", "window_end", "key", "x"]
+    assert emitted.count_rows() == 0
+    assert agg.watermark == 7
+
+    # An event that is out of order by less than the delay is still aggregated.
+    emitted = agg.update(daft.from_pydict({"ts": [7, 16, 3], "key": ["a", "b", "b"], "x": [4, 5, 6]}))
+    assert sorted_pydict(emitted, ["key"]) == {
+        "window_start": [0, 0],
+        "window_end": [10, 10],
+        "key": ["a", "b"],
+        "x": [7, 6],
+    }
+    assert agg.watermark == 11
+
+    # Events of windows that have been emitted are late and dropped.
+    emitted = agg.update(daft.from_pydict({"ts": [2, 30], "key": ["a", "a"], "x": [7, 8]}))
+    assert agg.num_late_events == 1
+    assert sorted_pydict(emitted, ["key"]) == {"window_start": [10], "window_end": [20], "key": ["b"], "x": [8]}
+
+    assert sorted_pydict(agg.flush(), ["key"]) == {"window_start": [30], "window_end": [40], "key": ["a"], "x": [8]}
+    assert agg.flush().count_rows() == 0
+
+
+def test_streaming_window_aggregation_sliding():
+    agg = StreamingWindowAggregation("ts", 10, col("x").sum(), slide=5)
+    emitted = agg.update(daft.from_pydict({"ts": [1, 6], "x": [1, 2]}))
+    assert emitted.to_pydict() == {"window_start": [-5], "window_end": [5], "x": [1]}
+    emitted = agg.update(daft.from_pydict({"ts": [11], "x": [4]}))
+    assert emitted.to_pydict() == {"window_start": [0], "window_end": [10], "x": [3]}
+
+    # An event whose earlier window was emitted is still aggregated in its later window.
+    emitted = agg.update(daft.from_pydict({"ts": [7], "x": [8]}))
+    assert agg.num_late_events == 0
+    assert emitted.count_rows() == 0
+    assert sorted_pydict(agg.flush(), ["window_start"]) == {
+        "window_start": [5, 10],
+        "window_end": [15, 20],
+        "x": [14, 4],
+    }
+
+
+def test_streaming_window_aggregation_timestamps():
+    start = datetime(2024, 1, 1)
+    agg = StreamingWindowAggregation("ts", timedelta(minutes=5), col("x").sum(), watermark_delay=timedelta(minutes=1))
+
+    def batch(minutes, xs):
+        df = daft.from_pydict({"ts": [start + timedelta(minutes=m) for m in minutes], "x": xs})
+        return df.with_column("ts", col("ts").cast(DataType.timestamp("us")))
+
+    assert agg.update(batch([0, 4], [1, 2])).count_rows() == 0
+    emitted = agg.update(batch([6], [3])).to_pydict()
+    assert emitted == {"window_start": [start], "window_end": [start + timedelta(minutes=5)], "x": [3]}
+    assert agg.watermark == start + timedelta(minutes=5)
+
+
+def test_streaming_window_aggregation_invalid():
+    with pytest.raises(ValueError, match="at least one aggregation"):
+        StreamingWindowAggregation("ts", 10)
+    with pytest.raises(ValueError, match="updated with a batch"):
+        StreamingWindowAggregation("ts", 10, col("x").sum()).flush()
+    agg = StreamingWindowAggregation("ts", 10, col("x").sum(), watermark_delay=-1)
+    with pytest.raises(ValueError, match="non-negative"):
+        agg.update(daft.from_pydict({"ts": [1], "x": [1]}))
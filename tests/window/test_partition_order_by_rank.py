@@ -6,7 +6,7 @@ import pandas as pd
 import pytest
 
 from daft import Window, col
-from daft.functions import row_number
+from daft.functions import dense_rank, rank, row_number
 from tests.conftest import assert_df_equals, get_tests_daft_runner_name
 
 pytestmark = pytest.mark.skipif(
@@ -335,3 +335,47 @@ def test_multi_ordering_combinations(make_df):
                             f"secondary key {['x', 'y'][not primary_key]} {('asc' if secondary_desc == 1 else 'desc')}: "
                             f"expected {expected_row_num}, got {actual_row_num}"
                         )
+
+
+def test_rank_and_dense_rank(make_df):
+    df = make_df({"category": ["A", "A", "A", "A", "B", "B"], "sales": [100, 200, 100, 300, 50, 50]})
+
+    window_spec = Window().partition_by("category").order_by("sales")
+
+    result = df.select(
+        col("category"),
+        col("sales"),
+        rank().over(window_spec).alias("rank"),
+        dense_rank().over(window_spec).alias("dense_rank"),
+    ).collect()
+
+    expected = {
+        "category": ["A", "A", "A", "A", "B", "B"],
+        "sales": [100, 200, 100, 300, 50, 50],
+        "rank": [1, 3, 1, 4, 1, 1],
+        "dense_rank": [1, 2, 1, 3, 1, 1],
+    }
+
+    assert_df_equals(result.to_pandas(), pd.DataFrame(expected), sort_key=list(expected.keys()), check_dtype=False)
+
+
+def test_lag_and_lead(make_df):
+    df = make_df({"category": ["A", "A", "A", "B", "B"], "ts": [3, 1, 2, 2, 1], "value": [30, 10, 20, 200, 100]})
+
+    window_spec = Window().partition_by("category").order_by("ts")
+
+    result = df.select(
+        col("category"),
+        col("ts"),
+        col("value").lag().over(window_spec).alias("prev"),
+        col("value").lead(1, default=-1).over(window_spec).alias("next"),
+    ).collect()
+
+    expected = {
+        "category": ["A", "A", "A", "B", "B"],
+        "ts": [3, 1, 2, 2, 1],
+        "prev": [20, None, 10, 100, None],
+        "next": [-1, 20, 30, -1, 200],
+    }
+
+    assert_df_equals(result.to_pandas(), pd.DataFrame(expected), sort_key=["category", "ts"], check_dtype=False)
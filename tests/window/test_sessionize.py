@@ -0,0 +1,65 @@
+from __future__ import annotations
+
+from datetime import datetime, timedelta
+
+import pytest
+
+import daft
+from tests.conftest import get_tests_daft_runner_name
+
+pytestmark = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Window tests only run on native runner"
+)
+
+
+def sorted_pydict(df, keys):
+    return df.sort(keys).to_pydict()
+
+
+def test_sessionize_numeric(make_df):
+    df = make_df({"user": ["a", "b", "a", "a", "b", "a"], "ts": [1, 5, 3, 20, 30, 25]})
+    result = df.sessionize("user", "ts", gap=10)
+    assert result.column_names == ["user", "ts", "session_id"]
+    assert sorted_pydict(result, ["user", "ts"]) == {
+        "user": ["a", "a", "a", "a", "b", "b"],
+        "ts": [1, 3, 20, 25, 5, 30],
+        "session_id": [0, 0, 1, 1, 0, 1],
+    }
+
+
+def test_sessionize_gap_is_inclusive(make_df):
+    df = make_df({"user": ["a"] * 3, "ts": [0, 10, 21]})
+    result = df.sessionize("user", "ts", gap=10, session_column="session")
+    assert sorted_pydict(result, ["ts"])["session"] == [0, 0, 1]
+
+
+def test_sessionize_timestamps(make_df):
+    start = datetime(2024, 1, 1)
+    df = make_df(
+        {
+            "user": ["a", "a", "a", "b"],
+            "device": ["x", "x", "y", "x"],
+            "ts": [start, start + timedelta(minutes=20), start + timedelta(minutes=5), start + timedelta(hours=1)],
+        }
+    )
+    result = df.sessionize(["user", "device"], "ts", gap=timedelta(minutes=15))
+    assert sorted_pydict(result, ["user", "device", "ts"])["session_id"] == [0, 1, 0, 0]
+
+
+def test_sessionize_across_partitions():
+    # The events of each key are spread across partitions, with sessions that span their boundaries.
+    df = daft.from_pydict({"user": ["a", "b"] * 10, "ts": [i // 2 * 3 + (30 if i >= 10 else 0) for i in range(20)]})
+    result = df.repartition(4).sessionize("user", "ts", gap=5)
+    assert sorted_pydict(result, ["user", "ts"])["session_id"] == [0] * 5 + [1] * 5 + [0] * 5 + [1] * 5
+
+
+def test_sessionize_null_timestamps(make_df):
+    df = make_df({"user": ["a", "a", "a"], "ts": [1, None, 2]})
+    result = df.sessionize("user", "ts", gap=10).where(daft.col("ts").is_null())
+    assert result.count_rows() == 1
+
+
+def test_sessionize_invalid_gap(make_df):
+    df = make_df({"user": ["a"], "ts": [1]})
+    with pytest.raises(ValueError, match="non-negative"):
+        df.sessionize("user", "ts", gap=-1)
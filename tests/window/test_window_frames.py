@@ -0,0 +1,64 @@
+from __future__ import annotations
+
+import pandas as pd
+import pytest
+
+from daft import Window, col
+from tests.conftest import assert_df_equals, get_tests_daft_runner_name
+
+pytestmark = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Window tests only run on native runner"
+)
+
+
+def test_running_sum(make_df):
+    df = make_df({"category": ["A", "A", "A", "B", "B"], "ts": [3, 1, 2, 1, 2], "value": [3, 1, 2, 10, 20]})
+
+    window_spec = (
+        Window().partition_by("category").order_by("ts").rows_between(Window.unbounded_preceding, Window.current_row)
+    )
+
+    result = df.select(col("category"), col("ts"), col("value").sum().over(window_spec).alias("running")).collect()
+
+    expected = {"category": ["A", "A", "A", "B", "B"], "ts": [3, 1, 2, 1, 2], "running": [6, 1, 3, 10, 30]}
+
+    assert_df_equals(result.to_pandas(), pd.DataFrame(expected), sort_key=["category", "ts"], check_dtype=False)
+
+
+def test_sliding_rows_with_min_periods(make_df):
+    df = make_df({"category": ["A"] * 4, "ts": [1, 2, 3, 4], "value": [1, 2, 3, 4]})
+
+    window_spec = Window().partition_by("category").order_by("ts").rows_between(-1, 1, min_periods=3)
+
+    result = df.select(col("ts"), col("value").mean().over(window_spec).alias("avg")).collect()
+
+    expected = {"ts": [1, 2, 3, 4], "avg": [None, 2.0, 3.0, None]}
+
+    assert_df_equals(result.to_pandas(), pd.DataFrame(expected), sort_key=["ts"], check_dtype=False)
+
+
+def test_range_frame(make_df):
+    df = make_df({"category": ["A"] * 5, "ts": [1, 2, 2, 5, 6], "value": [1, 1, 1, 1, 1]})
+
+    window_spec = Window().partition_by("category").order_by("ts").range_between(-1, Window.current_row)
+
+    result = df.select(col("ts"), col("value").count().over(window_spec).alias("count")).collect()
+
+    expected = {"ts": [1, 2, 2, 5, 6], "count": [1, 3, 3, 1, 2]}
+
+    assert_df_equals(result.to_pandas(), pd.DataFrame(expected), sort_key=["ts", "count"], check_dtype=False)
+
+
+def test_range_frame_descending(make_df):
+    df = make_df({"category": ["A"] * 4, "ts": [1, 2, 3, 5], "value": [1, 2, 3, 5]})
+
+    window_spec = (
+        Window().partition_by("category").order_by("ts", desc=True).range_between(Window.current_row, 2)
+    )
+
+    result = df.select(col("ts"), col("value").sum().over(window_spec).alias("sum")).collect()
+
+    # following rows hold lower values when descending
+    expected = {"ts": [1, 2, 3, 5], "sum": [1, 3, 6, 8]}
+
+    assert_df_equals(result.to_pandas(), pd.DataFrame(expected), sort_key=["ts"], check_dtype=False)